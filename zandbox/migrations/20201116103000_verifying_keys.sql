@@ -0,0 +1,6 @@
+ALTER TABLE zandbox.contracts
+    ADD COLUMN IF NOT EXISTS verifying_keys JSON NOT NULL DEFAULT '{}';
+
+UPDATE zandbox.contracts
+SET
+    verifying_keys = json_build_object('new', encode(verifying_key, 'hex'));
@@ -0,0 +1,2 @@
+ALTER TABLE zandbox.contracts
+    ADD COLUMN IF NOT EXISTS allowed_tokens JSON NOT NULL DEFAULT '[]';
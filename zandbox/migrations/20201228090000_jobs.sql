@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS zandbox.jobs (
+    id                 TEXT NOT NULL,
+
+    status             TEXT NOT NULL,
+    error              TEXT,
+
+    eth_address        BYTEA,
+    account_id         BIGINT,
+
+    created_at         TIMESTAMP NOT NULL,
+    updated_at         TIMESTAMP NOT NULL,
+
+    PRIMARY KEY        (id)
+);
@@ -0,0 +1,87 @@
+//!
+//! The privileged request authorization error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+///
+/// The privileged request authorization error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The nonce or signature header is missing.
+    CredentialsMissing,
+    /// The nonce has not been issued by the server or has already been used.
+    NonceUnknown(String),
+    /// The nonce lifetime has elapsed.
+    NonceExpired(String),
+    /// The signature is malformed or the signer cannot be recovered from it.
+    InvalidSignature(String),
+    /// The contract has no owner recorded, e.g. it has been published by an older server.
+    OwnerUnknown(String),
+    /// The nonce has been signed by someone other than the contract owner.
+    NotOwner(String),
+}
+
+impl Error {
+    ///
+    /// The error variant name, which is used as the `error` label of the errors metric.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::CredentialsMissing => "CredentialsMissing",
+            Self::NonceUnknown(..) => "NonceUnknown",
+            Self::NonceExpired(..) => "NonceExpired",
+            Self::InvalidSignature(..) => "InvalidSignature",
+            Self::OwnerUnknown(..) => "OwnerUnknown",
+            Self::NotOwner(..) => "NotOwner",
+        }
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::CredentialsMissing => StatusCode::UNAUTHORIZED,
+            Self::NonceUnknown(..) => StatusCode::UNAUTHORIZED,
+            Self::NonceExpired(..) => StatusCode::UNAUTHORIZED,
+            Self::InvalidSignature(..) => StatusCode::UNAUTHORIZED,
+            Self::OwnerUnknown(..) => StatusCode::FORBIDDEN,
+            Self::NotOwner(..) => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::CredentialsMissing => format!(
+                "The `{}` and `{}` headers are required",
+                zinc_const::zandbox::HEADER_AUTH_NONCE,
+                zinc_const::zandbox::HEADER_AUTH_SIGNATURE
+            ),
+            Self::NonceUnknown(nonce) => format!("Nonce `{}` is unknown or already used", nonce),
+            Self::NonceExpired(nonce) => format!("Nonce `{}` has expired", nonce),
+            Self::InvalidSignature(inner) => format!("Invalid signature: {}", inner),
+            Self::OwnerUnknown(address) => {
+                format!("Contract with address {} has no owner", address)
+            }
+            Self::NotOwner(address) => format!("Signer {} is not the contract owner", address),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
@@ -0,0 +1,100 @@
+//!
+//! The privileged request authorization.
+//!
+//! The privileged operations, e.g. re-publishing a contract or viewing its private storage
+//! fields, are only allowed to the contract owner, who is the sender of the initial transfer.
+//! The owner requests a nonce, signs it with the EIP-191 personal message signature, and sends
+//! the nonce and signature in the request headers, which are checked with `authorize`.
+//!
+
+pub mod error;
+pub mod nonces;
+
+use actix_web::dev::Payload;
+use actix_web::FromRequest;
+use actix_web::HttpRequest;
+use futures::future::Ready;
+
+use zksync::web3::types::Address;
+
+use zinc_zksync::Credentials;
+
+use self::error::Error;
+use self::nonces::Nonces;
+
+///
+/// The privileged request credentials extractor.
+///
+/// The credentials are optional, since the same endpoints serve both the ordinary and the
+/// privileged requests. The malformed credentials are rejected before the handler is called.
+///
+#[derive(Debug, Default)]
+pub struct Authorization(pub Option<Credentials>);
+
+impl Authorization {
+    ///
+    /// Parses the credentials from the nonce and signature header values.
+    ///
+    pub fn try_from_headers(nonce: Option<&str>, signature: Option<&str>) -> Result<Self, Error> {
+        match (nonce, signature) {
+            (None, None) => Ok(Self(None)),
+            (Some(nonce), Some(signature)) => Credentials::try_from_headers(nonce, signature)
+                .map(|credentials| Self(Some(credentials)))
+                .map_err(|error| Error::InvalidSignature(error.to_string())),
+            _ => Err(Error::CredentialsMissing),
+        }
+    }
+}
+
+impl FromRequest for Authorization {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+    type Config = ();
+
+    fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+
+        futures::future::ready(Self::try_from_headers(
+            header(zinc_const::zandbox::HEADER_AUTH_NONCE),
+            header(zinc_const::zandbox::HEADER_AUTH_SIGNATURE),
+        ))
+    }
+}
+
+///
+/// Checks that the request is signed by the contract `owner` and consumes its nonce.
+///
+/// Returns the address of the signer.
+///
+pub fn authorize(
+    nonces: &Nonces,
+    authorization: &Authorization,
+    contract_address: Address,
+    owner: Option<Address>,
+) -> Result<Address, Error> {
+    let credentials = authorization.0.as_ref().ok_or(Error::CredentialsMissing)?;
+
+    nonces.take(credentials.nonce.as_str())?;
+
+    let signer = credentials
+        .recover_signer()
+        .map_err(|error| Error::InvalidSignature(error.to_string()))?;
+
+    let owner = owner.ok_or_else(|| {
+        Error::OwnerUnknown(
+            serde_json::to_string(&contract_address).expect(zinc_const::panic::DATA_CONVERSION),
+        )
+    })?;
+    if signer != owner {
+        return Err(Error::NotOwner(
+            serde_json::to_string(&signer).expect(zinc_const::panic::DATA_CONVERSION),
+        ));
+    }
+
+    Ok(signer)
+}
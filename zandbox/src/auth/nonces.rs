@@ -0,0 +1,89 @@
+//!
+//! The privileged request nonces.
+//!
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use rustc_hex::ToHex;
+
+use zksync::web3::types::H256;
+
+use crate::auth::error::Error;
+
+///
+/// The privileged request nonces issued by the server.
+///
+/// A nonce can be used only once, so a captured signature cannot be replayed. The expired
+/// nonces are removed when a new one is issued.
+///
+#[derive(Debug)]
+pub struct Nonces {
+    /// The nonce lifetime.
+    lifetime: Duration,
+    /// The issue times of the unused nonces.
+    inner: Mutex<HashMap<String, Instant>>,
+}
+
+impl Default for Nonces {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(
+            zinc_const::zandbox::AUTH_NONCE_LIFETIME,
+        ))
+    }
+}
+
+impl Nonces {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            lifetime,
+            inner: Mutex::new(HashMap::new()),
+        }
+    }
+
+    ///
+    /// The nonce lifetime.
+    ///
+    pub fn lifetime(&self) -> Duration {
+        self.lifetime
+    }
+
+    ///
+    /// Issues a new random nonce.
+    ///
+    pub fn issue(&self) -> String {
+        let mut random = H256::default();
+        random.randomize();
+        let nonce: String = random.as_bytes().to_hex();
+
+        let mut nonces = self.inner.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+        let lifetime = self.lifetime;
+        nonces.retain(|_nonce, issued_at| issued_at.elapsed() < lifetime);
+        nonces.insert(nonce.clone(), Instant::now());
+
+        nonce
+    }
+
+    ///
+    /// Consumes the `nonce`, checking that it has been issued and has not expired yet.
+    ///
+    pub fn take(&self, nonce: &str) -> Result<(), Error> {
+        let issued_at = self
+            .inner
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .remove(nonce)
+            .ok_or_else(|| Error::NonceUnknown(nonce.to_owned()))?;
+
+        if issued_at.elapsed() >= self.lifetime {
+            return Err(Error::NonceExpired(nonce.to_owned()));
+        }
+
+        Ok(())
+    }
+}
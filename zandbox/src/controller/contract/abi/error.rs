@@ -0,0 +1,56 @@
+//!
+//! The contract ABI resource GET error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+///
+/// The contract ABI resource GET error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+    /// The contract has been published without the ABI, e.g. by an older Zargo version.
+    AbiNotFound(String),
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::AbiNotFound(..) => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+            Self::AbiNotFound(address) => {
+                format!(
+                    "Contract with address {} has been published without ABI",
+                    address
+                )
+            }
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
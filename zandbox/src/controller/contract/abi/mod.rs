@@ -0,0 +1,46 @@
+//!
+//! The contract ABI resource GET method module.
+//!
+
+pub mod error;
+pub mod request;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use serde_json::Value as JsonValue;
+
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Path as RequestPath;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Return the contract ABI document written by the compiler to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+) -> crate::Result<JsonValue, Error> {
+    let path = path.into_inner();
+    let address = serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let abi = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&path.address)
+        .ok_or_else(|| Error::ContractNotFound(address.clone()))?
+        .abi
+        .clone()
+        .ok_or(Error::AbiNotFound(address))?;
+
+    Ok(Response::new_with_data(StatusCode::OK, abi))
+}
@@ -0,0 +1,210 @@
+//!
+//! The contract resource POST method `call` transaction batch.
+//!
+
+use std::time::Instant;
+
+use async_std::sync::Mutex as AsyncMutex;
+use futures::future::LocalBoxFuture;
+use num_old::BigUint;
+
+use zksync::web3::types::Address;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::TxHash;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::Nonce;
+use zksync_types::Token;
+
+use crate::gateway::IZkSyncWallet;
+use crate::shared_data::metrics::Metrics;
+
+use super::error::Error;
+
+///
+/// The zkSync transaction batch sender.
+///
+/// Is extracted from the handler in order to test the nonce locking and retrying logic.
+///
+pub trait IBatchSender {
+    ///
+    /// Fetches the committed nonce of the contract account.
+    ///
+    fn committed_nonce(&self) -> LocalBoxFuture<'_, Result<Nonce, Error>>;
+
+    ///
+    /// Signs the contract transfers starting from `nonce` and sends the batch to zkSync.
+    ///
+    /// Must return `Error::NonceMismatch` if zkSync rejects the batch due to an outdated nonce.
+    ///
+    fn send(&self, nonce: Nonce) -> LocalBoxFuture<'_, Result<Vec<TxHash>, Error>>;
+}
+
+///
+/// Fetches the nonce, signs, and sends the batch while holding the contract `lock`.
+///
+/// If zkSync rejects the batch due to a nonce mismatch, the nonce is fetched again and the
+/// batch is re-signed and re-sent once.
+///
+pub async fn send_locked<S>(
+    sender: &S,
+    lock: &AsyncMutex<()>,
+    metrics: &Metrics,
+) -> Result<Vec<TxHash>, Error>
+where
+    S: IBatchSender,
+{
+    let wait_time = Instant::now();
+    let _guard = lock.lock().await;
+    metrics.record_nonce_lock_wait(wait_time.elapsed());
+
+    let nonce = sender.committed_nonce().await?;
+    let submission_time = Instant::now();
+    let result = sender.send(nonce).await;
+    metrics.record_zksync_submission(submission_time.elapsed());
+    match result {
+        Err(Error::NonceMismatch(message)) => {
+            log::warn!(
+                "The batch with nonce {} is rejected: {}. Retrying",
+                nonce,
+                message
+            );
+            metrics.record_nonce_retry();
+
+            let nonce = sender.committed_nonce().await?;
+            let submission_time = Instant::now();
+            let result = sender.send(nonce).await;
+            metrics.record_zksync_submission(submission_time.elapsed());
+            result
+        }
+        result => result,
+    }
+}
+
+///
+/// The contract transfer, whose token is already resolved and checked.
+///
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    /// The transferred token.
+    pub token: Token,
+    /// The packable transfer amount.
+    pub amount: BigUint,
+    /// The transfer recipient.
+    pub recipient: Address,
+}
+
+impl Transfer {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(token: Token, amount: BigUint, recipient: Address) -> Self {
+        Self {
+            token,
+            amount,
+            recipient,
+        }
+    }
+}
+
+///
+/// The transaction batch, which consists of the client transactions and the contract transfers.
+///
+pub struct Batch<'a> {
+    /// The contract wallet.
+    wallet: &'a dyn IZkSyncWallet,
+    /// The client transactions, which are already signed by the client.
+    transactions: Vec<(ZkSyncTx, PackedEthSignature)>,
+    /// The contract transfers, which are signed with the current nonce before each sending.
+    transfers: Vec<Transfer>,
+}
+
+impl<'a> Batch<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        wallet: &'a dyn IZkSyncWallet,
+        transactions: Vec<(ZkSyncTx, PackedEthSignature)>,
+        transfers: Vec<Transfer>,
+    ) -> Self {
+        Self {
+            wallet,
+            transactions,
+            transfers,
+        }
+    }
+
+    ///
+    /// Returns the labels of the batch transactions in the order they are sent.
+    ///
+    /// The client transaction is labeled `client_transfer`, or `client_transfer[i]` if there
+    /// are several of them, and the contract transfers are labeled `contract_transfer[i]`.
+    ///
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels = Vec::with_capacity(self.transactions.len() + self.transfers.len());
+
+        if self.transactions.len() == 1 {
+            labels.push("client_transfer".to_owned());
+        } else {
+            labels.extend(
+                (0..self.transactions.len()).map(|index| format!("client_transfer[{}]", index)),
+            );
+        }
+        labels
+            .extend((0..self.transfers.len()).map(|index| format!("contract_transfer[{}]", index)));
+
+        labels
+    }
+}
+
+impl<'a> IBatchSender for Batch<'a> {
+    fn committed_nonce(&self) -> LocalBoxFuture<'_, Result<Nonce, Error>> {
+        Box::pin(async move { Ok(self.wallet.account_info().await?.committed.nonce) })
+    }
+
+    fn send(&self, mut nonce: Nonce) -> LocalBoxFuture<'_, Result<Vec<TxHash>, Error>> {
+        Box::pin(async move {
+            let mut transactions: Vec<(ZkSyncTx, Option<PackedEthSignature>)> = self
+                .transactions
+                .iter()
+                .map(|(tx, signature)| (tx.to_owned(), Some(signature.to_owned())))
+                .collect();
+
+            for transfer in self.transfers.iter() {
+                log::debug!(
+                    "Signing {} {} to {} with nonce {}",
+                    zksync_utils::format_units(&transfer.amount, transfer.token.decimals),
+                    transfer.token.symbol,
+                    serde_json::to_string(&transfer.recipient)
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    nonce,
+                );
+
+                let (tx, signature) = self
+                    .wallet
+                    .sign_transfer(
+                        transfer.token.to_owned(),
+                        transfer.amount.to_owned(),
+                        transfer.recipient,
+                        nonce,
+                    )
+                    .await?;
+                transactions.push((ZkSyncTx::Transfer(Box::new(tx)), signature));
+
+                nonce += 1;
+            }
+
+            self.wallet
+                .send_txs_batch(transactions)
+                .await
+                .map_err(|error| {
+                    let message = format!("{:?}", error);
+                    if message.to_lowercase().contains("nonce mismatch") {
+                        Error::NonceMismatch(message)
+                    } else {
+                        Error::ZkSyncClient(error)
+                    }
+                })
+        })
+    }
+}
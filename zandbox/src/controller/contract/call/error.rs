@@ -5,12 +5,18 @@
 use std::fmt;
 
 use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
 use actix_web::ResponseError;
+use serde_json::json;
 
 use zinc_build::ValueError as BuildValueError;
 use zinc_vm::RuntimeError;
 use zinc_zksync::TransactionError;
 
+use crate::storage::error::Error as StorageError;
+
+use super::response::Transaction as ResponseTransaction;
+
 ///
 /// The contract resource POST `call` error.
 ///
@@ -20,27 +26,61 @@ pub enum Error {
     ContractNotFound(String),
     /// The contract with the specified address is locked.
     ContractLocked(String),
+    /// The contract with the specified address has been archived.
+    ContractArchived(String),
     /// The specified method does not exist in the contract.
     MethodNotFound(String),
     /// The immutable method must be called via the `query` endpoint.
     MethodIsImmutable(String),
+    /// The constructor is only run once while publishing the contract.
+    MethodIsConstructor(String),
     /// Invalid contract method arguments.
     InvalidInput(BuildValueError),
     /// The contract method input transaction is invalid.
     Transaction(TransactionError),
     /// Token with such identifier cannot be resolved by zkSync.
     TokenNotFound(String),
+    /// The payable method received a transfer in a token other than the expected one.
+    PayableTokenMismatch {
+        /// The method name.
+        method: String,
+        /// The expected token symbol.
+        expected: String,
+    },
+    /// The transaction sender does not match the method `#[only(field)]` restriction.
+    SenderNotAllowed {
+        /// The method name.
+        method: String,
+        /// The contract storage field holding the allowed sender address.
+        field: String,
+    },
+    /// The contract method attempted a transfer in a token missing from the contract allow-list.
+    TokenNotAllowed {
+        /// The token symbol.
+        token: String,
+        /// The method name.
+        method: String,
+    },
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
     /// The PostgreSQL database error.
     Database(sqlx::Error),
+    /// The contract storage loading error.
+    Storage(StorageError),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
     /// The ZkSync server signer error.
     ZkSyncSigner(zksync_eth_signer::error::SignerError),
     /// The ZkSync transfer errors.
-    TransferFailure(String),
+    TransferFailure {
+        /// The failure reason reported by zkSync.
+        reason: String,
+        /// The batch transactions with their statuses.
+        transactions: Vec<ResponseTransaction>,
+    },
+    /// The transaction batch nonce is outdated even after re-fetching it.
+    NonceMismatch(String),
 }
 
 impl From<TransactionError> for Error {
@@ -55,6 +95,12 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<StorageError> for Error {
+    fn from(inner: StorageError) -> Self {
+        Self::Storage(inner)
+    }
+}
+
 impl From<zksync::error::ClientError> for Error {
     fn from(inner: zksync::error::ClientError) -> Self {
         Self::ZkSyncClient(inner)
@@ -67,22 +113,79 @@ impl From<zksync_eth_signer::error::SignerError> for Error {
     }
 }
 
+impl Error {
+    ///
+    /// The error variant name, which is used as the `error` label of the errors metric.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ContractNotFound(..) => "ContractNotFound",
+            Self::ContractLocked(..) => "ContractLocked",
+            Self::ContractArchived(..) => "ContractArchived",
+            Self::MethodNotFound(..) => "MethodNotFound",
+            Self::MethodIsImmutable(..) => "MethodIsImmutable",
+            Self::MethodIsConstructor(..) => "MethodIsConstructor",
+            Self::InvalidInput(..) => "InvalidInput",
+            Self::Transaction(..) => "Transaction",
+            Self::TokenNotFound(..) => "TokenNotFound",
+            Self::PayableTokenMismatch { .. } => "PayableTokenMismatch",
+            Self::SenderNotAllowed { .. } => "SenderNotAllowed",
+            Self::TokenNotAllowed { .. } => "TokenNotAllowed",
+            Self::RuntimeError(..) => "RuntimeError",
+            Self::Database(..) => "Database",
+            Self::Storage(..) => "Storage",
+            Self::ZkSyncClient(..) => "ZkSyncClient",
+            Self::ZkSyncSigner(..) => "ZkSyncSigner",
+            Self::TransferFailure { .. } => "TransferFailure",
+            Self::NonceMismatch(..) => "NonceMismatch",
+        }
+    }
+}
+
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
             Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
             Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ContractArchived(..) => StatusCode::GONE,
             Self::MethodNotFound(..) => StatusCode::BAD_REQUEST,
             Self::MethodIsImmutable(..) => StatusCode::BAD_REQUEST,
+            Self::MethodIsConstructor(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
             Self::Transaction(..) => StatusCode::BAD_REQUEST,
             Self::TokenNotFound(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PayableTokenMismatch { .. } => StatusCode::BAD_REQUEST,
+            Self::SenderNotAllowed { .. } => StatusCode::FORBIDDEN,
+            Self::TokenNotAllowed { .. } => StatusCode::FORBIDDEN,
 
+            Self::RuntimeError(RuntimeError::PayableMethodWithoutTransfer { .. }) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::RuntimeError(RuntimeError::NonPayableMethodWithTransfer { .. }) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::RuntimeError(RuntimeError::AlreadyInitialized { .. }) => StatusCode::BAD_REQUEST,
+            Self::RuntimeError(RuntimeError::StorageLayoutMismatch { .. }) => StatusCode::CONFLICT,
             Self::RuntimeError(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(StorageError::ZkSyncClient(..)) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(..) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ZkSyncClient(..) => StatusCode::SERVICE_UNAVAILABLE,
             Self::ZkSyncSigner(..) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::TransferFailure { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::NonceMismatch(..) => StatusCode::CONFLICT,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::RuntimeError(inner) => HttpResponse::build(self.status_code())
+                .json(json!({ "message": self.to_string(), "error": inner.to_json() })),
+            Self::TransferFailure { transactions, .. } => HttpResponse::build(self.status_code())
+                .json(json!({ "message": self.to_string(), "transactions": transactions })),
+            _ => HttpResponse::build(self.status_code())
+                .content_type("text/plain; charset=utf-8")
+                .body(self.to_string()),
         }
     }
 }
@@ -103,21 +206,42 @@ impl fmt::Display for Error {
                 format!("Contract with address {} not found", address)
             }
             Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::ContractArchived(address) => {
+                format!("Contract with address {} has been archived", address)
+            }
             Self::MethodNotFound(name) => format!("Method `{}` not found", name),
             Self::MethodIsImmutable(name) => {
                 format!("Method `{}` is immutable: use 'query' instead", name)
             }
+            Self::MethodIsConstructor(name) => format!(
+                "Method `{}` is the constructor, which is only run while publishing",
+                name
+            ),
             Self::InvalidInput(inner) => format!("Input: {}", inner),
             Self::Transaction(inner) => format!("Transaction: {}", inner),
             Self::TokenNotFound(token_id) => {
                 format!("Token with identifier `{}` cannot be resolved", token_id)
             }
+            Self::PayableTokenMismatch { method, expected } => {
+                format!("Method `{}` accepts only {} transfers", method, expected)
+            }
+            Self::SenderNotAllowed { method, field } => format!(
+                "Method `{}` can be called only by the address stored in the `{}` field",
+                method, field
+            ),
+            Self::TokenNotAllowed { token, method } => format!(
+                "Method `{}` cannot transfer {}, as the token is not allowed for the contract",
+                method, token
+            ),
 
+            Self::RuntimeError(RuntimeError::RequireError(message)) => message.to_owned(),
             Self::RuntimeError(inner) => format!("Runtime: {:?}", inner),
             Self::Database(inner) => format!("Database: {:?}", inner),
+            Self::Storage(inner) => format!("Storage: {}", inner),
             Self::ZkSyncClient(inner) => format!("ZkSync: {:?}", inner),
             Self::ZkSyncSigner(inner) => format!("ZkSync: {:?}", inner),
-            Self::TransferFailure(inner) => format!("Transfer failure: {}", inner),
+            Self::TransferFailure { reason, .. } => format!("Transfer failure: {}", reason),
+            Self::NonceMismatch(inner) => format!("Nonce mismatch: {}", inner),
         };
 
         log::warn!("{}", error);
@@ -2,59 +2,92 @@
 //! The contract resource POST method `call` module.
 //!
 
+pub mod batch;
 pub mod error;
 pub mod request;
+pub mod response;
 
 use std::sync::Arc;
 use std::sync::RwLock;
-use std::time::Duration;
+use std::time::Instant;
 
 use actix_web::http::StatusCode;
 use actix_web::web;
-use num_old::BigUint;
-use num_old::Zero;
 use serde_json::json;
 use serde_json::Value as JsonValue;
 
-use zksync::operations::SyncTransactionHandle;
-use zksync_eth_signer::PrivateKeySigner;
-use zksync_types::tx::ZkSyncTx;
+use zksync_types::TokenLike;
 
 use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::ContractInput;
-use zinc_zksync::Transaction;
 use zinc_zksync::TransactionMsg;
 
 use crate::database::model::field::select::Input as FieldSelectInput;
 use crate::response::Response;
+use crate::shared_data::contract::Contract as SharedDataContract;
+use crate::shared_data::metrics::Metrics;
+use crate::shared_data::metrics::HANDLER_CALL;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
 
+use self::batch::Batch;
+use self::batch::Transfer as BatchTransfer;
 use self::error::Error;
 use self::request::Body as RequestBody;
 use self::request::Query as RequestQuery;
+use self::response::Status as ResponseStatus;
+use self::response::Transaction as ResponseTransaction;
 
 ///
 /// The HTTP request handler.
 ///
 /// Sequence:
 /// 1. Get the contract from the in-memory cache.
-/// 2. Extract the called method from its metadata and check if it is mutable.
-/// 3. Parse the method input arguments.
-/// 4. Get the contract storage from data sources and convert it to the Zinc VM representation.
-/// 5. Run the method on the Zinc VM.
-/// 6. Extract the storage with the updated state from the Zinc VM.
-/// 7. Create a transactions array from the client and contract transfers.
-/// 8. Send the transactions to zkSync and store its handles.
-/// 9. Wait for all transactions to be committed.
-/// 10. Update the contract storage state in the database.
-/// 11. Send the contract method execution result back to the client.
+/// 2. Extract the called method from its metadata and check if it is mutable and is not the
+///    constructor, which is only run while publishing.
+/// 3. Check if the attached transfers match the method `#[payable]` attribute.
+/// 4. Parse the method input arguments.
+/// 5. Get the contract storage from data sources and convert it to the Zinc VM representation.
+/// 6. Check if the transaction sender matches the method `#[only]` attribute.
+/// 7. Run the method on the Zinc VM.
+/// 8. Extract the storage with the updated state from the Zinc VM.
+/// 9. Create a transactions array from the client and contract transfers, checking the
+///    contract transfer tokens against the contract token allow-list.
+/// 10. Fetch the contract nonce, sign the contract transfers, and send the transactions to
+///     zkSync while holding the contract nonce lock, retrying once on a nonce mismatch.
+/// 11. Wait for all transactions to be committed. If the batch has failed, query the status
+///     of each transaction and return them along with the error.
+/// 12. Update the contract storage state in the database.
+/// 13. Send the contract method execution result and the labeled transaction hashes back
+///     to the client.
 ///
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
     query: web::Query<RequestQuery>,
     body: web::Json<RequestBody>,
+) -> crate::Result<JsonValue, Error> {
+    let metrics = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .metrics
+        .clone();
+
+    let result = execute(app_data, query, body, metrics.as_ref()).await;
+    if let Err(ref error) = result {
+        metrics.record_error(HANDLER_CALL, error.kind());
+    }
+    result
+}
+
+///
+/// Executes the `handle` sequence, recording the timings into `metrics`.
+///
+async fn execute(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+    body: web::Json<RequestBody>,
+    metrics: &Metrics,
 ) -> crate::Result<JsonValue, Error> {
     let query = query.into_inner();
     let body = body.into_inner();
@@ -71,23 +104,31 @@ pub async fn handle(
         serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
     );
 
-    let contract = app_data
-        .read()
-        .expect(zinc_const::panic::SYNCHRONIZATION)
-        .contracts
-        .get(&query.address)
-        .cloned()
-        .ok_or_else(|| {
-            Error::ContractNotFound(
-                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
-            )
-        })?;
+    let contract = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        shared_data
+            .contracts
+            .get(&query.address)
+            .cloned()
+            .ok_or_else(|| {
+                let address = serde_json::to_string(&query.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+                if shared_data.archived_contracts.contains_key(&query.address) {
+                    Error::ContractArchived(address)
+                } else {
+                    Error::ContractNotFound(address)
+                }
+            })?
+    };
     let account_id = contract.account_id.ok_or_else(|| {
         Error::ContractLocked(
             serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
         )
     })?;
 
+    if query.method.as_str() == zinc_const::contract::CONSTRUCTOR_NAME {
+        return Err(Error::MethodIsConstructor(query.method));
+    }
     let method = match contract.build.methods.get(query.method.as_str()).cloned() {
         Some(method) => method,
         None => return Err(Error::MethodNotFound(query.method)),
@@ -97,60 +138,122 @@ pub async fn handle(
     }
 
     log::debug!("Initializing the contract wallet");
-    let provider = zksync::Provider::new(query.network);
-    let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-        query.address,
-        PrivateKeySigner::new(contract.eth_private_key),
-        query.network,
-    )
-    .await?;
-    let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+    let gateway = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .gateway
+        .clone();
+    let wallet = gateway
+        .connect(query.network, query.address, contract.eth_private_key)
+        .await?;
 
-    let input_value = BuildValue::try_from_typed_json(body.arguments, method.input)
-        .map_err(Error::InvalidInput)?;
+    let mut transaction_msgs: Vec<TransactionMsg> = Vec::new();
+    for transaction in (&body.transaction).iter() {
+        let transaction_msg = transaction.try_to_msg(wallet.tokens())?;
+        log::debug!("transactionMsg:{:?}", transaction_msg);
+        transaction_msgs.push(transaction_msg);
+    }
+
+    log::debug!("Checking the transfers attached to the call");
+    zinc_vm::ContractFacade::check_transfers(&method, transaction_msgs.as_slice())
+        .map_err(Error::RuntimeError)?;
+    if let Some(token_symbol) = method.payable_token {
+        let token = wallet
+            .tokens()
+            .resolve(TokenLike::Symbol(token_symbol.clone()))
+            .ok_or_else(|| Error::TokenNotFound(token_symbol.clone()))?;
+        if transaction_msgs
+            .iter()
+            .filter(|transaction_msg| transaction_msg.is_transfer())
+            .any(|transaction_msg| transaction_msg.token_address != token.address)
+        {
+            return Err(Error::PayableTokenMismatch {
+                method: query.method,
+                expected: token_symbol,
+            });
+        }
+    }
+
+    let settings = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings;
+    let input_value = BuildValue::try_from_typed_json_with_depth_limit(
+        body.arguments,
+        method.input,
+        settings.json_depth_limit,
+    )
+    .map_err(Error::InvalidInput)?;
 
     log::debug!("Loading the pre-transaction contract storage");
-    let database_fields = postgresql
-        .select_fields(FieldSelectInput::new(account_id))
+    let database_fields = metrics
+        .time_database_query(
+            "select_fields",
+            postgresql.select_fields(FieldSelectInput::new(account_id)),
+        )
         .await?;
     let storage = Storage::new_with_data(
         database_fields,
         contract.build.storage.as_slice(),
         contract.eth_address,
-        &wallet,
+        wallet.as_ref(),
     )
     .await?;
+    let balances = storage.balances();
+
+    if let Some(field) = method.only {
+        log::debug!(
+            "Checking the transaction sender against the `{}` field",
+            field
+        );
+        let is_allowed = transaction_msgs
+            .first()
+            .map(|transaction_msg| {
+                storage.is_field_address(field.as_str(), &transaction_msg.sender)
+            })
+            .unwrap_or_default();
+        if !is_allowed {
+            return Err(Error::SenderNotAllowed {
+                method: query.method,
+                field,
+            });
+        }
+    }
 
     log::debug!("Running the contract method on the virtual machine");
     let method = query.method;
+    let method_to_run = method.clone();
     let contract_build = contract.build;
-    let vm_time = std::time::Instant::now();
-    log::debug!("input_value:{:?}", input_value);
-    let mut transaction_msgs: Vec<TransactionMsg> = Vec::new();
-
-    for transaction in (&body.transaction).iter() {
-        let transaction_msg = transaction.try_to_msg(&wallet)?;
-        log::debug!("transactionMsg:{:?}", transaction_msg);
-        transaction_msgs.push(transaction_msg);
-    }
+    let vm_time = Instant::now();
 
     let output = async_std::task::spawn_blocking(move || {
-        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(ContractInput::new(
+        let mut input = ContractInput::new(
             input_value,
             storage.into_build(),
-            method,
+            method_to_run,
             transaction_msgs,
-        ))
+        );
+        input.balances = balances;
+        input.privacy = settings.privacy;
+        input.environment = crate::environment::current();
+
+        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(input)
     })
-    .await
-    .map_err(Error::RuntimeError)?;
+    .await;
+    metrics.record_vm_execution(
+        HANDLER_CALL,
+        contract.name.as_str(),
+        method.as_str(),
+        vm_time.elapsed(),
+    );
     log::debug!("VM executed in {} ms", vm_time.elapsed().as_millis());
+    let output = output.map_err(Error::RuntimeError)?;
 
     log::debug!("Loading the post-transaction contract storage");
     let storage = Storage::from_build(output.storage).into_database_update(account_id);
 
     log::debug!("Building the transaction list");
-    let mut transactions = body.transaction;
+    let transactions = body.transaction;
     // if let ZkSyncTx::Transfer(ref transfer) = transactions[0].tx {
     //     log::debug!("transfer:{:?}", transfer);
     //     let token = wallet
@@ -169,17 +272,12 @@ pub async fn handle(
     //     );
     // }
 
-    let mut nonce = wallet
-        .provider
-        .account_info(query.address)
-        .await?
-        .committed
-        .nonce;
     log::debug!("output:{:?}", output.transfers);
+    let mut transfers = Vec::with_capacity(output.transfers.len());
     for transfer in output.transfers.into_iter() {
         let recipient = transfer.recipient.into();
         let token = wallet
-            .tokens
+            .tokens()
             .resolve(
                 zinc_zksync::eth_address_from_vec(transfer.token_address.to_bytes_be().to_vec())
                     .into(),
@@ -191,30 +289,17 @@ pub async fn handle(
                         .to_str_radix(zinc_const::base::HEXADECIMAL),
                 )
             })?;
+        if !SharedDataContract::is_token_allowed(contract.allowed_tokens.as_slice(), &token) {
+            return Err(Error::TokenNotAllowed {
+                token: token.symbol,
+                method,
+            });
+        }
         let amount = zksync::utils::closest_packable_token_amount(
             &zinc_zksync::num_compat_backward(transfer.amount),
         );
-        let fee = BigUint::zero();
 
-        log::debug!(
-            "Sending {} {} from {} to {} fee {}",
-            zksync_utils::format_units(&amount, token.decimals),
-            token.symbol,
-            serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
-            serde_json::to_string(&recipient).expect(zinc_const::panic::DATA_CONVERSION),
-            fee.to_string(),
-        );
-
-        let (transfer, signature) = wallet
-            .signer
-            .sign_transfer(token, amount, fee, recipient, nonce)
-            .await?;
-        transactions.push(Transaction::new(
-            ZkSyncTx::Transfer(Box::new(transfer)),
-            signature.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-        ));
-
-        nonce += 1;
+        transfers.push(BatchTransfer::new(token, amount, recipient));
     }
 
     log::debug!(
@@ -222,50 +307,67 @@ pub async fn handle(
         query.network,
         &transactions
     );
-    let handles: Vec<SyncTransactionHandle> = wallet
-        .provider
-        .send_txs_batch(
-            transactions
-                .into_iter()
-                .map(|transaction| {
-                    (
-                        transaction.tx,
-                        Some(transaction.ethereum_signature.signature),
-                    )
-                })
-                .collect(),
-            None,
-        )
-        .await?
+    let batch = Batch::new(
+        wallet.as_ref(),
+        transactions
+            .into_iter()
+            .map(|transaction| (transaction.tx, transaction.ethereum_signature.signature))
+            .collect(),
+        transfers,
+    );
+    let nonce_lock = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .nonce_locks
+        .get(query.address);
+    let labels = batch.labels();
+    let tx_hashes = batch::send_locked(&batch, nonce_lock.as_ref(), metrics).await?;
+    let mut transactions: Vec<ResponseTransaction> = tx_hashes
         .into_iter()
-        .map(|tx_hash| {
-            let mut handle = SyncTransactionHandle::new(tx_hash, wallet.provider.clone())
-                .commit_timeout(Duration::from_secs(10));
-            handle
-                .polling_interval(Duration::from_millis(200))
-                .expect("Validated inside the method");
-            handle
-        })
+        .zip(labels.into_iter())
+        .map(|(hash, label)| ResponseTransaction::new(hash, label, ResponseStatus::Pending))
         .collect();
 
-    if let Some(handle) = handles.last() {
+    if let Some(last) = transactions.last() {
         log::debug!("Waiting for the batch transaction to be committed");
 
-        let tx_info = handle.wait_for_commit().await?;
+        let commit_wait_time = Instant::now();
+        let tx_info = wallet.wait_for_commit(last.hash.to_owned()).await;
+        metrics.record_zksync_commit_wait(commit_wait_time.elapsed());
+        let tx_info = tx_info?;
         if !tx_info.success.unwrap_or_default() {
-            return Err(Error::TransferFailure(
-                tx_info
-                    .fail_reason
-                    .unwrap_or_else(|| "Unknown error".to_owned()),
-            ));
+            let reason = tx_info
+                .fail_reason
+                .unwrap_or_else(|| "Unknown error".to_owned());
+
+            let last_index = transactions.len() - 1;
+            for (index, transaction) in transactions.iter_mut().enumerate() {
+                transaction.status = if index == last_index {
+                    ResponseStatus::Failed
+                } else {
+                    ResponseStatus::from(&wallet.tx_info(transaction.hash.to_owned()).await?)
+                };
+            }
+
+            return Err(Error::TransferFailure {
+                reason,
+                transactions,
+            });
+        }
+
+        for transaction in transactions.iter_mut() {
+            transaction.status = ResponseStatus::Committed;
         }
     }
 
     log::debug!("Committing the contract storage state to the database");
-    postgresql.update_fields(storage).await?;
+    metrics
+        .time_database_query("update_fields", postgresql.update_fields(storage))
+        .await?;
 
     let response = json!({
         "output": output.result.into_json(),
+        "transactions": transactions,
     });
 
     log::debug!("The call has been successfully executed");
@@ -0,0 +1,58 @@
+//!
+//! The contract resource POST method `call` response.
+//!
+
+use serde::Serialize;
+
+use zksync::types::TransactionInfo;
+use zksync_types::tx::TxHash;
+
+///
+/// The zkSync transaction sent while calling the contract method.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct Transaction {
+    /// The zkSync transaction hash.
+    pub hash: TxHash,
+    /// The logical transfer the transaction corresponds to, e.g. `contract_transfer[0]`.
+    pub label: String,
+    /// The transaction status.
+    pub status: Status,
+}
+
+impl Transaction {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(hash: TxHash, label: String, status: Status) -> Self {
+        Self {
+            hash,
+            label,
+            status,
+        }
+    }
+}
+
+///
+/// The zkSync transaction status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The transaction has not been executed yet.
+    Pending,
+    /// The transaction has been committed successfully.
+    Committed,
+    /// The transaction has failed.
+    Failed,
+}
+
+impl From<&TransactionInfo> for Status {
+    fn from(info: &TransactionInfo) -> Self {
+        match (info.executed, info.success) {
+            (true, Some(true)) => Self::Committed,
+            (true, _) => Self::Failed,
+            (false, _) => Self::Pending,
+        }
+    }
+}
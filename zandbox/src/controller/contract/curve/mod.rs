@@ -23,7 +23,8 @@ use self::response::Instance as ResponseInstance;
 ///
 /// Sequence:
 /// 1. Get all the contract instances with the name 'curve' from the database.
-/// 2. Return the instances to the client.
+/// 2. Get the contract metadata from the in-memory cache.
+/// 3. Return the instances along with their token allow-lists and metadata to the client.
 ///
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
@@ -34,16 +35,27 @@ pub async fn handle(
         .postgresql
         .clone();
 
-    let response: ResponseBody = postgresql
-        .select_contracts_curve()
-        .await?
+    let instances = postgresql.select_contracts_curve().await?;
+
+    let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+    let response: ResponseBody = instances
         .into_iter()
         .map(|instance| {
+            let address = zinc_zksync::eth_address_from_vec(instance.eth_address);
+            let metadata = app_data
+                .contracts
+                .get(&address)
+                .map(|contract| contract.build.metadata.clone())
+                .unwrap_or_default();
+
             ResponseInstance::new(
-                zinc_zksync::eth_address_from_vec(instance.eth_address),
+                address,
                 instance.name,
                 instance.version,
                 instance.instance,
+                serde_json::from_value(instance.allowed_tokens)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+                metadata,
             )
         })
         .collect();
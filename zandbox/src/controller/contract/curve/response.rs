@@ -2,6 +2,8 @@
 //! The contract resource GET `Curve` response.
 //!
 
+use std::collections::HashMap;
+
 use serde::Serialize;
 
 use zksync::web3::types::Address;
@@ -24,18 +26,31 @@ pub struct Instance {
     pub version: String,
     /// The contract instance name.
     pub instance: String,
+    /// The token symbols or addresses the contract is allowed to transfer.
+    pub allowed_tokens: Vec<String>,
+    /// The contract metadata, which consists of its public string constants.
+    pub metadata: HashMap<String, String>,
 }
 
 impl Instance {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(address: Address, name: String, version: String, instance: String) -> Self {
+    pub fn new(
+        address: Address,
+        name: String,
+        version: String,
+        instance: String,
+        allowed_tokens: Vec<String>,
+        metadata: HashMap<String, String>,
+    ) -> Self {
         Self {
             address,
             name,
             version,
             instance,
+            allowed_tokens,
+            metadata,
         }
     }
 }
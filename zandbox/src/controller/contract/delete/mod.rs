@@ -0,0 +1,100 @@
+//!
+//! The contract resource DELETE method module.
+//!
+
+pub mod error;
+pub mod request;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::auth::Authorization;
+use crate::database::model::contract::update_archived::Input as ContractUpdateArchivedInput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Path as RequestPath;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache and check that the request is signed by the
+///    contract owner.
+/// 2. Wait for the transaction batches being sent by the contract.
+/// 3. Mark the contract as archived in the persistent database, keeping its storage.
+/// 4. Move the contract from the in-memory cache to the archived ones, so it is not served.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+    authorization: Authorization,
+) -> crate::Result<(), Error> {
+    let path = path.into_inner();
+    let address = serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let contract = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        shared_data
+            .contracts
+            .get(&path.address)
+            .cloned()
+            .ok_or_else(|| {
+                if shared_data.archived_contracts.contains_key(&path.address) {
+                    Error::ContractArchived(address.clone())
+                } else {
+                    Error::ContractNotFound(address.clone())
+                }
+            })?
+    };
+    let account_id = contract
+        .account_id
+        .ok_or_else(|| Error::ContractLocked(address.clone()))?;
+
+    log::debug!("Checking the contract owner credentials");
+    crate::auth::authorize(
+        &app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .auth_nonces,
+        &authorization,
+        contract.eth_address,
+        contract.owner_address,
+    )?;
+
+    // the batches being sent are awaited, so the contract is not archived midway
+    let nonce_lock = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .nonce_locks
+        .get(path.address);
+    let _guard = nonce_lock.lock().await;
+
+    log::debug!("Archiving the contract in the persistent PostgreSQL database");
+    postgresql
+        .update_contract_archived(ContractUpdateArchivedInput::new(account_id, true))
+        .await?;
+
+    log::debug!("Moving the contract to the archived ones in the in-memory cache");
+    let mut shared_data = app_data.write().expect(zinc_const::panic::SYNCHRONIZATION);
+    let contract = shared_data
+        .contracts
+        .remove(&path.address)
+        .ok_or_else(|| Error::ContractNotFound(address.clone()))?;
+    shared_data
+        .archived_contracts
+        .insert(path.address, contract);
+
+    log::debug!("The contract {} has been archived", address);
+    Ok(Response::new(StatusCode::OK))
+}
@@ -0,0 +1,16 @@
+//!
+//! The contract resource DELETE request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract resource DELETE request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The contract ETH address.
+    pub address: Address,
+}
@@ -13,6 +13,8 @@ use zinc_build::ValueError as BuildValueError;
 use zinc_vm::RuntimeError;
 use zinc_zksync::TransactionError;
 
+use crate::storage::error::Error as StorageError;
+
 ///
 /// The contract resource PUT `fee` error.
 ///
@@ -22,6 +24,8 @@ pub enum Error {
     ContractNotFound(String),
     /// The contract with the specified address is locked.
     ContractLocked(String),
+    /// The contract with the specified address has been archived.
+    ContractArchived(String),
     /// The specified method does not exist in the contract.
     MethodNotFound(String),
     /// The immutable method must be called via the `query` endpoint.
@@ -32,11 +36,22 @@ pub enum Error {
     Transaction(TransactionError),
     /// Token ID cannot be resolved by zkSync.
     TokenNotFound(TokenId),
+    /// Token address of a contract transfer cannot be resolved by zkSync.
+    TokenAddressNotFound(String),
+    /// The contract method attempted a transfer in a token missing from the contract allow-list.
+    TokenNotAllowed {
+        /// The token symbol.
+        token: String,
+        /// The method name.
+        method: String,
+    },
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
     /// The PostgreSQL database error.
     Database(sqlx::Error),
+    /// The contract storage loading error.
+    Storage(StorageError),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
 }
@@ -53,6 +68,12 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<StorageError> for Error {
+    fn from(inner: StorageError) -> Self {
+        Self::Storage(inner)
+    }
+}
+
 impl From<zksync::error::ClientError> for Error {
     fn from(inner: zksync::error::ClientError) -> Self {
         Self::ZkSyncClient(inner)
@@ -64,14 +85,25 @@ impl ResponseError for Error {
         match self {
             Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
             Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ContractArchived(..) => StatusCode::GONE,
             Self::MethodNotFound(..) => StatusCode::BAD_REQUEST,
             Self::MethodIsImmutable(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
             Self::Transaction(..) => StatusCode::BAD_REQUEST,
             Self::TokenNotFound(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::TokenAddressNotFound(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::TokenNotAllowed { .. } => StatusCode::FORBIDDEN,
 
+            Self::RuntimeError(RuntimeError::PayableMethodWithoutTransfer { .. }) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::RuntimeError(RuntimeError::NonPayableMethodWithTransfer { .. }) => {
+                StatusCode::BAD_REQUEST
+            }
             Self::RuntimeError(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(StorageError::ZkSyncClient(..)) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(..) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ZkSyncClient(..) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
@@ -93,6 +125,9 @@ impl fmt::Display for Error {
                 format!("Contract with address {} not found", address)
             }
             Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::ContractArchived(address) => {
+                format!("Contract with address {} has been archived", address)
+            }
             Self::MethodNotFound(name) => format!("Method `{}` not found", name),
             Self::MethodIsImmutable(name) => {
                 format!("Method `{}` is immutable: use 'query' instead", name)
@@ -100,9 +135,17 @@ impl fmt::Display for Error {
             Self::InvalidInput(inner) => format!("Input: {}", inner),
             Self::Transaction(inner) => format!("Transaction: {}", inner),
             Self::TokenNotFound(token_id) => format!("Token ID {} cannot be resolved", token_id),
+            Self::TokenAddressNotFound(address) => {
+                format!("Token with address {} cannot be resolved", address)
+            }
+            Self::TokenNotAllowed { token, method } => format!(
+                "Method `{}` cannot transfer {}, as the token is not allowed for the contract",
+                method, token
+            ),
 
             Self::RuntimeError(inner) => format!("Runtime: {:?}", inner),
             Self::Database(inner) => format!("Database: {:?}", inner),
+            Self::Storage(inner) => format!("Storage: {}", inner),
             Self::ZkSyncClient(inner) => format!("ZkSync: {:?}", inner),
         };
 
@@ -14,7 +14,6 @@ use actix_web::web;
 use num_old::BigUint;
 use num_old::Zero;
 
-use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::tx::ZkSyncTx;
 use zksync_types::TxFeeTypes;
 
@@ -26,6 +25,7 @@ use zinc_zksync::TransactionMsg;
 
 use crate::database::model::field::select::Input as FieldSelectInput;
 use crate::response::Response;
+use crate::shared_data::contract::Contract as SharedDataContract;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
 
@@ -43,7 +43,8 @@ use self::response::Body as ResponseBody;
 /// 3. Parse the method input arguments.
 /// 4. Get the contract storage from data sources and convert it to the Zinc VM representation.
 /// 5. Run the method on the Zinc VM.
-/// 6. Extract the transfer data from the Zinc VM.
+/// 6. Extract the transfer data from the Zinc VM and check the transfer tokens against
+///    the contract token allow-list.
 /// 7. Calculate the fee required for the transfers.
 /// 8. Send the calculated fee back to the client.
 ///
@@ -66,17 +67,22 @@ pub async fn handle(
         serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
     );
 
-    let contract = app_data
-        .read()
-        .expect(zinc_const::panic::SYNCHRONIZATION)
-        .contracts
-        .get(&query.address)
-        .cloned()
-        .ok_or_else(|| {
-            Error::ContractNotFound(
-                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
-            )
-        })?;
+    let contract = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        shared_data
+            .contracts
+            .get(&query.address)
+            .cloned()
+            .ok_or_else(|| {
+                let address = serde_json::to_string(&query.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+                if shared_data.archived_contracts.contains_key(&query.address) {
+                    Error::ContractArchived(address)
+                } else {
+                    Error::ContractNotFound(address)
+                }
+            })?
+    };
     let account_id = contract.account_id.ok_or_else(|| {
         Error::ContractLocked(
             serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
@@ -92,17 +98,25 @@ pub async fn handle(
     }
 
     log::debug!("Initializing the contract wallet");
-    let provider = zksync::Provider::new(query.network);
-    let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-        query.address,
-        PrivateKeySigner::new(contract.eth_private_key),
-        query.network,
-    )
-    .await?;
-    let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+    let gateway = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .gateway
+        .clone();
+    let wallet = gateway
+        .connect(query.network, query.address, contract.eth_private_key)
+        .await?;
 
-    let input_value = BuildValue::try_from_typed_json(body.arguments, method.input)
-        .map_err(Error::InvalidInput)?;
+    let settings = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings;
+    let input_value = BuildValue::try_from_typed_json_with_depth_limit(
+        body.arguments,
+        method.input,
+        settings.json_depth_limit,
+    )
+    .map_err(Error::InvalidInput)?;
 
     log::debug!("Loading the pre-transaction contract storage");
     let database_fields = postgresql
@@ -112,30 +126,36 @@ pub async fn handle(
         database_fields,
         contract.build.storage.as_slice(),
         contract.eth_address,
-        &wallet,
+        wallet.as_ref(),
     )
     .await?;
+    let balances = storage.balances();
 
     log::debug!("Running the contract method on the virtual machine");
     let method = query.method;
+    let method_to_run = method.clone();
     let contract_build = contract.build;
     let vm_time = std::time::Instant::now();
-    log::debug!("input_value:{:?}", input_value);
     let mut transaction_msgs: Vec<TransactionMsg> = Vec::new();
 
     for transaction in (&body.transaction).iter() {
-        let transaction_msg = transaction.try_to_msg(&wallet)?;
+        let transaction_msg = transaction.try_to_msg(wallet.tokens())?;
         log::debug!("transactionMsg:{:?}", transaction_msg);
         transaction_msgs.push(transaction_msg);
     }
 
     let output = async_std::task::spawn_blocking(move || {
-        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(ContractInput::new(
+        let mut input = ContractInput::new(
             input_value,
             storage.into_build(),
-            method,
+            method_to_run,
             transaction_msgs,
-        ))
+        );
+        input.balances = balances;
+        input.privacy = settings.privacy;
+        input.environment = crate::environment::current();
+
+        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(input)
     })
     .await
     .map_err(Error::RuntimeError)?;
@@ -145,15 +165,41 @@ pub async fn handle(
     let mut fee = BigUint::zero();
     let token = match body.transaction[0].tx {
         ZkSyncTx::Transfer(ref transfer) => wallet
-            .tokens
+            .tokens()
             .resolve(transfer.token.into())
             .ok_or(Error::TokenNotFound(transfer.token))?,
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
     for transfer in output.transfers.into_iter() {
+        let transfer_token = wallet
+            .tokens()
+            .resolve(
+                zinc_zksync::eth_address_from_vec(transfer.token_address.to_bytes_be().to_vec())
+                    .into(),
+            )
+            .ok_or_else(|| {
+                Error::TokenAddressNotFound(
+                    transfer
+                        .token_address
+                        .to_str_radix(zinc_const::base::HEXADECIMAL),
+                )
+            })?;
+        if !SharedDataContract::is_token_allowed(
+            contract.allowed_tokens.as_slice(),
+            &transfer_token,
+        ) {
+            return Err(Error::TokenNotAllowed {
+                token: transfer_token.symbol,
+                method,
+            });
+        }
+
         fee += wallet
-            .provider
-            .get_tx_fee(TxFeeTypes::Transfer, transfer.recipient.into(), token.id)
+            .get_tx_fee(
+                TxFeeTypes::Transfer,
+                transfer.recipient.into(),
+                token.id.into(),
+            )
             .await?
             .total_fee;
     }
@@ -0,0 +1,47 @@
+//!
+//! The contract resource GET error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+///
+/// The contract resource GET error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
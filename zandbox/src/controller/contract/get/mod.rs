@@ -0,0 +1,59 @@
+//!
+//! The contract resource GET method module.
+//!
+
+pub mod error;
+pub mod request;
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Path as RequestPath;
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Return the contract instance data along with its metadata and method costs to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+) -> crate::Result<ResponseBody, Error> {
+    let path = path.into_inner();
+
+    let contract = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&path.address)
+        .cloned()
+        .ok_or_else(|| {
+            Error::ContractNotFound(
+                serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        })?;
+
+    let response = ResponseBody::new(
+        contract.eth_address,
+        contract.account_id,
+        contract.name,
+        contract.version,
+        contract.instance,
+        contract.allowed_tokens,
+        contract.build.metadata,
+        contract.method_costs,
+    );
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,63 @@
+//!
+//! The contract resource GET response.
+//!
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use zksync::web3::types::Address;
+use zksync_types::AccountId;
+
+use crate::sizing::cost::Cost as MethodCost;
+
+///
+/// The contract resource GET response body.
+///
+#[derive(Debug, Serialize)]
+pub struct Body {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The contract zkSync account ID, which is unset until the contract is initialized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountId>,
+    /// The contract project name.
+    pub name: String,
+    /// The contract version.
+    pub version: String,
+    /// The contract instance name.
+    pub instance: String,
+    /// The token symbols or addresses the contract is allowed to transfer.
+    pub allowed_tokens: Vec<String>,
+    /// The contract metadata, which consists of its public string constants.
+    pub metadata: HashMap<String, String>,
+    /// The contract method costs measured at publishing, where the keys are the method names.
+    pub method_costs: HashMap<String, MethodCost>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        address: Address,
+        account_id: Option<AccountId>,
+        name: String,
+        version: String,
+        instance: String,
+        allowed_tokens: Vec<String>,
+        metadata: HashMap<String, String>,
+        method_costs: HashMap<String, MethodCost>,
+    ) -> Self {
+        Self {
+            address,
+            account_id,
+            name,
+            version,
+            instance,
+            allowed_tokens,
+            metadata,
+            method_costs,
+        }
+    }
+}
@@ -19,6 +19,7 @@ use zksync_types::tx::ZkSyncTx;
 
 use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
 use crate::response::Response;
+use crate::shared_data::contract::Contract as SharedDataContract;
 use crate::shared_data::SharedData;
 
 use self::error::Error;
@@ -93,8 +94,8 @@ pub async fn handle(
         );
     }
 
-    let fee_token_id = match body.transaction.tx {
-        ZkSyncTx::Transfer(ref transfer) => transfer.token,
+    let (fee_token_id, owner_address) = match body.transaction.tx {
+        ZkSyncTx::Transfer(ref transfer) => (transfer.token, transfer.from),
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
 
@@ -162,7 +163,7 @@ pub async fn handle(
                 serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
             )
         })?
-        .set_account_id(account_id);
+        .set_account_id(account_id, owner_address);
 
     log::debug!("Writing the contract to the persistent PostgreSQL database");
     postgresql
@@ -175,8 +176,14 @@ pub async fn handle(
             contract.source_code,
             contract.bytecode,
             contract.verifying_key,
+            SharedDataContract::verifying_keys_to_json(&contract.verifying_keys),
+            serde_json::to_value(contract.allowed_tokens)
+                .expect(zinc_const::panic::DATA_CONVERSION),
+            contract.abi,
+            serde_json::to_value(contract.method_costs).expect(zinc_const::panic::DATA_CONVERSION),
             contract.eth_address,
             contract.eth_private_key,
+            Some(owner_address),
         ))
         .await?;
 
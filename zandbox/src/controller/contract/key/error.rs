@@ -0,0 +1,107 @@
+//!
+//! The contract resource PUT `key` error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+use crate::auth::error::Error as AuthError;
+
+///
+/// The contract resource PUT `key` error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+    /// The contract with the specified address is locked.
+    ContractLocked(String),
+    /// The key rotation is not signed by the contract owner.
+    Unauthorized(AuthError),
+    /// The committed zkSync signing key of the contract is not derived from the new private key.
+    KeyNotChanged(String),
+    /// The signing key has been changed on-chain, but the new private key could not be stored.
+    /// The request must be repeated with the same private key.
+    KeyNotPersisted(String, sqlx::Error),
+
+    /// The ZkSync server client error.
+    ZkSyncClient(zksync::error::ClientError),
+}
+
+impl From<AuthError> for Error {
+    fn from(inner: AuthError) -> Self {
+        Self::Unauthorized(inner)
+    }
+}
+
+impl From<zksync::error::ClientError> for Error {
+    fn from(inner: zksync::error::ClientError) -> Self {
+        Self::ZkSyncClient(inner)
+    }
+}
+
+impl Error {
+    ///
+    /// The error variant name, which is used as the `error` label of the errors metric.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ContractNotFound(..) => "ContractNotFound",
+            Self::ContractLocked(..) => "ContractLocked",
+            Self::Unauthorized(..) => "Unauthorized",
+            Self::KeyNotChanged(..) => "KeyNotChanged",
+            Self::KeyNotPersisted(..) => "KeyNotPersisted",
+            Self::ZkSyncClient(..) => "ZkSyncClient",
+        }
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Unauthorized(inner) => inner.status_code(),
+            Self::KeyNotChanged(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::KeyNotPersisted(..) => StatusCode::SERVICE_UNAVAILABLE,
+
+            Self::ZkSyncClient(..) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+            Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::Unauthorized(inner) => format!("Unauthorized: {}", inner),
+            Self::KeyNotChanged(address) => format!(
+                "The signing key of contract {} is not derived from the new private key: the change-pubkey transaction must be committed first",
+                address
+            ),
+            Self::KeyNotPersisted(address, inner) => format!(
+                "The signing key of contract {} has been changed, but the new private key could not be stored: {:?}. Repeat the request with the same private key",
+                address, inner
+            ),
+
+            Self::ZkSyncClient(inner) => format!("ZkSync: {:?}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
@@ -0,0 +1,144 @@
+//!
+//! The contract resource PUT method `key` module.
+//!
+
+pub mod error;
+pub mod request;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::auth::Authorization;
+use crate::database::model::contract::update_private_key::Input as ContractUpdatePrivateKeyInput;
+use crate::response::Response;
+use crate::shared_data::metrics::Metrics;
+use crate::shared_data::metrics::HANDLER_KEY;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Body as RequestBody;
+use self::request::Query as RequestQuery;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache and check that the request is signed by the
+///    contract owner.
+/// 2. Check that the committed zkSync signing key of the contract is derived from the new
+///    private key, that is, the change-pubkey transaction has been already committed.
+/// 3. Replace the private key in the persistent database.
+/// 4. Replace the private key in the in-memory cache.
+///
+/// The request is idempotent, so if the database update fails after the signing key has been
+/// changed on-chain, the client repeats the request with the same private key.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+    body: web::Json<RequestBody>,
+    authorization: Authorization,
+) -> crate::Result<(), Error> {
+    let metrics = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .metrics
+        .clone();
+
+    let result = execute(app_data, query, body, authorization, metrics.as_ref()).await;
+    if let Err(ref error) = result {
+        metrics.record_error(HANDLER_KEY, error.kind());
+    }
+    result
+}
+
+///
+/// Executes the `handle` sequence, recording the timings into `metrics`.
+///
+async fn execute(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+    body: web::Json<RequestBody>,
+    authorization: Authorization,
+    metrics: &Metrics,
+) -> crate::Result<(), Error> {
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let address = serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let contract = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&query.address)
+        .cloned()
+        .ok_or_else(|| Error::ContractNotFound(address.clone()))?;
+    let account_id = contract
+        .account_id
+        .ok_or_else(|| Error::ContractLocked(address.clone()))?;
+
+    log::debug!("Checking the contract owner credentials");
+    crate::auth::authorize(
+        &app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .auth_nonces,
+        &authorization,
+        contract.eth_address,
+        contract.owner_address,
+    )?;
+
+    log::debug!("Checking the committed signing key of contract {}", address);
+    let gateway = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .gateway
+        .clone();
+    let wallet = gateway
+        .connect(query.network, query.address, body.private_key)
+        .await?;
+    if !wallet.is_signing_key_set().await? {
+        return Err(Error::KeyNotChanged(address));
+    }
+
+    // the batches being sent with the old key are awaited, so the key is not replaced midway
+    let nonce_lock = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .nonce_locks
+        .get(query.address);
+    let _guard = nonce_lock.lock().await;
+
+    log::debug!("Replacing the contract private key in the persistent PostgreSQL database");
+    metrics
+        .time_database_query(
+            "update_contract_private_key",
+            postgresql.update_contract_private_key(ContractUpdatePrivateKeyInput::new(
+                account_id,
+                body.private_key,
+            )),
+        )
+        .await
+        .map_err(|error| Error::KeyNotPersisted(address.clone(), error))?;
+
+    log::debug!("Replacing the contract private key in the in-memory cache");
+    app_data
+        .write()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get_mut(&query.address)
+        .ok_or_else(|| Error::ContractNotFound(address.clone()))?
+        .eth_private_key = body.private_key;
+
+    log::debug!("The private key of contract {} has been rotated", address);
+    Ok(Response::new(StatusCode::OK))
+}
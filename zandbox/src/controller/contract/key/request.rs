@@ -0,0 +1,13 @@
+//!
+//! The contract resource PUT `key` request.
+//!
+
+///
+/// The contract resource PUT `key` request query.
+///
+pub type Query = zinc_zksync::KeyRequestQuery;
+
+///
+/// The contract resource PUT `key` request body.
+///
+pub type Body = zinc_zksync::KeyRequestBody;
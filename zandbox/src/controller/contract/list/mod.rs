@@ -0,0 +1,84 @@
+//!
+//! The contract resource GET list method module.
+//!
+
+pub mod error;
+pub mod request;
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use zksync_types::AccountId;
+
+use crate::database::model::contract::select_list::Input as ContractSelectListInput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Query as RequestQuery;
+use self::response::Body as ResponseBody;
+use self::response::Instance as ResponseInstance;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Select the requested page of the contract instances from the database.
+/// 2. Return the instances along with the total number of matching ones to the client.
+///
+/// The in-memory cache is not consulted, so the page is consistent with the total count.
+/// The archived contracts are excluded, unless the `include_archived` flag is set.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+) -> crate::Result<ResponseBody, Error> {
+    let query = query.into_inner();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let limit = query
+        .limit
+        .min(zinc_const::zandbox::CONTRACT_LIST_MAX_LIMIT);
+    let (instances, total_count) = postgresql
+        .select_contracts_list(ContractSelectListInput::new(
+            limit as i64,
+            query.offset as i64,
+            query.name_contains,
+            query.owner,
+            query.include_archived,
+            query.sort.into(),
+        ))
+        .await?;
+
+    let contracts = instances
+        .into_iter()
+        .map(|instance| {
+            ResponseInstance::new(
+                zinc_zksync::eth_address_from_vec(instance.eth_address),
+                instance.account_id as AccountId,
+                instance.name,
+                instance.version,
+                instance.instance,
+                serde_json::from_value(instance.allowed_tokens)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+                instance
+                    .owner_address
+                    .map(zinc_zksync::eth_address_from_vec),
+                instance.is_archived,
+            )
+        })
+        .collect();
+
+    let response = ResponseBody::new(total_count as u64, contracts);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
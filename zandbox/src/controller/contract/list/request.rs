@@ -0,0 +1,68 @@
+//!
+//! The contract resource GET list request.
+//!
+
+use serde::Deserialize;
+
+use zksync::web3::types::Address;
+
+use crate::database::model::contract::select_list::Sort as DatabaseSort;
+
+///
+/// The contract resource GET list request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The maximum number of contracts in the response.
+    #[serde(default = "Query::default_limit")]
+    pub limit: u32,
+    /// The number of contracts to skip.
+    #[serde(default)]
+    pub offset: u32,
+    /// The substring, which the contract name must contain.
+    pub name_contains: Option<String>,
+    /// The address of the account, which has initialized the contract.
+    pub owner: Option<Address>,
+    /// Whether the archived contracts must be listed as well.
+    #[serde(default)]
+    pub include_archived: bool,
+    /// The sorting key.
+    #[serde(default)]
+    pub sort: Sort,
+}
+
+impl Query {
+    ///
+    /// The default `limit` value, if the parameter is omitted.
+    ///
+    fn default_limit() -> u32 {
+        zinc_const::zandbox::CONTRACT_LIST_DEFAULT_LIMIT
+    }
+}
+
+///
+/// The contract resource GET list request sorting key.
+///
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sort {
+    /// Sort by the contract creation time, the oldest first.
+    CreatedAt,
+    /// Sort by the contract name, version, and instance name alphabetically.
+    Name,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Self::CreatedAt
+    }
+}
+
+impl From<Sort> for DatabaseSort {
+    fn from(value: Sort) -> Self {
+        match value {
+            Sort::CreatedAt => Self::CreatedAt,
+            Sort::Name => Self::Name,
+        }
+    }
+}
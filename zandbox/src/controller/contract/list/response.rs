@@ -0,0 +1,82 @@
+//!
+//! The contract resource GET list response.
+//!
+
+use serde::Serialize;
+
+use zksync::web3::types::Address;
+use zksync_types::AccountId;
+
+///
+/// The contract resource GET list response body.
+///
+#[derive(Debug, Serialize)]
+pub struct Body {
+    /// The total number of contracts matching the filters.
+    pub total_count: u64,
+    /// The requested page of contracts.
+    pub contracts: Vec<Instance>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(total_count: u64, contracts: Vec<Instance>) -> Self {
+        Self {
+            total_count,
+            contracts,
+        }
+    }
+}
+
+///
+/// The contract resource GET list response instance.
+///
+#[derive(Debug, Serialize)]
+pub struct Instance {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The contract zkSync account ID.
+    pub account_id: AccountId,
+    /// The contract project name.
+    pub name: String,
+    /// The contract version.
+    pub version: String,
+    /// The contract instance name.
+    pub instance: String,
+    /// The token symbols or addresses the contract is allowed to transfer.
+    pub allowed_tokens: Vec<String>,
+    /// The address of the account, which has initialized the contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Address>,
+    /// Whether the contract has been archived.
+    pub is_archived: bool,
+}
+
+impl Instance {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        address: Address,
+        account_id: AccountId,
+        name: String,
+        version: String,
+        instance: String,
+        allowed_tokens: Vec<String>,
+        owner: Option<Address>,
+        is_archived: bool,
+    ) -> Self {
+        Self {
+            address,
+            account_id,
+            name,
+            version,
+            instance,
+            allowed_tokens,
+            owner,
+            is_archived,
+        }
+    }
+}
@@ -2,9 +2,17 @@
 //! The contract instance resource.
 //!
 
+pub mod abi;
 pub mod call;
 pub mod curve;
+pub mod delete;
 pub mod fee;
+pub mod get;
 pub mod initialize;
+pub mod key;
+pub mod list;
+pub mod nonce;
 pub mod post;
 pub mod query;
+pub mod restore;
+pub mod verify;
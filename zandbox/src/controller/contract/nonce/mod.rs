@@ -0,0 +1,38 @@
+//!
+//! The contract resource POST method `nonce` module.
+//!
+
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::auth::error::Error;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Issue a single-use nonce for a privileged request.
+/// 2. Return the nonce and its lifetime to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+) -> crate::Result<ResponseBody, Error> {
+    let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+
+    let nonce = app_data.auth_nonces.issue();
+    let expires_in = app_data.auth_nonces.lifetime().as_secs();
+
+    Ok(Response::new_with_data(
+        StatusCode::CREATED,
+        ResponseBody::new(nonce, expires_in),
+    ))
+}
@@ -0,0 +1,8 @@
+//!
+//! The contract resource POST `nonce` response.
+//!
+
+///
+/// The contract resource POST `nonce` response body.
+///
+pub type Body = zinc_zksync::NonceResponseBody;
@@ -5,11 +5,16 @@
 use std::fmt;
 
 use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
 use actix_web::ResponseError;
+use serde_json::json;
 
 use zinc_build::ValueError as BuildValueError;
 use zinc_vm::RuntimeError;
 
+use crate::auth::error::Error as AuthError;
+use crate::migration::conflict::Conflict as MigrationConflict;
+
 ///
 /// The contract resource POST response error.
 ///
@@ -23,6 +28,21 @@ pub enum Error {
     ConstructorNotFound,
     /// Invalid contract method arguments.
     InvalidInput(BuildValueError),
+    /// The contract metadata value exceeds the size limit.
+    MetadataValueTooLarge {
+        /// The metadata constant name.
+        name: String,
+        /// The metadata value size in bytes.
+        size: usize,
+        /// The metadata value size limit in bytes.
+        limit: usize,
+    },
+    /// The re-published contract storage layout is incompatible with the old one.
+    StorageMigration(Vec<MigrationConflict>),
+    /// The initialized contract is re-published without the owner credentials.
+    Unauthorized(AuthError),
+    /// The contract instance with the same name has been archived and must be restored first.
+    ContractArchived(String),
 
     /// The virtual machine constructor runtime error.
     RuntimeError(RuntimeError),
@@ -36,6 +56,32 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<AuthError> for Error {
+    fn from(inner: AuthError) -> Self {
+        Self::Unauthorized(inner)
+    }
+}
+
+impl Error {
+    ///
+    /// The error variant name, which is used as the `error` label of the errors metric.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBytecode(..) => "InvalidBytecode",
+            Self::NotAContract => "NotAContract",
+            Self::ConstructorNotFound => "ConstructorNotFound",
+            Self::InvalidInput(..) => "InvalidInput",
+            Self::MetadataValueTooLarge { .. } => "MetadataValueTooLarge",
+            Self::StorageMigration(..) => "StorageMigration",
+            Self::Unauthorized(..) => "Unauthorized",
+            Self::ContractArchived(..) => "ContractArchived",
+            Self::RuntimeError(..) => "RuntimeError",
+            Self::Database(..) => "Database",
+        }
+    }
+}
+
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -43,11 +89,25 @@ impl ResponseError for Error {
             Self::NotAContract => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ConstructorNotFound => StatusCode::UNPROCESSABLE_ENTITY,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
+            Self::MetadataValueTooLarge { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::StorageMigration(..) => StatusCode::CONFLICT,
+            Self::Unauthorized(inner) => inner.status_code(),
+            Self::ContractArchived(..) => StatusCode::CONFLICT,
 
             Self::RuntimeError(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::StorageMigration(conflicts) => HttpResponse::build(self.status_code())
+                .json(json!({ "message": self.to_string(), "conflicts": conflicts })),
+            _ => HttpResponse::build(self.status_code())
+                .content_type("text/plain; charset=utf-8")
+                .body(self.to_string()),
+        }
+    }
 }
 
 impl serde::Serialize for Error {
@@ -66,6 +126,23 @@ impl fmt::Display for Error {
             Self::NotAContract => "Not a contract".to_owned(),
             Self::ConstructorNotFound => "Constructor not found".to_owned(),
             Self::InvalidInput(inner) => format!("Input: {}", inner),
+            Self::MetadataValueTooLarge { name, size, limit } => format!(
+                "Metadata value `{}` is {} bytes long, which exceeds the limit of {} bytes",
+                name, size, limit
+            ),
+            Self::StorageMigration(conflicts) => format!(
+                "Storage migration: {}",
+                conflicts
+                    .iter()
+                    .map(|conflict| conflict.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Unauthorized(inner) => format!("Unauthorized: {}", inner),
+            Self::ContractArchived(address) => format!(
+                "Contract with address {} has been archived and must be restored before publishing",
+                address
+            ),
 
             Self::RuntimeError(inner) => format!("Runtime: {:?}", inner),
             Self::Database(inner) => format!("Database: {:?}", inner),
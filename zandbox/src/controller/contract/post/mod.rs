@@ -8,22 +8,35 @@ pub mod response;
 
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use actix_web::http::StatusCode;
 use actix_web::web;
 
 use zinc_build::Application as BuildApplication;
+use zinc_build::Contract as BuildContract;
 use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::ContractInput;
+use zinc_zksync::JobStatus;
+use zinc_zksync::PublishAcceptedResponseBody as AcceptedResponseBody;
+use zinc_zksync::PublishResponseBody;
 // use zinc_zksync::TransactionMsg;
 
 use zksync::web3::types::Address;
 use zksync::web3::types::H256;
 use zksync_types::tx::PackedEthSignature;
 
+use crate::auth::Authorization;
+use crate::database::model::contract::update::Input as ContractUpdateInput;
+use crate::database::model::job::delete_expired::Input as JobDeleteExpiredInput;
+use crate::database::model::job::insert_new::Input as JobInsertNewInput;
+use crate::database::model::job::update::Input as JobUpdateInput;
+use crate::migration::Migration;
 use crate::response::Response;
 use crate::shared_data::contract::Contract as SharedDataContract;
+use crate::shared_data::metrics::Metrics;
+use crate::shared_data::metrics::HANDLER_PUBLISH;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
 
@@ -35,24 +48,210 @@ use self::response::Body as ResponseBody;
 ///
 /// The HTTP request handler.
 ///
+/// Unless `sync=true` is passed, the contract is published by a background job, and the job
+/// ID is returned to the client immediately with the `202 Accepted` status. The job status
+/// is polled via the `/api/v1/jobs/{id}` resource.
+///
 /// Sequence:
 /// 1. Parse the contract bytecode from the request.
-/// 2. Extract the contract constructor from its metadata.
-/// 3. Parse the construtor arguments.
-/// 4. Run the construtor on the Zinc VM which must return the contract storage.
-/// 5. Generate a private key for the contract.
-/// 6. Fill the implicit contract storage fields.
-/// 7. Write the contract and its storage to the in-memory cache.
-/// 8. Return the created contract address to the client.
+/// 2. Check the contract metadata value sizes.
+/// 3. If the initialized instance with the same name exists, re-publish it and stop here.
+///    Re-publishing requires the contract owner credentials.
+/// 4. If the instance with the same name has been archived, stop here, since it must be
+///    restored before being published again.
+/// 5. Extract the contract constructor from its metadata.
+/// 6. Parse the construtor arguments.
+/// 7. Run the construtor on the Zinc VM which must return the contract storage.
+/// 8. Measure the contract methods cost within the sizing timeout.
+/// 9. Generate a private key for the contract.
+/// 10. Fill the implicit contract storage fields.
+/// 11. Write the contract and its storage to the in-memory cache.
+/// 12. Return the created contract address to the client.
 ///
 pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
     query: web::Query<RequestQuery>,
     body: web::Json<RequestBody>,
+    authorization: Authorization,
 ) -> crate::Result<ResponseBody, Error> {
     let query = query.into_inner();
     let body = body.into_inner();
 
+    let metrics = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .metrics
+        .clone();
+
+    let result = if query.sync {
+        publish(app_data, query, body, authorization, metrics.clone()).await
+    } else {
+        start(app_data, query, body, authorization, metrics.clone()).await
+    };
+    if let Err(ref error) = result {
+        metrics.record_error(HANDLER_PUBLISH, error.kind());
+    }
+    result
+}
+
+///
+/// Executes the `handle` sequence within the request.
+///
+async fn publish(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: RequestQuery,
+    body: RequestBody,
+    authorization: Authorization,
+    metrics: Arc<Metrics>,
+) -> crate::Result<ResponseBody, Error> {
+    let response = execute(app_data, query, body, authorization, metrics.as_ref(), None).await?;
+
+    let code = if response.account_id.is_some() {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+    Ok(Response::new_with_data(
+        code,
+        ResponseBody::Published(response),
+    ))
+}
+
+///
+/// Creates the publishing job, whose ID is returned to the client, and starts it in the
+/// background.
+///
+/// The jobs finished more than the TTL ago are deleted from the database here.
+///
+async fn start(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: RequestQuery,
+    body: RequestBody,
+    authorization: Authorization,
+    metrics: Arc<Metrics>,
+) -> crate::Result<ResponseBody, Error> {
+    let (job_id, postgresql, ttl) = {
+        let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        (
+            app_data.jobs.create(),
+            app_data.postgresql.clone(),
+            app_data.jobs.ttl(),
+        )
+    };
+
+    log::debug!(
+        "Writing the job {} to the persistent PostgreSQL database",
+        job_id
+    );
+    postgresql
+        .delete_jobs_expired(JobDeleteExpiredInput::new(ttl))
+        .await?;
+    postgresql
+        .insert_job(JobInsertNewInput::new(job_id.clone()))
+        .await?;
+
+    log::debug!("Starting the job {}", job_id);
+    actix_rt::spawn(run(
+        app_data,
+        job_id.clone(),
+        query,
+        body,
+        authorization,
+        metrics,
+    ));
+
+    Ok(Response::new_with_data(
+        StatusCode::ACCEPTED,
+        ResponseBody::Accepted(AcceptedResponseBody::new(job_id)),
+    ))
+}
+
+///
+/// Runs the `handle` sequence as the background job with `job_id`, writing the job result
+/// to the in-memory cache and the persistent database.
+///
+async fn run(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    job_id: String,
+    query: RequestQuery,
+    body: RequestBody,
+    authorization: Authorization,
+    metrics: Arc<Metrics>,
+) {
+    let result = execute(
+        app_data.clone(),
+        query,
+        body,
+        authorization,
+        metrics.as_ref(),
+        Some(job_id.as_str()),
+    )
+    .await;
+
+    let (input, postgresql) = {
+        let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        let input = match result {
+            Ok(response) => {
+                log::debug!("The job {} is done", job_id);
+                app_data
+                    .jobs
+                    .finish(job_id.as_str(), response.address, response.account_id);
+                JobUpdateInput::new_done(job_id, response.address, response.account_id)
+            }
+            Err(error) => {
+                metrics.record_error(HANDLER_PUBLISH, error.kind());
+                let error = error.to_string();
+                app_data.jobs.fail(job_id.as_str(), error.clone());
+                JobUpdateInput::new_failed(job_id, error)
+            }
+        };
+        (input, app_data.postgresql.clone())
+    };
+
+    if let Err(error) = postgresql.update_job(input).await {
+        log::error!("Job result writing: {:?}", error);
+    }
+}
+
+///
+/// Sets the `status` of the job with `job_id`, if the contract is published by a job.
+///
+async fn progress(
+    app_data: &web::Data<Arc<RwLock<SharedData>>>,
+    job_id: Option<&str>,
+    status: JobStatus,
+) -> Result<(), Error> {
+    let job_id = match job_id {
+        Some(job_id) => job_id,
+        None => return Ok(()),
+    };
+
+    log::debug!("The job {} is {}", job_id, status);
+    let postgresql = {
+        let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        app_data.jobs.set_status(job_id, status);
+        app_data.postgresql.clone()
+    };
+    postgresql
+        .update_job(JobUpdateInput::new(job_id.to_owned(), status))
+        .await?;
+
+    Ok(())
+}
+
+///
+/// Executes the `handle` sequence, recording the timings into `metrics`.
+///
+/// If `job_id` is set, the job status is updated as the publishing proceeds.
+///
+async fn execute(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: RequestQuery,
+    body: RequestBody,
+    authorization: Authorization,
+    metrics: &Metrics,
+    job_id: Option<&str>,
+) -> Result<PublishResponseBody, Error> {
     log::debug!(
         "Publishing the instance `{}` of the contract `{} {}`",
         query.instance,
@@ -60,6 +259,8 @@ pub async fn handle(
         query.version
     );
 
+    progress(&app_data, job_id, JobStatus::Compiling).await?;
+
     let application = BuildApplication::try_from_slice(body.bytecode.as_slice())
         .map_err(Error::InvalidBytecode)?;
 
@@ -68,31 +269,107 @@ pub async fn handle(
         BuildApplication::Contract(contract) => contract,
     };
 
+    if let Some((name, value)) = build
+        .metadata
+        .iter()
+        .find(|(_name, value)| value.len() > zinc_const::limit::CONTRACT_METADATA_VALUE_SIZE)
+    {
+        return Err(Error::MetadataValueTooLarge {
+            name: name.to_owned(),
+            size: value.len(),
+            limit: zinc_const::limit::CONTRACT_METADATA_VALUE_SIZE,
+        });
+    }
+
+    let existing = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .values()
+        .find(|contract| {
+            contract.name == query.name
+                && contract.instance == query.instance
+                && contract.account_id.is_some()
+        })
+        .cloned();
+    if let Some(contract) = existing {
+        return republish(
+            app_data,
+            query,
+            body,
+            authorization,
+            build,
+            contract,
+            metrics,
+            job_id,
+        )
+        .await;
+    }
+
+    let archived = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .archived_contracts
+        .values()
+        .find(|contract| contract.name == query.name && contract.instance == query.instance)
+        .map(|contract| contract.eth_address);
+    if let Some(address) = archived {
+        return Err(Error::ContractArchived(
+            serde_json::to_string(&address).expect(zinc_const::panic::DATA_CONVERSION),
+        ));
+    }
+
     let constructor = build
         .methods
         .get(zinc_const::contract::CONSTRUCTOR_NAME)
         .cloned()
         .ok_or(Error::ConstructorNotFound)?;
 
-    let input_value = BuildValue::try_from_typed_json(body.arguments, constructor.input)
-        .map_err(Error::InvalidInput)?;
+    let settings = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings;
+    let input_value = BuildValue::try_from_typed_json_with_depth_limit(
+        body.arguments,
+        constructor.input,
+        settings.json_depth_limit,
+    )
+    .map_err(Error::InvalidInput)?;
 
     log::debug!("Initializing the contract storage");
     let storage = Storage::new(build.storage.as_slice()).into_build();
 
     log::debug!("Running the contract constructor on the virtual machine");
     let build_to_run = build.clone();
+    let vm_time = Instant::now();
     let output = async_std::task::spawn_blocking(move || {
-        zinc_vm::ContractFacade::new(build_to_run).run::<Bn256>(ContractInput::new(
+        let mut input = ContractInput::new(
             input_value,
             storage,
             zinc_const::contract::CONSTRUCTOR_NAME.to_owned(),
             // TransactionMsg::default(),
             Vec::new(),
-        ))
+        );
+        input.privacy = settings.privacy;
+
+        zinc_vm::ContractFacade::new(build_to_run).run::<Bn256>(input)
     })
-    .await
-    .map_err(Error::RuntimeError)?;
+    .await;
+    metrics.record_vm_execution(
+        HANDLER_PUBLISH,
+        query.name.as_str(),
+        zinc_const::contract::CONSTRUCTOR_NAME,
+        vm_time.elapsed(),
+    );
+    let output = output.map_err(Error::RuntimeError)?;
+
+    log::debug!("Measuring the contract methods cost");
+    let method_costs = crate::sizing::measure(&build, settings.sizing_timeout).await;
+
+    let mut verifying_keys = body.verifying_keys;
+    verifying_keys
+        .entry(zinc_const::contract::CONSTRUCTOR_NAME.to_owned())
+        .or_insert(body.verifying_key.clone());
 
     log::debug!("Generating an ETH private key");
     let mut contract_private_key = H256::default();
@@ -105,6 +382,12 @@ pub async fn handle(
         serde_json::to_string(&contract_address).expect(zinc_const::panic::DATA_CONVERSION),
     );
 
+    log::debug!("Filling the implicit contract storage fields");
+    let mut storage = Storage::from_build(output.result);
+    storage.set_layout_hash(build.layout_hash.clone());
+
+    progress(&app_data, job_id, JobStatus::Deploying).await?;
+
     log::debug!("Writing the contract to the temporary server cache");
     app_data
         .write()
@@ -120,15 +403,167 @@ pub async fn handle(
                 serde_json::to_value(body.source).expect(zinc_const::panic::DATA_CONVERSION),
                 body.bytecode,
                 body.verifying_key,
+                verifying_keys,
+                body.allowed_tokens,
+                body.abi,
+                method_costs,
+                None,
                 None,
                 contract_private_key,
                 build,
-                Storage::from_build(output.result),
+                storage,
             ),
         );
 
-    let response = ResponseBody::new(contract_address);
+    let response = PublishResponseBody::new(contract_address);
 
     log::debug!("The contract is waiting for the initialization");
-    Ok(Response::new_with_data(StatusCode::CREATED, response))
+    Ok(response)
+}
+
+///
+/// Re-publishes the initialized contract instance, keeping its address, account, and storage.
+///
+/// Sequence:
+/// 1. Check that the request is signed by the contract owner.
+/// 2. Migrate the contract storage to the new layout and set its new layout hash.
+/// 3. Measure the new contract methods cost within the sizing timeout.
+/// 4. Update the contract and its storage in the persistent database.
+/// 5. Update the contract and its storage in the in-memory cache.
+/// 6. Return the contract address and account ID to the client.
+///
+#[allow(clippy::too_many_arguments)]
+async fn republish(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: RequestQuery,
+    body: RequestBody,
+    authorization: Authorization,
+    build: BuildContract,
+    mut contract: SharedDataContract,
+    metrics: &Metrics,
+    job_id: Option<&str>,
+) -> Result<PublishResponseBody, Error> {
+    let account_id = contract
+        .account_id
+        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+    log::debug!(
+        "Re-publishing the instance `{}` of the contract `{}` from v{} to v{}",
+        query.instance,
+        query.name,
+        contract.version,
+        query.version
+    );
+
+    log::debug!("Checking the contract owner credentials");
+    crate::auth::authorize(
+        &app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .auth_nonces,
+        &authorization,
+        contract.eth_address,
+        contract.owner_address,
+    )?;
+
+    log::debug!("Migrating the contract storage");
+    let mut migration = Migration::new(
+        contract.build.storage.as_slice(),
+        contract.storage,
+        build.storage.as_slice(),
+        &body.renames,
+    )
+    .map_err(Error::StorageMigration)?;
+    migration.storage.set_layout_hash(build.layout_hash.clone());
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let settings = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings;
+
+    log::debug!("Measuring the contract methods cost");
+    let method_costs = crate::sizing::measure(&build, settings.sizing_timeout).await;
+
+    let source_code = serde_json::to_value(body.source).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let mut verifying_keys = body.verifying_keys;
+    verifying_keys
+        .entry(zinc_const::contract::CONSTRUCTOR_NAME.to_owned())
+        .or_insert(body.verifying_key.clone());
+
+    progress(&app_data, job_id, JobStatus::Deploying).await?;
+
+    log::debug!("Updating the contract in the persistent PostgreSQL database");
+    metrics
+        .time_database_query(
+            "update_contract",
+            postgresql.update_contract(ContractUpdateInput::new(
+                account_id,
+                query.version.clone(),
+                env!("CARGO_PKG_VERSION").to_owned(),
+                source_code.clone(),
+                body.bytecode.clone(),
+                body.verifying_key.clone(),
+                SharedDataContract::verifying_keys_to_json(&verifying_keys),
+                serde_json::to_value(&body.allowed_tokens)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+                body.abi.clone(),
+                serde_json::to_value(&method_costs).expect(zinc_const::panic::DATA_CONVERSION),
+            )),
+        )
+        .await?;
+
+    log::debug!(
+        "Renaming {} and appending {} contract storage fields",
+        migration.renamed.len(),
+        migration.appended.len()
+    );
+    metrics
+        .time_database_query(
+            "rename_fields",
+            postgresql.rename_fields(migration.to_database_rename(account_id)),
+        )
+        .await?;
+    metrics
+        .time_database_query(
+            "insert_fields",
+            postgresql.insert_fields(migration.to_database_insert(account_id)),
+        )
+        .await?;
+    metrics
+        .time_database_query(
+            "update_fields",
+            postgresql.update_fields(migration.storage.to_database_layout_hash_update(account_id)),
+        )
+        .await?;
+
+    contract.version = query.version;
+    contract.source_code = source_code;
+    contract.bytecode = body.bytecode;
+    contract.verifying_key = body.verifying_key;
+    contract.verifying_keys = verifying_keys;
+    contract.allowed_tokens = body.allowed_tokens;
+    contract.abi = body.abi;
+    contract.method_costs = method_costs;
+    contract.build = build;
+    contract.storage = migration.storage;
+
+    let contract_address = contract.eth_address;
+
+    log::debug!("Writing the contract to the temporary server cache");
+    app_data
+        .write()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .insert(contract_address, contract);
+
+    let response = PublishResponseBody::new_republished(contract_address, account_id);
+
+    log::debug!("The contract has been re-published");
+    Ok(response)
 }
@@ -2,7 +2,19 @@
 //! The contract resource POST response.
 //!
 
+use serde::Serialize;
+
+use zinc_zksync::PublishAcceptedResponseBody;
+use zinc_zksync::PublishResponseBody;
+
 ///
 /// The contract resource POST response body.
 ///
-pub type Body = zinc_zksync::PublishResponseBody;
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum Body {
+    /// The contract has been published within the request, which is made with `sync=true`.
+    Published(PublishResponseBody),
+    /// The contract is being published by the background job.
+    Accepted(PublishAcceptedResponseBody),
+}
@@ -5,11 +5,16 @@
 use std::fmt;
 
 use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
 use actix_web::ResponseError;
+use serde_json::json;
 
 use zinc_build::ValueError as BuildValueError;
 use zinc_vm::RuntimeError;
 
+use crate::auth::error::Error as AuthError;
+use crate::storage::error::Error as StorageError;
+
 ///
 /// The contract run feature PUT `query` error.
 ///
@@ -19,6 +24,8 @@ pub enum Error {
     ContractNotFound(String),
     /// The contract with the specified address is locked.
     ContractLocked(String),
+    /// The contract with the specified address has been archived.
+    ContractArchived(String),
     /// The specified method does not exist in the contract.
     MethodNotFound(String),
     /// The mutable method must be called via the `call` endpoint.
@@ -27,11 +34,15 @@ pub enum Error {
     MethodArgumentsNotFound(String),
     /// Invalid contract method arguments.
     InvalidInput(BuildValueError),
+    /// The private storage fields are requested without the owner credentials.
+    Unauthorized(AuthError),
 
     /// The virtual machine contract method runtime error.
     RuntimeError(RuntimeError),
     /// The PostgreSQL database error.
     Database(sqlx::Error),
+    /// The contract storage loading error.
+    Storage(StorageError),
     /// The ZkSync server client error.
     ZkSyncClient(zksync::error::ClientError),
 }
@@ -42,27 +53,75 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<AuthError> for Error {
+    fn from(inner: AuthError) -> Self {
+        Self::Unauthorized(inner)
+    }
+}
+
+impl From<StorageError> for Error {
+    fn from(inner: StorageError) -> Self {
+        Self::Storage(inner)
+    }
+}
+
 impl From<zksync::error::ClientError> for Error {
     fn from(inner: zksync::error::ClientError) -> Self {
         Self::ZkSyncClient(inner)
     }
 }
 
+impl Error {
+    ///
+    /// The error variant name, which is used as the `error` label of the errors metric.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ContractNotFound(..) => "ContractNotFound",
+            Self::ContractLocked(..) => "ContractLocked",
+            Self::ContractArchived(..) => "ContractArchived",
+            Self::MethodNotFound(..) => "MethodNotFound",
+            Self::MethodIsMutable(..) => "MethodIsMutable",
+            Self::MethodArgumentsNotFound(..) => "MethodArgumentsNotFound",
+            Self::InvalidInput(..) => "InvalidInput",
+            Self::Unauthorized(..) => "Unauthorized",
+            Self::RuntimeError(..) => "RuntimeError",
+            Self::Database(..) => "Database",
+            Self::Storage(..) => "Storage",
+            Self::ZkSyncClient(..) => "ZkSyncClient",
+        }
+    }
+}
+
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
             Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
             Self::ContractLocked(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ContractArchived(..) => StatusCode::GONE,
             Self::MethodNotFound(..) => StatusCode::BAD_REQUEST,
             Self::MethodIsMutable(..) => StatusCode::BAD_REQUEST,
             Self::MethodArgumentsNotFound(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(inner) => inner.status_code(),
 
             Self::RuntimeError(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(StorageError::ZkSyncClient(..)) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Storage(..) => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ZkSyncClient(..) => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::RuntimeError(inner) => HttpResponse::build(self.status_code())
+                .json(json!({ "message": self.to_string(), "error": inner.to_json() })),
+            _ => HttpResponse::build(self.status_code())
+                .content_type("text/plain; charset=utf-8")
+                .body(self.to_string()),
+        }
+    }
 }
 
 impl serde::Serialize for Error {
@@ -81,6 +140,9 @@ impl fmt::Display for Error {
                 format!("Contract with address {} not found", address)
             }
             Self::ContractLocked(address) => format!("Contract with address {} is locked", address),
+            Self::ContractArchived(address) => {
+                format!("Contract with address {} has been archived", address)
+            }
             Self::MethodNotFound(name) => format!("Method `{}` not found", name),
             Self::MethodIsMutable(name) => {
                 format!("Method `{}` is mutable: use 'call' instead", name)
@@ -89,9 +151,12 @@ impl fmt::Display for Error {
                 format!("Method `{}` input arguments missing in the request", name)
             }
             Self::InvalidInput(inner) => format!("Input: {}", inner),
+            Self::Unauthorized(inner) => format!("Unauthorized: {}", inner),
 
+            Self::RuntimeError(RuntimeError::RequireError(message)) => message.to_owned(),
             Self::RuntimeError(inner) => format!("Runtime: {:?}", inner),
             Self::Database(inner) => format!("Database: {:?}", inner),
+            Self::Storage(inner) => format!("Storage: {}", inner),
             Self::ZkSyncClient(inner) => format!("ZkSync: {:?}", inner),
         };
 
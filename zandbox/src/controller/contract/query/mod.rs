@@ -7,21 +7,24 @@ pub mod request;
 
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use actix_web::http::StatusCode;
 use actix_web::web;
 use serde_json::json;
+use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
 
-use zksync_eth_signer::PrivateKeySigner;
-
 use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::ContractInput;
 // use zinc_zksync::TransactionMsg;
 
+use crate::auth::Authorization;
 use crate::database::model::field::select::Input as FieldSelectInput;
 use crate::response::Response;
+use crate::shared_data::metrics::Metrics;
+use crate::shared_data::metrics::HANDLER_QUERY;
 use crate::shared_data::SharedData;
 use crate::storage::Storage;
 
@@ -33,9 +36,15 @@ use self::request::Query as RequestQuery;
 /// The HTTP request handler.
 ///
 /// Sequence:
-/// 1. Get the contract from the in-memory cache.
+/// 1. Get the contract from the in-memory cache. If the private storage fields are requested,
+///    check that the request is signed by the contract owner.
 /// 2. Get the contract storage from data sources and convert it to the Zinc VM representation.
+///    If the called method does not take `self`, the storage is not loaded, and the method is
+///    called with the default storage value.
 /// 3. If the method was not specified, return the contract storage to the client.
+///    If the proofs were requested, the storage Merkle tree root hash and the inclusion proofs
+///    of the public fields are returned along with the storage. The private fields are only
+///    returned to the contract owner.
 /// 4. Extract the called method from the contract metadata and check if it is immutable.
 /// 5. Parse the method input arguments.
 /// 6. Run the method on the Zinc VM.
@@ -45,6 +54,30 @@ pub async fn handle(
     app_data: web::Data<Arc<RwLock<SharedData>>>,
     query: web::Query<RequestQuery>,
     body: web::Json<RequestBody>,
+    authorization: Authorization,
+) -> crate::Result<JsonValue, Error> {
+    let metrics = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .metrics
+        .clone();
+
+    let result = execute(app_data, query, body, authorization, metrics.as_ref()).await;
+    if let Err(ref error) = result {
+        metrics.record_error(HANDLER_QUERY, error.kind());
+    }
+    result
+}
+
+///
+/// Executes the `handle` sequence, recording the timings into `metrics`.
+///
+async fn execute(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    query: web::Query<RequestQuery>,
+    body: web::Json<RequestBody>,
+    authorization: Authorization,
+    metrics: &Metrics,
 ) -> crate::Result<JsonValue, Error> {
     let query = query.into_inner();
     let body = body.into_inner();
@@ -55,44 +88,79 @@ pub async fn handle(
         .postgresql
         .clone();
 
-    let contract = app_data
-        .read()
-        .expect(zinc_const::panic::SYNCHRONIZATION)
-        .contracts
-        .get(&query.address)
-        .cloned()
-        .ok_or_else(|| {
-            Error::ContractNotFound(
-                serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
-            )
-        })?;
+    let contract = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        shared_data
+            .contracts
+            .get(&query.address)
+            .cloned()
+            .ok_or_else(|| {
+                let address = serde_json::to_string(&query.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+                if shared_data.archived_contracts.contains_key(&query.address) {
+                    Error::ContractArchived(address)
+                } else {
+                    Error::ContractNotFound(address)
+                }
+            })?
+    };
     let account_id = contract.account_id.ok_or_else(|| {
         Error::ContractLocked(
             serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION),
         )
     })?;
 
-    log::debug!("Initializing the contract wallet");
-    let provider = zksync::Provider::new(query.network);
-    let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-        query.address,
-        PrivateKeySigner::new(contract.eth_private_key),
-        query.network,
-    )
-    .await?;
-    let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
-
-    log::debug!("Loading the contract storage");
-    let database_fields = postgresql
-        .select_fields(FieldSelectInput::new(account_id))
-        .await?;
-    let storage = Storage::new_with_data(
-        database_fields,
-        contract.build.storage.as_slice(),
-        contract.eth_address,
-        &wallet,
-    )
-    .await?;
+    let is_private = query.private.unwrap_or_default();
+    if is_private {
+        log::debug!("Checking the contract owner credentials");
+        crate::auth::authorize(
+            &app_data
+                .read()
+                .expect(zinc_const::panic::SYNCHRONIZATION)
+                .auth_nonces,
+            &authorization,
+            contract.eth_address,
+            contract.owner_address,
+        )?;
+    }
+
+    let is_associated = query
+        .method
+        .as_ref()
+        .and_then(|method_name| contract.build.methods.get(method_name.as_str()))
+        .map(|method| method.is_associated)
+        .unwrap_or_default();
+
+    let storage = if is_associated {
+        log::debug!("Skipping the contract storage, as the method does not take `self`");
+        Storage::new(contract.build.storage.as_slice())
+    } else {
+        log::debug!("Initializing the contract wallet");
+        let gateway = app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .gateway
+            .clone();
+        let wallet = gateway
+            .connect(query.network, query.address, contract.eth_private_key)
+            .await?;
+
+        log::debug!("Loading the contract storage");
+        let database_fields = metrics
+            .time_database_query(
+                "select_fields",
+                postgresql.select_fields(FieldSelectInput::new(account_id)),
+            )
+            .await?;
+        Storage::new_with_data(
+            database_fields,
+            contract.build.storage.as_slice(),
+            contract.eth_address,
+            wallet.as_ref(),
+        )
+        .await?
+    };
+    let balances = storage.balances();
 
     let method_name = match query.method {
         Some(method_name) => {
@@ -108,10 +176,38 @@ pub async fn handle(
                 "Querying the storage of the contract {}",
                 serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION)
             );
-            return Ok(Response::new_with_data(
-                StatusCode::OK,
-                storage.into_public_build().into_json(),
-            ));
+            if !query.proof.unwrap_or_default() {
+                let storage = if is_private {
+                    storage.into_build()
+                } else {
+                    storage.into_public_build()
+                };
+                return Ok(Response::new_with_data(StatusCode::OK, storage.into_json()));
+            }
+
+            log::debug!("Building the contract storage proofs");
+            let (root_hash, proofs) = zinc_vm::ContractFacade::new(contract.build)
+                .storage_proofs::<Bn256>(storage.clone().into_build())
+                .map_err(Error::RuntimeError)?;
+            let proofs = storage
+                .fields
+                .iter()
+                .zip(proofs.into_iter())
+                .filter(|(field, _proofs)| field.is_public || is_private)
+                .map(|(field, proofs)| (field.name.to_owned(), json!(proofs)))
+                .collect::<JsonMap<String, JsonValue>>();
+
+            let storage = if is_private {
+                storage.into_build()
+            } else {
+                storage.into_public_build()
+            };
+            let response = json!({
+                "storage": storage.into_json(),
+                "root_hash": root_hash,
+                "proofs": proofs,
+            });
+            return Ok(Response::new_with_data(StatusCode::OK, response));
         }
     };
 
@@ -127,23 +223,45 @@ pub async fn handle(
         Some(arguments) => arguments,
         None => return Err(Error::MethodArgumentsNotFound(method_name)),
     };
-    let input_value =
-        BuildValue::try_from_typed_json(arguments, method.input).map_err(Error::InvalidInput)?;
+    let settings = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings;
+    let input_value = BuildValue::try_from_typed_json_with_depth_limit(
+        arguments,
+        method.input,
+        settings.json_depth_limit,
+    )
+    .map_err(Error::InvalidInput)?;
 
     log::debug!("Running the contract method on the virtual machine");
-    let vm_time = std::time::Instant::now();
+    let contract_name = contract.name;
+    let contract_build = contract.build;
+    let method_to_run = method_name.clone();
+    let vm_time = Instant::now();
     let output = async_std::task::spawn_blocking(move || {
-        zinc_vm::ContractFacade::new(contract.build).run::<Bn256>(ContractInput::new(
+        let mut input = ContractInput::new(
             input_value,
             storage.into_build(),
-            method_name,
+            method_to_run,
             // TransactionMsg::default(),
             Vec::new(),
-        ))
+        );
+        input.balances = balances;
+        input.privacy = settings.privacy;
+        input.environment = crate::environment::current();
+
+        zinc_vm::ContractFacade::new(contract_build).run::<Bn256>(input)
     })
-    .await
-    .map_err(Error::RuntimeError)?;
+    .await;
+    metrics.record_vm_execution(
+        HANDLER_QUERY,
+        contract_name.as_str(),
+        method_name.as_str(),
+        vm_time.elapsed(),
+    );
     log::debug!("VM executed in {} ms", vm_time.elapsed().as_millis());
+    let output = output.map_err(Error::RuntimeError)?;
 
     let response = json!({
         "output": output.result.into_json(),
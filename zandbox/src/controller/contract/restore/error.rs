@@ -0,0 +1,78 @@
+//!
+//! The contract resource POST `restore` error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+use crate::auth::error::Error as AuthError;
+
+///
+/// The contract resource POST `restore` error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+    /// The contract with the specified address has not been archived.
+    ContractNotArchived(String),
+    /// The restoring is not signed by the contract owner.
+    Unauthorized(AuthError),
+
+    /// The PostgreSQL database error.
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(inner: sqlx::Error) -> Self {
+        Self::Database(inner)
+    }
+}
+
+impl From<AuthError> for Error {
+    fn from(inner: AuthError) -> Self {
+        Self::Unauthorized(inner)
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::ContractNotArchived(..) => StatusCode::CONFLICT,
+            Self::Unauthorized(inner) => inner.status_code(),
+
+            Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+            Self::ContractNotArchived(address) => {
+                format!("Contract with address {} has not been archived", address)
+            }
+            Self::Unauthorized(inner) => format!("Unauthorized: {}", inner),
+
+            Self::Database(inner) => format!("Database: {:?}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
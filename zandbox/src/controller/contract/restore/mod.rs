@@ -0,0 +1,89 @@
+//!
+//! The contract resource POST method `restore` module.
+//!
+
+pub mod error;
+pub mod request;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::auth::Authorization;
+use crate::database::model::contract::update_archived::Input as ContractUpdateArchivedInput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Path as RequestPath;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the archived ones and check that the request is signed by the
+///    contract owner.
+/// 2. Unmark the contract as archived in the persistent database.
+/// 3. Move the contract back to the in-memory cache, so it is served again.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+    authorization: Authorization,
+) -> crate::Result<(), Error> {
+    let path = path.into_inner();
+    let address = serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let contract = {
+        let shared_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        shared_data
+            .archived_contracts
+            .get(&path.address)
+            .cloned()
+            .ok_or_else(|| {
+                if shared_data.contracts.contains_key(&path.address) {
+                    Error::ContractNotArchived(address.clone())
+                } else {
+                    Error::ContractNotFound(address.clone())
+                }
+            })?
+    };
+    let account_id = contract
+        .account_id
+        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+    log::debug!("Checking the contract owner credentials");
+    crate::auth::authorize(
+        &app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .auth_nonces,
+        &authorization,
+        contract.eth_address,
+        contract.owner_address,
+    )?;
+
+    log::debug!("Restoring the contract in the persistent PostgreSQL database");
+    postgresql
+        .update_contract_archived(ContractUpdateArchivedInput::new(account_id, false))
+        .await?;
+
+    log::debug!("Moving the contract back to the in-memory cache");
+    let mut shared_data = app_data.write().expect(zinc_const::panic::SYNCHRONIZATION);
+    let contract = shared_data
+        .archived_contracts
+        .remove(&path.address)
+        .ok_or_else(|| Error::ContractNotFound(address.clone()))?;
+    shared_data.contracts.insert(path.address, contract);
+
+    log::debug!("The contract {} has been restored", address);
+    Ok(Response::new(StatusCode::OK))
+}
@@ -0,0 +1,91 @@
+//!
+//! The contract resource POST `verify` error.
+//!
+
+use std::fmt;
+use std::io;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+use zinc_build::ValueError as BuildValueError;
+use zinc_vm::VerificationError;
+
+///
+/// The contract resource POST `verify` error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The contract with the specified address is not found in the server cache.
+    ContractNotFound(String),
+    /// The specified method does not exist in the contract.
+    MethodNotFound(String),
+    /// The verifying key has not been uploaded for the specified method.
+    VerifyingKeyNotFound(String),
+    /// The proof is not a valid hex string.
+    InvalidProofHex(rustc_hex::FromHexError),
+    /// The proof bytes cannot be decoded.
+    InvalidProof(io::Error),
+    /// The public input does not match the method output type.
+    InvalidPublicInput(BuildValueError),
+    /// The method arguments do not match the method input type.
+    InvalidArguments(BuildValueError),
+
+    /// The stored verifying key cannot be decoded.
+    InvalidVerifyingKey(io::Error),
+    /// The virtual machine proof verification error.
+    Verification(VerificationError),
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::MethodNotFound(..) => StatusCode::BAD_REQUEST,
+            Self::VerifyingKeyNotFound(..) => StatusCode::NOT_FOUND,
+            Self::InvalidProofHex(..) => StatusCode::BAD_REQUEST,
+            Self::InvalidProof(..) => StatusCode::BAD_REQUEST,
+            Self::InvalidPublicInput(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InvalidArguments(..) => StatusCode::UNPROCESSABLE_ENTITY,
+
+            Self::InvalidVerifyingKey(..) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Verification(VerificationError::ValueOverflow(..)) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Self::Verification(..) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::ContractNotFound(address) => {
+                format!("Contract with address {} not found", address)
+            }
+            Self::MethodNotFound(name) => format!("Method `{}` not found", name),
+            Self::VerifyingKeyNotFound(name) => {
+                format!("Verifying key for method `{}` not found", name)
+            }
+            Self::InvalidProofHex(inner) => format!("Proof hex: {}", inner),
+            Self::InvalidProof(inner) => format!("Proof: {}", inner),
+            Self::InvalidPublicInput(inner) => format!("Public input: {}", inner),
+            Self::InvalidArguments(inner) => format!("Arguments: {}", inner),
+
+            Self::InvalidVerifyingKey(inner) => format!("Verifying key: {}", inner),
+            Self::Verification(inner) => format!("Verification: {}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
@@ -0,0 +1,127 @@
+//!
+//! The contract resource POST `verify` method module.
+//!
+
+pub mod error;
+pub mod request;
+pub mod response;
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use rustc_hex::FromHex;
+
+use zinc_build::Value as BuildValue;
+use zinc_vm::Bn256;
+use zinc_vm::Facade;
+use zinc_vm::Proof;
+use zinc_vm::VerifyingKey;
+
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Body as RequestBody;
+use self::request::Path as RequestPath;
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract from the in-memory cache.
+/// 2. Extract the method from its metadata and get the verifying key uploaded for it.
+/// 3. Parse the public input using the method output type.
+/// 4. Parse the `#[public]` method arguments using the method input type.
+/// 5. Decode the proof from its hex representation.
+/// 6. Verify the proof on the Zinc VM, including the arguments and block environment it commits to.
+/// 7. Send the verification result back to the client.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+    body: web::Json<RequestBody>,
+) -> crate::Result<ResponseBody, Error> {
+    let path = path.into_inner();
+    let body = body.into_inner();
+
+    log::info!(
+        "Verifying the proof for method `{}` of contract {}",
+        body.method,
+        serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    let contract = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .get(&path.address)
+        .cloned()
+        .ok_or_else(|| {
+            Error::ContractNotFound(
+                serde_json::to_string(&path.address).expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        })?;
+
+    let method = match contract.build.methods.get(body.method.as_str()).cloned() {
+        Some(method) => method,
+        None => return Err(Error::MethodNotFound(body.method)),
+    };
+    let verifying_key = match contract.verifying_keys.get(body.method.as_str()) {
+        Some(verifying_key) => VerifyingKey::<Bn256>::read(verifying_key.as_slice())
+            .map_err(Error::InvalidVerifyingKey)?,
+        None => return Err(Error::VerifyingKeyNotFound(body.method)),
+    };
+
+    let output_type = method.output.clone().into_mutable_method_output();
+    let json_depth_limit = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .settings
+        .json_depth_limit;
+    let public_input = BuildValue::try_from_typed_json_with_depth_limit(
+        body.public_input,
+        output_type,
+        json_depth_limit,
+    )
+    .map_err(Error::InvalidPublicInput)?;
+    let public_arguments = if method.public_arguments.is_empty() {
+        vec![]
+    } else {
+        let arguments = BuildValue::try_from_typed_json_with_depth_limit(
+            body.arguments,
+            method.input.clone(),
+            json_depth_limit,
+        )
+        .map_err(Error::InvalidArguments)?;
+        method.public_argument_values(arguments)
+    };
+
+    let proof: Vec<u8> = body
+        .proof_hex
+        .trim()
+        .trim_start_matches("0x")
+        .from_hex()
+        .map_err(Error::InvalidProofHex)?;
+    let proof = Proof::<Bn256>::read(proof.as_slice()).map_err(Error::InvalidProof)?;
+    let environment = body.env;
+
+    log::debug!("Verifying the proof on the virtual machine");
+    let valid = async_std::task::spawn_blocking(move || {
+        Facade::verify_contract::<Bn256>(
+            verifying_key,
+            proof,
+            public_arguments,
+            public_input,
+            environment,
+        )
+    })
+    .await
+    .map_err(Error::Verification)?;
+
+    let response = ResponseBody::new(valid);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,13 @@
+//!
+//! The contract resource POST `verify` request.
+//!
+
+///
+/// The contract resource POST `verify` request path.
+///
+pub type Path = zinc_zksync::VerifyRequestPath;
+
+///
+/// The contract resource POST `verify` request body.
+///
+pub type Body = zinc_zksync::VerifyRequestBody;
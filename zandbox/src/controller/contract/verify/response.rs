@@ -0,0 +1,8 @@
+//!
+//! The contract resource POST `verify` response.
+//!
+
+///
+/// The contract resource POST `verify` response body.
+///
+pub type Body = zinc_zksync::VerifyResponseBody;
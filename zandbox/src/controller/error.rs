@@ -0,0 +1,70 @@
+//!
+//! The Zandbox server daemon request body error.
+//!
+
+use std::fmt;
+
+use actix_web::error::JsonPayloadError;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use actix_web::ResponseError;
+use serde_json::json;
+
+///
+/// The Zandbox server daemon request body error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The request body exceeds the endpoint size limit.
+    PayloadTooLarge {
+        /// The request body size limit in bytes.
+        limit: usize,
+    },
+    /// The request body is not a valid JSON of the expected shape.
+    InvalidPayload(String),
+}
+
+impl Error {
+    ///
+    /// Converts the actix JSON extractor `error`, which has the size `limit` configured.
+    ///
+    pub fn new(error: JsonPayloadError, limit: usize) -> Self {
+        match error {
+            JsonPayloadError::Overflow => Self::PayloadTooLarge { limit },
+            error => Self::InvalidPayload(error.to_string()),
+        }
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::InvalidPayload(..) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::PayloadTooLarge { limit } => HttpResponse::build(self.status_code())
+                .json(json!({ "message": self.to_string(), "limit": limit })),
+            Self::InvalidPayload(..) => {
+                HttpResponse::build(self.status_code()).json(json!({ "message": self.to_string() }))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::PayloadTooLarge { limit } => {
+                format!("Request body exceeds the limit of {} bytes", limit)
+            }
+            Self::InvalidPayload(inner) => format!("Request body: {}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
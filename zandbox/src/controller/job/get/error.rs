@@ -0,0 +1,58 @@
+//!
+//! The contract publishing job resource GET error.
+//!
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+///
+/// The contract publishing job resource GET error.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The job with the specified ID is not found or has expired.
+    JobNotFound(String),
+
+    /// The PostgreSQL database error.
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(inner: sqlx::Error) -> Self {
+        Self::Database(inner)
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::JobNotFound(..) => StatusCode::NOT_FOUND,
+
+            Self::Database(..) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = match self {
+            Self::JobNotFound(id) => format!("Job with ID {} not found", id),
+
+            Self::Database(inner) => format!("Database: {:?}", inner),
+        };
+
+        log::warn!("{}", error);
+        write!(f, "{}", error)
+    }
+}
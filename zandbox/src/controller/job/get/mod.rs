@@ -0,0 +1,71 @@
+//!
+//! The contract publishing job resource GET method module.
+//!
+
+pub mod error;
+pub mod request;
+pub mod response;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use zksync_types::AccountId;
+
+use zinc_zksync::JobStatus;
+
+use crate::database::model::job::select::Input as JobSelectInput;
+use crate::response::Response;
+use crate::shared_data::SharedData;
+
+use self::error::Error;
+use self::request::Path as RequestPath;
+use self::response::Body as ResponseBody;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the job from the in-memory cache.
+/// 2. If the job has been created before the server restart, get it from the database.
+/// 3. Return the job status along with the error details or the published contract address.
+///
+pub async fn handle(
+    app_data: web::Data<Arc<RwLock<SharedData>>>,
+    path: web::Path<RequestPath>,
+) -> crate::Result<ResponseBody, Error> {
+    let path = path.into_inner();
+
+    let (job, postgresql, ttl) = {
+        let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        (
+            app_data.jobs.get(path.id.as_str()),
+            app_data.postgresql.clone(),
+            app_data.jobs.ttl(),
+        )
+    };
+
+    let response = match job {
+        Some(job) => ResponseBody::new(path.id, job.status, job.error, job.address, job.account_id),
+        None => {
+            log::debug!("Selecting the job {} from the database", path.id);
+            let job = postgresql
+                .select_job(JobSelectInput::new(path.id.clone(), ttl))
+                .await?
+                .ok_or_else(|| Error::JobNotFound(path.id.clone()))?;
+
+            ResponseBody::new(
+                path.id,
+                JobStatus::from_str(job.status.as_str()).expect(zinc_const::panic::DATA_CONVERSION),
+                job.error,
+                job.eth_address.map(zinc_zksync::eth_address_from_vec),
+                job.account_id.map(|account_id| account_id as AccountId),
+            )
+        }
+    };
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,14 @@
+//!
+//! The contract publishing job resource GET request.
+//!
+
+use serde::Deserialize;
+
+///
+/// The contract publishing job resource GET request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The job ID.
+    pub id: String,
+}
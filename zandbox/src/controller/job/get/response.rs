@@ -0,0 +1,8 @@
+//!
+//! The contract publishing job resource GET response.
+//!
+
+///
+/// The contract publishing job resource GET response body.
+///
+pub type Body = zinc_zksync::JobResponseBody;
@@ -0,0 +1,5 @@
+//!
+//! The contract publishing job resource.
+//!
+
+pub mod get;
@@ -0,0 +1,28 @@
+//!
+//! The metrics GET method module.
+//!
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::web;
+use actix_web::HttpResponse;
+
+use crate::shared_data::SharedData;
+
+///
+/// The metrics GET method endpoint handler.
+///
+/// The metrics are returned in the Prometheus text exposition format.
+///
+pub async fn handle(app_data: web::Data<Arc<RwLock<SharedData>>>) -> HttpResponse {
+    let metrics = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .metrics
+        .to_prometheus();
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4; charset=utf-8")
+        .body(metrics)
+}
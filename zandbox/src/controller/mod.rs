@@ -3,49 +3,130 @@
 //!
 
 pub mod contract;
+pub mod error;
 pub mod head;
+pub mod job;
+pub mod metrics;
 
 use actix_web::web;
 
+use crate::settings::Settings;
+
+use self::error::Error;
+
 ///
 /// The Zandbox server daemon routing initializer.
 ///
-pub fn configure(config: &mut web::ServiceConfig) {
+/// The request body size limits are taken from the `settings`. The contract publishing
+/// endpoint has its own limit, since it receives the contract source code and bytecode.
+///
+pub fn configure(settings: Settings, config: &mut web::ServiceConfig) {
+    let publish_json = json_config(settings.publish_payload_limit);
+    let method_json = json_config(settings.method_payload_limit);
+
     config.service(
         web::scope("/api").service(
-            web::scope("/v1").service(
-                web::scope("/contract")
-                    .service(
-                        web::resource("")
-                            .route(web::head().to(head::handle))
-                            .route(web::post().to(contract::post::handle)),
-                    )
-                    .service(
-                        web::resource("/initialize")
-                            .route(web::head().to(head::handle))
-                            .route(web::put().to(contract::initialize::handle)),
-                    )
-                    .service(
-                        web::resource("/query")
-                            .route(web::head().to(head::handle))
-                            .route(web::put().to(contract::query::handle)),
-                    )
-                    .service(
-                        web::resource("/fee")
+            web::scope("/v1")
+                .service(
+                    web::scope("/contract")
+                        .service(
+                            web::resource("")
+                                .app_data(publish_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::post::handle)),
+                        )
+                        .service(
+                            web::resource("/initialize")
+                                .app_data(method_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::put().to(contract::initialize::handle)),
+                        )
+                        .service(
+                            web::resource("/query")
+                                .app_data(method_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::put().to(contract::query::handle)),
+                        )
+                        .service(
+                            web::resource("/fee")
+                                .app_data(method_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::put().to(contract::fee::handle)),
+                        )
+                        .service(
+                            web::resource("/call")
+                                .app_data(method_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::call::handle)),
+                        )
+                        .service(
+                            web::resource("/key")
+                                .app_data(method_json.clone())
+                                .route(web::head().to(head::handle))
+                                .route(web::put().to(contract::key::handle)),
+                        )
+                        .service(
+                            web::resource("/nonce")
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::nonce::handle)),
+                        )
+                        .service(
+                            web::resource("/curve")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::curve::handle)),
+                        ),
+                )
+                .service(
+                    web::scope("/contracts")
+                        .service(
+                            web::resource("")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::list::handle)),
+                        )
+                        .service(
+                            web::resource("/{address}")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::get::handle))
+                                .route(web::delete().to(contract::delete::handle)),
+                        )
+                        .service(
+                            web::resource("/{address}/restore")
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::restore::handle)),
+                        )
+                        .service(
+                            web::resource("/{address}/abi")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::abi::handle)),
+                        )
+                        .service(
+                            web::resource("/{address}/verify")
+                                .app_data(method_json)
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::verify::handle)),
+                        ),
+                )
+                .service(
+                    web::scope("/jobs").service(
+                        web::resource("/{id}")
                             .route(web::head().to(head::handle))
-                            .route(web::put().to(contract::fee::handle)),
-                    )
-                    .service(
-                        web::resource("/call")
-                            .route(web::head().to(head::handle))
-                            .route(web::post().to(contract::call::handle)),
-                    )
-                    .service(
-                        web::resource("/curve")
-                            .route(web::head().to(head::handle))
-                            .route(web::get().to(contract::curve::handle)),
+                            .route(web::get().to(job::get::handle)),
                     ),
-            ),
+                )
+                .service(
+                    web::resource("/metrics")
+                        .route(web::head().to(head::handle))
+                        .route(web::get().to(metrics::handle)),
+                ),
         ),
     );
 }
+
+///
+/// Creates the JSON extractor config with the request body size `limit`.
+///
+pub(crate) fn json_config(limit: usize) -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(limit)
+        .error_handler(move |error, _request| Error::new(error, limit).into())
+}
@@ -9,10 +9,21 @@ use sqlx::Postgres;
 use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
 use crate::database::model::contract::select_all::Output as ContractSelectAllOutput;
 use crate::database::model::contract::select_curve::Output as ContractSelectCurveOutput;
+use crate::database::model::contract::select_list::Input as ContractSelectListInput;
+use crate::database::model::contract::select_list::Output as ContractSelectListOutput;
+use crate::database::model::contract::update::Input as ContractUpdateInput;
+use crate::database::model::contract::update_archived::Input as ContractUpdateArchivedInput;
+use crate::database::model::contract::update_private_key::Input as ContractUpdatePrivateKeyInput;
 use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::rename::Input as FieldRenameInput;
 use crate::database::model::field::select::Input as FieldSelectInput;
 use crate::database::model::field::select::Output as FieldSelectOutput;
 use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::database::model::job::delete_expired::Input as JobDeleteExpiredInput;
+use crate::database::model::job::insert_new::Input as JobInsertNewInput;
+use crate::database::model::job::select::Input as JobSelectInput;
+use crate::database::model::job::select::Output as JobSelectOutput;
+use crate::database::model::job::update::Input as JobUpdateInput;
 
 ///
 /// The database asynchronous client adapter.
@@ -51,9 +62,16 @@ impl Client {
             source_code,
             bytecode,
             verifying_key,
+            verifying_keys,
+            allowed_tokens,
+            abi,
+            method_costs,
 
             eth_address,
-            eth_private_key
+            eth_private_key,
+            owner_address,
+
+            archived_at IS NOT NULL AS is_archived
         FROM zandbox.contracts
         ORDER BY created_at;
         "#;
@@ -73,7 +91,9 @@ impl Client {
             
             name,
             version,
-            instance
+            instance,
+
+            allowed_tokens
         FROM zandbox.contracts
         WHERE
             name = 'curve'
@@ -83,6 +103,78 @@ impl Client {
         Ok(sqlx::query_as(STATEMENT).fetch_all(&self.pool).await?)
     }
 
+    ///
+    /// Selects a page of the contracts from the `contracts` table.
+    ///
+    /// Returns the page along with the total number of contracts matching the filters,
+    /// regardless of the `limit` and `offset` values.
+    ///
+    /// The archived contracts are only selected if `include_archived` is set.
+    ///
+    pub async fn select_contracts_list(
+        &self,
+        input: ContractSelectListInput,
+    ) -> Result<(Vec<ContractSelectListOutput>, i64), sqlx::Error> {
+        const COUNT_STATEMENT: &str = r#"
+        SELECT
+            COUNT(*)
+        FROM zandbox.contracts
+        WHERE
+            ($1::TEXT IS NULL OR POSITION($1 IN name) > 0)
+        AND ($2::BYTEA IS NULL OR owner_address = $2)
+        AND ($3 OR archived_at IS NULL);
+        "#;
+
+        let statement = format!(
+            r#"
+        SELECT
+            account_id,
+
+            name,
+            version,
+            instance,
+
+            allowed_tokens,
+
+            eth_address,
+            owner_address,
+
+            archived_at IS NOT NULL AS is_archived
+        FROM zandbox.contracts
+        WHERE
+            ($1::TEXT IS NULL OR POSITION($1 IN name) > 0)
+        AND ($2::BYTEA IS NULL OR owner_address = $2)
+        AND ($3 OR archived_at IS NULL)
+        ORDER BY {}
+        LIMIT $4
+        OFFSET $5;
+        "#,
+            input.sort.order_by()
+        );
+
+        let owner_address = input
+            .owner_address
+            .map(|address| <[u8; zinc_const::size::ETH_ADDRESS]>::from(address).to_vec());
+
+        let (total_count,): (i64,) = sqlx::query_as(COUNT_STATEMENT)
+            .bind(input.name_contains.clone())
+            .bind(owner_address.clone())
+            .bind(input.include_archived)
+            .fetch_one(&self.pool)
+            .await?;
+
+        let contracts = sqlx::query_as(statement.as_str())
+            .bind(input.name_contains)
+            .bind(owner_address)
+            .bind(input.include_archived)
+            .bind(input.limit)
+            .bind(input.offset)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((contracts, total_count))
+    }
+
     ///
     /// Inserts a contract instance into the `contracts` table.
     ///
@@ -99,9 +191,14 @@ impl Client {
             source_code,
             bytecode,
             verifying_key,
+            verifying_keys,
+            allowed_tokens,
+            abi,
+            method_costs,
 
             eth_address,
             eth_private_key,
+            owner_address,
 
             created_at
         ) VALUES (
@@ -115,6 +212,11 @@ impl Client {
             $8,
             $9,
             $10,
+            $11,
+            $12,
+            $13,
+            $14,
+            $15,
             NOW()
         );
         "#;
@@ -128,8 +230,105 @@ impl Client {
             .bind(input.source_code)
             .bind(input.bytecode)
             .bind(input.verifying_key)
+            .bind(input.verifying_keys)
+            .bind(input.allowed_tokens)
+            .bind(input.abi)
+            .bind(input.method_costs)
             .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec())
             .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
+            .bind(
+                input
+                    .owner_address
+                    .map(|address| <[u8; zinc_const::size::ETH_ADDRESS]>::from(address).to_vec()),
+            )
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Updates a contract instance in the `contracts` table, when it is re-published.
+    ///
+    pub async fn update_contract(&self, input: ContractUpdateInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            version = $2,
+            zinc_version = $3,
+            source_code = $4,
+            bytecode = $5,
+            verifying_key = $6,
+            verifying_keys = $7,
+            allowed_tokens = $8,
+            abi = $9,
+            method_costs = $10
+        WHERE
+            account_id = $1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version)
+            .bind(input.zinc_version)
+            .bind(input.source_code)
+            .bind(input.bytecode)
+            .bind(input.verifying_key)
+            .bind(input.verifying_keys)
+            .bind(input.allowed_tokens)
+            .bind(input.abi)
+            .bind(input.method_costs)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Replaces the ETH private key of a contract instance in the `contracts` table, when the
+    /// contract signing key is rotated.
+    ///
+    pub async fn update_contract_private_key(
+        &self,
+        input: ContractUpdatePrivateKeyInput,
+    ) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            eth_private_key = $2
+        WHERE
+            account_id = $1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Archives or restores a contract instance in the `contracts` table.
+    ///
+    /// The contract storage fields are retained, so the contract can be restored later.
+    ///
+    pub async fn update_contract_archived(
+        &self,
+        input: ContractUpdateArchivedInput,
+    ) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            archived_at = CASE WHEN $2 THEN NOW() ELSE NULL END
+        WHERE
+            account_id = $1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.is_archived)
             .execute(&self.pool)
             .await?;
 
@@ -229,6 +428,31 @@ impl Client {
         Ok(())
     }
 
+    ///
+    /// Renames contract storage fields in the `fields` table.
+    ///
+    pub async fn rename_fields(&self, input: Vec<FieldRenameInput>) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.fields
+        SET
+            name = $3
+        WHERE
+            index = $2
+        AND account_id = $1;
+        "#;
+
+        for field in input.into_iter() {
+            sqlx::query(STATEMENT)
+                .bind(field.account_id)
+                .bind(field.index)
+                .bind(field.name)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     ///
     /// Deletes the `field` table contents.
     ///
@@ -241,4 +465,148 @@ impl Client {
 
         Ok(())
     }
+
+    ///
+    /// Selects a contract publishing job from the `jobs` table.
+    ///
+    /// The jobs finished more than the TTL ago are not selected.
+    ///
+    pub async fn select_job(
+        &self,
+        input: JobSelectInput,
+    ) -> Result<Option<JobSelectOutput>, sqlx::Error> {
+        const STATEMENT: &str = r#"
+        SELECT
+            status,
+            error,
+
+            eth_address,
+            account_id
+        FROM zandbox.jobs
+        WHERE
+            id = $1
+        AND (status NOT IN ('done', 'failed') OR updated_at > NOW() - $2 * INTERVAL '1 second');
+        "#;
+
+        Ok(sqlx::query_as(STATEMENT)
+            .bind(input.id)
+            .bind(input.ttl.as_secs_f64())
+            .fetch_optional(&self.pool)
+            .await?)
+    }
+
+    ///
+    /// Inserts a pending contract publishing job into the `jobs` table.
+    ///
+    pub async fn insert_job(&self, input: JobInsertNewInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.jobs (
+            id,
+
+            status,
+
+            created_at,
+            updated_at
+        ) VALUES (
+            $1,
+            'pending',
+            NOW(),
+            NOW()
+        );
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Updates a contract publishing job in the `jobs` table, when its status changes.
+    ///
+    pub async fn update_job(&self, input: JobUpdateInput) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.jobs
+        SET
+            status = $2,
+            error = $3,
+            eth_address = $4,
+            account_id = $5,
+            updated_at = NOW()
+        WHERE
+            id = $1;
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.id)
+            .bind(input.status.as_str())
+            .bind(input.error)
+            .bind(
+                input
+                    .eth_address
+                    .map(|address| <[u8; zinc_const::size::ETH_ADDRESS]>::from(address).to_vec()),
+            )
+            .bind(input.account_id.map(|account_id| account_id as i64))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Fails the contract publishing jobs in the `jobs` table, which have been interrupted by
+    /// the server shutdown.
+    ///
+    pub async fn update_jobs_interrupted(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.jobs
+        SET
+            status = 'failed',
+            error = 'Interrupted by the server shutdown',
+            updated_at = NOW()
+        WHERE
+            status NOT IN ('done', 'failed');
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the contract publishing jobs finished more than the TTL ago from the `jobs` table.
+    ///
+    pub async fn delete_jobs_expired(
+        &self,
+        input: JobDeleteExpiredInput,
+    ) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.jobs
+        WHERE
+            status IN ('done', 'failed')
+        AND updated_at <= NOW() - $1 * INTERVAL '1 second';
+        "#;
+
+        sqlx::query(STATEMENT)
+            .bind(input.ttl.as_secs_f64())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes the `jobs` table contents.
+    ///
+    pub async fn delete_jobs(&self) -> Result<(), sqlx::Error> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.jobs;
+        "#;
+
+        sqlx::query(STATEMENT).execute(&self.pool).await?;
+
+        Ok(())
+    }
 }
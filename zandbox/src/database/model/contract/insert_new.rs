@@ -31,11 +31,21 @@ pub struct Input {
     pub bytecode: Vec<u8>,
     /// The contract verifying key as a byte array.
     pub verifying_key: Vec<u8>,
+    /// The contract method verifying keys JSON representation.
+    pub verifying_keys: JsonValue,
+    /// The contract token allow-list JSON representation.
+    pub allowed_tokens: JsonValue,
+    /// The contract ABI JSON document, if it has been published.
+    pub abi: Option<JsonValue>,
+    /// The contract method costs JSON representation.
+    pub method_costs: JsonValue,
 
     /// The contract ETH address.
     pub eth_address: Address,
     /// The contract private key.
     pub eth_private_key: H256,
+    /// The address of the account, which has initialized the contract.
+    pub owner_address: Option<Address>,
 }
 
 impl Input {
@@ -54,9 +64,14 @@ impl Input {
         source_code: JsonValue,
         bytecode: Vec<u8>,
         verifying_key: Vec<u8>,
+        verifying_keys: JsonValue,
+        allowed_tokens: JsonValue,
+        abi: Option<JsonValue>,
+        method_costs: JsonValue,
 
         eth_address: Address,
         eth_private_key: H256,
+        owner_address: Option<Address>,
     ) -> Self {
         Self {
             account_id,
@@ -69,9 +84,14 @@ impl Input {
             source_code,
             bytecode,
             verifying_key,
+            verifying_keys,
+            allowed_tokens,
+            abi,
+            method_costs,
 
             eth_address,
             eth_private_key,
+            owner_address,
         }
     }
 }
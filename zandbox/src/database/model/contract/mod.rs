@@ -5,3 +5,7 @@
 pub mod insert_new;
 pub mod select_all;
 pub mod select_curve;
+pub mod select_list;
+pub mod update;
+pub mod update_archived;
+pub mod update_private_key;
@@ -25,9 +25,22 @@ pub struct Output {
     pub bytecode: Vec<u8>,
     /// The contract verifying key.
     pub verifying_key: Vec<u8>,
+    /// The contract method verifying keys.
+    pub verifying_keys: JsonValue,
+    /// The contract token allow-list.
+    pub allowed_tokens: JsonValue,
+    /// The contract ABI, if it has been published.
+    pub abi: Option<JsonValue>,
+    /// The contract method costs.
+    pub method_costs: JsonValue,
 
     /// The contract ETH address.
     pub eth_address: Vec<u8>,
     /// The contract private key.
     pub eth_private_key: Vec<u8>,
+    /// The address of the account, which has initialized the contract.
+    pub owner_address: Option<Vec<u8>>,
+
+    /// Whether the contract has been archived.
+    pub is_archived: bool,
 }
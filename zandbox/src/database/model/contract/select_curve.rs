@@ -2,6 +2,8 @@
 //! The database contract SELECT Curve model.
 //!
 
+use serde_json::Value as JsonValue;
+
 ///
 /// The database contract SELECT Curve output model.
 ///
@@ -16,18 +18,28 @@ pub struct Output {
     pub version: String,
     /// The contract instance name.
     pub instance: String,
+
+    /// The contract token allow-list.
+    pub allowed_tokens: JsonValue,
 }
 
 impl Output {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(eth_address: Vec<u8>, name: String, version: String, instance: String) -> Self {
+    pub fn new(
+        eth_address: Vec<u8>,
+        name: String,
+        version: String,
+        instance: String,
+        allowed_tokens: JsonValue,
+    ) -> Self {
         Self {
             eth_address,
             name,
             version,
             instance,
+            allowed_tokens,
         }
     }
 }
@@ -0,0 +1,107 @@
+//!
+//! The database contract SELECT list model.
+//!
+
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
+
+///
+/// The database contract SELECT list sorting key.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sort {
+    /// Sort by the contract creation time, the oldest first.
+    CreatedAt,
+    /// Sort by the contract name, version, and instance name alphabetically.
+    Name,
+}
+
+impl Sort {
+    ///
+    /// The SQL `ORDER BY` clause contents.
+    ///
+    /// The account ID is appended to make the order stable across pages.
+    ///
+    pub fn order_by(self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at, account_id",
+            Self::Name => "name, version, instance, account_id",
+        }
+    }
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Self::CreatedAt
+    }
+}
+
+///
+/// The database contract SELECT list input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The maximum number of contracts to select.
+    pub limit: i64,
+    /// The number of contracts to skip.
+    pub offset: i64,
+    /// The substring, which the contract name must contain.
+    pub name_contains: Option<String>,
+    /// The address of the account, which has initialized the contract.
+    pub owner_address: Option<Address>,
+    /// Whether the archived contracts must be selected as well.
+    pub include_archived: bool,
+    /// The sorting key.
+    pub sort: Sort,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        limit: i64,
+        offset: i64,
+        name_contains: Option<String>,
+        owner_address: Option<Address>,
+        include_archived: bool,
+        sort: Sort,
+    ) -> Self {
+        Self {
+            limit,
+            offset,
+            name_contains,
+            owner_address,
+            include_archived,
+            sort,
+        }
+    }
+}
+
+///
+/// The database contract SELECT list output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The contract account ID.
+    pub account_id: i64,
+
+    /// The contract project name.
+    pub name: String,
+    /// The contract version.
+    pub version: String,
+    /// The contract instance name.
+    pub instance: String,
+
+    /// The contract token allow-list.
+    pub allowed_tokens: JsonValue,
+
+    /// The contract ETH address.
+    pub eth_address: Vec<u8>,
+    /// The address of the account, which has initialized the contract.
+    pub owner_address: Option<Vec<u8>>,
+
+    /// Whether the contract has been archived.
+    pub is_archived: bool,
+}
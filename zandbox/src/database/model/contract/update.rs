@@ -0,0 +1,72 @@
+//!
+//! The database contract UPDATE model.
+//!
+
+use serde_json::Value as JsonValue;
+
+use zksync_types::AccountId;
+
+///
+/// The database contract UPDATE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: AccountId,
+
+    /// The contract version.
+    pub version: String,
+
+    /// The Zinc compiler version.
+    pub zinc_version: String,
+    /// The contract source code tree JSON representation.
+    pub source_code: JsonValue,
+    /// The contract bytecode.
+    pub bytecode: Vec<u8>,
+    /// The contract verifying key as a byte array.
+    pub verifying_key: Vec<u8>,
+    /// The contract method verifying keys JSON representation.
+    pub verifying_keys: JsonValue,
+    /// The contract token allow-list JSON representation.
+    pub allowed_tokens: JsonValue,
+    /// The contract ABI JSON document, if it has been published.
+    pub abi: Option<JsonValue>,
+    /// The contract method costs JSON representation.
+    pub method_costs: JsonValue,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: AccountId,
+
+        version: String,
+
+        zinc_version: String,
+        source_code: JsonValue,
+        bytecode: Vec<u8>,
+        verifying_key: Vec<u8>,
+        verifying_keys: JsonValue,
+        allowed_tokens: JsonValue,
+        abi: Option<JsonValue>,
+        method_costs: JsonValue,
+    ) -> Self {
+        Self {
+            account_id,
+
+            version,
+
+            zinc_version,
+            source_code,
+            bytecode,
+            verifying_key,
+            verifying_keys,
+            allowed_tokens,
+            abi,
+            method_costs,
+        }
+    }
+}
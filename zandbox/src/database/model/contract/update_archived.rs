@@ -0,0 +1,28 @@
+//!
+//! The database contract archived flag UPDATE model.
+//!
+
+use zksync_types::AccountId;
+
+///
+/// The database contract archived flag UPDATE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: AccountId,
+    /// Whether the contract is archived or restored.
+    pub is_archived: bool,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, is_archived: bool) -> Self {
+        Self {
+            account_id,
+            is_archived,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The database contract private key UPDATE model.
+//!
+
+use zksync::web3::types::H256;
+use zksync_types::AccountId;
+
+///
+/// The database contract private key UPDATE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: AccountId,
+    /// The new contract ETH private key.
+    pub eth_private_key: H256,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, eth_private_key: H256) -> Self {
+        Self {
+            account_id,
+            eth_private_key,
+        }
+    }
+}
@@ -3,5 +3,6 @@
 //!
 
 pub mod insert;
+pub mod rename;
 pub mod select;
 pub mod update;
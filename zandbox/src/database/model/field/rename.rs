@@ -0,0 +1,31 @@
+//!
+//! The database contract storage field rename UPDATE model.
+//!
+
+use zksync_types::AccountId;
+
+///
+/// The database contract storage field rename UPDATE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: AccountId,
+    /// The field index in the contract storage.
+    pub index: i16,
+    /// The new field name.
+    pub name: String,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: AccountId, index: i16, name: String) -> Self {
+        Self {
+            account_id,
+            index,
+            name,
+        }
+    }
+}
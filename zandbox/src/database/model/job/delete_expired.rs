@@ -0,0 +1,23 @@
+//!
+//! The database expired contract publishing job DELETE model.
+//!
+
+use std::time::Duration;
+
+///
+/// The database expired contract publishing job DELETE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The finished job lifetime, after which the job is deleted.
+    pub ttl: Duration,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl }
+    }
+}
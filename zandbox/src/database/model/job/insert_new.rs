@@ -0,0 +1,21 @@
+//!
+//! The database contract publishing job INSERT model.
+//!
+
+///
+/// The database contract publishing job INSERT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The job ID.
+    pub id: String,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
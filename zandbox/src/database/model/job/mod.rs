@@ -0,0 +1,8 @@
+//!
+//! The database contract publishing job model.
+//!
+
+pub mod delete_expired;
+pub mod insert_new;
+pub mod select;
+pub mod update;
@@ -0,0 +1,41 @@
+//!
+//! The database contract publishing job SELECT model.
+//!
+
+use std::time::Duration;
+
+///
+/// The database contract publishing job SELECT input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The job ID.
+    pub id: String,
+    /// The finished job lifetime, after which the job is not selected.
+    pub ttl: Duration,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(id: String, ttl: Duration) -> Self {
+        Self { id, ttl }
+    }
+}
+
+///
+/// The database contract publishing job SELECT output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The job status.
+    pub status: String,
+    /// The error details, which are only set if the job has failed.
+    pub error: Option<String>,
+
+    /// The published contract ETH address, which is only set if the job is done.
+    pub eth_address: Option<Vec<u8>>,
+    /// The re-published contract account ID, which is only set if the job is done.
+    pub account_id: Option<i64>,
+}
@@ -0,0 +1,66 @@
+//!
+//! The database contract publishing job UPDATE model.
+//!
+
+use zksync::web3::types::Address;
+use zksync_types::AccountId;
+
+use zinc_zksync::JobStatus;
+
+///
+/// The database contract publishing job UPDATE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The job ID.
+    pub id: String,
+    /// The job status.
+    pub status: JobStatus,
+    /// The error details, which are only set if the job has failed.
+    pub error: Option<String>,
+    /// The published contract address, which is only set if the job is done.
+    pub eth_address: Option<Address>,
+    /// The re-published contract account ID, which is only set if the job is done.
+    pub account_id: Option<AccountId>,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor, which is called when the job proceeds to the next stage.
+    ///
+    pub fn new(id: String, status: JobStatus) -> Self {
+        Self {
+            id,
+            status,
+            error: None,
+            eth_address: None,
+            account_id: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which is called when the job is done.
+    ///
+    pub fn new_done(id: String, eth_address: Address, account_id: Option<AccountId>) -> Self {
+        Self {
+            id,
+            status: JobStatus::Done,
+            error: None,
+            eth_address: Some(eth_address),
+            account_id,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which is called when the job has failed.
+    ///
+    pub fn new_failed(id: String, error: String) -> Self {
+        Self {
+            id,
+            status: JobStatus::Failed,
+            error: Some(error),
+            eth_address: None,
+            account_id: None,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//!
+//! The Zandbox server daemon contract environment utils.
+//!
+
+use zinc_zksync::Environment;
+
+///
+/// Returns the environment the contract methods are executed in.
+///
+/// The zkSync provider does not expose the pending block information, so the timestamp is taken
+/// from the server clock, and the block number is left zero.
+///
+pub fn current() -> Environment {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    Environment::new(timestamp, 0)
+}
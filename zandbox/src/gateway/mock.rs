@@ -0,0 +1,266 @@
+//!
+//! The Zandbox server daemon in-memory zkSync gateway mock.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use futures::future::LocalBoxFuture;
+use num_old::rational::Ratio;
+use num_old::BigUint;
+use num_old::Zero;
+
+use zksync::error::ClientError;
+use zksync::tokens_cache::TokensCache;
+use zksync::types::AccountInfo;
+use zksync::types::AccountState;
+use zksync::types::DepositingAccountBalances;
+use zksync::types::TransactionInfo;
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_eth_signer::error::SignerError;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::Transfer;
+use zksync_types::tx::TxHash;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::AccountId;
+use zksync_types::Fee;
+use zksync_types::Nonce;
+use zksync_types::OutputFeeType;
+use zksync_types::PubKeyHash;
+use zksync_types::Token;
+use zksync_types::TokenLike;
+use zksync_types::TxFeeTypes;
+use zksync_utils::BigUintSerdeWrapper;
+
+use super::IZkSyncGateway;
+use super::IZkSyncWallet;
+
+///
+/// The scriptable zkSync account state.
+///
+#[derive(Debug, Default)]
+pub struct State {
+    /// The contract zkSync account ID.
+    pub account_id: AccountId,
+    /// The contract ETH address, which is set when the account is connected.
+    pub address: Address,
+    /// The ETH private key, which is set when the account is connected.
+    pub private_key: H256,
+    /// The ETH private key, whose zkSync signing key is committed for the contract account.
+    pub signing_key: H256,
+    /// The committed nonce of the contract account.
+    pub nonce: Nonce,
+    /// The committed balances of the contract account, where the keys are token symbols.
+    pub balances: HashMap<String, BigUint>,
+    /// The fee returned for every transaction type.
+    pub fee: BigUint,
+    /// If set, zkSync rejects the batches with the error message.
+    pub batch_error: Option<String>,
+    /// If set, the last transaction of the batches is committed unsuccessfully with the failure
+    /// reason, whereas the preceding ones are committed successfully.
+    pub fail_reason: Option<String>,
+    /// The batches accepted by zkSync.
+    pub batches: Vec<Vec<(ZkSyncTx, Option<PackedEthSignature>)>>,
+}
+
+///
+/// The in-memory zkSync gateway mock.
+///
+/// All the connected accounts share the scriptable state, so the tests can set the responses
+/// before calling a handler and check the sent batches afterwards.
+///
+#[derive(Clone)]
+pub struct Gateway {
+    /// The zkSync tokens cache.
+    tokens: Arc<TokensCache>,
+    /// The scriptable account state.
+    state: Arc<Mutex<State>>,
+}
+
+impl Gateway {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(tokens: Vec<Token>, account_id: AccountId) -> Self {
+        let tokens = tokens
+            .into_iter()
+            .map(|token| (token.symbol.clone(), token))
+            .collect();
+
+        Self {
+            tokens: Arc::new(TokensCache::new(tokens)),
+            state: Arc::new(Mutex::new(State {
+                account_id,
+                ..State::default()
+            })),
+        }
+    }
+
+    ///
+    /// Locks the scriptable account state.
+    ///
+    pub fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect(zinc_const::panic::SYNCHRONIZATION)
+    }
+}
+
+impl IZkSyncGateway for Gateway {
+    fn connect(
+        &self,
+        _network: Network,
+        address: Address,
+        private_key: H256,
+    ) -> LocalBoxFuture<'_, Result<Box<dyn IZkSyncWallet>, ClientError>> {
+        Box::pin(async move {
+            let mut state = self.state();
+            state.address = address;
+            state.private_key = private_key;
+            Ok(Box::new(self.clone()) as Box<dyn IZkSyncWallet>)
+        })
+    }
+}
+
+impl IZkSyncWallet for Gateway {
+    fn tokens(&self) -> &TokensCache {
+        self.tokens.as_ref()
+    }
+
+    fn account_info(&self) -> LocalBoxFuture<'_, Result<AccountInfo, ClientError>> {
+        Box::pin(async move {
+            let state = self.state();
+            let account_state = AccountState {
+                balances: state
+                    .balances
+                    .iter()
+                    .map(|(symbol, balance)| {
+                        (symbol.to_owned(), BigUintSerdeWrapper(balance.to_owned()))
+                    })
+                    .collect(),
+                nonce: state.nonce,
+                pub_key_hash: PubKeyHash::default(),
+            };
+
+            Ok(AccountInfo {
+                address: state.address,
+                id: Some(state.account_id),
+                committed: account_state.clone(),
+                verified: account_state,
+                depositing: DepositingAccountBalances {
+                    balances: HashMap::new(),
+                },
+            })
+        })
+    }
+
+    fn is_signing_key_set(&self) -> LocalBoxFuture<'_, Result<bool, ClientError>> {
+        Box::pin(async move {
+            let state = self.state();
+            Ok(state.private_key == state.signing_key)
+        })
+    }
+
+    fn get_tx_fee(
+        &self,
+        _tx_type: TxFeeTypes,
+        _recipient: Address,
+        token: TokenLike,
+    ) -> LocalBoxFuture<'_, Result<Fee, ClientError>> {
+        Box::pin(async move {
+            self.tokens
+                .resolve(token)
+                .ok_or(ClientError::UnknownToken)?;
+
+            Ok(Fee::new(
+                OutputFeeType::Transfer,
+                Ratio::from_integer(self.state().fee.to_owned()),
+                Ratio::from_integer(BigUint::zero()),
+                BigUint::zero(),
+                BigUint::zero(),
+            ))
+        })
+    }
+
+    fn sign_transfer(
+        &self,
+        token: Token,
+        amount: BigUint,
+        recipient: Address,
+        nonce: Nonce,
+    ) -> LocalBoxFuture<'_, Result<(Transfer, Option<PackedEthSignature>), SignerError>> {
+        Box::pin(async move {
+            let state = self.state();
+            let transfer = Transfer::new(
+                state.account_id,
+                state.address,
+                recipient,
+                token.id,
+                amount,
+                BigUint::zero(),
+                nonce,
+                None,
+            );
+
+            Ok((transfer, None))
+        })
+    }
+
+    fn send_txs_batch(
+        &self,
+        transactions: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
+    ) -> LocalBoxFuture<'_, Result<Vec<TxHash>, ClientError>> {
+        Box::pin(async move {
+            let mut state = self.state();
+            if let Some(message) = state.batch_error.clone() {
+                return Err(ClientError::NetworkError(message));
+            }
+
+            let tx_hashes = transactions.iter().map(|(tx, _)| tx.hash()).collect();
+            let contract_transfers = transactions
+                .iter()
+                .filter(|(tx, _)| match tx {
+                    ZkSyncTx::Transfer(transfer) => transfer.from == state.address,
+                    _ => false,
+                })
+                .count();
+            state.nonce += contract_transfers as Nonce;
+            state.batches.push(transactions);
+
+            Ok(tx_hashes)
+        })
+    }
+
+    fn wait_for_commit(
+        &self,
+        tx_hash: TxHash,
+    ) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>> {
+        self.tx_info(tx_hash)
+    }
+
+    fn tx_info(&self, tx_hash: TxHash) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>> {
+        Box::pin(async move {
+            let state = self.state();
+            let is_last = state
+                .batches
+                .last()
+                .and_then(|batch| batch.last())
+                .map(|(tx, _signature)| tx.hash() == tx_hash)
+                .unwrap_or_default();
+            let fail_reason = if is_last {
+                state.fail_reason.clone()
+            } else {
+                None
+            };
+
+            Ok(TransactionInfo {
+                executed: true,
+                success: Some(fail_reason.is_none()),
+                fail_reason,
+                block: None,
+            })
+        })
+    }
+}
@@ -0,0 +1,108 @@
+//!
+//! The Zandbox server daemon zkSync gateway.
+//!
+
+#[cfg(test)]
+pub mod mock;
+pub mod wallet;
+
+use futures::future::LocalBoxFuture;
+use num_old::BigUint;
+
+use zksync::error::ClientError;
+use zksync::tokens_cache::TokensCache;
+use zksync::types::AccountInfo;
+use zksync::types::TransactionInfo;
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_eth_signer::error::SignerError;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::Transfer;
+use zksync_types::tx::TxHash;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::Fee;
+use zksync_types::Nonce;
+use zksync_types::Token;
+use zksync_types::TokenLike;
+use zksync_types::TxFeeTypes;
+
+///
+/// The zkSync gateway, which connects to the contract accounts.
+///
+/// Is stored in the shared data in order to replace zkSync with a mock in the handler tests.
+///
+pub trait IZkSyncGateway: Send + Sync {
+    ///
+    /// Connects to the zkSync account of the contract with the ETH `address` and `private_key`.
+    ///
+    fn connect(
+        &self,
+        network: Network,
+        address: Address,
+        private_key: H256,
+    ) -> LocalBoxFuture<'_, Result<Box<dyn IZkSyncWallet>, ClientError>>;
+}
+
+///
+/// The zkSync contract account, which performs the operations used by the handlers.
+///
+pub trait IZkSyncWallet {
+    ///
+    /// The zkSync tokens cache.
+    ///
+    fn tokens(&self) -> &TokensCache;
+
+    ///
+    /// Fetches the contract account info.
+    ///
+    fn account_info(&self) -> LocalBoxFuture<'_, Result<AccountInfo, ClientError>>;
+
+    ///
+    /// Checks whether the committed zkSync signing key of the contract account is derived from
+    /// the private key the wallet has been connected with.
+    ///
+    fn is_signing_key_set(&self) -> LocalBoxFuture<'_, Result<bool, ClientError>>;
+
+    ///
+    /// Fetches the fee of the `tx_type` transaction to the `recipient` paid in `token`.
+    ///
+    fn get_tx_fee(
+        &self,
+        tx_type: TxFeeTypes,
+        recipient: Address,
+        token: TokenLike,
+    ) -> LocalBoxFuture<'_, Result<Fee, ClientError>>;
+
+    ///
+    /// Signs the contract transfer with the zero fee, which is paid by the client transactions.
+    ///
+    fn sign_transfer(
+        &self,
+        token: Token,
+        amount: BigUint,
+        recipient: Address,
+        nonce: Nonce,
+    ) -> LocalBoxFuture<'_, Result<(Transfer, Option<PackedEthSignature>), SignerError>>;
+
+    ///
+    /// Sends the transaction batch to zkSync.
+    ///
+    fn send_txs_batch(
+        &self,
+        transactions: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
+    ) -> LocalBoxFuture<'_, Result<Vec<TxHash>, ClientError>>;
+
+    ///
+    /// Waits for the transaction with `tx_hash` to be committed.
+    ///
+    fn wait_for_commit(
+        &self,
+        tx_hash: TxHash,
+    ) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>>;
+
+    ///
+    /// Fetches the current state of the transaction with `tx_hash` without waiting.
+    ///
+    fn tx_info(&self, tx_hash: TxHash) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>>;
+}
@@ -0,0 +1,121 @@
+//!
+//! The Zandbox server daemon zkSync gateway backed by the zkSync server.
+//!
+
+use std::time::Duration;
+
+use futures::future::LocalBoxFuture;
+use num_old::BigUint;
+use num_old::Zero;
+
+use zksync::error::ClientError;
+use zksync::operations::SyncTransactionHandle;
+use zksync::tokens_cache::TokensCache;
+use zksync::types::AccountInfo;
+use zksync::types::TransactionInfo;
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_eth_signer::error::SignerError;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::Transfer;
+use zksync_types::tx::TxHash;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::Fee;
+use zksync_types::Nonce;
+use zksync_types::Token;
+use zksync_types::TokenLike;
+use zksync_types::TxFeeTypes;
+
+use super::IZkSyncGateway;
+use super::IZkSyncWallet;
+
+///
+/// The zkSync gateway, which connects to the contract accounts on the zkSync server.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Gateway;
+
+impl IZkSyncGateway for Gateway {
+    fn connect(
+        &self,
+        network: Network,
+        address: Address,
+        private_key: H256,
+    ) -> LocalBoxFuture<'_, Result<Box<dyn IZkSyncWallet>, ClientError>> {
+        Box::pin(async move {
+            let provider = zksync::Provider::new(network);
+            let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+                address,
+                PrivateKeySigner::new(private_key),
+                network,
+            )
+            .await?;
+            let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+
+            Ok(Box::new(wallet) as Box<dyn IZkSyncWallet>)
+        })
+    }
+}
+
+impl IZkSyncWallet for zksync::Wallet<PrivateKeySigner> {
+    fn tokens(&self) -> &TokensCache {
+        &self.tokens
+    }
+
+    fn account_info(&self) -> LocalBoxFuture<'_, Result<AccountInfo, ClientError>> {
+        Box::pin(zksync::Wallet::account_info(self))
+    }
+
+    fn is_signing_key_set(&self) -> LocalBoxFuture<'_, Result<bool, ClientError>> {
+        Box::pin(zksync::Wallet::is_signing_key_set(self))
+    }
+
+    fn get_tx_fee(
+        &self,
+        tx_type: TxFeeTypes,
+        recipient: Address,
+        token: TokenLike,
+    ) -> LocalBoxFuture<'_, Result<Fee, ClientError>> {
+        Box::pin(self.provider.get_tx_fee(tx_type, recipient, token))
+    }
+
+    fn sign_transfer(
+        &self,
+        token: Token,
+        amount: BigUint,
+        recipient: Address,
+        nonce: Nonce,
+    ) -> LocalBoxFuture<'_, Result<(Transfer, Option<PackedEthSignature>), SignerError>> {
+        Box::pin(
+            self.signer
+                .sign_transfer(token, amount, BigUint::zero(), recipient, nonce),
+        )
+    }
+
+    fn send_txs_batch(
+        &self,
+        transactions: Vec<(ZkSyncTx, Option<PackedEthSignature>)>,
+    ) -> LocalBoxFuture<'_, Result<Vec<TxHash>, ClientError>> {
+        Box::pin(self.provider.send_txs_batch(transactions, None))
+    }
+
+    fn wait_for_commit(
+        &self,
+        tx_hash: TxHash,
+    ) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>> {
+        Box::pin(async move {
+            let mut handle = SyncTransactionHandle::new(tx_hash, self.provider.clone())
+                .commit_timeout(Duration::from_secs(10));
+            handle
+                .polling_interval(Duration::from_millis(200))
+                .expect("Validated inside the method");
+            handle.wait_for_commit().await
+        })
+    }
+
+    fn tx_info(&self, tx_hash: TxHash) -> LocalBoxFuture<'_, Result<TransactionInfo, ClientError>> {
+        Box::pin(self.provider.tx_info(tx_hash))
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! The zkSync account public key changer arguments.
+//!
+
+use structopt::StructOpt;
+
+///
+/// The zkSync account public key changer arguments.
+///
+/// Every argument may be also passed via the environment variable, whose name is specified
+/// in the argument help message.
+///
+#[derive(StructOpt)]
+#[structopt(
+    name = "key-changer",
+    about = "Sets the signing key of a zkSync account"
+)]
+pub struct Arguments {
+    /// The zkSync network identifier.
+    #[structopt(
+        short = "n",
+        long = "network",
+        env = "ZKSYNC_NETWORK",
+        default_value = "localhost"
+    )]
+    pub network: String,
+
+    /// The token symbol, which the fee is paid in.
+    #[structopt(long = "fee-token", env = "ZKSYNC_FEE_TOKEN", default_value = "ETH")]
+    pub fee_token: String,
+
+    /// The fee amount in the smallest token units.
+    #[structopt(long = "fee", env = "ZKSYNC_FEE", default_value = "100000000000000000")]
+    pub fee: u64,
+
+    /// The ETH address of the account, without the `0x` prefix.
+    #[structopt(
+        long = "address",
+        env = "ETH_ADDRESS",
+        default_value = "E2Dc25Cbf196C276CcbB7fa003EC6F499e3067Ae"
+    )]
+    pub address: String,
+
+    /// The ETH private key of the account, without the `0x` prefix.
+    #[structopt(
+        long = "private-key",
+        env = "ETH_PRIVATE_KEY",
+        default_value = "1ccde6e3bb4a905bc0bffa996ea40898a35a25941ae0a18144865ccabf39c328",
+        hide_env_values = true
+    )]
+    pub private_key: String,
+}
+
+impl Arguments {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::from_args()
+    }
+}
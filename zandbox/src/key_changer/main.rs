@@ -2,31 +2,33 @@
 //! The zkSync account public key changer.
 //!
 
-use colored::Colorize;
+mod arguments;
 
-use zksync_eth_signer::PrivateKeySigner;
+use std::str::FromStr;
 
-static TOKEN_SYMBOL: &str = "ETH";
-const FEE: u64 = 100_000_000_000_000_000;
+use colored::Colorize;
 
-static ETH_ADDRESS: &str = "E2Dc25Cbf196C276CcbB7fa003EC6F499e3067Ae";
-static ETH_PRIVATE_KEY: &str = "1ccde6e3bb4a905bc0bffa996ea40898a35a25941ae0a18144865ccabf39c328";
+use zksync_eth_signer::PrivateKeySigner;
 
-const NETWORK: zksync::Network = zksync::Network::Localhost;
+use self::arguments::Arguments;
 
 ///
 /// The utility entry point.
 ///
 #[actix_rt::main]
 async fn main() {
-    let provider = zksync::Provider::new(NETWORK);
+    let args = Arguments::new();
+
+    let network = zksync::Network::from_str(args.network.as_str()).expect("Network parsing");
+
+    let provider = zksync::Provider::new(network);
     let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-        ETH_ADDRESS.parse().expect("ETH address parsing"),
-        ETH_PRIVATE_KEY
+        args.address.parse().expect("ETH address parsing"),
+        args.private_key
             .parse()
             .map(PrivateKeySigner::new)
             .expect("ETH private key parsing"),
-        NETWORK,
+        network,
     )
     .await
     .expect("Wallet credentials");
@@ -36,8 +38,8 @@ async fn main() {
 
     let tx_info = wallet
         .start_change_pubkey()
-        .fee(FEE)
-        .fee_token(TOKEN_SYMBOL)
+        .fee(args.fee)
+        .fee_token(args.fee_token.as_str())
         .expect("Fee token resolving")
         .send()
         .await
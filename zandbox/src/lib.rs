@@ -5,10 +5,16 @@
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod auth;
 pub(crate) mod controller;
 pub(crate) mod database;
+pub(crate) mod environment;
+pub(crate) mod gateway;
+pub(crate) mod migration;
 pub(crate) mod response;
+pub(crate) mod settings;
 pub(crate) mod shared_data;
+pub(crate) mod sizing;
 pub(crate) mod storage;
 
 pub use self::controller::configure;
@@ -16,8 +22,10 @@ pub use self::database::client::Client as DatabaseClient;
 pub use self::database::model::contract::select_all::Output as ContractSelectAllOutput;
 pub use self::database::model::field::select::Input as FieldSelectInput;
 pub use self::database::model::field::select::Output as FieldSelectOutput;
+pub use self::settings::Settings;
 pub use self::shared_data::contract::Contract as SharedDataContract;
 pub use self::shared_data::SharedData;
+pub use self::storage::error::Error as ContractStorageError;
 pub use self::storage::Storage as ContractStorage;
 
 ///
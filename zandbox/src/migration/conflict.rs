@@ -0,0 +1,88 @@
+//!
+//! The contract storage migration conflict.
+//!
+
+use std::fmt;
+
+use serde::Serialize;
+
+///
+/// The contract storage migration conflict, which makes the old and new layouts incompatible.
+///
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Conflict {
+    /// The field type has changed.
+    TypeChanged {
+        /// The field name in the new layout.
+        name: String,
+        /// The field type in the old layout.
+        expected: String,
+        /// The field type in the new layout.
+        found: String,
+    },
+    /// The old field is missing in the new layout.
+    Removed {
+        /// The field name in the old layout.
+        name: String,
+    },
+    /// The field has been moved to another position, which is only allowed for appended fields.
+    Moved {
+        /// The field name in the new layout.
+        name: String,
+        /// The field index in the old layout.
+        expected: usize,
+        /// The field index in the new layout.
+        found: usize,
+    },
+    /// The renamed field does not exist in the old layout.
+    RenameSourceNotFound {
+        /// The old field name.
+        name: String,
+    },
+    /// The renamed field does not exist in the new layout.
+    RenameTargetNotFound {
+        /// The new field name.
+        name: String,
+    },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeChanged {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "field `{}` type has changed from `{}` to `{}`",
+                name, expected, found
+            ),
+            Self::Removed { name } => write!(f, "field `{}` has been removed", name),
+            Self::Moved {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "field `{}` has been moved from index {} to {}",
+                name, expected, found
+            ),
+            Self::RenameSourceNotFound { name } => {
+                write!(
+                    f,
+                    "renamed field `{}` does not exist in the old storage",
+                    name
+                )
+            }
+            Self::RenameTargetNotFound { name } => {
+                write!(
+                    f,
+                    "renamed field `{}` does not exist in the new storage",
+                    name
+                )
+            }
+        }
+    }
+}
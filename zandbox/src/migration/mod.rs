@@ -0,0 +1,183 @@
+//!
+//! The contract storage migration.
+//!
+
+pub mod conflict;
+
+use std::collections::HashMap;
+
+use zinc_build::ContractFieldType;
+use zinc_build::ContractFieldValue as BuildContractFieldValue;
+
+use crate::database::model::field::insert::Input as FieldInsertInput;
+use crate::database::model::field::rename::Input as FieldRenameInput;
+use crate::storage::Storage;
+
+use self::conflict::Conflict;
+
+///
+/// The contract storage migration, which is performed when a contract is re-published.
+///
+/// The old fields are matched with the new ones by name, taking the explicit `renames` mapping
+/// into account. The new fields may only be appended to the end of the storage, whereas type
+/// changes, removals, and reordering are rejected.
+///
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// The storage with the new layout, with the old values and the appended default ones.
+    pub storage: Storage,
+    /// The indexes of the renamed fields.
+    pub renamed: Vec<usize>,
+    /// The indexes of the appended fields.
+    pub appended: Vec<usize>,
+}
+
+impl Migration {
+    ///
+    /// Compares the `old_types` and `new_types` storage layouts and migrates the `old_storage`.
+    ///
+    /// The `renames` mapping consists of the old field names as keys and the new ones as values.
+    ///
+    /// Returns all the conflicts found, if the layouts are incompatible.
+    ///
+    pub fn new(
+        old_types: &[ContractFieldType],
+        old_storage: Storage,
+        new_types: &[ContractFieldType],
+        renames: &HashMap<String, String>,
+    ) -> Result<Self, Vec<Conflict>> {
+        let mut conflicts = Vec::new();
+
+        for (old_name, new_name) in renames.iter() {
+            if !old_types
+                .iter()
+                .any(|field| !field.is_implicit && &field.name == old_name)
+            {
+                conflicts.push(Conflict::RenameSourceNotFound {
+                    name: old_name.to_owned(),
+                });
+            }
+            if !new_types
+                .iter()
+                .any(|field| !field.is_implicit && &field.name == new_name)
+            {
+                conflicts.push(Conflict::RenameTargetNotFound {
+                    name: new_name.to_owned(),
+                });
+            }
+        }
+
+        let mut sources = vec![None; new_types.len()];
+        for (old_index, old_field) in old_types.iter().enumerate() {
+            let name = if old_field.is_implicit {
+                &old_field.name
+            } else {
+                renames.get(&old_field.name).unwrap_or(&old_field.name)
+            };
+
+            let new_index = match new_types.iter().position(|field| &field.name == name) {
+                Some(new_index) => new_index,
+                None => {
+                    conflicts.push(Conflict::Removed {
+                        name: old_field.name.to_owned(),
+                    });
+                    continue;
+                }
+            };
+            let new_field = &new_types[new_index];
+
+            if new_index != old_index {
+                conflicts.push(Conflict::Moved {
+                    name: new_field.name.to_owned(),
+                    expected: old_index,
+                    found: new_index,
+                });
+                continue;
+            }
+
+            if new_field.r#type != old_field.r#type {
+                conflicts.push(Conflict::TypeChanged {
+                    name: new_field.name.to_owned(),
+                    expected: old_field.r#type.to_string(),
+                    found: new_field.r#type.to_string(),
+                });
+                continue;
+            }
+
+            sources[new_index] = Some(old_index);
+        }
+
+        if !conflicts.is_empty() {
+            return Err(conflicts);
+        }
+
+        let mut old_fields: Vec<Option<BuildContractFieldValue>> =
+            old_storage.fields.into_iter().map(Some).collect();
+
+        let mut fields = Vec::with_capacity(new_types.len());
+        let mut renamed = Vec::new();
+        let mut appended = Vec::new();
+        for (index, (new_field, source)) in new_types.iter().zip(sources).enumerate() {
+            match source.and_then(|source| old_fields.get_mut(source).and_then(Option::take)) {
+                Some(old_field) => {
+                    if old_field.name != new_field.name {
+                        renamed.push(index);
+                    }
+
+                    fields.push(BuildContractFieldValue::new(
+                        new_field.name.to_owned(),
+                        old_field.value,
+                        new_field.is_public,
+                        new_field.is_implicit,
+                    ));
+                }
+                None => {
+                    appended.push(index);
+
+                    fields.push(BuildContractFieldValue::new_from_type(new_field.to_owned()));
+                }
+            }
+        }
+
+        Ok(Self {
+            storage: Storage { fields },
+            renamed,
+            appended,
+        })
+    }
+
+    ///
+    /// Converts the renamed fields into the rename UPDATE query database representation.
+    ///
+    pub fn to_database_rename(&self, account_id: zksync_types::AccountId) -> Vec<FieldRenameInput> {
+        self.renamed
+            .iter()
+            .map(|index| {
+                FieldRenameInput::new(
+                    account_id,
+                    *index as i16,
+                    self.storage.fields[*index].name.to_owned(),
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Converts the appended fields into the INSERT query database representation.
+    ///
+    pub fn to_database_insert(&self, account_id: zksync_types::AccountId) -> Vec<FieldInsertInput> {
+        self.appended
+            .iter()
+            .map(|index| {
+                let field = &self.storage.fields[*index];
+
+                FieldInsertInput::new(
+                    account_id,
+                    *index as i16,
+                    field.name.to_owned(),
+                    field.value.to_owned().into_json(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The Zandbox server daemon settings.
+//!
+
+use std::time::Duration;
+
+///
+/// The Zandbox server daemon settings.
+///
+/// The limits protect the server from oversized and deeply nested request bodies.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    /// The contract publishing request body size limit in bytes, which must fit the source code.
+    pub publish_payload_limit: usize,
+    /// The contract method request body size limit in bytes.
+    pub method_payload_limit: usize,
+    /// The contract method arguments JSON nesting depth limit.
+    pub json_depth_limit: usize,
+    /// Whether the witness values are redacted from the virtual machine logs and errors.
+    pub privacy: bool,
+    /// The contract methods sizing run time limit at publishing.
+    pub sizing_timeout: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            publish_payload_limit: zinc_const::limit::JSON_PAYLOAD,
+            method_payload_limit: zinc_const::limit::JSON_METHOD_PAYLOAD,
+            json_depth_limit: zinc_const::limit::JSON_DEPTH,
+            privacy: true,
+            sizing_timeout: Duration::from_millis(zinc_const::limit::CONTRACT_SIZING_TIMEOUT_MS),
+        }
+    }
+}
+
+impl Settings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        publish_payload_limit: Option<usize>,
+        method_payload_limit: Option<usize>,
+        json_depth_limit: Option<usize>,
+        privacy: bool,
+        sizing_timeout: Option<Duration>,
+    ) -> Self {
+        let default = Self::default();
+
+        Self {
+            publish_payload_limit: publish_payload_limit.unwrap_or(default.publish_payload_limit),
+            method_payload_limit: method_payload_limit.unwrap_or(default.method_payload_limit),
+            json_depth_limit: json_depth_limit.unwrap_or(default.json_depth_limit),
+            privacy,
+            sizing_timeout: sizing_timeout.unwrap_or(default.sizing_timeout),
+        }
+    }
+}
@@ -2,14 +2,20 @@
 //! The cached contract data.
 //!
 
+use std::collections::HashMap;
+
+use rustc_hex::FromHex;
+use rustc_hex::ToHex;
 use serde_json::Value as JsonValue;
 
 use zksync::web3::types::H160;
 use zksync::web3::types::H256;
 use zksync_types::AccountId;
+use zksync_types::Token;
 
 use zinc_build::Contract as BuildContract;
 
+use crate::sizing::cost::Cost as MethodCost;
 use crate::storage::Storage;
 
 ///
@@ -33,11 +39,22 @@ pub struct Contract {
     pub bytecode: Vec<u8>,
     /// The contract verifying key.
     pub verifying_key: Vec<u8>,
+    /// The contract method verifying keys, where the keys are the method names.
+    pub verifying_keys: HashMap<String, Vec<u8>>,
+    /// The token symbols or addresses the contract is allowed to transfer.
+    pub allowed_tokens: Vec<String>,
+    /// The contract ABI document, if it has been published.
+    pub abi: Option<JsonValue>,
+    /// The contract method costs measured at publishing, where the keys are the method names.
+    pub method_costs: HashMap<String, MethodCost>,
 
     /// The contract ETH private key.
     pub eth_private_key: H256,
     /// The contract zkSync account ID. Is set when the change-pubkey transaction is executed.
     pub account_id: Option<AccountId>,
+    /// The contract owner ETH address, which is the initial transfer sender.
+    /// Is set along with the account ID.
+    pub owner_address: Option<H160>,
 
     /// The pre-built contract ready to be called.
     pub build: BuildContract,
@@ -60,8 +77,13 @@ impl Contract {
         source_code: JsonValue,
         bytecode: Vec<u8>,
         verifying_key: Vec<u8>,
+        verifying_keys: HashMap<String, Vec<u8>>,
+        allowed_tokens: Vec<String>,
+        abi: Option<JsonValue>,
+        method_costs: HashMap<String, MethodCost>,
 
         account_id: Option<AccountId>,
+        owner_address: Option<H160>,
         eth_private_key: H256,
 
         build: BuildContract,
@@ -77,8 +99,13 @@ impl Contract {
             source_code,
             bytecode,
             verifying_key,
+            verifying_keys,
+            allowed_tokens,
+            abi,
+            method_costs,
 
             account_id,
+            owner_address,
             eth_private_key,
 
             build,
@@ -87,9 +114,54 @@ impl Contract {
     }
 
     ///
-    /// Sets the zkSync account ID.
+    /// Sets the zkSync account ID and the owner address, which unlocks the contract.
     ///
-    pub fn set_account_id(&mut self, account_id: AccountId) {
+    pub fn set_account_id(&mut self, account_id: AccountId, owner_address: H160) {
         self.account_id = Some(account_id);
+        self.owner_address = Some(owner_address);
+    }
+
+    ///
+    /// Checks whether the contract is allowed to transfer the `token`.
+    ///
+    /// The allow-list entries are token symbols or addresses. An empty list allows any token.
+    ///
+    pub fn is_token_allowed(allowed_tokens: &[String], token: &Token) -> bool {
+        allowed_tokens.is_empty()
+            || allowed_tokens.iter().any(|allowed| {
+                allowed.eq_ignore_ascii_case(token.symbol.as_str())
+                    || zinc_math::eth_address_from_str(allowed)
+                        .map(|address| H160::from(address) == token.address)
+                        .unwrap_or_default()
+            })
+    }
+
+    ///
+    /// Converts the method verifying keys into the database JSON representation,
+    /// where the keys are stored as hex strings.
+    ///
+    pub fn verifying_keys_to_json(verifying_keys: &HashMap<String, Vec<u8>>) -> JsonValue {
+        let verifying_keys: HashMap<&str, String> = verifying_keys
+            .iter()
+            .map(|(method, key)| (method.as_str(), key.to_hex()))
+            .collect();
+
+        serde_json::to_value(verifying_keys).expect(zinc_const::panic::DATA_CONVERSION)
+    }
+
+    ///
+    /// Converts the method verifying keys from the database JSON representation.
+    ///
+    pub fn verifying_keys_from_json(value: JsonValue) -> HashMap<String, Vec<u8>> {
+        serde_json::from_value::<HashMap<String, String>>(value)
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .into_iter()
+            .map(|(method, key)| {
+                (
+                    method,
+                    key.from_hex().expect(zinc_const::panic::DATA_CONVERSION),
+                )
+            })
+            .collect()
     }
 }
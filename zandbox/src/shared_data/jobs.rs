@@ -0,0 +1,165 @@
+//!
+//! The Zandbox server daemon contract publishing jobs.
+//!
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use rustc_hex::ToHex;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_types::AccountId;
+
+use zinc_zksync::JobStatus;
+
+///
+/// The contract publishing job state.
+///
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// The job status.
+    pub status: JobStatus,
+    /// The error details, which are only set if the job has failed.
+    pub error: Option<String>,
+    /// The published contract address, which is only set if the job is done.
+    pub address: Option<Address>,
+    /// The re-published contract account ID, which is only set if the job is done.
+    pub account_id: Option<AccountId>,
+    /// The time of the last status change.
+    updated_at: Instant,
+}
+
+impl Job {
+    ///
+    /// Checks if the job has been finished more than `ttl` ago.
+    ///
+    fn is_expired(&self, ttl: Duration) -> bool {
+        self.status.is_finished() && self.updated_at.elapsed() >= ttl
+    }
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            status: JobStatus::Pending,
+            error: None,
+            address: None,
+            account_id: None,
+            updated_at: Instant::now(),
+        }
+    }
+}
+
+///
+/// The contract publishing jobs run in the background.
+///
+/// The finished jobs are removed after the TTL, which is checked when a job is created
+/// or requested.
+///
+#[derive(Debug)]
+pub struct Jobs {
+    /// The finished job lifetime.
+    ttl: Duration,
+    /// The jobs, where the keys are the job IDs.
+    inner: Mutex<HashMap<String, Job>>,
+}
+
+impl Default for Jobs {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(zinc_const::zandbox::JOB_TTL))
+    }
+}
+
+impl Jobs {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            inner: Mutex::new(HashMap::new()),
+        }
+    }
+
+    ///
+    /// The finished job lifetime.
+    ///
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    ///
+    /// Creates a pending job with a new random ID.
+    ///
+    pub fn create(&self) -> String {
+        let mut random = H256::default();
+        random.randomize();
+        let id: String = random.as_bytes().to_hex();
+
+        let mut jobs = self.inner.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+        let ttl = self.ttl;
+        jobs.retain(|_id, job| !job.is_expired(ttl));
+        jobs.insert(id.clone(), Job::default());
+
+        id
+    }
+
+    ///
+    /// Returns the job with `id`, unless it does not exist or has expired.
+    ///
+    pub fn get(&self, id: &str) -> Option<Job> {
+        let mut jobs = self.inner.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+        let ttl = self.ttl;
+        jobs.retain(|_id, job| !job.is_expired(ttl));
+        jobs.get(id).cloned()
+    }
+
+    ///
+    /// Sets the status of the unfinished job with `id`.
+    ///
+    pub fn set_status(&self, id: &str, status: JobStatus) {
+        self.update(id, |job| job.status = status);
+    }
+
+    ///
+    /// Finishes the job with `id` successfully, setting the published contract data.
+    ///
+    pub fn finish(&self, id: &str, address: Address, account_id: Option<AccountId>) {
+        self.update(id, |job| {
+            job.status = JobStatus::Done;
+            job.address = Some(address);
+            job.account_id = account_id;
+        });
+    }
+
+    ///
+    /// Finishes the job with `id` unsuccessfully, setting the `error` details.
+    ///
+    pub fn fail(&self, id: &str, error: String) {
+        self.update(id, |job| {
+            job.status = JobStatus::Failed;
+            job.error = Some(error);
+        });
+    }
+
+    ///
+    /// Applies `update` to the job with `id` and refreshes its status change time.
+    ///
+    fn update<F>(&self, id: &str, update: F)
+    where
+        F: FnOnce(&mut Job),
+    {
+        if let Some(job) = self
+            .inner
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .get_mut(id)
+        {
+            update(job);
+            job.updated_at = Instant::now();
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! The Zandbox server daemon metrics counter.
+//!
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use super::IMetric;
+
+///
+/// The monotonically increasing counter.
+///
+#[derive(Debug, Default)]
+pub struct Counter {
+    /// The counter value.
+    value: AtomicU64,
+}
+
+impl Counter {
+    ///
+    /// Increments the counter by one.
+    ///
+    pub fn increment(&self) {
+        self.value.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Returns the current counter value.
+    ///
+    pub fn get(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+impl IMetric for Counter {
+    const TYPE: &'static str = "counter";
+
+    fn render(&self, name: &str, labels: &str, output: &mut Vec<String>) {
+        output.push(format!("{}{} {}", name, super::braced(labels), self.get()));
+    }
+}
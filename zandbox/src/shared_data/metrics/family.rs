@@ -0,0 +1,93 @@
+//!
+//! The Zandbox server daemon metrics family.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::IMetric;
+
+///
+/// The metric family, which holds a separate series for each set of label values.
+///
+#[derive(Debug)]
+pub struct Family<M>
+where
+    M: IMetric,
+{
+    /// The metric name, which must be stable, since the dashboards depend on it.
+    pub name: &'static str,
+    /// The metric description, rendered as `# HELP`.
+    pub help: &'static str,
+    /// The label names, whose values are passed in the same order to `with`.
+    pub labels: &'static [&'static str],
+    /// The series, where the keys are the label values.
+    series: Mutex<BTreeMap<Vec<String>, M>>,
+}
+
+impl<M> Family<M>
+where
+    M: IMetric,
+{
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: &'static str, help: &'static str, labels: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            help,
+            labels,
+            series: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    ///
+    /// Calls `action` on the series with the label `values`, creating it if it does not exist.
+    ///
+    /// # Panics
+    /// If the number of `values` does not match the number of the family labels.
+    ///
+    pub fn with<F>(&self, values: &[&str], action: F)
+    where
+        F: FnOnce(&M),
+    {
+        assert_eq!(
+            values.len(),
+            self.labels.len(),
+            "Metric `{}` label values mismatch",
+            self.name
+        );
+
+        let mut series = self
+            .series
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let metric = series
+            .entry(values.iter().map(|value| (*value).to_owned()).collect())
+            .or_default();
+        action(metric);
+    }
+
+    ///
+    /// Renders the family in the Prometheus text exposition format.
+    ///
+    pub fn render(&self, output: &mut Vec<String>) {
+        output.push(format!("# HELP {} {}", self.name, self.help));
+        output.push(format!("# TYPE {} {}", self.name, M::TYPE));
+
+        let series = self
+            .series
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        for (values, metric) in series.iter() {
+            let labels = self
+                .labels
+                .iter()
+                .zip(values.iter())
+                .map(|(label, value)| format!("{}=\"{}\"", label, super::escape(value)))
+                .collect::<Vec<String>>()
+                .join(",");
+            metric.render(self.name, labels.as_str(), output);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//!
+//! The Zandbox server daemon metrics histogram.
+//!
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use super::IMetric;
+
+///
+/// The histogram bucket upper bounds, in seconds.
+///
+/// Cover everything from fast database queries to slow VM runs and zkSync commits.
+///
+pub const BUCKETS: [f64; 14] = [
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+///
+/// The duration histogram with the fixed `BUCKETS`.
+///
+#[derive(Debug, Default)]
+pub struct Histogram {
+    /// The number of observations falling into each bucket, non-cumulative.
+    buckets: [AtomicU64; BUCKETS.len()],
+    /// The sum of all observations, in microseconds.
+    sum_micros: AtomicU64,
+    /// The number of observations, including those above the last bucket.
+    count: AtomicU64,
+}
+
+impl Histogram {
+    ///
+    /// Records the `duration` observation.
+    ///
+    pub fn observe(&self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        if let Some(index) = BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        }
+        self.sum_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Returns the number of observations.
+    ///
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl IMetric for Histogram {
+    const TYPE: &'static str = "histogram";
+
+    fn render(&self, name: &str, labels: &str, output: &mut Vec<String>) {
+        let separator = if labels.is_empty() { "" } else { "," };
+
+        let mut cumulative = 0;
+        for (bound, bucket) in BUCKETS.iter().zip(self.buckets.iter()) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            output.push(format!(
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name, labels, separator, bound, cumulative
+            ));
+        }
+        let count = self.count();
+        output.push(format!(
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, separator, count
+        ));
+        output.push(format!(
+            "{}_sum{} {}",
+            name,
+            super::braced(labels),
+            self.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0
+        ));
+        output.push(format!("{}_count{} {}", name, super::braced(labels), count));
+    }
+}
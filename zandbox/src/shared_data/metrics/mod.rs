@@ -0,0 +1,253 @@
+//!
+//! The Zandbox server daemon metrics.
+//!
+//! The metrics are exposed at `/api/v1/metrics` in the Prometheus text exposition format.
+//! The metric names and label sets below are stable, since the dashboards and alerts depend
+//! on them:
+//!
+//! | Name | Type | Labels |
+//! |------|------|--------|
+//! | `zandbox_vm_execution_seconds` | histogram | `handler`, `contract`, `method` |
+//! | `zandbox_zksync_submission_seconds` | histogram | |
+//! | `zandbox_zksync_commit_wait_seconds` | histogram | |
+//! | `zandbox_database_query_seconds` | histogram | `query` |
+//! | `zandbox_errors_total` | counter | `handler`, `error` |
+//! | `zandbox_nonce_lock_acquisitions_total` | counter | |
+//! | `zandbox_nonce_lock_wait_seconds_total` | counter | |
+//! | `zandbox_nonce_retries_total` | counter | |
+//!
+
+pub mod counter;
+pub mod family;
+pub mod histogram;
+
+use std::future::Future;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use self::counter::Counter;
+use self::family::Family;
+use self::histogram::Histogram;
+
+/// The `handler` label value of the contract method `call` endpoint.
+pub static HANDLER_CALL: &str = "call";
+/// The `handler` label value of the contract method `query` endpoint.
+pub static HANDLER_QUERY: &str = "query";
+/// The `handler` label value of the contract publishing endpoint.
+pub static HANDLER_PUBLISH: &str = "publish";
+/// The `handler` label value of the contract private key rotation endpoint.
+pub static HANDLER_KEY: &str = "key";
+
+///
+/// The metric kind, which can be rendered in the Prometheus text exposition format.
+///
+pub trait IMetric: Default {
+    /// The Prometheus metric type, e.g. `counter`.
+    const TYPE: &'static str;
+
+    ///
+    /// Renders the metric `name` series with the pre-formatted `labels` into `output` lines.
+    ///
+    fn render(&self, name: &str, labels: &str, output: &mut Vec<String>);
+}
+
+///
+/// The Zandbox server daemon metrics registry.
+///
+#[derive(Debug)]
+pub struct Metrics {
+    /// The number of the contract nonce lock acquisitions.
+    pub nonce_lock_acquisitions: AtomicU64,
+    /// The total time spent waiting for the contract nonce locks, in microseconds.
+    pub nonce_lock_wait_micros: AtomicU64,
+    /// The number of transaction batches re-signed and re-sent after a nonce mismatch.
+    pub nonce_retries: AtomicU64,
+
+    /// The contract method execution time on the Zinc VM.
+    pub vm_execution: Family<Histogram>,
+    /// The time spent sending the transaction batches to zkSync.
+    pub zksync_submission: Family<Histogram>,
+    /// The time spent waiting for the transaction batches to be committed by zkSync.
+    pub zksync_commit_wait: Family<Histogram>,
+    /// The PostgreSQL query execution time.
+    pub database_query: Family<Histogram>,
+    /// The number of errors returned by the handlers.
+    pub errors: Family<Counter>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            nonce_lock_acquisitions: AtomicU64::default(),
+            nonce_lock_wait_micros: AtomicU64::default(),
+            nonce_retries: AtomicU64::default(),
+
+            vm_execution: Family::new(
+                "zandbox_vm_execution_seconds",
+                "The contract method execution time on the Zinc VM.",
+                &["handler", "contract", "method"],
+            ),
+            zksync_submission: Family::new(
+                "zandbox_zksync_submission_seconds",
+                "The time spent sending the transaction batches to zkSync.",
+                &[],
+            ),
+            zksync_commit_wait: Family::new(
+                "zandbox_zksync_commit_wait_seconds",
+                "The time spent waiting for the transaction batches to be committed.",
+                &[],
+            ),
+            database_query: Family::new(
+                "zandbox_database_query_seconds",
+                "The PostgreSQL query execution time.",
+                &["query"],
+            ),
+            errors: Family::new(
+                "zandbox_errors_total",
+                "The number of errors returned by the handlers.",
+                &["handler", "error"],
+            ),
+        }
+    }
+}
+
+impl Metrics {
+    ///
+    /// Records a contract nonce lock acquisition, which took `wait` to wait for.
+    ///
+    pub fn record_nonce_lock_wait(&self, wait: Duration) {
+        self.nonce_lock_acquisitions.fetch_add(1, Ordering::Relaxed);
+        self.nonce_lock_wait_micros
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records a transaction batch retry after a nonce mismatch.
+    ///
+    pub fn record_nonce_retry(&self) {
+        self.nonce_retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records the `duration` of the contract `method` run on the Zinc VM by the `handler`.
+    ///
+    pub fn record_vm_execution(
+        &self,
+        handler: &str,
+        contract: &str,
+        method: &str,
+        duration: Duration,
+    ) {
+        self.vm_execution
+            .with(&[handler, contract, method], |histogram| {
+                histogram.observe(duration)
+            });
+    }
+
+    ///
+    /// Records the `duration` of a transaction batch submission to zkSync.
+    ///
+    pub fn record_zksync_submission(&self, duration: Duration) {
+        self.zksync_submission
+            .with(&[], |histogram| histogram.observe(duration));
+    }
+
+    ///
+    /// Records the `duration` of waiting for a transaction batch to be committed by zkSync.
+    ///
+    pub fn record_zksync_commit_wait(&self, duration: Duration) {
+        self.zksync_commit_wait
+            .with(&[], |histogram| histogram.observe(duration));
+    }
+
+    ///
+    /// Records the error `kind` returned by the `handler`.
+    ///
+    pub fn record_error(&self, handler: &str, kind: &str) {
+        self.errors
+            .with(&[handler, kind], |counter| counter.increment());
+    }
+
+    ///
+    /// Awaits the database `query` future, recording its execution time.
+    ///
+    pub async fn time_database_query<F>(&self, query: &str, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let started = Instant::now();
+        let output = future.await;
+        self.database_query
+            .with(&[query], |histogram| histogram.observe(started.elapsed()));
+        output
+    }
+
+    ///
+    /// Renders the metrics in the Prometheus text exposition format.
+    ///
+    pub fn to_prometheus(&self) -> String {
+        let mut output = Vec::with_capacity(256);
+
+        self.vm_execution.render(&mut output);
+        self.zksync_submission.render(&mut output);
+        self.zksync_commit_wait.render(&mut output);
+        self.database_query.render(&mut output);
+        self.errors.render(&mut output);
+
+        Self::render_scalar(
+            &mut output,
+            "zandbox_nonce_lock_acquisitions_total",
+            "The number of the contract nonce lock acquisitions.",
+            self.nonce_lock_acquisitions
+                .load(Ordering::Relaxed)
+                .to_string(),
+        );
+        Self::render_scalar(
+            &mut output,
+            "zandbox_nonce_lock_wait_seconds_total",
+            "The total time spent waiting for the contract nonce locks.",
+            (self.nonce_lock_wait_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0).to_string(),
+        );
+        Self::render_scalar(
+            &mut output,
+            "zandbox_nonce_retries_total",
+            "The number of transaction batches re-sent after a nonce mismatch.",
+            self.nonce_retries.load(Ordering::Relaxed).to_string(),
+        );
+
+        output.push(String::new());
+        output.join("\n")
+    }
+
+    ///
+    /// Renders an unlabeled counter with the `value`.
+    ///
+    fn render_scalar(output: &mut Vec<String>, name: &str, help: &str, value: String) {
+        output.push(format!("# HELP {} {}", name, help));
+        output.push(format!("# TYPE {} {}", name, Counter::TYPE));
+        output.push(format!("{} {}", name, value));
+    }
+}
+
+///
+/// Wraps the non-empty pre-formatted `labels` into curly brackets.
+///
+fn braced(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    }
+}
+
+///
+/// Escapes the label `value` according to the Prometheus text exposition format.
+///
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
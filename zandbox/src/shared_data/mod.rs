@@ -3,6 +3,9 @@
 //!
 
 pub mod contract;
+pub mod jobs;
+pub mod metrics;
+pub mod nonce_locks;
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -10,9 +13,16 @@ use std::sync::RwLock;
 
 use zksync::web3::types::Address;
 
+use crate::auth::nonces::Nonces as AuthNonces;
 use crate::database::client::Client as DatabaseClient;
+use crate::gateway::wallet::Gateway as ZkSyncGateway;
+use crate::gateway::IZkSyncGateway;
+use crate::settings::Settings;
 
 use self::contract::Contract;
+use self::jobs::Jobs;
+use self::metrics::Metrics;
+use self::nonce_locks::NonceLocks;
 
 ///
 /// The Zandbox server daemon shared application data.
@@ -22,16 +32,42 @@ pub struct SharedData {
     pub postgresql: DatabaseClient,
     /// The precompiled contracts written at application startup.
     pub contracts: HashMap<Address, Contract>,
+    /// The archived contracts, which are not served until they are restored.
+    pub archived_contracts: HashMap<Address, Contract>,
+    /// The server settings.
+    pub settings: Settings,
+    /// The contract locks held while fetching the nonce, signing, and sending transactions.
+    pub nonce_locks: NonceLocks,
+    /// The nonces issued for the privileged requests of the contract owners.
+    pub auth_nonces: AuthNonces,
+    /// The contract publishing jobs run in the background.
+    pub jobs: Jobs,
+    /// The server metrics counters.
+    pub metrics: Arc<Metrics>,
+    /// The zkSync gateway used to connect to the contract accounts.
+    pub gateway: Arc<dyn IZkSyncGateway>,
 }
 
 impl SharedData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(postgresql: DatabaseClient, contracts: HashMap<Address, Contract>) -> Self {
+    pub fn new(
+        postgresql: DatabaseClient,
+        contracts: HashMap<Address, Contract>,
+        archived_contracts: HashMap<Address, Contract>,
+        settings: Settings,
+    ) -> Self {
         Self {
             postgresql,
             contracts,
+            archived_contracts,
+            settings,
+            nonce_locks: NonceLocks::default(),
+            auth_nonces: AuthNonces::default(),
+            jobs: Jobs::default(),
+            metrics: Arc::new(Metrics::default()),
+            gateway: Arc::new(ZkSyncGateway),
         }
     }
 
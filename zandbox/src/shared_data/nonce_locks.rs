@@ -0,0 +1,37 @@
+//!
+//! The Zandbox server daemon contract nonce locks.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_std::sync::Mutex as AsyncMutex;
+
+use zksync::web3::types::Address;
+
+///
+/// The contract nonce locks.
+///
+/// Concurrent calls of the same contract must not fetch the same committed nonce, so the nonce
+/// fetching, transfer signing, and batch sending are performed while holding the contract lock.
+///
+#[derive(Debug, Default)]
+pub struct NonceLocks {
+    /// The contract locks, which are created on the first call of each contract.
+    inner: Mutex<HashMap<Address, Arc<AsyncMutex<()>>>>,
+}
+
+impl NonceLocks {
+    ///
+    /// Returns the lock of the contract with `address`, creating it if it does not exist yet.
+    ///
+    pub fn get(&self, address: Address) -> Arc<AsyncMutex<()>> {
+        self.inner
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .entry(address)
+            .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+            .clone()
+    }
+}
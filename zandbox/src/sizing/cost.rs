@@ -0,0 +1,34 @@
+//!
+//! The contract method cost.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zinc_vm::SetupReport;
+
+///
+/// The contract method cost, which is measured when the contract is published.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Cost {
+    /// The method has been synthesized with zeroed arguments and storage.
+    Known {
+        /// The number of constraints.
+        constraints: usize,
+        /// The number of witness variables.
+        witness: usize,
+    },
+    /// The method sizing run has failed or timed out.
+    Unknown,
+}
+
+impl From<SetupReport> for Cost {
+    fn from(report: SetupReport) -> Self {
+        Self::Known {
+            constraints: report.constraints,
+            witness: report.witness,
+        }
+    }
+}
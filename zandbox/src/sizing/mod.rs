@@ -0,0 +1,62 @@
+//!
+//! The contract method sizing.
+//!
+
+pub mod cost;
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use zinc_build::Contract as BuildContract;
+use zinc_vm::Bn256;
+
+use self::cost::Cost;
+
+///
+/// Measures the public methods of the contract `build` with zeroed arguments and storage.
+///
+/// The methods are measured one by one within the common `timeout`. A method whose sizing run
+/// fails, e.g. because it requires a non-zero argument, is marked as unknown, as well as the
+/// methods which have not been measured before the timeout has expired. The run which has
+/// exceeded the timeout is left to finish in the background, and its result is discarded.
+///
+/// The constructor is not measured, since it is never called after publishing.
+///
+pub async fn measure(build: &BuildContract, timeout: Duration) -> HashMap<String, Cost> {
+    let deadline = Instant::now() + timeout;
+
+    let mut costs = HashMap::with_capacity(build.methods.len());
+    for name in build.methods.keys() {
+        if name.as_str() == zinc_const::contract::CONSTRUCTOR_NAME {
+            continue;
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining == Duration::from_secs(0) {
+            log::debug!("Method `{}` sizing skipped: the timeout has expired", name);
+            costs.insert(name.to_owned(), Cost::Unknown);
+            continue;
+        }
+
+        let build = build.clone();
+        let method_name = name.to_owned();
+        let run = async_std::task::spawn_blocking(move || {
+            zinc_vm::ContractFacade::new(build).size::<Bn256>(method_name.as_str())
+        });
+
+        let cost = match async_std::future::timeout(remaining, run).await {
+            Ok(Ok(report)) => Cost::from(report),
+            Ok(Err(error)) => {
+                log::debug!("Method `{}` sizing failed: {}", name, error);
+                Cost::Unknown
+            }
+            Err(_elapsed) => {
+                log::debug!("Method `{}` sizing timed out", name);
+                Cost::Unknown
+            }
+        };
+        costs.insert(name.to_owned(), cost);
+    }
+    costs
+}
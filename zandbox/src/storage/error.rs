@@ -0,0 +1,26 @@
+//!
+//! The Zandbox contract storage error.
+//!
+
+use failure::Fail;
+
+use zinc_build::ValueError as BuildValueError;
+
+///
+/// The Zandbox contract storage error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The field value loaded from the database or zkSync does not fit the field type.
+    #[fail(display = "field `{}` is invalid: {}", _0, _1)]
+    InvalidField(String, BuildValueError),
+    /// The ZkSync server client error.
+    #[fail(display = "ZkSync client: {}", _0)]
+    ZkSyncClient(zksync::error::ClientError),
+}
+
+impl From<zksync::error::ClientError> for Error {
+    fn from(inner: zksync::error::ClientError) -> Self {
+        Self::ZkSyncClient(inner)
+    }
+}
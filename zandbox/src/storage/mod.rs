@@ -2,20 +2,30 @@
 //! The Zandbox server daemon contract storage utils.
 //!
 
+pub mod error;
+
+use std::collections::HashMap;
+
+use num::BigInt;
+use num::BigUint;
 use serde_json::json;
 use serde_json::Value as JsonValue;
 
-use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::TokenLike;
 
 use zinc_build::ContractFieldType;
 use zinc_build::ContractFieldValue;
 use zinc_build::ContractFieldValue as BuildContractFieldValue;
+use zinc_build::IntegerType;
+use zinc_build::ScalarValue;
 use zinc_build::Value as BuildValue;
 
 use crate::database::model::field::insert::Input as FieldInsertInput;
 use crate::database::model::field::select::Output as FieldSelectOutput;
 use crate::database::model::field::update::Input as FieldUpdateInput;
+use crate::gateway::IZkSyncWallet;
+
+use self::error::Error;
 
 ///
 /// The Zandbox contract storage wrapper.
@@ -50,13 +60,17 @@ impl Storage {
     ///
     /// The `address` field at the index `0` is taken from the Zandbox in-memory cache.
     /// The `balances` field at the index `1` is populated from the zkSync account info.
+    /// The `layout_hash` field at the index `2` and the explicit fields are read from the database.
+    ///
+    /// The balances and the database values are checked against their types, so a corrupted
+    /// value, e.g. an integer out of range, is reported instead of being reduced silently.
     ///
     pub async fn new_with_data(
         database_fields: Vec<FieldSelectOutput>,
         types: &[ContractFieldType],
         address: zksync_types::Address,
-        wallet: &zksync::Wallet<PrivateKeySigner>,
-    ) -> Result<Self, zksync::error::ClientError> {
+        wallet: &dyn IZkSyncWallet,
+    ) -> Result<Self, Error> {
         let mut fields = Vec::with_capacity(database_fields.len());
 
         fields.push(BuildContractFieldValue::new(
@@ -76,7 +90,7 @@ impl Storage {
         let mut balances = Vec::with_capacity(account_info.committed.balances.len());
         for (symbol, balance) in account_info.committed.balances.into_iter() {
             let token = wallet
-                .tokens
+                .tokens()
                 .resolve(TokenLike::Symbol(symbol))
                 .ok_or(zksync::error::ClientError::UnknownToken)?;
             balances.push(json!({
@@ -92,7 +106,9 @@ impl Storage {
                     .r#type
                     .to_owned(),
             )
-            .expect(zinc_const::panic::DATA_CONVERSION),
+            .map_err(|error| {
+                Error::InvalidField(zinc_const::contract::FIELD_NAME_BALANCES.to_owned(), error)
+            })?,
             true,
             true,
         ));
@@ -100,11 +116,11 @@ impl Storage {
         for (mut index, FieldSelectOutput { name, value }) in
             database_fields.into_iter().enumerate()
         {
-            index += zinc_const::contract::IMPLICIT_FIELDS_COUNT;
+            index += zinc_const::contract::FIELD_INDEX_LAYOUT_HASH;
 
             let r#type = types[index].r#type.to_owned();
             let value = BuildValue::try_from_typed_json(value, r#type)
-                .expect(zinc_const::panic::VALIDATED_DURING_DATABASE_POPULATION);
+                .map_err(|error| Error::InvalidField(name.to_owned(), error))?;
             fields.push(BuildContractFieldValue::new(
                 name,
                 value,
@@ -126,6 +142,63 @@ impl Storage {
         }
     }
 
+    ///
+    /// Extracts the implicit `balances` field, which is passed to the VM to answer the
+    /// `self.balance` calls.
+    ///
+    pub fn balances(&self) -> HashMap<BigUint, BigUint> {
+        match self
+            .fields
+            .get(zinc_const::contract::FIELD_INDEX_BALANCES)
+            .map(|field| &field.value)
+        {
+            Some(BuildValue::Map(entries)) => entries
+                .iter()
+                .filter_map(|(key, value)| {
+                    let key = key.to_owned().into_flat_values().pop()?.to_biguint()?;
+                    let value = value.to_owned().into_flat_values().pop()?.to_biguint()?;
+                    Some((key, value))
+                })
+                .collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    ///
+    /// Sets the implicit `layout_hash` field, which is checked by the VM before executing the
+    /// mutable methods.
+    ///
+    pub fn set_layout_hash(&mut self, layout_hash: BigInt) {
+        if let Some(field) = self
+            .fields
+            .get_mut(zinc_const::contract::FIELD_INDEX_LAYOUT_HASH)
+        {
+            field.value =
+                BuildValue::Scalar(ScalarValue::Integer(layout_hash, IntegerType::LAYOUT_HASH));
+        }
+    }
+
+    ///
+    /// Checks whether the address field `name` holds `address`.
+    ///
+    /// Is used to check the `#[only(name)]` method restriction before running the VM.
+    ///
+    pub fn is_field_address(&self, name: &str, address: &zksync_types::Address) -> bool {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .and_then(|field| {
+                field
+                    .value
+                    .to_owned()
+                    .into_flat_values()
+                    .pop()?
+                    .to_biguint()
+            })
+            .map(|value| value == BigUint::from_bytes_be(address.as_bytes()))
+            .unwrap_or_default()
+    }
+
     ///
     /// Converts the storage into the INSERT query database representation.
     ///
@@ -152,6 +225,8 @@ impl Storage {
     ///
     /// Converts the storage into the UPDATE query database representation.
     ///
+    /// The `layout_hash` field is immutable, so it is only updated on re-publishing.
+    ///
     pub fn into_database_update(
         self,
         account_id: zksync_types::AccountId,
@@ -162,6 +237,7 @@ impl Storage {
             .filter_map(|(index, field)| match index {
                 zinc_const::contract::FIELD_INDEX_ADDRESS => None,
                 zinc_const::contract::FIELD_INDEX_BALANCES => None,
+                zinc_const::contract::FIELD_INDEX_LAYOUT_HASH => None,
                 index => Some(FieldUpdateInput::new(
                     account_id,
                     index as i16,
@@ -171,6 +247,26 @@ impl Storage {
             .collect()
     }
 
+    ///
+    /// Converts the `layout_hash` field into the UPDATE query database representation.
+    ///
+    pub fn to_database_layout_hash_update(
+        &self,
+        account_id: zksync_types::AccountId,
+    ) -> Vec<FieldUpdateInput> {
+        self.fields
+            .get(zinc_const::contract::FIELD_INDEX_LAYOUT_HASH)
+            .map(|field| {
+                FieldUpdateInput::new(
+                    account_id,
+                    zinc_const::contract::FIELD_INDEX_LAYOUT_HASH as i16,
+                    field.value.to_owned().into_json(),
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+
     ///
     /// Wraps the fields with the VM value type.
     ///
@@ -0,0 +1,267 @@
+//!
+//! The Zandbox contract archival tests.
+//!
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use actix_web::ResponseError;
+use serde_json::json;
+
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_types::AccountId;
+
+use zinc_build::ContractFieldType;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+use zinc_zksync::CallRequestBody;
+use zinc_zksync::CallRequestQuery;
+
+use crate::auth::error::Error as AuthError;
+use crate::controller::contract::call;
+use crate::controller::contract::call::error::Error as CallError;
+use crate::controller::contract::delete;
+use crate::controller::contract::delete::error::Error as DeleteError;
+use crate::controller::contract::delete::request::Path as DeleteRequestPath;
+use crate::controller::contract::restore;
+use crate::controller::contract::restore::error::Error as RestoreError;
+use crate::controller::contract::restore::request::Path as RestoreRequestPath;
+use crate::database::model::contract::select_list::Input as ContractSelectListInput;
+use crate::database::model::contract::select_list::Sort as ContractSelectListSort;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::gateway::mock::Gateway as MockGateway;
+use crate::shared_data::SharedData;
+
+use super::authorization;
+use super::contract_address;
+use super::owner_private_key;
+
+const ACCOUNT_ID: AccountId = 46;
+
+async fn shared_data() -> web::Data<Arc<RwLock<SharedData>>> {
+    let storage = vec![ContractFieldType::new(
+        "total".to_owned(),
+        BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE)),
+        true,
+        false,
+    )];
+
+    super::shared_data(
+        ACCOUNT_ID,
+        super::build(storage),
+        H256::from_low_u64_be(ACCOUNT_ID as u64),
+        false,
+        MockGateway::new(vec![], ACCOUNT_ID),
+    )
+    .await
+}
+
+async fn archive(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> Result<(), DeleteError> {
+    let authorization = authorization(app_data, &owner_private_key());
+    let path = web::Path::from(DeleteRequestPath {
+        address: contract_address(ACCOUNT_ID),
+    });
+
+    delete::handle(app_data.clone(), path, authorization)
+        .await
+        .map(|_response| ())
+}
+
+async fn restore(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> Result<(), RestoreError> {
+    let authorization = authorization(app_data, &owner_private_key());
+    let path = web::Path::from(RestoreRequestPath {
+        address: contract_address(ACCOUNT_ID),
+    });
+
+    restore::handle(app_data.clone(), path, authorization)
+        .await
+        .map(|_response| ())
+}
+
+async fn is_archived_in_database(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> bool {
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    postgresql
+        .select_contracts()
+        .await
+        .expect("Database contracts selecting")
+        .into_iter()
+        .find(|contract| contract.account_id == ACCOUNT_ID as i64)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .is_archived
+}
+
+fn is_served(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> bool {
+    app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contracts
+        .contains_key(&contract_address(ACCOUNT_ID))
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_archived() {
+    let app_data = shared_data().await;
+
+    let result = archive(&app_data).await;
+
+    assert!(result.is_ok());
+    assert!(is_archived_in_database(&app_data).await);
+    assert!(!is_served(&app_data));
+    assert!(app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .archived_contracts
+        .contains_key(&contract_address(ACCOUNT_ID)));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_archived_storage_retained() {
+    let app_data = shared_data().await;
+
+    archive(&app_data)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let fields = postgresql
+        .select_fields(FieldSelectInput::new(ACCOUNT_ID))
+        .await
+        .expect("Database contract storage selecting");
+    assert_eq!(fields.len(), 1);
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_archived_listed_on_demand() {
+    let app_data = shared_data().await;
+
+    archive(&app_data)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    for (include_archived, expected) in vec![(false, 0), (true, 1)].into_iter() {
+        let (contracts, total_count) = postgresql
+            .select_contracts_list(ContractSelectListInput::new(
+                10,
+                0,
+                None,
+                None,
+                include_archived,
+                ContractSelectListSort::CreatedAt,
+            ))
+            .await
+            .expect("Database contracts selecting");
+
+        assert_eq!(total_count, expected);
+        assert!(contracts.iter().all(|contract| contract.is_archived));
+    }
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_restored() {
+    let app_data = shared_data().await;
+    archive(&app_data)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = restore(&app_data).await;
+
+    assert!(result.is_ok());
+    assert!(!is_archived_in_database(&app_data).await);
+    assert!(is_served(&app_data));
+    assert!(app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .archived_contracts
+        .is_empty());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_call_archived() {
+    let app_data = shared_data().await;
+    archive(&app_data)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = call::handle(
+        app_data,
+        web::Query(CallRequestQuery::new(
+            contract_address(ACCOUNT_ID),
+            "deposit".to_owned(),
+            Network::Localhost,
+        )),
+        web::Json(CallRequestBody::new(json!({}), vec![])),
+    )
+    .await;
+
+    match result {
+        Err(error @ CallError::ContractArchived(_)) => {
+            assert_eq!(error.status_code(), StatusCode::GONE)
+        }
+        _ => panic!("The call must be rejected"),
+    }
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_archived_twice() {
+    let app_data = shared_data().await;
+    archive(&app_data)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = archive(&app_data).await;
+
+    assert!(matches!(result, Err(DeleteError::ContractArchived(_))));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_archived_not_owner() {
+    let app_data = shared_data().await;
+    let authorization = authorization(&app_data, &H256::from_low_u64_be(2));
+    let path = web::Path::from(DeleteRequestPath {
+        address: contract_address(ACCOUNT_ID),
+    });
+
+    let result = delete::handle(app_data.clone(), path, authorization).await;
+
+    assert!(matches!(
+        result,
+        Err(DeleteError::Unauthorized(AuthError::NotOwner(_)))
+    ));
+    assert!(!is_archived_in_database(&app_data).await);
+    assert!(is_served(&app_data));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_restored_not_archived() {
+    let app_data = shared_data().await;
+
+    let result = restore(&app_data).await;
+
+    assert!(matches!(result, Err(RestoreError::ContractNotArchived(_))));
+}
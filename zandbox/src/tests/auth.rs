@@ -0,0 +1,138 @@
+//!
+//! The Zandbox privileged request authorization tests.
+//!
+
+use std::time::Duration;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_types::AccountId;
+
+use zinc_zksync::Credentials;
+
+use crate::auth::error::Error;
+use crate::auth::nonces::Nonces;
+use crate::auth::Authorization;
+
+use super::contract_address;
+use super::owner_address;
+use super::owner_private_key;
+
+const ACCOUNT_ID: AccountId = 42;
+
+fn sign(nonces: &Nonces, private_key: &H256) -> Authorization {
+    let credentials =
+        Credentials::sign(nonces.issue(), private_key).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    Authorization(Some(credentials))
+}
+
+fn authorize(nonces: &Nonces, authorization: &Authorization) -> Result<Address, Error> {
+    crate::auth::authorize(
+        nonces,
+        authorization,
+        contract_address(ACCOUNT_ID),
+        Some(owner_address()),
+    )
+}
+
+#[test]
+fn ok_owner() {
+    let nonces = Nonces::default();
+    let authorization = sign(&nonces, &owner_private_key());
+
+    assert_eq!(
+        authorize(&nonces, &authorization).ok(),
+        Some(owner_address())
+    );
+}
+
+#[test]
+fn ok_headers() {
+    let nonces = Nonces::default();
+    let credentials = Credentials::sign(nonces.issue(), &owner_private_key())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let authorization = Authorization::try_from_headers(
+        Some(credentials.nonce.as_str()),
+        Some(credentials.signature_hex().as_str()),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        authorize(&nonces, &authorization).ok(),
+        Some(owner_address())
+    );
+}
+
+#[test]
+fn error_credentials_missing() {
+    let nonces = Nonces::default();
+
+    let result = authorize(&nonces, &Authorization::default());
+
+    assert!(matches!(result, Err(Error::CredentialsMissing)));
+}
+
+#[test]
+fn error_invalid_signature() {
+    let nonces = Nonces::default();
+    let nonce = nonces.issue();
+
+    let result = Authorization::try_from_headers(Some(nonce.as_str()), Some("0xdeadbeef"));
+
+    assert!(matches!(result, Err(Error::InvalidSignature(_))));
+}
+
+#[test]
+fn error_nonce_expired() {
+    let nonces = Nonces::new(Duration::from_secs(0));
+    let authorization = sign(&nonces, &owner_private_key());
+
+    let result = authorize(&nonces, &authorization);
+
+    assert!(matches!(result, Err(Error::NonceExpired(_))));
+}
+
+#[test]
+fn error_nonce_reused() {
+    let nonces = Nonces::default();
+    let authorization = sign(&nonces, &owner_private_key());
+
+    assert!(authorize(&nonces, &authorization).is_ok());
+    let result = authorize(&nonces, &authorization);
+
+    assert!(matches!(result, Err(Error::NonceUnknown(_))));
+}
+
+#[test]
+fn error_nonce_unknown() {
+    let nonces = Nonces::default();
+    let credentials = Credentials::sign("forged".to_owned(), &owner_private_key())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = authorize(&nonces, &Authorization(Some(credentials)));
+
+    assert!(matches!(result, Err(Error::NonceUnknown(_))));
+}
+
+#[test]
+fn error_not_owner() {
+    let nonces = Nonces::default();
+    let authorization = sign(&nonces, &H256::from_low_u64_be(2));
+
+    let result = authorize(&nonces, &authorization);
+
+    assert!(matches!(result, Err(Error::NotOwner(_))));
+}
+
+#[test]
+fn error_owner_unknown() {
+    let nonces = Nonces::default();
+    let authorization = sign(&nonces, &owner_private_key());
+
+    let result =
+        crate::auth::authorize(&nonces, &authorization, contract_address(ACCOUNT_ID), None);
+
+    assert!(matches!(result, Err(Error::OwnerUnknown(_))));
+}
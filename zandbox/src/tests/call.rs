@@ -0,0 +1,328 @@
+//!
+//! The Zandbox contract method call tests.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::test;
+use actix_web::test::TestRequest;
+use actix_web::web;
+use actix_web::App;
+use num_old::BigUint;
+use serde_json::json;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::tx::Transfer;
+use zksync_types::tx::ZkSyncTx;
+use zksync_types::AccountId;
+use zksync_types::Token;
+
+use zinc_build::Contract as BuildContract;
+use zinc_build::ContractFieldType;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::Exit;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+use zinc_zksync::CallRequestBody;
+use zinc_zksync::CallRequestQuery;
+use zinc_zksync::Transaction;
+
+use crate::controller::contract::call;
+use crate::controller::contract::call::error::Error;
+use crate::controller::contract::call::response::Status as ResponseStatus;
+use crate::controller::metrics;
+use crate::gateway::mock::Gateway as MockGateway;
+use crate::shared_data::contract::Contract as SharedDataContract;
+use crate::shared_data::SharedData;
+
+use super::contract_address;
+
+const ACCOUNT_ID: AccountId = 43;
+const SENDER_ACCOUNT_ID: AccountId = 44;
+const METHOD_NAME: &str = "deposit";
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+fn sender_address() -> Address {
+    Address::from_low_u64_be(SENDER_ACCOUNT_ID as u64)
+}
+
+fn eth_token() -> Token {
+    Token::new(0, Address::zero(), "ETH", 18)
+}
+
+fn build(payable_token: &str) -> BuildContract {
+    let address_type = BuildType::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS));
+    let balance_type = BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+    let storage = vec![
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_ADDRESS.to_owned(),
+            address_type.clone(),
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_BALANCES.to_owned(),
+            BuildType::Map {
+                key_type: Box::new(address_type),
+                value_type: Box::new(balance_type.clone()),
+            },
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            BuildType::Scalar(ScalarType::Integer(IntegerType::LAYOUT_HASH)),
+            true,
+            true,
+        ),
+        ContractFieldType::new("total".to_owned(), balance_type, true, false),
+    ];
+
+    let method = BuildContractMethod::new(
+        0,
+        METHOD_NAME.to_owned(),
+        0,
+        true,
+        false,
+        true,
+        Some(payable_token.to_owned()),
+        None,
+        vec![],
+        BuildType::new_empty_structure(),
+        BuildType::Unit,
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    BuildContract::new(
+        "test".to_owned(),
+        storage,
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![Exit::new(0).into()],
+    )
+}
+
+async fn shared_data(
+    build: BuildContract,
+    gateway: MockGateway,
+) -> web::Data<Arc<RwLock<SharedData>>> {
+    super::shared_data(
+        ACCOUNT_ID,
+        build,
+        H256::from_low_u64_be(ACCOUNT_ID as u64),
+        true,
+        gateway,
+    )
+    .await
+}
+
+async fn scrape(app_data: web::Data<Arc<RwLock<SharedData>>>) -> String {
+    let mut service = test::init_service(
+        App::new()
+            .app_data(app_data)
+            .route("/metrics", web::get().to(metrics::handle)),
+    )
+    .await;
+    let request = TestRequest::get().uri("/metrics").to_request();
+    let body = test::read_response(&mut service, request).await;
+
+    String::from_utf8(body.to_vec()).expect(zinc_const::panic::DATA_CONVERSION)
+}
+
+fn request() -> (web::Query<CallRequestQuery>, web::Json<CallRequestBody>) {
+    let transfer = Transfer::new(
+        SENDER_ACCOUNT_ID,
+        sender_address(),
+        contract_address(ACCOUNT_ID),
+        eth_token().id,
+        BigUint::from(TRANSFER_AMOUNT),
+        BigUint::from(0u64),
+        0,
+        None,
+    );
+    let signature = PackedEthSignature::sign(
+        &H256::from_low_u64_be(SENDER_ACCOUNT_ID as u64),
+        METHOD_NAME.as_bytes(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    (
+        web::Query(CallRequestQuery::new(
+            contract_address(ACCOUNT_ID),
+            METHOD_NAME.to_owned(),
+            Network::Localhost,
+        )),
+        web::Json(CallRequestBody::new(
+            json!({}),
+            vec![Transaction::new(
+                ZkSyncTx::Transfer(Box::new(transfer)),
+                signature,
+            )],
+        )),
+    )
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_call() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    let app_data = shared_data(build("ETH"), gateway.clone()).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data, query, body).await;
+
+    let response = serde_json::to_value(result.expect(zinc_const::panic::TEST_DATA_VALID))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    let transactions = response["data"]["transactions"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0]["label"], "client_transfer");
+    assert_eq!(transactions[0]["status"], "committed");
+    let state = gateway.state();
+    assert_eq!(state.batches.len(), 1);
+    assert_eq!(state.batches[0].len(), 1);
+    assert!(matches!(
+        state.batches[0][0],
+        (ZkSyncTx::Transfer(_), Some(_))
+    ));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_call_metrics() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    let app_data = shared_data(build("ETH"), gateway).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data.clone(), query, body).await;
+    assert!(result.is_ok());
+
+    let metrics = scrape(app_data).await;
+    for series in [
+        "zandbox_vm_execution_seconds_count{handler=\"call\",contract=\"test\",method=\"deposit\"} 1",
+        "zandbox_zksync_submission_seconds_count 1",
+        "zandbox_zksync_commit_wait_seconds_count 1",
+        "zandbox_database_query_seconds_count{query=\"select_fields\"} 1",
+        "zandbox_database_query_seconds_count{query=\"update_fields\"} 1",
+        "zandbox_nonce_lock_acquisitions_total 1",
+    ]
+    .iter()
+    {
+        assert!(
+            metrics.lines().any(|line| line == *series),
+            "Series `{}` not found in:\n{}",
+            series,
+            metrics
+        );
+    }
+    assert!(!metrics.contains("zandbox_errors_total{"));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_token_not_found_metrics() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    let app_data = shared_data(build("DAI"), gateway).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data.clone(), query, body).await;
+    assert!(result.is_err());
+
+    let metrics = scrape(app_data).await;
+    assert!(metrics
+        .lines()
+        .any(|line| line == "zandbox_errors_total{handler=\"call\",error=\"TokenNotFound\"} 1"));
+    assert!(!metrics.contains("zandbox_vm_execution_seconds_count{"));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_token_not_found() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    let app_data = shared_data(build("DAI"), gateway.clone()).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data, query, body).await;
+
+    assert!(matches!(result, Err(Error::TokenNotFound(token)) if token == "DAI"));
+    assert!(gateway.state().batches.is_empty());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_batch_rejected() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    gateway.state().batch_error = Some("Insufficient balance".to_owned());
+    let app_data = shared_data(build("ETH"), gateway.clone()).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data, query, body).await;
+
+    assert!(matches!(result, Err(Error::ZkSyncClient(_))));
+    assert!(gateway.state().batches.is_empty());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_batch_failed() {
+    let gateway = MockGateway::new(vec![eth_token()], ACCOUNT_ID);
+    gateway.state().fail_reason = Some("Insufficient balance".to_owned());
+    let app_data = shared_data(build("ETH"), gateway.clone()).await;
+    let (query, body) = request();
+
+    let result = call::handle(app_data, query, body).await;
+
+    match result {
+        Err(Error::TransferFailure {
+            reason,
+            transactions,
+        }) => {
+            assert_eq!(reason, "Insufficient balance");
+            assert_eq!(transactions.len(), 1);
+            assert_eq!(transactions[0].label, "client_transfer");
+            assert_eq!(transactions[0].status, ResponseStatus::Failed);
+        }
+        _ => panic!("The transfer must fail"),
+    }
+    assert_eq!(gateway.state().batches.len(), 1);
+}
+
+#[test]
+fn ok_token_allowed_address_forms() {
+    let token = Token::new(
+        1,
+        "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+            .parse()
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        "TST",
+        18,
+    );
+
+    for allowed in [
+        "TST",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        "517705355260207604495801938720638392742277016301",
+    ]
+    .iter()
+    {
+        assert!(SharedDataContract::is_token_allowed(
+            &[(*allowed).to_owned()],
+            &token
+        ));
+    }
+
+    assert!(!SharedDataContract::is_token_allowed(
+        &["0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".to_owned()],
+        &token
+    ));
+}
@@ -11,9 +11,9 @@ use serde_json::json;
 use crate::database::client::Client as DatabaseClient;
 use std::io::Write;
 
-static MANIFEST_PATH: &str = "/home/hedgar/src/curve-zinc/";
+use super::POSTGRESQL_URL;
 
-static POSTGRESQL_URL: &str = "postgres://postgres@localhost/zinc";
+static MANIFEST_PATH: &str = "/home/hedgar/src/curve-zinc/";
 
 static OWNER_ADDRESS: &str = "0x36615cf349d7f6344891b1e7ca7c72883f5dc049";
 
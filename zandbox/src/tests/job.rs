@@ -0,0 +1,225 @@
+//!
+//! The Zandbox contract publishing job tests.
+//!
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use actix_web::web;
+use actix_web::ResponseError;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
+use zksync::Network;
+
+use zinc_zksync::File;
+use zinc_zksync::JobStatus;
+use zinc_zksync::PublishRequestBody;
+use zinc_zksync::PublishRequestQuery;
+use zinc_zksync::Source;
+
+use crate::auth::Authorization;
+use crate::controller::contract::post;
+use crate::controller::contract::post::error::Error as PublishError;
+use crate::controller::job::get;
+use crate::controller::job::get::error::Error as JobError;
+use crate::controller::job::get::request::Path as JobRequestPath;
+use crate::controller::job::get::response::Body as JobResponseBody;
+use crate::database::client::Client as DatabaseClient;
+use crate::shared_data::jobs::Jobs;
+use crate::shared_data::SharedData;
+
+async fn shared_data() -> web::Data<Arc<RwLock<SharedData>>> {
+    let client = super::database().await;
+    client.delete_jobs().await.expect("Database jobs deleting");
+
+    restarted(client)
+}
+
+fn restarted(client: DatabaseClient) -> web::Data<Arc<RwLock<SharedData>>> {
+    super::app_data(client, HashMap::new(), None)
+}
+
+async fn publish(
+    app_data: &web::Data<Arc<RwLock<SharedData>>>,
+    sync: bool,
+) -> Result<JsonValue, PublishError> {
+    let query = PublishRequestQuery::new(
+        "test".to_owned(),
+        "0.1.0".to_owned(),
+        "default".to_owned(),
+        Network::Localhost,
+        sync,
+    );
+    let source = Source::File(File {
+        name: "main".to_owned(),
+        path: "main.zn".to_owned(),
+        code: String::new(),
+    });
+    let body = PublishRequestBody::new(
+        source,
+        vec![0xff],
+        JsonValue::Null,
+        vec![],
+        HashMap::new(),
+        HashMap::new(),
+        vec![],
+        None,
+    );
+
+    let response = post::handle(
+        app_data.clone(),
+        web::Query(query),
+        web::Json(body),
+        Authorization::default(),
+    )
+    .await?;
+
+    let response = serde_json::to_value(response).expect(zinc_const::panic::TEST_DATA_VALID);
+    Ok(response["data"].to_owned())
+}
+
+async fn job(
+    app_data: &web::Data<Arc<RwLock<SharedData>>>,
+    id: String,
+) -> Result<JobResponseBody, JobError> {
+    let response = get::handle(app_data.clone(), web::Path::from(JobRequestPath { id })).await?;
+
+    let response = serde_json::to_value(response).expect(zinc_const::panic::TEST_DATA_VALID);
+    Ok(serde_json::from_value(response["data"].to_owned())
+        .expect(zinc_const::panic::TEST_DATA_VALID))
+}
+
+async fn start_job(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> String {
+    let response = publish(app_data, false)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    response["job_id"]
+        .as_str()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .to_owned()
+}
+
+async fn wait_for_job(
+    app_data: &web::Data<Arc<RwLock<SharedData>>>,
+    id: String,
+) -> JobResponseBody {
+    loop {
+        let job = job(app_data, id.clone())
+            .await
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        if job.status.is_finished() {
+            return job;
+        }
+
+        actix_rt::time::delay_for(Duration::from_millis(10)).await;
+    }
+}
+
+#[test]
+fn ok_jobs_progress() {
+    let jobs = Jobs::default();
+    let id = jobs.create();
+    assert_eq!(
+        jobs.get(id.as_str()).map(|job| job.status),
+        Some(JobStatus::Pending)
+    );
+
+    jobs.set_status(id.as_str(), JobStatus::Compiling);
+    assert_eq!(
+        jobs.get(id.as_str()).map(|job| job.status),
+        Some(JobStatus::Compiling)
+    );
+
+    jobs.finish(id.as_str(), Address::from_low_u64_be(42), None);
+    let job = jobs
+        .get(id.as_str())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(job.status, JobStatus::Done);
+    assert_eq!(job.address, Some(Address::from_low_u64_be(42)));
+}
+
+#[test]
+fn ok_jobs_expired_removed() {
+    let jobs = Jobs::new(Duration::from_secs(0));
+    let finished = jobs.create();
+    let unfinished = jobs.create();
+    jobs.fail(finished.as_str(), "error".to_owned());
+    jobs.set_status(unfinished.as_str(), JobStatus::Deploying);
+
+    assert!(jobs.get(finished.as_str()).is_none());
+    assert!(jobs.get(unfinished.as_str()).is_some());
+}
+
+#[test]
+fn ok_status_json_representation() {
+    assert_eq!(
+        serde_json::to_value(JobStatus::Compiling).expect(zinc_const::panic::TEST_DATA_VALID),
+        JsonValue::String("compiling".to_owned())
+    );
+    assert_eq!("deploying".parse::<JobStatus>(), Ok(JobStatus::Deploying));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_job_failed() {
+    let app_data = shared_data().await;
+
+    let id = start_job(&app_data).await;
+    let job = wait_for_job(&app_data, id).await;
+
+    assert_eq!(job.status, JobStatus::Failed);
+    assert!(job
+        .error
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .starts_with("Invalid bytecode"));
+    assert!(job.address.is_none());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_job_failed_from_database() {
+    let app_data = shared_data().await;
+
+    let id = start_job(&app_data).await;
+    wait_for_job(&app_data, id.clone()).await;
+
+    let client = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let job = job(&restarted(client), id)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(job.status, JobStatus::Failed);
+    assert!(job.error.is_some());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_sync_invalid_bytecode() {
+    let app_data = shared_data().await;
+
+    let result = publish(&app_data, true).await;
+
+    assert!(matches!(result, Err(PublishError::InvalidBytecode(_))));
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_job_not_found() {
+    let app_data = shared_data().await;
+
+    let result = job(&app_data, "unknown".to_owned()).await;
+
+    assert!(matches!(&result, Err(JobError::JobNotFound(_))));
+    assert_eq!(
+        result.err().map(|error| error.status_code()),
+        Some(actix_web::http::StatusCode::NOT_FOUND)
+    );
+}
@@ -0,0 +1,148 @@
+//!
+//! The Zandbox contract private key rotation tests.
+//!
+
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::web;
+
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_types::AccountId;
+
+use zinc_zksync::KeyRequestBody;
+use zinc_zksync::KeyRequestQuery;
+
+use crate::auth::error::Error as AuthError;
+use crate::controller::contract::key;
+use crate::controller::contract::key::error::Error;
+use crate::gateway::mock::Gateway as MockGateway;
+use crate::shared_data::SharedData;
+
+use super::authorization;
+use super::contract_address;
+use super::owner_private_key;
+
+const ACCOUNT_ID: AccountId = 45;
+
+fn old_private_key() -> H256 {
+    H256::from_low_u64_be(ACCOUNT_ID as u64)
+}
+
+fn new_private_key() -> H256 {
+    H256::from_low_u64_be(ACCOUNT_ID as u64 + 1)
+}
+
+async fn shared_data(gateway: MockGateway) -> web::Data<Arc<RwLock<SharedData>>> {
+    super::shared_data(
+        ACCOUNT_ID,
+        super::build(vec![]),
+        old_private_key(),
+        false,
+        gateway,
+    )
+    .await
+}
+
+fn request() -> (web::Query<KeyRequestQuery>, web::Json<KeyRequestBody>) {
+    (
+        web::Query(KeyRequestQuery::new(
+            contract_address(ACCOUNT_ID),
+            Network::Localhost,
+        )),
+        web::Json(KeyRequestBody::new(new_private_key())),
+    )
+}
+
+async fn stored_private_key(app_data: &web::Data<Arc<RwLock<SharedData>>>) -> H256 {
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let contract = postgresql
+        .select_contracts()
+        .await
+        .expect("Database contracts selecting")
+        .into_iter()
+        .find(|contract| contract.account_id == ACCOUNT_ID as i64)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    H256::from_slice(contract.eth_private_key.as_slice())
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_rotated() {
+    let gateway = MockGateway::new(vec![], ACCOUNT_ID);
+    gateway.state().signing_key = new_private_key();
+    let app_data = shared_data(gateway).await;
+    let authorization = authorization(&app_data, &owner_private_key());
+    let (query, body) = request();
+
+    let result = key::handle(app_data.clone(), query, body, authorization).await;
+
+    assert!(result.is_ok());
+    assert_eq!(stored_private_key(&app_data).await, new_private_key());
+    assert_eq!(
+        app_data
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .contracts
+            .get(&contract_address(ACCOUNT_ID))
+            .map(|contract| contract.eth_private_key),
+        Some(new_private_key())
+    );
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_rotated_repeated() {
+    let gateway = MockGateway::new(vec![], ACCOUNT_ID);
+    gateway.state().signing_key = new_private_key();
+    let app_data = shared_data(gateway).await;
+
+    for _ in 0..2 {
+        let authorization = authorization(&app_data, &owner_private_key());
+        let (query, body) = request();
+
+        let result = key::handle(app_data.clone(), query, body, authorization).await;
+
+        assert!(result.is_ok());
+    }
+    assert_eq!(stored_private_key(&app_data).await, new_private_key());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_key_not_changed() {
+    let gateway = MockGateway::new(vec![], ACCOUNT_ID);
+    gateway.state().signing_key = old_private_key();
+    let app_data = shared_data(gateway).await;
+    let authorization = authorization(&app_data, &owner_private_key());
+    let (query, body) = request();
+
+    let result = key::handle(app_data.clone(), query, body, authorization).await;
+
+    assert!(matches!(result, Err(Error::KeyNotChanged(_))));
+    assert_eq!(stored_private_key(&app_data).await, old_private_key());
+}
+
+#[actix_rt::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn error_not_owner() {
+    let gateway = MockGateway::new(vec![], ACCOUNT_ID);
+    gateway.state().signing_key = new_private_key();
+    let app_data = shared_data(gateway).await;
+    let authorization = authorization(&app_data, &H256::from_low_u64_be(2));
+    let (query, body) = request();
+
+    let result = key::handle(app_data.clone(), query, body, authorization).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Unauthorized(AuthError::NotOwner(_)))
+    ));
+    assert_eq!(stored_private_key(&app_data).await, old_private_key());
+}
@@ -0,0 +1,170 @@
+//!
+//! The Zandbox contract list integration tests.
+//!
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+
+use crate::database::client::Client as DatabaseClient;
+use crate::database::model::contract::select_list::Input as ContractSelectListInput;
+use crate::database::model::contract::select_list::Output as ContractSelectListOutput;
+use crate::database::model::contract::select_list::Sort as ContractSelectListSort;
+
+const OWNER_ADDRESS: u64 = 0x42;
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_first_page() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            2,
+            0,
+            None,
+            None,
+            false,
+            ContractSelectListSort::CreatedAt,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 3);
+    assert_eq!(names(contracts), vec!["gamma", "alpha"]);
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_last_page() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            2,
+            2,
+            None,
+            None,
+            false,
+            ContractSelectListSort::CreatedAt,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 3);
+    assert_eq!(names(contracts), vec!["alphabet"]);
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_offset_beyond_end() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            10,
+            10,
+            None,
+            None,
+            false,
+            ContractSelectListSort::CreatedAt,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 3);
+    assert!(contracts.is_empty());
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_zero_limit() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            0,
+            0,
+            None,
+            None,
+            false,
+            ContractSelectListSort::CreatedAt,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 3);
+    assert!(contracts.is_empty());
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_name_contains_sorted_by_name() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            10,
+            0,
+            Some("alpha".to_owned()),
+            None,
+            false,
+            ContractSelectListSort::Name,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 2);
+    assert_eq!(names(contracts), vec!["alpha", "alphabet"]);
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_owner() {
+    let client = database_with_contracts().await;
+
+    let (contracts, total_count) = client
+        .select_contracts_list(ContractSelectListInput::new(
+            10,
+            0,
+            None,
+            Some(Address::from_low_u64_be(OWNER_ADDRESS)),
+            false,
+            ContractSelectListSort::CreatedAt,
+        ))
+        .await
+        .expect("Database contracts selecting");
+
+    assert_eq!(total_count, 1);
+    assert_eq!(names(contracts), vec!["alpha"]);
+}
+
+async fn database_with_contracts() -> DatabaseClient {
+    let client = super::empty_database().await;
+
+    for (account_id, name, owner_address) in [
+        (1, "gamma", None),
+        (2, "alpha", Some(Address::from_low_u64_be(OWNER_ADDRESS))),
+        (3, "alphabet", None),
+    ]
+    .iter()
+    .copied()
+    {
+        super::insert_contract(
+            &client,
+            account_id,
+            name,
+            H256::from_low_u64_be(account_id as u64),
+            owner_address,
+        )
+        .await;
+    }
+
+    client
+}
+
+fn names(contracts: Vec<ContractSelectListOutput>) -> Vec<String> {
+    contracts
+        .into_iter()
+        .map(|contract| contract.name)
+        .collect()
+}
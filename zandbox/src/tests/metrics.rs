@@ -0,0 +1,133 @@
+//!
+//! The Zandbox metrics rendering tests.
+//!
+
+use std::time::Duration;
+
+use crate::shared_data::metrics::Metrics;
+use crate::shared_data::metrics::HANDLER_CALL;
+use crate::shared_data::metrics::HANDLER_QUERY;
+
+fn has_line(metrics: &str, expected: &str) -> bool {
+    metrics.lines().any(|line| line == expected)
+}
+
+#[test]
+fn ok_histogram_buckets_cumulative() {
+    let metrics = Metrics::default();
+    metrics.record_vm_execution(HANDLER_CALL, "test", "deposit", Duration::from_millis(3));
+    metrics.record_vm_execution(HANDLER_CALL, "test", "deposit", Duration::from_millis(200));
+    metrics.record_vm_execution(HANDLER_CALL, "test", "deposit", Duration::from_secs(120));
+
+    let output = metrics.to_prometheus();
+
+    let labels = "handler=\"call\",contract=\"test\",method=\"deposit\"";
+    assert!(has_line(
+        output.as_str(),
+        "# TYPE zandbox_vm_execution_seconds histogram"
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!(
+            "zandbox_vm_execution_seconds_bucket{{{},le=\"0.001\"}} 0",
+            labels
+        )
+        .as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!(
+            "zandbox_vm_execution_seconds_bucket{{{},le=\"0.005\"}} 1",
+            labels
+        )
+        .as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!(
+            "zandbox_vm_execution_seconds_bucket{{{},le=\"0.25\"}} 2",
+            labels
+        )
+        .as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!(
+            "zandbox_vm_execution_seconds_bucket{{{},le=\"60\"}} 2",
+            labels
+        )
+        .as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!(
+            "zandbox_vm_execution_seconds_bucket{{{},le=\"+Inf\"}} 3",
+            labels
+        )
+        .as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!("zandbox_vm_execution_seconds_sum{{{}}} 120.203", labels).as_str()
+    ));
+    assert!(has_line(
+        output.as_str(),
+        format!("zandbox_vm_execution_seconds_count{{{}}} 3", labels).as_str()
+    ));
+}
+
+#[test]
+fn ok_series_separated_by_labels() {
+    let metrics = Metrics::default();
+    metrics.record_error(HANDLER_CALL, "MethodNotFound");
+    metrics.record_error(HANDLER_CALL, "MethodNotFound");
+    metrics.record_error(HANDLER_QUERY, "MethodNotFound");
+
+    let output = metrics.to_prometheus();
+
+    assert!(has_line(
+        output.as_str(),
+        "zandbox_errors_total{handler=\"call\",error=\"MethodNotFound\"} 2"
+    ));
+    assert!(has_line(
+        output.as_str(),
+        "zandbox_errors_total{handler=\"query\",error=\"MethodNotFound\"} 1"
+    ));
+}
+
+#[test]
+fn ok_label_values_escaped() {
+    let metrics = Metrics::default();
+    metrics.record_vm_execution(
+        HANDLER_CALL,
+        "quoted \"name\"",
+        "back\\slash",
+        Duration::from_millis(1),
+    );
+
+    let output = metrics.to_prometheus();
+
+    assert!(has_line(
+        output.as_str(),
+        "zandbox_vm_execution_seconds_count{handler=\"call\",contract=\"quoted \\\"name\\\"\",method=\"back\\\\slash\"} 1"
+    ));
+}
+
+#[test]
+fn ok_nonce_counters_rendered() {
+    let metrics = Metrics::default();
+    metrics.record_nonce_lock_wait(Duration::from_millis(500));
+    metrics.record_nonce_retry();
+
+    let output = metrics.to_prometheus();
+
+    assert!(has_line(
+        output.as_str(),
+        "zandbox_nonce_lock_acquisitions_total 1"
+    ));
+    assert!(has_line(
+        output.as_str(),
+        "zandbox_nonce_lock_wait_seconds_total 0.5"
+    ));
+    assert!(has_line(output.as_str(), "zandbox_nonce_retries_total 1"));
+}
@@ -0,0 +1,276 @@
+//!
+//! The Zandbox contract storage migration tests.
+//!
+
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::H256;
+use zksync_types::AccountId;
+
+use zinc_build::ContractFieldType;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+use zinc_build::Value as BuildValue;
+
+use crate::database::client::Client as DatabaseClient;
+use crate::database::model::field::select::Input as FieldSelectInput;
+use crate::migration::conflict::Conflict;
+use crate::migration::Migration;
+use crate::storage::Storage;
+
+const ACCOUNT_ID: AccountId = 42;
+
+fn field(name: &str, r#type: BuildType) -> ContractFieldType {
+    ContractFieldType::new(name.to_owned(), r#type, true, false)
+}
+
+fn integer(bitlength: usize) -> BuildType {
+    BuildType::Scalar(ScalarType::Integer(IntegerType::new(false, bitlength)))
+}
+
+fn layout(fields: Vec<ContractFieldType>) -> Vec<ContractFieldType> {
+    let mut layout = vec![
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_ADDRESS.to_owned(),
+            BuildType::new_eth_address_integer(),
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_BALANCES.to_owned(),
+            BuildType::Map {
+                key_type: Box::new(BuildType::new_eth_address_integer()),
+                value_type: Box::new(integer(248)),
+            },
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            integer(248),
+            true,
+            true,
+        ),
+    ];
+    layout.extend(fields);
+    layout
+}
+
+fn storage(types: &[ContractFieldType], values: Vec<JsonValue>) -> Storage {
+    let mut storage = Storage::new(types);
+    for ((field, r#type), value) in storage
+        .fields
+        .iter_mut()
+        .zip(types.iter())
+        .skip(zinc_const::contract::IMPLICIT_FIELDS_COUNT)
+        .zip(values)
+    {
+        field.value = BuildValue::try_from_typed_json(value, r#type.r#type.to_owned())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+    storage
+}
+
+async fn database_with_storage(storage: Storage) -> DatabaseClient {
+    let client = super::empty_database().await;
+    super::insert_contract(
+        &client,
+        ACCOUNT_ID,
+        "test",
+        H256::from_low_u64_be(ACCOUNT_ID as u64),
+        None,
+    )
+    .await;
+    client
+        .insert_fields(storage.into_database_insert(ACCOUNT_ID))
+        .await
+        .expect("Database contract storage inserting");
+
+    client
+}
+
+async fn migrate_database(
+    client: &DatabaseClient,
+    migration: &Migration,
+) -> Vec<(String, JsonValue)> {
+    client
+        .rename_fields(migration.to_database_rename(ACCOUNT_ID))
+        .await
+        .expect("Database contract storage renaming");
+    client
+        .insert_fields(migration.to_database_insert(ACCOUNT_ID))
+        .await
+        .expect("Database contract storage appending");
+
+    client
+        .select_fields(FieldSelectInput::new(ACCOUNT_ID))
+        .await
+        .expect("Database contract storage selecting")
+        .into_iter()
+        .map(|field| (field.name, field.value))
+        .collect()
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_append() {
+    let old_types = layout(vec![
+        field("owner", integer(160)),
+        field("total", integer(64)),
+    ]);
+    let new_types = layout(vec![
+        field("owner", integer(160)),
+        field("total", integer(64)),
+        field("fee", integer(8)),
+    ]);
+    let old_storage = storage(old_types.as_slice(), vec![json!("0x2a"), json!("1000")]);
+
+    let client = database_with_storage(old_storage.clone()).await;
+
+    let migration = Migration::new(
+        old_types.as_slice(),
+        old_storage,
+        new_types.as_slice(),
+        &HashMap::new(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(migration.appended, vec![5]);
+    assert!(migration.renamed.is_empty());
+
+    let fields = migrate_database(&client, &migration).await;
+    assert_eq!(
+        fields,
+        vec![
+            ("layout_hash".to_owned(), json!("0")),
+            ("owner".to_owned(), json!("0x2a")),
+            ("total".to_owned(), json!("1000")),
+            ("fee".to_owned(), json!("0")),
+        ]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "integration-tests"), ignore)]
+async fn ok_rename() {
+    let old_types = layout(vec![
+        field("owner", integer(160)),
+        field("total", integer(64)),
+    ]);
+    let new_types = layout(vec![
+        field("admin", integer(160)),
+        field("total", integer(64)),
+    ]);
+    let old_storage = storage(old_types.as_slice(), vec![json!("0x2a"), json!("1000")]);
+
+    let client = database_with_storage(old_storage.clone()).await;
+
+    let mut renames = HashMap::new();
+    renames.insert("owner".to_owned(), "admin".to_owned());
+    let migration = Migration::new(
+        old_types.as_slice(),
+        old_storage,
+        new_types.as_slice(),
+        &renames,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(migration.renamed, vec![3]);
+    assert!(migration.appended.is_empty());
+
+    let fields = migrate_database(&client, &migration).await;
+    assert_eq!(
+        fields,
+        vec![
+            ("layout_hash".to_owned(), json!("0")),
+            ("admin".to_owned(), json!("0x2a")),
+            ("total".to_owned(), json!("1000")),
+        ]
+    );
+}
+
+#[test]
+fn error_type_changed_and_removed() {
+    let old_types = layout(vec![
+        field("owner", integer(160)),
+        field("total", integer(64)),
+    ]);
+    let new_types = layout(vec![field("owner", integer(248))]);
+    let old_storage = storage(old_types.as_slice(), vec![json!("0x2a"), json!("1000")]);
+
+    let result = Migration::new(
+        old_types.as_slice(),
+        old_storage,
+        new_types.as_slice(),
+        &HashMap::new(),
+    );
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(vec![
+            Conflict::TypeChanged {
+                name: "owner".to_owned(),
+                expected: integer(160).to_string(),
+                found: integer(248).to_string(),
+            },
+            Conflict::Removed {
+                name: "total".to_owned(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn error_moved() {
+    let old_types = layout(vec![
+        field("owner", integer(160)),
+        field("total", integer(64)),
+    ]);
+    let new_types = layout(vec![
+        field("owner", integer(160)),
+        field("fee", integer(8)),
+        field("total", integer(64)),
+    ]);
+    let old_storage = storage(old_types.as_slice(), vec![json!("0x2a"), json!("1000")]);
+
+    let result = Migration::new(
+        old_types.as_slice(),
+        old_storage,
+        new_types.as_slice(),
+        &HashMap::new(),
+    );
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(vec![Conflict::Moved {
+            name: "total".to_owned(),
+            expected: 4,
+            found: 5,
+        }])
+    );
+}
+
+#[test]
+fn error_rename_source_not_found() {
+    let old_types = layout(vec![field("owner", integer(160))]);
+    let new_types = layout(vec![field("owner", integer(160))]);
+    let old_storage = storage(old_types.as_slice(), vec![json!("0x2a")]);
+
+    let mut renames = HashMap::new();
+    renames.insert("admin".to_owned(), "owner".to_owned());
+    let result = Migration::new(
+        old_types.as_slice(),
+        old_storage,
+        new_types.as_slice(),
+        &renames,
+    );
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(vec![Conflict::RenameSourceNotFound {
+            name: "admin".to_owned(),
+        }])
+    );
+}
@@ -9,4 +9,235 @@
 //! `zargo`
 //!
 
+mod archive;
+mod auth;
+mod call;
 mod curve;
+mod job;
+mod key;
+mod list;
+mod metrics;
+mod migration;
+mod nonce;
+mod payload;
+mod sizing;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use actix_web::web;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::AccountId;
+
+use zinc_build::Contract as BuildContract;
+use zinc_build::ContractFieldType;
+use zinc_build::Exit;
+use zinc_zksync::Credentials;
+
+use crate::auth::Authorization;
+use crate::database::client::Client as DatabaseClient;
+use crate::database::model::contract::insert_new::Input as ContractInsertNewInput;
+use crate::gateway::mock::Gateway as MockGateway;
+use crate::settings::Settings;
+use crate::shared_data::contract::Contract as SharedDataContract;
+use crate::shared_data::SharedData;
+use crate::storage::Storage;
+
+/// The PostgreSQL database used by the integration tests.
+static POSTGRESQL_URL: &str = "postgres://postgres@localhost/zinc";
+
+///
+/// The private key of the contract owner.
+///
+fn owner_private_key() -> H256 {
+    H256::from_low_u64_be(1)
+}
+
+///
+/// The address of the contract owner.
+///
+fn owner_address() -> Address {
+    PackedEthSignature::address_from_private_key(&owner_private_key())
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+///
+/// The address of the contract with `account_id`.
+///
+fn contract_address(account_id: AccountId) -> Address {
+    Address::from_low_u64_be(account_id as u64)
+}
+
+///
+/// Signs a nonce issued by the application with `private_key`.
+///
+fn authorization(
+    app_data: &web::Data<Arc<RwLock<SharedData>>>,
+    private_key: &H256,
+) -> Authorization {
+    let nonce = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .auth_nonces
+        .issue();
+    let credentials =
+        Credentials::sign(nonce, private_key).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    Authorization(Some(credentials))
+}
+
+///
+/// Connects to the test database.
+///
+async fn database() -> DatabaseClient {
+    DatabaseClient::new(POSTGRESQL_URL)
+        .await
+        .expect("Database client initialization")
+}
+
+///
+/// Connects to the test database and deletes all contracts along with their storage.
+///
+async fn empty_database() -> DatabaseClient {
+    let client = database().await;
+    client
+        .delete_fields()
+        .await
+        .expect("Database contract storage deleting");
+    client
+        .delete_contracts()
+        .await
+        .expect("Database contracts deleting");
+    client
+}
+
+///
+/// Inserts the contract with `account_id` and `name` into the `client` database.
+///
+async fn insert_contract(
+    client: &DatabaseClient,
+    account_id: AccountId,
+    name: &str,
+    eth_private_key: H256,
+    owner_address: Option<Address>,
+) {
+    client
+        .insert_contract(ContractInsertNewInput::new(
+            account_id,
+            name.to_owned(),
+            "0.1.0".to_owned(),
+            "default".to_owned(),
+            env!("CARGO_PKG_VERSION").to_owned(),
+            JsonValue::Null,
+            vec![],
+            vec![],
+            json!({}),
+            json!([]),
+            None,
+            json!({}),
+            contract_address(account_id),
+            eth_private_key,
+            owner_address,
+        ))
+        .await
+        .expect("Database contract inserting");
+}
+
+///
+/// Serves the `contracts` from the `client` database with the test settings.
+///
+/// If `gateway` is not set, the real zkSync gateway is used.
+///
+fn app_data(
+    client: DatabaseClient,
+    contracts: HashMap<Address, SharedDataContract>,
+    gateway: Option<MockGateway>,
+) -> web::Data<Arc<RwLock<SharedData>>> {
+    let mut shared_data = SharedData::new(
+        client,
+        contracts,
+        HashMap::new(),
+        Settings::new(None, None, None, true, None),
+    );
+    if let Some(gateway) = gateway {
+        shared_data.gateway = Arc::new(gateway);
+    }
+    web::Data::new(shared_data.wrap())
+}
+
+///
+/// The contract build without methods with the `storage` fields.
+///
+fn build(storage: Vec<ContractFieldType>) -> BuildContract {
+    BuildContract::new(
+        "test".to_owned(),
+        storage,
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::new(),
+        vec![Exit::new(0).into()],
+    )
+}
+
+///
+/// Resets the database and serves a single owned contract of the `build`.
+///
+/// If `is_layout_hash_set` is true, the build layout hash is written to the contract storage.
+///
+async fn shared_data(
+    account_id: AccountId,
+    build: BuildContract,
+    eth_private_key: H256,
+    is_layout_hash_set: bool,
+    gateway: MockGateway,
+) -> web::Data<Arc<RwLock<SharedData>>> {
+    let mut storage = Storage::new(build.storage.as_slice());
+    if is_layout_hash_set {
+        storage.set_layout_hash(build.layout_hash.to_owned());
+    }
+
+    let client = empty_database().await;
+    insert_contract(
+        &client,
+        account_id,
+        "test",
+        eth_private_key,
+        Some(owner_address()),
+    )
+    .await;
+    client
+        .insert_fields(storage.clone().into_database_insert(account_id))
+        .await
+        .expect("Database contract storage inserting");
+
+    let mut contracts = HashMap::with_capacity(1);
+    contracts.insert(
+        contract_address(account_id),
+        SharedDataContract::new(
+            contract_address(account_id),
+            "test".to_owned(),
+            "0.1.0".to_owned(),
+            "default".to_owned(),
+            JsonValue::Null,
+            vec![],
+            vec![],
+            HashMap::new(),
+            vec![],
+            None,
+            HashMap::new(),
+            Some(account_id),
+            Some(owner_address()),
+            eth_private_key,
+            build,
+            storage,
+        ),
+    );
+
+    app_data(client, contracts, Some(gateway))
+}
@@ -0,0 +1,117 @@
+//!
+//! The Zandbox contract nonce locking tests.
+//!
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering;
+
+use async_std::sync::Mutex as AsyncMutex;
+use futures::future::LocalBoxFuture;
+
+use zksync_types::tx::TxHash;
+use zksync_types::Nonce;
+
+use crate::controller::contract::call::batch;
+use crate::controller::contract::call::batch::IBatchSender;
+use crate::controller::contract::call::error::Error;
+use crate::shared_data::metrics::Metrics;
+
+///
+/// The zkSync account mock, which accepts a batch only with the current committed nonce.
+///
+#[derive(Default)]
+struct MockSender {
+    /// The committed nonce of the contract account.
+    committed: Cell<Nonce>,
+    /// The number of transactions sent to the account by other parties before the next batch.
+    external: Cell<usize>,
+    /// The nonces of the accepted batches.
+    accepted: RefCell<Vec<Nonce>>,
+}
+
+impl MockSender {
+    fn new(external: usize) -> Self {
+        Self {
+            external: Cell::new(external),
+            ..Self::default()
+        }
+    }
+}
+
+impl IBatchSender for MockSender {
+    fn committed_nonce(&self) -> LocalBoxFuture<'_, Result<Nonce, Error>> {
+        Box::pin(async move {
+            let nonce = self.committed.get();
+            async_std::task::yield_now().await;
+            Ok(nonce)
+        })
+    }
+
+    fn send(&self, nonce: Nonce) -> LocalBoxFuture<'_, Result<Vec<TxHash>, Error>> {
+        Box::pin(async move {
+            async_std::task::yield_now().await;
+
+            if self.external.get() > 0 {
+                self.external.set(self.external.get() - 1);
+                self.committed.set(self.committed.get() + 1);
+            }
+
+            if nonce != self.committed.get() {
+                return Err(Error::NonceMismatch(format!(
+                    "expected {}, found {}",
+                    self.committed.get(),
+                    nonce
+                )));
+            }
+
+            self.accepted.borrow_mut().push(nonce);
+            self.committed.set(nonce + 1);
+            Ok(vec![])
+        })
+    }
+}
+
+#[actix_rt::test]
+async fn ok_concurrent_calls_serialized() {
+    let sender = MockSender::new(0);
+    let lock = AsyncMutex::new(());
+    let metrics = Metrics::default();
+
+    let (result_1, result_2) = futures::join!(
+        batch::send_locked(&sender, &lock, &metrics),
+        batch::send_locked(&sender, &lock, &metrics),
+    );
+
+    assert!(result_1.is_ok());
+    assert!(result_2.is_ok());
+    assert_eq!(sender.accepted.into_inner(), vec![0, 1]);
+    assert_eq!(metrics.nonce_lock_acquisitions.load(Ordering::Relaxed), 2);
+    assert_eq!(metrics.nonce_retries.load(Ordering::Relaxed), 0);
+}
+
+#[actix_rt::test]
+async fn ok_nonce_mismatch_retried() {
+    let sender = MockSender::new(1);
+    let lock = AsyncMutex::new(());
+    let metrics = Metrics::default();
+
+    let result = batch::send_locked(&sender, &lock, &metrics).await;
+
+    assert!(result.is_ok());
+    assert_eq!(sender.accepted.into_inner(), vec![1]);
+    assert_eq!(metrics.nonce_retries.load(Ordering::Relaxed), 1);
+}
+
+#[actix_rt::test]
+async fn error_nonce_mismatch_retried_once() {
+    let sender = MockSender::new(2);
+    let lock = AsyncMutex::new(());
+    let metrics = Metrics::default();
+
+    let result = batch::send_locked(&sender, &lock, &metrics).await;
+
+    assert!(matches!(result, Err(Error::NonceMismatch(_))));
+    assert!(sender.accepted.into_inner().is_empty());
+    assert_eq!(metrics.nonce_retries.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,60 @@
+//!
+//! The Zandbox request body limits tests.
+//!
+
+use actix_web::http::header;
+use actix_web::http::StatusCode;
+use actix_web::test::TestRequest;
+use actix_web::web;
+use actix_web::FromRequest;
+use serde_json::Value as JsonValue;
+
+const LIMIT: usize = 1024;
+
+async fn extract(body: String) -> Result<JsonValue, StatusCode> {
+    let (request, mut payload) = TestRequest::default()
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::CONTENT_LENGTH, body.len().to_string())
+        .app_data(crate::controller::json_config(LIMIT))
+        .set_payload(body)
+        .to_http_parts();
+
+    web::Json::<JsonValue>::from_request(&request, &mut payload)
+        .await
+        .map(|json| json.into_inner())
+        .map_err(|error| error.as_response_error().status_code())
+}
+
+#[actix_rt::test]
+async fn ok_payload_within_limit() {
+    let body = format!(
+        "{{ \"arguments\": {{ \"value\": \"{}\" }} }}",
+        "1".repeat(64)
+    );
+
+    assert!(extract(body).await.is_ok());
+}
+
+#[actix_rt::test]
+async fn error_payload_too_large() {
+    let body = format!(
+        "{{ \"arguments\": {{ \"value\": \"{}\" }} }}",
+        "1".repeat(LIMIT)
+    );
+
+    assert_eq!(extract(body).await, Err(StatusCode::PAYLOAD_TOO_LARGE));
+}
+
+#[actix_rt::test]
+async fn error_payload_nested_too_deep() {
+    let body = format!("{}{}", "[".repeat(LIMIT / 2), "]".repeat(LIMIT / 2));
+
+    assert_eq!(extract(body).await, Err(StatusCode::BAD_REQUEST));
+}
+
+#[actix_rt::test]
+async fn error_payload_malformed() {
+    let body = "{ \"arguments\": ".to_owned();
+
+    assert_eq!(extract(body).await, Err(StatusCode::BAD_REQUEST));
+}
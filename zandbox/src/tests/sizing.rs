@@ -0,0 +1,143 @@
+//!
+//! The Zandbox contract method sizing tests.
+//!
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use zinc_build::Contract as BuildContract;
+use zinc_build::ContractFieldType;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::Exit;
+use zinc_build::IntegerType;
+use zinc_build::Load;
+use zinc_build::Require;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use crate::sizing::cost::Cost as MethodCost;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+///
+/// The contract with the `get` method, which returns its argument, and the `check` method,
+/// which requires its boolean argument to be `true`.
+///
+fn build() -> BuildContract {
+    let balance_type = BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+    let address = zinc_const::contract::TRANSACTION_SIZE + zinc_const::contract::ENVIRONMENT_SIZE;
+
+    let get = BuildContractMethod::new(
+        0,
+        "get".to_owned(),
+        0,
+        false,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![("value".to_owned(), balance_type.clone())]),
+        balance_type.clone(),
+    );
+    let check = BuildContractMethod::new(
+        1,
+        "check".to_owned(),
+        2,
+        false,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![(
+            "flag".to_owned(),
+            BuildType::Scalar(ScalarType::Boolean),
+        )]),
+        BuildType::Unit,
+    );
+    let mut methods = HashMap::with_capacity(2);
+    methods.insert(get.name.clone(), get);
+    methods.insert(check.name.clone(), check);
+
+    BuildContract::new(
+        "test".to_owned(),
+        vec![ContractFieldType::new(
+            "balance".to_owned(),
+            balance_type,
+            true,
+            false,
+        )],
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![
+            Load::new(address, 1).into(),
+            Exit::new(1).into(),
+            Load::new(address, 1).into(),
+            Require::new(None, vec![]).into(),
+            Exit::new(0).into(),
+        ],
+    )
+}
+
+#[actix_rt::test]
+async fn ok_known() {
+    let costs = crate::sizing::measure(&build(), TIMEOUT).await;
+
+    match costs.get("get") {
+        Some(MethodCost::Known {
+            constraints,
+            witness,
+        }) => {
+            assert!(*constraints > 0);
+            assert!(*witness > 0);
+        }
+        cost => panic!("the `get` method cost must be known, found {:?}", cost),
+    }
+}
+
+#[actix_rt::test]
+async fn ok_unknown_zero_argument_required() {
+    let costs = crate::sizing::measure(&build(), TIMEOUT).await;
+
+    assert_eq!(costs.get("check"), Some(&MethodCost::Unknown));
+    assert_eq!(costs.len(), 2);
+}
+
+#[actix_rt::test]
+async fn ok_unknown_timeout_expired() {
+    let costs = crate::sizing::measure(&build(), Duration::from_secs(0)).await;
+
+    assert_eq!(costs.get("get"), Some(&MethodCost::Unknown));
+    assert_eq!(costs.get("check"), Some(&MethodCost::Unknown));
+}
+
+#[test]
+fn ok_json_representation() {
+    let costs = vec![
+        (
+            "get".to_owned(),
+            MethodCost::Known {
+                constraints: 3,
+                witness: 2,
+            },
+        ),
+        ("check".to_owned(), MethodCost::Unknown),
+    ]
+    .into_iter()
+    .collect::<HashMap<String, MethodCost>>();
+
+    let json = serde_json::to_value(&costs).expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "get": { "status": "known", "constraints": 3, "witness": 2 },
+            "check": { "status": "unknown" },
+        })
+    );
+
+    let parsed: HashMap<String, MethodCost> =
+        serde_json::from_value(json).expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(parsed, costs);
+}
@@ -28,6 +28,26 @@ pub struct Arguments {
     /// The zkSync network identifier.
     #[structopt(short = "n", long = "network")]
     pub network: String,
+
+    /// The contract publishing request body size limit in bytes.
+    #[structopt(long = "publish-payload-limit")]
+    pub publish_payload_limit: Option<usize>,
+
+    /// The contract method request body size limit in bytes.
+    #[structopt(long = "method-payload-limit")]
+    pub method_payload_limit: Option<usize>,
+
+    /// The contract method arguments JSON nesting depth limit.
+    #[structopt(long = "json-depth-limit")]
+    pub json_depth_limit: Option<usize>,
+
+    /// Disables redacting the witness values from the virtual machine logs and errors.
+    #[structopt(long = "no-privacy")]
+    pub no_privacy: bool,
+
+    /// The contract methods sizing run time limit at publishing in milliseconds.
+    #[structopt(long = "sizing-timeout")]
+    pub sizing_timeout: Option<u64>,
 }
 
 impl Arguments {
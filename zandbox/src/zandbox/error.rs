@@ -6,6 +6,8 @@ use std::io;
 
 use failure::Fail;
 
+use zandbox::ContractStorageError;
+
 #[derive(Debug, Fail)]
 pub enum Error {
     #[fail(display = "Invalid network: {}", _0)]
@@ -14,6 +16,8 @@ pub enum Error {
     Database(sqlx::Error),
     #[fail(display = "ZkSync client: {}", _0)]
     ZkSyncClient(zksync::error::ClientError),
+    #[fail(display = "contract storage: {}", _0)]
+    ContractStorage(ContractStorageError),
     #[fail(display = "server binding: {}", _0)]
     ServerBinding(io::Error),
     #[fail(display = "server runtime: {}", _0)]
@@ -31,3 +35,9 @@ impl From<zksync::error::ClientError> for Error {
         Self::ZkSyncClient(inner)
     }
 }
+
+impl From<ContractStorageError> for Error {
+    fn from(inner: ContractStorageError) -> Self {
+        Self::ContractStorage(inner)
+    }
+}
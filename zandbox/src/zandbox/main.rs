@@ -7,9 +7,9 @@ mod error;
 
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Duration;
 
 use actix_web::middleware;
-use actix_web::web;
 use actix_web::App;
 use actix_web::HttpServer;
 use colored::Colorize;
@@ -25,6 +25,7 @@ use zandbox::ContractSelectAllOutput;
 use zandbox::ContractStorage;
 use zandbox::DatabaseClient;
 use zandbox::FieldSelectInput;
+use zandbox::Settings;
 use zandbox::SharedData;
 use zandbox::SharedDataContract;
 
@@ -48,6 +49,9 @@ async fn main() -> Result<(), Error> {
     log::info!("Initializing the PostgreSQL client{:?}", network);
     let postgresql = DatabaseClient::new(args.postgresql_uri.as_str()).await?;
 
+    log::info!("Failing the publishing jobs interrupted by the previous shutdown");
+    postgresql.update_jobs_interrupted().await?;
+
     log::info!("Loading the compiled contracts from the database");
     let database_data: Vec<ContractSelectAllOutput> = postgresql
         .select_contracts()
@@ -56,13 +60,18 @@ async fn main() -> Result<(), Error> {
         .collect();
 
     let mut contracts = HashMap::with_capacity(database_data.len());
+    let mut archived_contracts = HashMap::new();
     for contract in database_data.into_iter() {
         let eth_address = zinc_zksync::eth_address_from_vec(contract.eth_address);
         let eth_private_key = zinc_zksync::eth_private_key_from_vec(contract.eth_private_key);
 
         log::info!(
             "{} instance `{}` of the contract `{} v{}` with address {}",
-            "Loaded".bright_green(),
+            if contract.is_archived {
+                "Archived".bright_yellow()
+            } else {
+                "Loaded".bright_green()
+            },
             contract.instance,
             contract.name,
             contract.version,
@@ -100,7 +109,12 @@ async fn main() -> Result<(), Error> {
         )
         .await?;
 
-        contracts.insert(
+        let cache = if contract.is_archived {
+            &mut archived_contracts
+        } else {
+            &mut contracts
+        };
+        cache.insert(
             eth_address,
             SharedDataContract::new(
                 eth_address,
@@ -110,7 +124,16 @@ async fn main() -> Result<(), Error> {
                 contract.source_code,
                 contract.bytecode,
                 contract.verifying_key,
+                SharedDataContract::verifying_keys_from_json(contract.verifying_keys),
+                serde_json::from_value(contract.allowed_tokens)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+                contract.abi,
+                serde_json::from_value(contract.method_costs)
+                    .expect(zinc_const::panic::DATA_CONVERSION),
                 Some(contract.account_id as AccountId),
+                contract
+                    .owner_address
+                    .map(zinc_zksync::eth_address_from_vec),
                 eth_private_key,
                 build,
                 storage,
@@ -118,16 +141,22 @@ async fn main() -> Result<(), Error> {
         );
     }
 
-    let data = SharedData::new(postgresql, contracts).wrap();
+    let settings = Settings::new(
+        args.publish_payload_limit,
+        args.method_payload_limit,
+        args.json_depth_limit,
+        !args.no_privacy,
+        args.sizing_timeout.map(Duration::from_millis),
+    );
+    let data = SharedData::new(postgresql, contracts, archived_contracts, settings).wrap();
 
     HttpServer::new(move || {
         App::new()
             .wrap(middleware::Logger::default())
             .wrap(middleware::DefaultHeaders::new().content_type())
             .wrap(actix_cors::Cors::default())
-            .app_data(web::JsonConfig::default().limit(zinc_const::limit::JSON_PAYLOAD))
             .data(data.clone())
-            .configure(zandbox::configure)
+            .configure(|config| zandbox::configure(settings, config))
     })
     .bind(format!(
         "{}:{}",
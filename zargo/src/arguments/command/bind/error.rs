@@ -0,0 +1,49 @@
+//!
+//! The Zargo package manager `bind` subcommand error.
+//!
+
+use failure::Fail;
+
+use crate::error::directory::Error as DirectoryError;
+use crate::error::file::Error as FileError;
+use crate::executable::compiler::Error as CompilerError;
+
+///
+/// The Zargo package manager `bind` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The project is not a contract.
+    #[fail(display = "not a contract")]
+    NotAContract,
+    /// The bindings language is not specified.
+    #[fail(display = "bindings language must be specified, e.g. `--typescript`")]
+    LanguageMissing,
+    /// The project binary build directory error.
+    #[fail(display = "build directory {}", _0)]
+    BuildDirectory(DirectoryError),
+    /// The project template, keys, and other auxiliary data directory error.
+    #[fail(display = "data directory {}", _0)]
+    DataDirectory(DirectoryError),
+    /// The private key file generation error.
+    #[fail(display = "private key file {}", _0)]
+    PrivateKeyFile(FileError),
+    /// The compiler process error.
+    #[fail(display = "compiler {}", _0)]
+    Compiler(CompilerError),
+    /// The bytecode binary file error.
+    #[fail(display = "bytecode binary file {}", _0)]
+    BinaryFile(FileError),
+    /// The bytecode binary file contents error.
+    #[fail(display = "invalid bytecode: {}", _0)]
+    InvalidBytecode(String),
+    /// The bindings output directory error.
+    #[fail(display = "output directory {}", _0)]
+    OutputDirectory(DirectoryError),
+    /// The bindings output file error.
+    #[fail(display = "output file {}", _0)]
+    OutputFile(FileError),
+}
@@ -0,0 +1,154 @@
+//!
+//! The Zargo package manager `bind` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zinc_build::Application as BuildApplication;
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::bindings::typescript::Module as TypeScriptModule;
+use crate::error::directory::Error as DirectoryError;
+use crate::error::file::Error as FileError;
+use crate::executable::compiler::Compiler;
+use crate::project::build::bytecode::Bytecode as BytecodeFile;
+use crate::project::build::Directory as BuildDirectory;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::source::Directory as SourceDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `bind` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Generates the contract client bindings")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Generates the TypeScript bindings.
+    #[structopt(long = "typescript")]
+    pub is_typescript: bool,
+
+    /// The directory where the bindings are written to.
+    #[structopt(long = "output", parse(from_os_str), default_value = "./bindings")]
+    pub output_path: PathBuf,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> Result<(), Error> {
+        if !self.is_typescript {
+            return Err(Error::LanguageMissing);
+        }
+
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        match manifest.project.r#type {
+            ProjectType::Contract => {}
+            _ => return Err(Error::NotAContract),
+        }
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let source_directory_path = SourceDirectory::path(&manifest_path);
+
+        DataDirectory::create(&manifest_path).map_err(Error::DataDirectory)?;
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        if !PrivateKeyFile::exists_at(&data_directory_path) {
+            PrivateKeyFile::default()
+                .write_to(&data_directory_path)
+                .map_err(Error::PrivateKeyFile)?;
+        }
+
+        BuildDirectory::create(&manifest_path).map_err(Error::BuildDirectory)?;
+        let build_directory_path = BuildDirectory::path(&manifest_path);
+        let mut binary_path = build_directory_path;
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        let libraries =
+            Compiler::build_dependencies(self.verbosity, &manifest, &manifest_path, true)
+                .map_err(Error::Compiler)?;
+
+        Compiler::build_release(
+            self.verbosity,
+            manifest.project.name.as_str(),
+            manifest.project.version.as_str(),
+            &manifest_path,
+            &data_directory_path,
+            &source_directory_path,
+            &binary_path,
+            &libraries,
+            false,
+            false,
+            None,
+            false,
+        )
+        .map_err(Error::Compiler)?;
+
+        let bytecode = BytecodeFile::try_from(&binary_path).map_err(Error::BinaryFile)?;
+        let contract = match BuildApplication::try_from_slice(bytecode.inner.as_slice())
+            .map_err(Error::InvalidBytecode)?
+        {
+            BuildApplication::Contract(contract) => contract,
+            BuildApplication::Circuit(_circuit) => return Err(Error::NotAContract),
+        };
+
+        let module = TypeScriptModule::new(&contract);
+
+        fs::create_dir_all(&self.output_path)
+            .map_err(|error| Error::OutputDirectory(DirectoryError::Creating(error)))?;
+        let file_name = format!(
+            "{}.{}",
+            manifest.project.name,
+            zinc_const::extension::TYPESCRIPT
+        );
+        let mut output_path = self.output_path;
+        output_path.push(file_name.as_str());
+
+        let mut file = File::create(&output_path)
+            .map_err(|error| Error::OutputFile(FileError::Creating(file_name.clone(), error)))?;
+        file.write_all(module.inner.as_bytes())
+            .map_err(|error| Error::OutputFile(FileError::Writing(file_name, error)))?;
+
+        eprintln!(
+            "   {} the TypeScript bindings of `{} v{}` to {:?}",
+            "Generated".bright_green(),
+            manifest.project.name,
+            manifest.project.version,
+            output_path,
+        );
+
+        Ok(())
+    }
+}
@@ -28,4 +28,10 @@ pub enum Error {
     /// The compiler process error.
     #[fail(display = "compiler {}", _0)]
     Compiler(CompilerError),
+    /// The bytecode binary file error.
+    #[fail(display = "bytecode binary file {}", _0)]
+    BinaryFile(FileError),
+    /// The incremental build cache file error.
+    #[fail(display = "build cache file {}", _0)]
+    BuildCacheFile(FileError),
 }
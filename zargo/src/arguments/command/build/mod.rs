@@ -7,12 +7,15 @@ pub mod error;
 use std::convert::TryFrom;
 use std::path::PathBuf;
 
+use colored::Colorize;
 use structopt::StructOpt;
 
 use zinc_manifest::Manifest;
 use zinc_manifest::ProjectType;
 
 use crate::executable::compiler::Compiler;
+use crate::project::build::bytecode::Bytecode as BytecodeFile;
+use crate::project::build::cache::Cache as BuildCacheFile;
 use crate::project::build::Directory as BuildDirectory;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
@@ -41,6 +44,23 @@ pub struct Command {
     /// Builds the release version.
     #[structopt(long = "release")]
     pub is_release: bool,
+
+    /// Fails the build if the compiler emits any warnings.
+    #[structopt(long = "deny-warnings")]
+    pub is_deny_warnings: bool,
+
+    /// Rebuilds the project even if no source files have changed since the last build.
+    #[structopt(long = "force")]
+    pub is_force: bool,
+
+    /// Writes the generator IR (`ir`), the bytecode disassembly (`bytecode`), or `both`
+    /// listings to the build directory. Implies `--force`.
+    #[structopt(long = "emit")]
+    pub emit: Option<String>,
+
+    /// Prints the per-module compilation phase timings. Implies `--force`.
+    #[structopt(long = "timings")]
+    pub is_timings: bool,
 }
 
 impl Command {
@@ -62,12 +82,19 @@ impl Command {
 
         BuildDirectory::create(&manifest_path).map_err(Error::BuildDirectory)?;
         let build_directory_path = BuildDirectory::path(&manifest_path);
-        let mut binary_path = build_directory_path;
-        binary_path.push(format!(
-            "{}.{}",
-            zinc_const::file_name::BINARY,
-            zinc_const::extension::BINARY
-        ));
+        let mut binary_path = build_directory_path.clone();
+        binary_path.push(match manifest.project.r#type {
+            ProjectType::Library => format!(
+                "{}.{}",
+                zinc_const::file_name::LIBRARY,
+                zinc_const::extension::LIBRARY
+            ),
+            _ => format!(
+                "{}.{}",
+                zinc_const::file_name::BINARY,
+                zinc_const::extension::BINARY
+            ),
+        });
 
         if let ProjectType::Contract = manifest.project.r#type {
             if !PrivateKeyFile::exists_at(&data_directory_path) {
@@ -77,6 +104,56 @@ impl Command {
             }
         }
 
+        let mut manifest_file_path = manifest_path.clone();
+        manifest_file_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        ));
+        let mut cache = BuildCacheFile::new(
+            &manifest_path,
+            &manifest_file_path,
+            &source_directory_path,
+            self.is_release,
+            self.is_deny_warnings,
+        )
+        .map_err(Error::BuildCacheFile)?;
+
+        // the library dependencies are not tracked by the cache, so they are always rebuilt
+        if !self.is_force
+            && self.emit.is_none()
+            && !self.is_timings
+            && manifest.dependencies.is_empty()
+        {
+            if let Ok(previous) = BuildCacheFile::try_from(&build_directory_path) {
+                let bytecode = BytecodeFile::try_from(&binary_path).ok();
+                if cache.is_fresh(
+                    &previous,
+                    bytecode.as_ref().map(|bytecode| bytecode.inner.as_slice()),
+                ) {
+                    eprintln!(
+                        "       {} {} v{}",
+                        "Fresh".bright_green(),
+                        manifest.project.name,
+                        manifest.project.version
+                    );
+                    return Ok(());
+                }
+
+                for path in cache.changed_files(&previous).into_iter() {
+                    eprintln!("     {} {}", "Changed".bright_green(), path);
+                }
+            }
+        }
+
+        let libraries = Compiler::build_dependencies(
+            self.verbosity,
+            &manifest,
+            &manifest_path,
+            self.is_release,
+        )
+        .map_err(Error::Compiler)?;
+
         if self.is_release {
             Compiler::build_release(
                 self.verbosity,
@@ -86,7 +163,11 @@ impl Command {
                 &data_directory_path,
                 &source_directory_path,
                 &binary_path,
+                &libraries,
                 false,
+                self.is_deny_warnings,
+                self.emit.as_deref(),
+                self.is_timings,
             )
             .map_err(Error::Compiler)?;
         } else {
@@ -98,11 +179,21 @@ impl Command {
                 &data_directory_path,
                 &source_directory_path,
                 &binary_path,
+                &libraries,
                 false,
+                self.is_deny_warnings,
+                self.emit.as_deref(),
+                self.is_timings,
             )
             .map_err(Error::Compiler)?;
         }
 
+        let bytecode = BytecodeFile::try_from(&binary_path).map_err(Error::BinaryFile)?;
+        cache.set_binary(bytecode.inner.as_slice());
+        cache
+            .write_to(&build_directory_path)
+            .map_err(Error::BuildCacheFile)?;
+
         Ok(())
     }
 }
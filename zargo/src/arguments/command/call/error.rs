@@ -5,6 +5,7 @@
 use failure::Fail;
 
 use crate::error::file::Error as FileError;
+use crate::project::network::error::Error as NetworkConfigError;
 use crate::transaction::error::Error as TransactionError;
 
 ///
@@ -14,16 +15,13 @@ use crate::transaction::error::Error as TransactionError;
 pub enum Error {
     /// The ETH address is invalid.
     #[fail(display = "invalid ETH address: {}", _0)]
-    InvalidContractAddress(rustc_hex::FromHexError),
-    /// The invalid network error.
-    #[fail(display = "invalid network name: {}", _0)]
-    NetworkInvalid(String),
-    /// The unimplemented network error.
-    #[fail(display = "unimplemented network: {}", _0)]
-    NetworkUnimplemented(zksync::Network),
+    InvalidContractAddress(zinc_math::EthAddressError),
     /// The manifest file error.
     #[fail(display = "manifest {}", _0)]
     Manifest(zinc_manifest::Error),
+    /// The network configuration error.
+    #[fail(display = "network configuration: {}", _0)]
+    Network(NetworkConfigError),
     /// The project is not a contract.
     #[fail(display = "not a contract")]
     NotAContract,
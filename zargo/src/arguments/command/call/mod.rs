@@ -6,7 +6,6 @@ pub mod error;
 
 use std::convert::TryFrom;
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use colored::Colorize;
 use reqwest::Client as HttpClient;
@@ -29,10 +28,10 @@ use zinc_zksync::FeeRequestQuery;
 use zinc_zksync::FeeResponseBody;
 use zinc_zksync::TransactionMsg;
 
-use crate::network::Network;
 use crate::project::data::input::Input as InputFile;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
 use crate::transaction::error::Error as TransactionError;
 
 use self::error::Error;
@@ -56,8 +55,13 @@ pub struct Command {
     pub manifest_path: PathBuf,
 
     /// Sets the network name, where the contract resides.
-    #[structopt(long = "network", default_value = "localhost")]
-    pub network: String,
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
+
+    /// Sets the Zandbox server URL, which overrides the default one of the network.
+    #[structopt(long = "zandbox-url")]
+    pub zandbox_url: Option<String>,
 
     /// Sets the ETH address of the contract.
     #[structopt(long = "address")]
@@ -77,20 +81,17 @@ impl Command {
     /// Executes the command.
     ///
     pub async fn execute(self) -> Result<(), Error> {
-        let address = self.address["0x".len()..]
-            .parse()
+        let address: Address = zinc_math::eth_address_from_str(self.address.as_str())
+            .map(Address::from)
             .map_err(Error::InvalidContractAddress)?;
 
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
-
-        let url = network
-            .try_into_url()
-            .map_err(Error::NetworkUnimplemented)?;
-
         let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
 
+        let config = NetworkConfig::new(&manifest, self.network, self.zandbox_url)
+            .map_err(Error::Network)?;
+        let network = config.network;
+        let url = config.zandbox_url().map_err(Error::Network)?;
+
         eprintln!(
             "     {} method `{}` of the contract `{} v{}` with address {} on network `{}`",
             "Calling".bright_green(),
@@ -123,16 +124,7 @@ impl Command {
 
         let input = InputFile::try_from_path(&input_path).map_err(Error::InputFile)?;
         let arguments = input
-            .inner
-            .as_object()
-            .ok_or(Error::InvalidInputData)?
-            .get("arguments")
-            .cloned()
-            .ok_or(Error::InvalidInputData)?
-            .as_object()
-            .ok_or(Error::InvalidInputData)?
-            .get(self.method.as_str())
-            .cloned()
+            .method_arguments(self.method.as_str())
             .ok_or(Error::InvalidInputData)?;
 
         let private_key =
@@ -210,16 +202,9 @@ impl Command {
             .await
             .map_err(Error::HttpRequest)?;
 
-        if !http_response.status().is_success() {
-            return Err(Error::ActionFailed(format!(
-                "HTTP error ({}) {}",
-                http_response.status(),
-                http_response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
-        }
+        let http_response = crate::response::check_status(http_response)
+            .await
+            .map_err(Error::ActionFailed)?;
         let response = http_response
             .json::<FeeResponseBody>()
             .await
@@ -269,16 +254,9 @@ impl Command {
                 .await
                 .map_err(Error::HttpRequest)?;
 
-            if !http_response.status().is_success() {
-                return Err(Error::ActionFailed(format!(
-                    "HTTP error ({}) {}",
-                    http_response.status(),
-                    http_response
-                        .text()
-                        .await
-                        .expect(zinc_const::panic::DATA_CONVERSION),
-                )));
-            }
+            let http_response = crate::response::check_status(http_response)
+                .await
+                .map_err(Error::ActionFailed)?;
             let response = http_response
                 .json::<FeeResponseBody>()
                 .await
@@ -317,26 +295,15 @@ impl Command {
             .await
             .map_err(Error::HttpRequest)?;
 
-        if !http_response.status().is_success() {
-            return Err(Error::ActionFailed(format!(
-                "HTTP error ({}) {}",
-                http_response.status(),
-                http_response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
-        }
+        let http_response = crate::response::check_status(http_response)
+            .await
+            .map_err(Error::ActionFailed)?;
 
-        println!(
-            "{}",
-            serde_json::to_string_pretty(
-                &http_response
-                    .json::<JsonValue>()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION)
-            )
-            .expect(zinc_const::panic::DATA_CONVERSION)
+        crate::response::print_json(
+            &http_response
+                .json::<JsonValue>()
+                .await
+                .expect(zinc_const::panic::DATA_CONVERSION),
         );
 
         Ok(())
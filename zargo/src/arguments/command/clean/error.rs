@@ -20,4 +20,7 @@ pub enum Error {
     /// The project template, keys, and other auxiliary data directory error.
     #[fail(display = "data directory {}", _0)]
     DataDirectory(DirectoryError),
+    /// The proving keys cache directory error.
+    #[fail(display = "keys cache directory {}", _0)]
+    KeysCacheDirectory(DirectoryError),
 }
@@ -5,12 +5,15 @@
 pub mod error;
 
 use std::convert::TryFrom;
+use std::env;
+use std::fs;
 use std::path::PathBuf;
 
 use structopt::StructOpt;
 
 use zinc_manifest::Manifest;
 
+use crate::error::directory::Error as DirectoryError;
 use crate::project::build::Directory as BuildDirectory;
 use crate::project::data::Directory as DataDirectory;
 
@@ -20,7 +23,7 @@ use self::error::Error;
 /// The Zargo package manager `clean` subcommand.
 ///
 #[derive(Debug, StructOpt)]
-#[structopt(about = "Removes the project build artifacts")]
+#[structopt(about = "Removes the project build artifacts and the incremental build cache")]
 pub struct Command {
     /// Prints more logs, if passed several times.
     #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
@@ -33,6 +36,15 @@ pub struct Command {
         default_value = "./Zargo.toml"
     )]
     pub manifest_path: PathBuf,
+
+    /// Removes all the project state, that is, the build artifacts, keys, and incremental build
+    /// cache. They are removed by default, so the flag only makes the request explicit.
+    #[structopt(long = "all")]
+    pub is_all: bool,
+
+    /// Also removes the proving keys cache in the home directory, which is shared by all projects.
+    #[structopt(long = "keys-cache")]
+    pub is_keys_cache: bool,
 }
 
 impl Command {
@@ -50,6 +62,19 @@ impl Command {
         DataDirectory::remove(&manifest_path).map_err(Error::DataDirectory)?;
         BuildDirectory::remove(&manifest_path).map_err(Error::BuildDirectory)?;
 
+        if self.is_keys_cache {
+            if let Some(home) = env::var_os("HOME") {
+                let mut keys_cache_path = PathBuf::from(home);
+                keys_cache_path.push(zinc_const::directory::KEYS_CACHE);
+
+                if keys_cache_path.exists() {
+                    fs::remove_dir_all(&keys_cache_path)
+                        .map_err(DirectoryError::Removing)
+                        .map_err(Error::KeysCacheDirectory)?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,46 @@
+//!
+//! The Zargo package manager `deposit` subcommand.
+//!
+
+use failure::Fail;
+
+use crate::error::file::Error as FileError;
+use crate::project::network::error::Error as NetworkConfigError;
+use crate::transaction::error::Error as TransactionError;
+
+///
+/// The Zargo package manager `deposit` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The ETH address is invalid.
+    #[fail(display = "invalid ETH address: {}", _0)]
+    InvalidContractAddress(zinc_math::EthAddressError),
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The network configuration error.
+    #[fail(display = "network configuration: {}", _0)]
+    Network(NetworkConfigError),
+    /// The project is not a contract.
+    #[fail(display = "not a contract")]
+    NotAContract,
+    /// The private key file error.
+    #[fail(display = "private key file {}", _0)]
+    PrivateKeyFile(FileError),
+    /// The sender private key is invalid.
+    #[fail(display = "sender private key is invalid: {}", _0)]
+    SenderPrivateKeyInvalid(rustc_hex::FromHexError),
+    /// The sender address cannot be derived from the private key.
+    #[fail(
+        display = "could not derive the ETH address from the private key: {}",
+        _0
+    )]
+    SenderAddressDeriving(anyhow::Error),
+    /// The wallet initialization error.
+    #[fail(display = "wallet initialization: {}", _0)]
+    WalletInitialization(zksync::error::ClientError),
+    /// The deposit transaction error.
+    #[fail(display = "transaction: {}", _0)]
+    Transaction(TransactionError),
+}
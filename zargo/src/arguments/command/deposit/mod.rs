@@ -0,0 +1,156 @@
+//!
+//! The Zargo package manager `deposit` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::TokenLike;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
+use crate::transaction::error::Error as TransactionError;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `deposit` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Deposits tokens from Ethereum to a smart contract")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the contract resides.
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
+
+    /// Sets the Ethereum node URL, which the priority operation is sent to.
+    #[structopt(long = "ethereum-url", default_value = "http://localhost:8545")]
+    pub ethereum_url: String,
+
+    /// Sets the ETH address of the contract.
+    #[structopt(long = "address")]
+    pub address: String,
+
+    /// Sets the deposit token symbol.
+    #[structopt(long = "token", default_value = "ETH")]
+    pub token: String,
+
+    /// Sets the deposit amount in token units, e.g. `1.5`.
+    #[structopt(long = "amount")]
+    pub amount: String,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> Result<(), Error> {
+        let address: Address = zinc_math::eth_address_from_str(self.address.as_str())
+            .map(Address::from)
+            .map_err(Error::InvalidContractAddress)?;
+
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        let network = NetworkConfig::new(&manifest, self.network, None)
+            .map_err(Error::Network)?
+            .network;
+
+        match manifest.project.r#type {
+            ProjectType::Contract => {}
+            _ => return Err(Error::NotAContract),
+        }
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let mut private_key_path = DataDirectory::path(&manifest_path);
+        private_key_path.push(zinc_const::file_name::PRIVATE_KEY.to_owned());
+
+        let private_key =
+            PrivateKeyFile::try_from(&private_key_path).map_err(Error::PrivateKeyFile)?;
+
+        let signer_private_key: H256 = private_key
+            .inner
+            .parse()
+            .map_err(Error::SenderPrivateKeyInvalid)?;
+        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)
+            .map_err(Error::SenderAddressDeriving)?;
+
+        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            signer_address,
+            PrivateKeySigner::new(signer_private_key),
+            network.into(),
+        )
+        .await
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        let wallet = zksync::Wallet::new(zksync::Provider::new(network.into()), wallet_credentials)
+            .await
+            .map_err(Error::WalletInitialization)?;
+
+        let token = wallet
+            .tokens
+            .resolve(TokenLike::Symbol(self.token))
+            .ok_or(TransactionError::TokenNotFound)
+            .map_err(Error::Transaction)?;
+        let amount = crate::transaction::amount::parse(self.amount.as_str(), token.decimals)
+            .map_err(Error::Transaction)?;
+
+        eprintln!(
+            "  {} {} {} to the contract `{} v{}` with address {} on network `{}`",
+            "Depositing".bright_green(),
+            crate::transaction::amount::format(&amount, token.decimals),
+            token.symbol,
+            manifest.project.name,
+            manifest.project.version,
+            self.address,
+            network,
+        );
+
+        let balance = crate::transaction::deposit(
+            &wallet,
+            self.ethereum_url.as_str(),
+            address,
+            &token,
+            amount,
+        )
+        .await
+        .map_err(Error::Transaction)?;
+
+        eprintln!(
+            "     {} {} {}",
+            "Balance".bright_green(),
+            crate::transaction::amount::format(&balance, token.decimals),
+            token.symbol,
+        );
+
+        Ok(())
+    }
+}
@@ -4,19 +4,24 @@
 
 use failure::Fail;
 
+use crate::arguments::command::bind::error::Error as BindCommandError;
 use crate::arguments::command::build::error::Error as BuildCommandError;
 use crate::arguments::command::call::error::Error as CallCommandError;
 use crate::arguments::command::clean::error::Error as CleanCommandError;
+use crate::arguments::command::deposit::error::Error as DepositCommandError;
+use crate::arguments::command::fmt::error::Error as FmtCommandError;
 use crate::arguments::command::init::error::Error as InitCommandError;
 use crate::arguments::command::new::error::Error as NewCommandError;
 use crate::arguments::command::proof_check::error::Error as ProofCheckCommandError;
 use crate::arguments::command::prove::error::Error as ProveCommandError;
 use crate::arguments::command::publish::error::Error as PublishCommandError;
 use crate::arguments::command::query::error::Error as QueryCommandError;
+use crate::arguments::command::rotate_key::error::Error as RotateKeyCommandError;
 use crate::arguments::command::run::error::Error as RunCommandError;
 use crate::arguments::command::setup::error::Error as SetupCommandError;
 use crate::arguments::command::test::error::Error as TestCommandError;
 use crate::arguments::command::verify::error::Error as VerifyCommandError;
+use crate::arguments::command::withdraw::error::Error as WithdrawCommandError;
 
 ///
 /// The Zargo package manager error.
@@ -35,6 +40,9 @@ pub enum Error {
     /// The `clean` command error.
     #[fail(display = "{}", _0)]
     Clean(CleanCommandError),
+    /// The `fmt` command error.
+    #[fail(display = "{}", _0)]
+    Fmt(FmtCommandError),
     /// The `run` command error.
     #[fail(display = "{}", _0)]
     Run(RunCommandError),
@@ -62,6 +70,18 @@ pub enum Error {
     /// The `call` command error.
     #[fail(display = "{}", _0)]
     Call(CallCommandError),
+    /// The `deposit` command error.
+    #[fail(display = "{}", _0)]
+    Deposit(DepositCommandError),
+    /// The `withdraw` command error.
+    #[fail(display = "{}", _0)]
+    Withdraw(WithdrawCommandError),
+    /// The `rotate-key` command error.
+    #[fail(display = "{}", _0)]
+    RotateKey(RotateKeyCommandError),
+    /// The `bind` command error.
+    #[fail(display = "{}", _0)]
+    Bind(BindCommandError),
 }
 
 impl From<NewCommandError> for Error {
@@ -88,6 +108,12 @@ impl From<CleanCommandError> for Error {
     }
 }
 
+impl From<FmtCommandError> for Error {
+    fn from(inner: FmtCommandError) -> Self {
+        Self::Fmt(inner)
+    }
+}
+
 impl From<RunCommandError> for Error {
     fn from(inner: RunCommandError) -> Self {
         Self::Run(inner)
@@ -141,3 +167,27 @@ impl From<CallCommandError> for Error {
         Self::Call(inner)
     }
 }
+
+impl From<DepositCommandError> for Error {
+    fn from(inner: DepositCommandError) -> Self {
+        Self::Deposit(inner)
+    }
+}
+
+impl From<WithdrawCommandError> for Error {
+    fn from(inner: WithdrawCommandError) -> Self {
+        Self::Withdraw(inner)
+    }
+}
+
+impl From<RotateKeyCommandError> for Error {
+    fn from(inner: RotateKeyCommandError) -> Self {
+        Self::RotateKey(inner)
+    }
+}
+
+impl From<BindCommandError> for Error {
+    fn from(inner: BindCommandError) -> Self {
+        Self::Bind(inner)
+    }
+}
@@ -0,0 +1,26 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+use failure::Fail;
+
+use crate::error::file::Error as FileError;
+
+///
+/// The Zargo package manager `fmt` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The source file error.
+    #[fail(display = "source file {}", _0)]
+    SourceFile(FileError),
+    /// The source file cannot be parsed, so it cannot be formatted.
+    #[fail(display = "`{}` parsing failed, run `zargo build` for details", _0)]
+    Parsing(String),
+    /// The `--check` mode has found the source files, which would be changed by formatting.
+    #[fail(display = "{} source file(s) are not formatted", _0)]
+    Unformatted(usize),
+}
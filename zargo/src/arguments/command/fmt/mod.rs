@@ -0,0 +1,117 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zinc_manifest::Manifest;
+use zinc_syntax::Formatter;
+
+use crate::error::file::Error as FileError;
+use crate::project::source::Directory as SourceDirectory;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `fmt` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Formats the project source files")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Lists the unformatted files and fails instead of rewriting them.
+    #[structopt(long = "check")]
+    pub is_check: bool,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> Result<(), Error> {
+        let _manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let source_directory_path = SourceDirectory::path(&manifest_path);
+        let mut paths = Vec::new();
+        Self::source_files(&source_directory_path, &mut paths).map_err(Error::SourceFile)?;
+        paths.sort();
+
+        let mut unformatted = 0;
+        for path in paths.into_iter() {
+            let name = path.to_string_lossy().to_string();
+            let input = fs::read_to_string(&path)
+                .map_err(|error| FileError::Reading(name.clone(), error))
+                .map_err(Error::SourceFile)?;
+
+            let output = Formatter::format(input.as_str(), 0)
+                .map_err(|_error| Error::Parsing(name.clone()))?;
+            if output == input {
+                continue;
+            }
+
+            if self.is_check {
+                eprintln!(" {} {}", "Unformatted".bright_red(), name);
+                unformatted += 1;
+            } else {
+                fs::write(&path, output)
+                    .map_err(|error| FileError::Writing(name.clone(), error))
+                    .map_err(Error::SourceFile)?;
+                eprintln!("   {} {}", "Formatted".bright_green(), name);
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(Error::Unformatted(unformatted));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Recursively collects the source files in the directory at `path`.
+    ///
+    fn source_files(path: &Path, paths: &mut Vec<PathBuf>) -> Result<(), FileError> {
+        let name = path.to_string_lossy().to_string();
+        let entries =
+            fs::read_dir(path).map_err(|error| FileError::Opening(name.clone(), error))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|error| FileError::Reading(name.clone(), error))?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                Self::source_files(&path, paths)?;
+            } else if path.extension().and_then(|extension| extension.to_str())
+                == Some(zinc_const::extension::SOURCE)
+            {
+                paths.push(path);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use structopt::StructOpt;
 
 use crate::project::source::circuit::Circuit as CircuitFile;
 use crate::project::source::contract::Contract as ContractFile;
+use crate::project::source::library::Library as LibraryFile;
 use crate::project::source::Directory as SourceDirectory;
 use zinc_manifest::Manifest;
 use zinc_manifest::ProjectType;
@@ -32,7 +33,7 @@ pub struct Command {
     #[structopt(long = "name")]
     pub name: Option<String>,
 
-    /// Sets the project type, either 'circuit' or 'contract'.
+    /// Sets the project type, either 'circuit', 'contract', or 'library'.
     #[structopt(long = "type")]
     pub r#type: String,
 
@@ -91,6 +92,13 @@ impl Command {
                         .map_err(Error::ContractFile)?;
                 }
             }
+            ProjectType::Library => {
+                if !LibraryFile::exists_at(&self.path) {
+                    LibraryFile::new(&project_name)
+                        .write_to(&self.path)
+                        .map_err(Error::LibraryFile)?;
+                }
+            }
         }
 
         eprintln!(
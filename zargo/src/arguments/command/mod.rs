@@ -2,37 +2,47 @@
 //! The Zargo package manager subcommand.
 //!
 
+pub mod bind;
 pub mod build;
 pub mod call;
 pub mod clean;
+pub mod deposit;
 pub mod error;
+pub mod fmt;
 pub mod init;
 pub mod new;
 pub mod proof_check;
 pub mod prove;
 pub mod publish;
 pub mod query;
+pub mod rotate_key;
 pub mod run;
 pub mod setup;
 pub mod test;
 pub mod verify;
+pub mod withdraw;
 
 use structopt::StructOpt;
 
+use self::bind::Command as BindCommand;
 use self::build::Command as BuildCommand;
 use self::call::Command as CallCommand;
 use self::clean::Command as CleanCommand;
+use self::deposit::Command as DepositCommand;
 use self::error::Error;
+use self::fmt::Command as FmtCommand;
 use self::init::Command as InitCommand;
 use self::new::Command as NewCommand;
 use self::proof_check::Command as ProofCheckCommand;
 use self::prove::Command as ProveCommand;
 use self::publish::Command as PublishCommand;
 use self::query::Command as QueryCommand;
+use self::rotate_key::Command as RotateKeyCommand;
 use self::run::Command as RunCommand;
 use self::setup::Command as SetupCommand;
 use self::test::Command as TestCommand;
 use self::verify::Command as VerifyCommand;
+use self::withdraw::Command as WithdrawCommand;
 
 ///
 /// The Zargo package manager subcommand.
@@ -48,6 +58,8 @@ pub enum Command {
     Build(BuildCommand),
     /// Removes the project build artifacts.
     Clean(CleanCommand),
+    /// Formats the project source files.
+    Fmt(FmtCommand),
     /// Runs the project and prints its output.
     Run(RunCommand),
     /// Runs the project unit tests.
@@ -66,6 +78,14 @@ pub enum Command {
     Query(QueryCommand),
     /// Calls a mutable smart contract method.
     Call(CallCommand),
+    /// Deposits tokens from Ethereum to a smart contract.
+    Deposit(DepositCommand),
+    /// Withdraws tokens from zkSync to Ethereum.
+    Withdraw(WithdrawCommand),
+    /// Replaces the private key of a published contract.
+    RotateKey(RotateKeyCommand),
+    /// Generates the contract client bindings.
+    Bind(BindCommand),
 }
 
 impl Command {
@@ -78,6 +98,7 @@ impl Command {
             Self::Init(inner) => inner.execute()?,
             Self::Build(inner) => inner.execute()?,
             Self::Clean(inner) => inner.execute()?,
+            Self::Fmt(inner) => inner.execute()?,
             Self::Run(inner) => inner.execute()?,
             Self::Test(inner) => inner.execute()?,
             Self::Setup(inner) => inner.execute()?,
@@ -87,6 +108,10 @@ impl Command {
             Self::Publish(inner) => inner.execute().await?,
             Self::Query(inner) => inner.execute().await?,
             Self::Call(inner) => inner.execute().await?,
+            Self::Deposit(inner) => inner.execute().await?,
+            Self::Withdraw(inner) => inner.execute().await?,
+            Self::RotateKey(inner) => inner.execute().await?,
+            Self::Bind(inner) => inner.execute()?,
         }
 
         Ok(())
@@ -48,4 +48,7 @@ pub enum Error {
     /// The contract source code entry point file generation error.
     #[fail(display = "contract file {}", _0)]
     ContractFile(FileError),
+    /// The library source code entry point file generation error.
+    #[fail(display = "library file {}", _0)]
+    LibraryFile(FileError),
 }
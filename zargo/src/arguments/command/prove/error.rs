@@ -15,6 +15,9 @@ pub enum Error {
     /// The manifest file error.
     #[fail(display = "manifest {}", _0)]
     Manifest(zinc_manifest::Error),
+    /// The project is a library, which can only be built and used as a dependency.
+    #[fail(display = "libraries cannot be executed, but only used as dependencies")]
+    LibraryNotExecutable,
     /// The contract method to call is missing.
     #[fail(display = "contract method to call must be specified")]
     MethodMissing,
@@ -6,10 +6,12 @@ use failure::Fail;
 
 use zinc_zksync::SourceError;
 
+use crate::auth::error::Error as AuthError;
 use crate::error::directory::Error as DirectoryError;
 use crate::error::file::Error as FileError;
 use crate::executable::compiler::Error as CompilerError;
 use crate::executable::virtual_machine::Error as VirtualMachineError;
+use crate::project::network::error::Error as NetworkConfigError;
 use crate::transaction::error::Error as TransactionError;
 
 ///
@@ -17,15 +19,15 @@ use crate::transaction::error::Error as TransactionError;
 ///
 #[derive(Debug, Fail)]
 pub enum Error {
-    /// The invalid network error.
-    #[fail(display = "invalid network name: {}", _0)]
-    NetworkInvalid(String),
-    /// The unimplemented network error.
-    #[fail(display = "unimplemented network: {}", _0)]
-    NetworkUnimplemented(zksync::Network),
     /// The manifest file error.
     #[fail(display = "manifest {}", _0)]
     Manifest(zinc_manifest::Error),
+    /// The network configuration error.
+    #[fail(display = "network configuration: {}", _0)]
+    Network(NetworkConfigError),
+    /// The storage field rename is not in the `old=new` format.
+    #[fail(display = "invalid storage field rename `{}`, expected `old=new`", _0)]
+    RenameFieldInvalid(String),
     /// The project is not a contract.
     #[fail(display = "not a contract")]
     NotAContract,
@@ -47,6 +49,9 @@ pub enum Error {
     /// The contract bytecode binary file error.
     #[fail(display = "bytecode binary file {}", _0)]
     BinaryFile(FileError),
+    /// The contract ABI file error.
+    #[fail(display = "ABI file {}", _0)]
+    AbiFile(FileError<serde_json::Error>),
     /// The input file error.
     #[fail(display = "input file {}", _0)]
     InputFile(FileError<serde_json::Error>),
@@ -59,6 +64,9 @@ pub enum Error {
     /// The verifying key file error.
     #[fail(display = "verifying key file {}", _0)]
     VerifyingKeyFile(FileError),
+    /// The contract owner credentials error.
+    #[fail(display = "authorization: {}", _0)]
+    Authorization(AuthError),
     /// The publish HTTP request error.
     #[fail(display = "HTTP request: {}", _0)]
     HttpRequest(reqwest::Error),
@@ -4,9 +4,10 @@
 
 pub mod error;
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::path::PathBuf;
-use std::str::FromStr;
+use std::time::Duration;
 
 use colored::Colorize;
 use num::BigUint;
@@ -24,6 +25,9 @@ use zinc_manifest::ProjectType;
 use zinc_zksync::InitializeRequestBody;
 use zinc_zksync::InitializeRequestQuery;
 use zinc_zksync::InitializeResponseBody;
+use zinc_zksync::JobResponseBody;
+use zinc_zksync::JobStatus;
+use zinc_zksync::PublishAcceptedResponseBody;
 use zinc_zksync::PublishRequestBody;
 use zinc_zksync::PublishRequestQuery;
 use zinc_zksync::PublishResponseBody;
@@ -31,13 +35,14 @@ use zinc_zksync::Source;
 
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
-use crate::network::Network;
+use crate::project::build::abi::Abi as AbiFile;
 use crate::project::build::bytecode::Bytecode as BytecodeFile;
 use crate::project::build::Directory as BuildDirectory;
 use crate::project::data::input::Input as InputFile;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::verifying_key::VerifyingKey as VerifyingKeyFile;
 use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
 use crate::project::source::Directory as SourceDirectory;
 
 use self::error::Error;
@@ -65,16 +70,36 @@ pub struct Command {
     pub instance: String,
 
     /// Sets the network name, where the contract must be published to.
-    #[structopt(long = "network", default_value = "localhost")]
-    pub network: String,
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
 
-    /// Sets the initial deposit token.
-    #[structopt(long = "deposit-token", default_value = "ETH")]
-    pub deposit_token: String,
+    /// Sets the Zandbox server URL, which overrides the default one of the network.
+    #[structopt(long = "zandbox-url")]
+    pub zandbox_url: Option<String>,
+
+    /// Sets the initial deposit token, which is also used to pay the fee.
+    /// Defaults to the `network.fee_token` configuration key or `ETH`.
+    #[structopt(long = "deposit-token")]
+    pub deposit_token: Option<String>,
 
     /// Sets the initial deposit amount.
     #[structopt(long = "deposit-amount", default_value = "0")]
     pub deposit_amount: String,
+
+    /// Renames a storage field as `old=new`, when an initialized instance is re-published.
+    #[structopt(long = "rename-field")]
+    pub rename_fields: Vec<String>,
+
+    /// Allows the contract to transfer only the specified token symbol or address.
+    /// If not passed, the contract is allowed to transfer any token.
+    #[structopt(long = "allow-token")]
+    pub allowed_tokens: Vec<String>,
+
+    /// Publishes the contract within the request instead of polling the publishing job.
+    /// Only suitable for small contracts, since the request may time out.
+    #[structopt(long = "sync")]
+    pub sync: bool,
 }
 
 impl Command {
@@ -82,16 +107,25 @@ impl Command {
     /// Executes the command.
     ///
     pub async fn execute(self) -> Result<(), Error> {
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
-
-        let url = network
-            .try_into_url()
-            .map_err(Error::NetworkUnimplemented)?;
+        let mut renames = HashMap::with_capacity(self.rename_fields.len());
+        for rename in self.rename_fields.into_iter() {
+            let mut names = rename.splitn(2, '=');
+            match (names.next(), names.next()) {
+                (Some(old), Some(new)) if !old.is_empty() && !new.is_empty() => {
+                    renames.insert(old.to_owned(), new.to_owned());
+                }
+                _ => return Err(Error::RenameFieldInvalid(rename)),
+            }
+        }
 
         let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
 
+        let config = NetworkConfig::new(&manifest, self.network, self.zandbox_url)
+            .map_err(Error::Network)?;
+        let network = config.network;
+        let url = config.zandbox_url().map_err(Error::Network)?;
+        let deposit_token = self.deposit_token.unwrap_or(config.fee_token);
+
         match manifest.project.r#type {
             ProjectType::Contract => {}
             _ => return Err(Error::NotAContract),
@@ -122,7 +156,7 @@ impl Command {
 
         BuildDirectory::create(&manifest_path).map_err(Error::BuildDirectory)?;
         let build_directory_path = BuildDirectory::path(&manifest_path);
-        let mut binary_path = build_directory_path;
+        let mut binary_path = build_directory_path.clone();
         binary_path.push(format!(
             "{}.{}",
             zinc_const::file_name::BINARY,
@@ -137,6 +171,10 @@ impl Command {
             }
         }
 
+        let libraries =
+            Compiler::build_dependencies(self.verbosity, &manifest, &manifest_path, true)
+                .map_err(Error::Compiler)?;
+
         Compiler::build_release(
             self.verbosity,
             manifest.project.name.as_str(),
@@ -145,11 +183,17 @@ impl Command {
             &data_directory_path,
             &source_directory_path,
             &binary_path,
+            &libraries,
+            false,
+            false,
+            None,
             false,
         )
         .map_err(Error::Compiler)?;
 
         let bytecode = BytecodeFile::try_from(&binary_path).map_err(Error::BinaryFile)?;
+        let abi = AbiFile::try_from_path(&build_directory_path, manifest.project.name.as_str())
+            .map_err(Error::AbiFile)?;
 
         let input = InputFile::try_from_path(&input_path).map_err(Error::InputFile)?;
         let arguments = input
@@ -178,6 +222,11 @@ impl Command {
 
         let verifying_key =
             VerifyingKeyFile::try_from(&verifying_key_path).map_err(Error::VerifyingKeyFile)?;
+        let mut verifying_keys = HashMap::with_capacity(1);
+        verifying_keys.insert(
+            zinc_const::contract::CONSTRUCTOR_NAME.to_owned(),
+            verifying_key.inner.clone(),
+        );
 
         eprintln!(
             "   {} the instance `{}` of `{} v{}` to network `{}`",
@@ -188,12 +237,23 @@ impl Command {
             network,
         );
 
+        let private_key =
+            PrivateKeyFile::try_from(&private_key_path).map_err(Error::PrivateKeyFile)?;
+        let signer_private_key: H256 = private_key
+            .inner
+            .parse()
+            .map_err(Error::SenderPrivateKeyInvalid)?;
+
         let http_client = HttpClient::new();
 
+        let credentials = crate::auth::credentials(&http_client, url.as_str(), &signer_private_key)
+            .await
+            .map_err(Error::Authorization)?;
+
         let http_response = http_client
             .execute(
-                http_client
-                    .request(
+                crate::auth::authorize(
+                    http_client.request(
                         Method::POST,
                         Url::parse_with_params(
                             format!("{}{}", url, zinc_const::zandbox::CONTRACT_PUBLISH_URL)
@@ -203,18 +263,25 @@ impl Command {
                                 manifest.project.version,
                                 self.instance,
                                 network.into(),
+                                self.sync,
                             ),
                         )
                         .expect(zinc_const::panic::DATA_CONVERSION),
-                    )
-                    .json(&PublishRequestBody::new(
-                        source,
-                        bytecode.inner,
-                        arguments,
-                        verifying_key.inner,
-                    ))
-                    .build()
-                    .expect(zinc_const::panic::DATA_CONVERSION),
+                    ),
+                    &credentials,
+                )
+                .json(&PublishRequestBody::new(
+                    source,
+                    bytecode.inner,
+                    arguments,
+                    verifying_key.inner,
+                    verifying_keys,
+                    renames,
+                    self.allowed_tokens,
+                    Some(abi.inner),
+                ))
+                .build()
+                .expect(zinc_const::panic::DATA_CONVERSION),
             )
             .await
             .map_err(Error::HttpRequest)?;
@@ -230,10 +297,23 @@ impl Command {
             )));
         }
 
-        let response = http_response
-            .json::<PublishResponseBody>()
-            .await
-            .expect(zinc_const::panic::DATA_CONVERSION);
+        let response = if self.sync {
+            http_response
+                .json::<PublishResponseBody>()
+                .await
+                .expect(zinc_const::panic::DATA_CONVERSION)
+        } else {
+            let response = http_response
+                .json::<PublishAcceptedResponseBody>()
+                .await
+                .expect(zinc_const::panic::DATA_CONVERSION);
+            eprintln!(
+                "     {} for the publishing job {}",
+                "Waiting".bright_green(),
+                response.job_id,
+            );
+            Self::wait_for_job(&http_client, url.as_str(), response.job_id.as_str()).await?
+        };
         println!(
             "     {} {}",
             "Address".bright_green(),
@@ -242,13 +322,11 @@ impl Command {
                 .replace("\"", "")
         );
 
-        let private_key =
-            PrivateKeyFile::try_from(&private_key_path).map_err(Error::PrivateKeyFile)?;
+        if let Some(account_id) = response.account_id {
+            println!("  {} {}", "Account ID".bright_green(), account_id);
+            return Ok(());
+        }
 
-        let signer_private_key: H256 = private_key
-            .inner
-            .parse()
-            .map_err(Error::SenderPrivateKeyInvalid)?;
         let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)
             .map_err(Error::SenderAddressDeriving)?;
 
@@ -271,7 +349,7 @@ impl Command {
         let initial_transfer = crate::transaction::new_initial(
             &wallet,
             response.address,
-            self.deposit_token,
+            deposit_token,
             initial_deposit_amount,
         )
         .await
@@ -315,4 +393,66 @@ impl Command {
 
         Ok(())
     }
+
+    ///
+    /// Polls the publishing job with `job_id` until it is finished.
+    ///
+    /// Returns the published contract address and account ID, if the job is done.
+    ///
+    async fn wait_for_job(
+        http_client: &HttpClient,
+        url: &str,
+        job_id: &str,
+    ) -> Result<PublishResponseBody, Error> {
+        loop {
+            tokio::time::delay_for(Duration::from_millis(
+                zinc_const::zargo::PUBLISH_JOB_POLLING_INTERVAL_MS,
+            ))
+            .await;
+
+            let http_response = http_client
+                .get(
+                    Url::parse(
+                        format!("{}{}/{}", url, zinc_const::zandbox::JOB_URL, job_id).as_str(),
+                    )
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+                )
+                .send()
+                .await
+                .map_err(Error::HttpRequest)?;
+
+            if !http_response.status().is_success() {
+                return Err(Error::ActionFailed(format!(
+                    "HTTP error ({}) {}",
+                    http_response.status(),
+                    http_response
+                        .text()
+                        .await
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                )));
+            }
+
+            let job = http_response
+                .json::<JobResponseBody>()
+                .await
+                .expect(zinc_const::panic::DATA_CONVERSION);
+            match job.status {
+                JobStatus::Done => {
+                    let address = job.address.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    return Ok(match job.account_id {
+                        Some(account_id) => {
+                            PublishResponseBody::new_republished(address, account_id)
+                        }
+                        None => PublishResponseBody::new(address),
+                    });
+                }
+                JobStatus::Failed => {
+                    return Err(Error::ActionFailed(
+                        job.error.unwrap_or_else(|| "Unknown error".to_owned()),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
 }
@@ -4,25 +4,27 @@
 
 use failure::Fail;
 
+use crate::auth::error::Error as AuthError;
 use crate::error::file::Error as FileError;
+use crate::project::network::error::Error as NetworkConfigError;
 
 ///
 /// The Zargo package manager `query` subcommand error.
 ///
 #[derive(Debug, Fail)]
 pub enum Error {
+    /// The ETH address is required for the queries to the Zandbox server.
+    #[fail(display = "contract address must be specified with `--address` or use `--local`")]
+    ContractAddressMissing,
     /// The ETH address is invalid.
     #[fail(display = "invalid ETH address: {}", _0)]
-    InvalidContractAddress(rustc_hex::FromHexError),
-    /// The invalid network error.
-    #[fail(display = "invalid network name: {}", _0)]
-    NetworkInvalid(String),
-    /// The unimplemented network error.
-    #[fail(display = "unimplemented network: {}", _0)]
-    NetworkUnimplemented(zksync::Network),
+    InvalidContractAddress(zinc_math::EthAddressError),
     /// The manifest file error.
     #[fail(display = "manifest {}", _0)]
     Manifest(zinc_manifest::Error),
+    /// The network configuration error.
+    #[fail(display = "network configuration: {}", _0)]
+    Network(NetworkConfigError),
     /// The project is not a contract.
     #[fail(display = "not a contract")]
     NotAContract,
@@ -32,6 +34,48 @@ pub enum Error {
     /// The input file data is invalid.
     #[fail(display = "invalid input file data")]
     InvalidInputData,
+    /// The method arguments file error.
+    #[fail(display = "arguments file {}", _0)]
+    ArgumentsFile(FileError<serde_json::Error>),
+    /// The storage snapshot file error.
+    #[fail(display = "storage file {}", _0)]
+    StorageFile(FileError<serde_json::Error>),
+    /// The storage snapshot is not an array of the storage field values.
+    #[fail(display = "contract storage must be an array of the storage field values")]
+    InvalidStorageFormat,
+    /// The storage snapshot field count does not match the contract storage.
+    #[fail(display = "contract storage expected {} fields, found {}", _0, _1)]
+    StorageFieldCount(usize, usize),
+    /// The storage snapshot field value does not match the contract storage field type.
+    #[fail(display = "contract storage field `{}`: {}", _0, _1)]
+    StorageFieldType(String, zinc_build::ValueError),
+    /// The bytecode binary file error.
+    #[fail(display = "binary file {}", _0)]
+    BinaryFile(FileError),
+    /// The bytecode binary file is invalid.
+    #[fail(display = "invalid bytecode: {}", _0)]
+    InvalidBytecode(String),
+    /// The contract method does not exist.
+    #[fail(display = "method `{}` not found", _0)]
+    MethodNotFound(String),
+    /// The contract method is mutable, so it must be called with `zargo call`.
+    #[fail(display = "method `{}` is mutable: use `zargo call` instead", _0)]
+    MethodIsMutable(String),
+    /// The method arguments do not match the method input type.
+    #[fail(display = "invalid method arguments: {}", _0)]
+    InvalidArguments(zinc_build::ValueError),
+    /// The virtual machine runtime error.
+    #[fail(display = "virtual machine: {}", _0)]
+    VirtualMachine(zinc_vm::RuntimeError),
+    /// The private key file error.
+    #[fail(display = "private key file {}", _0)]
+    PrivateKeyFile(FileError),
+    /// The owner private key is invalid.
+    #[fail(display = "owner private key is invalid: {}", _0)]
+    OwnerPrivateKeyInvalid(rustc_hex::FromHexError),
+    /// The contract owner credentials error.
+    #[fail(display = "authorization: {}", _0)]
+    Authorization(AuthError),
     /// The publish HTTP request error.
     #[fail(display = "HTTP request: {}", _0)]
     HttpRequest(reqwest::Error),
@@ -5,24 +5,39 @@
 pub mod error;
 
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use colored::Colorize;
 use reqwest::Client as HttpClient;
 use reqwest::Method;
 use reqwest::Url;
+use serde_json::json;
 use serde_json::Value as JsonValue;
 use structopt::StructOpt;
 
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::ContractFieldType as BuildContractFieldType;
+use zinc_build::ContractFieldValue as BuildContractFieldValue;
+use zinc_build::Value as BuildValue;
 use zinc_manifest::Manifest;
 use zinc_manifest::ProjectType;
+use zinc_vm::Bn256;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
 use zinc_zksync::QueryRequestBody;
 use zinc_zksync::QueryRequestQuery;
 
-use crate::network::Network;
+use crate::error::file::Error as FileError;
+use crate::project::build::bytecode::Bytecode as BytecodeFile;
+use crate::project::build::Directory as BuildDirectory;
 use crate::project::data::input::Input as InputFile;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
 
 use self::error::Error;
 
@@ -45,16 +60,40 @@ pub struct Command {
     pub manifest_path: PathBuf,
 
     /// Sets the network name, where the contract resides.
-    #[structopt(long = "network", default_value = "localhost")]
-    pub network: String,
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
+
+    /// Sets the Zandbox server URL, which overrides the default one of the network.
+    #[structopt(long = "zandbox-url")]
+    pub zandbox_url: Option<String>,
 
-    /// Sets the ETH address of the contract.
+    /// Sets the ETH address of the contract. Is not required for the local queries.
     #[structopt(long = "address")]
-    pub address: String,
+    pub address: Option<String>,
 
     /// Sets the contract method to call. If not specified, the contract storage is queried.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// Sets the path to the method arguments JSON file.
+    /// If not specified, the method arguments are taken from the project input file.
+    #[structopt(long = "arguments", parse(from_os_str))]
+    pub arguments_path: Option<PathBuf>,
+
+    /// Returns the private storage fields as well. The query is signed with the project private
+    /// key, which must belong to the contract owner.
+    #[structopt(long = "private")]
+    pub is_private: bool,
+
+    /// Runs the locally built bytecode instead of sending the query to the Zandbox server.
+    #[structopt(long = "local")]
+    pub is_local: bool,
+
+    /// Sets the path to the contract storage snapshot file for the local queries.
+    /// If not specified, the storage is taken from the project input file.
+    #[structopt(long = "storage", parse(from_os_str))]
+    pub storage_path: Option<PathBuf>,
 }
 
 impl Command {
@@ -62,18 +101,6 @@ impl Command {
     /// Executes the command.
     ///
     pub async fn execute(self) -> Result<(), Error> {
-        let address = self.address["0x".len()..]
-            .parse()
-            .map_err(Error::InvalidContractAddress)?;
-
-        let network = zksync::Network::from_str(self.network.as_str())
-            .map(Network::from)
-            .map_err(Error::NetworkInvalid)?;
-
-        let url = network
-            .try_into_url()
-            .map_err(Error::NetworkUnimplemented)?;
-
         let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
 
         match manifest.project.r#type {
@@ -81,102 +108,263 @@ impl Command {
             _ => return Err(Error::NotAContract),
         }
 
-        let mut manifest_path = self.manifest_path;
+        let mut manifest_path = self.manifest_path.clone();
         if manifest_path.is_file() {
             manifest_path.pop();
         }
 
-        let arguments = match self.method {
-            Some(ref method) => {
-                let data_directory_path = DataDirectory::path(&manifest_path);
-                let mut input_path = data_directory_path;
-                input_path.push(format!(
-                    "{}.{}",
-                    zinc_const::file_name::INPUT,
-                    zinc_const::extension::JSON,
-                ));
-
-                let input = InputFile::try_from_path(&input_path).map_err(Error::InputFile)?;
-                let arguments = input
-                    .inner
-                    .as_object()
-                    .ok_or(Error::InvalidInputData)?
-                    .get("arguments")
-                    .cloned()
-                    .ok_or(Error::InvalidInputData)?
-                    .as_object()
-                    .ok_or(Error::InvalidInputData)?
-                    .get(method)
-                    .cloned()
-                    .ok_or(Error::InvalidInputData)?;
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut input_path = data_directory_path;
+        input_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::INPUT,
+            zinc_const::extension::JSON,
+        ));
+
+        let arguments = match (self.method.as_ref(), self.arguments_path.as_ref()) {
+            (Some(_method), Some(arguments_path)) => {
+                Some(Self::read_json(arguments_path).map_err(Error::ArgumentsFile)?)
+            }
+            (Some(method), None) => Some(
+                InputFile::try_from_path(&input_path)
+                    .map_err(Error::InputFile)?
+                    .method_arguments(method.as_str())
+                    .ok_or(Error::InvalidInputData)?,
+            ),
+            (None, _) => None,
+        };
+
+        if self.is_local {
+            self.execute_local(manifest, manifest_path, input_path, arguments)
+        } else {
+            self.execute_remote(manifest, manifest_path, arguments)
+                .await
+        }
+    }
+
+    ///
+    /// Sends the query to the Zandbox server and prints the response.
+    ///
+    async fn execute_remote(
+        self,
+        manifest: Manifest,
+        manifest_path: PathBuf,
+        arguments: Option<JsonValue>,
+    ) -> Result<(), Error> {
+        let address = self.address.ok_or(Error::ContractAddressMissing)?;
+        let address_parsed: Address = zinc_math::eth_address_from_str(address.as_str())
+            .map(Address::from)
+            .map_err(Error::InvalidContractAddress)?;
+
+        let config = NetworkConfig::new(&manifest, self.network, self.zandbox_url)
+            .map_err(Error::Network)?;
+        let network = config.network;
+        let url = config.zandbox_url().map_err(Error::Network)?;
+
+        match self.method {
+            Some(ref method) => eprintln!(
+                "    {} method `{}` of the contract `{} v{}` with address {} on network `{}`",
+                "Querying".bright_green(),
+                method,
+                manifest.project.name,
+                manifest.project.version,
+                address,
+                network,
+            ),
+            None => eprintln!(
+                "    {} the storage of the contract `{} v{}` with address {} on network `{}`",
+                "Querying".bright_green(),
+                manifest.project.name,
+                manifest.project.version,
+                address,
+                network,
+            ),
+        }
+
+        let http_client = HttpClient::new();
+
+        let credentials = if self.is_private {
+            let mut private_key_path = DataDirectory::path(&manifest_path);
+            private_key_path.push(zinc_const::file_name::PRIVATE_KEY.to_owned());
+            let private_key: H256 = PrivateKeyFile::try_from(&private_key_path)
+                .map_err(Error::PrivateKeyFile)?
+                .inner
+                .parse()
+                .map_err(Error::OwnerPrivateKeyInvalid)?;
+
+            Some(
+                crate::auth::credentials(&http_client, url.as_str(), &private_key)
+                    .await
+                    .map_err(Error::Authorization)?,
+            )
+        } else {
+            None
+        };
+
+        let mut query = QueryRequestQuery::new(address_parsed, self.method, network.into());
+        query.private = credentials.as_ref().map(|_credentials| true);
+        let mut request = http_client.request(
+            Method::PUT,
+            Url::parse_with_params(
+                format!("{}{}", url, zinc_const::zandbox::CONTRACT_QUERY_URL).as_str(),
+                query,
+            )
+            .expect(zinc_const::panic::DATA_CONVERSION),
+        );
+        if let Some(ref credentials) = credentials {
+            request = crate::auth::authorize(request, credentials);
+        }
+
+        let http_response = http_client
+            .execute(
+                request
+                    .json(&QueryRequestBody::new(arguments))
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await
+            .map_err(Error::HttpRequest)?;
+
+        let http_response = crate::response::check_status(http_response)
+            .await
+            .map_err(Error::ActionFailed)?;
 
+        crate::response::print_json(
+            &http_response
+                .json::<JsonValue>()
+                .await
+                .expect(zinc_const::panic::DATA_CONVERSION),
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Runs the locally built bytecode on the storage snapshot and prints the output in the
+    /// same format as the Zandbox server does.
+    ///
+    fn execute_local(
+        self,
+        manifest: Manifest,
+        manifest_path: PathBuf,
+        input_path: PathBuf,
+        arguments: Option<JsonValue>,
+    ) -> Result<(), Error> {
+        let mut binary_path = BuildDirectory::path(&manifest_path);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+        let bytecode = BytecodeFile::try_from(&binary_path).map_err(Error::BinaryFile)?;
+        let contract = match BuildApplication::try_from_slice(bytecode.inner.as_slice())
+            .map_err(Error::InvalidBytecode)?
+        {
+            BuildApplication::Contract(contract) => contract,
+            BuildApplication::Circuit(_circuit) => return Err(Error::NotAContract),
+        };
+
+        let storage = match self.storage_path {
+            Some(ref storage_path) => Self::read_json(storage_path).map_err(Error::StorageFile)?,
+            None => InputFile::try_from_path(&input_path)
+                .map_err(Error::InputFile)?
+                .storage()
+                .ok_or(Error::InvalidInputData)?,
+        };
+        let storage = Self::storage_value(contract.storage.as_slice(), storage)?;
+
+        let method_name = match self.method {
+            Some(method_name) => {
                 eprintln!(
-                    "    {} method `{}` of the contract `{} v{}` with address {} on network `{}`",
+                    "    {} method `{}` of the contract `{} v{}` locally",
                     "Querying".bright_green(),
-                    method,
+                    method_name,
                     manifest.project.name,
                     manifest.project.version,
-                    self.address,
-                    network,
                 );
-
-                Some(arguments)
+                method_name
             }
             None => {
                 eprintln!(
-                    "    {} the storage of the contract `{} v{}` with address {} on network `{}`",
+                    "    {} the storage of the contract `{} v{}` locally",
                     "Querying".bright_green(),
                     manifest.project.name,
                     manifest.project.version,
-                    self.address,
-                    network,
                 );
-
-                None
+                crate::response::print_json(&storage.into_json());
+                return Ok(());
             }
         };
 
-        let http_client = HttpClient::new();
-        let http_response = http_client
-            .execute(
-                http_client
-                    .request(
-                        Method::PUT,
-                        Url::parse_with_params(
-                            format!("{}{}", url, zinc_const::zandbox::CONTRACT_QUERY_URL).as_str(),
-                            QueryRequestQuery::new(address, self.method, network.into()),
-                        )
-                        .expect(zinc_const::panic::DATA_CONVERSION),
-                    )
-                    .json(&QueryRequestBody::new(arguments))
-                    .build()
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )
-            .await
-            .map_err(Error::HttpRequest)?;
-
-        if !http_response.status().is_success() {
-            return Err(Error::ActionFailed(format!(
-                "HTTP error ({}) {}",
-                http_response.status(),
-                http_response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+        let method = contract
+            .methods
+            .get(method_name.as_str())
+            .cloned()
+            .ok_or_else(|| Error::MethodNotFound(method_name.clone()))?;
+        if method.is_mutable {
+            return Err(Error::MethodIsMutable(method_name));
         }
 
-        println!(
-            "{}",
-            serde_json::to_string_pretty(
-                &http_response
-                    .json::<JsonValue>()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION)
-            )
-            .expect(zinc_const::panic::DATA_CONVERSION)
-        );
+        let arguments = BuildValue::try_from_typed_json(
+            arguments.ok_or(Error::InvalidInputData)?,
+            method.input,
+        )
+        .map_err(Error::InvalidArguments)?;
+
+        let output = ContractFacade::new(contract)
+            .run::<Bn256>(ContractInput::new(
+                arguments,
+                storage,
+                method_name,
+                Vec::new(),
+            ))
+            .map_err(Error::VirtualMachine)?;
+
+        crate::response::print_json(&json!({
+            "output": output.result.into_json(),
+        }));
 
         Ok(())
     }
+
+    ///
+    /// Reads the JSON file at `path`.
+    ///
+    fn read_json(path: &PathBuf) -> Result<JsonValue, FileError<serde_json::Error>> {
+        let name = path.to_string_lossy().to_string();
+        let data =
+            fs::read_to_string(path).map_err(|error| FileError::Reading(name.clone(), error))?;
+        serde_json::from_str(data.as_str()).map_err(|error| FileError::Parsing(name, error))
+    }
+
+    ///
+    /// Parses the storage snapshot, which is an array of the contract storage field values.
+    ///
+    fn storage_value(
+        fields: &[BuildContractFieldType],
+        storage: JsonValue,
+    ) -> Result<BuildValue, Error> {
+        let values = match storage {
+            JsonValue::Array(values) => values,
+            _ => return Err(Error::InvalidStorageFormat),
+        };
+        if values.len() != fields.len() {
+            return Err(Error::StorageFieldCount(fields.len(), values.len()));
+        }
+
+        let mut storage_values = Vec::with_capacity(fields.len());
+        for (field, value) in fields.iter().zip(values) {
+            let value = BuildValue::try_from_typed_json(value, field.r#type.to_owned())
+                .map_err(|error| Error::StorageFieldType(field.name.to_owned(), error))?;
+
+            storage_values.push(BuildContractFieldValue::new(
+                field.name.to_owned(),
+                value,
+                field.is_public,
+                field.is_implicit,
+            ));
+        }
+
+        Ok(BuildValue::Contract(storage_values))
+    }
 }
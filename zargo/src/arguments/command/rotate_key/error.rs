@@ -0,0 +1,58 @@
+//!
+//! The Zargo package manager `rotate-key` subcommand.
+//!
+
+use failure::Fail;
+
+use crate::auth::error::Error as AuthError;
+use crate::error::file::Error as FileError;
+use crate::project::network::error::Error as NetworkConfigError;
+
+///
+/// The Zargo package manager `rotate-key` subcommand error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The ETH address is invalid.
+    #[fail(display = "invalid ETH address: {}", _0)]
+    InvalidContractAddress(zinc_math::EthAddressError),
+    /// The manifest file error.
+    #[fail(display = "manifest {}", _0)]
+    Manifest(zinc_manifest::Error),
+    /// The network configuration error.
+    #[fail(display = "network configuration: {}", _0)]
+    Network(NetworkConfigError),
+    /// The project is not a contract.
+    #[fail(display = "not a contract")]
+    NotAContract,
+    /// The private key file error.
+    #[fail(display = "private key file {}", _0)]
+    PrivateKeyFile(FileError),
+    /// The owner private key is invalid.
+    #[fail(display = "owner private key is invalid: {}", _0)]
+    OwnerPrivateKeyInvalid(rustc_hex::FromHexError),
+    /// The pending contract private key file error.
+    #[fail(display = "rotated private key file {}", _0)]
+    RotatedPrivateKeyFile(FileError<rustc_hex::FromHexError>),
+    /// The wallet initialization error.
+    #[fail(display = "wallet initialization: {}", _0)]
+    WalletInitialization(zksync::error::ClientError),
+    /// The signing key checking error.
+    #[fail(display = "signing key checking: {}", _0)]
+    SigningKeyChecking(zksync::error::ClientError),
+    /// The change-pubkey transaction sending or waiting error.
+    #[fail(display = "change-pubkey: {}", _0)]
+    ChangePubkey(zksync::error::ClientError),
+    /// The change-pubkey transaction has been rejected by the zkSync server.
+    #[fail(display = "change-pubkey has failed: {}", _0)]
+    ChangePubkeyFailed(String),
+    /// The contract owner credentials error.
+    #[fail(display = "authorization: {}", _0)]
+    Authorization(AuthError),
+    /// The key rotation HTTP request error.
+    #[fail(display = "HTTP request: {}", _0)]
+    HttpRequest(reqwest::Error),
+    /// The smart contract server failure.
+    #[fail(display = "action failed: {}", _0)]
+    ActionFailed(String),
+}
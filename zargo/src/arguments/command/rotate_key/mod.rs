@@ -0,0 +1,254 @@
+//!
+//! The Zargo package manager `rotate-key` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use reqwest::Client as HttpClient;
+use reqwest::Method;
+use reqwest::Url;
+use structopt::StructOpt;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::TokenLike;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+use zinc_zksync::KeyRequestBody;
+use zinc_zksync::KeyRequestQuery;
+
+use crate::error::file::Error as FileError;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `rotate-key` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Replaces the private key of a published contract")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the contract resides.
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
+
+    /// Sets the Zandbox server URL, which overrides the default one of the network.
+    #[structopt(long = "zandbox-url")]
+    pub zandbox_url: Option<String>,
+
+    /// Sets the ETH address of the contract.
+    #[structopt(long = "address")]
+    pub address: String,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    /// The new private key is written to the project data directory before anything is sent,
+    /// so if the command is interrupted after the change-pubkey transaction has been committed,
+    /// running it again resumes the rotation with the same key instead of generating another.
+    /// The key file is removed once the Zandbox server has stored the new key.
+    ///
+    pub async fn execute(self) -> Result<(), Error> {
+        let address: Address = zinc_math::eth_address_from_str(self.address.as_str())
+            .map(Address::from)
+            .map_err(Error::InvalidContractAddress)?;
+
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        match manifest.project.r#type {
+            ProjectType::Contract => {}
+            _ => return Err(Error::NotAContract),
+        }
+
+        let config = NetworkConfig::new(&manifest, self.network, self.zandbox_url)
+            .map_err(Error::Network)?;
+        let network = config.network;
+        let url = config.zandbox_url().map_err(Error::Network)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let mut private_key_path = DataDirectory::path(&manifest_path);
+        private_key_path.push(zinc_const::file_name::PRIVATE_KEY.to_owned());
+        let owner_private_key: H256 = PrivateKeyFile::try_from(&private_key_path)
+            .map_err(Error::PrivateKeyFile)?
+            .inner
+            .parse()
+            .map_err(Error::OwnerPrivateKeyInvalid)?;
+
+        let mut rotated_key_path = DataDirectory::path(&manifest_path);
+        rotated_key_path.push(format!(
+            "{}_{:x}",
+            zinc_const::file_name::ROTATED_PRIVATE_KEY,
+            address
+        ));
+        let private_key = if rotated_key_path.exists() {
+            eprintln!(
+                "    {} the key rotation of the contract `{} v{}` with address {} on network `{}`",
+                "Resuming".bright_green(),
+                manifest.project.name,
+                manifest.project.version,
+                self.address,
+                network,
+            );
+            Self::read_key(&rotated_key_path).map_err(Error::RotatedPrivateKeyFile)?
+        } else {
+            eprintln!(
+                "    {} the key of the contract `{} v{}` with address {} on network `{}`",
+                "Rotating".bright_green(),
+                manifest.project.name,
+                manifest.project.version,
+                self.address,
+                network,
+            );
+            let mut private_key = H256::default();
+            private_key.randomize();
+            Self::write_key(&rotated_key_path, &private_key)
+                .map_err(Error::RotatedPrivateKeyFile)?;
+            private_key
+        };
+
+        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            address,
+            PrivateKeySigner::new(private_key),
+            network.into(),
+        )
+        .await
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        let wallet = zksync::Wallet::new(zksync::Provider::new(network.into()), wallet_credentials)
+            .await
+            .map_err(Error::WalletInitialization)?;
+
+        if wallet
+            .is_signing_key_set()
+            .await
+            .map_err(Error::SigningKeyChecking)?
+        {
+            eprintln!(
+                "    {} the signing key has been already changed",
+                "Skipping".bright_green(),
+            );
+        } else {
+            let tx_info = wallet
+                .start_change_pubkey()
+                .fee_token(TokenLike::Symbol(config.fee_token))
+                .map_err(Error::ChangePubkey)?
+                .send()
+                .await
+                .map_err(Error::ChangePubkey)?
+                .wait_for_commit()
+                .await
+                .map_err(Error::ChangePubkey)?;
+            if !tx_info.success.unwrap_or_default() {
+                return Err(Error::ChangePubkeyFailed(
+                    tx_info
+                        .fail_reason
+                        .unwrap_or_else(|| "Unknown error".to_owned()),
+                ));
+            }
+        }
+
+        let http_client = HttpClient::new();
+        let credentials = crate::auth::credentials(&http_client, url.as_str(), &owner_private_key)
+            .await
+            .map_err(Error::Authorization)?;
+        let request = crate::auth::authorize(
+            http_client.request(
+                Method::PUT,
+                Url::parse_with_params(
+                    format!("{}{}", url, zinc_const::zandbox::CONTRACT_KEY_URL).as_str(),
+                    KeyRequestQuery::new(address, network.into()),
+                )
+                .expect(zinc_const::panic::DATA_CONVERSION),
+            ),
+            &credentials,
+        );
+        let http_response = http_client
+            .execute(
+                request
+                    .json(&KeyRequestBody::new(private_key))
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await
+            .map_err(Error::HttpRequest);
+        let result = match http_response {
+            Ok(http_response) => crate::response::check_status(http_response)
+                .await
+                .map(|_http_response| ())
+                .map_err(Error::ActionFailed),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            eprintln!(
+                "     {} the new key is kept at {:?}, run the command again to finish the rotation",
+                "Warning".bright_yellow(),
+                rotated_key_path,
+            );
+            return Err(error);
+        }
+
+        if let Err(error) = fs::remove_file(&rotated_key_path) {
+            eprintln!(
+                "     {} the key file {:?} could not be removed: {}",
+                "Warning".bright_yellow(),
+                rotated_key_path,
+                error,
+            );
+        }
+
+        eprintln!(
+            "     {} the key of the contract with address {}",
+            "Rotated".bright_green(),
+            self.address,
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Reads the pending private key from the file at `path`.
+    ///
+    fn read_key(path: &PathBuf) -> Result<H256, FileError<rustc_hex::FromHexError>> {
+        let name = path.to_string_lossy().to_string();
+        let data =
+            fs::read_to_string(path).map_err(|error| FileError::Reading(name.clone(), error))?;
+        data.trim()
+            .parse()
+            .map_err(|error| FileError::Parsing(name, error))
+    }
+
+    ///
+    /// Writes the pending private key to the file at `path`.
+    ///
+    fn write_key(path: &PathBuf, key: &H256) -> Result<(), FileError<rustc_hex::FromHexError>> {
+        let name = path.to_string_lossy().to_string();
+        fs::write(path, format!("{:x}", key)).map_err(|error| FileError::Writing(name, error))
+    }
+}
@@ -57,6 +57,7 @@ impl Command {
 
         match manifest.project.r#type {
             ProjectType::Contract if self.method.is_none() => return Err(Error::MethodMissing),
+            ProjectType::Library => return Err(Error::LibraryNotExecutable),
             _ => {}
         }
 
@@ -96,6 +97,14 @@ impl Command {
             zinc_const::extension::BINARY
         ));
 
+        let libraries = Compiler::build_dependencies(
+            self.verbosity,
+            &manifest,
+            &manifest_path,
+            self.is_release,
+        )
+        .map_err(Error::Compiler)?;
+
         if self.is_release {
             Compiler::build_release(
                 self.verbosity,
@@ -105,6 +114,10 @@ impl Command {
                 &data_directory_path,
                 &source_directory_path,
                 &binary_path,
+                &libraries,
+                false,
+                false,
+                None,
                 false,
             )
             .map_err(Error::Compiler)?;
@@ -117,6 +130,10 @@ impl Command {
                 &data_directory_path,
                 &source_directory_path,
                 &binary_path,
+                &libraries,
+                false,
+                false,
+                None,
                 false,
             )
             .map_err(Error::Compiler)?;
@@ -50,6 +50,7 @@ impl Command {
 
         match manifest.project.r#type {
             ProjectType::Contract if self.method.is_none() => return Err(Error::MethodMissing),
+            ProjectType::Library => return Err(Error::LibraryNotExecutable),
             _ => {}
         }
 
@@ -16,6 +16,9 @@ pub enum Error {
     /// The manifest file error.
     #[fail(display = "manifest {}", _0)]
     Manifest(zinc_manifest::Error),
+    /// The project is a library, which can only be built and used as a dependency.
+    #[fail(display = "libraries cannot be executed, but only used as dependencies")]
+    LibraryNotExecutable,
     /// The project binary build directory error.
     #[fail(display = "build directory {}", _0)]
     BuildDirectory(DirectoryError),
@@ -10,6 +10,7 @@ use std::path::PathBuf;
 use structopt::StructOpt;
 
 use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
 
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
@@ -56,6 +57,10 @@ impl Command {
     pub fn execute(self) -> Result<(), Error> {
         let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
 
+        if let ProjectType::Library = manifest.project.r#type {
+            return Err(Error::LibraryNotExecutable);
+        }
+
         let mut manifest_path = self.manifest_path.clone();
         if manifest_path.is_file() {
             manifest_path.pop();
@@ -74,6 +79,10 @@ impl Command {
             zinc_const::extension::BINARY
         ));
 
+        let libraries =
+            Compiler::build_dependencies(self.verbosity, &manifest, &manifest_path, true)
+                .map_err(Error::Compiler)?;
+
         Compiler::build_release(
             self.verbosity,
             manifest.project.name.as_str(),
@@ -82,7 +91,11 @@ impl Command {
             &data_directory_path,
             &source_directory_path,
             &binary_path,
+            &libraries,
             true,
+            false,
+            None,
+            false,
         )
         .map_err(Error::Compiler)?;
 
@@ -50,6 +50,7 @@ impl Command {
 
         match manifest.project.r#type {
             ProjectType::Contract if self.method.is_none() => return Err(Error::MethodMissing),
+            ProjectType::Library => return Err(Error::LibraryNotExecutable),
             _ => {}
         }
 
@@ -59,6 +60,12 @@ impl Command {
         }
 
         let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut input_path = data_directory_path.clone();
+        input_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::INPUT,
+            zinc_const::extension::JSON,
+        ));
         let mut output_path = data_directory_path.clone();
         output_path.push(format!(
             "{}.{}",
@@ -81,6 +88,7 @@ impl Command {
                 self.verbosity,
                 &binary_path,
                 &verifying_key_path,
+                &input_path,
                 &output_path,
                 method.as_str(),
             ),
@@ -0,0 +1,151 @@
+//!
+//! The Zargo package manager `withdraw` subcommand.
+//!
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zksync::web3::types::H256;
+use zksync_eth_signer::PrivateKeySigner;
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::Address;
+use zksync_types::TokenLike;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::network::Config as NetworkConfig;
+use crate::transaction::error::Error as TransactionError;
+
+use self::error::Error;
+
+///
+/// The Zargo package manager `withdraw` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Withdraws tokens from zkSync to Ethereum")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the zkSync account resides.
+    /// Defaults to the `network.name` configuration key or `localhost`.
+    #[structopt(long = "network")]
+    pub network: Option<String>,
+
+    /// Sets the ETH address of the recipient. Defaults to the project private key address.
+    #[structopt(long = "recipient")]
+    pub recipient: Option<String>,
+
+    /// Sets the withdraw token symbol, which is also used to pay the fee.
+    /// Defaults to the `network.fee_token` configuration key or `ETH`.
+    #[structopt(long = "token")]
+    pub token: Option<String>,
+
+    /// Sets the withdraw amount in token units, e.g. `1.5`.
+    #[structopt(long = "amount")]
+    pub amount: String,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> Result<(), Error> {
+        let recipient: Option<Address> = match self.recipient {
+            Some(ref recipient) => Some(
+                zinc_math::eth_address_from_str(recipient.as_str())
+                    .map(Address::from)
+                    .map_err(Error::InvalidRecipientAddress)?,
+            ),
+            None => None,
+        };
+
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::Manifest)?;
+
+        let config = NetworkConfig::new(&manifest, self.network, None).map_err(Error::Network)?;
+        let network = config.network;
+        let token = self.token.unwrap_or(config.fee_token);
+
+        match manifest.project.r#type {
+            ProjectType::Contract => {}
+            _ => return Err(Error::NotAContract),
+        }
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let mut private_key_path = DataDirectory::path(&manifest_path);
+        private_key_path.push(zinc_const::file_name::PRIVATE_KEY.to_owned());
+
+        let private_key =
+            PrivateKeyFile::try_from(&private_key_path).map_err(Error::PrivateKeyFile)?;
+
+        let signer_private_key: H256 = private_key
+            .inner
+            .parse()
+            .map_err(Error::SenderPrivateKeyInvalid)?;
+        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)
+            .map_err(Error::SenderAddressDeriving)?;
+
+        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            signer_address,
+            PrivateKeySigner::new(signer_private_key),
+            network.into(),
+        )
+        .await
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        let wallet = zksync::Wallet::new(zksync::Provider::new(network.into()), wallet_credentials)
+            .await
+            .map_err(Error::WalletInitialization)?;
+
+        let token = wallet
+            .tokens
+            .resolve(TokenLike::Symbol(token))
+            .ok_or(TransactionError::TokenNotFound)
+            .map_err(Error::Transaction)?;
+        let amount = crate::transaction::amount::parse(self.amount.as_str(), token.decimals)
+            .map_err(Error::Transaction)?;
+        let recipient = recipient.unwrap_or(signer_address);
+
+        eprintln!(
+            " {} {} {} to {:?} on network `{}`",
+            "Withdrawing".bright_green(),
+            crate::transaction::amount::format(&amount, token.decimals),
+            token.symbol,
+            recipient,
+            network,
+        );
+
+        let balance = crate::transaction::withdraw(&wallet, recipient, &token, amount)
+            .await
+            .map_err(Error::Transaction)?;
+
+        eprintln!(
+            "     {} {} {}",
+            "Balance".bright_green(),
+            crate::transaction::amount::format(&balance, token.decimals),
+            token.symbol,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//!
+//! The Zandbox privileged request authorization error.
+//!
+
+use failure::Fail;
+
+///
+/// The Zandbox privileged request authorization error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The nonce HTTP request error.
+    #[fail(display = "nonce HTTP request: {}", _0)]
+    HttpRequest(reqwest::Error),
+    /// The nonce has not been issued by the server.
+    #[fail(display = "nonce request failed: {}", _0)]
+    ActionFailed(String),
+    /// The nonce signing error.
+    #[fail(display = "nonce {}", _0)]
+    Signing(zinc_zksync::CredentialsError),
+}
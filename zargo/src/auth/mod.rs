@@ -0,0 +1,64 @@
+//!
+//! The Zandbox privileged request authorization.
+//!
+
+pub mod error;
+
+use reqwest::Client as HttpClient;
+use reqwest::Method;
+use reqwest::RequestBuilder;
+
+use zksync::web3::types::H256;
+
+use zinc_zksync::Credentials;
+use zinc_zksync::NonceResponseBody;
+
+use self::error::Error;
+
+///
+/// Requests a nonce from the Zandbox server at `url` and signs it with the `private_key`,
+/// which must be the key of the contract owner.
+///
+pub async fn credentials(
+    http_client: &HttpClient,
+    url: &str,
+    private_key: &H256,
+) -> Result<Credentials, Error> {
+    let http_response = http_client
+        .execute(
+            http_client
+                .request(
+                    Method::POST,
+                    format!("{}{}", url, zinc_const::zandbox::CONTRACT_NONCE_URL).as_str(),
+                )
+                .build()
+                .expect(zinc_const::panic::DATA_CONVERSION),
+        )
+        .await
+        .map_err(Error::HttpRequest)?;
+
+    let http_response = crate::response::check_status(http_response)
+        .await
+        .map_err(Error::ActionFailed)?;
+    let response = http_response
+        .json::<NonceResponseBody>()
+        .await
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+    Credentials::sign(response.nonce, private_key).map_err(Error::Signing)
+}
+
+///
+/// Attaches the `credentials` headers to the privileged `request`.
+///
+pub fn authorize(request: RequestBuilder, credentials: &Credentials) -> RequestBuilder {
+    request
+        .header(
+            zinc_const::zandbox::HEADER_AUTH_NONCE,
+            credentials.nonce.as_str(),
+        )
+        .header(
+            zinc_const::zandbox::HEADER_AUTH_SIGNATURE,
+            credentials.signature_hex(),
+        )
+}
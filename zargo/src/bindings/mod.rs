@@ -0,0 +1,5 @@
+//!
+//! The contract client bindings.
+//!
+
+pub mod typescript;
@@ -0,0 +1,172 @@
+//!
+//! The TypeScript contract bindings.
+//!
+
+pub mod r#type;
+
+use std::fmt::Write;
+
+use inflector::Inflector;
+
+use zinc_build::Contract as BuildContract;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::Type as BuildType;
+
+use self::r#type::Mapper;
+
+///
+/// The TypeScript module with the contract client bindings.
+///
+/// Each contract method except the constructor is bound to an async function, which sends the
+/// request to the zandbox `query` endpoint for immutable methods and to the `call` endpoint for
+/// mutable ones.
+///
+pub struct Module {
+    /// The module source code.
+    pub inner: String,
+}
+
+impl Module {
+    /// The client helpers, which do not depend on the contract.
+    const PRELUDE: &'static str = r#"export interface ContractOptions {
+    /** The zandbox server URL, e.g. `http://localhost:4001`. */
+    url: string;
+    /** The contract ETH address. */
+    address: string;
+    /** The network where the contract resides, e.g. `localhost` or `rinkeby`. */
+    network: string;
+}
+
+function encode(value: unknown): string {
+    return JSON.stringify(value, (_key: string, item: unknown) =>
+        typeof item === "number" || typeof item === "bigint" ? item.toString() : item,
+    );
+}
+
+async function request(
+    options: ContractOptions,
+    httpMethod: "PUT" | "POST",
+    path: string,
+    method: string,
+    body: unknown,
+): Promise<any> {
+    const query = new URLSearchParams({
+        address: options.address,
+        method,
+        network: options.network,
+    });
+    const response = await fetch(`${options.url}${path}?${query}`, {
+        method: httpMethod,
+        headers: { "Content-Type": "application/json" },
+        body: encode(body),
+    });
+    const data = await response.json();
+    if (!response.ok) {
+        throw new Error(typeof data === "string" ? data : JSON.stringify(data));
+    }
+    return data.output;
+}
+"#;
+
+    ///
+    /// Generates the bindings for the `contract` methods.
+    ///
+    pub fn new(contract: &BuildContract) -> Self {
+        let mut methods: Vec<&BuildContractMethod> = contract
+            .methods
+            .values()
+            .filter(|method| method.name != zinc_const::contract::CONSTRUCTOR_NAME)
+            .collect();
+        methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut mapper = Mapper::new();
+        let mut functions = Vec::with_capacity(methods.len());
+        for method in methods.into_iter() {
+            functions.push(Self::function(&mut mapper, method));
+        }
+
+        let mut inner = String::new();
+        writeln!(inner, "//").expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(
+            inner,
+            "// The `{}` contract client bindings.",
+            contract.name
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(inner, "//").expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(inner, "// Generated by `zargo bind`. Do not edit.")
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(inner, "//").expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(inner).expect(zinc_const::panic::DATA_CONVERSION);
+        inner.push_str(Self::PRELUDE);
+        for interface in mapper.interfaces().iter() {
+            writeln!(inner).expect(zinc_const::panic::DATA_CONVERSION);
+            write!(inner, "{}", interface).expect(zinc_const::panic::DATA_CONVERSION);
+        }
+        for function in functions.into_iter() {
+            writeln!(inner).expect(zinc_const::panic::DATA_CONVERSION);
+            inner.push_str(function.as_str());
+        }
+
+        Self { inner }
+    }
+
+    ///
+    /// Generates the async function for the contract `method`.
+    ///
+    fn function(mapper: &mut Mapper, method: &BuildContractMethod) -> String {
+        let prefix = method.name.to_pascal_case();
+
+        let mut parameters = vec!["options: ContractOptions".to_owned()];
+        let arguments = match method.input {
+            BuildType::Structure(ref fields) if fields.is_empty() => "{}",
+            _ => {
+                let input_type = mapper.map(&method.input, format!("{}Input", prefix).as_str());
+                parameters.push(format!("args: {}", input_type));
+                "args"
+            }
+        };
+        let output_type = mapper.map(&method.output, format!("{}Output", prefix).as_str());
+
+        let (description, http_method, path, body, output) = if method.is_mutable {
+            parameters.push("transaction: unknown[]".to_owned());
+            (
+                "Calls the mutable",
+                "POST",
+                zinc_const::zandbox::CONTRACT_CALL_URL,
+                format!("{{ arguments: {}, transaction }}", arguments),
+                Mapper::decoder(&method.output, "output.result"),
+            )
+        } else {
+            (
+                "Queries the immutable",
+                "PUT",
+                zinc_const::zandbox::CONTRACT_QUERY_URL,
+                format!("{{ arguments: {} }}", arguments),
+                Mapper::decoder(&method.output, "output"),
+            )
+        };
+
+        let mut function = String::new();
+        writeln!(function, "/** {} `{}` method. */", description, method.name)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(
+            function,
+            "export async function {}(\n    {},\n): Promise<{}> {{",
+            method.name.to_camel_case(),
+            parameters.join(",\n    "),
+            output_type,
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(
+            function,
+            "    const output = await request(options, \"{}\", \"{}\", \"{}\", {});",
+            http_method, path, method.name, body,
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(function, "    return {} as {};", output, output_type)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        writeln!(function, "}}").expect(zinc_const::panic::DATA_CONVERSION);
+        function
+    }
+}
@@ -0,0 +1,331 @@
+//!
+//! The TypeScript type mapping.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use inflector::Inflector;
+
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+///
+/// The TypeScript interface declaration.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interface {
+    /// The interface name.
+    pub name: String,
+    /// The interface fields with their TypeScript type expressions.
+    pub fields: Vec<(String, String)>,
+}
+
+impl Interface {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, fields: Vec<(String, String)>) -> Self {
+        Self { name, fields }
+    }
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "export interface {} {{", self.name)?;
+        for (name, r#type) in self.fields.iter() {
+            writeln!(f, "    {}: {};", name, r#type)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+///
+/// The Zinc to TypeScript type mapper.
+///
+/// Zinc structures are anonymous in the bytecode metadata, so the mapper declares an interface
+/// for each of them, named after its path from the root type, e.g. `TransferInput` for the
+/// root structure and `TransferInputRecipient` for its `recipient` field.
+///
+/// The zandbox JSON API passes all integers as strings, so the mapper also generates the
+/// expressions, which convert the JSON values into the mapped TypeScript types.
+///
+#[derive(Debug, Default)]
+pub struct Mapper {
+    /// The interfaces declared so far, in the order of declaration.
+    interfaces: Vec<Interface>,
+}
+
+impl Mapper {
+    /// The maximal integer bitlength, whose values fit into the TypeScript `number` type.
+    pub const NUMBER_BITLENGTH_LIMIT: usize = 53;
+
+    /// The maximal integer bitlength, which is mapped to the TypeScript `bigint` type.
+    /// Wider integers, like `u160` addresses or `u248`, are mapped to strings.
+    pub const BIGINT_BITLENGTH_LIMIT: usize = 128;
+
+    /// The maximal array size, which is mapped to a TypeScript tuple instead of an array.
+    pub const TUPLE_SIZE_LIMIT: usize = 8;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Maps the Zinc `type` to a TypeScript type expression.
+    ///
+    /// The structures are declared as interfaces, where the root one is named `name`.
+    ///
+    pub fn map(&mut self, r#type: &BuildType, name: &str) -> String {
+        match r#type {
+            BuildType::Unit => "null".to_owned(),
+            BuildType::Scalar(r#type) => Self::scalar(r#type).to_owned(),
+            BuildType::Enumeration { variants, .. } => variants
+                .iter()
+                .map(|(name, _value)| format!("\"{}\"", name))
+                .collect::<Vec<String>>()
+                .join(" | "),
+
+            BuildType::Array(r#type, size) => {
+                let element = self.map(r#type, format!("{}Element", name).as_str());
+                if *size <= Self::TUPLE_SIZE_LIMIT {
+                    format!("[{}]", vec![element; *size].join(", "))
+                } else {
+                    format!("Array<{}>", element)
+                }
+            }
+            BuildType::Tuple(types) => {
+                let mut elements = Vec::with_capacity(types.len());
+                for (index, r#type) in types.iter().enumerate() {
+                    elements.push(self.map(r#type, format!("{}{}", name, index).as_str()));
+                }
+                format!("[{}]", elements.join(", "))
+            }
+            BuildType::Structure(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(field, r#type)| (field.to_owned(), r#type.to_owned()))
+                    .collect();
+                self.declare(name, fields)
+            }
+            BuildType::Contract(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| (field.name.to_owned(), field.r#type.to_owned()))
+                    .collect();
+                self.declare(name, fields)
+            }
+
+            BuildType::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "Array<{{ key: {}; value: {} }}>",
+                self.map(key_type, format!("{}Key", name).as_str()),
+                self.map(value_type, format!("{}Value", name).as_str()),
+            ),
+            BuildType::Maybe(r#type) => format!("{} | null", self.map(r#type, name)),
+        }
+    }
+
+    ///
+    /// Generates the expression, which converts the JSON `expression` of the Zinc `type`
+    /// into the value of the type returned by `map`.
+    ///
+    pub fn decoder(r#type: &BuildType, expression: &str) -> String {
+        Self::decoder_nested(r#type, expression, 0)
+    }
+
+    ///
+    /// Returns the interfaces declared so far.
+    ///
+    pub fn interfaces(&self) -> &[Interface] {
+        self.interfaces.as_slice()
+    }
+
+    ///
+    /// Maps the Zinc scalar `type` to a TypeScript type.
+    ///
+    fn scalar(r#type: &ScalarType) -> &'static str {
+        match r#type {
+            ScalarType::Boolean => "boolean",
+            ScalarType::Integer(inner) if inner.bitlength <= Self::NUMBER_BITLENGTH_LIMIT => {
+                "number"
+            }
+            ScalarType::Integer(inner) if inner.bitlength <= Self::BIGINT_BITLENGTH_LIMIT => {
+                "bigint"
+            }
+            ScalarType::Integer(_) => "string",
+            ScalarType::Field => "string",
+        }
+    }
+
+    ///
+    /// Declares the interface for the structure `fields`, naming it `name`.
+    ///
+    /// If a different interface with the same name has been already declared, a numeric suffix
+    /// is appended to the name. Identical declarations are reused.
+    ///
+    fn declare(&mut self, name: &str, fields: Vec<(String, BuildType)>) -> String {
+        let fields = fields
+            .into_iter()
+            .map(|(field, r#type)| {
+                let prefix = format!("{}{}", name, field.to_pascal_case());
+                let r#type = self.map(&r#type, prefix.as_str());
+                (field, r#type)
+            })
+            .collect::<Vec<(String, String)>>();
+
+        let mut unique_name = name.to_owned();
+        let mut index = 1;
+        while let Some(declared) = self
+            .interfaces
+            .iter()
+            .find(|interface| interface.name == unique_name)
+        {
+            if declared.fields == fields {
+                return unique_name;
+            }
+
+            index += 1;
+            unique_name = format!("{}{}", name, index);
+        }
+
+        self.interfaces
+            .push(Interface::new(unique_name.clone(), fields));
+        unique_name
+    }
+
+    ///
+    /// Generates the conversion expression, using the `depth` to name the closure arguments.
+    ///
+    fn decoder_nested(r#type: &BuildType, expression: &str, depth: usize) -> String {
+        if !Self::needs_decoding(r#type) {
+            return expression.to_owned();
+        }
+
+        let argument = format!("v{}", depth);
+        match r#type {
+            BuildType::Scalar(ScalarType::Integer(inner))
+                if inner.bitlength <= Self::NUMBER_BITLENGTH_LIMIT =>
+            {
+                format!("Number({})", expression)
+            }
+            BuildType::Scalar(ScalarType::Integer(_)) => format!("BigInt({})", expression),
+
+            BuildType::Array(r#type, _size) => format!(
+                "{}.map(({}: any) => {})",
+                expression,
+                argument,
+                Self::decoder_nested(r#type, argument.as_str(), depth + 1),
+            ),
+            BuildType::Tuple(types) => format!(
+                "[{}]",
+                types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, r#type)| Self::decoder_nested(
+                        r#type,
+                        format!("{}[{}]", expression, index).as_str(),
+                        depth
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            BuildType::Structure(fields) => Self::decoder_object(
+                fields
+                    .iter()
+                    .map(|(name, r#type)| (name.as_str(), r#type))
+                    .collect(),
+                expression,
+                depth,
+            ),
+            BuildType::Contract(fields) => Self::decoder_object(
+                fields
+                    .iter()
+                    .map(|field| (field.name.as_str(), &field.r#type))
+                    .collect(),
+                expression,
+                depth,
+            ),
+
+            BuildType::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "{}.map(({}: any) => ({{ key: {}, value: {} }}))",
+                expression,
+                argument,
+                Self::decoder_nested(key_type, format!("{}.key", argument).as_str(), depth + 1),
+                Self::decoder_nested(
+                    value_type,
+                    format!("{}.value", argument).as_str(),
+                    depth + 1
+                ),
+            ),
+            BuildType::Maybe(r#type) => format!(
+                "({} === null ? null : {})",
+                expression,
+                Self::decoder_nested(r#type, expression, depth),
+            ),
+
+            _ => expression.to_owned(),
+        }
+    }
+
+    ///
+    /// Generates the conversion expression for a structure or contract with `fields`.
+    ///
+    fn decoder_object(fields: Vec<(&str, &BuildType)>, expression: &str, depth: usize) -> String {
+        format!(
+            "({{ {} }})",
+            fields
+                .into_iter()
+                .map(|(name, r#type)| format!(
+                    "{}: {}",
+                    name,
+                    Self::decoder_nested(
+                        r#type,
+                        format!("{}.{}", expression, name).as_str(),
+                        depth
+                    )
+                ))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    ///
+    /// Checks whether the JSON value of the Zinc `type` differs from the mapped TypeScript value.
+    ///
+    fn needs_decoding(r#type: &BuildType) -> bool {
+        match r#type {
+            BuildType::Unit => false,
+            BuildType::Scalar(ScalarType::Integer(inner)) => {
+                inner.bitlength <= Self::BIGINT_BITLENGTH_LIMIT
+            }
+            BuildType::Scalar(_) => false,
+            BuildType::Enumeration { .. } => false,
+
+            BuildType::Array(r#type, _size) => Self::needs_decoding(r#type),
+            BuildType::Tuple(types) => types.iter().any(Self::needs_decoding),
+            BuildType::Structure(fields) => fields
+                .iter()
+                .any(|(_name, r#type)| Self::needs_decoding(r#type)),
+            BuildType::Contract(fields) => fields
+                .iter()
+                .any(|field| Self::needs_decoding(&field.r#type)),
+
+            BuildType::Map {
+                key_type,
+                value_type,
+            } => Self::needs_decoding(key_type) || Self::needs_decoding(value_type),
+            BuildType::Maybe(r#type) => Self::needs_decoding(r#type),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//!
+//! The TypeScript type mapping tests.
+//!
+
+use num::BigInt;
+
+use zinc_build::ContractFieldType;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use super::Interface;
+use super::Mapper;
+
+fn integer(is_signed: bool, bitlength: usize) -> BuildType {
+    BuildType::Scalar(ScalarType::Integer(IntegerType::new(is_signed, bitlength)))
+}
+
+#[test]
+fn ok_scalars() {
+    let mut mapper = Mapper::new();
+
+    assert_eq!(
+        mapper.map(&BuildType::Scalar(ScalarType::Boolean), "X"),
+        "boolean"
+    );
+    assert_eq!(mapper.map(&integer(false, 8), "X"), "number");
+    assert_eq!(mapper.map(&integer(true, 32), "X"), "number");
+    assert_eq!(mapper.map(&integer(false, 64), "X"), "bigint");
+    assert_eq!(mapper.map(&integer(false, 128), "X"), "bigint");
+    assert_eq!(mapper.map(&integer(false, 160), "X"), "string");
+    assert_eq!(mapper.map(&integer(false, 248), "X"), "string");
+    assert_eq!(
+        mapper.map(&BuildType::Scalar(ScalarType::Field), "X"),
+        "string"
+    );
+    assert_eq!(mapper.map(&BuildType::Unit, "X"), "null");
+    assert!(mapper.interfaces().is_empty());
+}
+
+#[test]
+fn ok_enumeration() {
+    let mut mapper = Mapper::new();
+
+    let r#type = BuildType::Enumeration {
+        bitlength: 8,
+        variants: vec![
+            ("Red".to_owned(), BigInt::from(0)),
+            ("Green".to_owned(), BigInt::from(1)),
+        ],
+    };
+
+    assert_eq!(mapper.map(&r#type, "X"), "\"Red\" | \"Green\"");
+}
+
+#[test]
+fn ok_arrays() {
+    let mut mapper = Mapper::new();
+
+    assert_eq!(
+        mapper.map(&BuildType::Array(Box::new(integer(false, 8)), 3), "X"),
+        "[number, number, number]"
+    );
+    assert_eq!(
+        mapper.map(
+            &BuildType::Array(Box::new(integer(false, 64)), Mapper::TUPLE_SIZE_LIMIT + 1),
+            "X"
+        ),
+        "Array<bigint>"
+    );
+}
+
+#[test]
+fn ok_nested_structures() {
+    let mut mapper = Mapper::new();
+
+    let r#type = BuildType::Structure(vec![
+        ("amount".to_owned(), integer(false, 64)),
+        (
+            "recipient".to_owned(),
+            BuildType::Structure(vec![
+                ("address".to_owned(), integer(false, 160)),
+                (
+                    "tags".to_owned(),
+                    BuildType::Array(
+                        Box::new(BuildType::Structure(vec![(
+                            "value".to_owned(),
+                            integer(false, 8),
+                        )])),
+                        2,
+                    ),
+                ),
+            ]),
+        ),
+        (
+            "pair".to_owned(),
+            BuildType::Tuple(vec![
+                BuildType::Scalar(ScalarType::Boolean),
+                BuildType::Structure(vec![(
+                    "hash".to_owned(),
+                    BuildType::Scalar(ScalarType::Field),
+                )]),
+            ]),
+        ),
+    ]);
+
+    assert_eq!(mapper.map(&r#type, "TransferInput"), "TransferInput");
+    assert_eq!(
+        mapper.interfaces(),
+        &[
+            Interface::new(
+                "TransferInputRecipientTagsElement".to_owned(),
+                vec![("value".to_owned(), "number".to_owned())],
+            ),
+            Interface::new(
+                "TransferInputRecipient".to_owned(),
+                vec![
+                    ("address".to_owned(), "string".to_owned()),
+                    (
+                        "tags".to_owned(),
+                        "[TransferInputRecipientTagsElement, TransferInputRecipientTagsElement]"
+                            .to_owned()
+                    ),
+                ],
+            ),
+            Interface::new(
+                "TransferInputPair1".to_owned(),
+                vec![("hash".to_owned(), "string".to_owned())],
+            ),
+            Interface::new(
+                "TransferInput".to_owned(),
+                vec![
+                    ("amount".to_owned(), "bigint".to_owned()),
+                    ("recipient".to_owned(), "TransferInputRecipient".to_owned()),
+                    (
+                        "pair".to_owned(),
+                        "[boolean, TransferInputPair1]".to_owned()
+                    ),
+                ],
+            ),
+        ][..]
+    );
+}
+
+#[test]
+fn ok_map_of_maybe_structures() {
+    let mut mapper = Mapper::new();
+
+    let r#type = BuildType::Map {
+        key_type: Box::new(integer(false, 160)),
+        value_type: Box::new(BuildType::Maybe(Box::new(BuildType::Structure(vec![(
+            "balance".to_owned(),
+            integer(false, 248),
+        )])))),
+    };
+
+    assert_eq!(
+        mapper.map(&r#type, "Balances"),
+        "Array<{ key: string; value: BalancesValue | null }>"
+    );
+    assert_eq!(
+        mapper.interfaces(),
+        &[Interface::new(
+            "BalancesValue".to_owned(),
+            vec![("balance".to_owned(), "string".to_owned())],
+        )][..]
+    );
+}
+
+#[test]
+fn ok_contract_storage() {
+    let mut mapper = Mapper::new();
+
+    let r#type = BuildType::Contract(vec![
+        ContractFieldType::new("balance".to_owned(), integer(false, 248), true, false),
+        ContractFieldType::new("count".to_owned(), integer(false, 16), true, false),
+    ]);
+
+    assert_eq!(mapper.map(&r#type, "Storage"), "Storage");
+    assert_eq!(
+        mapper.interfaces(),
+        &[Interface::new(
+            "Storage".to_owned(),
+            vec![
+                ("balance".to_owned(), "string".to_owned()),
+                ("count".to_owned(), "number".to_owned()),
+            ],
+        )][..]
+    );
+}
+
+#[test]
+fn ok_identical_interfaces_reused() {
+    let mut mapper = Mapper::new();
+
+    let r#type = BuildType::Structure(vec![("value".to_owned(), integer(false, 8))]);
+
+    assert_eq!(mapper.map(&r#type, "Point"), "Point");
+    assert_eq!(mapper.map(&r#type, "Point"), "Point");
+    assert_eq!(mapper.interfaces().len(), 1);
+}
+
+#[test]
+fn ok_conflicting_interfaces_renamed() {
+    let mut mapper = Mapper::new();
+
+    let first = BuildType::Structure(vec![("value".to_owned(), integer(false, 8))]);
+    let second = BuildType::Structure(vec![(
+        "value".to_owned(),
+        BuildType::Scalar(ScalarType::Field),
+    )]);
+
+    assert_eq!(mapper.map(&first, "Point"), "Point");
+    assert_eq!(mapper.map(&second, "Point"), "Point2");
+    assert_eq!(mapper.interfaces().len(), 2);
+}
+
+#[test]
+fn ok_decoder_identity() {
+    let r#type = BuildType::Structure(vec![
+        ("flag".to_owned(), BuildType::Scalar(ScalarType::Boolean)),
+        ("hash".to_owned(), BuildType::Scalar(ScalarType::Field)),
+        ("address".to_owned(), integer(false, 160)),
+    ]);
+
+    assert_eq!(Mapper::decoder(&r#type, "output"), "output");
+}
+
+#[test]
+fn ok_decoder_nested() {
+    let r#type = BuildType::Structure(vec![
+        ("hash".to_owned(), BuildType::Scalar(ScalarType::Field)),
+        (
+            "points".to_owned(),
+            BuildType::Array(
+                Box::new(BuildType::Structure(vec![
+                    ("x".to_owned(), integer(false, 8)),
+                    (
+                        "y".to_owned(),
+                        BuildType::Array(Box::new(integer(false, 64)), 2),
+                    ),
+                ])),
+                4,
+            ),
+        ),
+        (
+            "pair".to_owned(),
+            BuildType::Tuple(vec![
+                BuildType::Maybe(Box::new(integer(true, 16))),
+                BuildType::Scalar(ScalarType::Boolean),
+            ]),
+        ),
+    ]);
+
+    assert_eq!(
+        Mapper::decoder(&r#type, "output"),
+        "({ hash: output.hash, \
+         points: output.points.map((v0: any) => ({ x: Number(v0.x), y: v0.y.map((v1: any) => BigInt(v1)) })), \
+         pair: [(output.pair[0] === null ? null : Number(output.pair[0])), output.pair[1]] })"
+    );
+}
+
+#[test]
+fn ok_decoder_map() {
+    let r#type = BuildType::Map {
+        key_type: Box::new(integer(false, 160)),
+        value_type: Box::new(integer(false, 64)),
+    };
+
+    assert_eq!(
+        Mapper::decoder(&r#type, "output"),
+        "output.map((v0: any) => ({ key: v0.key, value: BigInt(v0.value) }))"
+    );
+}
@@ -2,6 +2,7 @@
 //! The compiler executable.
 //!
 
+use std::convert::TryFrom;
 use std::io;
 use std::path::PathBuf;
 use std::process;
@@ -10,6 +11,13 @@ use std::process::ExitStatus;
 use colored::Colorize;
 use failure::Fail;
 
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::project::build::Directory as BuildDirectory;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::source::Directory as SourceDirectory;
+
 ///
 /// The Zinc compiler process representation.
 ///
@@ -29,6 +37,15 @@ pub enum Error {
     /// The process returned a non-success exit code.
     #[fail(display = "failure: {}", _0)]
     Failure(ExitStatus),
+    /// The dependency manifest file error.
+    #[fail(display = "dependency `{}` manifest {}", _0, _1)]
+    DependencyManifest(String, zinc_manifest::Error),
+    /// The dependency project is not a library.
+    #[fail(
+        display = "dependency `{}` is a {}, but only libraries can be dependencies",
+        _0, _1
+    )]
+    DependencyNotLibrary(String, ProjectType),
 }
 
 impl Compiler {
@@ -37,6 +54,15 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `is_deny_warnings` is set, the compiler fails if there are any warnings.
+    ///
+    /// If `emit` is set, the compiler writes the `ir`, `bytecode`, or `both` listings next to
+    /// the binary.
+    ///
+    /// If `is_timings` is set, the compiler prints the per-module compilation timings.
+    ///
+    /// The `libraries` are the dependency names with the paths to their library interfaces.
+    ///
     #[allow(clippy::too_many_arguments)]
     pub fn build_debug(
         verbosity: usize,
@@ -46,7 +72,11 @@ impl Compiler {
         data_path: &PathBuf,
         source_path: &PathBuf,
         binary_path: &PathBuf,
+        libraries: &[(String, PathBuf)],
         is_test_only: bool,
+        is_deny_warnings: bool,
+        emit: Option<&str>,
+        is_timings: bool,
     ) -> Result<(), Error> {
         eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
 
@@ -58,11 +88,31 @@ impl Compiler {
             .arg(data_path)
             .arg("--binary")
             .arg(binary_path)
+            .args(libraries.iter().flat_map(|(name, path)| {
+                vec![
+                    "--library".to_owned(),
+                    format!("{}={}", name, path.to_string_lossy()),
+                ]
+            }))
             .args(if is_test_only {
                 vec!["--test-only"]
             } else {
                 vec![]
             })
+            .args(if is_deny_warnings {
+                vec!["--deny-warnings"]
+            } else {
+                vec![]
+            })
+            .args(match emit {
+                Some(emit) => vec!["--emit", emit],
+                None => vec![],
+            })
+            .args(if is_timings {
+                vec!["--timings"]
+            } else {
+                vec![]
+            })
             .arg(source_path)
             .spawn()
             .map_err(Error::Spawning)?;
@@ -83,6 +133,15 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `is_deny_warnings` is set, the compiler fails if there are any warnings.
+    ///
+    /// If `emit` is set, the compiler writes the `ir`, `bytecode`, or `both` listings next to
+    /// the binary.
+    ///
+    /// If `is_timings` is set, the compiler prints the per-module compilation timings.
+    ///
+    /// The `libraries` are the dependency names with the paths to their library interfaces.
+    ///
     #[allow(clippy::too_many_arguments)]
     pub fn build_release(
         verbosity: usize,
@@ -92,7 +151,11 @@ impl Compiler {
         data_path: &PathBuf,
         source_path: &PathBuf,
         binary_path: &PathBuf,
+        libraries: &[(String, PathBuf)],
         is_test_only: bool,
+        is_deny_warnings: bool,
+        emit: Option<&str>,
+        is_timings: bool,
     ) -> Result<(), Error> {
         eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
 
@@ -104,12 +167,33 @@ impl Compiler {
             .arg(data_path)
             .arg("--binary")
             .arg(binary_path)
+            .args(libraries.iter().flat_map(|(name, path)| {
+                vec![
+                    "--library".to_owned(),
+                    format!("{}={}", name, path.to_string_lossy()),
+                ]
+            }))
             .args(if is_test_only {
                 vec!["--test-only"]
             } else {
                 vec![]
             })
+            .args(if is_deny_warnings {
+                vec!["--deny-warnings"]
+            } else {
+                vec![]
+            })
+            .args(match emit {
+                Some(emit) => vec!["--emit", emit],
+                None => vec![],
+            })
+            .args(if is_timings {
+                vec!["--timings"]
+            } else {
+                vec![]
+            })
             .arg("--opt-dfe")
+            .arg("--opt-peephole")
             .arg(source_path)
             .spawn()
             .map_err(Error::Spawning)?;
@@ -127,4 +211,74 @@ impl Compiler {
 
         Ok(())
     }
+
+    ///
+    /// Builds the library dependencies of the project at `project_path` recursively.
+    ///
+    /// Returns the dependency names with the paths to their library interfaces, which must be
+    /// passed to the compiler building the dependent project.
+    ///
+    pub fn build_dependencies(
+        verbosity: usize,
+        manifest: &Manifest,
+        project_path: &PathBuf,
+        is_release: bool,
+    ) -> Result<Vec<(String, PathBuf)>, Error> {
+        let mut dependencies: Vec<(&String, &zinc_manifest::Dependency)> =
+            manifest.dependencies.iter().collect();
+        dependencies.sort_by(|(name_1, _), (name_2, _)| name_1.cmp(name_2));
+
+        let mut libraries = Vec::with_capacity(dependencies.len());
+        for (name, dependency) in dependencies.into_iter() {
+            let mut dependency_path = project_path.to_owned();
+            dependency_path.push(&dependency.path);
+
+            let dependency_manifest = Manifest::try_from(&dependency_path)
+                .map_err(|error| Error::DependencyManifest(name.to_owned(), error))?;
+            if !matches!(dependency_manifest.project.r#type, ProjectType::Library) {
+                return Err(Error::DependencyNotLibrary(
+                    name.to_owned(),
+                    dependency_manifest.project.r#type,
+                ));
+            }
+
+            let dependency_libraries = Self::build_dependencies(
+                verbosity,
+                &dependency_manifest,
+                &dependency_path,
+                is_release,
+            )?;
+
+            let mut library_path = BuildDirectory::path(&dependency_path);
+            library_path.push(format!(
+                "{}.{}",
+                zinc_const::file_name::LIBRARY,
+                zinc_const::extension::LIBRARY
+            ));
+
+            let build = if is_release {
+                Self::build_release
+            } else {
+                Self::build_debug
+            };
+            build(
+                verbosity,
+                dependency_manifest.project.name.as_str(),
+                dependency_manifest.project.version.as_str(),
+                &dependency_path,
+                &DataDirectory::path(&dependency_path),
+                &SourceDirectory::path(&dependency_path),
+                &library_path,
+                dependency_libraries.as_slice(),
+                false,
+                false,
+                None,
+                false,
+            )?;
+
+            libraries.push((name.to_owned(), library_path));
+        }
+
+        Ok(libraries)
+    }
 }
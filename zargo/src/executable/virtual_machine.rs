@@ -346,6 +346,7 @@ impl VirtualMachine {
         verbosity: usize,
         binary_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        input_path: &PathBuf,
         output_path: &PathBuf,
         method: &str,
     ) -> Result<(), Error> {
@@ -363,6 +364,8 @@ impl VirtualMachine {
             .arg(binary_path)
             .arg("--verifying-key")
             .arg(verifying_key_path)
+            .arg("--input")
+            .arg(input_path)
             .arg("--output")
             .arg(output_path)
             .arg("--method")
@@ -498,6 +501,8 @@ impl VirtualMachine {
             .arg(binary_path)
             .arg("--verifying-key")
             .arg(verifying_key_path)
+            .arg("--input")
+            .arg(input_path)
             .arg("--output")
             .arg(output_path)
             .arg("--method")
@@ -3,10 +3,13 @@
 //!
 
 pub(crate) mod arguments;
+pub(crate) mod auth;
+pub(crate) mod bindings;
 pub(crate) mod error;
 pub(crate) mod executable;
 pub(crate) mod network;
 pub(crate) mod project;
+pub(crate) mod response;
 pub(crate) mod transaction;
 
 use std::process;
@@ -0,0 +1,62 @@
+//!
+//! The contract ABI file.
+//!
+
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use serde_json::Value as JsonValue;
+
+use crate::error::file::Error as FileError;
+
+///
+/// The contract ABI file representation.
+///
+pub struct Abi {
+    /// The ABI JSON document.
+    pub inner: JsonValue,
+}
+
+impl Abi {
+    ///
+    /// Parses the ABI file of the `name` contract at `path`.
+    ///
+    pub fn try_from_path(path: &PathBuf, name: &str) -> Result<Self, FileError<serde_json::Error>> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(zinc_const::directory::BUILD) {
+                path.push(PathBuf::from(zinc_const::directory::BUILD));
+            }
+            path.push(PathBuf::from(Self::file_name(name)));
+        }
+
+        let mut file =
+            File::open(path).map_err(|error| FileError::Opening(Self::file_name(name), error))?;
+        let size = file
+            .metadata()
+            .map_err(|error| FileError::Metadata(Self::file_name(name), error))?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .map_err(|error| FileError::Reading(Self::file_name(name), error))?;
+
+        let inner = serde_json::from_str(buffer.as_str())
+            .map_err(|error| FileError::Parsing(Self::file_name(name), error))?;
+
+        Ok(Self { inner })
+    }
+
+    ///
+    /// Creates a string with the `name` contract file name.
+    ///
+    fn file_name(name: &str) -> String {
+        format!(
+            "{}.{}.{}",
+            name,
+            zinc_const::extension::ABI,
+            zinc_const::extension::JSON,
+        )
+    }
+}
@@ -0,0 +1,234 @@
+//!
+//! The incremental build cache file.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::error::file::Error as FileError;
+
+///
+/// The incremental build cache file representation.
+///
+/// Contains the content hashes of the project manifest and source files, and the hash of the
+/// bytecode, which was built from them.
+///
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cache {
+    /// Whether the binary was built in the release mode.
+    pub is_release: bool,
+    /// Whether the binary was built with warnings denied, that is, without any warnings.
+    pub is_deny_warnings: bool,
+    /// The file content hashes, keyed by the path relative to the project root.
+    pub files: BTreeMap<String, String>,
+    /// The hash of the bytecode built from `files`.
+    pub binary: Option<String>,
+}
+
+impl Cache {
+    ///
+    /// Hashes the manifest and every source file of the project at `project_path`.
+    ///
+    pub fn new(
+        project_path: &Path,
+        manifest_path: &Path,
+        source_path: &Path,
+        is_release: bool,
+        is_deny_warnings: bool,
+    ) -> Result<Self, FileError> {
+        let mut files = BTreeMap::new();
+        Self::hash_file(project_path, manifest_path, &mut files)?;
+        Self::hash_directory(project_path, source_path, &mut files)?;
+
+        Ok(Self {
+            is_release,
+            is_deny_warnings,
+            files,
+            binary: None,
+        })
+    }
+
+    ///
+    /// Sets the hash of the `bytecode` built from the cached files.
+    ///
+    pub fn set_binary(&mut self, bytecode: &[u8]) {
+        self.binary = Some(Self::hash(bytecode));
+    }
+
+    ///
+    /// Returns the files which have been added, modified, or deleted since the `previous` build.
+    ///
+    pub fn changed_files(&self, previous: &Self) -> Vec<String> {
+        let mut changed: Vec<String> = self
+            .files
+            .iter()
+            .filter(|(path, hash)| previous.files.get(path.as_str()) != Some(hash))
+            .map(|(path, _hash)| path.to_owned())
+            .collect();
+
+        changed.extend(
+            previous
+                .files
+                .keys()
+                .filter(|path| !self.files.contains_key(path.as_str()))
+                .cloned(),
+        );
+        changed.sort();
+
+        changed
+    }
+
+    ///
+    /// Checks whether the `previous` build can be reused instead of running the compiler.
+    ///
+    /// The `bytecode` is the binary currently found in the build directory, if any.
+    ///
+    pub fn is_fresh(&self, previous: &Self, bytecode: Option<&[u8]>) -> bool {
+        if self.is_release != previous.is_release {
+            return false;
+        }
+
+        if self.is_deny_warnings && !previous.is_deny_warnings {
+            return false;
+        }
+
+        if !self.changed_files(previous).is_empty() {
+            return false;
+        }
+
+        match (previous.binary.as_ref(), bytecode) {
+            (Some(expected), Some(bytecode)) => expected == &Self::hash(bytecode),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Writes the contents to a file in the project build directory at the given `path`.
+    ///
+    pub fn write_to(&self, path: &PathBuf) -> Result<(), FileError> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(zinc_const::directory::BUILD) {
+                path.push(PathBuf::from(zinc_const::directory::BUILD));
+            }
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let data = serde_json::to_vec_pretty(self).expect(zinc_const::panic::DATA_CONVERSION);
+
+        let mut file =
+            File::create(&path).map_err(|error| FileError::Creating(Self::file_name(), error))?;
+        file.write_all(data.as_slice())
+            .map_err(|error| FileError::Writing(Self::file_name(), error))
+    }
+
+    ///
+    /// Hashes the file at `path` and stores the hash with a path relative to `root`.
+    ///
+    fn hash_file(
+        root: &Path,
+        path: &Path,
+        files: &mut BTreeMap<String, String>,
+    ) -> Result<(), FileError> {
+        let name = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join("/");
+
+        let data = fs::read(path).map_err(|error| FileError::Reading(name.clone(), error))?;
+        files.insert(name, Self::hash(data.as_slice()));
+
+        Ok(())
+    }
+
+    ///
+    /// Recursively hashes the source files in the directory at `path`.
+    ///
+    fn hash_directory(
+        root: &Path,
+        path: &Path,
+        files: &mut BTreeMap<String, String>,
+    ) -> Result<(), FileError> {
+        let name = path.to_string_lossy().to_string();
+        let entries =
+            fs::read_dir(path).map_err(|error| FileError::Opening(name.clone(), error))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|error| FileError::Reading(name.clone(), error))?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                Self::hash_directory(root, &path, files)?;
+            } else if path.extension().and_then(|extension| extension.to_str())
+                == Some(zinc_const::extension::SOURCE)
+            {
+                Self::hash_file(root, &path, files)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Computes the hex-encoded SHA-256 hash of `data`.
+    ///
+    fn hash(data: &[u8]) -> String {
+        hex::encode(Sha256::digest(data))
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::BUILD_CACHE,
+            zinc_const::extension::JSON,
+        )
+    }
+}
+
+impl TryFrom<&PathBuf> for Cache {
+    type Error = FileError<serde_json::Error>;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(zinc_const::directory::BUILD) {
+                path.push(PathBuf::from(zinc_const::directory::BUILD));
+            }
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file =
+            File::open(path).map_err(|error| FileError::Opening(Self::file_name(), error))?;
+        let size = file
+            .metadata()
+            .map_err(|error| FileError::Metadata(Self::file_name(), error))?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .map_err(|error| FileError::Reading(Self::file_name(), error))?;
+
+        serde_json::from_str(buffer.as_str())
+            .map_err(|error| FileError::Parsing(Self::file_name(), error))
+    }
+}
@@ -0,0 +1,200 @@
+//!
+//! The incremental build cache file tests.
+//!
+
+use std::convert::TryFrom;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::project::build::cache::Cache;
+
+struct Project {
+    path: PathBuf,
+}
+
+impl Project {
+    fn new(name: &str) -> Self {
+        let mut path = env::temp_dir();
+        path.push(format!("zargo-build-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+
+        let project = Self { path };
+        fs::create_dir_all(project.source_path().join("module"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::create_dir_all(project.build_path()).expect(zinc_const::panic::TEST_DATA_VALID);
+        project.write("Zargo.toml", "[project]\nname = \"test\"\n");
+        project.write("src/main.zn", "fn main() {}\n");
+        project.write("src/module/mod.zn", "const VALUE: u8 = 42;\n");
+        project
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.path.join("Zargo.toml")
+    }
+
+    fn source_path(&self) -> PathBuf {
+        self.path.join("src")
+    }
+
+    fn build_path(&self) -> PathBuf {
+        self.path.join("build")
+    }
+
+    fn write(&self, name: &str, data: &str) {
+        fs::write(self.path.join(name), data).expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    fn cache(&self, is_release: bool) -> Cache {
+        Cache::new(
+            &self.path,
+            &self.manifest_path(),
+            &self.source_path(),
+            is_release,
+            false,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+}
+
+impl Drop for Project {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[test]
+fn ok_hashes_manifest_and_sources() {
+    let project = Project::new("hashes");
+    project.write("src/notes.txt", "not a source file");
+
+    let cache = project.cache(false);
+
+    let files: Vec<&str> = cache.files.keys().map(String::as_str).collect();
+    assert_eq!(
+        files,
+        vec!["Zargo.toml", "src/main.zn", "src/module/mod.zn"]
+    );
+}
+
+#[test]
+fn ok_write_read_roundtrip() {
+    let project = Project::new("roundtrip");
+
+    let mut cache = project.cache(true);
+    cache.set_binary(b"bytecode");
+    cache
+        .write_to(&project.build_path())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let read = Cache::try_from(&project.build_path()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(read, cache);
+}
+
+#[test]
+fn ok_fresh() {
+    let project = Project::new("fresh");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    let current = project.cache(false);
+
+    assert!(current.changed_files(&previous).is_empty());
+    assert!(current.is_fresh(&previous, Some(b"bytecode")));
+}
+
+#[test]
+fn stale_file_modified() {
+    let project = Project::new("modified");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    project.write("src/module/mod.zn", "const VALUE: u8 = 64;\n");
+    let current = project.cache(false);
+
+    assert_eq!(
+        current.changed_files(&previous),
+        vec!["src/module/mod.zn".to_owned()]
+    );
+    assert!(!current.is_fresh(&previous, Some(b"bytecode")));
+}
+
+#[test]
+fn stale_file_added() {
+    let project = Project::new("added");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    project.write("src/other.zn", "const OTHER: u8 = 1;\n");
+    let current = project.cache(false);
+
+    assert_eq!(
+        current.changed_files(&previous),
+        vec!["src/other.zn".to_owned()]
+    );
+    assert!(!current.is_fresh(&previous, Some(b"bytecode")));
+}
+
+#[test]
+fn stale_file_deleted() {
+    let project = Project::new("deleted");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    fs::remove_file(project.source_path().join("module").join("mod.zn"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let current = project.cache(false);
+
+    assert_eq!(
+        current.changed_files(&previous),
+        vec!["src/module/mod.zn".to_owned()]
+    );
+    assert!(!current.is_fresh(&previous, Some(b"bytecode")));
+}
+
+#[test]
+fn stale_manifest_modified() {
+    let project = Project::new("manifest");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    project.write("Zargo.toml", "[project]\nname = \"renamed\"\n");
+    let current = project.cache(false);
+
+    assert_eq!(
+        current.changed_files(&previous),
+        vec!["Zargo.toml".to_owned()]
+    );
+    assert!(!current.is_fresh(&previous, Some(b"bytecode")));
+}
+
+#[test]
+fn stale_binary_modified_or_missing() {
+    let project = Project::new("binary");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    let current = project.cache(false);
+
+    assert!(!current.is_fresh(&previous, Some(b"tampered")));
+    assert!(!current.is_fresh(&previous, None));
+}
+
+#[test]
+fn stale_build_mode_changed() {
+    let project = Project::new("mode");
+
+    let mut previous = project.cache(false);
+    previous.set_binary(b"bytecode");
+
+    let current = project.cache(true);
+
+    assert!(!current.is_fresh(&previous, Some(b"bytecode")));
+}
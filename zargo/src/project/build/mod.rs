@@ -2,7 +2,9 @@
 //! The project `build` directory.
 //!
 
+pub mod abi;
 pub mod bytecode;
+pub mod cache;
 
 use std::fs;
 use std::path::PathBuf;
@@ -47,6 +47,25 @@ impl Input {
         Ok(Self { inner })
     }
 
+    ///
+    /// Returns the arguments of the contract `method`, if they are present in the file.
+    ///
+    pub fn method_arguments(&self, method: &str) -> Option<JsonValue> {
+        self.inner
+            .as_object()?
+            .get("arguments")?
+            .as_object()?
+            .get(method)
+            .cloned()
+    }
+
+    ///
+    /// Returns the contract storage, if it is present in the file.
+    ///
+    pub fn storage(&self) -> Option<JsonValue> {
+        self.inner.as_object()?.get("storage").cloned()
+    }
+
     ///
     /// Creates a string with the default file name.
     ///
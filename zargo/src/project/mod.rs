@@ -4,4 +4,5 @@
 
 pub mod build;
 pub mod data;
+pub mod network;
 pub mod source;
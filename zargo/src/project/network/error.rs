@@ -0,0 +1,33 @@
+//!
+//! The project network configuration error.
+//!
+
+use failure::Fail;
+
+use crate::error::file::Error as FileError;
+use crate::project::network::Origin;
+
+///
+/// The project network configuration error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The user-level configuration file error.
+    #[fail(display = "user configuration file {}", _0)]
+    UserConfigFile(FileError<toml::de::Error>),
+    /// The network name is unknown.
+    #[fail(display = "invalid network name `{}` in the {}", _0, _1)]
+    NameInvalid(String, Origin),
+    /// The Zandbox URL is malformed or has an unsupported scheme.
+    #[fail(display = "invalid Zandbox URL `{}` in the {}: {}", _0, _1, _2)]
+    ZandboxUrlInvalid(String, Origin, String),
+    /// The fee token symbol is empty.
+    #[fail(display = "empty fee token in the {}", _0)]
+    FeeTokenEmpty(Origin),
+    /// The Zandbox URL is neither set explicitly nor known for the network.
+    #[fail(
+        display = "the Zandbox URL of network `{}` is unknown and must be set with `--zandbox-url` or the `network.zandbox_url` key",
+        _0
+    )]
+    ZandboxUrlUnknown(zksync::Network),
+}
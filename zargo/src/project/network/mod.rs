@@ -0,0 +1,285 @@
+//!
+//! The project network configuration.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::convert::TryFrom;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use reqwest::Url;
+use serde::Deserialize;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::Network as NetworkSection;
+
+use crate::error::file::Error as FileError;
+use crate::network::Network;
+
+use self::error::Error;
+
+///
+/// The project network configuration.
+///
+/// Every value is taken from the subcommand argument, the project manifest `network` section,
+/// the user-level configuration file `network` section, or the default, in the order of
+/// precedence.
+///
+#[derive(Debug)]
+pub struct Config {
+    /// The zkSync network.
+    pub network: Network,
+    /// The Zandbox server URL, if it is set explicitly.
+    pub zandbox_url: Option<String>,
+    /// The token symbol, which the transaction fees are paid in.
+    pub fee_token: String,
+}
+
+impl Config {
+    ///
+    /// Resolves the configuration from the subcommand arguments, the project `manifest`,
+    /// and the user-level configuration file.
+    ///
+    pub fn new(
+        manifest: &Manifest,
+        network: Option<String>,
+        zandbox_url: Option<String>,
+    ) -> Result<Self, Error> {
+        let user_config = UserConfig::try_default().map_err(Error::UserConfigFile)?;
+
+        Self::resolve(
+            network,
+            zandbox_url,
+            &manifest.network,
+            &user_config.network,
+        )
+    }
+
+    ///
+    /// Resolves the configuration from the subcommand arguments, the project manifest section,
+    /// and the user-level configuration file section.
+    ///
+    pub fn resolve(
+        network: Option<String>,
+        zandbox_url: Option<String>,
+        manifest: &NetworkSection,
+        user_config: &NetworkSection,
+    ) -> Result<Self, Error> {
+        let (name, origin) = Self::select(
+            network.map(|network| (network, "--network")),
+            manifest.name.as_ref(),
+            user_config.name.as_ref(),
+            "network.name",
+        )
+        .unwrap_or_else(|| {
+            (
+                zinc_const::zargo::DEFAULT_NETWORK.to_owned(),
+                Origin::Default,
+            )
+        });
+        let network = zksync::Network::from_str(name.as_str())
+            .map(Network::from)
+            .map_err(|_error| Error::NameInvalid(name.clone(), origin))?;
+
+        let zandbox_url = match Self::select(
+            zandbox_url.map(|zandbox_url| (zandbox_url, "--zandbox-url")),
+            manifest.zandbox_url.as_ref(),
+            user_config.zandbox_url.as_ref(),
+            "network.zandbox_url",
+        ) {
+            Some((url, origin)) => {
+                let scheme = Url::parse(url.as_str())
+                    .map_err(|error| {
+                        Error::ZandboxUrlInvalid(url.clone(), origin, error.to_string())
+                    })?
+                    .scheme()
+                    .to_owned();
+                match scheme.as_str() {
+                    "http" | "https" => {}
+                    scheme => {
+                        return Err(Error::ZandboxUrlInvalid(
+                            url.clone(),
+                            origin,
+                            format!("unsupported scheme `{}`", scheme),
+                        ))
+                    }
+                }
+
+                Some(url.trim_end_matches('/').to_owned())
+            }
+            None => None,
+        };
+
+        let (fee_token, origin) = Self::select(
+            None,
+            manifest.fee_token.as_ref(),
+            user_config.fee_token.as_ref(),
+            "network.fee_token",
+        )
+        .unwrap_or_else(|| {
+            (
+                zinc_const::zargo::DEFAULT_FEE_TOKEN.to_owned(),
+                Origin::Default,
+            )
+        });
+        if fee_token.trim().is_empty() {
+            return Err(Error::FeeTokenEmpty(origin));
+        }
+
+        Ok(Self {
+            network,
+            zandbox_url,
+            fee_token,
+        })
+    }
+
+    ///
+    /// Returns the Zandbox server URL, which is either set explicitly or the network default one.
+    ///
+    pub fn zandbox_url(&self) -> Result<String, Error> {
+        match self.zandbox_url {
+            Some(ref url) => Ok(url.to_owned()),
+            None => self
+                .network
+                .try_into_url()
+                .map_err(Error::ZandboxUrlUnknown),
+        }
+    }
+
+    ///
+    /// Selects the value with the highest precedence along with its origin.
+    ///
+    fn select(
+        argument: Option<(String, &'static str)>,
+        manifest: Option<&String>,
+        user_config: Option<&String>,
+        key: &'static str,
+    ) -> Option<(String, Origin)> {
+        if let Some((value, name)) = argument {
+            return Some((value, Origin::Argument(name)));
+        }
+
+        if let Some(value) = manifest {
+            return Some((value.to_owned(), Origin::Manifest(key)));
+        }
+
+        user_config.map(|value| (value.to_owned(), Origin::UserConfig(key)))
+    }
+}
+
+///
+/// The configuration value origin, which is reported in the validation errors.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Origin {
+    /// The subcommand argument with the specified name.
+    Argument(&'static str),
+    /// The project manifest key.
+    Manifest(&'static str),
+    /// The user-level configuration file key.
+    UserConfig(&'static str),
+    /// The default value.
+    Default,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Argument(name) => write!(f, "`{}` argument", name),
+            Self::Manifest(key) => write!(
+                f,
+                "`{}` key of `{}.{}`",
+                key,
+                zinc_const::file_name::MANIFEST,
+                zinc_const::extension::MANIFEST
+            ),
+            Self::UserConfig(key) => write!(
+                f,
+                "`{}` key of `~/{}{}`",
+                key,
+                zinc_const::directory::USER_CONFIG,
+                UserConfig::file_name()
+            ),
+            Self::Default => write!(f, "default value"),
+        }
+    }
+}
+
+///
+/// The user-level configuration file representation.
+///
+/// The file is located at `~/.zinc/config.toml` and may only contain the `network` section,
+/// whose values are shared by all the projects of the user.
+///
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserConfig {
+    /// The optional `network` section.
+    #[serde(default)]
+    pub network: NetworkSection,
+}
+
+impl UserConfig {
+    ///
+    /// Reads the file at the default `~/.zinc/config.toml` location.
+    ///
+    /// Returns the empty configuration if the user home directory cannot be determined
+    /// or the file does not exist.
+    ///
+    pub fn try_default() -> Result<Self, FileError<toml::de::Error>> {
+        let path = match env::var_os("HOME") {
+            Some(home) => {
+                let mut path = PathBuf::from(home);
+                path.push(zinc_const::directory::USER_CONFIG);
+                path.push(Self::file_name());
+                path
+            }
+            None => return Ok(Self::default()),
+        };
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        Self::try_from(&path)
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::USER_CONFIG,
+            zinc_const::extension::MANIFEST,
+        )
+    }
+}
+
+impl TryFrom<&PathBuf> for UserConfig {
+    type Error = FileError<toml::de::Error>;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut file =
+            File::open(path).map_err(|error| FileError::Opening(Self::file_name(), error))?;
+        let size = file
+            .metadata()
+            .map_err(|error| FileError::Metadata(Self::file_name(), error))?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .map_err(|error| FileError::Reading(Self::file_name(), error))?;
+
+        toml::from_str(buffer.as_str())
+            .map_err(|error| FileError::Parsing(Self::file_name(), error))
+    }
+}
@@ -0,0 +1,140 @@
+//!
+//! The project network configuration tests.
+//!
+
+use zinc_manifest::Network as NetworkSection;
+
+use crate::project::network::error::Error;
+use crate::project::network::Config;
+use crate::project::network::Origin;
+use crate::project::network::UserConfig;
+
+fn section(
+    name: Option<&str>,
+    zandbox_url: Option<&str>,
+    fee_token: Option<&str>,
+) -> NetworkSection {
+    NetworkSection {
+        name: name.map(str::to_owned),
+        zandbox_url: zandbox_url.map(str::to_owned),
+        fee_token: fee_token.map(str::to_owned),
+    }
+}
+
+#[test]
+fn ok_defaults() {
+    let config = Config::resolve(
+        None,
+        None,
+        &NetworkSection::default(),
+        &NetworkSection::default(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        config.network.to_string(),
+        zinc_const::zargo::DEFAULT_NETWORK
+    );
+    assert_eq!(config.zandbox_url, None);
+    assert_eq!(
+        config
+            .zandbox_url()
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        "http://localhost:4001"
+    );
+    assert_eq!(config.fee_token, zinc_const::zargo::DEFAULT_FEE_TOKEN);
+}
+
+#[test]
+fn ok_precedence() {
+    let manifest = section(Some("rinkeby"), Some("https://manifest.local"), Some("DAI"));
+    let user_config = section(Some("localhost"), Some("https://user.local"), Some("USDC"));
+
+    let config = Config::resolve(None, None, &manifest, &user_config)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(config.network.to_string(), "rinkeby");
+    assert_eq!(
+        config.zandbox_url.as_deref(),
+        Some("https://manifest.local")
+    );
+    assert_eq!(config.fee_token, "DAI");
+
+    let config = Config::resolve(None, None, &NetworkSection::default(), &user_config)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(config.network.to_string(), "localhost");
+    assert_eq!(config.zandbox_url.as_deref(), Some("https://user.local"));
+    assert_eq!(config.fee_token, "USDC");
+
+    let config = Config::resolve(
+        Some("localhost".to_owned()),
+        Some("http://argument.local:4001/".to_owned()),
+        &manifest,
+        &user_config,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(config.network.to_string(), "localhost");
+    assert_eq!(
+        config
+            .zandbox_url()
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        "http://argument.local:4001"
+    );
+}
+
+#[test]
+fn error_name_invalid() {
+    let manifest = section(Some("mainnet-beta"), None, None);
+
+    match Config::resolve(None, None, &manifest, &NetworkSection::default()) {
+        Err(Error::NameInvalid(name, origin)) => {
+            assert_eq!(name, "mainnet-beta");
+            assert_eq!(origin, Origin::Manifest("network.name"));
+        }
+        another => panic!("unexpected result: {:?}", another),
+    }
+}
+
+#[test]
+fn error_zandbox_url_invalid() {
+    let user_config = section(None, Some("localhost:4001"), None);
+
+    match Config::resolve(None, None, &NetworkSection::default(), &user_config) {
+        Err(Error::ZandboxUrlInvalid(url, origin, _reason)) => {
+            assert_eq!(url, "localhost:4001");
+            assert_eq!(origin, Origin::UserConfig("network.zandbox_url"));
+        }
+        another => panic!("unexpected result: {:?}", another),
+    }
+
+    match Config::resolve(
+        None,
+        Some("not a url".to_owned()),
+        &NetworkSection::default(),
+        &user_config,
+    ) {
+        Err(Error::ZandboxUrlInvalid(_url, origin, _reason)) => {
+            assert_eq!(origin, Origin::Argument("--zandbox-url"));
+        }
+        another => panic!("unexpected result: {:?}", another),
+    }
+}
+
+#[test]
+fn error_fee_token_empty() {
+    let manifest = section(None, None, Some(" "));
+
+    match Config::resolve(None, None, &manifest, &NetworkSection::default()) {
+        Err(Error::FeeTokenEmpty(origin)) => {
+            assert_eq!(origin, Origin::Manifest("network.fee_token"));
+        }
+        another => panic!("unexpected result: {:?}", another),
+    }
+}
+
+#[test]
+fn error_user_config_unknown_key() {
+    let result = toml::from_str::<UserConfig>("[network]\nnetwork_name = \"rinkeby\"\n");
+
+    let error = result.expect_err(zinc_const::panic::TEST_DATA_VALID);
+    assert!(error.to_string().contains("network_name"));
+}
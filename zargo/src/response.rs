@@ -0,0 +1,35 @@
+//!
+//! The Zandbox response utilities shared by the contract subcommands.
+//!
+
+use serde_json::Value as JsonValue;
+
+///
+/// Checks the Zandbox response status.
+///
+/// Returns the error message with the response body, if the request has failed.
+///
+pub async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, String> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    Err(format!(
+        "HTTP error ({}) {}",
+        response.status(),
+        response
+            .text()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION),
+    ))
+}
+
+///
+/// Prints the contract method output or storage JSON to the standard output.
+///
+pub fn print_json(data: &JsonValue) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(data).expect(zinc_const::panic::DATA_CONVERSION)
+    );
+}
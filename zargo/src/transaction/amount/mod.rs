@@ -0,0 +1,69 @@
+//!
+//! The transaction token amount.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use num::BigUint;
+
+use crate::transaction::error::Error;
+
+///
+/// Parses a decimal token `amount`, e.g. `1.5`, into the smallest token units.
+///
+/// The number of fractional digits cannot exceed the token `decimals`.
+///
+pub fn parse(amount: &str, decimals: u8) -> Result<BigUint, Error> {
+    let (integer, fractional) = match amount.find('.') {
+        Some(index) => (&amount[..index], &amount[index + 1..]),
+        None => (amount, ""),
+    };
+
+    if (integer.is_empty() && fractional.is_empty())
+        || !integer.chars().all(|character| character.is_ascii_digit())
+        || !fractional
+            .chars()
+            .all(|character| character.is_ascii_digit())
+    {
+        return Err(Error::AmountInvalid(amount.to_owned()));
+    }
+
+    if fractional.len() > decimals as usize {
+        return Err(Error::AmountPrecision(amount.to_owned(), decimals));
+    }
+
+    let digits = format!(
+        "{}{}{}",
+        integer,
+        fractional,
+        "0".repeat(decimals as usize - fractional.len())
+    );
+
+    Ok(
+        BigUint::parse_bytes(digits.as_bytes(), zinc_const::base::DECIMAL)
+            .expect(zinc_const::panic::DATA_CONVERSION),
+    )
+}
+
+///
+/// Formats an `amount` of the smallest token units as a decimal number, e.g. `1.5`.
+///
+pub fn format(amount: &BigUint, decimals: u8) -> String {
+    let decimals = decimals as usize;
+
+    let digits = amount.to_str_radix(zinc_const::base::DECIMAL);
+    let digits = format!(
+        "{}{}",
+        "0".repeat((decimals + 1).saturating_sub(digits.len())),
+        digits
+    );
+
+    let (integer, fractional) = digits.split_at(digits.len() - decimals);
+    let fractional = fractional.trim_end_matches('0');
+    if fractional.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{}.{}", integer, fractional)
+    }
+}
@@ -0,0 +1,60 @@
+//!
+//! The transaction token amount tests.
+//!
+
+use num::BigUint;
+
+use crate::transaction::error::Error;
+
+#[test]
+fn ok_parse_integer() {
+    assert_eq!(
+        super::parse("2", 6).expect(zinc_const::panic::TEST_DATA_VALID),
+        BigUint::from(2_000_000u64)
+    );
+}
+
+#[test]
+fn ok_parse_fractional() {
+    assert_eq!(
+        super::parse("1.5", 18).expect(zinc_const::panic::TEST_DATA_VALID),
+        BigUint::from(1_500_000_000_000_000_000u64)
+    );
+}
+
+#[test]
+fn ok_parse_fractional_only() {
+    assert_eq!(
+        super::parse(".25", 2).expect(zinc_const::panic::TEST_DATA_VALID),
+        BigUint::from(25u64)
+    );
+}
+
+#[test]
+fn ok_format() {
+    assert_eq!(
+        super::format(&BigUint::from(1_500_000_000_000_000_000u64), 18),
+        "1.5"
+    );
+    assert_eq!(super::format(&BigUint::from(25u64), 3), "0.025");
+    assert_eq!(super::format(&BigUint::from(2_000_000u64), 6), "2");
+    assert_eq!(super::format(&BigUint::from(0u64), 0), "0");
+}
+
+#[test]
+fn error_parse_invalid() {
+    for amount in ["", ".", "1.2.3", "-1", "1e18", "0x10"].iter() {
+        assert!(matches!(
+            super::parse(amount, 18),
+            Err(Error::AmountInvalid(_))
+        ));
+    }
+}
+
+#[test]
+fn error_parse_precision() {
+    assert!(matches!(
+        super::parse("0.001", 2),
+        Err(Error::AmountPrecision(_, 2))
+    ));
+}
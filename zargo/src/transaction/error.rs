@@ -24,6 +24,36 @@ pub enum Error {
     /// The transaction signing error.
     #[fail(display = "signing error: {}", _0)]
     TransactionSigning(zksync_eth_signer::error::SignerError),
+    /// The token amount is not a valid decimal number.
+    #[fail(display = "amount `{}` is not a valid decimal number", _0)]
+    AmountInvalid(String),
+    /// The token amount has more fractional digits than the token decimals.
+    #[fail(display = "amount `{}` has more than {} fractional digits", _0, _1)]
+    AmountPrecision(String, u8),
+    /// The Ethereum provider initialization error.
+    #[fail(display = "Ethereum provider initialization error: {}", _0)]
+    EthereumProvider(zksync::error::ClientError),
+    /// The ERC20 token deposit approval error.
+    #[fail(display = "deposit approval error: {}", _0)]
+    DepositApproval(zksync::error::ClientError),
+    /// The deposit priority operation sending error.
+    #[fail(display = "deposit error: {}", _0)]
+    Deposit(zksync::error::ClientError),
+    /// The Ethereum transaction waiting error.
+    #[fail(display = "Ethereum transaction waiting error: {}", _0)]
+    EthereumTransactionWaiting(zksync::error::ClientError),
+    /// The Ethereum transaction has been reverted.
+    #[fail(display = "Ethereum transaction {:?} has failed", _0)]
+    EthereumTransactionFailed(zksync::web3::types::H256),
+    /// The deposit has not been committed in time.
+    #[fail(display = "deposit has not been committed in {} seconds", _0)]
+    DepositCommitTimeout(u64),
+    /// The withdraw transaction sending or waiting error.
+    #[fail(display = "withdraw error: {}", _0)]
+    Withdraw(zksync::error::ClientError),
+    /// The withdraw transaction has been rejected by the zkSync server.
+    #[fail(display = "withdraw has failed: {}", _0)]
+    WithdrawFailed(String),
 }
 
 impl From<zinc_zksync::TransactionMsgError> for Error {
@@ -2,13 +2,19 @@
 //! The transaction tools.
 //!
 
+pub mod amount;
 pub mod error;
 
+use std::time::Duration;
+use std::time::Instant;
+
 use num::BigUint;
 
 use zksync::web3::types::Address;
+use zksync::web3::types::U256;
 use zksync_eth_signer::PrivateKeySigner;
 use zksync_types::tx::ZkSyncTx;
+use zksync_types::Token;
 use zksync_types::TokenLike;
 use zksync_types::TxFeeTypes;
 
@@ -114,3 +120,136 @@ pub async fn try_into_zksync(
         signature,
     ))
 }
+
+///
+/// Deposits the `amount` of `token` from the wallet Ethereum account to the `recipient` zkSync
+/// account, e.g. a contract, with a priority operation.
+///
+/// Waits until the deposit is committed and returns the new `recipient` balance.
+///
+pub async fn deposit(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    ethereum_url: &str,
+    recipient: Address,
+    token: &Token,
+    amount: BigUint,
+) -> Result<BigUint, Error> {
+    let token_like = TokenLike::Symbol(token.symbol.clone());
+    let ethereum = wallet
+        .ethereum(ethereum_url)
+        .await
+        .map_err(Error::EthereumProvider)?;
+
+    if token.address != Address::zero()
+        && !ethereum
+            .is_erc20_deposit_approved(token_like.clone())
+            .await
+            .map_err(Error::DepositApproval)?
+    {
+        let tx_hash = ethereum
+            .approve_erc20_token_deposits(token_like.clone())
+            .await
+            .map_err(Error::DepositApproval)?;
+        let receipt = ethereum
+            .wait_for_tx(tx_hash)
+            .await
+            .map_err(Error::EthereumTransactionWaiting)?;
+        if receipt.status != Some(1.into()) {
+            return Err(Error::EthereumTransactionFailed(tx_hash));
+        }
+    }
+
+    let expected_balance = committed_balance(wallet, recipient, token).await? + amount.clone();
+
+    let tx_hash = ethereum
+        .deposit(
+            token_like,
+            U256::from_dec_str(amount.to_string().as_str())
+                .expect(zinc_const::panic::DATA_CONVERSION),
+            recipient,
+        )
+        .await
+        .map_err(Error::Deposit)?;
+    let receipt = ethereum
+        .wait_for_tx(tx_hash)
+        .await
+        .map_err(Error::EthereumTransactionWaiting)?;
+    if receipt.status != Some(1.into()) {
+        return Err(Error::EthereumTransactionFailed(tx_hash));
+    }
+
+    let timeout = Duration::from_millis(zinc_const::zargo::DEPOSIT_COMMIT_TIMEOUT_MS);
+    let started_at = Instant::now();
+    loop {
+        let balance = committed_balance(wallet, recipient, token).await?;
+        if balance >= expected_balance {
+            return Ok(balance);
+        }
+
+        if started_at.elapsed() > timeout {
+            return Err(Error::DepositCommitTimeout(timeout.as_secs()));
+        }
+
+        tokio::time::delay_for(Duration::from_millis(
+            zinc_const::zargo::DEPOSIT_COMMIT_POLLING_INTERVAL_MS,
+        ))
+        .await;
+    }
+}
+
+///
+/// Withdraws the `amount` of `token` from the wallet zkSync account to the `recipient`
+/// Ethereum account.
+///
+/// Waits until the withdraw is committed and returns the remaining wallet balance.
+///
+pub async fn withdraw(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    recipient: Address,
+    token: &Token,
+    amount: BigUint,
+) -> Result<BigUint, Error> {
+    let tx_info = wallet
+        .start_withdraw()
+        .token(TokenLike::Symbol(token.symbol.clone()))
+        .map_err(Error::Withdraw)?
+        .amount(zinc_zksync::num_compat_backward(amount))
+        .to(recipient)
+        .send()
+        .await
+        .map_err(Error::Withdraw)?
+        .wait_for_commit()
+        .await
+        .map_err(Error::Withdraw)?;
+    if !tx_info.success.unwrap_or_default() {
+        return Err(Error::WithdrawFailed(
+            tx_info
+                .fail_reason
+                .unwrap_or_else(|| "Unknown error".to_owned()),
+        ));
+    }
+
+    committed_balance(wallet, wallet.signer.address, token).await
+}
+
+///
+/// Returns the committed `token` balance of the zkSync account at `address`.
+///
+pub async fn committed_balance(
+    wallet: &zksync::Wallet<PrivateKeySigner>,
+    address: Address,
+    token: &Token,
+) -> Result<BigUint, Error> {
+    let balance = wallet
+        .provider
+        .account_info(address)
+        .await
+        .map_err(Error::AccountInfoRetrieving)?
+        .committed
+        .balances
+        .get(token.symbol.as_str())
+        .map(|balance| balance.0.to_owned())
+        .unwrap_or_default();
+
+    Ok(zinc_zksync::num_compat_forward(balance))
+}
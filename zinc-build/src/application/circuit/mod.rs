@@ -3,12 +3,16 @@
 //!
 
 use std::collections::HashMap;
+use std::fmt;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::application::listing::Listing;
 use crate::application::unit_test::UnitTest;
 use crate::data::r#type::Type as BuildType;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::evaluation_stack::push_pooled::PushPooled;
 use crate::instructions::Instruction;
 
 ///
@@ -28,6 +32,8 @@ pub struct Circuit {
     pub unit_tests: HashMap<String, UnitTest>,
     /// The circuit bytecode instructions.
     pub instructions: Vec<Instruction>,
+    /// The constant pool, referenced by the `push pooled constant` instructions.
+    pub constants: Vec<Push>,
 }
 
 impl Circuit {
@@ -49,6 +55,51 @@ impl Circuit {
             output,
             unit_tests,
             instructions,
+            constants: Vec::new(),
         }
     }
+
+    ///
+    /// Replaces the pooled constant references with the constants from the pool.
+    ///
+    /// Is called before the execution, so the virtual machine only sees the `push` instructions.
+    ///
+    pub fn resolve_constants(&mut self) {
+        PushPooled::resolve(self.instructions.as_mut_slice(), self.constants.as_slice());
+    }
+
+    ///
+    /// Returns the bytecode disassembly listing.
+    ///
+    pub fn listing(&self) -> Listing<'_> {
+        let mut listing = Listing::new(self.instructions.as_slice());
+        listing.set_constants(self.constants.as_slice());
+        listing
+    }
+
+    ///
+    /// Writes the circuit entry metadata and the bytecode disassembly listing.
+    ///
+    /// If the `function` name is specified, only the instructions of that function are written.
+    ///
+    pub fn fmt_listing<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        function: Option<&str>,
+    ) -> fmt::Result {
+        let listing = self.listing();
+
+        if let Some(function) = function {
+            return match listing.address(function) {
+                Some(address) => listing.fmt_range(writer, listing.function_range(address)),
+                None => Ok(()),
+            };
+        }
+
+        writeln!(writer, "circuit {}", self.name)?;
+        writeln!(writer, "    entry: {:04}", self.address)?;
+        writeln!(writer, "    output: {}", self.output)?;
+        writeln!(writer)?;
+        write!(writer, "{}", listing)
+    }
 }
@@ -0,0 +1,115 @@
+//!
+//! The Zinc VM bytecode contract application method.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use num::BigInt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::Type as BuildType;
+use crate::data::value::Value as BuildValue;
+
+///
+/// The contract method.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Method {
+    /// The contract function type unique ID.
+    pub type_id: usize,
+    /// The contract function name.
+    pub name: String,
+    /// The contract method address in the bytecode.
+    pub address: usize,
+    /// Whether the method can mutate the contract storage state.
+    pub is_mutable: bool,
+    /// Whether the method does not take `self`, so it does not access the contract storage.
+    pub is_associated: bool,
+    /// Whether the method must receive a transfer, that is, it is declared with `#[payable]`.
+    pub is_payable: bool,
+    /// The token the method must receive, e.g. `ETH` in `#[payable(token = "ETH")]`.
+    pub payable_token: Option<String>,
+    /// The storage field holding the only allowed sender, e.g. `owner` in `#[only(owner)]`.
+    pub only: Option<String>,
+    /// The arguments declared with `#[public]`, which are the leading part of the proof public input.
+    pub public_arguments: Vec<String>,
+    /// The contract method input arguments as a structure.
+    pub input: BuildType,
+    /// The contract method output type.
+    pub output: BuildType,
+}
+
+impl Method {
+    ///
+    /// A shortcut constructor.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        type_id: usize,
+        name: String,
+        address: usize,
+        is_mutable: bool,
+        is_associated: bool,
+        is_payable: bool,
+        payable_token: Option<String>,
+        only: Option<String>,
+        public_arguments: Vec<String>,
+        input: BuildType,
+        output: BuildType,
+    ) -> Self {
+        Self {
+            type_id,
+            name,
+            address,
+            is_mutable,
+            is_associated,
+            is_payable,
+            payable_token,
+            only,
+            public_arguments,
+            input,
+            output,
+        }
+    }
+
+    ///
+    /// Returns the indexes of the flat input scalars, which belong to the `#[public]` arguments.
+    ///
+    /// The contract instance is not a part of the flat input, so it is skipped.
+    ///
+    pub fn public_argument_indexes(&self) -> Vec<usize> {
+        let mut input = self.input.clone();
+        input.remove_contract_instance();
+
+        let fields = match input {
+            BuildType::Structure(fields) => fields,
+            _ => return vec![],
+        };
+
+        let mut indexes = Vec::with_capacity(fields.len());
+        let mut offset = 0;
+        for (name, r#type) in fields.into_iter() {
+            let size = r#type.size();
+            if self.public_arguments.contains(&name) {
+                indexes.extend(offset..offset + size);
+            }
+            offset += size;
+        }
+        indexes
+    }
+
+    ///
+    /// Selects the `#[public]` argument scalars from the method `arguments` value, in the order
+    /// they are inputized by the method proof.
+    ///
+    pub fn public_argument_values(&self, arguments: BuildValue) -> Vec<BigInt> {
+        let arguments = arguments.into_flat_values();
+
+        self.public_argument_indexes()
+            .into_iter()
+            .filter_map(|index| arguments.get(index).cloned())
+            .collect()
+    }
+}
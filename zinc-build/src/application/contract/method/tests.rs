@@ -0,0 +1,123 @@
+//!
+//! The contract method tests.
+//!
+
+use num::BigInt;
+
+use crate::application::contract::method::Method;
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::scalar::Value as ScalarValue;
+use crate::data::value::Value;
+
+fn method(public_arguments: Vec<&str>, input: Vec<(&str, Type)>) -> Method {
+    Method::new(
+        0,
+        "get".to_owned(),
+        0,
+        false,
+        false,
+        false,
+        None,
+        None,
+        public_arguments.into_iter().map(str::to_owned).collect(),
+        Type::Structure(
+            input
+                .into_iter()
+                .map(|(name, r#type)| (name.to_owned(), r#type))
+                .collect(),
+        ),
+        Type::Scalar(ScalarType::Boolean),
+    )
+}
+
+#[test]
+fn ok_public_argument_indexes_none() {
+    let method = method(vec![], vec![("value", Type::Scalar(ScalarType::Field))]);
+
+    assert!(method.public_argument_indexes().is_empty());
+}
+
+#[test]
+fn ok_public_argument_indexes_scalar() {
+    let method = method(
+        vec!["key"],
+        vec![
+            ("flag", Type::Scalar(ScalarType::Boolean)),
+            ("key", Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+            ("value", Type::Scalar(ScalarType::Field)),
+        ],
+    );
+
+    assert_eq!(method.public_argument_indexes(), vec![1]);
+}
+
+#[test]
+fn ok_public_argument_indexes_composite() {
+    let method = method(
+        vec!["keys", "value"],
+        vec![
+            (
+                "keys",
+                Type::Array(Box::new(Type::Scalar(ScalarType::Field)), 3),
+            ),
+            (
+                "pair",
+                Type::Tuple(vec![
+                    Type::Scalar(ScalarType::Boolean),
+                    Type::Scalar(ScalarType::Field),
+                ]),
+            ),
+            ("value", Type::Scalar(ScalarType::Field)),
+        ],
+    );
+
+    assert_eq!(method.public_argument_indexes(), vec![0, 1, 2, 5]);
+}
+
+#[test]
+fn ok_public_argument_indexes_contract_instance_skipped() {
+    let method = method(
+        vec!["value"],
+        vec![
+            (
+                "self",
+                Type::Contract(vec![ContractFieldType::new(
+                    "balance".to_owned(),
+                    Type::Scalar(ScalarType::Field),
+                    false,
+                    false,
+                )]),
+            ),
+            ("value", Type::Scalar(ScalarType::Field)),
+        ],
+    );
+
+    assert_eq!(method.public_argument_indexes(), vec![0]);
+}
+
+#[test]
+fn ok_public_argument_values() {
+    let method = method(
+        vec!["value"],
+        vec![
+            ("flag", Type::Scalar(ScalarType::Boolean)),
+            ("value", Type::Scalar(ScalarType::Field)),
+        ],
+    );
+
+    let arguments = Value::Structure(vec![
+        ("flag".to_owned(), Value::Scalar(ScalarValue::Boolean(true))),
+        (
+            "value".to_owned(),
+            Value::Scalar(ScalarValue::Field(BigInt::from(42))),
+        ),
+    ]);
+
+    assert_eq!(
+        method.public_argument_values(arguments),
+        vec![BigInt::from(42)]
+    );
+}
@@ -5,12 +5,25 @@
 pub mod method;
 
 use std::collections::HashMap;
+use std::fmt;
 
+use num::bigint::Sign;
+use num::BigInt;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 
+use crate::application::listing::Listing;
 use crate::application::unit_test::UnitTest;
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::Type;
+use crate::data::value::contract_field::ContractField as ContractFieldValue;
+use crate::data::value::scalar::Value as ScalarValue;
+use crate::data::value::Value;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::evaluation_stack::push_pooled::PushPooled;
 use crate::instructions::Instruction;
 
 use self::method::Method;
@@ -24,31 +37,181 @@ pub struct Contract {
     pub name: String,
     /// The contract storage structure.
     pub storage: Vec<ContractFieldType>,
+    /// The contract storage layout hash, which is checked by the mutable methods.
+    pub layout_hash: BigInt,
     /// The contract methods.
     pub methods: HashMap<String, Method>,
+    /// The contract metadata, which consists of the public string constants.
+    pub metadata: HashMap<String, String>,
     /// The contract unit tests.
     pub unit_tests: HashMap<String, UnitTest>,
     /// The contract bytecode instructions.
     pub instructions: Vec<Instruction>,
+    /// The constant pool, referenced by the `push pooled constant` instructions.
+    pub constants: Vec<Push>,
 }
 
 impl Contract {
     ///
     /// Creates a contract application instance.
     ///
+    /// The storage layout hash is computed here, so it is embedded into the contract bytecode.
+    ///
     pub fn new(
         name: String,
         storage: Vec<ContractFieldType>,
         methods: HashMap<String, Method>,
+        metadata: HashMap<String, String>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
+        let layout_hash = Self::layout_hash(storage.as_slice());
+
         Self {
             name,
             storage,
+            layout_hash,
             methods,
+            metadata,
             unit_tests,
             instructions,
+            constants: Vec::new(),
+        }
+    }
+
+    ///
+    /// Replaces the pooled constant references with the constants from the pool.
+    ///
+    /// Is called before the execution, so the virtual machine only sees the `push` instructions.
+    ///
+    pub fn resolve_constants(&mut self) {
+        PushPooled::resolve(self.instructions.as_mut_slice(), self.constants.as_slice());
+    }
+
+    ///
+    /// Computes the storage layout hash, that is, the SHA256 hash of the JSON serialized storage
+    /// field names and types, truncated to the `u248` integer.
+    ///
+    pub fn layout_hash(storage: &[ContractFieldType]) -> BigInt {
+        let layout: Vec<(&str, &Type)> = storage
+            .iter()
+            .map(|field| (field.name.as_str(), &field.r#type))
+            .collect();
+        let preimage = serde_json::to_vec(&layout).expect(zinc_const::panic::DATA_CONVERSION);
+
+        let digest = Sha256::digest(preimage.as_slice());
+        BigInt::from_bytes_be(
+            Sign::Plus,
+            &digest[..zinc_const::bitlength::LAYOUT_HASH / zinc_const::bitlength::BYTE],
+        )
+    }
+
+    ///
+    /// Creates the default storage value, with the layout hash field set to the contract one.
+    ///
+    pub fn default_storage(&self) -> Value {
+        let mut fields: Vec<ContractFieldValue> = self
+            .storage
+            .iter()
+            .cloned()
+            .map(ContractFieldValue::new_from_type)
+            .collect();
+        if let Some(field) = fields.get_mut(zinc_const::contract::FIELD_INDEX_LAYOUT_HASH) {
+            field.value = Value::Scalar(ScalarValue::Integer(
+                self.layout_hash.clone(),
+                IntegerType::LAYOUT_HASH,
+            ));
+        }
+
+        Value::Contract(fields)
+    }
+
+    ///
+    /// Returns the bytecode disassembly listing, where the method addresses are resolved into
+    /// their names.
+    ///
+    pub fn listing(&self) -> Listing<'_> {
+        let mut listing = Listing::new(self.instructions.as_slice());
+        listing.set_constants(self.constants.as_slice());
+        for (name, method) in self.methods.iter() {
+            listing.add_symbol(method.address, name.to_owned());
         }
+        listing
+    }
+
+    ///
+    /// Writes the contract storage layout, the method table, and the bytecode disassembly
+    /// listing.
+    ///
+    /// If the `method` name is specified, only the instructions of that method are written.
+    ///
+    pub fn fmt_listing<W: fmt::Write>(&self, writer: &mut W, method: Option<&str>) -> fmt::Result {
+        let listing = self.listing();
+
+        if let Some(method) = method {
+            let address = self
+                .methods
+                .get(method)
+                .map(|method| method.address)
+                .or_else(|| listing.address(method));
+            return match address {
+                Some(address) => listing.fmt_range(writer, listing.function_range(address)),
+                None => Ok(()),
+            };
+        }
+
+        writeln!(writer, "contract {}", self.name)?;
+        writeln!(writer)?;
+
+        writeln!(writer, "storage:")?;
+        for (index, field) in self.storage.iter().enumerate() {
+            writeln!(
+                writer,
+                "    {:04} {}{}: {}{}",
+                index,
+                if field.is_public { "pub " } else { "" },
+                field.name,
+                field.r#type,
+                if field.is_implicit { " (implicit)" } else { "" },
+            )?;
+        }
+        writeln!(writer)?;
+
+        let mut methods: Vec<&Method> = self.methods.values().collect();
+        methods.sort_by_key(|method| (method.address, method.name.as_str()));
+
+        writeln!(writer, "methods:")?;
+        for method in methods.into_iter() {
+            let mut attributes = Vec::new();
+            if method.is_mutable {
+                attributes.push("mutable".to_owned());
+            }
+            if method.is_associated {
+                attributes.push("associated".to_owned());
+            }
+            match method.payable_token {
+                Some(ref token) => attributes.push(format!("payable {}", token)),
+                None if method.is_payable => attributes.push("payable".to_owned()),
+                None => {}
+            }
+            if let Some(ref only) = method.only {
+                attributes.push(format!("only {}", only));
+            }
+
+            if attributes.is_empty() {
+                writeln!(writer, "    {:04} {}", method.address, method.name)?;
+            } else {
+                writeln!(
+                    writer,
+                    "    {:04} {} ({})",
+                    method.address,
+                    method.name,
+                    attributes.join(", ")
+                )?;
+            }
+        }
+        writeln!(writer)?;
+
+        write!(writer, "{}", listing)
     }
 }
@@ -0,0 +1,137 @@
+//!
+//! The Zinc VM bytecode disassembly listing.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::Instruction;
+
+///
+/// The bytecode disassembly listing.
+///
+/// The debug instructions are omitted, except for the function markers, which are printed
+/// as labels. The call instruction addresses are resolved into the function names, and the
+/// pooled constant references are resolved into the constants.
+///
+#[derive(Debug)]
+pub struct Listing<'a> {
+    /// The listed bytecode instructions.
+    instructions: &'a [Instruction],
+    /// The constant pool, referenced by the `push pooled constant` instructions.
+    constants: &'a [Push],
+    /// The function names by their start addresses.
+    symbols: BTreeMap<usize, String>,
+}
+
+impl<'a> Listing<'a> {
+    ///
+    /// Creates a listing, collecting the function names from the function markers.
+    ///
+    /// A function starts at its file marker, if the latter precedes the function marker.
+    ///
+    pub fn new(instructions: &'a [Instruction]) -> Self {
+        let mut symbols = BTreeMap::new();
+        for (address, instruction) in instructions.iter().enumerate() {
+            if let Instruction::FunctionMarker(marker) = instruction {
+                let address = match address.checked_sub(1).map(|index| &instructions[index]) {
+                    Some(Instruction::FileMarker(_)) => address - 1,
+                    _ => address,
+                };
+                symbols.insert(address, marker.function.to_owned());
+            }
+        }
+
+        Self {
+            instructions,
+            constants: &[],
+            symbols,
+        }
+    }
+
+    ///
+    /// Sets the constant pool, so the pooled constants are printed instead of their indexes.
+    ///
+    pub fn set_constants(&mut self, constants: &'a [Push]) {
+        self.constants = constants;
+    }
+
+    ///
+    /// Adds a function name from the application metadata, e.g. a contract method.
+    ///
+    /// The names found in the function markers take precedence.
+    ///
+    pub fn add_symbol(&mut self, address: usize, name: String) {
+        self.symbols.entry(address).or_insert(name);
+    }
+
+    ///
+    /// Returns the start address of the function with the specified `name`.
+    ///
+    pub fn address(&self, name: &str) -> Option<usize> {
+        self.symbols
+            .iter()
+            .find(|(_address, symbol)| symbol.as_str() == name)
+            .map(|(address, _symbol)| *address)
+    }
+
+    ///
+    /// Returns the address range of the function starting at `address`, which ends where the
+    /// next function starts.
+    ///
+    pub fn function_range(&self, address: usize) -> Range<usize> {
+        let end = self
+            .symbols
+            .range(address + 1..)
+            .next()
+            .map(|(address, _symbol)| *address)
+            .unwrap_or_else(|| self.instructions.len());
+
+        address..end
+    }
+
+    ///
+    /// Writes the listing of the instructions in the address `range`.
+    ///
+    pub fn fmt_range<W: fmt::Write>(&self, writer: &mut W, range: Range<usize>) -> fmt::Result {
+        for address in range {
+            let instruction = match self.instructions.get(address) {
+                Some(instruction) => instruction,
+                None => break,
+            };
+
+            match instruction {
+                Instruction::FunctionMarker(marker) => writeln!(writer, "{}:", marker.function)?,
+                instruction if instruction.is_debug() => continue,
+                Instruction::Call(call) => writeln!(
+                    writer,
+                    "    {:04} {} ; {}",
+                    address,
+                    instruction,
+                    self.symbols
+                        .get(&call.address)
+                        .map(String::as_str)
+                        .unwrap_or("<unknown>"),
+                )?,
+                Instruction::PushPooled(pooled) => match self.constants.get(pooled.index) {
+                    Some(constant) => writeln!(writer, "    {:04} {}", address, constant)?,
+                    None => writeln!(writer, "    {:04} {}", address, instruction)?,
+                },
+                instruction => writeln!(writer, "    {:04} {}", address, instruction)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for Listing<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_range(f, 0..self.instructions.len())
+    }
+}
@@ -0,0 +1,225 @@
+//!
+//! The bytecode disassembly listing tests.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use crate::application::contract::method::Method as ContractMethod;
+use crate::application::listing::Listing;
+use crate::application::Application;
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::data_stack::load::Load;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::evaluation_stack::push_pooled::PushPooled;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::exit::Exit;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::marker::file::FileMarker;
+use crate::instructions::marker::function::FunctionMarker;
+use crate::instructions::marker::line::LineMarker;
+use crate::instructions::operator::arithmetic::add::Add;
+use crate::instructions::Instruction;
+
+fn integer(bitlength: usize) -> Type {
+    Type::Scalar(ScalarType::Integer(IntegerType::new(false, bitlength)))
+}
+
+fn method(
+    name: &str,
+    address: usize,
+    is_mutable: bool,
+    is_payable: bool,
+    payable_token: Option<&str>,
+) -> ContractMethod {
+    ContractMethod::new(
+        0,
+        name.to_owned(),
+        address,
+        is_mutable,
+        false,
+        is_payable,
+        payable_token.map(str::to_owned),
+        None,
+        vec![],
+        Type::new_empty_structure(),
+        integer(64),
+    )
+}
+
+///
+/// The fixture contract, where the `deposit` method has no function markers, so its name is
+/// only known from the method table.
+///
+fn fixture() -> Application {
+    let instructions: Vec<Instruction> = vec![
+        FileMarker::new("main.zn".to_owned()).into(),
+        FunctionMarker::new("get".to_owned()).into(),
+        LineMarker::new(5).into(),
+        Load::new(0, 1).into(),
+        PushPooled::new(0).into(),
+        Call::new(7, 2).into(),
+        Exit::new(1).into(),
+        FileMarker::new("main.zn".to_owned()).into(),
+        FunctionMarker::new("add".to_owned()).into(),
+        Load::new(0, 1).into(),
+        Load::new(1, 1).into(),
+        Add.into(),
+        Return::new(1).into(),
+        Load::new(0, 1).into(),
+        Call::new(7, 2).into(),
+        Exit::new(0).into(),
+    ];
+
+    let storage = vec![
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_ADDRESS.to_owned(),
+            integer(zinc_const::bitlength::ETH_ADDRESS),
+            true,
+            true,
+        ),
+        ContractFieldType::new("value".to_owned(), integer(64), true, false),
+        ContractFieldType::new("owner".to_owned(), integer(160), false, false),
+    ];
+
+    let mut methods = HashMap::new();
+    methods.insert("get".to_owned(), method("get", 0, false, false, None));
+    methods.insert(
+        "deposit".to_owned(),
+        method("deposit", 13, true, true, Some("ETH")),
+    );
+
+    let mut application = Application::new_contract(
+        "Counter".to_owned(),
+        storage,
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        instructions,
+    );
+    application.set_constants(vec![Push::new(
+        BigInt::from(1),
+        ScalarType::Integer(IntegerType::U8),
+    )]);
+
+    Application::try_from_slice(application.into_vec().as_slice())
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+#[test]
+fn ok_contract() {
+    let expected = r#"contract Counter
+
+storage:
+    0000 pub address: u160 (implicit)
+    0001 pub value: u64
+    0002 owner: u160
+
+methods:
+    0000 get
+    0013 deposit (mutable, payable ETH)
+
+get:
+    0003 load 0 1
+    0004 push 1 as u8
+    0005 call 7 2 ; add
+    0006 exit 1
+add:
+    0009 load 0 1
+    0010 load 1 1
+    0011 add
+    0012 return 1
+    0013 load 0 1
+    0014 call 7 2 ; add
+    0015 exit 0
+"#;
+
+    let mut result = String::new();
+    fixture()
+        .fmt_listing(&mut result, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_contract_method() {
+    let expected = r#"    0013 load 0 1
+    0014 call 7 2 ; add
+    0015 exit 0
+"#;
+
+    let mut result = String::new();
+    fixture()
+        .fmt_listing(&mut result, Some("deposit"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_contract_function() {
+    let expected = r#"add:
+    0009 load 0 1
+    0010 load 1 1
+    0011 add
+    0012 return 1
+"#;
+
+    let mut result = String::new();
+    fixture()
+        .fmt_listing(&mut result, Some("add"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_circuit() {
+    let instructions: Vec<Instruction> = vec![
+        FileMarker::new("main.zn".to_owned()).into(),
+        FunctionMarker::new("main".to_owned()).into(),
+        Load::new(0, 1).into(),
+        Exit::new(1).into(),
+    ];
+
+    let application = Application::new_circuit(
+        "adder".to_owned(),
+        0,
+        Type::new_empty_structure(),
+        integer(8),
+        HashMap::new(),
+        instructions,
+    );
+
+    let expected = r#"circuit adder
+    entry: 0000
+    output: u8
+
+main:
+    0002 load 0 1
+    0003 exit 1
+"#;
+
+    let mut result = String::new();
+    application
+        .fmt_listing(&mut result, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_unknown_call_target() {
+    let instructions: Vec<Instruction> = vec![Call::new(42, 0).into(), Exit::new(0).into()];
+
+    let expected = r#"    0000 call 42 0 ; <unknown>
+    0001 exit 0
+"#;
+
+    assert_eq!(Listing::new(instructions.as_slice()).to_string(), expected);
+}
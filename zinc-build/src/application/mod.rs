@@ -4,9 +4,11 @@
 
 pub mod circuit;
 pub mod contract;
+pub mod listing;
 pub mod unit_test;
 
 use std::collections::HashMap;
+use std::fmt;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -17,8 +19,12 @@ use crate::application::unit_test::UnitTest;
 use crate::build::input::Input as InputBuild;
 use crate::build::Build;
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
 use crate::data::r#type::Type;
+use crate::data::template::Template;
+use crate::data::value::scalar::Value as ScalarValue;
 use crate::data::value::Value;
+use crate::instructions::evaluation_stack::push::Push;
 use crate::instructions::Instruction;
 
 use self::circuit::Circuit;
@@ -65,6 +71,7 @@ impl Application {
         name: String,
         storage: Vec<ContractFieldType>,
         methods: HashMap<String, ContractMethod>,
+        metadata: HashMap<String, String>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
@@ -72,6 +79,7 @@ impl Application {
             name,
             storage,
             methods,
+            metadata,
             unit_tests,
             instructions,
         ))
@@ -87,6 +95,42 @@ impl Application {
         }
     }
 
+    ///
+    /// Returns the start address of the method or function with the specified `name`.
+    ///
+    pub fn function_address(&self, name: &str) -> Option<usize> {
+        match self {
+            Self::Circuit(ref inner) => inner.listing().address(name),
+            Self::Contract(ref inner) => inner
+                .methods
+                .get(name)
+                .map(|method| method.address)
+                .or_else(|| inner.listing().address(name)),
+        }
+    }
+
+    ///
+    /// Writes the application metadata and the bytecode disassembly listing.
+    ///
+    /// If the `method` name is specified, only the instructions of that method are written.
+    ///
+    pub fn fmt_listing<W: fmt::Write>(&self, writer: &mut W, method: Option<&str>) -> fmt::Result {
+        match self {
+            Self::Circuit(ref inner) => inner.fmt_listing(writer, method),
+            Self::Contract(ref inner) => inner.fmt_listing(writer, method),
+        }
+    }
+
+    ///
+    /// Sets the constant pool, referenced by the `push pooled constant` instructions.
+    ///
+    pub fn set_constants(&mut self, constants: Vec<Push>) {
+        match self {
+            Self::Circuit(ref mut inner) => inner.constants = constants,
+            Self::Contract(ref mut inner) => inner.constants = constants,
+        }
+    }
+
     ///
     /// Converts the compiled application state into a set of byte arrays, which are ready to be
     /// written to the Zinc project build files.
@@ -94,7 +138,7 @@ impl Application {
     pub fn into_build(self) -> Build {
         match self {
             Application::Circuit(circuit) => {
-                let arguments = Value::new(circuit.input.clone()).into_json();
+                let arguments = Template::new(circuit.input.clone()).into_json();
                 let bytecode = Application::Circuit(circuit).into_vec();
 
                 Build::new(bytecode, InputBuild::new_circuit(arguments))
@@ -104,16 +148,23 @@ impl Application {
                 for (name, method) in contract.methods.iter() {
                     arguments.insert(
                         name.to_owned(),
-                        Value::new(method.input.to_owned()).into_json(),
+                        Template::new(method.input.to_owned()).into_json(),
                     );
                 }
 
-                let fields: Vec<JsonValue> = contract
+                let mut fields: Vec<JsonValue> = contract
                     .storage
                     .clone()
                     .into_iter()
-                    .map(|field| Value::new(field.r#type).into_json())
+                    .map(|field| Template::new(field.r#type).into_json())
                     .collect();
+                if let Some(field) = fields.get_mut(zinc_const::contract::FIELD_INDEX_LAYOUT_HASH) {
+                    *field = Value::Scalar(ScalarValue::Integer(
+                        contract.layout_hash.clone(),
+                        IntegerType::LAYOUT_HASH,
+                    ))
+                    .into_json();
+                }
                 let storage = JsonValue::Array(fields);
 
                 let transaction = json!({
@@ -122,12 +173,16 @@ impl Application {
                     "token_address": "0x0000000000000000000000000000000000000000",
                     "amount": "0",
                 });
+                let environment = json!({
+                    "timestamp": 0,
+                    "block_number": 0,
+                });
 
                 let bytecode = Application::Contract(contract).into_vec();
 
                 Build::new(
                     bytecode,
-                    InputBuild::new_contract(storage, transaction, arguments),
+                    InputBuild::new_contract(storage, transaction, arguments, environment),
                 )
             }
         }
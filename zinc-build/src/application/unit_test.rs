@@ -5,6 +5,8 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::data::value::Value;
+
 ///
 /// The circuit unit test.
 ///
@@ -16,17 +18,28 @@ pub struct UnitTest {
     pub should_panic: bool,
     /// If the test must be ignored, is set by the `#[ignore]` macro
     pub is_ignored: bool,
+    /// The initial contract storage fixture path, is set by the `#[test(storage = "...")]` macro
+    pub storage_fixture: Option<String>,
+    /// The initial contract storage parsed from the fixture at compile time.
+    pub storage: Option<Value>,
 }
 
 impl UnitTest {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(address: usize, should_panic: bool, is_ignored: bool) -> Self {
+    pub fn new(
+        address: usize,
+        should_panic: bool,
+        is_ignored: bool,
+        storage_fixture: Option<String>,
+    ) -> Self {
         Self {
             address,
             should_panic,
             is_ignored,
+            storage_fixture,
+            storage: None,
         }
     }
 }
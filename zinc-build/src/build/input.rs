@@ -27,6 +27,9 @@ pub enum Input {
         msg: JsonValue,
         /// The contract methods arguments JSON data.
         arguments: HashMap<String, JsonValue>,
+        /// The block environment, represented by the `zksync::env` variable, which defaults to zeros.
+        #[serde(default, skip_serializing_if = "JsonValue::is_null")]
+        env: JsonValue,
     },
 }
 
@@ -45,11 +48,13 @@ impl Input {
         storage: JsonValue,
         msg: JsonValue,
         arguments: HashMap<String, JsonValue>,
+        env: JsonValue,
     ) -> Self {
         Self::Contract {
             storage,
             msg,
             arguments,
+            env,
         }
     }
 }
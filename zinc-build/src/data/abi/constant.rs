@@ -0,0 +1,36 @@
+//!
+//! The contract ABI constant.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::data::abi::r#type::Type;
+
+///
+/// The contract ABI public constant.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Constant {
+    /// The constant name.
+    pub name: String,
+    /// The constant type.
+    #[serde(rename = "type")]
+    pub r#type: Type,
+    /// The constant value in the input JSON format.
+    pub value: JsonValue,
+}
+
+impl Constant {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, r#type: Type, value: JsonValue) -> Self {
+        Self {
+            name,
+            r#type,
+            value,
+        }
+    }
+}
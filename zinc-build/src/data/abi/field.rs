@@ -0,0 +1,29 @@
+//!
+//! The contract ABI named type.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::abi::r#type::Type;
+
+///
+/// The contract ABI named type, e.g. a method argument or a structure field.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl Field {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, r#type: Type) -> Self {
+        Self { name, r#type }
+    }
+}
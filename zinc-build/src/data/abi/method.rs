@@ -0,0 +1,47 @@
+//!
+//! The contract ABI method.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::abi::field::Field;
+use crate::data::abi::r#type::Type;
+
+///
+/// The contract ABI public method.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Method {
+    /// The method name.
+    pub name: String,
+    /// Whether the method can mutate the contract storage state.
+    pub is_mutable: bool,
+    /// Whether the method must receive a transfer, that is, it is declared with `#[payable]`.
+    pub is_payable: bool,
+    /// The method input arguments, excluding the contract instance.
+    pub inputs: Vec<Field>,
+    /// The method output type.
+    pub output: Type,
+}
+
+impl Method {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        is_mutable: bool,
+        is_payable: bool,
+        inputs: Vec<Field>,
+        output: Type,
+    ) -> Self {
+        Self {
+            name,
+            is_mutable,
+            is_payable,
+            inputs,
+            output,
+        }
+    }
+}
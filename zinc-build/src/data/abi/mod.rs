@@ -0,0 +1,67 @@
+//!
+//! The contract ABI document.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod constant;
+pub mod field;
+pub mod method;
+pub mod storage_field;
+pub mod r#type;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::constant::Constant;
+use self::method::Method;
+use self::storage_field::StorageField;
+
+///
+/// The contract ABI document.
+///
+/// Describes the contract public methods, storage fields, and public constants, so the
+/// clients can encode the method arguments and decode the storage without the source code.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Abi {
+    /// The type schema format version.
+    pub version: usize,
+    /// The contract project name.
+    pub name: String,
+    /// The contract public methods, sorted by name.
+    pub methods: Vec<Method>,
+    /// The contract storage fields in the storage order.
+    pub storage: Vec<StorageField>,
+    /// The contract public constants, sorted by name.
+    pub constants: Vec<Constant>,
+}
+
+impl Abi {
+    /// The type schema format version, which must be bumped on every incompatible change.
+    pub const VERSION: usize = 1;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The methods and constants are sorted to make the document deterministic.
+    ///
+    pub fn new(
+        name: String,
+        mut methods: Vec<Method>,
+        storage: Vec<StorageField>,
+        mut constants: Vec<Constant>,
+    ) -> Self {
+        methods.sort_by(|a, b| a.name.cmp(&b.name));
+        constants.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            version: Self::VERSION,
+            name,
+            methods,
+            storage,
+            constants,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//!
+//! The contract ABI storage field.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::abi::r#type::Type;
+use crate::data::r#type::contract_field::ContractField as BuildContractField;
+
+///
+/// The contract ABI storage field.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageField {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    #[serde(rename = "type")]
+    pub r#type: Type,
+    /// Whether the field is public.
+    pub is_public: bool,
+    /// Whether the field is implicit, that is, added by the compiler.
+    pub is_implicit: bool,
+}
+
+impl From<BuildContractField> for StorageField {
+    fn from(field: BuildContractField) -> Self {
+        Self {
+            name: field.name,
+            r#type: field.r#type.into(),
+            is_public: field.is_public,
+            is_implicit: field.is_implicit,
+        }
+    }
+}
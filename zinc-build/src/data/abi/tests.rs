@@ -0,0 +1,234 @@
+//!
+//! The contract ABI document tests.
+//!
+
+use num::BigInt;
+use serde_json::json;
+
+use crate::data::abi::constant::Constant;
+use crate::data::abi::field::Field;
+use crate::data::abi::method::Method;
+use crate::data::abi::r#type::Type;
+use crate::data::abi::storage_field::StorageField;
+use crate::data::abi::Abi;
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type as BuildType;
+
+#[test]
+fn ok_nested_composite() {
+    let r#type: Type = BuildType::Structure(vec![
+        (
+            "points".to_owned(),
+            BuildType::Array(
+                Box::new(BuildType::Tuple(vec![
+                    BuildType::Scalar(ScalarType::Integer(IntegerType {
+                        is_signed: false,
+                        bitlength: 8,
+                    })),
+                    BuildType::Scalar(ScalarType::Boolean),
+                ])),
+                2,
+            ),
+        ),
+        (
+            "kind".to_owned(),
+            BuildType::Enumeration {
+                bitlength: 8,
+                variants: vec![("A".to_owned(), BigInt::from(1))],
+            },
+        ),
+        (
+            "inner".to_owned(),
+            BuildType::Structure(vec![(
+                "value".to_owned(),
+                BuildType::Scalar(ScalarType::Field),
+            )]),
+        ),
+    ])
+    .into();
+
+    let expected = json!({
+        "kind": "structure",
+        "fields": [
+            {
+                "name": "points",
+                "type": {
+                    "kind": "array",
+                    "element": {
+                        "kind": "tuple",
+                        "elements": [
+                            { "kind": "integer", "is_signed": false, "bitlength": 8 },
+                            { "kind": "boolean" },
+                        ],
+                    },
+                    "size": 2,
+                },
+            },
+            {
+                "name": "kind",
+                "type": {
+                    "kind": "enumeration",
+                    "bitlength": 8,
+                    "variants": [
+                        { "name": "A", "value": "1" },
+                    ],
+                },
+            },
+            {
+                "name": "inner",
+                "type": {
+                    "kind": "structure",
+                    "fields": [
+                        { "name": "value", "type": { "kind": "field" } },
+                    ],
+                },
+            },
+        ],
+    });
+
+    assert_eq!(
+        serde_json::to_value(r#type).expect(zinc_const::panic::DATA_CONVERSION),
+        expected
+    );
+}
+
+#[test]
+fn ok_map() {
+    let r#type: Type = BuildType::Map {
+        key_type: Box::new(BuildType::Scalar(ScalarType::Integer(IntegerType {
+            is_signed: false,
+            bitlength: 160,
+        }))),
+        value_type: Box::new(BuildType::Maybe(Box::new(BuildType::Array(
+            Box::new(BuildType::Scalar(ScalarType::Integer(IntegerType {
+                is_signed: true,
+                bitlength: 64,
+            }))),
+            4,
+        )))),
+    }
+    .into();
+
+    let expected = json!({
+        "kind": "map",
+        "key": { "kind": "integer", "is_signed": false, "bitlength": 160 },
+        "value": {
+            "kind": "maybe",
+            "inner": {
+                "kind": "array",
+                "element": { "kind": "integer", "is_signed": true, "bitlength": 64 },
+                "size": 4,
+            },
+        },
+    });
+
+    assert_eq!(
+        serde_json::to_value(r#type).expect(zinc_const::panic::DATA_CONVERSION),
+        expected
+    );
+}
+
+#[test]
+fn ok_document() {
+    let abi = Abi::new(
+        "Test".to_owned(),
+        vec![
+            Method::new(
+                "withdraw".to_owned(),
+                true,
+                false,
+                vec![Field::new(
+                    "amount".to_owned(),
+                    Type::Integer {
+                        is_signed: false,
+                        bitlength: 248,
+                    },
+                )],
+                Type::Unit,
+            ),
+            Method::new(
+                "balance".to_owned(),
+                false,
+                false,
+                vec![],
+                Type::Integer {
+                    is_signed: false,
+                    bitlength: 248,
+                },
+            ),
+        ],
+        vec![StorageField::from(ContractField::new(
+            "balances".to_owned(),
+            BuildType::Map {
+                key_type: Box::new(BuildType::Scalar(ScalarType::Integer(IntegerType {
+                    is_signed: false,
+                    bitlength: 160,
+                }))),
+                value_type: Box::new(BuildType::Scalar(ScalarType::Integer(IntegerType {
+                    is_signed: false,
+                    bitlength: 248,
+                }))),
+            },
+            true,
+            false,
+        ))],
+        vec![Constant::new(
+            "NAME".to_owned(),
+            Type::String,
+            json!("test"),
+        )],
+    );
+
+    let expected = json!({
+        "version": 1,
+        "name": "Test",
+        "methods": [
+            {
+                "name": "balance",
+                "is_mutable": false,
+                "is_payable": false,
+                "inputs": [],
+                "output": { "kind": "integer", "is_signed": false, "bitlength": 248 },
+            },
+            {
+                "name": "withdraw",
+                "is_mutable": true,
+                "is_payable": false,
+                "inputs": [
+                    {
+                        "name": "amount",
+                        "type": { "kind": "integer", "is_signed": false, "bitlength": 248 },
+                    },
+                ],
+                "output": { "kind": "unit" },
+            },
+        ],
+        "storage": [
+            {
+                "name": "balances",
+                "type": {
+                    "kind": "map",
+                    "key": { "kind": "integer", "is_signed": false, "bitlength": 160 },
+                    "value": { "kind": "integer", "is_signed": false, "bitlength": 248 },
+                },
+                "is_public": true,
+                "is_implicit": false,
+            },
+        ],
+        "constants": [
+            {
+                "name": "NAME",
+                "type": { "kind": "string" },
+                "value": "test",
+            },
+        ],
+    });
+
+    let value = serde_json::to_value(&abi).expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(value, expected);
+
+    let parsed: Abi = serde_json::from_value(value).expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(parsed, abi);
+}
@@ -0,0 +1,141 @@
+//!
+//! The contract ABI type schema.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::abi::field::Field;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type as BuildType;
+
+///
+/// The contract ABI type schema.
+///
+/// Every type is an object with the `kind` tag, and the composite types describe their
+/// element types recursively.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Type {
+    /// The `()` type.
+    Unit,
+    /// The `bool` type.
+    Boolean,
+    /// The `u{n}` or `i{n}` type.
+    Integer {
+        /// Whether the integer type is signed.
+        is_signed: bool,
+        /// The integer type bitlength.
+        bitlength: usize,
+    },
+    /// The `field` type.
+    Field,
+    /// The `str` type, which is only used by the constants.
+    String,
+    /// The `enum` type, whose values are written as the variant names.
+    Enumeration {
+        /// The enumeration type bitlength.
+        bitlength: usize,
+        /// The enumeration variants.
+        variants: Vec<Variant>,
+    },
+    /// The `[T; N]` type.
+    Array {
+        /// The array element type.
+        element: Box<Type>,
+        /// The array size.
+        size: usize,
+    },
+    /// The `(T1, T2, ...)` type.
+    Tuple {
+        /// The tuple element types.
+        elements: Vec<Type>,
+    },
+    /// The `struct` type.
+    Structure {
+        /// The structure fields.
+        fields: Vec<Field>,
+    },
+    /// The `std::collections::MTreeMap<K, V>` type.
+    Map {
+        /// The map key type.
+        key: Box<Type>,
+        /// The map value type.
+        value: Box<Type>,
+    },
+    /// The `Maybe<T>` type, whose absent value is written as `null`.
+    Maybe {
+        /// The optional value type.
+        inner: Box<Type>,
+    },
+}
+
+///
+/// The contract ABI enumeration variant.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    /// The variant name.
+    pub name: String,
+    /// The variant value as a decimal string.
+    pub value: String,
+}
+
+impl From<BuildType> for Type {
+    fn from(r#type: BuildType) -> Self {
+        match r#type {
+            BuildType::Unit => Self::Unit,
+            BuildType::Scalar(ScalarType::Boolean) => Self::Boolean,
+            BuildType::Scalar(ScalarType::Integer(inner)) => Self::Integer {
+                is_signed: inner.is_signed,
+                bitlength: inner.bitlength,
+            },
+            BuildType::Scalar(ScalarType::Field) => Self::Field,
+            BuildType::Enumeration {
+                bitlength,
+                variants,
+            } => Self::Enumeration {
+                bitlength,
+                variants: variants
+                    .into_iter()
+                    .map(|(name, value)| Variant {
+                        name,
+                        value: value.to_string(),
+                    })
+                    .collect(),
+            },
+
+            BuildType::Array(r#type, size) => Self::Array {
+                element: Box::new((*r#type).into()),
+                size,
+            },
+            BuildType::Tuple(types) => Self::Tuple {
+                elements: types.into_iter().map(Self::from).collect(),
+            },
+            BuildType::Structure(fields) => Self::Structure {
+                fields: fields
+                    .into_iter()
+                    .map(|(name, r#type)| Field::new(name, r#type.into()))
+                    .collect(),
+            },
+            BuildType::Contract(fields) => Self::Structure {
+                fields: fields
+                    .into_iter()
+                    .map(|field| Field::new(field.name, field.r#type.into()))
+                    .collect(),
+            },
+
+            BuildType::Map {
+                key_type,
+                value_type,
+            } => Self::Map {
+                key: Box::new((*key_type).into()),
+                value: Box::new((*value_type).into()),
+            },
+            BuildType::Maybe(r#type) => Self::Maybe {
+                inner: Box::new((*r#type).into()),
+            },
+        }
+    }
+}
@@ -2,5 +2,7 @@
 //! The Zinc VM types and template data.
 //!
 
+pub mod abi;
+pub mod template;
 pub mod r#type;
 pub mod value;
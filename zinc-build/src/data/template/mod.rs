@@ -0,0 +1,169 @@
+//!
+//! The Zinc input JSON template.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+///
+/// The Zinc input JSON template.
+///
+/// Contains a placeholder value appropriate to each scalar type, so the template can be filled
+/// in without guessing the value format. Each JSON object is accompanied by the comments object,
+/// which describes the type and valid range of every field, and is ignored while reading values.
+///
+#[derive(Debug, Clone)]
+pub struct Template {
+    /// The template data type.
+    pub r#type: Type,
+}
+
+impl Template {
+    /// The maximal integer bitlength, whose range is written with decimal numbers.
+    const DECIMAL_RANGE_BITLENGTH_LIMIT: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(r#type: Type) -> Self {
+        Self { r#type }
+    }
+
+    ///
+    /// Converts the template into a JSON value with placeholders and comments.
+    ///
+    pub fn into_json(self) -> JsonValue {
+        Self::placeholder(self.r#type)
+    }
+
+    ///
+    /// Creates the placeholder JSON value of `r#type`.
+    ///
+    fn placeholder(r#type: Type) -> JsonValue {
+        match r#type {
+            Type::Unit => JsonValue::Null,
+            Type::Scalar(ScalarType::Boolean) => JsonValue::Bool(false),
+            Type::Scalar(ScalarType::Integer(inner)) => {
+                JsonValue::String(if inner.bitlength == zinc_const::bitlength::ETH_ADDRESS {
+                    format!("0x{}", "0".repeat(zinc_const::size::ETH_ADDRESS * 2))
+                } else {
+                    "0".to_owned()
+                })
+            }
+            Type::Scalar(ScalarType::Field) => JsonValue::String("0x0".to_owned()),
+            Type::Enumeration { mut variants, .. } => JsonValue::String(variants.remove(0).0),
+
+            Type::Array(r#type, size) => JsonValue::Array(vec![Self::placeholder(*r#type); size]),
+            Type::Tuple(types) => {
+                JsonValue::Array(types.into_iter().map(Self::placeholder).collect())
+            }
+            Type::Structure(fields) => Self::object(fields),
+            Type::Contract(fields) => Self::object(
+                fields
+                    .into_iter()
+                    .map(|field| (field.name, field.r#type))
+                    .collect(),
+            ),
+
+            Type::Map { .. } => JsonValue::Array(vec![]),
+            Type::Maybe(_) => JsonValue::Null,
+        }
+    }
+
+    ///
+    /// Creates the placeholder JSON object of `fields` with the comments object.
+    ///
+    fn object(fields: Vec<(String, Type)>) -> JsonValue {
+        let mut object = JsonMap::<String, JsonValue>::with_capacity(fields.len() + 1);
+        let mut comments = JsonMap::<String, JsonValue>::with_capacity(fields.len());
+        for (name, r#type) in fields.into_iter() {
+            comments.insert(name.clone(), JsonValue::String(Self::describe(&r#type)));
+            object.insert(name, Self::placeholder(r#type));
+        }
+        if !comments.is_empty() {
+            object.insert(
+                zinc_const::json::COMMENTS_KEY.to_owned(),
+                JsonValue::Object(comments),
+            );
+        }
+        JsonValue::Object(object)
+    }
+
+    ///
+    /// Describes the Zinc type and the valid range of `r#type`.
+    ///
+    pub fn describe(r#type: &Type) -> String {
+        match r#type {
+            Type::Unit => "(), must be null".to_owned(),
+            Type::Scalar(ScalarType::Boolean) => "bool, true or false".to_owned(),
+            Type::Scalar(ScalarType::Integer(inner))
+                if inner.bitlength == zinc_const::bitlength::ETH_ADDRESS =>
+            {
+                format!(
+                    "{}, an address as a hexadecimal string of {} bytes",
+                    inner,
+                    zinc_const::size::ETH_ADDRESS
+                )
+            }
+            Type::Scalar(ScalarType::Integer(inner)) => {
+                format!(
+                    "{}, a decimal or hexadecimal string from {}",
+                    inner,
+                    Self::range(inner)
+                )
+            }
+            Type::Scalar(ScalarType::Field) => {
+                "field, a decimal or hexadecimal string of a field element".to_owned()
+            }
+            Type::Enumeration { variants, .. } => format!(
+                "enum, one of {}",
+                variants
+                    .iter()
+                    .map(|(name, _value)| format!("\"{}\"", name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+
+            Type::Array(inner, size) => format!(
+                "[{}; {}], an array of {} elements of {}",
+                inner,
+                size,
+                size,
+                Self::describe(inner)
+            ),
+            Type::Tuple(_) => format!("{}, an array of the tuple elements", r#type),
+            Type::Structure(_) | Type::Contract(_) => {
+                "structure, an object described by its own comments".to_owned()
+            }
+
+            Type::Map { .. } => format!("{}, an array of `key` and `value` objects", r#type),
+            Type::Maybe(inner) => format!("{}, null or {}", r#type, Self::describe(inner)),
+        }
+    }
+
+    ///
+    /// Describes the valid range of the integer type.
+    ///
+    fn range(r#type: &IntegerType) -> String {
+        if r#type.bitlength <= Self::DECIMAL_RANGE_BITLENGTH_LIMIT {
+            return format!("{} to {}", r#type.min(), r#type.max());
+        }
+
+        if r#type.is_signed {
+            format!(
+                "-2^{} to 2^{} - 1",
+                r#type.bitlength - 1,
+                r#type.bitlength - 1
+            )
+        } else {
+            format!("0 to 2^{} - 1", r#type.bitlength)
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//!
+//! The Zinc input JSON template tests.
+//!
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+use serde_json::json;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::Value;
+
+use super::Template;
+
+fn nested_structure() -> Type {
+    let point = Type::Structure(vec![
+        (
+            "x".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::new(true, 8))),
+        ),
+        ("valid".to_owned(), Type::Scalar(ScalarType::Boolean)),
+    ]);
+
+    Type::Structure(vec![
+        ("owner".to_owned(), Type::new_eth_address_integer()),
+        (
+            "amount".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::BALANCE)),
+        ),
+        ("points".to_owned(), Type::Array(Box::new(point), 2)),
+        (
+            "kind".to_owned(),
+            Type::Enumeration {
+                bitlength: 8,
+                variants: vec![("A".to_owned(), 1.into()), ("B".to_owned(), 2.into())],
+            },
+        ),
+    ])
+}
+
+#[test]
+fn ok_placeholders() {
+    let point = json!({
+        "x": "0",
+        "valid": false,
+        "_comments": {
+            "x": "i8, a decimal or hexadecimal string from -128 to 127",
+            "valid": "bool, true or false",
+        },
+    });
+
+    assert_eq!(
+        Template::new(nested_structure()).into_json(),
+        json!({
+            "owner": "0x0000000000000000000000000000000000000000",
+            "amount": "0",
+            "points": [point.clone(), point],
+            "kind": "A",
+            "_comments": {
+                "owner": "u160, an address as a hexadecimal string of 20 bytes",
+                "amount": "u248, a decimal or hexadecimal string from 0 to 2^248 - 1",
+                "points": "[x: i8, valid: bool; 2], an array of 2 elements of structure, an object described by its own comments",
+                "kind": "enum, one of \"A\", \"B\"",
+            },
+        })
+    );
+}
+
+#[test]
+fn ok_empty_structure_without_comments() {
+    assert_eq!(
+        Template::new(Type::new_empty_structure()).into_json(),
+        json!({})
+    );
+}
+
+#[test]
+fn ok_template_accepted_as_input() {
+    let r#type = nested_structure();
+    let template = Template::new(r#type.clone()).into_json();
+
+    let value =
+        Value::try_from_typed_json(template, r#type).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut expected = vec![BigInt::zero(); 6];
+    expected.push(BigInt::one());
+    assert_eq!(value.into_flat_values(), expected);
+}
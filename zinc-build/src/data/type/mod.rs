@@ -51,6 +51,8 @@ pub enum Type {
         /// The map value type.
         value_type: Box<Type>,
     },
+    /// The `Maybe<T>` type, which is flattened into a boolean flag followed by the payload.
+    Maybe(Box<Type>),
 }
 
 impl Type {
@@ -101,6 +103,11 @@ impl Type {
                 .collect(),
 
             Self::Map { .. } => vec![],
+            Self::Maybe(r#type) => {
+                let mut types = vec![ScalarType::Boolean];
+                types.extend(Self::into_flat_scalar_types(*r#type));
+                types
+            }
         }
     }
 
@@ -108,7 +115,7 @@ impl Type {
     /// Wraps the type into a structure, which consists of the contract output result itself and a
     /// field for the contract storage root hash, which is also an implicit part of the method output.
     ///
-    /// Used only for mutable methods.
+    /// Used for both mutable and immutable methods, since the root hash is always inputized.
     ///
     pub fn into_mutable_method_output(self) -> Self {
         Self::Structure(vec![
@@ -132,6 +139,21 @@ impl Type {
             Self::Contract(fields) => fields.iter().map(|field| field.r#type.size()).sum(),
 
             Self::Map { .. } => 0,
+            Self::Maybe(r#type) => 1 + r#type.size(),
+        }
+    }
+
+    ///
+    /// Splits a contract storage field type into the types of the Merkle tree leaves it occupies.
+    ///
+    /// Each map occupies a separate leaf, so an array of maps occupies a leaf per element.
+    ///
+    pub fn into_storage_leaf_types(self) -> Vec<Self> {
+        match self {
+            Self::Array(r#type, size) if matches!(*r#type, Self::Map { .. }) => {
+                vec![*r#type; size]
+            }
+            r#type => vec![r#type],
         }
     }
 
@@ -203,6 +225,7 @@ impl fmt::Display for Type {
                 "std::collections::MTreeMap<{}, {}>",
                 key_type, value_type,
             ),
+            Self::Maybe(inner) => write!(f, "Maybe<{}>", inner),
         }
     }
 }
@@ -63,6 +63,24 @@ impl Type {
         bitlength: zinc_const::bitlength::BALANCE,
     };
 
+    /// An auxiliary internal type.
+    pub const LAYOUT_HASH: Self = Type {
+        is_signed: false,
+        bitlength: zinc_const::bitlength::LAYOUT_HASH,
+    };
+
+    /// An auxiliary internal type.
+    pub const TIMESTAMP: Self = Type {
+        is_signed: false,
+        bitlength: zinc_const::bitlength::TIMESTAMP,
+    };
+
+    /// An auxiliary internal type.
+    pub const BLOCK_NUMBER: Self = Type {
+        is_signed: false,
+        bitlength: zinc_const::bitlength::BLOCK_NUMBER,
+    };
+
     ///
     /// A shortcut constructor.
     ///
@@ -3,10 +3,9 @@
 //!
 
 use failure::Fail;
+use num::BigInt;
 use serde_json::Value as JsonValue;
 
-use zinc_math::InferenceError;
-
 ///
 /// The inner type error variant.
 ///
@@ -28,9 +27,34 @@ pub enum Type {
     )]
     InvalidNumberFormat(String),
 
-    /// The number is too large for the type.
-    #[fail(display = "value inference: {}", inner)]
-    ValueOverflow { inner: InferenceError },
+    /// The ETH address could not be parsed successfully.
+    #[fail(
+        display = "failed to parse an address `{}`: {} (expected a hexadecimal, EIP-55 checksummed, or decimal string)",
+        value, inner
+    )]
+    InvalidAddressFormat {
+        /// The invalid address string.
+        value: String,
+        /// The address parsing error.
+        inner: zinc_math::EthAddressError,
+    },
+
+    /// The number is out of the type range, e.g. a negative value of an unsigned type, or a
+    /// `field` value not less than the field modulus.
+    #[fail(
+        display = "value `{}` is out of range of type `{}`: expected from {} to {}",
+        value, r#type, min, max
+    )]
+    ValueOutOfRange {
+        /// The invalid value.
+        value: BigInt,
+        /// The expected type.
+        r#type: String,
+        /// The minimal allowed value.
+        min: BigInt,
+        /// The maximal allowed value.
+        max: BigInt,
+    },
 
     /// The structure field is missing.
     #[fail(display = "value for field `{}` is missing", _0)]
@@ -62,6 +86,13 @@ pub enum Type {
         _0
     )]
     InvalidMapFormat(String),
+
+    /// The JSON value is nested deeper than allowed.
+    #[fail(display = "the JSON value nesting exceeds the limit of {}", limit)]
+    JsonTooDeep {
+        /// The maximal nesting depth.
+        limit: usize,
+    },
 }
 
 impl Type {
@@ -2,14 +2,18 @@
 //! The Zinc VM template value.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod contract_field;
 pub mod error;
 pub mod scalar;
 
 use std::collections::HashSet;
 
+use num::bigint::Sign;
 use num::BigInt;
-use num::Signed;
+use num::One;
 use num::Zero;
 use serde::Deserialize;
 use serde::Serialize;
@@ -17,8 +21,6 @@ use serde_json::json;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
 
-use zinc_math::InferenceError;
-
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
 use crate::data::r#type::scalar::integer::Type as IntegerType;
 use crate::data::r#type::scalar::Type as ScalarType;
@@ -59,6 +61,13 @@ pub enum Value {
 
     /// The `std::collections::MTreeMap` value.
     Map(Vec<(Value, Value)>),
+    /// The `Maybe<T>` value, represented with `null` or the payload value.
+    Maybe {
+        /// Whether the payload value is present.
+        is_some: bool,
+        /// The payload value, which is set to the default one if it is absent.
+        value: Box<Value>,
+    },
 }
 
 impl Value {
@@ -109,13 +118,69 @@ impl Value {
             ),
 
             Type::Map { .. } => Self::Map(vec![]),
+            Type::Maybe(r#type) => Self::Maybe {
+                is_some: false,
+                value: Box::new(Self::new(*r#type)),
+            },
         }
     }
 
     ///
     /// Creates a value of `r#type` from the JSON `value`.
     ///
+    /// The JSON nesting depth is limited by the default limit.
+    ///
     pub fn try_from_typed_json(value: JsonValue, r#type: Type) -> Result<Self, Error> {
+        Self::try_from_typed_json_with_depth_limit(value, r#type, zinc_const::limit::JSON_DEPTH)
+    }
+
+    ///
+    /// Creates a value of `r#type` from the JSON `value`, whose nesting depth must not exceed
+    /// the `limit`.
+    ///
+    /// The depth is checked before the conversion, so pathological inputs are rejected before
+    /// any recursive processing.
+    ///
+    pub fn try_from_typed_json_with_depth_limit(
+        value: JsonValue,
+        r#type: Type,
+        limit: usize,
+    ) -> Result<Self, Error> {
+        if Self::json_depth(&value) > limit {
+            return Err(ErrorType::JsonTooDeep { limit }.into());
+        }
+
+        Self::from_json(value, r#type)
+    }
+
+    ///
+    /// Returns the nesting depth of the JSON `value`, where scalars have the depth of 1.
+    ///
+    /// The tree is traversed iteratively to not overflow the stack on deeply nested values.
+    ///
+    pub fn json_depth(value: &JsonValue) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(value, 1)];
+        while let Some((value, depth)) = stack.pop() {
+            max_depth = std::cmp::max(max_depth, depth);
+
+            match value {
+                JsonValue::Array(array) => {
+                    stack.extend(array.iter().map(|value| (value, depth + 1)))
+                }
+                JsonValue::Object(object) => {
+                    stack.extend(object.values().map(|value| (value, depth + 1)))
+                }
+                _ => {}
+            }
+        }
+        max_depth
+    }
+
+    ///
+    /// Creates a value of `r#type` from the JSON `value` without checking the nesting depth.
+    ///
+    fn from_json(value: JsonValue, r#type: Type) -> Result<Self, Error> {
         match r#type {
             Type::Unit => Self::unit_from_json(value),
             Type::Scalar(inner) => Self::scalar_from_json(value, inner),
@@ -133,6 +198,7 @@ impl Value {
                 key_type,
                 value_type,
             } => Self::map_from_json(value, *key_type, *value_type),
+            Type::Maybe(inner) => Self::maybe_from_json(value, *inner),
         }
     }
 
@@ -218,6 +284,13 @@ impl Value {
             }
 
             Type::Map { .. } => Self::Map(vec![]),
+            Type::Maybe(r#type) => Self::Maybe {
+                is_some: flat_values
+                    .first()
+                    .map(|value| !value.is_zero())
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                value: Box::new(Self::from_flat_values(*r#type, &flat_values[1..])),
+            },
         }
     }
 
@@ -249,9 +322,28 @@ impl Value {
                 .collect(),
 
             Self::Map(_entries) => vec![],
+            Self::Maybe { is_some, value } => {
+                let mut values = vec![if is_some {
+                    BigInt::one()
+                } else {
+                    BigInt::zero()
+                }];
+                values.extend(value.into_flat_values());
+                values
+            }
         }
     }
 
+    ///
+    /// Sorts the `std::collections::MTreeMap` entries by their flattened key values.
+    ///
+    /// The canonical order makes the map representation independent of the order the entries
+    /// have been inserted in.
+    ///
+    pub fn sort_map_entries(entries: &mut [(Self, Self)]) {
+        entries.sort_by_cached_key(|(key, _value)| key.to_owned().into_flat_values());
+    }
+
     ///
     /// Converts the value to a JSON value.
     ///
@@ -294,7 +386,9 @@ impl Value {
                 JsonValue::Object(object)
             }
 
-            Self::Map(entries) => {
+            Self::Map(mut entries) => {
+                Self::sort_map_entries(entries.as_mut_slice());
+
                 let mut array = Vec::with_capacity(entries.len());
                 for (key, value) in entries.into_iter() {
                     array.push(json!({
@@ -304,6 +398,13 @@ impl Value {
                 }
                 JsonValue::Array(array)
             }
+            Self::Maybe { is_some, value } => {
+                if is_some {
+                    value.into_json()
+                } else {
+                    JsonValue::Null
+                }
+            }
         }
     }
 
@@ -343,28 +444,26 @@ impl Value {
             found: value.to_string(),
         })?;
 
+        if r#type == IntegerType::ETH_ADDRESS {
+            let address = zinc_math::eth_address_from_str(value_string).map_err(|error| {
+                ErrorType::InvalidAddressFormat {
+                    value: value_string.to_owned(),
+                    inner: error,
+                }
+            })?;
+            let bigint = BigInt::from_bytes_be(Sign::Plus, &address);
+            return Ok(Self::Scalar(ScalarValue::Integer(bigint, r#type)));
+        }
+
         let bigint_result = zinc_math::bigint_from_str(value_string);
         let bigint =
             bigint_result.map_err(|_| ErrorType::InvalidNumberFormat(value_string.into()))?;
-        if bigint.is_negative() && !r#type.is_signed {
-            return Err(Error::from(ErrorType::ValueOverflow {
-                inner: InferenceError::Overflow {
-                    value: bigint,
-                    is_signed: r#type.is_signed,
-                    bitlength: r#type.bitlength,
-                },
-            }));
-        }
-
-        let bitlength = zinc_math::infer_minimal_bitlength(&bigint, r#type.is_signed)
-            .map_err(|error| Error::from(ErrorType::ValueOverflow { inner: error }))?;
-        if bitlength > r#type.bitlength {
-            return Err(Error::from(ErrorType::ValueOverflow {
-                inner: InferenceError::Overflow {
-                    value: bigint,
-                    is_signed: r#type.is_signed,
-                    bitlength: r#type.bitlength,
-                },
+        if bigint < r#type.min() || bigint > r#type.max() {
+            return Err(Error::from(ErrorType::ValueOutOfRange {
+                value: bigint,
+                r#type: r#type.to_string(),
+                min: r#type.min(),
+                max: r#type.max(),
             }));
         }
 
@@ -424,15 +523,14 @@ impl Value {
         let bigint =
             bigint_result.map_err(|_| ErrorType::InvalidNumberFormat(value_string.into()))?;
 
-        let bitlength = zinc_math::infer_minimal_bitlength(&bigint, false)
-            .map_err(|error| Error::from(ErrorType::ValueOverflow { inner: error }))?;
-        if bitlength > zinc_const::bitlength::FIELD {
-            return Err(Error::from(ErrorType::ValueOverflow {
-                inner: InferenceError::Overflow {
-                    value: bigint,
-                    is_signed: false,
-                    bitlength: zinc_const::bitlength::FIELD,
-                },
+        let range = zinc_math::field_range();
+        if !range.contains(&bigint) {
+            let (min, max) = range.into_inner();
+            return Err(Error::from(ErrorType::ValueOutOfRange {
+                value: bigint,
+                r#type: ScalarType::Field.to_string(),
+                min,
+                max,
             }));
         }
 
@@ -454,10 +552,10 @@ impl Value {
     /// Creates an array value from the JSON `value`.
     ///
     fn array_from_json(value: JsonValue, r#type: Type, size: usize) -> Result<Self, Error> {
-        let array = value
-            .as_array()
-            .cloned()
-            .ok_or_else(|| ErrorType::type_error("JSON array".to_owned(), value))?;
+        let array = match value {
+            JsonValue::Array(array) => array,
+            value => return Err(ErrorType::type_error("JSON array".to_owned(), value).into()),
+        };
 
         if array.len() != size {
             return Err(ErrorType::UnexpectedSize {
@@ -469,7 +567,7 @@ impl Value {
 
         let mut values = Vec::with_capacity(size);
         for (index, value) in array.into_iter().enumerate() {
-            let typed_value = Self::try_from_typed_json(value, r#type.clone()).push_array(index)?;
+            let typed_value = Self::from_json(value, r#type.clone()).push_array(index)?;
 
             values.push(typed_value);
         }
@@ -481,10 +579,10 @@ impl Value {
     /// Creates a tuple value from the JSON `value`.
     ///
     fn tuple_from_json(value: JsonValue, types: Vec<Type>) -> Result<Self, Error> {
-        let array = value
-            .as_array()
-            .cloned()
-            .ok_or_else(|| ErrorType::type_error("JSON array".to_owned(), value))?;
+        let array = match value {
+            JsonValue::Array(array) => array,
+            value => return Err(ErrorType::type_error("JSON array".to_owned(), value).into()),
+        };
 
         if array.len() != types.len() {
             return Err(ErrorType::UnexpectedSize {
@@ -496,7 +594,7 @@ impl Value {
 
         let mut values = Vec::with_capacity(types.len());
         for (index, (value, r#type)) in array.into_iter().zip(types).enumerate() {
-            let typed_value = Self::try_from_typed_json(value, r#type).push_array(index)?;
+            let typed_value = Self::from_json(value, r#type).push_array(index)?;
             values.push(typed_value);
         }
 
@@ -510,10 +608,12 @@ impl Value {
         value: JsonValue,
         field_types: Vec<(String, Type)>,
     ) -> Result<Self, Error> {
-        let mut object = value
-            .as_object()
-            .cloned()
-            .ok_or_else(|| ErrorType::type_error("JSON object".to_owned(), value))?;
+        let mut object = match value {
+            JsonValue::Object(object) => object,
+            value => return Err(ErrorType::type_error("JSON object".to_owned(), value).into()),
+        };
+
+        object.remove(zinc_const::json::COMMENTS_KEY);
 
         let mut used_fields = HashSet::with_capacity(field_types.len());
         let mut field_values = Vec::with_capacity(field_types.len());
@@ -524,8 +624,7 @@ impl Value {
                 .remove(name.as_str())
                 .ok_or_else(|| ErrorType::MissingField(name.clone()))?;
 
-            let value =
-                Self::try_from_typed_json(json_value, r#type).push_structure(name.as_str())?;
+            let value = Self::from_json(json_value, r#type).push_structure(name.as_str())?;
 
             field_values.push((name, value));
         }
@@ -546,10 +645,12 @@ impl Value {
         value: JsonValue,
         field_types: Vec<ContractFieldType>,
     ) -> Result<Self, Error> {
-        let mut object = value
-            .as_object()
-            .cloned()
-            .ok_or_else(|| ErrorType::type_error("JSON object".to_owned(), value))?;
+        let mut object = match value {
+            JsonValue::Object(object) => object,
+            value => return Err(ErrorType::type_error("JSON object".to_owned(), value).into()),
+        };
+
+        object.remove(zinc_const::json::COMMENTS_KEY);
 
         let mut used_fields = HashSet::with_capacity(field_types.len());
         let mut field_values = Vec::with_capacity(field_types.len());
@@ -560,7 +661,7 @@ impl Value {
                 .remove(field_type.name.as_str())
                 .ok_or_else(|| ErrorType::MissingField(field_type.name.clone()))?;
 
-            let value = Self::try_from_typed_json(json_value, field_type.r#type)
+            let value = Self::from_json(json_value, field_type.r#type)
                 .push_structure(field_type.name.as_str())?;
 
             field_values.push(ContractField::new(
@@ -599,16 +700,32 @@ impl Value {
                 .get("key")
                 .cloned()
                 .ok_or_else(|| ErrorType::MissingField("key".to_owned()))?;
-            let key = Self::try_from_typed_json(key, key_type.clone())?;
+            let key = Self::from_json(key, key_type.clone())?;
 
             let value = entry
                 .get("value")
                 .cloned()
                 .ok_or_else(|| ErrorType::MissingField("value".to_owned()))?;
-            let value = Self::try_from_typed_json(value, value_type.clone())?;
+            let value = Self::from_json(value, value_type.clone())?;
 
             result.push((key, value));
         }
+        Self::sort_map_entries(result.as_mut_slice());
+
         Ok(Self::Map(result))
     }
+
+    ///
+    /// Creates a `Maybe<T>` value from the JSON `value`, where `null` stands for the absent one.
+    ///
+    fn maybe_from_json(value: JsonValue, r#type: Type) -> Result<Self, Error> {
+        if value.is_null() {
+            return Ok(Self::new(Type::Maybe(Box::new(r#type))));
+        }
+
+        Ok(Self::Maybe {
+            is_some: true,
+            value: Box::new(Self::from_json(value, r#type)?),
+        })
+    }
 }
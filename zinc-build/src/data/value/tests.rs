@@ -0,0 +1,326 @@
+//!
+//! The Zinc VM template value tests.
+//!
+
+use num::BigInt;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+use super::error::r#type::Type as ErrorType;
+use super::Value;
+
+fn nested_arrays(depth: usize) -> (JsonValue, Type) {
+    let mut value = json!("42");
+    let mut r#type = Type::Scalar(ScalarType::Integer(IntegerType::new(false, 8)));
+    for _ in 1..depth {
+        value = JsonValue::Array(vec![value]);
+        r#type = Type::Array(Box::new(r#type), 1);
+    }
+    (value, r#type)
+}
+
+#[test]
+fn ok_depth_at_limit() {
+    let (value, r#type) = nested_arrays(zinc_const::limit::JSON_DEPTH);
+
+    assert_eq!(Value::json_depth(&value), zinc_const::limit::JSON_DEPTH);
+    assert!(Value::try_from_typed_json(value, r#type).is_ok());
+}
+
+#[test]
+fn ok_depth_of_scalars_and_objects() {
+    assert_eq!(Value::json_depth(&json!("42")), 1);
+    assert_eq!(Value::json_depth(&json!([])), 1);
+    assert_eq!(
+        Value::json_depth(&json!({ "a": "1", "b": ["2", { "c": "3" }] })),
+        4
+    );
+}
+
+#[test]
+fn error_depth_above_limit() {
+    let (value, r#type) = nested_arrays(zinc_const::limit::JSON_DEPTH + 1);
+
+    match Value::try_from_typed_json(value, r#type) {
+        Err(error) => match error.error {
+            ErrorType::JsonTooDeep { limit } => assert_eq!(limit, zinc_const::limit::JSON_DEPTH),
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn error_depth_pathological_input() {
+    let mut value = json!("42");
+    for _ in 0..1_000 {
+        value = json!({ "a": value });
+    }
+    let r#type = Type::Scalar(ScalarType::Field);
+
+    match Value::try_from_typed_json_with_depth_limit(value, r#type, 16) {
+        Err(error) => match error.error {
+            ErrorType::JsonTooDeep { limit } => assert_eq!(limit, 16),
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn error_array_size_checked_before_conversion() {
+    let value = JsonValue::Array(vec![json!(["invalid"]); 100_000]);
+    let r#type = Type::Array(
+        Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+            false, 8,
+        )))),
+        4,
+    );
+
+    match Value::try_from_typed_json(value, r#type) {
+        Err(error) => match error.error {
+            ErrorType::UnexpectedSize { expected, found } => {
+                assert_eq!(expected, 4);
+                assert_eq!(found, 100_000);
+            }
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn ok_map_entries_canonical_order() {
+    let r#type = Type::Map {
+        key_type: Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+            false, 8,
+        )))),
+        value_type: Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+            false, 8,
+        )))),
+    };
+
+    let ascending = json!([
+        { "key": "1", "value": "10" },
+        { "key": "2", "value": "20" },
+        { "key": "3", "value": "30" },
+    ]);
+    let shuffled = json!([
+        { "key": "3", "value": "30" },
+        { "key": "1", "value": "10" },
+        { "key": "2", "value": "20" },
+    ]);
+
+    let ascending = Value::try_from_typed_json(ascending, r#type.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_json();
+    let shuffled = Value::try_from_typed_json(shuffled, r#type)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_json();
+
+    assert_eq!(
+        serde_json::to_vec(&ascending).expect(zinc_const::panic::DATA_CONVERSION),
+        serde_json::to_vec(&shuffled).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+}
+
+#[test]
+fn ok_map_array_json_roundtrip() {
+    let map_type = Type::Map {
+        key_type: Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+            false, 8,
+        )))),
+        value_type: Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+            false, 8,
+        )))),
+    };
+    let r#type = Type::Array(Box::new(map_type.clone()), 2);
+
+    let json = json!([[], [{ "key": "1", "value": "10" }]]);
+
+    let value = Value::try_from_typed_json(json.clone(), r#type.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(value.into_json(), json);
+    assert_eq!(r#type.into_storage_leaf_types(), vec![map_type; 2]);
+}
+
+#[test]
+fn ok_structure_comments_ignored() {
+    let r#type = Type::Structure(vec![(
+        "a".to_owned(),
+        Type::Scalar(ScalarType::Integer(IntegerType::new(false, 8))),
+    )]);
+
+    let json = json!({ "a": "42", "_comments": { "a": "u8" } });
+
+    let value = Value::try_from_typed_json(json, r#type).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(value.into_json(), json!({ "a": "42" }));
+}
+
+#[test]
+fn ok_integers_above_javascript_precision_as_strings() {
+    let r#type = Type::Structure(vec![
+        (
+            "at_boundary".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::new(false, 128))),
+        ),
+        (
+            "above_boundary".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::new(false, 248))),
+        ),
+    ]);
+
+    let json = json!({
+        "at_boundary": "9007199254740992",
+        "above_boundary": "9007199254740993",
+    });
+
+    let value = Value::try_from_typed_json(json.clone(), r#type.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let output = value.into_json();
+
+    assert_eq!(output, json);
+    assert!(Value::try_from_typed_json(output, r#type).is_ok());
+}
+
+#[test]
+fn ok_field_full_width_roundtrip() {
+    let r#type = Type::Scalar(ScalarType::Field);
+
+    let json = json!("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000");
+
+    let value = Value::try_from_typed_json(json.clone(), r#type.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let output = value.into_json();
+    assert_eq!(output, json);
+
+    let value =
+        Value::try_from_typed_json(output, r#type).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(value.into_json(), json);
+}
+
+#[test]
+fn ok_integer_signed_bounds() {
+    let r#type = Type::Scalar(ScalarType::Integer(IntegerType::new(true, 8)));
+
+    assert!(Value::try_from_typed_json(json!("-128"), r#type.clone()).is_ok());
+    assert!(Value::try_from_typed_json(json!("127"), r#type).is_ok());
+}
+
+#[test]
+fn error_integer_unsigned_negative() {
+    let r#type = Type::Scalar(ScalarType::Integer(IntegerType::new(false, 8)));
+
+    match Value::try_from_typed_json(json!("-1"), r#type) {
+        Err(error) => match error.error {
+            ErrorType::ValueOutOfRange {
+                value, min, max, ..
+            } => {
+                assert_eq!(value, BigInt::from(-1));
+                assert_eq!(min, BigInt::from(0));
+                assert_eq!(max, BigInt::from(255));
+            }
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn error_integer_signed_above_range() {
+    let r#type = Type::Scalar(ScalarType::Integer(IntegerType::new(true, 8)));
+
+    match Value::try_from_typed_json(json!("128"), r#type) {
+        Err(error) => match error.error {
+            ErrorType::ValueOutOfRange { min, max, .. } => {
+                assert_eq!(min, BigInt::from(-128));
+                assert_eq!(max, BigInt::from(127));
+            }
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn error_field_modulus() {
+    let r#type = Type::Structure(vec![(
+        "values".to_owned(),
+        Type::Array(Box::new(Type::Scalar(ScalarType::Field)), 2),
+    )]);
+
+    let modulus = zinc_math::field_modulus();
+    let json = json!({ "values": ["0", modulus.to_string()] });
+
+    match Value::try_from_typed_json(json, r#type) {
+        Err(error) => {
+            assert_eq!(
+                error.to_string(),
+                format!(
+                    "value `{}` is out of range of type `field`: expected from 0 to {} at `.values[1]`",
+                    modulus,
+                    modulus.clone() - BigInt::from(1),
+                )
+            );
+        }
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn error_field_negative() {
+    let r#type = Type::Scalar(ScalarType::Field);
+
+    match Value::try_from_typed_json(json!("-1"), r#type) {
+        Err(error) => match error.error {
+            ErrorType::ValueOutOfRange { value, .. } => assert_eq!(value, BigInt::from(-1)),
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
+
+#[test]
+fn ok_address_forms() {
+    let r#type = Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS));
+
+    let expected = json!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    for input in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        "517705355260207604495801938720638392742277016301",
+    ]
+    .iter()
+    {
+        let value = Value::try_from_typed_json(json!(input), r#type.clone())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(value.into_json(), expected);
+    }
+}
+
+#[test]
+fn error_address_checksum_mismatch() {
+    let r#type = Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS));
+
+    match Value::try_from_typed_json(json!("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"), r#type) {
+        Err(error) => match error.error {
+            ErrorType::InvalidAddressFormat { inner, .. } => assert_eq!(
+                inner,
+                zinc_math::EthAddressError::ChecksumMismatch {
+                    position: 41,
+                    expected: 'd',
+                    found: 'D',
+                }
+            ),
+            error => panic!("unexpected error: {}", error),
+        },
+        Ok(_) => panic!("the value must be rejected"),
+    }
+}
@@ -25,6 +25,10 @@ pub enum LibraryFunctionIdentifier {
     ConvertFromBitsSigned,
     /// The `std::convert::from_bits_field` function identifier.
     ConvertFromBitsField,
+    /// The `std::convert::to_bytes_be` function identifier.
+    ConvertToBytesBe,
+    /// The `std::convert::from_bytes_be` function identifier.
+    ConvertFromBytesBe,
 
     /// The `std::array::reverse` function identifier.
     ArrayReverse,
@@ -38,6 +42,8 @@ pub enum LibraryFunctionIdentifier {
 
     /// The `zksync::transfer` function identifier.
     ZksyncTransfer,
+    /// The contract `self.balance` function identifier.
+    ZksyncBalance,
 
     /// The `std::collections::MTreeMap::get` function identifier.
     CollectionsMTreeMapGet,
@@ -47,4 +53,7 @@ pub enum LibraryFunctionIdentifier {
     CollectionsMTreeMapInsert,
     /// The `std::collections::MTreeMap::remove` function identifier.
     CollectionsMTreeMapRemove,
+
+    /// The `Maybe::unwrap_or` function identifier.
+    MaybeUnwrapOr,
 }
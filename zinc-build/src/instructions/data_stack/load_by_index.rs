@@ -15,6 +15,9 @@ use crate::instructions::Instruction;
 /// Takes `index` from evaluation stack, loads several values from the data stack
 /// at `address + index` onto the evaluation stack.
 ///
+/// If `is_index_checked` is set, the index bounds have been already enforced by a preceding
+/// access with the same index in the same straight-line block, so the check is not repeated.
+///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LoadByIndex {
     /// The start address where the data must be loaded from.
@@ -23,17 +26,25 @@ pub struct LoadByIndex {
     pub value_size: usize,
     /// The total size of the data chunk where the linear scan must be applied.
     pub total_size: usize,
+    /// Whether the index bounds have been already enforced by a preceding access.
+    pub is_index_checked: bool,
 }
 
 impl LoadByIndex {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(address: usize, value_size: usize, total_size: usize) -> Self {
+    pub fn new(
+        address: usize,
+        value_size: usize,
+        total_size: usize,
+        is_index_checked: bool,
+    ) -> Self {
         Self {
             address,
             value_size,
             total_size,
+            is_index_checked,
         }
     }
 
@@ -55,8 +66,15 @@ impl fmt::Display for LoadByIndex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "load_by_index {} {} {}",
-            self.address, self.value_size, self.total_size
+            "load_by_index {} {} {}{}",
+            self.address,
+            self.value_size,
+            self.total_size,
+            if self.is_index_checked {
+                " checked"
+            } else {
+                ""
+            }
         )
     }
 }
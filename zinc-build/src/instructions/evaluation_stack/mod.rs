@@ -4,4 +4,5 @@
 
 pub mod copy;
 pub mod push;
+pub mod push_pooled;
 pub mod slice;
@@ -0,0 +1,67 @@
+//!
+//! The `push pooled constant` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::Instruction;
+
+///
+/// The `push pooled constant` instruction.
+///
+/// References a constant stored once in the application constant pool instead of
+/// repeating it in every `push` instruction.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PushPooled {
+    /// The constant index in the application constant pool.
+    pub index: usize,
+}
+
+impl PushPooled {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+
+    ///
+    /// Replaces the pooled constant references in `instructions` with the `push` instructions
+    /// from the `constants` pool.
+    ///
+    /// The references with invalid indexes are left as is and fail at runtime.
+    ///
+    pub fn resolve(instructions: &mut [Instruction], constants: &[Push]) {
+        for instruction in instructions.iter_mut() {
+            if let Instruction::PushPooled(PushPooled { index }) = instruction {
+                if let Some(constant) = constants.get(*index) {
+                    *instruction = Instruction::Push(constant.to_owned());
+                }
+            }
+        }
+    }
+}
+
+impl Into<Instruction> for PushPooled {
+    fn into(self) -> Instruction {
+        Instruction::PushPooled(self)
+    }
+}
+
+impl fmt::Display for PushPooled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "push_pooled {}", self.index)
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The `dispatch table` instruction.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `dispatch table` instruction.
+///
+/// Pops the default branch value, the branch values in the discriminant order, and the scrutinee,
+/// and pushes the value of the branch whose discriminant equals the scrutinee, or the default
+/// value if there is no such branch.
+///
+/// The branch discriminants form a dense range starting from `start`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Dispatch {
+    /// The first branch discriminant.
+    pub start: BigInt,
+    /// The number of branches, excluding the default one.
+    pub branch_count: usize,
+    /// The size of each branch value.
+    pub size: usize,
+}
+
+impl Dispatch {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: BigInt, branch_count: usize, size: usize) -> Self {
+        Self {
+            start,
+            branch_count,
+            size,
+        }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for Dispatch {
+    fn into(self) -> Instruction {
+        Instruction::Dispatch(self)
+    }
+}
+
+impl fmt::Display for Dispatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "dispatch {} {} {}",
+            self.start, self.branch_count, self.size
+        )
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod call;
+pub mod dispatch;
 pub mod r#else;
 pub mod endif;
 pub mod exit;
@@ -10,3 +11,4 @@ pub mod r#if;
 pub mod loop_begin;
 pub mod loop_end;
 pub mod r#return;
+pub mod short_circuit;
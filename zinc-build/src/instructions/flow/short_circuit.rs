@@ -0,0 +1,42 @@
+//!
+//! The `short-circuit` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `short-circuit` instruction.
+///
+/// Follows the `If` instruction opening the right operand of a logical `&&` or `||`, which
+/// ends at the matching `Else` instruction. If the left operand already determines the result
+/// and the VM runs without generating constraints, the right operand is not evaluated, so it
+/// may be guarded by the left one, e.g. `index < LENGTH && array[index] == value`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShortCircuit;
+
+impl ShortCircuit {
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for ShortCircuit {
+    fn into(self) -> Instruction {
+        Instruction::ShortCircuit(self)
+    }
+}
+
+impl fmt::Display for ShortCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "short_circuit")
+    }
+}
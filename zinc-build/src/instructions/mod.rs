@@ -28,8 +28,10 @@ use self::data_stack::store_by_index::StoreByIndex;
 use self::dbg::Dbg;
 use self::evaluation_stack::copy::Copy;
 use self::evaluation_stack::push::Push;
+use self::evaluation_stack::push_pooled::PushPooled;
 use self::evaluation_stack::slice::Slice;
 use self::flow::call::Call;
+use self::flow::dispatch::Dispatch;
 use self::flow::exit::Exit;
 use self::flow::loop_begin::LoopBegin;
 use self::flow::loop_end::LoopEnd;
@@ -37,6 +39,7 @@ use self::flow::r#else::Else;
 use self::flow::r#endif::EndIf;
 use self::flow::r#if::If;
 use self::flow::r#return::Return;
+use self::flow::short_circuit::ShortCircuit;
 use self::marker::column::ColumnMarker;
 use self::marker::file::FileMarker;
 use self::marker::function::FunctionMarker;
@@ -78,6 +81,8 @@ pub enum Instruction {
     /// An evaluation stack instruction.
     Push(Push),
     /// An evaluation stack instruction.
+    PushPooled(PushPooled),
+    /// An evaluation stack instruction.
     Slice(Slice),
     /// An evaluation stack instruction.
     Copy(Copy),
@@ -154,6 +159,10 @@ pub enum Instruction {
     /// A flow control instruction.
     EndIf(EndIf),
     /// A flow control instruction.
+    ShortCircuit(ShortCircuit),
+    /// A flow control instruction.
+    Dispatch(Dispatch),
+    /// A flow control instruction.
     LoopBegin(LoopBegin),
     /// A flow control instruction.
     LoopEnd(LoopEnd),
@@ -190,6 +199,7 @@ impl Instruction {
             Self::NoOperation(inner) => inner.is_debug(),
 
             Self::Push(inner) => inner.is_debug(),
+            Self::PushPooled(inner) => inner.is_debug(),
             Self::Slice(inner) => inner.is_debug(),
             Self::Copy(inner) => inner.is_debug(),
 
@@ -232,6 +242,8 @@ impl Instruction {
             Self::If(inner) => inner.is_debug(),
             Self::Else(inner) => inner.is_debug(),
             Self::EndIf(inner) => inner.is_debug(),
+            Self::ShortCircuit(inner) => inner.is_debug(),
+            Self::Dispatch(inner) => inner.is_debug(),
             Self::LoopBegin(inner) => inner.is_debug(),
             Self::LoopEnd(inner) => inner.is_debug(),
             Self::Call(inner) => inner.is_debug(),
@@ -256,6 +268,7 @@ impl fmt::Display for Instruction {
             Self::NoOperation(inner) => write!(f, "{}", inner),
 
             Self::Push(inner) => write!(f, "{}", inner),
+            Self::PushPooled(inner) => write!(f, "{}", inner),
             Self::Slice(inner) => write!(f, "{}", inner),
             Self::Copy(inner) => write!(f, "{}", inner),
 
@@ -298,6 +311,8 @@ impl fmt::Display for Instruction {
             Self::If(inner) => write!(f, "{}", inner),
             Self::Else(inner) => write!(f, "{}", inner),
             Self::EndIf(inner) => write!(f, "{}", inner),
+            Self::ShortCircuit(inner) => write!(f, "{}", inner),
+            Self::Dispatch(inner) => write!(f, "{}", inner),
             Self::LoopBegin(inner) => write!(f, "{}", inner),
             Self::LoopEnd(inner) => write!(f, "{}", inner),
             Self::Call(inner) => write!(f, "{}", inner),
@@ -7,6 +7,7 @@ use std::fmt;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::data::r#type::Type as BuildType;
 use crate::instructions::Instruction;
 
 ///
@@ -14,16 +15,22 @@ use crate::instructions::Instruction;
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Require {
-    /// The optional error message.
+    /// The optional error message, which is a format string with `{}` placeholders, if there
+    /// are arguments.
     pub message: Option<String>,
+    /// The types of the values interpolated into the error message.
+    pub argument_types: Vec<BuildType>,
 }
 
 impl Require {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(message: Option<String>) -> Self {
-        Self { message }
+    pub fn new(message: Option<String>, argument_types: Vec<BuildType>) -> Self {
+        Self {
+            message,
+            argument_types,
+        }
     }
 
     ///
@@ -10,14 +10,22 @@ pub(crate) mod instructions;
 pub use self::application::circuit::Circuit;
 pub use self::application::contract::method::Method as ContractMethod;
 pub use self::application::contract::Contract;
+pub use self::application::listing::Listing;
 pub use self::application::unit_test::UnitTest;
 pub use self::application::Application;
 pub use self::build::input::Input as InputBuild;
 pub use self::build::Build;
+pub use self::data::abi::constant::Constant as AbiConstant;
+pub use self::data::abi::field::Field as AbiField;
+pub use self::data::abi::method::Method as AbiMethod;
+pub use self::data::abi::r#type::Type as AbiType;
+pub use self::data::abi::storage_field::StorageField as AbiStorageField;
+pub use self::data::abi::Abi;
 pub use self::data::r#type::contract_field::ContractField as ContractFieldType;
 pub use self::data::r#type::scalar::integer::Type as IntegerType;
 pub use self::data::r#type::scalar::Type as ScalarType;
 pub use self::data::r#type::Type;
+pub use self::data::template::Template;
 pub use self::data::value::contract_field::ContractField as ContractFieldValue;
 pub use self::data::value::error::Error as ValueError;
 pub use self::data::value::scalar::Value as ScalarValue;
@@ -33,8 +41,10 @@ pub use self::instructions::data_stack::store_by_index::StoreByIndex;
 pub use self::instructions::dbg::Dbg;
 pub use self::instructions::evaluation_stack::copy::Copy;
 pub use self::instructions::evaluation_stack::push::Push;
+pub use self::instructions::evaluation_stack::push_pooled::PushPooled;
 pub use self::instructions::evaluation_stack::slice::Slice;
 pub use self::instructions::flow::call::Call;
+pub use self::instructions::flow::dispatch::Dispatch;
 pub use self::instructions::flow::exit::Exit;
 pub use self::instructions::flow::loop_begin::LoopBegin;
 pub use self::instructions::flow::loop_end::LoopEnd;
@@ -42,6 +52,7 @@ pub use self::instructions::flow::r#else::Else;
 pub use self::instructions::flow::r#endif::EndIf;
 pub use self::instructions::flow::r#if::If;
 pub use self::instructions::flow::r#return::Return;
+pub use self::instructions::flow::short_circuit::ShortCircuit;
 pub use self::instructions::marker::column::ColumnMarker;
 pub use self::instructions::marker::file::FileMarker;
 pub use self::instructions::marker::function::FunctionMarker;
@@ -32,8 +32,11 @@ use crate::semantic::element::r#type::contract::error::Error as ContractTypeErro
 use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::debug::error::Error as DebugFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::error::Error as MemFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::require::error::Error as RequireFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::error::Error as StandardLibraryFunctionError;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
@@ -497,6 +500,26 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::OperatorRangeDynamicStartExpectedInteger{ location, found })) => {
+                Self::format_line( format!(
+                        "the range operator expected an integer as the start bound, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::OperatorRangeDynamicLengthExpectedConstant{ location, found })) => {
+                Self::format_line( format!(
+                        "the range operator expected a non-negative integer constant as the length, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("a range with a runtime start must be written as `start .. start + LENGTH`"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorOrFirstOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean{ location, found }))) => {
@@ -573,7 +596,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType{ location, found }))) => {
                 Self::format_line( format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
+                        "the equals operator `==` expected a unit, boolean, integer, tuple, array or structure as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -597,11 +620,21 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedString{ location, found }))) => {
+                Self::format_line( format!(
+                        "the equals operator `==` expected a string as the second operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ location, found }))) => {
                 Self::format_line( format!(
-                        "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
+                        "the not equals operator `!=` expected a unit, boolean, integer, tuple, array or structure as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -625,6 +658,49 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedString{ location, found }))) => {
+                Self::format_line( format!(
+                        "the not equals operator `!=` expected a string as the second operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorEqualityTypesMismatch{ location, operator, first, second }))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorEqualityTypesMismatch{ location, operator, first, second }))) => {
+                Self::format_line( format!(
+                        "the operator `{}` expected operands of the same type, found `{}` and `{}`",
+                        operator, first, second,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorEqualityIncomparableType{ location, operator, found, element }))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorEqualityIncomparableType{ location, operator, found, element }))) => {
+                Self::format_line( format!(
+                        "the operator `{}` cannot compare values of type `{}`, which contains an incomparable `{}`",
+                        operator, found, element,
+                    )
+                        .as_str(),
+                    location,
+                    Some("only units, booleans, integers, and tuples, arrays, or structures of them can be compared"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorOrderingComposite{ location, operator, found }))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorOrderingComposite{ location, operator, found }))) => {
+                Self::format_line( format!(
+                        "the ordering operator `{}` cannot be applied to `{}`",
+                        operator, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("tuples, arrays, and structures support only the `==` and `!=` operators"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger{ location, found }))) => {
@@ -805,13 +881,13 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ location, found })) |
+            Self::Semantic(SemanticError::Element(ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { location, found })))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { location, found })))) => {
                 Self::format_line( format!(
-                        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift left operator `<<` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -831,13 +907,13 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ location, found })) |
+            Self::Semantic(SemanticError::Element(ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { location, found })))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { location, found })))) => {
                 Self::format_line( format!(
-                        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift right operator `>>` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -869,6 +945,16 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedString{ location, found }))) => {
+                Self::format_line( format!(
+                        "the addition operator `+` expected a string as the second operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorSubtractionFirstOperandExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorSubtractionFirstOperandExpectedInteger{ location, found }))) => {
@@ -999,6 +1085,18 @@ impl Error {
                     Some("only integer values can be casted to an integer with different bitlength or field element"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Casting { location, inner: CastingError::LiteralOutOfRange { value, from, to }, reference }))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Casting { location, inner: CastingError::LiteralOutOfRange { value, from, to }, reference }))) => {
+                Self::format_line_with_reference(format!(
+                        "integer literal `{}` of type `{}` does not fit into the other operand type `{}`",
+                        value, from, to,
+                    )
+                        .as_str(),
+                    location,
+                    Some(reference),
+                    Some(format!("consider casting the other operand to `{}` with `as {}`", from, from).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorNotExpectedEvaluable{ location, found })) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::OperatorNotExpectedBoolean{ location, found }))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::OperatorNotExpectedBoolean{ location, found }))) => {
@@ -1077,6 +1175,16 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::StringRuntimeOperand { location, found }))) => {
+                Self::format_line( format!(
+                        "`{}` only exists at compile time and cannot be used as a runtime expression operand",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("use the string in constant expressions, `require` and `dbg!` only"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::OperatorDotSecondOperandExpectedIdentifier { location, found })) => {
                 Self::format_line( format!(
                         "the field access operator `.` expected a tuple or object instance field identifier as the second operand, found `{}`",
@@ -1138,7 +1246,19 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Array(ArrayConstantError::IndexOutOfRange { location, index, size })))) => {
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Array(ArrayValueError::ConcatenationInvalidType { location, expected, found })))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Array(ArrayConstantError::ConcatenationInvalidType { location, expected, found })))) => {
+                Self::format_line( format!(
+                        "the array `{}` cannot be concatenated with `{}`",
+                        expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("only arrays of the same element type can be concatenated"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Array(ArrayConstantError::IndexOutOfRange { location, index, size })))) |
+            Self::Semantic(SemanticError::Element(ElementError::Place(PlaceError::ArrayIndexOutOfRange { location, index, size }))) => {
                 Self::format_line( format!(
                         "index `{}` is out of range of the array of size {}",
                         index, size,
@@ -1235,7 +1355,14 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(ElementError::Place(PlaceError::MutatingImmutableMemory { location, name, reference }))) => {
+            Self::Semantic(SemanticError::Element(ElementError::Place(PlaceError::MutatingImmutableMemory { location, name, place, reference }))) if place != name => {
+                Self::format_line_with_reference(format!("cannot assign to `{}`, as the variable `{}` is immutable", place, name).as_str(),
+                    location,
+                    reference,
+                    Some(format!("make this variable mutable: `mut {}`", name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Place(PlaceError::MutatingImmutableMemory { location, name, reference, .. }))) => {
                 Self::format_line_with_reference(format!("cannot assign twice to immutable variable `{}`", name).as_str(),
                     location,
                     reference,
@@ -1248,6 +1375,12 @@ impl Error {
                                                  Some("such fields cannot be changed by the contract logic"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Place(PlaceError::MutatingStorageFromQuery { location, place, method }))) => {
+                Self::format_line(format!("cannot assign to `{}` in the immutable method `{}`", place, method).as_str(),
+                                                 location,
+                                                 Some("the storage changes of immutable methods are discarded, declare the method with `mut self` instead"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected { location, type_identifier, position, expected, found })))) |
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(ContractValueError::FieldExpected { location, type_identifier, position, expected, found })))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Structure(StructureConstantError::FieldExpected { location, type_identifier, position, expected, found })))) => {
@@ -1284,6 +1417,68 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Contract(ContractValueError::FieldUninitialized { location, type_identifier, field_name })))) => {
+                Self::format_line( format!(
+                        "field `{}` of `{}` is possibly uninitialized after the constructor",
+                        field_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    Some("initialize the field in the contract literal, or declare its default value, e.g. `fee: u16 = 30;`"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::BaseExpectedStructure { location, found })))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Structure(StructureConstantError::BaseExpectedStructure { location, found })))) => {
+                Self::format_line( format!(
+                        "the structure update base expected a structure, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("the base after `..` must be a structure of the same type, e.g. `Data { a: 42, ..base }`"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::BaseTypeMismatch { location, expected, found })))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Structure(StructureConstantError::BaseTypeMismatch { location, expected, found })))) => {
+                Self::format_line( format!(
+                        "the structure update base expected type `{}`, found `{}`",
+                        expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::BaseForbiddenInContract { location, type_identifier })))) => {
+                Self::format_line( format!(
+                        "the structure update syntax cannot be used with the contract `{}`",
+                        type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    Some("specify all the contract fields explicitly"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::FixedTypesMismatch { location, operator, first, second })))) => {
+                Self::format_line( format!(
+                        "the `{}` operator expected two fixed-point values of the same type, found `{}` and `{}`",
+                        operator, first, second,
+                    )
+                        .as_str(),
+                    location,
+                    Some("convert the operands with the `as` operator first"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Structure(StructureValueError::NewtypeMismatch { location, first, second })))) => {
+                Self::format_line( format!(
+                        "newtype operands must have the same type, found `{}` and `{}`",
+                        first, second,
+                    )
+                        .as_str(),
+                    location,
+                    Some("unwrap the newtype with `.0` and construct the expected one explicitly"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals{ location, first, second })))) |
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchEquals{ location, first, second })))) => {
                 Self::format_line( format!(
@@ -1292,7 +1487,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchNotEquals{ location, first, second })))) |
@@ -1303,7 +1498,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreaterEquals{ location, first, second })))) |
@@ -1314,7 +1509,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesserEquals{ location, first, second })))) |
@@ -1325,7 +1520,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater{ location, first, second })))) |
@@ -1336,7 +1531,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser{ location, first, second })))) |
@@ -1347,7 +1542,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseOr{ location, first, second })))) |
@@ -1358,7 +1553,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseXor{ location, first, second })))) |
@@ -1369,7 +1564,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseAnd{ location, first, second })))) |
@@ -1380,7 +1575,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchAddition{ location, first, second })))) |
@@ -1391,7 +1586,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchSubtraction{ location, first, second })))) |
@@ -1402,7 +1597,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchMultiplication{ location, first, second })))) |
@@ -1413,7 +1608,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchDivision{ location, first, second })))) |
@@ -1424,7 +1619,7 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchRemainder{ location, first, second })))) |
@@ -1435,7 +1630,18 @@ impl Error {
                     )
                         .as_str(),
                     location,
-                    None,
+                    Self::integer_casting_hint(first.as_str(), second.as_str()).as_deref(),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Value(ValueError::Integer(IntegerValueError::OperandTypeMismatch { location, lhs, rhs, hint })))) |
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperandTypeMismatch { location, lhs, rhs, hint })))) => {
+                Self::format_line( format!(
+                        "the operands of types `{}` and `{}` cannot be mixed without an explicit cast",
+                        lhs, rhs,
+                    )
+                        .as_str(),
+                    location,
+                    Some(hint.as_str()),
                 )
             }
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowAddition { location, value, r#type })))) => {
@@ -1498,6 +1704,16 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::CastingInvalidDiscriminant { location, value, r#type, expected })))) => {
+                Self::format_line( format!(
+                        "the value `{}` cannot be casted to type `{}`, as it is not one of its variants",
+                        value, r#type,
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("the valid values are {}", expected).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowNegation { location, value, r#type })))) => {
                 Self::format_line( format!(
                         "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
@@ -1619,6 +1835,16 @@ impl Error {
                                    Some("consider removing strings, ranges, functions, and maps from the type declaration"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::DuplicateTupleElement { location, name }))) => {
+                Self::format_line( format!(
+                    "tuple element `{}` has been already declared",
+                    name,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("named tuple elements must have unique names"),
+                )
+            }
             Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclared { location, name, reference })) => {
                 Self::format_line_with_reference(format!(
                         "item `{}` already declared here",
@@ -1630,6 +1856,16 @@ impl Error {
                     Some("consider giving the latter item another name"),
                 )
             }
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclaredBuiltIn { location, name })) => {
+                Self::format_line( format!(
+                        "item `{}` is already declared as an intrinsic item",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    Some("intrinsic items cannot be shadowed, consider giving the item another name"),
+                )
+            }
             Self::Semantic(SemanticError::Scope(ScopeError::ItemUndeclared { location, name })) => {
                 Self::format_line( format!(
                         "cannot find item `{}` in this scope",
@@ -1640,6 +1876,16 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Scope(ScopeError::AmbiguousItem { location, name, first, second })) => {
+                Self::format_line( format!(
+                        "item `{}` is ambiguous, as it is imported from both `{}::*` and `{}::*`",
+                        name, first, second,
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("consider importing the item explicitly: `use {}::{};`", first, name).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::Scope(ScopeError::ItemIsNotANamespace { location, name })) => {
                 Self::format_line( format!(
                         "item `{}` is not a namespace",
@@ -1660,6 +1906,17 @@ impl Error {
                    Some("consider adding the namespace or entity prefix to the item"),
                 )
             }
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemIsPrivate { location, name, declared_at })) => {
+                Self::format_line_with_reference(format!(
+                        "item `{}` is private and declared here",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    declared_at,
+                    Some("consider declaring the item with the `pub` keyword"),
+                )
+            }
             Self::Semantic(SemanticError::Scope(ScopeError::ContractRedeclared { location, reference })) => {
                 Self::format_line_with_reference("another contract is already declared here",
                     location,
@@ -1673,6 +1930,26 @@ impl Error {
                     Some("consider removing circular references between the items"),
                 )
             }
+            Self::Semantic(SemanticError::Scope(ScopeError::ConstantDependencyCycle { location, chain })) => {
+                Self::format_line( format!(
+                        "constant dependency cycle detected: {}",
+                        chain.join(" -> "),
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider defining one of the constants without referencing the others"),
+                )
+            }
+            Self::Semantic(SemanticError::Scope(ScopeError::TypeDependencyCycle { location, chain })) => {
+                Self::format_line( format!(
+                        "type dependency cycle detected: {}",
+                        chain.join(" -> "),
+                    )
+                        .as_str(),
+                    location,
+                    Some("a type cannot contain itself, consider removing circular references between the types"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::ArgumentCount { location, function, expected, found, reference })))) => {
                 Self::format_line_with_reference( format!(
                         "function `{}` expected {} arguments, found {}",
@@ -1745,6 +2022,16 @@ impl Error {
                                                  Some("consider making the instance mutable"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::CallingMutableFromQuery { location, function, method })))) => {
+                Self::format_line(format!(
+                    "the mutable function `{}` cannot change the contract storage in the immutable method `{}`",
+                    function, method,
+                )
+                                                     .as_str(),
+                                                 location,
+                                                 Some("the storage changes of immutable methods are discarded, declare the method with `mut self` instead"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Unknown { location, function }))))) => {
                 Self::format_line( format!(
                         "attempt to call a non-intrinsic function `{}` with `!` specifier",
@@ -1765,6 +2052,16 @@ impl Error {
                     Some("intrinsic functions require the `!` symbol after the function name"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Require(RequireFunctionError::ArgumentCount { location, expected, found })))))) => {
+                Self::format_line( format!(
+                        "the `require` function expected {} arguments, but got {}",
+                        expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("the number of `require` arguments after the message must be equal to the number of placeholders, e.g. `require(a < b, \"{} < {}\", a, b)`"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Debug(DebugFunctionError::ArgumentCount { location, expected, found })))))) => {
                 Self::format_line( format!(
                         "the `dbg!` function expected {} arguments, but got {}",
@@ -1805,6 +2102,26 @@ impl Error {
                     Some("array indexes cannot be greater than maximum of `u64`"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Mem(MemFunctionError::TypeUnsized { location, function, found })))))) => {
+                Self::format_line( format!(
+                        "function `{}` expected a sized type, found `{}`",
+                        function, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("strings, ranges, functions, and contracts do not occupy data slots; use `std::mem::field_count_of` for contracts"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Mem(MemFunctionError::TypeNotContract { location, function, found })))))) => {
+                Self::format_line( format!(
+                        "function `{}` expected a contract type, found `{}`",
+                        function, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("only the contract storage fields can be counted"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Test(TestFunctionError::CallForbidden { location, function }))))) => {
                 Self::format_line( format!(
                     "unit test function `{}` cannot be called",
@@ -1865,6 +2182,66 @@ impl Error {
                                    None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::Forbidden { location, function }))))) => {
+                Self::format_line( format!(
+                    "function `{}` cannot have type parameters",
+                    function,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only runtime functions, which are not constant, unit tests, or entry points, can be generic"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::Unexpected { location, name }))))) => {
+                Self::format_line( format!(
+                    "`{}` does not expect generic type arguments",
+                    name,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::ArgumentCount { location, function, expected, found }))))) => {
+                Self::format_line( format!(
+                    "function `{}` expected {} generic type arguments, found {}",
+                    function, expected, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::ArgumentNotScalar { location, function, parameter, found }))))) => {
+                Self::format_line( format!(
+                    "function `{}` expected a scalar type as the type parameter `{}`, found `{}`",
+                    function, parameter, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only integers, fields, booleans, and enumerations are allowed as generic type arguments"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::InferenceAmbiguous { location, function, parameter }))))) => {
+                Self::format_line( format!(
+                    "cannot infer the type parameter `{}` of function `{}`",
+                    parameter, function,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some(format!("specify the generic type arguments explicitly, e.g. `{}::<u8>(...)`", function).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Function(FunctionError::Generic(GenericFunctionError::InferenceConflict { location, function, parameter, expected, found }))))) => {
+                Self::format_line( format!(
+                    "function `{}` type parameter `{}` is inferred as `{}`, found `{}`",
+                    function, parameter, expected, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some(format!("specify the generic type arguments explicitly, e.g. `{}::<{}>(...)`", function, expected).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { location, type_identifier, field_name })))) => {
                 Self::format_line( format!(
                         "`{}` has a duplicate field `{}`",
@@ -1905,6 +2282,26 @@ impl Error {
                                    None,
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(StructureTypeError::FixedBitlengthOutOfRange { location, type_identifier, found, maximum })))) => {
+                Self::format_line( format!(
+                    "fixed-point type `{}` bitlength {} is bigger than the maximum of {}",
+                    type_identifier, found, maximum,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("the product of two fixed-point values must fit into the widest integer type"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Structure(StructureTypeError::FixedDecimalsOutOfRange { location, type_identifier, found, maximum })))) => {
+                Self::format_line( format!(
+                    "fixed-point type `{}` has {} decimals, but its integer can hold at most {}",
+                    type_identifier, found, maximum,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("consider using fewer decimals or a wider fixed-point type"),
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::DuplicateVariantValue { location, type_identifier, variant_name, variant_value })))) => {
                 Self::format_line( format!(
                     "`{}` has a duplicate variant `{}` with value `{}`",
@@ -1915,6 +2312,102 @@ impl Error {
                                    Some("variants with the same value are temporarily prohibited"),
                 )
             }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::DuplicatePayloadField { location, type_identifier, variant_name, field_name })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` has a duplicate payload field `{}`",
+                    type_identifier, variant_name, field_name,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadForbiddenType { location, type_identifier, variant_name, found })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` payload cannot contain `{}`",
+                    type_identifier, variant_name, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("enumeration payloads may only consist of scalar, array, tuple, and structure types"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadExpected { location, type_identifier, variant_name })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` must be constructed with its payload",
+                    type_identifier, variant_name,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadUnexpected { location, type_identifier, variant_name })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` does not have a payload",
+                    type_identifier, variant_name,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some(format!("use the variant without arguments, e.g. `{}::{}`", type_identifier, variant_name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadInvalidKind { location, type_identifier, variant_name, is_structure })))) => {
+                let (found, help) = if is_structure {
+                    ("tuple-like", format!("use the structure syntax, e.g. `{}::{} {{ ... }}`", type_identifier, variant_name))
+                } else {
+                    ("structure-like", format!("use the tuple syntax, e.g. `{}::{}(...)`", type_identifier, variant_name))
+                };
+
+                Self::format_line( format!(
+                    "`{}::{}` cannot be constructed as a {} variant",
+                    type_identifier, variant_name, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some(help.as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadInvalidLength { location, type_identifier, variant_name, expected, found })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` expected {} payload elements, found {}",
+                    type_identifier, variant_name, expected, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadFieldInvalidType { location, type_identifier, variant_name, field_name, expected, found })))) => {
+                Self::format_line( format!(
+                    "payload field `{}` of `{}::{}` expected type `{}`, found `{}`",
+                    field_name, type_identifier, variant_name, expected, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadFieldDoesNotExist { location, type_identifier, variant_name, field_name })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` payload has no field named `{}`",
+                    type_identifier, variant_name, field_name,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Enumeration(EnumerationTypeError::PayloadFieldMissing { location, type_identifier, variant_name, field_name })))) => {
+                Self::format_line( format!(
+                    "`{}::{}` payload field `{}` is not initialized",
+                    type_identifier, variant_name, field_name,
+                )
+                                       .as_str(),
+                                   location,
+                                   None,
+                )
+            }
             Self::Semantic(SemanticError::Element(ElementError::Type(TypeError::Contract(ContractTypeError::DuplicateField { location, type_identifier, field_name })))) => {
                 Self::format_line( format!(
                         "`{}` has a duplicate field `{}`",
@@ -1931,6 +2424,24 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Expression(ExpressionError::LoopInConstantContext { location })) => {
+                Self::format_line( "loops cannot be used in a constant expression",
+                    location,
+                    Some("consider computing the value with `let` bindings and constant functions"),
+                )
+            }
+            Self::Semantic(SemanticError::Expression(ExpressionError::AssignmentInConstantContext { location })) => {
+                Self::format_line( "assignments cannot be used in a constant expression",
+                    location,
+                    Some("consider declaring a new `let` binding instead"),
+                )
+            }
+            Self::Semantic(SemanticError::Expression(ExpressionError::StructureShorthandUndeclared { location, name })) => {
+                Self::format_line( format!("cannot find item `{}` in this scope, which is referenced by the structure field shorthand", name).as_str(),
+                    location,
+                    Some(format!("the shorthand `{{ {0} }}` means `{{ {0}: {0} }}`, so declare `{0}` or specify the field value explicitly", name).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::Expression(ExpressionError::Match(MatchExpressionError::ScrutineeInvalidType { location, found }))) => {
                 Self::format_line( format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
                     location,
@@ -1982,6 +2493,46 @@ impl Error {
                     Some("each pattern may occur only once"),
                 )
             }
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(MatchExpressionError::BranchPatternFieldDoesNotExist { location, type_identifier, field_name }))) => {
+                Self::format_line( format!(
+                        "field `{}` does not exist in `{}`",
+                        field_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(MatchExpressionError::BranchPatternFieldMissing { location, type_identifier, field_name }))) => {
+                Self::format_line( format!(
+                        "pattern does not mention field `{}` of `{}`",
+                        field_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    Some("use `..` to ignore the rest of the fields"),
+                )
+            }
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(MatchExpressionError::BranchPatternVariantDoesNotExist { location, type_identifier, variant_name }))) => {
+                Self::format_line( format!(
+                        "variant `{}` does not exist in `{}`",
+                        variant_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Expression(ExpressionError::Match(MatchExpressionError::BranchPatternPayloadInvalidLength { location, variant_name, expected, found }))) => {
+                Self::format_line( format!(
+                        "variant `{}` expects {} payload pattern(s), found {}",
+                        variant_name, expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Expression(ExpressionError::Conditional(ConditionalExpressionError::ExpectedBooleanCondition { location, found }))) => {
                 Self::format_line( format!("expected `bool`, found `{}`", found).as_str(),
                     location,
@@ -2028,6 +2579,16 @@ impl Error {
                     Some("only structures and enumerations can have an implementation"),
                 )
             }
+            Self::Semantic(SemanticError::Statement(StatementError::Impl(ImplStatementError::IntrinsicType { location, found }))) => {
+                Self::format_line( format!(
+                        "`impl` cannot be declared for the intrinsic type `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider wrapping the intrinsic type into a structure"),
+                )
+            }
 
             Self::Semantic(SemanticError::Attribute(AttributeError::Unknown { location, found })) => {
                 Self::format_line( format!(
@@ -2039,6 +2600,92 @@ impl Error {
                                    Some("see the reference to get the list of allowed attributes"),
                 )
             }
+            Self::Semantic(SemanticError::Attribute(AttributeError::UnexpectedArgument { location, attribute, found })) => {
+                Self::format_line( format!(
+                    "attribute `{}` does not accept the argument `{}`",
+                    attribute, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("see the reference to get the list of allowed attribute arguments"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::PayableOutsideMutableContractMethod { location, function })) => {
+                Self::format_line( format!(
+                    "function `{}` cannot be payable",
+                    function,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only public contract methods with the `mut self` argument can accept transfers"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::ArgumentValueMissing { location, attribute, argument })) => {
+                Self::format_line( format!(
+                    "attribute `{}` argument `{}` requires a value",
+                    attribute, argument,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("specify the argument value, e.g. `#[payable(token = \"ETH\")]`"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::OnlyExpectedField { location })) => {
+                Self::format_line( "attribute `only` expects a contract storage field name",
+                                   location,
+                                   Some("specify the field holding the allowed sender address, e.g. `#[only(owner)]`"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::OnlyOutsideMutableContractMethod { location, function })) => {
+                Self::format_line( format!(
+                    "function `{}` cannot be restricted to a sender",
+                    function,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only public contract methods with the `mut self` argument have a transaction sender"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::OnlyFieldNotFound { location, field })) => {
+                Self::format_line( format!(
+                    "contract storage field `{}` does not exist",
+                    field,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("the `only` attribute argument must be a contract storage field name"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::OnlyFieldExpectedAddress { location, field, found })) => {
+                Self::format_line( format!(
+                    "contract storage field `{}` must be an address of type `u160`, found `{}`",
+                    field, found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("the transaction sender can be compared only with an address"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::PublicOutsideContractMethodArgument { location, function })) => {
+                Self::format_line( format!(
+                    "function `{}` cannot have the `#[public]` attribute here",
+                    function,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only the named arguments of public contract methods can be marked as public inputs"),
+                )
+            }
+            Self::Semantic(SemanticError::Attribute(AttributeError::UnexpectedArgumentAttribute { location, found })) => {
+                Self::format_line( format!(
+                    "attribute `{}` cannot be applied to a function argument",
+                    found,
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("only the `#[public]` attribute is allowed on the contract method arguments"),
+                )
+            }
 
             Self::Semantic(SemanticError::Binding(BindingError::ExpectedTuple { location, expected, found })) => {
                 Self::format_line( format!(
@@ -2050,6 +2697,16 @@ impl Error {
                                    None,
                 )
             }
+            Self::Semantic(SemanticError::Binding(BindingError::MutableInConstantContext { location, name })) => {
+                Self::format_line( format!(
+                    "mutable variable `{}` cannot be declared in a constant expression",
+                    name
+                )
+                                       .as_str(),
+                                   location,
+                                   Some("consider removing the `mut` keyword"),
+                )
+            }
             Self::Semantic(SemanticError::Binding(BindingError::FunctionMethodSelfNotFirst { location, name, position })) => {
                 Self::format_line(format!(
                     "expected the `{}` binding to be at the first position, but found at the position #`{}`",
@@ -2082,6 +2739,26 @@ impl Error {
                     Some("consider choosing between the circuit and contract project type"),
                 )
             }
+            Self::Semantic(SemanticError::EntryInputTooLarge { location, method, size, limit }) => {
+                Self::format_line( format!(
+                        "method `{}` input and output size is {} field elements, which exceeds the limit of {}",
+                        method, size, limit,
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider moving the large data to the contract storage or passing its hash instead"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractStorageTooLarge { location, size, limit }) => {
+                Self::format_line( format!(
+                        "contract storage size is {} field elements, which exceeds the limit of {}",
+                        size, limit,
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider moving the large data to `MTreeMap` fields or storing its hash instead"),
+                )
+            }
             Self::Semantic(SemanticError::EntryPointConstant { location }) => {
                 Self::format_line( "the entry point cannot be constant",
                     location,
@@ -2184,6 +2861,33 @@ impl Error {
         strings.join("\n")
     }
 
+    ///
+    /// Suggests casting the operand of the narrower integer type to the wider one, if both the
+    /// `first` and `second` operand types are integer ones.
+    ///
+    fn integer_casting_hint(first: &str, second: &str) -> Option<String> {
+        let bitlength = |r#type: &str| -> Option<usize> {
+            match r#type {
+                "field" => Some(zinc_const::bitlength::FIELD),
+                r#type if r#type.starts_with('u') || r#type.starts_with('i') => {
+                    r#type[1..].parse().ok()
+                }
+                _ => None,
+            }
+        };
+
+        let (narrower, wider, operand) = if bitlength(first)? < bitlength(second)? {
+            (first, second, "first")
+        } else {
+            (second, first, "second")
+        };
+
+        Some(format!(
+            "consider casting the {} operand from `{}` to `{}` with `as {}`",
+            operand, narrower, wider, wider
+        ))
+    }
+
     ///
     /// Formats an error `message` with an optional `help` message.
     ///
@@ -4,6 +4,8 @@
 
 use crate::generator::expression::operand::Operand;
 use crate::generator::expression::operator::Operator;
+use crate::generator::printer::Printer;
+use crate::generator::IIntermediatePrintable;
 use zinc_lexical::Location;
 
 ///
@@ -21,3 +23,15 @@ pub enum Element {
         operator: Operator,
     },
 }
+
+impl IIntermediatePrintable for Element {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            Self::Operand(operand) => operand.print(printer),
+            Self::Operator { location, operator } => {
+                printer.line_at(operator, *location);
+                printer.nested(|printer| operator.print(printer));
+            }
+        }
+    }
+}
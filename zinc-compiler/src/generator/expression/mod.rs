@@ -20,8 +20,11 @@ use zinc_build::Type as BuildType;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::operand::place::Place;
+use crate::generator::printer::Printer;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
@@ -60,6 +63,13 @@ impl Expression {
         self.elements.push(element)
     }
 
+    ///
+    /// Pops the last element from the expression.
+    ///
+    pub fn pop_element(&mut self) -> Option<Element> {
+        self.elements.pop()
+    }
+
     ///
     /// Pushes an operand to the expression.
     ///
@@ -81,15 +91,49 @@ impl Expression {
         self.elements.extend(expression.elements);
     }
 
+    ///
+    /// Returns the expression value size, if the expression is a single constant or data stack
+    /// place operand, which has no side effects and cannot fail, so it may be evaluated
+    /// regardless of the execution condition.
+    ///
+    pub fn side_effect_free_size(&self) -> Option<usize> {
+        match self.elements.as_slice() {
+            [Element::Operand(Operand::Constant(constant))] => Some(constant.size()),
+            [Element::Operand(Operand::Place(place))]
+                if matches!(place.memory_type, MemoryType::Stack)
+                    && place.elements.iter().all(|element| {
+                        matches!(
+                            element,
+                            SemanticPlaceElement::StackField { .. }
+                                | SemanticPlaceElement::IndexConstant { .. }
+                        )
+                    }) =>
+            {
+                Some(place.element_size)
+            }
+            _ => None,
+        }
+    }
+
     ///
     /// Translates an assignment operator into the bytecode.
     ///
-    fn assignment(
+    fn assignment(state: Rc<RefCell<State>>, place: Place, expression: Self, location: Location) {
+        Self::assignment_with_writer(state, place, |state| expression.write_all(state), location);
+    }
+
+    ///
+    /// Translates an assignment into the bytecode, where the assigned value is written to the
+    /// evaluation stack by the `write_value` callback.
+    ///
+    fn assignment_with_writer<W>(
         state: Rc<RefCell<State>>,
         mut place: Place,
-        expression: Self,
+        write_value: W,
         location: Location,
-    ) {
+    ) where
+        W: FnOnce(Rc<RefCell<State>>),
+    {
         match place.memory_type {
             MemoryType::Stack => {
                 let is_indexed = !place.elements.is_empty();
@@ -104,7 +148,7 @@ impl Expression {
                     place.write_all(state.clone());
                 }
 
-                expression.write_all(state.clone());
+                write_value(state.clone());
 
                 state.borrow_mut().push_instruction(
                     if is_indexed {
@@ -164,7 +208,7 @@ impl Expression {
                     place.write_all(state.clone());
                 }
 
-                expression.write_all(state.clone());
+                write_value(state.clone());
 
                 state.borrow_mut().push_instruction(
                     if is_indexed {
@@ -201,11 +245,15 @@ impl Expression {
     ///
     /// Translates a shortcut assignment operator into the bytecode.
     ///
+    /// The `operand_casting` is applied to the loaded place value before the `operations` are
+    /// executed, which is required by the fixed-point decimal multiplication.
+    ///
     fn assignment_with_operation(
         state: Rc<RefCell<State>>,
         mut place: Place,
         expression: Self,
-        operation: Instruction,
+        operand_casting: Option<Instruction>,
+        operations: Vec<Instruction>,
         location: Location,
     ) {
         match place.memory_type {
@@ -218,8 +266,16 @@ impl Expression {
                 let element_size = place.element_size;
                 let total_size = place.total_size;
 
+                let mut is_index_checked = false;
                 if is_indexed {
+                    let index_address = state.borrow().next_instruction_address();
                     place.write_all(state.clone());
+                    is_index_checked = state.borrow_mut().check_index(
+                        index_address,
+                        address,
+                        element_size,
+                        total_size,
+                    );
                     state
                         .borrow_mut()
                         .push_instruction(Instruction::Copy(zinc_build::Copy), Some(location));
@@ -231,6 +287,7 @@ impl Expression {
                             address,
                             element_size,
                             total_size,
+                            is_index_checked,
                         ))
                     } else {
                         Instruction::Load(zinc_build::Load::new(address, total_size))
@@ -238,11 +295,19 @@ impl Expression {
                     Some(location),
                 );
 
+                if let Some(operand_casting) = operand_casting {
+                    state
+                        .borrow_mut()
+                        .push_instruction(operand_casting, Some(location));
+                }
+
                 expression.write_all(state.clone());
 
-                state
-                    .borrow_mut()
-                    .push_instruction(operation, Some(location));
+                for operation in operations.into_iter() {
+                    state
+                        .borrow_mut()
+                        .push_instruction(operation, Some(location));
+                }
 
                 state.borrow_mut().push_instruction(
                     if is_indexed {
@@ -311,6 +376,7 @@ impl Expression {
                             address,
                             element_size,
                             total_size,
+                            false,
                         ))
                     } else {
                         Instruction::Load(zinc_build::Load::new(address, total_size))
@@ -318,11 +384,19 @@ impl Expression {
                     Some(location),
                 );
 
+                if let Some(operand_casting) = operand_casting {
+                    state
+                        .borrow_mut()
+                        .push_instruction(operand_casting, Some(location));
+                }
+
                 expression.write_all(state.clone());
 
-                state
-                    .borrow_mut()
-                    .push_instruction(operation, Some(location));
+                for operation in operations.into_iter() {
+                    state
+                        .borrow_mut()
+                        .push_instruction(operation, Some(location));
+                }
 
                 state.borrow_mut().push_instruction(
                     if is_indexed {
@@ -374,6 +448,78 @@ impl Expression {
             .push_instruction(instruction, Some(location));
     }
 
+    ///
+    /// Returns the instructions, which multiply two fixed-point decimals already cast to the
+    /// `wide_type` and rescale the product back to the fixed-point integer `r#type`.
+    ///
+    /// The rescaling division rounds towards negative infinity, and the final casting checks
+    /// the result for overflow.
+    ///
+    fn multiplication_fixed(r#type: Type, wide_type: Type, scale: BigInt) -> Vec<Instruction> {
+        match (Self::scalar_type(r#type), Self::scalar_type(wide_type)) {
+            (Some(r#type), Some(wide_type)) => vec![
+                Instruction::Mul(zinc_build::Mul),
+                Instruction::Push(zinc_build::Push::new(scale, wide_type)),
+                Instruction::Div(zinc_build::Div),
+                Instruction::Cast(zinc_build::Cast::new(r#type)),
+            ],
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Translates the tuple, array, or structure comparison into the bytecode.
+    ///
+    /// Both operands of `size` are moved from the evaluation stack to temporary data stack
+    /// variables, and then compared element-wise with the results ANDed together.
+    /// If `is_negated` is set, the final result is inverted.
+    ///
+    fn equals_composite(
+        state: Rc<RefCell<State>>,
+        size: usize,
+        is_negated: bool,
+        location: Location,
+    ) {
+        let address_2 = state.borrow_mut().define_variable(None, size);
+        let address_1 = state.borrow_mut().define_variable(None, size);
+
+        let mut instructions = Vec::with_capacity(3 + size * 4);
+        instructions.push(Instruction::Store(zinc_build::Store::new(address_2, size)));
+        instructions.push(Instruction::Store(zinc_build::Store::new(address_1, size)));
+        instructions.push(Instruction::Push(zinc_build::Push::new(
+            BigInt::one(),
+            ScalarType::Boolean,
+        )));
+        for offset in 0..size {
+            instructions.push(Instruction::Load(zinc_build::Load::new(
+                address_1 + offset,
+                1,
+            )));
+            instructions.push(Instruction::Load(zinc_build::Load::new(
+                address_2 + offset,
+                1,
+            )));
+            instructions.push(Instruction::Eq(zinc_build::Eq));
+            instructions.push(Instruction::And(zinc_build::And));
+        }
+        if is_negated {
+            instructions.push(Instruction::Not(zinc_build::Not));
+        }
+
+        for instruction in instructions.into_iter() {
+            state
+                .borrow_mut()
+                .push_instruction(instruction, Some(location));
+        }
+    }
+
+    ///
+    /// Converts the scalar `r#type` into the bytecode one.
+    ///
+    fn scalar_type(r#type: Type) -> Option<ScalarType> {
+        r#type.into()
+    }
+
     ///
     /// Translates an ordinar function call into the bytecode.
     ///
@@ -384,6 +530,45 @@ impl Expression {
         );
     }
 
+    ///
+    /// Translates a `mut self` method call into the bytecode, where the method variant returning
+    /// the mutated instance along with the result is called, and the instance is written back
+    /// to the contract storage `place`.
+    ///
+    fn call_writeback(
+        state: Rc<RefCell<State>>,
+        type_id: usize,
+        input_size: usize,
+        place: Place,
+        location: Location,
+    ) {
+        let instance_size = place.element_size;
+        let writeback_type_id = state.borrow_mut().writeback_type_id(type_id);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Call(zinc_build::Call::new(writeback_type_id, input_size)),
+            Some(location),
+        );
+
+        let address = state.borrow_mut().define_variable(None, instance_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_build::Store::new(address, instance_size)),
+            Some(location),
+        );
+
+        Self::assignment_with_writer(
+            state,
+            place,
+            |state| {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(address, instance_size)),
+                    Some(location),
+                )
+            },
+            location,
+        );
+    }
+
     ///
     /// Translates a `dbg!(...)` function call into the bytecode.
     ///
@@ -402,9 +587,14 @@ impl Expression {
     ///
     /// Translates an `require(...)` function call into the bytecode.
     ///
-    fn call_assert(state: Rc<RefCell<State>>, message: Option<String>, location: Location) {
+    fn call_assert(
+        state: Rc<RefCell<State>>,
+        message: Option<String>,
+        argument_types: Vec<BuildType>,
+        location: Location,
+    ) {
         state.borrow_mut().push_instruction(
-            Instruction::Require(zinc_build::Require::new(message)),
+            Instruction::Require(zinc_build::Require::new(message, argument_types)),
             Some(location),
         );
     }
@@ -452,7 +642,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::BitwiseOr(zinc_build::BitwiseOr),
+                        None,
+                        vec![Instruction::BitwiseOr(zinc_build::BitwiseOr)],
                         location,
                     ),
                     Operator::AssignmentBitwiseXor {
@@ -463,7 +654,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::BitwiseXor(zinc_build::BitwiseXor),
+                        None,
+                        vec![Instruction::BitwiseXor(zinc_build::BitwiseXor)],
                         location,
                     ),
                     Operator::AssignmentBitwiseAnd {
@@ -474,7 +666,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::BitwiseAnd(zinc_build::BitwiseAnd),
+                        None,
+                        vec![Instruction::BitwiseAnd(zinc_build::BitwiseAnd)],
                         location,
                     ),
                     Operator::AssignmentBitwiseShiftLeft {
@@ -485,7 +678,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::BitwiseShiftLeft(zinc_build::BitwiseShiftLeft),
+                        None,
+                        vec![Instruction::BitwiseShiftLeft(zinc_build::BitwiseShiftLeft)],
                         location,
                     ),
                     Operator::AssignmentBitwiseShiftRight {
@@ -496,7 +690,10 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::BitwiseShiftRight(zinc_build::BitwiseShiftRight),
+                        None,
+                        vec![Instruction::BitwiseShiftRight(
+                            zinc_build::BitwiseShiftRight,
+                        )],
                         location,
                     ),
                     Operator::AssignmentAddition {
@@ -507,7 +704,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Add(zinc_build::Add),
+                        None,
+                        vec![Instruction::Add(zinc_build::Add)],
                         location,
                     ),
                     Operator::AssignmentSubtraction {
@@ -518,20 +716,45 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Sub(zinc_build::Sub),
+                        None,
+                        vec![Instruction::Sub(zinc_build::Sub)],
                         location,
                     ),
                     Operator::AssignmentMultiplication {
                         place,
                         expression,
-                        operator: _,
-                    } => Self::assignment_with_operation(
-                        state.clone(),
-                        place,
-                        expression,
-                        Instruction::Mul(zinc_build::Mul),
-                        location,
-                    ),
+                        operator,
+                    } => match *operator {
+                        Operator::MultiplicationFixed {
+                            r#type,
+                            wide_type,
+                            scale,
+                        } => {
+                            let wide_casting = Self::scalar_type(wide_type.clone())
+                                .map(|r#type| Instruction::Cast(zinc_build::Cast::new(r#type)));
+
+                            let mut operations = Vec::with_capacity(5);
+                            operations.extend(wide_casting.clone());
+                            operations.extend(Self::multiplication_fixed(r#type, wide_type, scale));
+
+                            Self::assignment_with_operation(
+                                state.clone(),
+                                place,
+                                expression,
+                                wide_casting,
+                                operations,
+                                location,
+                            )
+                        }
+                        _ => Self::assignment_with_operation(
+                            state.clone(),
+                            place,
+                            expression,
+                            None,
+                            vec![Instruction::Mul(zinc_build::Mul)],
+                            location,
+                        ),
+                    },
                     Operator::AssignmentDivision {
                         place,
                         expression,
@@ -540,7 +763,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Div(zinc_build::Div),
+                        None,
+                        vec![Instruction::Div(zinc_build::Div)],
                         location,
                     ),
                     Operator::AssignmentRemainder {
@@ -551,7 +775,8 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Rem(zinc_build::Rem),
+                        None,
+                        vec![Instruction::Rem(zinc_build::Rem)],
                         location,
                     ),
 
@@ -565,6 +790,10 @@ impl IBytecodeWritable for Expression {
                         state
                             .borrow_mut()
                             .push_instruction(Instruction::If(zinc_build::If), None);
+                        state.borrow_mut().push_instruction(
+                            Instruction::ShortCircuit(zinc_build::ShortCircuit),
+                            None,
+                        );
                     }
                     Operator::OrShortCircuitEnd => {
                         state
@@ -591,6 +820,10 @@ impl IBytecodeWritable for Expression {
                         state
                             .borrow_mut()
                             .push_instruction(Instruction::If(zinc_build::If), None);
+                        state.borrow_mut().push_instruction(
+                            Instruction::ShortCircuit(zinc_build::ShortCircuit),
+                            None,
+                        );
                     }
                     Operator::AndShortCircuitEnd => {
                         state
@@ -614,6 +847,12 @@ impl IBytecodeWritable for Expression {
                     Operator::NotEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ne(zinc_build::Ne), location)
                     }
+                    Operator::EqualsComposite { size } => {
+                        Self::equals_composite(state.clone(), size, false, location)
+                    }
+                    Operator::NotEqualsComposite { size } => {
+                        Self::equals_composite(state.clone(), size, true, location)
+                    }
                     Operator::GreaterEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ge(zinc_build::Ge), location)
                     }
@@ -668,6 +907,17 @@ impl IBytecodeWritable for Expression {
                     Operator::Remainder { .. } => {
                         Self::binary(state.clone(), Instruction::Rem(zinc_build::Rem), location)
                     }
+                    Operator::MultiplicationFixed {
+                        r#type,
+                        wide_type,
+                        scale,
+                    } => {
+                        for instruction in Self::multiplication_fixed(r#type, wide_type, scale) {
+                            state
+                                .borrow_mut()
+                                .push_instruction(instruction, Some(location));
+                        }
+                    }
 
                     Operator::Casting { r#type } => {
                         if let Some(scalar_type) = r#type.into() {
@@ -679,6 +929,47 @@ impl IBytecodeWritable for Expression {
                         }
                     }
 
+                    Operator::CastingToFixed { r#type, scale } => {
+                        if let Some(scalar_type) = Self::scalar_type(r#type) {
+                            let mut state = state.borrow_mut();
+                            state.push_instruction(
+                                Instruction::Cast(zinc_build::Cast::new(scalar_type.clone())),
+                                Some(location),
+                            );
+                            state.push_instruction(
+                                Instruction::Push(zinc_build::Push::new(scale, scalar_type)),
+                                Some(location),
+                            );
+                            state.push_instruction(
+                                Instruction::Mul(zinc_build::Mul),
+                                Some(location),
+                            );
+                        }
+                    }
+                    Operator::CastingFromFixed {
+                        inner_type,
+                        r#type,
+                        scale,
+                    } => {
+                        if let (Some(inner_type), Some(scalar_type)) =
+                            (Self::scalar_type(inner_type), Self::scalar_type(r#type))
+                        {
+                            let mut state = state.borrow_mut();
+                            state.push_instruction(
+                                Instruction::Push(zinc_build::Push::new(scale, inner_type)),
+                                Some(location),
+                            );
+                            state.push_instruction(
+                                Instruction::Div(zinc_build::Div),
+                                Some(location),
+                            );
+                            state.push_instruction(
+                                Instruction::Cast(zinc_build::Cast::new(scalar_type)),
+                                Some(location),
+                            );
+                        }
+                    }
+
                     Operator::Not => {
                         Self::unary(state.clone(), Instruction::Not(zinc_build::Not), location)
                     }
@@ -705,18 +996,16 @@ impl IBytecodeWritable for Expression {
                                 Instruction::Cast(zinc_build::Cast::new(ScalarType::Field)),
                                 Some(location),
                             );
-                            if access.slice_length == 1 {
-                                IntegerConstant::new(
-                                    BigInt::from(access.element_size),
-                                    false,
-                                    zinc_const::bitlength::FIELD,
-                                )
-                                .write_all(state.clone());
-                                state.borrow_mut().push_instruction(
-                                    Instruction::Mul(zinc_build::Mul),
-                                    Some(location),
-                                );
-                            }
+                            IntegerConstant::new(
+                                BigInt::from(access.element_size),
+                                false,
+                                zinc_const::bitlength::FIELD,
+                            )
+                            .write_all(state.clone());
+                            state.borrow_mut().push_instruction(
+                                Instruction::Mul(zinc_build::Mul),
+                                Some(location),
+                            );
                         }
                         state.borrow_mut().push_instruction(
                             Instruction::Slice(zinc_build::Slice::new(
@@ -746,6 +1035,11 @@ impl IBytecodeWritable for Expression {
                         type_id,
                         input_size,
                     } => Self::call(state.clone(), type_id, input_size, location),
+                    Operator::CallWriteback {
+                        type_id,
+                        input_size,
+                        place,
+                    } => Self::call_writeback(state.clone(), type_id, input_size, place, location),
                     Operator::CallDebug {
                         format,
                         argument_types,
@@ -758,9 +1052,18 @@ impl IBytecodeWritable for Expression {
                             .collect(),
                         location,
                     ),
-                    Operator::CallRequire { message } => {
-                        Self::call_assert(state.clone(), message, location)
-                    }
+                    Operator::CallRequire {
+                        message,
+                        argument_types,
+                    } => Self::call_assert(
+                        state.clone(),
+                        message,
+                        argument_types
+                            .into_iter()
+                            .map(|r#type| r#type.into())
+                            .collect(),
+                        location,
+                    ),
                     Operator::CallLibrary {
                         identifier,
                         input_size,
@@ -777,3 +1080,11 @@ impl IBytecodeWritable for Expression {
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        for element in self.elements.iter() {
+            element.print(printer);
+        }
+    }
+}
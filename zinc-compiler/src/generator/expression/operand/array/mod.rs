@@ -9,8 +9,10 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 
 use self::variant::Variant;
 
@@ -59,3 +61,26 @@ impl IBytecodeWritable for Expression {
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        match self.variant {
+            Variant::List { ref expressions } => {
+                printer.line(format!("array [{}]", expressions.len()));
+                printer.nested(|printer| {
+                    for (index, expression) in expressions.iter().enumerate() {
+                        printer.line(format!("element {}", index));
+                        printer.nested(|printer| expression.print(printer));
+                    }
+                });
+            }
+            Variant::Repeated {
+                ref expression,
+                size,
+            } => {
+                printer.line(format!("array repeated [{}]", size));
+                printer.nested(|printer| expression.print(printer));
+            }
+        }
+    }
+}
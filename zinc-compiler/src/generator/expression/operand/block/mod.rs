@@ -8,9 +8,11 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::statement::Statement;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 
 ///
 /// The block expression which is translated to Zinc VM bytecode.
@@ -45,3 +47,17 @@ impl IBytecodeWritable for Expression {
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        printer.line("block");
+        printer.nested(|printer| {
+            for statement in self.statements.iter() {
+                statement.print(printer);
+            }
+            if let Some(ref expression) = self.expression {
+                expression.print(printer);
+            }
+        });
+    }
+}
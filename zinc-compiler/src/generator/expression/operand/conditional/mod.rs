@@ -11,8 +11,10 @@ use zinc_build::Instruction;
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use zinc_lexical::Location;
 
 ///
@@ -69,3 +71,20 @@ impl IBytecodeWritable for Expression {
             .push_instruction(Instruction::EndIf(zinc_build::EndIf), Some(self.location));
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        printer.line_at("if", self.location);
+        printer.nested(|printer| {
+            self.condition.print(printer);
+
+            printer.line("then");
+            printer.nested(|printer| self.main_block.print(printer));
+
+            if let Some(ref else_block) = self.else_block {
+                printer.line("else");
+                printer.nested(|printer| else_block.print(printer));
+            }
+        });
+    }
+}
@@ -6,10 +6,13 @@ pub mod boolean;
 pub mod integer;
 
 use std::cell::RefCell;
+use std::fmt;
 use std::rc::Rc;
 
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::element::constant::Constant as SemanticConstant;
 
 use self::boolean::Boolean;
@@ -77,6 +80,16 @@ impl Constant {
             _ => None,
         }
     }
+
+    ///
+    /// Returns the number of data stack cells occupied by the constant.
+    ///
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Boolean(_) | Self::Integer(_) => 1,
+            Self::Group(inner) => inner.iter().map(Self::size).sum(),
+        }
+    }
 }
 
 impl IBytecodeWritable for Constant {
@@ -92,3 +105,33 @@ impl IBytecodeWritable for Constant {
         }
     }
 }
+
+impl IIntermediatePrintable for Constant {
+    fn print(&self, printer: &mut Printer) {
+        printer.line(format!("constant {}", self));
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(inner) => write!(f, "{}", inner.inner),
+            Self::Integer(inner) => write!(
+                f,
+                "{}: {}{}",
+                inner.value,
+                if inner.is_signed { "i" } else { "u" },
+                inner.bitlength
+            ),
+            Self::Group(inner) => write!(
+                f,
+                "({})",
+                inner
+                    .iter()
+                    .map(|constant| constant.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
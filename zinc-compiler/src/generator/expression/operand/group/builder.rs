@@ -2,6 +2,9 @@
 //! The generator expression group operand builder.
 //!
 
+use zinc_lexical::Location;
+
+use crate::generator::expression::operand::group::element::Element;
 use crate::generator::expression::operand::group::Expression as GroupExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -12,8 +15,10 @@ use crate::semantic::element::r#type::Type as SemanticType;
 ///
 #[derive(Debug, Default, Clone)]
 pub struct Builder {
-    /// The typed group element expressions.
-    expressions: Vec<(Type, GeneratorExpression)>,
+    /// The group elements.
+    elements: Vec<Element>,
+    /// The structure update base location, expression, and size.
+    base: Option<(Location, GeneratorExpression, usize)>,
 }
 
 impl Builder {
@@ -22,14 +27,33 @@ impl Builder {
     ///
     pub fn push_expression(&mut self, r#type: SemanticType, value: GeneratorExpression) {
         if let Some(r#type) = Type::try_from_semantic(&r#type) {
-            self.expressions.push((r#type, value));
+            self.elements.push(Element::Expression(r#type, value));
+        }
+    }
+
+    ///
+    /// Pushes a group element, which is copied from the structure update base at `offset`.
+    ///
+    pub fn push_base_element(&mut self, r#type: SemanticType, offset: usize) {
+        if Type::try_from_semantic(&r#type).is_some() {
+            self.elements.push(Element::Base {
+                offset,
+                size: r#type.size(),
+            });
         }
     }
 
+    ///
+    /// Sets the structure update base expression of `size`.
+    ///
+    pub fn set_base(&mut self, location: Location, value: GeneratorExpression, size: usize) {
+        self.base = Some((location, value, size));
+    }
+
     ///
     /// Finilizes the builder and returns the built item.
     ///
     pub fn finish(self) -> GroupExpression {
-        GroupExpression::new(self.expressions)
+        GroupExpression::new(self.elements, self.base)
     }
 }
@@ -0,0 +1,22 @@
+//!
+//! The generator expression group operand element.
+//!
+
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+
+///
+/// The group element, which is either specified explicitly or copied from the structure update base.
+///
+#[derive(Debug, Clone)]
+pub enum Element {
+    /// The typed element expression.
+    Expression(Type, GeneratorExpression),
+    /// The element copied from the structure update base at `offset` of `size`.
+    Base {
+        /// The element offset from the beginning of the base data.
+        offset: usize,
+        /// The element size.
+        size: usize,
+    },
+}
@@ -3,37 +3,97 @@
 //!
 
 pub mod builder;
+pub mod element;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_build::Instruction;
+use zinc_lexical::Location;
+
 use crate::generator::expression::Expression as GeneratorExpression;
-use crate::generator::r#type::Type;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
+
+use self::element::Element;
 
 ///
 /// The group expression which is translated to Zinc VM data.
 ///
 #[derive(Debug, Clone)]
 pub struct Expression {
-    /// The typed group element expressions.
-    expressions: Vec<(Type, GeneratorExpression)>,
+    /// The group elements.
+    elements: Vec<Element>,
+    /// The structure update base location, expression, and size, which is stored to a
+    /// temporary variable to copy the elements not specified explicitly.
+    base: Option<(Location, GeneratorExpression, usize)>,
 }
 
 impl Expression {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(expressions: Vec<(Type, GeneratorExpression)>) -> Self {
-        Self { expressions }
+    pub fn new(
+        elements: Vec<Element>,
+        base: Option<(Location, GeneratorExpression, usize)>,
+    ) -> Self {
+        Self { elements, base }
     }
 }
 
 impl IBytecodeWritable for Expression {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
-        for (_type, expression) in self.expressions.into_iter() {
-            expression.write_all(bytecode.clone());
+        let base = match self.base {
+            Some((location, expression, size)) => {
+                let address = bytecode.borrow_mut().define_variable(None, size);
+                expression.write_all(bytecode.clone());
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_build::Store::new(address, size)),
+                    Some(location),
+                );
+                Some((location, address))
+            }
+            None => None,
+        };
+
+        for element in self.elements.into_iter() {
+            match element {
+                Element::Expression(_type, expression) => expression.write_all(bytecode.clone()),
+                Element::Base { offset, size } => {
+                    let (location, address) =
+                        base.expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_build::Load::new(address + offset, size)),
+                        Some(location),
+                    );
+                }
+            }
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        printer.line("group");
+        printer.nested(|printer| {
+            if let Some((location, ref expression, size)) = self.base {
+                printer.line_at(format!("base size {}", size), location);
+                printer.nested(|printer| expression.print(printer));
+            }
+
+            for element in self.elements.iter() {
+                match element {
+                    Element::Expression(r#type, expression) => {
+                        printer.line(format!("element: {}", r#type));
+                        printer.nested(|printer| expression.print(printer));
+                    }
+                    Element::Base { offset, size } => {
+                        printer.line(format!("element from base at {} size {}", offset, size))
+                    }
+                }
+            }
+        });
+    }
+}
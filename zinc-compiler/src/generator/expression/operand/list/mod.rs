@@ -8,8 +8,10 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 
 ///
 /// The list expression which is translated to Zinc VM data.
@@ -36,3 +38,15 @@ impl IBytecodeWritable for Expression {
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        printer.line(format!("arguments [{}]", self.expressions.len()));
+        printer.nested(|printer| {
+            for (index, expression) in self.expressions.iter().enumerate() {
+                printer.line(format!("argument {}", index));
+                printer.nested(|printer| expression.print(printer));
+            }
+        });
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::pattern::Pattern;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -20,11 +21,12 @@ pub struct Builder {
     /// The scrutinee (matched) expression type.
     scrutinee_type: Option<Type>,
     /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Pattern, GeneratorExpression)>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
-    /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// The wildcard `_` or irrefutable destructuring branch, which is the last fallback branch.
+    /// Ignored if `binding_branch` is set.
+    wildcard_branch: Option<(Pattern, GeneratorExpression)>,
 }
 
 impl Builder {
@@ -47,6 +49,14 @@ impl Builder {
     /// Pushes a branch, which consists of a `pattern` and `expression`.
     ///
     pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
+        self.branches
+            .push((Pattern::new_constant(pattern), expression));
+    }
+
+    ///
+    /// Pushes a destructuring branch, which consists of a `pattern` and `expression`.
+    ///
+    pub fn push_destructuring_branch(&mut self, pattern: Pattern, expression: GeneratorExpression) {
         self.branches.push((pattern, expression));
     }
 
@@ -61,7 +71,18 @@ impl Builder {
     /// Sets the corresponding builder value.
     ///
     pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+        self.wildcard_branch = Some((Pattern::default(), value));
+    }
+
+    ///
+    /// Sets the irrefutable destructuring branch, which binds the scrutinee elements.
+    ///
+    pub fn set_destructuring_wildcard_branch(
+        &mut self,
+        pattern: Pattern,
+        expression: GeneratorExpression,
+    ) {
+        self.wildcard_branch = Some((pattern, expression));
     }
 
     ///
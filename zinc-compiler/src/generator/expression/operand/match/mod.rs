@@ -3,22 +3,33 @@
 //!
 
 pub mod builder;
+pub mod pattern;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+
 use zinc_build::Instruction;
 
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use zinc_lexical::Location;
 
+use self::pattern::Pattern;
+
 ///
 /// The match expression which is translated to Zinc VM conditional series.
 ///
+/// If all the branches compare the whole scrutinee with integer constants forming a dense range,
+/// and neither the branches nor the fallback bind variables or have side effects, the expression
+/// is translated to a dispatch table instead, which selects the result without branching.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     /// The `match` expression location.
@@ -28,11 +39,12 @@ pub struct Expression {
     /// The scrutinee (matched) expression type.
     scrutinee_type: Type,
     /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Pattern, GeneratorExpression)>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
-    /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// The wildcard `_` or irrefutable destructuring branch, which is the last fallback branch.
+    /// Ignored if `binding_branch` is set.
+    wildcard_branch: Option<(Pattern, GeneratorExpression)>,
 }
 
 impl Expression {
@@ -43,9 +55,9 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
+        branches: Vec<(Pattern, GeneratorExpression)>,
         binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        wildcard_branch: Option<(Pattern, GeneratorExpression)>,
     ) -> Self {
         Self {
             location,
@@ -58,8 +70,120 @@ impl Expression {
     }
 }
 
+impl Expression {
+    ///
+    /// Copies the bound scrutinee elements to their variables.
+    ///
+    fn write_bindings(
+        bytecode: Rc<RefCell<State>>,
+        scrutinee_address: usize,
+        bindings: Vec<(String, usize, usize)>,
+        location: Location,
+    ) {
+        for (name, offset, size) in bindings.into_iter() {
+            let address = bytecode.borrow_mut().define_variable(Some(name), size);
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Load(zinc_build::Load::new(scrutinee_address + offset, size)),
+                Some(location),
+            );
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_build::Store::new(address, size)),
+                Some(location),
+            );
+        }
+    }
+
+    ///
+    /// Returns the first discriminant and the branch value size, if the expression can be
+    /// translated to a dispatch table.
+    ///
+    fn dispatch_table(&self) -> Option<(BigInt, usize)> {
+        if self.binding_branch.is_some()
+            || self.scrutinee_type.size() != 1
+            || self.branches.len() < zinc_const::limit::DISPATCH_ARMS_MIN
+            || self.branches.len() > zinc_const::limit::DISPATCH_ARMS_MAX
+        {
+            return None;
+        }
+
+        let size = match self.wildcard_branch {
+            Some((ref pattern, ref expression)) if pattern.bindings.is_empty() => {
+                expression.side_effect_free_size()?
+            }
+            _ => return None,
+        };
+
+        let mut discriminants = Vec::with_capacity(self.branches.len());
+        for (pattern, expression) in self.branches.iter() {
+            if expression.side_effect_free_size() != Some(size) {
+                return None;
+            }
+
+            discriminants.push(Self::discriminant(pattern)?);
+        }
+        discriminants.sort();
+
+        let start = (*discriminants.first()?).to_owned();
+        let is_dense = discriminants
+            .iter()
+            .enumerate()
+            .all(|(index, discriminant)| **discriminant == &start + BigInt::from(index));
+        if !is_dense {
+            return None;
+        }
+
+        Some((start, size))
+    }
+
+    ///
+    /// Returns the integer constant the whole scrutinee is compared with, if the `pattern`
+    /// is a plain constant one.
+    ///
+    fn discriminant(pattern: &Pattern) -> Option<&BigInt> {
+        if !pattern.bindings.is_empty() {
+            return None;
+        }
+
+        match pattern.conditions.as_slice() {
+            [(0, Constant::Integer(integer))] => Some(&integer.value),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Writes the scrutinee, the branch values in the discriminant order, the fallback value,
+    /// and the dispatch table instruction selecting one of them.
+    ///
+    fn write_dispatch_table(self, bytecode: Rc<RefCell<State>>, start: BigInt, size: usize) {
+        let branch_count = self.branches.len();
+
+        let mut branches = self.branches;
+        branches.sort_by(|(left, _), (right, _)| {
+            Self::discriminant(left).cmp(&Self::discriminant(right))
+        });
+
+        self.scrutinee.write_all(bytecode.clone());
+        for (_pattern, expression) in branches.into_iter() {
+            expression.write_all(bytecode.clone());
+        }
+        if let Some((_pattern, expression)) = self.wildcard_branch {
+            expression.write_all(bytecode.clone());
+        }
+
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Dispatch(zinc_build::Dispatch::new(start, branch_count, size)),
+            Some(self.location),
+        );
+    }
+}
+
 impl IBytecodeWritable for Expression {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
+        if let Some((start, size)) = self.dispatch_table() {
+            self.write_dispatch_table(bytecode, start, size);
+            return;
+        }
+
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
 
@@ -79,17 +203,31 @@ impl IBytecodeWritable for Expression {
         );
 
         for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            bytecode.borrow_mut().push_instruction(
-                Instruction::Load(zinc_build::Load::new(scrutinee_address, scrutinee_size)),
-                Some(self.location),
-            );
-            branch_pattern.write_all(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_build::Eq), Some(self.location));
+            for (index, (offset, value)) in branch_pattern.conditions.into_iter().enumerate() {
+                let size = value.size();
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_build::Load::new(scrutinee_address + offset, size)),
+                    Some(self.location),
+                );
+                value.write_all(bytecode.clone());
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Eq(zinc_build::Eq), Some(self.location));
+                if index > 0 {
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::And(zinc_build::And), Some(self.location));
+                }
+            }
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_build::If), Some(self.location));
+            Self::write_bindings(
+                bytecode.clone(),
+                scrutinee_address,
+                branch_pattern.bindings,
+                self.location,
+            );
             branch_expression.write_all(bytecode.clone());
             bytecode
                 .borrow_mut()
@@ -98,7 +236,13 @@ impl IBytecodeWritable for Expression {
 
         if let Some(binding_branch) = binding_branch {
             binding_branch.write_all(bytecode.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
+        } else if let Some((wildcard_pattern, wildcard_branch)) = self.wildcard_branch {
+            Self::write_bindings(
+                bytecode.clone(),
+                scrutinee_address,
+                wildcard_pattern.bindings,
+                self.location,
+            );
             wildcard_branch.write_all(bytecode.clone());
         }
 
@@ -112,3 +256,24 @@ impl IBytecodeWritable for Expression {
         }
     }
 }
+
+impl IIntermediatePrintable for Expression {
+    fn print(&self, printer: &mut Printer) {
+        printer.line_at(format!("match: {}", self.scrutinee_type), self.location);
+        printer.nested(|printer| {
+            self.scrutinee.print(printer);
+
+            for (pattern, expression) in self.branches.iter() {
+                printer.line(format!("branch {}", pattern));
+                printer.nested(|printer| expression.print(printer));
+            }
+            if let Some((ref expression, ref name)) = self.binding_branch {
+                printer.line(format!("binding {}", name));
+                printer.nested(|printer| expression.print(printer));
+            } else if let Some((ref pattern, ref expression)) = self.wildcard_branch {
+                printer.line(format!("wildcard {}", pattern));
+                printer.nested(|printer| expression.print(printer));
+            }
+        });
+    }
+}
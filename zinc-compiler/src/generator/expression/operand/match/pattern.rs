@@ -0,0 +1,74 @@
+//!
+//! The generator expression match operand pattern.
+//!
+
+use std::fmt;
+
+use crate::generator::expression::operand::constant::Constant;
+
+///
+/// The match branch pattern, which matches if all its element equality conditions hold.
+///
+/// The scrutinee elements are addressed by their offset from the beginning of the scrutinee data.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Pattern {
+    /// The conditions, where each consists of an element offset and the expected value.
+    pub conditions: Vec<(usize, Constant)>,
+    /// The bindings, where each consists of a variable name, element offset, and size.
+    pub bindings: Vec<(String, usize, usize)>,
+}
+
+impl Pattern {
+    ///
+    /// Creates a pattern, which compares the whole scrutinee with the `value`.
+    ///
+    pub fn new_constant(value: Constant) -> Self {
+        Self {
+            conditions: vec![(0, value)],
+            bindings: vec![],
+        }
+    }
+
+    ///
+    /// Pushes an equality condition of the element at `offset` and the `value`.
+    ///
+    pub fn push_condition(&mut self, offset: usize, value: Constant) {
+        self.conditions.push((offset, value));
+    }
+
+    ///
+    /// Pushes a binding of the element at `offset` of `size` to the variable `name`.
+    ///
+    pub fn push_binding(&mut self, name: String, offset: usize, size: usize) {
+        self.bindings.push((name, offset, size));
+    }
+
+    ///
+    /// Whether the pattern always matches, that is, it has no conditions.
+    ///
+    pub fn is_irrefutable(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut elements: Vec<String> = self
+            .conditions
+            .iter()
+            .map(|(offset, value)| format!("@{} == {}", offset, value))
+            .collect();
+        elements.extend(
+            self.bindings
+                .iter()
+                .map(|(name, offset, size)| format!("{} = @{}:{}", name, offset, size)),
+        );
+
+        if elements.is_empty() {
+            write!(f, "_")
+        } else {
+            write!(f, "{}", elements.join(", "))
+        }
+    }
+}
@@ -17,10 +17,14 @@ use std::rc::Rc;
 use num::BigInt;
 
 use zinc_build::Instruction;
+use zinc_build::ScalarType;
+use zinc_lexical::Location;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
@@ -74,12 +78,20 @@ impl IBytecodeWritable for Operand {
                     let is_indexed = !inner.elements.is_empty();
 
                     if is_indexed {
+                        let index_address = state.borrow().next_instruction_address();
                         inner.write_all(state.clone());
+                        let is_index_checked = state.borrow_mut().check_index(
+                            index_address,
+                            address,
+                            element_size,
+                            total_size,
+                        );
                         state.borrow_mut().push_instruction(
                             Instruction::LoadByIndex(zinc_build::LoadByIndex::new(
                                 address,
                                 element_size,
                                 total_size,
+                                is_index_checked,
                             )),
                             Some(location),
                         );
@@ -101,10 +113,14 @@ impl IBytecodeWritable for Operand {
                                 position,
                                 element_size,
                                 is_mtreemap,
+                                leaves_count,
                                 ..
                             },
                     }) = inner.elements.first()
                     {
+                        let is_mtreemap = *is_mtreemap;
+                        let leaves_count = *leaves_count;
+
                         IntegerConstant::new(
                             BigInt::from(*position),
                             false,
@@ -122,6 +138,16 @@ impl IBytecodeWritable for Operand {
                         }
 
                         inner.elements.remove(0);
+
+                        if is_mtreemap && !inner.elements.is_empty() {
+                            Self::write_mtreemap_array_leaf_index(
+                                inner.elements.remove(0),
+                                leaves_count,
+                                location,
+                                state,
+                            );
+                            return;
+                        }
                     }
 
                     let is_indexed = !inner.elements.is_empty();
@@ -144,3 +170,76 @@ impl IBytecodeWritable for Operand {
         }
     }
 }
+
+impl Operand {
+    ///
+    /// Writes the instructions, which add the array index to the contract storage position
+    /// of the map array already on the evaluation stack, producing the selected map leaf index.
+    ///
+    /// The constant index is checked during the semantic analysis, whereas the runtime one
+    /// is constrained to be lesser than the array length.
+    ///
+    fn write_mtreemap_array_leaf_index(
+        element: SemanticPlaceElement,
+        array_length: usize,
+        location: Location,
+        state: Rc<RefCell<State>>,
+    ) {
+        match element {
+            SemanticPlaceElement::IndexConstant { constant, .. } => {
+                IntegerConstant::from_semantic(&constant).write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_build::Cast::new(ScalarType::Field)),
+                    Some(location),
+                );
+            }
+            SemanticPlaceElement::IndexExpression { expression, .. } => {
+                expression.write_all(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_build::Cast::new(ScalarType::Field)),
+                    Some(location),
+                );
+
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Copy(zinc_build::Copy), Some(location));
+                IntegerConstant::new(
+                    BigInt::from(array_length),
+                    false,
+                    zinc_const::bitlength::FIELD,
+                )
+                .write_all(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Lt(zinc_build::Lt), Some(location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Require(zinc_build::Require::new(
+                        Some("map array index is out of range".to_owned()),
+                        vec![],
+                    )),
+                    Some(location),
+                );
+            }
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Add(zinc_build::Add), Some(location));
+    }
+}
+
+impl IIntermediatePrintable for Operand {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            Self::Constant(inner) => inner.print(printer),
+            Self::Place(inner) => inner.print(printer),
+            Self::Array(inner) => inner.print(printer),
+            Self::Group(inner) => inner.print(printer),
+            Self::List(inner) => inner.print(printer),
+            Self::Block(inner) => inner.print(printer),
+            Self::Conditional(inner) => inner.print(printer),
+            Self::Match(inner) => inner.print(printer),
+        }
+    }
+}
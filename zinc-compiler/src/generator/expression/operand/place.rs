@@ -13,8 +13,10 @@ use zinc_build::ScalarType;
 use zinc_syntax::Identifier;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
 use crate::semantic::element::place::Place as SemanticPlace;
@@ -112,6 +114,54 @@ impl IBytecodeWritable for Place {
                         Some(self.identifier.location),
                     );
                 }
+                SemanticPlaceElement::IndexRangeDynamic {
+                    expression,
+                    array_length,
+                    access,
+                } => {
+                    expression.write_all(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Cast(zinc_build::Cast::new(ScalarType::Field)),
+                        Some(self.identifier.location),
+                    );
+
+                    state.borrow_mut().push_instruction(
+                        Instruction::Copy(zinc_build::Copy),
+                        Some(self.identifier.location),
+                    );
+                    IntegerConstant::new(
+                        BigInt::from(array_length - access.slice_length),
+                        false,
+                        zinc_const::bitlength::FIELD,
+                    )
+                    .write_all(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Le(zinc_build::Le),
+                        Some(self.identifier.location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::Require(zinc_build::Require::new(
+                            Some("array slice is out of range".to_owned()),
+                            vec![],
+                        )),
+                        Some(self.identifier.location),
+                    );
+
+                    IntegerConstant::new(
+                        BigInt::from(access.element_size),
+                        false,
+                        zinc_const::bitlength::FIELD,
+                    )
+                    .write_all(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Mul(zinc_build::Mul),
+                        Some(self.identifier.location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::Add(zinc_build::Add),
+                        Some(self.identifier.location),
+                    );
+                }
                 SemanticPlaceElement::StackField { access } => {
                     IntegerConstant::new(
                         BigInt::from(access.offset),
@@ -141,3 +191,37 @@ impl From<SemanticPlace> for Place {
         }
     }
 }
+
+impl IIntermediatePrintable for Place {
+    fn print(&self, printer: &mut Printer) {
+        printer.line_at(
+            format!(
+                "place {}{} [{}] size {}/{}",
+                self.identifier.name,
+                self.elements
+                    .iter()
+                    .map(|element| element.to_string())
+                    .collect::<Vec<String>>()
+                    .join(""),
+                match self.memory_type {
+                    MemoryType::Stack => "stack",
+                    MemoryType::ContractStorage => "storage",
+                },
+                self.element_size,
+                self.total_size,
+            ),
+            self.identifier.location,
+        );
+        printer.nested(|printer| {
+            for element in self.elements.iter() {
+                match element {
+                    SemanticPlaceElement::IndexExpression { expression, .. }
+                    | SemanticPlaceElement::IndexRangeDynamic { expression, .. } => {
+                        expression.print(printer)
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+}
@@ -2,13 +2,20 @@
 //! The generator expression operator.
 //!
 
+use std::fmt;
+
+use num::BigInt;
+
 use zinc_build::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::place::Place;
 use crate::generator::expression::Expression;
+use crate::generator::printer::Printer;
 use crate::generator::r#type::Type;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::access::index::Index as IndexAccess;
+use crate::semantic::element::r#type::structure::Structure as SemanticStructureType;
 use crate::semantic::element::r#type::Type as SemanticType;
 
 ///
@@ -174,6 +181,20 @@ pub enum Operator {
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
     },
+    /// The binary `==` comparison operator for tuples, arrays, and structures.
+    ///
+    /// The operands are compared element-wise, and the results are ANDed together.
+    EqualsComposite {
+        /// The size of each operand in the Zinc VM data stack.
+        size: usize,
+    },
+    /// The binary `!=` comparison operator for tuples, arrays, and structures.
+    ///
+    /// The operands are compared element-wise, and the ANDed result is inverted.
+    NotEqualsComposite {
+        /// The size of each operand in the Zinc VM data stack.
+        size: usize,
+    },
 
     /// The binary `|` bitwise OR operator.
     BitwiseOr {
@@ -236,12 +257,40 @@ pub enum Operator {
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
     },
+    /// The binary `*` fixed-point decimal multiplication operator.
+    ///
+    /// The operands are cast to the wide type, so their product does not overflow, and then
+    /// the product is divided by the scale and cast back to the fixed-point integer type.
+    MultiplicationFixed {
+        /// The fixed-point decimal integer type.
+        r#type: Type,
+        /// The integer type, which is wide enough to hold the product of the operands.
+        wide_type: Type,
+        /// The fixed-point decimal scale, that is, `10^D`.
+        scale: BigInt,
+    },
 
     /// The type casting operator.
     Casting {
         /// The type to cast into.
         r#type: Type,
     },
+    /// The integer to fixed-point decimal casting operator, which multiplies the integer by the scale.
+    CastingToFixed {
+        /// The fixed-point decimal integer type.
+        r#type: Type,
+        /// The fixed-point decimal scale, that is, `10^D`.
+        scale: BigInt,
+    },
+    /// The fixed-point decimal to integer casting operator, which divides the value by the scale.
+    CastingFromFixed {
+        /// The fixed-point decimal integer type.
+        inner_type: Type,
+        /// The integer type to cast into.
+        r#type: Type,
+        /// The fixed-point decimal scale, that is, `10^D`.
+        scale: BigInt,
+    },
 
     /// The unary logical `!` NOT operator.
     Not,
@@ -273,6 +322,15 @@ pub enum Operator {
         /// The function arguments size.
         input_size: usize,
     },
+    /// The `mut self` method call, whose instance is written back to the contract storage.
+    CallWriteback {
+        /// The method unique ID assigned during semantic analysis.
+        type_id: usize,
+        /// The method arguments size, including the instance.
+        input_size: usize,
+        /// The contract storage place the instance has been loaded from.
+        place: Place,
+    },
     /// The `dbg!(...)` function call operator.
     CallDebug {
         /// The format string with `{}` placeholders.
@@ -282,8 +340,10 @@ pub enum Operator {
     },
     /// The `require(...)` function call operator.
     CallRequire {
-        /// The optional error description message.
+        /// The optional error description message with `{}` placeholders.
         message: Option<String>,
+        /// The types of the values interpolated into the message.
+        argument_types: Vec<Type>,
     },
     /// The standard library function call.
     CallLibrary {
@@ -355,6 +415,20 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn equals_composite(size: usize) -> Self {
+        Self::EqualsComposite { size }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn not_equals_composite(size: usize) -> Self {
+        Self::NotEqualsComposite { size }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -703,6 +777,19 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn multiplication_fixed(r#type: &SemanticStructureType) -> Self {
+        let (is_signed, bitlength) = Self::fixed_layout(r#type);
+
+        Self::MultiplicationFixed {
+            r#type: Type::integer(is_signed, bitlength),
+            wide_type: Type::integer(is_signed, bitlength * 2),
+            scale: r#type.fixed_scale(),
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -719,6 +806,36 @@ impl Operator {
         Self::Casting { r#type }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn casting_to_fixed(r#type: &SemanticStructureType) -> Self {
+        let (is_signed, bitlength) = Self::fixed_layout(r#type);
+
+        Self::CastingToFixed {
+            r#type: Type::integer(is_signed, bitlength),
+            scale: r#type.fixed_scale(),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// If the `r#type` is not a valid type to cast to, `None` is returned.
+    ///
+    pub fn try_casting_from_fixed(
+        inner_type: &SemanticStructureType,
+        r#type: &SemanticType,
+    ) -> Option<Self> {
+        let (is_signed, bitlength) = Self::fixed_layout(inner_type);
+
+        Type::try_from_semantic(r#type).map(|r#type| Self::CastingFromFixed {
+            inner_type: Type::integer(is_signed, bitlength),
+            r#type,
+            scale: inner_type.fixed_scale(),
+        })
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -743,6 +860,17 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_writeback(type_id: usize, input_size: usize, place: Place) -> Self {
+        Self::CallWriteback {
+            type_id,
+            input_size,
+            place,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -760,8 +888,15 @@ impl Operator {
     ///
     /// A shortcut constructor.
     ///
-    pub fn call_assert(message: Option<String>) -> Self {
-        Self::CallRequire { message }
+    pub fn call_assert(message: Option<String>, argument_types: Vec<SemanticType>) -> Self {
+        Self::CallRequire {
+            message,
+            argument_types: argument_types
+                .as_slice()
+                .iter()
+                .filter_map(Type::try_from_semantic)
+                .collect(),
+        }
     }
 
     ///
@@ -779,10 +914,29 @@ impl Operator {
         }
     }
 
+    ///
+    /// Checks if the operator is a comparison, whose result is a boolean.
+    ///
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Equals { .. }
+                | Self::NotEquals { .. }
+                | Self::GreaterEquals { .. }
+                | Self::LesserEquals { .. }
+                | Self::Greater { .. }
+                | Self::Lesser { .. }
+                | Self::EqualsComposite { .. }
+                | Self::NotEqualsComposite { .. }
+        )
+    }
+
     ///
     /// Returns the first operand's integer inferred type, if the type was inferred for the literal
     /// to adopt the other operand's fixed type.
     ///
+    /// For the fixed-point decimal multiplication, the operand is cast to the wide integer type.
+    ///
     pub fn operand_1_inferred_type(&self) -> Option<Type> {
         match self.to_owned() {
             Self::Equals {
@@ -843,6 +997,7 @@ impl Operator {
                 operand_1_inferred_type,
                 ..
             } => operand_1_inferred_type,
+            Self::MultiplicationFixed { wide_type, .. } => Some(wide_type),
 
             _ => None,
         }
@@ -852,6 +1007,8 @@ impl Operator {
     /// Returns the second operand's integer inferred type, if the type was inferred for the literal
     /// to adopt the other operand's fixed type.
     ///
+    /// For the fixed-point decimal multiplication, the operand is cast to the wide integer type.
+    ///
     pub fn operand_2_inferred_type(&self) -> Option<Type> {
         match self.to_owned() {
             Self::Equals {
@@ -912,8 +1069,224 @@ impl Operator {
                 operand_2_inferred_type,
                 ..
             } => operand_2_inferred_type,
+            Self::MultiplicationFixed { wide_type, .. } => Some(wide_type),
 
             _ => None,
         }
     }
+
+    ///
+    /// Returns the fixed-point decimal integer type sign and bitlength.
+    ///
+    fn fixed_layout(r#type: &SemanticStructureType) -> (bool, usize) {
+        match r#type.fixed_inner_type() {
+            SemanticType::IntegerUnsigned { bitlength, .. } => (false, bitlength),
+            SemanticType::IntegerSigned { bitlength, .. } => (true, bitlength),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+}
+
+impl IIntermediatePrintable for Operator {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            Self::Assignment { place, expression }
+            | Self::AssignmentBitwiseOr {
+                place, expression, ..
+            }
+            | Self::AssignmentBitwiseXor {
+                place, expression, ..
+            }
+            | Self::AssignmentBitwiseAnd {
+                place, expression, ..
+            }
+            | Self::AssignmentBitwiseShiftLeft {
+                place, expression, ..
+            }
+            | Self::AssignmentBitwiseShiftRight {
+                place, expression, ..
+            }
+            | Self::AssignmentAddition {
+                place, expression, ..
+            }
+            | Self::AssignmentSubtraction {
+                place, expression, ..
+            }
+            | Self::AssignmentMultiplication {
+                place, expression, ..
+            }
+            | Self::AssignmentDivision {
+                place, expression, ..
+            }
+            | Self::AssignmentRemainder {
+                place, expression, ..
+            } => {
+                place.print(printer);
+                expression.print(printer);
+            }
+            Self::Index { expression, .. } => expression.print(printer),
+            Self::CallWriteback { place, .. } => place.print(printer),
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+
+            Self::Assignment { .. } => write!(f, "assignment"),
+            Self::AssignmentBitwiseOr { operator, .. }
+            | Self::AssignmentBitwiseXor { operator, .. }
+            | Self::AssignmentBitwiseAnd { operator, .. }
+            | Self::AssignmentBitwiseShiftLeft { operator, .. }
+            | Self::AssignmentBitwiseShiftRight { operator, .. }
+            | Self::AssignmentAddition { operator, .. }
+            | Self::AssignmentSubtraction { operator, .. }
+            | Self::AssignmentMultiplication { operator, .. }
+            | Self::AssignmentDivision { operator, .. }
+            | Self::AssignmentRemainder { operator, .. } => write!(f, "assignment {}", operator),
+
+            Self::Or => write!(f, "or"),
+            Self::OrShortCircuitStart => write!(f, "or short-circuit start"),
+            Self::OrShortCircuitEnd => write!(f, "or short-circuit end"),
+            Self::Xor => write!(f, "xor"),
+            Self::And => write!(f, "and"),
+            Self::AndShortCircuitStart => write!(f, "and short-circuit start"),
+            Self::AndShortCircuitEnd => write!(f, "and short-circuit end"),
+
+            Self::Equals { .. } => self.fmt_binary(f, "equals"),
+            Self::NotEquals { .. } => self.fmt_binary(f, "not equals"),
+            Self::GreaterEquals { .. } => self.fmt_binary(f, "greater equals"),
+            Self::LesserEquals { .. } => self.fmt_binary(f, "lesser equals"),
+            Self::Greater { .. } => self.fmt_binary(f, "greater"),
+            Self::Lesser { .. } => self.fmt_binary(f, "lesser"),
+            Self::EqualsComposite { size } => write!(f, "equals composite size {}", size),
+            Self::NotEqualsComposite { size } => write!(f, "not equals composite size {}", size),
+
+            Self::BitwiseOr { .. } => self.fmt_binary(f, "bitwise or"),
+            Self::BitwiseXor { .. } => self.fmt_binary(f, "bitwise xor"),
+            Self::BitwiseAnd { .. } => self.fmt_binary(f, "bitwise and"),
+            Self::BitwiseShiftLeft => write!(f, "bitwise shift left"),
+            Self::BitwiseShiftRight => write!(f, "bitwise shift right"),
+
+            Self::Addition { .. } => self.fmt_binary(f, "addition"),
+            Self::Subtraction { .. } => self.fmt_binary(f, "subtraction"),
+            Self::Multiplication { .. } => self.fmt_binary(f, "multiplication"),
+            Self::Division { .. } => self.fmt_binary(f, "division"),
+            Self::Remainder { .. } => self.fmt_binary(f, "remainder"),
+            Self::MultiplicationFixed {
+                r#type,
+                wide_type,
+                scale,
+            } => write!(
+                f,
+                "multiplication fixed {} via {} scale {}",
+                r#type, wide_type, scale
+            ),
+
+            Self::Casting { r#type } => write!(f, "casting {}", r#type),
+            Self::CastingToFixed { r#type, scale } => {
+                write!(f, "casting to fixed {} scale {}", r#type, scale)
+            }
+            Self::CastingFromFixed {
+                inner_type,
+                r#type,
+                scale,
+            } => write!(
+                f,
+                "casting from fixed {} to {} scale {}",
+                inner_type, r#type, scale
+            ),
+
+            Self::Not => write!(f, "not"),
+            Self::BitwiseNot => write!(f, "bitwise not"),
+            Self::Negation => write!(f, "negation"),
+
+            Self::Index { access, .. } => write!(
+                f,
+                "index size {}/{}",
+                access.element_size, access.total_size
+            ),
+            Self::Slice { access } => write!(
+                f,
+                "slice .{} at {} size {}/{}",
+                access.name, access.offset, access.element_size, access.total_size
+            ),
+
+            Self::Call {
+                type_id,
+                input_size,
+            } => write!(f, "call #{} input {}", type_id, input_size),
+            Self::CallWriteback {
+                type_id,
+                input_size,
+                ..
+            } => write!(f, "call #{} input {} writeback", type_id, input_size),
+            Self::CallDebug {
+                format,
+                argument_types,
+            } => write!(
+                f,
+                "call dbg {:?} ({})",
+                format,
+                Self::types_to_string(argument_types.as_slice())
+            ),
+            Self::CallRequire {
+                message,
+                argument_types,
+            } => write!(
+                f,
+                "call require {:?} ({})",
+                message.as_deref().unwrap_or_default(),
+                Self::types_to_string(argument_types.as_slice())
+            ),
+            Self::CallLibrary {
+                identifier,
+                input_size,
+                output_size,
+            } => write!(
+                f,
+                "call library {:?} input {} output {}",
+                identifier, input_size, output_size
+            ),
+        }
+    }
+}
+
+impl Operator {
+    ///
+    /// Formats a binary operator `name` with the integer literal inferred types, if any.
+    ///
+    fn fmt_binary(&self, f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+        match (
+            self.operand_1_inferred_type(),
+            self.operand_2_inferred_type(),
+        ) {
+            (None, None) => write!(f, "{}", name),
+            (operand_1, operand_2) => write!(
+                f,
+                "{} as ({}, {})",
+                name,
+                operand_1
+                    .map(|r#type| r#type.to_string())
+                    .unwrap_or_else(|| "_".to_owned()),
+                operand_2
+                    .map(|r#type| r#type.to_string())
+                    .unwrap_or_else(|| "_".to_owned()),
+            ),
+        }
+    }
+
+    ///
+    /// Joins the `types` into a comma-separated list.
+    ///
+    fn types_to_string(types: &[Type]) -> String {
+        types
+            .iter()
+            .map(|r#type| r#type.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
 }
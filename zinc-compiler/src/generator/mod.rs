@@ -4,6 +4,7 @@
 
 pub mod expression;
 pub mod module;
+pub mod printer;
 pub mod state;
 pub mod statement;
 pub mod r#type;
@@ -11,6 +12,7 @@ pub mod r#type;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use self::printer::Printer;
 use self::state::State;
 
 ///
@@ -22,3 +24,13 @@ pub trait IBytecodeWritable {
     ///
     fn write_all(self, state: Rc<RefCell<State>>);
 }
+
+///
+/// Implemented by items which are printed to the intermediate representation listing.
+///
+pub trait IIntermediatePrintable {
+    ///
+    /// Prints the item and its children to the `printer`.
+    ///
+    fn print(&self, printer: &mut Printer);
+}
@@ -5,9 +5,13 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::FILE_INDEX;
+
 use crate::generator::state::State;
 use crate::generator::statement::Statement;
 use crate::generator::IBytecodeWritable;
+use crate::timings::phase::Phase;
+use crate::timings::TIMINGS;
 
 ///
 /// The Zinc module, which is located in a separate file and consists of module-level statements.
@@ -29,8 +33,23 @@ impl Module {
 
 impl IBytecodeWritable for Module {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
+        if !TIMINGS.is_enabled() {
+            for statement in self.statements.into_iter() {
+                statement.write_all(bytecode.clone());
+            }
+            return;
+        }
+
         for statement in self.statements.into_iter() {
-            statement.write_all(bytecode.clone());
+            match statement.location() {
+                Some(location) => {
+                    let path = FILE_INDEX.get_path(location.file);
+                    TIMINGS.measure(path.to_string_lossy().as_ref(), Phase::Generation, || {
+                        statement.write_all(bytecode.clone())
+                    });
+                }
+                None => statement.write_all(bytecode.clone()),
+            }
         }
     }
 }
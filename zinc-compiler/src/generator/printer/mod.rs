@@ -0,0 +1,101 @@
+//!
+//! The generator intermediate representation and bytecode printer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use zinc_build::Instruction;
+use zinc_lexical::Location;
+
+use crate::generator::statement::Statement;
+use crate::generator::IIntermediatePrintable;
+
+///
+/// The intermediate representation and bytecode printer.
+///
+/// Each item is printed on a separate line, indented according to its depth in the tree and
+/// followed by its source code location, so the listings of two builds can be diffed.
+///
+#[derive(Debug, Default)]
+pub struct Printer {
+    /// The printed listing.
+    buffer: String,
+    /// The current nesting depth.
+    depth: usize,
+}
+
+impl Printer {
+    /// The indentation of a single nesting level.
+    const INDENT: &'static str = "    ";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Prints the intermediate representation listing of the application `statements`.
+    ///
+    /// The statements are sorted by their source code location, since their order in the
+    /// scope tree is not stable between compilations.
+    ///
+    pub fn intermediate(statements: &[Statement]) -> String {
+        let mut statements: Vec<&Statement> = statements.iter().collect();
+        statements.sort_by_key(|statement| {
+            statement
+                .location()
+                .map(|location| (location.file, location.line, location.column))
+        });
+
+        let mut printer = Self::new();
+        for statement in statements.into_iter() {
+            statement.print(&mut printer);
+        }
+        printer.buffer
+    }
+
+    ///
+    /// Prints the disassembly listing of the bytecode `instructions`.
+    ///
+    /// The debug instructions are omitted, except for the function markers, which are printed
+    /// as labels. The call instruction addresses are resolved into the function names.
+    ///
+    pub fn disassembly(instructions: &[Instruction]) -> String {
+        zinc_build::Listing::new(instructions).to_string()
+    }
+
+    ///
+    /// Prints a line at the current nesting depth.
+    ///
+    pub fn line<T: fmt::Display>(&mut self, text: T) {
+        for _ in 0..self.depth {
+            self.buffer.push_str(Self::INDENT);
+        }
+        self.buffer.push_str(text.to_string().as_str());
+        self.buffer.push('\n');
+    }
+
+    ///
+    /// Prints a line at the current nesting depth, followed by the source code `location`.
+    ///
+    pub fn line_at<T: fmt::Display>(&mut self, text: T, location: Location) {
+        self.line(format!("{} @ {}", text, location));
+    }
+
+    ///
+    /// Prints the children of the current item one nesting level deeper.
+    ///
+    pub fn nested<F>(&mut self, print: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.depth += 1;
+        print(self);
+        self.depth -= 1;
+    }
+}
@@ -0,0 +1,266 @@
+//!
+//! The generator intermediate representation and bytecode printer tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use num::BigInt;
+
+use zinc_build::Instruction;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::generator::module::Module;
+use crate::generator::state::State;
+use crate::generator::IBytecodeWritable;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::source::Source;
+
+use super::Printer;
+
+const CODE: &str = r#"
+contract Test {
+    pub data: u8;
+
+    pub fn main(mut self, value: u8) -> u8 {
+        self.data = Self::double(value) + 1;
+        self.data
+    }
+
+    fn double(value: u8) -> u8 {
+        value * 2
+    }
+}
+"#;
+
+const CODE_WRITEBACK: &str = r#"
+struct Counter {
+    value: u8,
+}
+
+impl Counter {
+    pub fn bump(mut self) -> u8 {
+        self.value += 1;
+        self.value
+    }
+}
+
+contract Test {
+    pub counters: [Counter; 2];
+
+    pub fn main(mut self, index: u8) -> u8 {
+        self.counters[index].bump()
+    }
+}
+"#;
+
+fn listings(code: &str) -> (String, String) {
+    let source = Source::test(code, PathBuf::from("test.zn"), HashMap::new())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let scope = EntryAnalyzer::define(source).expect(zinc_const::panic::TEST_DATA_VALID);
+    let intermediate = scope.borrow().get_intermediate();
+
+    let listing = Printer::intermediate(intermediate.as_slice());
+
+    let state = State::new(Manifest::new("test", ProjectType::Contract)).wrap();
+    Module::new(intermediate).write_all(state.clone());
+    let application = State::unwrap_rc(state)
+        .into_application(false, false)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    (listing, Printer::disassembly(application.instructions()))
+}
+
+fn push(value: usize) -> Instruction {
+    zinc_build::Push::new(BigInt::from(value), ScalarType::Integer(IntegerType::U8)).into()
+}
+
+#[test]
+fn ok_intermediate_sorted_by_location() {
+    let (intermediate, _) = listings(CODE);
+
+    let top_level: Vec<&str> = intermediate
+        .lines()
+        .filter(|line| !line.starts_with(' '))
+        .collect();
+
+    let main = top_level
+        .iter()
+        .position(|line| line.starts_with("fn main#"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let double = top_level
+        .iter()
+        .position(|line| line.starts_with("fn double#"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(top_level[0].starts_with("contract @ test.zn:2:"));
+    assert!(main < double);
+    assert!(top_level[main].contains(" -> u8 ["));
+    assert!(top_level[main].contains("entry"));
+    assert!(top_level[main].contains(" @ test.zn:5:"));
+    assert!(top_level[double].contains("(value: u8) -> u8 @ test.zn:10:"));
+}
+
+#[test]
+fn ok_intermediate_contract_fields() {
+    let (intermediate, _) = listings(CODE);
+
+    let fields: Vec<&str> = intermediate
+        .lines()
+        .skip(1)
+        .take_while(|line| line.starts_with(' '))
+        .map(str::trim)
+        .collect();
+
+    assert_eq!(
+        fields,
+        vec![
+            "pub address: u160 [implicit]",
+            "pub balances: std::collections::MTreeMap<u160, u248> [implicit]",
+            "pub layout_hash: u248 [implicit]",
+            "pub data: u8",
+        ]
+    );
+}
+
+#[test]
+fn ok_intermediate_operators() {
+    let (intermediate, _) = listings(CODE);
+
+    let operators: Vec<&str> = intermediate
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            line.starts_with("assignment")
+                || line.starts_with("addition")
+                || line.starts_with("multiplication")
+                || line.starts_with("call #")
+        })
+        .map(|line| line.split(" @ ").next().unwrap_or_default())
+        .collect();
+
+    assert_eq!(operators.len(), 4);
+    assert_eq!(operators[0], "assignment");
+    assert!(operators[1].starts_with("call #"));
+    assert!(operators[1].ends_with(" input 1"));
+    assert!(operators[2].starts_with("addition"));
+    assert!(operators[3].starts_with("multiplication"));
+}
+
+#[test]
+fn ok_intermediate_stable() {
+    let (intermediate_1, _) = listings(CODE);
+    let (intermediate_2, _) = listings(CODE);
+
+    let strip_type_ids = |listing: String| -> Vec<String> {
+        listing
+            .lines()
+            .map(|line| {
+                line.split(' ')
+                    .map(|word| match word.find('#') {
+                        Some(index) => &word[..index],
+                        None => word,
+                    })
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        strip_type_ids(intermediate_1),
+        strip_type_ids(intermediate_2)
+    );
+}
+
+#[test]
+fn ok_disassembly_call_resolved() {
+    let (_, disassembly) = listings(CODE);
+
+    assert!(disassembly.lines().any(|line| line == "main:"));
+    assert!(disassembly.lines().any(|line| line == "double:"));
+    assert!(disassembly
+        .lines()
+        .any(|line| line.starts_with("    ") && line.ends_with(" ; double")));
+    assert!(!disassembly.contains("marker"));
+}
+
+#[test]
+fn ok_disassembly_storage_method_writeback() {
+    let (intermediate, disassembly) = listings(CODE_WRITEBACK);
+
+    assert!(intermediate
+        .lines()
+        .map(str::trim)
+        .any(|line| line.starts_with("call #") && line.contains(" writeback @ ")));
+
+    let main: Vec<&str> = disassembly
+        .lines()
+        .skip_while(|line| *line != "main:")
+        .skip(1)
+        .take_while(|line| line.starts_with("    "))
+        .collect();
+    let call = main
+        .iter()
+        .position(|line| line.ends_with(" ; bump"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(main[call..]
+        .iter()
+        .any(|line| line.ends_with(" storage_store 2")));
+    assert_eq!(
+        disassembly.lines().filter(|line| *line == "bump:").count(),
+        2
+    );
+}
+
+#[test]
+fn ok_disassembly_snapshot() {
+    let instructions = vec![
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::FunctionMarker::new("main".to_owned()).into(),
+        zinc_build::LineMarker::new(2).into(),
+        zinc_build::Load::new(0, 1).into(),
+        push(1),
+        zinc_build::Call::new(7, 2).into(),
+        zinc_build::Exit::new(1).into(),
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::FunctionMarker::new("double".to_owned()).into(),
+        zinc_build::ColumnMarker::new(5).into(),
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::Add.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    let expected = r#"main:
+    0003 load 0 1
+    0004 push 1 as u8
+    0005 call 7 2 ; double
+    0006 exit 1
+double:
+    0010 load 0 1
+    0011 load 0 1
+    0012 add
+    0013 return 1
+"#;
+
+    assert_eq!(Printer::disassembly(instructions.as_slice()), expected);
+}
+
+#[test]
+fn ok_disassembly_unknown_call_target() {
+    let instructions = vec![
+        zinc_build::Call::new(42, 0).into(),
+        zinc_build::Exit::new(0).into(),
+    ];
+
+    let expected = r#"    0000 call 42 0 ; <unknown>
+    0001 exit 0
+"#;
+
+    assert_eq!(Printer::disassembly(instructions.as_slice()), expected);
+}
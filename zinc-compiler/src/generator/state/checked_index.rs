@@ -0,0 +1,93 @@
+//!
+//! The Zinc VM bytecode checked index.
+//!
+
+use zinc_build::Instruction;
+
+///
+/// The dynamic array index, whose bounds have been enforced by an array access.
+///
+/// The subsequent accesses to the same variable with the same index computation reuse the
+/// check until any data stack value the computation reads is reassigned or the control flow
+/// branches.
+///
+#[derive(Debug, PartialEq)]
+pub struct CheckedIndex {
+    /// The accessed variable data stack address.
+    pub address: usize,
+    /// The size of the loaded value.
+    pub value_size: usize,
+    /// The accessed variable total size.
+    pub total_size: usize,
+    /// The index computation instructions without the debug ones.
+    pub instructions: Vec<Instruction>,
+}
+
+impl CheckedIndex {
+    ///
+    /// Creates the checked index from the index computation `instructions`.
+    ///
+    /// Returns `None` if the computation is not a pure function of the data stack values,
+    /// or does not read any of them, that is, the index is constant and its check is free.
+    ///
+    pub fn new(
+        address: usize,
+        value_size: usize,
+        total_size: usize,
+        instructions: &[Instruction],
+    ) -> Option<Self> {
+        let mut is_dynamic = false;
+        let mut computation = Vec::with_capacity(instructions.len());
+        for instruction in instructions.iter() {
+            match instruction {
+                instruction if instruction.is_debug() => continue,
+                Instruction::Load(_) => is_dynamic = true,
+                Instruction::LoadByIndex(inner) => {
+                    is_dynamic = true;
+
+                    let mut inner = inner.to_owned();
+                    inner.is_index_checked = false;
+                    computation.push(Instruction::LoadByIndex(inner));
+                    continue;
+                }
+                Instruction::Push(_)
+                | Instruction::Cast(_)
+                | Instruction::Add(_)
+                | Instruction::Sub(_)
+                | Instruction::Mul(_) => {}
+                _ => return None,
+            }
+
+            computation.push(instruction.to_owned());
+        }
+
+        if !is_dynamic {
+            return None;
+        }
+
+        Some(Self {
+            address,
+            value_size,
+            total_size,
+            instructions: computation,
+        })
+    }
+
+    ///
+    /// Checks whether the index computation reads any data stack value in the range of
+    /// `size` values starting at `address`.
+    ///
+    pub fn depends_on(&self, address: usize, size: usize) -> bool {
+        self.instructions
+            .iter()
+            .any(|instruction| match instruction {
+                Instruction::Load(inner) => {
+                    inner.address < address + size && address < inner.address + inner.size
+                }
+                Instruction::LoadByIndex(inner) => {
+                    inner.address < address + size && address < inner.address + inner.total_size
+                }
+                _ => false,
+            })
+    }
+}
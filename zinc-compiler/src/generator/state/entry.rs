@@ -15,6 +15,16 @@ pub struct Entry {
     pub name: String,
     /// If the entry can mutate the contract storage state. Only for contracts.
     pub is_mutable: bool,
+    /// If the entry does not take `self` and cannot access the contract storage. Only for contracts.
+    pub is_associated: bool,
+    /// If the entry must receive a transfer. Only for contracts.
+    pub is_payable: bool,
+    /// The token the entry must receive, if specified. Only for contracts.
+    pub payable_token: Option<String>,
+    /// The storage field holding the only allowed sender, if specified. Only for contracts.
+    pub only: Option<String>,
+    /// The arguments declared with `#[public]`. Only for contracts.
+    pub public_arguments: Vec<String>,
     /// The entry function input arguments.
     pub input_fields: Vec<(String, bool, Type)>,
     /// The entry function result type.
@@ -25,10 +35,16 @@ impl Entry {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         type_id: usize,
         name: String,
         is_mutable: bool,
+        is_associated: bool,
+        is_payable: bool,
+        payable_token: Option<String>,
+        only: Option<String>,
+        public_arguments: Vec<String>,
         input_fields: Vec<(String, bool, Type)>,
         output_type: Type,
     ) -> Self {
@@ -36,6 +52,11 @@ impl Entry {
             type_id,
             name,
             is_mutable,
+            is_associated,
+            is_payable,
+            payable_token,
+            only,
+            public_arguments,
             input_fields,
             output_type,
         }
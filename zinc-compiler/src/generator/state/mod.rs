@@ -2,6 +2,10 @@
 //! The Zinc VM generator state.
 //!
 
+#[cfg(test)]
+mod tests;
+
+pub mod checked_index;
 pub mod entry;
 pub mod optimizer;
 pub mod unit_test;
@@ -10,7 +14,13 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use zinc_build::Abi;
+use zinc_build::AbiConstant;
+use zinc_build::AbiField;
+use zinc_build::AbiMethod;
+use zinc_build::AbiType;
 use zinc_build::Application as BuildApplication;
+use zinc_build::ContractFieldType as BuildContractFieldType;
 use zinc_build::ContractMethod;
 use zinc_build::Instruction;
 use zinc_build::Type as BuildType;
@@ -22,9 +32,14 @@ use zinc_manifest::ProjectType;
 
 use crate::generator::r#type::contract_field::ContractField as ContractFieldType;
 use crate::generator::r#type::Type;
+use crate::semantic::scope::item::r#type::index::INDEX as TYPE_INDEX;
 
+use self::checked_index::CheckedIndex;
 use self::entry::Entry;
+use self::optimizer::constant_pool::Optimizer as ConstantPoolOptimizer;
+use self::optimizer::dead_function_code_elimination::error::Error as DeadFunctionCodeEliminationError;
 use self::optimizer::dead_function_code_elimination::Optimizer as DeadFunctionCodeEliminationOptimizer;
+use self::optimizer::peephole::Optimizer as PeepholeOptimizer;
 use self::unit_test::UnitTest;
 
 ///
@@ -39,6 +54,10 @@ pub struct State {
     instructions: Vec<Instruction>,
     /// The contract storage structure.
     contract_storage: Option<Vec<ContractFieldType>>,
+    /// The contract metadata, which consists of the public string constants.
+    contract_metadata: HashMap<String, String>,
+    /// The contract public constants, which are written to the contract ABI.
+    contract_constants: Vec<AbiConstant>,
     /// Metadata of each application entry.
     entries: HashMap<usize, Entry>,
     /// Unit tests.
@@ -46,12 +65,19 @@ pub struct State {
 
     /// Bytecode addresses of the functions written to the bytecode.
     function_addresses: HashMap<usize, usize>,
+    /// Unique IDs of the `mut self` method variants, which also return the mutated instance.
+    writeback_type_ids: HashMap<usize, usize>,
     /// Data stack addresses of variables declared at runtime.
     variable_addresses: HashMap<String, usize>,
     /// The pointer which is reset at the beginning of each function.
     data_stack_pointer: usize,
+    /// The dynamic indexes, whose bounds have been enforced in the current straight-line block.
+    checked_indexes: Vec<CheckedIndex>,
     /// The location pointer used to pass debug information to the VM.
     current_location: Location,
+
+    /// The intermediate representation listing, if it has been requested.
+    intermediate: Option<String>,
 }
 
 impl State {
@@ -80,13 +106,19 @@ impl State {
 
             instructions: Vec::with_capacity(Self::INSTRUCTIONS_INITIAL_CAPACITY),
             contract_storage: None,
+            contract_metadata: HashMap::new(),
+            contract_constants: Vec::new(),
             entries: HashMap::with_capacity(Self::ENTRIES_INITIAL_CAPACITY),
             unit_tests: HashMap::with_capacity(Self::UNIT_TESTS_INITIAL_CAPACITY),
 
             function_addresses: HashMap::with_capacity(Self::FUNCTION_ADDRESSES_INITIAL_CAPACITY),
+            writeback_type_ids: HashMap::new(),
             variable_addresses: HashMap::with_capacity(Self::VARIABLE_ADDRESSES_INITIAL_CAPACITY),
             data_stack_pointer: 0,
+            checked_indexes: Vec::new(),
             current_location: Location::default(),
+
+            intermediate: None,
         }
     }
 
@@ -113,6 +145,24 @@ impl State {
         self.variable_addresses.get(name).copied()
     }
 
+    ///
+    /// Whether the project being compiled is a contract.
+    ///
+    pub fn is_contract(&self) -> bool {
+        matches!(self.manifest.project.r#type, ProjectType::Contract)
+    }
+
+    ///
+    /// Returns the unique ID of the `type_id` method variant, which returns the mutated `self`
+    /// instance after the method result, allocating the ID on the first request.
+    ///
+    pub fn writeback_type_id(&mut self, type_id: usize) -> usize {
+        *self
+            .writeback_type_ids
+            .entry(type_id)
+            .or_insert_with(|| TYPE_INDEX.next(format!("function #{} writeback", type_id)))
+    }
+
     ///
     /// Sets the contract storage field types.
     ///
@@ -120,6 +170,85 @@ impl State {
         self.contract_storage = Some(fields);
     }
 
+    ///
+    /// Sets the contract metadata.
+    ///
+    pub fn set_contract_metadata(&mut self, metadata: HashMap<String, String>) {
+        self.contract_metadata = metadata;
+    }
+
+    ///
+    /// Sets the contract public constants.
+    ///
+    pub fn set_contract_constants(&mut self, constants: Vec<AbiConstant>) {
+        self.contract_constants = constants;
+    }
+
+    ///
+    /// Builds the contract ABI document.
+    ///
+    /// Returns `None` if the application is not a contract.
+    ///
+    pub fn abi(&self) -> Option<Abi> {
+        let storage = self
+            .contract_storage
+            .as_ref()?
+            .iter()
+            .map(|field| {
+                let field: BuildContractFieldType = field.to_owned().into();
+                field.into()
+            })
+            .collect();
+
+        let methods = self
+            .entries
+            .values()
+            .map(|entry| {
+                let inputs = entry
+                    .input_fields
+                    .iter()
+                    .filter_map(|(name, _is_mutable, r#type)| match r#type {
+                        Type::Contract { .. } => None,
+                        r#type => {
+                            let r#type: BuildType = r#type.to_owned().into();
+                            Some(AbiField::new(name.to_owned(), r#type.into()))
+                        }
+                    })
+                    .collect();
+                let output: BuildType = entry.output_type.to_owned().into();
+
+                AbiMethod::new(
+                    entry.name.to_owned(),
+                    entry.is_mutable,
+                    entry.is_payable,
+                    inputs,
+                    AbiType::from(output),
+                )
+            })
+            .collect();
+
+        Some(Abi::new(
+            self.manifest.project.name.to_owned(),
+            methods,
+            storage,
+            self.contract_constants.to_owned(),
+        ))
+    }
+
+    ///
+    /// Sets the intermediate representation listing.
+    ///
+    pub fn set_intermediate(&mut self, listing: String) {
+        self.intermediate = Some(listing);
+    }
+
+    ///
+    /// Takes the intermediate representation listing, if it has been set.
+    ///
+    pub fn take_intermediate(&mut self) -> Option<String> {
+        self.intermediate.take()
+    }
+
     ///
     /// Starts a new function, resetting the data stack pointer and writing the
     /// function debug information.
@@ -128,6 +257,7 @@ impl State {
         let address = self.instructions.len();
         self.function_addresses.insert(type_id, address);
         self.data_stack_pointer = 0;
+        self.checked_indexes.clear();
 
         self.instructions
             .push(Instruction::FileMarker(zinc_build::FileMarker::new(
@@ -145,18 +275,28 @@ impl State {
                 Some(zinc_const::contract::TRANSACTION_VARIABLE_NAME.to_owned()),
                 zinc_const::contract::TRANSACTION_SIZE,
             );
+            self.define_variable(
+                Some(zinc_const::contract::ENVIRONMENT_VARIABLE_NAME.to_owned()),
+                zinc_const::contract::ENVIRONMENT_SIZE,
+            );
         }
     }
 
     ///
     /// Starts an entry function, saves its metadata and calls the `start_function` method.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn start_entry_function(
         &mut self,
         location: Location,
         type_id: usize,
         identifier: String,
         is_mutable: bool,
+        is_associated: bool,
+        is_payable: bool,
+        payable_token: Option<String>,
+        only: Option<String>,
+        public_arguments: Vec<String>,
         input_arguments: Vec<(String, bool, Type)>,
         output_type: Type,
     ) {
@@ -164,6 +304,11 @@ impl State {
             type_id,
             identifier.clone(),
             is_mutable,
+            is_associated,
+            is_payable,
+            payable_token,
+            only,
+            public_arguments,
             input_arguments,
             output_type,
         );
@@ -182,8 +327,15 @@ impl State {
         identifier: String,
         should_panic: bool,
         is_ignored: bool,
+        storage_fixture: Option<String>,
     ) {
-        let test = UnitTest::new(type_id, identifier.clone(), should_panic, is_ignored);
+        let test = UnitTest::new(
+            type_id,
+            identifier.clone(),
+            should_panic,
+            is_ignored,
+            storage_fixture,
+        );
         self.unit_tests.insert(type_id, test);
 
         self.start_function(location, type_id, identifier);
@@ -202,10 +354,67 @@ impl State {
         start_address
     }
 
+    ///
+    /// Returns the address of the next instruction to be written.
+    ///
+    pub fn next_instruction_address(&self) -> usize {
+        self.instructions.len()
+    }
+
+    ///
+    /// Registers the dynamic index of the variable at `address`, which has been computed by the
+    /// instructions written since `index_address` and is about to be checked by an array access.
+    ///
+    /// Returns `true` if the same index has been already checked in the current straight-line
+    /// block, so the access may omit the bounds check.
+    ///
+    pub fn check_index(
+        &mut self,
+        index_address: usize,
+        address: usize,
+        value_size: usize,
+        total_size: usize,
+    ) -> bool {
+        let checked_index = match CheckedIndex::new(
+            address,
+            value_size,
+            total_size,
+            &self.instructions[index_address..],
+        ) {
+            Some(checked_index) => checked_index,
+            None => return false,
+        };
+
+        if self.checked_indexes.contains(&checked_index) {
+            return true;
+        }
+
+        self.checked_indexes.push(checked_index);
+        false
+    }
+
     ///
     /// Writes the instruction along with its location debug information.
     ///
+    /// The checked indexes are invalidated if the instruction reassigns any value they depend on
+    /// or branches the control flow.
+    ///
     pub fn push_instruction(&mut self, instruction: Instruction, location: Option<Location>) {
+        match instruction {
+            Instruction::Store(ref inner) => self
+                .checked_indexes
+                .retain(|index| !index.depends_on(inner.address, inner.size)),
+            Instruction::StoreByIndex(ref inner) => self
+                .checked_indexes
+                .retain(|index| !index.depends_on(inner.address, inner.total_size)),
+            Instruction::If(_)
+            | Instruction::Else(_)
+            | Instruction::EndIf(_)
+            | Instruction::LoopBegin(_)
+            | Instruction::LoopEnd(_) => self.checked_indexes.clear(),
+            _ => {}
+        }
+
         if let Some(location) = location {
             if self.current_location != location {
                 if self.instructions.is_empty() || self.current_location.file != location.file {
@@ -239,10 +448,18 @@ impl State {
     /// Converts the compiled application state into a set of byte arrays, which are ready to be
     /// written to the Zinc project build files.
     ///
+    /// If the dead function code elimination is enabled, the functions unreachable from the
+    /// entries and unit tests are removed from the bytecode.
+    ///
     pub fn into_application(
         mut self,
         optimize_dead_function_elimination: bool,
-    ) -> BuildApplication {
+        optimize_peephole: bool,
+    ) -> Result<BuildApplication, DeadFunctionCodeEliminationError> {
+        if optimize_peephole {
+            PeepholeOptimizer::optimize(&mut self.instructions, &mut self.function_addresses);
+        }
+
         match self.contract_storage.take() {
             Some(storage) => {
                 let storage = storage.into_iter().map(|field| field.into()).collect();
@@ -264,7 +481,7 @@ impl State {
                         entry_ids,
                         &mut self.instructions,
                         &mut self.function_addresses,
-                    );
+                    )?;
                 } else {
                     DeadFunctionCodeEliminationOptimizer::set_addresses(
                         &mut self.instructions,
@@ -274,11 +491,12 @@ impl State {
 
                 let mut methods = HashMap::with_capacity(self.entries.len());
                 for (type_id, method) in self.entries.into_iter() {
-                    let address = self
-                        .function_addresses
-                        .get(&type_id)
-                        .cloned()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    let address = Self::function_address(
+                        &self.function_addresses,
+                        type_id,
+                        method.name.as_str(),
+                        "the contract method table",
+                    )?;
                     let mut input: BuildType = method.input_fields_as_struct().into();
                     input.remove_contract_instance();
                     let output = method.output_type.into();
@@ -289,6 +507,11 @@ impl State {
                             method.name,
                             address,
                             method.is_mutable,
+                            method.is_associated,
+                            method.is_payable,
+                            method.payable_token,
+                            method.only,
+                            method.public_arguments,
                             input,
                             output,
                         ),
@@ -297,26 +520,37 @@ impl State {
 
                 let mut unit_tests = HashMap::with_capacity(self.unit_tests.len());
                 for (type_id, unit_test) in self.unit_tests.into_iter() {
-                    let address = self
-                        .function_addresses
-                        .get(&type_id)
-                        .cloned()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    let address = Self::function_address(
+                        &self.function_addresses,
+                        type_id,
+                        unit_test.name.as_str(),
+                        "the unit test table",
+                    )?;
                     unit_tests.insert(
                         unit_test.name,
-                        BuildUnitTest::new(address, unit_test.should_panic, unit_test.is_ignored),
+                        BuildUnitTest::new(
+                            address,
+                            unit_test.should_panic,
+                            unit_test.is_ignored,
+                            unit_test.storage_fixture,
+                        ),
                     );
                 }
 
+                let constants = ConstantPoolOptimizer::optimize(self.instructions.as_mut_slice());
+
                 Self::print_instructions(self.instructions.as_slice());
 
-                BuildApplication::new_contract(
+                let mut application = BuildApplication::new_contract(
                     self.manifest.project.name,
                     storage,
                     methods,
+                    self.contract_metadata,
                     unit_tests,
                     self.instructions,
-                )
+                );
+                application.set_constants(constants);
+                Ok(application)
             }
             None => {
                 let (entry_id, entry) = self
@@ -340,7 +574,7 @@ impl State {
                         entry_ids,
                         &mut self.instructions,
                         &mut self.function_addresses,
-                    );
+                    )?;
                 } else {
                     DeadFunctionCodeEliminationOptimizer::set_addresses(
                         &mut self.instructions,
@@ -350,37 +584,68 @@ impl State {
 
                 let mut unit_tests = HashMap::with_capacity(self.unit_tests.len());
                 for (type_id, unit_test) in self.unit_tests.into_iter() {
-                    let address = self
-                        .function_addresses
-                        .get(&type_id)
-                        .cloned()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    let address = Self::function_address(
+                        &self.function_addresses,
+                        type_id,
+                        unit_test.name.as_str(),
+                        "the unit test table",
+                    )?;
                     unit_tests.insert(
                         unit_test.name,
-                        BuildUnitTest::new(address, unit_test.should_panic, unit_test.is_ignored),
+                        BuildUnitTest::new(
+                            address,
+                            unit_test.should_panic,
+                            unit_test.is_ignored,
+                            unit_test.storage_fixture,
+                        ),
                     );
                 }
 
-                let address = self
-                    .function_addresses
-                    .get(&entry_id)
-                    .cloned()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let address = Self::function_address(
+                    &self.function_addresses,
+                    entry_id,
+                    entry.name.as_str(),
+                    "the circuit entry",
+                )?;
+
+                let constants = ConstantPoolOptimizer::optimize(self.instructions.as_mut_slice());
 
                 Self::print_instructions(self.instructions.as_slice());
 
-                BuildApplication::new_circuit(
+                let mut application = BuildApplication::new_circuit(
                     self.manifest.project.name,
                     address,
                     input,
                     output,
                     unit_tests,
                     self.instructions,
-                )
+                );
+                application.set_constants(constants);
+                Ok(application)
             }
         }
     }
 
+    ///
+    /// Returns the address of the function referenced by the application metadata `referrer`.
+    ///
+    /// If the function has been removed by the dead function code elimination, an error is
+    /// returned, since the metadata would point to another function otherwise.
+    ///
+    fn function_address(
+        function_addresses: &HashMap<usize, usize>,
+        type_id: usize,
+        function: &str,
+        referrer: &str,
+    ) -> Result<usize, DeadFunctionCodeEliminationError> {
+        function_addresses.get(&type_id).copied().ok_or_else(|| {
+            DeadFunctionCodeEliminationError::FunctionEliminated {
+                function: function.to_owned(),
+                referrer: referrer.to_owned(),
+            }
+        })
+    }
+
     ///
     /// Prints the bytecode instructions to the terminal.
     ///
@@ -0,0 +1,78 @@
+//!
+//! The bytecode constant pool optimizer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use zinc_build::Instruction;
+use zinc_build::Push;
+
+///
+/// The constant pool optimization.
+///
+/// Large constants, e.g. ETH addresses, are often used many times across the application.
+/// Such constants are stored once in the application constant pool, and their `Push`
+/// instructions are replaced with the short `PushPooled` references.
+///
+pub struct Optimizer;
+
+impl Optimizer {
+    /// The minimal constant bitlength, starting from which the constants are pooled.
+    const BITLENGTH_THRESHOLD: u64 = 64;
+
+    /// The minimal number of the constant occurrences, starting from which it is pooled.
+    const OCCURRENCES_THRESHOLD: usize = 2;
+
+    ///
+    /// The algorithm works as follows:
+    ///
+    /// 1. Count the occurrences of each large constant in the `Push` instructions.
+    ///
+    /// 2. Put the constants, which occur often enough, to the pool in the order of their first
+    /// occurrence.
+    ///
+    /// 3. Replace the `Push` instructions of the pooled constants with `PushPooled` ones.
+    ///
+    /// The instructions are replaced one by one, so the function addresses are not shifted.
+    ///
+    /// Returns the constant pool.
+    ///
+    pub fn optimize(instructions: &mut [Instruction]) -> Vec<Push> {
+        let mut occurrences: Vec<(Push, usize)> = Vec::new();
+        for instruction in instructions.iter() {
+            if let Instruction::Push(push) = instruction {
+                if push.value.bits() < Self::BITLENGTH_THRESHOLD {
+                    continue;
+                }
+
+                match occurrences
+                    .iter_mut()
+                    .find(|(constant, _)| constant == push)
+                {
+                    Some((_, count)) => *count += 1,
+                    None => occurrences.push((push.to_owned(), 1)),
+                }
+            }
+        }
+
+        let constants: Vec<Push> = occurrences
+            .into_iter()
+            .filter(|(_, count)| *count >= Self::OCCURRENCES_THRESHOLD)
+            .map(|(constant, _)| constant)
+            .collect();
+
+        for instruction in instructions.iter_mut() {
+            let index = match instruction {
+                Instruction::Push(push) => constants.iter().position(|constant| constant == push),
+                _ => None,
+            };
+
+            if let Some(index) = index {
+                *instruction = zinc_build::PushPooled::new(index).into();
+            }
+        }
+
+        constants
+    }
+}
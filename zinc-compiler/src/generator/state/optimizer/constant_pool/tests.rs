@@ -0,0 +1,107 @@
+//!
+//! The bytecode constant pool optimizer tests.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::Instruction;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use super::Optimizer;
+
+const ADDRESS: &str = "deadbeefdeadbeefdeadbeefdeadbeefdeadbeef";
+
+fn push_address() -> Instruction {
+    zinc_build::Push::new(
+        BigInt::parse_bytes(ADDRESS.as_bytes(), zinc_const::base::HEXADECIMAL)
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        ScalarType::Integer(IntegerType::ETH_ADDRESS),
+    )
+    .into()
+}
+
+fn push_small(value: usize) -> Instruction {
+    zinc_build::Push::new(BigInt::from(value), ScalarType::Integer(IntegerType::U8)).into()
+}
+
+fn application(instructions: Vec<Instruction>) -> BuildApplication {
+    BuildApplication::new_circuit(
+        "test".to_owned(),
+        0,
+        BuildType::Unit,
+        BuildType::Unit,
+        HashMap::new(),
+        instructions,
+    )
+}
+
+#[test]
+fn ok_repeated_large_constant() {
+    let mut instructions = vec![
+        push_address(),
+        push_small(42),
+        push_address(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    let constants = Optimizer::optimize(instructions.as_mut_slice());
+
+    assert_eq!(constants.len(), 1);
+    assert_eq!(
+        instructions,
+        vec![
+            zinc_build::PushPooled::new(0).into(),
+            push_small(42),
+            zinc_build::PushPooled::new(0).into(),
+            zinc_build::Return::new(1).into(),
+        ]
+    );
+}
+
+#[test]
+fn ok_single_large_constant_not_pooled() {
+    let input = vec![push_address(), zinc_build::Return::new(1).into()];
+
+    let mut instructions = input.clone();
+    let constants = Optimizer::optimize(instructions.as_mut_slice());
+
+    assert!(constants.is_empty());
+    assert_eq!(instructions, input);
+}
+
+#[test]
+fn ok_small_constants_not_pooled() {
+    let input = vec![push_small(42), push_small(42), push_small(42)];
+
+    let mut instructions = input.clone();
+    let constants = Optimizer::optimize(instructions.as_mut_slice());
+
+    assert!(constants.is_empty());
+    assert_eq!(instructions, input);
+}
+
+#[test]
+fn ok_constant_heavy_binary_size() {
+    let input: Vec<Instruction> = (0..50)
+        .flat_map(|_| vec![push_address(), zinc_build::Eq.into()])
+        .collect();
+    let original_size = application(input.clone()).into_vec().len();
+
+    let mut instructions = input.clone();
+    let constants = Optimizer::optimize(instructions.as_mut_slice());
+    let mut optimized = application(instructions);
+    optimized.set_constants(constants);
+    let optimized_size = optimized.clone().into_vec().len();
+
+    assert!(optimized_size < original_size);
+
+    if let BuildApplication::Circuit(mut circuit) = optimized {
+        circuit.resolve_constants();
+        assert_eq!(circuit.instructions, input);
+    }
+}
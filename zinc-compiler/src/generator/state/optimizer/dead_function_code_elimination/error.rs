@@ -0,0 +1,31 @@
+//!
+//! The bytecode dead function code eliminator error.
+//!
+
+use std::fmt;
+
+///
+/// The bytecode dead function code eliminator error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// A function considered unreachable from the entries is still referenced after elimination.
+    FunctionEliminated {
+        /// The eliminated function name.
+        function: String,
+        /// The description of the place referencing the function.
+        referrer: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FunctionEliminated { function, referrer } => write!(
+                f,
+                "function `{}` has been eliminated as unreachable, but is still referenced by {}",
+                function, referrer
+            ),
+        }
+    }
+}
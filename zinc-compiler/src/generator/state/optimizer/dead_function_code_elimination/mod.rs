@@ -0,0 +1,197 @@
+//!
+//! The bytecode dead function code eliminator.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::Range;
+
+use petgraph::graph::Graph;
+use petgraph::visit::Dfs;
+
+use zinc_build::Instruction;
+
+use self::error::Error;
+
+///
+/// The dead function code elimination optimization.
+///
+/// Usually there are several entry points to an application. Each entry ends up with its own
+/// call graph, so we can remove the unused functions from the bytecode.
+///
+pub struct Optimizer;
+
+impl Optimizer {
+    ///
+    /// The algorithm works as follows:
+    ///
+    /// 1. Split the bytecode into the function bodies. Each body spans from the function address
+    /// to the address of the next function in the bytecode.
+    ///
+    /// 2. Populate the call graph using the `Call` instructions of each body, which contain the
+    /// callee function type IDs at this point.
+    ///
+    /// 3. Traverse the call graph from all the entries, e.g. the circuit `main` function,
+    /// the contract methods, and the unit tests, marking the reached functions.
+    ///
+    /// 4. Remove the bodies of the unreached functions from the bytecode and the address table,
+    /// shifting the addresses of functions which appear later in the bytecode.
+    ///
+    /// 5. Check that no eliminated function is referenced by the remaining `Call` instructions.
+    ///
+    /// 6. Replace the function type IDs in `Call` instructions with their shifted addresses.
+    ///
+    pub fn optimize(
+        entry_ids: Vec<usize>,
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &mut HashMap<usize, usize>,
+    ) -> Result<(), Error> {
+        let bodies = Self::bodies(instructions.as_slice(), function_addresses);
+
+        let mut graph = Graph::<usize, ()>::new();
+        let mut function_node_map = HashMap::with_capacity(bodies.len());
+        for (type_id, _body) in bodies.iter() {
+            function_node_map.insert(*type_id, graph.add_node(*type_id));
+        }
+        for (caller_id, body) in bodies.iter() {
+            let caller_node = function_node_map
+                .get(caller_id)
+                .copied()
+                .expect(zinc_const::panic::VALIDATED_DURING_TARGET_CODE_GENERATION);
+
+            for instruction in instructions[body.to_owned()].iter() {
+                if let Instruction::Call(zinc_build::Call {
+                    address: callee_id, ..
+                }) = instruction
+                {
+                    let callee_node = function_node_map
+                        .get(callee_id)
+                        .copied()
+                        .expect(zinc_const::panic::VALIDATED_DURING_TARGET_CODE_GENERATION);
+
+                    graph.update_edge(caller_node, callee_node, ());
+                }
+            }
+        }
+
+        let mut reachable = HashSet::with_capacity(bodies.len());
+        let mut dfs = Dfs::empty(&graph);
+        for entry_id in entry_ids.iter() {
+            dfs.move_to(
+                function_node_map
+                    .get(entry_id)
+                    .copied()
+                    .expect(zinc_const::panic::VALIDATED_DURING_TARGET_CODE_GENERATION),
+            );
+            while let Some(node) = dfs.next(&graph) {
+                reachable.insert(graph[node]);
+            }
+        }
+
+        let mut eliminated = HashMap::with_capacity(bodies.len() - reachable.len());
+        let mut removed_ranges = Vec::with_capacity(bodies.len() - reachable.len());
+        let mut removed_count = 0;
+        for (type_id, body) in bodies.into_iter() {
+            if reachable.contains(&type_id) {
+                function_addresses.insert(type_id, body.start - removed_count);
+            } else {
+                function_addresses.remove(&type_id);
+                eliminated.insert(
+                    type_id,
+                    Self::function_name(&instructions[body.to_owned()], type_id),
+                );
+                removed_count += body.len();
+                removed_ranges.push(body);
+            }
+        }
+
+        let mut address = 0;
+        instructions.retain(|_instruction| {
+            let is_removed = removed_ranges.iter().any(|range| range.contains(&address));
+            address += 1;
+            !is_removed
+        });
+
+        for (address, instruction) in instructions.iter().enumerate() {
+            if let Instruction::Call(zinc_build::Call {
+                address: callee_id, ..
+            }) = instruction
+            {
+                if let Some(function) = eliminated.get(callee_id) {
+                    return Err(Error::FunctionEliminated {
+                        function: function.to_owned(),
+                        referrer: format!("the call instruction at address {}", address),
+                    });
+                }
+            }
+        }
+
+        Self::set_addresses(instructions, function_addresses);
+
+        Ok(())
+    }
+
+    ///
+    /// Replaces the function type IDs in `Call` instructions with their addresses.
+    ///
+    pub fn set_addresses(
+        instructions: &mut [Instruction],
+        function_addresses: &HashMap<usize, usize>,
+    ) {
+        for instruction in instructions.iter_mut() {
+            if let Instruction::Call(zinc_build::Call {
+                address: ref mut type_id,
+                ..
+            }) = instruction
+            {
+                *type_id = function_addresses
+                    .get(type_id)
+                    .copied()
+                    .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+            }
+        }
+    }
+
+    ///
+    /// Returns the function bodies ordered by their addresses.
+    ///
+    /// The instructions preceding the first function do not belong to any body.
+    ///
+    fn bodies(
+        instructions: &[Instruction],
+        function_addresses: &HashMap<usize, usize>,
+    ) -> Vec<(usize, Range<usize>)> {
+        let mut functions: Vec<(usize, usize)> = function_addresses
+            .iter()
+            .map(|(type_id, address)| (*address, *type_id))
+            .collect();
+        functions.sort_unstable();
+
+        let mut bodies = Vec::with_capacity(functions.len());
+        for (index, (start_address, type_id)) in functions.iter().enumerate() {
+            let end_address = functions
+                .get(index + 1)
+                .map(|(address, _type_id)| *address)
+                .unwrap_or_else(|| instructions.len());
+            bodies.push((*type_id, *start_address..end_address));
+        }
+        bodies
+    }
+
+    ///
+    /// Returns the function name from its marker, or the type ID if the marker is missing.
+    ///
+    fn function_name(body: &[Instruction], type_id: usize) -> String {
+        body.iter()
+            .find_map(|instruction| match instruction {
+                Instruction::FunctionMarker(marker) => Some(marker.function.to_owned()),
+                _ => None,
+            })
+            .unwrap_or_else(|| format!("#{}", type_id))
+    }
+}
@@ -0,0 +1,105 @@
+//!
+//! The bytecode dead function code eliminator tests.
+//!
+
+use std::collections::HashMap;
+
+use zinc_build::Instruction;
+
+use super::Optimizer;
+
+const MAIN: usize = 10;
+const USED: usize = 11;
+const UNUSED: usize = 12;
+const UNUSED_CALLEE: usize = 13;
+const TEST: usize = 14;
+
+fn function(name: &str, calls: &[usize], is_entry: bool) -> Vec<Instruction> {
+    let mut instructions = vec![zinc_build::FunctionMarker::new(name.to_owned()).into()];
+    for callee_id in calls.iter() {
+        instructions.push(zinc_build::Call::new(*callee_id, 0).into());
+    }
+    instructions.push(if is_entry {
+        zinc_build::Exit::new(0).into()
+    } else {
+        zinc_build::Return::new(0).into()
+    });
+    instructions
+}
+
+fn application(
+    functions: Vec<(usize, Vec<Instruction>)>,
+) -> (Vec<Instruction>, HashMap<usize, usize>) {
+    let mut instructions = Vec::new();
+    let mut function_addresses = HashMap::new();
+    for (type_id, body) in functions.into_iter() {
+        function_addresses.insert(type_id, instructions.len());
+        instructions.extend(body);
+    }
+    (instructions, function_addresses)
+}
+
+#[test]
+fn ok_unreachable_removed() {
+    let (mut instructions, mut function_addresses) = application(vec![
+        (UNUSED, function("unused", &[UNUSED_CALLEE, USED], false)),
+        (MAIN, function("main", &[USED], true)),
+        (UNUSED_CALLEE, function("unused_callee", &[], false)),
+        (USED, function("used", &[], false)),
+    ]);
+
+    Optimizer::optimize(vec![MAIN], &mut instructions, &mut function_addresses)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let expected_instructions: Vec<Instruction> = vec![
+        zinc_build::FunctionMarker::new("main".to_owned()).into(),
+        zinc_build::Call::new(3, 0).into(),
+        zinc_build::Exit::new(0).into(),
+        zinc_build::FunctionMarker::new("used".to_owned()).into(),
+        zinc_build::Return::new(0).into(),
+    ];
+    let mut expected_function_addresses = HashMap::new();
+    expected_function_addresses.insert(MAIN, 0);
+    expected_function_addresses.insert(USED, 3);
+
+    assert_eq!(instructions, expected_instructions);
+    assert_eq!(function_addresses, expected_function_addresses);
+}
+
+#[test]
+fn ok_unit_test_callees_kept() {
+    let (mut instructions, mut function_addresses) = application(vec![
+        (MAIN, function("main", &[], true)),
+        (USED, function("used", &[], false)),
+        (TEST, function("test", &[USED], true)),
+    ]);
+
+    Optimizer::optimize(vec![MAIN, TEST], &mut instructions, &mut function_addresses)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(instructions.len(), 7);
+    assert_eq!(function_addresses.get(&USED).copied(), Some(2));
+    assert_eq!(
+        instructions[5],
+        Instruction::Call(zinc_build::Call::new(2, 0))
+    );
+}
+
+#[test]
+fn ok_unreachable_recursion_removed() {
+    let (mut instructions, mut function_addresses) = application(vec![
+        (MAIN, function("main", &[MAIN], true)),
+        (UNUSED, function("unused", &[UNUSED_CALLEE], false)),
+        (UNUSED_CALLEE, function("unused_callee", &[UNUSED], false)),
+    ]);
+
+    Optimizer::optimize(vec![MAIN], &mut instructions, &mut function_addresses)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(instructions.len(), 3);
+    assert_eq!(function_addresses.len(), 1);
+    assert_eq!(
+        instructions[1],
+        Instruction::Call(zinc_build::Call::new(0, 0))
+    );
+}
@@ -2,4 +2,6 @@
 //! The bytecode optimizers.
 //!
 
+pub mod constant_pool;
 pub mod dead_function_code_elimination;
+pub mod peephole;
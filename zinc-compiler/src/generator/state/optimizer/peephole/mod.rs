@@ -0,0 +1,213 @@
+//!
+//! The bytecode peephole optimizer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+
+use num::BigInt;
+use num::One;
+use num::Signed;
+use num::Zero;
+
+use zinc_build::Instruction;
+use zinc_build::ScalarType;
+
+///
+/// The peephole optimization pattern.
+///
+/// Each pattern matches two adjacent instructions. The debug location markers between the
+/// instructions are ignored, since they do not affect the evaluation stack.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    /// `Push 0; Add` and `Push 0; Sub` are removed.
+    AdditiveIdentity,
+    /// `Push 1; Mul` and `Push 1; Div` are removed.
+    MultiplicativeIdentity,
+    /// `Not; Not` is removed.
+    DoubleNot,
+    /// `Cast T; Cast T` is replaced with `Cast T`.
+    RepeatedCast,
+    /// `Push c; Cast T` is replaced with `Push c: T`, if the constant `c` fits into `T`.
+    ConstantCast,
+    /// `Store x; Load x` of a single cell is replaced with `Copy; Store x`.
+    StoreLoad,
+}
+
+impl Pattern {
+    /// All the patterns applied by default.
+    pub const ALL: [Self; 6] = [
+        Self::AdditiveIdentity,
+        Self::MultiplicativeIdentity,
+        Self::DoubleNot,
+        Self::RepeatedCast,
+        Self::ConstantCast,
+        Self::StoreLoad,
+    ];
+
+    ///
+    /// Returns the replacement of the `first` and `second` instructions, if they match the
+    /// pattern. The deleted instructions are replaced with `NoOperation`.
+    ///
+    fn rewrite(
+        &self,
+        first: &Instruction,
+        second: &Instruction,
+    ) -> Option<(Instruction, Instruction)> {
+        let no_operation = || Instruction::NoOperation(zinc_build::NoOperation);
+
+        match (self, first, second) {
+            (Self::AdditiveIdentity, Instruction::Push(push), Instruction::Add(_))
+            | (Self::AdditiveIdentity, Instruction::Push(push), Instruction::Sub(_))
+                if push.value.is_zero() =>
+            {
+                Some((no_operation(), no_operation()))
+            }
+            (Self::MultiplicativeIdentity, Instruction::Push(push), Instruction::Mul(_))
+            | (Self::MultiplicativeIdentity, Instruction::Push(push), Instruction::Div(_))
+                if push.value.is_one() =>
+            {
+                Some((no_operation(), no_operation()))
+            }
+            (Self::DoubleNot, Instruction::Not(_), Instruction::Not(_)) => {
+                Some((no_operation(), no_operation()))
+            }
+            (Self::RepeatedCast, Instruction::Cast(first), Instruction::Cast(second))
+                if first == second =>
+            {
+                Some((no_operation(), Instruction::Cast(second.to_owned())))
+            }
+            (Self::ConstantCast, Instruction::Push(push), Instruction::Cast(cast))
+                if Self::is_in_range(&push.value, &cast.r#type) =>
+            {
+                Some((
+                    Instruction::Push(zinc_build::Push::new(
+                        push.value.to_owned(),
+                        cast.r#type.to_owned(),
+                    )),
+                    no_operation(),
+                ))
+            }
+            (Self::StoreLoad, Instruction::Store(store), Instruction::Load(load))
+                if store.size == 1 && store.address == load.address && store.size == load.size =>
+            {
+                Some((
+                    Instruction::Copy(zinc_build::Copy),
+                    Instruction::Store(store.to_owned()),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks if the constant `value` fits into the scalar `type`, so the cast cannot fail.
+    ///
+    fn is_in_range(value: &BigInt, r#type: &ScalarType) -> bool {
+        match r#type {
+            ScalarType::Boolean => value.is_zero() || value.is_one(),
+            ScalarType::Integer(inner) => value >= &inner.min() && value <= &inner.max(),
+            ScalarType::Field => !value.is_negative(),
+        }
+    }
+}
+
+///
+/// The peephole optimization.
+///
+/// Rewrites the adjacent instruction patterns, which do not change the evaluation stack state,
+/// but waste constraints.
+///
+pub struct Optimizer;
+
+impl Optimizer {
+    ///
+    /// Applies all the patterns.
+    ///
+    /// Must be called before the function type IDs in `Call` instructions are replaced with
+    /// their addresses, as only the function start addresses are shifted after the deletions.
+    ///
+    pub fn optimize(
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &mut HashMap<usize, usize>,
+    ) {
+        Self::optimize_with_patterns(&Pattern::ALL, instructions, function_addresses)
+    }
+
+    ///
+    /// The algorithm works as follows:
+    ///
+    /// 1. Find the pairs of adjacent non-debug instructions matching any of the `patterns`.
+    ///
+    /// 2. Write the replacement instructions to the pair places, where the deleted ones are
+    ///    replaced with `NoOperation` instructions.
+    ///
+    /// 3. Repeat the steps above until nothing is rewritten, since a deletion can make another
+    ///    pair of instructions adjacent, e.g. in `Not; Not; Not; Not`.
+    ///
+    /// 4. Filter out the `NoOperation` instructions, shifting the function start addresses.
+    ///
+    pub fn optimize_with_patterns(
+        patterns: &[Pattern],
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &mut HashMap<usize, usize>,
+    ) {
+        while Self::rewrite(patterns, instructions) {}
+
+        let mut shifted_addresses = Vec::with_capacity(instructions.len() + 1);
+        let mut address = 0;
+        for instruction in instructions.iter() {
+            shifted_addresses.push(address);
+            if !matches!(instruction, Instruction::NoOperation(_)) {
+                address += 1;
+            }
+        }
+        shifted_addresses.push(address);
+
+        for address in function_addresses.values_mut() {
+            *address = shifted_addresses[*address];
+        }
+        instructions.retain(|instruction| !matches!(instruction, Instruction::NoOperation(_)));
+    }
+
+    ///
+    /// Makes a single rewriting pass over the `instructions`.
+    ///
+    /// Returns `true` if anything has been rewritten.
+    ///
+    fn rewrite(patterns: &[Pattern], instructions: &mut [Instruction]) -> bool {
+        let indexes: Vec<usize> = instructions
+            .iter()
+            .enumerate()
+            .filter(|(_index, instruction)| {
+                !instruction.is_debug() && !matches!(instruction, Instruction::NoOperation(_))
+            })
+            .map(|(index, _instruction)| index)
+            .collect();
+
+        let mut is_rewritten = false;
+        let mut position = 0;
+        while position + 1 < indexes.len() {
+            let (first, second) = (indexes[position], indexes[position + 1]);
+
+            let replacement = patterns
+                .iter()
+                .find_map(|pattern| pattern.rewrite(&instructions[first], &instructions[second]));
+
+            match replacement {
+                Some((first_replacement, second_replacement)) => {
+                    instructions[first] = first_replacement;
+                    instructions[second] = second_replacement;
+
+                    is_rewritten = true;
+                    position += 2;
+                }
+                None => position += 1,
+            }
+        }
+        is_rewritten
+    }
+}
@@ -0,0 +1,218 @@
+//!
+//! The bytecode peephole optimizer tests.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use zinc_build::Instruction;
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+
+use super::Optimizer;
+use super::Pattern;
+
+fn push(value: usize) -> Instruction {
+    zinc_build::Push::new(BigInt::from(value), ScalarType::Integer(IntegerType::U8)).into()
+}
+
+fn optimize(mut instructions: Vec<Instruction>) -> Vec<Instruction> {
+    Optimizer::optimize(&mut instructions, &mut HashMap::new());
+    instructions
+}
+
+#[test]
+fn ok_additive_identity() {
+    let input = vec![
+        zinc_build::Load::new(0, 1).into(),
+        push(0),
+        zinc_build::Add.into(),
+        push(0),
+        zinc_build::Sub.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    let expected = vec![
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_multiplicative_identity_with_markers() {
+    let input = vec![
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::LineMarker::new(2).into(),
+        push(1),
+        zinc_build::ColumnMarker::new(5).into(),
+        zinc_build::Mul.into(),
+        push(1),
+        zinc_build::Div.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    let expected = vec![
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::LineMarker::new(2).into(),
+        zinc_build::ColumnMarker::new(5).into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_double_not_chain() {
+    let input = vec![
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::Not.into(),
+        zinc_build::Not.into(),
+        zinc_build::Not.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    let expected = vec![
+        zinc_build::Load::new(0, 1).into(),
+        zinc_build::Not.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_repeated_cast() {
+    let input = vec![
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U16)).into(),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U16)).into(),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U8)).into(),
+    ];
+
+    let expected = vec![
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U16)).into(),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U8)).into(),
+    ];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_constant_cast_then_identity() {
+    let input = vec![
+        zinc_build::Load::new(0, 1).into(),
+        push(0),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U8)).into(),
+        zinc_build::Add.into(),
+        push(1),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U16)).into(),
+        zinc_build::Mul.into(),
+    ];
+
+    let expected = vec![zinc_build::Load::new(0, 1).into()];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_constant_cast_out_of_range_kept() {
+    let input = vec![
+        zinc_build::Push::new(BigInt::from(-1), ScalarType::Integer(IntegerType::I8)).into(),
+        zinc_build::Cast::new(ScalarType::Integer(IntegerType::U8)).into(),
+        zinc_build::Push::new(BigInt::from(-1), ScalarType::Integer(IntegerType::I8)).into(),
+        zinc_build::Cast::new(ScalarType::Field).into(),
+    ];
+
+    let expected = input.clone();
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_store_load() {
+    let input = vec![
+        zinc_build::Store::new(3, 1).into(),
+        zinc_build::Load::new(3, 1).into(),
+        zinc_build::Store::new(4, 2).into(),
+        zinc_build::Load::new(4, 2).into(),
+    ];
+
+    let expected = vec![
+        zinc_build::Copy.into(),
+        zinc_build::Store::new(3, 1).into(),
+        zinc_build::Store::new(4, 2).into(),
+        zinc_build::Load::new(4, 2).into(),
+    ];
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_non_identity_kept() {
+    let input = vec![
+        push(0),
+        zinc_build::Mul.into(),
+        push(2),
+        zinc_build::Add.into(),
+        push(1),
+        zinc_build::Sub.into(),
+    ];
+
+    let expected = input.clone();
+
+    assert_eq!(optimize(input), expected);
+}
+
+#[test]
+fn ok_function_addresses_shifted() {
+    let mut instructions = vec![
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::Call::new(2, 0).into(),
+        push(0),
+        zinc_build::Add.into(),
+        zinc_build::Return::new(1).into(),
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::Not.into(),
+        zinc_build::Not.into(),
+        zinc_build::Return::new(1).into(),
+    ];
+    let mut function_addresses = HashMap::new();
+    function_addresses.insert(1, 0);
+    function_addresses.insert(2, 5);
+
+    Optimizer::optimize(&mut instructions, &mut function_addresses);
+
+    let expected: Vec<Instruction> = vec![
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::Call::new(2, 0).into(),
+        zinc_build::Return::new(1).into(),
+        zinc_build::FileMarker::new("main.zn".to_owned()).into(),
+        zinc_build::Return::new(1).into(),
+    ];
+
+    assert_eq!(instructions, expected);
+    assert_eq!(function_addresses.get(&1).copied(), Some(0));
+    assert_eq!(function_addresses.get(&2).copied(), Some(3));
+}
+
+#[test]
+fn ok_configured_patterns_only() {
+    let mut instructions = vec![
+        push(0),
+        zinc_build::Add.into(),
+        zinc_build::Not.into(),
+        zinc_build::Not.into(),
+    ];
+
+    Optimizer::optimize_with_patterns(
+        &[Pattern::DoubleNot],
+        &mut instructions,
+        &mut HashMap::new(),
+    );
+
+    let expected: Vec<Instruction> = vec![push(0), zinc_build::Add.into()];
+
+    assert_eq!(instructions, expected);
+}
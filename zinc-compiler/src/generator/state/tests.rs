@@ -0,0 +1,369 @@
+//!
+//! The generator state tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use zinc_build::Application;
+use zinc_build::Instruction;
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::generator::module::Module;
+use crate::generator::state::State;
+use crate::generator::IBytecodeWritable;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::source::Source;
+
+fn state(code: &str, project_type: ProjectType) -> State {
+    let source = Source::test(code, PathBuf::from("test.zn"), HashMap::new())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let scope = EntryAnalyzer::define(source).expect(zinc_const::panic::TEST_DATA_VALID);
+    let intermediate = scope.borrow().get_intermediate();
+
+    let state = State::new(Manifest::new("test", project_type)).wrap();
+    Module::new(intermediate).write_all(state.clone());
+    State::unwrap_rc(state)
+}
+
+fn index_checks(code: &str) -> Vec<bool> {
+    state(code, ProjectType::Circuit)
+        .instructions
+        .into_iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::LoadByIndex(inner) => Some(inner.is_index_checked),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn ok_abi() {
+    let code = r#"
+enum Kind {
+    A = 1,
+    B = 2,
+}
+
+struct Point {
+    x: u8,
+    kind: Kind,
+}
+
+contract Test {
+    pub points: [Point; 2];
+    value: field;
+
+    pub const NAME: str = "Test";
+    pub const ORIGIN: Point = Point { x: 0, kind: Kind::B };
+    pub const LIMITS: [u8; 2] = [10, 20];
+    const HIDDEN: u8 = 42;
+
+    pub fn new(initial: field) -> Self {
+        Self {
+            points: [Point { x: 0, kind: Kind::A }; 2],
+            value: initial,
+        }
+    }
+
+    pub fn set(mut self, index: u8, point: Point) {
+        self.points[index] = point;
+    }
+
+    pub fn get(self) -> (field, bool) {
+        (self.value, Self::helper())
+    }
+
+    fn helper() -> bool {
+        true
+    }
+}
+"#;
+
+    let abi = state(code, ProjectType::Contract)
+        .abi()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let abi = serde_json::to_value(abi).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let point = json!({
+        "kind": "structure",
+        "fields": [
+            { "name": "x", "type": { "kind": "integer", "is_signed": false, "bitlength": 8 } },
+            {
+                "name": "kind",
+                "type": {
+                    "kind": "enumeration",
+                    "bitlength": 8,
+                    "variants": [
+                        { "name": "A", "value": "1" },
+                        { "name": "B", "value": "2" },
+                    ],
+                },
+            },
+        ],
+    });
+
+    assert_eq!(abi["version"], json!(1));
+    assert_eq!(abi["name"], json!("test"));
+    assert_eq!(
+        abi["methods"][0],
+        json!({
+            "name": "get",
+            "is_mutable": false,
+            "is_payable": false,
+            "inputs": [],
+            "output": {
+                "kind": "tuple",
+                "elements": [{ "kind": "field" }, { "kind": "boolean" }],
+            },
+        })
+    );
+    assert_eq!(abi["methods"][1]["name"], json!("new"));
+    assert_eq!(
+        abi["methods"][1]["inputs"],
+        json!([{ "name": "initial", "type": { "kind": "field" } }])
+    );
+    assert_eq!(
+        abi["methods"][2],
+        json!({
+            "name": "set",
+            "is_mutable": true,
+            "is_payable": false,
+            "inputs": [
+                { "name": "index", "type": { "kind": "integer", "is_signed": false, "bitlength": 8 } },
+                { "name": "point", "type": point },
+            ],
+            "output": { "kind": "unit" },
+        })
+    );
+    assert_eq!(
+        abi["storage"]
+            .as_array()
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+            .iter()
+            .map(|field| (field["name"].clone(), field["is_implicit"].clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (json!("address"), json!(true)),
+            (json!("balances"), json!(true)),
+            (json!("layout_hash"), json!(true)),
+            (json!("points"), json!(false)),
+            (json!("value"), json!(false)),
+        ]
+    );
+    assert_eq!(
+        abi["constants"],
+        json!([
+            {
+                "name": "LIMITS",
+                "type": {
+                    "kind": "array",
+                    "element": { "kind": "integer", "is_signed": false, "bitlength": 8 },
+                    "size": 2,
+                },
+                "value": ["10", "20"],
+            },
+            { "name": "NAME", "type": { "kind": "string" }, "value": "Test" },
+            {
+                "name": "ORIGIN",
+                "type": point,
+                "value": { "x": "0", "kind": "B" },
+            },
+        ])
+    );
+}
+
+#[test]
+fn ok_abi_circuit() {
+    let code = r#"
+fn main(value: u8) -> u8 {
+    value
+}
+"#;
+
+    assert!(state(code, ProjectType::Circuit).abi().is_none());
+}
+
+#[test]
+fn ok_contract_associated_method() {
+    let code = r#"
+contract Test {
+    value: u8;
+
+    pub fn new(initial: u8) -> Self {
+        Self { value: initial }
+    }
+
+    pub fn version(mut base: u8) -> u8 {
+        base += 1;
+        base
+    }
+
+    pub fn get(self) -> u8 {
+        self.value
+    }
+
+    pub fn set(mut self, other: u8) {
+        self.value = other;
+    }
+}
+"#;
+
+    let contract = match state(code, ProjectType::Contract)
+        .into_application(false, false)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+    {
+        Application::Contract(contract) => contract,
+        Application::Circuit(_) => panic!(zinc_const::panic::TEST_DATA_VALID),
+    };
+    let flags = |name: &str| {
+        let method = &contract.methods[name];
+        (method.is_mutable, method.is_associated)
+    };
+
+    assert_eq!(flags("new"), (false, true));
+    assert_eq!(flags("version"), (false, true));
+    assert_eq!(flags("get"), (false, false));
+    assert_eq!(flags("set"), (true, false));
+}
+
+#[test]
+fn ok_checked_index_repeated() {
+    let code = r#"
+fn main(array: [u8; 4], other: [u8; 4], index: u8) -> u8 {
+    let a = array[index];
+    let b = array[index];
+    let c = other[index];
+    let d = array[index + 1];
+    a + b + c + d + array[index] + array[index + 1]
+}
+"#;
+
+    assert_eq!(
+        index_checks(code),
+        vec![false, true, false, false, true, true]
+    );
+}
+
+#[test]
+fn ok_checked_index_reassigned() {
+    let code = r#"
+fn main(array: [u8; 4], index: u8) -> u8 {
+    let mut position = index;
+    let a = array[position];
+    position = 3 - index;
+    let b = array[position];
+    let c = array[position];
+    a + b + c
+}
+"#;
+
+    assert_eq!(index_checks(code), vec![false, false, true]);
+}
+
+#[test]
+fn ok_checked_index_branched() {
+    let code = r#"
+fn main(array: [u8; 4], index: u8, flag: bool) -> u8 {
+    let a = array[index];
+    let b = if flag { array[index] + array[index] } else { 0 };
+    a + b + array[index]
+}
+"#;
+
+    assert_eq!(index_checks(code), vec![false, false, true, false]);
+}
+
+#[test]
+fn ok_checked_index_looped() {
+    let code = r#"
+fn main(array: [u8; 4], index: u8) -> u8 {
+    let mut sum = array[index];
+    for i in 0..4 {
+        sum += array[index] + array[i] + array[i];
+    }
+    sum + array[index]
+}
+"#;
+
+    assert_eq!(index_checks(code), vec![false, false, false, true, false]);
+}
+
+fn match_16_arms(last: usize) -> String {
+    let arms = (0..15)
+        .chain(std::iter::once(last))
+        .map(|discriminant| format!("        {} => {},", discriminant, discriminant * 2 + 1))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!(
+        r#"
+fn main(value: u8) -> u8 {{
+    match value {{
+{}
+        _ => 0,
+    }}
+}}
+"#,
+        arms
+    )
+}
+
+#[test]
+fn ok_match_dispatch_table_16_arms() {
+    let count = |instructions: &[Instruction]| {
+        let dispatches = instructions
+            .iter()
+            .filter(|instruction| matches!(instruction, Instruction::Dispatch(_)))
+            .count();
+        let conditions = instructions
+            .iter()
+            .filter(|instruction| matches!(instruction, Instruction::If(_)))
+            .count();
+        (dispatches, conditions)
+    };
+
+    let dense = state(match_16_arms(15).as_str(), ProjectType::Circuit).instructions;
+    let sparse = state(match_16_arms(16).as_str(), ProjectType::Circuit).instructions;
+
+    assert_eq!(count(dense.as_slice()), (1, 0));
+    assert_eq!(count(sparse.as_slice()), (0, 16));
+    assert!(dense.len() < sparse.len());
+}
+
+#[test]
+fn ok_newtype_zero_overhead() {
+    let newtype = r#"
+struct Wei(u248);
+
+fn main(a: Wei, b: u248) -> (Wei, bool) {
+    let mut c = a + Wei(b) - a * a / Wei(b);
+    c += a;
+    (c, c.0 > a.0 && a == c)
+}
+"#;
+    let inner = r#"
+fn main(a: u248, b: u248) -> (u248, bool) {
+    let mut c = a + b - a * a / b;
+    c += a;
+    (c, c > a && a == c)
+}
+"#;
+
+    // the line and column markers differ, since the sources are laid out differently
+    let instructions = |code: &str| -> Vec<Instruction> {
+        state(code, ProjectType::Circuit)
+            .instructions
+            .into_iter()
+            .filter(|instruction| !instruction.is_debug())
+            .collect()
+    };
+    let newtype = instructions(newtype);
+    let inner = instructions(inner);
+
+    assert_eq!(newtype.len(), inner.len());
+    assert_eq!(newtype, inner);
+}
@@ -15,18 +15,27 @@ pub struct UnitTest {
     pub should_panic: bool,
     /// Whether the test is marked as ignored.
     pub is_ignored: bool,
+    /// The initial contract storage fixture path relative to the project.
+    pub storage_fixture: Option<String>,
 }
 
 impl UnitTest {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(type_id: usize, name: String, should_panic: bool, is_ignored: bool) -> Self {
+    pub fn new(
+        type_id: usize,
+        name: String,
+        should_panic: bool,
+        is_ignored: bool,
+        storage_fixture: Option<String>,
+    ) -> Self {
         Self {
             type_id,
             name,
             should_panic,
             is_ignored,
+            storage_fixture,
         }
     }
 }
@@ -3,13 +3,26 @@
 //!
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use zinc_build::AbiConstant;
+use zinc_build::AbiType;
+use zinc_build::Type as BuildType;
+use zinc_lexical::Location;
+
+use crate::generator::printer::Printer;
 use crate::generator::r#type::contract_field::ContractField as ContractFieldType;
+use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::contract::field::Field as SemanticContractFieldType;
-use zinc_lexical::Location;
+use crate::semantic::element::r#type::i_typed::ITyped;
 
 ///
 /// The Zinc VM storage memory allocating statement.
@@ -20,25 +33,131 @@ pub struct Statement {
     pub location: Location,
     /// The contract storage fields ordered array.
     pub fields: Vec<ContractFieldType>,
+    /// The contract metadata, which consists of the public string constants.
+    pub metadata: HashMap<String, String>,
+    /// The contract public constants, which are written to the contract ABI.
+    pub constants: Vec<AbiConstant>,
 }
 
 impl Statement {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(location: Location, fields: Vec<SemanticContractFieldType>) -> Self {
+    pub fn new(
+        location: Location,
+        fields: Vec<SemanticContractFieldType>,
+        metadata: HashMap<String, String>,
+        constants: Vec<(String, Constant)>,
+    ) -> Self {
         Self {
             location,
             fields: fields
                 .into_iter()
                 .filter_map(|field| ContractFieldType::try_from_semantic(&field))
                 .collect(),
+            metadata,
+            constants: constants
+                .into_iter()
+                .filter_map(|(name, constant)| Self::abi_constant(name, &constant))
+                .collect(),
         }
     }
+
+    ///
+    /// Converts the public constant into the contract ABI entry.
+    ///
+    /// Returns `None` for the constants, which cannot be represented in the ABI, e.g. ranges.
+    ///
+    fn abi_constant(name: String, constant: &Constant) -> Option<AbiConstant> {
+        let r#type = match constant {
+            Constant::String(_) => AbiType::String,
+            constant => {
+                let r#type: BuildType = Type::try_from_semantic(&constant.r#type())?.into();
+                r#type.into()
+            }
+        };
+        let value = Self::abi_constant_value(constant)?;
+
+        Some(AbiConstant::new(name, r#type, value))
+    }
+
+    ///
+    /// Converts the constant value into the input JSON format.
+    ///
+    fn abi_constant_value(constant: &Constant) -> Option<JsonValue> {
+        Some(match constant {
+            Constant::Unit(_) => JsonValue::Null,
+            Constant::Boolean(inner) => JsonValue::Bool(inner.inner),
+            Constant::Integer(inner) => match inner.enumeration {
+                Some(ref enumeration) => enumeration
+                    .values
+                    .iter()
+                    .position(|value| value == &inner.value)
+                    .map(|index| JsonValue::String(enumeration.names[index].to_owned()))?,
+                None if inner.bitlength == zinc_const::bitlength::FIELD
+                    || inner.bitlength == zinc_const::bitlength::ETH_ADDRESS =>
+                {
+                    JsonValue::String(format!(
+                        "0x{}",
+                        inner.value.to_str_radix(zinc_const::base::HEXADECIMAL)
+                    ))
+                }
+                None => JsonValue::String(inner.value.to_string()),
+            },
+            Constant::String(inner) => JsonValue::String(inner.inner.to_owned()),
+            Constant::Array(inner) => JsonValue::Array(
+                inner
+                    .values
+                    .iter()
+                    .map(Self::abi_constant_value)
+                    .collect::<Option<Vec<JsonValue>>>()?,
+            ),
+            Constant::Tuple(inner) => JsonValue::Array(
+                inner
+                    .values
+                    .iter()
+                    .map(Self::abi_constant_value)
+                    .collect::<Option<Vec<JsonValue>>>()?,
+            ),
+            Constant::Structure(inner) => {
+                let mut object = JsonMap::with_capacity(inner.values.len());
+                for (identifier, value) in inner.values.iter() {
+                    object.insert(identifier.name.to_owned(), Self::abi_constant_value(value)?);
+                }
+                JsonValue::Object(object)
+            }
+            Constant::Range(_) | Constant::RangeInclusive(_) => return None,
+        })
+    }
 }
 
 impl IBytecodeWritable for Statement {
     fn write_all(self, state: Rc<RefCell<State>>) {
         state.borrow_mut().set_contract_storage(self.fields);
+        state.borrow_mut().set_contract_metadata(self.metadata);
+        state.borrow_mut().set_contract_constants(self.constants);
+    }
+}
+
+impl IIntermediatePrintable for Statement {
+    fn print(&self, printer: &mut Printer) {
+        printer.line_at("contract", self.location);
+        printer.nested(|printer| {
+            for field in self.fields.iter() {
+                printer.line(format!(
+                    "{}{}: {}{}",
+                    if field.is_public { "pub " } else { "" },
+                    field.name,
+                    field.r#type,
+                    if field.is_implicit { " [implicit]" } else { "" },
+                ));
+            }
+
+            let mut metadata: Vec<(&String, &String)> = self.metadata.iter().collect();
+            metadata.sort();
+            for (name, value) in metadata.into_iter() {
+                printer.line(format!("metadata {} = {:?}", name, value));
+            }
+        });
     }
 }
@@ -5,13 +5,19 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+
 use zinc_build::Instruction;
+use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::printer::Printer;
 use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::Type as SemanticType;
@@ -27,6 +33,8 @@ pub struct Statement {
     pub identifier: String,
     /// Whether the function can mutate its arguments.
     pub is_mutable: bool,
+    /// Whether the function does not take `self`, e.g. a contract associated function.
+    pub is_associated: bool,
     /// The function arguments, where the compile time only ones like `()` are already filtered out.
     pub input_arguments: Vec<(String, bool, Type)>,
     /// The function body.
@@ -41,6 +49,11 @@ pub struct Statement {
     pub is_contract_entry: bool,
     /// The function attibutes, e.g. the unit test ones.
     pub attributes: Vec<Attribute>,
+    /// The `#[only(field)]` contract storage field name and position, which the transaction
+    /// sender is checked against at the beginning of the method.
+    pub only: Option<(String, usize)>,
+    /// The arguments declared with `#[public]`, which are inputized by the contract method proof.
+    pub public_arguments: Vec<String>,
 }
 
 impl Statement {
@@ -52,6 +65,7 @@ impl Statement {
         location: Location,
         identifier: String,
         is_mutable: bool,
+        is_associated: bool,
         bindings: Vec<Binding>,
         body: Expression,
         output_type: SemanticType,
@@ -59,6 +73,8 @@ impl Statement {
         is_main: bool,
         is_contract_entry: bool,
         attributes: Vec<Attribute>,
+        only: Option<(String, usize)>,
+        public_arguments: Vec<String>,
     ) -> Self {
         let input_arguments = bindings
             .into_iter()
@@ -74,6 +90,7 @@ impl Statement {
             location,
             identifier,
             is_mutable,
+            is_associated,
             input_arguments,
             body,
             output_type,
@@ -81,30 +98,164 @@ impl Statement {
             is_contract_entry,
             is_main,
             attributes,
+            only,
+            public_arguments,
         }
     }
 }
 
+impl Statement {
+    ///
+    /// Writes the equivalent of `require(zksync::msg.sender0 == self.{field})`, which is
+    /// injected at the beginning of the methods declared with `#[only(field)]`.
+    ///
+    fn write_sender_check(
+        state: Rc<RefCell<State>>,
+        field: String,
+        position: usize,
+        location: Location,
+    ) {
+        let transaction_address = state
+            .borrow()
+            .get_variable_address(zinc_const::contract::TRANSACTION_VARIABLE_NAME)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_build::Load::new(transaction_address, 1)),
+            Some(location),
+        );
+
+        IntegerConstant::new(BigInt::from(position), false, zinc_const::bitlength::FIELD)
+            .write_all(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::StorageLoad(zinc_build::StorageLoad::new(1)),
+            Some(location),
+        );
+
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_build::Eq), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_build::Require::new(
+                Some(format!("the sender must be the `{}` address", field)),
+                vec![],
+            )),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Returns the `self` instance size, if the function is a `mut self` structure method,
+    /// which can be called on a structure stored in the contract storage.
+    ///
+    fn writeback_instance_size(&self) -> Option<usize> {
+        if !self.is_mutable || self.is_main || self.is_contract_entry {
+            return None;
+        }
+
+        match self.input_arguments.first() {
+            Some((name, _is_mutable, r#type @ Type::Structure { .. }))
+                if name == Keyword::SelfLowercase.to_string().as_str() =>
+            {
+                Some(r#type.size())
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Writes the method variant, which returns the mutated `self` instance after the result,
+    /// so it can be written back to the contract storage by the caller.
+    ///
+    /// The variant is only called if the method instance is a contract storage projection,
+    /// so it is removed by the dead function code elimination otherwise.
+    ///
+    fn write_writeback_variant(self, state: Rc<RefCell<State>>, instance_size: usize) {
+        let output_size = self.output_type.size();
+        let type_id = state.borrow_mut().writeback_type_id(self.type_id);
+
+        state
+            .borrow_mut()
+            .start_function(self.location, type_id, self.identifier);
+
+        for (name, _is_mutable, r#type) in self.input_arguments.into_iter() {
+            match r#type {
+                Type::Contract { .. } => {}
+                argument_type => {
+                    state
+                        .borrow_mut()
+                        .define_variable(Some(name), argument_type.size());
+                }
+            }
+        }
+
+        self.body.write_all(state.clone());
+
+        let instance_address = state
+            .borrow()
+            .get_variable_address(Keyword::SelfLowercase.to_string().as_str())
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_build::Load::new(instance_address, instance_size)),
+            Some(self.location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Return(zinc_build::Return::new(output_size + instance_size)),
+            Some(self.location),
+        );
+    }
+}
+
 impl IBytecodeWritable for Statement {
     fn write_all(self, state: Rc<RefCell<State>>) {
         let output_size = self.output_type.size();
 
+        let writeback_variant = if state.borrow().is_contract() {
+            self.writeback_instance_size()
+                .map(|instance_size| (self.clone(), instance_size))
+        } else {
+            None
+        };
+
         if self.is_main || self.is_contract_entry {
+            let payable_token = self
+                .attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    Attribute::Payable { token } => Some(token.to_owned()),
+                    _ => None,
+                });
+
             state.borrow_mut().start_entry_function(
                 self.location,
                 self.type_id,
                 self.identifier,
                 self.is_mutable,
+                self.is_associated,
+                payable_token.is_some(),
+                payable_token.flatten(),
+                self.only
+                    .as_ref()
+                    .map(|(field, _position)| field.to_owned()),
+                self.public_arguments.clone(),
                 self.input_arguments.clone(),
                 self.output_type,
             );
-        } else if self.attributes.contains(&Attribute::Test) {
+        } else if self.attributes.iter().any(Attribute::is_unit_test) {
+            let storage_fixture = self
+                .attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    Attribute::Test { storage } => storage.to_owned(),
+                    _ => None,
+                });
+
             state.borrow_mut().start_unit_test_function(
                 self.location,
                 self.type_id,
                 self.identifier,
                 self.attributes.contains(&Attribute::ShouldPanic),
                 self.attributes.contains(&Attribute::Ignore),
+                storage_fixture,
             );
         } else {
             state
@@ -123,9 +274,16 @@ impl IBytecodeWritable for Statement {
             }
         }
 
+        if let Some((field, position)) = self.only {
+            Self::write_sender_check(state.clone(), field, position, self.location);
+        }
+
         self.body.write_all(state.clone());
 
-        if self.is_main || self.is_contract_entry || self.attributes.contains(&Attribute::Test) {
+        if self.is_main
+            || self.is_contract_entry
+            || self.attributes.iter().any(Attribute::is_unit_test)
+        {
             state.borrow_mut().push_instruction(
                 Instruction::Exit(zinc_build::Exit::new(output_size)),
                 Some(self.location),
@@ -136,5 +294,69 @@ impl IBytecodeWritable for Statement {
                 Some(self.location),
             );
         }
+
+        if let Some((statement, instance_size)) = writeback_variant {
+            statement.write_writeback_variant(state, instance_size);
+        }
+    }
+}
+
+impl IIntermediatePrintable for Statement {
+    fn print(&self, printer: &mut Printer) {
+        let arguments = self
+            .input_arguments
+            .iter()
+            .map(|(name, is_mutable, r#type)| {
+                format!(
+                    "{}{}: {}",
+                    if *is_mutable { "mut " } else { "" },
+                    name,
+                    match r#type {
+                        Type::Contract { .. } => "contract".to_owned(),
+                        r#type => r#type.to_string(),
+                    }
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let mut flags = Vec::new();
+        if self.is_mutable {
+            flags.push("mutable".to_owned());
+        }
+        if self.is_main {
+            flags.push("main".to_owned());
+        }
+        if self.is_contract_entry {
+            flags.push("entry".to_owned());
+        }
+        if let Some((field, _position)) = self.only.as_ref() {
+            flags.push(format!("only({})", field));
+        }
+        if !self.public_arguments.is_empty() {
+            flags.push(format!("public({})", self.public_arguments.join(", ")));
+        }
+        flags.extend(
+            self.attributes
+                .iter()
+                .map(|attribute| format!("{:?}", attribute)),
+        );
+
+        printer.line_at(
+            format!(
+                "fn {}#{}({}) -> {}{}",
+                self.identifier,
+                self.type_id,
+                arguments,
+                self.output_type,
+                if flags.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", flags.join(", "))
+                },
+            ),
+            self.location,
+        );
+        printer.nested(|printer| self.body.print(printer));
     }
 }
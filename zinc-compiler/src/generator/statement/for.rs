@@ -11,9 +11,11 @@ use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::printer::Printer;
 use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use zinc_lexical::Location;
 
 use num::BigInt;
@@ -226,3 +228,31 @@ impl IBytecodeWritable for Statement {
         );
     }
 }
+
+impl IIntermediatePrintable for Statement {
+    fn print(&self, printer: &mut Printer) {
+        printer.line_at(
+            format!(
+                "for {}: {}{} from {} times {}{}",
+                self.index_variable_name,
+                if self.index_variable_is_signed {
+                    "i"
+                } else {
+                    "u"
+                },
+                self.index_variable_bitlength,
+                self.initial_value,
+                self.iterations_count,
+                if self.is_reversed { " reversed" } else { "" },
+            ),
+            self.location,
+        );
+        printer.nested(|printer| {
+            if let Some(ref condition) = self.while_condition {
+                printer.line("while");
+                printer.nested(|printer| condition.print(printer));
+            }
+            self.body.print(printer);
+        });
+    }
+}
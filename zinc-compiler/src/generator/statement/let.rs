@@ -12,9 +12,11 @@ use zinc_lexical::Location;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::Expression;
+use crate::generator::printer::Printer;
 use crate::generator::r#type::Type;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 use crate::semantic::binding::Binding;
 
 ///
@@ -65,7 +67,12 @@ impl IBytecodeWritable for Statement {
 
             match r#type {
                 Type::Contract { fields } => {
-                    for (index, field) in fields.into_iter().enumerate().rev() {
+                    let leaf_types: Vec<Type> = fields
+                        .into_iter()
+                        .flat_map(|field| field.r#type.into_storage_leaf_types())
+                        .collect();
+
+                    for (index, r#type) in leaf_types.into_iter().enumerate().rev() {
                         IntegerConstant::new(
                             BigInt::from(index),
                             false,
@@ -73,9 +80,7 @@ impl IBytecodeWritable for Statement {
                         )
                         .write_all(state.clone());
                         state.borrow_mut().push_instruction(
-                            Instruction::StorageStore(zinc_build::StorageStore::new(
-                                field.r#type.size(),
-                            )),
+                            Instruction::StorageStore(zinc_build::StorageStore::new(r#type.size())),
                             Some(self.location),
                         );
                     }
@@ -96,3 +101,28 @@ impl IBytecodeWritable for Statement {
         }
     }
 }
+
+impl IIntermediatePrintable for Statement {
+    fn print(&self, printer: &mut Printer) {
+        let bindings = self
+            .bindings
+            .iter()
+            .map(|binding| {
+                if binding.is_wildcard {
+                    format!("_: {}", binding.r#type)
+                } else {
+                    format!(
+                        "{}{}: {}",
+                        if binding.is_mutable { "mut " } else { "" },
+                        binding.identifier.name,
+                        binding.r#type
+                    )
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        printer.line_at(format!("let {}", bindings), self.location);
+        printer.nested(|printer| self.expression.print(printer));
+    }
+}
@@ -10,9 +10,13 @@ pub mod r#let;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+
 use crate::generator::expression::Expression;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
+use crate::generator::IIntermediatePrintable;
 
 use self::contract::Statement as ContractStatement;
 use self::r#fn::Statement as FnStatement;
@@ -36,6 +40,21 @@ pub enum Statement {
     Expression(Expression),
 }
 
+impl Statement {
+    ///
+    /// Returns the statement location, if the statement has one.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::Fn(inner) => Some(inner.location),
+            Self::Let(inner) => Some(inner.location),
+            Self::Contract(inner) => Some(inner.location),
+            Self::For(inner) => Some(inner.location),
+            Self::Expression(_) => None,
+        }
+    }
+}
+
 impl IBytecodeWritable for Statement {
     fn write_all(self, bytecode: Rc<RefCell<State>>) {
         match self {
@@ -47,3 +66,15 @@ impl IBytecodeWritable for Statement {
         }
     }
 }
+
+impl IIntermediatePrintable for Statement {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            Self::Fn(inner) => inner.print(printer),
+            Self::Let(inner) => inner.print(printer),
+            Self::Contract(inner) => inner.print(printer),
+            Self::For(inner) => inner.print(printer),
+            Self::Expression(inner) => inner.print(printer),
+        }
+    }
+}
@@ -4,6 +4,8 @@
 
 pub mod contract_field;
 
+use std::fmt;
+
 use num::BigInt;
 
 use zinc_build::IntegerType;
@@ -76,9 +78,21 @@ pub enum Type {
         /// The value key type.
         value_type: Box<Self>,
     },
+    /// The IR `Maybe` type.
+    Maybe {
+        /// The payload type.
+        r#type: Box<Self>,
+    },
 }
 
 impl Type {
+    /// The discriminant field name of an enumeration with payloads.
+    pub const ENUMERATION_FIELD_VARIANT: &'static str = "variant";
+
+    /// The payload field name of an enumeration with payloads, which is zero-padded to the
+    /// size of the largest variant payload.
+    pub const ENUMERATION_FIELD_PAYLOAD: &'static str = "payload";
+
     ///
     /// A shortcut constructor.
     ///
@@ -176,6 +190,15 @@ impl Type {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn maybe(r#type: Self) -> Self {
+        Self::Maybe {
+            r#type: Box::new(r#type),
+        }
+    }
+
     ///
     /// The type size in the Zinc VM data stack.
     ///
@@ -192,6 +215,21 @@ impl Type {
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
             Self::Contract { fields } => fields.iter().map(|field| field.r#type.size()).sum(),
             Self::Map { .. } => 0,
+            Self::Maybe { r#type } => 1 + r#type.size(),
+        }
+    }
+
+    ///
+    /// Splits a contract storage field type into the types of the Merkle tree leaves it occupies.
+    ///
+    /// Each map occupies a separate leaf, so an array of maps occupies a leaf per element.
+    ///
+    pub fn into_storage_leaf_types(self) -> Vec<Self> {
+        match self {
+            Self::Array { r#type, size } if matches!(*r#type, Self::Map { .. }) => {
+                vec![*r#type; size]
+            }
+            r#type => vec![r#type],
         }
     }
 
@@ -213,6 +251,20 @@ impl Type {
             SemanticType::Array(inner) => Self::try_from_semantic(&*inner.r#type)
                 .map(|r#type| Self::array(r#type, inner.size)),
             SemanticType::Tuple(inner) => {
+                if let Some(ref names) = inner.names {
+                    return match names
+                        .iter()
+                        .zip(inner.types.iter())
+                        .filter_map(|(name, r#type)| {
+                            Self::try_from_semantic(r#type).map(|r#type| (name.to_owned(), r#type))
+                        })
+                        .collect::<Vec<(String, Type)>>()
+                    {
+                        fields if !fields.is_empty() => Some(Self::structure(fields)),
+                        _ => None,
+                    };
+                }
+
                 match inner
                     .types
                     .iter()
@@ -239,6 +291,19 @@ impl Type {
                     return Some(Self::map(key_type, value_type));
                 }
 
+                if inner.is_newtype {
+                    return Self::try_from_semantic(&inner.newtype_inner_type());
+                }
+
+                if inner.type_id == IntrinsicTypeId::Maybe as usize {
+                    let value_type =
+                        inner.params.as_ref()?.get("T").map(|r#type| {
+                            Self::try_from_semantic(r#type).unwrap_or_else(Self::unit)
+                        })?;
+
+                    return Some(Self::maybe(value_type));
+                }
+
                 match inner
                     .fields
                     .iter()
@@ -251,15 +316,29 @@ impl Type {
                     _ => None,
                 }
             }
-            SemanticType::Enumeration(inner) => Some(Self::enumeration(
-                inner.bitlength,
-                inner
-                    .names
-                    .to_owned()
-                    .into_iter()
-                    .zip(inner.values.to_owned())
-                    .collect::<Vec<(String, BigInt)>>(),
-            )),
+            SemanticType::Enumeration(inner) => {
+                let discriminant = Self::enumeration(
+                    inner.bitlength,
+                    inner
+                        .names
+                        .to_owned()
+                        .into_iter()
+                        .zip(inner.values.to_owned())
+                        .collect::<Vec<(String, BigInt)>>(),
+                );
+
+                if !inner.has_payloads() {
+                    return Some(discriminant);
+                }
+
+                Some(Self::structure(vec![
+                    (Self::ENUMERATION_FIELD_VARIANT.to_owned(), discriminant),
+                    (
+                        Self::ENUMERATION_FIELD_PAYLOAD.to_owned(),
+                        Self::array(Self::field(), inner.payload_size()),
+                    ),
+                ]))
+            }
             SemanticType::Contract(inner) => {
                 match inner
                     .fields
@@ -331,6 +410,10 @@ impl Into<BuildType> for Type {
                     value_type: Box::new(value_type),
                 }
             }
+            Self::Maybe { r#type } => {
+                let value_type: BuildType = (*r#type).into();
+                BuildType::Maybe(Box::new(value_type))
+            }
         }
     }
 }
@@ -352,3 +435,10 @@ impl Into<Option<ScalarType>> for Type {
         }
     }
 }
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let r#type: BuildType = self.to_owned().into();
+        write!(f, "{}", r#type)
+    }
+}
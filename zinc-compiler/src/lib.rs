@@ -6,9 +6,13 @@ pub(crate) mod error;
 pub(crate) mod generator;
 pub(crate) mod semantic;
 pub(crate) mod source;
+pub(crate) mod timings;
+pub(crate) mod warning;
 
 pub use self::error::Error;
+pub use self::generator::state::optimizer::dead_function_code_elimination::error::Error as DeadFunctionCodeEliminationError;
 pub use self::generator::module::Module;
+pub use self::generator::printer::Printer;
 pub use self::generator::state::State;
 pub use self::generator::IBytecodeWritable;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
@@ -16,4 +20,17 @@ pub use self::semantic::scope::Scope;
 pub use self::source::directory::Directory as SourceDirectory;
 pub use self::source::error::Error as SourceError;
 pub use self::source::file::File as SourceFile;
+pub use self::source::library::Library as SourceLibrary;
+pub use self::source::provider::file_system::Provider as FileSystemSourceProvider;
+pub use self::source::provider::memory::Provider as MemorySourceProvider;
+pub use self::source::provider::EntryType as SourceEntryType;
+pub use self::source::provider::ISourceProvider;
 pub use self::source::Source;
+pub use self::timings::report::Report as TimingsReport;
+pub use self::timings::TIMINGS;
+pub use self::warning::Warning;
+
+///
+/// The compiler version, which is written to the library interfaces.
+///
+pub static VERSION: &str = env!("CARGO_PKG_VERSION");
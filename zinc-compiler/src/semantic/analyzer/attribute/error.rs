@@ -16,4 +16,71 @@ pub enum Error {
         /// The invalid stringified attribute.
         found: String,
     },
+    /// The attribute does not accept the argument.
+    UnexpectedArgument {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        attribute: String,
+        /// The invalid argument name.
+        found: String,
+    },
+    /// The attribute argument requires a value, e.g. `token = "ETH"`.
+    ArgumentValueMissing {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        attribute: String,
+        /// The argument name.
+        argument: String,
+    },
+    /// The `#[payable]` attribute is applied to a function, which is not a mutable contract method.
+    PayableOutsideMutableContractMethod {
+        /// The error location data.
+        location: Location,
+        /// The function name.
+        function: String,
+    },
+    /// The `#[only]` attribute must have a single argument, which is a contract storage field name.
+    OnlyExpectedField {
+        /// The error location data.
+        location: Location,
+    },
+    /// The `#[only]` attribute is applied to a function, which is not a mutable contract method.
+    OnlyOutsideMutableContractMethod {
+        /// The error location data.
+        location: Location,
+        /// The function name.
+        function: String,
+    },
+    /// The `#[only]` attribute argument is not a contract storage field.
+    OnlyFieldNotFound {
+        /// The error location data.
+        location: Location,
+        /// The invalid field name.
+        field: String,
+    },
+    /// The `#[only]` attribute argument is a contract storage field, which is not an address.
+    OnlyFieldExpectedAddress {
+        /// The error location data.
+        location: Location,
+        /// The field name.
+        field: String,
+        /// The stringified field type.
+        found: String,
+    },
+    /// The `#[public]` attribute is applied to something else than a named contract method argument.
+    PublicOutsideContractMethodArgument {
+        /// The error location data.
+        location: Location,
+        /// The function name.
+        function: String,
+    },
+    /// The attribute cannot be applied to a function argument. Only `#[public]` is allowed there.
+    UnexpectedArgumentAttribute {
+        /// The error location data.
+        location: Location,
+        /// The invalid stringified attribute.
+        found: String,
+    },
 }
@@ -2,54 +2,200 @@
 //! The semantic attribute.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod error;
 
 use std::convert::TryFrom;
 
 use zinc_syntax::Attribute as SyntaxAttribute;
+use zinc_syntax::Identifier;
 
 use self::error::Error;
 
 ///
 /// The semantic attribute.
 ///
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Attribute {
-    /// The `#[test]` attribute.
-    Test,
+    /// The `#[test]` or `#[test(storage = "fixtures/storage.json")]` attribute.
+    Test {
+        /// The initial contract storage fixture path relative to the project, if specified.
+        storage: Option<String>,
+    },
     /// The `#[should_panic]` attribute.
     ShouldPanic,
     /// The `#[ignore]` attribute.
     Ignore,
+    /// The `#[payable]` or `#[payable(token = "ETH")]` attribute.
+    Payable {
+        /// The token which must be transferred to the method, if specified.
+        token: Option<String>,
+    },
+    /// The `#[only(field)]` attribute, which restricts the method to the sender stored in `field`.
+    Only {
+        /// The contract storage field, which holds the only allowed transaction sender address.
+        field: Identifier,
+    },
+    /// The `#[public]` contract method argument attribute, which makes the argument a part of the
+    /// proof public input.
+    Public,
 }
 
 impl Attribute {
+    /// The `#[payable]` attribute token argument name.
+    pub const PAYABLE_ARGUMENT_TOKEN: &'static str = "token";
+    /// The `#[test]` attribute storage fixture argument name.
+    pub const TEST_ARGUMENT_STORAGE: &'static str = "storage";
+
     ///
     /// If the attribute is related to unit tests.
     ///
     pub fn is_test(&self) -> bool {
         match self {
-            Self::Test => true,
+            Self::Test { .. } => true,
             Self::ShouldPanic => true,
             Self::Ignore => true,
+            Self::Payable { .. } => false,
+            Self::Only { .. } => false,
+            Self::Public => false,
         }
     }
+
+    ///
+    /// If the attribute is `#[test]`, which makes the function a unit test.
+    ///
+    pub fn is_unit_test(&self) -> bool {
+        matches!(self, Self::Test { .. })
+    }
+
+    ///
+    /// If the attribute is `#[payable]`.
+    ///
+    pub fn is_payable(&self) -> bool {
+        matches!(self, Self::Payable { .. })
+    }
+
+    ///
+    /// If the attribute is `#[only(field)]`.
+    ///
+    pub fn is_only(&self) -> bool {
+        matches!(self, Self::Only { .. })
+    }
+
+    ///
+    /// If the attribute is `#[public]`.
+    ///
+    pub fn is_public(&self) -> bool {
+        matches!(self, Self::Public)
+    }
 }
 
 impl TryFrom<SyntaxAttribute> for Attribute {
     type Error = Error;
 
     fn try_from(value: SyntaxAttribute) -> Result<Self, Self::Error> {
-        Ok(match value.identifier.name.as_str() {
-            "test" => Self::Test,
+        let name = value.identifier.name;
+
+        let attribute = match name.as_str() {
+            "test" => {
+                let mut storage = None;
+                for argument in value.arguments.into_iter() {
+                    match argument.identifier.name.as_str() {
+                        Self::TEST_ARGUMENT_STORAGE if storage.is_none() => match argument.value {
+                            Some(value) => storage = Some(value.into()),
+                            None => {
+                                return Err(Error::ArgumentValueMissing {
+                                    location: argument.location,
+                                    attribute: name,
+                                    argument: argument.identifier.name,
+                                })
+                            }
+                        },
+                        _ => {
+                            return Err(Error::UnexpectedArgument {
+                                location: argument.location,
+                                attribute: name,
+                                found: argument.identifier.name,
+                            })
+                        }
+                    }
+                }
+
+                return Ok(Self::Test { storage });
+            }
             "should_panic" => Self::ShouldPanic,
             "ignore" => Self::Ignore,
+            "public" => Self::Public,
+            "payable" => {
+                let mut token = None;
+                for argument in value.arguments.into_iter() {
+                    match argument.identifier.name.as_str() {
+                        Self::PAYABLE_ARGUMENT_TOKEN if token.is_none() => match argument.value {
+                            Some(value) => token = Some(value.into()),
+                            None => {
+                                return Err(Error::ArgumentValueMissing {
+                                    location: argument.location,
+                                    attribute: name,
+                                    argument: argument.identifier.name,
+                                })
+                            }
+                        },
+                        _ => {
+                            return Err(Error::UnexpectedArgument {
+                                location: argument.location,
+                                attribute: name,
+                                found: argument.identifier.name,
+                            })
+                        }
+                    }
+                }
+
+                return Ok(Self::Payable { token });
+            }
+            "only" => {
+                let mut arguments = value.arguments.into_iter();
+                let field = match arguments.next() {
+                    Some(argument) if argument.value.is_none() => argument.identifier,
+                    Some(argument) => {
+                        return Err(Error::OnlyExpectedField {
+                            location: argument.location,
+                        })
+                    }
+                    None => {
+                        return Err(Error::OnlyExpectedField {
+                            location: value.identifier.location,
+                        })
+                    }
+                };
+
+                if let Some(argument) = arguments.next() {
+                    return Err(Error::UnexpectedArgument {
+                        location: argument.location,
+                        attribute: name,
+                        found: argument.identifier.name,
+                    });
+                }
+
+                return Ok(Self::Only { field });
+            }
             _ => {
                 return Err(Error::Unknown {
                     location: value.identifier.location,
-                    found: value.identifier.name,
+                    found: name,
                 })
             }
-        })
+        };
+
+        if let Some(argument) = value.arguments.into_iter().next() {
+            return Err(Error::UnexpectedArgument {
+                location: argument.location,
+                attribute: name,
+                found: argument.identifier.name,
+            });
+        }
+
+        Ok(attribute)
     }
 }
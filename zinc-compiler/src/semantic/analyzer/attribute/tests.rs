@@ -0,0 +1,424 @@
+//!
+//! The attribute tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_payable() {
+    let input = r#"
+contract Test {
+    #[payable]
+    pub fn deposit(mut self) {}
+
+    #[payable(token = "ETH")]
+    pub fn deposit_eth(mut self) {}
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_unknown() {
+    let input = r#"
+#[unknown]
+fn test() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::Unknown {
+            location: Location::test(2, 3),
+            found: "unknown".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_argument() {
+    let input = r#"
+contract Test {
+    #[payable(amount = "1")]
+    pub fn deposit(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::UnexpectedArgument {
+            location: Location::test(3, 15),
+            attribute: "payable".to_owned(),
+            found: "amount".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_argument_test() {
+    let input = r#"
+#[test(token = "ETH")]
+fn test() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::UnexpectedArgument {
+            location: Location::test(2, 8),
+            attribute: "test".to_owned(),
+            found: "token".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_test_storage() {
+    let input = r#"
+#[test(storage = "fixtures/rich_account.json")]
+fn test() {}
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_value_missing_test_storage() {
+    let input = r#"
+#[test(storage)]
+fn test() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::ArgumentValueMissing {
+            location: Location::test(2, 8),
+            attribute: "test".to_owned(),
+            argument: "storage".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payable_outside_contract() {
+    let input = r#"
+#[payable]
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PayableOutsideMutableContractMethod {
+            location: Location::test(3, 1),
+            function: "main".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payable_private_contract_method() {
+    let input = r#"
+contract Test {
+    #[payable]
+    fn deposit(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PayableOutsideMutableContractMethod {
+            location: Location::test(4, 5),
+            function: "deposit".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payable_immutable_contract_method() {
+    let input = r#"
+contract Test {
+    #[payable]
+    pub fn deposit(self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PayableOutsideMutableContractMethod {
+            location: Location::test(4, 5),
+            function: "deposit".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_only() {
+    let input = r#"
+contract Test {
+    owner: u160;
+
+    #[only(owner)]
+    pub fn withdraw(mut self) {}
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_value_missing() {
+    let input = r#"
+contract Test {
+    #[payable(token)]
+    pub fn deposit(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::ArgumentValueMissing {
+            location: Location::test(3, 15),
+            attribute: "payable".to_owned(),
+            argument: "token".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_only_expected_field() {
+    let input = r#"
+contract Test {
+    owner: u160;
+
+    #[only]
+    pub fn withdraw(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::OnlyExpectedField {
+            location: Location::test(5, 7),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_only_immutable_contract_method() {
+    let input = r#"
+contract Test {
+    owner: u160;
+
+    #[only(owner)]
+    pub fn get_owner(self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::OnlyOutsideMutableContractMethod {
+            location: Location::test(6, 5),
+            function: "get_owner".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_only_field_not_found() {
+    let input = r#"
+contract Test {
+    owner: u160;
+
+    #[only(admin)]
+    pub fn withdraw(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::OnlyFieldNotFound {
+            location: Location::test(5, 12),
+            field: "admin".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_only_field_expected_address() {
+    let input = r#"
+contract Test {
+    owner: u64;
+
+    #[only(owner)]
+    pub fn withdraw(mut self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::OnlyFieldExpectedAddress {
+            location: Location::test(5, 12),
+            field: "owner".to_owned(),
+            found: "u64".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_public() {
+    let input = r#"
+contract Test {
+    pub fn get(self, #[public] key: u8, value: u8) -> u8 {
+        key + value
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_public_outside_contract() {
+    let input = r#"
+fn main(#[public] value: u8) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PublicOutsideContractMethodArgument {
+            location: Location::test(2, 9),
+            function: "main".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_public_private_contract_method() {
+    let input = r#"
+contract Test {
+    fn get(self, #[public] value: u8) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PublicOutsideContractMethodArgument {
+            location: Location::test(3, 18),
+            function: "get".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_public_self() {
+    let input = r#"
+contract Test {
+    pub fn get(#[public] self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PublicOutsideContractMethodArgument {
+            location: Location::test(3, 16),
+            function: "get".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_public_function() {
+    let input = r#"
+contract Test {
+    #[public]
+    pub fn get(self) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::PublicOutsideContractMethodArgument {
+            location: Location::test(4, 5),
+            function: "get".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_argument_attribute() {
+    let input = r#"
+contract Test {
+    pub fn get(self, #[payable] value: u8) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Attribute(
+        AttributeError::UnexpectedArgumentAttribute {
+            location: Location::test(3, 22),
+            found: "payable".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
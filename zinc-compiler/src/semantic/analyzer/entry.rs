@@ -3,12 +3,16 @@
 //!
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
+use crate::generator::r#type::Type as GeneratorType;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::module::Module as ScopeModuleItem;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::source::library::Library;
 use crate::source::Source;
 
 ///
@@ -24,7 +28,17 @@ impl Analyzer {
     /// 4. Validates entry points.
     ///
     pub fn define(module: Source) -> Result<Rc<RefCell<Scope>>, Error> {
-        let entry = ScopeModuleItem::new_entry(module)?;
+        Self::define_with_libraries(module, HashMap::new())
+    }
+
+    ///
+    /// The same as `define`, but also declares the `libraries` the application depends on.
+    ///
+    pub fn define_with_libraries(
+        module: Source,
+        libraries: HashMap<String, Library>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        let entry = ScopeModuleItem::new_entry(module, libraries)?;
         entry.borrow().define()?;
 
         let entry = entry.borrow();
@@ -52,4 +66,87 @@ impl Analyzer {
             panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
         }
     }
+
+    ///
+    /// Analyzes a library project root module.
+    ///
+    /// Libraries have no entry points, and the `main` function or contract declarations are
+    /// rejected the same way as in non-entry modules.
+    ///
+    pub fn define_library(
+        module: Source,
+        libraries: HashMap<String, Library>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        let identifier = module.name().to_owned();
+        let library = ScopeModuleItem::new_library(
+            identifier,
+            Library {
+                source: module,
+                dependencies: libraries,
+            },
+        )?;
+        library.borrow().define()?;
+
+        let library = library.borrow();
+        if let ScopeItem::Module(ref module) = *library {
+            module.scope()
+        } else {
+            panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        }
+    }
+
+    ///
+    /// Checks whether the contract public method inputs and outputs, and the contract storage,
+    /// are small enough to be set up and proven in practice.
+    ///
+    /// The sizes are measured in field elements of the flattened types. The method `self`
+    /// instance is not counted, as it is a part of the storage checked separately.
+    ///
+    pub fn check_entry_sizes(
+        intermediate: &[GeneratorStatement],
+        limit: usize,
+    ) -> Result<(), Error> {
+        for statement in intermediate.iter() {
+            match statement {
+                GeneratorStatement::Fn(function) if function.is_contract_entry => {
+                    let size = function
+                        .input_arguments
+                        .iter()
+                        .filter(|(_name, _is_mutable, r#type)| {
+                            !matches!(r#type, GeneratorType::Contract { .. })
+                        })
+                        .map(|(_name, _is_mutable, r#type)| r#type.size())
+                        .sum::<usize>()
+                        + function.output_type.size();
+
+                    if size > limit {
+                        return Err(Error::EntryInputTooLarge {
+                            location: function.location,
+                            method: function.identifier.to_owned(),
+                            size,
+                            limit,
+                        });
+                    }
+                }
+                GeneratorStatement::Contract(contract) => {
+                    let size = contract
+                        .fields
+                        .iter()
+                        .map(|field| field.r#type.size())
+                        .sum::<usize>();
+
+                    if size > limit {
+                        return Err(Error::ContractStorageTooLarge {
+                            location: contract.location,
+                            size,
+                            limit,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
 }
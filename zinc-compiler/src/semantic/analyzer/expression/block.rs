@@ -11,6 +11,7 @@ use zinc_syntax::FunctionLocalStatement;
 use crate::generator::expression::operand::block::builder::Builder as GeneratorBlockExpressionBuilder;
 use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::analyzer::statement::r#const::Analyzer as ConstStatementAnalyzer;
@@ -32,6 +33,9 @@ impl Analyzer {
     ///
     /// Analyzes the block expression.
     ///
+    /// In a constant context, the `let` bindings are evaluated as constants and declared in the
+    /// block scope, whereas loops are forbidden.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
@@ -46,19 +50,32 @@ impl Analyzer {
 
         for statement in block.statements.into_iter() {
             let intermediate = match statement {
-                FunctionLocalStatement::Let(statement) => {
-                    LetStatementAnalyzer::define(scope_stack.top(), statement)?
-                        .map(GeneratorStatement::Let)
-                }
+                FunctionLocalStatement::Let(statement) => match rule {
+                    TranslationRule::Constant => {
+                        LetStatementAnalyzer::define_constant(scope_stack.top(), statement)?;
+                        None
+                    }
+                    _ => LetStatementAnalyzer::define(scope_stack.top(), statement)?
+                        .map(GeneratorStatement::Let),
+                },
                 FunctionLocalStatement::Const(statement) => {
                     let identifier = statement.identifier.clone();
                     let constant = ConstStatementAnalyzer::define(scope_stack.top(), statement)?;
                     Scope::define_constant(scope_stack.top(), identifier, constant, false)?;
                     None
                 }
-                FunctionLocalStatement::For(statement) => Some(GeneratorStatement::For(
-                    ForStatementAnalyzer::define(scope_stack.top(), statement)?,
-                )),
+                FunctionLocalStatement::For(statement) => {
+                    if let TranslationRule::Constant = rule {
+                        return Err(Error::Expression(ExpressionError::LoopInConstantContext {
+                            location: statement.location,
+                        }));
+                    }
+
+                    Some(GeneratorStatement::For(ForStatementAnalyzer::define(
+                        scope_stack.top(),
+                        statement,
+                    )?))
+                }
                 FunctionLocalStatement::Expression(expression) => {
                     let (_result, expression) =
                         ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(expression)?;
@@ -11,11 +11,15 @@ use crate::generator::expression::element::Element as GeneratorExpressionElement
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::place::Place;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
 use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
@@ -24,6 +28,7 @@ use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 use zinc_lexical::Location;
 
 use self::r#type::Type as CallType;
@@ -94,9 +99,31 @@ impl Analyzer {
             argument_list.arguments.insert(0, *instance.to_owned());
         }
 
+        if function.is_mutable() {
+            let is_storage = match call_type {
+                CallType::Method { is_storage, .. } => is_storage,
+                _ => matches!(
+                    argument_list.arguments.first(),
+                    Some(Element::Value(Value::Contract(_)))
+                ),
+            };
+
+            if is_storage {
+                if let Some(method) = Scope::query_method(scope.clone()) {
+                    return Err(Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::CallingMutableFromQuery {
+                            location,
+                            function: function.identifier(),
+                            method,
+                        },
+                    ))));
+                }
+            }
+        }
+
         if let CallType::Method {
-            instance: _,
             is_mutable: is_instance_mutable,
+            ..
         } = call_type
         {
             if !is_instance_mutable && function.is_mutable() {
@@ -109,6 +136,13 @@ impl Analyzer {
             }
         }
 
+        let storage_place = match call_type {
+            CallType::Method {
+                ref storage_place, ..
+            } => storage_place.to_owned(),
+            _ => None,
+        };
+
         let mut input_size = 0;
         for element in argument_list.arguments.iter() {
             input_size += Type::from_element(element, scope.clone())?.size();
@@ -152,7 +186,21 @@ impl Analyzer {
                         )
                     }
                     IntrinsicFunctionType::Require(function) => {
-                        let (return_type, message) = function
+                        if let Some(Element::Constant(Constant::Boolean(condition))) =
+                            argument_list.arguments.first()
+                        {
+                            let location = function_location.unwrap_or(location);
+                            Scope::push_warning(
+                                scope.clone(),
+                                if condition.inner {
+                                    Warning::RedundantRequire { location }
+                                } else {
+                                    Warning::RequireAlwaysFails { location }
+                                },
+                            );
+                        }
+
+                        let (return_type, message, argument_types) = function
                             .call(function_location.unwrap_or(location), argument_list)
                             .map_err(|error| {
                                 Error::Element(ElementError::Type(TypeError::Function(error)))
@@ -164,7 +212,8 @@ impl Analyzer {
                                 .map_err(Error::Element)?,
                         );
 
-                        let intermediate = GeneratorExpressionOperator::call_assert(message);
+                        let intermediate =
+                            GeneratorExpressionOperator::call_assert(message, argument_types);
 
                         (
                             element,
@@ -186,12 +235,26 @@ impl Analyzer {
 
                         let intrinsic_identifier = function.library_identifier();
 
+                        let constant =
+                            function.fold(function_location.unwrap_or(location), &argument_list);
+
                         let return_type = function
                             .call(function_location.unwrap_or(location), argument_list)
                             .map_err(|error| {
                                 Error::Element(ElementError::Type(TypeError::Function(error)))
                             })?;
 
+                        if let Some(constant) = constant {
+                            let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                                .map(GeneratorExpressionOperand::Constant)
+                                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                            return Ok((
+                                Element::Constant(constant),
+                                GeneratorExpressionElement::Operand(intermediate),
+                            ));
+                        }
+
                         let element = Element::Value(
                             Value::try_from_type(&return_type, false, None)
                                 .map_err(ElementError::Value)
@@ -250,6 +313,56 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::StringLen(function) => {
+                        if let CallType::MacroLike = call_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                                    location: function_location.unwrap_or(location),
+                                    function: function.identifier.to_owned(),
+                                }),
+                            ))));
+                        }
+
+                        let constant = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
+                    IntrinsicFunctionType::Mem(function) => {
+                        if let CallType::MacroLike = call_type {
+                            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                                FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                                    location: function_location.unwrap_or(location),
+                                    function: function.identifier().to_owned(),
+                                }),
+                            ))));
+                        }
+
+                        let constant = function
+                            .call(function_location.unwrap_or(location), argument_list)
+                            .map_err(|error| {
+                                Error::Element(ElementError::Type(TypeError::Function(error)))
+                            })?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
                 }
             }
             FunctionType::Runtime(function) => {
@@ -262,28 +375,21 @@ impl Analyzer {
                     ))));
                 }
 
-                let location = function.location;
-                let type_id = function.type_id;
-
-                let return_type = function.call(argument_list).map_err(|error| {
-                    Error::Element(ElementError::Type(TypeError::Function(error)))
-                })?;
-
-                let element = Element::Value(
-                    Value::try_from_type(&return_type, false, None)
-                        .map_err(ElementError::Value)
-                        .map_err(Error::Element)?,
-                );
+                Self::runtime(function, argument_list, input_size, storage_place)?
+            }
+            FunctionType::Generic(function) => {
+                if let CallType::MacroLike = call_type {
+                    return Err(Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::Intrinsic(IntrinsicFunctionError::Unknown {
+                            location,
+                            function: function.identifier,
+                        }),
+                    ))));
+                }
 
-                let intermediate = GeneratorExpressionOperator::call(type_id, input_size);
+                let function = function.instantiate(location, &argument_list)?;
 
-                (
-                    element,
-                    GeneratorExpressionElement::Operator {
-                        location,
-                        operator: intermediate,
-                    },
-                )
+                Self::runtime(function, argument_list, input_size, storage_place)?
             }
             FunctionType::Constant(function) => {
                 if let CallType::MacroLike = call_type {
@@ -322,4 +428,46 @@ impl Analyzer {
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Analyzes the runtime function call, which is also used for generic function instances.
+    ///
+    /// If the `mut self` method instance is projected from the contract storage, the mutated
+    /// instance is written back to the `storage_place` after the call.
+    ///
+    fn runtime(
+        function: RuntimeFunction,
+        argument_list: ArgumentList,
+        input_size: usize,
+        storage_place: Option<Place>,
+    ) -> Result<(Element, GeneratorExpressionElement), Error> {
+        let location = function.location;
+        let type_id = function.type_id;
+        let storage_place = storage_place.filter(|_| function.is_mutable());
+
+        let return_type = function
+            .call(argument_list)
+            .map_err(|error| Error::Element(ElementError::Type(TypeError::Function(error))))?;
+
+        let element = Element::Value(
+            Value::try_from_type(&return_type, false, None)
+                .map_err(ElementError::Value)
+                .map_err(Error::Element)?,
+        );
+
+        let intermediate = match storage_place {
+            Some(place) => {
+                GeneratorExpressionOperator::call_writeback(type_id, input_size, place.into())
+            }
+            None => GeneratorExpressionOperator::call(type_id, input_size),
+        };
+
+        Ok((
+            element,
+            GeneratorExpressionElement::Operator {
+                location,
+                operator: intermediate,
+            },
+        ))
+    }
 }
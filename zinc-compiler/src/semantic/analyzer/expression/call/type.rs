@@ -4,6 +4,7 @@
 
 use std::mem;
 
+use crate::semantic::element::place::Place;
 use crate::semantic::element::Element;
 
 ///
@@ -21,6 +22,11 @@ pub enum Type {
         instance: Box<Element>,
         /// Whether the instance, for which the method is called, is mutable.
         is_mutable: bool,
+        /// Whether the instance is the contract instance or a part of its storage.
+        is_storage: bool,
+        /// The contract storage place of the instance, where the instance must be written back
+        /// to if the method is declared with `mut self`.
+        storage_place: Option<Place>,
     },
 }
 
@@ -34,10 +40,17 @@ impl Type {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new_method(instance: Element, is_mutable: bool) -> Self {
+    pub fn new_method(
+        instance: Element,
+        is_mutable: bool,
+        is_storage: bool,
+        storage_place: Option<Place>,
+    ) -> Self {
         Self::Method {
             instance: Box::new(instance),
             is_mutable,
+            is_storage,
+            storage_place,
         }
     }
 }
@@ -27,6 +27,7 @@ use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 ///
 /// The conditional expression semantic analyzer.
@@ -61,6 +62,7 @@ impl Analyzer {
         conditional: ConditionalExpression,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let condition_location = conditional.condition.location;
+        let condition_start = conditional.condition.start();
 
         let main_expression_location = conditional
             .main_block
@@ -100,6 +102,15 @@ impl Analyzer {
                 )));
             }
         }
+        if let Element::Constant(Constant::Boolean(ref value)) = condition_result {
+            Scope::push_warning(
+                scope_stack.top(),
+                Warning::ConstantCondition {
+                    location: condition_start,
+                    value: value.inner,
+                },
+            );
+        }
         builder.set_condition(condition);
 
         scope_stack.push(None);
@@ -18,6 +18,23 @@ pub enum Error {
         /// The strigified invalid element.
         found: String,
     },
+    /// A loop is found in a constant context, where only `let` and `const` bindings are allowed.
+    LoopInConstantContext {
+        /// The error location data.
+        location: Location,
+    },
+    /// An assignment is found in a constant context, where nothing can be mutated.
+    AssignmentInConstantContext {
+        /// The error location data.
+        location: Location,
+    },
+    /// A structure field shorthand, e.g. `{ a }`, refers to an undeclared variable.
+    StructureShorthandUndeclared {
+        /// The error location data.
+        location: Location,
+        /// The field and variable name.
+        name: String,
+    },
 
     /// The `match` expression error. See the inner element description.
     Match(MatchExpressionError),
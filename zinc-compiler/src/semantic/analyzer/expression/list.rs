@@ -33,7 +33,9 @@ impl Analyzer {
         rule: TranslationRule,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
         match rule {
-            TranslationRule::Constant => Self::constant(scope, list).map(|element| (element, None)),
+            TranslationRule::Constant | TranslationRule::Type => {
+                Self::compile_time(scope, list, rule).map(|element| (element, None))
+            }
             _rule => Self::runtime(scope, list)
                 .map(|(element, intermediate)| (element, Some(intermediate))),
         }
@@ -67,17 +69,21 @@ impl Analyzer {
     }
 
     ///
-    /// Returns the constant function argument list semantic element.
+    /// Returns the compile-time function argument list semantic element, whose arguments are
+    /// evaluated as constants or types, depending on the `rule`.
     ///
-    fn constant(scope: Rc<RefCell<Scope>>, list: ListExpression) -> Result<Element, Error> {
+    fn compile_time(
+        scope: Rc<RefCell<Scope>>,
+        list: ListExpression,
+        rule: TranslationRule,
+    ) -> Result<Element, Error> {
         let location = list.location;
 
         let mut arguments = Vec::with_capacity(list.len());
 
         for expression in list.elements.into_iter() {
             let (element, _intermediate) =
-                ExpressionAnalyzer::new(scope.clone(), TranslationRule::Constant)
-                    .analyze(expression)?;
+                ExpressionAnalyzer::new(scope.clone(), rule).analyze(expression)?;
             arguments.push(element);
         }
 
@@ -9,7 +9,7 @@ use zinc_lexical::Location;
 ///
 #[derive(Debug, PartialEq)]
 pub enum Error {
-    /// Only primitive types can act as scrutinee types (be matched) for now.
+    /// Only primitive types, tuples, and structures can act as scrutinee types (be matched).
     ScrutineeInvalidType {
         /// The error location data.
         location: Location,
@@ -68,4 +68,42 @@ pub enum Error {
         /// The first branch location, which helps user to find the error.
         reference: Location,
     },
+    /// The structure pattern field does not exist in the structure type.
+    BranchPatternFieldDoesNotExist {
+        /// The error location data.
+        location: Location,
+        /// The stringified structure type.
+        type_identifier: String,
+        /// The name of the invalid field.
+        field_name: String,
+    },
+    /// The structure pattern does not mention a field and has no `..` rest pattern.
+    BranchPatternFieldMissing {
+        /// The error location data.
+        location: Location,
+        /// The stringified structure type.
+        type_identifier: String,
+        /// The name of the missing field.
+        field_name: String,
+    },
+    /// The variant pattern does not exist in the enumeration type with payloads.
+    BranchPatternVariantDoesNotExist {
+        /// The error location data.
+        location: Location,
+        /// The stringified enumeration type.
+        type_identifier: String,
+        /// The name of the invalid variant.
+        variant_name: String,
+    },
+    /// The variant pattern payload elements number does not match the variant payload size.
+    BranchPatternPayloadInvalidLength {
+        /// The error location data.
+        location: Location,
+        /// The name of the variant.
+        variant_name: String,
+        /// The expected number of the payload elements.
+        expected: usize,
+        /// The actual number of the payload elements.
+        found: usize,
+    },
 }
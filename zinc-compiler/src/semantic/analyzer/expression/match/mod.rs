@@ -12,11 +12,18 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::Identifier;
 use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
 use zinc_syntax::MatchPatternVariant;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
+use crate::generator::expression::operand::r#match::pattern::Pattern as GeneratorMatchPattern;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
@@ -30,10 +37,15 @@ use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::memory_type::MemoryType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -49,6 +61,12 @@ impl Analyzer {
     /// The number of mandatory branches number to generate useful conditional code.
     const REQUIRED_BRANCHES_COUNT: usize = 2;
 
+    /// The `Maybe` variant with a payload.
+    const MAYBE_VARIANT_SOME: &'static str = "Some";
+
+    /// The `Maybe` variant without a payload.
+    const MAYBE_VARIANT_NONE: &'static str = "None";
+
     ///
     /// Analyzes the match expression.
     ///
@@ -88,7 +106,12 @@ impl Analyzer {
             ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                 .analyze(r#match.scrutinee)?;
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
+        if scrutinee_type.is_scalar()
+            || matches!(
+                scrutinee_type,
+                Type::Tuple(_) | Type::Structure(_) | Type::Enumeration(_)
+            )
+        {
             builder.set_scrutinee(
                 scrutinee_expression,
                 GeneratorType::try_from_semantic(&scrutinee_type)
@@ -126,7 +149,7 @@ impl Analyzer {
                 )));
             }
 
-            let result = match pattern.variant {
+            let result = match Self::payload_pattern(pattern.variant, &scrutinee_type) {
                 MatchPatternVariant::BooleanLiteral(boolean) => {
                     let location = boolean.location;
 
@@ -218,43 +241,29 @@ impl Analyzer {
                 MatchPatternVariant::Path(path) => {
                     let location = path.location;
 
-                    let constant =
-                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(path)?
-                        {
-                            (Element::Constant(constant), _intermediate) => {
-                                if let Constant::Integer(ref integer) = constant {
-                                    if let Some(duplicate) = exhausting_data.insert_integer(
-                                        integer.value.to_owned(),
-                                        integer.enumeration.to_owned(),
-                                        location,
-                                    ) {
-                                        return Err(Error::Expression(ExpressionError::Match(
-                                            MatchExpressionError::BranchDuplicate {
-                                                location,
-                                                reference: duplicate,
-                                            },
-                                        )));
-                                    }
-                                }
-                                constant
-                            }
-                            (element, _intermediate) => {
-                                return Err(Error::Expression(ExpressionError::Match(
-                                    MatchExpressionError::BranchPatternPathExpectedConstant {
-                                        location,
-                                        found: element.to_string(),
-                                    },
-                                )));
-                            }
-                        };
+                    let constant = Self::path_constant(scope_stack.top(), path)?;
+                    if let Constant::Integer(ref integer) = constant {
+                        if let Some(duplicate) = exhausting_data.insert_integer(
+                            integer.value.to_owned(),
+                            integer.enumeration.to_owned(),
+                            location,
+                        ) {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchDuplicate {
+                                    location,
+                                    reference: duplicate,
+                                },
+                            )));
+                        }
+                    }
+
                     let pattern_type = constant.r#type();
                     if pattern_type != scrutinee_type {
                         return Err(Error::Expression(ExpressionError::Match(
                             MatchExpressionError::BranchPatternInvalidType {
                                 location: pattern_location,
-                                expected: scrutinee_type.to_string(),
-                                found: pattern_type.to_string(),
+                                expected: Self::type_path_string(&scrutinee_type),
+                                found: Self::type_path_string(&pattern_type),
                                 reference: scrutinee_location,
                             },
                         )));
@@ -295,6 +304,106 @@ impl Analyzer {
 
                     result
                 }
+                variant @ MatchPatternVariant::Tuple(_)
+                | variant @ MatchPatternVariant::Structure { .. }
+                | variant @ MatchPatternVariant::Payload { .. } => {
+                    let maybe_is_some = match variant {
+                        MatchPatternVariant::Payload { ref elements, .. }
+                            if scrutinee_type.is_maybe() =>
+                        {
+                            Some(!elements.is_empty())
+                        }
+                        _ => None,
+                    };
+                    let enumeration_discriminant =
+                        match (&variant, Self::payload_enumeration(&scrutinee_type)) {
+                            (MatchPatternVariant::Payload { path, .. }, Some(enumeration))
+                            | (MatchPatternVariant::Structure { path, .. }, Some(enumeration)) => {
+                                let (_name, discriminant) = Self::enumeration_variant(
+                                    scope_stack.top(),
+                                    path.to_owned(),
+                                    enumeration,
+                                    scrutinee_location,
+                                )?;
+                                Some((enumeration.to_owned(), discriminant.value))
+                            }
+                            _ => None,
+                        };
+
+                    let mut destructuring = GeneratorMatchPattern::default();
+                    let mut bindings = Vec::new();
+                    Self::destructure(
+                        scope_stack.top(),
+                        MatchPattern::new(pattern_location, variant),
+                        &scrutinee_type,
+                        scrutinee_location,
+                        0,
+                        &mut destructuring,
+                        &mut bindings,
+                    )?;
+
+                    scope_stack.push(None);
+                    for (identifier, r#type) in bindings.into_iter() {
+                        Scope::define_variable(
+                            scope_stack.top(),
+                            identifier,
+                            false,
+                            r#type,
+                            MemoryType::Stack,
+                        )?;
+                    }
+                    let (result, branch) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(expression)?;
+                    scope_stack.pop();
+
+                    // only the variant checks without nested conditions exhaust the `Maybe` value
+                    if let Some(is_some) = maybe_is_some {
+                        if destructuring.conditions.len() == 1 {
+                            if let Some(duplicate) =
+                                exhausting_data.insert_boolean(is_some, pattern_location)
+                            {
+                                return Err(Error::Expression(ExpressionError::Match(
+                                    MatchExpressionError::BranchDuplicate {
+                                        location: pattern_location,
+                                        reference: duplicate,
+                                    },
+                                )));
+                            }
+                        }
+                    }
+
+                    // only the variant checks without nested conditions exhaust the enumeration value
+                    if let Some((ref enumeration, ref discriminant)) = enumeration_discriminant {
+                        if destructuring.conditions.len() == 1 {
+                            if let Some(duplicate) = exhausting_data.insert_integer(
+                                discriminant.to_owned(),
+                                Some(enumeration.to_owned()),
+                                pattern_location,
+                            ) {
+                                return Err(Error::Expression(ExpressionError::Match(
+                                    MatchExpressionError::BranchDuplicate {
+                                        location: pattern_location,
+                                        reference: duplicate,
+                                    },
+                                )));
+                            }
+                        }
+                    }
+
+                    if destructuring.is_irrefutable()
+                        || (maybe_is_some.is_some() && exhausting_data.has_exhausted_boolean())
+                        || (enumeration_discriminant.is_some()
+                            && exhausting_data.has_exhausted_integer())
+                    {
+                        is_exhausted = true;
+                        builder.set_destructuring_wildcard_branch(destructuring, branch);
+                    } else {
+                        builder.push_destructuring_branch(destructuring, branch);
+                    }
+
+                    result
+                }
                 MatchPatternVariant::Wildcard => {
                     is_exhausted = true;
                     let (result, branch) =
@@ -505,43 +614,29 @@ impl Analyzer {
                 MatchPatternVariant::Path(path) => {
                     let location = path.location;
 
-                    let constant =
-                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(path)?
-                        {
-                            (Element::Constant(constant), _intermediate) => {
-                                if let Constant::Integer(ref integer) = constant {
-                                    if let Some(duplicate) = exhausting_data.insert_integer(
-                                        integer.value.to_owned(),
-                                        integer.enumeration.to_owned(),
-                                        location,
-                                    ) {
-                                        return Err(Error::Expression(ExpressionError::Match(
-                                            MatchExpressionError::BranchDuplicate {
-                                                location,
-                                                reference: duplicate,
-                                            },
-                                        )));
-                                    }
-                                }
-                                constant
-                            }
-                            (element, _intermediate) => {
-                                return Err(Error::Expression(ExpressionError::Match(
-                                    MatchExpressionError::BranchPatternPathExpectedConstant {
-                                        location,
-                                        found: element.to_string(),
-                                    },
-                                )));
-                            }
-                        };
+                    let constant = Self::path_constant(scope_stack.top(), path)?;
+                    if let Constant::Integer(ref integer) = constant {
+                        if let Some(duplicate) = exhausting_data.insert_integer(
+                            integer.value.to_owned(),
+                            integer.enumeration.to_owned(),
+                            location,
+                        ) {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchDuplicate {
+                                    location,
+                                    reference: duplicate,
+                                },
+                            )));
+                        }
+                    }
+
                     let pattern_type = constant.r#type();
                     if pattern_type != scrutinee_type {
                         return Err(Error::Expression(ExpressionError::Match(
                             MatchExpressionError::BranchPatternInvalidType {
                                 location: pattern_location,
-                                expected: scrutinee_type.to_string(),
-                                found: pattern_type.to_string(),
+                                expected: Self::type_path_string(&scrutinee_type),
+                                found: Self::type_path_string(&pattern_type),
                                 reference: scrutinee_location,
                             },
                         )));
@@ -603,6 +698,52 @@ impl Analyzer {
 
                     result
                 }
+                MatchPatternVariant::Tuple(elements) => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location: pattern_location,
+                            expected: scrutinee_type.to_string(),
+                            found: Self::tuple_pattern_string(elements.len()),
+                            reference: scrutinee_location,
+                        },
+                    )));
+                }
+                MatchPatternVariant::Structure { path, .. } => {
+                    let found =
+                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Type)
+                            .analyze(path)?
+                        {
+                            (Element::Type(r#type), _intermediate) => r#type.to_string(),
+                            (element, _intermediate) => element.to_string(),
+                        };
+
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location: pattern_location,
+                            expected: scrutinee_type.to_string(),
+                            found,
+                            reference: scrutinee_location,
+                        },
+                    )));
+                }
+                MatchPatternVariant::Payload { path, .. } => {
+                    let found =
+                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Path)
+                            .analyze(path)?
+                        {
+                            (Element::Path(path), _intermediate) => path.to_string(),
+                            (element, _intermediate) => element.to_string(),
+                        };
+
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location: pattern_location,
+                            expected: scrutinee_type.to_string(),
+                            found,
+                            reference: scrutinee_location,
+                        },
+                    )));
+                }
                 MatchPatternVariant::Wildcard => {
                     is_exhausted = true;
                     let expression_location = expression.location;
@@ -657,4 +798,652 @@ impl Analyzer {
 
         Ok(element)
     }
+
+    ///
+    /// Checks the tuple or structure `pattern` against the scrutinee element `r#type`, which
+    /// starts at `offset` from the beginning of the scrutinee.
+    ///
+    /// The literal and constant sub-patterns are written to the `destructuring` conditions.
+    /// The identifier sub-patterns are written to the `destructuring` and semantic `bindings`.
+    ///
+    fn destructure(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        r#type: &Type,
+        reference: Location,
+        offset: usize,
+        destructuring: &mut GeneratorMatchPattern,
+        bindings: &mut Vec<(Identifier, Type)>,
+    ) -> Result<(), Error> {
+        let location = pattern.location;
+
+        let variant = match (Self::payload_pattern(pattern.variant, r#type), r#type) {
+            (variant @ MatchPatternVariant::Payload { .. }, Type::Enumeration(enumeration))
+            | (variant @ MatchPatternVariant::Structure { .. }, Type::Enumeration(enumeration))
+                if enumeration.has_payloads() =>
+            {
+                return Self::destructure_variant(
+                    scope,
+                    MatchPattern::new(location, variant),
+                    enumeration,
+                    reference,
+                    offset,
+                    destructuring,
+                    bindings,
+                );
+            }
+            (variant, _type) => variant,
+        };
+
+        match variant {
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let constant = BooleanConstant::from(boolean);
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference,
+                        },
+                    )));
+                }
+
+                let constant = GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
+                    .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                destructuring.push_condition(offset, constant);
+            }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let constant = IntegerConstant::try_from(&integer).map_err(|error| {
+                    Error::Element(ElementError::Constant(ConstantError::Integer(error)))
+                })?;
+                let pattern_type = constant.r#type();
+                if Caster::cast(&pattern_type, r#type).is_err() {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference,
+                        },
+                    )));
+                }
+
+                let constant = GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
+                    .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                destructuring.push_condition(offset, constant);
+            }
+            MatchPatternVariant::Path(path) => {
+                let constant = Self::path_constant(scope, path)?;
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location,
+                            expected: Self::type_path_string(r#type),
+                            found: Self::type_path_string(&pattern_type),
+                            reference,
+                        },
+                    )));
+                }
+
+                let constant = GeneratorConstant::try_from_semantic(&constant)
+                    .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                destructuring.push_condition(offset, constant);
+            }
+            MatchPatternVariant::Binding(identifier) => {
+                destructuring.push_binding(identifier.name.clone(), offset, r#type.size());
+                bindings.push((identifier, r#type.to_owned()));
+            }
+            MatchPatternVariant::Wildcard => {}
+            MatchPatternVariant::Tuple(elements) => {
+                let tuple = match r#type {
+                    Type::Tuple(tuple) if tuple.types.len() == elements.len() => tuple,
+                    r#type => {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternInvalidType {
+                                location,
+                                expected: r#type.to_string(),
+                                found: Self::tuple_pattern_string(elements.len()),
+                                reference,
+                            },
+                        )));
+                    }
+                };
+
+                let mut offset = offset;
+                for (element, r#type) in elements.into_iter().zip(tuple.types.iter()) {
+                    Self::destructure(
+                        scope.clone(),
+                        element,
+                        r#type,
+                        reference,
+                        offset,
+                        destructuring,
+                        bindings,
+                    )?;
+                    offset += r#type.size();
+                }
+            }
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                has_rest,
+            } => {
+                let path_location = path.location;
+                let pattern_type =
+                    match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Type)
+                        .analyze(path)?
+                    {
+                        (Element::Type(r#type), _intermediate) => r#type,
+                        (element, _intermediate) => {
+                            return Err(Error::Element(ElementError::Type(
+                                TypeError::AliasDoesNotPointToType {
+                                    location: path_location,
+                                    found: element.to_string(),
+                                },
+                            )));
+                        }
+                    };
+                let structure = match r#type {
+                    Type::Structure(structure) if &pattern_type == r#type => structure,
+                    r#type => {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternInvalidType {
+                                location,
+                                expected: r#type.to_string(),
+                                found: pattern_type.to_string(),
+                                reference,
+                            },
+                        )));
+                    }
+                };
+
+                let mut mentioned = Vec::with_capacity(fields.len());
+                for (identifier, pattern) in fields.into_iter() {
+                    let mut field_offset = offset;
+                    let mut field_type = None;
+                    for (name, r#type) in structure.fields.iter() {
+                        if name == &identifier.name {
+                            field_type = Some(r#type);
+                            break;
+                        }
+                        field_offset += r#type.size();
+                    }
+
+                    let field_type = match field_type {
+                        Some(field_type) => field_type,
+                        None => {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchPatternFieldDoesNotExist {
+                                    location: identifier.location,
+                                    type_identifier: structure.identifier.to_owned(),
+                                    field_name: identifier.name,
+                                },
+                            )));
+                        }
+                    };
+
+                    mentioned.push(identifier.name);
+                    Self::destructure(
+                        scope.clone(),
+                        pattern,
+                        field_type,
+                        reference,
+                        field_offset,
+                        destructuring,
+                        bindings,
+                    )?;
+                }
+
+                if !has_rest {
+                    if let Some((name, _type)) = structure
+                        .fields
+                        .iter()
+                        .find(|(name, _type)| !mentioned.contains(name))
+                    {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternFieldMissing {
+                                location,
+                                type_identifier: structure.identifier.to_owned(),
+                                field_name: name.to_owned(),
+                            },
+                        )));
+                    }
+                }
+            }
+            MatchPatternVariant::Payload { path, elements } => {
+                let (is_some, variant_name) =
+                    Self::maybe_variant(scope.clone(), path, r#type, reference)?;
+
+                let expected = if is_some { 1 } else { 0 };
+                if elements.len() != expected {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternPayloadInvalidLength {
+                            location,
+                            variant_name,
+                            expected,
+                            found: elements.len(),
+                        },
+                    )));
+                }
+
+                let constant = GeneratorConstant::try_from_semantic(&Constant::Boolean(
+                    BooleanConstant::new(location, is_some),
+                ))
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                destructuring.push_condition(offset, constant);
+
+                let value_type = match r#type {
+                    Type::Structure(structure) => &structure.fields[1].1,
+                    _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+                for element in elements.into_iter() {
+                    Self::destructure(
+                        scope.clone(),
+                        element,
+                        value_type,
+                        reference,
+                        offset + Type::boolean(None).size(),
+                        destructuring,
+                        bindings,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the enumeration variant `pattern` against the scrutinee element `enumeration` type,
+    /// which starts at `offset` from the beginning of the scrutinee.
+    ///
+    /// The variant discriminant is written to the `destructuring` conditions, and the payload
+    /// sub-patterns are destructured at the offsets of their fields after the discriminant.
+    ///
+    fn destructure_variant(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        enumeration: &EnumerationType,
+        reference: Location,
+        offset: usize,
+        destructuring: &mut GeneratorMatchPattern,
+        bindings: &mut Vec<(Identifier, Type)>,
+    ) -> Result<(), Error> {
+        let location = pattern.location;
+
+        let (path, elements, fields) = match pattern.variant {
+            MatchPatternVariant::Payload { path, elements } => (path, elements, None),
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                has_rest,
+            } => (path, vec![], Some((fields, has_rest))),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let (variant_name, discriminant) =
+            Self::enumeration_variant(scope.clone(), path, enumeration, reference)?;
+        let payload = enumeration
+            .payload(variant_name.as_str())
+            .cloned()
+            .unwrap_or_else(|| Payload::new(false, vec![]));
+
+        let constant = GeneratorConstant::try_from_semantic(&Constant::Integer(discriminant))
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        destructuring.push_condition(offset, constant);
+
+        let payload_offset = offset + Type::integer_unsigned(None, enumeration.bitlength).size();
+        match fields {
+            Some((fields, has_rest)) if payload.is_structure => {
+                let mut mentioned = Vec::with_capacity(fields.len());
+                for (identifier, pattern) in fields.into_iter() {
+                    let (field_offset, field_type) = match payload.field(identifier.name.as_str()) {
+                        Some(field) => field,
+                        None => {
+                            return Err(Error::Expression(ExpressionError::Match(
+                                MatchExpressionError::BranchPatternFieldDoesNotExist {
+                                    location: identifier.location,
+                                    type_identifier: format!(
+                                        "{}::{}",
+                                        enumeration.identifier, variant_name
+                                    ),
+                                    field_name: identifier.name,
+                                },
+                            )));
+                        }
+                    };
+
+                    mentioned.push(identifier.name);
+                    Self::destructure(
+                        scope.clone(),
+                        pattern,
+                        field_type,
+                        reference,
+                        payload_offset + field_offset,
+                        destructuring,
+                        bindings,
+                    )?;
+                }
+
+                if !has_rest {
+                    if let Some((name, _type)) = payload
+                        .fields
+                        .iter()
+                        .find(|(name, _type)| !mentioned.contains(name))
+                    {
+                        return Err(Error::Expression(ExpressionError::Match(
+                            MatchExpressionError::BranchPatternFieldMissing {
+                                location,
+                                type_identifier: format!(
+                                    "{}::{}",
+                                    enumeration.identifier, variant_name
+                                ),
+                                field_name: name.to_owned(),
+                            },
+                        )));
+                    }
+                }
+            }
+            None if !payload.is_structure => {
+                if elements.len() != payload.fields.len() {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternPayloadInvalidLength {
+                            location,
+                            variant_name,
+                            expected: payload.fields.len(),
+                            found: elements.len(),
+                        },
+                    )));
+                }
+
+                let mut field_offset = payload_offset;
+                for (element, (_name, r#type)) in elements.into_iter().zip(payload.fields.iter()) {
+                    Self::destructure(
+                        scope.clone(),
+                        element,
+                        r#type,
+                        reference,
+                        field_offset,
+                        destructuring,
+                        bindings,
+                    )?;
+                    field_offset += r#type.size();
+                }
+            }
+            fields => {
+                return Err(Error::Expression(ExpressionError::Match(
+                    MatchExpressionError::BranchPatternInvalidType {
+                        location,
+                        expected: Self::variant_pattern_string(
+                            enumeration,
+                            variant_name.as_str(),
+                            enumeration
+                                .payload(variant_name.as_str())
+                                .map(|payload| payload.is_structure),
+                        ),
+                        found: Self::variant_pattern_string(
+                            enumeration,
+                            variant_name.as_str(),
+                            Some(fields.is_some()),
+                        ),
+                        reference,
+                    },
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Converts the `None` and `Maybe::None` patterns, which are parsed as a binding and a path,
+    /// into the payload variant patterns, if the scrutinee `r#type` is `Maybe`.
+    ///
+    /// The path patterns are also converted into the payload variant patterns, if the
+    /// scrutinee `r#type` is an enumeration with payloads.
+    ///
+    fn payload_pattern(variant: MatchPatternVariant, r#type: &Type) -> MatchPatternVariant {
+        if Self::payload_enumeration(r#type).is_some() {
+            return match variant {
+                MatchPatternVariant::Path(path) => MatchPatternVariant::new_payload(path, vec![]),
+                variant => variant,
+            };
+        }
+
+        if !r#type.is_maybe() {
+            return variant;
+        }
+
+        match variant {
+            MatchPatternVariant::Path(path) => MatchPatternVariant::new_payload(path, vec![]),
+            MatchPatternVariant::Binding(identifier)
+                if identifier.name.as_str() == Self::MAYBE_VARIANT_NONE =>
+            {
+                let path = ExpressionTree::new(
+                    identifier.location,
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(identifier)),
+                );
+                MatchPatternVariant::new_payload(path, vec![])
+            }
+            variant => variant,
+        }
+    }
+
+    ///
+    /// Returns the enumeration type, if the scrutinee element `r#type` is an enumeration
+    /// with payloads.
+    ///
+    fn payload_enumeration(r#type: &Type) -> Option<&EnumerationType> {
+        match r#type {
+            Type::Enumeration(enumeration) if enumeration.has_payloads() => Some(enumeration),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Resolves the enumeration variant `path` of a payload or structure pattern, checked
+    /// against the scrutinee element `enumeration` type.
+    ///
+    /// Returns the variant name and discriminant.
+    ///
+    fn enumeration_variant(
+        scope: Rc<RefCell<Scope>>,
+        path: ExpressionTree,
+        enumeration: &EnumerationType,
+        reference: Location,
+    ) -> Result<(String, IntegerConstant), Error> {
+        let location = path.location;
+        let expected = Self::type_path_string(&Type::Enumeration(enumeration.to_owned()));
+
+        let path =
+            match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Path).analyze(path)? {
+                (Element::Path(path), _intermediate) => path,
+                (element, _intermediate) => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location,
+                            expected,
+                            found: element.to_string(),
+                            reference,
+                        },
+                    )));
+                }
+            };
+
+        let constant = match *Scope::resolve_path(scope, &path)?.borrow() {
+            ScopeItem::Variant(ref variant) => variant.constant.to_owned(),
+            ref item => {
+                return Err(Error::Expression(ExpressionError::Match(
+                    MatchExpressionError::BranchPatternInvalidType {
+                        location,
+                        expected,
+                        found: item.to_string(),
+                        reference,
+                    },
+                )));
+            }
+        };
+
+        match constant {
+            Constant::Integer(integer) if integer.enumeration.as_ref() == Some(enumeration) => {
+                Ok((path.last().name.to_owned(), integer))
+            }
+            constant => Err(Error::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternInvalidType {
+                    location,
+                    expected,
+                    found: Self::type_path_string(&constant.r#type()),
+                    reference,
+                },
+            ))),
+        }
+    }
+
+    ///
+    /// Resolves the `Maybe` variant `path` of a payload pattern, checked against the
+    /// scrutinee element `r#type`.
+    ///
+    /// Returns whether the variant is `Some`, and the variant name.
+    ///
+    fn maybe_variant(
+        scope: Rc<RefCell<Scope>>,
+        path: ExpressionTree,
+        r#type: &Type,
+        reference: Location,
+    ) -> Result<(bool, String), Error> {
+        let location = path.location;
+
+        let path =
+            match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Path).analyze(path)? {
+                (Element::Path(path), _intermediate) => path,
+                (element, _intermediate) => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: element.to_string(),
+                            reference,
+                        },
+                    )));
+                }
+            };
+
+        let mut owner = path.elements.clone();
+        owner.pop();
+        let is_owner_maybe = owner.is_empty()
+            || match *Scope::resolve_path(scope, &Path::new_complex(location, owner))?.borrow() {
+                ScopeItem::Type(ref item) => item.define()?.is_maybe(),
+                _ => false,
+            };
+        if !r#type.is_maybe() || !is_owner_maybe {
+            return Err(Error::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternInvalidType {
+                    location,
+                    expected: r#type.to_string(),
+                    found: path.to_string(),
+                    reference,
+                },
+            )));
+        }
+
+        let variant = path.last();
+        match variant.name.as_str() {
+            Self::MAYBE_VARIANT_SOME => Ok((true, variant.name.to_owned())),
+            Self::MAYBE_VARIANT_NONE => Ok((false, variant.name.to_owned())),
+            _ => Err(Error::Expression(ExpressionError::Match(
+                MatchExpressionError::BranchPatternVariantDoesNotExist {
+                    location: variant.location,
+                    type_identifier: r#type.to_string(),
+                    variant_name: variant.name.to_owned(),
+                },
+            ))),
+        }
+    }
+
+    ///
+    /// Resolves the constant or enumeration variant at the `path` of a path pattern.
+    ///
+    /// The path may have any number of segments, e.g. `module::Enumeration::Variant`.
+    ///
+    fn path_constant(scope: Rc<RefCell<Scope>>, path: ExpressionTree) -> Result<Constant, Error> {
+        let location = path.location;
+
+        let path =
+            match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Path).analyze(path)? {
+                (Element::Path(path), _intermediate) => path,
+                (element, _intermediate) => {
+                    return Err(Error::Expression(ExpressionError::Match(
+                        MatchExpressionError::BranchPatternPathExpectedConstant {
+                            location,
+                            found: element.to_string(),
+                        },
+                    )));
+                }
+            };
+
+        let item = Scope::resolve_path(scope, &path)?;
+        let mut constant = match *item.borrow() {
+            ScopeItem::Constant(ref constant) => constant.define()?,
+            ScopeItem::Variant(ref variant) => variant.constant.to_owned(),
+            ScopeItem::Type(ref r#type) => {
+                return Err(Error::Expression(ExpressionError::Match(
+                    MatchExpressionError::BranchPatternPathExpectedConstant {
+                        location,
+                        found: Element::Type(r#type.define()?).to_string(),
+                    },
+                )));
+            }
+            ref item => {
+                return Err(Error::Expression(ExpressionError::Match(
+                    MatchExpressionError::BranchPatternPathExpectedConstant {
+                        location,
+                        found: item.to_string(),
+                    },
+                )));
+            }
+        };
+        constant.set_location(location);
+
+        Ok(constant)
+    }
+
+    ///
+    /// Stringifies the `r#type` for the pattern type mismatch errors.
+    ///
+    /// The enumerations are qualified with their module paths, since the enumerations with the
+    /// same name may be declared in different modules.
+    ///
+    fn type_path_string(r#type: &Type) -> String {
+        match r#type {
+            Type::Enumeration(enumeration) => format!("enumeration {}", enumeration.path()),
+            r#type => r#type.to_string(),
+        }
+    }
+
+    ///
+    /// Stringifies an enumeration variant pattern for error messages, e.g. `Action::Noop`,
+    /// `Action::Burn(..)`, or `Action::Transfer { .. }`, depending on whether the pattern
+    /// `is_structure`, if it has a payload at all.
+    ///
+    fn variant_pattern_string(
+        enumeration: &EnumerationType,
+        variant_name: &str,
+        is_structure: Option<bool>,
+    ) -> String {
+        match is_structure {
+            Some(true) => format!("{}::{} {{ .. }}", enumeration.identifier, variant_name),
+            Some(false) => format!("{}::{}(..)", enumeration.identifier, variant_name),
+            None => format!("{}::{}", enumeration.identifier, variant_name),
+        }
+    }
+
+    ///
+    /// Stringifies a tuple pattern of `size` elements for error messages, e.g. `(_, _)`.
+    ///
+    fn tuple_pattern_string(size: usize) -> String {
+        format!("({})", vec!["_"; size].join(", "))
+    }
 }
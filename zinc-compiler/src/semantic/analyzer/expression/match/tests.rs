@@ -92,6 +92,148 @@ fn main() -> u8 {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_enumeration_nested_module_path() {
+    let module_inner = r#"
+enum Status {
+    Ok = 1,
+    Error = 2,
+}
+"#;
+
+    let module_outer = r#"
+mod inner;
+"#;
+
+    let entry = r#"
+mod outer;
+
+fn main() -> u8 {
+    let value = outer::inner::Status::Ok;
+    match value {
+        outer::inner::Status::Ok => 10,
+        outer::inner::Status::Error => 20,
+    }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "outer".to_owned(),
+            Source::test(
+                module_outer,
+                PathBuf::from("outer.zn"),
+                vec![(
+                    "inner".to_owned(),
+                    Source::test(module_inner, PathBuf::from("inner.zn"), HashMap::new())
+                        .expect(zinc_const::panic::TEST_DATA_VALID),
+                )]
+                .into_iter()
+                .collect::<HashMap<String, Source>>(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_enumeration_use_alias() {
+    let module_1 = r#"
+enum Status {
+    Ok = 1,
+    Error = 2,
+}
+"#;
+
+    let entry = r#"
+mod module_1;
+
+use module_1::Status as State;
+
+fn main() -> u8 {
+    let value = module_1::Status::Error;
+    match value {
+        State::Ok => 10,
+        module_1::Status::Error => 20,
+    }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "module_1".to_owned(),
+            Source::test(module_1, PathBuf::from("module_1.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_tuple() {
+    let input = r#"
+fn main() -> u8 {
+    let pair = (0, 42);
+    match pair {
+        (0, b) => b,
+        (a, _) => a,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() -> u8 {
+    let point = Point { x: 0, y: 42 };
+    match point {
+        Point { x: 0, y } => y,
+        Point { y: 1, .. } => 1,
+        _ => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure_nested_tuple() {
+    let input = r#"
+struct Pair {
+    key: u8,
+    value: (bool, u8),
+}
+
+fn main() -> u8 {
+    let pair = Pair { key: 1, value: (true, 42) };
+    match pair {
+        Pair { key: 1, value: (true, value) } => value,
+        _ => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_scrutinee_invalid_type() {
     let input = r#"
@@ -343,6 +485,66 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_branch_pattern_invalid_enum_module_path() {
+    let module_1 = r#"
+enum Status {
+    Ok = 1,
+    Error = 2,
+}
+"#;
+
+    let module_2 = r#"
+enum Status {
+    Ok = 1,
+    Error = 2,
+}
+"#;
+
+    let entry = r#"
+mod module_1;
+mod module_2;
+
+fn main() -> u8 {
+    let value = module_1::Status::Ok;
+    match value {
+        module_1::Status::Ok => 1,
+        module_2::Status::Error => 2,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternInvalidType {
+            location: Location::test(9, 9),
+            expected: "enumeration module_1::Status".to_owned(),
+            found: "enumeration module_2::Status".to_owned(),
+            reference: Location::test(7, 11),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![
+            (
+                "module_1".to_owned(),
+                Source::test(module_1, PathBuf::from("module_1.zn"), HashMap::new())
+                    .expect(zinc_const::panic::TEST_DATA_VALID),
+            ),
+            (
+                "module_2".to_owned(),
+                Source::test(module_2, PathBuf::from("module_2.zn"), HashMap::new())
+                    .expect(zinc_const::panic::TEST_DATA_VALID),
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    );
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_branch_expression_invalid_type() {
     let input = r#"
@@ -416,3 +618,284 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_not_exhausted_tuple() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, 2);
+    let result = match scrutinee {
+        (1, b) => b,
+        (2, b) => b,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::NotExhausted {
+            location: Location::test(4, 18),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_invalid_type_tuple_length() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, 2);
+    let result = match scrutinee {
+        (1, 2, 3) => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternInvalidType {
+            location: Location::test(5, 9),
+            expected: Type::tuple(
+                None,
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                ],
+            )
+            .to_string(),
+            found: "(_, _, _)".to_owned(),
+            reference: Location::test(4, 24),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_field_does_not_exist() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let scrutinee = Point { x: 1, y: 2 };
+    let result = match scrutinee {
+        Point { z: 1, .. } => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternFieldDoesNotExist {
+            location: Location::test(10, 17),
+            type_identifier: "Point".to_owned(),
+            field_name: "z".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_field_missing() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let scrutinee = Point { x: 1, y: 2 };
+    let result = match scrutinee {
+        Point { x: 1 } => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternFieldMissing {
+            location: Location::test(10, 9),
+            type_identifier: "Point".to_owned(),
+            field_name: "y".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_maybe() {
+    let input = r#"
+fn main(scrutinee: Maybe<u8>) -> u8 {
+    match scrutinee {
+        Maybe::Some(0) => 1,
+        Some(value) => value,
+        None => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_maybe() {
+    let input = r#"
+fn main(scrutinee: Maybe<u8>) {
+    let result = match scrutinee {
+        Some(0) => 10,
+        None => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::NotExhausted {
+            location: Location::test(3, 18),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_variant_does_not_exist() {
+    let input = r#"
+fn main(scrutinee: Maybe<u8>) {
+    let result = match scrutinee {
+        Maybe::Other(value) => value,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternVariantDoesNotExist {
+            location: Location::test(4, 16),
+            type_identifier: "structure Maybe<u8>".to_owned(),
+            variant_name: "Other".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_payload_invalid_length() {
+    let input = r#"
+fn main(scrutinee: Maybe<u8>) {
+    let result = match scrutinee {
+        None(value) => value,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternPayloadInvalidLength {
+            location: Location::test(4, 9),
+            variant_name: "None".to_owned(),
+            expected: 0,
+            found: 1,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_enumeration_payload() {
+    let input = r#"
+enum Action {
+    Transfer { to: u160, amount: u248 },
+    Burn(u248),
+    Noop,
+}
+
+fn main(action: Action) -> u248 {
+    match action {
+        Action::Transfer { amount: 0, .. } => 1 as u248,
+        Action::Transfer { to, amount } => amount,
+        Action::Burn(amount) => amount,
+        Action::Noop => 0 as u248,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_enumeration_payload() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main(action: Action) {
+    let result = match action {
+        Action::Burn(0) => 10,
+        Action::Noop => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::NotExhausted {
+            location: Location::test(8, 18),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_invalid_type_enumeration_payload() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main(action: Action) {
+    let result = match action {
+        Action::Burn { value: value } => value,
+        _ => 0 as u248,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::Match(MatchExpressionError::BranchPatternInvalidType {
+            location: Location::test(9, 9),
+            expected: "Action::Burn(..)".to_owned(),
+            found: "Action::Burn { .. }".to_owned(),
+            reference: Location::test(8, 24),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
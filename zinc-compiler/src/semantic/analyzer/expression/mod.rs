@@ -21,6 +21,7 @@ pub mod structure;
 pub mod tuple;
 pub mod tuple_index;
 pub mod r#type;
+pub mod variant;
 
 use std::cell::RefCell;
 use std::ops::Add;
@@ -41,6 +42,9 @@ use zinc_syntax::ExpressionOperand;
 use zinc_syntax::ExpressionOperator;
 use zinc_syntax::ExpressionTree;
 use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::ListExpression;
+use zinc_syntax::StructureExpression;
+use zinc_syntax::Type as SyntaxType;
 
 use crate::generator::expression::element::Element as GeneratorExpressionElement;
 use crate::generator::expression::operand::constant::integer::Integer as GeneratorExpressionIntegerConstant;
@@ -49,27 +53,42 @@ use crate::generator::expression::operand::Operand as GeneratorExpressionOperand
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::casting::Caster;
 use crate::semantic::element::access::dot::Dot as DotAccess;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::path::Path;
 use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::element::place::memory_type::MemoryType as PlaceMemoryType;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 use self::array::Analyzer as ArrayAnalyzer;
 use self::block::Analyzer as BlockAnalyzer;
 use self::call::r#type::Type as CallType;
 use self::call::Analyzer as CallAnalyzer;
 use self::conditional::Analyzer as ConditionalAnalyzer;
+use self::error::Error as ExpressionError;
 use self::identifier::Analyzer as IdentifierAnalyzer;
 use self::list::Analyzer as ListAnalyzer;
 use self::literal::Analyzer as LiteralAnalyzer;
@@ -82,6 +101,7 @@ use self::stack::Stack as EvaluationStack;
 use self::structure::Analyzer as StructureAnalyzer;
 use self::tuple::Analyzer as TupleAnalyzer;
 use self::tuple_index::Analyzer as TupleIndexAnalyzer;
+use self::variant::Analyzer as VariantAnalyzer;
 
 ///
 /// The expression semantic analyzer.
@@ -164,6 +184,13 @@ impl Analyzer {
                     rule,
                 );
             }
+            ExpressionTreeNode::Operator(operator)
+                if matches!(rule, TranslationRule::Constant) && operator.is_assignment() =>
+            {
+                return Err(Error::Expression(ExpressionError::AssignmentInConstantContext {
+                    location: tree.location,
+                }));
+            }
             ExpressionTreeNode::Operator(operator) => match operator {
                 ExpressionOperator::Assignment => {
                     self.left_separate(tree.left, operator, rule)?;
@@ -308,6 +335,12 @@ impl Analyzer {
                     );
                 }
 
+                ExpressionOperator::Range | ExpressionOperator::RangeInclusive
+                    if Self::is_range_dynamic(&tree.left, &tree.right) =>
+                {
+                    let is_inclusive = operator == ExpressionOperator::RangeInclusive;
+                    return self.range_dynamic(tree.left, tree.right, rule, is_inclusive);
+                }
                 ExpressionOperator::Range => {
                     let _intermediate = self.left_separate(tree.left, operator, rule)?;
                     let _intermediate = self.right_separate(tree.right, operator, rule)?;
@@ -541,18 +574,68 @@ impl Analyzer {
                 ExpressionOperator::Call => {
                     self.left_local(tree.left, operator, rule)?;
 
-                    // forces the constant translation rule, which prevents the arguments to be written to the IR
-                    let rule = match self.evaluation_stack.top() {
-                        StackElement::Evaluated(Element::Type(Type::Function(
-                            FunctionType::Constant(_),
-                        ))) => TranslationRule::Constant,
-                        _element => self.rule,
-                    };
-
-                    self.right_local(tree.right, operator, rule)?;
-
-                    let intermediate = self.call(tree.location, rule)?;
-                    self.intermediate.push_element(intermediate);
+                    // the tuple-like enumeration variants are constructed with the call syntax
+                    if let Some(variant) = self.take_payload_variant() {
+                        let list = match tree.right.map(|tree| *tree.value) {
+                            Some(ExpressionTreeNode::Operand(ExpressionOperand::List(list))) => {
+                                list
+                            }
+                            _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        };
+
+                        self.variant_tuple(variant, list, rule)?;
+                    } else if let Some(newtype) = self.take_newtype() {
+                        let list = match tree.right.map(|tree| *tree.value) {
+                            Some(ExpressionTreeNode::Operand(ExpressionOperand::List(list))) => {
+                                list
+                            }
+                            _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        };
+
+                        self.newtype(newtype, tree.location, list, rule)?;
+                    } else if let StackElement::Evaluated(Element::Type(Type::Function(
+                        FunctionType::Intrinsic(IntrinsicFunctionType::Mem(_)),
+                    ))) = self.evaluation_stack.top()
+                    {
+                        // the `std::mem` functions accept a type as the argument, e.g. `size_of(Data)`
+                        let list = match tree.right.map(|tree| *tree.value) {
+                            Some(ExpressionTreeNode::Operand(ExpressionOperand::List(list))) => {
+                                list
+                            }
+                            _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                        };
+
+                        let (element, _intermediate) = ListAnalyzer::analyze(
+                            self.scope_stack.top(),
+                            list,
+                            TranslationRule::Type,
+                        )?;
+                        self.evaluation_stack.push(StackElement::Evaluated(element));
+
+                        let intermediate = self.call(tree.location, TranslationRule::Constant)?;
+                        self.intermediate.push_element(intermediate);
+                    } else {
+                        // forces the constant translation rule, which prevents the arguments to be written to the IR
+                        let rule = match self.evaluation_stack.top() {
+                            StackElement::Evaluated(Element::Type(Type::Function(
+                                FunctionType::Constant(_),
+                            ))) => TranslationRule::Constant,
+                            _element => self.rule,
+                        };
+
+                        self.right_local(tree.right, operator, rule)?;
+
+                        let intermediate = self.call(tree.location, rule)?;
+
+                        // removes the argument list from the IR if the call has been evaluated at compile time
+                        if !matches!(rule, TranslationRule::Constant)
+                            && matches!(intermediate, GeneratorExpressionElement::Operand(_))
+                        {
+                            self.intermediate.pop_element();
+                        }
+
+                        self.intermediate.push_element(intermediate);
+                    }
                 }
                 ExpressionOperator::CallIntrinsic => {
                     self.next_call_type = CallType::MacroLike;
@@ -566,12 +649,30 @@ impl Analyzer {
 
                     self.path()?;
                 }
+                ExpressionOperator::Generics => {
+                    self.left_local(tree.left, operator, rule)?;
+
+                    let generics = match tree.right.map(|tree| *tree.value) {
+                        Some(ExpressionTreeNode::Operand(ExpressionOperand::Generics(
+                            generics,
+                        ))) => generics,
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+
+                    self.generics(tree.location, generics)?;
+                }
 
                 ExpressionOperator::Structure => {
                     self.left_local(tree.left, operator, rule)?;
-                    self.right_local(tree.right, operator, rule)?;
 
-                    self.structure()?;
+                    let structure = match tree.right.map(|tree| *tree.value) {
+                        Some(ExpressionTreeNode::Operand(ExpressionOperand::Structure(
+                            structure,
+                        ))) => structure,
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+
+                    self.structure(structure, TranslationRule::second(operator, rule))?;
                 }
             },
         }
@@ -645,14 +746,54 @@ impl Analyzer {
         rule: TranslationRule,
     ) -> Result<GeneratorExpression, Error> {
         let rule = TranslationRule::first(operator, rule);
+        let assignee = if operator.is_assignment() {
+            left.as_deref()
+                .and_then(|left| Self::unused_assignee(self.scope_stack.top(), left))
+        } else {
+            None
+        };
         let (element, intermediate) = match left {
             Some(left) => Self::new(self.scope_stack.top(), rule).analyze(*left)?,
             None => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
+        if let Some(assignee) = assignee {
+            assignee.borrow().mark_unused();
+        }
         self.evaluation_stack.push(StackElement::Evaluated(element));
         Ok(intermediate)
     }
 
+    ///
+    /// Returns the variable which is the whole target of an assignment, if it has not been
+    /// read yet.
+    ///
+    /// Writing to a variable does not count as its usage, so the variable is marked as unused
+    /// again after its place is resolved.
+    ///
+    fn unused_assignee(
+        scope: Rc<RefCell<Scope>>,
+        left: &ExpressionTree,
+    ) -> Option<Rc<RefCell<ScopeItem>>> {
+        let identifier = match *left.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref identifier)) => {
+                identifier
+            }
+            _ => return None,
+        };
+
+        let item = scope
+            .borrow()
+            .resolve_declared_item(identifier, true)
+            .ok()?;
+        let is_unused_variable =
+            matches!(*item.borrow(), ScopeItem::Variable(_)) && !item.borrow().is_used();
+        if is_unused_variable {
+            Some(item)
+        } else {
+            None
+        }
+    }
+
     ///
     /// Analyzes the right operand of a binary operand.
     ///
@@ -704,6 +845,17 @@ impl Analyzer {
                 },
             )));
         }
+        if let PlaceMemoryType::ContractStorage = place.memory_type {
+            if let Some(method) = Scope::query_method(self.scope_stack.top()) {
+                return Err(Error::Element(ElementError::Place(
+                    PlaceError::MutatingStorageFromQuery {
+                        location: place.identifier.location,
+                        place: place.to_string(),
+                        method,
+                    },
+                )));
+            }
+        }
         if !place.is_mutable {
             let item_location = self
                 .scope_stack
@@ -716,7 +868,8 @@ impl Analyzer {
             return Err(Error::Element(ElementError::Place(
                 PlaceError::MutatingImmutableMemory {
                     location: place.identifier.location,
-                    name: place.identifier.name,
+                    name: place.identifier.name.to_owned(),
+                    place: place.to_string(),
                     reference: item_location,
                 },
             )));
@@ -742,7 +895,7 @@ impl Analyzer {
     ///
     /// Analyzes the binary operation, which can be logical, comparison, bitwise or arithmetic.
     ///
-    fn binary<F>(
+    fn binary<F, E>(
         &mut self,
         callback: F,
         location: Location,
@@ -750,7 +903,8 @@ impl Analyzer {
         intermediate_2: GeneratorExpression,
     ) -> Result<(), Error>
     where
-        F: FnOnce(Element, Element) -> Result<(Element, GeneratorExpressionOperator), ElementError>,
+        F: FnOnce(Element, Element) -> Result<(Element, GeneratorExpressionOperator), E>,
+        Error: From<E>,
     {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
@@ -763,9 +917,40 @@ impl Analyzer {
             self.rule,
         )?;
 
-        let (result, operator) = callback(operand_1, operand_2).map_err(Error::Element)?;
+        let literal_1 = Self::inferred_literal(&operand_1, &operand_2);
+        let literal_2 = Self::inferred_literal(&operand_2, &operand_1);
+
+        let is_string_operation = matches!(
+            (&operand_1, &operand_2),
+            (
+                Element::Constant(Constant::String(_)),
+                Element::Constant(Constant::String(_))
+            )
+        );
+
+        let (result, operator) = callback(operand_1, operand_2)?;
+
+        // the strings only exist at compile time, so the folded result is written instead
+        if is_string_operation {
+            if let Element::Constant(ref constant) = result {
+                if let Some(constant) = GeneratorExpressionConstant::try_from_semantic(constant) {
+                    self.intermediate
+                        .push_operand(GeneratorExpressionOperand::Constant(constant));
+                }
+            }
+            self.evaluation_stack.push(StackElement::Evaluated(result));
+            return Ok(());
+        }
+
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
+        if let (Some(literal), Some(_)) = (literal_1, operator.operand_1_inferred_type()) {
+            self.check_inferred_literal(literal)?;
+        }
+        if let (Some(literal), Some(_)) = (literal_2, operator.operand_2_inferred_type()) {
+            self.check_inferred_literal(literal)?;
+        }
+
         self.intermediate.append_expression(intermediate_1);
         if let Some(r#type) = operator.operand_1_inferred_type() {
             self.intermediate
@@ -802,6 +987,73 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Returns the integer literal `operand` along with the type and location of the `other`
+    /// operand, if the literal type is going to be inferred from the non-literal `other` one.
+    ///
+    fn inferred_literal(
+        operand: &Element,
+        other: &Element,
+    ) -> Option<(IntegerConstant, Type, Option<Location>)> {
+        let literal = match operand {
+            Element::Constant(Constant::Integer(integer))
+                if integer.is_literal && integer.enumeration.is_none() =>
+            {
+                integer
+            }
+            _ => return None,
+        };
+
+        match other {
+            Element::Value(Value::Integer(integer))
+                if !integer.is_literal && integer.enumeration.is_none() =>
+            {
+                Some((literal.to_owned(), integer.r#type(), integer.location))
+            }
+            Element::Constant(Constant::Integer(integer))
+                if !integer.is_literal && integer.enumeration.is_none() =>
+            {
+                Some((literal.to_owned(), integer.r#type(), Some(integer.location)))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks the integer `literal`, whose type has been inferred from the other operand.
+    ///
+    /// Reports a warning, if the literal type has been widened.
+    ///
+    fn check_inferred_literal(
+        &self,
+        (literal, r#type, reference): (IntegerConstant, Type, Option<Location>),
+    ) -> Result<(), Error> {
+        let literal_type = literal.r#type();
+
+        let is_widened =
+            Caster::cast_literal(&literal.value, &literal_type, &r#type).map_err(|error| {
+                Error::Element(ElementError::Value(ValueError::Casting {
+                    location: literal.location,
+                    inner: error,
+                    reference: reference.unwrap_or(literal.location),
+                }))
+            })?;
+
+        if is_widened {
+            Scope::push_warning(
+                self.scope_stack.top(),
+                Warning::IntegerLiteralWidened {
+                    location: literal.location,
+                    value: literal.value.to_string(),
+                    from: literal_type.to_string(),
+                    to: r#type.to_string(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
     ///
     /// Analyzes the range operation, returns the range start value as the IR expression operand.
     ///
@@ -836,6 +1088,83 @@ impl Analyzer {
         Ok(intermediate)
     }
 
+    ///
+    /// Checks if the range operands have the `start .. start + length` form, where `start` is
+    /// the same expression in both bounds.
+    ///
+    fn is_range_dynamic(
+        left: &Option<Box<ExpressionTree>>,
+        right: &Option<Box<ExpressionTree>>,
+    ) -> bool {
+        match (left.as_ref(), right.as_ref()) {
+            (Some(start), Some(end)) => match (end.value.as_ref(), end.left.as_ref()) {
+                (ExpressionTreeNode::Operator(ExpressionOperator::Addition), Some(end_start)) => {
+                    start.is_equivalent(end_start)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    ///
+    /// Analyzes the range operation with a runtime start and a constant length.
+    ///
+    /// If the start is known at compile-time, the range is analyzed as a constant one.
+    ///
+    /// Returns the range start expression as the IR, which becomes the array slice offset.
+    ///
+    fn range_dynamic(
+        &mut self,
+        left: Option<Box<ExpressionTree>>,
+        right: Option<Box<ExpressionTree>>,
+        rule: TranslationRule,
+        is_inclusive: bool,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let operator = if is_inclusive {
+            ExpressionOperator::RangeInclusive
+        } else {
+            ExpressionOperator::Range
+        };
+
+        let intermediate = self.left_separate(left, operator, rule)?;
+
+        if let StackElement::Evaluated(Element::Constant(_)) = self.evaluation_stack.top() {
+            let _intermediate = self.right_separate(right, operator, rule)?;
+            let intermediate = if is_inclusive {
+                self.range(Element::range_inclusive)?
+            } else {
+                self.range(Element::range)?
+            };
+
+            return match self.evaluation_stack.pop() {
+                StackElement::Evaluated(element) => Ok((element, Some(intermediate))),
+                _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+            };
+        }
+
+        let length = right.and_then(|end| end.right);
+        let _intermediate = self.right_separate(length, operator, rule)?;
+
+        let (length, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            self.rule,
+        )?;
+        let (start, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            self.rule,
+        )?;
+
+        let result = start
+            .range_dynamic(length, is_inclusive)
+            .map_err(Error::Element)?;
+        self.intermediate.append_expression(intermediate);
+
+        Ok((result, None))
+    }
+
     ///
     /// Analyzes the casting operation.
     ///
@@ -911,6 +1240,14 @@ impl Analyzer {
             TranslationRule::Place,
         )?;
 
+        let array_length = match operand_1 {
+            Element::Place(Place {
+                r#type: Type::Array(ref array),
+                ..
+            }) => array.size,
+            _ => 0,
+        };
+
         let (result, access) =
             Element::index(operand_1, operand_2.clone()).map_err(Error::Element)?;
 
@@ -937,6 +1274,13 @@ impl Analyzer {
                             access,
                         })
                     }
+                    Element::RangeDynamic(_) => {
+                        place.push_element(PlaceElement::IndexRangeDynamic {
+                            expression,
+                            array_length,
+                            access,
+                        })
+                    }
                     _ => place.push_element(PlaceElement::IndexExpression { expression, access }),
                 }
 
@@ -968,7 +1312,7 @@ impl Analyzer {
             TranslationRule::Place,
         )?;
 
-        let (result, access) = Element::dot(operand_1, operand_2)?;
+        let (result, access) = Element::dot(operand_1, operand_2, self.scope_stack.top())?;
 
         match access {
             DotAccess::StackField(access) => match result {
@@ -1001,26 +1345,40 @@ impl Analyzer {
                     Ok(None)
                 }
             },
-            DotAccess::Method { instance } => {
-                let (instance, is_mutable) = if let Element::Place(instance) = *instance {
-                    let is_mutable = instance.is_mutable;
-
-                    let (instance, intermedidate) = Self::evaluate(
-                        self.scope_stack.top(),
-                        StackElement::Evaluated(Element::Place(instance)),
-                        TranslationRule::Value,
-                    )?;
-                    if let Some(intermediate) = intermedidate {
-                        self.intermediate.push_operand(intermediate);
-                    }
+            DotAccess::Newtype => {
+                self.evaluation_stack.push(StackElement::Evaluated(result));
 
-                    (instance, is_mutable)
-                } else {
-                    (*instance, true)
-                };
+                Ok(None)
+            }
+            DotAccess::Method { instance } => {
+                let (instance, is_mutable, is_storage, storage_place) =
+                    if let Element::Place(instance) = *instance {
+                        let is_mutable = instance.is_mutable;
+                        let is_storage =
+                            matches!(instance.memory_type, PlaceMemoryType::ContractStorage);
+                        let storage_place = if instance.is_storage_projection() {
+                            Some(instance.clone())
+                        } else {
+                            None
+                        };
+
+                        let (instance, intermedidate) = Self::evaluate(
+                            self.scope_stack.top(),
+                            StackElement::Evaluated(Element::Place(instance)),
+                            TranslationRule::Value,
+                        )?;
+                        if let Some(intermediate) = intermedidate {
+                            self.intermediate.push_operand(intermediate);
+                        }
+
+                        (instance, is_mutable, is_storage, storage_place)
+                    } else {
+                        (*instance, true, false, None)
+                    };
 
                 self.evaluation_stack.push(StackElement::Evaluated(result));
-                self.next_call_type = CallType::new_method(instance, is_mutable);
+                self.next_call_type =
+                    CallType::new_method(instance, is_mutable, is_storage, storage_place);
 
                 Ok(None)
             }
@@ -1080,20 +1438,100 @@ impl Analyzer {
     }
 
     ///
-    /// Analyzes the structure initialization operation.
+    /// Analyzes the generic type arguments `::<...>` operation.
     ///
-    fn structure(&mut self) -> Result<(), Error> {
-        let (operand_2, _) = Self::evaluate(
+    fn generics(&mut self, location: Location, generics: Vec<SyntaxType>) -> Result<(), Error> {
+        let (operand, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
-            self.rule,
+            TranslationRule::Type,
         )?;
+
+        let mut params = Vec::with_capacity(generics.len());
+        for generic in generics.into_iter() {
+            params.push(Type::try_from_syntax(generic, self.scope_stack.top())?);
+        }
+
+        let result = match operand {
+            Element::Type(Type::Function(FunctionType::Generic(mut function))) => {
+                function.set_params(location, params).map_err(|error| {
+                    Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::Generic(error),
+                    )))
+                })?;
+                Element::Type(Type::Function(FunctionType::Generic(function)))
+            }
+            Element::Type(Type::Function(FunctionType::Intrinsic(IntrinsicFunctionType::Mem(
+                mut function,
+            )))) => {
+                function.set_params(location, params).map_err(|error| {
+                    Error::Element(ElementError::Type(TypeError::Function(
+                        FunctionError::Generic(error),
+                    )))
+                })?;
+                Element::Type(Type::Function(FunctionType::Intrinsic(
+                    IntrinsicFunctionType::Mem(function),
+                )))
+            }
+            operand => {
+                return Err(Error::Element(ElementError::Type(TypeError::Function(
+                    FunctionError::Generic(GenericFunctionError::Unexpected {
+                        location,
+                        name: operand.to_string(),
+                    }),
+                ))))
+            }
+        };
+        self.evaluation_stack.push(StackElement::Evaluated(result));
+
+        Ok(())
+    }
+
+    ///
+    /// Analyzes the structure initialization operation.
+    ///
+    /// The contract literal is analyzed with its type, so the omitted fields get their defaults.
+    ///
+    fn structure(
+        &mut self,
+        structure: StructureExpression,
+        rule: TranslationRule,
+    ) -> Result<(), Error> {
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
             TranslationRule::Type,
         )?;
 
+        if let Element::Path(ref path) = operand_1 {
+            if let Some((discriminant, enumeration)) =
+                VariantAnalyzer::resolve(self.scope_stack.top(), path)
+            {
+                Self::check_variant_rule(path, rule)?;
+                let (element, intermediate) = VariantAnalyzer::structure(
+                    self.scope_stack.top(),
+                    path.to_owned(),
+                    discriminant,
+                    enumeration,
+                    structure,
+                )?;
+                self.intermediate.push_operand(intermediate);
+                self.evaluation_stack.push(StackElement::Evaluated(element));
+
+                return Ok(());
+            }
+        }
+
+        let (operand_2, intermediate) = match operand_1 {
+            Element::Type(Type::Contract(ref r#type)) => {
+                StructureAnalyzer::contract(self.scope_stack.top(), structure, r#type, rule)?
+            }
+            _ => StructureAnalyzer::analyze(self.scope_stack.top(), structure, rule)?,
+        };
+        if let Some(intermediate) = intermediate {
+            self.intermediate.push_operand(intermediate);
+        }
+
         let result = Element::structure(operand_1, operand_2, self.scope_stack.top())
             .map_err(Error::Element)?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
@@ -1101,6 +1539,121 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Pops the call operand from the evaluation stack, if it is a variant of an enumeration
+    /// with payloads, which is constructed instead of being called.
+    ///
+    /// Returns the variant path, discriminant, and enumeration type.
+    ///
+    fn take_payload_variant(&mut self) -> Option<(Path, IntegerConstant, EnumerationType)> {
+        let path = match self.evaluation_stack.top() {
+            StackElement::Evaluated(Element::Path(path)) => path.to_owned(),
+            _ => return None,
+        };
+
+        let (discriminant, enumeration) = VariantAnalyzer::resolve(self.scope_stack.top(), &path)?;
+        self.evaluation_stack.pop();
+
+        Some((path, discriminant, enumeration))
+    }
+
+    ///
+    /// Analyzes the tuple-like enumeration `variant` construction, e.g. `Action::Burn(amount)`.
+    ///
+    fn variant_tuple(
+        &mut self,
+        variant: (Path, IntegerConstant, EnumerationType),
+        list: ListExpression,
+        rule: TranslationRule,
+    ) -> Result<(), Error> {
+        let (path, discriminant, enumeration) = variant;
+
+        Self::check_variant_rule(&path, rule)?;
+        let (element, intermediate) = VariantAnalyzer::tuple(
+            self.scope_stack.top(),
+            path,
+            discriminant,
+            enumeration,
+            list,
+        )?;
+        self.intermediate.push_operand(intermediate);
+        self.evaluation_stack.push(StackElement::Evaluated(element));
+
+        Ok(())
+    }
+
+    ///
+    /// Takes the newtype structure, which the call operand at the top of the evaluation stack
+    /// points to, e.g. `Wei` in `Wei(amount)`.
+    ///
+    /// Returns `None` if the operand is not a newtype, so the call must be analyzed as usual.
+    ///
+    fn take_newtype(&mut self) -> Option<StructureType> {
+        let r#type = match self.evaluation_stack.top() {
+            StackElement::Evaluated(Element::Type(r#type)) => r#type.to_owned(),
+            StackElement::Evaluated(Element::Path(path)) => {
+                let item = Scope::resolve_path(self.scope_stack.top(), path).ok()?;
+                let item = item.borrow();
+                match *item {
+                    ScopeItem::Type(ref r#type) => r#type.define().ok()?,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        match r#type {
+            Type::Structure(structure) if structure.is_newtype => {
+                self.evaluation_stack.pop();
+                Some(structure)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Analyzes the newtype construction, e.g. `Wei(amount)`.
+    ///
+    /// The newtypes are runtime values only, like the enumeration variants with payloads.
+    ///
+    fn newtype(
+        &mut self,
+        r#type: StructureType,
+        location: Location,
+        list: ListExpression,
+        rule: TranslationRule,
+    ) -> Result<(), Error> {
+        if let TranslationRule::Constant = rule {
+            return Err(Error::Expression(ExpressionError::NonConstantElement {
+                location,
+                found: r#type.to_string(),
+            }));
+        }
+
+        let (element, intermediate) =
+            StructureAnalyzer::newtype(self.scope_stack.top(), location, r#type, list)?;
+        self.intermediate.push_operand(intermediate);
+        self.evaluation_stack.push(StackElement::Evaluated(element));
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the enumeration variant at `path` can be constructed with the `rule`.
+    ///
+    /// The variants of enumerations with payloads are runtime values only.
+    ///
+    fn check_variant_rule(path: &Path, rule: TranslationRule) -> Result<(), Error> {
+        if let TranslationRule::Constant = rule {
+            return Err(Error::Expression(ExpressionError::NonConstantElement {
+                location: path.location,
+                found: path.to_string(),
+            }));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Evaluates the element, turning it into the state specified with `rule`.
     ///
@@ -1127,6 +1680,9 @@ impl Analyzer {
                     IdentifierAnalyzer::analyze(scope, inner, rule)
                 }
                 ExpressionOperand::Type(inner) => Ok((TypeAnalyzer::analyze(scope, inner)?, None)),
+                ExpressionOperand::Generics(_) => {
+                    panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
+                }
                 ExpressionOperand::Array(inner) => ArrayAnalyzer::analyze(scope, inner, rule),
                 ExpressionOperand::Tuple(inner) => TupleAnalyzer::analyze(scope, inner, rule),
                 ExpressionOperand::Structure(inner) => {
@@ -9,6 +9,7 @@ use crate::generator::expression::operand::constant::Constant as GeneratorConsta
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
+use crate::semantic::analyzer::expression::variant::Analyzer as VariantAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::path::Path;
@@ -73,6 +74,13 @@ impl Translator {
                     ))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((discriminant, enumeration)) =
+                        VariantAnalyzer::payload_enumeration(&variant.constant)
+                    {
+                        return VariantAnalyzer::unit(path, discriminant, enumeration)
+                            .map(|(element, intermediate)| (element, Some(intermediate)));
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -145,6 +153,13 @@ impl Translator {
                     Ok((element, intermediate))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((discriminant, enumeration)) =
+                        VariantAnalyzer::payload_enumeration(&variant.constant)
+                    {
+                        return VariantAnalyzer::unit(path, discriminant, enumeration)
+                            .map(|(element, intermediate)| (element, Some(intermediate)));
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -185,7 +200,9 @@ impl Translator {
                     let element = Element::Constant(constant);
                     Ok((element, intermediate))
                 }
-                ScopeItem::Variant(ref variant) => {
+                ScopeItem::Variant(ref variant)
+                    if VariantAnalyzer::payload_enumeration(&variant.constant).is_none() =>
+                {
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
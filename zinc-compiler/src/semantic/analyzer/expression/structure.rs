@@ -3,23 +3,39 @@
 //!
 
 use std::cell::RefCell;
+use std::cmp;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::Identifier;
+use zinc_syntax::ListExpression;
 use zinc_syntax::StructureExpression;
 
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::group::builder::Builder as GeneratorGroupExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::structure::error::Error as StructureConstantError;
 use crate::semantic::element::constant::structure::Structure as StructureConstant;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::contract::Contract as ContractType;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::error::Error as ValueError;
+use crate::semantic::element::value::structure::error::Error as StructureValueError;
 use crate::semantic::element::value::structure::Structure as StructureValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 ///
 /// The structure semantic analyzer.
@@ -41,7 +57,27 @@ impl Analyzer {
             TranslationRule::Constant => {
                 Self::constant(scope, structure).map(|element| (element, None))
             }
-            _rule => Self::runtime(scope, structure)
+            _rule => Self::runtime(scope, structure, None)
+                .map(|(element, intermediate)| (element, Some(intermediate))),
+        }
+    }
+
+    ///
+    /// Analyzes the contract literal expression, e.g. `Self { ... }` in the constructor.
+    ///
+    /// The fields omitted in the literal are initialized with their default values.
+    ///
+    pub fn contract(
+        scope: Rc<RefCell<Scope>>,
+        structure: StructureExpression,
+        r#type: &ContractType,
+        rule: TranslationRule,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        match rule {
+            TranslationRule::Constant => {
+                Self::constant(scope, structure).map(|element| (element, None))
+            }
+            _rule => Self::runtime(scope, structure, Some(r#type))
                 .map(|(element, intermediate)| (element, Some(intermediate))),
         }
     }
@@ -52,6 +88,7 @@ impl Analyzer {
     fn runtime(
         scope: Rc<RefCell<Scope>>,
         structure: StructureExpression,
+        contract: Option<&ContractType>,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let location = structure.location;
 
@@ -59,27 +96,249 @@ impl Analyzer {
 
         let mut result = StructureValue::new(Some(location));
 
+        let mut fields = Vec::with_capacity(structure.fields.len());
         for (identifier, expression) in structure.fields.into_iter() {
+            let is_shorthand = StructureExpression::is_shorthand(&identifier, &expression);
             let (element, expression) =
                 ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
-                    .analyze(expression)?;
+                    .analyze(expression)
+                    .map_err(|error| Self::shorthand_error(error, &identifier, is_shorthand))?;
             let element_type = Type::from_element(&element, scope.clone())?;
 
-            result.push(
-                identifier.name,
-                Some(identifier.location),
-                element_type.clone(),
-            );
+            fields.push((identifier, element_type, expression));
+        }
+
+        let base = match structure.base {
+            Some(base) => base,
+            None => {
+                if let Some(contract) = contract {
+                    Self::fill_defaults(&mut fields, contract);
+                }
+
+                for (identifier, element_type, expression) in fields.into_iter() {
+                    result.push(
+                        identifier.name,
+                        Some(identifier.location),
+                        element_type.clone(),
+                    );
+
+                    builder.push_expression(element_type, expression);
+                }
+
+                let element = Element::Value(Value::Structure(result));
+                let intermediate = GeneratorExpressionOperand::Group(builder.finish());
+
+                return Ok((element, intermediate));
+            }
+        };
+
+        let base_location = base.location;
+        let (base_element, base_expression) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(base)?;
+        let base_type = Self::base_type(&base_element, scope.clone()).ok_or_else(|| {
+            Error::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::BaseExpectedStructure {
+                    location: base_location,
+                    found: base_element.to_string(),
+                },
+            )))
+        })?;
+        let base_constant = match base_element {
+            Element::Constant(Constant::Structure(constant)) => Some(constant),
+            _ => None,
+        };
+
+        let mut is_base_used = false;
+        let mut offset = 0;
+        for (name, r#type) in base_type.fields.iter() {
+            match fields
+                .iter()
+                .position(|(identifier, _type, _expression)| &identifier.name == name)
+            {
+                Some(index) => {
+                    let (identifier, element_type, expression) = fields.remove(index);
+
+                    result.push(
+                        identifier.name,
+                        Some(identifier.location),
+                        element_type.clone(),
+                    );
 
-            builder.push_expression(element_type, expression);
+                    builder.push_expression(element_type, expression);
+                }
+                None => {
+                    is_base_used = true;
+
+                    result.push(name.to_owned(), Some(base_location), r#type.to_owned());
+
+                    match base_constant {
+                        Some(ref constant) => {
+                            let mut expression = GeneratorExpression::new();
+                            if let Some(constant) = constant
+                                .values
+                                .iter()
+                                .find(|(identifier, _value)| &identifier.name == name)
+                                .and_then(|(_identifier, value)| {
+                                    GeneratorConstant::try_from_semantic(value)
+                                })
+                            {
+                                expression
+                                    .push_operand(GeneratorExpressionOperand::Constant(constant));
+                            }
+                            builder.push_expression(r#type.to_owned(), expression);
+                        }
+                        None => builder.push_base_element(r#type.to_owned(), offset),
+                    }
+                }
+            }
+
+            offset += r#type.size();
+        }
+
+        if let Some((identifier, _type, _expression)) = fields.into_iter().next() {
+            return Err(Error::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldDoesNotExist {
+                    location: identifier.location,
+                    type_identifier: base_type.identifier,
+                    field_name: identifier.name,
+                },
+            ))));
+        }
+
+        if !is_base_used {
+            Self::warn_redundant_base(scope, base_location, &base_type);
+        } else if base_constant.is_none() {
+            builder.set_base(base_location, base_expression, offset);
         }
 
+        result.set_base(base_location, base_type);
+
         let element = Element::Value(Value::Structure(result));
         let intermediate = GeneratorExpressionOperand::Group(builder.finish());
 
         Ok((element, intermediate))
     }
 
+    ///
+    /// Analyzes the newtype construction expression, e.g. `Wei(42)`.
+    ///
+    /// The newtype has the same representation as its inner value, so only the inner value
+    /// expression is written to the IR. The integer literals are cast to the inner type.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn newtype(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        r#type: StructureType,
+        list: ListExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let inner_type = r#type.newtype_inner_type();
+
+        if list.len() != 1 {
+            return Err(Error::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::FieldOutOfRange {
+                    location: list.location,
+                    type_identifier: r#type.identifier,
+                    expected: 1,
+                    found: list.len(),
+                },
+            ))));
+        }
+
+        let expression = list
+            .elements
+            .into_iter()
+            .next()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let expression_location = expression.location;
+        let (element, expression) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(expression)?;
+
+        let expression = match (element, &inner_type) {
+            (Element::Constant(constant @ Constant::Integer(_)), Type::IntegerUnsigned { .. })
+            | (Element::Constant(constant @ Constant::Integer(_)), Type::IntegerSigned { .. })
+            | (Element::Constant(constant @ Constant::Integer(_)), Type::Field(_)) => {
+                let (constant, _intermediate) = constant
+                    .cast(inner_type.clone())
+                    .map_err(ElementError::Constant)
+                    .map_err(Error::Element)?;
+
+                let mut expression = GeneratorExpression::new();
+                if let Some(constant) = GeneratorConstant::try_from_semantic(&constant) {
+                    expression.push_operand(GeneratorExpressionOperand::Constant(constant));
+                }
+                expression
+            }
+            (element, inner_type) => {
+                let element_type = Type::from_element(&element, scope)?;
+                if &element_type != inner_type {
+                    return Err(Error::Element(ElementError::Value(ValueError::Structure(
+                        StructureValueError::FieldInvalidType {
+                            location: expression_location,
+                            type_identifier: r#type.identifier,
+                            field_name: StructureType::NEWTYPE_FIELD.to_owned(),
+                            expected: inner_type.to_string(),
+                            found: element_type.to_string(),
+                        },
+                    ))));
+                }
+                expression
+            }
+        };
+
+        let mut builder = GeneratorGroupExpressionBuilder::default();
+        builder.push_expression(inner_type, expression);
+
+        let element = Element::Value(Value::Structure(StructureValue::new_with_type(
+            Some(location),
+            r#type,
+        )));
+        let intermediate = GeneratorExpressionOperand::Group(builder.finish());
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Inserts the default values of the contract fields omitted in the literal.
+    ///
+    /// The defaults are inserted at their declaration positions, so the fields order is checked
+    /// during the contract validation as if they were written explicitly.
+    ///
+    fn fill_defaults(
+        fields: &mut Vec<(Identifier, Type, GeneratorExpression)>,
+        contract: &ContractType,
+    ) {
+        for (index, field) in contract
+            .fields
+            .iter()
+            .filter(|field| !field.is_implicit)
+            .enumerate()
+        {
+            if fields
+                .iter()
+                .any(|(identifier, _type, _expression)| identifier.name == field.identifier.name)
+            {
+                continue;
+            }
+
+            let default = match field.default {
+                Some(ref default) => default,
+                None => continue,
+            };
+
+            let mut expression = GeneratorExpression::new();
+            if let Some(constant) = GeneratorConstant::try_from_semantic(default) {
+                expression.push_operand(GeneratorExpressionOperand::Constant(constant));
+            }
+
+            fields.insert(
+                cmp::min(index, fields.len()),
+                (field.identifier.clone(), field.r#type.clone(), expression),
+            );
+        }
+    }
+
     ///
     /// Returns the constant structure semantic element.
     ///
@@ -89,27 +348,139 @@ impl Analyzer {
     ) -> Result<Element, Error> {
         let mut result = StructureConstant::new(structure.location);
 
+        let mut fields = Vec::with_capacity(structure.fields.len());
         for (identifier, expression) in structure.fields.into_iter() {
-            let expression_location = expression.location;
+            let is_shorthand = StructureExpression::is_shorthand(&identifier, &expression);
+            let constant = Self::constant_element(scope.clone(), expression)
+                .map_err(|error| Self::shorthand_error(error, &identifier, is_shorthand))?;
+            fields.push((identifier, constant));
+        }
+
+        let base = match structure.base {
+            Some(base) => base,
+            None => {
+                for (identifier, constant) in fields.into_iter() {
+                    result.push(identifier, constant);
+                }
 
-            let (element, _) = ExpressionAnalyzer::new(scope.clone(), TranslationRule::Constant)
-                .analyze(expression)?;
+                return Ok(Element::Constant(Constant::Structure(result)));
+            }
+        };
 
-            match element {
-                Element::Constant(constant) => {
+        let base_location = base.location;
+        let base = match Self::constant_element(scope.clone(), base)? {
+            Constant::Structure(base) => base,
+            constant => {
+                return Err(Error::Element(ElementError::Constant(
+                    ConstantError::Structure(StructureConstantError::BaseExpectedStructure {
+                        location: base_location,
+                        found: constant.to_string(),
+                    }),
+                )))
+            }
+        };
+        let base_type = base
+            .r#type
+            .clone()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let mut is_base_used = false;
+        for (identifier, value) in base.values.into_iter() {
+            match fields
+                .iter()
+                .position(|(field, _constant)| field.name == identifier.name)
+            {
+                Some(index) => {
+                    let (identifier, constant) = fields.remove(index);
                     result.push(identifier, constant);
                 }
-                element => {
-                    return Err(Error::Expression(ExpressionError::NonConstantElement {
-                        location: expression_location,
-                        found: element.to_string(),
-                    }))
+                None => {
+                    is_base_used = true;
+
+                    result.push(Identifier::new(base_location, identifier.name), value);
                 }
             }
         }
 
-        let element = Element::Constant(Constant::Structure(result));
+        if let Some((identifier, _constant)) = fields.into_iter().next() {
+            return Err(Error::Element(ElementError::Constant(
+                ConstantError::Structure(StructureConstantError::FieldDoesNotExist {
+                    location: identifier.location,
+                    type_identifier: base_type.identifier,
+                    field_name: identifier.name,
+                }),
+            )));
+        }
+
+        if !is_base_used {
+            Self::warn_redundant_base(scope, base_location, &base_type);
+        }
+
+        result.set_base(base_location, base_type);
+
+        Ok(Element::Constant(Constant::Structure(result)))
+    }
+
+    ///
+    /// Analyzes the constant structure field or base `expression`.
+    ///
+    fn constant_element(
+        scope: Rc<RefCell<Scope>>,
+        expression: ExpressionTree,
+    ) -> Result<Constant, Error> {
+        let expression_location = expression.location;
+
+        let (element, _) =
+            ExpressionAnalyzer::new(scope, TranslationRule::Constant).analyze(expression)?;
+
+        match element {
+            Element::Constant(constant) => Ok(constant),
+            element => Err(Error::Expression(ExpressionError::NonConstantElement {
+                location: expression_location,
+                found: element.to_string(),
+            })),
+        }
+    }
+
+    ///
+    /// Points the undeclared item error at the field if the field `is_shorthand`, e.g. `{ a }`.
+    ///
+    fn shorthand_error(error: Error, identifier: &Identifier, is_shorthand: bool) -> Error {
+        match error {
+            Error::Scope(ScopeError::ItemUndeclared { location, name })
+                if is_shorthand && location == identifier.location =>
+            {
+                Error::Expression(ExpressionError::StructureShorthandUndeclared { location, name })
+            }
+            error => error,
+        }
+    }
+
+    ///
+    /// Returns the structure update base type, if the base `element` is a structure instance.
+    ///
+    fn base_type(element: &Element, scope: Rc<RefCell<Scope>>) -> Option<StructureType> {
+        match element {
+            Element::Value(_) | Element::Constant(_) | Element::Place(_) => {
+                match Type::from_element(element, scope) {
+                    Ok(Type::Structure(r#type)) => Some(r#type),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 
-        Ok(element)
+    ///
+    /// Reports the structure update base, which does not provide any field.
+    ///
+    fn warn_redundant_base(scope: Rc<RefCell<Scope>>, location: Location, r#type: &StructureType) {
+        Scope::push_warning(
+            scope,
+            Warning::RedundantStructureBase {
+                location,
+                type_identifier: r#type.identifier.to_owned(),
+            },
+        );
     }
 }
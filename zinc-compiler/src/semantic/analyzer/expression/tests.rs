@@ -4,6 +4,7 @@
 
 use crate::error::Error;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
+use crate::semantic::binding::error::Error as BindingError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::item::variable::Variable as ScopeVariableItem;
@@ -111,6 +112,43 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_constant_element_block_let_array_size() {
+    let input = r#"
+fn main() {
+    let array: [u8; {
+        let width = 4;
+        let height = width / 2;
+        width * height
+    }] = [0; 8];
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_element_block_let_enum_discriminant() {
+    let input = r#"
+enum Kind {
+    Small = 2,
+    Large = 8,
+}
+
+fn main() {
+    const RATIO: u8 = {
+        let (small, large) = (Kind::Small as u8, Kind::Large as u8);
+        let ratio: u8 = large / small;
+        ratio
+    };
+
+    let array: [bool; RATIO] = [true; 4];
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_non_constant_element_simple() {
     let input = r#"
@@ -434,3 +472,71 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_loop_in_constant_context() {
+    let input = r#"
+fn main() {
+    const VALUE: u8 = {
+        for i in 0..4 {}
+        42
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::LoopInConstantContext {
+            location: Location::test(4, 9),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_assignment_in_constant_context() {
+    let input = r#"
+fn main() {
+    const VALUE: u8 = {
+        let value = 42;
+        value = 43;
+        value
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::AssignmentInConstantContext {
+            location: Location::test(5, 15),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutable_binding_in_constant_context() {
+    let input = r#"
+fn main() {
+    const VALUE: u8 = {
+        let mut value = 42;
+        value
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Binding(
+        BindingError::MutableInConstantContext {
+            location: Location::test(4, 17),
+            name: "value".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,358 @@
+//!
+//! The enumeration variant semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_lexical::Location;
+use zinc_syntax::ListExpression;
+use zinc_syntax::StructureExpression;
+
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::group::builder::Builder as GeneratorGroupExpressionBuilder;
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::enumeration::Enumeration as EnumerationValue;
+use crate::semantic::element::value::error::Error as ValueError;
+use crate::semantic::element::value::structure::error::Error as StructureValueError;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+///
+/// The enumeration variant semantic analyzer.
+///
+/// Constructs the values of enumerations with payloads, e.g. `Action::Noop`,
+/// `Action::Burn(amount)`, and `Action::Transfer { to, amount }`.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Resolves the variant of an enumeration with payloads, which the `path` points to.
+    ///
+    /// Returns `None` if the path does not point to such a variant, so it must be analyzed as usual.
+    ///
+    pub fn resolve(
+        scope: Rc<RefCell<Scope>>,
+        path: &Path,
+    ) -> Option<(IntegerConstant, EnumerationType)> {
+        let item = Scope::resolve_path(scope, path).ok()?;
+        let item = item.borrow();
+        match *item {
+            ScopeItem::Variant(ref variant) => Self::payload_enumeration(&variant.constant),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the variant discriminant and enumeration type, if the variant `constant` belongs to
+    /// an enumeration with payloads.
+    ///
+    pub fn payload_enumeration(constant: &Constant) -> Option<(IntegerConstant, EnumerationType)> {
+        match constant {
+            Constant::Integer(integer) => match integer.enumeration {
+                Some(ref enumeration) if enumeration.has_payloads() => {
+                    Some((integer.to_owned(), enumeration.to_owned()))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    ///
+    /// Constructs the variant without a payload, e.g. `Action::Noop`.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn unit(
+        path: Path,
+        discriminant: IntegerConstant,
+        enumeration: EnumerationType,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let variant_name = path.last().name.to_owned();
+
+        if enumeration.payload(variant_name.as_str()).is_some() {
+            return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadExpected {
+                    location: path.location,
+                    type_identifier: enumeration.identifier,
+                    variant_name,
+                },
+            ))));
+        }
+
+        Ok(Self::finish(
+            path.location,
+            discriminant,
+            enumeration,
+            vec![],
+        ))
+    }
+
+    ///
+    /// Constructs the tuple-like variant, e.g. `Action::Burn(amount)`.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn tuple(
+        scope: Rc<RefCell<Scope>>,
+        path: Path,
+        discriminant: IntegerConstant,
+        enumeration: EnumerationType,
+        list: ListExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let payload = Self::payload(&path, &enumeration, false)?;
+        let variant_name = path.last().name.to_owned();
+
+        if list.len() != payload.fields.len() {
+            return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadInvalidLength {
+                    location: list.location,
+                    type_identifier: enumeration.identifier,
+                    variant_name,
+                    expected: payload.fields.len(),
+                    found: list.len(),
+                },
+            ))));
+        }
+
+        let mut fields = Vec::with_capacity(payload.fields.len());
+        for (expression, (name, r#type)) in list.elements.into_iter().zip(payload.fields) {
+            let location = expression.location;
+            let (element, expression) =
+                ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                    .analyze(expression)?;
+
+            Self::check_field(
+                scope.clone(),
+                location,
+                &enumeration,
+                variant_name.as_str(),
+                name,
+                &r#type,
+                &element,
+            )?;
+
+            fields.push((r#type, expression));
+        }
+
+        Ok(Self::finish(
+            path.location,
+            discriminant,
+            enumeration,
+            fields,
+        ))
+    }
+
+    ///
+    /// Constructs the structure-like variant, e.g. `Action::Transfer { to, amount }`.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn structure(
+        scope: Rc<RefCell<Scope>>,
+        path: Path,
+        discriminant: IntegerConstant,
+        enumeration: EnumerationType,
+        structure: StructureExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let payload = Self::payload(&path, &enumeration, true)?;
+        let variant_name = path.last().name.to_owned();
+
+        if let Some(base) = structure.base {
+            return Err(Error::Element(ElementError::Value(ValueError::Structure(
+                StructureValueError::BaseExpectedStructure {
+                    location: base.location,
+                    found: path.to_string(),
+                },
+            ))));
+        }
+
+        let mut literal_fields = structure.fields;
+        let mut fields = Vec::with_capacity(payload.fields.len());
+        for (name, r#type) in payload.fields.into_iter() {
+            let expression = match literal_fields
+                .iter()
+                .position(|(identifier, _expression)| identifier.name == name)
+            {
+                Some(index) => literal_fields.remove(index).1,
+                None => {
+                    return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                        EnumerationTypeError::PayloadFieldMissing {
+                            location: structure.location,
+                            type_identifier: enumeration.identifier,
+                            variant_name,
+                            field_name: name,
+                        },
+                    ))));
+                }
+            };
+
+            let location = expression.location;
+            let (element, expression) =
+                ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                    .analyze(expression)?;
+
+            Self::check_field(
+                scope.clone(),
+                location,
+                &enumeration,
+                variant_name.as_str(),
+                name,
+                &r#type,
+                &element,
+            )?;
+
+            fields.push((r#type, expression));
+        }
+
+        if let Some((identifier, _expression)) = literal_fields.into_iter().next() {
+            return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadFieldDoesNotExist {
+                    location: identifier.location,
+                    type_identifier: enumeration.identifier,
+                    variant_name,
+                    field_name: identifier.name,
+                },
+            ))));
+        }
+
+        Ok(Self::finish(
+            path.location,
+            discriminant,
+            enumeration,
+            fields,
+        ))
+    }
+
+    ///
+    /// Returns the payload of the variant at `path`, checking whether it is constructed with
+    /// the syntax of its kind, that is, with the structure one if `is_structure`.
+    ///
+    fn payload(
+        path: &Path,
+        enumeration: &EnumerationType,
+        is_structure: bool,
+    ) -> Result<Payload, Error> {
+        let variant = path.last();
+
+        let payload = enumeration.payload(variant.name.as_str()).ok_or_else(|| {
+            Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadUnexpected {
+                    location: path.location,
+                    type_identifier: enumeration.identifier.to_owned(),
+                    variant_name: variant.name.to_owned(),
+                },
+            )))
+        })?;
+
+        if payload.is_structure != is_structure {
+            return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadInvalidKind {
+                    location: path.location,
+                    type_identifier: enumeration.identifier.to_owned(),
+                    variant_name: variant.name.to_owned(),
+                    is_structure: payload.is_structure,
+                },
+            ))));
+        }
+
+        Ok(payload.to_owned())
+    }
+
+    ///
+    /// Checks whether the payload field `name` of `r#type` is initialized with the `element`.
+    ///
+    fn check_field(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        enumeration: &EnumerationType,
+        variant_name: &str,
+        name: String,
+        r#type: &Type,
+        element: &Element,
+    ) -> Result<(), Error> {
+        let element_type = Type::from_element(element, scope)?;
+        if &element_type != r#type {
+            return Err(Error::Element(ElementError::Type(TypeError::Enumeration(
+                EnumerationTypeError::PayloadFieldInvalidType {
+                    location,
+                    type_identifier: enumeration.identifier.to_owned(),
+                    variant_name: variant_name.to_owned(),
+                    field_name: name,
+                    expected: r#type.to_string(),
+                    found: element_type.to_string(),
+                },
+            ))));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the variant `discriminant` and the `payload` expressions to a group, padding the
+    /// payload with zeros to the size of the largest one.
+    ///
+    fn finish(
+        location: Location,
+        discriminant: IntegerConstant,
+        enumeration: EnumerationType,
+        payload: Vec<(Type, GeneratorExpression)>,
+    ) -> (Element, GeneratorExpressionOperand) {
+        let mut builder = GeneratorGroupExpressionBuilder::default();
+
+        let discriminant_type = Type::integer_unsigned(None, discriminant.bitlength);
+        let mut expression = GeneratorExpression::new();
+        expression.push_operand(GeneratorExpressionOperand::Constant(
+            GeneratorConstant::try_from_semantic(&Constant::Integer(discriminant))
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        ));
+        builder.push_expression(discriminant_type, expression);
+
+        let mut payload_size = 0;
+        for (r#type, expression) in payload.into_iter() {
+            payload_size += r#type.size();
+            builder.push_expression(r#type, expression);
+        }
+
+        for _ in payload_size..enumeration.payload_size() {
+            let mut expression = GeneratorExpression::new();
+            expression.push_operand(GeneratorExpressionOperand::Constant(
+                GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                    BigInt::zero(),
+                    false,
+                    zinc_const::bitlength::FIELD,
+                )),
+            ));
+            builder.push_expression(Type::field(None), expression);
+        }
+
+        let element = Element::Value(Value::Enumeration(EnumerationValue::new(
+            Some(location),
+            enumeration,
+        )));
+        let intermediate = GeneratorExpressionOperand::Group(builder.finish());
+
+        (element, intermediate)
+    }
+}
@@ -52,13 +52,14 @@ impl Analyzer {
         for hoisted_statement in module.statements.into_iter() {
             match hoisted_statement {
                 ModuleLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement, false)?;
+                    Scope::declare_constant(scope.clone(), statement, false, None)?;
                 }
                 ModuleLocalStatement::Type(statement) => {
                     Scope::declare_type(
                         scope.clone(),
                         TypeStatementVariant::Type(statement),
                         false,
+                        None,
                     )?;
                 }
                 ModuleLocalStatement::Struct(statement) => {
@@ -66,6 +67,7 @@ impl Analyzer {
                         scope.clone(),
                         TypeStatementVariant::Struct(statement),
                         false,
+                        None,
                     )?;
                 }
                 ModuleLocalStatement::Enum(statement) => {
@@ -73,6 +75,7 @@ impl Analyzer {
                         scope.clone(),
                         TypeStatementVariant::Enum(statement),
                         false,
+                        None,
                     )?;
                 }
                 ModuleLocalStatement::Fn(statement) => {
@@ -99,6 +102,7 @@ impl Analyzer {
                         scope.clone(),
                         TypeStatementVariant::Fn(statement, FnStatementAnalyzerContext::Module),
                         false,
+                        None,
                     )?;
                 }
                 ModuleLocalStatement::Mod(statement) => {
@@ -86,6 +86,7 @@ impl Rule {
             ExpressionOperator::Call => Self::Type,
 
             ExpressionOperator::Path => Self::Path,
+            ExpressionOperator::Generics => Self::Path,
 
             ExpressionOperator::Structure => Self::Type,
         }
@@ -155,6 +156,9 @@ impl Rule {
             ExpressionOperator::Call => rule.constant_or_value(),
 
             ExpressionOperator::Path => Self::Path,
+            ExpressionOperator::Generics => {
+                panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
+            }
 
             ExpressionOperator::Structure => rule.constant_or_value(),
         }
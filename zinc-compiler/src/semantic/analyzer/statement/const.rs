@@ -35,7 +35,8 @@ impl Analyzer {
                 .analyze(statement.expression)?;
 
         let const_type = Type::try_from_syntax(statement.r#type, scope)?;
-        if !const_type.is_instantiatable(false) {
+        let is_string = matches!(const_type, Type::String(_));
+        if !is_string && !const_type.is_instantiatable(false) {
             return Err(Error::Element(ElementError::Type(
                 TypeError::InstantiationForbidden {
                     location: statement.location,
@@ -6,6 +6,7 @@
 mod tests;
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use zinc_syntax::ContractLocalStatement;
@@ -15,10 +16,12 @@ use zinc_syntax::Identifier;
 use crate::generator::statement::contract::Statement as GeneratorContractStatement;
 use crate::semantic::analyzer::statement::field::Analyzer as FieldStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::contract::field::Field as ContractFieldType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
 
 ///
@@ -29,7 +32,8 @@ pub struct Analyzer {}
 impl Analyzer {
     ///
     /// Initializes the contract type and declares the hoisted items.
-    /// Removes the hoisted item statements from the contract statement.
+    /// Removes the hoisted item statements from the contract statement, except for the public
+    /// constants, which are kept to be collected into the contract metadata.
     /// Returns the statement and allocated scope.
     ///
     pub fn declare(
@@ -40,13 +44,18 @@ impl Analyzer {
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
                 ContractLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement, true)?;
+                    if statement.is_public {
+                        instant_statements.push(ContractLocalStatement::Const(statement.clone()));
+                    }
+
+                    Scope::declare_constant(scope.clone(), statement, true, None)?;
                 }
                 ContractLocalStatement::Fn(statement) => {
                     Scope::declare_type(
                         scope.clone(),
                         TypeStatementVariant::Fn(statement, FnStatementAnalyzerContext::Contract),
                         true,
+                        None,
                     )?;
                 }
                 ContractLocalStatement::Empty(_location) => {}
@@ -62,6 +71,12 @@ impl Analyzer {
     ///
     /// Defines the instant items and forcibly defines the hoisted ones.
     ///
+    /// The public string constants are collected into the contract metadata, and all the public
+    /// constants are collected into the contract ABI.
+    ///
+    /// The storage field default values are evaluated after the contract type is created, so they
+    /// can reference the contract constants via `Self::`.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         statement: ContractStatement,
@@ -95,31 +110,78 @@ impl Analyzer {
                 true,
             ),
         );
+        storage_fields.insert(
+            zinc_const::contract::FIELD_INDEX_LAYOUT_HASH,
+            ContractFieldType::new(
+                Identifier::new(
+                    statement.location,
+                    zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+                ),
+                Type::integer_unsigned(None, zinc_const::bitlength::LAYOUT_HASH),
+                true,
+                true,
+                true,
+            ),
+        );
 
+        let mut metadata = HashMap::new();
+        let mut constants = Vec::new();
+        let mut defaults = Vec::new();
         for instant_statement in statement.statements.into_iter() {
-            if let ContractLocalStatement::Field(statement) = instant_statement {
-                FieldStatementAnalyzer::define(
-                    scope.clone(),
-                    statement.clone(),
-                    storage_fields.len(),
-                )?;
+            match instant_statement {
+                ContractLocalStatement::Field(mut statement) => {
+                    let leaf_index = storage_fields
+                        .iter()
+                        .map(|field| field.r#type.storage_leaves_count())
+                        .sum();
+
+                    FieldStatementAnalyzer::define(scope.clone(), statement.clone(), leaf_index)?;
 
-                let field = ContractFieldType::try_from_syntax(statement, scope.clone())?;
+                    if let Some(expression) = statement.default.take() {
+                        defaults.push((storage_fields.len(), expression));
+                    }
 
-                storage_fields.push(field);
+                    let field = ContractFieldType::try_from_syntax(statement, scope.clone())?;
+
+                    storage_fields.push(field);
+                }
+                ContractLocalStatement::Const(statement) => {
+                    let item = scope.borrow().resolve_item(&statement.identifier, false)?;
+                    let constant = match *item.borrow() {
+                        ScopeItem::Constant(ref constant) => constant.define()?,
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    if let Constant::String(ref string) = constant {
+                        metadata.insert(statement.identifier.name.clone(), string.inner.clone());
+                    }
+                    constants.push((statement.identifier.name, constant));
+                }
+                _ => {}
             }
         }
 
-        let r#type = Type::contract(
+        let mut r#type = Type::contract(
             statement.location,
             statement.identifier.name,
             storage_fields.clone(),
             Some(scope.clone()),
         )?;
 
+        if let Type::Contract(ref mut contract) = r#type {
+            let mut values = Vec::with_capacity(defaults.len());
+            for (index, expression) in defaults.into_iter() {
+                let value =
+                    storage_fields[index].try_default_from_syntax(expression, scope.clone())?;
+                values.push((index, value));
+            }
+            contract.set_defaults(values);
+        }
+
         scope.borrow().define()?;
 
-        let intermediate = GeneratorContractStatement::new(location, storage_fields);
+        let intermediate =
+            GeneratorContractStatement::new(location, storage_fields, metadata, constants);
 
         Ok((r#type, intermediate))
     }
@@ -35,6 +35,47 @@ contract Uniswap {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_field_defaults() {
+    let input = r#"
+contract Uniswap {
+    pub fee_bps: u16 = 30;
+    owner: u160;
+    limit: u8 = Self::LIMIT * 2;
+
+    const LIMIT: u8 = 5;
+
+    pub fn new(creator: u160) -> Self {
+        Self { owner: creator }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_field_defaults_enumeration() {
+    let input = r#"
+enum State {
+    Idle = 0,
+    Active = 5,
+}
+
+contract Uniswap {
+    pub state: State = 5 as State;
+    pub state_code: u8 = State::Idle as u8;
+    owner: u160;
+
+    pub fn new(creator: u160) -> Self {
+        Self { owner: creator }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn ok_single_constant() {
     let input = r#"
@@ -152,3 +193,46 @@ contract Uniswap {
 
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
+
+#[test]
+fn ok_public_string_constants() {
+    let input = r#"
+contract Uniswap {
+    pub const NAME: str = "Uniswap";
+    pub const VERSION: str = "2.0.0";
+
+    pub fn f1() -> u8 { 42 }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_private_string_constant() {
+    let input = r#"
+contract Uniswap {
+    const NOTE: str = "compile-time only";
+
+    pub fn f1() -> u8 {
+        require(true, Self::NOTE);
+        42
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_public_non_string_constant() {
+    let input = r#"
+contract Uniswap {
+    pub const FEE: u8 = 42;
+
+    pub fn f1() -> u8 { Self::FEE }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
@@ -6,9 +6,12 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_syntax::BindingPatternVariant;
 use zinc_syntax::FnStatement;
+use zinc_syntax::Identifier;
 
 use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
+use crate::semantic::analyzer::attribute::error::Error as AttributeError;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
@@ -16,9 +19,11 @@ use crate::semantic::binding::Binder;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
@@ -63,7 +68,55 @@ impl Analyzer {
             attributes.push(attribute);
         }
 
-        if attributes.contains(&Attribute::Test) {
+        if attributes.iter().any(Attribute::is_payable) {
+            let is_contract_method = matches!(context, Context::Contract)
+                && statement.is_public
+                && !statement.is_constant;
+            if !is_contract_method || attributes.iter().any(Attribute::is_unit_test) {
+                return Err(Error::Attribute(
+                    AttributeError::PayableOutsideMutableContractMethod {
+                        location: statement.location,
+                        function: statement.identifier.name,
+                    },
+                ));
+            }
+        }
+
+        if attributes.iter().any(Attribute::is_only) {
+            let is_contract_method = matches!(context, Context::Contract)
+                && statement.is_public
+                && !statement.is_constant;
+            if !is_contract_method || attributes.iter().any(Attribute::is_unit_test) {
+                return Err(Error::Attribute(
+                    AttributeError::OnlyOutsideMutableContractMethod {
+                        location: statement.location,
+                        function: statement.identifier.name,
+                    },
+                ));
+            }
+        }
+
+        if attributes.iter().any(Attribute::is_public) {
+            return Err(Error::Attribute(
+                AttributeError::PublicOutsideContractMethodArgument {
+                    location: statement.location,
+                    function: statement.identifier.name,
+                },
+            ));
+        }
+
+        let is_contract_method = matches!(context, Context::Contract)
+            && statement.is_public
+            && !statement.is_constant
+            && !attributes.iter().any(Attribute::is_unit_test);
+        Self::public_arguments(&statement, is_contract_method)?;
+
+        if statement.is_generic() {
+            return Self::generic(scope, statement, context, attributes)
+                .map(|r#type| (r#type, None));
+        }
+
+        if attributes.iter().any(Attribute::is_unit_test) {
             return Self::test(scope, statement, context, attributes)
                 .map(|(r#type, intermediate)| (r#type, Some(intermediate)));
         }
@@ -79,7 +132,9 @@ impl Analyzer {
     ///
     /// Analyzes a runtime function statement and returns its IR for the next compiler phase.
     ///
-    fn runtime(
+    /// Is also used to analyze the instances of generic functions.
+    ///
+    pub fn runtime(
         scope: Rc<RefCell<Scope>>,
         statement: FnStatement,
         context: Context,
@@ -88,9 +143,33 @@ impl Analyzer {
         let mut scope_stack = ScopeStack::new(scope);
         scope_stack.push(Some(statement.identifier.name.clone()));
 
+        let public_arguments = Self::public_arguments(
+            &statement,
+            matches!(context, Context::Contract) && statement.is_public,
+        )?;
+
         let bindings =
             Binder::bind_arguments(statement.argument_bindings, context, scope_stack.top())?;
 
+        let is_associated = !bindings
+            .first()
+            .map(|binding| binding.identifier.is_self_lowercase())
+            .unwrap_or_default();
+        let is_mutable = !is_associated
+            && bindings
+                .first()
+                .map(|binding| binding.is_mutable)
+                .unwrap_or_default();
+
+        if let Context::Contract = context {
+            if !is_associated && !is_mutable {
+                scope_stack
+                    .top()
+                    .borrow()
+                    .set_query_method(statement.identifier.name.clone());
+            }
+        }
+
         let expected_type = match statement.return_type {
             Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope_stack.top())?,
             None => Type::unit(None),
@@ -152,10 +231,34 @@ impl Analyzer {
             )
         };
 
-        let is_mutable = bindings
-            .first()
-            .map(|binding| binding.is_mutable)
-            .unwrap_or_default();
+        if !is_mutable && attributes.iter().any(Attribute::is_payable) {
+            return Err(Error::Attribute(
+                AttributeError::PayableOutsideMutableContractMethod {
+                    location: statement.location,
+                    function: statement.identifier.name,
+                },
+            ));
+        }
+
+        if !is_mutable && attributes.iter().any(Attribute::is_only) {
+            return Err(Error::Attribute(
+                AttributeError::OnlyOutsideMutableContractMethod {
+                    location: statement.location,
+                    function: statement.identifier.name,
+                },
+            ));
+        }
+
+        let only = match attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Only { field } => Some(field),
+            _ => None,
+        }) {
+            Some(field) => Some((
+                field.name.to_owned(),
+                Self::only_field(scope_stack.top(), field)?,
+            )),
+            None => None,
+        };
 
         let (r#type, type_id) = Type::runtime_function(
             statement.location,
@@ -168,18 +271,150 @@ impl Analyzer {
             statement.location,
             statement.identifier.name,
             is_mutable,
+            is_associated,
             bindings,
             intermediate,
             expected_type,
             type_id,
-            is_contract_entry,
             is_main,
+            is_contract_entry,
             attributes,
+            only,
+            public_arguments,
         );
 
         Ok((r#type, intermediate))
     }
 
+    ///
+    /// Validates the function argument attributes and returns the names of the arguments marked
+    /// with `#[public]`.
+    ///
+    /// Only the named arguments of public contract methods can be marked as public, since they
+    /// become a part of the method proof public input.
+    ///
+    fn public_arguments(
+        statement: &FnStatement,
+        is_contract_method: bool,
+    ) -> Result<Vec<String>, Error> {
+        let mut public_arguments = Vec::new();
+
+        for binding in statement.argument_bindings.iter() {
+            for attribute in binding.attributes.iter() {
+                let location = attribute.location;
+                let name = attribute.identifier.name.to_owned();
+
+                let attribute =
+                    Attribute::try_from(attribute.to_owned()).map_err(Error::Attribute)?;
+                if !attribute.is_public() {
+                    return Err(Error::Attribute(
+                        AttributeError::UnexpectedArgumentAttribute {
+                            location,
+                            found: name,
+                        },
+                    ));
+                }
+
+                match binding.pattern.variant {
+                    BindingPatternVariant::Binding { ref identifier, .. }
+                        if is_contract_method && !identifier.is_self_lowercase() =>
+                    {
+                        if !public_arguments.contains(&identifier.name) {
+                            public_arguments.push(identifier.name.to_owned());
+                        }
+                    }
+                    _ => {
+                        return Err(Error::Attribute(
+                            AttributeError::PublicOutsideContractMethodArgument {
+                                location,
+                                function: statement.identifier.name.to_owned(),
+                            },
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(public_arguments)
+    }
+
+    ///
+    /// Resolves the contract storage field of the `#[only(field)]` attribute and returns its
+    /// position in the contract storage.
+    ///
+    /// The field must hold an address, since it is compared with the transaction sender.
+    ///
+    fn only_field(scope: Rc<RefCell<Scope>>, identifier: &Identifier) -> Result<usize, Error> {
+        let item = scope
+            .borrow()
+            .resolve_item(identifier, false)
+            .map_err(|_| {
+                Error::Attribute(AttributeError::OnlyFieldNotFound {
+                    location: identifier.location,
+                    field: identifier.name.to_owned(),
+                })
+            })?;
+
+        let (index, r#type) = match *item.borrow() {
+            ScopeItem::Field(ref field) => (field.index, field.r#type.to_owned()),
+            _ => {
+                return Err(Error::Attribute(AttributeError::OnlyFieldNotFound {
+                    location: identifier.location,
+                    field: identifier.name.to_owned(),
+                }))
+            }
+        };
+
+        match r#type {
+            Type::IntegerUnsigned { bitlength, .. }
+                if bitlength == zinc_const::bitlength::ETH_ADDRESS =>
+            {
+                Ok(index)
+            }
+            r#type => Err(Error::Attribute(AttributeError::OnlyFieldExpectedAddress {
+                location: identifier.location,
+                field: identifier.name.to_owned(),
+                found: r#type.to_string(),
+            })),
+        }
+    }
+
+    ///
+    /// Checks a generic function statement, whose body is analyzed for each instance created
+    /// at the call sites.
+    ///
+    /// Only ordinary runtime functions can have type parameters, since constant functions,
+    /// unit tests, and entry points cannot be monomorphized.
+    ///
+    fn generic(
+        scope: Rc<RefCell<Scope>>,
+        statement: FnStatement,
+        context: Context,
+        attributes: Vec<Attribute>,
+    ) -> Result<Type, Error> {
+        let is_entry = match context {
+            Context::Contract => statement.is_public,
+            _ => statement.identifier.name.as_str() == zinc_const::source::FUNCTION_MAIN_IDENTIFIER,
+        };
+
+        if statement.is_constant || is_entry || attributes.iter().any(Attribute::is_unit_test) {
+            return Err(Error::Element(ElementError::Type(TypeError::Function(
+                FunctionError::Generic(GenericFunctionError::Forbidden {
+                    location: statement.location,
+                    function: statement.identifier.name,
+                }),
+            ))));
+        }
+
+        Ok(Type::generic_function(
+            statement.location,
+            statement,
+            context,
+            attributes,
+            scope,
+        ))
+    }
+
     ///
     /// Analyzes a constant function statement.
     ///
@@ -192,6 +427,11 @@ impl Analyzer {
         let mut scope_stack = ScopeStack::new(scope);
         scope_stack.push(Some(statement.identifier.name.clone()));
 
+        Self::public_arguments(
+            &statement,
+            matches!(context, Context::Contract) && statement.is_public,
+        )?;
+
         let bindings =
             Binder::bind_arguments(statement.argument_bindings, context, scope_stack.top())?;
 
@@ -332,6 +572,7 @@ impl Analyzer {
             location,
             statement.identifier.name,
             false,
+            true,
             vec![],
             intermediate,
             Type::Unit(None),
@@ -339,6 +580,8 @@ impl Analyzer {
             false,
             false,
             attributes,
+            None,
+            vec![],
         );
 
         Ok((r#type, intermediate))
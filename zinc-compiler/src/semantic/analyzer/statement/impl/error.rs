@@ -16,4 +16,11 @@ pub enum Error {
         /// The invalid type identifier.
         found: String,
     },
+    /// The intrinsic types cannot have an implementation, since they are shared between compilations.
+    IntrinsicType {
+        /// The intrinsic type location in the code.
+        location: Location,
+        /// The intrinsic type identifier.
+        found: String,
+    },
 }
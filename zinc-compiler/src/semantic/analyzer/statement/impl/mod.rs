@@ -38,11 +38,14 @@ impl Analyzer {
     ///
     /// Also declares the `Self` alias for the type being implemented.
     ///
+    /// The non-public items are only accessible from the module, where the `impl` is declared.
+    ///
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
         statement: ImplStatement,
     ) -> Result<Rc<RefCell<Scope>>, Error> {
         let identifier_location = statement.identifier.location;
+        let module = scope.clone();
 
         let item = scope.borrow().resolve_item(&statement.identifier, true)?;
 
@@ -93,12 +96,21 @@ impl Analyzer {
             }
         };
 
+        if scope.borrow().is_built_in() {
+            return Err(Error::Statement(StatementError::Impl(
+                ImplStatementError::IntrinsicType {
+                    location: identifier_location,
+                    found: statement.identifier.name,
+                },
+            )));
+        }
+
         Scope::insert_item(scope.clone(), Keyword::SelfUppercase.to_string(), item);
 
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
                 ImplementationLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement, true)?;
+                    Scope::declare_constant(scope.clone(), statement, true, Some(module.clone()))?;
                 }
                 ImplementationLocalStatement::Fn(statement) => {
                     Scope::declare_type(
@@ -108,6 +120,7 @@ impl Analyzer {
                             FnStatementAnalyzerContext::Implementation,
                         ),
                         true,
+                        Some(module.clone()),
                     )?;
                 }
                 ImplementationLocalStatement::Empty(_location) => {}
@@ -2,10 +2,15 @@
 //! The `impl` statement tests.
 //!
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use crate::error::Error;
 use crate::semantic::analyzer::statement::error::Error as StatementError;
 use crate::semantic::analyzer::statement::r#impl::error::Error as ImplStatementError;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::source::Source;
 use zinc_lexical::Location;
 
 #[test]
@@ -75,3 +80,235 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_private_method_same_module() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    const OFFSET: u8 = 1;
+
+    fn offset(self) -> u8 {
+        self.value + Self::OFFSET
+    }
+
+    pub fn get(self) -> u8 {
+        self.offset()
+    }
+}
+
+fn main() -> u8 {
+    let data = Data { value: 42 };
+    data.offset() + Data::OFFSET + data.get()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_private_method_nested_module() {
+    let inner = r#"
+fn access() -> u8 {
+    super::Data::secret()
+}
+"#;
+
+    let entry = r#"
+mod inner;
+
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn secret() -> u8 {
+        42
+    }
+}
+
+fn main() -> u8 {
+    inner::access()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "inner".to_owned(),
+            Source::test(inner, PathBuf::from("inner.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+        ),]
+        .into_iter()
+        .collect::<HashMap<String, Source>>()
+    )
+    .is_ok());
+}
+
+#[test]
+fn ok_public_method_other_module() {
+    let other = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    pub const VALUE: u8 = 42;
+
+    pub fn new() -> Self {
+        Self { value: Self::VALUE }
+    }
+
+    pub fn get(self) -> u8 {
+        self.value
+    }
+}
+"#;
+
+    let entry = r#"
+mod other;
+
+fn main() -> u8 {
+    other::Data::new().get() + other::Data::VALUE
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "other".to_owned(),
+            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+        ),]
+        .into_iter()
+        .collect::<HashMap<String, Source>>()
+    )
+    .is_ok());
+}
+
+fn compile_with_other(entry: &str, other: &str) -> Result<(), Error> {
+    crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "other".to_owned(),
+            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    )
+}
+
+const OTHER_WITH_PRIVATE_ITEMS: &str = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    const SECRET: u8 = 42;
+
+    pub fn new() -> Self {
+        Self { value: 42 }
+    }
+
+    fn secret(self) -> u8 {
+        self.value
+    }
+}
+"#;
+
+#[test]
+fn error_item_is_private_method_path() {
+    let entry = r#"
+mod other;
+
+fn main() -> u8 {
+    other::Data::secret(other::Data::new())
+}
+"#;
+
+    match compile_with_other(entry, OTHER_WITH_PRIVATE_ITEMS) {
+        Err(Error::Semantic(SemanticError::Scope(ScopeError::ItemIsPrivate {
+            location,
+            name,
+            declared_at: Some(declared_at),
+        }))) => {
+            assert_eq!((location.line, location.column), (5, 18));
+            assert_eq!(name, "secret");
+            assert_eq!((declared_at.line, declared_at.column), (13, 5));
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn error_item_is_private_method_call() {
+    let entry = r#"
+mod other;
+
+fn main() -> u8 {
+    let data = other::Data::new();
+    data.secret()
+}
+"#;
+
+    match compile_with_other(entry, OTHER_WITH_PRIVATE_ITEMS) {
+        Err(Error::Semantic(SemanticError::Scope(ScopeError::ItemIsPrivate {
+            location,
+            name,
+            ..
+        }))) => {
+            assert_eq!((location.line, location.column), (6, 10));
+            assert_eq!(name, "secret");
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn error_item_is_private_constant() {
+    let entry = r#"
+mod other;
+
+fn main() -> u8 {
+    other::Data::SECRET
+}
+"#;
+
+    match compile_with_other(entry, OTHER_WITH_PRIVATE_ITEMS) {
+        Err(Error::Semantic(SemanticError::Scope(ScopeError::ItemIsPrivate {
+            location,
+            name,
+            ..
+        }))) => {
+            assert_eq!((location.line, location.column), (5, 18));
+            assert_eq!(name, "SECRET");
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn error_intrinsic_type() {
+    let input = r#"
+impl Maybe {
+    fn impossible() {}
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Statement(
+        StatementError::Impl(ImplStatementError::IntrinsicType {
+            location: Location::test(2, 6),
+            found: "Maybe".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -8,6 +8,7 @@ use std::rc::Rc;
 use zinc_syntax::LetStatement;
 
 use crate::generator::statement::r#let::Statement as GeneratorDeclarationStatement;
+use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binder;
@@ -71,4 +72,44 @@ impl Analyzer {
             ))
         })
     }
+    ///
+    /// Defines the `let` bindings as constants in a constant context, for example, in a block
+    /// expression used as an array size.
+    ///
+    /// Nothing is written to the IR, as the bindings only exist at compile time.
+    ///
+    pub fn define_constant(
+        scope: Rc<RefCell<Scope>>,
+        statement: LetStatement,
+    ) -> Result<(), Error> {
+        let expression_location = statement.expression.location;
+
+        let (element, _intermediate) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Constant)
+                .analyze(statement.expression)?;
+
+        let constant = match element {
+            Element::Constant(constant) => constant,
+            element => {
+                return Err(Error::Expression(ExpressionError::NonConstantElement {
+                    location: expression_location,
+                    found: element.to_string(),
+                }));
+            }
+        };
+
+        let constant = match statement.binding.r#type {
+            Some(r#type) => {
+                let r#type = Type::try_from_syntax(r#type, scope.clone())?;
+                let (constant, _intermediate) = constant
+                    .cast(r#type)
+                    .map_err(ElementError::Constant)
+                    .map_err(Error::Element)?;
+                constant
+            }
+            None => constant,
+        };
+
+        Binder::bind_constants(statement.binding.pattern, constant, scope)
+    }
 }
@@ -24,6 +24,8 @@ impl Analyzer {
     /// Defines a compile-time only structure type.
     ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: StructStatement) -> Result<Type, Error> {
+        let is_newtype = statement.is_newtype;
+
         let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
         for field in statement.fields.into_iter() {
             if fields
@@ -45,13 +47,16 @@ impl Analyzer {
             ));
         }
 
-        let r#type = Type::structure(
+        let mut r#type = Type::structure(
             Some(statement.location),
             statement.identifier.name,
             fields,
             None,
             Some(scope),
         );
+        if let Type::Structure(ref mut structure) = r#type {
+            structure.is_newtype = is_newtype;
+        }
 
         if !r#type.is_instantiatable(false) {
             return Err(Error::Element(ElementError::Type(
@@ -29,6 +29,9 @@ impl Analyzer {
     ///
     /// Defines an item imported by the compile-time only `use` statement.
     ///
+    /// If the statement is a glob import, the namespace at the path is registered in the `scope`,
+    /// so its items are resolved lazily when referenced.
+    ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: UseStatement) -> Result<(), Error> {
         let path_location = statement.path.location;
 
@@ -46,6 +49,13 @@ impl Analyzer {
             }
         };
 
+        if statement.is_glob {
+            let item = Scope::resolve_path(scope.clone(), &path)?;
+            let namespace = Scope::get_namespace_scope(&item.borrow(), path.last())?;
+            Scope::define_glob(scope, path.to_string(), namespace);
+            return Ok(());
+        }
+
         let mut item = Scope::resolve_path(scope.clone(), &path)?.borrow().clone();
         item.set_not_associated();
         let identifier = match statement.alias_identifier {
@@ -2,6 +2,9 @@
 //! The `use` statement tests.
 //!
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use num::BigInt;
 
 use crate::error::Error;
@@ -11,6 +14,8 @@ use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::source::Source;
 use zinc_lexical::Location;
 
 #[test]
@@ -77,6 +82,213 @@ fn main() -> u8 {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_glob_module() {
+    let module_1 = r#"
+const VALUE: u8 = 42;
+
+fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let entry = r#"
+mod module_1;
+
+use module_1::*;
+
+fn main() -> u8 {
+    double(VALUE)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "module_1".to_owned(),
+            Source::test(module_1, PathBuf::from("module_1.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_glob_enumeration() {
+    let input = r#"
+enum Data {
+    A = 1,
+    B = 2,
+}
+
+use Data::*;
+
+fn main() -> u8 {
+    (A + B) as u8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_glob_explicit_item_wins() {
+    let input = r#"
+enum Data {
+    A = 1,
+    VALUE = 2,
+}
+
+use Data::*;
+
+const VALUE: u8 = 42;
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_glob_explicit_use_wins() {
+    let input = r#"
+enum First {
+    A = 1,
+    B = 2,
+}
+
+enum Second {
+    B = 3,
+    C = 4,
+}
+
+use First::*;
+use Second::*;
+use Second::B;
+
+fn main() -> u8 {
+    (B + C) as u8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_glob_ambiguous_not_referenced() {
+    let input = r#"
+enum First {
+    A = 1,
+    B = 2,
+}
+
+enum Second {
+    B = 3,
+    C = 4,
+}
+
+use First::*;
+use Second::*;
+
+fn main() -> u8 {
+    (Second::B + C) as u8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_glob_ambiguous_item() {
+    let input = r#"
+enum First {
+    A = 1,
+    B = 2,
+}
+
+enum Second {
+    B = 3,
+    C = 4,
+}
+
+use First::*;
+use Second::*;
+
+fn main() -> u8 {
+    B as u8
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::AmbiguousItem {
+            location: Location::test(16, 5),
+            name: "B".to_owned(),
+            first: "First".to_owned(),
+            second: "Second".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_glob_ambiguous_item_message() {
+    let input = r#"
+enum First {
+    A = 1,
+    B = 2,
+}
+
+enum Second {
+    B = 3,
+    C = 4,
+}
+
+use First::*;
+use Second::*;
+
+fn main() -> u8 {
+    B as u8
+}
+"#;
+
+    let message = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(message.contains("imported from both `First::*` and `Second::*`"));
+    assert!(message.contains("`use First::B;`"));
+}
+
+#[test]
+fn error_glob_not_a_namespace() {
+    let input = r#"
+const VALUE: u8 = 42;
+
+use VALUE::*;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemIsNotANamespace {
+            location: Location::test(4, 5),
+            name: "VALUE".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_expected_path() {
     let input = r#"
@@ -18,6 +18,13 @@ pub enum Error {
         /// The found type.
         found: String,
     },
+    /// A mutable variable is declared in a constant context, where nothing can be mutated.
+    MutableInConstantContext {
+        /// The binding location.
+        location: Location,
+        /// The binding identifier.
+        name: String,
+    },
     /// The `self` argument of a method must be first in argument list, but it is not.
     FunctionMethodSelfNotFirst {
         /// The invalid argument location.
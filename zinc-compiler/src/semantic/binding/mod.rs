@@ -16,8 +16,10 @@ use zinc_syntax::BindingPatternVariant;
 use zinc_syntax::Identifier;
 
 use crate::semantic::analyzer::statement::r#fn::Context as FnAnalyzerContext;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::item::Item as ScopeItem;
@@ -117,6 +119,50 @@ impl Binder {
         }
     }
 
+    ///
+    /// Validates a binding pattern and defines the bound constants in a constant context.
+    ///
+    /// Tuple constants are destructured with binding lists, whereas mutable bindings are
+    /// forbidden, since constants cannot be mutated.
+    ///
+    pub fn bind_constants(
+        pattern: BindingPattern,
+        constant: Constant,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<(), SemanticError> {
+        match pattern.variant {
+            BindingPatternVariant::Binding {
+                identifier,
+                is_mutable: true,
+            } => Err(SemanticError::Binding(Error::MutableInConstantContext {
+                location: identifier.location,
+                name: identifier.name,
+            })),
+            BindingPatternVariant::Binding {
+                identifier,
+                is_mutable: false,
+            } => Scope::define_constant(scope, identifier, constant, false),
+            BindingPatternVariant::BindingList { bindings } => {
+                let values = match constant {
+                    Constant::Tuple(tuple) if tuple.values.len() == bindings.len() => tuple.values,
+                    constant => {
+                        return Err(SemanticError::Binding(Error::ExpectedTuple {
+                            location: pattern.location,
+                            expected: bindings.len(),
+                            found: constant.r#type().to_string(),
+                        }));
+                    }
+                };
+
+                for (pattern, constant) in bindings.into_iter().zip(values.into_iter()) {
+                    Self::bind_constants(pattern, constant, scope.clone())?;
+                }
+                Ok(())
+            }
+            BindingPatternVariant::Wildcard => Ok(()),
+        }
+    }
+
     ///
     /// Validates a binding pattern and returns the variable declaration list.
     ///
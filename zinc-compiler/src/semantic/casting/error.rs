@@ -21,4 +21,13 @@ pub enum Error {
         /// The second operand type.
         to: String,
     },
+    /// When the integer literal does not fit into the type inferred from the other operand.
+    LiteralOutOfRange {
+        /// The literal value.
+        value: String,
+        /// The literal type.
+        from: String,
+        /// The other operand type.
+        to: String,
+    },
 }
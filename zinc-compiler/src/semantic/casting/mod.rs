@@ -7,6 +7,8 @@ mod tests;
 
 pub mod error;
 
+use num::BigInt;
+
 use crate::semantic::element::r#type::Type;
 
 use self::error::Error;
@@ -30,9 +32,15 @@ impl Caster {
     /// enum<b1> -> i<b2>
     /// enum<b1> -> u<b2>
     /// enum<b1> -> field
+    /// (the enumerations with payloads cannot be cast)
+    /// u<b1> -> ufixed<b2>x<d>
+    /// i<b1> -> fixed<b2>x<d>
+    /// ufixed<b1>x<d> -> u<b2>
+    /// fixed<b1>x<d> -> i<b2>
     /// T -> T (no effect, no errors)
     ///
     /// `b1` and `b2` are bitlengths
+    /// `d` is the number of fixed-point decimals
     /// `T` is any type
     ///
     /// For more information on type semantics, see the official Zinc book.
@@ -45,10 +53,28 @@ impl Caster {
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::Field(_)) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::Field(_)) => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerSigned { .. }) if !inner.has_payloads() => {
+                Ok(())
+            }
+            (Type::Enumeration(inner), Type::IntegerUnsigned { .. }) if !inner.has_payloads() => {
+                Ok(())
+            }
+            (Type::Enumeration(inner), Type::Field(_)) if !inner.has_payloads() => Ok(()),
             (from, to) if from == to => Ok(()),
+            (Type::IntegerUnsigned { .. }, Type::Structure(inner))
+            | (Type::IntegerSigned { .. }, Type::Structure(inner))
+                if inner.is_fixed()
+                    && from.is_scalar_signed() == inner.fixed_inner_type().is_scalar_signed() =>
+            {
+                Ok(())
+            }
+            (Type::Structure(inner), Type::IntegerUnsigned { .. })
+            | (Type::Structure(inner), Type::IntegerSigned { .. })
+                if inner.is_fixed()
+                    && inner.fixed_inner_type().is_scalar_signed() == to.is_scalar_signed() =>
+            {
+                Ok(())
+            }
 
             (from @ Type::IntegerUnsigned { .. }, to) => Err(Error::CastingToInvalidType {
                 from: from.to_string(),
@@ -64,4 +90,42 @@ impl Caster {
             }),
         }
     }
+
+    ///
+    /// Validates inferring the integer `literal` type `from` to the type `to` of the other
+    /// operand of a binary operator.
+    ///
+    /// Returns whether the literal type has been widened, or an error if the literal value does
+    /// not fit into the inferred type.
+    ///
+    pub fn cast_literal(literal: &BigInt, from: &Type, to: &Type) -> Result<bool, Error> {
+        let (from_bitlength, (is_signed, bitlength)) =
+            match (Self::integer_layout(from), Self::integer_layout(to)) {
+                (Some((_, from_bitlength)), Some(layout)) => (from_bitlength, layout),
+                _ => return Ok(false),
+            };
+
+        match zinc_math::infer_minimal_bitlength(literal, is_signed) {
+            Ok(minimal_bitlength) if minimal_bitlength <= bitlength => {
+                Ok(from_bitlength < bitlength)
+            }
+            _ => Err(Error::LiteralOutOfRange {
+                value: literal.to_string(),
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Returns the integer type sign and bitlength, if the type is an integer one.
+    ///
+    fn integer_layout(r#type: &Type) -> Option<(bool, usize)> {
+        match r#type {
+            Type::IntegerUnsigned { bitlength, .. } => Some((false, *bitlength)),
+            Type::IntegerSigned { bitlength, .. } => Some((true, *bitlength)),
+            Type::Field(_) => Some((false, zinc_const::bitlength::FIELD)),
+            _ => None,
+        }
+    }
 }
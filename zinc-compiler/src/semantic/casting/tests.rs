@@ -257,3 +257,48 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_literal_out_of_range() {
+    let input = r#"
+fn main() {
+    let value: u8 = 42;
+    let result = value + 300;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Casting {
+            location: Location::test(4, 26),
+            inner: CastingError::LiteralOutOfRange {
+                value: "300".to_owned(),
+                from: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 2).to_string(),
+                to: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            },
+            reference: Location::test(4, 18),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_literal_out_of_range_message() {
+    let input = r#"
+fn main() {
+    let value: u8 = 42;
+    let result = 300 + value;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(result.contains(
+        "integer literal `300` of type `u16` does not fit into the other operand type `u8`"
+    ));
+    assert!(result.contains("consider casting the other operand to `u16` with `as u16`"));
+}
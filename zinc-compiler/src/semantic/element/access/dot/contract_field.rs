@@ -26,13 +26,17 @@ pub struct ContractField {
     /// For this type the `StorageLoad` instruction is not created, so the storage field index
     /// remains on the evaluation stack in order to be used by the `MTreeMap` methods as the
     /// instance argument, which is simply the map's position in the contract storage.
+    /// The same applies to arrays of maps, whose elements occupy consecutive storage positions.
     pub is_mtreemap: bool,
+    /// The number of the contract storage Merkle tree leaves occupied by the field.
+    pub leaves_count: usize,
 }
 
 impl ContractField {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         position: usize,
@@ -41,6 +45,7 @@ impl ContractField {
         total_size: usize,
         is_immutable: bool,
         is_mtreemap: bool,
+        leaves_count: usize,
     ) -> Self {
         Self {
             name,
@@ -50,6 +55,7 @@ impl ContractField {
             total_size,
             is_immutable,
             is_mtreemap,
+            leaves_count,
         }
     }
 }
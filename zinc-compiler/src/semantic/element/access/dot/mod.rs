@@ -18,6 +18,9 @@ pub enum Dot {
     StackField(StackField),
     /// Contract storage field access via the dot `.` operator
     ContractField(ContractField),
+    /// Newtype inner value access via `.0`, which is a no-op, since the newtype has the same
+    /// representation as its inner value
+    Newtype,
     /// Method call via the dot `.` operator
     Method {
         /// The `self`, instance argument, for which the method is called.
@@ -111,6 +111,26 @@ impl Array {
         Ok(())
     }
 
+    ///
+    /// Concatenates the array with `other`, producing an array of the combined size.
+    ///
+    /// An empty array takes the element type of the other operand.
+    ///
+    pub fn concatenate(mut self, other: Self) -> Result<Self, Error> {
+        if self.is_empty() {
+            self.r#type = other.r#type;
+        } else if !other.is_empty() && other.r#type != self.r#type {
+            return Err(Error::ConcatenationInvalidType {
+                location: other.location,
+                expected: self.r#type().to_string(),
+                found: other.r#type().to_string(),
+            });
+        }
+        self.values.extend(other.values);
+
+        Ok(self)
+    }
+
     ///
     /// Applies the index operator, getting a single element from the array.
     ///
@@ -12,6 +12,21 @@ use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use zinc_lexical::Location;
 
+#[test]
+fn ok_concatenation() {
+    let input = r#"
+const ARRAY: [u8; 5] = [1, 2] + [3, 4, 5];
+
+fn main() -> u8 {
+    ARRAY[4]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn error_pushing_invalid_type() {
     let input = r#"
@@ -123,3 +138,31 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_concatenation_invalid_type() {
+    let input = r#"
+fn main() {
+    const ARRAY: [u8; 3] = [1, 2] + [true];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Array(
+            ArrayConstantError::ConcatenationInvalidType {
+                location: Location::test(3, 37),
+                expected: Type::array(
+                    None,
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    2,
+                )
+                .to_string(),
+                found: Type::array(None, Type::boolean(None), 1).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -117,6 +117,13 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The `==` operator expects a string constant as the second operand.
+    OperatorEqualsSecondOperandExpectedString {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
 
     /// The `!=` operator expects a primitive type constant as the first operand.
     /// Primitive types are units, booleans, and integers.
@@ -147,6 +154,44 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The `!=` operator expects a string constant as the second operand.
+    OperatorNotEqualsSecondOperandExpectedString {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
+    /// The `==` or `!=` operator operands have different composite types.
+    OperatorEqualityTypesMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified first operand type.
+        first: String,
+        /// The stringified second operand type.
+        second: String,
+    },
+    /// The `==` or `!=` operator composite operand contains an incomparable element.
+    OperatorEqualityIncomparableType {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified operand type.
+        found: String,
+        /// The stringified incomparable element type.
+        element: String,
+    },
+    /// The ordering operators cannot be applied to tuple, array, or structure constants.
+    OperatorOrderingComposite {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
 
     /// The `>=` operator expects an integer type constant as the first operand.
     OperatorGreaterEqualsFirstOperandExpectedInteger {
@@ -297,6 +342,13 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The `+` operator expects a string constant as the second operand.
+    OperatorAdditionSecondOperandExpectedString {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
 
     /// The `-` operator expects an integer type constant as the first operand.
     OperatorSubtractionFirstOperandExpectedInteger {
@@ -413,6 +465,15 @@ pub enum Error {
         found: String,
     },
 
+    /// The string constant is used as an operand of a runtime expression, whereas strings only
+    /// exist at compile time.
+    StringRuntimeOperand {
+        /// The error location data.
+        location: Location,
+        /// The stringified string constant.
+        found: String,
+    },
+
     /// The integer constant error. See inner element description.
     Integer(IntegerConstantError),
     /// The array constant error. See inner element description.
@@ -140,6 +140,18 @@ pub enum Error {
         second: String,
     },
 
+    /// The binary operators do not mix `field` with sized integers without an explicit cast.
+    OperandTypeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified first operand type.
+        lhs: String,
+        /// The stringified second operand type.
+        rhs: String,
+        /// The explicit casting hint.
+        hint: String,
+    },
+
     /// The `<<` operator expects an unsigned integer as the second operand.
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
         /// The error location data.
@@ -209,6 +221,17 @@ pub enum Error {
         /// The type overflowed by `value`.
         r#type: String,
     },
+    /// The binary `as` operator casts a value which is not a variant of the target enumeration.
+    CastingInvalidDiscriminant {
+        /// The error location data.
+        location: Location,
+        /// The value which does not match any enumeration variant.
+        value: BigInt,
+        /// The enumeration type casted to.
+        r#type: String,
+        /// The stringified list of the enumeration variant values.
+        expected: String,
+    },
     /// The unary `-` operator overflow.
     OverflowNegation {
         /// The error location data.
@@ -90,6 +90,36 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    ///
+    /// Returns the explicit casting hint, if one of the operands is `field` and the other one is
+    /// a sized integer.
+    ///
+    /// The bitwise operators are forbidden for `field`, so the `field` operand must be cast
+    /// to the sized integer type. Otherwise, the sized integer operand must be cast to `field`.
+    ///
+    fn field_mixing_hint(&self, other: &Self, is_bitwise: bool) -> Option<String> {
+        let is_field_1 = self.bitlength == zinc_const::bitlength::FIELD;
+        let is_field_2 = other.bitlength == zinc_const::bitlength::FIELD;
+        if is_field_1 == is_field_2 {
+            return None;
+        }
+
+        let (operand, r#type) = match (is_field_1, is_bitwise) {
+            (true, false) => ("second", Type::field(None)),
+            (false, false) => ("first", Type::field(None)),
+            (true, true) => (
+                "first",
+                Type::integer(None, other.is_signed, other.bitlength),
+            ),
+            (false, true) => (
+                "second",
+                Type::integer(None, self.is_signed, self.bitlength),
+            ),
+        };
+
+        Some(format!("cast the {} operand with `as {}`", operand, r#type))
+    }
+
     ///
     /// Returns the inner `BigInt` value.
     ///
@@ -159,6 +189,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchEquals {
                 location: self.location,
@@ -197,6 +236,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchNotEquals {
                 location: self.location,
@@ -235,6 +283,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchGreaterEquals {
                 location: self.location,
@@ -273,6 +330,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchLesserEquals {
                 location: self.location,
@@ -311,6 +377,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchGreater {
                 location: self.location,
@@ -349,6 +424,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchLesser {
                 location: self.location,
@@ -388,6 +472,15 @@ impl BitOr for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseOr {
                 location: self.location,
@@ -446,6 +539,15 @@ impl BitXor for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseXor {
                 location: self.location,
@@ -504,6 +606,15 @@ impl BitAnd for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseAnd {
                 location: self.location,
@@ -666,6 +777,15 @@ impl Add for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: location_1,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchAddition {
                 location: location_1,
@@ -740,6 +860,15 @@ impl Sub for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: location_1,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchSubtraction {
                 location: location_1,
@@ -814,6 +943,15 @@ impl Mul for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: location_1,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchMultiplication {
                 location: location_1,
@@ -888,6 +1026,15 @@ impl Div for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchDivision {
                 location: self.location,
@@ -971,6 +1118,15 @@ impl Rem for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location,
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchRemainder {
                 location: self.location,
@@ -1091,6 +1247,52 @@ impl Integer {
         Ok((result, operator))
     }
 
+    ///
+    /// Executes the `as` casting operator to the `enumeration` type.
+    ///
+    /// The value must be equal to one of the enumeration variant values, which is only
+    /// checked for constants, as they are known at compile time.
+    ///
+    pub fn cast_to_enumeration(
+        self,
+        enumeration: Enumeration,
+    ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
+        if !enumeration.values.contains(&self.value) {
+            return Err(Error::CastingInvalidDiscriminant {
+                location: self.location,
+                value: self.value,
+                r#type: Type::Enumeration(enumeration.clone()).to_string(),
+                expected: enumeration
+                    .values
+                    .iter()
+                    .map(|value| format!("`{}`", value))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            });
+        }
+
+        let operator = if self.is_signed || self.bitlength != enumeration.bitlength {
+            GeneratorExpressionOperator::try_casting(&Type::scalar(
+                Some(self.location),
+                false,
+                enumeration.bitlength,
+            ))
+        } else {
+            None
+        };
+
+        let result = Self {
+            location: self.location,
+            value: self.value,
+            is_signed: false,
+            bitlength: enumeration.bitlength,
+            enumeration: Some(enumeration),
+            is_literal: false,
+        };
+
+        Ok((result, operator))
+    }
+
     ///
     /// Executes the `~` bitwise NOT operator.
     ///
@@ -989,6 +989,78 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operand_type_mismatch_arithmetic() {
+    let input = r#"
+fn main() {
+    let value = 42 as field - 64 as u64;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OperandTypeMismatch {
+                location: Location::test(3, 17),
+                lhs: Type::field(None).to_string(),
+                rhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                hint: "cast the second operand with `as field`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operand_type_mismatch_comparison() {
+    let input = r#"
+fn main() {
+    let value = 42 as u64 == 64 as field;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OperandTypeMismatch {
+                location: Location::test(3, 17),
+                lhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                rhs: Type::field(None).to_string(),
+                hint: "cast the first operand with `as field`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operand_type_mismatch_bitwise() {
+    let input = r#"
+fn main() {
+    let value = 42 as u64 | 64 as field;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OperandTypeMismatch {
+                location: Location::test(3, 17),
+                lhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                rhs: Type::field(None).to_string(),
+                hint: "cast the second operand with `as u64`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_bitwise_shift_left_2nd_operand_expected_unsigned() {
     let input = r#"
@@ -1351,6 +1423,72 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_casting_enumeration_to_integer() {
+    let input = r#"
+enum Size {
+    Small = 4,
+    Large = 16,
+}
+
+const SMALL: u8 = Size::Small as u8;
+const LARGE: field = Size::Large as field;
+
+fn main() -> [u8; Size::Large as u8] {
+    require(SMALL + 12 == Size::Large as u8);
+    [0; SMALL * 4]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_casting_integer_to_enumeration() {
+    let input = r#"
+enum State {
+    Idle = 0,
+    Active = 5,
+}
+
+const ACTIVE: State = 5 as State;
+const IDLE: State = (ACTIVE as u8 - 5) as State;
+
+fn main() -> bool {
+    ACTIVE == State::Active && IDLE == State::Idle
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_casting_invalid_discriminant() {
+    let input = r#"
+enum State {
+    Idle = 0,
+    Active = 5,
+}
+
+const INVALID: State = 3 as State;
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::CastingInvalidDiscriminant {
+                location: Location::test(7, 24),
+                value: BigInt::from(3),
+                r#type: "enumeration State".to_owned(),
+                expected: "`0`, `5`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_overflow_negation_signed_positive() {
     let input = r#"
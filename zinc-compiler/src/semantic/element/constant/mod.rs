@@ -42,6 +42,7 @@ use crate::semantic::element::tuple_index::TupleIndex;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 
+use self::array::error::Error as ArrayError;
 use self::array::Array;
 use self::boolean::Boolean;
 use self::error::Error;
@@ -194,6 +195,8 @@ impl Constant {
     ///
     /// Executes the `==` equals comparison operator.
     ///
+    /// The strings only exist at compile time, so their comparison does not write an operator.
+    ///
     pub fn equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
         match (self, other) {
             (Self::Unit(constant_1), Self::Unit(_)) => Ok((
@@ -224,6 +227,29 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
+            (Self::String(constant_1), Self::String(constant_2)) => Ok((
+                Self::Boolean(constant_1.equals(constant_2)),
+                GeneratorExpressionOperator::None,
+            )),
+            (Self::String(_), constant_2) => {
+                Err(Error::OperatorEqualsSecondOperandExpectedString {
+                    location: constant_2.location(),
+                    found: constant_2.to_string(),
+                })
+            }
+            (constant_1 @ Self::Array(_), constant_2)
+            | (constant_1 @ Self::Tuple(_), constant_2)
+            | (constant_1 @ Self::Structure(_), constant_2) => {
+                Self::check_composite_equality(&constant_1, &constant_2, "==").map(|size| {
+                    (
+                        Self::Boolean(Boolean::new(
+                            constant_1.location(),
+                            constant_1.is_equal_to(&constant_2),
+                        )),
+                        GeneratorExpressionOperator::equals_composite(size),
+                    )
+                })
+            }
             (constant_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
@@ -234,6 +260,8 @@ impl Constant {
     ///
     /// Executes the `!=` not-equals comparison operator.
     ///
+    /// The strings only exist at compile time, so their comparison does not write an operator.
+    ///
     pub fn not_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
         match (self, other) {
             (Self::Unit(constant_1), Self::Unit(_)) => Ok((
@@ -264,6 +292,29 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
+            (Self::String(constant_1), Self::String(constant_2)) => Ok((
+                Self::Boolean(constant_1.not_equals(constant_2)),
+                GeneratorExpressionOperator::None,
+            )),
+            (Self::String(_), constant_2) => {
+                Err(Error::OperatorNotEqualsSecondOperandExpectedString {
+                    location: constant_2.location(),
+                    found: constant_2.to_string(),
+                })
+            }
+            (constant_1 @ Self::Array(_), constant_2)
+            | (constant_1 @ Self::Tuple(_), constant_2)
+            | (constant_1 @ Self::Structure(_), constant_2) => {
+                Self::check_composite_equality(&constant_1, &constant_2, "!=").map(|size| {
+                    (
+                        Self::Boolean(Boolean::new(
+                            constant_1.location(),
+                            !constant_1.is_equal_to(&constant_2),
+                        )),
+                        GeneratorExpressionOperator::not_equals_composite(size),
+                    )
+                })
+            }
             (constant_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
@@ -286,6 +337,13 @@ impl Constant {
                     found: constant.to_string(),
                 }),
             },
+            constant @ Self::Array(_)
+            | constant @ Self::Tuple(_)
+            | constant @ Self::Structure(_) => Err(Error::OperatorOrderingComposite {
+                location: constant.location(),
+                operator: ">=".to_owned(),
+                found: constant.r#type().to_string(),
+            }),
             constant => Err(Error::OperatorGreaterEqualsFirstOperandExpectedInteger {
                 location: constant.location(),
                 found: constant.to_string(),
@@ -308,6 +366,13 @@ impl Constant {
                     found: constant.to_string(),
                 }),
             },
+            constant @ Self::Array(_)
+            | constant @ Self::Tuple(_)
+            | constant @ Self::Structure(_) => Err(Error::OperatorOrderingComposite {
+                location: constant.location(),
+                operator: "<=".to_owned(),
+                found: constant.r#type().to_string(),
+            }),
             constant => Err(Error::OperatorLesserEqualsFirstOperandExpectedInteger {
                 location: constant.location(),
                 found: constant.to_string(),
@@ -330,6 +395,13 @@ impl Constant {
                     found: constant.to_string(),
                 }),
             },
+            constant @ Self::Array(_)
+            | constant @ Self::Tuple(_)
+            | constant @ Self::Structure(_) => Err(Error::OperatorOrderingComposite {
+                location: constant.location(),
+                operator: ">".to_owned(),
+                found: constant.r#type().to_string(),
+            }),
             constant => Err(Error::OperatorGreaterFirstOperandExpectedInteger {
                 location: constant.location(),
                 found: constant.to_string(),
@@ -352,6 +424,13 @@ impl Constant {
                     found: constant.to_string(),
                 }),
             },
+            constant @ Self::Array(_)
+            | constant @ Self::Tuple(_)
+            | constant @ Self::Structure(_) => Err(Error::OperatorOrderingComposite {
+                location: constant.location(),
+                operator: "<".to_owned(),
+                found: constant.r#type().to_string(),
+            }),
             constant => Err(Error::OperatorLesserFirstOperandExpectedInteger {
                 location: constant.location(),
                 found: constant.to_string(),
@@ -496,6 +575,27 @@ impl Add for Constant {
                     found: constant.to_string(),
                 }),
             },
+            Self::Array(array_1) => match other {
+                Self::Array(array_2) => array_1
+                    .concatenate(array_2)
+                    .map(|array| (Self::Array(array), GeneratorExpressionOperator::None))
+                    .map_err(Error::Array),
+                constant => Err(Error::Array(ArrayError::ConcatenationInvalidType {
+                    location: constant.location(),
+                    expected: array_1.r#type().to_string(),
+                    found: constant.r#type().to_string(),
+                })),
+            },
+            Self::String(string_1) => match other {
+                Self::String(string_2) => Ok((
+                    Self::String(string_1.concatenate(string_2)),
+                    GeneratorExpressionOperator::None,
+                )),
+                constant => Err(Error::OperatorAdditionSecondOperandExpectedString {
+                    location: constant.location(),
+                    found: constant.to_string(),
+                }),
+            },
             constant => Err(Error::OperatorAdditionFirstOperandExpectedInteger {
                 location: constant.location(),
                 found: constant.to_string(),
@@ -600,7 +700,27 @@ impl Constant {
     ///
     /// Executes the `as` casting operator.
     ///
+    /// Unlike runtime values, integer constants can be also cast to enumerations without
+    /// payloads, since their membership in the variant list is checked at compile time.
+    ///
     pub fn cast(self, to: Type) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
+        match (self, to) {
+            (Self::Integer(integer), Type::Enumeration(enumeration))
+                if integer.enumeration.is_none() && !enumeration.has_payloads() =>
+            {
+                integer
+                    .cast_to_enumeration(enumeration)
+                    .map(|(integer, operator)| (Self::Integer(integer), operator))
+                    .map_err(Error::Integer)
+            }
+            (constant, to) => constant.cast_scalar(to),
+        }
+    }
+
+    ///
+    /// Executes the `as` casting operator to a scalar type, or to the same type.
+    ///
+    fn cast_scalar(self, to: Type) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
         let from = self.r#type();
         Caster::cast(&from, &to).map_err(|error| Error::Casting {
             location: self.location(),
@@ -788,6 +908,73 @@ impl Constant {
         }
     }
 
+    ///
+    /// Checks whether the tuple, array, or structure operands of the equality `operator` have
+    /// the same type consisting of comparable elements only.
+    ///
+    /// Returns the operand size, that is, the number of elements compared by the generator.
+    ///
+    fn check_composite_equality(
+        constant_1: &Self,
+        constant_2: &Self,
+        operator: &str,
+    ) -> Result<usize, Error> {
+        let type_1 = constant_1.r#type();
+        if let Some(element) = type_1.find_incomparable() {
+            return Err(Error::OperatorEqualityIncomparableType {
+                location: constant_1.location(),
+                operator: operator.to_owned(),
+                found: type_1.to_string(),
+                element: element.to_string(),
+            });
+        }
+
+        let type_2 = constant_2.r#type();
+        if type_1 != type_2 {
+            return Err(Error::OperatorEqualityTypesMismatch {
+                location: constant_2.location(),
+                operator: operator.to_owned(),
+                first: type_1.to_string(),
+                second: type_2.to_string(),
+            });
+        }
+
+        Ok(type_1.size())
+    }
+
+    ///
+    /// Compares the constants element-wise.
+    ///
+    /// The constants must be already checked to have the same comparable type.
+    ///
+    fn is_equal_to(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unit(_), Self::Unit(_)) => true,
+            (Self::Boolean(constant_1), Self::Boolean(constant_2)) => {
+                constant_1.inner == constant_2.inner
+            }
+            (Self::Integer(constant_1), Self::Integer(constant_2)) => {
+                constant_1.value == constant_2.value
+            }
+            (Self::Array(constant_1), Self::Array(constant_2)) => constant_1
+                .values
+                .iter()
+                .zip(constant_2.values.iter())
+                .all(|(value_1, value_2)| value_1.is_equal_to(value_2)),
+            (Self::Tuple(constant_1), Self::Tuple(constant_2)) => constant_1
+                .values
+                .iter()
+                .zip(constant_2.values.iter())
+                .all(|(value_1, value_2)| value_1.is_equal_to(value_2)),
+            (Self::Structure(constant_1), Self::Structure(constant_2)) => constant_1
+                .values
+                .iter()
+                .zip(constant_2.values.iter())
+                .all(|((_, value_1), (_, value_2))| value_1.is_equal_to(value_2)),
+            _ => false,
+        }
+    }
+
     ///
     /// If the constant was created from a literal.
     ///
@@ -4,9 +4,13 @@
 
 use std::fmt;
 
+use num::BigInt;
+
 use zinc_lexical::Location;
 use zinc_syntax::StringLiteral;
 
+use crate::semantic::element::constant::boolean::Boolean;
+use crate::semantic::element::constant::integer::Integer;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 
@@ -28,6 +32,40 @@ impl String {
     pub fn new(location: Location, inner: ::std::string::String) -> Self {
         Self { location, inner }
     }
+
+    ///
+    /// Appends the `other` string, keeping the location of the first operand.
+    ///
+    pub fn concatenate(mut self, other: Self) -> Self {
+        self.inner.push_str(other.inner.as_str());
+        self
+    }
+
+    ///
+    /// Executes the `==` equals comparison operator.
+    ///
+    pub fn equals(self, other: Self) -> Boolean {
+        Boolean::new(self.location, self.inner == other.inner)
+    }
+
+    ///
+    /// Executes the `!=` not-equals comparison operator.
+    ///
+    pub fn not_equals(self, other: Self) -> Boolean {
+        Boolean::new(self.location, self.inner != other.inner)
+    }
+
+    ///
+    /// Returns the string length in bytes as an integer literal, whose type is inferred from
+    /// the other operand, like in `"hello".len() == 5`.
+    ///
+    pub fn len(&self, location: Location) -> Integer {
+        let value = BigInt::from(self.inner.len());
+        let bitlength = Integer::minimal_bitlength_bigints(&[&value], false, location)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        Integer::new(location, value, false, bitlength, true)
+    }
 }
 
 impl ITyped for String {
@@ -55,4 +55,20 @@ pub enum Error {
         /// The position of the provided structure field.
         found: usize,
     },
+    /// The structure update base is not a structure.
+    BaseExpectedStructure {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid base element.
+        found: String,
+    },
+    /// The structure update base type does not match the structure type.
+    BaseTypeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified expected structure type.
+        expected: String,
+        /// The stringified base structure type.
+        found: String,
+    },
 }
@@ -31,6 +31,8 @@ pub struct Structure {
     pub r#type: Option<StructureType>,
     /// The ordered structure values array.
     pub values: Vec<(Identifier, Constant)>,
+    /// The structure update base location and type, which must match the structure type.
+    pub base: Option<(Location, StructureType)>,
 }
 
 impl Structure {
@@ -44,6 +46,7 @@ impl Structure {
             location,
             r#type: None,
             values: vec![],
+            base: None,
         }
     }
 
@@ -54,10 +57,27 @@ impl Structure {
         self.values.push((identifier, value));
     }
 
+    ///
+    /// Sets the structure update base `location` and `type`, which are checked during validation.
+    ///
+    pub fn set_base(&mut self, location: Location, r#type: StructureType) {
+        self.base = Some((location, r#type));
+    }
+
     ///
     /// Sets the structure type and checks if the pushed field types match it.
     ///
     pub fn validate(&mut self, expected: StructureType) -> Result<(), Error> {
+        if let Some((location, ref base_type)) = self.base {
+            if base_type != &expected {
+                return Err(Error::BaseTypeMismatch {
+                    location,
+                    expected: expected.identifier,
+                    found: base_type.identifier.to_owned(),
+                });
+            }
+        }
+
         for (index, (identifier, constant)) in self.values.iter().enumerate() {
             match expected.fields.get(index) {
                 Some((expected_name, expected_type)) => {
@@ -135,3 +135,57 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_base() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+const BASE: Data = Data { a: 1, b: 2 };
+const DATA: Data = Data { b: 42, ..BASE };
+
+fn main() -> u8 {
+    DATA.a + DATA.b
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_base_type_mismatch() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+struct Other {
+    a: u8,
+    b: u8,
+}
+
+const BASE: Other = Other { a: 1, b: 2 };
+
+fn main() {
+    const DATA: Data = Data { a: 42, ..BASE };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::Structure(
+            StructureConstantError::BaseTypeMismatch {
+                location: Location::test(15, 40),
+                expected: "Data".to_owned(),
+                found: "Other".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -10,6 +10,7 @@ use crate::semantic::element::constant::array::Array as ArrayConstant;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::range::Range as RangeConstant;
 use crate::semantic::element::constant::string::String as StringConstant;
 use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
 use crate::semantic::element::constant::Constant;
@@ -270,17 +271,20 @@ fn main() {
 fn error_operator_equals_1st_expected_primitive() {
     let input = r#"
 fn main() {
-    let value = "string" == 42;
+    let value = (0 .. 8) == 42;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         ElementError::Constant(
             ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                location: Location::test(3, 17),
-                found: Constant::String(StringConstant::new(
-                    Location::test(3, 17),
-                    "string".to_owned(),
+                location: Location::test(3, 18),
+                found: Constant::Range(RangeConstant::new(
+                    Location::test(3, 18),
+                    BigInt::from(0),
+                    BigInt::from(8),
+                    false,
+                    zinc_const::bitlength::BYTE,
                 ))
                 .to_string(),
             },
@@ -366,21 +370,51 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_equals_2nd_expected_string() {
+    let input = r#"
+fn main() {
+    let value = "string" == 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedString {
+            location: Location::test(3, 29),
+            found: Constant::Integer(IntegerConstant::new(
+                Location::test(3, 29),
+                BigInt::from(42),
+                false,
+                zinc_const::bitlength::BYTE,
+                true,
+            ))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_not_equals_1st_expected_primitive() {
     let input = r#"
 fn main() {
-    let value = "string" != 42;
+    let value = (0 .. 8) != 42;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         ElementError::Constant(
             ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                location: Location::test(3, 17),
-                found: Constant::String(StringConstant::new(
-                    Location::test(3, 17),
-                    "string".to_owned(),
+                location: Location::test(3, 18),
+                found: Constant::Range(RangeConstant::new(
+                    Location::test(3, 18),
+                    BigInt::from(0),
+                    BigInt::from(8),
+                    false,
+                    zinc_const::bitlength::BYTE,
                 ))
                 .to_string(),
             },
@@ -471,6 +505,153 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_not_equals_2nd_expected_string() {
+    let input = r#"
+fn main() {
+    let value = "string" != 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(
+            ConstantError::OperatorNotEqualsSecondOperandExpectedString {
+                location: Location::test(3, 29),
+                found: Constant::Integer(IntegerConstant::new(
+                    Location::test(3, 29),
+                    BigInt::from(42),
+                    false,
+                    zinc_const::bitlength::BYTE,
+                    true,
+                ))
+                .to_string(),
+            },
+        ),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_operator_equals_composite() {
+    let input = r#"
+const TUPLES: bool = (1, [true, false]) == (1, [true, false]);
+const ARRAYS: bool = [1, 2, 3] != [1, 2, 4];
+
+fn main() {
+    require(TUPLES);
+    require(ARRAYS);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_operator_equals_string() {
+    let input = r#"
+const NAME: str = "world";
+const EQUALS: bool = "hello" == "hello";
+const NOT_EQUALS: bool = NAME != "hello";
+
+fn main() {
+    require(EQUALS);
+    require(NOT_EQUALS);
+    require(NAME == "world", "the name must be world");
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_string_runtime_operand() {
+    let input = r#"
+fn main(value: u8) {
+    let result = value == "string";
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::StringRuntimeOperand {
+            location: Location::test(3, 27),
+            found: Constant::String(StringConstant::new(
+                Location::test(3, 27),
+                "string".to_owned(),
+            ))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_equals_types_mismatch() {
+    let input = r#"
+const VALUE: bool = (1, 2) == (true, 2);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::OperatorEqualityTypesMismatch {
+            location: Location::test(2, 31),
+            operator: "==".to_owned(),
+            first: Type::tuple(
+                None,
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                ],
+            )
+            .to_string(),
+            second: Type::tuple(
+                None,
+                vec![
+                    Type::boolean(None),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                ],
+            )
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_greater_composite() {
+    let input = r#"
+const VALUE: bool = [1, 2] > [1, 2];
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::OperatorOrderingComposite {
+            location: Location::test(2, 21),
+            operator: ">".to_owned(),
+            found: Type::array(
+                None,
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                2,
+            )
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_greater_equals_1st_expected_integer() {
     let input = r#"
@@ -916,6 +1097,48 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_operator_addition_string() {
+    let input = r#"
+const NAME: str = "world";
+const GREETING: str = "hello" + " " + NAME;
+
+fn main() {
+    require(GREETING == "hello world", GREETING);
+    dbg!(GREETING + "! {}", 42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_operator_addition_2nd_expected_string() {
+    let input = r#"
+fn main() {
+    let value = "string" + 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedString {
+            location: Location::test(3, 28),
+            found: Constant::Integer(IntegerConstant::new(
+                Location::test(3, 28),
+                BigInt::from(42),
+                false,
+                zinc_const::bitlength::BYTE,
+                true,
+            ))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_subtraction_1st_expected_integer() {
     let input = r#"
@@ -182,6 +182,20 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The dynamic range operator expects an integer value as the start bound.
+    OperatorRangeDynamicStartExpectedInteger {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
+    /// The dynamic range operator expects a non-negative integer constant as the length.
+    OperatorRangeDynamicLengthExpectedConstant {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
     /// The `..` operator expects a constant element as the first operand.
     OperatorRangeFirstOperandExpectedConstant {
         /// The error location data.
@@ -385,7 +399,7 @@ pub enum Error {
         found: String,
     },
     /// The `<<` operator expects an evaluable element as the second operand.
-    OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
+    OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
         /// The error location data.
         location: Location,
         /// The stringified invalid element found instead.
@@ -400,7 +414,7 @@ pub enum Error {
         found: String,
     },
     /// The `>>` operator expects an evaluable element as the second operand.
-    OperatorBitwiseShiftRightSecondOperandExpectedConstant {
+    OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
         /// The error location data.
         location: Location,
         /// The stringified invalid element found instead.
@@ -11,6 +11,7 @@ pub mod constant;
 pub mod error;
 pub mod path;
 pub mod place;
+pub mod range_dynamic;
 pub mod tuple_index;
 pub mod r#type;
 pub mod value;
@@ -30,13 +31,21 @@ use std::ops::Shr;
 use std::ops::Sub;
 use std::rc::Rc;
 
+use num::ToPrimitive;
+
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::intrinsic::string_len::Function as StringLenFunction;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunction;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::value::error::Error as ValueError;
+use crate::semantic::element::value::structure::error::Error as StructureValueError;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
@@ -49,6 +58,7 @@ use self::error::Error;
 use self::path::Path;
 use self::place::Place;
 use self::r#type::Type;
+use self::range_dynamic::RangeDynamic;
 use self::tuple_index::TupleIndex;
 use self::value::Value;
 
@@ -73,6 +83,8 @@ pub enum Element {
     Path(Path),
     /// Memory descriptor (`lvalue`)
     Place(Place),
+    /// Range with a runtime start and constant length, used for array slicing
+    RangeDynamic(RangeDynamic),
     /// Tuple field index
     TupleIndex(TupleIndex),
     /// Structure field identifier
@@ -119,11 +131,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .bitor(value_2)
+                        .newtype_operator(value_2, Value::bitor)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .bitor(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::bitor)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -156,11 +168,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .bitxor(value_2)
+                        .newtype_operator(value_2, Value::bitxor)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .bitxor(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::bitxor)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -193,11 +205,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .bitand(value_2)
+                        .newtype_operator(value_2, Value::bitand)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .bitand(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::bitand)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -233,11 +245,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .shl(value_2)
+                        .newtype_operator(value_2, Value::shl)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .shl(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::shl)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -273,11 +285,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .shr(value_2)
+                        .newtype_operator(value_2, Value::shr)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .shr(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::shr)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -304,17 +316,27 @@ impl Element {
     ///
     /// Executes the `+=` assignment with addition operator.
     ///
+    /// Arrays cannot be concatenated in place, since the concatenation changes the array size.
+    ///
     pub fn assign_add(self, other: Self) -> Result<(Place, GeneratorExpressionOperator), Error> {
         match self {
             Self::Place(place) => {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
+                if let Value::Array(_) = value_1 {
+                    return Err(Error::Value(
+                        ValueError::OperatorAdditionFirstOperandExpectedInteger {
+                            location: place.identifier.location,
+                            found: place.r#type.to_string(),
+                        },
+                    ));
+                }
                 match other {
                     Self::Value(value_2) => value_1
-                        .add(value_2)
+                        .newtype_operator(value_2, Value::add)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .add(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::add)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -348,11 +370,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .sub(value_2)
+                        .newtype_operator(value_2, Value::sub)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .sub(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::sub)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -388,11 +410,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .mul(value_2)
+                        .newtype_operator(value_2, Value::mul)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .mul(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::mul)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -425,11 +447,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .div(value_2)
+                        .newtype_operator(value_2, Value::div)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .div(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::div)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -463,11 +485,11 @@ impl Element {
                 let value_1 = Value::try_from_place(&place).map_err(Error::Value)?;
                 match other {
                     Self::Value(value_2) => value_1
-                        .rem(value_2)
+                        .newtype_operator(value_2, Value::rem)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     Self::Constant(value_2) => value_1
-                        .rem(Value::try_from_constant(value_2).map_err(Error::Value)?)
+                        .newtype_operator(Self::runtime_operand(value_2)?, Value::rem)
                         .map(|(_value, operator)| (place, operator))
                         .map_err(Error::Value),
                     element => Err(
@@ -517,6 +539,43 @@ impl Element {
         }
     }
 
+    ///
+    /// Executes the `..` or `..=` range operator with a runtime start and a constant length.
+    ///
+    /// The `length` is the constant added to the start in the right bound, e.g. `4` in `i .. i + 4`.
+    ///
+    pub fn range_dynamic(self, length: Self, is_inclusive: bool) -> Result<Self, Error> {
+        let location = match self {
+            Element::Value(Value::Integer(ref integer)) => integer
+                .location
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            element => {
+                return Err(Error::OperatorRangeDynamicStartExpectedInteger {
+                    location: element
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    found: element.to_string(),
+                })
+            }
+        };
+
+        let length = match length {
+            Element::Constant(Constant::Integer(ref integer)) => integer
+                .value
+                .to_usize()
+                .map(|length| if is_inclusive { length + 1 } else { length }),
+            _ => None,
+        }
+        .ok_or_else(|| Error::OperatorRangeDynamicLengthExpectedConstant {
+            location: length
+                .location()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            found: length.to_string(),
+        })?;
+
+        Ok(Element::RangeDynamic(RangeDynamic::new(location, length)))
+    }
+
     ///
     /// Executes the `..` range operator.
     ///
@@ -547,441 +606,153 @@ impl Element {
     /// Executes the `||` logical OR operator.
     ///
     pub fn or(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .or(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .or(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorOrSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .or(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .or(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorOrSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorOrFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::or,
+            Constant::or,
+            |location, found| Error::OperatorOrFirstOperandExpectedEvaluable { location, found },
+            |location, found| Error::OperatorOrSecondOperandExpectedEvaluable { location, found },
+        )
     }
 
     ///
     /// Executes the `^^` logical XOR operator.
     ///
     pub fn xor(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .xor(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .xor(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorXorSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .xor(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .xor(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorXorSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorXorFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::xor,
+            Constant::xor,
+            |location, found| Error::OperatorXorFirstOperandExpectedEvaluable { location, found },
+            |location, found| Error::OperatorXorSecondOperandExpectedEvaluable { location, found },
+        )
     }
 
     ///
     /// Executes the `&&` logical AND operator.
     ///
     pub fn and(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .and(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .and(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorAndSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .and(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .and(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorAndSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorAndFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::and,
+            Constant::and,
+            |location, found| Error::OperatorAndFirstOperandExpectedEvaluable { location, found },
+            |location, found| Error::OperatorAndSecondOperandExpectedEvaluable { location, found },
+        )
     }
 
     ///
     /// Executes the `==` equals comparison operator.
     ///
     pub fn equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .equals(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .equals(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .equals(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .equals(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::equals,
+            Constant::equals,
+            |location, found| Error::OperatorEqualsFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorEqualsSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 
     ///
     /// Executes the `!=` not-equals comparison operator.
     ///
     pub fn not_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .not_equals(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .not_equals(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorNotEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .not_equals(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .not_equals(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorNotEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::not_equals,
+            Constant::not_equals,
+            |location, found| Error::OperatorNotEqualsFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorNotEqualsSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 
     ///
     /// Executes the `>=` greater-equals comparison operator.
     ///
     pub fn greater_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .greater_equals(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .greater_equals(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorGreaterEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .greater_equals(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .greater_equals(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorGreaterEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorGreaterEqualsFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::greater_equals,
+            Constant::greater_equals,
+            |location, found| Error::OperatorGreaterEqualsFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorGreaterEqualsSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 
     ///
     /// Executes the `<=` lesser-equals comparison operator.
     ///
     pub fn lesser_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .lesser_equals(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .lesser_equals(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorLesserEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .lesser_equals(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .lesser_equals(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorLesserEqualsSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorLesserEqualsFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::lesser_equals,
+            Constant::lesser_equals,
+            |location, found| Error::OperatorLesserEqualsFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorLesserEqualsSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 
     ///
     /// Executes the `>` greater comparison operator.
     ///
     pub fn greater(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .greater(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .greater(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorGreaterSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .greater(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .greater(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorGreaterSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorGreaterFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::greater,
+            Constant::greater,
+            |location, found| Error::OperatorGreaterFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorGreaterSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 
     ///
     /// Executes the `<` lesser comparison operator.
     ///
     pub fn lesser(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .lesser(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .lesser(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorLesserSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .lesser(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .lesser(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorLesserSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorLesserFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::lesser,
+            Constant::lesser,
+            |location, found| Error::OperatorLesserFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorLesserSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -989,49 +760,19 @@ impl BitOr for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitor(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .bitor(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .bitor(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorBitwiseOrSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .bitor(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .bitor(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorBitwiseOrSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorBitwiseOrFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::bitor,
+            Constant::bitor,
+            |location, found| Error::OperatorBitwiseOrFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorBitwiseOrSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1039,49 +780,19 @@ impl BitXor for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitxor(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .bitxor(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .bitxor(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorBitwiseXorSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .bitxor(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .bitxor(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorBitwiseXorSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorBitwiseXorFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::bitxor,
+            Constant::bitxor,
+            |location, found| Error::OperatorBitwiseXorFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorBitwiseXorSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1089,49 +800,19 @@ impl BitAnd for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitand(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .bitand(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .bitand(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorBitwiseAndSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .bitand(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .bitand(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorBitwiseAndSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorBitwiseAndFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::bitand,
+            Constant::bitand,
+            |location, found| Error::OperatorBitwiseAndFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorBitwiseAndSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1139,40 +820,19 @@ impl Shl<Self> for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shl(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .shl(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => Err(
-                Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .shl(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (element_1, _) => Err(
-                Error::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable {
-                    location: element_1
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_1.to_string(),
-                },
-            ),
-        }
+        self.binary_operator(
+            other,
+            Value::shl,
+            Constant::shl,
+            |location, found| Error::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1180,90 +840,52 @@ impl Shr<Self> for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shr(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .shr(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => Err(
-                Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .shr(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (element_1, _) => Err(
-                Error::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable {
-                    location: element_1
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_1.to_string(),
-                },
-            ),
-        }
+        self.binary_operator(
+            other,
+            Value::shr,
+            Constant::shr,
+            |location, found| Error::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
 impl Add for Element {
-    type Output = Result<(Self, GeneratorExpressionOperator), Error>;
+    type Output = Result<(Self, GeneratorExpressionOperator), SemanticError>;
 
+    ///
+    /// If the first operand is a structure, whose implementation defines the `add` method,
+    /// the operator is resolved to the method call.
+    ///
+    /// If the operands are arrays, they are concatenated into an array of the combined size.
+    /// The operands are already laid out sequentially on the evaluation stack, so no instruction
+    /// is written.
+    ///
     fn add(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .add(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .add(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorAdditionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .add(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .add(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorAdditionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorAdditionFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
+        if let Some(function) = self.operator_method("add")? {
+            return Self::operator_method_call(function, self, other);
         }
+
+        self.binary_operator(
+            other,
+            Value::add,
+            Constant::add,
+            |location, found| Error::OperatorAdditionFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorAdditionSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
+        .map_err(SemanticError::Element)
     }
 }
 
@@ -1271,49 +893,19 @@ impl Sub for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn sub(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .sub(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .sub(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorSubtractionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .sub(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .sub(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorSubtractionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorSubtractionFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::sub,
+            Constant::sub,
+            |location, found| Error::OperatorSubtractionFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorSubtractionSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1321,49 +913,19 @@ impl Mul for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn mul(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .mul(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .mul(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => Err(
-                Error::OperatorMultiplicationSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .mul(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .mul(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => Err(
-                Error::OperatorMultiplicationSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                },
-            ),
-            (element_1, _) => Err(Error::OperatorMultiplicationFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::mul,
+            Constant::mul,
+            |location, found| Error::OperatorMultiplicationFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorMultiplicationSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1371,49 +933,19 @@ impl Div for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn div(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .div(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .div(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorDivisionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .div(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .div(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorDivisionSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorDivisionFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::div,
+            Constant::div,
+            |location, found| Error::OperatorDivisionFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorDivisionSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1421,49 +953,19 @@ impl Rem for Element {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn rem(self, other: Self) -> Self::Output {
-        match (self, other) {
-            (Element::Value(value_1), Element::Value(value_2)) => value_1
-                .rem(value_2)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .rem(Value::try_from_constant(value_2).map_err(Error::Value)?)
-                .map(|(value, operator)| (Self::Value(value), operator))
-                .map_err(Error::Value),
-            (Element::Value(_), element_2) => {
-                Err(Error::OperatorRemainderSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (Element::Constant(value_1), Element::Value(value_2)) => {
-                Value::try_from_constant(value_1)
-                    .map_err(Error::Value)?
-                    .rem(value_2)
-                    .map(|(value, operator)| (Self::Value(value), operator))
-                    .map_err(Error::Value)
-            }
-            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
-                .rem(value_2)
-                .map(|(constant, operator)| (Self::Constant(constant), operator))
-                .map_err(Error::Constant),
-            (Element::Constant(_), element_2) => {
-                Err(Error::OperatorRemainderSecondOperandExpectedEvaluable {
-                    location: element_2
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element_2.to_string(),
-                })
-            }
-            (element_1, _) => Err(Error::OperatorRemainderFirstOperandExpectedEvaluable {
-                location: element_1
-                    .location()
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                found: element_1.to_string(),
-            }),
-        }
+        self.binary_operator(
+            other,
+            Value::rem,
+            Constant::rem,
+            |location, found| Error::OperatorRemainderFirstOperandExpectedEvaluable {
+                location,
+                found,
+            },
+            |location, found| Error::OperatorRemainderSecondOperandExpectedEvaluable {
+                location,
+                found,
+            },
+        )
     }
 }
 
@@ -1489,6 +991,10 @@ impl Element {
                 .cast(r#type)
                 .map(|(value, operator)| (Self::Value(value), operator))
                 .map_err(Error::Value),
+            Element::Constant(constant) if r#type.is_fixed() => Value::try_from_constant(constant)
+                .and_then(|value| value.cast(r#type))
+                .map(|(value, operator)| (Self::Value(value), operator))
+                .map_err(Error::Value),
             Element::Constant(constant) => constant
                 .cast(r#type)
                 .map(|(constant, operator)| (Self::Constant(constant), operator))
@@ -1585,6 +1091,10 @@ impl Element {
                     .index(element)
                     .map(|(place, access)| (Element::Place(place), access))
                     .map_err(Error::Place),
+                element @ Self::RangeDynamic(_) => place
+                    .index(element)
+                    .map(|(place, access)| (Element::Place(place), access))
+                    .map_err(Error::Place),
                 element => Err(Error::OperatorIndexSecondOperandExpectedEvaluable {
                     location: element
                         .location()
@@ -1601,6 +1111,10 @@ impl Element {
                     .index_constant(index)
                     .map(|(value, access)| (Element::Value(value), access))
                     .map_err(Error::Value),
+                Self::RangeDynamic(range) => value
+                    .index_range_dynamic(range)
+                    .map(|(value, access)| (Element::Value(value), access))
+                    .map_err(Error::Value),
                 element => Err(Error::OperatorIndexSecondOperandExpectedEvaluable {
                     location: element
                         .location()
@@ -1630,7 +1144,14 @@ impl Element {
     ///
     /// Executes the `.` dot field access operator.
     ///
-    pub fn dot(self, other: Self) -> Result<(Self, DotAccessVariant), SemanticError> {
+    /// The `scope` is the one the method is accessed from, which is checked for having access
+    /// to the non-public implementation methods.
+    ///
+    pub fn dot(
+        self,
+        other: Self,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<(Self, DotAccessVariant), SemanticError> {
         match self {
             Self::Place(place) => match other {
                 Self::TupleIndex(index) => place
@@ -1639,7 +1160,7 @@ impl Element {
                     .map_err(Error::Place)
                     .map_err(SemanticError::Element),
                 Self::Identifier(identifier) => {
-                    let scope = match place.r#type {
+                    let namespace = match place.r#type {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
@@ -1652,10 +1173,11 @@ impl Element {
                         }
                     };
 
-                    let item = scope.borrow().resolve_item(&identifier, false);
+                    let item = namespace.borrow().resolve_item(&identifier, false);
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                Scope::check_item_access(scope, &item.borrow(), &identifier)?;
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1689,13 +1211,11 @@ impl Element {
             Self::Value(value) => match other {
                 Self::TupleIndex(index) => value
                     .tuple_field(index)
-                    .map(|(value, access)| {
-                        (Element::Value(value), DotAccessVariant::StackField(access))
-                    })
+                    .map(|(value, access)| (Element::Value(value), access))
                     .map_err(Error::Value)
                     .map_err(SemanticError::Element),
                 Self::Identifier(identifier) => {
-                    let scope = match value.r#type() {
+                    let namespace = match value.r#type() {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
@@ -1708,10 +1228,11 @@ impl Element {
                         }
                     };
 
-                    let item = scope.borrow().resolve_item(&identifier, false);
+                    let item = namespace.borrow().resolve_item(&identifier, false);
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                Scope::check_item_access(scope, &item.borrow(), &identifier)?;
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1754,7 +1275,20 @@ impl Element {
                     .map_err(Error::Constant)
                     .map_err(SemanticError::Element),
                 Self::Identifier(identifier) => {
-                    let scope = match constant.r#type() {
+                    if let Constant::String(_) = constant {
+                        if identifier.name.as_str() == StringLenFunction::IDENTIFIER {
+                            return Ok((
+                                Element::Type(Type::Function(FunctionType::Intrinsic(
+                                    IntrinsicFunction::new_string_len(),
+                                ))),
+                                DotAccessVariant::Method {
+                                    instance: Box::new(Self::Constant(constant)),
+                                },
+                            ));
+                        }
+                    }
+
+                    let namespace = match constant.r#type() {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
@@ -1772,10 +1306,11 @@ impl Element {
                         }
                     };
 
-                    let item = scope.borrow().resolve_item(&identifier, false);
+                    let item = namespace.borrow().resolve_item(&identifier, false);
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                Scope::check_item_access(scope, &item.borrow(), &identifier)?;
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1893,6 +1428,15 @@ impl Element {
             },
             Element::Type(Type::Contract(r#type)) => match other {
                 Element::Value(Value::Structure(structure)) => {
+                    if let Some((location, _)) = structure.base {
+                        return Err(Error::Value(ValueError::Structure(
+                            StructureValueError::BaseForbiddenInContract {
+                                location,
+                                type_identifier: r#type.identifier,
+                            },
+                        )));
+                    }
+
                     let mut contract = structure.into_contract(scope);
                     contract
                         .validate(r#type)
@@ -1936,11 +1480,156 @@ impl Element {
             Self::ArgumentList(inner) => Some(inner.location),
             Self::Path(inner) => Some(inner.location),
             Self::Place(inner) => Some(inner.identifier.location),
+            Self::RangeDynamic(inner) => Some(inner.location),
             Self::TupleIndex(inner) => Some(inner.location),
             Self::Identifier(inner) => Some(inner.location),
             Self::Module(inner) => Some(inner.location),
         }
     }
+
+    ///
+    /// Converts the `constant` operand of a runtime expression to a value.
+    ///
+    /// The strings only exist at compile time, so they cannot be mixed with runtime values.
+    ///
+    fn runtime_operand(constant: Constant) -> Result<Value, Error> {
+        match constant {
+            constant @ Constant::String(_) => {
+                Err(Error::Constant(ConstantError::StringRuntimeOperand {
+                    location: constant.location(),
+                    found: constant.to_string(),
+                }))
+            }
+            constant => Value::try_from_constant(constant).map_err(Error::Value),
+        }
+    }
+
+    ///
+    /// Executes a binary operator, which accepts evaluable operands, that is, values and constants.
+    ///
+    /// If either of the operands is a runtime value, the other one is converted to a value as well.
+    /// The error constructors are called with the location and description of the invalid operand.
+    ///
+    fn binary_operator<VF, CF, FE, SE>(
+        self,
+        other: Self,
+        value_operator: VF,
+        constant_operator: CF,
+        first_operand_error: FE,
+        second_operand_error: SE,
+    ) -> Result<(Self, GeneratorExpressionOperator), Error>
+    where
+        VF: FnOnce(Value, Value) -> Result<(Value, GeneratorExpressionOperator), ValueError>,
+        CF: FnOnce(
+            Constant,
+            Constant,
+        ) -> Result<(Constant, GeneratorExpressionOperator), ConstantError>,
+        FE: FnOnce(Location, String) -> Error,
+        SE: FnOnce(Location, String) -> Error,
+    {
+        match (self, other) {
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .newtype_operator(value_2, value_operator)
+                .map(|(value, operator)| (Self::Value(value), operator))
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .newtype_operator(Self::runtime_operand(value_2)?, value_operator)
+                .map(|(value, operator)| (Self::Value(value), operator))
+                .map_err(Error::Value),
+            (Element::Constant(value_1), Element::Value(value_2)) => {
+                Self::runtime_operand(value_1)?
+                    .newtype_operator(value_2, value_operator)
+                    .map(|(value, operator)| (Self::Value(value), operator))
+                    .map_err(Error::Value)
+            }
+            (Element::Constant(value_1), Element::Constant(value_2)) => {
+                constant_operator(value_1, value_2)
+                    .map(|(constant, operator)| (Self::Constant(constant), operator))
+                    .map_err(Error::Constant)
+            }
+            (Element::Value(_), element_2) | (Element::Constant(_), element_2) => {
+                Err(second_operand_error(
+                    element_2
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    element_2.to_string(),
+                ))
+            }
+            (element_1, _) => Err(first_operand_error(
+                element_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                element_1.to_string(),
+            )),
+        }
+    }
+
+    ///
+    /// Resolves the method `name` in the implementation of the operand structure type.
+    ///
+    /// Returns `None` if the element is not a structure or the method is not implemented.
+    ///
+    fn operator_method(&self, name: &str) -> Result<Option<RuntimeFunction>, SemanticError> {
+        let scope = match self {
+            Element::Value(Value::Structure(structure)) => match structure.r#type {
+                Some(ref r#type) => r#type.scope.to_owned(),
+                None => return Ok(None),
+            },
+            Element::Constant(Constant::Structure(structure)) => match structure.r#type {
+                Some(ref r#type) => r#type.scope.to_owned(),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        let location = self
+            .location()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let identifier = Identifier::new(location, name.to_owned());
+
+        let item = match scope.borrow().resolve_item(&identifier, false) {
+            Ok(item) => item,
+            Err(_) => return Ok(None),
+        };
+        let r#type = match *item.borrow() {
+            ScopeItem::Type(ref r#type) => r#type.define()?,
+            _ => return Ok(None),
+        };
+
+        match r#type {
+            Type::Function(FunctionType::Runtime(function)) => Ok(Some(function)),
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Checks the operands against the operator method signature and converts the operator
+    /// into the method call.
+    ///
+    fn operator_method_call(
+        function: RuntimeFunction,
+        operand_1: Self,
+        operand_2: Self,
+    ) -> Result<(Self, GeneratorExpressionOperator), SemanticError> {
+        let location = operand_1
+            .location()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let type_id = function.type_id;
+        let input_size = function.input_size();
+
+        let return_type = function
+            .call(ArgumentList::new(location, vec![operand_1, operand_2]))
+            .map_err(|error| SemanticError::Element(Error::Type(TypeError::Function(error))))?;
+
+        let value = Value::try_from_type(&return_type, false, None)
+            .map_err(Error::Value)
+            .map_err(SemanticError::Element)?;
+
+        Ok((
+            Self::Value(value),
+            GeneratorExpressionOperator::call(type_id, input_size),
+        ))
+    }
 }
 
 impl fmt::Display for Element {
@@ -1952,6 +1641,7 @@ impl fmt::Display for Element {
             Self::ArgumentList(inner) => write!(f, "argument list {}", inner),
             Self::Path(inner) => write!(f, "path {}", inner),
             Self::Place(inner) => write!(f, "place {}", inner),
+            Self::RangeDynamic(inner) => write!(f, "range {}", inner),
             Self::TupleIndex(inner) => write!(f, "tuple field {}", inner),
             Self::Identifier(inner) => write!(f, "structure field {}", inner.name),
             Self::Module(inner) => write!(f, "module {}", inner.name),
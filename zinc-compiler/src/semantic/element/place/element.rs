@@ -49,6 +49,15 @@ pub enum Element {
         /// The array access data, which helps to generate the target code without redundant calculations.
         access: IndexAccess,
     },
+    /// Array slicing with a runtime start and constant length, which is the second operand of the `[]` index operator.
+    IndexRangeDynamic {
+        /// The start expression intermediate representation, which must be translated to the target code separately.
+        expression: GeneratorExpression,
+        /// The length of the array being sliced, which is used to constrain the runtime start.
+        array_length: usize,
+        /// The array access data, which helps to generate the target code without redundant calculations.
+        access: IndexAccess,
+    },
     /// The tuple or structure field access via the `.` operator.
     StackField {
         /// The data stack access data, which helps to generate the target code without redundant calculations.
@@ -68,6 +77,9 @@ impl fmt::Display for Element {
             Self::IndexConstant { constant, .. } => write!(f, "[{}]", constant.value),
             Self::IndexRange { start, end, .. } => write!(f, "[{} .. {}]", start, end),
             Self::IndexRangeInclusive { start, end, .. } => write!(f, "[{} ..= {}]", start, end),
+            Self::IndexRangeDynamic { access, .. } => {
+                write!(f, "[<runtime> .. <runtime> + {}]", access.slice_length)
+            }
             Self::StackField { access } => write!(f, ".{}", access.name),
             Self::ContractField { access } => write!(f, ".{}", access.name),
         }
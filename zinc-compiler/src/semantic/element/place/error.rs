@@ -18,13 +18,15 @@ pub enum Error {
         /// The invalid actual type.
         found: String,
     },
-    /// Tried to change an immutable variable.
+    /// Tried to change an immutable variable or its element.
     MutatingImmutableMemory {
         /// The memory descriptor location, usually a variable name.
         location: Location,
-        /// The name of the immutable variable.
+        /// The name of the immutable root variable.
         name: String,
-        /// The location of the immutable variable. `None` for intrinsic items.
+        /// The stringified mutated place, including the array indexes and field accesses.
+        place: String,
+        /// The location of the immutable root variable. `None` for intrinsic items.
         reference: Option<Location>,
     },
     /// Tried to change an immutable contract storage field.
@@ -34,6 +36,15 @@ pub enum Error {
         /// The name of the immutable contract storage field.
         name: String,
     },
+    /// Tried to change the contract storage in an immutable contract method.
+    MutatingStorageFromQuery {
+        /// The mutating expression location.
+        location: Location,
+        /// The stringified mutated place, including the array indexes and field accesses.
+        place: String,
+        /// The identifier of the immutable contract method, where the assignment is made.
+        method: String,
+    },
 
     /// The `[]` index operator expects an array value as the first operand.
     OperatorIndexFirstOperandExpectedArray {
@@ -65,6 +76,15 @@ pub enum Error {
         found: String,
     },
 
+    /// The constant index of a contract storage map array is out of the compile time-known range.
+    ArrayIndexOutOfRange {
+        /// The memory descriptor location, usually a variable name.
+        location: Location,
+        /// The invalid array index, which is actually found.
+        index: String,
+        /// The actual array size, which is violated by `index`.
+        size: usize,
+    },
     /// The slice left bound is negative.
     ArraySliceStartOutOfRange {
         /// The memory descriptor location, usually a variable name.
@@ -79,9 +79,10 @@ impl Place {
     /// Validates the array index or slice operator and changes the internal state.
     ///
     pub fn index(mut self, index_value: Element) -> Result<(Self, IndexAccess), Error> {
-        let (inner_type, array_size) = match self.r#type {
+        let (inner_type, array_length, array_size) = match self.r#type {
             Type::Array(ref array) => (
                 array.r#type.deref().to_owned(),
+                array.size,
                 array.r#type.size() * array.size,
             ),
             ref r#type => {
@@ -101,7 +102,17 @@ impl Place {
 
                 Ok((self, access))
             }
-            Element::Constant(Constant::Integer(_integer)) => {
+            Element::Constant(Constant::Integer(integer)) => {
+                if inner_type.is_mtreemap()
+                    && (integer.value.is_negative() || integer.value >= BigInt::from(array_length))
+                {
+                    return Err(Error::ArrayIndexOutOfRange {
+                        location: integer.location,
+                        index: integer.value.to_string(),
+                        size: array_length,
+                    });
+                }
+
                 let access = IndexAccess::new(inner_type_size, 1, array_size, None);
 
                 self.r#type = inner_type;
@@ -220,6 +231,21 @@ impl Place {
 
                 Ok((self, access))
             }
+            Element::RangeDynamic(range) => {
+                if range.length > array_length {
+                    return Err(Error::ArraySliceEndOutOfRange {
+                        location: range.location,
+                        end: format!("<runtime> + {}", range.length),
+                        size: array_length,
+                    });
+                }
+
+                let access = IndexAccess::new(inner_type_size, range.length, array_size, None);
+
+                self.r#type = Type::array(Some(self.identifier.location), inner_type, range.length);
+
+                Ok((self, access))
+            }
             value => Err(Error::OperatorIndexSecondOperandExpectedIntegerOrRange {
                 location: value
                     .location()
@@ -232,12 +258,21 @@ impl Place {
     ///
     /// Validates the tuple field access operator and changes the internal state.
     ///
+    /// The newtype `.0` access only changes the place type, since the newtype has the same
+    /// representation as its inner value.
+    ///
     pub fn tuple_field(mut self, index: TupleIndex) -> Result<(Self, DotAccessVariant), Error> {
         let TupleIndex {
             location,
             value: index,
         } = index;
 
+        if matches!(self.r#type, Type::Structure(ref structure) if structure.is_newtype) {
+            return self
+                .structure_field(Identifier::new(location, index.to_string()))
+                .map(|(place, _access)| (place, DotAccessVariant::Newtype));
+        }
+
         let mut offset = 0;
         let total_size = self.r#type.size();
         match self.r#type {
@@ -327,7 +362,8 @@ impl Place {
                             element_size,
                             total_size,
                             field.is_immutable,
-                            field.r#type.is_mtreemap(),
+                            field.r#type.is_mtreemap() || field.r#type.is_mtreemap_array(),
+                            field.r#type.storage_leaves_count(),
                         ));
 
                         self.r#type = field.r#type.to_owned();
@@ -374,6 +410,23 @@ impl Place {
         }
         None
     }
+
+    ///
+    /// Whether the place is a structure projected from a contract storage field, e.g. an array
+    /// element, which can be written back to the storage after being mutated by a method.
+    ///
+    pub fn is_storage_projection(&self) -> bool {
+        if !matches!(self.memory_type, MemoryType::ContractStorage)
+            || !matches!(self.r#type, Type::Structure(_))
+        {
+            return false;
+        }
+
+        matches!(
+            self.elements.first(),
+            Some(PlaceElement::ContractField { access }) if !access.is_mtreemap
+        )
+    }
 }
 
 impl fmt::Display for Place {
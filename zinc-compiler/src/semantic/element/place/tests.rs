@@ -50,6 +50,30 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_slicing_runtime_start() {
+    let input = r#"
+fn main(offset: u8) -> [u8; 3] {
+    let array = [1, 2, 3, 4, 5];
+    array[offset .. offset + 3]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_slicing_runtime_start_inclusive() {
+    let input = r#"
+fn main(offset: u8) -> [u8; 3] {
+    let array = [1, 2, 3, 4, 5];
+    array[offset ..= offset + 2]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn ok_mutating_tuple() {
     let input = r#"
@@ -74,6 +98,36 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_mutating_tuple_element_chained() {
+    let input = r#"
+fn main() -> u8 {
+    let mut result = ((1, 2), 3);
+    result.0.1 = 42;
+    result.0.1
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_mutating_tuple_structure_chained() {
+    let input = r#"
+struct Data {
+    pair: (u8, (u8, u8)),
+}
+
+fn main() -> u8 {
+    let mut result = (Data { pair: (1, (2, 3)) }, 4);
+    result.0.pair.1.0 = 42;
+    result.0.pair.1.0
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn ok_mutating_structure() {
     let input = r#"
@@ -164,6 +218,42 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_mutating_array_element_field() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main(index: u8) {
+    let mut result = [Data { a: 1 }, Data { a: 2 }];
+
+    result[0].a = 42;
+    result[index].a = 64;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_mutating_nested_projections() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main(mut argument: (Data, [Data; 2])) {
+    let mut result = [[Data { a: 1 }; 2]; 2];
+
+    result[0][1].a = 42;
+    argument.1[0].a = 64;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_mutating_immutable_memory() {
     let input = r#"
@@ -177,6 +267,7 @@ fn main() {
         ElementError::Place(PlaceError::MutatingImmutableMemory {
             location: Location::test(4, 5),
             name: "result".to_string(),
+            place: "result".to_string(),
             reference: Some(Location::test(3, 9)),
         }),
     )));
@@ -186,6 +277,82 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_mutating_immutable_memory_array_element_field() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main() {
+    let result = [Data { a: 1 }, Data { a: 2 }];
+    result[1].a = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::MutatingImmutableMemory {
+            location: Location::test(8, 5),
+            name: "result".to_string(),
+            place: "result[1].a".to_string(),
+            reference: Some(Location::test(7, 9)),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_immutable_memory_nested_projections() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main(argument: (Data, [Data; 2])) {
+    argument.1[0].a = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::MutatingImmutableMemory {
+            location: Location::test(7, 5),
+            name: "argument".to_string(),
+            place: "argument.1[0].a".to_string(),
+            reference: Some(Location::test(6, 9)),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_immutable_memory_message() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main() {
+    let result = [Data { a: 1 }, Data { a: 2 }];
+    result[1].a = 42;
+}
+"#;
+
+    let message = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(
+        message.contains("cannot assign to `result[1].a`, as the variable `result` is immutable")
+    );
+    assert!(message.contains("make this variable mutable: `mut result`"));
+}
+
 #[test]
 fn error_mutating_immutable_contract_field_address() {
     let input = r#"
@@ -208,6 +375,57 @@ contract Test {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_mutating_storage_from_query() {
+    let input = r#"
+contract Test {
+    value: u8;
+
+    pub fn query(self) {
+        self.value = 42;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::MutatingStorageFromQuery {
+            location: Location::test(6, 9),
+            place: "self.value".to_owned(),
+            method: "query".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_storage_from_query_copied_instance() {
+    let input = r#"
+contract Test {
+    values: [u8; 4];
+
+    pub fn query(self) {
+        let mut instance = self;
+        instance.values[1] = 42;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::MutatingStorageFromQuery {
+            location: Location::test(7, 9),
+            place: "instance.values[1]".to_owned(),
+            method: "query".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_mutating_with_different_type() {
     let input = r#"
@@ -381,6 +599,29 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_array_slice_end_out_of_range_dynamic() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4, 5];
+    let offset = 1;
+    let slice = array[offset .. offset + 6];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::ArraySliceEndOutOfRange {
+            location: Location::test(5, 23),
+            end: "<runtime> + 6".to_owned(),
+            size: 5,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_slice_end_lesser_than_start() {
     let input = r#"
@@ -429,6 +670,32 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_tuple_field_out_of_range_chained() {
+    let input = r#"
+fn main() {
+    let tuple = ((1, 2), 3);
+    let result = tuple.0.5;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::TupleFieldOutOfRange {
+            location: Location::test(4, 26),
+            type_identifier: Type::tuple(
+                Some(Location::test(4, 26)),
+                vec![Type::integer_unsigned(None, zinc_const::bitlength::BYTE); 2],
+            )
+            .to_string(),
+            field_index: 5,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_structure_field_does_not_exist() {
     let input = r#"
@@ -481,3 +748,48 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_contract_map_array_index() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    pools: [MTreeMap<u8, field>; 4];
+
+    pub fn default(mut self, index: u8, key: u8, value: field) -> Maybe<field> {
+        self.pools[index].insert(key, value);
+        self.pools[3].get(key)
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_contract_map_array_index_out_of_range() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    pools: [MTreeMap<u8, field>; 4];
+
+    pub fn default(self, key: u8) -> Maybe<field> {
+        self.pools[4].get(key)
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Place(PlaceError::ArrayIndexOutOfRange {
+            location: Location::test(8, 20),
+            index: "4".to_owned(),
+            size: 4,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
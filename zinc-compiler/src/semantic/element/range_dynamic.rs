@@ -0,0 +1,36 @@
+//!
+//! The semantic analyzer dynamic range element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+///
+/// A range with a runtime start and a constant length, e.g. `i .. i + 4`.
+///
+/// Can only be used as the second operand of the `[]` index operator, producing an array slice
+/// of the constant length.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeDynamic {
+    /// The location in the code.
+    pub location: Location,
+    /// The constant range length.
+    pub length: usize,
+}
+
+impl RangeDynamic {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, length: usize) -> Self {
+        Self { location, length }
+    }
+}
+
+impl fmt::Display for RangeDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<runtime> .. <runtime> + {}", self.length)
+    }
+}
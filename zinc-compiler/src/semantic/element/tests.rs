@@ -8,6 +8,8 @@ use crate::error::Error;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
@@ -691,6 +693,63 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_range_dynamic_start_expected_integer() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4, 5];
+    let b = true;
+    let slice = array[b .. b + 2];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::OperatorRangeDynamicStartExpectedInteger {
+            location: Location::test(5, 23),
+            found: Element::Value(
+                Value::try_from_type(&Type::boolean(None), false, None)
+                    .expect(zinc_const::panic::TEST_DATA_VALID),
+            )
+            .to_string(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_range_dynamic_length_expected_constant() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4, 5];
+    let offset = 1;
+    let length = 2;
+    let slice = array[offset .. offset + length];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::OperatorRangeDynamicLengthExpectedConstant {
+            location: Location::test(6, 42),
+            found: Element::Value(
+                Value::try_from_type(
+                    &Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    false,
+                    None,
+                )
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            )
+            .to_string(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_or_1st_operand_expected_evaluable() {
     let input = r#"
@@ -1267,26 +1326,20 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_left_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_left_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 << offset;
+    let value = 42 << X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-            location: Location::test(4, 23),
-            found: Element::Value(
-                Value::try_from_type(
-                    &Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
-                    false,
-                    None,
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            )
-            .to_string(),
+        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+            location: Location::test(5, 23),
+            found: Element::Type(Type::integer_unsigned(None, zinc_const::bitlength::BYTE))
+                .to_string(),
         },
     )));
 
@@ -1319,26 +1372,20 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_right_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_right_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 >> offset;
+    let value = 42 >> X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-            location: Location::test(4, 23),
-            found: Element::Value(
-                Value::try_from_type(
-                    &Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
-                    false,
-                    None,
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            )
-            .to_string(),
+        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+            location: Location::test(5, 23),
+            found: Element::Type(Type::integer_unsigned(None, zinc_const::bitlength::BYTE))
+                .to_string(),
         },
     )));
 
@@ -1393,6 +1440,41 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_addition_method_argument_type() {
+    let input = r#"
+struct Point {
+    x: u8,
+}
+
+impl Point {
+    pub fn add(self, other: u8) -> Self {
+        Self { x: self.x + other }
+    }
+}
+
+fn main() {
+    let a = Point { x: 1 };
+    let b = a + false;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(14, 17),
+            function: "add".to_owned(),
+            name: "other".to_owned(),
+            position: 2,
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_subtraction_1st_operand_expected_evaluable() {
     let input = r#"
@@ -5,10 +5,17 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::FieldStatement;
 use zinc_syntax::Identifier;
 
+use crate::semantic::analyzer::expression::error::Error as ExpressionError;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 
@@ -27,6 +34,8 @@ pub struct Field {
     pub is_implicit: bool,
     /// Whether the field is immutable.
     pub is_immutable: bool,
+    /// The default value, which is assigned if the field is omitted in the constructor.
+    pub default: Option<Constant>,
 }
 
 impl Field {
@@ -46,12 +55,17 @@ impl Field {
             is_public,
             is_implicit,
             is_immutable,
+            default: None,
         }
     }
 
     ///
     /// A shortcut constructor.
     ///
+    /// The default value is not evaluated here, since it may reference the contract constants
+    /// via `Self::`, which is only available after the contract type is created.
+    /// See `try_default_from_syntax`.
+    ///
     pub fn try_from_syntax(
         statement: FieldStatement,
         scope: Rc<RefCell<Scope>>,
@@ -64,6 +78,33 @@ impl Field {
             is_public: statement.is_public,
             is_implicit: false,
             is_immutable: false,
+            default: None,
         })
     }
+
+    ///
+    /// Evaluates the default value `expression` as a constant of the field type.
+    ///
+    pub fn try_default_from_syntax(
+        &self,
+        expression: ExpressionTree,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Constant, Error> {
+        let expression_location = expression.location;
+
+        let (element, _intermediate) =
+            ExpressionAnalyzer::new(scope, TranslationRule::Constant).analyze(expression)?;
+
+        match element {
+            Element::Constant(constant) => constant
+                .cast(self.r#type.clone())
+                .map(|(constant, _intermediate)| constant)
+                .map_err(ElementError::Constant)
+                .map_err(Error::Element),
+            element => Err(Error::Expression(ExpressionError::NonConstantElement {
+                location: expression_location,
+                found: element.to_string(),
+            })),
+        }
+    }
 }
@@ -12,12 +12,16 @@ use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
 
+use zinc_build::LibraryFunctionIdentifier;
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
@@ -81,29 +85,80 @@ impl Contract {
             true,
             true,
         )?;
+        Scope::define_field(
+            scope.clone(),
+            Identifier::new(
+                location,
+                zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            ),
+            Type::integer_unsigned(None, zinc_const::bitlength::LAYOUT_HASH),
+            zinc_const::contract::FIELD_INDEX_LAYOUT_HASH,
+            true,
+            true,
+            true,
+        )?;
+
+        let balance = FunctionType::new_library(LibraryFunctionIdentifier::ZksyncBalance);
+        let balance_identifier = Identifier::new(location, balance.identifier());
+        if let Ok(item) = scope
+            .borrow()
+            .resolve_declared_item(&balance_identifier, false)
+        {
+            return Err(Error::Scope(ScopeError::ItemRedeclared {
+                location: item.borrow().location().unwrap_or(location),
+                name: balance_identifier.name,
+                reference: None,
+            }));
+        }
+        Scope::insert_item(
+            scope.clone(),
+            balance_identifier.name,
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(balance), false)).wrap(),
+        );
 
         let contract = Self {
             location,
             identifier,
             type_id,
             fields,
-            scope: scope.clone(),
+            scope,
         };
+        contract.insert_self_alias();
 
+        Ok(contract)
+    }
+
+    ///
+    /// Sets the storage field default values, which are pairs of the field index and value.
+    ///
+    /// The defaults are evaluated after the contract type is created, since they may reference
+    /// the contract constants via `Self::`. The `Self` alias holds a copy of the type, so it is
+    /// updated as well.
+    ///
+    pub fn set_defaults(&mut self, defaults: Vec<(usize, Constant)>) {
+        for (index, default) in defaults.into_iter() {
+            self.fields[index].default = Some(default);
+        }
+
+        self.insert_self_alias();
+    }
+
+    ///
+    /// Inserts the `Self` alias of the contract type into the contract scope.
+    ///
+    fn insert_self_alias(&self) {
         Scope::insert_item(
-            scope,
+            self.scope.clone(),
             Keyword::SelfUppercase.to_string(),
             ScopeItem::Type(ScopeTypeItem::new_defined(
-                Some(location),
-                Type::Contract(contract.clone()),
+                Some(self.location),
+                Type::Contract(self.clone()),
                 true,
                 false,
                 None,
             ))
             .wrap(),
         );
-
-        Ok(contract)
     }
 }
 
@@ -29,3 +29,26 @@ contract Contract {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_balance_method_redeclared() {
+    let input = r#"
+contract Contract {
+    pub fn balance(self) -> u8 {
+        42
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemRedeclared {
+            location: Location::test(3, 5),
+            name: "balance".to_owned(),
+            reference: None,
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -22,4 +22,105 @@ pub enum Error {
         /// The duplicate variant value.
         variant_value: BigInt,
     },
+    /// A structure-like variant payload has two fields with the same name.
+    DuplicatePayloadField {
+        /// The duplicate field location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The duplicate field name.
+        field_name: String,
+    },
+    /// A variant payload contains a type, which cannot be stored in an enumeration value.
+    PayloadForbiddenType {
+        /// The invalid field location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The stringified forbidden type.
+        found: String,
+    },
+    /// A variant with a payload is used without one, e.g. `Action::Burn` instead of `Action::Burn(amount)`.
+    PayloadExpected {
+        /// The variant location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+    },
+    /// A variant without a payload is constructed with one.
+    PayloadUnexpected {
+        /// The variant location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+    },
+    /// A tuple-like variant is constructed with the structure syntax, or vice versa.
+    PayloadInvalidKind {
+        /// The variant location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// Whether the variant payload is structure-like.
+        is_structure: bool,
+    },
+    /// A tuple-like variant is constructed with an invalid number of payload elements.
+    PayloadInvalidLength {
+        /// The payload location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The expected number of elements.
+        expected: usize,
+        /// The actual number of elements.
+        found: usize,
+    },
+    /// A payload field is initialized with a value of invalid type.
+    PayloadFieldInvalidType {
+        /// The invalid value location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The field name, which is the element index for tuple-like variants.
+        field_name: String,
+        /// The stringified expected type.
+        expected: String,
+        /// The stringified actual type.
+        found: String,
+    },
+    /// A structure-like variant is constructed with a field, which the payload does not have.
+    PayloadFieldDoesNotExist {
+        /// The invalid field location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The invalid field name.
+        field_name: String,
+    },
+    /// A structure-like variant is constructed without some of its payload fields.
+    PayloadFieldMissing {
+        /// The payload location.
+        location: Location,
+        /// The enumeration type name.
+        type_identifier: String,
+        /// The variant name.
+        variant_name: String,
+        /// The missing field name.
+        field_name: String,
+    },
 }
@@ -6,6 +6,7 @@
 mod tests;
 
 pub mod error;
+pub mod payload;
 
 use std::cell::RefCell;
 use std::convert::TryFrom;
@@ -16,16 +17,19 @@ use num::BigInt;
 
 use zinc_lexical::Location;
 use zinc_syntax::Variant;
+use zinc_syntax::VariantPayload;
 
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
 
 use self::error::Error;
+use self::payload::Payload;
 
 ///
 /// Describes an enumeration type.
@@ -34,6 +38,9 @@ use self::error::Error;
 /// and the implementation `scope`, which contains the enumeration variants and
 /// reference to its parent scope.
 ///
+/// If some variants have payloads, the enumeration value consists of the variant discriminant
+/// followed by the payload, which is zero-padded to the size of the largest one.
+///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     /// The enumeration type location in the code.
@@ -48,6 +55,8 @@ pub struct Enumeration {
     pub names: Vec<String>,
     /// The ordered list of the variant values.
     pub values: Vec<BigInt>,
+    /// The variant payloads in the declaration order. The variants without payloads are omitted.
+    pub payloads: Vec<(String, Payload)>,
     /// The ordered list of the enumeration generic type arguments.
     pub generics: Vec<String>,
     /// The enumeration scope, where its methods and associated items are declared.
@@ -69,11 +78,30 @@ impl Enumeration {
         let scope = scope.unwrap_or_else(|| Scope::new(identifier.clone(), None).wrap());
 
         let mut variants_bigint = Vec::with_capacity(variants.len());
-        for variant in variants.iter() {
-            let value = IntegerConstant::try_from(&variant.literal).map_err(|error| {
-                SemanticError::Element(ElementError::Constant(ConstantError::Integer(error)))
-            })?;
-            variants_bigint.push((variant.identifier.to_owned(), value.value.to_owned()));
+        let mut payloads = Vec::new();
+        for (index, variant) in variants.iter().enumerate() {
+            let value = match variant.literal {
+                Some(ref literal) => {
+                    let constant = IntegerConstant::try_from(literal).map_err(|error| {
+                        SemanticError::Element(ElementError::Constant(ConstantError::Integer(
+                            error,
+                        )))
+                    })?;
+                    constant.value
+                }
+                None => BigInt::from(index),
+            };
+            variants_bigint.push((variant.identifier.to_owned(), value));
+
+            if let Some(ref payload) = variant.payload {
+                let payload = Self::resolve_payload(
+                    identifier.as_str(),
+                    variant,
+                    payload.to_owned(),
+                    scope.clone(),
+                )?;
+                payloads.push((variant.identifier.name.to_owned(), payload));
+            }
         }
         let names: Vec<String> = variants_bigint
             .iter()
@@ -117,6 +145,7 @@ impl Enumeration {
             bitlength: minimal_bitlength,
             names,
             values: bigints,
+            payloads,
             generics,
             scope: scope.clone(),
         };
@@ -136,6 +165,111 @@ impl Enumeration {
 
         Ok(enumeration)
     }
+
+    ///
+    /// Returns the payload of the variant `name`, if the variant has one.
+    ///
+    pub fn payload(&self, name: &str) -> Option<&Payload> {
+        self.payloads
+            .iter()
+            .find(|(variant_name, _payload)| variant_name == name)
+            .map(|(_variant_name, payload)| payload)
+    }
+
+    ///
+    /// Whether the enumeration has variants with payloads, so its values are not scalar.
+    ///
+    pub fn has_payloads(&self) -> bool {
+        !self.payloads.is_empty()
+    }
+
+    ///
+    /// The size of the largest variant payload.
+    ///
+    pub fn payload_size(&self) -> usize {
+        self.payloads
+            .iter()
+            .map(|(_name, payload)| payload.size())
+            .max()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// The enumeration value size in the Zinc VM data stack, that is, the discriminant and
+    /// the largest payload.
+    ///
+    pub fn size(&self) -> usize {
+        1 + self.payload_size()
+    }
+
+    ///
+    /// Resolves the `payload` types of the `variant` in the enumeration `scope`.
+    ///
+    fn resolve_payload(
+        type_identifier: &str,
+        variant: &Variant,
+        payload: VariantPayload,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Payload, SemanticError> {
+        let (is_structure, syntax_fields) = match payload {
+            VariantPayload::Tuple(types) => (
+                false,
+                types
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, r#type)| (index.to_string(), r#type.location, r#type))
+                    .collect::<Vec<_>>(),
+            ),
+            VariantPayload::Structure(fields) => (
+                true,
+                fields
+                    .into_iter()
+                    .map(|field| (field.identifier.name, field.location, field.r#type))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
+        let mut fields: Vec<(String, Type)> = Vec::with_capacity(syntax_fields.len());
+        for (name, location, r#type) in syntax_fields.into_iter() {
+            if fields.iter().any(|(field_name, _type)| field_name == &name) {
+                return Err(SemanticError::Element(ElementError::Type(
+                    TypeError::Enumeration(Error::DuplicatePayloadField {
+                        location,
+                        type_identifier: type_identifier.to_owned(),
+                        variant_name: variant.identifier.name.to_owned(),
+                        field_name: name,
+                    }),
+                )));
+            }
+
+            let r#type = Type::try_from_syntax(r#type, scope.clone())?;
+            if let Some(forbidden) = Payload::find_forbidden(&r#type) {
+                return Err(SemanticError::Element(ElementError::Type(
+                    TypeError::Enumeration(Error::PayloadForbiddenType {
+                        location,
+                        type_identifier: type_identifier.to_owned(),
+                        variant_name: variant.identifier.name.to_owned(),
+                        found: forbidden.to_string(),
+                    }),
+                )));
+            }
+
+            fields.push((name, r#type));
+        }
+
+        Ok(Payload::new(is_structure, fields))
+    }
+
+    ///
+    /// Returns the enumeration path from the crate root, e.g. `module::Enumeration`.
+    ///
+    pub fn path(&self) -> String {
+        let parent = self.scope.borrow().parent();
+
+        let mut path = parent.map(Scope::get_module_path).unwrap_or_default();
+        path.push(self.identifier.to_owned());
+        path.join("::")
+    }
 }
 
 impl PartialEq<Self> for Enumeration {
@@ -0,0 +1,76 @@
+//!
+//! The semantic analyzer enumeration variant payload.
+//!
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// The enumeration variant payload, which is placed after the variant discriminant and
+/// zero-padded to the size of the largest payload of the enumeration.
+///
+#[derive(Debug, Clone)]
+pub struct Payload {
+    /// Whether the payload fields are named, e.g. `Transfer { to: u160, amount: u248 }`.
+    pub is_structure: bool,
+    /// The ordered payload fields. The tuple-like payload fields are named with their indexes.
+    pub fields: Vec<(String, Type)>,
+}
+
+impl Payload {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(is_structure: bool, fields: Vec<(String, Type)>) -> Self {
+        Self {
+            is_structure,
+            fields,
+        }
+    }
+
+    ///
+    /// The payload size in the Zinc VM data stack.
+    ///
+    pub fn size(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|(_name, r#type)| r#type.size())
+            .sum()
+    }
+
+    ///
+    /// Returns the offset from the payload start and the type of the field `name`.
+    ///
+    pub fn field(&self, name: &str) -> Option<(usize, &Type)> {
+        let mut offset = 0;
+        for (field_name, r#type) in self.fields.iter() {
+            if field_name == name {
+                return Some((offset, r#type));
+            }
+            offset += r#type.size();
+        }
+
+        None
+    }
+
+    ///
+    /// Returns the first inner type, which cannot be a part of a payload, that is, a unit,
+    /// string, range, function, contract, or map. Returns `None` for the allowed types.
+    ///
+    pub fn find_forbidden(r#type: &Type) -> Option<&Type> {
+        match r#type {
+            Type::Boolean(_) => None,
+            Type::IntegerUnsigned { .. } => None,
+            Type::IntegerSigned { .. } => None,
+            Type::Field(_) => None,
+            Type::Enumeration(_) => None,
+            Type::Array(inner) => Self::find_forbidden(&inner.r#type),
+            Type::Tuple(inner) => inner.types.iter().find_map(Self::find_forbidden),
+            Type::Structure(_) if r#type.is_mtreemap() => Some(r#type),
+            Type::Structure(inner) => inner
+                .fields
+                .iter()
+                .find_map(|(_name, r#type)| Self::find_forbidden(r#type)),
+            r#type => Some(r#type),
+        }
+    }
+}
@@ -38,3 +38,268 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_payload() {
+    let input = r#"
+enum Action {
+    Transfer { to: u160, amount: u248 },
+    Burn(u248),
+    Noop,
+}
+
+fn main(recipient: u160, value: u248) -> u248 {
+    let transfer = Action::Transfer { to: recipient, amount: value };
+    let burn = Action::Burn(value);
+    let noop = Action::Noop;
+
+    match transfer {
+        Action::Transfer { to: _, amount } => amount,
+        Action::Burn(amount) => amount,
+        Action::Noop => 0 as u248,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_duplicate_payload_field() {
+    let input = r#"
+enum Action {
+    Transfer { to: u160, to: u248 },
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::DuplicatePayloadField {
+            location: Location::test(3, 26),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Transfer".to_owned(),
+            field_name: "to".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_forbidden_type() {
+    let input = r#"
+enum Action {
+    Burn(u248, ()),
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadForbiddenType {
+            location: Location::test(3, 16),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Burn".to_owned(),
+            found: "()".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_expected() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main() {
+    let action = Action::Burn;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadExpected {
+            location: Location::test(8, 18),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Burn".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_unexpected() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main() {
+    let action = Action::Noop(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadUnexpected {
+            location: Location::test(8, 18),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Noop".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_invalid_kind() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main(value: u248) {
+    let action = Action::Burn { value: value };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadInvalidKind {
+            location: Location::test(8, 18),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Burn".to_owned(),
+            is_structure: false,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_invalid_length() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main(value: u248) {
+    let action = Action::Burn(value, value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadInvalidLength {
+            location: Location::test(8, 30),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Burn".to_owned(),
+            expected: 1,
+            found: 2,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_field_invalid_type() {
+    let input = r#"
+enum Action {
+    Burn(u248),
+    Noop,
+}
+
+fn main(value: u8) {
+    let action = Action::Burn(value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadFieldInvalidType {
+            location: Location::test(8, 31),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Burn".to_owned(),
+            field_name: "0".to_owned(),
+            expected: "u248".to_owned(),
+            found: "u8".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_field_does_not_exist() {
+    let input = r#"
+enum Action {
+    Transfer { to: u160, amount: u248 },
+}
+
+fn main(to: u160, amount: u248) {
+    let action = Action::Transfer { to, amount, fee: amount };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadFieldDoesNotExist {
+            location: Location::test(7, 49),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Transfer".to_owned(),
+            field_name: "fee".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_field_missing() {
+    let input = r#"
+enum Action {
+    Transfer { to: u160, amount: u248 },
+}
+
+fn main(to: u160) {
+    let action = Action::Transfer { to: to };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Enumeration(EnumerationTypeError::PayloadFieldMissing {
+            location: Location::test(7, 35),
+            type_identifier: "Action".to_owned(),
+            variant_name: "Transfer".to_owned(),
+            field_name: "amount".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
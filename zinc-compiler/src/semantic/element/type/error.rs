@@ -41,6 +41,13 @@ pub enum Error {
         /// The found type.
         found: String,
     },
+    /// A named tuple element with the same name occurs for the second time.
+    DuplicateTupleElement {
+        /// The duplicate element name location.
+        location: Location,
+        /// The duplicate element name.
+        name: String,
+    },
 
     /// The function type error. See the inner element description.
     Function(FunctionError),
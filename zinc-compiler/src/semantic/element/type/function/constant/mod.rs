@@ -182,7 +182,7 @@ impl Function {
     ) -> Result<Constant, SemanticError> {
         let location = self.location;
 
-        let scope = Scope::new_child(self.identifier, scope);
+        let scope = Scope::new(self.identifier, Some(scope)).wrap();
 
         for (name, constant) in arguments.into_iter() {
             Scope::insert_item(
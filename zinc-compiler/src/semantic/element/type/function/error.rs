@@ -4,6 +4,7 @@
 
 use zinc_lexical::Location;
 
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
 use crate::semantic::element::r#type::function::test::error::Error as TestFunctionError;
 
@@ -91,9 +92,20 @@ pub enum Error {
         /// The function identifier.
         function: String,
     },
+    /// A mutable function was called with the contract storage in an immutable contract method.
+    CallingMutableFromQuery {
+        /// The function location.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The identifier of the immutable contract method, where the call is made.
+        method: String,
+    },
 
     /// The intrinsic function error. See the inner element description.
     Intrinsic(IntrinsicFunctionError),
+    /// The generic function error. See the inner element description.
+    Generic(GenericFunctionError),
     /// The unit test function error. See the inner element description.
     Test(TestFunctionError),
 }
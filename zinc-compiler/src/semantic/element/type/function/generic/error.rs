@@ -0,0 +1,70 @@
+//!
+//! The semantic analyzer generic function error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer generic function error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The function cannot have generic type parameters, e.g. it is constant or an entry point.
+    Forbidden {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+    },
+    /// The generic type arguments are specified for a non-generic item.
+    Unexpected {
+        /// The error location data.
+        location: Location,
+        /// The stringified item.
+        name: String,
+    },
+    /// The number of generic type arguments does not match the number of type parameters.
+    ArgumentCount {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The expected number of generic type arguments.
+        expected: usize,
+        /// The actual number of generic type arguments.
+        found: usize,
+    },
+    /// The generic type argument is not a scalar type.
+    ArgumentNotScalar {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The type parameter identifier.
+        parameter: String,
+        /// The stringified invalid type.
+        found: String,
+    },
+    /// The type parameter cannot be inferred from the call arguments.
+    InferenceAmbiguous {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The type parameter identifier.
+        parameter: String,
+    },
+    /// The type parameter is inferred as different types from different call arguments.
+    InferenceConflict {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The type parameter identifier.
+        parameter: String,
+        /// The stringified type inferred from the previous arguments.
+        expected: String,
+        /// The stringified type inferred from the current argument.
+        found: String,
+    },
+}
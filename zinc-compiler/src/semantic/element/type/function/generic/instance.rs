@@ -0,0 +1,45 @@
+//!
+//! The semantic analyzer generic function instance.
+//!
+
+use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The generic function instance, created for a specific set of generic type arguments.
+///
+#[derive(Debug, Clone)]
+pub struct Instance {
+    /// The generic type actual arguments.
+    pub params: Vec<Type>,
+    /// The runtime function, which is `None` while the instance body is being analyzed.
+    pub function: Option<RuntimeFunction>,
+    /// The instance bytecode generator IR representation.
+    pub intermediate: Option<GeneratorFunctionStatement>,
+}
+
+impl Instance {
+    ///
+    /// Creates an instance, whose body is being analyzed.
+    ///
+    pub fn new_pending(params: Vec<Type>) -> Self {
+        Self {
+            params,
+            function: None,
+            intermediate: None,
+        }
+    }
+
+    ///
+    /// Sets the analyzed runtime function and its IR.
+    ///
+    pub fn set_defined(
+        &mut self,
+        function: RuntimeFunction,
+        intermediate: GeneratorFunctionStatement,
+    ) {
+        self.function = Some(function);
+        self.intermediate = Some(intermediate);
+    }
+}
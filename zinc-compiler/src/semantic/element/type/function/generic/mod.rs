@@ -0,0 +1,328 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod instance;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::FnStatement;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::analyzer::statement::r#fn::Analyzer as FnStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::Scope;
+
+use self::error::Error;
+use self::instance::Instance;
+
+///
+/// The semantic analyzer generic function element.
+///
+/// The function body is analyzed separately for each set of generic type arguments, producing
+/// a distinct runtime function instance with its own type ID.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Location,
+    /// The function identifier.
+    pub identifier: String,
+    /// The function syntax representation, which is analyzed for each instance.
+    pub statement: FnStatement,
+    /// The context where the function is declared.
+    pub context: FnStatementAnalyzerContext,
+    /// The function attributes, which are passed to each instance.
+    pub attributes: Vec<Attribute>,
+    /// The scope where the function is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The generic type actual arguments, which are set with the `::<...>` syntax.
+    pub params: Option<Vec<Type>>,
+    /// The instances created so far, shared between all the copies of the function element.
+    pub instances: Rc<RefCell<Vec<Instance>>>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        statement: FnStatement,
+        context: FnStatementAnalyzerContext,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            location,
+            identifier: statement.identifier.name.to_owned(),
+            statement,
+            context,
+            attributes,
+            scope,
+            params: None,
+            instances: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    ///
+    /// Whether the function must be called from mutable context.
+    ///
+    pub fn is_mutable(&self) -> bool {
+        self.statement
+            .argument_bindings
+            .first()
+            .map(|binding| match binding.pattern.variant {
+                BindingPatternVariant::Binding { is_mutable, .. } => is_mutable,
+                _ => false,
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Validates and sets the generic type arguments specified with the `::<...>` syntax.
+    ///
+    pub fn set_params(&mut self, location: Location, params: Vec<Type>) -> Result<(), Error> {
+        if params.len() != self.statement.generics.len() {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: self.statement.generics.len(),
+                found: params.len(),
+            });
+        }
+
+        self.params = Some(params);
+        Ok(())
+    }
+
+    ///
+    /// Returns the runtime function instance for the call at `location` with `argument_list`.
+    ///
+    /// The generic type arguments are either set explicitly or inferred from the types of
+    /// the arguments. If the instance with such arguments does not exist yet, the function
+    /// body is analyzed with the type parameters aliased to the actual types.
+    ///
+    pub fn instantiate(
+        &self,
+        location: Location,
+        argument_list: &ArgumentList,
+    ) -> Result<RuntimeFunction, SemanticError> {
+        let params = match self.params {
+            Some(ref params) => params.to_owned(),
+            None => self.infer(location, argument_list)?,
+        };
+
+        for (generic, r#type) in self.statement.generics.iter().zip(params.iter()) {
+            if !r#type.is_scalar() {
+                return Err(Self::error(Error::ArgumentNotScalar {
+                    location,
+                    function: self.identifier.to_owned(),
+                    parameter: generic.name.to_owned(),
+                    found: r#type.to_string(),
+                }));
+            }
+        }
+
+        let existing = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|instance| instance.params == params)
+            .map(|instance| instance.function.to_owned());
+        match existing {
+            Some(Some(function)) => return Ok(function),
+            Some(None) => return Err(SemanticError::Scope(ScopeError::ReferenceLoop { location })),
+            None => {}
+        }
+
+        self.instances
+            .borrow_mut()
+            .push(Instance::new_pending(params.clone()));
+
+        let result = self.analyze(params.as_slice());
+        let mut instances = self.instances.borrow_mut();
+        let index = instances
+            .iter()
+            .position(|instance| instance.params == params)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        match result {
+            Ok((function, intermediate)) => {
+                instances[index].set_defined(function.clone(), intermediate);
+                Ok(function)
+            }
+            Err(error) => {
+                instances.remove(index);
+                Err(error)
+            }
+        }
+    }
+
+    ///
+    /// Infers the generic type arguments from the types of the call arguments, whose formal
+    /// parameter types are the type parameters themselves.
+    ///
+    fn infer(
+        &self,
+        location: Location,
+        argument_list: &ArgumentList,
+    ) -> Result<Vec<Type>, SemanticError> {
+        let mut params: Vec<Option<Type>> = vec![None; self.statement.generics.len()];
+
+        for (binding, element) in self
+            .statement
+            .argument_bindings
+            .iter()
+            .zip(argument_list.arguments.iter())
+        {
+            let index = match binding
+                .r#type
+                .as_ref()
+                .and_then(|r#type| self.generic_index(r#type))
+            {
+                Some(index) => index,
+                None => continue,
+            };
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                _ => continue,
+            };
+
+            match params[index] {
+                Some(ref inferred) if inferred != &r#type => {
+                    return Err(Self::error(Error::InferenceConflict {
+                        location: element.location().unwrap_or(location),
+                        function: self.identifier.to_owned(),
+                        parameter: self.statement.generics[index].name.to_owned(),
+                        expected: inferred.to_string(),
+                        found: r#type.to_string(),
+                    }));
+                }
+                Some(_) => {}
+                None => params[index] = Some(r#type),
+            }
+        }
+
+        params
+            .into_iter()
+            .zip(self.statement.generics.iter())
+            .map(|(r#type, generic)| {
+                r#type.ok_or_else(|| {
+                    Self::error(Error::InferenceAmbiguous {
+                        location,
+                        function: self.identifier.to_owned(),
+                        parameter: generic.name.to_owned(),
+                    })
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Analyzes the function body with the type parameters aliased to `params`.
+    ///
+    fn analyze(
+        &self,
+        params: &[Type],
+    ) -> Result<(RuntimeFunction, GeneratorFunctionStatement), SemanticError> {
+        let mut statement = self.statement.clone();
+        statement.identifier.name = format!(
+            "{}<{}>",
+            self.identifier,
+            params
+                .iter()
+                .map(|r#type| r#type.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        let scope = Scope::new_child(statement.identifier.name.clone(), self.scope.clone());
+        for (generic, r#type) in statement.generics.drain(..).zip(params.iter()) {
+            Scope::define_type(scope.clone(), generic, r#type.to_owned(), false, None)?;
+        }
+
+        let (r#type, intermediate) = FnStatementAnalyzer::runtime(
+            scope.clone(),
+            statement,
+            self.context,
+            self.attributes.clone(),
+        )?;
+        Scope::close(scope);
+
+        match r#type {
+            Type::Function(FunctionType::Runtime(function)) => Ok((function, intermediate)),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Returns the index of the type parameter, if the syntax `type` is one.
+    ///
+    fn generic_index(&self, r#type: &SyntaxType) -> Option<usize> {
+        match r#type.variant {
+            SyntaxTypeVariant::Alias {
+                ref path,
+                generics: None,
+            } if path.left.is_none() && path.right.is_none() => match *path.value {
+                ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref identifier)) => self
+                    .statement
+                    .generics
+                    .iter()
+                    .position(|generic| generic.name == identifier.name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    ///
+    /// Wraps the generic function error into the semantic one.
+    ///
+    fn error(error: Error) -> SemanticError {
+        SemanticError::Element(ElementError::Type(TypeError::Function(
+            FunctionError::Generic(error),
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>",
+            self.identifier,
+            self.statement
+                .generics
+                .iter()
+                .map(|generic| generic.name.to_owned())
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
@@ -0,0 +1,228 @@
+//!
+//! The generic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
+use crate::semantic::element::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_inferred() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> (u8, u32) {
+    let small: u8 = 42;
+    let big: u32 = 1000;
+
+    (max(small, 64), max(big, 500 as u32))
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_explicit() {
+    let input = r#"
+fn zero<T>() -> T {
+    0 as T
+}
+
+fn main() -> u64 {
+    zero::<u64>()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_method() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    pub fn pick<T>(self, a: T, b: T) -> T {
+        if self.value > 0 { a } else { b }
+    }
+}
+
+fn main() -> field {
+    let data = Data { value: 1 };
+    data.pick(1 as field, 2 as field)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_forbidden_constant() {
+    let input = r#"
+const fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(GenericFunctionError::Forbidden {
+            location: Location::test(2, 1),
+            function: "max".to_owned(),
+        })),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected() {
+    let input = r#"
+fn another(a: u8) -> u8 {
+    a
+}
+
+fn main() -> u8 {
+    another::<u8>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(GenericFunctionError::Unexpected {
+            location: Location::test(7, 12),
+            name: "type function fn another(a: u8) -> u8".to_owned(),
+        })),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> u8 {
+    max::<u8, u8>(1, 2)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::ArgumentCount {
+                location: Location::test(7, 8),
+                function: "max".to_owned(),
+                expected: 1,
+                found: 2,
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_not_scalar() {
+    let input = r#"
+fn first<T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() -> [u8; 2] {
+    first([1, 2], [3, 4])
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::ArgumentNotScalar {
+                location: Location::test(7, 10),
+                function: "first".to_owned(),
+                parameter: "T".to_owned(),
+                found: "array [u8; 2]".to_owned(),
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_inference_ambiguous() {
+    let input = r#"
+fn zero<T>() -> T {
+    0 as T
+}
+
+fn main() -> u8 {
+    zero()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::InferenceAmbiguous {
+                location: Location::test(7, 9),
+                function: "zero".to_owned(),
+                parameter: "T".to_owned(),
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_inference_conflict() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> u8 {
+    let small: u8 = 42;
+    let big: u32 = 1000;
+
+    max(small, big)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::InferenceConflict {
+                location: Location::test(10, 16),
+                function: "max".to_owned(),
+                parameter: "T".to_owned(),
+                expected: "u8".to_owned(),
+                found: "u32".to_owned(),
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -129,9 +129,34 @@ impl Function {
             .skip(Self::ARGUMENT_INDEX_VALUES)
             .map(|(r#type, _is_constant, _string, _location)| r#type)
             .collect();
+        let format_string = Self::prefix_structure_names(format_string, argument_types.as_slice());
 
         Ok((Type::unit(None), format_string, argument_types))
     }
+
+    ///
+    /// Prefixes the placeholders of the structure arguments with the structure names, so the
+    /// virtual machine prints `Point { x: 3, y: 5 }`, as the bytecode types are anonymous.
+    ///
+    /// The generic intrinsic structures like `Maybe<T>` are printed in their own way.
+    ///
+    fn prefix_structure_names(format: String, argument_types: &[Type]) -> String {
+        let mut parts = format.split("{}");
+        let mut result = parts.next().unwrap_or_default().to_owned();
+
+        for (part, r#type) in parts.zip(argument_types.iter()) {
+            if let Type::Structure(inner) = r#type {
+                if inner.generics.is_none() {
+                    result.push_str(inner.identifier.as_str());
+                    result.push(' ');
+                }
+            }
+            result.push_str("{}");
+            result.push_str(part);
+        }
+
+        result
+    }
 }
 
 impl fmt::Display for Function {
@@ -2,9 +2,14 @@
 //! The `dbg!` intrinsic function tests.
 //!
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use zinc_lexical::Location;
 
 use crate::error::Error;
+use crate::generator::printer::Printer;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
 use crate::semantic::element::r#type::function::intrinsic::debug::error::Error as DebugFunctionError;
@@ -13,6 +18,7 @@ use crate::semantic::element::r#type::function::intrinsic::error::Error as Intri
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
 use crate::semantic::error::Error as SemanticError;
+use crate::source::Source;
 
 #[test]
 fn error_argument_count_lesser() {
@@ -83,3 +89,42 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_composite_arguments() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let point = Point { x: 3, y: 5 };
+    dbg!("{} {} {}", point, (1, true), [1, 2, 3]);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure_name_prefixed() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let point = Point { x: 3, y: 5 };
+    dbg!("point: {}, array: {}", point, [point.x, point.y]);
+}
+"#;
+
+    let source = Source::test(input, PathBuf::from("test.zn"), HashMap::new())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let scope = EntryAnalyzer::define(source).expect(zinc_const::panic::TEST_DATA_VALID);
+    let listing = Printer::intermediate(scope.borrow().get_intermediate().as_slice());
+
+    assert!(listing.contains(r#"call dbg "point: Point {}, array: {}""#));
+}
@@ -3,6 +3,8 @@
 //!
 
 use crate::semantic::element::r#type::function::intrinsic::debug::error::Error as DebugFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::error::Error as MemFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::require::error::Error as RequireFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::error::Error as StandardLibraryFunctionError;
 use zinc_lexical::Location;
 
@@ -25,8 +27,12 @@ pub enum Error {
         /// The function identifier.
         function: &'static str,
     },
+    /// The `require(...)` function error.
+    Require(RequireFunctionError),
     /// The `dbg!(...)` function error.
     Debug(DebugFunctionError),
     /// The standary library function error.
     StandardLibrary(StandardLibraryFunctionError),
+    /// The `std::mem` library function error.
+    Mem(MemFunctionError),
 }
@@ -0,0 +1,30 @@
+//!
+//! The semantic analyzer `std::mem` library function error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The semantic analyzer `std::mem` library function error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The type only exists at compile time, so it has no size, e.g. `str` or a range.
+    TypeUnsized {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: &'static str,
+        /// The stringified invalid type.
+        found: String,
+    },
+    /// The type argument is expected to be a contract.
+    TypeNotContract {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: &'static str,
+        /// The stringified invalid type.
+        found: String,
+    },
+}
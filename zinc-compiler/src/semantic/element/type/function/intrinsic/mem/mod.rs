@@ -0,0 +1,176 @@
+//!
+//! The semantic analyzer `std::mem` library function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod field_count_of;
+pub mod size_of;
+
+use std::fmt;
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+use self::field_count_of::Function as FieldCountOfFunction;
+use self::size_of::Function as SizeOfFunction;
+
+///
+/// The semantic analyzer `std::mem` library function element.
+///
+/// The functions take a type argument with the `::<...>` syntax or as the only call argument,
+/// and are always evaluated by the compiler, so they can be used in constant contexts, e.g. array sizes.
+///
+#[derive(Debug, Clone)]
+pub enum Function {
+    /// The `std::mem::size_of` function variant.
+    SizeOf(SizeOfFunction),
+    /// The `std::mem::field_count_of` function variant.
+    FieldCountOf(FieldCountOfFunction),
+}
+
+impl Function {
+    ///
+    /// Sets the type argument specified with the `::<...>` syntax.
+    ///
+    pub fn set_params(
+        &mut self,
+        location: Location,
+        params: Vec<Type>,
+    ) -> Result<(), GenericFunctionError> {
+        if params.len() != 1 {
+            return Err(GenericFunctionError::ArgumentCount {
+                location,
+                function: self.identifier().to_owned(),
+                expected: 1,
+                found: params.len(),
+            });
+        }
+
+        let r#type = params.into_iter().next().map(Box::new);
+        match self {
+            Self::SizeOf(inner) => inner.r#type = r#type,
+            Self::FieldCountOf(inner) => inner.r#type = r#type,
+        }
+        Ok(())
+    }
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the computed count as an integer literal constant.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Constant, Error> {
+        match self {
+            Self::SizeOf(inner) => inner.call(location, argument_list),
+            Self::FieldCountOf(inner) => inner.call(location, argument_list),
+        }
+    }
+
+    ///
+    /// Returns the function identifier, which is known at compile time.
+    ///
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Self::SizeOf(inner) => inner.identifier,
+            Self::FieldCountOf(inner) => inner.identifier,
+        }
+    }
+
+    ///
+    /// Sets the function call location in the code.
+    ///
+    pub fn set_location(&mut self, location: Location) {
+        match self {
+            Self::SizeOf(inner) => inner.location = Some(location),
+            Self::FieldCountOf(inner) => inner.location = Some(location),
+        }
+    }
+
+    ///
+    /// Returns the location of the function call.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::SizeOf(inner) => inner.location,
+            Self::FieldCountOf(inner) => inner.location,
+        }
+    }
+
+    ///
+    /// Returns the type argument, which is passed either with the `::<...>` syntax, or as the
+    /// only call argument, e.g. `size_of(Data)`.
+    ///
+    /// The call argument must be a type path, since type keywords cannot be used as expressions.
+    ///
+    pub fn type_argument(
+        location: Location,
+        identifier: &'static str,
+        r#type: Option<Box<Type>>,
+        argument_list: ArgumentList,
+    ) -> Result<Type, Error> {
+        let expected = if r#type.is_some() { 0 } else { 1 };
+        if argument_list.arguments.len() > expected {
+            return Err(Error::ArgumentCount {
+                location,
+                function: identifier.to_owned(),
+                expected,
+                found: argument_list.arguments.len(),
+                reference: None,
+            });
+        }
+
+        if let Some(r#type) = r#type {
+            return Ok(*r#type);
+        }
+
+        match argument_list.arguments.into_iter().next() {
+            Some(Element::Type(r#type)) => Ok(r#type),
+            Some(element) => Err(Error::ArgumentType {
+                location: element.location().unwrap_or(location),
+                function: identifier.to_owned(),
+                name: "T".to_owned(),
+                position: 1,
+                expected: "type".to_owned(),
+                found: element.to_string(),
+            }),
+            None => Err(Error::Generic(GenericFunctionError::InferenceAmbiguous {
+                location,
+                function: identifier.to_owned(),
+                parameter: "T".to_owned(),
+            })),
+        }
+    }
+
+    ///
+    /// Converts the computed `count` into an integer literal constant.
+    ///
+    pub fn count(location: Location, count: usize) -> Constant {
+        let value = BigInt::from(count);
+        let bitlength = IntegerConstant::minimal_bitlength_bigints(&[&value], false, location)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        let integer = IntegerConstant::new(location, value, false, bitlength, true);
+        Constant::Integer(integer)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeOf(inner) => write!(f, "{}", inner),
+            Self::FieldCountOf(inner) => write!(f, "{}", inner),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//!
+//! The semantic analyzer `std::mem::size_of` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::error::Error as MemFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::Function as MemFunction;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The semantic analyzer `std::mem::size_of` function element.
+///
+/// Returns the number of scalar values, that is, field elements, which a value of the type
+/// argument occupies when flattened.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The type argument, which is set with the `::<...>` syntax, if specified.
+    pub r#type: Option<Box<Type>>,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+            r#type: None,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "size_of";
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the flattened size of the type argument as an integer literal constant.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Constant, Error> {
+        let r#type =
+            MemFunction::type_argument(location, self.identifier, self.r#type, argument_list)?;
+
+        match r#type {
+            Type::String(_)
+            | Type::Range(_)
+            | Type::RangeInclusive(_)
+            | Type::Function(_)
+            | Type::Contract(_) => Err(Error::Intrinsic(IntrinsicFunctionError::Mem(
+                MemFunctionError::TypeUnsized {
+                    location,
+                    function: self.identifier,
+                    found: r#type.to_string(),
+                },
+            ))),
+            r#type => Ok(MemFunction::count(location, r#type.size())),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mem::{}<T>() -> integer", self.identifier)
+    }
+}
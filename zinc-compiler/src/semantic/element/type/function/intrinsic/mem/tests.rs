@@ -0,0 +1,251 @@
+//!
+//! The `std::mem` library function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::generic::error::Error as GenericFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::error::Error as MemFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::mem::field_count_of::Function as FieldCountOfFunction;
+use crate::semantic::element::r#type::function::intrinsic::mem::size_of::Function as SizeOfFunction;
+use crate::semantic::element::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_size_of() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: [field; 3],
+    c: (bool, u64),
+}
+
+const SIZE: u8 = std::mem::size_of::<Data>();
+
+fn main() -> [u8; std::mem::size_of::<[u8; 4]>()] {
+    require(SIZE == 6);
+    require(std::mem::size_of::<()>() == 0);
+
+    [0; std::mem::size_of::<[u8; 4]>()]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_field_count_of() {
+    let input = r#"
+contract Test {
+    pub a: u8;
+    b: [u8; 4];
+
+    pub fn count(self) -> u8 {
+        std::mem::field_count_of::<Self>()
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_type_literal_argument() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: [field; 3],
+}
+
+type Pair = (Data, bool);
+
+contract Test {
+    pub a: u8;
+    b: [u8; 4];
+
+    pub fn count(self) -> u8 {
+        require(std::mem::size_of(Data) == 4);
+        require(std::mem::size_of(Pair) == std::mem::size_of::<(Data, bool)>());
+
+        std::mem::field_count_of(Self)
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_type_unsized() {
+    let input = r#"
+fn main() -> u8 {
+    std::mem::size_of::<str>()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Mem(
+            MemFunctionError::TypeUnsized {
+                location: Location::test(3, 5),
+                function: SizeOfFunction::IDENTIFIER,
+                found: "str".to_owned(),
+            },
+        ))),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_not_contract() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main() -> u8 {
+    std::mem::field_count_of::<Data>()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Mem(
+            MemFunctionError::TypeNotContract {
+                location: Location::test(7, 5),
+                function: FieldCountOfFunction::IDENTIFIER,
+                found: "structure Data".to_owned(),
+            },
+        ))),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_argument_missing() {
+    let input = r#"
+fn main() -> u8 {
+    std::mem::size_of()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::InferenceAmbiguous {
+                location: Location::test(3, 5),
+                function: SizeOfFunction::IDENTIFIER.to_owned(),
+                parameter: "T".to_owned(),
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_argument_count() {
+    let input = r#"
+fn main() -> u8 {
+    std::mem::size_of::<u8, u8>()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Generic(
+            GenericFunctionError::ArgumentCount {
+                location: Location::test(3, 22),
+                function: SizeOfFunction::IDENTIFIER.to_owned(),
+                expected: 1,
+                found: 2,
+            },
+        )),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+fn main() -> u8 {
+    std::mem::size_of::<u8>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: SizeOfFunction::IDENTIFIER.to_owned(),
+            expected: 0,
+            found: 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_type_literal() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main() -> u8 {
+    std::mem::size_of(Data, Data)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(7, 5),
+            function: SizeOfFunction::IDENTIFIER.to_owned(),
+            expected: 1,
+            found: 2,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_not_type() {
+    let input = r#"
+fn main() -> u8 {
+    std::mem::size_of(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 23),
+            function: SizeOfFunction::IDENTIFIER.to_owned(),
+            name: "T".to_owned(),
+            position: 1,
+            expected: "type".to_owned(),
+            found: "constant integer '42' of type 'u8'".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
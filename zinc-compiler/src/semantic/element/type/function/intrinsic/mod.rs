@@ -7,8 +7,10 @@ mod tests;
 
 pub mod debug;
 pub mod error;
+pub mod mem;
 pub mod require;
 pub mod stdlib;
+pub mod string_len;
 pub mod zksync;
 
 use std::fmt;
@@ -18,6 +20,9 @@ use zinc_build::LibraryFunctionIdentifier;
 use zinc_lexical::Location;
 
 use self::debug::Function as DebugFunction;
+use self::mem::field_count_of::Function as MemFieldCountOfFunction;
+use self::mem::size_of::Function as MemSizeOfFunction;
+use self::mem::Function as MemFunction;
 use self::require::Function as RequireFunction;
 use self::stdlib::array_pad::Function as StdArrayPadFunction;
 use self::stdlib::array_reverse::Function as StdArrayReverseFunction;
@@ -29,12 +34,17 @@ use self::stdlib::collections_mtreemap_remove::Function as StdCollectionsMTreeMa
 use self::stdlib::convert_from_bits_field::Function as StdConvertFromBitsFieldFunction;
 use self::stdlib::convert_from_bits_signed::Function as StdConvertFromBitsSignedFunction;
 use self::stdlib::convert_from_bits_unsigned::Function as StdConvertFromBitsUnsignedFunction;
+use self::stdlib::convert_from_bytes_be::Function as StdConvertFromBytesBeFunction;
 use self::stdlib::convert_to_bits::Function as StdConvertToBitsFunction;
+use self::stdlib::convert_to_bytes_be::Function as StdConvertToBytesBeFunction;
 use self::stdlib::crypto_pedersen::Function as StdConvertPedersenFunction;
 use self::stdlib::crypto_schnorr_signature_verify::Function as StdCryptoSchnorrSignatureVerifyFunction;
 use self::stdlib::crypto_sha256::Function as StdCryptoSha256Function;
 use self::stdlib::ff_invert::Function as StdFfInvertFunction;
+use self::stdlib::maybe_unwrap_or::Function as MaybeUnwrapOrFunction;
 use self::stdlib::Function as StandardLibraryFunction;
+use self::string_len::Function as StringLenFunction;
+use self::zksync::balance::Function as ZkSyncBalanceFunction;
 use self::zksync::transfer::Function as ZkSyncTransferFunction;
 use self::zksync::Function as ZkSyncLibraryFunction;
 
@@ -51,6 +61,10 @@ pub enum Function {
     StandardLibrary(StandardLibraryFunction),
     /// The zkSync library function. See the inner element description.
     ZkSyncLibrary(ZkSyncLibraryFunction),
+    /// The `str::len` method. See the inner element description.
+    StringLen(StringLenFunction),
+    /// The `std::mem` library function. See the inner element description.
+    Mem(MemFunction),
 }
 
 impl Function {
@@ -68,6 +82,27 @@ impl Function {
         Self::Debug(DebugFunction::default())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_string_len() -> Self {
+        Self::StringLen(StringLenFunction::default())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_mem_size_of() -> Self {
+        Self::Mem(MemFunction::SizeOf(MemSizeOfFunction::default()))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_mem_field_count_of() -> Self {
+        Self::Mem(MemFunction::FieldCountOf(MemFieldCountOfFunction::default()))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -103,6 +138,14 @@ impl Function {
                     StdConvertFromBitsFieldFunction::default(),
                 ))
             }
+            LibraryFunctionIdentifier::ConvertToBytesBe => Self::StandardLibrary(
+                StandardLibraryFunction::ConvertToBytesBe(StdConvertToBytesBeFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ConvertFromBytesBe => {
+                Self::StandardLibrary(StandardLibraryFunction::ConvertFromBytesBe(
+                    StdConvertFromBytesBeFunction::default(),
+                ))
+            }
 
             LibraryFunctionIdentifier::ArrayReverse => Self::StandardLibrary(
                 StandardLibraryFunction::ArrayReverse(StdArrayReverseFunction::default()),
@@ -121,6 +164,9 @@ impl Function {
             LibraryFunctionIdentifier::ZksyncTransfer => Self::ZkSyncLibrary(
                 ZkSyncLibraryFunction::Transfer(ZkSyncTransferFunction::default()),
             ),
+            LibraryFunctionIdentifier::ZksyncBalance => Self::ZkSyncLibrary(
+                ZkSyncLibraryFunction::Balance(ZkSyncBalanceFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => {
                 Self::StandardLibrary(StandardLibraryFunction::CollectionsMTreeMapGet(
@@ -142,6 +188,10 @@ impl Function {
                     StdCollectionsMTreeMapRemoveFunction::default(),
                 ))
             }
+
+            LibraryFunctionIdentifier::MaybeUnwrapOr => Self::StandardLibrary(
+                StandardLibraryFunction::MaybeUnwrapOr(MaybeUnwrapOrFunction::default()),
+            ),
         }
     }
 
@@ -161,6 +211,8 @@ impl Function {
             Self::Debug(_) => false,
             Self::StandardLibrary(inner) => inner.is_mutable(),
             Self::ZkSyncLibrary(inner) => inner.is_mutable(),
+            Self::StringLen(_) => false,
+            Self::Mem(_) => false,
         }
     }
 
@@ -173,6 +225,8 @@ impl Function {
             Self::Debug(inner) => inner.identifier,
             Self::StandardLibrary(inner) => inner.identifier(),
             Self::ZkSyncLibrary(inner) => inner.identifier(),
+            Self::StringLen(inner) => inner.identifier,
+            Self::Mem(inner) => inner.identifier(),
         }
     }
 
@@ -185,6 +239,8 @@ impl Function {
             Self::Debug(inner) => inner.location = Some(location),
             Self::StandardLibrary(inner) => inner.set_location(location),
             Self::ZkSyncLibrary(inner) => inner.set_location(location),
+            Self::StringLen(inner) => inner.location = Some(location),
+            Self::Mem(inner) => inner.set_location(location),
         }
     }
 
@@ -197,6 +253,8 @@ impl Function {
             Self::Debug(inner) => inner.location,
             Self::StandardLibrary(inner) => inner.location(),
             Self::ZkSyncLibrary(inner) => inner.location(),
+            Self::StringLen(inner) => inner.location,
+            Self::Mem(inner) => inner.location(),
         }
     }
 }
@@ -208,6 +266,8 @@ impl fmt::Display for Function {
             Self::Debug(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "std::{}", inner),
             Self::ZkSyncLibrary(inner) => write!(f, "zksync::{}", inner),
+            Self::StringLen(inner) => write!(f, "{}", inner),
+            Self::Mem(inner) => write!(f, "std::{}", inner),
         }
     }
 }
@@ -0,0 +1,21 @@
+//!
+//! The `require` intrinsic function error.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The `require` intrinsic function error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The `require(...)` function argument count does not match the number of placeholders in the message.
+    ArgumentCount {
+        /// The error location data.
+        location: Location,
+        /// The number of expected function arguments including the condition and message.
+        expected: usize,
+        /// The number of actual function arguments including the condition and message.
+        found: usize,
+    },
+}
@@ -5,6 +5,8 @@
 #[cfg(test)]
 mod tests;
 
+pub mod error;
+
 use std::fmt;
 
 use zinc_lexical::Location;
@@ -12,6 +14,8 @@ use zinc_lexical::Location;
 use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::require::error::Error as RequireFunctionError;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
@@ -46,20 +50,27 @@ impl Function {
     /// The position of the optional `message` argument in the function argument list.
     pub const ARGUMENT_INDEX_MESSAGE: usize = 1;
 
+    /// The position, where the variadic argument list part starts from.
+    pub const ARGUMENT_INDEX_VALUES: usize = 2;
+
     /// The number of arguments, not including the optional ones.
     pub const ARGUMENT_COUNT_MANDATORY: usize = 1;
 
-    /// The number of arguments, including the optional ones.
+    /// The number of arguments, including the optional message, but not the values interpolated
+    /// into the message.
     pub const ARGUMENT_COUNT_OPTIONAL: usize = 2;
 
     ///
     /// Calls the function with the `argument_list`, validating the call.
     ///
+    /// If the message is specified, it is a format string, whose `{}` placeholders are replaced
+    /// with the trailing argument values, like in `dbg!`.
+    ///
     pub fn call(
         self,
         location: Location,
         argument_list: ArgumentList,
-    ) -> Result<(Type, Option<String>), Error> {
+    ) -> Result<(Type, Option<String>, Vec<Type>), Error> {
         let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
         for (index, element) in argument_list.arguments.into_iter().enumerate() {
             let location = element.location();
@@ -130,22 +141,35 @@ impl Function {
             None => None,
         };
 
-        if actual_params.len() > Self::ARGUMENT_COUNT_OPTIONAL {
-            return Err(Error::ArgumentCount {
-                location,
-                function: self.identifier.to_owned(),
-                expected: Self::ARGUMENT_COUNT_OPTIONAL,
-                found: actual_params.len(),
-                reference: None,
-            });
+        if let Some(ref message) = string {
+            let arguments_expected_count = message.matches("{}").count();
+            if arguments_expected_count != actual_params.len() - Self::ARGUMENT_COUNT_OPTIONAL {
+                return Err(Error::Intrinsic(IntrinsicFunctionError::Require(
+                    RequireFunctionError::ArgumentCount {
+                        location,
+                        expected: arguments_expected_count + Self::ARGUMENT_COUNT_OPTIONAL,
+                        found: actual_params.len(),
+                    },
+                )));
+            }
         }
 
-        Ok((Type::unit(None), string))
+        let argument_types: Vec<Type> = actual_params
+            .into_iter()
+            .skip(Self::ARGUMENT_INDEX_VALUES)
+            .map(|(r#type, _is_constant, _string, _location)| r#type)
+            .collect();
+
+        Ok((Type::unit(None), string, argument_types))
     }
 }
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}(condition: bool, [message: str])", self.identifier)
+        write!(
+            f,
+            "{}(condition: bool, [message: str, ...])",
+            self.identifier
+        )
     }
 }
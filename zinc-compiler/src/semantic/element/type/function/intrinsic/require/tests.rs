@@ -7,6 +7,8 @@ use zinc_lexical::Location;
 use crate::error::Error;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::intrinsic::error::Error as IntrinsicFunctionError;
+use crate::semantic::element::r#type::function::intrinsic::require::error::Error as RequireFunctionError;
 use crate::semantic::element::r#type::function::intrinsic::require::Function as RequireFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
@@ -35,6 +37,19 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_formatted_message() {
+    let input = r#"
+fn main() {
+    let balance = 5;
+    let amount = 10;
+    require(balance >= amount, "insufficient balance: {} < {}", balance, amount);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_argument_count_greater() {
     let input = r#"
@@ -44,13 +59,36 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
-        TypeError::Function(FunctionError::ArgumentCount {
-            location: Location::test(3, 5),
-            function: RequireFunction::IDENTIFIER.to_owned(),
-            expected: RequireFunction::ARGUMENT_COUNT_OPTIONAL,
-            found: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 1,
-            reference: None,
-        }),
+        TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Require(
+            RequireFunctionError::ArgumentCount {
+                location: Location::test(3, 5),
+                expected: RequireFunction::ARGUMENT_COUNT_OPTIONAL,
+                found: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 1,
+            },
+        ))),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_lesser_than_placeholders() {
+    let input = r#"
+fn main() {
+    require(true, "{} {}", 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::Intrinsic(IntrinsicFunctionError::Require(
+            RequireFunctionError::ArgumentCount {
+                location: Location::test(3, 5),
+                expected: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 2,
+                found: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 1,
+            },
+        ))),
     ))));
 
     let result = crate::semantic::tests::compile_entry(input);
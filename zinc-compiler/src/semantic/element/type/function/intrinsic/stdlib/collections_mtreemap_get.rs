@@ -13,6 +13,7 @@ use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
+use crate::semantic::scope::intrinsic::IntrinsicScope;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 
 ///
@@ -147,10 +148,12 @@ impl Function {
             });
         }
 
-        Ok(Type::tuple(
-            None,
-            vec![value_type.to_owned(), Type::boolean(None)],
-        ))
+        let mut maybe = IntrinsicScope::type_maybe();
+        maybe
+            .set_generics(location, Some(vec![value_type.to_owned()]))
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        Ok(Type::Structure(maybe))
     }
 }
 
@@ -158,7 +161,7 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "collections::MTreeMap<K, V>::{}(key: K) -> (V, bool)",
+            "collections::MTreeMap<K, V>::{}(key: K) -> Maybe<V>",
             self.identifier
         )
     }
@@ -0,0 +1,202 @@
+//!
+//! The semantic analyzer standard library `std::convert::from_bytes_be` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer standard library `std::convert::from_bytes_be` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ConvertFromBytesBe,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "from_bytes_be";
+
+    /// The position of the `bytes` argument in the function argument list.
+    pub const ARGUMENT_INDEX_BYTES: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        let return_type = match actual_params.get(Self::ARGUMENT_INDEX_BYTES) {
+            Some((Type::Array(array), location)) => match (array.r#type.deref(), array.size) {
+                (
+                    Type::IntegerUnsigned {
+                        bitlength: zinc_const::bitlength::BYTE,
+                        ..
+                    },
+                    size,
+                ) if Self::is_size_valid(size) => {
+                    Type::integer_unsigned(None, size * zinc_const::bitlength::BYTE)
+                }
+                (r#type, size) => {
+                    return Err(Error::ArgumentType {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: "bytes".to_owned(),
+                        position: Self::ARGUMENT_INDEX_BYTES + 1,
+                        expected: Self::expected_type(),
+                        found: format!("array [{}; {}]", r#type, size),
+                    })
+                }
+            },
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bytes".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BYTES + 1,
+                    expected: Self::expected_type(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(return_type)
+    }
+
+    ///
+    /// Evaluates the function at compile time, if the `bytes` argument is a valid constant.
+    ///
+    pub fn fold(&self, location: Location, argument_list: &ArgumentList) -> Option<Constant> {
+        if argument_list.arguments.len() != Self::ARGUMENT_COUNT {
+            return None;
+        }
+
+        let array = match argument_list.arguments.get(Self::ARGUMENT_INDEX_BYTES) {
+            Some(Element::Constant(Constant::Array(array))) if Self::is_size_valid(array.len()) => {
+                array
+            }
+            _ => return None,
+        };
+
+        let mut value = BigInt::zero();
+        for byte in array.values.iter() {
+            match byte {
+                Constant::Integer(IntegerConstant {
+                    value: byte,
+                    is_signed: false,
+                    bitlength: zinc_const::bitlength::BYTE,
+                    enumeration: None,
+                    ..
+                }) => {
+                    value <<= zinc_const::bitlength::BYTE;
+                    value += byte;
+                }
+                _ => return None,
+            }
+        }
+
+        Some(Constant::Integer(IntegerConstant::new(
+            location,
+            value,
+            false,
+            array.len() * zinc_const::bitlength::BYTE,
+            false,
+        )))
+    }
+
+    ///
+    /// Checks if the byte array of `size` fits into the largest unsigned integer type.
+    ///
+    fn is_size_valid(size: usize) -> bool {
+        size > 0 && size * zinc_const::bitlength::BYTE <= zinc_const::bitlength::INTEGER_MAX
+    }
+
+    ///
+    /// The `bytes` argument type description used in the error messages.
+    ///
+    fn expected_type() -> String {
+        format!(
+            "[u8; N], 0 < N <= {}",
+            zinc_const::bitlength::INTEGER_MAX / zinc_const::bitlength::BYTE
+        )
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "convert::{}(bytes: [u8; N]) -> u{{N * 8}}",
+            self.identifier
+        )
+    }
+}
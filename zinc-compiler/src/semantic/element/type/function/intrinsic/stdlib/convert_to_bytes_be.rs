@@ -0,0 +1,186 @@
+//!
+//! The semantic analyzer standard library `std::convert::to_bytes_be` function element.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer standard library `std::convert::to_bytes_be` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ConvertToBytesBe,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "to_bytes_be";
+
+    /// The position of the `value` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        let return_type = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((r#type, argument_location)) => match Self::output_size(r#type) {
+                Some(size) => Type::array(
+                    Some(location),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    size,
+                ),
+                None => {
+                    return Err(Error::ArgumentType {
+                        location: argument_location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: "value".to_owned(),
+                        position: Self::ARGUMENT_INDEX_VALUE + 1,
+                        expected: "u{N}, field".to_owned(),
+                        found: r#type.to_string(),
+                    })
+                }
+            },
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(return_type)
+    }
+
+    ///
+    /// Evaluates the function at compile time, if the `value` argument is a valid constant.
+    ///
+    pub fn fold(&self, location: Location, argument_list: &ArgumentList) -> Option<Constant> {
+        if argument_list.arguments.len() != Self::ARGUMENT_COUNT {
+            return None;
+        }
+
+        let integer = match argument_list.arguments.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some(Element::Constant(Constant::Integer(integer))) => integer,
+            _ => return None,
+        };
+        let size = Self::output_size(&integer.r#type())?;
+
+        let (_sign, bytes) = integer.value.to_bytes_be();
+        let padding = size.checked_sub(bytes.len())?;
+
+        let values = vec![0; padding]
+            .into_iter()
+            .chain(bytes.into_iter())
+            .map(|byte| {
+                Constant::Integer(IntegerConstant::new(
+                    location,
+                    BigInt::from(byte),
+                    false,
+                    zinc_const::bitlength::BYTE,
+                    false,
+                ))
+            })
+            .collect();
+
+        Some(Constant::Array(ArrayConstant::new_with_values(
+            location,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            values,
+        )))
+    }
+
+    ///
+    /// Returns the byte array size for the `value` argument of type `r#type`.
+    ///
+    /// The field elements are padded to the whole number of bytes.
+    ///
+    fn output_size(r#type: &Type) -> Option<usize> {
+        match r#type {
+            Type::IntegerUnsigned { bitlength, .. }
+                if bitlength % zinc_const::bitlength::BYTE == 0 =>
+            {
+                Some(bitlength / zinc_const::bitlength::BYTE)
+            }
+            Type::Field(_) => {
+                Some(zinc_const::bitlength::FIELD_PADDED / zinc_const::bitlength::BYTE)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "convert::{}(value: T) -> [u8; bitlength(T) / 8]",
+            self.identifier,
+        )
+    }
+}
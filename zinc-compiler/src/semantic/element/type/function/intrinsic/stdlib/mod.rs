@@ -15,12 +15,15 @@ pub mod collections_mtreemap_remove;
 pub mod convert_from_bits_field;
 pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
+pub mod convert_from_bytes_be;
 pub mod convert_to_bits;
+pub mod convert_to_bytes_be;
 pub mod crypto_pedersen;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod error;
 pub mod ff_invert;
+pub mod maybe_unwrap_or;
 
 use std::fmt;
 
@@ -28,6 +31,7 @@ use zinc_build::LibraryFunctionIdentifier;
 use zinc_lexical::Location;
 
 use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 
@@ -41,11 +45,14 @@ use self::collections_mtreemap_remove::Function as MTreeMapRemoveFunction;
 use self::convert_from_bits_field::Function as FromBitsFieldFunction;
 use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
+use self::convert_from_bytes_be::Function as FromBytesBeFunction;
 use self::convert_to_bits::Function as ToBitsFunction;
+use self::convert_to_bytes_be::Function as ToBytesBeFunction;
 use self::crypto_pedersen::Function as PedersenFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
 use self::ff_invert::Function as FfInvertFunction;
+use self::maybe_unwrap_or::Function as MaybeUnwrapOrFunction;
 
 ///
 /// The semantic analyzer standard library function element.
@@ -67,6 +74,10 @@ pub enum Function {
     ConvertFromBitsSigned(FromBitsSignedFunction),
     /// The `std::convert::from_bits_field` function variant.
     ConvertFromBitsField(FromBitsFieldFunction),
+    /// The `std::convert::to_bytes_be` function variant.
+    ConvertToBytesBe(ToBytesBeFunction),
+    /// The `std::convert::from_bytes_be` function variant.
+    ConvertFromBytesBe(FromBytesBeFunction),
 
     /// The `std::array::reverse` function variant.
     ArrayReverse(ArrayReverseFunction),
@@ -86,6 +97,9 @@ pub enum Function {
     CollectionsMTreeMapInsert(MTreeMapInsertFunction),
     /// The `std::collections::MTreeMap::remove` function variant.
     CollectionsMTreeMapRemove(MTreeMapRemoveFunction),
+
+    /// The `Maybe::unwrap_or` function variant.
+    MaybeUnwrapOr(MaybeUnwrapOrFunction),
 }
 
 impl Function {
@@ -102,6 +116,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsSigned(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsField(inner) => inner.call(location, argument_list),
+            Self::ConvertToBytesBe(inner) => inner.call(location, argument_list),
+            Self::ConvertFromBytesBe(inner) => inner.call(location, argument_list),
 
             Self::ArrayReverse(inner) => inner.call(location, argument_list),
             Self::ArrayTruncate(inner) => inner.call(location, argument_list),
@@ -113,6 +129,20 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapInsert(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapRemove(inner) => inner.call(location, argument_list),
+
+            Self::MaybeUnwrapOr(inner) => inner.call(location, argument_list),
+        }
+    }
+
+    ///
+    /// Evaluates the function at compile time, if it is supported by the function and
+    /// the `argument_list` consists of constants only.
+    ///
+    pub fn fold(&self, location: Location, argument_list: &ArgumentList) -> Option<Constant> {
+        match self {
+            Self::ConvertToBytesBe(inner) => inner.fold(location, argument_list),
+            Self::ConvertFromBytesBe(inner) => inner.fold(location, argument_list),
+            _ => None,
         }
     }
 
@@ -129,6 +159,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(inner) => inner.identifier,
             Self::ConvertFromBitsSigned(inner) => inner.identifier,
             Self::ConvertFromBitsField(inner) => inner.identifier,
+            Self::ConvertToBytesBe(inner) => inner.identifier,
+            Self::ConvertFromBytesBe(inner) => inner.identifier,
 
             Self::ArrayReverse(inner) => inner.identifier,
             Self::ArrayTruncate(inner) => inner.identifier,
@@ -140,6 +172,8 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.identifier,
             Self::CollectionsMTreeMapInsert(inner) => inner.identifier,
             Self::CollectionsMTreeMapRemove(inner) => inner.identifier,
+
+            Self::MaybeUnwrapOr(inner) => inner.identifier,
         }
     }
 
@@ -156,6 +190,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(inner) => inner.library_identifier,
             Self::ConvertFromBitsSigned(inner) => inner.library_identifier,
             Self::ConvertFromBitsField(inner) => inner.library_identifier,
+            Self::ConvertToBytesBe(inner) => inner.library_identifier,
+            Self::ConvertFromBytesBe(inner) => inner.library_identifier,
 
             Self::ArrayReverse(inner) => inner.library_identifier,
             Self::ArrayTruncate(inner) => inner.library_identifier,
@@ -167,6 +203,8 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.library_identifier,
             Self::CollectionsMTreeMapInsert(inner) => inner.library_identifier,
             Self::CollectionsMTreeMapRemove(inner) => inner.library_identifier,
+
+            Self::MaybeUnwrapOr(inner) => inner.library_identifier,
         }
     }
 
@@ -183,6 +221,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(_) => false,
             Self::ConvertFromBitsSigned(_) => false,
             Self::ConvertFromBitsField(_) => false,
+            Self::ConvertToBytesBe(_) => false,
+            Self::ConvertFromBytesBe(_) => false,
 
             Self::ArrayReverse(_) => false,
             Self::ArrayTruncate(_) => false,
@@ -194,6 +234,8 @@ impl Function {
             Self::CollectionsMTreeMapContains(_) => false,
             Self::CollectionsMTreeMapInsert(_) => true,
             Self::CollectionsMTreeMapRemove(_) => true,
+
+            Self::MaybeUnwrapOr(_) => false,
         }
     }
 
@@ -210,6 +252,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(inner) => inner.location = Some(location),
             Self::ConvertFromBitsSigned(inner) => inner.location = Some(location),
             Self::ConvertFromBitsField(inner) => inner.location = Some(location),
+            Self::ConvertToBytesBe(inner) => inner.location = Some(location),
+            Self::ConvertFromBytesBe(inner) => inner.location = Some(location),
 
             Self::ArrayReverse(inner) => inner.location = Some(location),
             Self::ArrayTruncate(inner) => inner.location = Some(location),
@@ -221,6 +265,8 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.location = Some(location),
             Self::CollectionsMTreeMapInsert(inner) => inner.location = Some(location),
             Self::CollectionsMTreeMapRemove(inner) => inner.location = Some(location),
+
+            Self::MaybeUnwrapOr(inner) => inner.location = Some(location),
         }
     }
 
@@ -237,6 +283,8 @@ impl Function {
             Self::ConvertFromBitsUnsigned(inner) => inner.location,
             Self::ConvertFromBitsSigned(inner) => inner.location,
             Self::ConvertFromBitsField(inner) => inner.location,
+            Self::ConvertToBytesBe(inner) => inner.location,
+            Self::ConvertFromBytesBe(inner) => inner.location,
 
             Self::ArrayReverse(inner) => inner.location,
             Self::ArrayTruncate(inner) => inner.location,
@@ -248,6 +296,8 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.location,
             Self::CollectionsMTreeMapInsert(inner) => inner.location,
             Self::CollectionsMTreeMapRemove(inner) => inner.location,
+
+            Self::MaybeUnwrapOr(inner) => inner.location,
         }
     }
 }
@@ -263,6 +313,8 @@ impl fmt::Display for Function {
             Self::ConvertFromBitsUnsigned(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsSigned(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsField(inner) => write!(f, "{}", inner),
+            Self::ConvertToBytesBe(inner) => write!(f, "{}", inner),
+            Self::ConvertFromBytesBe(inner) => write!(f, "{}", inner),
 
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
@@ -274,6 +326,8 @@ impl fmt::Display for Function {
             Self::CollectionsMTreeMapContains(inner) => write!(f, "{}", inner),
             Self::CollectionsMTreeMapInsert(inner) => write!(f, "{}", inner),
             Self::CollectionsMTreeMapRemove(inner) => write!(f, "{}", inner),
+
+            Self::MaybeUnwrapOr(inner) => write!(f, "{}", inner),
         }
     }
 }
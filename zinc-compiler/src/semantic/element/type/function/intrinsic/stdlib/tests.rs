@@ -24,7 +24,9 @@ use crate::semantic::element::r#type::function::intrinsic::stdlib::array_truncat
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_field::Function as ConvertFromBitsFieldFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_signed::Function as ConvertFromBitsSignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bytes_be::Function as ConvertFromBytesBeFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ConvertToBitsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bytes_be::Function as ConvertToBytesBeFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as CryptoPedersenFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as CryptoSha256Function;
@@ -49,9 +51,10 @@ contract Data {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
-        TypeError::Function(FunctionError::CallingMutableFromImmutable {
+        TypeError::Function(FunctionError::CallingMutableFromQuery {
             location: Location::test(8, 25),
             function: CollectionsMTreeMapInsertFunction::IDENTIFIER.to_owned(),
+            method: "immutable".to_owned(),
         }),
     ))));
 
@@ -75,9 +78,10 @@ contract Data {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
-        TypeError::Function(FunctionError::CallingMutableFromImmutable {
+        TypeError::Function(FunctionError::CallingMutableFromQuery {
             location: Location::test(8, 25),
             function: CollectionsMTreeMapRemoveFunction::IDENTIFIER.to_owned(),
+            method: "immutable".to_owned(),
         }),
     ))));
 
@@ -1113,6 +1117,135 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_convert_to_bytes_be_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::convert::to_bytes_be();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: ConvertToBytesBeFunction::IDENTIFIER.to_owned(),
+            expected: ConvertToBytesBeFunction::ARGUMENT_COUNT,
+            found: ConvertToBytesBeFunction::ARGUMENT_COUNT - 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_to_bytes_be_argument_1_value_expected_unsigned_integer() {
+    let input = r#"
+fn main() {
+    std::convert::to_bytes_be(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 31),
+            function: ConvertToBytesBeFunction::IDENTIFIER.to_owned(),
+            name: "value".to_owned(),
+            position: ConvertToBytesBeFunction::ARGUMENT_INDEX_VALUE + 1,
+            expected: "u{N}, field".to_owned(),
+            found: Type::boolean(None).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_from_bytes_be_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::convert::from_bytes_be([0; 2], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(3, 5),
+            function: ConvertFromBytesBeFunction::IDENTIFIER.to_owned(),
+            expected: ConvertFromBytesBeFunction::ARGUMENT_COUNT,
+            found: ConvertFromBytesBeFunction::ARGUMENT_COUNT + 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_from_bytes_be_argument_1_bytes_expected_byte_array() {
+    let input = r#"
+fn main() {
+    std::convert::from_bytes_be([false; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 33),
+            function: ConvertFromBytesBeFunction::IDENTIFIER.to_owned(),
+            name: "bytes".to_owned(),
+            position: ConvertFromBytesBeFunction::ARGUMENT_INDEX_BYTES + 1,
+            expected: format!(
+                "[u8; N], 0 < N <= {}",
+                zinc_const::bitlength::INTEGER_MAX / zinc_const::bitlength::BYTE
+            ),
+            found: Type::array(Some(Location::test(3, 33)), Type::boolean(None), 8).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_from_bytes_be_argument_1_bytes_expected_byte_array_size_integer() {
+    let input = r#"
+fn main() {
+    std::convert::from_bytes_be([0; 32]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(3, 33),
+            function: ConvertFromBytesBeFunction::IDENTIFIER.to_owned(),
+            name: "bytes".to_owned(),
+            position: ConvertFromBytesBeFunction::ARGUMENT_INDEX_BYTES + 1,
+            expected: format!(
+                "[u8; N], 0 < N <= {}",
+                zinc_const::bitlength::INTEGER_MAX / zinc_const::bitlength::BYTE
+            ),
+            found: Type::array(
+                Some(Location::test(3, 33)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                zinc_const::bitlength::FIELD_PADDED / zinc_const::bitlength::BYTE,
+            )
+            .to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_reverse_argument_count_lesser() {
     let input = r#"
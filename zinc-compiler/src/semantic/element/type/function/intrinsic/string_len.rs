@@ -0,0 +1,103 @@
+//!
+//! The semantic analyzer `str::len` intrinsic function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer `str::len` intrinsic function element.
+///
+/// The strings only exist at compile time, so the function is always evaluated by the compiler.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "len";
+
+    /// The position of the `self` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SELF: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the string length in bytes as an integer literal constant.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Constant, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, string) = match element {
+                Element::Value(value) => (value.r#type(), None),
+                Element::Constant(Constant::String(inner)) => (inner.r#type(), Some(inner)),
+                Element::Constant(constant) => (constant.r#type(), None),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, string, location));
+        }
+
+        if actual_params.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        match actual_params.remove(Self::ARGUMENT_INDEX_SELF) {
+            (_type, Some(string), _location) => Ok(Constant::Integer(string.len(location))),
+            (r#type, None, location) => Err(Error::ArgumentType {
+                location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                function: self.identifier.to_owned(),
+                name: "self".to_owned(),
+                position: Self::ARGUMENT_INDEX_SELF + 1,
+                expected: Type::string(None).to_string(),
+                found: r#type.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "str::{}(self) -> integer", self.identifier)
+    }
+}
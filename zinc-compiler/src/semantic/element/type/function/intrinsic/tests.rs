@@ -54,3 +54,20 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_string_len() {
+    let input = r#"
+const GREETING: str = "hello" + " " + "world";
+const LENGTH: u8 = GREETING.len();
+
+fn main() -> u8 {
+    require("hello".len() == 5);
+    require(GREETING.len() == LENGTH, GREETING);
+
+    LENGTH
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
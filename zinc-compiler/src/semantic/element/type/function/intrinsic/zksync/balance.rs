@@ -0,0 +1,153 @@
+//!
+//! The semantic analyzer `zksync` library `balance` function element.
+//!
+
+use std::fmt;
+
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The semantic analyzer `zksync` library `balance` function element.
+///
+/// The function is declared in every contract scope, so it is called as `self.balance(token)`.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ZksyncBalance,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "balance";
+
+    /// The position of the `self` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SELF: usize = 0;
+
+    /// The position of the `token_address` argument in the function argument list.
+    pub const ARGUMENT_INDEX_TOKEN_ADDRESS: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::ArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_SELF) {
+            Some((Type::Contract(_), _location)) => {}
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: Keyword::SelfLowercase.to_string(),
+                    position: Self::ARGUMENT_INDEX_SELF + 1,
+                    expected: "contract".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_TOKEN_ADDRESS) {
+            Some((
+                Type::IntegerUnsigned {
+                    bitlength: zinc_const::bitlength::ETH_ADDRESS,
+                    ..
+                },
+                _location,
+            )) => {}
+            Some((r#type, location)) => {
+                return Err(Error::ArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "token_address".to_owned(),
+                    position: Self::ARGUMENT_INDEX_TOKEN_ADDRESS + 1,
+                    expected: Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS)
+                        .to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::ArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::ArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::integer_unsigned(
+            self.location,
+            zinc_const::bitlength::BALANCE,
+        ))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(self, token_address: u160) -> u248", self.identifier)
+    }
+}
@@ -5,6 +5,7 @@
 #[cfg(test)]
 mod tests;
 
+pub mod balance;
 pub mod transfer;
 
 use std::fmt;
@@ -16,6 +17,7 @@ use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 
+use self::balance::Function as BalanceFunction;
 use self::transfer::Function as TransferFunction;
 
 ///
@@ -25,6 +27,8 @@ use self::transfer::Function as TransferFunction;
 pub enum Function {
     /// The `zksync::transfer` function variant.
     Transfer(TransferFunction),
+    /// The `self.balance` contract function variant.
+    Balance(BalanceFunction),
 }
 
 impl Function {
@@ -34,6 +38,7 @@ impl Function {
     pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
         match self {
             Self::Transfer(inner) => inner.call(location, argument_list),
+            Self::Balance(inner) => inner.call(location, argument_list),
         }
     }
 
@@ -43,6 +48,7 @@ impl Function {
     pub fn identifier(&self) -> &'static str {
         match self {
             Self::Transfer(inner) => inner.identifier,
+            Self::Balance(inner) => inner.identifier,
         }
     }
 
@@ -52,6 +58,7 @@ impl Function {
     pub fn library_identifier(&self) -> LibraryFunctionIdentifier {
         match self {
             Self::Transfer(inner) => inner.library_identifier,
+            Self::Balance(inner) => inner.library_identifier,
         }
     }
 
@@ -61,6 +68,7 @@ impl Function {
     pub fn is_mutable(&self) -> bool {
         match self {
             Self::Transfer(_) => true,
+            Self::Balance(_) => false,
         }
     }
 
@@ -70,6 +78,7 @@ impl Function {
     pub fn set_location(&mut self, location: Location) {
         match self {
             Self::Transfer(inner) => inner.location = Some(location),
+            Self::Balance(inner) => inner.location = Some(location),
         }
     }
 
@@ -79,6 +88,7 @@ impl Function {
     pub fn location(&self) -> Option<Location> {
         match self {
             Self::Transfer(inner) => inner.location,
+            Self::Balance(inner) => inner.location,
         }
     }
 }
@@ -87,6 +97,7 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Transfer(inner) => write!(f, "{}", inner),
+            Self::Balance(inner) => write!(f, "{}", inner),
         }
     }
 }
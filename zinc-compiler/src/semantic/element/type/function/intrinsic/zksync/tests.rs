@@ -7,6 +7,7 @@ use zinc_lexical::Location;
 use crate::error::Error;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionError;
+use crate::semantic::element::r#type::function::intrinsic::zksync::balance::Function as ZksyncBalanceFunction;
 use crate::semantic::element::r#type::function::intrinsic::zksync::transfer::Function as ZksyncTransferFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
@@ -129,3 +130,54 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_balance_argument_count_greater() {
+    let input = r#"
+contract Test {
+    pub fn main(self) -> u248 {
+        self.balance(0x0 as u160, 0x0 as u160)
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentCount {
+            location: Location::test(4, 21),
+            function: ZksyncBalanceFunction::IDENTIFIER.to_owned(),
+            expected: ZksyncBalanceFunction::ARGUMENT_COUNT,
+            found: ZksyncBalanceFunction::ARGUMENT_COUNT + 1,
+            reference: None,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_balance_argument_2_token_address_expected_u160() {
+    let input = r#"
+contract Test {
+    pub fn main(self) -> u248 {
+        self.balance(0x0 as u8)
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::ArgumentType {
+            location: Location::test(4, 22),
+            function: ZksyncBalanceFunction::IDENTIFIER.to_owned(),
+            name: "token_address".to_owned(),
+            position: ZksyncBalanceFunction::ARGUMENT_INDEX_TOKEN_ADDRESS + 1,
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS).to_string(),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
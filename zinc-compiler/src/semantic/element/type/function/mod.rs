@@ -4,20 +4,28 @@
 
 pub mod constant;
 pub mod error;
+pub mod generic;
 pub mod intrinsic;
 pub mod runtime;
 pub mod test;
 
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 
 use zinc_build::LibraryFunctionIdentifier;
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::FnStatement;
 
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::Scope;
 
 use self::constant::Function as ConstantFunction;
+use self::generic::Function as GenericFunction;
 use self::intrinsic::Function as IntrinsicFunction;
 use self::runtime::Function as RuntimeFunction;
 use self::test::Function as TestFunction;
@@ -35,6 +43,9 @@ pub enum Function {
     /// Runtime functions declared anywhere within a project. There is a special `main` function,
     /// which is also declared by user, but serves as the circuit entry point.
     Runtime(RuntimeFunction),
+    /// Generic runtime functions declared with type parameters. They are monomorphized at each
+    /// call site, that is, a separate runtime function is analyzed for each set of type arguments.
+    Generic(GenericFunction),
     /// Constant functions declared anywhere within a project. There are executed at compile-time
     /// only and do not produce the intermediate representation.
     Constant(ConstantFunction),
@@ -65,6 +76,20 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::new_library(identifier))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_mem_size_of() -> Self {
+        Self::Intrinsic(IntrinsicFunction::new_mem_size_of())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_mem_field_count_of() -> Self {
+        Self::Intrinsic(IntrinsicFunction::new_mem_field_count_of())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -84,6 +109,21 @@ impl Function {
         ))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_generic(
+        location: Location,
+        statement: FnStatement,
+        context: FnStatementAnalyzerContext,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self::Generic(GenericFunction::new(
+            location, statement, context, attributes, scope,
+        ))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -119,6 +159,7 @@ impl Function {
         match self {
             Self::Intrinsic(inner) => inner.identifier().to_owned(),
             Self::Runtime(inner) => inner.identifier.to_owned(),
+            Self::Generic(inner) => inner.identifier.to_owned(),
             Self::Constant(inner) => inner.identifier.to_owned(),
             Self::Test(inner) => inner.identifier.to_owned(),
         }
@@ -131,6 +172,7 @@ impl Function {
         match self {
             Self::Intrinsic(inner) => inner.is_mutable(),
             Self::Runtime(inner) => inner.is_mutable(),
+            Self::Generic(inner) => inner.is_mutable(),
             Self::Constant(inner) => inner.is_mutable(),
             Self::Test(_) => false,
         }
@@ -143,6 +185,7 @@ impl Function {
         match self {
             Self::Intrinsic(inner) => inner.set_location(value),
             Self::Runtime(inner) => inner.location = value,
+            Self::Generic(inner) => inner.location = value,
             Self::Constant(inner) => inner.location = value,
             Self::Test(inner) => inner.location = value,
         }
@@ -155,6 +198,7 @@ impl Function {
         match self {
             Self::Intrinsic(inner) => inner.location(),
             Self::Runtime(inner) => Some(inner.location),
+            Self::Generic(inner) => Some(inner.location),
             Self::Constant(inner) => Some(inner.location),
             Self::Test(inner) => Some(inner.location),
         }
@@ -166,6 +210,7 @@ impl fmt::Display for Function {
         match self {
             Self::Intrinsic(inner) => write!(f, "{}", inner),
             Self::Runtime(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
             Self::Constant(inner) => write!(f, "{}", inner),
             Self::Test(inner) => write!(f, "{}", inner),
         }
@@ -296,9 +296,137 @@ contract Data {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
-        TypeError::Function(FunctionError::CallingMutableFromImmutable {
+        TypeError::Function(FunctionError::CallingMutableFromQuery {
             location: Location::test(6, 21),
             function: "mutable".to_owned(),
+            method: "immutable".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_calling_mutable_from_query_associated() {
+    let input = r#"
+contract Data {
+    value: u8;
+
+    pub fn immutable(self) -> u8 {
+        Self::mutable(self)
+    }
+
+    pub fn mutable(mut self) -> u8 {
+        self.value = 0;
+        self.value
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::CallingMutableFromQuery {
+            location: Location::test(6, 22),
+            function: "mutable".to_owned(),
+            method: "immutable".to_owned(),
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_calling_mutable_on_storage_array_element() {
+    let input = r#"
+struct Counter {
+    value: u8,
+}
+
+impl Counter {
+    pub fn bump(mut self) -> u8 {
+        self.value += 1;
+        self.value
+    }
+}
+
+contract Data {
+    counters: [Counter; 4];
+
+    pub fn constant(mut self) -> u8 {
+        self.counters[1].bump()
+    }
+
+    pub fn runtime(mut self, index: u8) -> u8 {
+        self.counters[index].bump()
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_calling_mutable_on_storage_nested_structure() {
+    let input = r#"
+struct Counter {
+    value: u8,
+}
+
+impl Counter {
+    pub fn bump(mut self) -> u8 {
+        self.value += 1;
+        self.value
+    }
+}
+
+struct Pair {
+    left: Counter,
+    right: Counter,
+}
+
+contract Data {
+    pairs: [Pair; 2];
+
+    pub fn main(mut self, index: u8) -> u8 {
+        self.pairs[index].right.bump()
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_calling_mutable_on_storage_array_element_from_immutable() {
+    let input = r#"
+struct Counter {
+    value: u8,
+}
+
+impl Counter {
+    pub fn bump(mut self) -> u8 {
+        self.value += 1;
+        self.value
+    }
+}
+
+contract Data {
+    counters: [Counter; 4];
+
+    pub fn immutable(self) -> u8 {
+        self.counters[1].bump()
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Function(FunctionError::CallingMutableFromQuery {
+            location: Location::test(17, 30),
+            function: "bump".to_owned(),
+            method: "immutable".to_owned(),
         }),
     ))));
 
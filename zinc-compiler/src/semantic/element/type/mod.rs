@@ -23,13 +23,16 @@ use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::FnStatement;
 use zinc_syntax::Type as SyntaxType;
 use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 use zinc_syntax::Variant;
 
+use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::r#fn::Context as FnStatementAnalyzerContext;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::Constant;
@@ -203,6 +206,13 @@ impl Type {
         Self::Tuple(Tuple::new(location, types))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn named_tuple(location: Option<Location>, types: Vec<Self>, names: Vec<String>) -> Self {
+        Self::Tuple(Tuple::new_named(location, types, names))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -281,6 +291,23 @@ impl Type {
         ))
     }
 
+    ///
+    /// A helper type constructor.
+    ///
+    /// The unique sequence IDs are allocated for each instance of the generic function.
+    ///
+    pub fn generic_function(
+        location: Location,
+        statement: FnStatement,
+        context: FnStatementAnalyzerContext,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self::Function(Function::new_generic(
+            location, statement, context, attributes, scope,
+        ))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -330,7 +357,7 @@ impl Type {
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
-            Self::Enumeration(_inner) => 1,
+            Self::Enumeration(inner) => inner.size(),
             Self::Contract(_inner) => 0,
             Self::Function(_inner) => 0,
         }
@@ -345,21 +372,21 @@ impl Type {
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.has_payloads(),
             _ => false,
         }
     }
 
     ///
     /// Checks if the type is an unsigned scalar one (booleans, unsigned integers, fields and
-    /// enumeration values).
+    /// enumeration values without payloads).
     ///
     pub fn is_scalar_unsigned(&self) -> bool {
         match self {
             Self::Boolean(_) => true,
             Self::IntegerUnsigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.has_payloads(),
             _ => false,
         }
     }
@@ -372,13 +399,14 @@ impl Type {
     }
 
     ///
-    /// Checks if the type is an unsigned integer one (unsigned integers, fields and enumeration values).
+    /// Checks if the type is an unsigned integer one (unsigned integers, fields and enumeration
+    /// values without payloads).
     ///
     pub fn is_integer_unsigned(&self) -> bool {
         match self {
             Self::IntegerUnsigned { .. } => true,
             Self::Field(_) => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(inner) => !inner.has_payloads(),
             _ => false,
         }
     }
@@ -421,6 +449,7 @@ impl Type {
     pub fn is_source_function(&self) -> bool {
         match self {
             Self::Function(Function::Runtime(_)) => true,
+            Self::Function(Function::Generic(_)) => true,
             Self::Function(Function::Constant(_)) => true,
             _ => false,
         }
@@ -441,7 +470,9 @@ impl Type {
             Self::String(_) => false,
             Self::Range(_) => false,
             Self::RangeInclusive(_) => false,
-            Self::Array(inner) => inner.r#type.is_instantiatable(false),
+            Self::Array(inner) => inner
+                .r#type
+                .is_instantiatable(is_contract_field && inner.r#type.is_mtreemap()),
             Self::Tuple(inner) => inner
                 .types
                 .iter()
@@ -471,6 +502,33 @@ impl Type {
         }
     }
 
+    ///
+    /// Returns the first inner type, which cannot be compared with the `==` and `!=` operators,
+    /// that is, a string, range, function, contract, or map. Returns `None` for comparable types.
+    ///
+    pub fn find_incomparable(&self) -> Option<&Self> {
+        match self {
+            Self::Unit(_) => None,
+            Self::Boolean(_) => None,
+            Self::IntegerUnsigned { .. } => None,
+            Self::IntegerSigned { .. } => None,
+            Self::Field(_) => None,
+            Self::String(_) => Some(self),
+            Self::Range(_) => Some(self),
+            Self::RangeInclusive(_) => Some(self),
+            Self::Array(inner) => inner.r#type.find_incomparable(),
+            Self::Tuple(inner) => inner.types.iter().find_map(Self::find_incomparable),
+            Self::Structure(_) if self.is_mtreemap() => Some(self),
+            Self::Structure(inner) => inner
+                .fields
+                .iter()
+                .find_map(|(_name, r#type)| r#type.find_incomparable()),
+            Self::Enumeration(_) => None,
+            Self::Function(_) => Some(self),
+            Self::Contract(_) => Some(self),
+        }
+    }
+
     ///
     /// Checks if the type is an `std::collections::MTreeMap`, which is treated specially.
     ///
@@ -482,6 +540,52 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the type is an array of `std::collections::MTreeMap`s, which is allowed only
+    /// as a contract storage field.
+    ///
+    pub fn is_mtreemap_array(&self) -> bool {
+        if let Self::Array(array) = self {
+            array.r#type.is_mtreemap()
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// The number of the contract storage Merkle tree leaves occupied by a field of the type.
+    ///
+    /// Each map occupies a separate leaf, so an array of maps occupies a leaf per element.
+    ///
+    pub fn storage_leaves_count(&self) -> usize {
+        match self {
+            Self::Array(array) if array.r#type.is_mtreemap() => array.size,
+            _ => 1,
+        }
+    }
+
+    ///
+    /// Checks if the type is a `Maybe<T>`, which is treated specially.
+    ///
+    pub fn is_maybe(&self) -> bool {
+        if let Self::Structure(structure) = self {
+            structure.type_id == IntrinsicTypeId::Maybe as usize
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Checks if the type is a `ufixed{N}x{D}` or `fixed{N}x{D}` fixed-point decimal.
+    ///
+    pub fn is_fixed(&self) -> bool {
+        if let Self::Structure(structure) = self {
+            structure.is_fixed()
+        } else {
+            false
+        }
+    }
+
     ///
     /// Sets the generic arguments for the type.
     ///
@@ -526,7 +630,18 @@ impl Type {
             SyntaxTypeVariant::IntegerSigned { bitlength } => {
                 Self::integer_signed(Some(location), bitlength)
             }
+            SyntaxTypeVariant::Fixed {
+                is_signed,
+                bitlength,
+                decimals,
+            } => Self::Structure(
+                Structure::new_fixed(location, is_signed, bitlength, decimals)
+                    .map_err(TypeError::Structure)
+                    .map_err(ElementError::Type)
+                    .map_err(Error::Element)?,
+            ),
             SyntaxTypeVariant::Field => Self::field(Some(location)),
+            SyntaxTypeVariant::String => Self::string(Some(location)),
             SyntaxTypeVariant::Array { inner, size } => {
                 let r#type = Self::try_from_syntax(*inner, scope.clone())?;
 
@@ -556,6 +671,24 @@ impl Type {
                 }
                 Self::tuple(Some(location), types)
             }
+            SyntaxTypeVariant::NamedTuple { fields } => {
+                let mut names: Vec<String> = Vec::with_capacity(fields.len());
+                let mut types = Vec::with_capacity(fields.len());
+                for (identifier, inner) in fields.into_iter() {
+                    if names.contains(&identifier.name) {
+                        return Err(Error::Element(ElementError::Type(
+                            TypeError::DuplicateTupleElement {
+                                location: identifier.location,
+                                name: identifier.name,
+                            },
+                        )));
+                    }
+
+                    names.push(identifier.name);
+                    types.push(Self::try_from_syntax(inner, scope.clone())?);
+                }
+                Self::named_tuple(Some(location), types, names)
+            }
             SyntaxTypeVariant::Alias { path, generics } => {
                 let location = path.location;
                 match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Type).analyze(path)? {
@@ -714,6 +847,7 @@ impl fmt::Display for Type {
             Self::RangeInclusive(inner) => write!(f, "range inclusive {}", inner),
             Self::Array(inner) => write!(f, "array {}", inner),
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
+            Self::Structure(inner) if inner.is_fixed() => write!(f, "{}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
             Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Function(inner) => write!(f, "function {}", inner),
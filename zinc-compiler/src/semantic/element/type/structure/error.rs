@@ -45,4 +45,26 @@ pub enum Error {
         /// The number of found generics.
         found: usize,
     },
+    /// The fixed-point decimal bitlength is too big to multiply the values.
+    FixedBitlengthOutOfRange {
+        /// The type location.
+        location: Location,
+        /// The fixed-point decimal type name.
+        type_identifier: String,
+        /// The found bitlength.
+        found: usize,
+        /// The maximal allowed bitlength.
+        maximum: usize,
+    },
+    /// The fixed-point decimal scale does not fit into the underlying integer type.
+    FixedDecimalsOutOfRange {
+        /// The type location.
+        location: Location,
+        /// The fixed-point decimal type name.
+        type_identifier: String,
+        /// The found number of decimals.
+        found: usize,
+        /// The maximal allowed number of decimals.
+        maximum: usize,
+    },
 }
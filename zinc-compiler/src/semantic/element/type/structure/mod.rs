@@ -12,9 +12,13 @@ use std::collections::HashMap;
 use std::fmt;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::One;
+
 use zinc_lexical::Location;
 
 use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::semantic::scope::Scope;
 
 use self::error::Error;
@@ -40,11 +44,30 @@ pub struct Structure {
     /// The structure generic type actual arguments.
     /// These are set upon the structure value initialization, where arguments are set in `<...>`.
     pub params: Option<HashMap<String, Type>>,
+    /// The number of decimal fractional digits, if the structure is a fixed-point decimal.
+    pub decimals: Option<usize>,
+    /// Whether the structure is a newtype like `struct Wei(u248);`, whose only field is `0`.
+    pub is_newtype: bool,
     /// The structure scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
 }
 
 impl Structure {
+    /// The `Maybe<T>` flag field name, which is set for the `Some(T)` variant.
+    pub const MAYBE_FIELD_IS_SOME: &'static str = "is_some";
+
+    /// The `Maybe<T>` payload field name, which is zero-filled for the `None` variant.
+    pub const MAYBE_FIELD_VALUE: &'static str = "value";
+
+    /// The fixed-point decimal field name, which holds the value scaled by `10^D`.
+    pub const FIXED_FIELD_RAW: &'static str = "raw";
+
+    /// The newtype single field name, which is accessed with `.0`.
+    pub const NEWTYPE_FIELD: &'static str = "0";
+
+    /// The maximal fixed-point decimal bitlength, whose product still fits into an integer.
+    pub const FIXED_BITLENGTH_MAX: usize = zinc_const::bitlength::INTEGER_MAX / 2;
+
     ///
     /// A shortcut constructor.
     ///
@@ -66,10 +89,130 @@ impl Structure {
             fields,
             generics,
             params,
+            decimals: None,
+            is_newtype: false,
             scope,
         }
     }
 
+    ///
+    /// Creates the `ufixed{N}x{D}` or `fixed{N}x{D}` fixed-point decimal type, which is a
+    /// structure with a single integer field holding the value scaled by `10^D`.
+    ///
+    /// The bitlength must allow multiplying two values without overflowing the integer range,
+    /// and the scale `10^D` must fit into the underlying integer type.
+    ///
+    pub fn new_fixed(
+        location: Location,
+        is_signed: bool,
+        bitlength: usize,
+        decimals: usize,
+    ) -> Result<Self, Error> {
+        let identifier = format!(
+            "{}fixed{}x{}",
+            if is_signed { "" } else { "u" },
+            bitlength,
+            decimals
+        );
+
+        if bitlength > Self::FIXED_BITLENGTH_MAX {
+            return Err(Error::FixedBitlengthOutOfRange {
+                location,
+                type_identifier: identifier,
+                found: bitlength,
+                maximum: Self::FIXED_BITLENGTH_MAX,
+            });
+        }
+
+        let maximum = Self::fixed_decimals_max(is_signed, bitlength);
+        if decimals > maximum {
+            return Err(Error::FixedDecimalsOutOfRange {
+                location,
+                type_identifier: identifier,
+                found: decimals,
+                maximum,
+            });
+        }
+
+        let mut structure = Self::new(
+            Some(location),
+            identifier,
+            IntrinsicTypeId::Fixed as usize,
+            vec![(
+                Self::FIXED_FIELD_RAW.to_owned(),
+                Type::integer(None, is_signed, bitlength),
+            )],
+            None,
+            None,
+            None,
+        );
+        structure.decimals = Some(decimals);
+        Ok(structure)
+    }
+
+    ///
+    /// Checks if the structure is a fixed-point decimal.
+    ///
+    pub fn is_fixed(&self) -> bool {
+        self.decimals.is_some()
+    }
+
+    ///
+    /// Returns the fixed-point decimal underlying integer type.
+    ///
+    /// # Panics
+    /// If the structure is not a fixed-point decimal.
+    ///
+    pub fn fixed_inner_type(&self) -> Type {
+        self.fields
+            .first()
+            .map(|(_name, r#type)| r#type.to_owned())
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+    }
+
+    ///
+    /// Returns the fixed-point decimal scale, that is, `10^D`.
+    ///
+    /// # Panics
+    /// If the structure is not a fixed-point decimal.
+    ///
+    pub fn fixed_scale(&self) -> BigInt {
+        num::pow(
+            BigInt::from(10),
+            self.decimals
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        )
+    }
+
+    ///
+    /// Returns the newtype underlying type, which the operators are delegated to.
+    ///
+    /// # Panics
+    /// If the structure is not a newtype.
+    ///
+    pub fn newtype_inner_type(&self) -> Type {
+        self.fields
+            .first()
+            .map(|(_name, r#type)| r#type.to_owned())
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+    }
+
+    ///
+    /// Returns the maximal number of decimals, whose scale `10^D` fits into the integer type.
+    ///
+    fn fixed_decimals_max(is_signed: bool, bitlength: usize) -> usize {
+        let bitlength = if is_signed { bitlength - 1 } else { bitlength };
+        let limit = BigInt::one() << bitlength;
+
+        let mut decimals = 0;
+        let mut scale = BigInt::from(10);
+        while scale < limit {
+            decimals += 1;
+            scale *= 10;
+        }
+        decimals
+    }
+
     ///
     /// Validates and sets the generic type arguments.
     ///
@@ -89,6 +232,13 @@ impl Structure {
                     });
                 }
 
+                if self.type_id == IntrinsicTypeId::Maybe as usize {
+                    self.fields = vec![
+                        (Self::MAYBE_FIELD_IS_SOME.to_owned(), Type::boolean(None)),
+                        (Self::MAYBE_FIELD_VALUE.to_owned(), actual[0].to_owned()),
+                    ];
+                }
+
                 let mut params = HashMap::with_capacity(actual.len());
                 for (name, r#type) in formal.iter().zip(actual.into_iter()) {
                     params.insert(name.to_owned(), r#type);
@@ -114,11 +264,27 @@ impl Structure {
 impl PartialEq<Self> for Structure {
     fn eq(&self, other: &Self) -> bool {
         self.type_id == other.type_id
+            && self.params == other.params
+            && self.decimals == other.decimals
+            && (!self.is_fixed() || self.fields == other.fields)
     }
 }
 
 impl fmt::Display for Structure {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.identifier)
+        match (self.generics.as_ref(), self.params.as_ref()) {
+            (Some(generics), Some(params)) => write!(
+                f,
+                "{}<{}>",
+                self.identifier,
+                generics
+                    .iter()
+                    .filter_map(|name| params.get(name))
+                    .map(|r#type| r#type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => write!(f, "{}", self.identifier),
+        }
     }
 }
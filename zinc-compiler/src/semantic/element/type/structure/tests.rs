@@ -111,3 +111,43 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_fixed_bitlength_out_of_range() {
+    let input = r#"
+fn main(value: ufixed128x2) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Structure(StructureTypeError::FixedBitlengthOutOfRange {
+            location: Location::test(2, 16),
+            type_identifier: "ufixed128x2".to_owned(),
+            found: 128,
+            maximum: zinc_const::bitlength::INTEGER_MAX / 2,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_fixed_decimals_out_of_range() {
+    let input = r#"
+fn main(value: ufixed8x3) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::Structure(StructureTypeError::FixedDecimalsOutOfRange {
+            location: Location::test(2, 16),
+            type_identifier: "ufixed8x3".to_owned(),
+            found: 3,
+            maximum: 2,
+        }),
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
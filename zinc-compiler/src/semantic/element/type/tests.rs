@@ -13,6 +13,31 @@ use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_named_tuple() {
+    let input = r#"
+struct Data {
+    pair: (amount: u248, ok: bool),
+}
+
+contract Test {
+    pub a: u8;
+
+    pub fn pair(self) -> (amount: u248, ok: bool) {
+        (42 as u248, true)
+    }
+
+    pub fn nested(self) -> (data: Data, inner: (x: u8, y: u8), plain: (u8, u8)) {
+        let data = Data { pair: self.pair() };
+        let inner: (x: u8, y: u8) = (1, 2);
+        (data, inner, (inner.0, inner.1))
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_type_required() {
     let input = r#"
@@ -121,7 +146,7 @@ fn main(map: MTreeMap<u8, field>) {}
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
         TypeError::InstantiationForbidden {
             location: Location::test(4, 9),
-            found: "structure MTreeMap".to_owned(),
+            found: "structure MTreeMap<u8, field>".to_owned(),
         },
     ))));
 
@@ -141,7 +166,7 @@ fn main() -> MTreeMap<u8, field> { MTreeMap }
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
         TypeError::InstantiationForbidden {
             location: Location::test(4, 14),
-            found: "structure MTreeMap".to_owned(),
+            found: "structure MTreeMap<u8, field>".to_owned(),
         },
     ))));
 
@@ -167,7 +192,7 @@ contract Test {
     let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
         TypeError::InstantiationForbidden {
             location: Location::test(5, 5),
-            found: "structure MTreeMap".to_owned(),
+            found: "structure MTreeMap<u8, structure MTreeMap<u8, u8>>".to_owned(),
         },
     ))));
 
@@ -202,3 +227,23 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_duplicate_tuple_element() {
+    let input = r#"
+fn main() -> (amount: u248, amount: bool) {
+    (42, true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(ElementError::Type(
+        TypeError::DuplicateTupleElement {
+            location: Location::test(2, 29),
+            name: "amount".to_owned(),
+        },
+    ))));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -16,6 +16,8 @@ pub struct Tuple {
     pub location: Option<Location>,
     /// The tuple element types.
     pub types: Vec<Type>,
+    /// The tuple element names, which are only specified for named tuples.
+    pub names: Option<Vec<String>>,
 }
 
 impl Tuple {
@@ -23,12 +25,40 @@ impl Tuple {
     /// A shortcut constructor.
     ///
     pub fn new(location: Option<Location>, types: Vec<Type>) -> Self {
-        Self { location, types }
+        Self {
+            location,
+            types,
+            names: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_named(location: Option<Location>, types: Vec<Type>, names: Vec<String>) -> Self {
+        Self {
+            location,
+            types,
+            names: Some(names),
+        }
     }
 }
 
 impl fmt::Display for Tuple {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref names) = self.names {
+            return write!(
+                f,
+                "({})",
+                names
+                    .iter()
+                    .zip(self.types.iter())
+                    .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+
         write!(
             f,
             "({})",
@@ -18,6 +18,15 @@ pub enum Error {
         /// The invalid array element, which is actually found.
         found: String,
     },
+    /// The concatenated arrays have different element types.
+    ConcatenationInvalidType {
+        /// The error location data.
+        location: Location,
+        /// The first array type, which dictates the element type.
+        expected: String,
+        /// The second array type, which is actually found.
+        found: String,
+    },
     /// The slice left bound is negative.
     SliceStartOutOfRange {
         /// The error location data.
@@ -17,6 +17,7 @@ use crate::semantic::element::constant::range::Range as RangeConstant;
 use crate::semantic::element::constant::range_inclusive::RangeInclusive as RangeInclusiveConstant;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::range_dynamic::RangeDynamic;
 use crate::semantic::element::value::Value;
 use zinc_lexical::Location;
 
@@ -119,6 +120,28 @@ impl Array {
         Ok(())
     }
 
+    ///
+    /// Concatenates the array with `other`, producing an array of the combined size.
+    ///
+    /// An empty array takes the element type of the other operand.
+    ///
+    pub fn concatenate(mut self, other: Self) -> Result<Self, Error> {
+        if self.is_empty() {
+            self.r#type = other.r#type;
+        } else if !other.is_empty() && other.r#type != self.r#type {
+            return Err(Error::ConcatenationInvalidType {
+                location: other
+                    .location
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                expected: self.r#type().to_string(),
+                found: other.r#type().to_string(),
+            });
+        }
+        self.size += other.size;
+
+        Ok(self)
+    }
+
     ///
     /// Applies the index operator, getting a single element from the array.
     ///
@@ -247,6 +270,30 @@ impl Array {
 
         Ok((result, access))
     }
+
+    ///
+    /// Applies the dynamic range operator, getting an array slice of the constant length
+    /// starting at a runtime offset.
+    ///
+    pub fn slice_range_dynamic(self, range: RangeDynamic) -> Result<(Value, IndexAccess), Error> {
+        if range.length > self.size {
+            return Err(Error::SliceEndOutOfRange {
+                location: range.location,
+                end: format!("<runtime> + {}", range.length),
+                size: self.size,
+            });
+        }
+
+        let access = IndexAccess::new(self.r#type.size(), range.length, self.r#type().size(), None);
+
+        let result = Value::Array(Self::new_with_values(
+            self.location,
+            self.r#type,
+            range.length,
+        ));
+
+        Ok((result, access))
+    }
 }
 
 impl ITyped for Array {
@@ -12,6 +12,32 @@ use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::error::Error as SemanticError;
 use zinc_lexical::Location;
 
+#[test]
+fn ok_concatenation() {
+    let input = r#"
+fn main(array: [u8; 2]) -> [u8; 5] {
+    array + [3, 4, 5]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_repeated_runtime_expression() {
+    let input = r#"
+fn main(value: u8) -> [u8; 4] {
+    [value + 1; 4]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn error_pushing_invalid_type() {
     let input = r#"
@@ -96,3 +122,31 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_concatenation_invalid_type() {
+    let input = r#"
+fn main(array: [u8; 2]) -> [u8; 4] {
+    array + [true, false]
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Array(
+            ArrayValueError::ConcatenationInvalidType {
+                location: Location::test(3, 13),
+                expected: Type::array(
+                    None,
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    2,
+                )
+                .to_string(),
+                found: Type::array(None, Type::boolean(None), 2).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -55,4 +55,13 @@ pub enum Error {
         /// The position of the provided contract field.
         found: usize,
     },
+    /// A field without a default value is not initialized in the contract literal.
+    FieldUninitialized {
+        /// The error location data.
+        location: Location,
+        /// The stringified contract type.
+        type_identifier: String,
+        /// The name of the uninitialized field.
+        field_name: String,
+    },
 }
@@ -76,6 +76,11 @@ impl Contract {
                 scope,
             ),
         ));
+        fields.push((
+            zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::LAYOUT_HASH),
+        ));
         fields.extend(structure.fields);
 
         Self {
@@ -88,11 +93,27 @@ impl Contract {
     ///
     /// Sets the contract type and checks if the pushed field types match it.
     ///
+    /// The fields with default values must be already filled, so all the other expected fields
+    /// missing in the literal are reported as uninitialized.
+    ///
     pub fn validate(&mut self, expected: ContractType) -> Result<(), Error> {
         for (index, (name, location, r#type)) in self.fields.iter().enumerate() {
             match expected.fields.get(index) {
                 Some(expected_field) => {
                     if name != &expected_field.identifier.name {
+                        if !self.contains(expected_field.identifier.name.as_str())
+                            && expected
+                                .fields
+                                .iter()
+                                .any(|field| &field.identifier.name == name)
+                        {
+                            return Err(Error::FieldUninitialized {
+                                location: self.location.unwrap_or_else(|| expected.location),
+                                type_identifier: expected.identifier.to_owned(),
+                                field_name: expected_field.identifier.name.to_owned(),
+                            });
+                        }
+
                         return Err(Error::FieldExpected {
                             location: location
                                 .unwrap_or_else(|| expected_field.identifier.location),
@@ -125,28 +146,47 @@ impl Contract {
             }
         }
 
+        if let Some(expected_field) = expected.fields.get(self.fields.len()) {
+            return Err(Error::FieldUninitialized {
+                location: self.location.unwrap_or_else(|| expected.location),
+                type_identifier: expected.identifier.to_owned(),
+                field_name: expected_field.identifier.name.to_owned(),
+            });
+        }
+
         self.r#type = Some(expected);
 
         Ok(())
     }
 
+    ///
+    /// Checks if the contract value contains the field with the specified `name`.
+    ///
+    fn contains(&self, name: &str) -> bool {
+        self.fields
+            .iter()
+            .any(|(field_name, _location, _type)| field_name == name)
+    }
+
     ///
     /// Slices the contract storage, returning the specified field.
     ///
     pub fn slice(self, expected: Identifier) -> Result<(Value, ContractFieldAccess), Error> {
         let mut offset = 0;
+        let mut leaf_index = 0;
         let total_size = self.r#type().size();
 
-        for (index, (name, _location, r#type)) in self.fields.iter().enumerate() {
+        for (name, _location, r#type) in self.fields.iter() {
             if name == expected.name.as_str() {
                 let access = ContractFieldAccess::new(
                     expected.name,
-                    index,
+                    leaf_index,
                     offset,
                     r#type.size(),
                     total_size,
                     false,
-                    r#type.is_mtreemap(),
+                    r#type.is_mtreemap() || r#type.is_mtreemap_array(),
+                    r#type.storage_leaves_count(),
                 );
 
                 let result = Value::try_from_type(r#type, false, self.location)
@@ -155,6 +195,7 @@ impl Contract {
                 return Ok((result, access));
             }
             offset += r#type.size();
+            leaf_index += r#type.storage_leaves_count();
         }
 
         Err(Error::FieldDoesNotExist {
@@ -51,7 +51,7 @@ contract Test {
         ElementError::Value(ValueError::Contract(ContractValueError::FieldExpected {
             location: Location::test(6, 38),
             type_identifier: "Test".to_owned(),
-            position: 4,
+            position: zinc_const::contract::IMPLICIT_FIELDS_COUNT + 2,
             expected: "b".to_owned(),
             found: "c".to_owned(),
         })),
@@ -103,8 +103,8 @@ contract Test {
         ElementError::Value(ValueError::Contract(ContractValueError::FieldOutOfRange {
             location: Location::test(6, 45),
             type_identifier: "Test".to_owned(),
-            expected: 4,
-            found: 5,
+            expected: zinc_const::contract::IMPLICIT_FIELDS_COUNT + 2,
+            found: zinc_const::contract::IMPLICIT_FIELDS_COUNT + 3,
         })),
     )));
 
@@ -112,3 +112,56 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_field_uninitialized() {
+    let input = r#"
+contract Test {
+    a: u8;
+    b: u8;
+
+    fn main() -> Self { Self { a: 5 } }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Contract(
+            ContractValueError::FieldUninitialized {
+                location: Location::test(6, 30),
+                type_identifier: "Test".to_owned(),
+                field_name: "b".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_field_uninitialized_before_default() {
+    let input = r#"
+contract Test {
+    a: u8;
+    b: u8;
+    c: u8 = 10;
+
+    fn main() -> Self { Self { a: 5 } }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Contract(
+            ContractValueError::FieldUninitialized {
+                location: Location::test(7, 30),
+                type_identifier: "Test".to_owned(),
+                field_name: "b".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer enumeration value element.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use zinc_lexical::Location;
+
+///
+/// The value of an enumeration with payloads, which consists of the variant discriminant
+/// followed by the zero-padded variant payload.
+///
+/// The enumerations without payloads are represented with integer values.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enumeration {
+    /// The location, where the value appears in the code.
+    pub location: Option<Location>,
+    /// The enumeration type.
+    pub r#type: EnumerationType,
+}
+
+impl Enumeration {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Option<Location>, r#type: EnumerationType) -> Self {
+        Self { location, r#type }
+    }
+}
+
+impl ITyped for Enumeration {
+    fn r#type(&self) -> Type {
+        Type::Enumeration(self.r#type.to_owned())
+    }
+
+    fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+    }
+}
+
+impl fmt::Display for Enumeration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<runtime> '{}'", self.r#type)
+    }
+}
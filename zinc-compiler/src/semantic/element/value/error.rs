@@ -119,6 +119,37 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The `==` or `!=` operator operands have different composite types.
+    OperatorEqualityTypesMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified first operand type.
+        first: String,
+        /// The stringified second operand type.
+        second: String,
+    },
+    /// The `==` or `!=` operator composite operand contains an incomparable element.
+    OperatorEqualityIncomparableType {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified operand type.
+        found: String,
+        /// The stringified incomparable element type.
+        element: String,
+    },
+    /// The ordering operators cannot be applied to tuple, array, or structure values.
+    OperatorOrderingComposite {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
 
     /// The `>=` operator expects an integer type value as the first operand.
     OperatorGreaterEqualsFirstOperandExpectedInteger {
@@ -136,6 +136,18 @@ pub enum Error {
         second: String,
     },
 
+    /// The binary operators do not mix `field` with sized integers without an explicit cast.
+    OperandTypeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified first operand type.
+        lhs: String,
+        /// The stringified second operand type.
+        rhs: String,
+        /// The explicit casting hint.
+        hint: String,
+    },
+
     /// The `<<` operator expects an unsigned integer as the second operand.
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
         /// The error location data.
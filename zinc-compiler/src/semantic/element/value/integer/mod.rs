@@ -73,6 +73,36 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    ///
+    /// Returns the explicit casting hint, if one of the operands is `field` and the other one is
+    /// a sized integer.
+    ///
+    /// The bitwise operators are forbidden for `field`, so the `field` operand must be cast
+    /// to the sized integer type. Otherwise, the sized integer operand must be cast to `field`.
+    ///
+    fn field_mixing_hint(&self, other: &Self, is_bitwise: bool) -> Option<String> {
+        let is_field_1 = self.bitlength == zinc_const::bitlength::FIELD;
+        let is_field_2 = other.bitlength == zinc_const::bitlength::FIELD;
+        if is_field_1 == is_field_2 {
+            return None;
+        }
+
+        let (operand, r#type) = match (is_field_1, is_bitwise) {
+            (true, false) => ("second", Type::field(None)),
+            (false, false) => ("first", Type::field(None)),
+            (true, true) => (
+                "first",
+                Type::integer(None, other.is_signed, other.bitlength),
+            ),
+            (false, true) => (
+                "second",
+                Type::integer(None, self.is_signed, self.bitlength),
+            ),
+        };
+
+        Some(format!("cast the {} operand with `as {}`", operand, r#type))
+    }
+
     ///
     /// Executes the `==` equals comparison operator.
     ///
@@ -86,6 +116,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchEquals {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -121,6 +160,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchNotEquals {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -156,6 +204,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchGreaterEquals {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -191,6 +248,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchLesserEquals {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -226,6 +292,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchGreater {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -261,6 +336,15 @@ impl Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchLesser {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -297,6 +381,15 @@ impl BitOr for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseOr {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -346,6 +439,15 @@ impl BitXor for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseXor {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -395,6 +497,15 @@ impl BitAnd for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, true) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchBitwiseAnd {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -516,6 +627,15 @@ impl Add for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchAddition {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -553,6 +673,15 @@ impl Sub for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchSubtraction {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -590,6 +719,15 @@ impl Mul for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchMultiplication {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -627,6 +765,15 @@ impl Div for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchDivision {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -670,6 +817,15 @@ impl Rem for Integer {
             &mut other.bitlength,
         );
 
+        if let Some(hint) = self.field_mixing_hint(&other, false) {
+            return Err(Error::OperandTypeMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                lhs: self.r#type().to_string(),
+                rhs: other.r#type().to_string(),
+                hint,
+            });
+        }
+
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchRemainder {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -11,6 +11,19 @@ use crate::semantic::element::value::integer::Integer as IntegerValue;
 use crate::semantic::error::Error as SemanticError;
 use zinc_lexical::Location;
 
+#[test]
+fn ok_division_runtime_divisor() {
+    let input = r#"
+fn main(a: u8, b: u8, c: i8, d: i8, e: u248, f: u248) -> (u8, u8, i8, i8, u248, u248) {
+    (a / b, a % b, c / d, c % d, e / f, e % f)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn error_types_mismatch_equals() {
     let input = r#"
@@ -261,6 +274,25 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_types_mismatch_addition_casting_hint() {
+    let input = r#"
+fn main() {
+    let integer_64: u64 = 42;
+    let integer_128: u128 = 64;
+    let value = integer_64 + integer_128;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(
+        result.contains("consider casting the first operand from `u64` to `u128` with `as u128`")
+    );
+}
+
 #[test]
 fn error_types_mismatch_subtraction() {
     let input = r#"
@@ -361,6 +393,101 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operand_type_mismatch_arithmetic() {
+    let input = r#"
+fn main() {
+    let field_1: field = 42;
+    let integer_64: u64 = 64;
+    let value = field_1 + integer_64;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::OperandTypeMismatch {
+                location: Location::test(5, 17),
+                lhs: Type::field(None).to_string(),
+                rhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                hint: "cast the second operand with `as field`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operand_type_mismatch_comparison() {
+    let input = r#"
+fn main() {
+    let integer_64: u64 = 42;
+    let field_1: field = 64;
+    let value = integer_64 < field_1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::OperandTypeMismatch {
+                location: Location::test(5, 17),
+                lhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                rhs: Type::field(None).to_string(),
+                hint: "cast the first operand with `as field`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operand_type_mismatch_bitwise() {
+    let input = r#"
+fn main() {
+    let field_1: field = 42;
+    let integer_64: u64 = 64;
+    let value = field_1 & integer_64;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::OperandTypeMismatch {
+                location: Location::test(5, 17),
+                lhs: Type::field(None).to_string(),
+                rhs: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8).to_string(),
+                hint: "cast the first operand with `as u64`".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operand_type_mismatch_casting_hint() {
+    let input = r#"
+fn main() {
+    let integer_64: u64 = 42;
+    let field_1: field = 64;
+    let value = integer_64 * field_1;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(result.contains("cast the first operand with `as field`"));
+}
+
 #[test]
 fn error_operator_bitwise_shift_left_2nd_operand_expected_unsigned() {
     let input = r#"
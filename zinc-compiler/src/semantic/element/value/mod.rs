@@ -8,6 +8,7 @@ mod tests;
 pub mod array;
 pub mod boolean;
 pub mod contract;
+pub mod enumeration;
 pub mod error;
 pub mod integer;
 pub mod structure;
@@ -32,20 +33,24 @@ use zinc_syntax::Identifier;
 
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::casting::Caster;
-use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::access::dot::Dot as DotAccessVariant;
 use crate::semantic::element::access::index::Index as IndexAccess;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::place::Place;
 use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::range_dynamic::RangeDynamic;
 use crate::semantic::element::tuple_index::TupleIndex;
 
+use self::array::error::Error as ArrayError;
 use self::array::Array;
 use self::boolean::Boolean;
 use self::contract::Contract;
+use self::enumeration::Enumeration;
 use self::error::Error;
 use self::integer::Integer;
+use self::structure::error::Error as StructureError;
 use self::structure::Structure;
 use self::tuple::Tuple;
 use self::unit::Unit;
@@ -70,6 +75,8 @@ pub enum Value {
     Tuple(Tuple),
     /// The structure type value.
     Structure(Structure),
+    /// The enumeration with payloads type value.
+    Enumeration(Enumeration),
     /// The contract type value.
     Contract(Contract),
 }
@@ -183,6 +190,27 @@ impl Value {
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                 found: value_2.r#type().to_string(),
             }),
+            (Self::Structure(structure_1), value_2) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(value_2, GeneratorExpressionOperator::equals(), "==")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2)
+            | (value_1 @ Self::Enumeration(_), value_2) => {
+                let location = value_1.location();
+
+                Self::check_composite_equality(&value_1, &value_2, "==").map(|size| {
+                    (
+                        Self::Boolean(Boolean::new(location)),
+                        GeneratorExpressionOperator::equals_composite(size),
+                    )
+                })
+            }
             (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
                 location: value_1
                     .location()
@@ -235,6 +263,27 @@ impl Value {
                     found: value_2.r#type().to_string(),
                 })
             }
+            (Self::Structure(structure_1), value_2) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(value_2, GeneratorExpressionOperator::not_equals(), "!=")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2)
+            | (value_1 @ Self::Enumeration(_), value_2) => {
+                let location = value_1.location();
+
+                Self::check_composite_equality(&value_1, &value_2, "!=").map(|size| {
+                    (
+                        Self::Boolean(Boolean::new(location)),
+                        GeneratorExpressionOperator::not_equals_composite(size),
+                    )
+                })
+            }
             (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
                 location: value_1
                     .location()
@@ -265,6 +314,23 @@ impl Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(other, GeneratorExpressionOperator::greater_equals(), ">=")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            value @ Self::Array(_) | value @ Self::Tuple(_) | value @ Self::Structure(_) => {
+                Err(Error::OperatorOrderingComposite {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: ">=".to_owned(),
+                    found: value.r#type().to_string(),
+                })
+            }
             value => Err(Error::OperatorGreaterEqualsFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -295,6 +361,23 @@ impl Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(other, GeneratorExpressionOperator::lesser_equals(), "<=")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            value @ Self::Array(_) | value @ Self::Tuple(_) | value @ Self::Structure(_) => {
+                Err(Error::OperatorOrderingComposite {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: "<=".to_owned(),
+                    found: value.r#type().to_string(),
+                })
+            }
             value => Err(Error::OperatorLesserEqualsFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -325,6 +408,23 @@ impl Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(other, GeneratorExpressionOperator::greater(), ">")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            value @ Self::Array(_) | value @ Self::Tuple(_) | value @ Self::Structure(_) => {
+                Err(Error::OperatorOrderingComposite {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: ">".to_owned(),
+                    found: value.r#type().to_string(),
+                })
+            }
             value => Err(Error::OperatorGreaterFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -355,6 +455,23 @@ impl Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => {
+                let location = structure_1.location;
+
+                structure_1
+                    .compare(other, GeneratorExpressionOperator::lesser(), "<")
+                    .map(|operator| (Self::Boolean(Boolean::new(location)), operator))
+                    .map_err(Error::Structure)
+            }
+            value @ Self::Array(_) | value @ Self::Tuple(_) | value @ Self::Structure(_) => {
+                Err(Error::OperatorOrderingComposite {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: "<".to_owned(),
+                    found: value.r#type().to_string(),
+                })
+            }
             value => Err(Error::OperatorLesserFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -523,6 +640,23 @@ impl Add for Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Array(array_1) => match other {
+                Self::Array(array_2) => array_1
+                    .concatenate(array_2)
+                    .map(|array| (Self::Array(array), GeneratorExpressionOperator::None))
+                    .map_err(Error::Array),
+                value => Err(Error::Array(ArrayError::ConcatenationInvalidType {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    expected: array_1.r#type().to_string(),
+                    found: value.r#type().to_string(),
+                })),
+            },
+            Self::Structure(structure_1) if structure_1.is_fixed() => structure_1
+                .add(other)
+                .map(|(structure, operator)| (Self::Structure(structure), operator))
+                .map_err(Error::Structure),
             value => Err(Error::OperatorAdditionFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -550,6 +684,10 @@ impl Sub for Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => structure_1
+                .sub(other)
+                .map(|(structure, operator)| (Self::Structure(structure), operator))
+                .map_err(Error::Structure),
             value => Err(Error::OperatorSubtractionFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -577,6 +715,10 @@ impl Mul for Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Structure(structure_1) if structure_1.is_fixed() => structure_1
+                .mul(other)
+                .map(|(structure, operator)| (Self::Structure(structure), operator))
+                .map_err(Error::Structure),
             value => Err(Error::OperatorMultiplicationFirstOperandExpectedInteger {
                 location: value
                     .location()
@@ -655,6 +797,35 @@ impl Value {
             reference: to.location().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
         })?;
 
+        match (self, to) {
+            (Self::Integer(integer), Type::Structure(structure)) if structure.is_fixed() => {
+                let operator = GeneratorExpressionOperator::casting_to_fixed(&structure);
+                Ok((
+                    Self::Structure(Structure::new_with_type(integer.location, structure)),
+                    Some(operator),
+                ))
+            }
+            (Self::Structure(structure), to) if structure.is_fixed() && to.is_scalar() => {
+                let operator = GeneratorExpressionOperator::try_casting_from_fixed(
+                    structure
+                        .r#type
+                        .as_ref()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    &to,
+                );
+                Ok((
+                    Self::try_from_type(&to, false, structure.location)?,
+                    operator,
+                ))
+            }
+            (value, to) => value.cast_integer(to),
+        }
+    }
+
+    ///
+    /// Executes the `as` casting operator for the integer types.
+    ///
+    fn cast_integer(self, to: Type) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
         let (is_signed, bitlength) = match to {
             Type::IntegerUnsigned { bitlength, .. } => (false, bitlength),
             Type::IntegerSigned { bitlength, .. } => (true, bitlength),
@@ -778,11 +949,91 @@ impl Value {
     }
 
     ///
-    /// Executes the `.` dot field access operator for a tuple.
+    /// Executes the `[]` array index operator with a runtime start and constant length range.
+    ///
+    pub fn index_range_dynamic(self, other: RangeDynamic) -> Result<(Self, IndexAccess), Error> {
+        match self {
+            Value::Array(array) => array.slice_range_dynamic(other).map_err(Error::Array),
+            value => Err(Error::OperatorIndexFirstOperandExpectedArray {
+                location: value
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                found: value.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Executes the binary `operator`, delegating it to the inner values if the operands are
+    /// newtypes, e.g. `Wei`.
+    ///
+    /// The result is wrapped back into the newtype, unless it is a comparison one. Both operands
+    /// must be of the same newtype, otherwise the newtypes would be mixed up with each other or
+    /// with their inner type. The operands which are not newtypes are passed to `operator` as is.
+    ///
+    pub fn newtype_operator<F>(
+        self,
+        other: Self,
+        operator: F,
+    ) -> Result<(Self, GeneratorExpressionOperator), Error>
+    where
+        F: FnOnce(Self, Self) -> Result<(Self, GeneratorExpressionOperator), Error>,
+    {
+        let r#type = match (self.newtype().cloned(), other.newtype().cloned()) {
+            (None, None) => return operator(self, other),
+            (Some(type_1), Some(type_2)) if type_1 == type_2 => type_1,
+            _ => {
+                return Err(Error::Structure(StructureError::NewtypeMismatch {
+                    location: self
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    first: self.r#type().to_string(),
+                    second: other.r#type().to_string(),
+                }))
+            }
+        };
+
+        let location = self.location();
+        let inner_type = r#type.newtype_inner_type();
+        let inner_1 = Self::try_from_type(&inner_type, false, location)?;
+        let inner_2 = Self::try_from_type(&inner_type, false, other.location())?;
+
+        let (result, operator) = operator(inner_1, inner_2)?;
+        let result = if operator.is_comparison() {
+            result
+        } else {
+            Self::Structure(Structure::new_with_type(location, r#type))
+        };
+
+        Ok((result, operator))
+    }
+
+    ///
+    /// Returns the newtype structure type, if the value is a newtype.
+    ///
+    fn newtype(&self) -> Option<&StructureType> {
+        match self {
+            Self::Structure(structure) if structure.is_newtype() => structure.r#type.as_ref(),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Executes the `.` dot field access operator for a tuple or newtype.
     ///
-    pub fn tuple_field(self, tuple_index: TupleIndex) -> Result<(Self, StackFieldAccess), Error> {
+    pub fn tuple_field(self, tuple_index: TupleIndex) -> Result<(Self, DotAccessVariant), Error> {
         match self {
-            Value::Tuple(tuple) => tuple.slice(tuple_index).map_err(Error::Tuple),
+            Value::Tuple(tuple) => tuple
+                .slice(tuple_index)
+                .map(|(value, access)| (value, DotAccessVariant::StackField(access)))
+                .map_err(Error::Tuple),
+            Value::Structure(structure) if structure.is_newtype() => structure
+                .slice(Identifier::new(
+                    tuple_index.location,
+                    tuple_index.value.to_string(),
+                ))
+                .map(|(value, _access)| (value, DotAccessVariant::Newtype))
+                .map_err(Error::Structure),
             value => Err(Error::OperatorDotFirstOperandExpectedTuple {
                 location: value
                     .location()
@@ -871,6 +1122,9 @@ impl Value {
                 location.or_else(|| inner.location),
                 inner.to_owned(),
             )),
+            Type::Enumeration(inner) if inner.has_payloads() => Self::Enumeration(
+                Enumeration::new(location.or_else(|| inner.location), inner.to_owned()),
+            ),
             Type::Enumeration(inner) => {
                 let mut integer = Integer::new(
                     location.or_else(|| inner.location),
@@ -912,9 +1166,48 @@ impl Value {
             Self::Array(inner) => inner.location,
             Self::Tuple(inner) => inner.location,
             Self::Structure(inner) => inner.location,
+            Self::Enumeration(inner) => inner.location,
             Self::Contract(inner) => inner.location,
         }
     }
+
+    ///
+    /// Checks whether the tuple, array, structure, or enumeration operands of the equality
+    /// `operator` have the same type consisting of comparable elements only.
+    ///
+    /// Returns the operand size, that is, the number of elements compared by the generator.
+    ///
+    fn check_composite_equality(
+        value_1: &Self,
+        value_2: &Self,
+        operator: &str,
+    ) -> Result<usize, Error> {
+        let type_1 = value_1.r#type();
+        if let Some(element) = type_1.find_incomparable() {
+            return Err(Error::OperatorEqualityIncomparableType {
+                location: value_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                operator: operator.to_owned(),
+                found: type_1.to_string(),
+                element: element.to_string(),
+            });
+        }
+
+        let type_2 = value_2.r#type();
+        if type_1 != type_2 {
+            return Err(Error::OperatorEqualityTypesMismatch {
+                location: value_2
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                operator: operator.to_owned(),
+                first: type_1.to_string(),
+                second: type_2.to_string(),
+            });
+        }
+
+        Ok(type_1.size())
+    }
 }
 
 impl ITyped for Value {
@@ -926,6 +1219,7 @@ impl ITyped for Value {
             Self::Array(inner) => inner.r#type(),
             Self::Tuple(inner) => inner.r#type(),
             Self::Structure(inner) => inner.r#type(),
+            Self::Enumeration(inner) => inner.r#type(),
             Self::Contract(inner) => inner.r#type(),
         }
     }
@@ -942,6 +1236,9 @@ impl ITyped for Value {
             (Self::Structure(value_1), Self::Structure(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
+            (Self::Enumeration(value_1), Self::Enumeration(value_2)) => {
+                value_1.has_the_same_type_as(value_2)
+            }
             (Self::Contract(value_1), Self::Contract(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
@@ -959,6 +1256,7 @@ impl fmt::Display for Value {
             Self::Array(inner) => write!(f, "array {}", inner),
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
+            Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Contract(inner) => write!(f, "contract {}", inner),
         }
     }
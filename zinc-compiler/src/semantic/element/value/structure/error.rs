@@ -62,4 +62,47 @@ pub enum Error {
         /// The position of the provided structure field.
         found: usize,
     },
+    /// The structure update base is not a structure.
+    BaseExpectedStructure {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid base element.
+        found: String,
+    },
+    /// The structure update base type does not match the structure type.
+    BaseTypeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified expected structure type.
+        expected: String,
+        /// The stringified base structure type.
+        found: String,
+    },
+    /// The structure update syntax is used with a contract type.
+    BaseForbiddenInContract {
+        /// The error location data.
+        location: Location,
+        /// The stringified contract type.
+        type_identifier: String,
+    },
+    /// The fixed-point decimal operands of a binary operator have different types.
+    FixedTypesMismatch {
+        /// The error location data.
+        location: Location,
+        /// The binary operator, e.g. `+`.
+        operator: String,
+        /// The stringified first operand type.
+        first: String,
+        /// The stringified second operand type.
+        second: String,
+    },
+    /// The operands of a binary operator are a newtype and a value of another type.
+    NewtypeMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified first operand type.
+        first: String,
+        /// The stringified second operand type.
+        second: String,
+    },
 }
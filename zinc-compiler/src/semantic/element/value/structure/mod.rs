@@ -14,6 +14,7 @@ use std::rc::Rc;
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
+use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
@@ -35,6 +36,8 @@ pub struct Structure {
     pub fields: Vec<(String, Option<Location>, Type)>,
     /// The structure type, which is set for values validation.
     pub r#type: Option<StructureType>,
+    /// The structure update base location and type, which must match the structure type.
+    pub base: Option<(Location, StructureType)>,
 }
 
 impl Structure {
@@ -48,6 +51,7 @@ impl Structure {
             location,
             fields: vec![],
             r#type: None,
+            base: None,
         }
     }
 
@@ -64,6 +68,7 @@ impl Structure {
                 .map(|(name, r#type)| (name, None, r#type))
                 .collect(),
             r#type: Some(r#type),
+            base: None,
         }
     }
 
@@ -81,10 +86,27 @@ impl Structure {
         self.fields.push((name, location, r#type));
     }
 
+    ///
+    /// Sets the structure update base `location` and `type`, which are checked during validation.
+    ///
+    pub fn set_base(&mut self, location: Location, r#type: StructureType) {
+        self.base = Some((location, r#type));
+    }
+
     ///
     /// Sets the structure type and checks if the pushed field types match it.
     ///
     pub fn validate(&mut self, expected: StructureType) -> Result<(), Error> {
+        if let Some((location, ref base_type)) = self.base {
+            if base_type != &expected {
+                return Err(Error::BaseTypeMismatch {
+                    location,
+                    expected: expected.identifier,
+                    found: base_type.identifier.to_owned(),
+                });
+            }
+        }
+
         for (index, (name, location, r#type)) in self.fields.iter().enumerate() {
             match expected.fields.get(index) {
                 Some((expected_name, expected_type)) => {
@@ -124,6 +146,96 @@ impl Structure {
         Ok(())
     }
 
+    ///
+    /// Checks if the structure value is a fixed-point decimal.
+    ///
+    pub fn is_fixed(&self) -> bool {
+        self.r#type
+            .as_ref()
+            .map(StructureType::is_fixed)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Checks if the structure value is a newtype.
+    ///
+    pub fn is_newtype(&self) -> bool {
+        self.r#type
+            .as_ref()
+            .map(|r#type| r#type.is_newtype)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Executes the `+` fixed-point decimal addition operator.
+    ///
+    pub fn add(self, other: Value) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        self.check_fixed_operand(&other, "+")?;
+
+        Ok((self, GeneratorExpressionOperator::addition()))
+    }
+
+    ///
+    /// Executes the `-` fixed-point decimal subtraction operator.
+    ///
+    pub fn sub(self, other: Value) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        self.check_fixed_operand(&other, "-")?;
+
+        Ok((self, GeneratorExpressionOperator::subtraction()))
+    }
+
+    ///
+    /// Executes the `*` fixed-point decimal multiplication operator.
+    ///
+    /// The product is rescaled back to the operands' number of decimals.
+    ///
+    pub fn mul(self, other: Value) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        let r#type = self.check_fixed_operand(&other, "*")?;
+
+        Ok((
+            self,
+            GeneratorExpressionOperator::multiplication_fixed(&r#type),
+        ))
+    }
+
+    ///
+    /// Executes a fixed-point decimal comparison `operator`, which is returned on success.
+    ///
+    pub fn compare(
+        &self,
+        other: Value,
+        operator: GeneratorExpressionOperator,
+        symbol: &str,
+    ) -> Result<GeneratorExpressionOperator, Error> {
+        self.check_fixed_operand(&other, symbol)?;
+
+        Ok(operator)
+    }
+
+    ///
+    /// Checks if the `other` operand of the binary `operator` is a fixed-point decimal of the
+    /// same type, and returns the type.
+    ///
+    fn check_fixed_operand(&self, other: &Value, operator: &str) -> Result<StructureType, Error> {
+        let r#type = self
+            .r#type
+            .to_owned()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        match other {
+            Value::Structure(Self {
+                r#type: Some(other_type),
+                ..
+            }) if other_type == &r#type => Ok(r#type),
+            other => Err(Error::FixedTypesMismatch {
+                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                operator: operator.to_owned(),
+                first: Type::Structure(r#type).to_string(),
+                second: other.r#type().to_string(),
+            }),
+        }
+    }
+
     ///
     /// Slices the structure, returning the specified field.
     ///
@@ -3,6 +3,7 @@
 //!
 
 use crate::error::Error;
+use crate::semantic::analyzer::expression::error::Error as ExpressionError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
@@ -166,3 +167,370 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_shorthand() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main(x: u8, y: u8) -> Point {
+    Point { x, y }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_shorthand_mixed() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+fn main(a: u8) -> Data {
+    let c = 42;
+    Data { a, b: 25, c, }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_shorthand_undeclared() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main(x: u8) -> Point {
+    Point { x, y }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Expression(
+        ExpressionError::StructureShorthandUndeclared {
+            location: Location::test(8, 16),
+            name: "y".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_shorthand_field_invalid_type() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main(x: u8, y: bool) -> Point {
+    Point { x, y }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FieldInvalidType {
+                location: Location::test(8, 16),
+                type_identifier: "Point".to_owned(),
+                field_name: "y".to_owned(),
+                expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+                found: Type::boolean(None).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_base() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+fn main() -> Data {
+    let base = Data { a: 1, b: 2, c: 3 };
+    Data { b: 42, ..base }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_base_constant() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+const BASE: Data = Data { a: 1, b: 2 };
+
+fn main() -> Data {
+    let value: u8 = 42;
+    Data { a: value, ..BASE }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_base_expected_structure() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+fn main() -> Data {
+    let base: u8 = 42;
+    Data { a: 1, ..base }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::BaseExpectedStructure {
+                location: Location::test(9, 20),
+                found: "value integer <runtime> of type 'u8'".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_base_type_mismatch() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+struct Other {
+    a: u8,
+    b: u8,
+}
+
+fn main() -> Data {
+    let base = Other { a: 1, b: 2 };
+    Data { a: 1, ..base }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::BaseTypeMismatch {
+                location: Location::test(14, 20),
+                expected: "Data".to_owned(),
+                found: "Other".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_base_field_does_not_exist() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+fn main() -> Data {
+    let base = Data { a: 1, b: 2 };
+    Data { c: 1, ..base }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FieldDoesNotExist {
+                location: Location::test(9, 12),
+                type_identifier: "Data".to_owned(),
+                field_name: "c".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_base_forbidden_in_contract() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+contract Test {
+    a: u8;
+
+    pub fn new(data: Data) -> Self {
+        Self { ..data }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::BaseForbiddenInContract {
+                location: Location::test(10, 18),
+                type_identifier: "Test".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_fixed_types_mismatch() {
+    let input = r#"
+fn main(a: ufixed64x2, b: ufixed64x4) -> ufixed64x2 {
+    a + b
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FixedTypesMismatch {
+                location: Location::test(3, 5),
+                operator: "+".to_owned(),
+                first: "ufixed64x2".to_owned(),
+                second: "ufixed64x4".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_newtype() {
+    let input = r#"
+struct Wei(u248);
+
+fn main(a: Wei, b: Wei) -> u248 {
+    let fee = Wei(10);
+    let mut total = a + b - fee;
+    total += Wei(1);
+    if total > a { total.0 } else { (a * Wei(2)).0 }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_newtype_mismatch() {
+    let input = r#"
+struct Wei(u248);
+struct Gwei(u248);
+
+fn main(a: Wei, b: Gwei) -> Wei {
+    a + b
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::NewtypeMismatch {
+                location: Location::test(6, 5),
+                first: "structure Wei".to_owned(),
+                second: "structure Gwei".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_newtype_mismatch_inner_type() {
+    let input = r#"
+struct Wei(u248);
+
+fn main(a: Wei, b: u248) -> Wei {
+    a + b
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::NewtypeMismatch {
+                location: Location::test(5, 5),
+                first: "structure Wei".to_owned(),
+                second: "u248".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_newtype_argument_invalid_type() {
+    let input = r#"
+struct Wei(u248);
+
+fn main(a: u8) -> Wei {
+    Wei(a)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FieldInvalidType {
+                location: Location::test(5, 9),
+                type_identifier: "Wei".to_owned(),
+                field_name: "0".to_owned(),
+                expected: "u248".to_owned(),
+                found: "u8".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
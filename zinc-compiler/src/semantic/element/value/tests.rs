@@ -145,7 +145,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -155,17 +155,17 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        ElementError::Value(
-            ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                location: Location::test(5, 17),
-                found: Type::array(
-                    Some(Location::test(5, 17)),
-                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
-                    3,
-                )
-                .to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorEqualityTypesMismatch {
+            location: Location::test(5, 26),
+            operator: "==".to_owned(),
+            first: Type::array(
+                None,
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                3,
+            )
+            .to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -173,6 +173,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_operator_equals_composite() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: (bool, [u8; 2]),
+}
+
+fn main() {
+    let tuple = (1, true);
+    let array = [1, 2, 3];
+    let data = Data { a: 1, b: (true, [1, 2]) };
+
+    let tuples = tuple == (1, true);
+    let arrays = array == [1, 2, 3];
+    let structures = data == Data { a: 1, b: (true, [1, 2]) };
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_operator_equals_2nd_operand_expected_unit() {
     let input = r#"
@@ -240,7 +262,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_operand_expected_primitive() {
+fn error_operator_not_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -250,17 +272,17 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        ElementError::Value(
-            ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                location: Location::test(5, 17),
-                found: Type::array(
-                    Some(Location::test(5, 17)),
-                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
-                    3,
-                )
-                .to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorEqualityTypesMismatch {
+            location: Location::test(5, 26),
+            operator: "!=".to_owned(),
+            first: Type::array(
+                None,
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                3,
+            )
+            .to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -268,6 +290,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_operator_not_equals_composite() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: (bool, [u8; 2]),
+}
+
+fn main() {
+    let tuple = (1, true);
+    let array = [1, 2, 3];
+    let data = Data { a: 1, b: (true, [1, 2]) };
+
+    let tuples = tuple != (1, true);
+    let arrays = array != [1, 2, 3];
+    let structures = data != Data { a: 1, b: (true, [1, 2]) };
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_operator_not_equals_2nd_operand_expected_unit() {
     let input = r#"
@@ -334,6 +378,35 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_greater_equals_composite() {
+    let input = r#"
+fn main() {
+    let tuple = (1, 2);
+    let value = tuple >= (1, 2);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::OperatorOrderingComposite {
+            location: Location::test(4, 17),
+            operator: ">=".to_owned(),
+            found: Type::tuple(
+                None,
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                ],
+            )
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_greater_equals_1st_operand_expected_integer() {
     let input = r#"
@@ -31,3 +31,36 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_field_chained() {
+    let input = r#"
+fn main(a: u8, b: u8, c: u8) -> u8 {
+    ((a, b), c).0.1
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_field_out_of_range_chained() {
+    let input = r#"
+fn main() {
+    let result = ((true, false), true).0.5;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        ElementError::Value(ValueError::Tuple(TupleValueError::FieldOutOrRange {
+            location: Location::test(3, 42),
+            type_identifier: Type::tuple(Some(Location::test(3, 42)), vec![Type::boolean(None); 2])
+                .to_string(),
+            field_index: 5,
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -53,6 +53,26 @@ pub enum Error {
         /// The location where the contract is declared.
         location: Location,
     },
+    /// The contract public method input and output flattened size exceeds the limit.
+    EntryInputTooLarge {
+        /// The location where the method is declared.
+        location: Location,
+        /// The method name.
+        method: String,
+        /// The method input and output flattened size.
+        size: usize,
+        /// The size limit, which is set in the project manifest.
+        limit: usize,
+    },
+    /// The contract storage flattened size exceeds the limit.
+    ContractStorageTooLarge {
+        /// The location where the contract is declared.
+        location: Location,
+        /// The contract storage flattened size.
+        size: usize,
+        /// The size limit, which is set in the project manifest.
+        limit: usize,
+    },
     /// The source code file for module `name` cannot be found.
     ModuleFileNotFound {
         /// The location where the module is declared.
@@ -67,3 +87,9 @@ impl From<ScopeError> for Error {
         Self::Scope(inner)
     }
 }
+
+impl From<ElementError> for Error {
+    fn from(inner: ElementError) -> Self {
+        Self::Element(inner)
+    }
+}
@@ -25,6 +25,24 @@ pub enum Error {
         /// The location where the item is declared for the first item. `None` for intrinsic items.
         reference: Option<Location>,
     },
+    /// The item shadows an intrinsic item, like the `require` function or the `std` library.
+    ItemRedeclaredBuiltIn {
+        /// The error location data.
+        location: Location,
+        /// The redeclared item name.
+        name: String,
+    },
+    /// The item is imported by two or more glob `use` statements, so it cannot be resolved.
+    AmbiguousItem {
+        /// The error location data.
+        location: Location,
+        /// The ambiguous item name.
+        name: String,
+        /// The path of the namespace which the item is imported from for the first time.
+        first: String,
+        /// The path of the namespace which the item is imported from for the second time.
+        second: String,
+    },
     /// The item is not a namespace, and cannot be a part of a path expression.
     ItemIsNotANamespace {
         /// The error location data.
@@ -32,6 +50,15 @@ pub enum Error {
         /// The non-namespace item name.
         name: String,
     },
+    /// The private implementation item is accessed from outside the module of its `impl`.
+    ItemIsPrivate {
+        /// The error location data.
+        location: Location,
+        /// The private item name.
+        name: String,
+        /// The location where the item is declared.
+        declared_at: Option<Location>,
+    },
     /// The associated item is accessed without specifying its namespace.
     AssociatedItemWithoutOwner {
         /// The error location data.
@@ -53,4 +80,20 @@ pub enum Error {
         /// The error location data.
         location: Location,
     },
+    /// There is a cycle between constants, which are defined in terms of each other, possibly
+    /// through some types.
+    ConstantDependencyCycle {
+        /// The error location data.
+        location: Location,
+        /// The names of the constants in the cycle, starting and ending with the same constant.
+        chain: Vec<String>,
+    },
+    /// There is a cycle between types, which are defined in terms of each other, possibly
+    /// through some constants.
+    TypeDependencyCycle {
+        /// The error location data.
+        location: Location,
+        /// The names of the items in the cycle, starting and ending with the same type.
+        chain: Vec<String>,
+    },
 }
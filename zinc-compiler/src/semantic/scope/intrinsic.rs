@@ -20,7 +20,8 @@ use crate::semantic::scope::Scope;
 ///
 /// An intrinsic items set instance creator.
 ///
-/// The intrinsic items are functions `dbg!` and `require` and the `std` and `zksync` libraries.
+/// The intrinsic items are functions `dbg!` and `require`, the `Maybe` type, and the `std` and
+/// `zksync` libraries.
 ///
 #[derive(Debug)]
 pub struct IntrinsicScope {}
@@ -37,17 +38,38 @@ pub enum IntrinsicTypeId {
     ZkSyncTransaction = 2,
     /// The `std::collections::MTreeMap` structure type ID.
     StdCollectionsMTreeMap = 3,
+    /// The `Maybe` generic enumeration type ID.
+    Maybe = 4,
+    /// The `ufixed{N}x{D}` and `fixed{N}x{D}` fixed-point decimal structures type ID.
+    Fixed = 5,
+    /// The `zksync::Environment` structure type ID.
+    ZkSyncEnvironment = 6,
+}
+
+thread_local! {
+    /// The intrinsic scope, which is built once and shared by all compilations in the thread.
+    static INTRINSIC_SCOPE: Rc<RefCell<Scope>> = IntrinsicScope::build();
 }
 
 impl IntrinsicScope {
     ///
-    /// Initializes the intrinsic module scope.
+    /// Returns the intrinsic module scope.
+    ///
+    /// The scope is built lazily once per thread and shared by reference, so it is never
+    /// modified after initialization.
     ///
     pub fn initialize() -> Rc<RefCell<Scope>> {
+        INTRINSIC_SCOPE.with(Rc::clone)
+    }
+
+    ///
+    /// Builds the intrinsic module scope.
+    ///
+    fn build() -> Rc<RefCell<Scope>> {
         let scope = Scope::new_intrinsic("intrinsic").wrap();
 
         let function_dbg = FunctionType::new_dbg();
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             function_dbg.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -58,7 +80,7 @@ impl IntrinsicScope {
         );
 
         let function_require = FunctionType::new_require();
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             function_require.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -68,7 +90,14 @@ impl IntrinsicScope {
             .wrap(),
         );
 
-        Scope::insert_item(
+        let maybe = Self::type_maybe();
+        Scope::insert_built_in_item(
+            scope.clone(),
+            maybe.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(maybe), false)).wrap(),
+        );
+
+        Scope::insert_built_in_item(
             scope.clone(),
             "std".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -78,7 +107,7 @@ impl IntrinsicScope {
             .wrap(),
         );
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             "zksync".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -91,13 +120,40 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Creates the `Maybe<T>` type, which is a generic enumeration with the `None` and `Some(T)`
+    /// variants, represented as a boolean flag followed by the payload.
+    ///
+    /// The flag and payload fields are set along with the generic type argument.
+    ///
+    pub fn type_maybe() -> StructureType {
+        let scope = Scope::new_intrinsic("Maybe").wrap();
+
+        let unwrap_or = FunctionType::new_library(LibraryFunctionIdentifier::MaybeUnwrapOr);
+        Scope::insert_built_in_item(
+            scope.clone(),
+            unwrap_or.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(unwrap_or), true)).wrap(),
+        );
+
+        StructureType::new(
+            None,
+            "Maybe".to_owned(),
+            IntrinsicTypeId::Maybe as usize,
+            vec![],
+            Some(vec!["T".to_owned()]),
+            None,
+            Some(scope),
+        )
+    }
+
     ///
     /// Initializes the `std` module scope.
     ///
     fn module_std() -> Rc<RefCell<Scope>> {
         let scope = Scope::new_intrinsic("std").wrap();
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             "crypto".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -106,7 +162,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             "convert".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -115,7 +171,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             "array".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -124,7 +180,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             "ff".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -133,7 +189,16 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
+            scope.clone(),
+            "mem".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "mem".to_owned(),
+                Self::module_mem(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_built_in_item(
             scope.clone(),
             "collections".to_owned(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -159,7 +224,7 @@ impl IntrinsicScope {
         let schnorr_signature_scope = Scope::new_intrinsic("Signature").wrap();
         let schnorr_verify =
             FunctionType::new_library(LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             schnorr_signature_scope.clone(),
             schnorr_verify.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -193,7 +258,7 @@ impl IntrinsicScope {
             None,
             Some(schnorr_signature_scope.clone()),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             schnorr_scope.clone(),
             schnorr_signature_scope.borrow().name(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -204,7 +269,7 @@ impl IntrinsicScope {
         );
 
         let ecc_scope = Scope::new_intrinsic("ecc").wrap();
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             ecc_scope.clone(),
             ecc_point.identifier.clone(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -214,17 +279,17 @@ impl IntrinsicScope {
             .wrap(),
         );
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             sha256.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(sha256), false)).wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             pedersen.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pedersen), false)).wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             ecc_scope.borrow().name(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -233,7 +298,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             schnorr_scope.borrow().name(),
             ScopeItem::Module(ScopeModuleItem::new_built_in(
@@ -259,13 +324,16 @@ impl IntrinsicScope {
             FunctionType::new_library(LibraryFunctionIdentifier::ConvertFromBitsSigned);
         let from_bits_field =
             FunctionType::new_library(LibraryFunctionIdentifier::ConvertFromBitsField);
+        let to_bytes_be = FunctionType::new_library(LibraryFunctionIdentifier::ConvertToBytesBe);
+        let from_bytes_be =
+            FunctionType::new_library(LibraryFunctionIdentifier::ConvertFromBytesBe);
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             to_bits.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(to_bits), false)).wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             from_bits_unsigned.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -274,7 +342,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             from_bits_signed.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -283,7 +351,7 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             from_bits_field.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -292,6 +360,24 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
+        Scope::insert_built_in_item(
+            scope.clone(),
+            to_bytes_be.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Function(to_bytes_be),
+                false,
+            ))
+            .wrap(),
+        );
+        Scope::insert_built_in_item(
+            scope.clone(),
+            from_bytes_be.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Function(from_bytes_be),
+                false,
+            ))
+            .wrap(),
+        );
 
         scope
     }
@@ -306,17 +392,17 @@ impl IntrinsicScope {
         let truncate = FunctionType::new_library(LibraryFunctionIdentifier::ArrayTruncate);
         let pad = FunctionType::new_library(LibraryFunctionIdentifier::ArrayPad);
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             reverse.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(reverse), false)).wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             truncate.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(truncate), false)).wrap(),
         );
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             pad.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pad), false)).wrap(),
@@ -333,7 +419,7 @@ impl IntrinsicScope {
 
         let invert = FunctionType::new_library(LibraryFunctionIdentifier::FfInvert);
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             invert.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(invert), false)).wrap(),
@@ -342,6 +428,33 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Initializes the `std::mem` module scope.
+    ///
+    fn module_mem() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("mem").wrap();
+
+        let size_of = FunctionType::new_mem_size_of();
+        let field_count_of = FunctionType::new_mem_field_count_of();
+
+        Scope::insert_built_in_item(
+            scope.clone(),
+            size_of.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(size_of), false)).wrap(),
+        );
+        Scope::insert_built_in_item(
+            scope.clone(),
+            field_count_of.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Function(field_count_of),
+                false,
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
     ///
     /// Initializes the `std::collections` module scope.
     ///
@@ -360,7 +473,7 @@ impl IntrinsicScope {
         );
         let merkle_tree_map_get =
             FunctionType::new_library(LibraryFunctionIdentifier::CollectionsMTreeMapGet);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             merkle_tree_map_scope.clone(),
             merkle_tree_map_get.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -371,7 +484,7 @@ impl IntrinsicScope {
         );
         let merkle_tree_map_contains =
             FunctionType::new_library(LibraryFunctionIdentifier::CollectionsMTreeMapContains);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             merkle_tree_map_scope.clone(),
             merkle_tree_map_contains.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -382,7 +495,7 @@ impl IntrinsicScope {
         );
         let merkle_tree_map_insert =
             FunctionType::new_library(LibraryFunctionIdentifier::CollectionsMTreeMapInsert);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             merkle_tree_map_scope.clone(),
             merkle_tree_map_insert.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -393,7 +506,7 @@ impl IntrinsicScope {
         );
         let merkle_tree_map_remove =
             FunctionType::new_library(LibraryFunctionIdentifier::CollectionsMTreeMapRemove);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             merkle_tree_map_scope,
             merkle_tree_map_remove.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -403,7 +516,7 @@ impl IntrinsicScope {
             .wrap(),
         );
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             merkle_tree_map.identifier.clone(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -423,7 +536,7 @@ impl IntrinsicScope {
         let scope = Scope::new_intrinsic("zksync").wrap();
 
         let transfer = FunctionType::new_library(LibraryFunctionIdentifier::ZksyncTransfer);
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             transfer.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(transfer), false)).wrap(),
@@ -472,7 +585,7 @@ impl IntrinsicScope {
             None,
         );
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             transaction_type.identifier.clone(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(
@@ -482,7 +595,7 @@ impl IntrinsicScope {
             .wrap(),
         );
 
-        Scope::insert_item(
+        Scope::insert_built_in_item(
             scope.clone(),
             zinc_const::contract::TRANSACTION_VARIABLE_NAME.to_owned(),
             ScopeItem::Variable(ScopeVariableItem::new(
@@ -495,6 +608,48 @@ impl IntrinsicScope {
             .wrap(),
         );
 
+        let environment_type = StructureType::new(
+            None,
+            "Environment".to_owned(),
+            IntrinsicTypeId::ZkSyncEnvironment as usize,
+            vec![
+                (
+                    "timestamp".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::TIMESTAMP),
+                ),
+                (
+                    "block_number".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BLOCK_NUMBER),
+                ),
+            ],
+            None,
+            None,
+            None,
+        );
+
+        Scope::insert_built_in_item(
+            scope.clone(),
+            environment_type.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                Type::Structure(environment_type.clone()),
+                false,
+            ))
+            .wrap(),
+        );
+
+        Scope::insert_built_in_item(
+            scope.clone(),
+            zinc_const::contract::ENVIRONMENT_VARIABLE_NAME.to_owned(),
+            ScopeItem::Variable(ScopeVariableItem::new(
+                None,
+                false,
+                zinc_const::contract::ENVIRONMENT_VARIABLE_NAME.to_owned(),
+                Type::Structure(environment_type),
+                MemoryType::Stack,
+            ))
+            .wrap(),
+        );
+
         scope
     }
 }
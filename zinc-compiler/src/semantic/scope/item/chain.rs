@@ -0,0 +1,47 @@
+//!
+//! The semantic analyzer scope item definition chain.
+//!
+
+use std::cell::RefCell;
+
+thread_local! {
+    /// The constants and types being defined, where each item is defined on demand while
+    /// defining the previous one.
+    static CHAIN: RefCell<Vec<(usize, String)>> = RefCell::new(Vec::new());
+}
+
+///
+/// Runs the item `define` closure with the item put onto the definition chain.
+///
+/// The item is removed from the chain regardless of the definition result, since the chain is
+/// shared by all compilations in the thread.
+///
+pub fn enter<T, F>(item_id: usize, name: String, define: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    CHAIN.with(|chain| chain.borrow_mut().push((item_id, name)));
+    let result = define();
+    CHAIN.with(|chain| chain.borrow_mut().pop());
+    result
+}
+
+///
+/// Returns the names of the items from the item with `item_id` to the item referencing it
+/// again, ending with the item with `item_id` itself.
+///
+/// Returns `None` if the item is not being defined, e.g. if it is a function.
+///
+pub fn cycle(item_id: usize) -> Option<Vec<String>> {
+    CHAIN.with(|chain| {
+        let chain = chain.borrow();
+        let start = chain.iter().position(|(id, _name)| *id == item_id)?;
+
+        let mut cycle: Vec<String> = chain[start..]
+            .iter()
+            .map(|(_id, name)| name.to_owned())
+            .collect();
+        cycle.push(chain[start].1.to_owned());
+        Some(cycle)
+    })
+}
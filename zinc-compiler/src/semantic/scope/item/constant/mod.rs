@@ -4,6 +4,7 @@
 
 pub mod state;
 
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
@@ -15,6 +16,7 @@ use crate::semantic::analyzer::statement::r#const::Analyzer as ConstStatementAna
 use crate::semantic::element::constant::Constant as ConstantElement;
 use crate::semantic::error::Error;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::item::chain;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::Scope;
 
@@ -33,6 +35,11 @@ pub struct Constant {
     pub state: RefCell<Option<State>>,
     /// Whether the constant is associated with some implementation or smart contract definition.
     pub is_associated: bool,
+    /// The module, which the private implementation constant is accessible from, including its
+    /// nested modules. `None` if the constant is accessible from anywhere.
+    pub module: Option<Rc<RefCell<Scope>>>,
+    /// Whether the constant has been referenced at least once.
+    pub is_used: Cell<bool>,
 }
 
 impl Constant {
@@ -42,19 +49,25 @@ impl Constant {
     ///
     /// Is used during module items hoisting.
     ///
+    /// The `module` is only kept if the constant is not public.
+    ///
     pub fn new_declared(
         location: Location,
         inner: ConstStatement,
         scope: Rc<RefCell<Scope>>,
         is_associated: bool,
+        module: Option<Rc<RefCell<Scope>>>,
     ) -> Self {
         let item_id = ITEM_INDEX.next(format!("constant {}", inner.identifier.name));
+        let module = if inner.is_public { None } else { module };
 
         Self {
             location,
             item_id,
             state: RefCell::new(Some(State::Declared { inner, scope })),
             is_associated,
+            module,
+            is_used: Cell::new(false),
         }
     }
 
@@ -69,6 +82,8 @@ impl Constant {
             item_id,
             state: RefCell::new(Some(State::Defined { inner })),
             is_associated,
+            module: None,
+            is_used: Cell::new(false),
         }
     }
 
@@ -77,14 +92,18 @@ impl Constant {
     ///
     /// The method is able to detect reference loops. It happens naturally when the method
     /// is reentered before the item being defined is put back into `variant`, which means that
-    /// the item is taken twice during its resolution process.
+    /// the item is taken twice during its resolution process. The loop is reported with the
+    /// chain of the items depending on each other.
     ///
     pub fn define(&self) -> Result<ConstantElement, Error> {
         let variant = self.state.borrow_mut().take();
 
         match variant {
             Some(State::Declared { inner, scope }) => {
-                let defined = ConstStatementAnalyzer::define(scope, inner)?;
+                let name = inner.identifier.name.to_owned();
+                let defined = chain::enter(self.item_id, name, || {
+                    ConstStatementAnalyzer::define(scope, inner)
+                })?;
                 self.state.replace(Some(State::Defined {
                     inner: defined.clone(),
                 }));
@@ -98,9 +117,15 @@ impl Constant {
 
                 Ok(inner)
             }
-            None => Err(Error::Scope(ScopeError::ReferenceLoop {
-                location: self.location,
-            })),
+            None => match chain::cycle(self.item_id) {
+                Some(chain) => Err(Error::Scope(ScopeError::ConstantDependencyCycle {
+                    location: self.location,
+                    chain,
+                })),
+                None => Err(Error::Scope(ScopeError::ReferenceLoop {
+                    location: self.location,
+                })),
+            },
         }
     }
 }
@@ -21,7 +21,7 @@ pub struct Field {
     pub identifier: String,
     /// The variable type.
     pub r#type: Type,
-    /// The index of the field in the contract storage.
+    /// The index of the first contract storage Merkle tree leaf occupied by the field.
     pub index: usize,
     /// Whether the field is public, that is, queryable as a part of the storage state.
     pub is_public: bool,
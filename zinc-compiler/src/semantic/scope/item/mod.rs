@@ -2,6 +2,7 @@
 //! The semantic analyzer scope item.
 //!
 
+pub mod chain;
 pub mod constant;
 pub mod field;
 pub mod index;
@@ -16,6 +17,7 @@ use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
 use zinc_lexical::Location;
 
 use self::constant::Constant;
@@ -55,6 +57,10 @@ use self::variant::Variant;
 /// 5. Define item `C`, which was referenced by the item `A` definition, and also references the item `B`.
 /// 6. Define item `B`, which was referenced by the item `C`.
 ///
+/// The constants and types being defined are tracked in the definition chain, so if an item is
+/// referenced again during its own definition, the dependency cycle is reported with the chain of
+/// the items involved.
+///
 #[derive(Debug, Clone)]
 pub enum Item {
     /// The variable item. See the inner element description.
@@ -134,6 +140,57 @@ impl Item {
         }
     }
 
+    ///
+    /// The module, which the private implementation item is accessible from.
+    ///
+    /// Returns `None` if the item is accessible from anywhere.
+    ///
+    pub fn private_module(&self) -> Option<Rc<RefCell<Scope>>> {
+        match self {
+            Self::Constant(inner) => inner.module.to_owned(),
+            Self::Type(inner) => inner.module.to_owned(),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Marks the item as referenced at least once.
+    ///
+    /// Only variables, constants, and types keep track of their usage.
+    ///
+    pub fn mark_used(&self) {
+        match self {
+            Self::Variable(inner) => inner.is_used.set(true),
+            Self::Constant(inner) => inner.is_used.set(true),
+            Self::Type(inner) => inner.is_used.set(true),
+            _ => {}
+        }
+    }
+
+    ///
+    /// Reverts `mark_used` for a variable, whose reference has been only written to, that is,
+    /// has been the target of an assignment.
+    ///
+    pub fn mark_unused(&self) {
+        if let Self::Variable(inner) = self {
+            inner.is_used.set(false);
+        }
+    }
+
+    ///
+    /// Whether the item has been referenced at least once.
+    ///
+    /// Items which do not keep track of their usage are always considered used.
+    ///
+    pub fn is_used(&self) -> bool {
+        match self {
+            Self::Variable(inner) => inner.is_used.get(),
+            Self::Constant(inner) => inner.is_used.get(),
+            Self::Type(inner) => inner.is_used.get(),
+            _ => true,
+        }
+    }
+
     ///
     /// The location where the item has been declared.
     ///
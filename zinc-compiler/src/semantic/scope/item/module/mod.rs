@@ -16,7 +16,10 @@ use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::source::library::Library;
 use crate::source::Source;
+use crate::timings::phase::Phase;
+use crate::timings::TIMINGS;
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
@@ -41,17 +44,50 @@ impl Module {
     ///
     /// Initializes an application entry module scope.
     ///
-    pub fn new_entry(module: Source) -> Result<Rc<RefCell<ScopeItem>>, Error> {
-        let scope = Scope::new_global(module.name().to_owned()).wrap();
+    /// The `libraries` are declared in the entry scope as modules named after the dependencies.
+    ///
+    pub fn new_entry(
+        module: Source,
+        libraries: HashMap<String, Library>,
+    ) -> Result<Rc<RefCell<ScopeItem>>, Error> {
+        let identifier = module.name().to_owned();
+
+        Self::new_root(identifier, module, libraries, true)
+    }
+
+    ///
+    /// Initializes an external library module scope.
+    ///
+    /// The library is a separate crate, so its `crate` alias points to the library root, and
+    /// its own dependencies are only visible inside the library.
+    ///
+    pub fn new_library(
+        identifier: String,
+        library: Library,
+    ) -> Result<Rc<RefCell<ScopeItem>>, Error> {
+        Self::new_root(identifier, library.source, library.dependencies, false)
+    }
+
+    ///
+    /// Initializes a crate root module scope with the `self` and `crate` aliases.
+    ///
+    fn new_root(
+        identifier: String,
+        module: Source,
+        libraries: HashMap<String, Library>,
+        is_entry: bool,
+    ) -> Result<Rc<RefCell<ScopeItem>>, Error> {
+        let scope = Scope::new_global(identifier.clone()).wrap();
+        Self::insert_libraries(scope.clone(), libraries)?;
 
         let module = Self::new_declared(
             None,
             scope.clone(),
-            module.name().to_owned(),
+            identifier,
             module,
             scope.clone(),
             None,
-            true,
+            is_entry,
         )?;
         let item = ScopeItem::Module(module).wrap();
 
@@ -69,6 +105,21 @@ impl Module {
         Ok(item)
     }
 
+    ///
+    /// Declares the `libraries` in the `scope` as modules named after the dependencies.
+    ///
+    fn insert_libraries(
+        scope: Rc<RefCell<Scope>>,
+        libraries: HashMap<String, Library>,
+    ) -> Result<(), Error> {
+        for (name, library) in libraries.into_iter() {
+            let item = Self::new_library(name.clone(), library)?;
+            scope.borrow().items.borrow_mut().insert(name, item);
+        }
+
+        Ok(())
+    }
+
     ///
     /// Creates an declared module, which must be defined during the second pass or when
     /// the item is referenced for the first time.
@@ -86,24 +137,37 @@ impl Module {
     ) -> Result<Self, Error> {
         let item_id = ITEM_INDEX.next(format!("module {}", identifier));
 
-        let (module, dependencies) = match module {
-            Source::File(file) => (file.tree, HashMap::new()),
-            Source::Directory(directory) => (directory.entry.tree, directory.dependencies),
+        let (path, module, dependencies) = match module {
+            Source::File(file) => (file.path, file.tree, HashMap::new()),
+            Source::Directory(directory) => (
+                directory.entry.path,
+                directory.entry.tree,
+                directory.dependencies,
+            ),
         };
+        let path = path.to_string_lossy().to_string();
 
-        let (module, implementation_scopes) = ModuleAnalyzer::declare(
-            scope.clone(),
-            module,
-            dependencies,
-            scope_crate.clone(),
-            is_entry,
-        )?;
+        let declare = || {
+            ModuleAnalyzer::declare(
+                scope.clone(),
+                module,
+                dependencies,
+                scope_crate.clone(),
+                is_entry,
+            )
+        };
+        let (module, implementation_scopes) = if TIMINGS.is_enabled() {
+            TIMINGS.measure(path.as_str(), Phase::Semantic, declare)?
+        } else {
+            declare()?
+        };
 
         Ok(Self {
             location,
             item_id,
             identifier,
             state: RefCell::new(Some(State::Declared {
+                path,
                 scope,
                 module,
                 implementation_scopes,
@@ -161,6 +225,7 @@ impl Module {
 
         match variant {
             Some(State::Declared {
+                path,
                 scope,
                 module,
                 implementation_scopes,
@@ -174,13 +239,21 @@ impl Module {
                 let crate_item = Scope::get_module_self_alias(scope_crate);
                 let super_item = scope_super.map(Scope::get_module_self_alias);
 
-                ModuleAnalyzer::define(
-                    scope.clone(),
-                    module,
-                    implementation_scopes,
-                    crate_item,
-                    super_item,
-                )?;
+                let define = || {
+                    ModuleAnalyzer::define(
+                        scope.clone(),
+                        module,
+                        implementation_scopes,
+                        crate_item,
+                        super_item,
+                    )
+                };
+                if TIMINGS.is_enabled() {
+                    TIMINGS.measure(path.as_str(), Phase::Semantic, define)?;
+                    TIMINGS.set_items(path.as_str(), scope.borrow().items.borrow().len());
+                } else {
+                    define()?;
+                }
 
                 Ok(scope)
             }
@@ -17,6 +17,8 @@ use crate::semantic::scope::Scope;
 pub enum State {
     /// Waiting to be defined during the second pass.
     Declared {
+        /// The module file path, which is used to attribute the compilation timings.
+        path: String,
         /// The module scope, which contains all the items declared within.
         scope: Rc<RefCell<Scope>>,
         /// The module syntax representation.
@@ -52,6 +52,18 @@ impl Index {
             "structure std::collections::MTreeMap".to_owned(),
             IntrinsicTypeId::StdCollectionsMTreeMap as usize,
         );
+        index.next_with_id(
+            "structure Maybe".to_owned(),
+            IntrinsicTypeId::Maybe as usize,
+        );
+        index.next_with_id(
+            "structure Fixed".to_owned(),
+            IntrinsicTypeId::Fixed as usize,
+        );
+        index.next_with_id(
+            "structure zksync::Environment".to_owned(),
+            IntrinsicTypeId::ZkSyncEnvironment as usize,
+        );
         index
     }
 
@@ -6,6 +6,7 @@ pub mod index;
 pub mod state;
 pub mod statement;
 
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
@@ -19,6 +20,7 @@ use crate::semantic::analyzer::statement::r#type::Analyzer as TypeStatementAnaly
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::error::Error;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::item::chain;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::Scope;
 use zinc_lexical::Keyword;
@@ -40,6 +42,13 @@ pub struct Type {
     pub state: RefCell<Option<State>>,
     /// Whether the type is associated with some implementation or smart contract definition.
     pub is_associated: bool,
+    /// Whether the type is declared as public. Only functions can be declared with `pub`.
+    pub is_public: bool,
+    /// The module, which the private implementation method is accessible from, including its
+    /// nested modules. `None` if the type is accessible from anywhere.
+    pub module: Option<Rc<RefCell<Scope>>>,
+    /// Whether the type has been referenced at least once.
+    pub is_used: Cell<bool>,
 }
 
 impl Type {
@@ -51,14 +60,23 @@ impl Type {
     ///
     /// If the declared type is a contract, its items are hoisted to be defined later.
     ///
+    /// The `module` is only kept if the type is a non-public function.
+    ///
     pub fn new_declared(
         location: Option<Location>,
         inner: TypeStatementVariant,
         scope: Rc<RefCell<Scope>>,
         is_associated: bool,
+        module: Option<Rc<RefCell<Scope>>>,
     ) -> Result<Self, Error> {
         let item_id = ITEM_INDEX.next(format!("type {}", inner.identifier().name));
 
+        let is_public = match inner {
+            TypeStatementVariant::Fn(ref statement, _) => statement.is_public,
+            _ => false,
+        };
+        let module = if is_public { None } else { module };
+
         let (inner, scope) = match inner {
             TypeStatementVariant::Contract(statement) => {
                 let scope = Scope::new_child(statement.identifier.name.clone(), scope);
@@ -81,6 +99,9 @@ impl Type {
             item_id,
             state: RefCell::new(Some(State::Declared { inner, scope })),
             is_associated,
+            is_public,
+            module,
+            is_used: Cell::new(false),
         })
     }
 
@@ -115,6 +136,9 @@ impl Type {
                 intermediate,
             })),
             is_associated,
+            is_public: false,
+            module: None,
+            is_used: Cell::new(false),
         }
     }
 
@@ -132,6 +156,9 @@ impl Type {
                 intermediate: None,
             })),
             is_associated,
+            is_public: false,
+            module: None,
+            is_used: Cell::new(false),
         }
     }
 
@@ -140,35 +167,49 @@ impl Type {
     ///
     /// The method is able to detect reference loops. It happens naturally when the method
     /// is reentered before the item being defined is put back into `variant`, which means that
-    /// the item is taken twice during its resolution process.
+    /// the item is taken twice during its resolution process. The loops between types are
+    /// reported with the chain of the items depending on each other, whereas the function
+    /// loops are reported as plain reference loops.
     ///
     pub fn define(&self) -> Result<TypeElement, Error> {
         let variant = self.state.borrow_mut().take();
 
         match variant {
             Some(State::Declared { inner, scope }) => {
+                let name = inner.identifier().name.to_owned();
                 let (r#type, intermediate) = match inner {
-                    TypeStatementVariant::Type(inner) => {
-                        (TypeStatementAnalyzer::define(scope, inner)?, None)
-                    }
-                    TypeStatementVariant::Struct(inner) => {
-                        (StructStatementAnalyzer::define(scope, inner)?, None)
-                    }
-                    TypeStatementVariant::Enum(inner) => {
-                        (EnumStatementAnalyzer::define(scope, inner)?, None)
-                    }
+                    TypeStatementVariant::Type(inner) => (
+                        chain::enter(self.item_id, name, || {
+                            TypeStatementAnalyzer::define(scope, inner)
+                        })?,
+                        None,
+                    ),
+                    TypeStatementVariant::Struct(inner) => (
+                        chain::enter(self.item_id, name, || {
+                            StructStatementAnalyzer::define(scope, inner)
+                        })?,
+                        None,
+                    ),
+                    TypeStatementVariant::Enum(inner) => (
+                        chain::enter(self.item_id, name, || {
+                            EnumStatementAnalyzer::define(scope, inner)
+                        })?,
+                        None,
+                    ),
                     TypeStatementVariant::Fn(inner, context) => FnStatementAnalyzer::define(
                         scope, inner, context,
                     )
                     .map(|(r#type, intermediate)| {
                         (r#type, intermediate.map(GeneratorStatement::Fn))
                     })?,
-                    TypeStatementVariant::Contract(inner) => ContractStatementAnalyzer::define(
-                        scope, inner,
-                    )
-                    .map(|(r#type, intermediate)| {
-                        (r#type, Some(GeneratorStatement::Contract(intermediate)))
-                    })?,
+                    TypeStatementVariant::Contract(inner) => {
+                        chain::enter(self.item_id, name, || {
+                            ContractStatementAnalyzer::define(scope, inner)
+                        })
+                        .map(|(r#type, intermediate)| {
+                            (r#type, Some(GeneratorStatement::Contract(intermediate)))
+                        })?
+                    }
                 };
 
                 self.state.replace(Some(State::Defined {
@@ -189,9 +230,16 @@ impl Type {
 
                 Ok(inner)
             }
-            None => Err(Error::Scope(ScopeError::ReferenceLoop {
-                location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-            })),
+            None => {
+                let location = self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                match chain::cycle(self.item_id) {
+                    Some(chain) => Err(Error::Scope(ScopeError::TypeDependencyCycle {
+                        location,
+                        chain,
+                    })),
+                    None => Err(Error::Scope(ScopeError::ReferenceLoop { location })),
+                }
+            }
         }
     }
 
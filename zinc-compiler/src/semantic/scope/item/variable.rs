@@ -2,6 +2,7 @@
 //! The semantic analyzer scope variable item.
 //!
 
+use std::cell::Cell;
 use std::fmt;
 
 use crate::semantic::element::r#type::Type;
@@ -26,6 +27,8 @@ pub struct Variable {
     pub r#type: Type,
     /// The memory type, where the variable is declared.
     pub memory_type: MemoryType,
+    /// Whether the variable has been referenced at least once.
+    pub is_used: Cell<bool>,
 }
 
 impl Variable {
@@ -48,6 +51,7 @@ impl Variable {
             identifier,
             r#type,
             memory_type,
+            is_used: Cell::new(false),
         }
     }
 }
@@ -13,7 +13,9 @@ pub mod stack;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
+use std::rc::Weak;
 use std::str;
 
 use zinc_lexical::Keyword;
@@ -25,10 +27,12 @@ use zinc_syntax::Identifier;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::source::Source;
+use crate::warning::Warning;
 
 use self::error::Error;
 use self::intrinsic::IntrinsicScope;
@@ -57,6 +61,16 @@ pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     /// The hashmap with items declared at the current scope level, with item names as keys.
     items: RefCell<HashMap<String, Rc<RefCell<Item>>>>,
+    /// The namespaces imported with glob `use` statements, with the stringified paths as origins.
+    /// Their items are resolved lazily, after the ones declared at the current scope level.
+    globs: RefCell<Vec<(String, Rc<RefCell<Self>>)>>,
+    /// The child scopes, which are walked through by the post-analysis passes while they are
+    /// alive. The references are weak, since the children hold strong references to the parent.
+    children: RefCell<Vec<Weak<RefCell<Self>>>>,
+    /// The warnings reported during the analysis of the scope and its descendants.
+    warnings: RefCell<Vec<Warning>>,
+    /// The name of the immutable contract method, whose body is analyzed in the scope.
+    query_method: RefCell<Option<String>>,
     /// Whether the scope is the intrinsic one, that is, the root scope with intrinsic items.
     is_built_in: bool,
 }
@@ -76,6 +90,10 @@ impl Scope {
             name,
             parent,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            globs: RefCell::new(Vec::new()),
+            children: RefCell::new(Vec::new()),
+            warnings: RefCell::new(Vec::new()),
+            query_method: RefCell::new(None),
             is_built_in: false,
         }
     }
@@ -88,6 +106,10 @@ impl Scope {
             name,
             parent: Some(IntrinsicScope::initialize()),
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            globs: RefCell::new(Vec::new()),
+            children: RefCell::new(Vec::new()),
+            warnings: RefCell::new(Vec::new()),
+            query_method: RefCell::new(None),
             is_built_in: false,
         }
     }
@@ -100,6 +122,10 @@ impl Scope {
             name: name.to_owned(),
             parent: None,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            globs: RefCell::new(Vec::new()),
+            children: RefCell::new(Vec::new()),
+            warnings: RefCell::new(Vec::new()),
+            query_method: RefCell::new(None),
             is_built_in: true,
         }
     }
@@ -107,8 +133,31 @@ impl Scope {
     ///
     /// Creates a child scope with `parent` as its parent.
     ///
+    /// The child is also registered in the `parent` to be reachable by the post-analysis passes.
+    /// The scopes which are dropped after the analysis, like the ones of blocks and functions,
+    /// must be passed to `close` before that.
+    ///
     pub fn new_child(name: String, parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Self>> {
-        Self::new(name, Some(parent)).wrap()
+        let child = Self::new(name, Some(parent.clone())).wrap();
+        parent
+            .borrow()
+            .children
+            .borrow_mut()
+            .push(Rc::downgrade(&child));
+        child
+    }
+
+    ///
+    /// Reports the unused items of the `scope`, which is not going to be analyzed anymore.
+    ///
+    /// The warnings are passed to the module scope, since the `scope` may be dropped before
+    /// the unused items are collected with `get_unused_items`.
+    ///
+    pub fn close(scope: Rc<RefCell<Scope>>) {
+        let warnings = scope.borrow().unused_items();
+        for warning in warnings.into_iter() {
+            Self::push_warning(scope.clone(), warning);
+        }
     }
 
     ///
@@ -148,6 +197,15 @@ impl Scope {
     /// Inserts an item, does not check if the item has been already declared.
     ///
     pub fn insert_item(scope: Rc<RefCell<Scope>>, name: String, item: Rc<RefCell<Item>>) {
+        scope.borrow().insert(name, item);
+    }
+
+    ///
+    /// Inserts an item into the `scope`, which is being initialized as an intrinsic one.
+    ///
+    /// Must only be used by the intrinsic scope initializer.
+    ///
+    fn insert_built_in_item(scope: Rc<RefCell<Scope>>, name: String, item: Rc<RefCell<Item>>) {
         scope.borrow().items.borrow_mut().insert(name, item);
     }
 
@@ -159,23 +217,30 @@ impl Scope {
         identifier: Identifier,
         item: Rc<RefCell<Item>>,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
-
         scope
             .borrow()
-            .items
-            .borrow_mut()
-            .insert(identifier.name, item);
+            .check_item_redeclared(&identifier, identifier.location, true)?;
+
+        scope.borrow().insert(identifier.name, item);
 
         Ok(())
     }
 
+    ///
+    /// Imports all the items of the `namespace` scope with a glob `use` statement.
+    ///
+    /// The items are not copied, but resolved lazily, so the items declared explicitly at the
+    /// current scope level take precedence, and the ambiguity between several globs is only
+    /// reported if the ambiguous item is actually referenced.
+    ///
+    pub fn define_glob(scope: Rc<RefCell<Scope>>, origin: String, namespace: Rc<RefCell<Scope>>) {
+        if scope.borrow().is_built_in {
+            panic!(zinc_const::panic::INTRINSIC_SCOPE_IMMUTABLE);
+        }
+
+        scope.borrow().globs.borrow_mut().push((origin, namespace));
+    }
+
     ///
     /// Defines a variable, which is usually a `let` binding or a function actual parameter.
     ///
@@ -189,16 +254,11 @@ impl Scope {
         r#type: Type,
         memory_type: MemoryType,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope
-            .borrow()
-            .resolve_item(&identifier, !identifier.is_self_lowercase())
-        {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope.borrow().check_item_redeclared(
+            &identifier,
+            identifier.location,
+            !identifier.is_self_lowercase(),
+        )?;
 
         let name = identifier.name.clone();
         let item = Item::Variable(VariableItem::new(
@@ -209,7 +269,7 @@ impl Scope {
             memory_type,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -226,13 +286,9 @@ impl Scope {
         is_implicit: bool,
         is_immutable: bool,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, false) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(&identifier, identifier.location, false)?;
 
         let name = identifier.name.clone();
         let item = Item::Field(FieldItem::new(
@@ -245,7 +301,7 @@ impl Scope {
             is_immutable,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -254,18 +310,18 @@ impl Scope {
     /// Declares a constant, saving the `const` statement to define itself later during the second
     /// pass or referencing for the first time.
     ///
+    /// The `module` is specified for implementation constants, which are only accessible from
+    /// the `impl` module if they are not public.
+    ///
     pub fn declare_constant(
         scope: Rc<RefCell<Scope>>,
         statement: ConstStatement,
         is_associated: bool,
+        module: Option<Rc<RefCell<Scope>>>,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&statement.identifier, true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: statement.location,
-                name: statement.identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(&statement.identifier, statement.location, true)?;
 
         let name = statement.identifier.name.clone();
         let item = Item::Constant(ConstantItem::new_declared(
@@ -273,9 +329,10 @@ impl Scope {
             statement,
             scope.clone(),
             is_associated,
+            module,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -289,13 +346,9 @@ impl Scope {
         constant: Constant,
         is_associated: bool,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(&identifier, identifier.location, true)?;
 
         let name = identifier.name;
         let item = Item::Constant(ConstantItem::new_defined(
@@ -304,7 +357,7 @@ impl Scope {
             is_associated,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -317,13 +370,9 @@ impl Scope {
         identifier: Identifier,
         constant: Constant,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, false) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(&identifier, identifier.location, false)?;
 
         let name = identifier.name;
         let item = Item::Variant(VariantItem::new(
@@ -332,7 +381,7 @@ impl Scope {
             constant,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -341,18 +390,18 @@ impl Scope {
     /// Declares a type, saving the `type`, `struct`, `enum`, `contract` or another statement to
     /// define itself later during the second pass or referencing for the first time.
     ///
+    /// The `module` is specified for implementation methods, which are only accessible from
+    /// the `impl` module if they are not public.
+    ///
     pub fn declare_type(
         scope: Rc<RefCell<Scope>>,
         statement: TypeStatementVariant,
         is_associated: bool,
+        module: Option<Rc<RefCell<Scope>>>,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&statement.identifier(), true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: statement.location(),
-                name: statement.identifier().name.to_owned(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(statement.identifier(), statement.location(), true)?;
 
         let name = statement.identifier().name.clone();
         let item = Item::Type(TypeItem::new_declared(
@@ -360,9 +409,10 @@ impl Scope {
             statement,
             scope.clone(),
             is_associated,
+            module,
         )?);
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -377,13 +427,11 @@ impl Scope {
         is_associated: bool,
         intermediate: Option<GeneratorStatement>,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: r#type.location().unwrap_or(identifier.location),
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope.borrow().check_item_redeclared(
+            &identifier,
+            r#type.location().unwrap_or(identifier.location),
+            true,
+        )?;
 
         let name = identifier.name;
         let item = Item::Type(TypeItem::new_defined(
@@ -394,7 +442,7 @@ impl Scope {
             intermediate,
         ));
 
-        scope.borrow().items.borrow_mut().insert(name, item.wrap());
+        scope.borrow().insert(name, item.wrap());
 
         Ok(())
     }
@@ -413,7 +461,12 @@ impl Scope {
             }));
         }
 
-        Scope::declare_type(scope, TypeStatementVariant::Contract(statement), false)
+        Scope::declare_type(
+            scope,
+            TypeStatementVariant::Contract(statement),
+            false,
+            None,
+        )
     }
 
     ///
@@ -427,13 +480,9 @@ impl Scope {
         scope_crate: Rc<RefCell<Scope>>,
         is_entry: bool,
     ) -> Result<(), SemanticError> {
-        if let Ok(item) = scope.borrow().resolve_item(&identifier, true) {
-            return Err(SemanticError::Scope(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: item.borrow().location(),
-            }));
-        }
+        scope
+            .borrow()
+            .check_item_redeclared(&identifier, identifier.location, true)?;
 
         let name = identifier.name.clone();
         let module_scope = Self::new_global(identifier.name.clone()).wrap();
@@ -453,11 +502,53 @@ impl Scope {
             .items
             .borrow_mut()
             .insert(Keyword::SelfLowercase.to_string(), item.clone());
-        scope.borrow().items.borrow_mut().insert(name, item);
+        scope.borrow().insert(name, item);
 
         Ok(())
     }
 
+    ///
+    /// Returns the names of the modules from the crate root down to the module containing the
+    /// `scope`. The crate root itself is not included, so the items declared there have an
+    /// empty module path.
+    ///
+    /// The modules are linked only with their `super` aliases, so the path can only be
+    /// resolved after the modules have been defined.
+    ///
+    pub fn get_module_path(scope: Rc<RefCell<Scope>>) -> Vec<String> {
+        let mut names = Vec::new();
+
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            if scope.borrow().is_built_in {
+                break;
+            }
+
+            let parent = scope.borrow().parent.to_owned();
+            match parent {
+                Some(parent) if !parent.borrow().is_built_in => current = Some(parent),
+                _ => {
+                    let super_item = scope
+                        .borrow()
+                        .items
+                        .borrow()
+                        .get(Keyword::Super.to_string().as_str())
+                        .cloned();
+
+                    if let Some(super_item) = super_item {
+                        names.push(scope.borrow().name.to_owned());
+                        if let Item::Module(ref module) = *super_item.borrow() {
+                            current = module.scope().ok();
+                        }
+                    }
+                }
+            }
+        }
+
+        names.reverse();
+        names
+    }
+
     ///
     /// Returns the module `self` alias. Panics if the scope does not belong to a module or
     /// the alias has not been declared yet.
@@ -472,6 +563,69 @@ impl Scope {
             .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
     }
 
+    ///
+    /// Checks whether the `scope` belongs to the `module` or one of its nested modules.
+    ///
+    /// The scope hierarchy is walked up to the module level, and then the modules are walked
+    /// up through their `super` aliases, like in `get_module_path`.
+    ///
+    pub fn is_within_module(scope: Rc<RefCell<Scope>>, module: &Rc<RefCell<Scope>>) -> bool {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            if Rc::ptr_eq(&scope, module) {
+                return true;
+            }
+
+            if scope.borrow().is_built_in {
+                break;
+            }
+
+            let parent = scope.borrow().parent.to_owned();
+            match parent {
+                Some(parent) if !parent.borrow().is_built_in => current = Some(parent),
+                _ => {
+                    let super_item = scope
+                        .borrow()
+                        .items
+                        .borrow()
+                        .get(Keyword::Super.to_string().as_str())
+                        .cloned();
+
+                    if let Some(super_item) = super_item {
+                        if let Item::Module(ref module) = *super_item.borrow() {
+                            current = module.scope().ok();
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    ///
+    /// Checks whether the `item` is accessible from the `scope`.
+    ///
+    /// The non-public implementation items are only accessible from the module of their `impl`
+    /// and its nested modules.
+    ///
+    pub fn check_item_access(
+        scope: Rc<RefCell<Scope>>,
+        item: &Item,
+        identifier: &Identifier,
+    ) -> Result<(), SemanticError> {
+        match item.private_module() {
+            Some(ref module) if !Self::is_within_module(scope, module) => {
+                Err(SemanticError::Scope(Error::ItemIsPrivate {
+                    location: identifier.location,
+                    name: identifier.name.to_owned(),
+                    declared_at: item.location(),
+                }))
+            }
+            _ => Ok(()),
+        }
+    }
+
     ///
     /// Resolves an item at the specified path by looking through modules and type scopes.
     ///
@@ -487,11 +641,13 @@ impl Scope {
     /// an item can be accessed from within other implementation items (e.g. methods) without
     /// specifying the `Self::` prefix.
     ///
+    /// The path elements starting from the 2nd are checked for being accessible from the `scope`.
+    ///
     pub fn resolve_path(
         scope: Rc<RefCell<Scope>>,
         path: &Path,
     ) -> Result<Rc<RefCell<Item>>, SemanticError> {
-        let mut current_scope = scope;
+        let mut current_scope = scope.clone();
 
         for (index, identifier) in path.elements.iter().enumerate() {
             let is_element_first = index == 0;
@@ -500,6 +656,9 @@ impl Scope {
             let item = current_scope
                 .borrow()
                 .resolve_item(identifier, is_element_first)?;
+            if !is_element_first {
+                Self::check_item_access(scope.clone(), &item.borrow(), identifier)?;
+            }
             item.borrow().define()?;
 
             if path.elements.len() == 1 && item.borrow().is_associated() {
@@ -513,29 +672,7 @@ impl Scope {
                 return Ok(item);
             }
 
-            current_scope = match *item.borrow() {
-                Item::Module(ref module) => module.define()?,
-                Item::Type(ref r#type) => {
-                    let r#type = r#type.define()?;
-                    match r#type {
-                        Type::Enumeration(ref inner) => inner.scope.to_owned(),
-                        Type::Structure(ref inner) => inner.scope.to_owned(),
-                        Type::Contract(ref inner) => inner.scope.to_owned(),
-                        _ => {
-                            return Err(SemanticError::Scope(Error::ItemIsNotANamespace {
-                                location: identifier.location,
-                                name: identifier.name.to_owned(),
-                            }))
-                        }
-                    }
-                }
-                _ => {
-                    return Err(SemanticError::Scope(Error::ItemIsNotANamespace {
-                        location: identifier.location,
-                        name: identifier.name.to_owned(),
-                    }))
-                }
-            };
+            current_scope = Self::get_namespace_scope(&item.borrow(), identifier)?;
         }
 
         Err(SemanticError::Scope(Error::ItemUndeclared {
@@ -544,29 +681,196 @@ impl Scope {
         }))
     }
 
+    ///
+    /// Returns the scope of the namespace `item`, which is a module, structure, enumeration,
+    /// or contract.
+    ///
+    /// The `identifier` is the item name used for error reporting.
+    ///
+    pub fn get_namespace_scope(
+        item: &Item,
+        identifier: &Identifier,
+    ) -> Result<Rc<RefCell<Scope>>, SemanticError> {
+        match item {
+            Item::Module(ref module) => module.define(),
+            Item::Type(ref r#type) => match r#type.define()? {
+                Type::Enumeration(ref inner) => Ok(inner.scope.to_owned()),
+                Type::Structure(ref inner) => Ok(inner.scope.to_owned()),
+                Type::Contract(ref inner) => Ok(inner.scope.to_owned()),
+                _ => Err(SemanticError::Scope(Error::ItemIsNotANamespace {
+                    location: identifier.location,
+                    name: identifier.name.to_owned(),
+                })),
+            },
+            _ => Err(SemanticError::Scope(Error::ItemIsNotANamespace {
+                location: identifier.location,
+                name: identifier.name.to_owned(),
+            })),
+        }
+    }
+
     ///
     /// Resolves the item with `identifier` within the current `scope`. Looks through the parent scopes
     /// if `recursive` is true.
     ///
+    /// The items imported with glob `use` statements are looked up if there is no item declared
+    /// explicitly at the scope level.
+    ///
+    /// The resolved item is marked as used.
+    ///
     pub fn resolve_item(
         &self,
         identifier: &Identifier,
         recursive: bool,
     ) -> Result<Rc<RefCell<Item>>, SemanticError> {
-        match self.items.borrow().get(identifier.name.as_str()) {
-            Some(item) => Ok(item.to_owned()),
-            None => match self.parent {
-                Some(ref parent) if recursive => {
-                    parent.borrow().resolve_item(identifier, recursive)
-                }
-                Some(_) | None => Err(SemanticError::Scope(Error::ItemUndeclared {
-                    location: identifier.location,
+        let item = self.resolve_item_inner(identifier, recursive, true)?;
+        if let Ok(inner) = item.try_borrow() {
+            inner.mark_used();
+        }
+        Ok(item)
+    }
+
+    ///
+    /// Resolves the item with `identifier` like `resolve_item`, but ignores the glob imports.
+    ///
+    /// Is used to check whether an item is redeclared, since the explicit declarations
+    /// take precedence over the glob imports. The resolved item is not marked as used.
+    ///
+    pub fn resolve_declared_item(
+        &self,
+        identifier: &Identifier,
+        recursive: bool,
+    ) -> Result<Rc<RefCell<Item>>, SemanticError> {
+        self.resolve_item_inner(identifier, recursive, false)
+    }
+
+    ///
+    /// Checks whether the item with `identifier` has been already declared, which is used by the
+    /// item definition and declaration functions.
+    ///
+    /// If the conflicting item is an intrinsic one, the dedicated error without the reference
+    /// location is returned, since intrinsic items are not declared anywhere in the source code.
+    ///
+    fn check_item_redeclared(
+        &self,
+        identifier: &Identifier,
+        location: Location,
+        recursive: bool,
+    ) -> Result<(), SemanticError> {
+        if let Some(item) = self.items.borrow().get(identifier.name.as_str()) {
+            if self.is_built_in {
+                return Err(SemanticError::Scope(Error::ItemRedeclaredBuiltIn {
+                    location,
                     name: identifier.name.to_owned(),
-                })),
-            },
+                }));
+            }
+
+            return Err(SemanticError::Scope(Error::ItemRedeclared {
+                location,
+                name: identifier.name.to_owned(),
+                reference: item.borrow().location(),
+            }));
+        }
+
+        match self.parent {
+            Some(ref parent) if recursive => parent
+                .borrow()
+                .check_item_redeclared(identifier, location, recursive),
+            Some(_) | None => Ok(()),
         }
     }
 
+    ///
+    /// The `resolve_item` and `resolve_declared_item` implementation.
+    ///
+    fn resolve_item_inner(
+        &self,
+        identifier: &Identifier,
+        recursive: bool,
+        is_glob_included: bool,
+    ) -> Result<Rc<RefCell<Item>>, SemanticError> {
+        if let Some(item) = self.items.borrow().get(identifier.name.as_str()) {
+            return Ok(item.to_owned());
+        }
+
+        if is_glob_included {
+            if let Some(item) = self.resolve_glob_item(identifier)? {
+                return Ok(item);
+            }
+        }
+
+        match self.parent {
+            Some(ref parent) if recursive => {
+                parent
+                    .borrow()
+                    .resolve_item_inner(identifier, recursive, is_glob_included)
+            }
+            Some(_) | None => Err(SemanticError::Scope(Error::ItemUndeclared {
+                location: identifier.location,
+                name: identifier.name.to_owned(),
+            })),
+        }
+    }
+
+    ///
+    /// Resolves the item with `identifier` among the items imported with glob `use` statements.
+    ///
+    /// The namespace aliases like `self` and `Self` are never imported. If the item is imported
+    /// from several namespaces, the ambiguity error is returned.
+    ///
+    /// Associated items are imported as non-associated ones, like with ordinar `use` statements.
+    ///
+    fn resolve_glob_item(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<Option<Rc<RefCell<Item>>>, SemanticError> {
+        if Keyword::is_alias(identifier.name.as_str()) {
+            return Ok(None);
+        }
+
+        let mut found: Option<(&str, Rc<RefCell<Item>>)> = None;
+        let globs = self.globs.borrow();
+        for (origin, namespace) in globs.iter() {
+            let item = match namespace
+                .borrow()
+                .items
+                .borrow()
+                .get(identifier.name.as_str())
+            {
+                Some(item) => item.to_owned(),
+                None => continue,
+            };
+
+            match found {
+                Some((_first, ref first_item)) if Rc::ptr_eq(first_item, &item) => {}
+                Some((first, _)) => {
+                    return Err(SemanticError::Scope(Error::AmbiguousItem {
+                        location: identifier.location,
+                        name: identifier.name.to_owned(),
+                        first: first.to_owned(),
+                        second: origin.to_owned(),
+                    }))
+                }
+                None => found = Some((origin.as_str(), item)),
+            }
+        }
+
+        let item = match found {
+            Some((_origin, item)) => item,
+            None => return Ok(None),
+        };
+
+        item.borrow().define()?;
+        item.borrow().mark_used();
+        if !item.borrow().is_associated() {
+            return Ok(Some(item));
+        }
+
+        let mut item = item.borrow().clone();
+        item.set_not_associated();
+        Ok(Some(item.wrap()))
+    }
+
     ///
     /// Resolves the `std::collections::MTreeMap` type.
     ///
@@ -654,6 +958,191 @@ impl Scope {
             .collect()
     }
 
+    ///
+    /// Reports a `warning` found during the analysis.
+    ///
+    /// The warning is stored in the outermost non-intrinsic ancestor of the `scope`, so it is
+    /// not lost if the `scope` itself is not reachable by the post-analysis passes.
+    ///
+    pub fn push_warning(scope: Rc<RefCell<Scope>>, warning: Warning) {
+        let mut scope = scope;
+        loop {
+            let parent = match scope.borrow().parent {
+                Some(ref parent) if !parent.borrow().is_built_in => parent.to_owned(),
+                _ => break,
+            };
+            scope = parent;
+        }
+
+        scope.borrow().warnings.borrow_mut().push(warning);
+    }
+
+    ///
+    /// Marks the scope as the body of the immutable contract method `name`.
+    ///
+    /// Such methods are executed as queries, so their storage changes would be discarded.
+    ///
+    pub fn set_query_method(&self, name: String) {
+        self.query_method.replace(Some(name));
+    }
+
+    ///
+    /// Returns the name of the immutable contract method, which the scope is a part of.
+    ///
+    pub fn query_method(scope: Rc<RefCell<Scope>>) -> Option<String> {
+        let mut scope = scope;
+        loop {
+            if let Some(name) = scope.borrow().query_method.borrow().to_owned() {
+                return Some(name);
+            }
+
+            let parent = match scope.borrow().parent {
+                Some(ref parent) if !parent.borrow().is_built_in => parent.to_owned(),
+                _ => return None,
+            };
+            scope = parent;
+        }
+    }
+
+    ///
+    /// Walks through the scope, its child scopes, and module scopes, collecting warnings about
+    /// variables, constants, and non-public functions which have never been referenced.
+    ///
+    /// The items whose names start with an underscore are not reported.
+    ///
+    /// The warnings reported during the analysis with `push_warning` are also collected.
+    ///
+    pub fn get_unused_items(scope: Rc<RefCell<Scope>>) -> Vec<Warning> {
+        let mut visited = HashSet::new();
+        let mut warnings = Vec::new();
+        Self::collect_unused_items(scope, &mut visited, &mut warnings);
+
+        warnings.sort_by_key(|warning| {
+            let location = warning.location();
+            (location.file, location.line, location.column)
+        });
+        warnings.dedup();
+        warnings
+    }
+
+    ///
+    /// The recursive `get_unused_items` implementation.
+    ///
+    fn collect_unused_items(
+        scope: Rc<RefCell<Scope>>,
+        visited: &mut HashSet<*const RefCell<Scope>>,
+        warnings: &mut Vec<Warning>,
+    ) {
+        if scope.borrow().is_built_in || !visited.insert(Rc::as_ptr(&scope)) {
+            return;
+        }
+
+        warnings.extend(scope.borrow().warnings.borrow().iter().cloned());
+        warnings.extend(scope.borrow().unused_items());
+
+        let modules: Vec<Rc<RefCell<Scope>>> = scope
+            .borrow()
+            .items
+            .borrow()
+            .iter()
+            .filter(|(name, _item)| !Keyword::is_alias(name.as_str()))
+            .filter_map(|(_name, item)| match *item.borrow() {
+                Item::Module(ref module) => module.scope().ok(),
+                _ => None,
+            })
+            .collect();
+        for module in modules.into_iter() {
+            Self::collect_unused_items(module, visited, warnings);
+        }
+
+        let children: Vec<Rc<RefCell<Scope>>> = scope
+            .borrow()
+            .children
+            .borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect();
+        for child in children.into_iter() {
+            Self::collect_unused_items(child, visited, warnings);
+        }
+    }
+
+    ///
+    /// Returns the warnings about the variables, constants, and non-public functions declared
+    /// in the scope itself, which have never been referenced.
+    ///
+    fn unused_items(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for (name, item) in self.items.borrow().iter() {
+            if Keyword::is_alias(name.as_str()) {
+                continue;
+            }
+
+            let item = item.borrow();
+            if let Item::Module(_) = *item {
+                continue;
+            }
+
+            if item.is_used() || name.starts_with('_') {
+                continue;
+            }
+
+            let location = match item.location() {
+                Some(location) => location,
+                None => continue,
+            };
+
+            match *item {
+                Item::Variable(_) => warnings.push(Warning::UnusedVariable {
+                    location,
+                    name: name.to_owned(),
+                }),
+                Item::Constant(_) => warnings.push(Warning::UnusedConstant {
+                    location,
+                    name: name.to_owned(),
+                }),
+                Item::Type(ref r#type) if !r#type.is_public => match r#type.define() {
+                    Ok(Type::Function(FunctionType::Runtime(_)))
+                    | Ok(Type::Function(FunctionType::Generic(_)))
+                    | Ok(Type::Function(FunctionType::Constant(_)))
+                        if name.as_str() != zinc_const::source::FUNCTION_MAIN_IDENTIFIER =>
+                    {
+                        warnings.push(Warning::UnusedFunction {
+                            location,
+                            name: name.to_owned(),
+                        })
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        warnings
+    }
+
+    ///
+    /// Whether the scope is an intrinsic one, which is shared between compilations.
+    ///
+    pub fn is_built_in(&self) -> bool {
+        self.is_built_in
+    }
+
+    ///
+    /// Inserts an item into the scope hashmap.
+    ///
+    /// Panics if the scope is an intrinsic one, since it is shared between compilations and
+    /// must not be modified after initialization.
+    ///
+    fn insert(&self, name: String, item: Rc<RefCell<Item>>) {
+        if self.is_built_in {
+            panic!(zinc_const::panic::INTRINSIC_SCOPE_IMMUTABLE);
+        }
+
+        self.items.borrow_mut().insert(name, item);
+    }
+
     ///
     /// Returns the scope name.
     ///
@@ -54,11 +54,13 @@ impl Stack {
     }
 
     ///
-    /// Removes the deepest scope from the current hierarchy.
+    /// Removes the deepest scope from the current hierarchy, reporting its unused items.
     ///
     pub fn pop(&mut self) {
-        self.elements
+        let scope = self
+            .elements
             .pop()
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        Scope::close(scope);
     }
 }
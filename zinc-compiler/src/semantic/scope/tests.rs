@@ -4,11 +4,15 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::rc::Rc;
 
 use crate::error::Error;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::intrinsic::IntrinsicScope;
+use crate::semantic::scope::Scope;
 use crate::source::Source;
+use crate::warning::Warning;
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
@@ -193,6 +197,101 @@ fn main() -> Y {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_item_redeclared_built_in_function_require() {
+    let input = r#"
+fn require(value: bool) -> bool { value }
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemRedeclaredBuiltIn {
+            location: Location::test(2, 1),
+            name: "require".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_built_in_function_dbg() {
+    let input = r#"
+fn main() {
+    let dbg = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemRedeclaredBuiltIn {
+            location: Location::test(3, 9),
+            name: "dbg".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_built_in_module_std() {
+    let std = r#"
+const VALUE: u8 = 42;
+"#;
+
+    let entry = r#"
+mod std;
+
+fn main() -> u8 { 42 }
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemRedeclaredBuiltIn {
+            location: Location::test(2, 5),
+            name: "std".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(
+        entry,
+        vec![(
+            "std".to_owned(),
+            Source::test(std, PathBuf::from("std.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Source>>(),
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_built_in_module_zksync() {
+    let input = r#"
+struct zksync {
+    value: u8,
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::ItemRedeclaredBuiltIn {
+            location: Location::test(2, 1),
+            name: "zksync".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_item_undeclared() {
     let input = r#"
@@ -577,6 +676,40 @@ contract Multiswap {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_constant_forward_reference() {
+    let input = r#"
+const A: u8 = B * 2;
+const B: u8 = C + 1;
+const C: u8 = 20;
+
+fn main() -> u8 { A }
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_type_forward_reference() {
+    let input = r#"
+struct Outer {
+    inner: Inner,
+}
+
+struct Inner {
+    values: [u8; SIZE],
+}
+
+const SIZE: u8 = 4;
+
+fn main() -> Outer {
+    Outer { inner: Inner { values: [1, 2, 3, 4] } }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_reference_loop_constant_direct() {
     let input = r#"
@@ -587,8 +720,9 @@ fn main() -> u8 { B }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::ConstantDependencyCycle {
             location: Location::test(2, 7),
+            chain: vec!["A".to_owned(), "B".to_owned(), "A".to_owned()],
         },
     )));
 
@@ -609,8 +743,15 @@ fn main() -> u8 { D }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::ConstantDependencyCycle {
             location: Location::test(2, 7),
+            chain: vec![
+                "A".to_owned(),
+                "B".to_owned(),
+                "C".to_owned(),
+                "D".to_owned(),
+                "A".to_owned(),
+            ],
         },
     )));
 
@@ -629,8 +770,36 @@ fn main() -> A {}
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::TypeDependencyCycle {
             location: Location::test(2, 1),
+            chain: vec!["A".to_owned(), "B".to_owned(), "A".to_owned()],
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_loop_type_mutual_structures() {
+    let input = r#"
+struct First {
+    value: u8,
+    second: Second,
+}
+
+struct Second {
+    first: First,
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        ScopeError::TypeDependencyCycle {
+            location: Location::test(2, 1),
+            chain: vec!["First".to_owned(), "Second".to_owned(), "First".to_owned()],
         },
     )));
 
@@ -660,8 +829,14 @@ fn main() -> bool {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::TypeDependencyCycle {
             location: Location::test(2, 1),
+            chain: vec![
+                "Outer".to_owned(),
+                "Inner".to_owned(),
+                "InnerMost".to_owned(),
+                "Outer".to_owned(),
+            ],
         },
     )));
 
@@ -681,8 +856,9 @@ fn main() {}
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::TypeDependencyCycle {
             location: Location::test(2, 1),
+            chain: vec!["Array".to_owned(), "SIZE".to_owned(), "Array".to_owned()],
         },
     )));
 
@@ -709,8 +885,14 @@ fn main() -> Array {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        ScopeError::ReferenceLoop {
+        ScopeError::TypeDependencyCycle {
             location: Location::test(2, 1),
+            chain: vec![
+                "Array".to_owned(),
+                "SIZE".to_owned(),
+                "Size".to_owned(),
+                "Array".to_owned(),
+            ],
         },
     )));
 
@@ -1132,3 +1314,358 @@ fn main() -> u8 { Call { value: 42 }.call() }
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_used_items_no_warnings() {
+    let input = r#"
+const VALUE: u8 = 42;
+
+fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn main() -> u8 {
+    let result = add(VALUE, 1);
+    result
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_underscored_items_no_warnings() {
+    let input = r#"
+const _VALUE: u8 = 42;
+
+fn _unused() {}
+
+fn main() {
+    let _result = 42;
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main() {
+    let result = 42;
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(3, 9),
+        name: "result".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_constant() {
+    let input = r#"
+const VALUE: u8 = 42;
+
+fn main() {}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedConstant {
+        location: Location::test(2, 7),
+        name: "VALUE".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function() {
+    let input = r#"
+fn unused() -> u8 {
+    42
+}
+
+fn main() {}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedFunction {
+        location: Location::test(2, 1),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_redundant_structure_base() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+fn main() -> Data {
+    let base = Data { a: 1, b: 2 };
+    Data { a: 42, b: 25, ..base }
+}
+"#;
+
+    let expected = Ok(vec![Warning::RedundantStructureBase {
+        location: Location::test(9, 28),
+        type_identifier: "Data".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_integer_literal_widened() {
+    let input = r#"
+fn main(value: u64) -> u64 {
+    value + 200
+}
+"#;
+
+    let expected = Ok(vec![Warning::IntegerLiteralWidened {
+        location: Location::test(3, 13),
+        value: "200".to_owned(),
+        from: "u8".to_owned(),
+        to: "u64".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_integer_literal_casted_explicitly() {
+    let input = r#"
+fn main(value: u64) -> u64 {
+    value + 200 as u64
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function_argument() {
+    let input = r#"
+fn unused(value: u8) -> u8 {
+    42
+}
+
+fn main() -> u8 {
+    unused(42)
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(2, 11),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_variable_assigned_and_read_no_warnings() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value = 42;
+    value = value + 1;
+    value
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable_assigned_only() {
+    let input = r#"
+fn main() {
+    let mut value = 42;
+    value = 64;
+    value += 1;
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(3, 13),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable_nested_block() {
+    let input = r#"
+fn main() -> u8 {
+    {
+        let value = 42;
+    };
+    42
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(4, 13),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable_generic_function() {
+    let input = r#"
+fn first<T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() -> u8 {
+    first(1, 2)
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(2, 19),
+        name: "b".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_require_always_fails() {
+    let input = r#"
+fn main() {
+    require(false, "always");
+}
+"#;
+
+    let expected = Ok(vec![Warning::RequireAlwaysFails {
+        location: Location::test(3, 5),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_redundant_require() {
+    let input = r#"
+fn main() {
+    require(2 > 1);
+}
+"#;
+
+    let expected = Ok(vec![Warning::RedundantRequire {
+        location: Location::test(3, 5),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_constant_condition() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    if 1 == 2 { value } else { 42 }
+}
+"#;
+
+    let expected = Ok(vec![Warning::ConstantCondition {
+        location: Location::test(3, 8),
+        value: false,
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_runtime_require_and_condition_no_warnings() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    require(value > 0);
+    if value > 1 { value } else { 42 }
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_intrinsic_scope_shared() {
+    let input = r#"
+fn main() -> u8 {
+    require(true);
+    42
+}
+"#;
+
+    let intrinsic = IntrinsicScope::initialize();
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+
+    let parent = Scope::new_global("test".to_owned())
+        .parent()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(Rc::ptr_eq(&intrinsic, &parent));
+    assert!(Rc::ptr_eq(&intrinsic, &IntrinsicScope::initialize()));
+}
+
+#[test]
+#[should_panic(expected = "The intrinsic scope must not be modified")]
+fn error_intrinsic_scope_modified() {
+    let intrinsic = IntrinsicScope::initialize();
+
+    Scope::define_glob(intrinsic.clone(), "intrinsic".to_owned(), intrinsic);
+}
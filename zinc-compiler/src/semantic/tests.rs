@@ -15,7 +15,9 @@ use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
+use crate::source::library::Library;
 use crate::source::Source;
+use crate::warning::Warning;
 
 pub(crate) fn compile_entry(code: &str) -> Result<(), Error> {
     compile_entry_with_dependencies(code, HashMap::new())
@@ -31,6 +33,43 @@ pub(crate) fn compile_entry_with_dependencies(
     Ok(())
 }
 
+pub(crate) fn compile_entry_with_libraries(
+    code: &str,
+    libraries: HashMap<String, Library>,
+) -> Result<(), Error> {
+    let path = PathBuf::from("test.zn");
+    EntryAnalyzer::define_with_libraries(Source::test(code, path, HashMap::new())?, libraries)
+        .map_err(Error::Semantic)?;
+
+    Ok(())
+}
+
+pub(crate) fn compile_library(code: &str) -> Result<(), Error> {
+    let path = PathBuf::from("main.zn");
+    EntryAnalyzer::define_library(Source::test(code, path, HashMap::new())?, HashMap::new())
+        .map_err(Error::Semantic)?;
+
+    Ok(())
+}
+
+pub(crate) fn compile_entry_with_entry_size_limit(code: &str, limit: usize) -> Result<(), Error> {
+    let path = PathBuf::from("test.zn");
+    let scope = EntryAnalyzer::define(Source::test(code, path, HashMap::new())?)
+        .map_err(Error::Semantic)?;
+    EntryAnalyzer::check_entry_sizes(scope.borrow().get_intermediate().as_slice(), limit)
+        .map_err(Error::Semantic)?;
+
+    Ok(())
+}
+
+pub(crate) fn compile_entry_warnings(code: &str) -> Result<Vec<Warning>, Error> {
+    let path = PathBuf::from("test.zn");
+    let scope = EntryAnalyzer::define(Source::test(code, path, HashMap::new())?)
+        .map_err(Error::Semantic)?;
+
+    Ok(Scope::get_unused_items(scope))
+}
+
 pub(crate) fn compile_module(
     code: &str,
     file: usize,
@@ -143,6 +182,89 @@ contract Uniswap {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_entry_input_size_limit_overridden() {
+    let code = r#"
+contract Uniswap {
+    pub fn deposit(self, data: [u8; 100000]) -> bool { true }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_entry_size_limit(code, 200000);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_entry_input_too_large() {
+    let code = r#"
+contract Uniswap {
+    pub fn deposit(self, data: [u8; 100000]) -> bool { true }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::EntryInputTooLarge {
+        location: Location::test(3, 5),
+        method: "deposit".to_owned(),
+        size: 100001,
+        limit: zinc_const::limit::ENTRY_INPUT_SIZE,
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_entry_size_limit(
+        code,
+        zinc_const::limit::ENTRY_INPUT_SIZE,
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_entry_input_too_large_concatenation() {
+    let code = r#"
+contract Uniswap {
+    pub fn deposit(self, data: [u8; 10000]) -> [u8; 20000] { data + data }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::EntryInputTooLarge {
+        location: Location::test(3, 5),
+        method: "deposit".to_owned(),
+        size: 30000,
+        limit: zinc_const::limit::ENTRY_INPUT_SIZE,
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_entry_size_limit(
+        code,
+        zinc_const::limit::ENTRY_INPUT_SIZE,
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_contract_storage_too_large() {
+    let code = r#"
+contract Uniswap {
+    data: [u8; 100000];
+
+    pub fn deposit(self) -> bool { true }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractStorageTooLarge {
+        location: Location::test(2, 1),
+        size: 100002,
+        limit: zinc_const::limit::ENTRY_INPUT_SIZE,
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_entry_size_limit(
+        code,
+        zinc_const::limit::ENTRY_INPUT_SIZE,
+    );
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_function_main_beyond_entry() {
     let code = r#"
@@ -167,6 +289,57 @@ fn main() -> u8 {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_library_dependency() {
+    let library = r#"
+pub const VALUE: u8 = 21;
+
+pub fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let entry = r#"
+use math::double;
+
+fn main() -> u8 {
+    double(math::VALUE)
+}
+"#;
+
+    let library = Library {
+        source: Source::test(library, PathBuf::from("main.zn"), HashMap::new())
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        dependencies: HashMap::new(),
+    };
+
+    let result = crate::semantic::tests::compile_entry_with_libraries(
+        entry,
+        vec![("math".to_owned(), library)]
+            .into_iter()
+            .collect::<HashMap<String, Library>>(),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_library_function_main() {
+    let code = r#"
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionMainBeyondEntry {
+        location: Location::test(2, 1),
+    }));
+
+    let result = crate::semantic::tests::compile_library(code);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_contract_beyond_entry() {
     let code = r#"
@@ -6,7 +6,6 @@ pub mod error;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -14,12 +13,18 @@ use zinc_manifest::Manifest;
 
 use crate::error::Error as CompilerError;
 use crate::generator::module::Module;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::scope::Scope;
 use crate::source::error::Error as SourceError;
 use crate::source::file::File;
+use crate::source::library::Library;
+use crate::source::provider::file_system::Provider as FileSystemProvider;
+use crate::source::provider::ISourceProvider;
 use crate::source::Source;
+use crate::warning::Warning;
 
 use self::error::Error;
 
@@ -95,7 +100,19 @@ impl Directory {
     /// Initializes an application module from a hard disk directory.
     ///
     pub fn try_from_path(path: &PathBuf, is_entry: bool) -> Result<Self, SourceError> {
-        let directory = fs::read_dir(path)
+        Self::try_from_path_with_provider(path, is_entry, &FileSystemProvider::default())
+    }
+
+    ///
+    /// Initializes an application module from a directory of the source code `provider`.
+    ///
+    pub fn try_from_path_with_provider<P: ISourceProvider>(
+        path: &PathBuf,
+        is_entry: bool,
+        provider: &P,
+    ) -> Result<Self, SourceError> {
+        let directory = provider
+            .list_dir(path)
             .map_err(Error::Reading)
             .map_err(SourceError::Directory)?;
 
@@ -109,12 +126,8 @@ impl Directory {
         let mut entry = None;
         let mut modules = HashMap::new();
 
-        for directory_entry in directory.into_iter() {
-            let directory_entry = directory_entry
-                .map_err(Error::DirectoryEntry)
-                .map_err(SourceError::Directory)?;
-            let path = directory_entry.path();
-            let module = Source::try_from_path(&path)?;
+        for path in directory.into_iter() {
+            let module = Source::try_from_path_with_provider(&path, provider)?;
             let name = module.name().to_owned();
 
             match module {
@@ -155,16 +168,41 @@ impl Directory {
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
-    pub fn compile(self, manifest: Manifest) -> Result<Rc<RefCell<State>>, SourceError> {
-        let scope = EntryAnalyzer::define(Source::Directory(self))
+    /// Also returns the warnings collected after the semantic analysis.
+    ///
+    /// If `is_intermediate_printed` is set, the intermediate representation listing is saved
+    /// to the generator state.
+    ///
+    pub fn compile(
+        self,
+        manifest: Manifest,
+        libraries: HashMap<String, Library>,
+        is_intermediate_printed: bool,
+    ) -> Result<(Rc<RefCell<State>>, Vec<Warning>), SourceError> {
+        let scope = EntryAnalyzer::define_with_libraries(Source::Directory(self), libraries)
             .map_err(CompilerError::Semantic)
             .map_err(|error| error.format())
             .map_err(SourceError::Compiling)?;
 
-        let state = State::new(manifest).wrap();
-        Module::new(scope.borrow().get_intermediate()).write_all(state.clone());
+        let warnings = Scope::get_unused_items(scope.clone());
+
+        let intermediate = scope.borrow().get_intermediate();
+        EntryAnalyzer::check_entry_sizes(
+            intermediate.as_slice(),
+            manifest.compiler.entry_input_size_limit,
+        )
+        .map_err(CompilerError::Semantic)
+        .map_err(|error| error.format())
+        .map_err(SourceError::Compiling)?;
+
+        let mut state = State::new(manifest);
+        if is_intermediate_printed {
+            state.set_intermediate(Printer::intermediate(intermediate.as_slice()));
+        }
+        let state = state.wrap();
+        Module::new(intermediate).write_all(state.clone());
 
-        Ok(state)
+        Ok((state, warnings))
     }
 
     ///
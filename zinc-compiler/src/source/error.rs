@@ -23,6 +23,16 @@ pub enum Error {
     /// The source code directory error.
     Directory(DirectoryError),
 
+    /// The library interface has been built by another compiler version.
+    LibraryVersionMismatch {
+        /// The library name.
+        name: String,
+        /// The current compiler version.
+        expected: String,
+        /// The version of the compiler which has built the library.
+        found: String,
+    },
+
     /// The source code compiler analysis error, formatted as string.
     Compiling(String),
 }
@@ -35,6 +45,15 @@ impl fmt::Display for Error {
 
             Self::File(inner) => write!(f, "file: {}", inner),
             Self::Directory(inner) => write!(f, "directory: {}", inner),
+            Self::LibraryVersionMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "library `{}` has been built by the compiler v{}, but the current one is v{}: rebuild the library",
+                name, found, expected
+            ),
 
             Self::Compiling(inner) => write!(f, "{}", inner),
         }
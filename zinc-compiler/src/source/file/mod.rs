@@ -5,23 +5,35 @@
 pub mod error;
 
 use std::cell::RefCell;
-use std::fs;
-use std::io::Read;
+use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Instant;
 
+use zinc_lexical::Lexeme;
+use zinc_lexical::TokenStream;
 use zinc_lexical::FILE_INDEX;
 use zinc_manifest::Manifest;
 use zinc_syntax::Module as SyntaxModule;
 use zinc_syntax::Parser;
+use zinc_syntax::ParsingError;
 
 use crate::error::Error as CompilerError;
 use crate::generator::module::Module;
+use crate::generator::printer::Printer;
 use crate::generator::state::State;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::scope::Scope;
 use crate::source::error::Error as SourceError;
+use crate::source::library::Library;
+use crate::source::provider::file_system::Provider as FileSystemProvider;
+use crate::source::provider::ISourceProvider;
 use crate::source::Source;
+use crate::timings::phase::Phase;
+use crate::timings::TIMINGS;
+use crate::warning::Warning;
 
 use self::error::Error;
 
@@ -48,21 +60,21 @@ impl File {
         let path = PathBuf::from(file.path);
 
         let next_file_id = FILE_INDEX.next(&path, file.code);
-        let tree = Parser::default()
-            .parse(
-                FILE_INDEX
-                    .inner
-                    .read()
-                    .expect(zinc_const::panic::SYNCHRONIZATION)
-                    .get(&next_file_id)
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-                    .code
-                    .as_str(),
-                next_file_id,
-            )
-            .map_err(CompilerError::from)
-            .map_err(|error| error.format())
-            .map_err(SourceError::Compiling)?;
+        let tree = Self::parse(
+            &path,
+            FILE_INDEX
+                .inner
+                .read()
+                .expect(zinc_const::panic::SYNCHRONIZATION)
+                .get(&next_file_id)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+                .code
+                .as_str(),
+            next_file_id,
+        )
+        .map_err(CompilerError::from)
+        .map_err(|error| error.format())
+        .map_err(SourceError::Compiling)?;
 
         Ok(Self {
             path,
@@ -75,18 +87,18 @@ impl File {
     /// Initializes an application module from a hard disk file.
     ///
     pub fn try_from_path(path: &PathBuf) -> Result<Self, SourceError> {
-        let mut file = fs::File::open(&path)
-            .map_err(Error::Opening)
-            .map_err(SourceError::File)?;
-
-        let size = file
-            .metadata()
-            .map_err(Error::Metadata)
-            .map_err(SourceError::File)?
-            .len() as usize;
+        Self::try_from_path_with_provider(path, &FileSystemProvider::default())
+    }
 
-        let mut code = String::with_capacity(size);
-        file.read_to_string(&mut code)
+    ///
+    /// Initializes an application module from a file of the source code `provider`.
+    ///
+    pub fn try_from_path_with_provider<P: ISourceProvider>(
+        path: &PathBuf,
+        provider: &P,
+    ) -> Result<Self, SourceError> {
+        let code = provider
+            .read_file(path)
             .map_err(Error::Reading)
             .map_err(SourceError::File)?;
 
@@ -108,21 +120,21 @@ impl File {
             .to_string();
 
         let next_file_id = FILE_INDEX.next(path, code);
-        let tree = Parser::default()
-            .parse(
-                FILE_INDEX
-                    .inner
-                    .read()
-                    .expect(zinc_const::panic::SYNCHRONIZATION)
-                    .get(&next_file_id)
-                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-                    .code
-                    .as_str(),
-                next_file_id,
-            )
-            .map_err(CompilerError::from)
-            .map_err(|error| error.format())
-            .map_err(SourceError::Compiling)?;
+        let tree = Self::parse(
+            path,
+            FILE_INDEX
+                .inner
+                .read()
+                .expect(zinc_const::panic::SYNCHRONIZATION)
+                .get(&next_file_id)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+                .code
+                .as_str(),
+            next_file_id,
+        )
+        .map_err(CompilerError::from)
+        .map_err(|error| error.format())
+        .map_err(SourceError::Compiling)?;
 
         Ok(Self {
             path: path.to_owned(),
@@ -135,16 +147,41 @@ impl File {
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
-    pub fn compile(self, manifest: Manifest) -> Result<Rc<RefCell<State>>, SourceError> {
-        let scope = EntryAnalyzer::define(Source::File(self))
+    /// Also returns the warnings collected after the semantic analysis.
+    ///
+    /// If `is_intermediate_printed` is set, the intermediate representation listing is saved
+    /// to the generator state.
+    ///
+    pub fn compile(
+        self,
+        manifest: Manifest,
+        libraries: HashMap<String, Library>,
+        is_intermediate_printed: bool,
+    ) -> Result<(Rc<RefCell<State>>, Vec<Warning>), SourceError> {
+        let scope = EntryAnalyzer::define_with_libraries(Source::File(self), libraries)
             .map_err(CompilerError::Semantic)
             .map_err(|error| error.format())
             .map_err(SourceError::Compiling)?;
 
-        let state = State::new(manifest).wrap();
-        Module::new(scope.borrow().get_intermediate()).write_all(state.clone());
+        let warnings = Scope::get_unused_items(scope.clone());
+
+        let intermediate = scope.borrow().get_intermediate();
+        EntryAnalyzer::check_entry_sizes(
+            intermediate.as_slice(),
+            manifest.compiler.entry_input_size_limit,
+        )
+        .map_err(CompilerError::Semantic)
+        .map_err(|error| error.format())
+        .map_err(SourceError::Compiling)?;
 
-        Ok(state)
+        let mut state = State::new(manifest);
+        if is_intermediate_printed {
+            state.set_intermediate(Printer::intermediate(intermediate.as_slice()));
+        }
+        let state = state.wrap();
+        Module::new(intermediate).write_all(state.clone());
+
+        Ok((state, warnings))
     }
 
     ///
@@ -174,7 +211,7 @@ impl File {
     pub fn test(code: &str, path: PathBuf) -> Result<Self, CompilerError> {
         let next_file_id = FILE_INDEX.peek();
 
-        let tree = Parser::default().parse(code, next_file_id)?;
+        let tree = Self::parse(&path, code, next_file_id)?;
 
         FILE_INDEX.next(&path, code.to_owned());
 
@@ -184,4 +221,37 @@ impl File {
             tree,
         })
     }
+
+    ///
+    /// Parses the file `code` with the `file` index into the syntax tree.
+    ///
+    /// If the compilation timings are enabled, the lexical analysis is additionally performed
+    /// in a separate pass to measure it apart from the syntax analysis, which includes the
+    /// on-demand lexical analysis performed by the parser.
+    ///
+    fn parse(path: &Path, code: &str, file: usize) -> Result<SyntaxModule, ParsingError> {
+        if !TIMINGS.is_enabled() {
+            return Parser::default().parse(code, file);
+        }
+
+        let path = path.to_string_lossy();
+
+        let start = Instant::now();
+        let mut stream = TokenStream::new(code, file);
+        while let Ok(token) = stream.next() {
+            if let Lexeme::Eof = token.lexeme {
+                break;
+            }
+        }
+        let lexical = start.elapsed();
+
+        let start = Instant::now();
+        let tree = Parser::default().parse(code, file);
+        let syntax = start.elapsed().checked_sub(lexical).unwrap_or_default();
+
+        TIMINGS.record(path.as_ref(), Phase::Lexical, lexical);
+        TIMINGS.record(path.as_ref(), Phase::Syntax, syntax);
+
+        tree
+    }
 }
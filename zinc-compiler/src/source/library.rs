@@ -0,0 +1,50 @@
+//!
+//! The source code library.
+//!
+
+use std::collections::HashMap;
+
+use crate::source::error::Error as SourceError;
+use crate::source::Source;
+
+///
+/// The external library, which is analyzed as a separate crate and injected into the scope
+/// of the dependent application or library.
+///
+#[derive(Debug, Clone)]
+pub struct Library {
+    /// The library root module.
+    pub source: Source,
+    /// The libraries the library depends on, with the dependency names as keys.
+    pub dependencies: HashMap<String, Library>,
+}
+
+impl Library {
+    ///
+    /// Initializes a library from its interface, which is the library project build output.
+    ///
+    /// The interface must have been built by the same compiler version, including the
+    /// interfaces of the library own dependencies.
+    ///
+    pub fn try_from_interface(interface: zinc_source::Library) -> Result<Self, SourceError> {
+        if interface.compiler_version.as_str() != crate::VERSION {
+            return Err(SourceError::LibraryVersionMismatch {
+                name: interface.name,
+                expected: crate::VERSION.to_owned(),
+                found: interface.compiler_version,
+            });
+        }
+
+        let source = Source::try_from_string(interface.source, true)?;
+
+        let mut dependencies = HashMap::with_capacity(interface.dependencies.len());
+        for (name, dependency) in interface.dependencies.into_iter() {
+            dependencies.insert(name, Self::try_from_interface(dependency)?);
+        }
+
+        Ok(Self {
+            source,
+            dependencies,
+        })
+    }
+}
@@ -5,10 +5,14 @@
 pub mod directory;
 pub mod error;
 pub mod file;
+pub mod library;
+pub mod provider;
+
+#[cfg(test)]
+mod tests;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -16,10 +20,17 @@ use zinc_manifest::Manifest;
 
 use crate::error::Error as CompilerError;
 use crate::generator::state::State;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 use self::directory::Directory;
 use self::error::Error;
 use self::file::File;
+use self::library::Library;
+use self::provider::file_system::Provider as FileSystemProvider;
+use self::provider::EntryType;
+use self::provider::ISourceProvider;
 
 ///
 /// The file system source code representation.
@@ -49,47 +60,93 @@ impl Source {
     /// Initializes the entry application module representation from the file system.
     ///
     pub fn try_from_entry(path: &PathBuf) -> Result<Self, Error> {
-        let file_type = fs::metadata(path).map_err(Error::FileMetadata)?.file_type();
-
-        if file_type.is_dir() {
-            return Directory::try_from_path(path, true).map(Self::Directory);
-        }
-
-        if file_type.is_file() {
-            return File::try_from_path(path).map(Self::File);
-        }
+        Self::try_from_entry_with_provider(path, &FileSystemProvider::default())
+    }
 
-        Err(Error::FileTypeUnknown)
+    ///
+    /// Initializes the entry application module representation from the source code `provider`.
+    ///
+    pub fn try_from_entry_with_provider<P: ISourceProvider>(
+        path: &PathBuf,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        Self::try_from_provider(path, true, provider)
     }
 
     ///
     /// Initializes an application module representation from the file system.
     ///
     pub fn try_from_path(path: &PathBuf) -> Result<Self, Error> {
-        let file_type = fs::metadata(path).map_err(Error::FileMetadata)?.file_type();
+        Self::try_from_path_with_provider(path, &FileSystemProvider::default())
+    }
 
-        if file_type.is_dir() {
-            return Directory::try_from_path(path, false).map(Self::Directory);
-        }
+    ///
+    /// Initializes an application module representation from the source code `provider`.
+    ///
+    pub fn try_from_path_with_provider<P: ISourceProvider>(
+        path: &PathBuf,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        Self::try_from_provider(path, false, provider)
+    }
 
-        if file_type.is_file() {
-            return File::try_from_path(path).map(Self::File);
+    ///
+    /// Initializes a module representation from the source code `provider`, depending on
+    /// whether the `path` is a file or directory.
+    ///
+    fn try_from_provider<P: ISourceProvider>(
+        path: &PathBuf,
+        is_entry: bool,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        match provider.entry_type(path).map_err(Error::FileMetadata)? {
+            EntryType::Directory => {
+                Directory::try_from_path_with_provider(path, is_entry, provider)
+                    .map(Self::Directory)
+            }
+            EntryType::File => File::try_from_path_with_provider(path, provider).map(Self::File),
+            EntryType::Unknown => Err(Error::FileTypeUnknown),
         }
-
-        Err(Error::FileTypeUnknown)
     }
 
     ///
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
-    pub fn compile(self, manifest: Manifest) -> Result<Rc<RefCell<State>>, Error> {
+    /// Also returns the warnings collected after the semantic analysis.
+    ///
+    /// The `libraries` are the dependencies of the project, with the dependency names as keys.
+    ///
+    /// If `is_intermediate_printed` is set, the intermediate representation listing is saved
+    /// to the generator state.
+    ///
+    pub fn compile(
+        self,
+        manifest: Manifest,
+        libraries: HashMap<String, Library>,
+        is_intermediate_printed: bool,
+    ) -> Result<(Rc<RefCell<State>>, Vec<Warning>), Error> {
         match self {
-            Self::File(inner) => inner.compile(manifest),
-            Self::Directory(inner) => inner.compile(manifest),
+            Self::File(inner) => inner.compile(manifest, libraries, is_intermediate_printed),
+            Self::Directory(inner) => inner.compile(manifest, libraries, is_intermediate_printed),
         }
     }
 
+    ///
+    /// Checks the library project source code, which is not compiled into bytecode, but is
+    /// distributed as source code and analyzed again as a part of each dependent project.
+    ///
+    /// Returns the warnings collected after the semantic analysis.
+    ///
+    pub fn check_library(self, libraries: HashMap<String, Library>) -> Result<Vec<Warning>, Error> {
+        let scope = EntryAnalyzer::define_library(self, libraries)
+            .map_err(CompilerError::Semantic)
+            .map_err(|error| error.format())
+            .map_err(Error::Compiling)?;
+
+        Ok(Scope::get_unused_items(scope))
+    }
+
     ///
     /// Gets the file or directory name.
     ///
@@ -0,0 +1,43 @@
+//!
+//! The file system source code provider.
+//!
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::source::provider::EntryType;
+use crate::source::provider::ISourceProvider;
+
+///
+/// The file system source code provider, which is used by the compiler executable.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Provider {}
+
+impl ISourceProvider for Provider {
+    fn entry_type(&self, path: &Path) -> io::Result<EntryType> {
+        let file_type = fs::metadata(path)?.file_type();
+
+        Ok(if file_type.is_dir() {
+            EntryType::Directory
+        } else if file_type.is_file() {
+            EntryType::File
+        } else {
+            EntryType::Unknown
+        })
+    }
+
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn list_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(path)? {
+            paths.push(entry?.path());
+        }
+        Ok(paths)
+    }
+}
@@ -0,0 +1,81 @@
+//!
+//! The in-memory source code provider.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::source::provider::EntryType;
+use crate::source::provider::ISourceProvider;
+
+///
+/// The in-memory source code provider, which allows compiling a whole module tree without
+/// touching the file system, e.g. in tests or on the server.
+///
+/// The directories are not stored explicitly, but are derived from the file paths.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Provider {
+    /// The source code files, with the file paths as keys.
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl Provider {
+    ///
+    /// Adds a source code file at `path`, replacing the previous one if it exists.
+    ///
+    pub fn insert(&mut self, path: PathBuf, code: String) {
+        self.files.insert(path, code);
+    }
+
+    ///
+    /// Returns the error for a missing file or directory.
+    ///
+    fn not_found(path: &Path) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("`{}` not found", path.to_string_lossy()),
+        )
+    }
+}
+
+impl ISourceProvider for Provider {
+    fn entry_type(&self, path: &Path) -> io::Result<EntryType> {
+        if self.files.contains_key(path) {
+            return Ok(EntryType::File);
+        }
+
+        if self.files.keys().any(|file| file.starts_with(path)) {
+            return Ok(EntryType::Directory);
+        }
+
+        Err(Self::not_found(path))
+    }
+
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| Self::not_found(path))
+    }
+
+    fn list_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let paths: BTreeSet<PathBuf> = self
+            .files
+            .keys()
+            .filter(|file| *file != path)
+            .filter_map(|file| file.strip_prefix(path).ok())
+            .filter_map(|relative| relative.components().next())
+            .map(|component| path.join(component))
+            .collect();
+
+        if paths.is_empty() {
+            return Err(Self::not_found(path));
+        }
+
+        Ok(paths.into_iter().collect())
+    }
+}
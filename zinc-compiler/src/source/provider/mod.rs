@@ -0,0 +1,44 @@
+//!
+//! The source code provider.
+//!
+
+pub mod file_system;
+pub mod memory;
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The source code provider entry type.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryType {
+    /// The source code file.
+    File,
+    /// The source code directory.
+    Directory,
+    /// Neither file nor directory.
+    Unknown,
+}
+
+///
+/// The source code provider, which abstracts the source code loading from the storage it is
+/// kept in, e.g. the file system or memory.
+///
+pub trait ISourceProvider {
+    ///
+    /// Gets the type of the entry at `path`.
+    ///
+    fn entry_type(&self, path: &Path) -> io::Result<EntryType>;
+
+    ///
+    /// Reads the file at `path` into a string.
+    ///
+    fn read_file(&self, path: &Path) -> io::Result<String>;
+
+    ///
+    /// Lists the paths of the files and directories located directly in the directory at `path`.
+    ///
+    fn list_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+}
@@ -0,0 +1,225 @@
+//!
+//! The source code tests.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
+
+use crate::generator::state::State;
+use crate::source::directory::error::Error as DirectoryError;
+use crate::source::error::Error as SourceError;
+use crate::source::provider::file_system::Provider as FileSystemProvider;
+use crate::source::provider::memory::Provider as MemoryProvider;
+use crate::source::provider::ISourceProvider;
+use crate::source::Source;
+use crate::timings::report::Row;
+use crate::timings::TIMINGS;
+
+const FILES: [(&str, &str); 4] = [
+    (
+        "main.zn",
+        r#"
+mod constants;
+mod level_1;
+
+fn main(value: u8) -> u8 {
+    value + constants::VALUE + level_1::VALUE + level_1::level_2::VALUE
+}
+"#,
+    ),
+    (
+        "constants.zn",
+        r#"
+const VALUE: u8 = 25;
+"#,
+    ),
+    (
+        "level_1/mod.zn",
+        r#"
+mod level_2;
+
+const VALUE: u8 = 42;
+"#,
+    ),
+    (
+        "level_1/level_2.zn",
+        r#"
+const VALUE: u8 = 64;
+"#,
+    ),
+];
+
+fn memory_provider(root: &Path) -> MemoryProvider {
+    let mut provider = MemoryProvider::default();
+    for (path, code) in FILES.iter() {
+        provider.insert(root.join(path), (*code).to_owned());
+    }
+    provider
+}
+
+fn compile<P: ISourceProvider>(
+    root: &PathBuf,
+    provider: &P,
+    optimize_dead_function_elimination: bool,
+) -> Vec<u8> {
+    let source = Source::try_from_entry_with_provider(root, provider)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let (state, _warnings) = source
+        .compile(
+            Manifest::new("test", ProjectType::Circuit),
+            HashMap::new(),
+            false,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    State::unwrap_rc(state)
+        .into_application(optimize_dead_function_elimination, true)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_build()
+        .bytecode
+}
+
+#[test]
+fn ok_memory_provider_same_bytecode_as_file_system() {
+    let root = std::env::temp_dir().join(format!("zinc-source-provider-{}", std::process::id()));
+    for (path, code) in FILES.iter() {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().expect(zinc_const::panic::TEST_DATA_VALID))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::write(path, code).expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    let from_file_system = compile(&root, &FileSystemProvider::default(), true);
+    let from_memory = compile(&root, &memory_provider(&root), true);
+
+    fs::remove_dir_all(&root).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(from_memory, from_file_system);
+}
+
+#[test]
+fn ok_dead_function_elimination_unused_module() {
+    let root = PathBuf::from("/memory/src");
+    let mut provider = MemoryProvider::default();
+    provider.insert(
+        root.join("main.zn"),
+        r#"
+mod helpers;
+
+fn main(value: u8) -> u8 {
+    helpers::double(value)
+}
+"#
+        .to_owned(),
+    );
+    provider.insert(
+        root.join("helpers.zn"),
+        r#"
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn sum(values: [u8; 4]) -> u8 {
+    let mut total = 0;
+    for i in 0..4 {
+        total += values[i];
+    }
+    total
+}
+
+fn average(values: [u8; 4]) -> u8 {
+    sum(values) / 4
+}
+
+fn clamp(value: u8, min: u8, max: u8) -> u8 {
+    if value < min { min } else if value > max { max } else { value }
+}
+"#
+        .to_owned(),
+    );
+
+    let optimized = compile(&root, &provider, true);
+    let unoptimized = compile(&root, &provider, false);
+
+    assert!(optimized.len() < unoptimized.len());
+}
+
+#[test]
+fn ok_timings_row_per_module() {
+    let root = PathBuf::from("/memory/timings/src");
+    let provider = memory_provider(&root);
+
+    TIMINGS.enable();
+    compile(&root, &provider, true);
+
+    let rows: Vec<Row> = TIMINGS
+        .report()
+        .rows
+        .into_iter()
+        .filter(|row| row.path.starts_with(root.to_string_lossy().as_ref()))
+        .collect();
+    let mut paths: Vec<String> = rows.iter().map(|row| row.path.to_owned()).collect();
+    paths.sort();
+    let mut expected: Vec<String> = FILES
+        .iter()
+        .map(|(path, _code)| root.join(path).to_string_lossy().to_string())
+        .collect();
+    expected.sort();
+
+    assert_eq!(paths, expected);
+    assert!(rows.iter().all(|row| row.items > 0));
+}
+
+#[test]
+fn ok_memory_provider_module_tree() {
+    let root = PathBuf::from("/memory/src");
+    let provider = memory_provider(&root);
+
+    let source = Source::try_from_entry_with_provider(&root, &provider)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match source {
+        Source::Directory(directory) => {
+            assert!(directory.entry.is_application_entry());
+            assert!(directory.dependencies.contains_key("constants"));
+            match directory.dependencies.get("level_1") {
+                Some(Source::Directory(level_1)) => {
+                    assert!(level_1.entry.is_module_entry());
+                    assert!(level_1.dependencies.contains_key("level_2"));
+                }
+                _ => panic!("the `level_1` module must be a directory"),
+            }
+        }
+        Source::File(_) => panic!("the entry must be a directory"),
+    }
+}
+
+#[test]
+fn error_memory_provider_application_entry_not_found() {
+    let root = PathBuf::from("/memory/src");
+    let mut provider = MemoryProvider::default();
+    provider.insert(root.join("constants.zn"), FILES[1].1.to_owned());
+
+    let result = Source::try_from_entry_with_provider(&root, &provider);
+
+    assert!(matches!(
+        result,
+        Err(SourceError::Directory(
+            DirectoryError::ApplicationEntryNotFound
+        ))
+    ));
+}
+
+#[test]
+fn error_memory_provider_path_not_found() {
+    let provider = memory_provider(&PathBuf::from("/memory/src"));
+
+    let result = Source::try_from_entry_with_provider(&PathBuf::from("/other/src"), &provider);
+
+    assert!(matches!(result, Err(SourceError::FileMetadata(_))));
+}
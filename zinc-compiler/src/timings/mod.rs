@@ -0,0 +1,163 @@
+//!
+//! The compilation timings.
+//!
+
+pub mod phase;
+pub mod report;
+
+use std::cell::RefCell;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use lazy_static::lazy_static;
+
+use self::phase::Phase;
+use self::report::Report;
+
+///
+/// The per-module compilation timings collector.
+///
+/// The collector is disabled by default, and the instrumented code checks the flag once per
+/// module or statement, doing no extra work if the timings are not requested.
+///
+#[derive(Debug)]
+pub struct Timings {
+    /// Whether the timings are being recorded.
+    is_enabled: AtomicBool,
+    /// The report collected so far.
+    report: RwLock<Report>,
+}
+
+lazy_static! {
+    ///
+    /// The global compilation timings collector.
+    ///
+    pub static ref TIMINGS: Timings = Timings::new();
+}
+
+thread_local! {
+    ///
+    /// The time spent in the nested measurements of the measurements being run, which is
+    /// subtracted from their own time to get the exclusive time of each module.
+    ///
+    static NESTED: RefCell<Vec<Duration>> = RefCell::new(Vec::new());
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timings {
+    ///
+    /// Initializes a disabled collector instance.
+    ///
+    pub fn new() -> Self {
+        Self {
+            is_enabled: AtomicBool::new(false),
+            report: RwLock::new(Report::default()),
+        }
+    }
+
+    ///
+    /// Enables the timings recording, discarding the report of the previous compilation.
+    ///
+    /// Must be called before each compilation, since the collector is shared by the whole
+    /// process.
+    ///
+    pub fn enable(&self) {
+        *self
+            .report
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION) = Report::default();
+        self.is_enabled.store(true, Ordering::Relaxed);
+    }
+
+    ///
+    /// Checks whether the timings are being recorded.
+    ///
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// Runs `action`, adding its exclusive time to the `phase` time of the module at `path`.
+    ///
+    /// The time of the measurements nested into `action`, for example, of the modules defined
+    /// on demand during the semantic analysis, is not included.
+    ///
+    pub fn measure<T, F>(&self, path: &str, phase: Phase, action: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        NESTED.with(|nested| nested.borrow_mut().push(Duration::default()));
+
+        let start = Instant::now();
+        let result = action();
+        let elapsed = start.elapsed();
+
+        let nested = NESTED
+            .with(|nested| nested.borrow_mut().pop())
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        Self::add_nested(elapsed);
+        self.add(path, phase, elapsed.checked_sub(nested).unwrap_or_default());
+
+        result
+    }
+
+    ///
+    /// Adds the `duration` to the `phase` time of the module at `path`.
+    ///
+    /// Is used for the phases which cannot be wrapped into a closure, for example, when
+    /// a phase time is calculated from another measurement.
+    ///
+    pub fn record(&self, path: &str, phase: Phase, duration: Duration) {
+        Self::add_nested(duration);
+        self.add(path, phase, duration);
+    }
+
+    ///
+    /// Updates the peak number of items in the scope of the module at `path`.
+    ///
+    pub fn set_items(&self, path: &str, items: usize) {
+        self.report
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .set_items(path, items);
+    }
+
+    ///
+    /// Returns a snapshot of the report collected so far.
+    ///
+    pub fn report(&self) -> Report {
+        self.report
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .to_owned()
+    }
+
+    ///
+    /// Adds the `duration` to the report.
+    ///
+    fn add(&self, path: &str, phase: Phase, duration: Duration) {
+        self.report
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .add(path, phase, duration);
+    }
+
+    ///
+    /// Adds the `duration` to the nested time of the enclosing measurement, if there is one.
+    ///
+    fn add_nested(duration: Duration) {
+        NESTED.with(|nested| {
+            if let Some(parent) = nested.borrow_mut().last_mut() {
+                *parent += duration;
+            }
+        });
+    }
+}
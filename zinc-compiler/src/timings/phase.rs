@@ -0,0 +1,31 @@
+//!
+//! The compilation timings phase.
+//!
+
+use std::fmt;
+
+///
+/// The compilation phase, whose time is recorded separately for each module.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    /// The lexical analysis, that is, the source code tokenization.
+    Lexical,
+    /// The syntax analysis, excluding the lexical analysis performed on demand by the parser.
+    Syntax,
+    /// The semantic analysis, that is, the module declaration and definition.
+    Semantic,
+    /// The intermediate representation bytecode generation.
+    Generation,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lexical => write!(f, "lexical"),
+            Self::Syntax => write!(f, "syntax"),
+            Self::Semantic => write!(f, "semantic"),
+            Self::Generation => write!(f, "generation"),
+        }
+    }
+}
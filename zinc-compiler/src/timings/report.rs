@@ -0,0 +1,171 @@
+//!
+//! The compilation timings report.
+//!
+
+use std::fmt;
+use std::time::Duration;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::timings::phase::Phase;
+
+///
+/// The compilation timings report, which consists of one row per module.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Report {
+    /// The module rows in the order of their first record.
+    pub rows: Vec<Row>,
+}
+
+///
+/// The compilation timings of a single module.
+///
+#[derive(Debug, Clone)]
+pub struct Row {
+    /// The module file path.
+    pub path: String,
+    /// The lexical analysis time.
+    pub lexical: Duration,
+    /// The syntax analysis time.
+    pub syntax: Duration,
+    /// The semantic analysis time.
+    pub semantic: Duration,
+    /// The bytecode generation time.
+    pub generation: Duration,
+    /// The peak number of items in the module scope.
+    pub items: usize,
+}
+
+impl Report {
+    ///
+    /// Adds the `duration` to the `phase` time of the module at `path`.
+    ///
+    pub fn add(&mut self, path: &str, phase: Phase, duration: Duration) {
+        let row = self.row_mut(path);
+        match phase {
+            Phase::Lexical => row.lexical += duration,
+            Phase::Syntax => row.syntax += duration,
+            Phase::Semantic => row.semantic += duration,
+            Phase::Generation => row.generation += duration,
+        }
+    }
+
+    ///
+    /// Updates the peak number of items in the scope of the module at `path`.
+    ///
+    pub fn set_items(&mut self, path: &str, items: usize) {
+        let row = self.row_mut(path);
+        row.items = row.items.max(items);
+    }
+
+    ///
+    /// Converts the report into the JSON representation with the times in milliseconds.
+    ///
+    pub fn into_json(self) -> JsonValue {
+        JsonValue::Array(
+            self.rows
+                .into_iter()
+                .map(|row| {
+                    json!({
+                        "path": row.path,
+                        "lexical_ms": Self::milliseconds(row.lexical),
+                        "syntax_ms": Self::milliseconds(row.syntax),
+                        "semantic_ms": Self::milliseconds(row.semantic),
+                        "generation_ms": Self::milliseconds(row.generation),
+                        "items": row.items,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// Returns the row of the module at `path`, creating it if the module is not recorded yet.
+    ///
+    fn row_mut(&mut self, path: &str) -> &mut Row {
+        let index = match self.rows.iter().position(|row| row.path == path) {
+            Some(index) => index,
+            None => {
+                self.rows.push(Row::new(path.to_owned()));
+                self.rows.len() - 1
+            }
+        };
+
+        &mut self.rows[index]
+    }
+
+    ///
+    /// Converts the `duration` into fractional milliseconds.
+    ///
+    fn milliseconds(duration: Duration) -> f64 {
+        duration.as_secs_f64() * 1000.0
+    }
+}
+
+impl Row {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+            lexical: Duration::default(),
+            syntax: Duration::default(),
+            semantic: Duration::default(),
+            generation: Duration::default(),
+            items: 0,
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .rows
+            .iter()
+            .map(|row| row.path.len())
+            .max()
+            .unwrap_or_default()
+            .max("module".len());
+
+        writeln!(
+            f,
+            "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>8}",
+            "module",
+            Phase::Lexical.to_string(),
+            Phase::Syntax.to_string(),
+            Phase::Semantic.to_string(),
+            Phase::Generation.to_string(),
+            "items",
+            width = width,
+        )?;
+
+        let mut total = Row::new("total".to_owned());
+        for row in self.rows.iter() {
+            writeln!(f, "{:<width$} {}", row.path, row, width = width)?;
+
+            total.lexical += row.lexical;
+            total.syntax += row.syntax;
+            total.semantic += row.semantic;
+            total.generation += row.generation;
+            total.items += row.items;
+        }
+        write!(f, "{:<width$} {}", total.path, total, width = width)
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>9.3} ms {:>9.3} ms {:>9.3} ms {:>9.3} ms {:>8}",
+            Report::milliseconds(self.lexical),
+            Report::milliseconds(self.syntax),
+            Report::milliseconds(self.semantic),
+            Report::milliseconds(self.generation),
+            self.items,
+        )
+    }
+}
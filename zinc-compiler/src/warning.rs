@@ -0,0 +1,234 @@
+//!
+//! The Zinc compiler warning.
+//!
+
+use colored::Colorize;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// The Zinc compiler warning.
+///
+/// Unlike errors, warnings do not stop the compilation process.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// The variable is declared, but never referenced.
+    UnusedVariable {
+        /// The location where the variable is declared.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The constant is declared, but never referenced.
+    UnusedConstant {
+        /// The location where the constant is declared.
+        location: Location,
+        /// The constant name.
+        name: String,
+    },
+    /// The non-public function is declared, but never called.
+    UnusedFunction {
+        /// The location where the function is declared.
+        location: Location,
+        /// The function name.
+        name: String,
+    },
+    /// The structure update base is specified, but all the structure fields are listed explicitly.
+    RedundantStructureBase {
+        /// The location of the base expression.
+        location: Location,
+        /// The structure type identifier.
+        type_identifier: String,
+    },
+    /// The integer literal type is widened to the type of the other operand of a binary operator.
+    IntegerLiteralWidened {
+        /// The location of the literal.
+        location: Location,
+        /// The literal value.
+        value: String,
+        /// The literal type.
+        from: String,
+        /// The other operand type.
+        to: String,
+    },
+    /// The `require` condition is a constant `false`, so the call always fails if executed.
+    RequireAlwaysFails {
+        /// The location of the `require` call.
+        location: Location,
+    },
+    /// The `require` condition is a constant `true`, so the call has no effect.
+    RedundantRequire {
+        /// The location of the `require` call.
+        location: Location,
+    },
+    /// The conditional expression condition is a constant, so one of the branches is unreachable.
+    ConstantCondition {
+        /// The location of the condition expression.
+        location: Location,
+        /// The folded condition value.
+        value: bool,
+    },
+}
+
+impl Warning {
+    ///
+    /// The location the warning points to.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. } => *location,
+            Self::UnusedConstant { location, .. } => *location,
+            Self::UnusedFunction { location, .. } => *location,
+            Self::RedundantStructureBase { location, .. } => *location,
+            Self::IntegerLiteralWidened { location, .. } => *location,
+            Self::RequireAlwaysFails { location } => *location,
+            Self::RedundantRequire { location } => *location,
+            Self::ConstantCondition { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Formats the compiler warning into the user-friendly readable output.
+    ///
+    pub fn format(self) -> String {
+        match self {
+            Self::UnusedVariable { location, name } => Self::format_line(
+                format!("unused variable `{}`", name).as_str(),
+                location,
+                Some(
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        name
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::UnusedConstant { location, name } => Self::format_line(
+                format!("constant `{}` is never used", name).as_str(),
+                location,
+                Some(
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        name
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::UnusedFunction { location, name } => Self::format_line(
+                format!("function `{}` is never used", name).as_str(),
+                location,
+                Some(
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        name
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::RedundantStructureBase {
+                location,
+                type_identifier,
+            } => Self::format_line(
+                format!(
+                    "the structure update has no effect, as all the fields of `{}` are specified",
+                    type_identifier
+                )
+                .as_str(),
+                location,
+                Some("remove the `..` base expression"),
+            ),
+            Self::IntegerLiteralWidened {
+                location,
+                value,
+                from,
+                to,
+            } => Self::format_line(
+                format!(
+                    "integer literal `{}` is widened from `{}` to `{}` to match the other operand",
+                    value, from, to
+                )
+                .as_str(),
+                location,
+                Some(
+                    format!(
+                        "if this is intentional, cast the literal explicitly: `{} as {}`",
+                        value, to
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::RequireAlwaysFails { location } => Self::format_line(
+                "the `require` condition is always `false`",
+                location,
+                Some("the call fails whenever it is executed, so the proof cannot be generated"),
+            ),
+            Self::RedundantRequire { location } => Self::format_line(
+                "the `require` condition is always `true`",
+                location,
+                Some("remove the redundant call"),
+            ),
+            Self::ConstantCondition { location, value } => Self::format_line(
+                format!("the condition is always `{}`", value).as_str(),
+                location,
+                Some(
+                    format!(
+                        "the `{}` branch is never executed",
+                        if value { "else" } else { "if" }
+                    )
+                    .as_str(),
+                ),
+            ),
+        }
+    }
+
+    ///
+    /// Formats a warning `message` with an optional `help` message.
+    ///
+    fn format_line(message: &str, location: Location, help: Option<&str>) -> String {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let context = index
+            .get(&location.file)
+            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+            .code
+            .lines()
+            .collect::<Vec<&str>>();
+        let line_number_length = location.line.to_string().len();
+        let mut strings = Vec::with_capacity(8);
+        strings.push(String::new());
+        strings.push(format!(
+            "{}: {}",
+            "warning".bright_yellow(),
+            message.bright_white()
+        ));
+        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        if let Some(line) = context.get(location.line - 1) {
+            strings.push(format!(
+                "{}{}",
+                (location.line.to_string() + " | ").bright_cyan(),
+                line
+            ));
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            "_".repeat(location.column - 1).bright_yellow(),
+            "^".bright_yellow()
+        ));
+        if let Some(help) = help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use structopt::StructOpt;
 
@@ -47,6 +48,31 @@ pub struct Arguments {
     /// Enables the dead function code elimination optimization.
     #[structopt(long = "opt-dfe")]
     pub optimize_dead_function_elimination: bool,
+
+    /// Enables the peephole optimization of the adjacent instruction patterns.
+    #[structopt(long = "opt-peephole")]
+    pub optimize_peephole: bool,
+
+    /// Fails the compilation if there are any warnings.
+    #[structopt(long = "deny-warnings")]
+    pub deny_warnings: bool,
+
+    /// Writes the generator IR and/or the bytecode disassembly listings next to the binary.
+    /// Accepts `ir`, `bytecode`, or `both`.
+    #[structopt(long = "emit")]
+    pub emit: Option<Emit>,
+
+    /// Prints the per-module compilation phase timings and scope item counts.
+    #[structopt(long = "timings")]
+    pub timings: bool,
+
+    /// Writes the per-module compilation timings report to the specified JSON file.
+    #[structopt(long = "timings-json", parse(from_os_str))]
+    pub timings_json: Option<PathBuf>,
+
+    /// The library dependency interface, specified as `name=path`. May be passed several times.
+    #[structopt(long = "library", parse(try_from_str = parse_library))]
+    pub libraries: Vec<(String, PathBuf)>,
 }
 
 impl Arguments {
@@ -57,3 +83,64 @@ impl Arguments {
         Self::from_args()
     }
 }
+
+///
+/// The auxiliary listings written alongside the bytecode.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Emit {
+    /// The generator intermediate representation listing.
+    Intermediate,
+    /// The bytecode disassembly listing.
+    Bytecode,
+    /// Both of the listings.
+    Both,
+}
+
+impl Emit {
+    ///
+    /// Checks whether the intermediate representation listing is requested.
+    ///
+    pub fn is_intermediate(self) -> bool {
+        matches!(self, Self::Intermediate | Self::Both)
+    }
+
+    ///
+    /// Checks whether the bytecode disassembly listing is requested.
+    ///
+    pub fn is_bytecode(self) -> bool {
+        matches!(self, Self::Bytecode | Self::Both)
+    }
+}
+
+impl FromStr for Emit {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ir" => Ok(Self::Intermediate),
+            "bytecode" => Ok(Self::Bytecode),
+            "both" => Ok(Self::Both),
+            value => Err(format!(
+                "invalid emit value `{}`, expected `ir`, `bytecode`, or `both`",
+                value
+            )),
+        }
+    }
+}
+
+///
+/// Parses the `name=path` library dependency argument.
+///
+fn parse_library(value: &str) -> Result<(String, PathBuf), String> {
+    let mut parts = value.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(name), Some(path)) if !name.is_empty() && !path.is_empty() => {
+            Ok((name.to_owned(), PathBuf::from(path)))
+        }
+        _ => Err(format!(
+            "invalid library `{}`, expected the `name=path` format",
+            value
+        )),
+    }
+}
@@ -6,6 +6,7 @@ use std::ffi::OsString;
 use std::fmt;
 use std::io;
 
+use zinc_compiler::DeadFunctionCodeEliminationError;
 use zinc_compiler::SourceError;
 
 ///
@@ -16,12 +17,44 @@ pub enum Error {
     Manifest(zinc_manifest::Error),
     /// The Zinc source code error.
     Source(SourceError),
+    /// There are warnings, and they are denied with the `--deny-warnings` flag.
+    WarningsDenied(usize),
+    /// The bytecode optimization error.
+    Optimization(DeadFunctionCodeEliminationError),
     /// The output directories creating error.
     DirectoryCreating(OsString, io::Error),
     /// The bytecode binary file writing error.
     BytecodeWriting(OsString, OutputError),
+    /// The intermediate representation listing file writing error.
+    IntermediateWriting(OsString, OutputError),
+    /// The bytecode disassembly listing file writing error.
+    DisassemblyWriting(OsString, OutputError),
+    /// The contract ABI JSON file writing error.
+    AbiWriting(OsString, OutputError),
     /// The witness template JSON file writing error.
     InputTemplateWriting(OsString, OutputError),
+    /// The compilation timings JSON file writing error.
+    TimingsWriting(OsString, OutputError),
+    /// The library interface file reading error.
+    LibraryReading(OsString, io::Error),
+    /// The library interface file parsing error.
+    LibraryParsing(OsString, serde_json::Error),
+    /// The library interface source code error.
+    LibrarySource(zinc_source::Error),
+    /// The library interface file writing error.
+    LibraryWriting(OsString, OutputError),
+    /// The unit test storage fixture file reading error.
+    StorageFixtureReading(OsString, io::Error),
+    /// The unit test storage fixture file parsing error.
+    StorageFixtureParsing(OsString, serde_json::Error),
+    /// The unit test storage fixture is not an array of the storage field values.
+    StorageFixtureFormat(OsString),
+    /// The unit test storage fixture field count does not match the contract storage.
+    StorageFixtureFieldCount(OsString, usize, usize),
+    /// The unit test storage fixture field value does not match the contract storage field type.
+    StorageFixtureField(OsString, String, zinc_build::ValueError),
+    /// The unit test storage fixture is specified in a circuit project.
+    StorageFixtureOutsideContract(String),
 }
 
 impl From<SourceError> for Error {
@@ -35,15 +68,77 @@ impl fmt::Display for Error {
         match self {
             Self::Manifest(inner) => write!(f, "{}", inner),
             Self::Source(inner) => write!(f, "{}", inner),
+            Self::WarningsDenied(count) => write!(
+                f,
+                "aborting due to {} warning(s) denied with `--deny-warnings`",
+                count
+            ),
+            Self::Optimization(inner) => write!(f, "bytecode optimization: {}", inner),
             Self::DirectoryCreating(path, inner) => {
                 write!(f, "directory `{:?}` creating: {}", path, inner)
             }
             Self::BytecodeWriting(path, inner) => {
                 write!(f, "bytecode file `{:?}` writing: {}", path, inner)
             }
+            Self::IntermediateWriting(path, inner) => {
+                write!(
+                    f,
+                    "intermediate listing file `{:?}` writing: {}",
+                    path, inner
+                )
+            }
+            Self::DisassemblyWriting(path, inner) => {
+                write!(
+                    f,
+                    "disassembly listing file `{:?}` writing: {}",
+                    path, inner
+                )
+            }
+            Self::AbiWriting(path, inner) => {
+                write!(f, "contract ABI file `{:?}` writing: {}", path, inner)
+            }
             Self::InputTemplateWriting(path, inner) => {
                 write!(f, "input template file `{:?}` writing: {}", path, inner)
             }
+            Self::TimingsWriting(path, inner) => {
+                write!(f, "timings file `{:?}` writing: {}", path, inner)
+            }
+            Self::LibraryReading(path, inner) => {
+                write!(f, "library file `{:?}` reading: {}", path, inner)
+            }
+            Self::LibraryParsing(path, inner) => {
+                write!(f, "library file `{:?}` parsing: {}", path, inner)
+            }
+            Self::LibrarySource(inner) => write!(f, "library source code: {}", inner),
+            Self::LibraryWriting(path, inner) => {
+                write!(f, "library file `{:?}` writing: {}", path, inner)
+            }
+            Self::StorageFixtureReading(path, inner) => {
+                write!(f, "storage fixture file `{:?}` reading: {}", path, inner)
+            }
+            Self::StorageFixtureParsing(path, inner) => {
+                write!(f, "storage fixture file `{:?}` parsing: {}", path, inner)
+            }
+            Self::StorageFixtureFormat(path) => write!(
+                f,
+                "storage fixture file `{:?}` must contain an array of the storage field values",
+                path
+            ),
+            Self::StorageFixtureFieldCount(path, expected, found) => write!(
+                f,
+                "storage fixture file `{:?}` expected {} fields, found {}",
+                path, expected, found
+            ),
+            Self::StorageFixtureField(path, name, inner) => write!(
+                f,
+                "storage fixture file `{:?}` field `{}`: {}",
+                path, name, inner
+            ),
+            Self::StorageFixtureOutsideContract(name) => write!(
+                f,
+                "unit test `{}` storage fixture is only allowed in contract projects",
+                name
+            ),
         }
     }
 }
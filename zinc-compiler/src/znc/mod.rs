@@ -5,19 +5,36 @@
 mod arguments;
 mod error;
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
 use std::process;
 use std::thread;
 
+use serde_json::Value as JsonValue;
+
+use zinc_build::Abi;
+use zinc_build::Application as BuildApplication;
 use zinc_build::Build;
+use zinc_build::ContractFieldType;
+use zinc_build::ContractFieldValue;
+use zinc_build::Value as BuildValue;
+use zinc_compiler::Printer;
 use zinc_compiler::Source;
+use zinc_compiler::SourceError;
+use zinc_compiler::SourceLibrary;
 use zinc_compiler::State;
+use zinc_compiler::Warning;
+use zinc_compiler::TIMINGS;
 use zinc_manifest::Manifest;
+use zinc_manifest::ProjectType;
 
 use self::arguments::Arguments;
+use self::arguments::Emit;
 use self::error::Error;
 use self::error::OutputError;
 
@@ -42,22 +59,73 @@ fn main_inner() -> Result<(), Error> {
 
     zinc_logger::initialize(zinc_const::app_name::COMPILER, args.verbosity);
 
+    if args.timings || args.timings_json.is_some() {
+        TIMINGS.enable();
+    }
+
     let manifest = Manifest::try_from(&args.manifest_path).map_err(Error::Manifest)?;
 
+    let mut interfaces = HashMap::with_capacity(args.libraries.len());
+    for (name, path) in args.libraries.iter() {
+        interfaces.insert(name.to_owned(), read_library(path)?);
+    }
+
+    if let ProjectType::Library = manifest.project.r#type {
+        return main_library(args, manifest, interfaces);
+    }
+
+    let mut project_path = args.manifest_path.clone();
+    project_path.pop();
+
     let source_directory_path = args.source_directory_path;
     let optimize_dead_function_elimination = args.optimize_dead_function_elimination;
-    let build = thread::Builder::new()
+    let optimize_peephole = args.optimize_peephole;
+    let emit = args.emit;
+    let result = thread::Builder::new()
         .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
-        .spawn(move || -> Result<Build, Error> {
+        .spawn(move || -> Result<(Build, Vec<String>, Artifacts), Error> {
+            let libraries = into_libraries(interfaces)?;
             let source = Source::try_from_entry(&source_directory_path)?;
-            let state = source.compile(manifest)?;
-            let application =
-                State::unwrap_rc(state).into_application(optimize_dead_function_elimination);
-            Ok(application.into_build())
+            let (state, warnings) = source.compile(
+                manifest,
+                libraries,
+                emit.map(Emit::is_intermediate).unwrap_or_default(),
+            )?;
+            let warnings = warnings.into_iter().map(Warning::format).collect();
+            let mut state = State::unwrap_rc(state);
+            let intermediate = state.take_intermediate();
+            let abi = state.abi();
+            let mut application = state
+                .into_application(optimize_dead_function_elimination, optimize_peephole)
+                .map_err(Error::Optimization)?;
+            load_storage_fixtures(&mut application, project_path.as_path())?;
+            let disassembly = if emit.map(Emit::is_bytecode).unwrap_or_default() {
+                Some(Printer::disassembly(application.instructions()))
+            } else {
+                None
+            };
+            Ok((
+                application.into_build(),
+                warnings,
+                Artifacts {
+                    abi,
+                    intermediate,
+                    disassembly,
+                },
+            ))
         })
         .expect(zinc_const::panic::SYNCHRONIZATION)
         .join()
-        .expect(zinc_const::panic::SYNCHRONIZATION)?;
+        .expect(zinc_const::panic::SYNCHRONIZATION);
+    write_timings(args.timings, args.timings_json.as_ref())?;
+    let (build, warnings, artifacts) = result?;
+
+    for warning in warnings.iter() {
+        eprintln!("{}", warning);
+    }
+    if args.deny_warnings && !warnings.is_empty() {
+        return Err(Error::WarningsDenied(warnings.len()));
+    }
 
     let mut build_directory_path = args.binary_path.clone();
     build_directory_path.pop();
@@ -111,5 +179,248 @@ fn main_inner() -> Result<(), Error> {
         .map_err(|error| Error::BytecodeWriting(binary_path.as_os_str().to_owned(), error))?;
     log::info!("Compiled to {:?}", binary_path);
 
+    if let Some(abi) = artifacts.abi {
+        let abi_path = build_directory_path.join(format!(
+            "{}.{}.{}",
+            abi.name,
+            zinc_const::extension::ABI,
+            zinc_const::extension::JSON
+        ));
+        let abi_data = serde_json::to_vec_pretty(&abi).expect(zinc_const::panic::DATA_CONVERSION);
+        File::create(&abi_path)
+            .map_err(OutputError::Creating)
+            .map_err(|error| Error::AbiWriting(abi_path.as_os_str().to_owned(), error))?
+            .write_all(abi_data.as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(|error| Error::AbiWriting(abi_path.as_os_str().to_owned(), error))?;
+        log::info!("Contract ABI written to {:?}", abi_path);
+    }
+
+    if let Some(intermediate) = artifacts.intermediate {
+        let intermediate_path = binary_path.with_extension(zinc_const::extension::INTERMEDIATE);
+        File::create(&intermediate_path)
+            .map_err(OutputError::Creating)
+            .map_err(|error| {
+                Error::IntermediateWriting(intermediate_path.as_os_str().to_owned(), error)
+            })?
+            .write_all(intermediate.as_bytes())
+            .map_err(OutputError::Writing)
+            .map_err(|error| {
+                Error::IntermediateWriting(intermediate_path.as_os_str().to_owned(), error)
+            })?;
+        log::info!("Intermediate listing written to {:?}", intermediate_path);
+    }
+
+    if let Some(disassembly) = artifacts.disassembly {
+        let disassembly_path = binary_path.with_extension(zinc_const::extension::DISASSEMBLY);
+        File::create(&disassembly_path)
+            .map_err(OutputError::Creating)
+            .map_err(|error| {
+                Error::DisassemblyWriting(disassembly_path.as_os_str().to_owned(), error)
+            })?
+            .write_all(disassembly.as_bytes())
+            .map_err(OutputError::Writing)
+            .map_err(|error| {
+                Error::DisassemblyWriting(disassembly_path.as_os_str().to_owned(), error)
+            })?;
+        log::info!("Disassembly listing written to {:?}", disassembly_path);
+    }
+
     Ok(())
 }
+
+///
+/// The auxiliary build artifacts, that is, the contract ABI and the listings requested with
+/// the `--emit` option.
+///
+struct Artifacts {
+    /// The contract ABI document, which is only produced for contracts.
+    abi: Option<Abi>,
+    /// The generator intermediate representation listing.
+    intermediate: Option<String>,
+    /// The bytecode disassembly listing.
+    disassembly: Option<String>,
+}
+
+///
+/// Checks the library project and writes its interface, which is used by the dependent projects.
+///
+fn main_library(
+    args: Arguments,
+    manifest: Manifest,
+    interfaces: HashMap<String, zinc_source::Library>,
+) -> Result<(), Error> {
+    let source = zinc_source::Source::try_from_path(&args.source_directory_path, true)
+        .map_err(Error::LibrarySource)?;
+    let library = zinc_source::Library::new(
+        manifest.project.name,
+        manifest.project.version,
+        zinc_compiler::VERSION.to_owned(),
+        source,
+        interfaces,
+    );
+
+    let checked = library.clone();
+    let result = thread::Builder::new()
+        .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+        .spawn(move || -> Result<Vec<String>, Error> {
+            let libraries = into_libraries(checked.dependencies)?;
+            let source = Source::try_from_string(checked.source, true)?;
+            let warnings = source.check_library(libraries)?;
+            Ok(warnings.into_iter().map(Warning::format).collect())
+        })
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION);
+    write_timings(args.timings, args.timings_json.as_ref())?;
+    let warnings = result?;
+
+    for warning in warnings.iter() {
+        eprintln!("{}", warning);
+    }
+    if args.deny_warnings && !warnings.is_empty() {
+        return Err(Error::WarningsDenied(warnings.len()));
+    }
+
+    let library_path = args.binary_path;
+    let mut build_directory_path = library_path.clone();
+    build_directory_path.pop();
+    fs::create_dir_all(&build_directory_path).map_err(|error| {
+        Error::DirectoryCreating(build_directory_path.as_os_str().to_owned(), error)
+    })?;
+
+    let library_data =
+        serde_json::to_vec_pretty(&library).expect(zinc_const::panic::DATA_CONVERSION);
+    File::create(&library_path)
+        .map_err(OutputError::Creating)
+        .map_err(|error| Error::LibraryWriting(library_path.as_os_str().to_owned(), error))?
+        .write_all(library_data.as_slice())
+        .map_err(OutputError::Writing)
+        .map_err(|error| Error::LibraryWriting(library_path.as_os_str().to_owned(), error))?;
+    log::info!("Library interface written to {:?}", library_path);
+
+    Ok(())
+}
+
+///
+/// Prints the compilation timings table if `is_printed` is set, and writes the JSON report to
+/// `json_path` if it is specified.
+///
+fn write_timings(is_printed: bool, json_path: Option<&PathBuf>) -> Result<(), Error> {
+    if is_printed {
+        eprintln!("{}", TIMINGS.report());
+    }
+
+    if let Some(path) = json_path {
+        let data = serde_json::to_vec_pretty(&TIMINGS.report().into_json())
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        File::create(path)
+            .map_err(OutputError::Creating)
+            .map_err(|error| Error::TimingsWriting(path.as_os_str().to_owned(), error))?
+            .write_all(data.as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(|error| Error::TimingsWriting(path.as_os_str().to_owned(), error))?;
+        log::info!("Compilation timings written to {:?}", path);
+    }
+
+    Ok(())
+}
+
+///
+/// Reads a library interface written by the compiler while building a library project.
+///
+fn read_library(path: &PathBuf) -> Result<zinc_source::Library, Error> {
+    let data = fs::read(path)
+        .map_err(|error| Error::LibraryReading(path.as_os_str().to_owned(), error))?;
+
+    serde_json::from_slice(data.as_slice())
+        .map_err(|error| Error::LibraryParsing(path.as_os_str().to_owned(), error))
+}
+
+///
+/// Parses the `#[test(storage = "...")]` fixtures against the contract storage types and embeds
+/// the values into the unit test metadata.
+///
+/// The fixture paths are relative to the project directory.
+///
+fn load_storage_fixtures(
+    application: &mut BuildApplication,
+    project_path: &Path,
+) -> Result<(), Error> {
+    match application {
+        BuildApplication::Circuit(circuit) => {
+            if let Some((name, _unit_test)) = circuit
+                .unit_tests
+                .iter()
+                .find(|(_name, unit_test)| unit_test.storage_fixture.is_some())
+            {
+                return Err(Error::StorageFixtureOutsideContract(name.to_owned()));
+            }
+        }
+        BuildApplication::Contract(contract) => {
+            for unit_test in contract.unit_tests.values_mut() {
+                if let Some(ref fixture) = unit_test.storage_fixture {
+                    let path = project_path.join(fixture);
+                    let storage = read_storage_fixture(&path, contract.storage.as_slice())?;
+                    unit_test.storage = Some(storage);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// Reads a contract storage fixture, which has the same format as the storage input file.
+///
+fn read_storage_fixture(path: &Path, fields: &[ContractFieldType]) -> Result<BuildValue, Error> {
+    let data = fs::read(path)
+        .map_err(|error| Error::StorageFixtureReading(path.as_os_str().to_owned(), error))?;
+    let values = match serde_json::from_slice(data.as_slice())
+        .map_err(|error| Error::StorageFixtureParsing(path.as_os_str().to_owned(), error))?
+    {
+        JsonValue::Array(values) => values,
+        _ => return Err(Error::StorageFixtureFormat(path.as_os_str().to_owned())),
+    };
+    if values.len() != fields.len() {
+        return Err(Error::StorageFixtureFieldCount(
+            path.as_os_str().to_owned(),
+            fields.len(),
+            values.len(),
+        ));
+    }
+
+    let mut storage = Vec::with_capacity(fields.len());
+    for (field, value) in fields.iter().zip(values) {
+        let value =
+            BuildValue::try_from_typed_json(value, field.r#type.to_owned()).map_err(|error| {
+                Error::StorageFixtureField(
+                    path.as_os_str().to_owned(),
+                    field.name.to_owned(),
+                    error,
+                )
+            })?;
+
+        storage.push(ContractFieldValue::new(
+            field.name.to_owned(),
+            value,
+            field.is_public,
+            field.is_implicit,
+        ));
+    }
+
+    Ok(BuildValue::Contract(storage))
+}
+
+///
+/// Converts the library interfaces into the compiler representation.
+///
+fn into_libraries(
+    interfaces: HashMap<String, zinc_source::Library>,
+) -> Result<HashMap<String, SourceLibrary>, SourceError> {
+    interfaces
+        .into_iter()
+        .map(|(name, interface)| Ok((name, SourceLibrary::try_from_interface(interface)?)))
+        .collect()
+}
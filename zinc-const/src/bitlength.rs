@@ -29,5 +29,15 @@ pub const TOKEN_ID: usize = BYTE * 2;
 /// The smart contract balance bitlength.
 pub const BALANCE: usize = self::INTEGER_MAX;
 
+/// The smart contract storage layout hash bitlength, which is the SHA256 hash truncated to fit
+/// into the field element.
+pub const LAYOUT_HASH: usize = self::INTEGER_MAX;
+
 /// The ETH address bitlength.
 pub const ETH_ADDRESS: usize = crate::size::ETH_ADDRESS * BYTE;
+
+/// The smart contract environment timestamp bitlength.
+pub const TIMESTAMP: usize = BYTE * 8;
+
+/// The smart contract environment block number bitlength.
+pub const BLOCK_NUMBER: usize = BYTE * 8;
@@ -6,10 +6,10 @@
 pub static CONSTRUCTOR_NAME: &str = "new";
 
 /// The implicit fields count.
-pub const IMPLICIT_FIELDS_COUNT: usize = 2;
+pub const IMPLICIT_FIELDS_COUNT: usize = 3;
 
 /// The implicit fields size.
-pub const IMPLICIT_FIELDS_SIZE: usize = 1;
+pub const IMPLICIT_FIELDS_SIZE: usize = 2;
 
 /// The first default implicit field index.
 pub const FIELD_INDEX_ADDRESS: usize = 0;
@@ -23,8 +23,23 @@ pub const FIELD_INDEX_BALANCES: usize = 1;
 /// The second default implicit field name.
 pub static FIELD_NAME_BALANCES: &str = "balances";
 
+/// The third default implicit field index.
+pub const FIELD_INDEX_LAYOUT_HASH: usize = 2;
+
+/// The third default implicit field name.
+pub static FIELD_NAME_LAYOUT_HASH: &str = "layout_hash";
+
 /// The implicit transaction variable name.
 pub static TRANSACTION_VARIABLE_NAME: &str = "msg";
 
 /// The implicit transaction variable size.
 pub const TRANSACTION_SIZE: usize = 8;
+
+/// The implicit environment variable name.
+pub static ENVIRONMENT_VARIABLE_NAME: &str = "env";
+
+/// The implicit environment variable size.
+pub const ENVIRONMENT_SIZE: usize = 2;
+
+/// The placeholder address set by the virtual machine binary to the contracts initialized locally.
+pub const LOCAL_ADDRESS: u64 = 1;
@@ -10,3 +10,9 @@ pub static DATA: &str = "data/";
 
 /// The default binary build directory within a project.
 pub static BUILD: &str = "build/";
+
+/// The proving keys cache directory, relative to the user home directory.
+pub static KEYS_CACHE: &str = ".zinc/keys/";
+
+/// The user-level configuration directory, relative to the user home directory.
+pub static USER_CONFIG: &str = ".zinc/";
@@ -11,5 +11,23 @@ pub static SOURCE: &str = "zn";
 /// The binary file extension.
 pub static BINARY: &str = "znb";
 
+/// The library interface file extension.
+pub static LIBRARY: &str = "znl";
+
 /// The JSON data file extension.
 pub static JSON: &str = "json";
+
+/// The contract ABI file extension, which precedes the JSON one.
+pub static ABI: &str = "abi";
+
+/// The cached proving key parameters file extension.
+pub static PARAMETERS: &str = "params";
+
+/// The TypeScript source code file extension.
+pub static TYPESCRIPT: &str = "ts";
+
+/// The generator intermediate representation listing file extension.
+pub static INTERMEDIATE: &str = "znir";
+
+/// The bytecode disassembly listing file extension.
+pub static DISASSEMBLY: &str = "znasm";
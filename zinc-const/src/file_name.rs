@@ -14,6 +14,12 @@ pub static MODULE_ENTRY: &str = "mod";
 /// The binary file default name.
 pub static BINARY: &str = "main";
 
+/// The library interface file default name.
+pub static LIBRARY: &str = "main";
+
+/// The incremental build cache file default name.
+pub static BUILD_CACHE: &str = "cache";
+
 /// The input template file default name.
 pub static INPUT: &str = "input";
 
@@ -28,3 +34,10 @@ pub static VERIFYING_KEY: &str = "verifying_key";
 
 /// The private key file default name (testnet only!).
 pub static PRIVATE_KEY: &str = "private_key";
+
+/// The pending contract private key file default name, which is kept until the key rotation
+/// is completed.
+pub static ROTATED_PRIVATE_KEY: &str = "rotated_private_key";
+
+/// The user-level configuration file name.
+pub static USER_CONFIG: &str = "config";
@@ -0,0 +1,6 @@
+//!
+//! The Zinc JSON template constants.
+//!
+
+/// The template object key holding the field descriptions, which is ignored while reading values.
+pub static COMMENTS_KEY: &str = "_comments";
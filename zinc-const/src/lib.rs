@@ -10,6 +10,7 @@ pub mod directory;
 pub mod exit_code;
 pub mod extension;
 pub mod file_name;
+pub mod json;
 pub mod limit;
 pub mod panic;
 pub mod path;
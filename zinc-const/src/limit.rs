@@ -19,3 +19,33 @@ pub const COMPILER_STACK_SIZE: usize = 64 * 1024 * 1024;
 
 /// The JSON payload limit to fit large contract source code.
 pub static JSON_PAYLOAD: usize = 16 * 1024 * 1024;
+
+/// The JSON payload limit of the contract method requests, which only carry the arguments.
+pub static JSON_METHOD_PAYLOAD: usize = 1024 * 1024;
+
+/// The input JSON value nesting depth limit, beyond which the value is rejected.
+pub const JSON_DEPTH: usize = 64;
+
+/// The proving keys cache directory size limit, beyond which the least recently used keys are removed.
+pub const KEYS_CACHE_SIZE: u64 = 4 * 1024 * 1024 * 1024;
+
+/// The default virtual machine data stack size limit in cells, beyond which the execution fails.
+pub const VM_DATA_STACK_SIZE: usize = 4 * 1024 * 1024;
+
+/// The contract metadata value size limit in bytes, beyond which the contract cannot be published.
+pub const CONTRACT_METADATA_VALUE_SIZE: usize = 1024;
+
+/// The default time limit of the contract methods sizing run at publishing in milliseconds,
+/// beyond which the remaining methods cost is unknown.
+pub const CONTRACT_SIZING_TIMEOUT_MS: u64 = 10_000;
+
+/// The default flattened size limit of the contract public method inputs, outputs, and storage,
+/// beyond which setting up and proving the contract is impractical.
+pub const ENTRY_INPUT_SIZE: usize = 16 * 1024;
+
+/// The minimal number of `match` constant branches, which are lowered to a dispatch table.
+pub const DISPATCH_ARMS_MIN: usize = 2;
+
+/// The maximal number of `match` constant branches, which are lowered to a dispatch table,
+/// so the branches are indexed by a `u8` offset from the first discriminant.
+pub const DISPATCH_ARMS_MAX: usize = (std::u8::MAX as usize) + 1;
@@ -23,6 +23,9 @@ pub static LAST_SHARED_REFERENCE: &str = "There must be no other references at t
 /// The builder pattern entity must be provided with the specified value.
 pub static BUILDER_REQUIRES_VALUE: &str = "The builder requires a value: ";
 
+/// The intrinsic scope is shared between compilations, so it must never be modified.
+pub static INTRINSIC_SCOPE_IMMUTABLE: &str = "The intrinsic scope must not be modified";
+
 /// The unit test data validity is checked by the test authors.
 pub static TEST_DATA_VALID: &str = "Test data is always valid";
 
@@ -22,3 +22,30 @@ pub static CONTRACT_FEE_URL: &str = "/api/v1/contract/fee";
 
 /// The contract call URL.
 pub static CONTRACT_CALL_URL: &str = "/api/v1/contract/call";
+
+/// The contract private key rotation URL.
+pub static CONTRACT_KEY_URL: &str = "/api/v1/contract/key";
+
+/// The privileged request nonce URL.
+pub static CONTRACT_NONCE_URL: &str = "/api/v1/contract/nonce";
+
+/// The contract publishing job URL, which is followed by the job ID.
+pub static JOB_URL: &str = "/api/v1/jobs";
+
+/// The finished contract publishing job lifetime in seconds.
+pub const JOB_TTL: u64 = 3600;
+
+/// The privileged request nonce header.
+pub static HEADER_AUTH_NONCE: &str = "X-Zandbox-Nonce";
+
+/// The privileged request nonce signature header.
+pub static HEADER_AUTH_SIGNATURE: &str = "X-Zandbox-Signature";
+
+/// The privileged request nonce lifetime in seconds.
+pub const AUTH_NONCE_LIFETIME: u64 = 300;
+
+/// The contract list page size, if the `limit` parameter is omitted.
+pub const CONTRACT_LIST_DEFAULT_LIMIT: u32 = 100;
+
+/// The contract list maximal page size. Greater `limit` values are clamped to it.
+pub const CONTRACT_LIST_MAX_LIMIT: u32 = 1000;
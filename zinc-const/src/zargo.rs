@@ -4,3 +4,18 @@
 
 /// The default initial Zinc project version.
 pub static INITIAL_PROJECT_VERSION: &str = "0.1.0";
+
+/// The deposit priority operation commitment timeout in milliseconds.
+pub const DEPOSIT_COMMIT_TIMEOUT_MS: u64 = 120_000;
+
+/// The deposit priority operation commitment polling interval in milliseconds.
+pub const DEPOSIT_COMMIT_POLLING_INTERVAL_MS: u64 = 1_000;
+
+/// The contract publishing job status polling interval in milliseconds.
+pub const PUBLISH_JOB_POLLING_INTERVAL_MS: u64 = 1_000;
+
+/// The default zkSync network name, which is used if it is set neither in arguments nor configs.
+pub static DEFAULT_NETWORK: &str = "localhost";
+
+/// The default token symbol, which the transaction fees are paid in.
+pub static DEFAULT_FEE_TOKEN: &str = "ETH";
@@ -0,0 +1,64 @@
+/*
+ * The Zinc virtual machine C interface.
+ *
+ * The strings returned by the library must be released with `zinc_free_string`.
+ */
+
+#ifndef ZINC_FFI_H
+#define ZINC_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * The error code returned by `zinc_run_circuit`.
+ */
+typedef enum {
+    ZINC_SUCCESS = 0,
+    ZINC_NULL_POINTER = 1,
+    ZINC_INVALID_UTF8 = 2,
+    ZINC_BYTECODE_DECODING = 3,
+    ZINC_NOT_CIRCUIT = 4,
+    ZINC_INPUT_DECODING = 5,
+
+    ZINC_SYNTHESIS = 16,
+    ZINC_INTERNAL = 17,
+    ZINC_MALFORMED_BYTECODE = 18,
+    ZINC_REQUIRE = 19,
+    ZINC_INDEX_OUT_OF_BOUNDS = 20,
+    ZINC_TYPE = 21,
+    ZINC_UNSATISFIED_CONSTRAINT = 22,
+    ZINC_DIVISION_BY_ZERO = 23,
+    ZINC_VALUE_OVERFLOW = 24,
+    ZINC_UNSUPPORTED = 25,
+
+    ZINC_PANIC = 255,
+} zinc_error_code_t;
+
+/*
+ * Evaluates the circuit witness.
+ *
+ * `input_json` has the same format as the `zargo` input file. On success, `*out_json_ptr`
+ * is set to the output JSON string, and on failure, to the error description.
+ */
+zinc_error_code_t zinc_run_circuit(
+    const uint8_t *bytecode_ptr,
+    size_t bytecode_len,
+    const char *input_json,
+    char **out_json_ptr
+);
+
+/*
+ * Releases the string returned by the library. Does nothing if `string` is null.
+ */
+void zinc_free_string(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ZINC_FFI_H */
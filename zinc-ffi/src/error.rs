@@ -0,0 +1,76 @@
+//!
+//! The Zinc FFI error code.
+//!
+
+use zinc_vm::RuntimeError;
+
+///
+/// The error code returned by the C interface functions.
+///
+/// The runtime error codes mirror the categories of the virtual machine `RuntimeError`.
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// The circuit has been evaluated successfully.
+    Success = 0,
+    /// A required pointer argument is null.
+    NullPointer = 1,
+    /// The input JSON string is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The bytecode cannot be decoded.
+    BytecodeDecoding = 3,
+    /// The bytecode is not a circuit.
+    NotCircuit = 4,
+    /// The input JSON is malformed or does not match the circuit input type.
+    InputDecoding = 5,
+
+    /// The constraint system synthesis has failed.
+    Synthesis = 16,
+    /// The virtual machine internal error.
+    Internal = 17,
+    /// The bytecode is malformed, e.g. the data stack limit is exceeded.
+    MalformedBytecode = 18,
+    /// A `require` statement has failed.
+    Require = 19,
+    /// An array index is out of bounds.
+    IndexOutOfBounds = 20,
+    /// A value has an unexpected type or size.
+    Type = 21,
+    /// A constraint is not satisfied.
+    UnsatisfiedConstraint = 22,
+    /// A division by zero or zero inversion.
+    DivisionByZero = 23,
+    /// A value does not fit into its type.
+    ValueOverflow = 24,
+    /// The error is specific to contracts or unit tests and cannot occur in a circuit.
+    Unsupported = 25,
+
+    /// The Rust code has panicked, which has been caught at the FFI boundary.
+    Panic = 255,
+}
+
+impl From<&RuntimeError> for ErrorCode {
+    fn from(error: &RuntimeError) -> Self {
+        match error {
+            RuntimeError::SynthesisError(_) => Self::Synthesis,
+            RuntimeError::InternalError(_) => Self::Internal,
+            RuntimeError::MalformedBytecode(_) => Self::MalformedBytecode,
+            RuntimeError::RequireError(_) => Self::Require,
+            RuntimeError::IndexOutOfBounds { .. } => Self::IndexOutOfBounds,
+            RuntimeError::TypeError { .. }
+            | RuntimeError::ExpectedConstant
+            | RuntimeError::ExpectedUsize(_)
+            | RuntimeError::TypeSize(_) => Self::Type,
+            RuntimeError::UnsatisfiedConstraint => Self::UnsatisfiedConstraint,
+            RuntimeError::DivisionByZero | RuntimeError::ZeroInversion => Self::DivisionByZero,
+            RuntimeError::ValueOverflow { .. } => Self::ValueOverflow,
+            RuntimeError::UnitTestDataMissing
+            | RuntimeError::OnlyForContracts
+            | RuntimeError::InvalidStorageValue
+            | RuntimeError::MethodNotFound { .. }
+            | RuntimeError::PayableMethodWithoutTransfer { .. }
+            | RuntimeError::NonPayableMethodWithTransfer { .. } => Self::Unsupported,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//!
+//! The Zinc virtual machine C interface.
+//!
+//! The strings returned by the library are owned by the library and must be released
+//! with `zinc_free_string`. The strings and buffers passed to the library are only borrowed
+//! for the duration of the call.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic;
+use std::ptr;
+use std::slice;
+
+use zinc_build::Application as BuildApplication;
+use zinc_build::InputBuild;
+use zinc_build::Value as BuildValue;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::CircuitInput;
+
+use self::error::ErrorCode;
+
+///
+/// Evaluates the circuit witness.
+///
+/// Decodes the circuit from `bytecode_len` bytes at `bytecode_ptr`, runs it with the
+/// null-terminated input JSON `input_json`, which has the same format as the `zargo`
+/// input file, and returns the error code.
+///
+/// On success, `*out_json_ptr` is set to the output JSON string. On failure, it is set to
+/// the error description, unless the `out_json_ptr` itself is null. Either string must be
+/// released with `zinc_free_string`.
+///
+/// # Safety
+/// `bytecode_ptr` must point to `bytecode_len` readable bytes, `input_json` must be a valid
+/// null-terminated string, and `out_json_ptr` must be valid for writes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn zinc_run_circuit(
+    bytecode_ptr: *const u8,
+    bytecode_len: usize,
+    input_json: *const c_char,
+    out_json_ptr: *mut *mut c_char,
+) -> ErrorCode {
+    if out_json_ptr.is_null() {
+        return ErrorCode::NullPointer;
+    }
+    *out_json_ptr = ptr::null_mut();
+
+    let result = panic::catch_unwind(|| {
+        if bytecode_ptr.is_null() || input_json.is_null() {
+            return Err((ErrorCode::NullPointer, "null pointer argument".to_owned()));
+        }
+
+        let bytecode = slice::from_raw_parts(bytecode_ptr, bytecode_len);
+        let input = CStr::from_ptr(input_json)
+            .to_str()
+            .map_err(|error| (ErrorCode::InvalidUtf8, error.to_string()))?;
+
+        run_circuit(bytecode, input)
+    });
+
+    let (code, string) = match result {
+        Ok(Ok(output)) => (ErrorCode::Success, output),
+        Ok(Err((code, message))) => (code, message),
+        Err(_panic) => (ErrorCode::Panic, "the virtual machine panicked".to_owned()),
+    };
+
+    *out_json_ptr = into_raw_string(string);
+    code
+}
+
+///
+/// Releases the string returned by the library.
+///
+/// # Safety
+/// `string` must be either null or a pointer returned by the library, which has not been
+/// released yet.
+///
+#[no_mangle]
+pub unsafe extern "C" fn zinc_free_string(string: *mut c_char) {
+    if string.is_null() {
+        return;
+    }
+
+    drop(CString::from_raw(string));
+}
+
+///
+/// Runs the circuit `bytecode` with the `input` JSON and returns the output JSON.
+///
+fn run_circuit(bytecode: &[u8], input: &str) -> Result<String, (ErrorCode, String)> {
+    let circuit = match BuildApplication::try_from_slice(bytecode)
+        .map_err(|error| (ErrorCode::BytecodeDecoding, error))?
+    {
+        BuildApplication::Circuit(circuit) => circuit,
+        BuildApplication::Contract(_) => {
+            return Err((
+                ErrorCode::NotCircuit,
+                "the bytecode is a contract".to_owned(),
+            ))
+        }
+    };
+
+    let arguments = match serde_json::from_str::<InputBuild>(input)
+        .map_err(|error| (ErrorCode::InputDecoding, error.to_string()))?
+    {
+        InputBuild::Circuit { arguments } => arguments,
+        InputBuild::Contract { .. } => {
+            return Err((
+                ErrorCode::InputDecoding,
+                "the input is for a contract".to_owned(),
+            ))
+        }
+    };
+    let arguments = BuildValue::try_from_typed_json(arguments, circuit.input.clone())
+        .map_err(|error| (ErrorCode::InputDecoding, error.to_string()))?;
+
+    let output = CircuitFacade::new(circuit)
+        .run::<Bn256>(CircuitInput::new(arguments))
+        .map_err(|error| (ErrorCode::from(&error), error.to_string()))?;
+
+    Ok(output.result.into_json().to_string())
+}
+
+///
+/// Converts the `string` into a raw C string owned by the caller.
+///
+/// The interior null bytes, which cannot be represented in a C string, are dropped.
+///
+fn into_raw_string(string: String) -> *mut c_char {
+    let string = CString::new(string).unwrap_or_else(|error| {
+        let mut bytes = error.into_vec();
+        bytes.retain(|byte| *byte != 0);
+        CString::new(bytes).expect(zinc_const::panic::DATA_CONVERSION)
+    });
+
+    string.into_raw()
+}
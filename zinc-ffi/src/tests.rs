@@ -0,0 +1,137 @@
+//!
+//! The Zinc FFI tests.
+//!
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+use zinc_build::Add;
+use zinc_build::Application as BuildApplication;
+use zinc_build::Exit;
+use zinc_build::IntegerType;
+use zinc_build::Load;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use crate::error::ErrorCode;
+
+fn bytecode() -> Vec<u8> {
+    BuildApplication::new_circuit(
+        "test".to_owned(),
+        0,
+        BuildType::Structure(vec![
+            (
+                "a".to_owned(),
+                BuildType::Scalar(ScalarType::Integer(IntegerType::U8)),
+            ),
+            (
+                "b".to_owned(),
+                BuildType::Scalar(ScalarType::Integer(IntegerType::U8)),
+            ),
+        ]),
+        BuildType::Scalar(ScalarType::Integer(IntegerType::U8)),
+        HashMap::new(),
+        vec![Load::new(0, 2).into(), Add.into(), Exit::new(1).into()],
+    )
+    .into_vec()
+}
+
+fn run(bytecode: &[u8], input: &str) -> (ErrorCode, String) {
+    let input = CString::new(input).expect(zinc_const::panic::TEST_DATA_VALID);
+    let mut output: *mut c_char = ptr::null_mut();
+
+    let code = unsafe {
+        crate::zinc_run_circuit(
+            bytecode.as_ptr(),
+            bytecode.len(),
+            input.as_ptr(),
+            &mut output,
+        )
+    };
+
+    assert!(!output.is_null());
+    let string = unsafe { CStr::from_ptr(output) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { crate::zinc_free_string(output) };
+
+    (code, string)
+}
+
+#[test]
+fn ok() {
+    let (code, output) = run(
+        bytecode().as_slice(),
+        r#"{ "type": "circuit", "arguments": { "a": "2", "b": "3" } }"#,
+    );
+
+    assert_eq!(code, ErrorCode::Success);
+    assert_eq!(output, r#""5""#);
+}
+
+#[test]
+fn ok_free_null() {
+    unsafe { crate::zinc_free_string(ptr::null_mut()) };
+}
+
+#[test]
+fn error_null_pointer() {
+    let bytecode = bytecode();
+    let mut output: *mut c_char = ptr::null_mut();
+
+    let code = unsafe {
+        crate::zinc_run_circuit(bytecode.as_ptr(), bytecode.len(), ptr::null(), &mut output)
+    };
+    assert_eq!(code, ErrorCode::NullPointer);
+
+    unsafe { crate::zinc_free_string(output) };
+}
+
+#[test]
+fn error_null_output_pointer() {
+    let bytecode = bytecode();
+    let input = CString::new("{}").expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let code = unsafe {
+        crate::zinc_run_circuit(
+            bytecode.as_ptr(),
+            bytecode.len(),
+            input.as_ptr(),
+            ptr::null_mut(),
+        )
+    };
+    assert_eq!(code, ErrorCode::NullPointer);
+}
+
+#[test]
+fn error_bytecode_decoding() {
+    let (code, _message) = run(
+        &[0xff, 0xff, 0xff],
+        r#"{ "type": "circuit", "arguments": {} }"#,
+    );
+
+    assert_eq!(code, ErrorCode::BytecodeDecoding);
+}
+
+#[test]
+fn error_input_decoding() {
+    let (code, _message) = run(
+        bytecode().as_slice(),
+        r#"{ "type": "circuit", "arguments": { "a": "2" } }"#,
+    );
+
+    assert_eq!(code, ErrorCode::InputDecoding);
+}
+
+#[test]
+fn error_value_overflow() {
+    let (code, _message) = run(
+        bytecode().as_slice(),
+        r#"{ "type": "circuit", "arguments": { "a": "255", "b": "1" } }"#,
+    );
+
+    assert_eq!(code, ErrorCode::ValueOverflow);
+}
@@ -0,0 +1,105 @@
+/*
+ * The Zinc FFI test harness.
+ *
+ * Usage: harness <bytecode path>
+ *
+ * The bytecode must be the `tests/circuit` project compiled with `znc`.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "zinc_ffi.h"
+
+static int failures = 0;
+
+static void check(
+    const char *name,
+    const uint8_t *bytecode,
+    size_t bytecode_len,
+    const char *input,
+    zinc_error_code_t expected_code,
+    const char *expected_output
+) {
+    char *output = NULL;
+    zinc_error_code_t code = zinc_run_circuit(bytecode, bytecode_len, input, &output);
+
+    if (code != expected_code) {
+        fprintf(stderr, "%s: expected code %d, got %d (%s)\n",
+            name, expected_code, code, output ? output : "null");
+        failures++;
+    } else if (expected_output != NULL && (output == NULL || strcmp(output, expected_output) != 0)) {
+        fprintf(stderr, "%s: expected output %s, got %s\n",
+            name, expected_output, output ? output : "null");
+        failures++;
+    } else {
+        printf("%s: ok\n", name);
+    }
+
+    zinc_free_string(output);
+}
+
+static uint8_t *read_file(const char *path, size_t *length) {
+    FILE *file = fopen(path, "rb");
+    if (file == NULL) {
+        return NULL;
+    }
+
+    fseek(file, 0, SEEK_END);
+    long size = ftell(file);
+    fseek(file, 0, SEEK_SET);
+
+    uint8_t *data = malloc(size > 0 ? (size_t) size : 1);
+    *length = fread(data, 1, (size_t) size, file);
+    fclose(file);
+
+    return data;
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <bytecode path>\n", argv[0]);
+        return EXIT_FAILURE;
+    }
+
+    size_t bytecode_len = 0;
+    uint8_t *bytecode = read_file(argv[1], &bytecode_len);
+    if (bytecode == NULL) {
+        fprintf(stderr, "cannot read the bytecode file %s\n", argv[1]);
+        return EXIT_FAILURE;
+    }
+
+    check("ok", bytecode, bytecode_len,
+        "{ \"type\": \"circuit\", \"arguments\": { \"a\": \"17\", \"b\": \"5\" } }",
+        ZINC_SUCCESS, "\"5\"");
+    check("error_require", bytecode, bytecode_len,
+        "{ \"type\": \"circuit\", \"arguments\": { \"a\": \"17\", \"b\": \"0\" } }",
+        ZINC_REQUIRE, NULL);
+    check("error_input_decoding", bytecode, bytecode_len,
+        "{ \"type\": \"circuit\", \"arguments\": { \"a\": \"256\", \"b\": \"5\" } }",
+        ZINC_INPUT_DECODING, NULL);
+    check("error_bytecode_decoding", (const uint8_t *) "\xff\xff\xff", 3,
+        "{ \"type\": \"circuit\", \"arguments\": {} }",
+        ZINC_BYTECODE_DECODING, NULL);
+    check("error_null_pointer", NULL, 0,
+        "{ \"type\": \"circuit\", \"arguments\": {} }",
+        ZINC_NULL_POINTER, NULL);
+
+    if (zinc_run_circuit(bytecode, bytecode_len, "{}", NULL) != ZINC_NULL_POINTER) {
+        fprintf(stderr, "error_null_output_pointer: expected code %d\n", ZINC_NULL_POINTER);
+        failures++;
+    } else {
+        printf("error_null_output_pointer: ok\n");
+    }
+
+    zinc_free_string(NULL);
+    free(bytecode);
+
+    if (failures > 0) {
+        fprintf(stderr, "%d test(s) failed\n", failures);
+        return EXIT_FAILURE;
+    }
+
+    return EXIT_SUCCESS;
+}
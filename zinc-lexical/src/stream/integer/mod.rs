@@ -293,3 +293,18 @@ pub fn parse(input: &str) -> Result<Output, Error> {
         }
     }
 }
+
+///
+/// Parses a tuple index, like `1` in `tuple.1.0`, which follows the member access dot.
+///
+/// Only the decimal digits are taken, so the next dot is left to the chained member access
+/// instead of being parsed as the decimal point.
+///
+pub fn parse_tuple_index(input: &str) -> Output {
+    let size = input
+        .chars()
+        .take_while(|character| Integer::CHARACTERS_DECIMAL.contains(character))
+        .count();
+
+    Output::new(size, Integer::new_decimal(input[..size].to_owned()))
+}
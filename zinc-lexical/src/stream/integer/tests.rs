@@ -3,6 +3,7 @@
 //!
 
 use super::parse;
+use super::parse_tuple_index;
 use super::Error;
 use super::Output;
 use crate::token::lexeme::literal::integer::Integer;
@@ -230,6 +231,14 @@ fn error_expected_one_of_decimal() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_tuple_index() {
+    let input = "1.0";
+    let expected = Output::new(1, Integer::new_decimal("1".to_owned()));
+    let result = parse_tuple_index(input);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_expected_one_of_decimal_zero_with_exponent() {
     let input = "0E";
@@ -9,6 +9,7 @@ pub mod symbol;
 pub mod word;
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::rc::Rc;
 
@@ -17,6 +18,7 @@ use crate::token::lexeme::comment::Comment;
 use crate::token::lexeme::identifier::Identifier;
 use crate::token::lexeme::literal::string::String as StringLiteral;
 use crate::token::lexeme::literal::Literal;
+use crate::token::lexeme::symbol::Symbol;
 use crate::token::lexeme::Lexeme;
 use crate::token::location::Location;
 use crate::token::Token;
@@ -38,7 +40,20 @@ pub struct TokenStream<'a> {
     location: Location,
     /// The queue buffer where the characters acquired with the look-ahead method are stored.
     /// If the queue is not empty, the next character will be taken therefrom.
-    look_ahead: VecDeque<Token>,
+    /// Each token is stored along with its end location.
+    look_ahead: VecDeque<(Token, Location)>,
+    /// The end location of the last token taken with the `next` method.
+    consumed_end: Location,
+    /// The end location of the last token read from the input.
+    last_end: Location,
+    /// The end locations of the preceding tokens, where the keys are the token start lines
+    /// and columns.
+    previous_ends: HashMap<(usize, usize), Location>,
+    /// If the comments are yielded as tokens instead of being skipped.
+    preserves_comments: bool,
+    /// If the last token read from the input is the member access dot, so the number following
+    /// it is a tuple index.
+    is_after_dot: bool,
 }
 
 impl<'a> TokenStream<'a> {
@@ -55,9 +70,25 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(file),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            consumed_end: Location::new(file),
+            last_end: Location::new(file),
+            previous_ends: HashMap::new(),
+            preserves_comments: false,
+            is_after_dot: false,
         }
     }
 
+    ///
+    /// Makes the stream yield the comments as tokens instead of skipping them.
+    ///
+    /// The parsers do not expect the comment tokens, so the mode is only used by tools
+    /// reproducing the source code, e.g. the formatter.
+    ///
+    pub fn with_comments(mut self) -> Self {
+        self.preserves_comments = true;
+        self
+    }
+
     ///
     /// Wraps the stream into `Rc<RefCell<_>>` simplifying most of initializations.
     ///
@@ -71,10 +102,11 @@ impl<'a> TokenStream<'a> {
     ///
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<Token, Error> {
-        let token = match self.look_ahead.pop_front() {
+        let (token, end) = match self.look_ahead.pop_front() {
             Some(token) => token,
-            None => self.advance()?,
+            None => self.advance_with_end()?,
         };
+        self.consumed_end = end;
         log::debug!("{:?}", token);
         Ok(token)
     }
@@ -89,15 +121,34 @@ impl<'a> TokenStream<'a> {
     ///
     pub fn look_ahead(&mut self, distance: usize) -> Result<&Token, Error> {
         while self.look_ahead.len() < distance {
-            let token = self.advance()?;
+            let token = self.advance_with_end()?;
             self.look_ahead.push_back(token);
         }
 
         self.look_ahead
             .get(distance - 1)
+            .map(|(token, _end)| token)
             .ok_or_else(|| Error::unexpected_end(self.location))
     }
 
+    ///
+    /// Returns the end location of the last token consumed before `next`.
+    ///
+    /// The parsers usually take one token more than they need and return it to the caller,
+    /// so if the `next` token is passed, the end of the token preceding it is returned.
+    /// Otherwise, the end of the last token taken with the `next` method is returned.
+    ///
+    pub fn end_before(&self, next: Option<&Token>) -> Location {
+        match next {
+            Some(token) => self
+                .previous_ends
+                .get(&(token.location.line, token.location.column))
+                .copied()
+                .unwrap_or(token.location),
+            None => self.consumed_end,
+        }
+    }
+
     ///
     /// Initializes a stream with an auto-generated file identifier.
     /// The file identifier can be used to get its path from the global type index.
@@ -109,15 +160,41 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(0),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            consumed_end: Location::new(0),
+            last_end: Location::new(0),
+            previous_ends: HashMap::new(),
+            preserves_comments: false,
+            is_after_dot: false,
         }
     }
 
+    ///
+    /// Advances the stream iterator and remembers the end location of the read token.
+    ///
+    fn advance_with_end(&mut self) -> Result<(Token, Location), Error> {
+        let token = self.advance()?;
+        let end = self.location;
+
+        self.previous_ends
+            .entry((token.location.line, token.location.column))
+            .or_insert(self.last_end);
+        self.last_end = end;
+
+        if !matches!(token.lexeme, Lexeme::Comment(_)) {
+            self.is_after_dot = token.lexeme == Lexeme::Symbol(Symbol::Dot);
+        }
+
+        Ok((token, end))
+    }
+
     ///
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
-    /// 2. Starts a comment -> start the comment subparser
+    /// 2. Starts a comment -> start the comment subparser, and skip the comment unless
+    ///    the comments are preserved
     /// 3. Starts a string literal -> start the string subparser
-    /// 4. Starts a number -> start the number subparser
+    /// 4. Starts a number -> start the number subparser, or take the tuple index digits if
+    ///    the number follows the member access dot
     /// 5. Starts a word -> start the word subparser
     /// 6. Starts a symbol -> start the operand subparser
     /// 7. Is unknown -> yield an 'invalid character' error
@@ -140,12 +217,19 @@ impl<'a> TokenStream<'a> {
             if character == '/' {
                 match self::comment::parse(&self.input[self.offset..]) {
                     Ok(output) => {
+                        let location = self.location;
                         self.location.line += output.lines;
                         self.location.column = match output.comment {
                             Comment::Line { .. } => 1,
+                            Comment::Block { .. } if output.lines == 0 => {
+                                location.column + output.size
+                            }
                             Comment::Block { .. } => output.column,
                         };
                         self.offset += output.size;
+                        if self.preserves_comments {
+                            return Ok(Token::new(Lexeme::Comment(output.comment), location));
+                        }
                         continue;
                     }
                     Err(CommentParserError::NotAComment) => {}
@@ -179,6 +263,17 @@ impl<'a> TokenStream<'a> {
                 }
             }
 
+            if character.is_ascii_digit() && self.is_after_dot {
+                let output = self::integer::parse_tuple_index(&self.input[self.offset..]);
+                let location = self.location;
+                self.location.column += output.size;
+                self.offset += output.size;
+                return Ok(Token::new(
+                    Lexeme::Literal(Literal::Integer(output.integer)),
+                    location,
+                ));
+            }
+
             if character.is_ascii_digit() {
                 match self::integer::parse(&self.input[self.offset..]) {
                     Ok(output) => {
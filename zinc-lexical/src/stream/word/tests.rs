@@ -55,6 +55,17 @@ fn ok_identifier_invalid_modulo() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_identifier_fixed_without_decimals() {
+    let input = "ufixed64";
+    let expected = Output::new(
+        input.len(),
+        Lexeme::Identifier(Identifier::new(input.to_owned())),
+    );
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn ok_keyword() {
     let input = "match";
@@ -84,6 +95,28 @@ fn ok_keyword_unsigned_integer_max() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_keyword_fixed_unsigned() {
+    let input = "ufixed64x2";
+    let expected = Output::new(
+        input.len(),
+        Lexeme::Keyword(Keyword::new_fixed_unsigned(64, 2)),
+    );
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_keyword_fixed_signed() {
+    let input = "fixed128x18";
+    let expected = Output::new(
+        input.len(),
+        Lexeme::Keyword(Keyword::new_fixed_signed(128, 18)),
+    );
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn ok_literal_boolean() {
     let input = "true";
@@ -4,6 +4,7 @@
 
 use crate::error::Error;
 use crate::stream::TokenStream;
+use crate::token::lexeme::comment::Comment;
 use crate::token::lexeme::identifier::Identifier;
 use crate::token::lexeme::keyword::Keyword;
 use crate::token::lexeme::literal::integer::Integer;
@@ -82,6 +83,140 @@ let mut c: u8 = 2 + 2;
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_end_before() {
+    let input = "value + 0x42  /* comment */ ;";
+
+    let mut stream = TokenStream::test(input);
+    stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(stream.end_before(None), Location::test(1, 13));
+
+    let next = stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(stream.end_before(Some(&next)), Location::test(1, 13));
+
+    stream
+        .look_ahead(1)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(stream.end_before(None), Location::test(1, 30));
+}
+
+#[test]
+fn ok_comments() {
+    let input = r#"
+// line
+let a /* block */ = 42; /* multi
+line */
+"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_line(" line".to_owned())),
+            location: Location::test(2, 1),
+        },
+        Token {
+            lexeme: Lexeme::Keyword(Keyword::Let),
+            location: Location::test(3, 1),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("a".to_owned())),
+            location: Location::test(3, 5),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" block ".to_owned())),
+            location: Location::test(3, 7),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Equals),
+            location: Location::test(3, 19),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Integer(Integer::new_decimal("42".to_owned()))),
+            location: Location::test(3, 21),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+            location: Location::test(3, 23),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" multi\nline ".to_owned())),
+            location: Location::test(3, 25),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::test(input).with_comments();
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                location,
+            } => {
+                assert_eq!(location, Location::test(5, 1));
+                break;
+            }
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_tuple_index_chain() {
+    let input = "t.1.0 + 1.5E1";
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("t".to_owned())),
+            location: Location::test(1, 1),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Dot),
+            location: Location::test(1, 2),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Integer(Integer::new_decimal("1".to_owned()))),
+            location: Location::test(1, 3),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Dot),
+            location: Location::test(1, 4),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Integer(Integer::new_decimal("0".to_owned()))),
+            location: Location::test(1, 5),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Plus),
+            location: Location::test(1, 7),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Integer(Integer::new_decimal_with_exponent(
+                "1".to_owned(),
+                Some("5".to_owned()),
+                Some("1".to_owned()),
+            ))),
+            location: Location::test(1, 9),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::test(input);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_unterminated_block_comment() {
     let input = "/*block comment";
@@ -62,8 +62,24 @@ pub enum Keyword {
         /// The signed type bitlength.
         bitlength: usize,
     },
+    /// The `ufixed{N}x{D}` type keyword.
+    FixedUnsigned {
+        /// The unsigned underlying integer bitlength.
+        bitlength: usize,
+        /// The number of decimal fractional digits.
+        decimals: usize,
+    },
+    /// The `fixed{N}x{D}` type keyword.
+    FixedSigned {
+        /// The signed underlying integer bitlength.
+        bitlength: usize,
+        /// The number of decimal fractional digits.
+        decimals: usize,
+    },
     /// The `field` type keyword.
     Field,
+    /// The `str` type keyword.
+    Str,
 
     /// The `true` literal keyword.
     True,
@@ -119,6 +135,61 @@ impl Keyword {
         Self::IntegerSigned { bitlength }
     }
 
+    ///
+    /// Creates a `ufixed{N}x{D}` keyword.
+    ///
+    pub fn new_fixed_unsigned(bitlength: usize, decimals: usize) -> Self {
+        Self::FixedUnsigned {
+            bitlength,
+            decimals,
+        }
+    }
+
+    ///
+    /// Creates a `fixed{N}x{D}` keyword.
+    ///
+    pub fn new_fixed_signed(bitlength: usize, decimals: usize) -> Self {
+        Self::FixedSigned {
+            bitlength,
+            decimals,
+        }
+    }
+
+    ///
+    /// Parses the `{N}x{D}` fixed-point type keyword suffix into the bitlength and decimals.
+    ///
+    fn parse_fixed(input: &str) -> Result<(usize, usize), Error> {
+        let mut parts = input.splitn(2, 'x');
+        let bitlength = parts.next().unwrap_or_default();
+        if bitlength.is_empty() {
+            return Err(Error::IntegerBitlengthEmpty);
+        }
+        let bitlength = bitlength
+            .parse::<usize>()
+            .map_err(|_| Error::IntegerBitlengthNotNumeric(bitlength.to_owned()))?;
+        if !Self::INTEGER_BITLENGTH_RANGE.contains(&bitlength) {
+            return Err(Error::IntegerBitlengthOutOfRange(
+                bitlength,
+                Self::INTEGER_BITLENGTH_RANGE,
+            ));
+        }
+        if bitlength % zinc_const::bitlength::BYTE != 0 {
+            return Err(Error::IntegerBitlengthNotMultipleOfEight(
+                bitlength,
+                zinc_const::bitlength::BYTE,
+            ));
+        }
+
+        let decimals = parts
+            .next()
+            .ok_or_else(|| Error::FixedDecimalsNotNumeric(input.to_owned()))?;
+        let decimals = decimals
+            .parse::<usize>()
+            .map_err(|_| Error::FixedDecimalsNotNumeric(decimals.to_owned()))?;
+
+        Ok((bitlength, decimals))
+    }
+
     ///
     /// Checks if the keyword is an alias.
     ///
@@ -146,6 +217,8 @@ pub enum Error {
     IntegerBitlengthNotMultipleOfEight(usize, usize),
     /// The bitlength is beyond the allowed range.
     IntegerBitlengthOutOfRange(usize, RangeInclusive<usize>),
+    /// There is an invalid number of decimals after the `x` character of a fixed-point type.
+    FixedDecimalsNotNumeric(String),
     /// The keyword is unknown, which means that the word is a valid identifier or something else.
     Unknown(String),
 }
@@ -182,6 +255,7 @@ impl TryFrom<&str> for Keyword {
 
             "bool" => return Ok(Self::Bool),
             "field" => return Ok(Self::Field),
+            "str" => return Ok(Self::Str),
 
             "true" => return Ok(Self::True),
             "false" => return Ok(Self::False),
@@ -205,6 +279,16 @@ impl TryFrom<&str> for Keyword {
             _ => {}
         }
 
+        if let Some("ufixed") = input.get(..6) {
+            let (bitlength, decimals) = Self::parse_fixed(&input[6..])?;
+            return Ok(Self::new_fixed_unsigned(bitlength, decimals));
+        }
+
+        if let Some("fixed") = input.get(..5) {
+            let (bitlength, decimals) = Self::parse_fixed(&input[5..])?;
+            return Ok(Self::new_fixed_signed(bitlength, decimals));
+        }
+
         if let Some("u") = input.get(..1) {
             let bitlength = &input[1..];
             if bitlength.is_empty() {
@@ -281,7 +365,16 @@ impl fmt::Display for Keyword {
             Self::Bool => write!(f, "bool"),
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
             Self::IntegerSigned { bitlength } => write!(f, "i{}", bitlength),
+            Self::FixedUnsigned {
+                bitlength,
+                decimals,
+            } => write!(f, "ufixed{}x{}", bitlength, decimals),
+            Self::FixedSigned {
+                bitlength,
+                decimals,
+            } => write!(f, "fixed{}x{}", bitlength, decimals),
             Self::Field => write!(f, "field"),
+            Self::Str => write!(f, "str"),
 
             Self::True => write!(f, "true"),
             Self::False => write!(f, "false"),
@@ -4,6 +4,7 @@
 
 pub mod file_index;
 
+use std::cmp::Ordering;
 use std::fmt;
 
 use self::file_index::FILE_INDEX;
@@ -75,6 +76,12 @@ impl PartialEq for Location {
     }
 }
 
+impl PartialOrd for Location {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some((self.line, self.column).cmp(&(other.line, other.column)))
+    }
+}
+
 impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match (self.line, self.column) {
@@ -7,6 +7,9 @@ pub mod manifest;
 pub mod project_type;
 
 pub use self::error::Error;
+pub use self::manifest::Compiler;
+pub use self::manifest::Dependency;
 pub use self::manifest::Manifest;
+pub use self::manifest::Network;
 pub use self::manifest::Project;
 pub use self::project_type::ProjectType;
@@ -2,6 +2,7 @@
 //! The Zinc project manifest file.
 //!
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::Read;
@@ -20,6 +21,15 @@ use crate::project_type::ProjectType;
 pub struct Manifest {
     /// The `project` section.
     pub project: Project,
+    /// The optional `compiler` section.
+    #[serde(default)]
+    pub compiler: Compiler,
+    /// The optional `dependencies` section, with the dependency names as keys.
+    #[serde(default)]
+    pub dependencies: HashMap<String, Dependency>,
+    /// The optional `network` section.
+    #[serde(default)]
+    pub network: Network,
 }
 
 ///
@@ -35,6 +45,59 @@ pub struct Project {
     pub version: String,
 }
 
+///
+/// The `compiler` section representation.
+///
+#[derive(Debug, Deserialize)]
+pub struct Compiler {
+    /// The flattened size limit of the contract public method inputs, outputs, and storage.
+    #[serde(default = "Compiler::default_entry_input_size_limit")]
+    pub entry_input_size_limit: usize,
+}
+
+///
+/// The `network` section representation.
+///
+/// The values are the defaults for the Zargo subcommands talking to Zandbox or zkSync,
+/// which are overridden by the subcommand arguments.
+///
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Network {
+    /// The zkSync network name, e.g. `rinkeby`.
+    pub name: Option<String>,
+    /// The Zandbox server URL, which overrides the default one of the network.
+    pub zandbox_url: Option<String>,
+    /// The token symbol, which the transaction fees are paid in.
+    pub fee_token: Option<String>,
+}
+
+///
+/// The `dependencies` section entry representation.
+///
+#[derive(Debug, Deserialize)]
+pub struct Dependency {
+    /// The path to the library project directory, relative to the dependent project one.
+    pub path: PathBuf,
+}
+
+impl Compiler {
+    ///
+    /// The default `entry_input_size_limit` value.
+    ///
+    fn default_entry_input_size_limit() -> usize {
+        zinc_const::limit::ENTRY_INPUT_SIZE
+    }
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self {
+            entry_input_size_limit: Self::default_entry_input_size_limit(),
+        }
+    }
+}
+
 impl Manifest {
     ///
     /// Creates a new manifest instance.
@@ -46,6 +109,9 @@ impl Manifest {
                 r#type: project_type,
                 version: zinc_const::zargo::INITIAL_PROJECT_VERSION.to_owned(),
             },
+            compiler: Compiler::default(),
+            dependencies: HashMap::new(),
+            network: Network::default(),
         }
     }
 
@@ -18,6 +18,9 @@ pub enum ProjectType {
     /// The smart-contract.
     #[serde(rename = "contract")]
     Contract,
+    /// The library, which is not executable, but can be used by other projects.
+    #[serde(rename = "library")]
+    Library,
 }
 
 impl FromStr for ProjectType {
@@ -27,6 +30,7 @@ impl FromStr for ProjectType {
         match value {
             "circuit" => Ok(Self::Circuit),
             "contract" => Ok(Self::Contract),
+            "library" => Ok(Self::Library),
             another => Err(another.to_owned()),
         }
     }
@@ -37,6 +41,7 @@ impl fmt::Display for ProjectType {
         match self {
             Self::Circuit => write!(f, "circuit"),
             Self::Contract => write!(f, "contract"),
+            Self::Library => write!(f, "library"),
         }
     }
 }
@@ -0,0 +1,76 @@
+//!
+//! The ETH address parsing error.
+//!
+
+use failure::Fail;
+use num::BigInt;
+
+use crate::bigint::error::Error as BigIntError;
+
+///
+/// The ETH address parsing error.
+///
+#[derive(Debug, Fail, PartialEq)]
+pub enum Error {
+    /// The hexadecimal address has no digits after the `0x` prefix.
+    #[fail(display = "the hexadecimal address has no digits")]
+    Empty,
+    /// The hexadecimal address contains a non-hexadecimal character.
+    #[fail(
+        display = "invalid character `{}` at position {}, expected a hexadecimal digit",
+        character, position
+    )]
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+        /// The character position in the address string.
+        position: usize,
+    },
+    /// The hexadecimal address has too many digits.
+    #[fail(
+        display = "expected at most {} hexadecimal digits, found {}",
+        expected, found
+    )]
+    TooLong {
+        /// The number of ETH address hexadecimal digits.
+        expected: usize,
+        /// The number of found hexadecimal digits.
+        found: usize,
+    },
+    /// The mixed-case address, which is checksummed according to EIP-55, is too short.
+    #[fail(
+        display = "the mixed-case checksummed address must have exactly {} hexadecimal digits, found {}",
+        expected, found
+    )]
+    ChecksumLength {
+        /// The number of ETH address hexadecimal digits.
+        expected: usize,
+        /// The number of found hexadecimal digits.
+        found: usize,
+    },
+    /// The EIP-55 checksum does not match the letter case.
+    #[fail(
+        display = "EIP-55 checksum mismatch at position {}: expected `{}`, found `{}`",
+        position, expected, found
+    )]
+    ChecksumMismatch {
+        /// The mismatching character position in the address string.
+        position: usize,
+        /// The character with the expected letter case.
+        expected: char,
+        /// The found character.
+        found: char,
+    },
+    /// The decimal address is invalid.
+    #[fail(display = "number parsing: {}", _0)]
+    NumberParsing(BigIntError),
+    /// The decimal address is negative or exceeds the ETH address size.
+    #[fail(display = "address `{}` is out of range", _0)]
+    OutOfRange(BigInt),
+}
+
+impl From<BigIntError> for Error {
+    fn from(inner: BigIntError) -> Self {
+        Self::NumberParsing(inner)
+    }
+}
@@ -0,0 +1,148 @@
+//!
+//! The ETH address parsing tools.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use num::Signed;
+use tiny_keccak::Hasher;
+use tiny_keccak::Keccak;
+
+use self::error::Error;
+
+/// The number of hexadecimal digits in an ETH address.
+const HEXADECIMAL_DIGITS: usize = zinc_const::size::ETH_ADDRESS * 2;
+
+/// The Keccak-256 hash size, used to compute the EIP-55 checksum.
+const KECCAK256_HASH_SIZE: usize = 32;
+
+///
+/// The ETH address parsing function, which supports:
+///
+/// - `0x`-prefixed hexadecimal addresses
+/// - non-prefixed hexadecimal addresses of 40 digits, if there is at least one letter
+/// - EIP-55 checksummed addresses, which are verified if the letters have mixed case
+/// - decimal, binary, and octal numbers supported by the extended BigInt parser
+///
+/// Returns the address bytes in the big-endian order.
+///
+pub fn from_str(string: &str) -> Result<[u8; zinc_const::size::ETH_ADDRESS], Error> {
+    let (digits, offset) = if string.starts_with("0x") || string.starts_with("0X") {
+        (&string["0x".len()..], "0x".len())
+    } else if string.len() == HEXADECIMAL_DIGITS
+        && string
+            .chars()
+            .any(|character| character.is_ascii_alphabetic())
+    {
+        (string, 0)
+    } else {
+        return from_number(string);
+    };
+
+    if digits.is_empty() {
+        return Err(Error::Empty);
+    }
+    if let Some((position, character)) = digits
+        .chars()
+        .enumerate()
+        .find(|(_position, character)| !character.is_ascii_hexdigit())
+    {
+        return Err(Error::InvalidCharacter {
+            character,
+            position: offset + position,
+        });
+    }
+    if digits.len() > HEXADECIMAL_DIGITS {
+        return Err(Error::TooLong {
+            expected: HEXADECIMAL_DIGITS,
+            found: digits.len(),
+        });
+    }
+
+    let has_lowercase = digits
+        .chars()
+        .any(|character| character.is_ascii_lowercase());
+    let has_uppercase = digits
+        .chars()
+        .any(|character| character.is_ascii_uppercase());
+    if has_lowercase && has_uppercase {
+        if digits.len() != HEXADECIMAL_DIGITS {
+            return Err(Error::ChecksumLength {
+                expected: HEXADECIMAL_DIGITS,
+                found: digits.len(),
+            });
+        }
+        verify_checksum(digits, offset)?;
+    }
+
+    let digits = format!("{:0>width$}", digits, width = HEXADECIMAL_DIGITS);
+    let mut address = [0; zinc_const::size::ETH_ADDRESS];
+    for (index, byte) in address.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(
+            &digits[index * 2..index * 2 + 2],
+            zinc_const::base::HEXADECIMAL,
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    }
+    Ok(address)
+}
+
+///
+/// Parses the address represented as a decimal, binary, or octal number.
+///
+fn from_number(string: &str) -> Result<[u8; zinc_const::size::ETH_ADDRESS], Error> {
+    let value = crate::bigint::from_str(string)?;
+    if value.is_negative() || value.bits() > (zinc_const::size::ETH_ADDRESS * 8) as u64 {
+        return Err(Error::OutOfRange(value));
+    }
+
+    let (_sign, bytes) = value.to_bytes_be();
+    let mut address = [0; zinc_const::size::ETH_ADDRESS];
+    address[zinc_const::size::ETH_ADDRESS - bytes.len()..].copy_from_slice(bytes.as_slice());
+    Ok(address)
+}
+
+///
+/// Verifies the EIP-55 checksum of the mixed-case hexadecimal `digits`.
+///
+/// A letter must be uppercase if the corresponding nibble of the Keccak-256 hash of the
+/// lowercase address is not less than 8, and lowercase otherwise.
+///
+fn verify_checksum(digits: &str, offset: usize) -> Result<(), Error> {
+    let lowercase = digits.to_ascii_lowercase();
+
+    let mut hash = [0; KECCAK256_HASH_SIZE];
+    let mut hasher = Keccak::v256();
+    hasher.update(lowercase.as_bytes());
+    hasher.finalize(&mut hash);
+
+    for (index, (found, character)) in digits.chars().zip(lowercase.chars()).enumerate() {
+        if !character.is_ascii_alphabetic() {
+            continue;
+        }
+
+        let nibble = if index % 2 == 0 {
+            hash[index / 2] >> 4
+        } else {
+            hash[index / 2] & 0x0f
+        };
+        let expected = if nibble >= 8 {
+            character.to_ascii_uppercase()
+        } else {
+            character
+        };
+
+        if found != expected {
+            return Err(Error::ChecksumMismatch {
+                position: offset + index,
+                expected,
+                found,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//!
+//! The ETH address parsing tests.
+//!
+
+use num::BigInt;
+
+use crate::address;
+use crate::address::error::Error;
+
+const ADDRESS: [u8; zinc_const::size::ETH_ADDRESS] = [
+    0x5a, 0xae, 0xb6, 0x05, 0x3f, 0x3e, 0x94, 0xc9, 0xb9, 0xa0, 0x9f, 0x33, 0x66, 0x94, 0x35, 0xe7,
+    0xef, 0x1b, 0xea, 0xed,
+];
+
+#[test]
+fn ok_checksummed() {
+    assert_eq!(
+        address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        Ok(ADDRESS)
+    );
+    assert!(address::from_str("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359").is_ok());
+    assert!(address::from_str("0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB").is_ok());
+    assert!(address::from_str("0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb").is_ok());
+}
+
+#[test]
+fn ok_hexadecimal() {
+    assert_eq!(
+        address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        Ok(ADDRESS)
+    );
+    assert_eq!(
+        address::from_str("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"),
+        Ok(ADDRESS)
+    );
+    assert_eq!(
+        address::from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        Ok(ADDRESS)
+    );
+
+    let mut short = [0; zinc_const::size::ETH_ADDRESS];
+    short[zinc_const::size::ETH_ADDRESS - 1] = 0x2a;
+    assert_eq!(address::from_str("0x2a"), Ok(short));
+}
+
+#[test]
+fn ok_decimal() {
+    assert_eq!(
+        address::from_str("517705355260207604495801938720638392742277016301"),
+        Ok(ADDRESS)
+    );
+
+    let mut short = [0; zinc_const::size::ETH_ADDRESS];
+    short[zinc_const::size::ETH_ADDRESS - 1] = 42;
+    assert_eq!(address::from_str("42"), Ok(short));
+    assert_eq!(
+        address::from_str("0"),
+        Ok([0; zinc_const::size::ETH_ADDRESS])
+    );
+}
+
+#[test]
+fn error_checksum_mismatch() {
+    assert_eq!(
+        address::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        Err(Error::ChecksumMismatch {
+            position: 41,
+            expected: 'd',
+            found: 'D',
+        })
+    );
+}
+
+#[test]
+fn error_checksum_length() {
+    assert_eq!(
+        address::from_str("0x5aAeb6"),
+        Err(Error::ChecksumLength {
+            expected: 40,
+            found: 6,
+        })
+    );
+}
+
+#[test]
+fn error_invalid_character() {
+    assert_eq!(
+        address::from_str("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg"),
+        Err(Error::InvalidCharacter {
+            character: 'g',
+            position: 41,
+        })
+    );
+}
+
+#[test]
+fn error_too_long() {
+    assert_eq!(
+        address::from_str("0x005aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        Err(Error::TooLong {
+            expected: 40,
+            found: 42,
+        })
+    );
+}
+
+#[test]
+fn error_empty() {
+    assert_eq!(address::from_str("0x"), Err(Error::Empty));
+}
+
+#[test]
+fn error_out_of_range() {
+    let value = BigInt::from(2).pow(160);
+    assert_eq!(
+        address::from_str(value.to_string().as_str()),
+        Err(Error::OutOfRange(value))
+    );
+}
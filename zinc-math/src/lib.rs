@@ -2,11 +2,15 @@
 //! The Zinc math utilities.
 //!
 
+pub(crate) mod address;
 pub(crate) mod bigint;
 pub(crate) mod euclidean;
 pub(crate) mod inference;
 pub(crate) mod misc;
+pub(crate) mod range;
 
+pub use crate::address::error::Error as EthAddressError;
+pub use crate::address::from_str as eth_address_from_str;
 pub use crate::bigint::error::Error as BigIntError;
 pub use crate::bigint::from_str as bigint_from_str;
 pub use crate::euclidean::div_rem as euclidean_div_rem;
@@ -17,3 +21,5 @@ pub use crate::inference::r#type::Type as InferredType;
 pub use crate::inference::result::Binary as BinaryInferenceResult;
 pub use crate::misc::floor_to_power_of_two;
 pub use crate::misc::log2ceil;
+pub use crate::range::field_modulus;
+pub use crate::range::field_range;
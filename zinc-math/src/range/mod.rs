@@ -0,0 +1,31 @@
+//!
+//! The field element value range.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+/// The BN256 scalar field modulus, which all the `field` values must be less than.
+const FIELD_MODULUS: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+///
+/// The BN256 scalar field modulus.
+///
+pub fn field_modulus() -> BigInt {
+    BigInt::from_str(FIELD_MODULUS).expect(zinc_const::panic::DATA_CONVERSION)
+}
+
+///
+/// The range of the `field` type values, that is, from zero to the modulus exclusively.
+///
+pub fn field_range() -> RangeInclusive<BigInt> {
+    BigInt::zero()..=field_modulus() - BigInt::one()
+}
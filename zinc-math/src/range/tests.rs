@@ -0,0 +1,21 @@
+//!
+//! The field element value range tests.
+//!
+
+use num::BigInt;
+use num::One;
+
+use crate::range::field_modulus;
+use crate::range::field_range;
+
+#[test]
+fn ok_field() {
+    let range = field_range();
+
+    assert_eq!(range.start(), &BigInt::from(0));
+    assert_eq!(range.end() + BigInt::one(), field_modulus());
+    assert_eq!(
+        field_modulus().bits() as usize,
+        zinc_const::bitlength::FIELD
+    );
+}
@@ -2,8 +2,10 @@
 //! The Zinc source code library.
 //!
 
+pub mod library;
 pub mod source;
 
+pub use self::library::Library;
 pub use self::source::directory::Directory;
 pub use self::source::error::Error;
 pub use self::source::file::File;
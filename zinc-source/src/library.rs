@@ -0,0 +1,54 @@
+//!
+//! The Zinc library interface representation.
+//!
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::source::Source;
+
+///
+/// The Zinc library interface, which is the build output of a library project.
+///
+/// The interface is not bytecode, but the library source code checked by the compiler, which is
+/// analyzed again as an external module while compiling each dependent project.
+///
+/// The interface is stamped with the version of the compiler which has built it, since the
+/// source code accepted by one compiler version may be rejected or treated differently by another.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Library {
+    /// The library project name.
+    pub name: String,
+    /// The library project version.
+    pub version: String,
+    /// The version of the compiler which has built the library.
+    pub compiler_version: String,
+    /// The library source code.
+    pub source: Source,
+    /// The libraries the library depends on, with the dependency names as keys.
+    pub dependencies: HashMap<String, Library>,
+}
+
+impl Library {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: String,
+        compiler_version: String,
+        source: Source,
+        dependencies: HashMap<String, Library>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            compiler_version,
+            source,
+            dependencies,
+        }
+    }
+}
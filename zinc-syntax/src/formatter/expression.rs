@@ -0,0 +1,858 @@
+//!
+//! The formatter expressions, types, and patterns.
+//!
+
+use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+use zinc_lexical::Location;
+
+use crate::formatter::precedence::Precedence;
+use crate::formatter::Formatter;
+use crate::tree::expression::array::variant::Variant as ArrayExpressionVariant;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::tree::expression::r#match::Expression as MatchExpression;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::identifier::Identifier;
+use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
+use crate::tree::pattern_match::Pattern as MatchPattern;
+use crate::tree::r#type::variant::Variant as TypeVariant;
+use crate::tree::r#type::Type;
+
+use super::output::Output;
+
+///
+/// The deferred list item formatter, which is called with the item indentation level.
+///
+pub type ItemFormatter<'a> = Box<dyn Fn(&mut Formatter, usize) -> String + 'a>;
+
+///
+/// The list item source code location and formatter.
+///
+pub type Item<'a> = (Location, ItemFormatter<'a>);
+
+impl Formatter {
+    ///
+    /// Formats an expression tree, adding the parentheses required by the operator precedence
+    /// and keeping the ones written in the source code.
+    ///
+    pub fn expression(&mut self, tree: &ExpressionTree, indent: usize) -> String {
+        let operator = match tree.value.as_ref() {
+            ExpressionTreeNode::Operand(operand) => return self.operand(operand, indent),
+            ExpressionTreeNode::Operator(operator) => *operator,
+        };
+        let start = self.column;
+
+        let prefix = match operator {
+            ExpressionOperator::Not => "!",
+            ExpressionOperator::BitwiseNot => "~",
+            ExpressionOperator::Negation => "-",
+            _ => "",
+        };
+        let mut result = prefix.to_owned();
+        self.move_after(start, result.as_str());
+        if let Some(ref left) = tree.left {
+            result.push_str(self.child(left, operator, true, indent).as_str());
+        }
+
+        let (separator, suffix) = match operator {
+            ExpressionOperator::Not
+            | ExpressionOperator::BitwiseNot
+            | ExpressionOperator::Negation => ("", ""),
+            ExpressionOperator::CallIntrinsic => ("!", ""),
+            ExpressionOperator::Index => ("[", "]"),
+            ExpressionOperator::Dot => (".", ""),
+            ExpressionOperator::Call => ("", ""),
+            ExpressionOperator::Path | ExpressionOperator::Generics => ("::", ""),
+            ExpressionOperator::Structure => (" ", ""),
+            ExpressionOperator::Casting => (" as ", ""),
+
+            ExpressionOperator::Range => ("..", ""),
+            ExpressionOperator::RangeInclusive => ("..=", ""),
+
+            ExpressionOperator::Assignment => (" = ", ""),
+            ExpressionOperator::AssignmentBitwiseOr => (" |= ", ""),
+            ExpressionOperator::AssignmentBitwiseXor => (" ^= ", ""),
+            ExpressionOperator::AssignmentBitwiseAnd => (" &= ", ""),
+            ExpressionOperator::AssignmentBitwiseShiftLeft => (" <<= ", ""),
+            ExpressionOperator::AssignmentBitwiseShiftRight => (" >>= ", ""),
+            ExpressionOperator::AssignmentAddition => (" += ", ""),
+            ExpressionOperator::AssignmentSubtraction => (" -= ", ""),
+            ExpressionOperator::AssignmentMultiplication => (" *= ", ""),
+            ExpressionOperator::AssignmentDivision => (" /= ", ""),
+            ExpressionOperator::AssignmentRemainder => (" %= ", ""),
+            ExpressionOperator::Or => (" || ", ""),
+            ExpressionOperator::Xor => (" ^^ ", ""),
+            ExpressionOperator::And => (" && ", ""),
+            ExpressionOperator::Equals => (" == ", ""),
+            ExpressionOperator::NotEquals => (" != ", ""),
+            ExpressionOperator::GreaterEquals => (" >= ", ""),
+            ExpressionOperator::LesserEquals => (" <= ", ""),
+            ExpressionOperator::Greater => (" > ", ""),
+            ExpressionOperator::Lesser => (" < ", ""),
+            ExpressionOperator::BitwiseOr => (" | ", ""),
+            ExpressionOperator::BitwiseXor => (" ^ ", ""),
+            ExpressionOperator::BitwiseAnd => (" & ", ""),
+            ExpressionOperator::BitwiseShiftLeft => (" << ", ""),
+            ExpressionOperator::BitwiseShiftRight => (" >> ", ""),
+            ExpressionOperator::Addition => (" + ", ""),
+            ExpressionOperator::Subtraction => (" - ", ""),
+            ExpressionOperator::Multiplication => (" * ", ""),
+            ExpressionOperator::Division => (" / ", ""),
+            ExpressionOperator::Remainder => (" % ", ""),
+        };
+        result.push_str(separator);
+
+        if let Some(ref right) = tree.right {
+            self.move_after(start, result.as_str());
+            let right = if Precedence::from(operator) >= Precedence::Access {
+                self.expression(right, indent)
+            } else {
+                self.child(right, operator, false, indent)
+            };
+            result.push_str(right.as_str());
+        }
+        result.push_str(suffix);
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a type.
+    ///
+    pub fn r#type(&mut self, r#type: &Type, indent: usize) -> String {
+        let start = self.column;
+
+        let result = match r#type.variant {
+            TypeVariant::Unit => "()".to_owned(),
+            TypeVariant::Boolean => "bool".to_owned(),
+            TypeVariant::IntegerUnsigned { bitlength } => format!("u{}", bitlength),
+            TypeVariant::IntegerSigned { bitlength } => format!("i{}", bitlength),
+            TypeVariant::Fixed {
+                is_signed,
+                bitlength,
+                decimals,
+            } => format!(
+                "{}fixed{}x{}",
+                if is_signed { "" } else { "u" },
+                bitlength,
+                decimals
+            ),
+            TypeVariant::Field => "field".to_owned(),
+            TypeVariant::String => "str".to_owned(),
+            TypeVariant::Array {
+                ref inner,
+                ref size,
+            } => {
+                self.column += 1;
+                let mut result = format!("[{}; ", self.r#type(inner, indent));
+                self.move_after(start, result.as_str());
+                result.push_str(self.expression(size, indent).as_str());
+                result.push(']');
+                result
+            }
+            TypeVariant::Tuple { ref inners } if inners.len() == 1 => {
+                self.column += 1;
+                format!("({},)", self.r#type(&inners[0], indent))
+            }
+            TypeVariant::Tuple { ref inners } => {
+                let items = Self::type_items(inners);
+                self.list("(", items, ")", indent, false)
+            }
+            TypeVariant::NamedTuple { ref fields } => {
+                let items = Self::named_type_items(fields);
+                self.list("(", items, ")", indent, false)
+            }
+            TypeVariant::Alias {
+                ref path,
+                ref generics,
+            } => {
+                let mut result = self.expression(path, indent);
+                if let Some(generics) = generics {
+                    self.move_after(start, result.as_str());
+                    let items = Self::type_items(generics);
+                    result.push_str(self.list("<", items, ">", indent, false).as_str());
+                }
+                result
+            }
+        };
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a list of items, which is written on a single line if it fits and has been
+    /// written on a single line in the source code, or one item per line with trailing commas
+    /// otherwise.
+    ///
+    /// The list location is the location of its first item, that is, the first token after the
+    /// opening bracket.
+    ///
+    /// If `is_padded` is set, the single-line list items are separated from the brackets with
+    /// spaces.
+    ///
+    pub fn list(
+        &mut self,
+        opening: &str,
+        items: Vec<Item>,
+        closing: &str,
+        indent: usize,
+        is_padded: bool,
+    ) -> String {
+        if items.is_empty() {
+            return format!("{}{}", opening, closing);
+        }
+
+        let start = self.column;
+        let comment_index = self.comment_index;
+
+        let padding = if is_padded { " " } else { "" };
+        let mut result = format!("{}{}", opening, padding);
+        let mut is_single_line = true;
+        for (index, (location, item)) in items.iter().enumerate() {
+            if index > 0 {
+                result.push_str(", ");
+            }
+            self.move_after(start, result.as_str());
+            let text = item(self, indent);
+            is_single_line &= location.line == items[0].0.line
+                && (index == items.len() - 1 || !text.contains('\n'));
+            result.push_str(text.as_str());
+        }
+        result.push_str(padding);
+        result.push_str(closing);
+        let width = result.lines().next().map(str::len).unwrap_or_default();
+        if is_single_line
+            && !self.layout.is_multiline(items[0].0)
+            && start + width <= Self::MAX_WIDTH
+        {
+            self.column = start;
+            return result;
+        }
+        self.comment_index = comment_index;
+
+        let mut result = opening.to_owned();
+        for (_location, item) in items.iter() {
+            result.push('\n');
+            result.push_str(Self::INDENT.repeat(indent + 1).as_str());
+            self.column = Self::indentation(indent + 1);
+            result.push_str(item(self, indent + 1).as_str());
+            result.push(',');
+        }
+        result.push('\n');
+        result.push_str(Self::INDENT.repeat(indent).as_str());
+        result.push_str(closing);
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a conditional expression.
+    ///
+    /// The `if`-`else` expressions with single-expression blocks, which have been written on
+    /// a single line in the source code, are kept on a single line if it fits.
+    ///
+    pub fn conditional(&mut self, conditional: &ConditionalExpression, indent: usize) -> String {
+        if let Some(result) = self.conditional_inline(conditional, indent) {
+            return result;
+        }
+        let start = self.column;
+
+        let mut result = "if ".to_owned();
+        self.move_after(start, result.as_str());
+        result.push_str(self.expression(&conditional.condition, indent).as_str());
+        result.push(' ');
+        result.push_str(self.block(&conditional.main_block, indent).as_str());
+        if let Some(ref else_block) = conditional.else_block {
+            result.push_str(" else ");
+            self.move_after(start, result.as_str());
+            let else_block = match Self::else_if(else_block) {
+                Some(nested) => self.conditional(nested, indent),
+                None => self.block(else_block, indent),
+            };
+            result.push_str(else_block.as_str());
+        }
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a `match` expression, whose arms are always written on separate lines.
+    ///
+    pub fn r#match(&mut self, expression: &MatchExpression, indent: usize) -> String {
+        let start = self.column;
+
+        let scrutinee = &expression.scrutinee;
+        let closing = self
+            .layout
+            .closing_bracket_after(if scrutinee.end > scrutinee.location {
+                scrutinee.end
+            } else {
+                scrutinee.location
+            });
+
+        self.column += "match ".len();
+        let mut output = Output::new(
+            format!("match {} {{", self.expression(scrutinee, indent)),
+            indent + 1,
+        );
+        for (pattern, branch) in expression.branches.iter() {
+            self.item_start(&mut output, pattern.location);
+
+            self.column = Self::indentation(indent + 1);
+            let mut line = self.match_pattern(pattern, indent + 1);
+            line.push_str(" => ");
+            self.move_after(Self::indentation(indent + 1), line.as_str());
+            line.push_str(self.expression(branch, indent + 1).as_str());
+            line.push(',');
+            output.line(line);
+        }
+
+        self.column = start;
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Checks if the expression statement starting with `tree` would continue the preceding
+    /// unterminated block-like expression, e.g. as a call, index, or binary operator, so the
+    /// latter must be terminated with a semicolon.
+    ///
+    pub fn is_continuation(&self, tree: &ExpressionTree) -> bool {
+        match tree.value.as_ref() {
+            ExpressionTreeNode::Operand(ExpressionOperand::LiteralUnit(_))
+            | ExpressionTreeNode::Operand(ExpressionOperand::Tuple(_))
+            | ExpressionTreeNode::Operand(ExpressionOperand::Array(_)) => true,
+            ExpressionTreeNode::Operand(_) => false,
+            ExpressionTreeNode::Operator(ExpressionOperator::Not)
+            | ExpressionTreeNode::Operator(ExpressionOperator::Negation) => true,
+            ExpressionTreeNode::Operator(ExpressionOperator::BitwiseNot) => false,
+            ExpressionTreeNode::Operator(operator) => match tree.left {
+                Some(ref left) => {
+                    self.is_wrapped(left, *operator, true) || self.is_continuation(left)
+                }
+                None => false,
+            },
+        }
+    }
+
+    ///
+    /// Formats an operand.
+    ///
+    fn operand(&mut self, operand: &ExpressionOperand, indent: usize) -> String {
+        let start = self.column;
+
+        let result = match operand {
+            ExpressionOperand::LiteralUnit(_) => "()".to_owned(),
+            ExpressionOperand::LiteralBoolean(literal) => literal.inner.to_string(),
+            ExpressionOperand::LiteralInteger(literal) => self.integer_literal(literal),
+            ExpressionOperand::LiteralString(literal) => Self::string(literal.inner.inner.as_str()),
+            ExpressionOperand::TupleIndex(index) => Self::integer(&index.literal.inner),
+            ExpressionOperand::Identifier(identifier) => identifier.name.to_owned(),
+            ExpressionOperand::Type(r#type) => self.r#type(r#type, indent),
+            ExpressionOperand::Generics(types) => {
+                let items = Self::type_items(types);
+                self.list("<", items, ">", indent, false)
+            }
+            ExpressionOperand::Array(array) => match array.variant {
+                ArrayExpressionVariant::List { ref elements } => {
+                    let items = Self::expression_items(elements);
+                    self.list("[", items, "]", indent, false)
+                }
+                ArrayExpressionVariant::Repeated {
+                    ref expression,
+                    ref size_expression,
+                } => {
+                    self.column += 1;
+                    let mut result = format!("[{}; ", self.expression(expression, indent));
+                    self.move_after(start, result.as_str());
+                    result.push_str(self.expression(size_expression, indent).as_str());
+                    result.push(']');
+                    result
+                }
+            },
+            ExpressionOperand::Tuple(tuple) if tuple.elements.len() == 1 => {
+                self.column += 1;
+                format!("({},)", self.expression(&tuple.elements[0], indent))
+            }
+            ExpressionOperand::Tuple(tuple) => {
+                let items = Self::expression_items(&tuple.elements);
+                self.list("(", items, ")", indent, false)
+            }
+            ExpressionOperand::Structure(structure) => {
+                let mut items: Vec<Item> = structure
+                    .fields
+                    .iter()
+                    .map(|(identifier, expression)| {
+                        let is_shorthand = identifier.location == expression.location;
+                        (
+                            identifier.location,
+                            Box::new(move |formatter: &mut Self, indent| {
+                                if is_shorthand {
+                                    return identifier.name.to_owned();
+                                }
+
+                                let prefix = format!("{}: ", identifier.name);
+                                formatter.column += prefix.len();
+                                let result = format!(
+                                    "{}{}",
+                                    prefix,
+                                    formatter.expression(expression, indent)
+                                );
+                                formatter.column -= prefix.len();
+                                result
+                            }) as ItemFormatter,
+                        )
+                    })
+                    .collect();
+                if let Some(ref base) = structure.base {
+                    items.push((
+                        base.start(),
+                        Box::new(move |formatter: &mut Self, indent| {
+                            formatter.column += "..".len();
+                            let result = format!("..{}", formatter.expression(base, indent));
+                            formatter.column -= "..".len();
+                            result
+                        }),
+                    ));
+                }
+                self.list("{", items, "}", indent, true)
+            }
+            ExpressionOperand::List(list) => {
+                let items = Self::expression_items(&list.elements);
+                self.list("(", items, ")", indent, false)
+            }
+            ExpressionOperand::Block(block) => {
+                let is_single_line = self
+                    .layout
+                    .closing_bracket(block.location)
+                    .map(|closing| closing.line == block.location.line)
+                    .unwrap_or_default();
+                let inline = if is_single_line {
+                    self.block_inline(block, indent)
+                } else {
+                    None
+                };
+                match inline {
+                    Some(result) => result,
+                    None => self.block(block, indent),
+                }
+            }
+            ExpressionOperand::Conditional(conditional) => self.conditional(conditional, indent),
+            ExpressionOperand::Match(expression) => self.r#match(expression, indent),
+        };
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a `match` arm pattern.
+    ///
+    fn match_pattern(&mut self, pattern: &MatchPattern, indent: usize) -> String {
+        let start = self.column;
+
+        let result = match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(ref literal) => literal.inner.to_string(),
+            MatchPatternVariant::IntegerLiteral(ref literal) => self.integer_literal(literal),
+            MatchPatternVariant::Binding(ref identifier) => identifier.name.to_owned(),
+            MatchPatternVariant::Path(ref path) => self.expression(path, indent),
+            MatchPatternVariant::Tuple(ref elements) if elements.len() == 1 => {
+                self.column += 1;
+                format!("({},)", self.match_pattern(&elements[0], indent))
+            }
+            MatchPatternVariant::Tuple(ref elements) => {
+                let items = Self::pattern_items(elements);
+                self.list("(", items, ")", indent, false)
+            }
+            MatchPatternVariant::Structure {
+                ref path,
+                ref fields,
+                has_rest,
+            } => {
+                let mut result = self.expression(path, indent);
+                result.push(' ');
+
+                let mut items: Vec<Item> = fields
+                    .iter()
+                    .map(|(identifier, pattern)| {
+                        let is_shorthand = identifier.location == pattern.location;
+                        (
+                            identifier.location,
+                            Box::new(move |formatter: &mut Self, indent| {
+                                if is_shorthand {
+                                    return identifier.name.to_owned();
+                                }
+
+                                let prefix = format!("{}: ", identifier.name);
+                                formatter.column += prefix.len();
+                                let result = format!(
+                                    "{}{}",
+                                    prefix,
+                                    formatter.match_pattern(pattern, indent)
+                                );
+                                formatter.column -= prefix.len();
+                                result
+                            }) as ItemFormatter,
+                        )
+                    })
+                    .collect();
+                if has_rest {
+                    items.push((
+                        pattern.location,
+                        Box::new(|_formatter: &mut Self, _indent| "..".to_owned()),
+                    ));
+                }
+                self.move_after(start, result.as_str());
+                result.push_str(self.list("{", items, "}", indent, true).as_str());
+                result
+            }
+            MatchPatternVariant::Payload {
+                ref path,
+                ref elements,
+            } => {
+                let mut result = self.expression(path, indent);
+                self.move_after(start, result.as_str());
+                let items = Self::pattern_items(elements);
+                result.push_str(self.list("(", items, ")", indent, false).as_str());
+                result
+            }
+            MatchPatternVariant::Wildcard => "_".to_owned(),
+        };
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a conditional expression on a single line, e.g. `if c { a } else { b }`.
+    ///
+    /// Returns `None` if the conditional has no `else` block, is an `else if` chain, has
+    /// statements or comments in its blocks, is too wide, or has been written on multiple lines
+    /// in the source code.
+    ///
+    fn conditional_inline(
+        &mut self,
+        conditional: &ConditionalExpression,
+        indent: usize,
+    ) -> Option<String> {
+        let else_block = conditional.else_block.as_ref()?;
+        if Self::else_if(else_block).is_some() {
+            return None;
+        }
+        if self.layout.closing_bracket(else_block.location)?.line != conditional.location.line {
+            return None;
+        }
+
+        let start = self.column;
+        let comment_index = self.comment_index;
+
+        let mut result = "if ".to_owned();
+        self.move_after(start, result.as_str());
+        result.push_str(self.expression(&conditional.condition, indent).as_str());
+        result.push(' ');
+        self.move_after(start, result.as_str());
+        if let Some(main_block) = self.block_inline(&conditional.main_block, indent) {
+            result.push_str(main_block.as_str());
+            result.push_str(" else ");
+            self.move_after(start, result.as_str());
+            if let Some(else_block) = self.block_inline(else_block, indent) {
+                result.push_str(else_block.as_str());
+                if !result.contains('\n') && start + result.len() <= Self::MAX_WIDTH {
+                    self.column = start;
+                    return Some(result);
+                }
+            }
+        }
+
+        self.column = start;
+        self.comment_index = comment_index;
+        None
+    }
+
+    ///
+    /// Formats a block consisting of a single expression and no comments on a single line.
+    ///
+    /// Returns `None` if the block cannot be written on a single line or does not fit.
+    ///
+    fn block_inline(&mut self, block: &BlockExpression, indent: usize) -> Option<String> {
+        if !block.statements.is_empty() {
+            return None;
+        }
+        let expression = block.expression.as_ref()?;
+
+        if let Some(closing) = self.layout.closing_bracket(block.location) {
+            if let Some(comment) = self.layout.comments.get(self.comment_index) {
+                if comment.location < closing {
+                    return None;
+                }
+            }
+        }
+
+        let start = self.column;
+        self.column += "{ ".len();
+        let result = format!("{{ {} }}", self.expression(expression, indent));
+        self.column = start;
+
+        if result.contains('\n') || start + result.len() > Self::MAX_WIDTH {
+            return None;
+        }
+        Some(result)
+    }
+
+    ///
+    /// Returns the nested conditional if the `else` block is an `else if` one.
+    ///
+    /// The parser represents `else if` as an `else` block consisting of the nested conditional
+    /// and starting at its `if` keyword.
+    ///
+    fn else_if(block: &BlockExpression) -> Option<&ConditionalExpression> {
+        if !block.statements.is_empty() {
+            return None;
+        }
+        let expression = block.expression.as_ref()?;
+
+        match expression.value.as_ref() {
+            ExpressionTreeNode::Operand(ExpressionOperand::Conditional(conditional))
+                if expression.location == block.location =>
+            {
+                Some(conditional)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Formats an operand of the `parent` operator, wrapping it into parentheses if required
+    /// or written in the source code.
+    ///
+    fn child(
+        &mut self,
+        tree: &ExpressionTree,
+        parent: ExpressionOperator,
+        is_left: bool,
+        indent: usize,
+    ) -> String {
+        if !self.is_wrapped(tree, parent, is_left) {
+            return self.expression(tree, indent);
+        }
+
+        self.column += 1;
+        let result = format!("({})", self.expression(tree, indent));
+        self.column -= 1;
+        result
+    }
+
+    ///
+    /// Checks if the `tree` operand of the `parent` operator is written in parentheses.
+    ///
+    fn is_wrapped(&self, tree: &ExpressionTree, parent: ExpressionOperator, is_left: bool) -> bool {
+        self.layout.is_parenthesized(tree) || self.needs_parentheses(tree, parent, is_left)
+    }
+
+    ///
+    /// Checks if the `tree` operand of the `parent` operator must be wrapped into parentheses
+    /// to be parsed back into the same tree.
+    ///
+    fn needs_parentheses(
+        &self,
+        tree: &ExpressionTree,
+        parent: ExpressionOperator,
+        is_left: bool,
+    ) -> bool {
+        match parent {
+            ExpressionOperator::Lesser if is_left && self.ends_with_alias_cast(tree) => {
+                return true;
+            }
+            ExpressionOperator::Dot if is_left && Self::ends_with_tuple_index(tree) => {
+                return true;
+            }
+            _ => {}
+        }
+
+        let precedence = Precedence::of(tree);
+        let parent = Precedence::from(parent);
+        if parent.is_non_associative() || !is_left {
+            precedence <= parent
+        } else {
+            precedence < parent
+        }
+    }
+
+    ///
+    /// Checks if the formatted expression ends with a cast to a type alias, e.g. `value as T`,
+    /// which cannot be followed by `<`, as it would be parsed as the type generics.
+    ///
+    fn ends_with_alias_cast(&self, tree: &ExpressionTree) -> bool {
+        let operator = match tree.value.as_ref() {
+            ExpressionTreeNode::Operator(operator) => *operator,
+            ExpressionTreeNode::Operand(_) => return false,
+        };
+
+        match (operator, tree.left.as_ref(), tree.right.as_ref()) {
+            (ExpressionOperator::Casting, _, Some(right)) => matches!(
+                right.value.as_ref(),
+                ExpressionTreeNode::Operand(ExpressionOperand::Type(Type {
+                    variant: TypeVariant::Alias { .. },
+                    ..
+                }))
+            ),
+            (ExpressionOperator::CallIntrinsic, _, None) => false,
+            (operator, Some(left), None) => {
+                !self.is_wrapped(left, operator, true) && self.ends_with_alias_cast(left)
+            }
+            (operator, _, Some(right)) => {
+                !self.is_wrapped(right, operator, false) && self.ends_with_alias_cast(right)
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the expression is a tuple field access, e.g. `value.0`, which cannot be
+    /// followed by `.`, as the indexes would be parsed as a decimal literal.
+    ///
+    fn ends_with_tuple_index(tree: &ExpressionTree) -> bool {
+        match (tree.value.as_ref(), tree.right.as_ref()) {
+            (ExpressionTreeNode::Operator(ExpressionOperator::Dot), Some(right)) => matches!(
+                right.value.as_ref(),
+                ExpressionTreeNode::Operand(ExpressionOperand::TupleIndex(_))
+            ),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Formats an integer literal as it has been written in the source code, e.g. with the `_`
+    /// separators, falling back to the lexical literal value.
+    ///
+    pub fn integer_literal(&self, literal: &IntegerLiteral) -> String {
+        match self.layout.integer(literal.location) {
+            Some(integer) => integer.to_owned(),
+            None => Self::integer(&literal.inner),
+        }
+    }
+
+    ///
+    /// Formats an integer literal.
+    ///
+    pub fn integer(literal: &LexicalIntegerLiteral) -> String {
+        match literal {
+            LexicalIntegerLiteral::Binary { inner } => format!("0b{}", inner),
+            LexicalIntegerLiteral::Octal { inner } => format!("0o{}", inner),
+            LexicalIntegerLiteral::Hexadecimal { inner } => format!("0x{}", inner),
+            LexicalIntegerLiteral::Decimal {
+                integer,
+                fractional,
+                exponent,
+            } => {
+                let mut result = integer.to_owned();
+                if let Some(fractional) = fractional {
+                    result.push('.');
+                    result.push_str(fractional.as_str());
+                }
+                if let Some(exponent) = exponent {
+                    result.push('E');
+                    result.push_str(exponent.as_str());
+                }
+                result
+            }
+        }
+    }
+
+    ///
+    /// Formats a string literal, escaping the quotes and backslashes.
+    ///
+    pub fn string(inner: &str) -> String {
+        let mut result = String::with_capacity(inner.len() + 2);
+        result.push('"');
+        for character in inner.chars() {
+            if character == '"' || character == '\\' {
+                result.push('\\');
+            }
+            result.push(character);
+        }
+        result.push('"');
+        result
+    }
+
+    ///
+    /// Creates the list items from the expressions.
+    ///
+    fn expression_items(elements: &[ExpressionTree]) -> Vec<Item<'_>> {
+        elements
+            .iter()
+            .map(|element| {
+                (
+                    element.start(),
+                    Box::new(move |formatter: &mut Self, indent| {
+                        formatter.expression(element, indent)
+                    }) as ItemFormatter,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Creates the list items from the types.
+    ///
+    fn type_items(types: &[Type]) -> Vec<Item<'_>> {
+        types
+            .iter()
+            .map(|r#type| {
+                (
+                    r#type.location,
+                    Box::new(move |formatter: &mut Self, indent| formatter.r#type(r#type, indent))
+                        as ItemFormatter,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Creates the list items from the named tuple elements.
+    ///
+    fn named_type_items(fields: &[(Identifier, Type)]) -> Vec<Item<'_>> {
+        fields
+            .iter()
+            .map(|(identifier, r#type)| {
+                (
+                    identifier.location,
+                    Box::new(move |formatter: &mut Self, indent| {
+                        let start = formatter.column;
+
+                        let mut result = format!("{}: ", identifier.name);
+                        formatter.move_after(start, result.as_str());
+                        result.push_str(formatter.r#type(r#type, indent).as_str());
+
+                        formatter.column = start;
+                        result
+                    }) as ItemFormatter,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Creates the list items from the `match` patterns.
+    ///
+    fn pattern_items(patterns: &[MatchPattern]) -> Vec<Item<'_>> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                (
+                    pattern.location,
+                    Box::new(move |formatter: &mut Self, indent| {
+                        formatter.match_pattern(pattern, indent)
+                    }) as ItemFormatter,
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,227 @@
+//!
+//! The formatter source code layout.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use zinc_lexical::Comment as LexicalComment;
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Literal;
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_lexical::TokenStream;
+
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The source code comment with its position relative to the surrounding tokens.
+///
+#[derive(Debug, Clone)]
+pub struct Comment {
+    /// The location of the comment start.
+    pub location: Location,
+    /// The lexical comment.
+    pub inner: LexicalComment,
+    /// If the comment follows another token on the same line.
+    pub is_trailing: bool,
+}
+
+impl Comment {
+    ///
+    /// If the comment is a line one, that is, nothing can follow it on the same line.
+    ///
+    pub fn is_line(&self) -> bool {
+        matches!(self.inner, LexicalComment::Line { .. })
+    }
+
+    ///
+    /// Returns the comment source code representation.
+    ///
+    pub fn to_source(&self) -> String {
+        match self.inner {
+            LexicalComment::Line { ref inner } => format!("//{}", inner.trim_end()),
+            LexicalComment::Block { ref inner } => format!("/*{}*/", inner),
+        }
+    }
+}
+
+///
+/// The source code properties, which are not represented in the syntax tree, but must be
+/// preserved by the formatter.
+///
+#[derive(Debug, Default)]
+pub struct Layout {
+    /// The comments in the order of appearance.
+    pub comments: Vec<Comment>,
+    /// The numbers of the lines consisting of whitespaces only.
+    blank_lines: HashSet<usize>,
+    /// The closing curly bracket locations, where the keys are the opening ones.
+    closing_brackets: BTreeMap<(usize, usize), Location>,
+    /// The closing parenthesis end locations, where the keys are the first tokens inside.
+    parentheses: HashMap<(usize, usize), Location>,
+    /// The first tokens inside the brackets, which are closed on another line.
+    multiline_brackets: HashSet<(usize, usize)>,
+    /// The integer literals as written in the source code, e.g. with the `_` separators.
+    integers: HashMap<(usize, usize), String>,
+    /// The locations of the tokens following the `pub` keyword.
+    public_items: HashSet<(usize, usize)>,
+}
+
+impl Layout {
+    ///
+    /// Scans the `input` source code with the comments preserved.
+    ///
+    pub fn new(input: &str, file: usize) -> Result<Self, zinc_lexical::Error> {
+        let mut layout = Self {
+            blank_lines: input
+                .lines()
+                .enumerate()
+                .filter(|(_index, line)| line.trim().is_empty())
+                .map(|(index, _line)| index + 1)
+                .collect(),
+            ..Self::default()
+        };
+
+        let mut stream = TokenStream::new(input, file).with_comments();
+        let mut previous_line = None;
+        let mut is_public = false;
+        let mut opening_brackets = Vec::new();
+        let mut inner_brackets: Vec<(Location, Option<(usize, usize)>)> = Vec::new();
+        loop {
+            let token = stream.next()?;
+            let key = (token.location.line, token.location.column);
+
+            match token.lexeme {
+                Lexeme::Eof => break,
+                Lexeme::Comment(inner) => {
+                    layout.comments.push(Comment {
+                        location: token.location,
+                        inner,
+                        is_trailing: previous_line == Some(token.location.line),
+                    });
+                    previous_line = Some(token.location.line);
+                    continue;
+                }
+                Lexeme::Symbol(Symbol::BracketCurlyLeft) => opening_brackets.push(key),
+                Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                    if let Some(opening) = opening_brackets.pop() {
+                        layout.closing_brackets.insert(opening, token.location);
+                    }
+                }
+                _ => {}
+            }
+
+            match token.lexeme {
+                Lexeme::Symbol(Symbol::ParenthesisRight)
+                | Lexeme::Symbol(Symbol::BracketSquareRight)
+                | Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                    if let Some((opening, Some(first))) = inner_brackets.pop() {
+                        if opening.line != token.location.line {
+                            layout.multiline_brackets.insert(first);
+                        }
+                        if let Lexeme::Symbol(Symbol::ParenthesisRight) = token.lexeme {
+                            layout.parentheses.insert(first, stream.end_before(None));
+                        }
+                    }
+                }
+                ref lexeme => {
+                    if let Some((_opening, first @ None)) = inner_brackets.last_mut() {
+                        *first = Some(key);
+                    }
+                    match lexeme {
+                        Lexeme::Symbol(Symbol::ParenthesisLeft)
+                        | Lexeme::Symbol(Symbol::BracketSquareLeft)
+                        | Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
+                            inner_brackets.push((token.location, None))
+                        }
+                        Lexeme::Literal(Literal::Integer(_)) => {
+                            let end = stream.end_before(None);
+                            if let Some(line) = input.lines().nth(token.location.line - 1) {
+                                let integer: String = line
+                                    .chars()
+                                    .skip(token.location.column - 1)
+                                    .take(end.column - token.location.column)
+                                    .collect();
+                                layout.integers.insert(key, integer);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if is_public {
+                layout.public_items.insert(key);
+            }
+            is_public = matches!(token.lexeme, Lexeme::Keyword(Keyword::Pub));
+            previous_line = Some(token.location.line);
+        }
+
+        Ok(layout)
+    }
+
+    ///
+    /// Checks if the line preceding the `location` one is blank.
+    ///
+    pub fn is_blank_before(&self, location: Location) -> bool {
+        location.line > 1 && self.blank_lines.contains(&(location.line - 1))
+    }
+
+    ///
+    /// Returns the location of the curly bracket closing the one at `opening`.
+    ///
+    pub fn closing_bracket(&self, opening: Location) -> Option<Location> {
+        self.closing_brackets
+            .get(&(opening.line, opening.column))
+            .copied()
+    }
+
+    ///
+    /// Returns the location of the curly bracket closing the first one opened at or after
+    /// `location`.
+    ///
+    pub fn closing_bracket_after(&self, location: Location) -> Option<Location> {
+        self.closing_brackets
+            .range((location.line, location.column)..)
+            .next()
+            .map(|(_opening, closing)| *closing)
+    }
+
+    ///
+    /// Checks if the expression `tree` has been wrapped into parentheses in the source code.
+    ///
+    pub fn is_parenthesized(&self, tree: &ExpressionTree) -> bool {
+        let start = tree.start();
+        self.parentheses.get(&(start.line, start.column)) == Some(&tree.end)
+    }
+
+    ///
+    /// Checks if the list starting at `location` has been closed on another line than opened.
+    ///
+    pub fn is_multiline(&self, location: Location) -> bool {
+        self.multiline_brackets
+            .contains(&(location.line, location.column))
+    }
+
+    ///
+    /// Returns the integer literal at `location` as written in the source code.
+    ///
+    pub fn integer(&self, location: Location) -> Option<&str> {
+        self.integers
+            .get(&(location.line, location.column))
+            .map(String::as_str)
+    }
+
+    ///
+    /// Checks if the token at `location` is preceded by the `pub` keyword.
+    ///
+    /// Is used for the items whose syntax tree does not store the visibility.
+    ///
+    pub fn is_public(&self, location: Location) -> bool {
+        self.public_items
+            .contains(&(location.line, location.column))
+    }
+}
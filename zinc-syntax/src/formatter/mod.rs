@@ -0,0 +1,698 @@
+//!
+//! The Zinc source code formatter.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod expression;
+pub mod layout;
+pub mod output;
+pub mod precedence;
+
+use zinc_lexical::Location;
+
+use crate::error::ParsingError;
+use crate::parser::Parser;
+use crate::tree::attribute::Attribute;
+use crate::tree::binding::Binding;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
+use crate::tree::statement::contract::Statement as ContractStatement;
+use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
+use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
+use crate::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::tree::statement::r#const::Statement as ConstStatement;
+use crate::tree::statement::r#enum::Statement as EnumStatement;
+use crate::tree::statement::r#fn::Statement as FnStatement;
+use crate::tree::statement::r#for::Statement as ForStatement;
+use crate::tree::statement::r#impl::Statement as ImplStatement;
+use crate::tree::statement::r#let::Statement as LetStatement;
+use crate::tree::statement::r#struct::Statement as StructStatement;
+use crate::tree::statement::r#use::Statement as UseStatement;
+use crate::tree::variant::payload::Payload as VariantPayload;
+
+use self::expression::ItemFormatter;
+use self::layout::Layout;
+use self::output::Output;
+
+///
+/// The Zinc source code formatter.
+///
+/// Parses the source code and prints the syntax tree back with the deterministic layout rules.
+/// The comments, blank lines, parentheses, and the module item visibility, which are not
+/// represented in the syntax tree, are restored from the source code token stream.
+///
+#[derive(Debug)]
+pub struct Formatter {
+    /// The source code properties missing from the syntax tree.
+    layout: Layout,
+    /// The index of the first comment, which has not been written yet.
+    comment_index: usize,
+    /// The column where the currently formatted text starts, which is used to check the width.
+    column: usize,
+}
+
+impl Formatter {
+    /// The indentation of a single nesting level.
+    pub const INDENT: &'static str = "    ";
+
+    /// The maximal line width, after which lists are broken into multiple lines.
+    pub const MAX_WIDTH: usize = 100;
+
+    ///
+    /// Formats the `input` source code of a module.
+    ///
+    /// Returns an error if the source code cannot be parsed.
+    ///
+    pub fn format(input: &str, file: usize) -> Result<String, ParsingError> {
+        let module = Parser::default().parse(input, file)?;
+
+        let mut formatter = Self {
+            layout: Layout::new(input, file)?,
+            comment_index: 0,
+            column: 0,
+        };
+
+        let mut output = Output::new(String::new(), 0);
+        for statement in module.statements.iter() {
+            formatter.item_start(&mut output, Self::module_statement_start(statement));
+            let line = formatter.module_statement(statement);
+            output.line(line);
+        }
+        formatter.comments(&mut output, None);
+
+        Ok(output.finish("", 0))
+    }
+
+    ///
+    /// Formats a module-level statement.
+    ///
+    fn module_statement(&mut self, statement: &ModuleLocalStatement) -> String {
+        self.column = 0;
+
+        match statement {
+            ModuleLocalStatement::Const(inner) => self.const_statement(inner, 0),
+            ModuleLocalStatement::Type(inner) => {
+                let result = format!(
+                    "{}type {} = ",
+                    self.visibility(false, inner.location),
+                    inner.identifier.name
+                );
+                self.move_after(0, result.as_str());
+                let result = format!("{}{};", result, self.r#type(&inner.r#type, 0));
+                self.column = 0;
+                result
+            }
+            ModuleLocalStatement::Struct(inner) => self.struct_statement(inner, 0),
+            ModuleLocalStatement::Enum(inner) => self.enum_statement(inner, 0),
+            ModuleLocalStatement::Fn(inner) => self.fn_statement(inner, 0),
+            ModuleLocalStatement::Mod(inner) => format!(
+                "{}mod {};",
+                self.visibility(false, inner.location),
+                inner.identifier.name
+            ),
+            ModuleLocalStatement::Use(inner) => self.use_statement(inner),
+            ModuleLocalStatement::Impl(inner) => self.impl_statement(inner, 0),
+            ModuleLocalStatement::Contract(inner) => self.contract_statement(inner, 0),
+            ModuleLocalStatement::Empty(_location) => ";".to_owned(),
+        }
+    }
+
+    ///
+    /// Formats a constant statement.
+    ///
+    fn const_statement(&mut self, statement: &ConstStatement, indent: usize) -> String {
+        let start = self.column;
+
+        let mut result = format!(
+            "{}const {}: ",
+            self.visibility(statement.is_public, statement.location),
+            statement.identifier.name,
+        );
+        self.move_after(start, result.as_str());
+        result.push_str(self.r#type(&statement.r#type, indent).as_str());
+        result.push_str(" = ");
+        self.move_after(start, result.as_str());
+        result.push_str(self.expression(&statement.expression, indent).as_str());
+        result.push(';');
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a `let` statement.
+    ///
+    fn let_statement(&mut self, statement: &LetStatement, indent: usize) -> String {
+        let start = self.column;
+
+        let mut result = "let ".to_owned();
+        self.move_after(start, result.as_str());
+        result.push_str(self.binding(&statement.binding, indent).as_str());
+        result.push_str(" = ");
+        self.move_after(start, result.as_str());
+        result.push_str(self.expression(&statement.expression, indent).as_str());
+        result.push(';');
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a structure statement.
+    ///
+    fn struct_statement(&mut self, statement: &StructStatement, indent: usize) -> String {
+        if statement.is_newtype {
+            let types: Vec<String> = statement
+                .fields
+                .iter()
+                .map(|field| self.r#type(&field.r#type, indent))
+                .collect();
+
+            return format!(
+                "{}struct {}({});",
+                self.visibility(false, statement.location),
+                statement.identifier.name,
+                types.join(", ")
+            );
+        }
+
+        let closing = self
+            .layout
+            .closing_bracket_after(statement.identifier.location);
+
+        let mut output = Output::new(
+            format!(
+                "{}struct {} {{",
+                self.visibility(false, statement.location),
+                statement.identifier.name
+            ),
+            indent + 1,
+        );
+        for field in statement.fields.iter() {
+            self.item_start(&mut output, field.location);
+
+            let mut line = format!("{}: ", field.identifier.name);
+            self.move_after(Self::indentation(indent + 1), line.as_str());
+            line.push_str(self.r#type(&field.r#type, indent + 1).as_str());
+            line.push(',');
+            output.line(line);
+        }
+
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Formats an enumeration statement.
+    ///
+    fn enum_statement(&mut self, statement: &EnumStatement, indent: usize) -> String {
+        let closing = self
+            .layout
+            .closing_bracket_after(statement.identifier.location);
+
+        let mut output = Output::new(
+            format!(
+                "{}enum {} {{",
+                self.visibility(false, statement.location),
+                statement.identifier.name
+            ),
+            indent + 1,
+        );
+        for variant in statement.variants.iter() {
+            self.item_start(&mut output, variant.location);
+
+            let mut line = variant.identifier.name.to_owned();
+            if let Some(ref literal) = variant.literal {
+                line.push_str(format!(" = {}", self.integer_literal(literal)).as_str());
+            }
+            match variant.payload {
+                Some(VariantPayload::Tuple(ref types)) => {
+                    let types: Vec<String> = types
+                        .iter()
+                        .map(|r#type| self.r#type(r#type, indent + 1))
+                        .collect();
+                    line.push_str(format!("({})", types.join(", ")).as_str());
+                }
+                Some(VariantPayload::Structure(ref fields)) => {
+                    let fields: Vec<String> = fields
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{}: {}",
+                                field.identifier.name,
+                                self.r#type(&field.r#type, indent + 1)
+                            )
+                        })
+                        .collect();
+                    line.push_str(format!(" {{ {} }}", fields.join(", ")).as_str());
+                }
+                None => {}
+            }
+            line.push(',');
+            output.line(line);
+        }
+
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Formats a function statement with its attributes.
+    ///
+    fn fn_statement(&mut self, statement: &FnStatement, indent: usize) -> String {
+        let start = Self::indentation(indent);
+
+        let mut result = String::new();
+        for attribute in statement.attributes.iter() {
+            result.push_str(Self::attribute(attribute).as_str());
+            result.push('\n');
+            result.push_str(Self::INDENT.repeat(indent).as_str());
+        }
+
+        if statement.is_public {
+            result.push_str("pub ");
+        }
+        if statement.is_constant {
+            result.push_str("const ");
+        }
+        result.push_str("fn ");
+        result.push_str(statement.identifier.name.as_str());
+        if !statement.generics.is_empty() {
+            let generics: Vec<&str> = statement
+                .generics
+                .iter()
+                .map(|identifier| identifier.name.as_str())
+                .collect();
+            result.push_str(format!("<{}>", generics.join(", ")).as_str());
+        }
+
+        let bindings = statement
+            .argument_bindings
+            .iter()
+            .map(|binding| {
+                (
+                    binding.location,
+                    Box::new(move |formatter: &mut Self, indent| formatter.binding(binding, indent))
+                        as ItemFormatter,
+                )
+            })
+            .collect();
+        self.move_after(start, result.as_str());
+        result.push_str(self.list("(", bindings, ")", indent, false).as_str());
+
+        if let Some(ref r#type) = statement.return_type {
+            result.push_str(" -> ");
+            self.move_after(start, result.as_str());
+            result.push_str(self.r#type(r#type, indent).as_str());
+        }
+
+        result.push(' ');
+        result.push_str(self.block(&statement.body, indent).as_str());
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats an import statement.
+    ///
+    fn use_statement(&mut self, statement: &UseStatement) -> String {
+        let mut result = format!("{}use ", self.visibility(false, statement.location));
+        self.move_after(0, result.as_str());
+        result.push_str(self.expression(&statement.path, 0).as_str());
+        if statement.is_glob {
+            result.push_str("::*");
+        }
+        if let Some(ref alias) = statement.alias_identifier {
+            result.push_str(" as ");
+            result.push_str(alias.name.as_str());
+        }
+        result.push(';');
+
+        self.column = 0;
+        result
+    }
+
+    ///
+    /// Formats an implementation statement.
+    ///
+    fn impl_statement(&mut self, statement: &ImplStatement, indent: usize) -> String {
+        let closing = self
+            .layout
+            .closing_bracket_after(statement.identifier.location);
+
+        let mut output = Output::new(format!("impl {} {{", statement.identifier.name), indent + 1);
+        for statement in statement.statements.iter() {
+            let start = match statement {
+                ImplementationLocalStatement::Const(inner) => inner.location,
+                ImplementationLocalStatement::Fn(inner) => Self::fn_start(inner),
+                ImplementationLocalStatement::Empty(location) => *location,
+            };
+            self.item_start(&mut output, start);
+
+            self.column = Self::indentation(indent + 1);
+            let line = match statement {
+                ImplementationLocalStatement::Const(inner) => {
+                    self.const_statement(inner, indent + 1)
+                }
+                ImplementationLocalStatement::Fn(inner) => self.fn_statement(inner, indent + 1),
+                ImplementationLocalStatement::Empty(_location) => ";".to_owned(),
+            };
+            output.line(line);
+        }
+
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Formats a contract statement.
+    ///
+    fn contract_statement(&mut self, statement: &ContractStatement, indent: usize) -> String {
+        let closing = self
+            .layout
+            .closing_bracket_after(statement.identifier.location);
+
+        let mut output = Output::new(
+            format!(
+                "{}contract {} {{",
+                self.visibility(false, statement.location),
+                statement.identifier.name
+            ),
+            indent + 1,
+        );
+        for statement in statement.statements.iter() {
+            let start = match statement {
+                ContractLocalStatement::Field(inner) => inner.location,
+                ContractLocalStatement::Const(inner) => inner.location,
+                ContractLocalStatement::Fn(inner) => Self::fn_start(inner),
+                ContractLocalStatement::Empty(location) => *location,
+            };
+            self.item_start(&mut output, start);
+
+            self.column = Self::indentation(indent + 1);
+            let line = match statement {
+                ContractLocalStatement::Field(inner) => {
+                    let mut line = format!(
+                        "{}{}: ",
+                        if inner.is_public { "pub " } else { "" },
+                        inner.identifier.name,
+                    );
+                    self.move_after(Self::indentation(indent + 1), line.as_str());
+                    line.push_str(self.r#type(&inner.r#type, indent + 1).as_str());
+                    if let Some(ref default) = inner.default {
+                        line.push_str(" = ");
+                        self.move_after(Self::indentation(indent + 1), line.as_str());
+                        line.push_str(self.expression(default, indent + 1).as_str());
+                    }
+                    line.push(';');
+                    line
+                }
+                ContractLocalStatement::Const(inner) => self.const_statement(inner, indent + 1),
+                ContractLocalStatement::Fn(inner) => self.fn_statement(inner, indent + 1),
+                ContractLocalStatement::Empty(_location) => ";".to_owned(),
+            };
+            output.line(line);
+        }
+
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Formats a block expression on multiple lines, unless it is empty.
+    ///
+    pub fn block(&mut self, block: &BlockExpression, indent: usize) -> String {
+        let column = self.column;
+        let closing = self.layout.closing_bracket(block.location);
+
+        let mut output = Output::new("{".to_owned(), indent + 1);
+        for (index, statement) in block.statements.iter().enumerate() {
+            self.item_start(&mut output, Self::fn_local_statement_start(statement));
+
+            self.column = Self::indentation(indent + 1);
+            let line = match statement {
+                FunctionLocalStatement::Let(inner) => self.let_statement(inner, indent + 1),
+                FunctionLocalStatement::Const(inner) => self.const_statement(inner, indent + 1),
+                FunctionLocalStatement::For(inner) => self.for_statement(inner, indent + 1),
+                FunctionLocalStatement::Empty(_location) => ";".to_owned(),
+                FunctionLocalStatement::Expression(inner) => {
+                    let mut line = self.expression(inner, indent + 1);
+                    let is_terminated = !inner.can_be_unterminated()
+                        || match block.statements.get(index + 1) {
+                            Some(FunctionLocalStatement::Empty(_)) => true,
+                            Some(FunctionLocalStatement::Expression(next)) => {
+                                self.is_continuation(next)
+                            }
+                            Some(_) => false,
+                            None => block.expression.is_none(),
+                        };
+                    if is_terminated {
+                        line.push(';');
+                    }
+                    line
+                }
+            };
+            output.line(line);
+        }
+        if let Some(ref expression) = block.expression {
+            self.item_start(&mut output, expression.start());
+
+            self.column = Self::indentation(indent + 1);
+            let line = self.expression(expression, indent + 1);
+            output.line(line);
+        }
+
+        self.column = column;
+        self.close(output, closing, indent)
+    }
+
+    ///
+    /// Formats a `for` loop statement.
+    ///
+    fn for_statement(&mut self, statement: &ForStatement, indent: usize) -> String {
+        let start = self.column;
+
+        let mut result = format!("for {} in ", statement.index_identifier.name);
+        self.move_after(start, result.as_str());
+        result.push_str(
+            self.expression(&statement.bounds_expression, indent)
+                .as_str(),
+        );
+        if let Some(ref condition) = statement.while_condition {
+            result.push_str(" while ");
+            self.move_after(start, result.as_str());
+            result.push_str(self.expression(condition, indent).as_str());
+        }
+        result.push(' ');
+        result.push_str(self.block(&statement.block, indent).as_str());
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a binding, e.g. `mut value: u8`.
+    ///
+    pub fn binding(&mut self, binding: &Binding, indent: usize) -> String {
+        let start = self.column;
+
+        let mut result = self.binding_pattern(&binding.pattern, indent);
+        if let Some(ref r#type) = binding.r#type {
+            result.push_str(": ");
+            self.move_after(start, result.as_str());
+            result.push_str(self.r#type(r#type, indent).as_str());
+        }
+
+        self.column = start;
+        result
+    }
+
+    ///
+    /// Formats a binding pattern.
+    ///
+    fn binding_pattern(&mut self, pattern: &BindingPattern, indent: usize) -> String {
+        match pattern.variant {
+            BindingPatternVariant::Binding {
+                ref identifier,
+                is_mutable,
+            } => {
+                if is_mutable {
+                    format!("mut {}", identifier.name)
+                } else {
+                    identifier.name.to_owned()
+                }
+            }
+            BindingPatternVariant::BindingList { ref bindings } if bindings.len() == 1 => {
+                self.column += 1;
+                let result = format!("({},)", self.binding_pattern(&bindings[0], indent));
+                self.column -= 1;
+                result
+            }
+            BindingPatternVariant::BindingList { ref bindings } => {
+                let items = bindings
+                    .iter()
+                    .map(|binding| {
+                        (
+                            binding.location,
+                            Box::new(move |formatter: &mut Self, indent| {
+                                formatter.binding_pattern(binding, indent)
+                            }) as ItemFormatter,
+                        )
+                    })
+                    .collect();
+                self.list("(", items, ")", indent, false)
+            }
+            BindingPatternVariant::Wildcard => "_".to_owned(),
+        }
+    }
+
+    ///
+    /// Formats an attribute, e.g. `#[test]`.
+    ///
+    fn attribute(attribute: &Attribute) -> String {
+        let mut result = if attribute.is_inner {
+            "#![".to_owned()
+        } else {
+            "#[".to_owned()
+        };
+        result.push_str(attribute.identifier.name.as_str());
+        if !attribute.arguments.is_empty() {
+            let arguments: Vec<String> = attribute
+                .arguments
+                .iter()
+                .map(|argument| match argument.value {
+                    Some(ref value) => format!(
+                        "{} = {}",
+                        argument.identifier.name,
+                        Self::string(value.inner.inner.as_str())
+                    ),
+                    None => argument.identifier.name.to_owned(),
+                })
+                .collect();
+            result.push_str(format!("({})", arguments.join(", ")).as_str());
+        }
+        result.push(']');
+        result
+    }
+
+    ///
+    /// Returns the `pub ` prefix if the item is public.
+    ///
+    /// The module-level items do not store their visibility in the syntax tree, so it is also
+    /// checked in the source code layout.
+    ///
+    fn visibility(&self, is_public: bool, location: Location) -> &'static str {
+        if is_public || self.layout.is_public(location) {
+            "pub "
+        } else {
+            ""
+        }
+    }
+
+    ///
+    /// Writes the comments preceding the item at `location` and the blank line separating it
+    /// from the previous one.
+    ///
+    fn item_start(&mut self, output: &mut Output, location: Location) {
+        self.comments(output, Some(location));
+        if output.start_item(location.line) && self.layout.is_blank_before(location) {
+            output.blank_line();
+        }
+    }
+
+    ///
+    /// Writes the remaining comments of the list closed by the bracket at `closing`, and
+    /// returns the list text.
+    ///
+    fn close(&mut self, mut output: Output, closing: Option<Location>, indent: usize) -> String {
+        if let Some(closing) = closing {
+            self.comments(&mut output, Some(closing));
+        }
+        output.finish("}", indent)
+    }
+
+    ///
+    /// Writes the comments located before `location`, or all the remaining ones if it is `None`.
+    ///
+    /// The trailing comments are appended to the last written line if possible.
+    ///
+    fn comments(&mut self, output: &mut Output, location: Option<Location>) {
+        while let Some(comment) = self.layout.comments.get(self.comment_index) {
+            if let Some(location) = location {
+                if comment.location >= location {
+                    break;
+                }
+            }
+            self.comment_index += 1;
+
+            let text = comment.to_source();
+            if comment.is_trailing && output.append(text.as_str(), comment.is_line()) {
+                continue;
+            }
+            if self.layout.is_blank_before(comment.location) {
+                output.blank_line();
+            }
+            output.comment(text, comment.is_line());
+        }
+    }
+
+    ///
+    /// Moves the current column after the `text` written from the `start` column.
+    ///
+    pub fn move_after(&mut self, start: usize, text: &str) {
+        self.column = match text.rfind('\n') {
+            Some(index) => text.len() - index - 1,
+            None => start + text.len(),
+        };
+    }
+
+    ///
+    /// Returns the column of the `indent` nesting level.
+    ///
+    pub fn indentation(indent: usize) -> usize {
+        indent * Self::INDENT.len()
+    }
+
+    ///
+    /// Returns the location of the module-level statement first token, including attributes.
+    ///
+    fn module_statement_start(statement: &ModuleLocalStatement) -> Location {
+        match statement {
+            ModuleLocalStatement::Const(inner) => inner.location,
+            ModuleLocalStatement::Type(inner) => inner.location,
+            ModuleLocalStatement::Struct(inner) => inner.location,
+            ModuleLocalStatement::Enum(inner) => inner.location,
+            ModuleLocalStatement::Fn(inner) => Self::fn_start(inner),
+            ModuleLocalStatement::Mod(inner) => inner.location,
+            ModuleLocalStatement::Use(inner) => inner.location,
+            ModuleLocalStatement::Impl(inner) => inner.location,
+            ModuleLocalStatement::Contract(inner) => inner.location,
+            ModuleLocalStatement::Empty(location) => *location,
+        }
+    }
+
+    ///
+    /// Returns the location of the function-level statement first token.
+    ///
+    fn fn_local_statement_start(statement: &FunctionLocalStatement) -> Location {
+        match statement {
+            FunctionLocalStatement::Let(inner) => inner.location,
+            FunctionLocalStatement::Const(inner) => inner.location,
+            FunctionLocalStatement::For(inner) => inner.location,
+            FunctionLocalStatement::Empty(location) => *location,
+            FunctionLocalStatement::Expression(inner) => inner.start(),
+        }
+    }
+
+    ///
+    /// Returns the location of the function first attribute or keyword.
+    ///
+    fn fn_start(statement: &FnStatement) -> Location {
+        statement
+            .attributes
+            .first()
+            .map(|attribute| attribute.location)
+            .unwrap_or(statement.location)
+    }
+}
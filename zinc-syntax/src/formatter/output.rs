@@ -0,0 +1,123 @@
+//!
+//! The formatter output.
+//!
+
+use crate::formatter::Formatter;
+
+///
+/// The formatted item list, e.g. a module, block, or structure fields, which is written line
+/// by line.
+///
+#[derive(Debug)]
+pub struct Output {
+    /// The text written so far, which starts with the opening line, e.g. `fn main() {`.
+    text: String,
+    /// The indentation level of the list items.
+    indent: usize,
+    /// If nothing has been written after the opening line.
+    is_empty: bool,
+    /// If a blank line must be written before the next item.
+    is_blank_line_pending: bool,
+    /// If the last line ends with a line comment, so nothing can be appended to it.
+    is_line_commented: bool,
+    /// The source code line where the previous item starts.
+    previous_line: Option<usize>,
+}
+
+impl Output {
+    ///
+    /// Creates an output starting with the `opening` line.
+    ///
+    pub fn new(opening: String, indent: usize) -> Self {
+        Self {
+            text: opening,
+            indent,
+            is_empty: true,
+            is_blank_line_pending: false,
+            is_line_commented: false,
+            previous_line: None,
+        }
+    }
+
+    ///
+    /// Writes the `line` at the list indentation level.
+    ///
+    /// The continuation lines of `line` must be already indented.
+    ///
+    pub fn line(&mut self, line: String) {
+        if !self.text.is_empty() {
+            self.text.push('\n');
+        }
+        if self.is_blank_line_pending {
+            self.text.push('\n');
+            self.is_blank_line_pending = false;
+        }
+        self.text
+            .push_str(Formatter::INDENT.repeat(self.indent).as_str());
+        self.text.push_str(line.as_str());
+
+        self.is_empty = false;
+        self.is_line_commented = false;
+    }
+
+    ///
+    /// Writes the `comment` on a separate line.
+    ///
+    pub fn comment(&mut self, comment: String, is_line: bool) {
+        self.line(comment);
+        self.is_line_commented = is_line;
+    }
+
+    ///
+    /// Appends the trailing `comment` to the last line.
+    ///
+    /// Returns `false` if the comment cannot be appended and must be written on a separate line.
+    ///
+    pub fn append(&mut self, comment: &str, is_line: bool) -> bool {
+        if self.text.is_empty() || self.is_line_commented {
+            return false;
+        }
+
+        self.text.push(' ');
+        self.text.push_str(comment);
+
+        self.is_empty = false;
+        self.is_line_commented = is_line;
+        true
+    }
+
+    ///
+    /// Starts an item at the source code `line`.
+    ///
+    /// Returns `false` if the previous item starts on the same line.
+    ///
+    pub fn start_item(&mut self, line: usize) -> bool {
+        let is_new_line = self.previous_line != Some(line);
+        self.previous_line = Some(line);
+        is_new_line
+    }
+
+    ///
+    /// Requests a blank line before the next item, unless it is the first one.
+    ///
+    pub fn blank_line(&mut self) {
+        if !self.is_empty {
+            self.is_blank_line_pending = true;
+        }
+    }
+
+    ///
+    /// Writes the `closing` line at the `indent` level and returns the text.
+    ///
+    /// If nothing has been written after the opening line, the `closing` is appended to it.
+    ///
+    pub fn finish(mut self, closing: &str, indent: usize) -> String {
+        if !self.is_empty {
+            self.text.push('\n');
+            self.text
+                .push_str(Formatter::INDENT.repeat(indent).as_str());
+        }
+        self.text.push_str(closing);
+        self.text
+    }
+}
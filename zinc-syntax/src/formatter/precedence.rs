@@ -0,0 +1,118 @@
+//!
+//! The formatter expression precedence.
+//!
+
+use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The expression precedence level, from the lowest to the highest one.
+///
+/// Mirrors the order of the expression parsers.
+///
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Precedence {
+    /// `=`, `+=`, etc., non-associative
+    Assignment,
+    /// `..`, `..=`, non-associative
+    Range,
+    /// `||`
+    Or,
+    /// `^^`
+    Xor,
+    /// `&&`
+    And,
+    /// `==`, `<`, etc., non-associative
+    Comparison,
+    /// `|`
+    BitwiseOr,
+    /// `^`
+    BitwiseXor,
+    /// `&`
+    BitwiseAnd,
+    /// `<<`, `>>`
+    BitwiseShift,
+    /// `+`, `-`
+    AddSub,
+    /// `*`, `/`, `%`
+    MulDivRem,
+    /// `as`
+    Casting,
+    /// `!`, `~`, `-`
+    Unary,
+    /// `[]`, `.`, `()`, `!`
+    Access,
+    /// `::`, `::<>`, `{}`
+    Path,
+    /// The operands.
+    Terminal,
+}
+
+impl Precedence {
+    ///
+    /// Returns the precedence of the `tree` root node.
+    ///
+    pub fn of(tree: &ExpressionTree) -> Self {
+        match tree.value.as_ref() {
+            ExpressionTreeNode::Operator(operator) => Self::from(*operator),
+            ExpressionTreeNode::Operand(_) => Self::Terminal,
+        }
+    }
+
+    ///
+    /// If the operands of the same precedence cannot be chained without parentheses.
+    ///
+    pub fn is_non_associative(self) -> bool {
+        matches!(self, Self::Assignment | Self::Range | Self::Comparison)
+    }
+}
+
+impl From<ExpressionOperator> for Precedence {
+    fn from(operator: ExpressionOperator) -> Self {
+        match operator {
+            ExpressionOperator::Assignment
+            | ExpressionOperator::AssignmentBitwiseOr
+            | ExpressionOperator::AssignmentBitwiseXor
+            | ExpressionOperator::AssignmentBitwiseAnd
+            | ExpressionOperator::AssignmentBitwiseShiftLeft
+            | ExpressionOperator::AssignmentBitwiseShiftRight
+            | ExpressionOperator::AssignmentAddition
+            | ExpressionOperator::AssignmentSubtraction
+            | ExpressionOperator::AssignmentMultiplication
+            | ExpressionOperator::AssignmentDivision
+            | ExpressionOperator::AssignmentRemainder => Self::Assignment,
+            ExpressionOperator::Range | ExpressionOperator::RangeInclusive => Self::Range,
+            ExpressionOperator::Or => Self::Or,
+            ExpressionOperator::Xor => Self::Xor,
+            ExpressionOperator::And => Self::And,
+            ExpressionOperator::Equals
+            | ExpressionOperator::NotEquals
+            | ExpressionOperator::GreaterEquals
+            | ExpressionOperator::LesserEquals
+            | ExpressionOperator::Greater
+            | ExpressionOperator::Lesser => Self::Comparison,
+            ExpressionOperator::BitwiseOr => Self::BitwiseOr,
+            ExpressionOperator::BitwiseXor => Self::BitwiseXor,
+            ExpressionOperator::BitwiseAnd => Self::BitwiseAnd,
+            ExpressionOperator::BitwiseShiftLeft | ExpressionOperator::BitwiseShiftRight => {
+                Self::BitwiseShift
+            }
+            ExpressionOperator::Addition | ExpressionOperator::Subtraction => Self::AddSub,
+            ExpressionOperator::Multiplication
+            | ExpressionOperator::Division
+            | ExpressionOperator::Remainder => Self::MulDivRem,
+            ExpressionOperator::Casting => Self::Casting,
+            ExpressionOperator::Not
+            | ExpressionOperator::BitwiseNot
+            | ExpressionOperator::Negation => Self::Unary,
+            ExpressionOperator::Index
+            | ExpressionOperator::Dot
+            | ExpressionOperator::CallIntrinsic
+            | ExpressionOperator::Call => Self::Access,
+            ExpressionOperator::Path
+            | ExpressionOperator::Generics
+            | ExpressionOperator::Structure => Self::Path,
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//!
+//! The formatter tests.
+//!
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::formatter::Formatter;
+use crate::parser::Parser;
+
+///
+/// Parses the `input` and prints the syntax tree without the locations, which are changed by
+/// formatting.
+///
+fn parse(input: &str) -> Option<String> {
+    let module = Parser::default().parse(input, 0).ok()?;
+
+    let debug = format!("{:?}", module);
+    let mut result = String::with_capacity(debug.len());
+    let mut remaining = debug.as_str();
+    while let Some(start) = remaining.find("Location {") {
+        result.push_str(&remaining[..start]);
+        let end = remaining[start..]
+            .find('}')
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        remaining = &remaining[start + end + 1..];
+    }
+    result.push_str(remaining);
+
+    Some(result)
+}
+
+fn sources(directory: &Path, sources: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).expect(zinc_const::panic::TEST_DATA_VALID) {
+        let path = entry.expect(zinc_const::panic::TEST_DATA_VALID).path();
+        if path.is_dir() {
+            self::sources(&path, sources);
+        } else if path.extension().and_then(|extension| extension.to_str())
+            == Some(zinc_const::extension::SOURCE)
+        {
+            sources.push(path);
+        }
+    }
+}
+
+#[test]
+fn ok_round_trip_corpus() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let mut paths = Vec::new();
+    for directory in ["../zinc-tester/tests", "../zinc-examples"].iter() {
+        sources(&root.join(directory), &mut paths);
+    }
+    assert!(!paths.is_empty());
+
+    for path in paths.into_iter() {
+        let input = fs::read_to_string(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+        let expected = match parse(input.as_str()) {
+            Some(expected) => expected,
+            None => continue,
+        };
+
+        let formatted = Formatter::format(input.as_str(), 0)
+            .unwrap_or_else(|error| panic!("{:?}: {:?}", path, error));
+        assert_eq!(
+            parse(formatted.as_str()).as_ref(),
+            Some(&expected),
+            "{:?}: the syntax tree has changed:\n{}",
+            path,
+            formatted
+        );
+
+        let reformatted = Formatter::format(formatted.as_str(), 0)
+            .unwrap_or_else(|error| panic!("{:?}: {:?}", path, error));
+        assert_eq!(reformatted, formatted, "{:?}: not idempotent", path);
+    }
+}
+
+#[test]
+fn ok_layout() {
+    let input = r#"
+use std::collections::MTreeMap;
+pub const LIMIT:u8=10;
+
+struct Data{a:u8,b:(u8,),}
+
+fn main(value:u8)->u8{
+    let mut data=Data{a:1,b:(2,)};
+    if value>LIMIT{data.a=value;}else if value==0{data.a=0;}
+    let x=if value>0{1}else{2};
+    for i in 0..LIMIT while i<value{dbg!("{}",i);}
+    match value{0=>1,_=>{value+1}}
+}
+"#;
+
+    let expected = r#"use std::collections::MTreeMap;
+pub const LIMIT: u8 = 10;
+
+struct Data {
+    a: u8,
+    b: (u8,),
+}
+
+fn main(value: u8) -> u8 {
+    let mut data = Data { a: 1, b: (2,) };
+    if value > LIMIT {
+        data.a = value;
+    } else if value == 0 {
+        data.a = 0;
+    }
+    let x = if value > 0 { 1 } else { 2 };
+    for i in 0..LIMIT while i < value {
+        dbg!("{}", i);
+    }
+    match value {
+        0 => 1,
+        _ => { value + 1 },
+    }
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_contract_field_default() {
+    let input = r#"
+contract Pool{pub fee_bps:u16=30;owner:u160;
+    pub fn new(owner:u160)->Self{Self{owner:owner}}
+}
+"#;
+
+    let expected = r#"contract Pool {
+    pub fee_bps: u16 = 30;
+    owner: u160;
+    pub fn new(owner: u160) -> Self {
+        Self { owner: owner }
+    }
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_enum_payload() {
+    let input = r#"
+enum Action{Transfer{to:u160,amount:u248},Burn(u248),Noop,Fee=5}
+"#;
+
+    let expected = r#"enum Action {
+    Transfer { to: u160, amount: u248 },
+    Burn(u248),
+    Noop,
+    Fee = 5,
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_newtype() {
+    let input = r#"
+struct Wei ( u248 ) ;
+
+fn main(a:Wei)->u248{(a+Wei(1)).0}
+"#;
+
+    let expected = r#"struct Wei(u248);
+
+fn main(a: Wei) -> u248 {
+    (a + Wei(1)).0
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_named_tuple() {
+    let input = r#"
+fn main()->(amount:u248,inner:(ok:bool,)){(42,(true,))}
+"#;
+
+    let expected = r#"fn main() -> (amount: u248, inner: (ok: bool)) {
+    (42, (true,))
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_parentheses() {
+    let input = r#"
+fn main() {
+    let a = ((1 + 2)) * -(3 - 4);
+    let b = (1 - (2 - 3)) - 4;
+    let c = (x as T) < y;
+    let d = (a == b) == c;
+    let e = (-a).0;
+}
+"#;
+
+    let expected = r#"fn main() {
+    let a = (1 + 2) * -(3 - 4);
+    let b = (1 - (2 - 3)) - 4;
+    let c = (x as T) < y;
+    let d = (a == b) == c;
+    let e = (-a).0;
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_comments() {
+    let input = r#"//! The module comment.
+
+// The function comment.
+fn main() { // trailing
+    let a = 42; /* trailing */
+
+
+    // The statement comment.
+    a /* hoisted */ + 1
+    // The final comment.
+}
+// The end.
+"#;
+
+    let expected = r#"//! The module comment.
+
+// The function comment.
+fn main() { // trailing
+    let a = 42; /* trailing */
+
+    // The statement comment.
+    a + 1 /* hoisted */
+    // The final comment.
+}
+// The end.
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
+
+#[test]
+fn ok_semicolons() {
+    let input = r#"
+fn main() {
+    if a { b; };
+    (c, d);
+    if a { b; }
+    let e = f;
+    match f { _ => g, };
+    -e;
+    h
+}
+"#;
+
+    let expected = r#"fn main() {
+    if a {
+        b;
+    };
+    (c, d);
+    if a {
+        b;
+    }
+    let e = f;
+    match f {
+        _ => g,
+    };
+    -e;
+    h
+}
+"#;
+
+    assert_eq!(
+        Formatter::format(input, 0).expect(zinc_const::panic::TEST_DATA_VALID),
+        expected
+    );
+}
@@ -3,11 +3,13 @@
 //!
 
 pub(crate) mod error;
+pub(crate) mod formatter;
 pub(crate) mod parser;
 pub(crate) mod tree;
 
 pub use self::error::Error;
 pub use self::error::ParsingError;
+pub use self::formatter::Formatter;
 pub use self::parser::Parser;
 pub use self::tree::attribute::Attribute;
 pub use self::tree::binding::Binding;
@@ -51,4 +53,5 @@ pub use self::tree::statement::r#struct::Statement as StructStatement;
 pub use self::tree::statement::r#type::Statement as TypeStatement;
 pub use self::tree::statement::r#use::Statement as UseStatement;
 pub use self::tree::tuple_index::TupleIndex;
+pub use self::tree::variant::payload::Payload as VariantPayload;
 pub use self::tree::variant::Variant;
@@ -6,15 +6,23 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Lexeme;
+use zinc_lexical::Literal as LexicalLiteral;
+use zinc_lexical::Location;
 use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::tree::attribute::argument::Argument as AttributeArgument;
 use crate::tree::attribute::builder::Builder as AttributeBuilder;
 use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
+use crate::tree::literal::string::Literal as StringLiteral;
+
+/// The missing argument value error hint.
+pub static HINT_EXPECTED_STRING_LITERAL: &str =
+    "attribute arguments must be string literals, e.g. `#[payable(token = \"ETH\")]`";
 
 ///
 /// The parser state.
@@ -30,6 +38,16 @@ pub enum State {
     /// The `#[` has been parsed so far.
     Identifier,
     /// The `#[ {identifier}` has been parsed so far.
+    ParenthesisLeftOrBracketSquareRight,
+    /// The `#[ {identifier} (` or `#[ {identifier} ( {argument} ,` has been parsed so far.
+    ArgumentIdentifierOrParenthesisRight,
+    /// The `#[ {identifier} ( {argument-identifier}` has been parsed so far.
+    ArgumentEqualsOrCommaOrParenthesisRight,
+    /// The `#[ {identifier} ( {argument-identifier} =` has been parsed so far.
+    ArgumentValue,
+    /// The `#[ {identifier} ( {argument}` has been parsed so far.
+    CommaOrParenthesisRight,
+    /// The `#[ {identifier}` with optional arguments has been parsed so far.
     BrackerSquareRight,
 }
 
@@ -50,6 +68,8 @@ pub struct Parser {
     builder: AttributeBuilder,
     /// The token returned from a subparser.
     next: Option<Token>,
+    /// The argument identifier, which is parsed before its value.
+    argument: Option<(Location, Identifier)>,
 }
 
 impl Parser {
@@ -57,6 +77,8 @@ impl Parser {
     /// Parses an attribute.
     ///
     /// '#[test]'
+    /// '#[payable(token = "ETH")]'
+    /// '#[only(owner)]'
     ///
     pub fn parse(
         mut self,
@@ -125,8 +147,41 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
+                            self.state = State::ParenthesisLeftOrBracketSquareRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::ParenthesisLeftOrBracketSquareRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::ArgumentIdentifierOrParenthesisRight,
+                        token => {
+                            self.next = Some(token);
                             self.state = State::BrackerSquareRight;
                         }
+                    }
+                }
+                State::ArgumentIdentifierOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.argument = Some((location, identifier));
+                            self.state = State::ArgumentEqualsOrCommaOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::BrackerSquareRight,
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
                                 location, lexeme, None,
@@ -134,6 +189,88 @@ impl Parser {
                         }
                     }
                 }
+                State::ArgumentEqualsOrCommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::ArgumentValue,
+                        token @ Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        }
+                        | token @ Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            let (argument_location, identifier) = self
+                                .argument
+                                .take()
+                                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                            self.builder.push_argument(AttributeArgument::new(
+                                argument_location,
+                                identifier,
+                                None,
+                            ));
+                            self.next = Some(token);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["=", ",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ArgumentValue => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::String(string)),
+                            location,
+                        } => {
+                            let (argument_location, identifier) = self
+                                .argument
+                                .take()
+                                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                            self.builder.push_argument(AttributeArgument::new(
+                                argument_location,
+                                identifier,
+                                Some(StringLiteral::new(location, string)),
+                            ));
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_value(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_STRING_LITERAL),
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::ArgumentIdentifierOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::BrackerSquareRight,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::BrackerSquareRight => {
                     return match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -152,16 +289,21 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
     use zinc_lexical::Location;
+    use zinc_lexical::StringLiteral as LexicalStringLiteral;
     use zinc_lexical::Symbol;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::attribute::argument::Argument as AttributeArgument;
     use crate::tree::attribute::Attribute;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::string::Literal as StringLiteral;
 
     #[test]
     fn ok_outer() {
@@ -172,6 +314,7 @@ mod tests {
                 Location::test(1, 1),
                 false,
                 Identifier::new(Location::test(1, 3), "test".to_owned()),
+                vec![],
             ),
             None,
         ));
@@ -190,6 +333,95 @@ mod tests {
                 Location::test(1, 1),
                 true,
                 Identifier::new(Location::test(1, 4), "test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_with_arguments() {
+        let input = r#"#[payable(token = "ETH", note = "deposit",)]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 3), "payable".to_owned()),
+                vec![
+                    AttributeArgument::new(
+                        Location::test(1, 11),
+                        Identifier::new(Location::test(1, 11), "token".to_owned()),
+                        Some(StringLiteral::new(
+                            Location::test(1, 19),
+                            LexicalStringLiteral::new("ETH".to_owned()),
+                        )),
+                    ),
+                    AttributeArgument::new(
+                        Location::test(1, 26),
+                        Identifier::new(Location::test(1, 26), "note".to_owned()),
+                        Some(StringLiteral::new(
+                            Location::test(1, 33),
+                            LexicalStringLiteral::new("deposit".to_owned()),
+                        )),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_with_arguments_without_values() {
+        let input = r#"#[only(owner, token = "ETH")]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 3), "only".to_owned()),
+                vec![
+                    AttributeArgument::new(
+                        Location::test(1, 8),
+                        Identifier::new(Location::test(1, 8), "owner".to_owned()),
+                        None,
+                    ),
+                    AttributeArgument::new(
+                        Location::test(1, 15),
+                        Identifier::new(Location::test(1, 15), "token".to_owned()),
+                        Some(StringLiteral::new(
+                            Location::test(1, 23),
+                            LexicalStringLiteral::new("ETH".to_owned()),
+                        )),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_with_empty_arguments() {
+        let input = r#"#[payable()]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 3), "payable".to_owned()),
+                vec![],
             ),
             None,
         ));
@@ -245,4 +477,55 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_argument_equals() {
+        let input = r#"#[payable(token "ETH")]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 17),
+            vec!["=", ",", ")"],
+            Lexeme::Literal(LexicalLiteral::String(LexicalStringLiteral::new(
+                "ETH".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_argument_value() {
+        let input = r#"#[payable(token = 42)]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
+            Location::test(1, 19),
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            Some(super::HINT_EXPECTED_STRING_LITERAL),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"#[payable(token = "ETH"]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 24),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::BracketSquareRight),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -11,8 +11,11 @@ use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
+use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::attribute::Parser as AttributeParser;
 use crate::parser::binding::Parser as BindingParser;
+use crate::tree::attribute::Attribute;
 use crate::tree::binding::Binding;
 
 ///
@@ -20,7 +23,7 @@ use crate::tree::binding::Binding;
 ///
 #[derive(Debug, Clone, Copy)]
 pub enum State {
-    /// The initial state.
+    /// The initial state. The binding may be preceded by outer attributes.
     Binding,
     /// The `{binding}` has been parsed so far. A comma prepends the next binding pattern.
     CommaOrEnd,
@@ -41,6 +44,8 @@ pub struct Parser {
     state: State,
     /// The parsed bindings.
     bindings: Vec<Binding>,
+    /// The outer attributes of the binding being parsed.
+    attributes: Vec<Attribute>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -49,7 +54,7 @@ impl Parser {
     ///
     /// Parses a binding pattern list.
     ///
-    /// 'mut a: u8, b: field, c: (bool, bool)'
+    /// 'mut a: u8, #[public] b: field, c: (bool, bool)'
     ///
     pub fn parse(
         mut self,
@@ -62,43 +67,48 @@ impl Parser {
             match self.state {
                 State::Binding => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-                        token
-                        @
-                        Token {
+                        token @ Token {
+                            lexeme: Lexeme::Symbol(Symbol::Number),
+                            ..
+                        } => {
+                            let (attribute, next) =
+                                AttributeParser::default().parse(stream.clone(), Some(token))?;
+                            self.attributes.push(attribute);
+                            self.next = next;
+                        }
+                        token @ Token {
                             lexeme: Lexeme::Keyword(Keyword::Mut),
                             ..
                         }
-                        | token
-                        @
-                        Token {
+                        | token @ Token {
                             lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
                             ..
                         }
-                        | token
-                        @
-                        Token {
+                        | token @ Token {
                             lexeme: Lexeme::Identifier(_),
                             ..
                         }
-                        | token
-                        @
-                        Token {
+                        | token @ Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             ..
                         }
-                        | token
-                        @
-                        Token {
+                        | token @ Token {
                             lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
                             ..
                         } => {
                             let (binding, next) =
                                 BindingParser::default().parse(stream.clone(), Some(token))?;
-                            self.bindings.push(binding);
+                            self.bindings
+                                .push(binding.with_attributes(self.attributes.drain(..).collect()));
                             self.next = next;
 
                             self.state = State::CommaOrEnd;
                         }
+                        Token { lexeme, location } if !self.attributes.is_empty() => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_binding_pattern(location, lexeme),
+                            ));
+                        }
                         token => return Ok((self.bindings, Some(token))),
                     }
                 }
@@ -126,6 +136,9 @@ mod tests {
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
@@ -254,4 +267,64 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_with_attribute() {
+        let input = r#"a: u8, #[public] b: field"#;
+
+        let expected = Ok((
+            vec![
+                Binding::new(
+                    Location::test(1, 1),
+                    BindingPattern::new(
+                        Location::test(1, 1),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 1), "a".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(Type::new(
+                        Location::test(1, 4),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                    )),
+                ),
+                Binding::new(
+                    Location::test(1, 18),
+                    BindingPattern::new(
+                        Location::test(1, 18),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 18), "b".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(Type::new(Location::test(1, 21), TypeVariant::field())),
+                )
+                .with_attributes(vec![Attribute::new(
+                    Location::test(1, 8),
+                    false,
+                    Identifier::new(Location::test(1, 10), "public".to_owned()),
+                    vec![],
+                )]),
+            ],
+            Some(Token::new(Lexeme::Eof, Location::test(1, 26))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_attribute_without_binding() {
+        let input = r#"a: u8, #[public]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_binding_pattern(
+            Location::test(1, 17),
+            Lexeme::Eof,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
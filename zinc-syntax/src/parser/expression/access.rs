@@ -134,7 +134,11 @@ impl Parser {
                             self.state = State::FieldDescriptor;
                         }
                         token => {
-                            return Ok((self.builder.finish(), Some(token)));
+                            return Ok(crate::parser::with_expression_end(
+                                self.builder.finish(),
+                                Some(token),
+                                stream,
+                            ));
                         }
                     }
                 }
@@ -244,6 +248,8 @@ mod tests {
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
 
     #[test]
     fn ok() {
@@ -288,6 +294,40 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generics() {
+        let input = r#"max::<u8>;"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::test(1, 4),
+                ExpressionTreeNode::operator(ExpressionOperator::Generics),
+                Some(ExpressionTree::new(
+                    Location::test(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 1),
+                        "max".to_owned(),
+                    ))),
+                )),
+                Some(ExpressionTree::new(
+                    Location::test(1, 4),
+                    ExpressionTreeNode::operand(ExpressionOperand::Generics(vec![Type::new(
+                        Location::test(1, 7),
+                        TypeVariant::integer_unsigned(8),
+                    )])),
+                )),
+            ),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::Semicolon),
+                Location::test(1, 10),
+            )),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"array[42)"#;
@@ -163,6 +163,55 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_access_tuple_index_chain() {
+        let input = r#"tuple.1.0"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::test(1, 8),
+                ExpressionTreeNode::operator(ExpressionOperator::Dot),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::test(1, 6),
+                    ExpressionTreeNode::operator(ExpressionOperator::Dot),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "tuple".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::TupleIndex(
+                            TupleIndex::new(
+                                Location::test(1, 7),
+                                IntegerLiteral::new(
+                                    Location::test(1, 7),
+                                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                ),
+                            ),
+                        )),
+                    )),
+                )),
+                Some(ExpressionTree::new(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operand(ExpressionOperand::TupleIndex(TupleIndex::new(
+                        Location::test(1, 9),
+                        IntegerLiteral::new(
+                            Location::test(1, 9),
+                            LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                        ),
+                    ))),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 10))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_negation() {
         let input = r#"-42"#;
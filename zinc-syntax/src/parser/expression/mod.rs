@@ -576,4 +576,36 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_span_parenthesized() {
+        let input = r#"a * (b + c)"#;
+
+        let (result, _next) = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(result.start(), Location::test(1, 1));
+        assert_eq!(result.end, Location::test(1, 12));
+        assert_eq!(
+            result.right.map(|right| right.end),
+            Some(Location::test(1, 12))
+        );
+    }
+
+    #[test]
+    fn ok_span_postfix() {
+        let input = r#"value.fetch(1, 2)[0];"#;
+
+        let (result, next) = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(result.start(), Location::test(1, 1));
+        assert_eq!(result.end, Location::test(1, 21));
+        assert_eq!(
+            next.map(|token| token.location),
+            Some(Location::test(1, 21))
+        );
+    }
 }
@@ -83,7 +83,13 @@ impl Parser {
                                 .eat_operator(ExpressionOperator::Casting, location);
                             self.state = State::CastingSecondOperand;
                         }
-                        token => return Ok((self.builder.finish(), Some(token))),
+                        token => {
+                            return Ok(crate::parser::with_expression_end(
+                                self.builder.finish(),
+                                Some(token),
+                                stream,
+                            ))
+                        }
                     }
                 }
                 State::CastingSecondOperand => {
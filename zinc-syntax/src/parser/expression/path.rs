@@ -13,6 +13,7 @@ use zinc_lexical::TokenStream;
 use crate::error::ParsingError;
 use crate::parser::expression::structure::Parser as StructureExpressionParser;
 use crate::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::parser::r#type::generics::Parser as GenericsParser;
 use crate::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
 use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
@@ -25,7 +26,8 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 pub enum State {
     /// The initial state.
     Terminal,
-    /// The operand has been parsed and a `::` operator or structure literal is expected.
+    /// The operand has been parsed and a `::` operator, generic type arguments, or structure
+    /// literal is expected.
     DoubleColonOrStructureOrEnd,
 }
 
@@ -56,6 +58,10 @@ impl Parser {
     /// 'value'
     /// 'path::to::Type'
     /// 'path::to::Structure { a: 42, b: 25 }'
+    /// 'path::to::Structure { a: 42, ..base }'
+    /// 'path::to::function::<u8>'
+    ///
+    /// If the path is followed by the `::*` glob, the `::` token is returned to the caller.
     ///
     pub fn parse(
         mut self,
@@ -79,9 +85,42 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::DoubleColon),
                             location,
                         } => {
-                            self.builder
-                                .eat_operator(ExpressionOperator::Path, location);
-                            self.state = State::Terminal;
+                            let look_ahead = stream.borrow_mut().look_ahead(1)?.to_owned();
+
+                            match look_ahead {
+                                Token {
+                                    lexeme: Lexeme::Symbol(Symbol::Lesser),
+                                    ..
+                                } => {
+                                    self.builder
+                                        .eat_operator(ExpressionOperator::Generics, location);
+
+                                    let (generics, next) =
+                                        GenericsParser::default().parse(stream.clone(), None)?;
+                                    self.next = next;
+                                    self.builder.eat_operand(
+                                        ExpressionOperand::Generics(generics),
+                                        location,
+                                    );
+                                }
+                                Token {
+                                    lexeme: Lexeme::Symbol(Symbol::Asterisk),
+                                    ..
+                                } => {
+                                    let token =
+                                        Token::new(Lexeme::Symbol(Symbol::DoubleColon), location);
+                                    return Ok(crate::parser::with_expression_end(
+                                        self.builder.finish(),
+                                        Some(token),
+                                        stream,
+                                    ));
+                                }
+                                _ => {
+                                    self.builder
+                                        .eat_operator(ExpressionOperator::Path, location);
+                                    self.state = State::Terminal;
+                                }
+                            }
                         }
                         token
                         @
@@ -89,13 +128,34 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
                         } => {
-                            let look_ahead = stream.borrow_mut().look_ahead(2)?.to_owned();
+                            let look_ahead_1 = stream.borrow_mut().look_ahead(1)?.to_owned();
+                            let look_ahead_2 = stream.borrow_mut().look_ahead(2)?.to_owned();
 
-                            return match look_ahead {
-                                Token {
-                                    lexeme: Lexeme::Symbol(Symbol::Colon),
-                                    ..
-                                } => {
+                            return match (look_ahead_1, look_ahead_2) {
+                                (
+                                    Token {
+                                        lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                                        ..
+                                    },
+                                    _,
+                                )
+                                | (
+                                    _,
+                                    Token {
+                                        lexeme: Lexeme::Symbol(Symbol::Colon),
+                                        ..
+                                    },
+                                )
+                                | (
+                                    Token {
+                                        lexeme: Lexeme::Identifier(_),
+                                        ..
+                                    },
+                                    Token {
+                                        lexeme: Lexeme::Symbol(Symbol::Comma),
+                                        ..
+                                    },
+                                ) => {
                                     let location = token.location;
 
                                     self.builder
@@ -108,12 +168,26 @@ impl Parser {
                                         location,
                                     );
 
-                                    Ok((self.builder.finish(), next))
+                                    Ok(crate::parser::with_expression_end(
+                                        self.builder.finish(),
+                                        next,
+                                        stream,
+                                    ))
                                 }
-                                _ => Ok((self.builder.finish(), Some(token))),
+                                _ => Ok(crate::parser::with_expression_end(
+                                    self.builder.finish(),
+                                    Some(token),
+                                    stream,
+                                )),
                             };
                         }
-                        token => return Ok((self.builder.finish(), Some(token))),
+                        token => {
+                            return Ok(crate::parser::with_expression_end(
+                                self.builder.finish(),
+                                Some(token),
+                                stream,
+                            ))
+                        }
                     }
                 }
             }
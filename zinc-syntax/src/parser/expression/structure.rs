@@ -21,7 +21,11 @@ use crate::tree::identifier::Identifier;
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "structure field must have an identifier, e.g. `{ a: 42 }`";
 /// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "structure field must be initialized, e.g. `{ a: 42 }`";
+pub static HINT_EXPECTED_VALUE: &str =
+    "structure field must be initialized, e.g. `{ a: 42 }`, or use the shorthand, e.g. `{ a }`";
+/// The structure update base followed by something error hint.
+pub static HINT_BASE_MUST_BE_LAST: &str =
+    "the structure update base must be the last item, e.g. `{ a: 42, ..base }`";
 
 ///
 /// The parser state.
@@ -33,11 +37,15 @@ pub enum State {
     /// The `{` has been parsed so far.
     IdentifierOrBracketCurlyRight,
     /// The `{ {identifier}` has been parsed so far.
-    Colon,
+    ColonOrCommaOrBracketCurlyRight,
     /// The `{ {identifier} :` has been parsed so far.
     Expression,
     /// The `{ {identifier} : {expression}` has been parsed so far.
     CommaOrBracketCurlyRight,
+    /// The `{ ..` has been parsed so far.
+    Base,
+    /// The `{ .. {expression}` has been parsed so far.
+    BracketCurlyRight,
 }
 
 impl Default for State {
@@ -65,6 +73,8 @@ impl Parser {
     ///
     /// '
     /// { a: 1, b: true, c: (10, 20) }
+    /// { a: 1, ..base }
+    /// { a, b: true }
     /// '
     ///
     pub fn parse(
@@ -108,8 +118,12 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.push_field_identifier(identifier);
-                            self.state = State::Colon;
+                            self.state = State::ColonOrCommaOrBracketCurlyRight;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => self.state = State::Base,
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
                                 location,
@@ -119,12 +133,26 @@ impl Parser {
                         }
                     }
                 }
-                State::Colon => {
+                State::ColonOrCommaOrBracketCurlyRight => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Colon),
                             ..
                         } => self.state = State::Expression,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder.set_field_shorthand();
+                            self.state = State::IdentifierOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.builder.set_field_shorthand();
+                            return Ok((self.builder.finish(), None));
+                        }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_value(
                                 location,
@@ -163,6 +191,31 @@ impl Parser {
                         }
                     }
                 }
+                State::Base => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_base(expression);
+                    self.state = State::BracketCurlyRight;
+                }
+                State::BracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_one_of_or_operator(
+                                    location,
+                                    vec!["}"],
+                                    lexeme,
+                                    Some(HINT_BASE_MUST_BE_LAST),
+                                ),
+                            ));
+                        }
+                    }
+                }
             }
         }
     }
@@ -172,6 +225,7 @@ impl Parser {
 mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
+    use zinc_lexical::Literal as LexicalLiteral;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
     use zinc_lexical::TokenStream;
@@ -209,6 +263,7 @@ mod tests {
                         )),
                     ),
                 )],
+                None,
             ),
             None,
         ));
@@ -269,6 +324,120 @@ mod tests {
                         ),
                     ),
                 ],
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_base() {
+        let input = r#"
+{
+    a: 1,
+    ..base
+}
+"#;
+
+        let expected = Ok((
+            StructureExpression::new(
+                Location::test(2, 1),
+                vec![(
+                    Identifier::new(Location::test(3, 5), "a".to_owned()),
+                    ExpressionTree::new(
+                        Location::test(3, 8),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(3, 8),
+                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                            ),
+                        )),
+                    ),
+                )],
+                Some(ExpressionTree::new(
+                    Location::test(4, 7),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(4, 7),
+                        "base".to_owned(),
+                    ))),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_base_only() {
+        let input = r#"{ ..base }"#;
+
+        let expected = Ok((
+            StructureExpression::new(
+                Location::test(1, 1),
+                vec![],
+                Some(ExpressionTree::new(
+                    Location::test(1, 5),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 5),
+                        "base".to_owned(),
+                    ))),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_shorthand() {
+        let input = r#"{ a, b: 2, c }"#;
+
+        let expected = Ok((
+            StructureExpression::new(
+                Location::test(1, 1),
+                vec![
+                    (
+                        Identifier::new(Location::test(1, 3), "a".to_owned()),
+                        ExpressionTree::new(
+                            Location::test(1, 3),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 3), "a".to_owned()),
+                            )),
+                        ),
+                    ),
+                    (
+                        Identifier::new(Location::test(1, 6), "b".to_owned()),
+                        ExpressionTree::new(
+                            Location::test(1, 9),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(1, 9),
+                                    LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                    (
+                        Identifier::new(Location::test(1, 12), "c".to_owned()),
+                        ExpressionTree::new(
+                            Location::test(1, 12),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 12), "c".to_owned()),
+                            )),
+                        ),
+                    ),
+                ],
+                None,
             ),
             None,
         ));
@@ -296,12 +465,14 @@ mod tests {
 
     #[test]
     fn error_expected_value() {
-        let input = r#"{ a: 42, b }"#;
+        let input = r#"{ a: 42, b 42 }"#;
 
         let expected: Result<_, ParsingError> =
             Err(ParsingError::Syntax(SyntaxError::expected_value(
                 Location::test(1, 12),
-                Lexeme::Symbol(Symbol::BracketCurlyRight),
+                Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                    "42".to_owned(),
+                ))),
                 Some(super::HINT_EXPECTED_VALUE),
             )));
 
@@ -324,6 +495,22 @@ mod tests {
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
 
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn error_expected_bracket_curly_right() {
+        let input = r#"{ ..base, a: 42 }"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 9),
+                vec!["}"],
+                Lexeme::Symbol(Symbol::Comma),
+                Some(super::HINT_BASE_MUST_BE_LAST),
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
         assert_eq!(result, expected);
     }
 }
@@ -72,8 +72,10 @@ impl Parser {
                     ..
                 } => {
                     return TupleExpressionParser::default()
-                        .parse(stream, Some(token))
-                        .map(|(operand, token)| (operand, token));
+                        .parse(stream.clone(), Some(token))
+                        .map(|(operand, token)| {
+                            crate::parser::with_expression_end(operand, token, stream.clone())
+                        });
                 }
                 token
                 @
@@ -83,7 +85,7 @@ impl Parser {
                 } => {
                     let location = token.location;
                     BlockExpressionParser::default()
-                        .parse(stream, Some(token))
+                        .parse(stream.clone(), Some(token))
                         .map(|(operand, token)| {
                             (ExpressionOperand::Block(operand), location, token)
                         })
@@ -96,7 +98,7 @@ impl Parser {
                 } => {
                     let location = token.location;
                     ArrayExpressionParser::default()
-                        .parse(stream, Some(token))
+                        .parse(stream.clone(), Some(token))
                         .map(|(operand, token)| {
                             (ExpressionOperand::Array(operand), location, token)
                         })
@@ -109,7 +111,7 @@ impl Parser {
                 } => {
                     let location = token.location;
                     ConditionalExpressionParser::default()
-                        .parse(stream, Some(token))
+                        .parse(stream.clone(), Some(token))
                         .map(|(operand, token)| {
                             (ExpressionOperand::Conditional(operand), location, token)
                         })
@@ -122,7 +124,7 @@ impl Parser {
                 } => {
                     let location = token.location;
                     MatchExpressionParser::default()
-                        .parse(stream, Some(token))
+                        .parse(stream.clone(), Some(token))
                         .map(|(operand, token)| {
                             (ExpressionOperand::Match(operand), location, token)
                         })
@@ -194,9 +196,10 @@ impl Parser {
                 )),
             }?;
 
-        Ok((
+        Ok(crate::parser::with_expression_end(
             ExpressionTree::new(location, ExpressionTreeNode::Operand(operand)),
             next,
+            stream,
         ))
     }
 }
@@ -24,6 +24,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::ParsingError;
 use crate::parser::statement::local_mod::Parser as ModuleLocalStatementParser;
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::module::Module;
 
 ///
@@ -74,3 +75,15 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+///
+/// Extends the expression `tree` to the end of the last token consumed before the `next` one.
+///
+pub fn with_expression_end(
+    tree: ExpressionTree,
+    next: Option<Token>,
+    stream: Rc<RefCell<TokenStream>>,
+) -> (ExpressionTree, Option<Token>) {
+    let end = stream.borrow().end_before(next.as_ref());
+    (tree.with_end(end), next)
+}
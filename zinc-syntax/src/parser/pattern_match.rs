@@ -20,6 +20,7 @@ use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
@@ -33,6 +34,24 @@ pub enum State {
     PathOperatorOrEnd,
     /// The first path operand and a `::` path operator have been parsed so far.
     PathOperand,
+    /// The `(` or `( {pattern} ,` has been parsed so far.
+    TupleElementOrParenthesisRight,
+    /// The `( {pattern}` has been parsed so far.
+    TupleCommaOrParenthesisRight,
+    /// The `{path} {` or `{path} { {field} ,` has been parsed so far.
+    StructureFieldOrBracketCurlyRight,
+    /// The `{path} { {identifier}` has been parsed so far.
+    StructureColonOrNext,
+    /// The `{path} { {identifier} :` has been parsed so far.
+    StructureFieldPattern,
+    /// The `{path} { {field}` has been parsed so far.
+    StructureCommaOrBracketCurlyRight,
+    /// The `{path} { .. ` has been parsed so far.
+    StructureBracketCurlyRight,
+    /// The `{path} (` or `{path} ( {pattern} ,` has been parsed so far.
+    PayloadElementOrParenthesisRight,
+    /// The `{path} ( {pattern}` has been parsed so far.
+    PayloadCommaOrParenthesisRight,
 }
 
 impl Default for State {
@@ -52,6 +71,8 @@ pub struct Parser {
     builder: MatchPatternBuilder,
     /// The token returned from a subparser.
     next: Option<Token>,
+    /// The structure field identifier, which is parsed before its pattern.
+    field: Option<Identifier>,
 }
 
 impl Parser {
@@ -62,6 +83,9 @@ impl Parser {
     /// '42'
     /// 'variable'
     /// 'Path::To::Item'
+    /// '(0, b)'
+    /// 'Point { x: 0, y, .. }'
+    /// 'Maybe::Some(value)'
     /// '_'
     ///
     pub fn parse(
@@ -123,6 +147,14 @@ impl Parser {
                                 .set_binding(Identifier::new(location, keyword.to_string()));
                             self.state = State::PathOperatorOrEnd;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_tuple();
+                            self.state = State::TupleElementOrParenthesisRight;
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             location,
@@ -148,6 +180,20 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.builder.set_structure();
+                            self.state = State::StructureFieldOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_payload();
+                            self.state = State::PayloadElementOrParenthesisRight;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
@@ -158,6 +204,176 @@ impl Parser {
                     self.builder.push_path_element(expression);
                     self.state = State::PathOperatorOrEnd;
                 }
+                State::TupleElementOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_tuple_element(pattern);
+                            self.state = State::TupleCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::TupleCommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TupleElementOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureFieldOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::StructureColonOrNext;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => {
+                            self.builder.set_structure_rest();
+                            self.state = State::StructureBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_field_identifier(location, lexeme, None),
+                            ));
+                        }
+                    }
+                }
+                State::StructureColonOrNext => {
+                    let identifier = self
+                        .field
+                        .take()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            self.field = Some(identifier);
+                            self.state = State::StructureFieldPattern;
+                        }
+                        token => {
+                            let pattern = MatchPattern::new(
+                                identifier.location,
+                                MatchPatternVariant::Binding(identifier.clone()),
+                            );
+                            self.builder.push_structure_field(identifier, pattern);
+                            self.next = Some(token);
+                            self.state = State::StructureCommaOrBracketCurlyRight;
+                        }
+                    }
+                }
+                State::StructureFieldPattern => {
+                    let identifier = self
+                        .field
+                        .take()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    let (pattern, next) =
+                        Self::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.push_structure_field(identifier, pattern);
+                    self.state = State::StructureCommaOrBracketCurlyRight;
+                }
+                State::StructureCommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::StructureFieldOrBracketCurlyRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::PayloadElementOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_payload_element(pattern);
+                            self.state = State::PayloadCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::PayloadCommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::PayloadElementOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
             }
         }
     }
@@ -166,14 +382,18 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::BooleanLiteral as LexicalBooleanLiteral;
+    use zinc_lexical::Identifier as LexicalIdentifier;
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
     use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -334,4 +554,194 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_tuple() {
+        let input = r#"(0, b)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                            Location::test(1, 2),
+                            LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 5),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 5),
+                            "b".to_owned(),
+                        )),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_nested() {
+        let input = r#"((true, _), c)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::Tuple(vec![
+                            MatchPattern::new(
+                                Location::test(1, 3),
+                                MatchPatternVariant::BooleanLiteral(BooleanLiteral::new(
+                                    Location::test(1, 3),
+                                    LexicalBooleanLiteral::r#true(),
+                                )),
+                            ),
+                            MatchPattern::new(Location::test(1, 9), MatchPatternVariant::Wildcard),
+                        ]),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 13),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 13),
+                            "c".to_owned(),
+                        )),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = r#"Point { x: 0, y, .. }"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Structure {
+                    path: ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "Point".to_owned()),
+                        )),
+                    ),
+                    fields: vec![
+                        (
+                            Identifier::new(Location::test(1, 9), "x".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 12),
+                                MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                    Location::test(1, 12),
+                                    LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::test(1, 15), "y".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 15),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 15),
+                                    "y".to_owned(),
+                                )),
+                            ),
+                        ),
+                    ],
+                    has_rest: true,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_payload() {
+        let input = r#"Maybe::Some(value)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Payload {
+                    path: ExpressionTree::new_with_leaves(
+                        Location::test(1, 6),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Maybe".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 8),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 8), "Some".to_owned()),
+                            )),
+                        )),
+                    ),
+                    elements: vec![MatchPattern::new(
+                        Location::test(1, 13),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 13),
+                            "value".to_owned(),
+                        )),
+                    )],
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"(a b)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 4),
+            vec![",", ")"],
+            Lexeme::Identifier(LexicalIdentifier::new("b".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_structure_bracket_curly_right() {
+        let input = r#"Point { .., x }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 11),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::Comma),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -212,6 +212,7 @@ mod tests {
         let expected = Ok((
             ConstStatement::new(
                 Location::test(1, 1),
+                false,
                 Identifier::new(Location::test(1, 7), "A".to_owned()),
                 Type::new(Location::test(1, 10), TypeVariant::integer_unsigned(64)),
                 ExpressionTree::new(
@@ -232,6 +232,7 @@ mod tests {
                     false,
                     Identifier::new(Location::test(3, 9), "a".to_owned()),
                     Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                    None,
                 ))],
             ),
             None,
@@ -262,18 +263,21 @@ mod tests {
                         false,
                         Identifier::new(Location::test(3, 9), "a".to_owned()),
                         Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(4, 9),
                         true,
                         Identifier::new(Location::test(4, 13), "b".to_owned()),
                         Type::new(Location::test(4, 16), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(5, 9),
                         true,
                         Identifier::new(Location::test(5, 13), "c".to_owned()),
                         Type::new(Location::test(5, 16), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                 ],
             ),
@@ -299,6 +303,7 @@ mod tests {
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
                 vec![ContractLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
+                    false,
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                     Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                     ExpressionTree::new(
@@ -337,6 +342,7 @@ mod tests {
                 vec![
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
+                        false,
                         Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                         Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -351,6 +357,7 @@ mod tests {
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(4, 9),
+                        false,
                         Identifier::new(Location::test(4, 15), "ANOTHER".to_owned()),
                         Type::new(Location::test(4, 24), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -365,6 +372,7 @@ mod tests {
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(5, 9),
+                        false,
                         Identifier::new(Location::test(5, 15), "YET_ANOTHER".to_owned()),
                         Type::new(Location::test(5, 28), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -404,6 +412,7 @@ mod tests {
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -450,6 +459,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -470,6 +480,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -490,6 +501,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -537,9 +549,11 @@ mod tests {
                         true,
                         Identifier::new(Location::test(3, 13), "a".to_owned()),
                         Type::new(Location::test(3, 16), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(5, 9),
+                        false,
                         Identifier::new(Location::test(5, 15), "VALUE".to_owned()),
                         Type::new(Location::test(5, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -557,6 +571,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -612,21 +627,25 @@ mod tests {
                         false,
                         Identifier::new(Location::test(3, 9), "a".to_owned()),
                         Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(4, 9),
                         true,
                         Identifier::new(Location::test(4, 13), "b".to_owned()),
                         Type::new(Location::test(4, 16), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(5, 9),
                         true,
                         Identifier::new(Location::test(5, 13), "c".to_owned()),
                         Type::new(Location::test(5, 16), TypeVariant::integer_unsigned(232)),
+                        None,
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(7, 9),
+                        false,
                         Identifier::new(Location::test(7, 15), "VALUE".to_owned()),
                         Type::new(Location::test(7, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -641,6 +660,7 @@ mod tests {
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(8, 9),
+                        false,
                         Identifier::new(Location::test(8, 15), "ANOTHER".to_owned()),
                         Type::new(Location::test(8, 24), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -655,6 +675,7 @@ mod tests {
                     )),
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(9, 9),
+                        false,
                         Identifier::new(Location::test(9, 15), "YET_ANOTHER".to_owned()),
                         Type::new(Location::test(9, 28), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -672,6 +693,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -692,6 +714,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -712,6 +735,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(15, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(15, 15),
                             BindingPattern::new(
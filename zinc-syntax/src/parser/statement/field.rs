@@ -12,6 +12,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::expression::Parser as ExpressionParser;
 use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::field::builder::Builder as FieldStatementBuilder;
@@ -33,6 +34,10 @@ pub enum State {
     /// The `{identifier} :` has been parsed so far.
     Type,
     /// The `{identifier} : {type}` has been parsed so far.
+    EqualsOrSemicolon,
+    /// The `{identifier} : {type} =` has been parsed so far.
+    Expression,
+    /// The `{identifier} : {type} = {expression}` has been parsed so far.
     Semicolon,
 }
 
@@ -60,6 +65,7 @@ impl Parser {
     /// Parses a 'field' statement.
     ///
     /// 'data: u64;'
+    /// 'data: u64 = 42;'
     ///
     pub fn parse(
         mut self,
@@ -110,6 +116,35 @@ impl Parser {
                         TypeParser::default().parse(stream.clone(), self.next.take())?;
                     self.next = next;
                     self.builder.set_type(r#type);
+                    self.state = State::EqualsOrSemicolon;
+                }
+                State::EqualsOrSemicolon => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Expression,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => return Ok((self.builder, None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_one_of_or_operator(
+                                    location,
+                                    vec!["=", ";"],
+                                    lexeme,
+                                    None,
+                                ),
+                            ));
+                        }
+                    }
+                }
+                State::Expression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_default(expression);
                     self.state = State::Semicolon;
                 }
                 State::Semicolon => {
@@ -135,6 +170,7 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -143,7 +179,11 @@ mod tests {
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
     use crate::tree::statement::field::Statement as FieldStatement;
@@ -158,6 +198,37 @@ mod tests {
                 false,
                 Identifier::new(Location::test(1, 1), "data".to_owned()),
                 Type::new(Location::test(1, 7), TypeVariant::integer_unsigned(64)),
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_with_default() {
+        let input = r#"data: u64 = 42;"#;
+
+        let expected = Ok((
+            FieldStatement::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 1), "data".to_owned()),
+                Type::new(Location::test(1, 7), TypeVariant::integer_unsigned(64)),
+                Some(ExpressionTree::new(
+                    Location::test(1, 13),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 13),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
             ),
             None,
         ));
@@ -187,11 +258,29 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_semicolon() {
+    fn error_expected_equals_or_semicolon() {
         let input = r#"a: u64"#;
 
         let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
             Location::test(1, 7),
+            vec!["=", ";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"a: u64 = 42"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 12),
             vec![";"],
             Lexeme::Eof,
             None,
@@ -22,6 +22,9 @@ use crate::tree::statement::r#fn::builder::Builder as FnStatementBuilder;
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "function must have an identifier, e.g. `fn sum(...) { ... }`";
+/// The missing generic type parameter error hint.
+pub static HINT_EXPECTED_GENERIC: &str =
+    "generic type parameter must be an identifier, e.g. `fn max<T>(a: T, b: T) -> T { ... }`";
 /// The missing argument list error hint.
 pub static HINT_EXPECTED_ARGUMENT_LIST: &str =
     "function must have the argument list, e.g. `fn sum(a: u8, b: u8) { ... }`";
@@ -36,6 +39,12 @@ pub enum State {
     /// The `fn` has been parsed so far.
     Identifier,
     /// The `fn {identifier}` has been parsed so far.
+    LesserOrParenthesisLeft,
+    /// The `fn {identifier} <` has been parsed so far.
+    GenericOrGreater,
+    /// The `fn {identifier} < {generic}` has been parsed so far.
+    CommaOrGreater,
+    /// The `fn {identifier}` with optional `< {generics} >` has been parsed so far.
     ParenthesisLeft,
     /// The `fn {identifier} (` has been parsed so far.
     ArgumentBindingList,
@@ -78,6 +87,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// fn max<T>(a: T, b: T) -> T {
+    ///     if a > b { a } else { b }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -114,7 +129,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::LesserOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -125,6 +140,61 @@ impl Parser {
                         }
                     }
                 }
+                State::LesserOrParenthesisLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => self.state = State::GenericOrGreater,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::ParenthesisLeft;
+                        }
+                    }
+                }
+                State::GenericOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.push_generic(identifier);
+                            self.state = State::CommaOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => self.state = State::ParenthesisLeft,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_GENERIC),
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::GenericOrGreater,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => self.state = State::ParenthesisLeft,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::ParenthesisLeft => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -197,6 +267,8 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::Identifier as LexicalIdentifier;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -207,6 +279,9 @@ mod tests {
     use crate::error::ParsingError;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
@@ -224,6 +299,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -259,6 +335,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -284,6 +361,93 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generic() {
+        let input = r#"fn max<T>(a: T) -> T {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "max".to_owned()),
+                vec![Identifier::new(Location::test(1, 8), "T".to_owned())],
+                vec![Binding::new(
+                    Location::test(1, 11),
+                    BindingPattern::new(
+                        Location::test(1, 11),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 11), "a".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(Type::new(
+                        Location::test(1, 14),
+                        TypeVariant::alias(
+                            ExpressionTree::new(
+                                Location::test(1, 14),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(1, 14), "T".to_owned()),
+                                )),
+                            ),
+                            None,
+                        ),
+                    )),
+                )],
+                Some(Type::new(
+                    Location::test(1, 20),
+                    TypeVariant::alias(
+                        ExpressionTree::new(
+                            Location::test(1, 20),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 20), "T".to_owned()),
+                            )),
+                        ),
+                        None,
+                    ),
+                )),
+                BlockExpression::new(Location::test(1, 22), vec![], None),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic_multiple() {
+        let input = r#"fn f<T, U>() {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![
+                    Identifier::new(Location::test(1, 6), "T".to_owned()),
+                    Identifier::new(Location::test(1, 9), "U".to_owned()),
+                ],
+                vec![],
+                None,
+                BlockExpression::new(Location::test(1, 14), vec![], None),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"fn (a: u8) -> field {}"#;
@@ -319,6 +483,41 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_generic() {
+        let input = r#"fn max<u8>(a: u8) -> u8 {}"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 8),
+            Lexeme::Keyword(Keyword::IntegerUnsigned { bitlength: 8 }),
+            Some(super::HINT_EXPECTED_GENERIC),
+        )));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"fn max<T U>(a: T) -> T {}"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 10),
+            vec![",", ">"],
+            Lexeme::Identifier(LexicalIdentifier::new("U".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_comma_or_parenthesis_right() {
         let input = r#"fn sort(array: [u8; 100]] -> field {}"#;
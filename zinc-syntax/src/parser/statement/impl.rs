@@ -206,6 +206,7 @@ mod tests {
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
                 vec![ImplementationLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
+                    false,
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                     Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                     ExpressionTree::new(
@@ -246,6 +247,7 @@ mod tests {
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
+                        false,
                         Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                         Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -260,6 +262,7 @@ mod tests {
                     )),
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(5, 9),
+                        false,
                         Identifier::new(Location::test(5, 15), "ANOTHER".to_owned()),
                         Type::new(Location::test(5, 24), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -274,6 +277,7 @@ mod tests {
                     )),
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(7, 9),
+                        false,
                         Identifier::new(Location::test(7, 15), "YET_ANOTHER".to_owned()),
                         Type::new(Location::test(7, 28), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -313,6 +317,7 @@ mod tests {
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -359,6 +364,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -379,6 +385,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -399,6 +406,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -441,6 +449,7 @@ mod tests {
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
+                        false,
                         Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                         Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -458,6 +467,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 14),
                             BindingPattern::new(
@@ -508,6 +518,7 @@ mod tests {
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
+                        false,
                         Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
                         Type::new(Location::test(3, 22), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -522,6 +533,7 @@ mod tests {
                     )),
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(5, 9),
+                        false,
                         Identifier::new(Location::test(5, 15), "ANOTHER".to_owned()),
                         Type::new(Location::test(5, 24), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -536,6 +548,7 @@ mod tests {
                     )),
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(7, 9),
+                        false,
                         Identifier::new(Location::test(7, 15), "YET_ANOTHER".to_owned()),
                         Type::new(Location::test(7, 28), TypeVariant::integer_unsigned(64)),
                         ExpressionTree::new(
@@ -553,6 +566,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(9, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(9, 15),
                             BindingPattern::new(
@@ -573,6 +587,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -593,6 +608,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
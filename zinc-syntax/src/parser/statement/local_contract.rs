@@ -125,9 +125,15 @@ impl Parser {
                                 continue;
                             }
 
+                            let keyword_public = self.keyword_public.take();
                             return ConstStatementParser::default()
                                 .parse(stream.clone(), Some(token))
-                                .map(|(statement, next)| {
+                                .map(|(mut statement, next)| {
+                                    if let Some(token) = keyword_public {
+                                        statement.location = token.location;
+                                        statement.is_public = true;
+                                    }
+
                                     (ContractLocalStatement::Const(statement), next)
                                 });
                         }
@@ -187,20 +193,54 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::Location;
+    use zinc_lexical::StringLiteral as LexicalStringLiteral;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::string::Literal as StringLiteral;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
     use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
+    use crate::tree::statement::r#const::Statement as ConstStatement;
     use crate::tree::statement::r#fn::Statement as FnStatement;
 
+    #[test]
+    fn ok_const_public() {
+        let input = r#"pub const NAME: str = "MyToken";"#;
+
+        let expected = Ok((
+            ContractLocalStatement::Const(ConstStatement::new(
+                Location::test(1, 1),
+                true,
+                Identifier::new(Location::test(1, 11), "NAME".to_owned()),
+                Type::new(Location::test(1, 17), TypeVariant::string()),
+                ExpressionTree::new(
+                    Location::test(1, 23),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralString(
+                        StringLiteral::new(
+                            Location::test(1, 23),
+                            LexicalStringLiteral::new("MyToken".to_owned()),
+                        ),
+                    )),
+                ),
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_fn_public() {
         let input = r#"pub fn f(a: field) -> field {}"#;
@@ -211,6 +251,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -244,6 +285,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -277,6 +319,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -314,12 +357,14 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
                     Location::test(2, 1),
                     false,
                     Identifier::new(Location::test(2, 3), "test".to_owned()),
+                    vec![],
                 )],
             )),
             None,
@@ -346,6 +391,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
@@ -353,16 +399,19 @@ fn test() {}
                         Location::test(2, 1),
                         false,
                         Identifier::new(Location::test(2, 3), "test".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(3, 1),
                         false,
                         Identifier::new(Location::test(3, 3), "should_panic".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(4, 1),
                         false,
                         Identifier::new(Location::test(4, 3), "ignore".to_owned()),
+                        vec![],
                     ),
                 ],
             )),
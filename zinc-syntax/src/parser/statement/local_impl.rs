@@ -124,9 +124,15 @@ impl Parser {
                             {
                                 self.keyword_constant = Some(token);
                             } else {
+                                let keyword_public = self.keyword_public.take();
                                 return ConstStatementParser::default()
                                     .parse(stream.clone(), Some(token))
-                                    .map(|(statement, next)| {
+                                    .map(|(mut statement, next)| {
+                                        if let Some(token) = keyword_public {
+                                            statement.location = token.location;
+                                            statement.is_public = true;
+                                        }
+
                                         (ImplementationLocalStatement::Const(statement), next)
                                     });
                             }
@@ -182,6 +188,7 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Location;
     use zinc_lexical::TokenStream;
 
@@ -189,14 +196,50 @@ mod tests {
     use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
     use crate::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+    use crate::tree::statement::r#const::Statement as ConstStatement;
     use crate::tree::statement::r#fn::Statement as FnStatement;
 
+    #[test]
+    fn ok_const_public() {
+        let input = r#"pub const VALUE: u8 = 42;"#;
+
+        let expected = Ok((
+            ImplementationLocalStatement::Const(ConstStatement::new(
+                Location::test(1, 1),
+                true,
+                Identifier::new(Location::test(1, 11), "VALUE".to_owned()),
+                Type::new(
+                    Location::test(1, 18),
+                    TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                ),
+                ExpressionTree::new(
+                    Location::test(1, 23),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 23),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                ),
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_fn_public() {
         let input = r#"pub fn f(a: field) -> field {}"#;
@@ -207,6 +250,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -240,6 +284,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -273,6 +318,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -310,12 +356,14 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
                     Location::test(2, 1),
                     false,
                     Identifier::new(Location::test(2, 3), "test".to_owned()),
+                    vec![],
                 )],
             )),
             None,
@@ -342,6 +390,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
@@ -349,16 +398,19 @@ fn test() {}
                         Location::test(2, 1),
                         false,
                         Identifier::new(Location::test(2, 3), "test".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(3, 1),
                         false,
                         Identifier::new(Location::test(3, 3), "should_panic".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(4, 1),
                         false,
                         Identifier::new(Location::test(4, 3), "ignore".to_owned()),
+                        vec![],
                     ),
                 ],
             )),
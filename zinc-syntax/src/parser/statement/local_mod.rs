@@ -274,6 +274,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -307,6 +308,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -340,6 +342,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -377,12 +380,14 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
                     Location::test(2, 1),
                     false,
                     Identifier::new(Location::test(2, 3), "test".to_owned()),
+                    vec![],
                 )],
             )),
             None,
@@ -409,6 +414,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
@@ -416,16 +422,19 @@ fn test() {}
                         Location::test(2, 1),
                         false,
                         Identifier::new(Location::test(2, 3), "test".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(3, 1),
                         false,
                         Identifier::new(Location::test(3, 3), "should_panic".to_owned()),
+                        vec![],
                     ),
                     Attribute::new(
                         Location::test(4, 1),
                         false,
                         Identifier::new(Location::test(4, 3), "ignore".to_owned()),
+                        vec![],
                     ),
                 ],
             )),
@@ -14,6 +14,7 @@ use zinc_lexical::TokenStream;
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#struct::builder::Builder as StructStatementBuilder;
 use crate::tree::statement::r#struct::Statement as StructStatement;
@@ -22,6 +23,10 @@ use crate::tree::statement::r#struct::Statement as StructStatement;
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "structure type must have an identifier, e.g. `struct Data { ... }`";
 
+/// The newtype with several fields error hint.
+pub static HINT_EXPECTED_SINGLE_NEWTYPE_FIELD: &str =
+    "newtype structure must have exactly one field, e.g. `struct Wei(u248);`";
+
 ///
 /// The parser state.
 ///
@@ -32,11 +37,17 @@ pub enum State {
     /// The `struct` has been parsed so far.
     Identifier,
     /// The `struct {identifier}` has been parsed so far.
-    BracketCurlyLeftOrEnd,
+    BracketOrEnd,
     /// The `struct {identifier} {` has been parsed so far.
     FieldList,
     /// The `struct {identifier} { {fields}` has been parsed so far.
     BracketCurlyRight,
+    /// The `struct {identifier} (` has been parsed so far.
+    NewtypeType,
+    /// The `struct {identifier} ( {type}` has been parsed so far.
+    ParenthesisRight,
+    /// The `struct {identifier} ( {type} )` has been parsed so far.
+    Semicolon,
 }
 
 impl Default for State {
@@ -70,6 +81,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// or a newtype one:
+    ///
+    /// '
+    /// struct Wei(u248);
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -106,7 +123,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeftOrEnd;
+                            self.state = State::BracketOrEnd;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -117,7 +134,7 @@ impl Parser {
                         }
                     }
                 }
-                State::BracketCurlyLeftOrEnd => {
+                State::BracketOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
@@ -125,6 +142,12 @@ impl Parser {
                         } => {
                             self.state = State::FieldList;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.state = State::NewtypeType;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
@@ -146,6 +169,42 @@ impl Parser {
                         )),
                     };
                 }
+                State::NewtypeType => {
+                    let (r#type, next) =
+                        TypeParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_newtype(r#type);
+                    self.next = next;
+                    self.state = State::ParenthesisRight;
+                }
+                State::ParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![")"],
+                                lexeme,
+                                Some(HINT_EXPECTED_SINGLE_NEWTYPE_FIELD),
+                            )));
+                        }
+                    }
+                }
+                State::Semicolon => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of(location, vec![";"], lexeme, None),
+                        )),
+                    };
+                }
             }
         }
     }
@@ -277,6 +336,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_newtype() {
+        let input = r#"
+    struct Wei(u248);
+"#;
+
+        let expected = Ok((
+            StructStatement::new_newtype(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 12), "Wei".to_owned()),
+                Type::new(Location::test(2, 16), TypeVariant::integer_unsigned(248)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"struct { a: u8 };"#;
@@ -307,4 +386,36 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_newtype_expected_parenthesis_right() {
+        let input = r#"struct Wei(u248, u8);"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 16),
+            vec![")"],
+            Lexeme::Symbol(Symbol::Comma),
+            Some(super::HINT_EXPECTED_SINGLE_NEWTYPE_FIELD),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_newtype_expected_semicolon() {
+        let input = r#"struct Wei(u248)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 17),
+            vec![";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -32,7 +32,9 @@ pub enum State {
     /// The `use` has been parsed so far.
     Path,
     /// The `use {path}` has been parsed so far.
-    AsOrNext,
+    AsOrGlobOrNext,
+    /// The `use {path}::` has been parsed so far.
+    GlobAsterisk,
     /// The `use {path} as` has been parsed so far.
     AliasIdentifier,
     /// The `use {path} as {identifier}` has been parsed so far.
@@ -63,6 +65,7 @@ impl Parser {
     /// Parses a 'use' statement.
     ///
     /// 'use jabberwocky::gone;'
+    /// 'use jabberwocky::*;'
     ///
     pub fn parse(
         mut self,
@@ -97,9 +100,9 @@ impl Parser {
                         PathOperandParser::default().parse(stream.clone(), self.next.take())?;
                     self.builder.set_path(expression);
                     self.next = next;
-                    self.state = State::AsOrNext;
+                    self.state = State::AsOrGlobOrNext;
                 }
-                State::AsOrNext => {
+                State::AsOrGlobOrNext => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::As),
@@ -107,12 +110,37 @@ impl Parser {
                         } => {
                             self.state = State::AliasIdentifier;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleColon),
+                            ..
+                        } => {
+                            self.state = State::GlobAsterisk;
+                        }
                         token => {
                             self.next = Some(token);
                             self.state = State::Semicolon;
                         }
                     }
                 }
+                State::GlobAsterisk => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Asterisk),
+                            ..
+                        } => {
+                            self.builder.set_glob();
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["*"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::AliasIdentifier => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -150,6 +178,7 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::TokenStream;
@@ -198,6 +227,7 @@ mod tests {
                     )),
                 ),
                 None,
+                false,
             ),
             None,
         ));
@@ -244,6 +274,7 @@ mod tests {
                     Location::test(1, 31),
                     "MegaUltraNamespace".to_owned(),
                 )),
+                false,
             ),
             None,
         ));
@@ -253,6 +284,56 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_glob() {
+        let input = r#"use mega::ultra::*;"#;
+
+        let expected = Ok((
+            UseStatement::new(
+                Location::test(1, 1),
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Path),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 5), "mega".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 11), "ultra".to_owned()),
+                        )),
+                    )),
+                ),
+                None,
+                true,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_glob_with_alias() {
+        let input = r#"use mega::* as Mega;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 13),
+            vec![";"],
+            Lexeme::Keyword(Keyword::As),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_semicolon() {
         let input = r#"use jabberwocky"#;
@@ -112,7 +112,10 @@ impl Parser {
                 keyword @ Keyword::Bool
                 | keyword @ Keyword::IntegerSigned { .. }
                 | keyword @ Keyword::IntegerUnsigned { .. }
-                | keyword @ Keyword::Field => {
+                | keyword @ Keyword::FixedSigned { .. }
+                | keyword @ Keyword::FixedUnsigned { .. }
+                | keyword @ Keyword::Field
+                | keyword @ Keyword::Str => {
                     self.builder.set_location(location);
                     self.builder.set_keyword(keyword);
                     Ok((self.builder.finish(), None))
@@ -192,6 +195,20 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_fixed() {
+        let input = r#"fixed64x4"#;
+
+        let expected = Ok((
+            Type::new(Location::test(1, 1), TypeVariant::fixed(true, 64, 4)),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_field() {
         let input = r#"field"#;
@@ -203,6 +220,17 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_string() {
+        let input = r#"str"#;
+
+        let expected = Ok((Type::new(Location::test(1, 1), TypeVariant::string()), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_self_alias() {
         let input = r#"Self"#;
@@ -13,9 +13,14 @@ use zinc_lexical::TokenStream;
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::identifier::Identifier;
 use crate::tree::r#type::builder::Builder as TypeBuilder;
 use crate::tree::r#type::Type;
 
+/// The missing tuple element name error hint.
+pub static HINT_EXPECTED_ELEMENT_NAME: &str =
+    "either all or none of the tuple elements must be named, e.g. `(amount: u248, ok: bool)`";
+
 ///
 /// The parser state.
 ///
@@ -46,6 +51,8 @@ pub struct Parser {
     next: Option<Token>,
     /// The builder of the parsed type.
     builder: TypeBuilder,
+    /// Whether the tuple elements are named, which is decided by the first element.
+    is_named: Option<bool>,
 }
 
 impl Parser {
@@ -53,6 +60,7 @@ impl Parser {
     /// Parses a tuple type literal.
     ///
     /// '(u8, field, bool)'
+    /// '(amount: u248, ok: bool)'
     ///
     pub fn parse(
         mut self,
@@ -91,10 +99,56 @@ impl Parser {
                             self.builder.set_unit_if_empty();
                             return Ok((self.builder.finish(), self.next.take()));
                         }
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } if self.is_named == Some(true)
+                            || self.is_named.is_none()
+                                && matches!(
+                                    stream.borrow_mut().look_ahead(1)?,
+                                    Token {
+                                        lexeme: Lexeme::Symbol(Symbol::Colon),
+                                        ..
+                                    }
+                                ) =>
+                        {
+                            match stream.borrow_mut().next()? {
+                                Token {
+                                    lexeme: Lexeme::Symbol(Symbol::Colon),
+                                    ..
+                                } => {}
+                                Token { lexeme, location } => {
+                                    return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                        location,
+                                        vec![":"],
+                                        lexeme,
+                                        Some(HINT_EXPECTED_ELEMENT_NAME),
+                                    )))
+                                }
+                            }
+
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.is_named = Some(true);
+                            self.builder.push_tuple_element_name(identifier);
+
+                            let (element_type, next) =
+                                TypeParser::default().parse(stream.clone(), None)?;
+                            self.next = next;
+                            self.builder.push_tuple_element_type(element_type);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                        Token { lexeme, location } if self.is_named == Some(true) => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_ELEMENT_NAME),
+                            )))
+                        }
                         token => {
                             let (element_type, next) =
                                 TypeParser::default().parse(stream.clone(), Some(token))?;
                             self.next = next;
+                            self.is_named = Some(false);
                             self.builder.push_tuple_element_type(element_type);
                             self.state = State::CommaOrParenthesisRight;
                         }
@@ -128,6 +182,7 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -139,6 +194,7 @@ mod tests {
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
@@ -249,6 +305,98 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_named() {
+        let input = r#"(amount: u248, ok: bool)"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::named_tuple(vec![
+                    (
+                        Identifier::new(Location::test(1, 2), "amount".to_owned()),
+                        Type::new(
+                            Location::test(1, 10),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::INTEGER_MAX),
+                        ),
+                    ),
+                    (
+                        Identifier::new(Location::test(1, 16), "ok".to_owned()),
+                        Type::new(Location::test(1, 20), TypeVariant::boolean()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_named_nested() {
+        let input = r#"(inner: (value: field,), flag: bool)"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::named_tuple(vec![
+                    (
+                        Identifier::new(Location::test(1, 2), "inner".to_owned()),
+                        Type::new(
+                            Location::test(1, 9),
+                            TypeVariant::named_tuple(vec![(
+                                Identifier::new(Location::test(1, 10), "value".to_owned()),
+                                Type::new(Location::test(1, 17), TypeVariant::field()),
+                            )]),
+                        ),
+                    ),
+                    (
+                        Identifier::new(Location::test(1, 26), "flag".to_owned()),
+                        Type::new(Location::test(1, 32), TypeVariant::boolean()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_element_name() {
+        let input = r#"(amount: u248, bool)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 16),
+            Lexeme::Keyword(Keyword::Bool),
+            Some(super::HINT_EXPECTED_ELEMENT_NAME),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_colon() {
+        let input = r#"(amount: u248, ok)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 18),
+            vec![":"],
+            Lexeme::Symbol(Symbol::ParenthesisRight),
+            Some(super::HINT_EXPECTED_ELEMENT_NAME),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_comma_or_parenthesis_right() {
         let input = r#"(field;)"#;
@@ -13,16 +13,18 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::r#type::Type;
 use crate::tree::variant::builder::Builder as VariantBuilder;
+use crate::tree::variant::payload::Payload;
 use crate::tree::variant::Variant;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "enumeration variant must have an identifier, e.g. `Value = 42`";
-/// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
 
 ///
 /// The variant parser.
@@ -40,6 +42,9 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'A'
+    /// 'A(u8, field)'
+    /// 'A { a: u8, b: field }'
     ///
     pub fn parse(
         mut self,
@@ -71,13 +76,33 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::Equals),
                 ..
             } => {}
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_value(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_VALUE),
-                )));
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => {
+                let types = self.tuple_payload(stream)?;
+                self.builder.set_payload(Payload::Tuple(types));
+                return Ok((self.builder.finish(), None));
+            }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => {
+                let (fields, next) = FieldListParser::default().parse(stream.clone(), None)?;
+                return match crate::parser::take_or_next(next, stream)? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                        ..
+                    } => {
+                        self.builder.set_payload(Payload::Structure(fields));
+                        Ok((self.builder.finish(), None))
+                    }
+                    Token { lexeme, location } => Err(ParsingError::Syntax(
+                        SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
+                    )),
+                };
             }
+            token => return Ok((self.builder.finish(), Some(token))),
         }
 
         match crate::parser::take_or_next(self.next.take(), stream)? {
@@ -94,6 +119,52 @@ impl Parser {
             )),
         }
     }
+
+    ///
+    /// Parses the tuple-like payload types after the opening parenthesis.
+    ///
+    /// 'u8, field)'
+    ///
+    fn tuple_payload(
+        &mut self,
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<Vec<Type>, ParsingError> {
+        let mut types = Vec::new();
+
+        loop {
+            match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => return Ok(types),
+                token => {
+                    let (r#type, next) =
+                        TypeParser::default().parse(stream.clone(), Some(token))?;
+                    self.next = next;
+                    types.push(r#type);
+                }
+            }
+
+            match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => continue,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => return Ok(types),
+                Token { lexeme, location } => {
+                    return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", ")"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -102,13 +173,18 @@ mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::field::Field;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::variant::payload::Payload;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -133,13 +209,81 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_value() {
+    fn ok_unit() {
         let input = r#"A"#;
 
-        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
-            Location::test(1, 2),
-            Lexeme::Eof,
-            Some(super::HINT_EXPECTED_VALUE),
+        let expected = Ok((
+            Variant::new_unit(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 2))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_payload_tuple() {
+        let input = r#"A(u8, field)"#;
+
+        let expected = Ok((
+            Variant::new_payload(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                Payload::Tuple(vec![
+                    Type::new(Location::test(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::test(1, 7), TypeVariant::field()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_payload_structure() {
+        let input = r#"A { a: u8, b: field }"#;
+
+        let expected = Ok((
+            Variant::new_payload(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                Payload::Structure(vec![
+                    Field::new(
+                        Location::test(1, 5),
+                        Identifier::new(Location::test(1, 5), "a".to_owned()),
+                        Type::new(Location::test(1, 8), TypeVariant::integer_unsigned(8)),
+                    ),
+                    Field::new(
+                        Location::test(1, 12),
+                        Identifier::new(Location::test(1, 12), "b".to_owned()),
+                        Type::new(Location::test(1, 15), TypeVariant::field()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"A(u8 value)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec![",", ")"],
+            Lexeme::Identifier(LexicalIdentifier::new("value".to_owned())),
+            None,
         )));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
@@ -0,0 +1,35 @@
+//!
+//! The attribute argument.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::literal::string::Literal as StringLiteral;
+
+///
+/// The attribute argument, e.g. `token = "ETH"` in `#[payable(token = "ETH")]`,
+/// or `owner` in `#[only(owner)]`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argument {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The argument name.
+    pub identifier: Identifier,
+    /// The argument value, which is absent if only the argument name is specified.
+    pub value: Option<StringLiteral>,
+}
+
+impl Argument {
+    ///
+    /// Creates the attribute argument value.
+    ///
+    pub fn new(location: Location, identifier: Identifier, value: Option<StringLiteral>) -> Self {
+        Self {
+            location,
+            identifier,
+            value,
+        }
+    }
+}
@@ -2,12 +2,15 @@
 //! The attribute.
 //!
 
+pub mod argument;
 pub mod builder;
 
 use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 
+use self::argument::Argument;
+
 ///
 /// The attribute.
 ///
@@ -19,17 +22,25 @@ pub struct Attribute {
     pub is_inner: bool,
     /// The attribute identifier.
     pub identifier: Identifier,
+    /// The optional attribute arguments, e.g. `token = "ETH"` in `#[payable(token = "ETH")]`.
+    pub arguments: Vec<Argument>,
 }
 
 impl Attribute {
     ///
     /// Creates the attribute value.
     ///
-    pub fn new(location: Location, is_inner: bool, identifier: Identifier) -> Self {
+    pub fn new(
+        location: Location,
+        is_inner: bool,
+        identifier: Identifier,
+        arguments: Vec<Argument>,
+    ) -> Self {
         Self {
             location,
             is_inner,
             identifier,
+            arguments,
         }
     }
 }
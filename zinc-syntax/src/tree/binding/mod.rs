@@ -4,6 +4,7 @@
 
 pub mod builder;
 
+use crate::tree::attribute::Attribute;
 use crate::tree::pattern_binding::Pattern as BindingPattern;
 use crate::tree::r#type::Type;
 
@@ -20,6 +21,8 @@ pub struct Binding {
     pub pattern: BindingPattern,
     /// The optional binding type.
     pub r#type: Option<Type>,
+    /// The binding outer attributes, e.g. `#[public]` on a contract method argument.
+    pub attributes: Vec<Attribute>,
 }
 
 impl Binding {
@@ -31,6 +34,15 @@ impl Binding {
             location,
             pattern,
             r#type,
+            attributes: vec![],
         }
     }
+
+    ///
+    /// Sets the binding outer attributes.
+    ///
+    pub fn with_attributes(mut self, attributes: Vec<Attribute>) -> Self {
+        self.attributes = attributes;
+        self
+    }
 }
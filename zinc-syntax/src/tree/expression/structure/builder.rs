@@ -5,6 +5,8 @@
 use zinc_lexical::Location;
 
 use crate::tree::expression::structure::Expression as StructureExpression;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 
@@ -17,6 +19,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The structure expression inner fields.
     fields: Vec<(Identifier, Option<ExpressionTree>)>,
+    /// The structure update base expression.
+    base: Option<ExpressionTree>,
 }
 
 impl Builder {
@@ -50,6 +54,35 @@ impl Builder {
             .1 = Some(value);
     }
 
+    ///
+    /// Initializes the last field with the variable of the same name, e.g. `{ a }` as `{ a: a }`.
+    ///
+    pub fn set_field_shorthand(&mut self) {
+        let identifier = self
+            .fields
+            .last()
+            .map(|(identifier, _expression)| identifier.to_owned())
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "field identifier"
+                )
+            });
+
+        self.set_field_expression(ExpressionTree::new(
+            identifier.location,
+            ExpressionTreeNode::operand(ExpressionOperand::Identifier(identifier)),
+        ));
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_base(&mut self, value: ExpressionTree) {
+        self.base = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -80,6 +113,7 @@ impl Builder {
                     )
                 })
                 .collect::<Vec<(Identifier, ExpressionTree)>>(),
+            self.base,
         )
     }
 }
@@ -6,6 +6,8 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 
@@ -18,13 +20,35 @@ pub struct Expression {
     pub location: Location,
     /// The structure expression inner fields.
     pub fields: Vec<(Identifier, ExpressionTree)>,
+    /// The structure update base expression, which is specified after `..`.
+    pub base: Option<ExpressionTree>,
 }
 
 impl Expression {
     ///
     /// Creates a structure expression.
     ///
-    pub fn new(location: Location, fields: Vec<(Identifier, ExpressionTree)>) -> Self {
-        Self { location, fields }
+    pub fn new(
+        location: Location,
+        fields: Vec<(Identifier, ExpressionTree)>,
+        base: Option<ExpressionTree>,
+    ) -> Self {
+        Self {
+            location,
+            fields,
+            base,
+        }
+    }
+
+    ///
+    /// Checks whether the field is initialized with the shorthand, e.g. `{ a }` instead of `{ a: a }`.
+    ///
+    pub fn is_shorthand(identifier: &Identifier, expression: &ExpressionTree) -> bool {
+        match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref operand)) => {
+                operand.location == identifier.location && operand.name == identifier.name
+            }
+            _ => false,
+        }
     }
 }
@@ -7,6 +7,7 @@ pub mod node;
 
 use zinc_lexical::Location;
 
+use self::node::operand::Operand;
 use self::node::Node;
 
 ///
@@ -15,10 +16,12 @@ use self::node::Node;
 /// The highest operator precedence nodes are located deeper within a tree,
 /// whereas the lowest ones are located at the top.
 ///
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Tree {
     /// The location of the syntax construction.
     pub location: Location,
+    /// The end location of the last token of the whole expression.
+    pub end: Location,
     /// The value of the node, which is either an operand or operator.
     pub value: Box<Node>,
     /// The left child of the node.
@@ -34,6 +37,7 @@ impl Tree {
     pub fn new(location: Location, value: Node) -> Self {
         Self {
             location,
+            end: location,
             value: Box::new(value),
             left: None,
             right: None,
@@ -43,17 +47,53 @@ impl Tree {
     ///
     /// Initializes a tree with left and right leaves.
     ///
+    /// The tree end is set to the furthest end of the leaves.
+    ///
     pub fn new_with_leaves(
         location: Location,
         value: Node,
         left: Option<Self>,
         right: Option<Self>,
     ) -> Self {
-        Self {
+        let tree = Self {
             location,
+            end: location,
             value: Box::new(value),
             left: left.map(Box::new),
             right: right.map(Box::new),
+        };
+
+        let leaf_ends: Vec<Location> = tree
+            .left
+            .iter()
+            .chain(tree.right.iter())
+            .map(|leaf| leaf.end)
+            .collect();
+        leaf_ends
+            .into_iter()
+            .fold(tree, |tree, leaf_end| tree.with_end(leaf_end))
+    }
+
+    ///
+    /// Sets the end location of the last token of the whole expression.
+    ///
+    pub fn with_end(mut self, end: Location) -> Self {
+        if end > self.end {
+            self.end = end;
+        }
+        self
+    }
+
+    ///
+    /// Returns the location of the first token of the whole expression.
+    ///
+    /// The binary and postfix operator nodes are located at their operator tokens,
+    /// so the start is taken from the leftmost leaf.
+    ///
+    pub fn start(&self) -> Location {
+        match self.left.as_ref() {
+            Some(left) if left.start() < self.location => left.start(),
+            _ => self.location,
         }
     }
 
@@ -68,4 +108,59 @@ impl Tree {
             _ => false,
         }
     }
+
+    ///
+    /// Checks if the tree is structurally equal to `other`, ignoring the locations.
+    ///
+    /// Only operators, identifiers, tuple indexes, and boolean and integer literals are compared,
+    /// so trees containing any other operand are never considered equivalent.
+    ///
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        let is_node_equivalent = match (self.value.as_ref(), other.value.as_ref()) {
+            (Node::Operator(operator_1), Node::Operator(operator_2)) => operator_1 == operator_2,
+            (Node::Operand(operand_1), Node::Operand(operand_2)) => match (operand_1, operand_2) {
+                (Operand::Identifier(identifier_1), Operand::Identifier(identifier_2)) => {
+                    identifier_1.name == identifier_2.name
+                }
+                (Operand::TupleIndex(index_1), Operand::TupleIndex(index_2)) => {
+                    index_1.literal.inner == index_2.literal.inner
+                }
+                (Operand::LiteralInteger(literal_1), Operand::LiteralInteger(literal_2)) => {
+                    literal_1.inner == literal_2.inner
+                }
+                (Operand::LiteralBoolean(literal_1), Operand::LiteralBoolean(literal_2)) => {
+                    literal_1.inner == literal_2.inner
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+
+        let is_left_equivalent = match (self.left.as_ref(), other.left.as_ref()) {
+            (Some(left_1), Some(left_2)) => left_1.is_equivalent(left_2),
+            (None, None) => true,
+            _ => false,
+        };
+
+        let is_right_equivalent = match (self.right.as_ref(), other.right.as_ref()) {
+            (Some(right_1), Some(right_2)) => right_1.is_equivalent(right_2),
+            (None, None) => true,
+            _ => false,
+        };
+
+        is_node_equivalent && is_left_equivalent && is_right_equivalent
+    }
+}
+
+impl PartialEq for Tree {
+    ///
+    /// The end location is ignored, since it is derived from the tokens following the
+    /// node location and the leaves.
+    ///
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.value == other.value
+            && self.left == other.left
+            && self.right == other.right
+    }
 }
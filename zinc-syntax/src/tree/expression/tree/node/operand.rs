@@ -37,6 +37,8 @@ pub enum Operand {
     Identifier(Identifier),
     /// A syntax type, e.g. a keyword, array, tuple, etc.
     Type(Type),
+    /// A generic type arguments list, e.g. `::<u8, field>`.
+    Generics(Vec<Type>),
     /// An array literal expression.
     Array(ArrayExpression),
     /// A tuple literal expression.
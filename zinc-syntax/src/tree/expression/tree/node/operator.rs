@@ -101,7 +101,31 @@ pub enum Operator {
 
     /// The `::` operator.
     Path,
+    /// The generic type arguments `::<...>` quasi-operator.
+    Generics,
 
     /// The structure literal `{ ... }` quasi-operator.
     Structure,
 }
+
+impl Operator {
+    ///
+    /// Whether the operator is the ordinar or a shortcut assignment one.
+    ///
+    pub fn is_assignment(&self) -> bool {
+        matches!(
+            self,
+            Self::Assignment
+                | Self::AssignmentBitwiseOr
+                | Self::AssignmentBitwiseXor
+                | Self::AssignmentBitwiseAnd
+                | Self::AssignmentBitwiseShiftLeft
+                | Self::AssignmentBitwiseShiftRight
+                | Self::AssignmentAddition
+                | Self::AssignmentSubtraction
+                | Self::AssignmentMultiplication
+                | Self::AssignmentDivision
+                | Self::AssignmentRemainder
+        )
+    }
+}
@@ -29,6 +29,14 @@ pub struct Builder {
     binding: Option<Identifier>,
     /// The path builder variant, which means that the pattern is a path expression.
     path_builder: ExpressionTreeBuilder,
+    /// The tuple elements, which means that the pattern is a tuple.
+    tuple_elements: Option<Vec<MatchPattern>>,
+    /// The structure fields, which means that the pattern is a structure with the `path_builder` path.
+    structure_fields: Option<Vec<(Identifier, MatchPattern)>>,
+    /// If the structure pattern has the `..` rest pattern.
+    has_structure_rest: bool,
+    /// The payload elements, which means that the pattern is an enumeration variant with the `path_builder` path.
+    payload_elements: Option<Vec<MatchPattern>>,
     /// If the pattern variant is a wildcard.
     is_wildcard: bool,
 }
@@ -78,6 +86,81 @@ impl Builder {
         self.path_builder.eat(tree);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_tuple(&mut self) {
+        self.tuple_elements = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    /// # Panics
+    /// If the tuple has not been set.
+    ///
+    pub fn push_tuple_element(&mut self, value: MatchPattern) {
+        self.tuple_elements
+            .as_mut()
+            .unwrap_or_else(|| panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "tuple"))
+            .push(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_structure(&mut self) {
+        self.move_binding_to_path();
+        self.structure_fields = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    /// # Panics
+    /// If the structure has not been set.
+    ///
+    pub fn push_structure_field(&mut self, identifier: Identifier, pattern: MatchPattern) {
+        self.structure_fields
+            .as_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "structure"
+                )
+            })
+            .push((identifier, pattern));
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_structure_rest(&mut self) {
+        self.has_structure_rest = true;
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_payload(&mut self) {
+        self.move_binding_to_path();
+        self.payload_elements = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    /// # Panics
+    /// If the payload has not been set.
+    ///
+    pub fn push_payload_element(&mut self, value: MatchPattern) {
+        self.payload_elements
+            .as_mut()
+            .unwrap_or_else(|| panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "payload"))
+            .push(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -106,6 +189,19 @@ impl Builder {
             MatchPatternVariant::BooleanLiteral(boolean_literal)
         } else if let Some(integer_literal) = self.integer_literal.take() {
             MatchPatternVariant::IntegerLiteral(integer_literal)
+        } else if let Some(elements) = self.tuple_elements.take() {
+            MatchPatternVariant::Tuple(elements)
+        } else if let Some(fields) = self.structure_fields.take() {
+            MatchPatternVariant::Structure {
+                path: self.path_builder.finish(),
+                fields,
+                has_rest: self.has_structure_rest,
+            }
+        } else if let Some(elements) = self.payload_elements.take() {
+            MatchPatternVariant::Payload {
+                path: self.path_builder.finish(),
+                elements,
+            }
         } else if let Some(identifier) = self.binding.take() {
             MatchPatternVariant::Binding(identifier)
         } else if !self.path_builder.is_empty() {
@@ -114,7 +210,7 @@ impl Builder {
             panic!(
                 "{}{}",
                 zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | binding | path | tuple | structure | payload | wildcard"
             );
         };
 
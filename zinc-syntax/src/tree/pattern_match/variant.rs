@@ -6,6 +6,7 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::Pattern;
 
 ///
 /// The match pattern variant.
@@ -20,6 +21,25 @@ pub enum Variant {
     Binding(Identifier),
     /// An expression path refutable pattern, usually points to a constant or enumeration variant.
     Path(ExpressionTree),
+    /// A tuple pattern, like `(0, b)`, which is refutable if any of its elements is.
+    Tuple(Vec<Pattern>),
+    /// A structure pattern, like `Point { x: 0, y }`, which is refutable if any of its fields is.
+    Structure {
+        /// The structure type path expression.
+        path: ExpressionTree,
+        /// The field patterns. The shorthand `{ y }` form is stored as `{ y: y }`.
+        fields: Vec<(Identifier, Pattern)>,
+        /// If the `..` rest pattern is specified, so the omitted fields are ignored.
+        has_rest: bool,
+    },
+    /// An enumeration variant pattern with a payload, like `Maybe::Some(value)`, which is
+    /// always refutable.
+    Payload {
+        /// The enumeration variant path expression.
+        path: ExpressionTree,
+        /// The payload element patterns.
+        elements: Vec<Pattern>,
+    },
     /// A wildcard irrefutable pattern.
     Wildcard,
 }
@@ -53,6 +73,35 @@ impl Variant {
         Self::Path(expression)
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple(elements: Vec<Pattern>) -> Self {
+        Self::Tuple(elements)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_structure(
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Pattern)>,
+        has_rest: bool,
+    ) -> Self {
+        Self::Structure {
+            path,
+            fields,
+            has_rest,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_payload(path: ExpressionTree, elements: Vec<Pattern>) -> Self {
+        Self::Payload { path, elements }
+    }
+
     ///
     /// A shortcut constructor.
     ///
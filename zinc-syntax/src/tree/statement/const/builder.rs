@@ -68,6 +68,7 @@ impl Builder {
                     "location"
                 )
             }),
+            false,
             self.identifier.take().unwrap_or_else(|| {
                 panic!(
                     "{}{}",
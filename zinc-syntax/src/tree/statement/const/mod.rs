@@ -17,6 +17,8 @@ use crate::tree::r#type::Type;
 pub struct Statement {
     /// The location of the syntax construction.
     pub location: Location,
+    /// If the constant is public. Only contract constants can be declared with `pub`.
+    pub is_public: bool,
     /// The constant identifier.
     pub identifier: Identifier,
     /// The constant type.
@@ -31,12 +33,14 @@ impl Statement {
     ///
     pub fn new(
         location: Location,
+        is_public: bool,
         identifier: Identifier,
         r#type: Type,
         expression: ExpressionTree,
     ) -> Self {
         Self {
             location,
+            is_public,
             identifier,
             r#type,
             expression,
@@ -6,6 +6,7 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 
@@ -22,18 +23,27 @@ pub struct Statement {
     pub identifier: Identifier,
     /// The contract storage field type.
     pub r#type: Type,
+    /// The contract storage field default value, e.g. `= 30` in `pub fee_bps: u16 = 30;`.
+    pub default: Option<ExpressionTree>,
 }
 
 impl Statement {
     ///
     /// Creates a contract storage `field` statement.
     ///
-    pub fn new(location: Location, is_public: bool, identifier: Identifier, r#type: Type) -> Self {
+    pub fn new(
+        location: Location,
+        is_public: bool,
+        identifier: Identifier,
+        r#type: Type,
+        default: Option<ExpressionTree>,
+    ) -> Self {
         Self {
             location,
             is_public,
             identifier,
             r#type,
+            default,
         }
     }
 }
@@ -24,6 +24,8 @@ pub struct Builder {
     is_constant: bool,
     /// The function identifier.
     identifier: Option<Identifier>,
+    /// The function generic type parameters.
+    generics: Vec<Identifier>,
     /// The function argument bindings.
     argument_bindings: Vec<Binding>,
     /// The optional function return type, which is `()` if not specified.
@@ -63,6 +65,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Pushes a generic type parameter to the parameter list.
+    ///
+    pub fn push_generic(&mut self, value: Identifier) {
+        self.generics.push(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -115,6 +124,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.generics,
             self.argument_bindings,
             self.return_type.take(),
             self.body.take().unwrap_or_else(|| {
@@ -25,6 +25,8 @@ pub struct Statement {
     pub is_constant: bool,
     /// The function identifier.
     pub identifier: Identifier,
+    /// The function generic type parameters list, which is empty for non-generic functions.
+    pub generics: Vec<Identifier>,
     /// The function argument bindings list.
     pub argument_bindings: Vec<Binding>,
     /// The optional function return type, which is `()` if not specified.
@@ -45,6 +47,7 @@ impl Statement {
         is_public: bool,
         is_constant: bool,
         identifier: Identifier,
+        generics: Vec<Identifier>,
         argument_bindings: Vec<Binding>,
         return_type: Option<Type>,
         body: BlockExpression,
@@ -55,10 +58,18 @@ impl Statement {
             is_public,
             is_constant,
             identifier,
+            generics,
             argument_bindings,
             return_type,
             body,
             attributes,
         }
     }
+
+    ///
+    /// Whether the function has generic type parameters.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.generics.is_empty()
+    }
 }
@@ -6,6 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::field::Field;
 use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
 use crate::tree::statement::r#struct::Statement as StructStatement;
 
 ///
@@ -19,6 +20,8 @@ pub struct Builder {
     identifier: Option<Identifier>,
     /// The structure type fields.
     fields: Vec<Field>,
+    /// The newtype structure inner type.
+    newtype: Option<Type>,
 }
 
 impl Builder {
@@ -43,6 +46,13 @@ impl Builder {
         self.fields = value;
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_newtype(&mut self, value: Type) {
+        self.newtype = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -50,22 +60,24 @@ impl Builder {
     /// If some of the required items has not been set.
     ///
     pub fn finish(mut self) -> StructStatement {
-        StructStatement::new(
-            self.location.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "location"
-                )
-            }),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "identifier"
-                )
-            }),
-            self.fields,
-        )
+        let location = self.location.take().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "location"
+            )
+        });
+        let identifier = self.identifier.take().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "identifier"
+            )
+        });
+
+        match self.newtype.take() {
+            Some(r#type) => StructStatement::new_newtype(location, identifier, r#type),
+            None => StructStatement::new(location, identifier, self.fields),
+        }
     }
 }
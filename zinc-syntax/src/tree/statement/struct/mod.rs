@@ -8,6 +8,7 @@ use zinc_lexical::Location;
 
 use crate::tree::field::Field;
 use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
 
 ///
 /// The `struct` statement.
@@ -20,9 +21,14 @@ pub struct Statement {
     pub identifier: Identifier,
     /// The structure type fields.
     pub fields: Vec<Field>,
+    /// Whether the structure is a newtype like `struct Wei(u248);`, whose only field is `0`.
+    pub is_newtype: bool,
 }
 
 impl Statement {
+    /// The newtype field name, which is accessed with the tuple index syntax, e.g. `amount.0`.
+    pub const NEWTYPE_FIELD: &'static str = "0";
+
     ///
     /// Creates a `struct` statement.
     ///
@@ -31,6 +37,25 @@ impl Statement {
             location,
             identifier,
             fields,
+            is_newtype: false,
+        }
+    }
+
+    ///
+    /// Creates a newtype `struct` statement, which wraps a single unnamed field of `r#type`.
+    ///
+    pub fn new_newtype(location: Location, identifier: Identifier, r#type: Type) -> Self {
+        let field = Field::new(
+            r#type.location,
+            Identifier::new(r#type.location, Self::NEWTYPE_FIELD.to_owned()),
+            r#type,
+        );
+
+        Self {
+            location,
+            identifier,
+            fields: vec![field],
+            is_newtype: true,
         }
     }
 }
@@ -19,6 +19,8 @@ pub struct Builder {
     path: Option<ExpressionTree>,
     /// The imported item optional alias.
     alias_identifier: Option<Identifier>,
+    /// Whether the statement is a glob import.
+    is_glob: bool,
 }
 
 impl Builder {
@@ -43,6 +45,13 @@ impl Builder {
         self.alias_identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_glob(&mut self) {
+        self.is_glob = true;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -62,6 +71,7 @@ impl Builder {
                 panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "path")
             }),
             self.alias_identifier.take(),
+            self.is_glob,
         )
     }
 }
@@ -20,6 +20,8 @@ pub struct Statement {
     pub path: ExpressionTree,
     /// The imported item optional alias.
     pub alias_identifier: Option<Identifier>,
+    /// Whether the statement imports all the items of the `path` namespace, e.g. `use module::*;`.
+    pub is_glob: bool,
 }
 
 impl Statement {
@@ -30,11 +32,13 @@ impl Statement {
         location: Location,
         path: ExpressionTree,
         alias_identifier: Option<Identifier>,
+        is_glob: bool,
     ) -> Self {
         Self {
             location,
             path,
             alias_identifier,
+            is_glob,
         }
     }
 }
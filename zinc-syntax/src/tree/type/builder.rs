@@ -6,6 +6,7 @@ use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::identifier::Identifier;
 use crate::tree::r#type::variant::Variant as TypeVariant;
 use crate::tree::r#type::Type;
 
@@ -26,6 +27,8 @@ pub struct Builder {
     array_size: Option<ExpressionTree>,
     /// The tuple elements, which means that the type is a tuple.
     tuple_element_types: Vec<Type>,
+    /// The tuple element names, which means that the tuple is named.
+    tuple_element_names: Vec<Identifier>,
     /// The path expression, which means that the type is an alias.
     path_expression: Option<ExpressionTree>,
     /// The optional generic type arguments.
@@ -84,6 +87,13 @@ impl Builder {
         self.tuple_element_types.push(value)
     }
 
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_tuple_element_name(&mut self, value: Identifier) {
+        self.tuple_element_names.push(value)
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -120,7 +130,16 @@ impl Builder {
                 Keyword::Bool => TypeVariant::boolean(),
                 Keyword::IntegerUnsigned { bitlength } => TypeVariant::integer_unsigned(bitlength),
                 Keyword::IntegerSigned { bitlength } => TypeVariant::integer_signed(bitlength),
+                Keyword::FixedUnsigned {
+                    bitlength,
+                    decimals,
+                } => TypeVariant::fixed(false, bitlength, decimals),
+                Keyword::FixedSigned {
+                    bitlength,
+                    decimals,
+                } => TypeVariant::fixed(true, bitlength, decimals),
                 Keyword::Field => TypeVariant::field(),
+                Keyword::Str => TypeVariant::string(),
                 keyword => panic!("{}{}", self::BUILDER_TYPE_INVALID_KEYWORD, keyword),
             }
         } else if let Some(array_type) = self.array_type.take() {
@@ -134,6 +153,13 @@ impl Builder {
                     )
                 }),
             )
+        } else if !self.tuple_element_names.is_empty() {
+            TypeVariant::named_tuple(
+                self.tuple_element_names
+                    .into_iter()
+                    .zip(self.tuple_element_types)
+                    .collect(),
+            )
         } else if !self.tuple_element_types.is_empty() {
             TypeVariant::tuple(self.tuple_element_types)
         } else if self.is_unit {
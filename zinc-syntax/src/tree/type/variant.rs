@@ -3,6 +3,7 @@
 //!
 
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 
 ///
@@ -28,8 +29,19 @@ pub enum Variant {
         /// The signed integer bitlength.
         bitlength: usize,
     },
+    /// `ufixed{N}x{D}` or `fixed{N}x{D}` in the source code.
+    Fixed {
+        /// If the underlying integer is signed.
+        is_signed: bool,
+        /// The underlying integer bitlength.
+        bitlength: usize,
+        /// The number of decimal fractional digits.
+        decimals: usize,
+    },
     /// `field` in the source code.
     Field,
+    /// `str` in the source code.
+    String,
     /// `[{type}; {expression}]` in the source code.
     Array {
         /// The array element type.
@@ -42,6 +54,11 @@ pub enum Variant {
         /// The tuple element types.
         inners: Vec<Type>,
     },
+    /// `({name1}: {type1}, {name2}: {type2}, ...)` in the source code.
+    NamedTuple {
+        /// The tuple element names and types.
+        fields: Vec<(Identifier, Type)>,
+    },
     /// `{namespace1}::{namespace2}::...::{type}<generic1, generic2, ...>` in the source code.
     Alias {
         /// The path expression, which points to an aliased type.
@@ -91,6 +108,17 @@ impl Variant {
         Self::IntegerSigned { bitlength }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn fixed(is_signed: bool, bitlength: usize, decimals: usize) -> Self {
+        Self::Fixed {
+            is_signed,
+            bitlength,
+            decimals,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -98,6 +126,13 @@ impl Variant {
         Self::Field
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn string() -> Self {
+        Self::String
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -115,6 +150,13 @@ impl Variant {
         Self::Tuple { inners }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn named_tuple(fields: Vec<(Identifier, Type)>) -> Self {
+        Self::NamedTuple { fields }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -6,6 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::variant::payload::Payload;
 use crate::tree::variant::Variant;
 
 ///
@@ -19,6 +20,8 @@ pub struct Builder {
     identifier: Option<Identifier>,
     /// The enumeration variant integer value.
     literal: Option<IntegerLiteral>,
+    /// The enumeration variant payload.
+    payload: Option<Payload>,
 }
 
 impl Builder {
@@ -43,6 +46,13 @@ impl Builder {
         self.literal = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_payload(&mut self, value: Payload) {
+        self.payload = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -50,24 +60,25 @@ impl Builder {
     /// If some of the required items has not been set.
     ///
     pub fn finish(&mut self) -> Variant {
-        Variant::new(
-            self.location.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "location"
-                )
-            }),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "identifier"
-                )
-            }),
-            self.literal.take().unwrap_or_else(|| {
-                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "literal")
-            }),
-        )
+        let location = self.location.take().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "location"
+            )
+        });
+        let identifier = self.identifier.take().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "identifier"
+            )
+        });
+
+        match (self.literal.take(), self.payload.take()) {
+            (Some(literal), _) => Variant::new(location, identifier, literal),
+            (None, Some(payload)) => Variant::new_payload(location, identifier, payload),
+            (None, None) => Variant::new_unit(location, identifier),
+        }
     }
 }
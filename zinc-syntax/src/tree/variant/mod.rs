@@ -3,12 +3,15 @@
 //!
 
 pub mod builder;
+pub mod payload;
 
 use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::payload::Payload;
+
 ///
 /// The enumeration variant.
 ///
@@ -18,19 +21,46 @@ pub struct Variant {
     pub location: Location,
     /// The enumeration variant identifier.
     pub identifier: Identifier,
-    /// The enumeration variant integer value.
-    pub literal: IntegerLiteral,
+    /// The enumeration variant integer value. If omitted, the variant index is used.
+    pub literal: Option<IntegerLiteral>,
+    /// The enumeration variant payload, e.g. `Burn(u248)`.
+    pub payload: Option<Payload>,
 }
 
 impl Variant {
     ///
-    /// Creates an enumeration variant.
+    /// Creates an enumeration variant with an explicit value, e.g. `A = 1`.
     ///
     pub fn new(location: Location, identifier: Identifier, literal: IntegerLiteral) -> Self {
         Self {
             location,
             identifier,
-            literal,
+            literal: Some(literal),
+            payload: None,
+        }
+    }
+
+    ///
+    /// Creates an enumeration variant without a value and payload, e.g. `A`.
+    ///
+    pub fn new_unit(location: Location, identifier: Identifier) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            payload: None,
+        }
+    }
+
+    ///
+    /// Creates an enumeration variant with a payload, e.g. `Burn(u248)`.
+    ///
+    pub fn new_payload(location: Location, identifier: Identifier, payload: Payload) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            payload: Some(payload),
         }
     }
 }
@@ -0,0 +1,17 @@
+//!
+//! The enumeration variant payload.
+//!
+
+use crate::tree::field::Field;
+use crate::tree::r#type::Type;
+
+///
+/// The enumeration variant payload.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// The tuple-like payload, e.g. `Burn(u248)`.
+    Tuple(Vec<Type>),
+    /// The structure-like payload, e.g. `Transfer { to: u160, amount: u248 }`.
+    Structure(Vec<Field>),
+}
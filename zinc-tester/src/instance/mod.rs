@@ -64,7 +64,9 @@ impl Instance {
                 IntermediateApplication::new(scope.borrow().get_intermediate())
                     .write_all(state.clone());
 
-                Ok(State::unwrap_rc(state).into_application(true))
+                State::unwrap_rc(state)
+                    .into_application(true, true)
+                    .map_err(|error| Error::Compiler(error.to_string()))
             })
             .expect(zinc_const::panic::SYNCHRONIZATION)
             .join()
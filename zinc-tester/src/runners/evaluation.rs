@@ -9,10 +9,9 @@ use std::sync::Mutex;
 use colored::Colorize;
 
 use zinc_build::Application as BuildApplication;
-use zinc_build::ContractFieldValue as BuildContractFieldValue;
-use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::CircuitFacade;
+use zinc_vm::CircuitInput;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
 use zinc_zksync::TransactionMsg;
@@ -94,7 +93,8 @@ impl IRunnable for Runner {
 
             match instance.application {
                 BuildApplication::Circuit(circuit) => {
-                    let output = CircuitFacade::new(circuit).run::<Bn256>(instance.input);
+                    let output =
+                        CircuitFacade::new(circuit).run::<Bn256>(CircuitInput::new(instance.input));
 
                     match output {
                         Ok(output) => {
@@ -167,16 +167,11 @@ impl IRunnable for Runner {
                     }
                 }
                 BuildApplication::Contract(contract) => {
-                    let storage: Vec<BuildContractFieldValue> = contract
-                        .storage
-                        .clone()
-                        .into_iter()
-                        .map(BuildContractFieldValue::new_from_type)
-                        .collect();
+                    let storage = contract.default_storage();
 
                     let output = ContractFacade::new(contract).run::<Bn256>(ContractInput::new(
                         instance.input,
-                        BuildValue::Contract(storage),
+                        storage,
                         case.method.unwrap_or_else(|| {
                             zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
                         }),
@@ -9,13 +9,12 @@ use std::sync::Mutex;
 use colored::Colorize;
 
 use zinc_build::Application as BuildApplication;
-use zinc_build::ContractFieldValue as BuildContractFieldValue;
-use zinc_build::Value as BuildValue;
 use zinc_vm::Bn256;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
 use zinc_vm::Facade;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
 use crate::file::File;
@@ -94,13 +93,19 @@ impl IRunnable for Runner {
             };
 
             let params = match match instance.application.clone() {
-                BuildApplication::Circuit(circuit) => CircuitFacade::new(circuit).setup::<Bn256>(),
+                BuildApplication::Circuit(circuit) => {
+                    CircuitFacade::new(circuit).setup::<Bn256>(None, None)
+                }
                 BuildApplication::Contract(contract) => ContractFacade::new(contract)
-                    .setup::<Bn256>(case.method.clone().unwrap_or_else(|| {
-                        zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
-                    })),
+                    .setup::<Bn256>(
+                        case.method.clone().unwrap_or_else(|| {
+                            zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
+                        }),
+                        None,
+                        None,
+                    ),
             } {
-                Ok(params) => params,
+                Ok((params, _report)) => params,
                 Err(error) => {
                     summary
                         .lock()
@@ -116,10 +121,26 @@ impl IRunnable for Runner {
                 }
             };
 
+            let public_arguments = match instance.application {
+                BuildApplication::Circuit(_) => vec![],
+                BuildApplication::Contract(ref contract) => contract
+                    .methods
+                    .get(
+                        case.method
+                            .as_deref()
+                            .unwrap_or(zinc_const::source::FUNCTION_MAIN_IDENTIFIER),
+                    )
+                    .map(|method| method.public_argument_values(instance.input.clone()))
+                    .unwrap_or_default(),
+            };
+
             let (output, proof) = match instance.application.clone() {
                 BuildApplication::Circuit(circuit) => {
-                    let result =
-                        CircuitFacade::new(circuit).prove::<Bn256>(params.clone(), instance.input);
+                    let result = CircuitFacade::new(circuit).prove::<Bn256>(
+                        params.clone(),
+                        instance.input,
+                        None,
+                    );
 
                     match result {
                         Ok((result, proof)) => {
@@ -170,24 +191,20 @@ impl IRunnable for Runner {
                     }
                 }
                 BuildApplication::Contract(contract) => {
-                    let storage: Vec<BuildContractFieldValue> = contract
-                        .storage
-                        .clone()
-                        .into_iter()
-                        .map(BuildContractFieldValue::new_from_type)
-                        .collect();
+                    let storage = contract.default_storage();
 
                     let result = ContractFacade::new(contract).prove::<Bn256>(
                         params.clone(),
                         ContractInput::new(
                             instance.input,
-                            BuildValue::Contract(storage),
+                            storage,
                             case.method.unwrap_or_else(|| {
                                 zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
                             }),
                             // TransactionMsg::default(),
                             Vec::new(),
                         ),
+                        None,
                     );
 
                     match result {
@@ -240,7 +257,18 @@ impl IRunnable for Runner {
                 }
             };
 
-            match Facade::verify(params.vk, proof, output) {
+            let verification = match instance.application {
+                BuildApplication::Circuit(_) => Facade::verify(params.vk, proof, output),
+                BuildApplication::Contract(_) => Facade::verify_contract(
+                    params.vk,
+                    proof,
+                    public_arguments,
+                    output,
+                    Environment::default(),
+                ),
+            };
+
+            match verification {
                 Ok(success) => {
                     if success {
                         summary
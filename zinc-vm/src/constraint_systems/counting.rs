@@ -0,0 +1,86 @@
+//!
+//! The counting constraint system.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::Index;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::bellman::Variable;
+
+use crate::core::setup_report::SetupReport;
+use crate::IEngine;
+
+///
+/// The constraint system, which only counts the allocated variables and constraints, without
+/// evaluating the witness or storing the constraints.
+///
+/// Is used to measure the circuit size before the expensive parameters generation.
+///
+pub struct Counting {
+    inputs: usize,
+    witness: usize,
+    constraints: usize,
+}
+
+impl Counting {
+    pub fn new() -> Self {
+        Self {
+            inputs: 1,
+            witness: 0,
+            constraints: 0,
+        }
+    }
+
+    pub fn report(&self) -> SetupReport {
+        SetupReport::new(self.inputs, self.witness, self.constraints)
+    }
+}
+
+impl<E: IEngine> ConstraintSystem<E> for Counting {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, _annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.witness += 1;
+        Ok(Variable::new_unchecked(Index::Aux(self.witness - 1)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, _annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.inputs += 1;
+        Ok(Variable::new_unchecked(Index::Input(self.inputs - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, _a: LA, _b: LB, _c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod constant;
+pub mod counting;
 pub mod dedup;
 pub mod logging;
 pub mod main;
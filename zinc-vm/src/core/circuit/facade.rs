@@ -11,16 +11,22 @@ use franklin_crypto::bellman::groth16;
 use franklin_crypto::bellman::groth16::Parameters;
 use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::Circuit;
 use franklin_crypto::circuit::test::TestConstraintSystem;
 
 use zinc_build::Circuit as BuildCircuit;
 use zinc_build::Value as BuildValue;
 use zinc_const::UnitTestExitCode;
 
+use crate::constraint_systems::counting::Counting as CountingCS;
 use crate::constraint_systems::main::Main as MainCS;
+use crate::core::circuit::input::Input as CircuitInput;
 use crate::core::circuit::output::Output as CircuitOutput;
 use crate::core::circuit::synthesizer::Synthesizer as CircuitSynthesizer;
 use crate::core::circuit::State as CircuitState;
+use crate::core::rng;
+use crate::core::rng::SEED_SIZE as RNG_SEED_SIZE;
+use crate::core::setup_report::SetupReport;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::RuntimeError;
 use crate::IEngine;
@@ -30,17 +36,18 @@ pub struct Facade {
 }
 
 impl Facade {
-    pub fn new(inner: BuildCircuit) -> Self {
+    pub fn new(mut inner: BuildCircuit) -> Self {
+        inner.resolve_constants();
         Self { inner }
     }
 
-    pub fn run<E: IEngine>(self, input: BuildValue) -> Result<CircuitOutput, RuntimeError> {
+    pub fn run<E: IEngine>(self, input: CircuitInput) -> Result<CircuitOutput, RuntimeError> {
         let cs = MainCS::<Bn256>::new();
 
-        let inputs_flat = input.into_flat_values();
+        let inputs_flat = input.arguments.into_flat_values();
         let output_type = self.inner.output.clone();
 
-        let mut state = CircuitState::new(cs);
+        let mut state = CircuitState::new(cs, input.max_stack_size);
 
         let mut num_constraints = 0;
         let result = state.run(
@@ -82,7 +89,7 @@ impl Facade {
 
             let cs = TestConstraintSystem::<Bn256>::new();
 
-            let mut state = CircuitState::new(cs);
+            let mut state = CircuitState::new(cs, zinc_const::limit::VM_DATA_STACK_SIZE);
 
             let result = state.run(self.inner.clone(), Some(&[]), |_| {}, |_| Ok(()));
             match result {
@@ -111,10 +118,38 @@ impl Facade {
         Ok(exit_code)
     }
 
-    pub fn setup<E: IEngine>(self) -> Result<Parameters<E>, RuntimeError> {
-        let rng = &mut rand::thread_rng();
+    ///
+    /// Generates the circuit parameters.
+    ///
+    /// The circuit is synthesized into a counting constraint system first, so the generation
+    /// fails fast if the number of constraints exceeds the optional `max_constraints` limit.
+    ///
+    /// If the `rng_seed` is specified, the parameters are reproducible. It is for testing only.
+    ///
+    pub fn setup<E: IEngine>(
+        self,
+        max_constraints: Option<usize>,
+        rng_seed: Option<[u8; RNG_SEED_SIZE]>,
+    ) -> Result<(Parameters<E>, SetupReport), RuntimeError> {
+        let rng = &mut rng::new(rng_seed);
+
         let mut result = None;
+        let synthesizable = CircuitSynthesizer::<E> {
+            inputs: None,
+            output: &mut result,
+            bytecode: self.inner.clone(),
 
+            _pd: PhantomData,
+        };
+        let mut cs = CountingCS::new();
+        synthesizable.synthesize(&mut cs)?;
+        result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)?;
+
+        let report = cs.report();
+        log::debug!("Setup: {}", report);
+        report.check_limit(max_constraints)?;
+
+        let mut result = None;
         let synthesizable = CircuitSynthesizer {
             inputs: None,
             output: &mut result,
@@ -126,18 +161,24 @@ impl Facade {
         let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
 
         match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
-            Ok(_) => Ok(params),
+            Ok(_) => Ok((params, report)),
             Err(error) => Err(error),
         }
     }
 
+    ///
+    /// Generates the circuit proof.
+    ///
+    /// If the `rng_seed` is specified, the proof is reproducible. It is for testing only.
+    ///
     pub fn prove<E: IEngine>(
         self,
         params: Parameters<E>,
         input: BuildValue,
+        rng_seed: Option<[u8; RNG_SEED_SIZE]>,
     ) -> Result<(BuildValue, Proof<E>), RuntimeError> {
         let mut result = None;
-        let rng = &mut rand::thread_rng();
+        let rng = &mut rng::new(rng_seed);
 
         let inputs_flat = input.into_flat_values();
         let output_type = self.inner.output.clone();
@@ -0,0 +1,27 @@
+//!
+//! The virtual machine circuit input.
+//!
+
+use zinc_build::Value as BuildValue;
+
+///
+/// The virtual machine circuit input.
+///
+pub struct Input {
+    /// The circuit arguments, which is witness for now.
+    pub arguments: BuildValue,
+    /// The maximal data stack size in cells, which defaults to `zinc_const::limit::VM_DATA_STACK_SIZE`.
+    pub max_stack_size: usize,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(arguments: BuildValue) -> Self {
+        Self {
+            arguments,
+            max_stack_size: zinc_const::limit::VM_DATA_STACK_SIZE,
+        }
+    }
+}
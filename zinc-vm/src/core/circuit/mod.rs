@@ -3,6 +3,7 @@
 //!
 
 pub mod facade;
+pub mod input;
 pub mod output;
 pub mod synthesizer;
 
@@ -52,10 +53,10 @@ where
     E: IEngine,
     CS: ConstraintSystem<E>,
 {
-    pub fn new(cs: CS) -> Self {
+    pub fn new(cs: CS, data_stack_limit: usize) -> Self {
         Self {
             counter: NamespaceCounter::new(cs),
-            execution_state: ExecutionState::new(),
+            execution_state: ExecutionState::new(data_stack_limit),
             outputs: vec![],
 
             location: Location::new(),
@@ -199,10 +200,16 @@ where
 
     fn load(&mut self, address: usize) -> Result<Cell<E>, RuntimeError> {
         let frame_start = self.top_frame()?.stack_frame_start;
+        self.execution_state
+            .check_data_stack_address(frame_start.saturating_add(address))?;
         self.execution_state.data_stack.get(frame_start + address)
     }
 
     fn store(&mut self, address: usize, cell: Cell<E>) -> Result<(), RuntimeError> {
+        let frame_start = self.top_frame()?.stack_frame_start;
+        self.execution_state
+            .check_data_stack_address(frame_start.saturating_add(address))?;
+
         let frame = self.top_frame()?;
         frame.stack_frame_end =
             std::cmp::max(frame.stack_frame_end, frame.stack_frame_start + address + 1);
@@ -390,6 +397,11 @@ where
         Ok(())
     }
 
+    fn short_circuit(&mut self) -> Result<(), RuntimeError> {
+        // the circuit is always synthesized, so both operands must be evaluated
+        Ok(())
+    }
+
     fn exit(&mut self, outputs_count: usize) -> Result<(), RuntimeError> {
         for _ in 0..outputs_count {
             let value = self.pop()?.try_into_value()?;
@@ -31,7 +31,10 @@ where
     E: IEngine,
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
-        let mut circuit = State::new(DedupCS::new(LoggingCS::new(cs)));
+        let mut circuit = State::new(
+            DedupCS::new(LoggingCS::new(cs)),
+            zinc_const::limit::VM_DATA_STACK_SIZE,
+        );
         *self.output = Some(circuit.run(self.bytecode, self.inputs.as_deref(), |_| {}, |_| Ok(())));
 
         Ok(())
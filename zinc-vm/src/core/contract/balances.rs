@@ -0,0 +1,45 @@
+//!
+//! The virtual machine contract balances.
+//!
+
+use std::collections::HashMap;
+
+use num::BigUint;
+use num::Zero;
+
+use crate::error::RuntimeError;
+
+///
+/// The contract account balances, which are read with the `self.balance` function.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Balances {
+    /// The balances by the token address.
+    values: HashMap<BigUint, BigUint>,
+    /// Whether a missing balance is an error, which is the case in the proving mode.
+    is_strict: bool,
+}
+
+impl Balances {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(values: HashMap<BigUint, BigUint>, is_strict: bool) -> Self {
+        Self { values, is_strict }
+    }
+
+    ///
+    /// Returns the balance of the token at `token_address`.
+    ///
+    /// A missing balance is treated as zero, unless the balances are strict.
+    ///
+    pub fn get(&self, token_address: &BigUint) -> Result<BigUint, RuntimeError> {
+        match self.values.get(token_address) {
+            Some(balance) => Ok(balance.to_owned()),
+            None if self.is_strict => Err(RuntimeError::BalanceMissing {
+                token_address: token_address.to_owned(),
+            }),
+            None => Ok(BigUint::zero()),
+        }
+    }
+}
@@ -6,30 +6,42 @@ use std::marker::PhantomData;
 
 use colored::Colorize;
 use num::BigInt;
+use num::ToBigUint;
+use num::Zero;
 
 use franklin_crypto::bellman::groth16;
 use franklin_crypto::bellman::groth16::Parameters;
 use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::Circuit;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::test::TestConstraintSystem;
 
 use zinc_build::Contract as BuildContract;
 use zinc_build::ContractFieldValue;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::ScalarValue;
 use zinc_build::Type as BuildType;
 use zinc_build::Value as BuildValue;
 use zinc_const::UnitTestExitCode;
-// use zinc_zksync::TransactionMsg;
+use zinc_zksync::Environment;
+use zinc_zksync::TransactionMsg;
 
 use crate::constraint_systems::constant::Constant as ConstantCS;
+use crate::constraint_systems::counting::Counting as CountingCS;
+use crate::core::contract::balances::Balances;
 use crate::core::contract::input::Input as ContractInput;
 use crate::core::contract::output::Output as ContractOutput;
 use crate::core::contract::storage::database::Storage as DatabaseStorage;
 use crate::core::contract::storage::leaf::LeafInput;
 use crate::core::contract::storage::leaf::LeafOutput;
+use crate::core::contract::storage::proof::StorageProof;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::contract::synthesizer::Synthesizer as ContractSynthesizer;
 use crate::core::contract::State as ContractState;
+use crate::core::rng;
+use crate::core::rng::SEED_SIZE as RNG_SEED_SIZE;
+use crate::core::setup_report::SetupReport;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
@@ -45,15 +57,80 @@ impl Facade {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(inner: BuildContract) -> Self {
+    pub fn new(mut inner: BuildContract) -> Self {
+        inner.resolve_constants();
         Self { inner }
     }
 
+    ///
+    /// Checks if the transfers attached to the call match the method `#[payable]` attribute.
+    ///
+    pub fn check_transfers(
+        method: &BuildContractMethod,
+        transactions: &[TransactionMsg],
+    ) -> Result<(), RuntimeError> {
+        let has_transfers = transactions.iter().any(TransactionMsg::is_transfer);
+
+        match (method.is_payable, has_transfers) {
+            (true, false) => Err(RuntimeError::PayableMethodWithoutTransfer {
+                method: method.name.to_owned(),
+            }),
+            (false, true) => Err(RuntimeError::NonPayableMethodWithTransfer {
+                method: method.name.to_owned(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks if the constructor is called on the uninitialized storage, that is, with the
+    /// implicit address field still set to zero.
+    ///
+    /// The other methods can be called at any time.
+    ///
+    pub fn check_initialization(
+        method_name: &str,
+        storage: &BuildValue,
+    ) -> Result<(), RuntimeError> {
+        if method_name != zinc_const::contract::CONSTRUCTOR_NAME {
+            return Ok(());
+        }
+
+        let fields = match storage {
+            BuildValue::Contract(fields) => fields,
+            _ => return Err(RuntimeError::InvalidStorageValue),
+        };
+        let address = match fields.get(zinc_const::contract::FIELD_INDEX_ADDRESS) {
+            Some(ContractFieldValue {
+                value: BuildValue::Scalar(ScalarValue::Integer(address, _)),
+                is_implicit: true,
+                ..
+            }) => address,
+            _ => return Err(RuntimeError::InvalidStorageValue),
+        };
+
+        if address.is_zero() {
+            Ok(())
+        } else {
+            Err(RuntimeError::AlreadyInitialized {
+                address: address.to_biguint().unwrap_or_default(),
+            })
+        }
+    }
+
+    ///
+    /// Executes the contract method without generating constraints.
+    ///
+    /// Since the run is never proven, the right operands of the logical operators are skipped
+    /// if the left ones determine the result.
+    ///
     pub fn run<E: IEngine>(self, input: ContractInput) -> Result<ContractOutput, RuntimeError> {
         let mut cs = ConstantCS {};
-        log::debug!("input.transactions:{:?}",input.transactions);
-        log::debug!("input.arguments:{:?}",input.arguments);
-        log::debug!("input.storage:{:?}",input.storage);
+        if !input.privacy {
+            log::debug!("input.transactions:{:?}",input.transactions);
+            log::debug!("input.arguments:{:?}",input.arguments);
+            log::debug!("input.storage:{:?}",input.storage);
+        }
         log::debug!("input.method_name:{:?}",input.method_name);
         let method = self
             .inner
@@ -63,6 +140,15 @@ impl Facade {
             .ok_or(RuntimeError::MethodNotFound {
                 found: input.method_name.clone(),
             })?;
+        if input.privacy {
+            log::debug!(
+                "input.transactions: {} (values redacted)",
+                input.transactions.len()
+            );
+            log::debug!("input.arguments: {} (values redacted)", method.input);
+        }
+        Self::check_transfers(&method, input.transactions.as_slice())?;
+        Self::check_initialization(input.method_name.as_str(), &input.storage)?;
 
         let arguments_flat = input.arguments.into_flat_values();
         let output_type = if method.is_mutable {
@@ -78,51 +164,22 @@ impl Facade {
         for field in self.inner.storage.iter() {
             storage_types.push(field.r#type.to_owned());
         }
-        let storage_leaves = match input.storage {
-            BuildValue::Contract(fields) => fields
-                .into_iter()
-                .enumerate()
-                .map(|(index, field)| {
-                    let r#type = storage_types[index].to_owned();
-
-                    match field.value {
-                        BuildValue::Map(map) => {
-                            let (key_type, value_type) = match r#type {
-                                BuildType::Map {
-                                    key_type,
-                                    value_type,
-                                } => (*key_type, *value_type),
-                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                            };
-
-                            let entries = map
-                                .into_iter()
-                                .map(|(key, value)| {
-                                    (key.into_flat_values(), value.into_flat_values())
-                                })
-                                .collect();
-                            LeafInput::Map {
-                                key_type,
-                                value_type,
-                                entries,
-                            }
-                        }
-                        value => {
-                            let mut values = value.into_flat_values();
-                            values.reverse();
-                            LeafInput::Array { r#type, values }
-                        }
-                    }
-                })
-                .collect::<Vec<LeafInput>>(),
-            _ => return Err(RuntimeError::InvalidStorageValue),
-        };
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
         let storage = DatabaseStorage::<Bn256>::new(storage_leaves);
         let storage_gadget =
             StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
 
-        let mut state =
-            ContractState::new(cs, storage_gadget, input.method_name, input.transactions);
+        let mut state = ContractState::new(
+            cs,
+            storage_gadget,
+            input.method_name,
+            input.transactions,
+            Balances::new(input.balances, false),
+            input.environment,
+            input.max_stack_size,
+            input.privacy,
+            true,
+        );
 
         let mut num_constraints = 0;
         let result = state.run(
@@ -152,45 +209,27 @@ impl Facade {
         let output_value: Vec<BigInt> = result.into_iter().filter_map(|value| value).collect();
         let output_value = BuildValue::from_flat_values(output_type, &output_value);
 
+        let mut leaves = state.storage.into_inner().into_values().into_iter();
         let storage_value = BuildValue::Contract(
-            state
-                .storage
-                .into_inner()
-                .into_values()
+            storage_fields
                 .into_iter()
-                .zip(storage_fields)
-                .enumerate()
-                .map(|(index, (leaf, field))| {
-                    let r#type = storage_types
-                        .get(index)
-                        .cloned()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
-
-                    let value = match leaf {
-                        LeafOutput::Array(array) => {
-                            BuildValue::from_flat_values(r#type, array.as_slice())
-                        }
-                        LeafOutput::Map(entries) => {
-                            let (key_type, value_type) = match r#type {
-                                BuildType::Map {
-                                    key_type,
-                                    value_type,
-                                } => (*key_type, *value_type),
-                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                            };
-
-                            let mut values = Vec::with_capacity(entries.len());
-                            for (key, value) in entries.into_iter() {
-                                let key =
-                                    BuildValue::from_flat_values(key_type.clone(), key.as_slice());
-                                let value = BuildValue::from_flat_values(
-                                    value_type.clone(),
-                                    value.as_slice(),
-                                );
-                                values.push((key, value));
-                            }
-                            BuildValue::Map(values)
+                .map(|field| {
+                    let value = match field.r#type.to_owned() {
+                        BuildType::Array(r#type, size)
+                            if matches!(*r#type, BuildType::Map { .. }) =>
+                        {
+                            BuildValue::Array(
+                                leaves
+                                    .by_ref()
+                                    .take(size)
+                                    .map(|leaf| Self::leaf_value(*r#type.clone(), leaf))
+                                    .collect(),
+                            )
                         }
+                        r#type => Self::leaf_value(
+                            r#type,
+                            leaves.next().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        ),
                     };
 
                     ContractFieldValue::new(field.name, value, field.is_public, field.is_implicit)
@@ -203,6 +242,37 @@ impl Facade {
         Ok(ContractOutput::new(output_value, storage_value, transfers))
     }
 
+    ///
+    /// Builds the storage Merkle tree and returns its SHA256 root hash along with the inclusion
+    /// proofs of the leaves occupied by each storage field, in the storage field order.
+    ///
+    pub fn storage_proofs<E: IEngine>(
+        &self,
+        storage: BuildValue,
+    ) -> Result<(Vec<u8>, Vec<Vec<StorageProof>>), RuntimeError> {
+        let mut storage_types = Vec::with_capacity(self.inner.storage.len());
+        for field in self.inner.storage.iter() {
+            storage_types.push(field.r#type.to_owned());
+        }
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), storage)?;
+        let storage = DatabaseStorage::<E>::new(storage_leaves);
+
+        let mut index = 0;
+        let mut proofs = Vec::with_capacity(storage_types.len());
+        for r#type in storage_types.into_iter() {
+            let leaf_count = r#type.into_storage_leaf_types().len();
+
+            let mut field_proofs = Vec::with_capacity(leaf_count);
+            for _ in 0..leaf_count {
+                field_proofs.push(storage.proof(BigInt::from(index))?);
+                index += 1;
+            }
+            proofs.push(field_proofs);
+        }
+
+        Ok((storage.root_hash_bytes(), proofs))
+    }
+
     pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, RuntimeError> {
         let mut exit_code = UnitTestExitCode::Passed;
 
@@ -212,31 +282,38 @@ impl Facade {
                 return Ok(UnitTestExitCode::Ignored);
             }
 
-            let mut cs = TestConstraintSystem::<Bn256>::new();
-
-            let storage_types = self
-                .inner
-                .storage
-                .clone()
-                .into_iter()
-                .map(|field| field.r#type)
-                .collect::<Vec<BuildType>>();
-            let storage = SetupStorage::new(storage_types);
-            let storage_gadget =
-                StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
-
-            let mut state =
-                //ContractState::new(cs, storage_gadget, name.clone(), TransactionMsg::default());
-                ContractState::new(cs, storage_gadget, name.clone(), Vec::new());
-
-            let result = state.run(
-                self.inner.clone(),
-                BuildType::new_empty_structure(),
-                Some(&[]),
-                |_| {},
-                |_| Ok(()),
-                unit_test.address,
-            );
+            let result = match unit_test.storage {
+                Some(storage) => {
+                    let storage_types: Vec<BuildType> = self
+                        .inner
+                        .storage
+                        .iter()
+                        .map(|field| field.r#type.to_owned())
+                        .collect();
+                    let storage_leaves = Self::storage_leaves(storage_types.as_slice(), storage)?;
+                    Self::run_unit_test(
+                        self.inner.clone(),
+                        name.clone(),
+                        unit_test.address,
+                        DatabaseStorage::<Bn256>::new(storage_leaves),
+                    )
+                }
+                None => {
+                    let storage_types = self
+                        .inner
+                        .storage
+                        .clone()
+                        .into_iter()
+                        .flat_map(|field| field.r#type.into_storage_leaf_types())
+                        .collect::<Vec<BuildType>>();
+                    Self::run_unit_test(
+                        self.inner.clone(),
+                        name.clone(),
+                        unit_test.address,
+                        SetupStorage::new(storage_types),
+                    )
+                }
+            };
 
             match result {
                 Err(_) if unit_test.should_panic => {
@@ -264,9 +341,89 @@ impl Facade {
         Ok(exit_code)
     }
 
-    pub fn setup<E: IEngine>(self, method_name: String) -> Result<Parameters<E>, RuntimeError> {
-        let rng = &mut rand::thread_rng();
-        let mut result = None;
+    ///
+    /// Runs the unit test at `address` with the initial contract `storage`.
+    ///
+    /// The storage is zeroed by default and is loaded from the embedded fixture if the test is
+    /// declared with `#[test(storage = "...")]`.
+    ///
+    fn run_unit_test<S: IMerkleTree<Bn256>>(
+        contract: BuildContract,
+        name: String,
+        address: usize,
+        storage: S,
+    ) -> Result<Vec<Option<BigInt>>, RuntimeError> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let storage_gadget =
+            StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
+
+        let mut state = ContractState::new(
+            cs,
+            storage_gadget,
+            name,
+            Vec::new(),
+            Balances::default(),
+            Environment::default(),
+            zinc_const::limit::VM_DATA_STACK_SIZE,
+            false,
+            false,
+        );
+
+        state.run(
+            contract,
+            BuildType::new_empty_structure(),
+            Some(&[]),
+            |_| {},
+            |_| Ok(()),
+            address,
+        )
+    }
+
+    ///
+    /// Measures the contract method size by synthesizing it into a counting constraint system
+    /// with zeroed arguments and storage, without generating the parameters.
+    ///
+    /// The method may fail on the zeroed values, e.g. if it asserts that an argument is non-zero.
+    ///
+    pub fn size<E: IEngine>(&self, method_name: &str) -> Result<SetupReport, RuntimeError> {
+        let method =
+            self.inner
+                .methods
+                .get(method_name)
+                .cloned()
+                .ok_or(RuntimeError::MethodNotFound {
+                    found: method_name.to_owned(),
+                })?;
+
+        let storage_fields: Vec<BuildType> = self
+            .inner
+            .storage
+            .iter()
+            .flat_map(|field| field.r#type.to_owned().into_storage_leaf_types())
+            .collect();
+
+        let inputs = vec![BigInt::zero(); method.input.clone().into_flat_scalar_types().len()];
+
+        let report = self.count::<E>(&method, Some(inputs), storage_fields)?;
+        log::debug!("Size `{}`: {}", method_name, report);
+        Ok(report)
+    }
+
+    ///
+    /// Generates the contract method parameters.
+    ///
+    /// The method is synthesized into a counting constraint system first, so the generation
+    /// fails fast if the number of constraints exceeds the optional `max_constraints` limit.
+    ///
+    /// If the `rng_seed` is specified, the parameters are reproducible. It is for testing only.
+    ///
+    pub fn setup<E: IEngine>(
+        self,
+        method_name: String,
+        max_constraints: Option<usize>,
+        rng_seed: Option<[u8; RNG_SEED_SIZE]>,
+    ) -> Result<(Parameters<E>, SetupReport), RuntimeError> {
+        let rng = &mut rng::new(rng_seed);
 
         let method = self
             .inner
@@ -277,21 +434,27 @@ impl Facade {
                 found: method_name.clone(),
             })?;
 
-        let storage_fields = self
+        let storage_fields: Vec<BuildType> = self
             .inner
             .storage
             .iter()
-            .map(|field| field.r#type.to_owned())
+            .flat_map(|field| field.r#type.to_owned().into_storage_leaf_types())
             .collect();
-        let storage = SetupStorage::new(storage_fields);
 
+        let report = self.count::<E>(&method, None, storage_fields.clone())?;
+        log::debug!("Setup `{}`: {}", method_name, report);
+        report.check_limit(max_constraints)?;
+
+        let mut result = None;
         let synthesizable = ContractSynthesizer {
             inputs: None,
             output: &mut result,
             bytecode: self.inner,
             method,
-            storage,
+            storage: SetupStorage::new(storage_fields),
             transactions: Vec::new(),
+            balances: Balances::default(),
+            environment: Environment::default(),
 
             _pd: PhantomData,
         };
@@ -299,15 +462,53 @@ impl Facade {
         let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
 
         match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
-            Ok(_) => Ok(params),
+            Ok(_) => Ok((params, report)),
             Err(error) => Err(error),
         }
     }
 
+    ///
+    /// Synthesizes the contract method into a counting constraint system.
+    ///
+    fn count<E: IEngine>(
+        &self,
+        method: &BuildContractMethod,
+        inputs: Option<Vec<BigInt>>,
+        storage_fields: Vec<BuildType>,
+    ) -> Result<SetupReport, RuntimeError> {
+        let mut result = None;
+        let synthesizable = ContractSynthesizer::<E, _> {
+            inputs,
+            output: &mut result,
+            bytecode: self.inner.clone(),
+            method: method.to_owned(),
+            storage: SetupStorage::new(storage_fields),
+            transactions: Vec::new(),
+            balances: Balances::default(),
+            environment: Environment::default(),
+
+            _pd: PhantomData,
+        };
+        let mut cs = CountingCS::new();
+        synthesizable.synthesize(&mut cs)?;
+        result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)?;
+
+        Ok(cs.report())
+    }
+
+    ///
+    /// Proves the contract method execution and returns its output along with the proof.
+    ///
+    /// The output always contains the storage root hash, which is committed to by the proof of
+    /// both mutable and immutable methods, so a query result can be verified against the root.
+    ///
+    /// If the `rng_seed` is specified, the proof is reproducible. It is for testing only.
+    ///
     pub fn prove<E: IEngine>(
         self,
         params: Parameters<E>,
         input: ContractInput,
+        rng_seed: Option<[u8; RNG_SEED_SIZE]>,
     ) -> Result<(BuildValue, Proof<E>), RuntimeError> {
         let method = self
             .inner
@@ -317,60 +518,19 @@ impl Facade {
             .ok_or(RuntimeError::MethodNotFound {
                 found: input.method_name.clone(),
             })?;
+        Self::check_transfers(&method, input.transactions.as_slice())?;
 
         let mut result = None;
-        let rng = &mut rand::thread_rng();
+        let rng = &mut rng::new(rng_seed);
 
         let arguments_flat = input.arguments.into_flat_values();
-        let output_type = if method.is_mutable {
-            method.output.clone().into_mutable_method_output()
-        } else {
-            method.output.clone()
-        };
+        let output_type = method.output.clone().into_mutable_method_output();
 
         let mut storage_types = Vec::with_capacity(self.inner.storage.len());
         for field in self.inner.storage.iter() {
             storage_types.push(field.r#type.to_owned());
         }
-        let storage_leaves = match input.storage {
-            BuildValue::Contract(fields) => fields
-                .into_iter()
-                .enumerate()
-                .map(|(index, field)| {
-                    let r#type = storage_types[index].to_owned();
-
-                    match field.value {
-                        BuildValue::Map(map) => {
-                            let (key_type, value_type) = match r#type {
-                                BuildType::Map {
-                                    key_type,
-                                    value_type,
-                                } => (*key_type, *value_type),
-                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                            };
-
-                            let entries = map
-                                .into_iter()
-                                .map(|(key, value)| {
-                                    (key.into_flat_values(), value.into_flat_values())
-                                })
-                                .collect();
-                            LeafInput::Map {
-                                key_type,
-                                value_type,
-                                entries,
-                            }
-                        }
-                        value => {
-                            let mut values = value.into_flat_values();
-                            values.reverse();
-                            LeafInput::Array { r#type, values }
-                        }
-                    }
-                })
-                .collect::<Vec<LeafInput>>(),
-            _ => return Err(RuntimeError::InvalidStorageValue),
-        };
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
         let storage = DatabaseStorage::new(storage_leaves);
 
         let synthesizable = ContractSynthesizer {
@@ -380,6 +540,8 @@ impl Facade {
             method,
             storage,
             transactions: input.transactions,
+            balances: Balances::new(input.balances, true),
+            environment: input.environment,
 
             _pd: PhantomData,
         };
@@ -403,4 +565,175 @@ impl Facade {
             },
         }
     }
+
+    ///
+    /// Executes the contract method with the constant constraint system and returns its output
+    /// along with the public input, which the proof generated for the same input would commit to.
+    ///
+    /// Is used to get the public input without paying for proving.
+    ///
+    pub fn witness<E: IEngine>(
+        self,
+        input: ContractInput,
+    ) -> Result<(BuildValue, Vec<BigInt>), RuntimeError> {
+        let method = self
+            .inner
+            .methods
+            .get(input.method_name.as_str())
+            .cloned()
+            .ok_or(RuntimeError::MethodNotFound {
+                found: input.method_name.clone(),
+            })?;
+        Self::check_transfers(&method, input.transactions.as_slice())?;
+
+        let public_arguments = method.public_argument_values(input.arguments.clone());
+        let arguments_flat = input.arguments.into_flat_values();
+        let environment = input.environment.clone();
+        let result_type = method.output.clone();
+        let output_type = method.output.clone().into_mutable_method_output();
+
+        let storage_types: Vec<BuildType> = self
+            .inner
+            .storage
+            .iter()
+            .map(|field| field.r#type.to_owned())
+            .collect();
+        let storage_leaves = Self::storage_leaves(storage_types.as_slice(), input.storage)?;
+        let storage = DatabaseStorage::<E>::new(storage_leaves);
+
+        let mut result = None;
+        let synthesizable = ContractSynthesizer {
+            inputs: Some(arguments_flat),
+            output: &mut result,
+            bytecode: self.inner,
+            method,
+            storage,
+            transactions: input.transactions,
+            balances: Balances::new(input.balances, false),
+            environment: input.environment,
+
+            _pd: PhantomData,
+        };
+        let mut cs = ConstantCS {};
+        synthesizable.synthesize(&mut cs)?;
+
+        let mut output_flat: Vec<BigInt> = result
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)?
+            .into_iter()
+            .filter_map(|value| value)
+            .collect();
+        output_flat.truncate(
+            output_flat
+                .len()
+                .saturating_sub(zinc_const::contract::ENVIRONMENT_SIZE),
+        );
+        let output_value = BuildValue::from_flat_values(output_type, &output_flat);
+
+        let root_hash = output_flat.pop();
+        let result_value = BuildValue::from_flat_values(result_type, &output_flat);
+        let public_input = crate::core::public_input::assemble_contract(
+            public_arguments,
+            result_value,
+            root_hash,
+            environment,
+        );
+
+        Ok((output_value, public_input))
+    }
+
+    ///
+    /// Converts the contract storage value into the storage Merkle tree leaves.
+    ///
+    /// An array of maps is expanded into a separate leaf for each map.
+    ///
+    fn storage_leaves(
+        storage_types: &[BuildType],
+        storage: BuildValue,
+    ) -> Result<Vec<LeafInput>, RuntimeError> {
+        let fields = match storage {
+            BuildValue::Contract(fields) => fields,
+            _ => return Err(RuntimeError::InvalidStorageValue),
+        };
+
+        let mut leaves = Vec::with_capacity(fields.len());
+        for (index, field) in fields.into_iter().enumerate() {
+            let r#type = storage_types[index].to_owned();
+
+            match (field.value, r#type) {
+                (BuildValue::Map(entries), r#type) => leaves.push(Self::map_leaf(r#type, entries)),
+                (BuildValue::Array(values), BuildType::Array(r#type, _size))
+                    if matches!(*r#type, BuildType::Map { .. }) =>
+                {
+                    for value in values.into_iter() {
+                        match value {
+                            BuildValue::Map(entries) => {
+                                leaves.push(Self::map_leaf(*r#type.clone(), entries))
+                            }
+                            _ => return Err(RuntimeError::InvalidStorageValue),
+                        }
+                    }
+                }
+                (value, r#type) => {
+                    let mut values = value.into_flat_values();
+                    values.reverse();
+                    leaves.push(LeafInput::Array { r#type, values });
+                }
+            }
+        }
+
+        Ok(leaves)
+    }
+
+    ///
+    /// Converts the map value into a storage Merkle tree leaf.
+    ///
+    fn map_leaf(r#type: BuildType, entries: Vec<(BuildValue, BuildValue)>) -> LeafInput {
+        let (key_type, value_type) = match r#type {
+            BuildType::Map {
+                key_type,
+                value_type,
+            } => (*key_type, *value_type),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| (key.into_flat_values(), value.into_flat_values()))
+            .collect();
+
+        LeafInput::Map {
+            key_type,
+            value_type,
+            entries,
+        }
+    }
+
+    ///
+    /// Converts the storage Merkle tree leaf back into the contract field value.
+    ///
+    fn leaf_value(r#type: BuildType, leaf: LeafOutput) -> BuildValue {
+        match leaf {
+            LeafOutput::Array(array) => BuildValue::from_flat_values(r#type, array.as_slice()),
+            LeafOutput::Map(mut entries) => {
+                // the canonical order does not depend on the insertion order
+                entries.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+
+                let (key_type, value_type) = match r#type {
+                    BuildType::Map {
+                        key_type,
+                        value_type,
+                    } => (*key_type, *value_type),
+                    _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+
+                let mut values = Vec::with_capacity(entries.len());
+                for (key, value) in entries.into_iter() {
+                    let key = BuildValue::from_flat_values(key_type.clone(), key.as_slice());
+                    let value = BuildValue::from_flat_values(value_type.clone(), value.as_slice());
+                    values.push((key, value));
+                }
+                BuildValue::Map(values)
+            }
+        }
+    }
 }
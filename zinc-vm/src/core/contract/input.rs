@@ -2,7 +2,12 @@
 //! The virtual machine contract input.
 //!
 
+use std::collections::HashMap;
+
+use num::BigUint;
+
 use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
 ///
@@ -17,6 +22,14 @@ pub struct Input {
     pub method_name: String,
     /// The contract input transaction.
     pub transactions: Vec<TransactionMsg>,
+    /// The contract account balances by the token address, which defaults to an empty map.
+    pub balances: HashMap<BigUint, BigUint>,
+    /// The block environment, represented by the `zksync::env` variable, which defaults to zeros.
+    pub environment: Environment,
+    /// The maximal data stack size in cells, which defaults to `zinc_const::limit::VM_DATA_STACK_SIZE`.
+    pub max_stack_size: usize,
+    /// Whether the witness values must be redacted from the logs and errors, which defaults to `false`.
+    pub privacy: bool,
 }
 
 impl Input {
@@ -37,6 +50,10 @@ impl Input {
             storage,
             method_name,
             transactions,
+            balances: HashMap::new(),
+            environment: Environment::default(),
+            max_stack_size: zinc_const::limit::VM_DATA_STACK_SIZE,
+            privacy: false,
         }
     }
 }
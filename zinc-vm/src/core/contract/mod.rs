@@ -2,6 +2,10 @@
 //! The virtual machine contract.
 //!
 
+#[cfg(test)]
+mod tests;
+
+pub mod balances;
 pub mod facade;
 pub mod input;
 pub mod output;
@@ -12,15 +16,21 @@ use colored::Colorize;
 use num::bigint::Sign;
 use num::bigint::ToBigInt;
 use num::BigInt;
+use num::ToBigUint;
+use num::Zero;
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
 use zinc_build::Contract as BytecodeContract;
+use zinc_build::Instruction;
 use zinc_build::IntegerType;
 use zinc_build::ScalarType;
 use zinc_build::Type as BuildType;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
+use crate::core::contract::balances::Balances;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::counter::NamespaceCounter;
 use crate::core::execution_state::block::branch::Branch;
@@ -54,8 +64,14 @@ where
     outputs: Vec<Scalar<E>>,
 
     storage: StorageGadget<E, S, H>,
+    layout_hash: BigInt,
     method_name: String,
     transactions: Vec<TransactionMsg>,
+    environment: Environment,
+    environment_scalars: Vec<Scalar<E>>,
+    privacy: bool,
+    instructions: Vec<Instruction>,
+    is_short_circuit: bool,
 
     pub(crate) location: Location,
 }
@@ -67,20 +83,35 @@ where
     S: IMerkleTree<E>,
     H: IMerkleTreeHasher<E>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         cs: CS,
         storage: StorageGadget<E, S, H>,
         method_name: String,
         transactions: Vec<TransactionMsg>,
+        balances: Balances,
+        environment: Environment,
+        data_stack_limit: usize,
+        privacy: bool,
+        is_short_circuit: bool,
     ) -> Self {
+        let mut execution_state = ExecutionState::new(data_stack_limit);
+        execution_state.balances = balances;
+
         Self {
             counter: NamespaceCounter::new(cs),
-            execution_state: ExecutionState::new(),
+            execution_state,
             outputs: vec![],
 
             storage,
+            layout_hash: BigInt::zero(),
             method_name,
             transactions,
+            environment,
+            environment_scalars: Vec::with_capacity(zinc_const::contract::ENVIRONMENT_SIZE),
+            privacy,
+            instructions: Vec::new(),
+            is_short_circuit,
 
             location: Location::new(),
         }
@@ -109,31 +140,48 @@ where
         let one = Scalar::new_constant_usize(1, ScalarType::Boolean);
         self.condition_push(one)?;
 
+        self.layout_hash = contract.layout_hash.clone();
+        self.instructions = contract.instructions;
+        let is_mutable = contract
+            .methods
+            .get(self.method_name.as_str())
+            .map(|method| method.is_mutable)
+            .unwrap_or_default();
+        let public_indexes = contract
+            .methods
+            .get(self.method_name.as_str())
+            .map(|method| method.public_argument_indexes())
+            .unwrap_or_default();
+
         let input_size = input_type.size();
         self.execution_state
             .frames_stack
             .push(Frame::new(0, std::usize::MAX));
-        self.init_root_frame(input_type, input_values)?;
+        self.init_environment()?;
+        self.init_root_frame(input_type, input_values, public_indexes.as_slice())?;
 
         if let Err(error) = zinc_build::Call::new(address, input_size)
             .execute(self)
             .and(check_cs(&self.counter.cs))
         {
+            let error = self.redact_error(error);
             log::error!("{}\nat {}", error, self.location.to_string().blue());
             return Err(error);
         }
         self.init_storage()?;
+        if is_mutable && self.method_name.as_str() != zinc_const::contract::CONSTRUCTOR_NAME {
+            self.check_layout_hash()?;
+        }
 
         let mut step = 0;
         let execution_time = std::time::Instant::now();
-        while self.execution_state.instruction_counter < contract.instructions.len() {
+        while self.execution_state.instruction_counter < self.instructions.len() {
             let namespace = format!(
                 "step={}, addr={}",
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                contract.instructions[self.execution_state.instruction_counter].clone();
+            let instruction = self.instructions[self.execution_state.instruction_counter].clone();
 
             log::trace!(
                 "{}:{} > {}",
@@ -145,11 +193,14 @@ where
             self.execution_state.instruction_counter += 1;
             log::debug!("instruction,{:?}",instruction);
             if let Err(error) = instruction.execute(self).and(check_cs(&self.counter.cs)) {
+                let error = self.redact_error(error);
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
                 return Err(error);
             }
 
-            log::trace!("{}", self.execution_state);
+            if !self.privacy {
+                log::trace!("{}", self.execution_state);
+            }
             instruction_callback(&self.counter.cs);
             self.counter.cs.pop_namespace();
             step += 1;
@@ -163,6 +214,30 @@ where
         self.get_outputs()
     }
 
+    ///
+    /// Formats a witness for the debug log.
+    ///
+    /// In the privacy mode, only the witness type is logged.
+    ///
+    fn log_scalar(&self, scalar: &Scalar<E>) -> String {
+        if self.privacy {
+            format!("<redacted {}>", scalar.get_type())
+        } else {
+            format!("{:?}", scalar)
+        }
+    }
+
+    ///
+    /// Replaces the operand values in the error with their hash in the privacy mode.
+    ///
+    fn redact_error(&self, error: RuntimeError) -> RuntimeError {
+        if self.privacy {
+            error.redacted()
+        } else {
+            error
+        }
+    }
+
     fn init_storage(&mut self) -> Result<(), RuntimeError> {
         // Temporary fix to avoid "unconstrained" error
         let root_hash = self.storage.root_hash()?;
@@ -177,10 +252,82 @@ where
         Ok(())
     }
 
+    ///
+    /// Enforces the storage layout hash field to be equal to the one embedded into the bytecode,
+    /// so a method cannot be executed against a storage of another layout.
+    ///
+    fn check_layout_hash(&mut self) -> Result<(), RuntimeError> {
+        // the implicit fields occupy a single leaf each, so the leaf index is the field index
+        let index = Scalar::new_constant_usize(
+            zinc_const::contract::FIELD_INDEX_LAYOUT_HASH,
+            ScalarType::Field,
+        );
+        let found = self
+            .storage
+            .load(self.counter.next(), 1, index)?
+            .pop()
+            .ok_or(RuntimeError::InvalidStorageValue)?;
+        let expected = Scalar::new_constant_bigint(
+            self.layout_hash.clone(),
+            ScalarType::Integer(IntegerType::LAYOUT_HASH),
+        )?;
+
+        if let Some(found) = found.to_bigint() {
+            if found != self.layout_hash {
+                return Err(RuntimeError::StorageLayoutMismatch {
+                    expected: self.layout_hash.to_biguint().unwrap_or_default(),
+                    found: found.to_biguint().unwrap_or_default(),
+                });
+            }
+        }
+
+        let cs = self.constraint_system();
+        cs.enforce(
+            || "layout hash equality",
+            |zero| {
+                zero + &found.to_linear_combination::<CS>()
+                    - &expected.to_linear_combination::<CS>()
+            },
+            |zero| zero + CS::one(),
+            |zero| zero,
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Allocates the `zksync::env` environment variable fields as witnesses once per method
+    /// execution, so every function frame gets the same variables, which are committed to in
+    /// the public input.
+    ///
+    fn init_environment(&mut self) -> Result<(), RuntimeError> {
+        let timestamp = gadgets::witness::allocate(
+            self.counter.next(),
+            Some(&BigInt::from(self.environment.timestamp)),
+            ScalarType::Integer(IntegerType::TIMESTAMP),
+        )?;
+        let block_number = gadgets::witness::allocate(
+            self.counter.next(),
+            Some(&BigInt::from(self.environment.block_number)),
+            ScalarType::Integer(IntegerType::BLOCK_NUMBER),
+        )?;
+
+        self.environment_scalars = vec![timestamp, block_number];
+
+        Ok(())
+    }
+
+    ///
+    /// Allocates the method arguments and pushes them onto the root frame.
+    ///
+    /// The `#[public]` argument scalars at `public_indexes` are also inputized, so they become
+    /// the leading part of the proof public input.
+    ///
     fn init_root_frame(
         &mut self,
         input_type: BuildType,
         inputs: Option<&[BigInt]>,
+        public_indexes: &[usize],
     ) -> Result<(), RuntimeError> {
         self.execution_state
             .frames_stack
@@ -194,8 +341,11 @@ where
             None => std::iter::repeat(None).zip(types).collect(),
         };
 
-        for (value, dtype) in value_type_pairs {
-            let variable = gadgets::witness::allocate(self.counter.next(), value, dtype)?;
+        for (index, (value, dtype)) in value_type_pairs.into_iter().enumerate() {
+            let mut variable = gadgets::witness::allocate(self.counter.next(), value, dtype)?;
+            if public_indexes.contains(&index) {
+                variable = gadgets::output::output(self.counter.next(), variable)?;
+            }
             self.push(Cell::Value(variable))?;
         }
 
@@ -214,6 +364,11 @@ where
         let root_hash = gadgets::output::output(self.counter.next(), root_hash)?;
         outputs_bigint.push(root_hash.to_bigint());
 
+        for scalar in self.environment_scalars.clone().into_iter() {
+            let scalar = gadgets::output::output(self.counter.next(), scalar)?;
+            outputs_bigint.push(scalar.to_bigint());
+        }
+
         Ok(outputs_bigint)
     }
 
@@ -259,10 +414,16 @@ where
     fn load(&mut self, address: usize) -> Result<Cell<E>, RuntimeError> {
         let frame_start = self.top_frame()?.stack_frame_start;
         log::debug!("address:{:?}-------frame_start:{:?}",address,frame_start);
+        self.execution_state
+            .check_data_stack_address(frame_start.saturating_add(address))?;
         self.execution_state.data_stack.get(frame_start + address)
     }
 
     fn store(&mut self, address: usize, cell: Cell<E>) -> Result<(), RuntimeError> {
+        let frame_start = self.top_frame()?.stack_frame_start;
+        self.execution_state
+            .check_data_stack_address(frame_start.saturating_add(address))?;
+
         let frame = self.top_frame()?;
         frame.stack_frame_end =
             std::cmp::max(frame.stack_frame_end, frame.stack_frame_start + address + 1);
@@ -344,7 +505,7 @@ where
                 )),
                 ScalarType::Integer(IntegerType::ETH_ADDRESS),
             )?;
-            log::debug!("sender========================={:?}", sender.clone());
+            log::debug!("sender========================={}", self.log_scalar(&sender));
     
             self.store(
                 transaction_field_iter
@@ -363,7 +524,7 @@ where
                 )),
                 ScalarType::Integer(IntegerType::ETH_ADDRESS),
             )?;
-            log::debug!("recipient========================={:?}", recipient.clone());
+            log::debug!("recipient========================={}", self.log_scalar(&recipient));
     
             self.store(
                 transaction_field_iter
@@ -383,7 +544,7 @@ where
                 ScalarType::Integer(IntegerType::ETH_ADDRESS),
             )?;
     
-            log::debug!("token_address========================={:?}", token_address.clone());
+            log::debug!("token_address========================={}", self.log_scalar(&token_address));
     
             self.store(
                 transaction_field_iter
@@ -402,7 +563,7 @@ where
                 ScalarType::Integer(IntegerType::BALANCE),
             )?;
     
-            log::debug!("amount========================={:?}", amount.clone());
+            log::debug!("amount========================={}", self.log_scalar(&amount));
     
             self.store(
                 transaction_field_iter
@@ -412,13 +573,29 @@ where
             )?;
         }
         
+        for (index, scalar) in self.environment_scalars.clone().into_iter().enumerate() {
+            self.store(
+                zinc_const::contract::TRANSACTION_SIZE + index,
+                Cell::Value(scalar),
+            )?;
+        }
+
         log::debug!("inputs_count========================={:?}", inputs_count);
 
         for i in 0..inputs_count {
             let arg = self.pop()?;
-            log::debug!("arg========================={:?}", arg);
+            log::debug!(
+                "arg========================={}",
+                match arg {
+                    Cell::Value(ref scalar) => self.log_scalar(scalar),
+                }
+            );
             self.store(
-                zinc_const::contract::TRANSACTION_SIZE + inputs_count - i - 1,
+                zinc_const::contract::TRANSACTION_SIZE
+                    + zinc_const::contract::ENVIRONMENT_SIZE
+                    + inputs_count
+                    - i
+                    - 1,
                 arg,
             )?;
         }
@@ -540,6 +717,43 @@ where
         Ok(())
     }
 
+    fn short_circuit(&mut self) -> Result<(), RuntimeError> {
+        if !self.is_short_circuit {
+            return Ok(());
+        }
+
+        let is_skipped = self
+            .condition_top()?
+            .get_value()
+            .map(|value| value.is_zero())
+            .unwrap_or_default();
+        if !is_skipped {
+            return Ok(());
+        }
+
+        let mut depth = 0;
+        let mut address = self.execution_state.instruction_counter;
+        loop {
+            match self.instructions.get(address) {
+                Some(Instruction::If(_)) => depth += 1,
+                Some(Instruction::Else(_)) if depth == 0 => break,
+                Some(Instruction::EndIf(_)) if depth == 0 => {
+                    return Err(MalformedBytecode::UnexpectedShortCircuit.into())
+                }
+                Some(Instruction::EndIf(_)) => depth -= 1,
+                Some(_) => {}
+                None => return Err(MalformedBytecode::UnexpectedShortCircuit.into()),
+            }
+            address += 1;
+        }
+
+        // the placeholder is never selected by the merge, since the branch condition is false
+        self.push(Cell::Value(Scalar::new_constant_bool(false)))?;
+        self.execution_state.instruction_counter = address;
+
+        Ok(())
+    }
+
     fn exit(&mut self, mut outputs_count: usize) -> Result<(), RuntimeError> {
         if self.method_name.as_str() == zinc_const::contract::CONSTRUCTOR_NAME {
             outputs_count -= zinc_const::contract::IMPLICIT_FIELDS_SIZE;
@@ -552,10 +766,13 @@ where
 
         if self.method_name.as_str() == zinc_const::contract::CONSTRUCTOR_NAME {
             self.outputs.extend(
-                vec![Scalar::new_constant_usize(
-                    0,
-                    ScalarType::Integer(IntegerType::ETH_ADDRESS),
-                )]
+                vec![
+                    Scalar::new_constant_usize(0, ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+                    Scalar::new_constant_bigint(
+                        self.layout_hash.clone(),
+                        ScalarType::Integer(IntegerType::LAYOUT_HASH),
+                    )?,
+                ]
                 .into_iter()
                 .rev(),
             );
@@ -4,6 +4,10 @@
 
 pub mod transfer;
 
+use num::BigInt;
+
+use zinc_build::IntegerType;
+use zinc_build::ScalarValue;
 use zinc_build::Value as BuildValue;
 
 use self::transfer::Transfer;
@@ -32,4 +36,24 @@ impl Output {
             transfers,
         }
     }
+
+    ///
+    /// Converts the constructor output into the initialized contract storage, setting its
+    /// implicit address field to `address`.
+    ///
+    /// The constructor result is the whole contract instance with the zero address, so the
+    /// address must be set, otherwise the constructor could be called on the storage again.
+    ///
+    pub fn into_initialized_storage(self, address: BigInt) -> BuildValue {
+        match self.result {
+            BuildValue::Contract(mut fields) => {
+                if let Some(field) = fields.get_mut(zinc_const::contract::FIELD_INDEX_ADDRESS) {
+                    field.value =
+                        BuildValue::Scalar(ScalarValue::Integer(address, IntegerType::ETH_ADDRESS));
+                }
+                BuildValue::Contract(fields)
+            }
+            result => result,
+        }
+    }
 }
@@ -9,6 +9,8 @@ use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafInput;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::contract::storage::proof;
+use crate::core::contract::storage::proof::StorageProof;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
@@ -89,6 +91,20 @@ impl<E: IEngine> Storage<E> {
     }
 }
 
+impl<E: IEngine> Storage<E> {
+    ///
+    /// Builds the SHA256 Merkle tree of the current leaf values.
+    ///
+    fn sha256_hash_tree(&self) -> Vec<Vec<u8>> {
+        proof::hash_tree::<E>(
+            self.leaf_values
+                .iter()
+                .map(LeafVariant::hashed_values)
+                .collect(),
+        )
+    }
+}
+
 impl<E: IEngine> IMerkleTree<E> for Storage<E> {
     fn load(&self, index: BigInt) -> Result<Leaf<E>, RuntimeError> {
         let index = index.to_usize().ok_or(RuntimeError::ExpectedUsize(index))?;
@@ -168,6 +184,16 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         E::Fr::from_repr(hash_repr).expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
     }
 
+    fn root_hash_bytes(&self) -> Vec<u8> {
+        self.sha256_hash_tree().swap_remove(1)
+    }
+
+    fn proof(&self, index: BigInt) -> Result<StorageProof, RuntimeError> {
+        let index = index.to_usize().ok_or(RuntimeError::ExpectedUsize(index))?;
+
+        StorageProof::new(self.sha256_hash_tree().as_slice(), index)
+    }
+
     fn depth(&self) -> usize {
         self.depth
     }
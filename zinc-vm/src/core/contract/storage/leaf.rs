@@ -43,6 +43,20 @@ pub enum LeafOutput {
     Map(Vec<(Vec<BigInt>, Vec<BigInt>)>),
 }
 
+impl<E: IEngine> LeafVariant<E> {
+    ///
+    /// Returns the values the leaf hash is computed from.
+    ///
+    /// The map leaves are hashed as empty, since their entries are not the circuit witness.
+    ///
+    pub fn hashed_values(&self) -> Vec<Scalar<E>> {
+        match self {
+            Self::Array(array) => array.to_owned(),
+            Self::Map { .. } => vec![],
+        }
+    }
+}
+
 impl<E: IEngine> Leaf<E> {
     pub fn new(
         leaf_values: LeafVariant<E>,
@@ -55,11 +69,7 @@ impl<E: IEngine> Leaf<E> {
                 let mut hash = Vec::with_capacity(
                     zinc_const::bitlength::SHA256_HASH * zinc_const::bitlength::BYTE,
                 );
-                let values = match leaf_values {
-                    LeafVariant::Array(array) => array,
-                    LeafVariant::Map { .. } => vec![],
-                };
-                for i in sha256::leaf_value_hash::<E>(values).into_iter() {
+                for i in sha256::leaf_value_hash::<E>(leaf_values.hashed_values()).into_iter() {
                     for j in (0..zinc_const::bitlength::BYTE).rev() {
                         let bit = ((i >> j) & 1u8) == 1u8;
                         hash.push(bit);
@@ -1,4 +1,5 @@
 pub mod database;
 pub mod leaf;
+pub mod proof;
 pub mod setup;
 pub mod sha256;
@@ -0,0 +1,224 @@
+use num::BigInt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use zinc_build::ScalarType;
+
+use crate::core::contract::storage::sha256;
+use crate::error::RuntimeError;
+use crate::gadgets::scalar::fr_bigint;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The storage leaf inclusion proof.
+///
+/// Is checked against the Merkle tree root hash with `verify_storage_proof`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageProof {
+    /// The leaf index in the Merkle tree.
+    pub index: usize,
+    /// The SHA256 hash of the leaf value.
+    pub leaf_value_hash: Vec<u8>,
+    /// The sibling node hashes from the leaf level up to the root.
+    pub authentication_path: Vec<Vec<u8>>,
+}
+
+impl StorageProof {
+    ///
+    /// Extracts the proof of the leaf at `index` from the `hash_tree` built with `hash_tree`.
+    ///
+    pub fn new(hash_tree: &[Vec<u8>], index: usize) -> Result<Self, RuntimeError> {
+        let leaf_count = hash_tree.len() / 2;
+        if index >= leaf_count {
+            return Err(RuntimeError::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: leaf_count,
+                found: index,
+            });
+        }
+
+        let mut node = leaf_count + index;
+        let leaf_value_hash = hash_tree[node].to_owned();
+        let mut authentication_path = Vec::new();
+        while node > 1 {
+            authentication_path.push(hash_tree[node ^ 1].to_owned());
+            node >>= 1;
+        }
+
+        Ok(Self {
+            index,
+            leaf_value_hash,
+            authentication_path,
+        })
+    }
+}
+
+///
+/// Builds the SHA256 Merkle tree of `leaves`, whose count must be a power of two.
+///
+/// The root is stored at the index `1`, and the children of the node `i` are stored at the
+/// indexes `2 * i` and `2 * i + 1`, so the leaf hashes start at the index `leaves.len()`.
+///
+pub fn hash_tree<E: IEngine>(leaves: Vec<Vec<Scalar<E>>>) -> Vec<Vec<u8>> {
+    let leaf_count = leaves.len();
+    let mut hash_tree = vec![vec![]; leaf_count * 2];
+
+    for (index, leaf) in leaves.into_iter().enumerate() {
+        hash_tree[leaf_count + index] = sha256::leaf_value_hash::<E>(leaf);
+    }
+    for index in (1..leaf_count).rev() {
+        hash_tree[index] = sha256::sha256::<E>(
+            [
+                hash_tree[index * 2].as_slice(),
+                hash_tree[index * 2 + 1].as_slice(),
+            ]
+            .concat()
+            .as_slice(),
+        );
+    }
+
+    hash_tree
+}
+
+///
+/// Checks whether the leaf at `index` with the flattened `leaf_values` is included into the
+/// Merkle tree with the `root` hash.
+///
+/// The values must be passed in the Merkle tree leaf order. A map leaf is hashed as empty,
+/// so its `leaf_values` must be empty as well.
+///
+pub fn verify_storage_proof<E: IEngine>(
+    root: &[u8],
+    index: usize,
+    leaf_values: Vec<BigInt>,
+    authentication_path: &[Vec<u8>],
+) -> bool {
+    if index
+        .checked_shr(authentication_path.len() as u32)
+        .unwrap_or_default()
+        != 0
+    {
+        return false;
+    }
+
+    let mut scalars = Vec::with_capacity(leaf_values.len());
+    for value in leaf_values.iter() {
+        match fr_bigint::bigint_to_fr::<E>(value) {
+            Some(fr) => scalars.push(Scalar::<E>::new_constant_fr(fr, ScalarType::Field)),
+            None => return false,
+        }
+    }
+
+    let mut node = index;
+    let mut hash = sha256::leaf_value_hash::<E>(scalars);
+    for sibling in authentication_path.iter() {
+        let preimage = if node & 1 == 0 {
+            [hash.as_slice(), sibling.as_slice()].concat()
+        } else {
+            [sibling.as_slice(), hash.as_slice()].concat()
+        };
+        hash = sha256::sha256::<E>(preimage.as_slice());
+        node >>= 1;
+    }
+
+    hash.as_slice() == root
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use zinc_build::ScalarType;
+    use zinc_build::Type as BuildType;
+
+    use crate::core::contract::storage::database::Storage as DatabaseStorage;
+    use crate::core::contract::storage::leaf::LeafInput;
+    use crate::gadgets::contract::merkle_tree::IMerkleTree;
+
+    use super::verify_storage_proof;
+
+    fn storage() -> DatabaseStorage<Bn256> {
+        DatabaseStorage::<Bn256>::new(
+            (0..5)
+                .map(|index| LeafInput::Array {
+                    r#type: BuildType::Array(Box::new(BuildType::Scalar(ScalarType::Field)), 2),
+                    values: vec![BigInt::from(index), BigInt::from(-index)],
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn ok_every_leaf() {
+        let storage = storage();
+        let root = storage.root_hash_bytes();
+
+        for index in 0..5 {
+            let proof = storage
+                .proof(BigInt::from(index))
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert_eq!(proof.authentication_path.len(), storage.depth());
+            assert!(verify_storage_proof::<Bn256>(
+                root.as_slice(),
+                index,
+                vec![BigInt::from(index), BigInt::from(-(index as isize))],
+                proof.authentication_path.as_slice(),
+            ));
+        }
+    }
+
+    #[test]
+    fn error_tampered_path() {
+        let storage = storage();
+        let root = storage.root_hash_bytes();
+        let mut proof = storage
+            .proof(BigInt::from(2))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        proof.authentication_path[1][0] ^= 1;
+
+        assert!(!verify_storage_proof::<Bn256>(
+            root.as_slice(),
+            2,
+            vec![BigInt::from(2), BigInt::from(-2)],
+            proof.authentication_path.as_slice(),
+        ));
+    }
+
+    #[test]
+    fn error_tampered_value() {
+        let storage = storage();
+        let root = storage.root_hash_bytes();
+        let proof = storage
+            .proof(BigInt::from(2))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(!verify_storage_proof::<Bn256>(
+            root.as_slice(),
+            2,
+            vec![BigInt::from(2), BigInt::from(2)],
+            proof.authentication_path.as_slice(),
+        ));
+    }
+
+    #[test]
+    fn error_wrong_index() {
+        let storage = storage();
+        let root = storage.root_hash_bytes();
+        let proof = storage
+            .proof(BigInt::from(2))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(!verify_storage_proof::<Bn256>(
+            root.as_slice(),
+            3,
+            vec![BigInt::from(2), BigInt::from(-2)],
+            proof.authentication_path.as_slice(),
+        ));
+    }
+}
@@ -9,6 +9,8 @@ use zinc_build::Type as BuildType;
 use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::contract::storage::proof;
+use crate::core::contract::storage::proof::StorageProof;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
@@ -85,6 +87,19 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         E::Fr::zero()
     }
 
+    fn root_hash_bytes(&self) -> Vec<u8> {
+        proof::hash_tree::<E>(self.leaf_values.to_owned()).swap_remove(1)
+    }
+
+    fn proof(&self, index: BigInt) -> Result<StorageProof, RuntimeError> {
+        let index = index.to_usize().ok_or(RuntimeError::ExpectedUsize(index))?;
+
+        StorageProof::new(
+            proof::hash_tree::<E>(self.leaf_values.to_owned()).as_slice(),
+            index,
+        )
+    }
+
     fn depth(&self) -> usize {
         self.depth
     }
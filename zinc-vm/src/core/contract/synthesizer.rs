@@ -12,10 +12,12 @@ use franklin_crypto::bellman::SynthesisError;
 
 use zinc_build::Contract as BytecodeContract;
 use zinc_build::ContractMethod;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
 use crate::constraint_systems::dedup::Dedup as DedupCS;
 use crate::constraint_systems::logging::Logging as LoggingCS;
+use crate::core::contract::balances::Balances;
 use crate::core::contract::State;
 use crate::error::RuntimeError;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
@@ -30,6 +32,8 @@ pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>> {
     pub method: ContractMethod,
     pub storage: S,
     pub transactions: Vec<TransactionMsg>,
+    pub balances: Balances,
+    pub environment: Environment,
 
     pub _pd: PhantomData<E>,
 }
@@ -50,6 +54,11 @@ where
             storage,
             self.method.name,
             self.transactions,
+            self.balances,
+            self.environment,
+            zinc_const::limit::VM_DATA_STACK_SIZE,
+            false,
+            false,
         );
 
         *self.output = Some(contract.run(
@@ -0,0 +1,820 @@
+//!
+//! The virtual machine contract tests.
+//!
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use num::BigInt;
+use num::BigUint;
+use num::ToBigUint;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_build::CallLibrary;
+use zinc_build::Contract as BuildContract;
+use zinc_build::ContractFieldType;
+use zinc_build::ContractFieldValue;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::Exit;
+use zinc_build::IntegerType;
+use zinc_build::LibraryFunctionIdentifier;
+use zinc_build::Load;
+use zinc_build::LoadByIndex;
+use zinc_build::Push;
+use zinc_build::ScalarType;
+use zinc_build::ScalarValue;
+use zinc_build::Type as BuildType;
+use zinc_build::Value as BuildValue;
+
+use crate::core::contract::facade::Facade as ContractFacade;
+use crate::core::contract::input::Input as ContractInput;
+use crate::core::facade::Facade;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+
+const ARGUMENT_VALUE: &str = "987654321987654321";
+const STORAGE_VALUE: &str = "123456789123456789";
+const CONSTRUCTOR_VALUE: u64 = 42;
+const VERSION_VALUE: u64 = 3;
+const TRANSFER_TOKEN: u64 = 0;
+const TRANSFER_RECIPIENT: u64 = 1;
+const TRANSFER_AMOUNT_THEN: u64 = 100;
+const TRANSFER_AMOUNT_ELSE: u64 = 200;
+const GUARDED_LENGTH: usize = 2;
+const GUARDED_VALUE: u64 = 7;
+const RNG_SEED: [u8; crate::RNG_SEED_SIZE] = [42; crate::RNG_SEED_SIZE];
+
+lazy_static! {
+    static ref RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+struct CapturingLogger;
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        RECORDS
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger;
+
+fn new_contract() -> BuildContract {
+    let balance_type = BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+
+    let method = BuildContractMethod::new(
+        0,
+        "get".to_owned(),
+        0,
+        false,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![("value".to_owned(), balance_type.clone())]),
+        balance_type.clone(),
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    BuildContract::new(
+        "test".to_owned(),
+        vec![ContractFieldType::new(
+            "balance".to_owned(),
+            balance_type,
+            true,
+            false,
+        )],
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![
+            Load::new(
+                zinc_const::contract::TRANSACTION_SIZE + zinc_const::contract::ENVIRONMENT_SIZE,
+                1,
+            )
+            .into(),
+            Exit::new(1).into(),
+        ],
+    )
+}
+
+fn new_contract_with_public_argument() -> BuildContract {
+    let mut contract = new_contract();
+    contract
+        .methods
+        .get_mut("get")
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .public_arguments = vec!["value".to_owned()];
+    contract
+}
+
+fn new_input(privacy: bool) -> ContractInput {
+    let value = |decimal: &str| {
+        BuildValue::Scalar(ScalarValue::Integer(
+            decimal
+                .parse::<BigInt>()
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            IntegerType::BALANCE,
+        ))
+    };
+
+    let mut input = ContractInput::new(
+        BuildValue::Structure(vec![("value".to_owned(), value(ARGUMENT_VALUE))]),
+        BuildValue::Contract(vec![ContractFieldValue::new(
+            "balance".to_owned(),
+            value(STORAGE_VALUE),
+            true,
+            false,
+        )]),
+        "get".to_owned(),
+        Vec::new(),
+    );
+    input.privacy = privacy;
+    input
+}
+
+fn new_storage_types() -> Vec<ContractFieldType> {
+    let address_type = BuildType::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS));
+    let balance_type = BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+
+    vec![
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_ADDRESS.to_owned(),
+            address_type.clone(),
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_BALANCES.to_owned(),
+            BuildType::Map {
+                key_type: Box::new(address_type),
+                value_type: Box::new(balance_type.clone()),
+            },
+            true,
+            true,
+        ),
+        ContractFieldType::new(
+            zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            BuildType::Scalar(ScalarType::Integer(IntegerType::LAYOUT_HASH)),
+            true,
+            true,
+        ),
+        ContractFieldType::new("balance".to_owned(), balance_type, true, false),
+    ]
+}
+
+fn new_contract_with_constructor() -> BuildContract {
+    let balance_type = BuildType::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+    let storage = new_storage_types();
+
+    let method = BuildContractMethod::new(
+        0,
+        zinc_const::contract::CONSTRUCTOR_NAME.to_owned(),
+        0,
+        false,
+        true,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![("value".to_owned(), balance_type)]),
+        BuildType::Contract(storage.clone()),
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    BuildContract::new(
+        "test".to_owned(),
+        storage,
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![
+            Load::new(
+                zinc_const::contract::TRANSACTION_SIZE + zinc_const::contract::ENVIRONMENT_SIZE,
+                1,
+            )
+            .into(),
+            Exit::new(3).into(),
+        ],
+    )
+}
+
+fn new_contract_with_mutable_method() -> BuildContract {
+    let method = BuildContractMethod::new(
+        0,
+        "touch".to_owned(),
+        0,
+        true,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::new_empty_structure(),
+        BuildType::Unit,
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    BuildContract::new(
+        "test".to_owned(),
+        new_storage_types(),
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![Exit::new(0).into()],
+    )
+}
+
+fn new_contract_with_associated_method() -> BuildContract {
+    let method = BuildContractMethod::new(
+        0,
+        "version".to_owned(),
+        0,
+        false,
+        true,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::new_empty_structure(),
+        BuildType::Scalar(ScalarType::Integer(IntegerType::U8)),
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    BuildContract::new(
+        "test".to_owned(),
+        new_storage_types(),
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![
+            Push::new(BigInt::from(VERSION_VALUE), IntegerType::U8.into()).into(),
+            Exit::new(1).into(),
+        ],
+    )
+}
+
+fn new_contract_with_conditional_transfers() -> BuildContract {
+    let method = BuildContractMethod::new(
+        0,
+        "send".to_owned(),
+        0,
+        false,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![(
+            "flag".to_owned(),
+            BuildType::Scalar(ScalarType::Boolean),
+        )]),
+        BuildType::Unit,
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    let transfer = |amount: u64| {
+        vec![
+            Push::new(
+                BigInt::from(TRANSFER_RECIPIENT),
+                IntegerType::ETH_ADDRESS.into(),
+            )
+            .into(),
+            Push::new(
+                BigInt::from(TRANSFER_TOKEN),
+                IntegerType::ETH_ADDRESS.into(),
+            )
+            .into(),
+            Push::new(BigInt::from(amount), IntegerType::BALANCE.into()).into(),
+            CallLibrary::new(LibraryFunctionIdentifier::ZksyncTransfer, 3, 0).into(),
+        ]
+    };
+
+    let mut instructions = vec![
+        Load::new(
+            zinc_const::contract::TRANSACTION_SIZE + zinc_const::contract::ENVIRONMENT_SIZE,
+            1,
+        )
+        .into(),
+        zinc_build::If.into(),
+    ];
+    instructions.extend(transfer(TRANSFER_AMOUNT_THEN));
+    instructions.push(zinc_build::Else.into());
+    instructions.extend(transfer(TRANSFER_AMOUNT_ELSE));
+    instructions.push(zinc_build::EndIf.into());
+    instructions.push(Exit::new(0).into());
+
+    BuildContract::new(
+        "test".to_owned(),
+        new_storage_types(),
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        instructions,
+    )
+}
+
+///
+/// The `index < 2 && values[index] == 7` method, whose right operand traps if the index is out
+/// of bounds.
+///
+fn new_contract_with_guarded_index() -> BuildContract {
+    let byte_type = BuildType::Scalar(ScalarType::Integer(IntegerType::U8));
+
+    let method = BuildContractMethod::new(
+        0,
+        "contains".to_owned(),
+        0,
+        false,
+        false,
+        false,
+        None,
+        None,
+        vec![],
+        BuildType::Structure(vec![
+            ("index".to_owned(), byte_type.clone()),
+            (
+                "values".to_owned(),
+                BuildType::Array(Box::new(byte_type), GUARDED_LENGTH),
+            ),
+        ]),
+        BuildType::Scalar(ScalarType::Boolean),
+    );
+    let mut methods = HashMap::with_capacity(1);
+    methods.insert(method.name.clone(), method);
+
+    let address = zinc_const::contract::TRANSACTION_SIZE + zinc_const::contract::ENVIRONMENT_SIZE;
+
+    BuildContract::new(
+        "test".to_owned(),
+        new_storage_types(),
+        methods,
+        HashMap::new(),
+        HashMap::new(),
+        vec![
+            Load::new(address, 1).into(),
+            Push::new(BigInt::from(GUARDED_LENGTH), IntegerType::U8.into()).into(),
+            zinc_build::Lt.into(),
+            zinc_build::If.into(),
+            zinc_build::ShortCircuit.into(),
+            Load::new(address, 1).into(),
+            LoadByIndex::new(address + 1, 1, GUARDED_LENGTH, false).into(),
+            Push::new(BigInt::from(GUARDED_VALUE), IntegerType::U8.into()).into(),
+            zinc_build::Eq.into(),
+            zinc_build::Else.into(),
+            Push::new(BigInt::from(0), ScalarType::Boolean).into(),
+            zinc_build::EndIf.into(),
+            Exit::new(1).into(),
+        ],
+    )
+}
+
+fn new_guarded_index_input(storage: BuildValue, index: usize, values: [u64; 2]) -> ContractInput {
+    let byte = |value: u64| BuildValue::Scalar(ScalarValue::Integer(value.into(), IntegerType::U8));
+
+    ContractInput::new(
+        BuildValue::Structure(vec![
+            ("index".to_owned(), byte(index as u64)),
+            (
+                "values".to_owned(),
+                BuildValue::Array(values.iter().copied().map(byte).collect()),
+            ),
+        ]),
+        storage,
+        "contains".to_owned(),
+        Vec::new(),
+    )
+}
+
+fn new_conditional_transfers_input(storage: BuildValue, flag: bool) -> ContractInput {
+    ContractInput::new(
+        BuildValue::Structure(vec![(
+            "flag".to_owned(),
+            BuildValue::Scalar(ScalarValue::Boolean(flag)),
+        )]),
+        storage,
+        "send".to_owned(),
+        Vec::new(),
+    )
+}
+
+fn new_constructor_input(storage: BuildValue) -> ContractInput {
+    ContractInput::new(
+        BuildValue::Structure(vec![(
+            "value".to_owned(),
+            BuildValue::Scalar(ScalarValue::Integer(
+                BigInt::from(CONSTRUCTOR_VALUE),
+                IntegerType::BALANCE,
+            )),
+        )]),
+        storage,
+        zinc_const::contract::CONSTRUCTOR_NAME.to_owned(),
+        Vec::new(),
+    )
+}
+
+fn new_mutable_method_input(storage: BuildValue) -> ContractInput {
+    ContractInput::new(
+        BuildValue::Structure(vec![]),
+        storage,
+        "touch".to_owned(),
+        Vec::new(),
+    )
+}
+
+fn new_storage(address: u64, layout_hash: BigInt) -> BuildValue {
+    BuildValue::Contract(vec![
+        ContractFieldValue::new(
+            zinc_const::contract::FIELD_NAME_ADDRESS.to_owned(),
+            BuildValue::Scalar(ScalarValue::Integer(
+                BigInt::from(address),
+                IntegerType::ETH_ADDRESS,
+            )),
+            true,
+            true,
+        ),
+        ContractFieldValue::new(
+            zinc_const::contract::FIELD_NAME_BALANCES.to_owned(),
+            BuildValue::Map(vec![]),
+            true,
+            true,
+        ),
+        ContractFieldValue::new(
+            zinc_const::contract::FIELD_NAME_LAYOUT_HASH.to_owned(),
+            BuildValue::Scalar(ScalarValue::Integer(layout_hash, IntegerType::LAYOUT_HASH)),
+            true,
+            true,
+        ),
+        ContractFieldValue::new(
+            "balance".to_owned(),
+            BuildValue::Scalar(ScalarValue::Integer(BigInt::from(0), IntegerType::BALANCE)),
+            true,
+            false,
+        ),
+    ])
+}
+
+fn new_uninitialized_storage() -> BuildValue {
+    new_storage(0, BigInt::from(0))
+}
+
+#[test]
+fn ok_constructor_initializes_storage() {
+    let contract = new_contract_with_constructor();
+    let layout_hash = contract.layout_hash.clone();
+
+    let output = ContractFacade::new(contract)
+        .run::<Bn256>(new_constructor_input(new_uninitialized_storage()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let storage =
+        output.into_initialized_storage(BigInt::from(zinc_const::contract::LOCAL_ADDRESS));
+
+    assert_eq!(
+        storage.into_flat_values(),
+        vec![
+            BigInt::from(zinc_const::contract::LOCAL_ADDRESS),
+            layout_hash,
+            BigInt::from(CONSTRUCTOR_VALUE),
+        ]
+    );
+}
+
+#[test]
+fn error_constructor_called_twice() {
+    let output = ContractFacade::new(new_contract_with_constructor())
+        .run::<Bn256>(new_constructor_input(new_uninitialized_storage()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let storage =
+        output.into_initialized_storage(BigInt::from(zinc_const::contract::LOCAL_ADDRESS));
+
+    let result = ContractFacade::new(new_contract_with_constructor())
+        .run::<Bn256>(new_constructor_input(storage));
+
+    match result {
+        Err(RuntimeError::AlreadyInitialized { address }) => {
+            assert_eq!(address, BigUint::from(zinc_const::contract::LOCAL_ADDRESS))
+        }
+        result => panic!("expected the already initialized error, found {:?}", result),
+    }
+}
+
+#[test]
+fn ok_mutable_method_layout_hash_matches() {
+    let contract = new_contract_with_mutable_method();
+    let storage = new_storage(
+        zinc_const::contract::LOCAL_ADDRESS,
+        contract.layout_hash.clone(),
+    );
+
+    let result = ContractFacade::new(contract).run::<Bn256>(new_mutable_method_input(storage));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_mutable_method_layout_hash_mismatch() {
+    let contract = new_contract_with_mutable_method();
+    let layout_hash = contract.layout_hash.clone();
+    let storage = new_storage(zinc_const::contract::LOCAL_ADDRESS, BigInt::from(42));
+
+    let result = ContractFacade::new(contract).run::<Bn256>(new_mutable_method_input(storage));
+
+    match result {
+        Err(RuntimeError::StorageLayoutMismatch { expected, found }) => {
+            assert_eq!(expected, layout_hash.to_biguint().unwrap_or_default());
+            assert_eq!(found, BigUint::from(42u8));
+        }
+        result => panic!(
+            "expected the storage layout mismatch error, found {:?}",
+            result
+        ),
+    }
+}
+
+#[test]
+fn ok_associated_method_empty_storage() {
+    let contract = new_contract_with_associated_method();
+    let storage = BuildValue::new(BuildType::Contract(contract.storage.clone()));
+
+    let output = ContractFacade::new(contract)
+        .run::<Bn256>(ContractInput::new(
+            BuildValue::Structure(vec![]),
+            storage,
+            "version".to_owned(),
+            Vec::new(),
+        ))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        output.result.into_flat_values(),
+        vec![BigInt::from(VERSION_VALUE)]
+    );
+}
+
+#[test]
+fn ok_conditional_transfers() {
+    for (flag, amount) in [(true, TRANSFER_AMOUNT_THEN), (false, TRANSFER_AMOUNT_ELSE)]
+        .iter()
+        .copied()
+    {
+        let contract = new_contract_with_conditional_transfers();
+        let storage = new_storage(
+            zinc_const::contract::LOCAL_ADDRESS,
+            contract.layout_hash.clone(),
+        );
+
+        let output = ContractFacade::new(contract)
+            .run::<Bn256>(new_conditional_transfers_input(storage, flag))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(output.transfers.len(), 1);
+        assert_eq!(output.transfers[0].amount, BigUint::from(amount));
+        assert_eq!(
+            output.transfers[0].token_address,
+            BigUint::from(TRANSFER_TOKEN)
+        );
+    }
+}
+
+#[test]
+fn ok_privacy_logs_redacted() {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let output = ContractFacade::new(new_contract())
+        .run::<Bn256>(new_input(true))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        output.result.into_flat_values(),
+        vec![ARGUMENT_VALUE
+            .parse::<BigInt>()
+            .expect(zinc_const::panic::TEST_DATA_VALID)]
+    );
+
+    let records = RECORDS.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+    assert!(!records.is_empty());
+    for record in records.iter() {
+        assert!(!record.contains(ARGUMENT_VALUE), "leaked: {}", record);
+        assert!(!record.contains(STORAGE_VALUE), "leaked: {}", record);
+    }
+}
+
+#[test]
+fn ok_short_circuit_guarded_index() {
+    for (index, values, expected) in [
+        (0, [GUARDED_VALUE, 0], 1),
+        (1, [GUARDED_VALUE, 0], 0),
+        (GUARDED_LENGTH, [GUARDED_VALUE, GUARDED_VALUE], 0),
+        (u8::MAX as usize, [GUARDED_VALUE, GUARDED_VALUE], 0),
+    ]
+    .iter()
+    .copied()
+    {
+        let contract = new_contract_with_guarded_index();
+        let storage = new_storage(
+            zinc_const::contract::LOCAL_ADDRESS,
+            contract.layout_hash.clone(),
+        );
+
+        let output = ContractFacade::new(contract)
+            .run::<Bn256>(new_guarded_index_input(storage, index, values))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(
+            output.result.into_flat_values(),
+            vec![BigInt::from(expected)],
+            "index {}",
+            index
+        );
+    }
+}
+
+#[test]
+fn error_short_circuit_guarded_index_proving() {
+    let contract = new_contract_with_guarded_index();
+    let storage = new_storage(
+        zinc_const::contract::LOCAL_ADDRESS,
+        contract.layout_hash.clone(),
+    );
+
+    let result = ContractFacade::new(contract).witness::<Bn256>(new_guarded_index_input(
+        storage,
+        GUARDED_LENGTH,
+        [GUARDED_VALUE, GUARDED_VALUE],
+    ));
+
+    assert!(result.is_err(), "the right operand must be evaluated");
+}
+
+#[test]
+fn error_short_circuit_without_else() {
+    let mut contract = new_contract_with_guarded_index();
+    contract.instructions.retain(|instruction| {
+        !matches!(
+            instruction,
+            zinc_build::Instruction::Else(_) | zinc_build::Instruction::EndIf(_)
+        )
+    });
+    let storage = new_storage(
+        zinc_const::contract::LOCAL_ADDRESS,
+        contract.layout_hash.clone(),
+    );
+
+    let result = ContractFacade::new(contract).run::<Bn256>(new_guarded_index_input(
+        storage,
+        GUARDED_LENGTH,
+        [GUARDED_VALUE, GUARDED_VALUE],
+    ));
+
+    match result {
+        Err(RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedShortCircuit)) => {}
+        result => panic!(
+            "expected the unexpected short-circuit error, found {:?}",
+            result
+        ),
+    }
+}
+
+#[test]
+fn ok_immutable_method_public_argument_prove_verify() {
+    let contract = new_contract_with_public_argument();
+    let argument_value = ARGUMENT_VALUE
+        .parse::<BigInt>()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let (params, _report) = ContractFacade::new(contract.clone())
+        .setup::<Bn256>("get".to_owned(), None, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let verifying_key = params.vk.clone();
+
+    let input = new_input(false);
+    let environment = input.environment.clone();
+    let public_arguments = contract
+        .methods
+        .get("get")
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .public_argument_values(input.arguments.clone());
+    assert_eq!(public_arguments, vec![argument_value.clone()]);
+
+    let (_output, public_input) = ContractFacade::new(contract.clone())
+        .witness::<Bn256>(new_input(false))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(public_input[0], argument_value);
+    assert_eq!(public_input[1], argument_value);
+
+    let (output, proof) = ContractFacade::new(contract)
+        .prove::<Bn256>(params, input, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(output.clone().into_flat_values()[0], argument_value);
+
+    let verified = Facade::verify_contract(
+        verifying_key.clone(),
+        proof.clone(),
+        public_arguments,
+        output.clone(),
+        environment.clone(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(verified, "the getter proof must be verified");
+
+    let forged = Facade::verify_contract(
+        verifying_key,
+        proof,
+        vec![BigInt::from(1)],
+        output,
+        environment,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(!forged, "the proof must commit to the public argument");
+}
+
+#[test]
+fn ok_size_matches_setup() {
+    let contract = new_contract_with_public_argument();
+
+    let report = ContractFacade::new(contract.clone())
+        .size::<Bn256>("get")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let (_params, setup_report) = ContractFacade::new(contract)
+        .setup::<Bn256>("get".to_owned(), None, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(report, setup_report);
+}
+
+#[test]
+fn error_size_method_not_found() {
+    let result = ContractFacade::new(new_contract()).size::<Bn256>("missing");
+
+    match result {
+        Err(RuntimeError::MethodNotFound { found }) if found == "missing" => {}
+        result => panic!("expected the method not found error, found {:?}", result),
+    }
+}
+
+#[test]
+fn ok_seeded_setup_reproducible() {
+    let contract = new_contract_with_public_argument();
+
+    let mut first = Vec::new();
+    ContractFacade::new(contract.clone())
+        .setup::<Bn256>("get".to_owned(), None, Some(RNG_SEED))
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .0
+        .write(&mut first)
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+    let mut second = Vec::new();
+    ContractFacade::new(contract)
+        .setup::<Bn256>("get".to_owned(), None, Some(RNG_SEED))
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .0
+        .write(&mut second)
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+    assert!(first == second, "the seeded parameters must be identical");
+}
+
+#[test]
+fn ok_seeded_prove_verify() {
+    let contract = new_contract_with_public_argument();
+
+    let (params, _report) = ContractFacade::new(contract.clone())
+        .setup::<Bn256>("get".to_owned(), None, Some(RNG_SEED))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let verifying_key = params.vk.clone();
+
+    let input = new_input(false);
+    let environment = input.environment.clone();
+    let public_arguments = contract
+        .methods
+        .get("get")
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .public_argument_values(input.arguments.clone());
+
+    let (output, proof) = ContractFacade::new(contract)
+        .prove::<Bn256>(params, input, Some(RNG_SEED))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let verified =
+        Facade::verify_contract(verifying_key, proof, public_arguments, output, environment)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(verified, "the seeded proof must be verified");
+}
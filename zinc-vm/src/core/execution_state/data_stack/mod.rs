@@ -26,19 +26,28 @@ use self::branch::DataStackDelta;
 pub struct DataStack<E: IEngine> {
     memory: Vec<Option<Cell<E>>>,
     branches: Vec<DataStackBranch<E>>,
+    limit: usize,
 }
 
 impl<E: IEngine> DataStack<E> {
     const MEMORY_INITIAL_CAPACITY: usize = 16384;
     const BRANCHES_INITIAL_CAPACITY: usize = 64;
 
-    pub fn new() -> Self {
+    pub fn new(limit: usize) -> Self {
         Self {
-            memory: Vec::with_capacity(Self::MEMORY_INITIAL_CAPACITY),
+            memory: Vec::with_capacity(std::cmp::min(Self::MEMORY_INITIAL_CAPACITY, limit)),
             branches: Vec::with_capacity(Self::BRANCHES_INITIAL_CAPACITY),
+            limit,
         }
     }
 
+    ///
+    /// The maximal number of cells the data stack can hold.
+    ///
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
     pub fn get(&mut self, address: usize) -> Result<Cell<E>, RuntimeError> {
         self.memory
             .get(address)
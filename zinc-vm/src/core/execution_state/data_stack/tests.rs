@@ -25,7 +25,7 @@ fn assert_cell_eq<E: IEngine>(cell: Cell<E>, value: BigInt) {
 
 #[test]
 fn test_get_set() {
-    let mut ds = DataStack::<Bn256>::new();
+    let mut ds = DataStack::<Bn256>::new(zinc_const::limit::VM_DATA_STACK_SIZE);
     let value = Scalar::new_constant_usize(42, ScalarType::Field);
     ds.set(4, Cell::Value(value))
         .expect(zinc_const::panic::TEST_DATA_VALID);
@@ -38,7 +38,7 @@ fn test_get_set() {
 
 #[test]
 fn test_fork_merge_true() {
-    let mut ds = DataStack::new();
+    let mut ds = DataStack::new(zinc_const::limit::VM_DATA_STACK_SIZE);
     let cs = TestConstraintSystem::<Bn256>::new();
     let value = Scalar::new_constant_usize(42, ScalarType::Field);
     ds.set(4, Cell::Value(value))
@@ -70,7 +70,7 @@ fn test_fork_merge_true() {
 
 #[test]
 fn test_fork_merge_false() {
-    let mut ds = DataStack::new();
+    let mut ds = DataStack::new(zinc_const::limit::VM_DATA_STACK_SIZE);
     let cs = TestConstraintSystem::<Bn256>::new();
     let value = Scalar::new_constant_usize(42, ScalarType::Field);
     ds.set(4, Cell::Value(value))
@@ -59,7 +59,7 @@ impl<E: IEngine> EvaluationStack<E> {
             .push(Vec::with_capacity(Self::STACK_INITIAL_CAPACITY));
     }
 
-    pub fn merge<CS>(&mut self, mut cs: CS, condition: &Scalar<E>) -> Result<(), RuntimeError>
+    pub fn merge<CS>(&mut self, cs: CS, condition: &Scalar<E>) -> Result<(), RuntimeError>
     where
         CS: ConstraintSystem<E>,
     {
@@ -74,22 +74,51 @@ impl<E: IEngine> EvaluationStack<E> {
             return Err(MalformedBytecode::BranchStacksDoNotMatch.into());
         }
 
-        for (index, (main_value, else_value)) in
-            then_case.into_iter().zip(else_case.into_iter()).enumerate()
-        {
-            match (main_value, else_value) {
-                (Cell::Value(main_value), Cell::Value(else_value)) => {
-                    let merged = gadgets::select::conditional(
-                        cs.namespace(|| format!("merge {}", index)),
-                        condition,
-                        &main_value,
-                        &else_value,
-                    )?;
-
-                    self.push(Cell::Value(merged))?;
-                }
-            }
-        }
+        let then_values: Vec<Scalar<E>> = then_case
+            .into_iter()
+            .map(|cell| {
+                let Cell::Value(value) = cell;
+                value
+            })
+            .collect();
+        let else_values: Vec<Scalar<E>> = else_case
+            .into_iter()
+            .map(|cell| {
+                let Cell::Value(value) = cell;
+                value
+            })
+            .collect();
+
+        self.merge_many(
+            cs,
+            condition,
+            then_values.as_slice(),
+            else_values.as_slice(),
+        )
+    }
+
+    ///
+    /// Conditionally selects the contiguous `then_values` or `else_values` slots at once
+    /// and pushes the selected values onto the stack.
+    ///
+    pub fn merge_many<CS>(
+        &mut self,
+        cs: CS,
+        condition: &Scalar<E>,
+        then_values: &[Scalar<E>],
+        else_values: &[Scalar<E>],
+    ) -> Result<(), RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let merged = gadgets::select::conditional_many(cs, condition, then_values, else_values)?;
+
+        self.stack
+            .last_mut()
+            .ok_or_else(|| {
+                RuntimeError::InternalError("Evaluation stack root frame missing".into())
+            })?
+            .extend(merged.into_iter().map(Cell::Value));
 
         Ok(())
     }
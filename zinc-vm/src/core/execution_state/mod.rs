@@ -10,7 +10,10 @@ pub mod function_frame;
 
 use std::fmt;
 
+use crate::core::contract::balances::Balances;
 use crate::core::contract::output::transfer::Transfer;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
@@ -26,6 +29,7 @@ pub struct ExecutionState<E: IEngine> {
     pub conditions_stack: Vec<Scalar<E>>,
     pub frames_stack: Vec<Frame<E>>,
     pub transfers: Vec<Transfer>,
+    pub balances: Balances,
 }
 
 impl<E: IEngine> ExecutionState<E> {
@@ -33,16 +37,44 @@ impl<E: IEngine> ExecutionState<E> {
     const FRAMES_INITIAL_CAPACITY: usize = 16;
     const TRANSFERS_INITIAL_CAPACITY: usize = 4;
 
-    pub fn new() -> Self {
+    pub fn new(data_stack_limit: usize) -> Self {
         Self {
             instruction_counter: 0,
             evaluation_stack: EvaluationStack::new(),
-            data_stack: DataStack::new(),
+            data_stack: DataStack::new(data_stack_limit),
             conditions_stack: Vec::with_capacity(Self::CONDITIONS_INITIAL_CAPACITY),
             frames_stack: Vec::with_capacity(Self::FRAMES_INITIAL_CAPACITY),
             transfers: Vec::with_capacity(Self::TRANSFERS_INITIAL_CAPACITY),
+            balances: Balances::default(),
         }
     }
+
+    ///
+    /// Checks whether the absolute data stack `address` fits the data stack limit.
+    ///
+    /// The return address of the current function frame is attached to the error, so the
+    /// function which has tried to access the address can be identified.
+    ///
+    pub fn check_data_stack_address(&self, address: usize) -> Result<(), RuntimeError> {
+        let limit = self.data_stack.limit();
+        if address < limit {
+            return Ok(());
+        }
+
+        let return_address = self
+            .frames_stack
+            .last()
+            .map(|frame| frame.return_address)
+            .ok_or(MalformedBytecode::StackUnderflow)?;
+
+        Err(RuntimeError::MalformedBytecode(
+            MalformedBytecode::DataStackOverflow {
+                address,
+                limit,
+                return_address,
+            },
+        ))
+    }
 }
 
 impl<E: IEngine> fmt::Display for ExecutionState<E> {
@@ -6,7 +6,10 @@ use franklin_crypto::bellman::groth16;
 use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::groth16::VerifyingKey;
 
+use num::BigInt;
+
 use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
 
 use crate::error::VerificationError;
 use crate::gadgets;
@@ -19,9 +22,46 @@ impl Facade {
         verifying_key: VerifyingKey<E>,
         proof: Proof<E>,
         public_input: BuildValue,
+    ) -> Result<bool, VerificationError> {
+        Self::verify_flat(
+            verifying_key,
+            proof,
+            crate::core::public_input::assemble(public_input, None),
+        )
+    }
+
+    ///
+    /// Verifies the contract method proof, which also commits to the `#[public]` method
+    /// argument scalars and the block `environment`.
+    ///
+    pub fn verify_contract<E: IEngine>(
+        verifying_key: VerifyingKey<E>,
+        proof: Proof<E>,
+        public_arguments: Vec<BigInt>,
+        public_input: BuildValue,
+        environment: Environment,
+    ) -> Result<bool, VerificationError> {
+        Self::verify_flat(
+            verifying_key,
+            proof,
+            crate::core::public_input::assemble_contract(
+                public_arguments,
+                public_input,
+                None,
+                environment,
+            ),
+        )
+    }
+
+    ///
+    /// Verifies the proof using the flat public input.
+    ///
+    fn verify_flat<E: IEngine>(
+        verifying_key: VerifyingKey<E>,
+        proof: Proof<E>,
+        public_input: Vec<BigInt>,
     ) -> Result<bool, VerificationError> {
         let public_input_flat = public_input
-            .into_flat_values()
             .into_iter()
             .map(|value| {
                 gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&value)
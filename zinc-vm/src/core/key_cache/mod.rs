@@ -0,0 +1,187 @@
+//!
+//! The virtual machine proving key cache.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::pairing::Engine;
+
+use zinc_build::Circuit as BuildCircuit;
+use zinc_build::Contract as BuildContract;
+
+///
+/// The persistent proving key cache.
+///
+/// The keys are stored as `<hash>.params` files, where the hash is calculated from the bytecode
+/// and types which affect the circuit shape. If the cache grows beyond its size limit,
+/// the least recently used keys are removed.
+///
+pub struct KeyCache {
+    /// The cache directory path.
+    path: PathBuf,
+    /// The cache directory size limit in bytes.
+    size_limit: u64,
+}
+
+impl KeyCache {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: PathBuf, size_limit: u64) -> Self {
+        Self { path, size_limit }
+    }
+
+    ///
+    /// Initializes the cache at the default `~/.zinc/keys/` location.
+    ///
+    /// Returns `None` if the user home directory cannot be determined.
+    ///
+    pub fn new_default() -> Option<Self> {
+        let mut path = PathBuf::from(env::var_os("HOME")?);
+        path.push(zinc_const::directory::KEYS_CACHE);
+
+        Some(Self::new(path, zinc_const::limit::KEYS_CACHE_SIZE))
+    }
+
+    ///
+    /// Calculates the cache key of a circuit.
+    ///
+    pub fn hash_circuit(circuit: &BuildCircuit) -> String {
+        let data = serde_json::to_vec(&(
+            circuit.address,
+            &circuit.input,
+            &circuit.output,
+            &circuit.instructions,
+            &circuit.constants,
+        ))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+        hex::encode(Sha256::digest(data.as_slice()))
+    }
+
+    ///
+    /// Calculates the cache key of a contract method.
+    ///
+    /// Returns `None` if the method does not exist.
+    ///
+    pub fn hash_contract_method(contract: &BuildContract, method_name: &str) -> Option<String> {
+        let method = contract.methods.get(method_name)?;
+
+        let data = serde_json::to_vec(&(
+            method,
+            &contract.storage,
+            &contract.instructions,
+            &contract.constants,
+        ))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+        Some(hex::encode(Sha256::digest(data.as_slice())))
+    }
+
+    ///
+    /// Loads the proving key with the `hash` from the cache.
+    ///
+    /// Returns `None` if the key is absent. If the key file is corrupted, it is removed and
+    /// `None` is returned as well.
+    ///
+    pub fn load<E: Engine>(&self, hash: &str) -> Option<Parameters<E>> {
+        let path = self.file_path(hash);
+
+        let file = fs::File::open(&path).ok()?;
+        match Parameters::<E>::read(&file, true) {
+            Ok(params) => {
+                if let Err(error) = file.set_modified(SystemTime::now()) {
+                    log::warn!("Proving key cache file {:?} touching: {}", path, error);
+                }
+                log::debug!("Proving key cache hit: {}", hash);
+                Some(params)
+            }
+            Err(error) => {
+                log::warn!(
+                    "Proving key cache file {:?} is corrupted and will be removed: {}",
+                    path,
+                    error
+                );
+                if let Err(error) = fs::remove_file(&path) {
+                    log::warn!("Proving key cache file {:?} removing: {}", path, error);
+                }
+                None
+            }
+        }
+    }
+
+    ///
+    /// Stores the proving key with the `hash` in the cache, and prunes the least recently used
+    /// keys if the cache size limit is exceeded.
+    ///
+    pub fn store<E: Engine>(&self, hash: &str, params: &Parameters<E>) -> io::Result<()> {
+        fs::create_dir_all(&self.path)?;
+
+        let path = self.file_path(hash);
+        let mut temporary_path = path.clone();
+        temporary_path.set_extension("tmp");
+
+        let file = fs::File::create(&temporary_path)?;
+        params.write(file)?;
+        fs::rename(&temporary_path, &path)?;
+        log::debug!("Proving key cached: {}", hash);
+
+        self.prune()
+    }
+
+    ///
+    /// Removes the least recently used keys until the cache fits into its size limit.
+    ///
+    /// The most recently used key is never removed.
+    ///
+    pub fn prune(&self) -> io::Result<()> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|extension| extension.to_str())
+                != Some(zinc_const::extension::PARAMETERS)
+            {
+                continue;
+            }
+
+            let metadata = fs::metadata(&path)?;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((path, modified, metadata.len()));
+        }
+        files.sort_by_key(|(_path, modified, _size)| *modified);
+
+        let mut total_size: u64 = files.iter().map(|(_path, _modified, size)| size).sum();
+        let removable = files.len().saturating_sub(1);
+        for (path, _modified, size) in files.into_iter().take(removable) {
+            if total_size <= self.size_limit {
+                break;
+            }
+
+            fs::remove_file(&path)?;
+            log::debug!("Proving key cache file {:?} pruned", path);
+            total_size -= size;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the key file path for the `hash`.
+    ///
+    fn file_path(&self, hash: &str) -> PathBuf {
+        let mut path = self.path.clone();
+        path.push(format!("{}.{}", hash, zinc_const::extension::PARAMETERS));
+        path
+    }
+}
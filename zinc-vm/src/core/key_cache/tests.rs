@@ -0,0 +1,113 @@
+//!
+//! The virtual machine proving key cache tests.
+//!
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_build::Circuit as BuildCircuit;
+use zinc_build::Push;
+use zinc_build::Return;
+use zinc_build::ScalarType;
+use zinc_build::Type as BuildType;
+
+use crate::core::circuit::facade::Facade as CircuitFacade;
+use crate::core::key_cache::KeyCache;
+
+fn new_circuit(value: u8) -> BuildCircuit {
+    BuildCircuit::new(
+        "test".to_owned(),
+        0,
+        BuildType::Unit,
+        BuildType::Scalar(ScalarType::Field),
+        HashMap::new(),
+        vec![
+            Push::new_field(BigInt::from(value)).into(),
+            Return::new(1).into(),
+        ],
+    )
+}
+
+fn new_cache(name: &str) -> KeyCache {
+    let mut path = env::temp_dir();
+    path.push(format!("zinc-key-cache-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+
+    KeyCache::new(path, zinc_const::limit::KEYS_CACHE_SIZE)
+}
+
+#[test]
+fn hash_changes_with_bytecode() {
+    let first = KeyCache::hash_circuit(&new_circuit(42));
+    let second = KeyCache::hash_circuit(&new_circuit(42));
+    let third = KeyCache::hash_circuit(&new_circuit(24));
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+}
+
+#[test]
+fn miss() {
+    let cache = new_cache("miss");
+    let hash = KeyCache::hash_circuit(&new_circuit(42));
+
+    assert!(cache.load::<Bn256>(hash.as_str()).is_none());
+}
+
+#[test]
+fn hit() {
+    let cache = new_cache("hit");
+    let circuit = new_circuit(42);
+    let hash = KeyCache::hash_circuit(&circuit);
+
+    let (params, _report): (Parameters<Bn256>, _) = CircuitFacade::new(circuit)
+        .setup::<Bn256>(None, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    cache
+        .store(hash.as_str(), &params)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let loaded = cache
+        .load::<Bn256>(hash.as_str())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(loaded.vk == params.vk);
+}
+
+#[test]
+fn corrupted() {
+    let cache = new_cache("corrupted");
+    let hash = KeyCache::hash_circuit(&new_circuit(42));
+    let path = cache.file_path(hash.as_str());
+
+    fs::create_dir_all(path.parent().expect(zinc_const::panic::TEST_DATA_VALID))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::write(&path, b"corrupted").expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(cache.load::<Bn256>(hash.as_str()).is_none());
+    assert!(!path.exists());
+}
+
+#[test]
+fn prune() {
+    let mut cache = new_cache("prune");
+    cache.size_limit = 16;
+
+    fs::create_dir_all(&cache.path).expect(zinc_const::panic::TEST_DATA_VALID);
+    let oldest = cache.file_path("oldest");
+    let newest = cache.file_path("newest");
+    fs::write(&oldest, [0u8; 16]).expect(zinc_const::panic::TEST_DATA_VALID);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    fs::write(&newest, [0u8; 16]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    cache.prune().expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(!oldest.exists());
+    assert!(newest.exists());
+}
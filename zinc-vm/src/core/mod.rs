@@ -7,5 +7,9 @@ pub mod contract;
 pub mod counter;
 pub mod execution_state;
 pub mod facade;
+pub mod key_cache;
 pub mod location;
+pub mod public_input;
+pub mod rng;
+pub mod setup_report;
 pub mod virtual_machine;
@@ -0,0 +1,46 @@
+//!
+//! The proof public input.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use num::BigInt;
+
+use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
+
+///
+/// Assembles the proof public input from the application output value and the optional
+/// contract storage root hash.
+///
+/// The output value scalars go first in the order they are flattened, and the root hash goes
+/// last, which is the order the outputs are allocated in the constraint system during proving.
+///
+/// The mutable contract method output already contains the root hash, so it is assembled
+/// without the `root_hash` argument.
+///
+pub fn assemble(output_value: BuildValue, root_hash: Option<BigInt>) -> Vec<BigInt> {
+    let mut public_input = output_value.into_flat_values();
+    public_input.extend(root_hash);
+    public_input
+}
+
+///
+/// Assembles the contract method proof public input.
+///
+/// The `#[public]` method argument scalars go first, since they are allocated along with the
+/// method arguments before the execution. The contract `environment` scalars go after the root
+/// hash, since they are allocated as the last outputs of the contract method constraint system.
+///
+pub fn assemble_contract(
+    public_arguments: Vec<BigInt>,
+    output_value: BuildValue,
+    root_hash: Option<BigInt>,
+    environment: Environment,
+) -> Vec<BigInt> {
+    let mut public_input = public_arguments;
+    public_input.extend(assemble(output_value, root_hash));
+    public_input.extend(environment.into_flat_values());
+    public_input
+}
@@ -0,0 +1,133 @@
+//!
+//! The proof public input tests.
+//!
+
+use num::BigInt;
+
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+use zinc_build::ScalarValue;
+use zinc_build::Type as BuildType;
+use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
+
+use crate::core::public_input;
+
+fn integer(value: u8) -> BuildValue {
+    BuildValue::Scalar(ScalarValue::Integer(
+        BigInt::from(value),
+        IntegerType::new(false, zinc_const::bitlength::BYTE),
+    ))
+}
+
+#[test]
+fn ok_circuit_output() {
+    let output_value = BuildValue::Structure(vec![
+        ("a".to_owned(), integer(1)),
+        (
+            "b".to_owned(),
+            BuildValue::Array(vec![integer(2), integer(3)]),
+        ),
+    ]);
+
+    let public_input = public_input::assemble(output_value, None);
+
+    assert_eq!(
+        public_input,
+        vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)]
+    );
+}
+
+#[test]
+fn ok_contract_output_root_hash_last() {
+    let output_value = BuildValue::Array(vec![integer(1), integer(2)]);
+    let root_hash = BigInt::from(42);
+
+    let public_input = public_input::assemble(output_value, Some(root_hash));
+
+    assert_eq!(
+        public_input,
+        vec![BigInt::from(1), BigInt::from(2), BigInt::from(42)]
+    );
+}
+
+#[test]
+fn ok_contract_unit_output() {
+    let public_input = public_input::assemble(BuildValue::Unit, Some(BigInt::from(42)));
+
+    assert_eq!(public_input, vec![BigInt::from(42)]);
+}
+
+#[test]
+fn ok_mutable_method_output_matches() {
+    let output_type = BuildType::Array(
+        Box::new(BuildType::Scalar(ScalarType::Integer(IntegerType::new(
+            false,
+            zinc_const::bitlength::BYTE,
+        )))),
+        2,
+    );
+    let output_flat = vec![BigInt::from(1), BigInt::from(2)];
+    let root_hash = BigInt::from(42);
+
+    let mut mutable_output_flat = output_flat.clone();
+    mutable_output_flat.push(root_hash.clone());
+    let mutable_output_value = BuildValue::from_flat_values(
+        output_type.clone().into_mutable_method_output(),
+        mutable_output_flat.as_slice(),
+    );
+    let output_value = BuildValue::from_flat_values(output_type, output_flat.as_slice());
+
+    assert_eq!(
+        public_input::assemble(mutable_output_value, None),
+        public_input::assemble(output_value, Some(root_hash)),
+    );
+}
+
+#[test]
+fn ok_contract_environment_after_root_hash() {
+    let output_value = BuildValue::Array(vec![integer(1), integer(2)]);
+    let root_hash = BigInt::from(42);
+    let environment = Environment::new(1_600_000_000, 256);
+
+    let public_input =
+        public_input::assemble_contract(vec![], output_value, Some(root_hash), environment);
+
+    assert_eq!(
+        public_input,
+        vec![
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(42),
+            BigInt::from(1_600_000_000),
+            BigInt::from(256),
+        ]
+    );
+}
+
+#[test]
+fn ok_contract_public_arguments_first() {
+    let output_value = BuildValue::Array(vec![integer(1), integer(2)]);
+    let root_hash = BigInt::from(42);
+    let environment = Environment::new(1_600_000_000, 256);
+
+    let public_input = public_input::assemble_contract(
+        vec![BigInt::from(7), BigInt::from(8)],
+        output_value,
+        Some(root_hash),
+        environment,
+    );
+
+    assert_eq!(
+        public_input,
+        vec![
+            BigInt::from(7),
+            BigInt::from(8),
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(42),
+            BigInt::from(1_600_000_000),
+            BigInt::from(256),
+        ]
+    );
+}
@@ -0,0 +1,31 @@
+//!
+//! The virtual machine random number generator.
+//!
+
+use rand::ChaChaRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// The random number generator seed size in bytes.
+pub const SEED_SIZE: usize = 32;
+
+///
+/// Creates the random number generator for the parameters and proof generation.
+///
+/// If the `seed` is specified, the ChaCha generator seeded with it is returned, so the
+/// parameters and proofs are reproducible bit-for-bit. The seeded mode is only intended for
+/// debugging and golden tests, since anyone knowing the seed can recover the toxic waste of the
+/// parameters and forge proofs.
+///
+pub fn new(seed: Option<[u8; SEED_SIZE]>) -> Box<dyn Rng> {
+    match seed {
+        Some(seed) => {
+            let words: Vec<u32> = seed
+                .chunks(4)
+                .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect();
+            Box::new(ChaChaRng::from_seed(words.as_slice()))
+        }
+        None => Box::new(rand::thread_rng()),
+    }
+}
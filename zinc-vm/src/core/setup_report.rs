@@ -0,0 +1,53 @@
+//!
+//! The virtual machine setup report.
+//!
+
+use std::fmt;
+
+use crate::error::RuntimeError;
+
+///
+/// The constraint system statistics, which are measured before generating the parameters.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetupReport {
+    /// The number of public inputs, including the constant `one` input.
+    pub inputs: usize,
+    /// The number of witness variables.
+    pub witness: usize,
+    /// The number of constraints.
+    pub constraints: usize,
+}
+
+impl SetupReport {
+    pub fn new(inputs: usize, witness: usize, constraints: usize) -> Self {
+        Self {
+            inputs,
+            witness,
+            constraints,
+        }
+    }
+
+    ///
+    /// Checks whether the number of constraints does not exceed the optional `limit`.
+    ///
+    pub fn check_limit(&self, limit: Option<usize>) -> Result<(), RuntimeError> {
+        match limit {
+            Some(limit) if self.constraints > limit => Err(RuntimeError::CircuitTooLarge {
+                constraints: self.constraints,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for SetupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "constraints: {}, inputs: {}, witness: {}",
+            self.constraints, self.inputs, self.witness
+        )
+    }
+}
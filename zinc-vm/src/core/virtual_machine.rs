@@ -53,6 +53,7 @@ pub trait IVirtualMachine {
     fn branch_then(&mut self) -> Result<(), RuntimeError>;
     fn branch_else(&mut self) -> Result<(), RuntimeError>;
     fn branch_end(&mut self) -> Result<(), RuntimeError>;
+    fn short_circuit(&mut self) -> Result<(), RuntimeError>;
 
     fn exit(&mut self, values_count: usize) -> Result<(), RuntimeError>;
 
@@ -4,6 +4,11 @@
 
 use failure::Fail;
 use num::BigInt;
+use num::BigUint;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use sha2::Digest;
+use sha2::Sha256;
 
 use franklin_crypto::bellman::SynthesisError;
 
@@ -35,14 +40,77 @@ pub enum MalformedBytecode {
     #[fail(display = "unexpected `end_if` instruction")]
     UnexpectedEndIf,
 
+    #[fail(display = "unexpected `short_circuit` instruction")]
+    UnexpectedShortCircuit,
+
     #[fail(display = "stack underflow")]
     StackUnderflow,
 
     #[fail(display = "reading uninitialized memory")]
     UninitializedStorageAccess,
 
+    #[fail(
+        display = "data stack overflow: address {} exceeds the limit of {} cells in the function returning to {}",
+        address, limit, return_address
+    )]
+    DataStackOverflow {
+        address: usize,
+        limit: usize,
+        return_address: usize,
+    },
+
     #[fail(display = "conditional branches produced results of different sizes")]
     BranchStacksDoNotMatch,
+
+    #[fail(display = "constant {} is missing from the constant pool", _0)]
+    InvalidConstantIndex(usize),
+}
+
+impl MalformedBytecode {
+    ///
+    /// The stable error code, which does not depend on the error message wording.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::InvalidArguments(..) => "malformed_bytecode_invalid_arguments",
+            Self::UnexpectedLoopEnd => "malformed_bytecode_unexpected_loop_end",
+            Self::UnexpectedReturn => "malformed_bytecode_unexpected_return",
+            Self::UnexpectedElse => "malformed_bytecode_unexpected_else",
+            Self::UnexpectedEndIf => "malformed_bytecode_unexpected_end_if",
+            Self::UnexpectedShortCircuit => "malformed_bytecode_unexpected_short_circuit",
+            Self::StackUnderflow => "malformed_bytecode_stack_underflow",
+            Self::UninitializedStorageAccess => "malformed_bytecode_uninitialized_storage_access",
+            Self::DataStackOverflow { .. } => "malformed_bytecode_data_stack_overflow",
+            Self::BranchStacksDoNotMatch => "malformed_bytecode_branch_stacks_do_not_match",
+            Self::InvalidConstantIndex(..) => "malformed_bytecode_invalid_constant_index",
+        }
+    }
+
+    ///
+    /// Converts the error into a JSON object with the code, message, and context fields.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        let context = match self {
+            Self::InvalidArguments(details) => json!({ "details": details }),
+            Self::InvalidConstantIndex(index) => json!({ "index": index }),
+            Self::DataStackOverflow {
+                address,
+                limit,
+                return_address,
+            } => json!({
+                "address": address,
+                "limit": limit,
+                "return_address": return_address,
+            }),
+            _ => json!({}),
+        };
+
+        json!({
+            "code": self.error_code(),
+            "message": self.to_string(),
+            "context": context,
+        })
+    }
 }
 
 #[derive(Debug, Fail)]
@@ -119,6 +187,165 @@ pub enum RuntimeError {
 
     #[fail(display = "contract method `{}` does not exist", _0)]
     MethodNotFound { found: String },
+
+    #[fail(
+        display = "contract method `{}` is payable and requires a transfer",
+        method
+    )]
+    PayableMethodWithoutTransfer { method: String },
+
+    #[fail(
+        display = "contract method `{}` is not payable and cannot accept transfers",
+        method
+    )]
+    NonPayableMethodWithTransfer { method: String },
+
+    #[fail(
+        display = "the contract is already initialized with address 0x{:x}, so its constructor cannot be called",
+        address
+    )]
+    AlreadyInitialized { address: BigUint },
+
+    #[fail(
+        display = "the contract storage layout hash 0x{:x} does not match the bytecode one 0x{:x}",
+        found, expected
+    )]
+    StorageLayoutMismatch { expected: BigUint, found: BigUint },
+
+    #[fail(
+        display = "the circuit has {} constraints, which exceeds the limit of {}",
+        constraints, limit
+    )]
+    CircuitTooLarge { constraints: usize, limit: usize },
+
+    #[fail(
+        display = "the contract balance of token 0x{:x} is missing",
+        token_address
+    )]
+    BalanceMissing { token_address: BigUint },
+
+    #[fail(
+        display = "{}: the operand values are redacted, digest {}",
+        code, digest
+    )]
+    Redacted { code: &'static str, digest: String },
+}
+
+impl RuntimeError {
+    ///
+    /// The stable error code, which does not depend on the error message wording.
+    ///
+    /// The malformed bytecode errors are given the codes of their inner errors.
+    ///
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::SynthesisError(..) => "synthesis_error",
+            Self::InternalError(..) => "internal_error",
+            Self::MalformedBytecode(inner) => inner.error_code(),
+            Self::RequireError(..) => "require_error",
+            Self::IndexOutOfBounds { .. } => "index_out_of_bounds",
+            Self::TypeError { .. } => "type_error",
+            Self::ExpectedConstant => "expected_constant",
+            Self::ExpectedUsize(..) => "expected_usize",
+            Self::UnsatisfiedConstraint => "unsatisfied_constraint",
+            Self::DivisionByZero => "division_by_zero",
+            Self::ZeroInversion => "zero_inversion",
+            Self::TypeSize(TypeSizeError::Input { .. }) => "type_size_input",
+            Self::TypeSize(TypeSizeError::Output { .. }) => "type_size_output",
+            Self::ValueOverflow { .. } => "value_overflow",
+            Self::UnitTestDataMissing => "unit_test_data_missing",
+            Self::OnlyForContracts => "only_for_contracts",
+            Self::InvalidStorageValue => "invalid_storage_value",
+            Self::MethodNotFound { .. } => "method_not_found",
+            Self::PayableMethodWithoutTransfer { .. } => "payable_method_without_transfer",
+            Self::NonPayableMethodWithTransfer { .. } => "non_payable_method_with_transfer",
+            Self::AlreadyInitialized { .. } => "already_initialized",
+            Self::StorageLayoutMismatch { .. } => "storage_layout_mismatch",
+            Self::CircuitTooLarge { .. } => "circuit_too_large",
+            Self::BalanceMissing { .. } => "balance_missing",
+            Self::Redacted { code, .. } => code,
+        }
+    }
+
+    ///
+    /// Replaces the errors carrying operand values with their SHA-256 digest, so the values do
+    /// not leak into the logs and responses, but the same failures can still be correlated.
+    ///
+    /// The errors without operand values are returned as is.
+    ///
+    pub fn redacted(self) -> Self {
+        match self {
+            Self::ExpectedUsize(..)
+            | Self::ValueOverflow { .. }
+            | Self::IndexOutOfBounds { .. } => Self::Redacted {
+                code: self.error_code(),
+                digest: hex::encode(Sha256::digest(self.to_string().as_bytes())),
+            },
+            error => error,
+        }
+    }
+
+    ///
+    /// Converts the error into a JSON object with the code, message, and context fields.
+    ///
+    /// Is used by the services, which must classify the failures without parsing the messages.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        let context = match self {
+            Self::SynthesisError(inner) => json!({ "details": inner.to_string() }),
+            Self::InternalError(details) => json!({ "details": details }),
+            Self::MalformedBytecode(inner) => return inner.to_json(),
+            Self::RequireError(message) => json!({ "message": message }),
+            Self::IndexOutOfBounds {
+                lower_bound,
+                upper_bound,
+                found,
+            } => json!({
+                "lower_bound": lower_bound,
+                "upper_bound": upper_bound,
+                "found": found,
+            }),
+            Self::TypeError { expected, found } => json!({
+                "expected": expected,
+                "found": found,
+            }),
+            Self::ExpectedUsize(found) => json!({ "found": found.to_string() }),
+            Self::TypeSize(TypeSizeError::Input { expected, found })
+            | Self::TypeSize(TypeSizeError::Output { expected, found }) => json!({
+                "expected": expected,
+                "found": found,
+            }),
+            Self::ValueOverflow { value, scalar_type } => json!({
+                "value": value.to_string(),
+                "type": scalar_type.to_string(),
+            }),
+            Self::MethodNotFound { found } => json!({ "method": found }),
+            Self::PayableMethodWithoutTransfer { method }
+            | Self::NonPayableMethodWithTransfer { method } => json!({ "method": method }),
+            Self::AlreadyInitialized { address } => json!({
+                "address": format!("0x{:x}", address),
+            }),
+            Self::StorageLayoutMismatch { expected, found } => json!({
+                "expected": format!("0x{:x}", expected),
+                "found": format!("0x{:x}", found),
+            }),
+            Self::CircuitTooLarge { constraints, limit } => json!({
+                "constraints": constraints,
+                "limit": limit,
+            }),
+            Self::BalanceMissing { token_address } => json!({
+                "token_address": format!("0x{:x}", token_address),
+            }),
+            Self::Redacted { digest, .. } => json!({ "digest": digest }),
+            _ => json!({}),
+        };
+
+        json!({
+            "code": self.error_code(),
+            "message": self.to_string(),
+            "context": context,
+        })
+    }
 }
 
 impl From<SynthesisError> for RuntimeError {
@@ -138,3 +365,160 @@ impl From<TypeSizeError> for RuntimeError {
         RuntimeError::TypeSize(error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use num::BigInt;
+    use num::BigUint;
+
+    use franklin_crypto::bellman::SynthesisError;
+
+    use zinc_build::ScalarType;
+
+    use super::MalformedBytecode;
+    use super::RuntimeError;
+    use super::TypeSizeError;
+
+    fn all_variants() -> Vec<RuntimeError> {
+        vec![
+            RuntimeError::SynthesisError(SynthesisError::AssignmentMissing),
+            RuntimeError::InternalError("internal".to_owned()),
+            RuntimeError::MalformedBytecode(MalformedBytecode::InvalidArguments(
+                "arguments".to_owned(),
+            )),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedLoopEnd),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedReturn),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedElse),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedEndIf),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UnexpectedShortCircuit),
+            RuntimeError::MalformedBytecode(MalformedBytecode::StackUnderflow),
+            RuntimeError::MalformedBytecode(MalformedBytecode::UninitializedStorageAccess),
+            RuntimeError::MalformedBytecode(MalformedBytecode::DataStackOverflow {
+                address: 1024,
+                limit: 1000,
+                return_address: 42,
+            }),
+            RuntimeError::MalformedBytecode(MalformedBytecode::BranchStacksDoNotMatch),
+            RuntimeError::RequireError("requirement".to_owned()),
+            RuntimeError::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: 4,
+                found: 5,
+            },
+            RuntimeError::TypeError {
+                expected: "u8".to_owned(),
+                found: "field".to_owned(),
+            },
+            RuntimeError::ExpectedConstant,
+            RuntimeError::ExpectedUsize(BigInt::from(-1)),
+            RuntimeError::UnsatisfiedConstraint,
+            RuntimeError::DivisionByZero,
+            RuntimeError::ZeroInversion,
+            RuntimeError::TypeSize(TypeSizeError::Input {
+                expected: 2,
+                found: 3,
+            }),
+            RuntimeError::TypeSize(TypeSizeError::Output {
+                expected: 2,
+                found: 3,
+            }),
+            RuntimeError::ValueOverflow {
+                value: BigInt::from(256),
+                scalar_type: ScalarType::Field,
+            },
+            RuntimeError::UnitTestDataMissing,
+            RuntimeError::OnlyForContracts,
+            RuntimeError::InvalidStorageValue,
+            RuntimeError::MethodNotFound {
+                found: "missing".to_owned(),
+            },
+            RuntimeError::PayableMethodWithoutTransfer {
+                method: "deposit".to_owned(),
+            },
+            RuntimeError::NonPayableMethodWithTransfer {
+                method: "withdraw".to_owned(),
+            },
+            RuntimeError::AlreadyInitialized {
+                address: BigUint::from(42u8),
+            },
+            RuntimeError::StorageLayoutMismatch {
+                expected: BigUint::from(42u8),
+                found: BigUint::from(24u8),
+            },
+            RuntimeError::CircuitTooLarge {
+                constraints: 2,
+                limit: 1,
+            },
+            RuntimeError::BalanceMissing {
+                token_address: BigUint::from(42u8),
+            },
+        ]
+    }
+
+    #[test]
+    fn ok_error_codes_unique() {
+        let variants = all_variants();
+
+        let codes: HashSet<&'static str> = variants.iter().map(RuntimeError::error_code).collect();
+
+        assert_eq!(codes.len(), variants.len());
+    }
+
+    #[test]
+    fn ok_error_json_structure() {
+        for error in all_variants().into_iter() {
+            let json = error.to_json();
+
+            assert_eq!(json["code"], error.error_code());
+            assert_eq!(json["message"], error.to_string());
+            assert!(json["context"].is_object());
+        }
+    }
+
+    #[test]
+    fn ok_error_json_context() {
+        let error = RuntimeError::IndexOutOfBounds {
+            lower_bound: 0,
+            upper_bound: 4,
+            found: 5,
+        };
+
+        let json = error.to_json();
+
+        assert_eq!(json["code"], "index_out_of_bounds");
+        assert_eq!(json["context"]["upper_bound"], 4);
+        assert_eq!(json["context"]["found"], 5);
+    }
+
+    #[test]
+    fn ok_error_redacted() {
+        let new_error = || RuntimeError::ValueOverflow {
+            value: BigInt::from(987_654_321),
+            scalar_type: ScalarType::Field,
+        };
+
+        let error = new_error().redacted();
+        let json = error.to_json();
+
+        assert_eq!(error.error_code(), "value_overflow");
+        assert!(!error.to_string().contains("987654321"));
+        assert!(!json.to_string().contains("987654321"));
+        assert_eq!(
+            json["context"]["digest"],
+            new_error().redacted().to_json()["context"]["digest"]
+        );
+    }
+
+    #[test]
+    fn ok_error_redacted_without_values() {
+        let error = RuntimeError::MethodNotFound {
+            found: "missing".to_owned(),
+        }
+        .redacted();
+
+        assert_eq!(error.error_code(), "method_not_found");
+        assert_eq!(error.to_json()["context"]["method"], "missing");
+    }
+}
@@ -30,7 +30,12 @@ where
     auto_const!(div_rem_enforce, cs, left, &denom)
 }
 
-/// This is enforcing that `right` is not zero.
+///
+/// Computes the Euclidean quotient and remainder of `left` and `right`.
+///
+/// The remainder is always non-negative, as with `zinc_math::euclidean_div_rem`. The `right`
+/// is enforced to be non-zero by the existence of its inverse.
+///
 pub fn div_rem_enforce<E, CS>(
     mut cs: CS,
     left: &Scalar<E>,
@@ -58,6 +63,11 @@ where
         remainder_value = gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&r);
     }
 
+    gadgets::arithmetic::field::inverse(
+        cs.namespace(|| "denominator inverse"),
+        &denominator.to_field(),
+    )?;
+
     let (quotient, remainder) = {
         let quotient_var = cs.alloc(|| "quotient", || quotient_value.grab())?;
 
@@ -95,3 +105,77 @@ where
 
     Ok((quotient, remainder))
 }
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use zinc_build::IntegerType;
+
+    use crate::error::RuntimeError;
+    use crate::gadgets;
+    use crate::gadgets::scalar::fr_bigint;
+    use crate::gadgets::scalar::Scalar;
+
+    fn allocate<CS>(cs: CS, value: isize) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        gadgets::witness::allocate(cs, Some(&BigInt::from(value)), IntegerType::I8.into())
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    fn to_bigint(scalar: &Scalar<Bn256>) -> BigInt {
+        fr_bigint::fr_to_bigint::<Bn256>(
+            &scalar
+                .get_value()
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_div_rem_signed() {
+        for (left, right, quotient, remainder) in
+            [(9, 4, 2, 1), (9, -4, -2, 1), (-9, 4, -3, 3), (-9, -4, 3, 3)]
+                .iter()
+                .copied()
+        {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+
+            let left = allocate(cs.namespace(|| "left"), left);
+            let right = allocate(cs.namespace(|| "right"), right);
+
+            let (q, r) = gadgets::arithmetic::div_rem::div_rem_enforce(
+                cs.namespace(|| "div_rem"),
+                &left,
+                &right,
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert_eq!(to_bigint(&q), BigInt::from(quotient));
+            assert_eq!(to_bigint(&r), BigInt::from(remainder));
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_div_rem_by_zero() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let left = allocate(cs.namespace(|| "left"), 9);
+        let right = allocate(cs.namespace(|| "right"), 0);
+
+        let result = gadgets::arithmetic::div_rem::div_rem_enforce(
+            cs.namespace(|| "div_rem"),
+            &left,
+            &right,
+        );
+
+        assert!(matches!(result, Err(RuntimeError::DivisionByZero)));
+    }
+}
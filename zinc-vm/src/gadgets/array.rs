@@ -37,15 +37,7 @@ where
     let lt = gadgets::comparison::lesser_than(cs.namespace(|| "lt"), index, &length)?;
     gadgets::require::require(cs.namespace(|| "require"), lt, Some("index out of bounds"))?;
 
-    let i = index.to_constant_unchecked()?.get_constant_usize()?;
-    if i >= array.len() {
-        return Err(RuntimeError::IndexOutOfBounds {
-            lower_bound: 0,
-            upper_bound: array.len(),
-            found: i,
-        });
-    }
-    Ok(array[i].clone())
+    prechecked_get(array, index)
 
     //            let mut cs = cs.namespace(|| "index");
     //            let num_bits = zinc_math::log2ceil(array.len());
@@ -63,6 +55,25 @@ where
     //        }
 }
 
+/// This gadget does not enforce 0 <= index < array.len(), which must have been enforced
+/// by a preceding access with the same index
+pub fn prechecked_get<E>(array: &[Scalar<E>], index: &Scalar<E>) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+{
+    assert!(!array.is_empty(), "reading from empty array");
+
+    let i = index.to_constant_unchecked()?.get_constant_usize()?;
+    if i >= array.len() {
+        return Err(RuntimeError::IndexOutOfBounds {
+            lower_bound: 0,
+            upper_bound: array.len(),
+            found: i,
+        });
+    }
+    Ok(array[i].clone())
+}
+
 pub fn set<E, CS>(
     _cs: CS,
     array: &[Scalar<E>],
@@ -0,0 +1,224 @@
+//!
+//! The barrel shifter gadget.
+//!
+
+use std::cmp;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::ScalarType;
+
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The bit shift direction.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// Towards the most significant bit, that is, `<<`.
+    Left,
+    /// Towards the least significant bit, that is, the logical `>>`.
+    Right,
+}
+
+///
+/// Shifts the `num` bits by the variable `shift` amount in the `direction`.
+///
+/// The shift amount is decomposed into bits, and each bit `k` conditionally shifts the `num`
+/// bits by `2^k`, so an `N`-bit operand only requires `log2(N)` layers of `N` bit selects.
+/// The shift bits weighing at least `N` shift all the bits out, so the result is constrained
+/// to zero if any of them is set.
+///
+pub fn shift<E, CS>(
+    mut cs: CS,
+    num: &Scalar<E>,
+    shift: &Scalar<E>,
+    direction: Direction,
+) -> Result<Scalar<E>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let scalar_type = num.get_type();
+    let len = scalar_type.bitlength::<E>();
+
+    let mut bits = num
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "num bits"), len)?;
+    let shift_bits = shift.to_expression::<CS>().into_bits_le_fixed(
+        cs.namespace(|| "shift bits"),
+        shift.get_type().bitlength::<E>(),
+    )?;
+
+    let layers = cmp::min(zinc_math::log2ceil(len), shift_bits.len());
+    let (layer_bits, overflow_bits) = shift_bits.split_at(layers);
+
+    for (layer, shift_bit) in layer_bits.iter().enumerate() {
+        let mut cs = cs.namespace(|| format!("layer {}", layer));
+        let offset = 1 << layer;
+
+        bits = (0..len)
+            .map(|position| {
+                let shifted = match direction {
+                    Direction::Left => position
+                        .checked_sub(offset)
+                        .map(|position| bits[position].clone()),
+                    Direction::Right => bits.get(position + offset).cloned(),
+                }
+                .unwrap_or_else(|| Boolean::constant(false));
+
+                Boolean::sha256_ch(
+                    cs.namespace(|| format!("bit {}", position)),
+                    shift_bit,
+                    &shifted,
+                    &bits[position],
+                )
+            })
+            .collect::<Result<Vec<Boolean>, SynthesisError>>()?;
+    }
+
+    let mut is_in_range = Boolean::constant(true);
+    for (index, overflow_bit) in overflow_bits.iter().enumerate() {
+        is_in_range = Boolean::and(
+            cs.namespace(|| format!("in range {}", index)),
+            &is_in_range,
+            &overflow_bit.not(),
+        )?;
+    }
+    let is_in_range = Scalar::from_boolean(cs.namespace(|| "is in range"), is_in_range)?;
+
+    let result: Scalar<E> =
+        AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack result bits"), &bits)?.into();
+    let result = gadgets::select::conditional(
+        cs.namespace(|| "select"),
+        &is_in_range,
+        &result,
+        &Scalar::new_constant_usize(0, ScalarType::Field),
+    )?;
+
+    Ok(result.to_type_unchecked(scalar_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::One;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use zinc_build::IntegerType;
+
+    use crate::gadgets;
+    use crate::gadgets::bitwise::barrel::Direction;
+    use crate::gadgets::scalar::fr_bigint;
+    use crate::gadgets::scalar::Scalar;
+
+    fn allocate<CS>(cs: CS, value: &BigInt, bitlength: usize) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        gadgets::witness::allocate(cs, Some(value), IntegerType::new(false, bitlength).into())
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    fn expected(num: &BigInt, shift: usize, bitlength: usize, direction: Direction) -> BigInt {
+        if shift >= bitlength {
+            return BigInt::from(0);
+        }
+
+        let mask = (BigInt::one() << bitlength) - BigInt::one();
+        match direction {
+            Direction::Left => (num << shift) & mask,
+            Direction::Right => num >> shift,
+        }
+    }
+
+    fn check(num: &BigInt, shift: usize, bitlength: usize, direction: Direction) -> usize {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let num_scalar = allocate(cs.namespace(|| "num"), num, bitlength);
+        let shift_scalar = allocate(
+            cs.namespace(|| "shift"),
+            &BigInt::from(shift),
+            zinc_const::bitlength::BYTE,
+        );
+
+        let constraints_before = cs.num_constraints();
+        let result = gadgets::bitwise::barrel::shift(
+            cs.namespace(|| "barrel"),
+            &num_scalar,
+            &shift_scalar,
+            direction,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(
+            fr_bigint::fr_to_bigint::<Bn256>(
+                &result
+                    .get_value()
+                    .expect(zinc_const::panic::TEST_DATA_VALID),
+                false
+            ),
+            expected(num, shift, bitlength, direction),
+            "{:?} {} by {}",
+            direction,
+            num,
+            shift
+        );
+
+        cs.num_constraints() - constraints_before
+    }
+
+    ///
+    /// The decompositions of the operands, `log2(N)` layers of `N` bit selects, the overflow
+    /// check over the remaining shift bits, the result packing and the final select.
+    ///
+    fn constraints_limit(bitlength: usize) -> usize {
+        let layers = zinc_math::log2ceil(bitlength);
+
+        (bitlength + 1)
+            + (zinc_const::bitlength::BYTE + 1)
+            + layers * bitlength
+            + (zinc_const::bitlength::BYTE - layers)
+            + 2
+    }
+
+    #[test]
+    fn test_barrel_shift_8() {
+        let bitlength = zinc_const::bitlength::BYTE;
+        let limit = constraints_limit(bitlength);
+
+        for direction in [Direction::Left, Direction::Right].iter().copied() {
+            for num in [0b1011_0110u64, 0xFF, 1].iter() {
+                for shift in 0..=u8::MAX as usize {
+                    let constraints = check(&BigInt::from(*num), shift, bitlength, direction);
+                    assert!(constraints <= limit, "{} > {}", constraints, limit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_barrel_shift_64() {
+        let bitlength = zinc_const::bitlength::BYTE * 8;
+        let limit = constraints_limit(bitlength);
+
+        for direction in [Direction::Left, Direction::Right].iter().copied() {
+            for num in [0x0123_4567_89AB_CDEFu64, u64::MAX, 1].iter() {
+                for shift in [0, 1, 7, 8, 31, 32, 63, 64, 65, 128, 255].iter().copied() {
+                    let constraints = check(&BigInt::from(*num), shift, bitlength, direction);
+                    assert!(constraints <= limit, "{} > {}", constraints, limit);
+                }
+            }
+        }
+    }
+}
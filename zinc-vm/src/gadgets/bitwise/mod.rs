@@ -1,4 +1,5 @@
 pub mod and;
+pub mod barrel;
 pub mod not;
 pub mod or;
 pub mod shift_left;
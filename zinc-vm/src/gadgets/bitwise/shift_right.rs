@@ -5,11 +5,10 @@ use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::boolean::Boolean;
 use franklin_crypto::circuit::num::AllocatedNum;
 
-use zinc_build::ScalarType;
-
 use crate::error::RuntimeError;
 use crate::gadgets;
 use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::barrel::Direction;
 use crate::gadgets::scalar::expectation::ITypeExpectation;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
@@ -27,7 +26,9 @@ where
     shift.get_type().assert_signed(false)?;
 
     match shift.get_variant() {
-        ScalarVariant::Variable(_) => variable_shift(cs, num, shift),
+        ScalarVariant::Variable(_) => {
+            gadgets::bitwise::barrel::shift(cs, num, shift, Direction::Right)
+        }
         ScalarVariant::Constant(_) => match num.get_variant() {
             ScalarVariant::Variable(_) => variable_num(cs, num, shift.get_constant_usize()?),
             ScalarVariant::Constant(_) => {
@@ -55,54 +56,6 @@ where
     }
 }
 
-fn variable_shift<E, CS>(
-    mut cs: CS,
-    num: &Scalar<E>,
-    shift: &Scalar<E>,
-) -> Result<Scalar<E>, RuntimeError>
-where
-    E: IEngine,
-    CS: ConstraintSystem<E>,
-{
-    let scalar_type = num.get_type();
-    let len = scalar_type.bitlength::<E>();
-
-    let bits = num
-        .to_expression::<CS>()
-        .into_bits_le_fixed(cs.namespace(|| "left bits"), len)?;
-
-    let mut padded_bits = vec![Boolean::Constant(false); len];
-    padded_bits.extend(bits);
-
-    let mut variants = Vec::with_capacity(len);
-    variants.push(num.clone());
-
-    for i in 1..len {
-        let variant = AllocatedNum::pack_bits_to_element(
-            cs.namespace(|| format!("offset {}", i)),
-            &padded_bits[len - i..len * 2 - i],
-        )?;
-        variants.push(variant.into());
-    }
-    variants.push(Scalar::new_constant_usize(0, ScalarType::Field)); // offset `len` will clear all bits.
-
-    let shift_bits_be = shift
-        .to_expression::<CS>()
-        .into_bits_le_fixed(
-            cs.namespace(|| "shift bits"),
-            shift.get_type().bitlength::<E>(),
-        )?
-        .into_iter()
-        .rev()
-        .enumerate()
-        .map(|(i, b)| Scalar::from_boolean(cs.namespace(|| format!("bit {}", i)), b))
-        .collect::<Result<Vec<_>, RuntimeError>>()?;
-
-    let result = gadgets::select::recursive(cs, &shift_bits_be, &variants)?;
-
-    Ok(result.to_type_unchecked(scalar_type))
-}
-
 fn variable_num<E, CS>(mut cs: CS, num: &Scalar<E>, shift: usize) -> Result<Scalar<E>, RuntimeError>
 where
     E: IEngine,
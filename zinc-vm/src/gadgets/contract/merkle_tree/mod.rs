@@ -6,6 +6,7 @@ use num::BigInt;
 use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::contract::storage::proof::StorageProof;
 use crate::error::RuntimeError;
 use crate::IEngine;
 
@@ -30,6 +31,16 @@ pub trait IMerkleTree<E: IEngine> {
     ///
     fn root_hash(&self) -> E::Fr;
 
+    ///
+    /// Returns the SHA256 Merkle tree root hash, which the storage proofs are verified against.
+    ///
+    fn root_hash_bytes(&self) -> Vec<u8>;
+
+    ///
+    /// Returns the inclusion proof of the leaf at `index`.
+    ///
+    fn proof(&self, index: BigInt) -> Result<StorageProof, RuntimeError>;
+
     ///
     /// Returns the depth of the Merkle tree.
     ///
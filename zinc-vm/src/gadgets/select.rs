@@ -5,10 +5,12 @@
 use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::Assignment;
 
 use zinc_build::ScalarType;
 
 use crate::error::RuntimeError;
+use crate::gadgets;
 use crate::gadgets::scalar::expectation::ITypeExpectation;
 use crate::gadgets::scalar::variant::Variant as ScalarVariant;
 use crate::gadgets::scalar::Scalar;
@@ -109,3 +111,351 @@ where
         }
     }
 }
+
+///
+/// Selects the `if_true` or `if_false` values element-wise based on the `condition`.
+///
+/// Is equivalent to calling `conditional` for each pair of elements, but the condition is
+/// checked and converted into a linear combination only once, and all the elements are
+/// selected within the same namespace, which makes merging large composite values much faster.
+///
+pub fn conditional_many<E, CS>(
+    mut cs: CS,
+    condition: &Scalar<E>,
+    if_true: &[Scalar<E>],
+    if_false: &[Scalar<E>],
+) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    assert_eq!(
+        if_true.len(),
+        if_false.len(),
+        "internal error in conditional_many"
+    );
+
+    condition.get_type().assert_type(ScalarType::Boolean)?;
+    let scalar_types = if_true
+        .iter()
+        .zip(if_false.iter())
+        .map(|(if_true, if_false)| ScalarType::expect_same(if_true.get_type(), if_false.get_type()))
+        .collect::<Result<Vec<ScalarType>, RuntimeError>>()?;
+
+    match condition.get_variant() {
+        ScalarVariant::Constant(constant) => {
+            if constant.value.is_zero() {
+                Ok(if_false.to_vec())
+            } else {
+                Ok(if_true.to_vec())
+            }
+        }
+        ScalarVariant::Variable(_) => {
+            let condition_value = condition.get_value().map(|value| !value.is_zero());
+            let condition = condition.to_linear_combination::<CS>();
+
+            let mut selected = Vec::with_capacity(if_true.len());
+            for (index, ((if_true, if_false), scalar_type)) in if_true
+                .iter()
+                .zip(if_false.iter())
+                .zip(scalar_types.into_iter())
+                .enumerate()
+            {
+                let value = match condition_value {
+                    Some(true) => if_true.get_value(),
+                    Some(false) => if_false.get_value(),
+                    None => None,
+                };
+                let variable = cs.alloc(|| format!("selected {}", index), || value.grab())?;
+
+                let if_true = if_true.to_linear_combination::<CS>();
+                let if_false = if_false.to_linear_combination::<CS>();
+
+                // Selected, Right, Left, Condition
+                // s = r + c * (l - r)
+                // (l - r) * (c) = (s - r)
+                cs.enforce(
+                    || format!("constraint {}", index),
+                    |lc| lc + &if_true - &if_false,
+                    |lc| lc + &condition,
+                    |lc| lc + variable - &if_false,
+                );
+
+                selected.push(Scalar::new_unchecked_variable(value, variable, scalar_type));
+            }
+
+            Ok(selected)
+        }
+    }
+}
+
+///
+/// Selects the `branches` element whose discriminant equals the `scrutinee`, or the `default`
+/// values if there is no such discriminant.
+///
+/// The scrutinee is one-hot encoded against the `discriminants`, and each selected element is
+/// the weighted sum `default + sum(flag_i * (branch_i - default))`. The products with constant
+/// branch values are linear, so only the discriminant comparisons, the products with variable
+/// branch values, and the sums themselves are constrained.
+///
+pub fn one_hot<E, CS>(
+    mut cs: CS,
+    scrutinee: &Scalar<E>,
+    discriminants: &[Scalar<E>],
+    branches: &[Vec<Scalar<E>>],
+    default: &[Scalar<E>],
+) -> Result<Vec<Scalar<E>>, RuntimeError>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    assert_eq!(
+        discriminants.len(),
+        branches.len(),
+        "internal error in one_hot 1"
+    );
+    assert!(
+        branches.iter().all(|branch| branch.len() == default.len()),
+        "internal error in one_hot 2"
+    );
+
+    if scrutinee.is_constant() {
+        let scrutinee = scrutinee.get_constant()?;
+        for (discriminant, branch) in discriminants.iter().zip(branches.iter()) {
+            if discriminant.get_constant()? == scrutinee {
+                return Ok(branch.to_vec());
+            }
+        }
+        return Ok(default.to_vec());
+    }
+
+    let flags = discriminants
+        .iter()
+        .enumerate()
+        .map(|(index, discriminant)| {
+            gadgets::comparison::equals(
+                cs.namespace(|| format!("flag {}", index)),
+                scrutinee,
+                discriminant,
+            )
+        })
+        .collect::<Result<Vec<Scalar<E>>, RuntimeError>>()?;
+    let matched = flags
+        .iter()
+        .map(|flag| flag.get_value().map(|value| !value.is_zero()))
+        .collect::<Option<Vec<bool>>>()
+        .map(|flags| flags.into_iter().position(|flag| flag));
+
+    let mut selected = Vec::with_capacity(default.len());
+    for (index, if_default) in default.iter().enumerate() {
+        let mut cs = cs.namespace(|| format!("element {}", index));
+
+        let mut scalar_type = if_default.get_type();
+        let mut sum = if_default.to_linear_combination::<CS>();
+        for (branch_index, (flag, branch)) in flags.iter().zip(branches.iter()).enumerate() {
+            let element = &branch[index];
+            scalar_type = ScalarType::expect_same(scalar_type, element.get_type())?;
+
+            if element.is_constant() && if_default.is_constant() {
+                let mut difference = element.get_constant()?;
+                difference.sub_assign(&if_default.get_constant()?);
+
+                match flag.get_variant() {
+                    ScalarVariant::Constant(constant) => {
+                        if !constant.value.is_zero() {
+                            sum = sum + (difference, CS::one());
+                        }
+                    }
+                    ScalarVariant::Variable(variable) => {
+                        sum = sum + (difference, variable.variable);
+                    }
+                }
+            } else {
+                let value = match (
+                    flag.get_value(),
+                    element.get_value(),
+                    if_default.get_value(),
+                ) {
+                    (Some(flag), Some(element), Some(if_default)) => {
+                        let mut value = element;
+                        value.sub_assign(&if_default);
+                        value.mul_assign(&flag);
+                        Some(value)
+                    }
+                    _ => None,
+                };
+                let product = cs.alloc(|| format!("product {}", branch_index), || value.grab())?;
+
+                // Product, Flag, Branch, Default
+                // (b - d) * (f) = (p)
+                cs.enforce(
+                    || format!("product constraint {}", branch_index),
+                    |lc| {
+                        lc + &element.to_linear_combination::<CS>()
+                            - &if_default.to_linear_combination::<CS>()
+                    },
+                    |lc| lc + &flag.to_linear_combination::<CS>(),
+                    |lc| lc + product,
+                );
+
+                sum = sum + product;
+            }
+        }
+
+        let value = match matched {
+            Some(Some(branch_index)) => branches[branch_index][index].get_value(),
+            Some(None) => if_default.get_value(),
+            None => None,
+        };
+        let variable = cs.alloc(|| "selected", || value.grab())?;
+
+        cs.enforce(
+            || "constraint",
+            |lc| lc + &sum,
+            |lc| lc + CS::one(),
+            |lc| lc + variable,
+        );
+
+        selected.push(Scalar::new_unchecked_variable(value, variable, scalar_type));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use zinc_build::ScalarType;
+
+    use crate::gadgets;
+    use crate::gadgets::scalar::fr_bigint;
+    use crate::gadgets::scalar::Scalar;
+
+    fn allocate<CS>(mut cs: CS, value: usize, scalar_type: ScalarType) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        let value = fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(value));
+        let variable = cs
+            .alloc(
+                || "variable",
+                || Ok(value.expect(zinc_const::panic::TEST_DATA_VALID)),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        Scalar::new_unchecked_variable(value, variable, scalar_type)
+    }
+
+    fn allocate_branches<CS>(mut cs: CS, count: usize) -> (Vec<Scalar<Bn256>>, Vec<Scalar<Bn256>>)
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        let if_true = (0..count)
+            .map(|index| {
+                allocate(
+                    cs.namespace(|| format!("true {}", index)),
+                    index,
+                    ScalarType::Field,
+                )
+            })
+            .collect();
+        let if_false = (0..count)
+            .map(|index| {
+                allocate(
+                    cs.namespace(|| format!("false {}", index)),
+                    index * 2 + 1,
+                    ScalarType::Field,
+                )
+            })
+            .collect();
+
+        (if_true, if_false)
+    }
+
+    #[test]
+    fn test_conditional_many_constraints_linear() {
+        for count in [1, 10, 100, 1000].iter().copied() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+
+            let condition = allocate(cs.namespace(|| "condition"), 1, ScalarType::Boolean);
+            let (if_true, if_false) = allocate_branches(cs.namespace(|| "branches"), count);
+
+            let constraints_before = cs.num_constraints();
+            let selected = gadgets::select::conditional_many(
+                cs.namespace(|| "merge"),
+                &condition,
+                if_true.as_slice(),
+                if_false.as_slice(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert_eq!(cs.num_constraints() - constraints_before, count);
+            assert!(cs.is_satisfied());
+            for (selected, expected) in selected.iter().zip(if_true.iter()) {
+                assert_eq!(selected.get_value(), expected.get_value());
+            }
+        }
+    }
+
+    #[test]
+    fn test_conditional_many_same_as_conditional() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let condition = allocate(cs.namespace(|| "condition"), 0, ScalarType::Boolean);
+        let (if_true, if_false) = allocate_branches(cs.namespace(|| "branches"), 16);
+
+        let selected_many = gadgets::select::conditional_many(
+            cs.namespace(|| "merge many"),
+            &condition,
+            if_true.as_slice(),
+            if_false.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        for (index, ((if_true, if_false), selected_many)) in if_true
+            .iter()
+            .zip(if_false.iter())
+            .zip(selected_many.iter())
+            .enumerate()
+        {
+            let selected = gadgets::select::conditional(
+                cs.namespace(|| format!("merge {}", index)),
+                &condition,
+                if_true,
+                if_false,
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert_eq!(selected.get_value(), selected_many.get_value());
+            assert_eq!(selected.get_value(), if_false.get_value());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_conditional_many_constant_condition() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let condition = Scalar::new_constant_bool(true);
+        let (if_true, if_false) = allocate_branches(cs.namespace(|| "branches"), 16);
+
+        let constraints_before = cs.num_constraints();
+        let selected = gadgets::select::conditional_many(
+            cs.namespace(|| "merge"),
+            &condition,
+            if_true.as_slice(),
+            if_false.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(cs.num_constraints(), constraints_before);
+        for (selected, expected) in selected.iter().zip(if_true.iter()) {
+            assert_eq!(selected.get_value(), expected.get_value());
+        }
+    }
+}
@@ -57,21 +57,21 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Get {
             LeafVariant::Map { data, .. } => data,
             LeafVariant::Array(_array) => return Err(RuntimeError::InvalidStorageValue),
         };
-        let (output, found) = data
+        let (found, output) = data
             .into_iter()
             .find(|(map_key, _value)| map_key == &input)
-            .map(|(_key, value)| (value, true))
+            .map(|(_key, value)| (true, value))
             .unwrap_or((
-                vec![Scalar::new_constant_bool(false); self.output_size],
                 false,
+                vec![Scalar::new_constant_bool(false); self.output_size.saturating_sub(1)],
             ));
 
-        for value in output.into_iter() {
-            state.evaluation_stack.push(Cell::Value(value))?;
-        }
         state
             .evaluation_stack
             .push(Cell::Value(Scalar::new_constant_bool(found)))?;
+        for value in output.into_iter() {
+            state.evaluation_stack.push(Cell::Value(value))?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,72 @@
+//!
+//! The `std::convert::from_bytes_be` function call.
+//!
+
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::IntegerType;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct FromBytesBe {
+    bitlength: usize,
+}
+
+impl FromBytesBe {
+    pub fn new(inputs_count: usize) -> Self {
+        Self {
+            bitlength: inputs_count * zinc_const::bitlength::BYTE,
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for FromBytesBe {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        if self.bitlength > E::Fr::CAPACITY as usize {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "from_bytes_be: integer type with length {} is not supported",
+                self.bitlength
+            ))
+            .into());
+        }
+
+        // the bytes are popped starting from the least significant one
+        let mut bits = Vec::with_capacity(self.bitlength);
+        for i in 0..self.bitlength / zinc_const::bitlength::BYTE {
+            let byte = state.evaluation_stack.pop()?.try_into_value()?;
+            let byte_bits = byte.to_expression::<CS>().into_bits_le_fixed(
+                cs.namespace(|| format!("into_bits_le_fixed {}", i)),
+                zinc_const::bitlength::BYTE,
+            )?;
+            bits.extend(byte_bits);
+        }
+
+        let num =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), &bits)?;
+
+        let int_type = IntegerType {
+            is_signed: false,
+            bitlength: self.bitlength,
+        };
+
+        let scalar =
+            Scalar::new_unchecked_variable(num.get_value(), num.get_variable(), int_type.into());
+
+        state.evaluation_stack.push(scalar.into())?;
+
+        Ok(())
+    }
+}
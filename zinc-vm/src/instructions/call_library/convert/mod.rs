@@ -5,4 +5,6 @@
 pub mod from_bits_field;
 pub mod from_bits_signed;
 pub mod from_bits_unsigned;
+pub mod from_bytes_be;
 pub mod to_bits;
+pub mod to_bytes_be;
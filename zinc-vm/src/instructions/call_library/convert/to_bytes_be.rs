@@ -0,0 +1,66 @@
+//!
+//! The `std::convert::to_bytes_be` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use zinc_build::IntegerType;
+use zinc_build::ScalarType;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::RuntimeError;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct ToBytesBe;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for ToBytesBe {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let scalar = state.evaluation_stack.pop()?.try_into_value()?;
+        let expr = scalar.to_expression::<CS>();
+
+        let bits = match scalar.get_type() {
+            ScalarType::Integer(IntegerType {
+                is_signed: false,
+                bitlength,
+            }) => expr.into_bits_le_fixed(cs.namespace(|| "into_bits_le"), bitlength)?,
+            ScalarType::Field => {
+                expr.into_bits_le_strict(cs.namespace(|| "into_bits_le_strict"))?
+            }
+            r#type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "unsigned integer or field".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+        };
+
+        let mut bytes = Vec::with_capacity(bits.len() / zinc_const::bitlength::BYTE + 1);
+        for (i, byte_bits) in bits.chunks(zinc_const::bitlength::BYTE).enumerate() {
+            let byte = AllocatedNum::pack_bits_to_element(
+                cs.namespace(|| format!("pack_bits_to_element {}", i)),
+                byte_bits,
+            )?;
+            bytes.push(Scalar::new_unchecked_variable(
+                byte.get_value(),
+                byte.get_variable(),
+                IntegerType::U8.into(),
+            ));
+        }
+
+        // We use big-endian
+        for byte in bytes.into_iter().rev() {
+            state.evaluation_stack.push(byte.into())?;
+        }
+
+        Ok(())
+    }
+}
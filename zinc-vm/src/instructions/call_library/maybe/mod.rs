@@ -0,0 +1,5 @@
+//!
+//! The `Maybe` type method calls.
+//!
+
+pub mod unwrap_or;
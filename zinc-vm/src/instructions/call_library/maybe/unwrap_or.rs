@@ -0,0 +1,71 @@
+//!
+//! The `Maybe::unwrap_or` function call.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct UnwrapOr {
+    value_size: usize,
+}
+
+impl UnwrapOr {
+    pub fn new(inputs_count: usize) -> Result<Self, RuntimeError> {
+        inputs_count
+            .checked_sub(1)
+            .filter(|payload_size| payload_size % 2 == 0)
+            .map(|payload_size| Self {
+                value_size: payload_size / 2,
+            })
+            .ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(
+                    "Maybe::unwrap_or expects a flag and two values of the same size".into(),
+                )
+                .into()
+            })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for UnwrapOr {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError> {
+        let mut default = Vec::with_capacity(self.value_size);
+        for _ in 0..self.value_size {
+            default.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        default.reverse();
+
+        let mut value = Vec::with_capacity(self.value_size);
+        for _ in 0..self.value_size {
+            value.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        value.reverse();
+
+        let is_some = state.evaluation_stack.pop()?.try_into_value()?;
+
+        for (index, (value, default)) in value.into_iter().zip(default).enumerate() {
+            // the absent payload is zero-filled, so its type is taken from `default`
+            let value = value.to_type_unchecked(default.get_type());
+            let result = gadgets::select::conditional(
+                cs.namespace(|| format!("select {}", index)),
+                &is_some,
+                &value,
+                &default,
+            )?;
+            state.evaluation_stack.push(result.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ pub mod collections_mtreemap;
 pub mod convert;
 pub mod crypto;
 pub mod ff;
+pub mod maybe;
 pub mod zksync;
 
 use franklin_crypto::bellman::ConstraintSystem;
@@ -31,11 +32,15 @@ use self::collections_mtreemap::remove::Remove as CollectionsMTreeMapRemove;
 use self::convert::from_bits_field::FromBitsField as ConvertFromBitsField;
 use self::convert::from_bits_signed::FromBitsSigned as ConvertFromBitsSigned;
 use self::convert::from_bits_unsigned::FromBitsUnsigned as ConvertFromBitsUnsigned;
+use self::convert::from_bytes_be::FromBytesBe as ConvertFromBytesBe;
 use self::convert::to_bits::ToBits as ConvertToBits;
+use self::convert::to_bytes_be::ToBytesBe as ConvertToBytesBe;
 use self::crypto::pedersen::Pedersen as CryptoPedersen;
 use self::crypto::schnorr_verify::SchnorrSignatureVerify as CryptoSchnorrSignatureVerify;
 use self::crypto::sha256::Sha256 as CryptoSha256;
 use self::ff::invert::Inverse as FfInverse;
+use self::maybe::unwrap_or::UnwrapOr as MaybeUnwrapOr;
+use self::zksync::balance::Balance as ZksyncBalance;
 use self::zksync::transfer::Transfer as ZksyncTransfer;
 
 pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>> {
@@ -68,6 +73,10 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
                 vm.call_native(ConvertFromBitsSigned::new(self.input_size))
             }
             LibraryFunctionIdentifier::ConvertFromBitsField => vm.call_native(ConvertFromBitsField),
+            LibraryFunctionIdentifier::ConvertToBytesBe => vm.call_native(ConvertToBytesBe),
+            LibraryFunctionIdentifier::ConvertFromBytesBe => {
+                vm.call_native(ConvertFromBytesBe::new(self.input_size))
+            }
 
             LibraryFunctionIdentifier::ArrayReverse => {
                 vm.call_native(ArrayReverse::new(self.input_size)?)
@@ -80,6 +89,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::FfInvert => vm.call_native(FfInverse),
 
             LibraryFunctionIdentifier::ZksyncTransfer => vm.call_native(ZksyncTransfer),
+            LibraryFunctionIdentifier::ZksyncBalance => vm.call_native(ZksyncBalance),
 
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => vm.call_native(
                 CollectionsMTreeMapGet::new(self.input_size, self.output_size),
@@ -93,6 +103,10 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::CollectionsMTreeMapRemove => vm.call_native(
                 CollectionsMTreeMapRemove::new(self.input_size, self.output_size),
             ),
+
+            LibraryFunctionIdentifier::MaybeUnwrapOr => {
+                vm.call_native(MaybeUnwrapOr::new(self.input_size)?)
+            }
         }
     }
 }
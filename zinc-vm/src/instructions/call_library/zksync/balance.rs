@@ -0,0 +1,51 @@
+//!
+//! The contract `self.balance` function call.
+//!
+
+use num::bigint::ToBigInt;
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use zinc_build::IntegerType;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Balance;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Balance {
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storage: Option<&mut S>,
+    ) -> Result<(), RuntimeError>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let token_address = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let balance = match token_address
+            .to_bigint()
+            .and_then(|token_address| token_address.to_biguint())
+        {
+            Some(token_address) => Some(BigInt::from(state.balances.get(&token_address)?)),
+            None => None,
+        };
+
+        let balance = gadgets::witness::allocate(
+            cs.namespace(|| "balance"),
+            balance.as_ref(),
+            IntegerType::BALANCE.into(),
+        )?;
+
+        state.evaluation_stack.push(balance.into())?;
+
+        Ok(())
+    }
+}
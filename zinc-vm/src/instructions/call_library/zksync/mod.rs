@@ -2,4 +2,5 @@
 //! The `zksync` module calls.
 //!
 
+pub mod balance;
 pub mod transfer;
@@ -4,21 +4,31 @@
 
 use num::bigint::ToBigInt;
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
 use crate::core::contract::output::transfer::Transfer as TransferOutput;
 use crate::core::execution_state::ExecutionState;
+use crate::error::MalformedBytecode;
 use crate::error::RuntimeError;
+use crate::gadgets;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
+///
+/// The `zksync::transfer` function call.
+///
+/// The transfer is only recorded if the current branch condition is true, so the transfers
+/// under the unexecuted branches are not sent. The amount is multiplied by the condition bit,
+/// which keeps the constraint system consistent with the recorded transfers.
+///
 pub struct Transfer;
 
 impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Transfer {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
         _storage: Option<&mut S>,
     ) -> Result<(), RuntimeError>
@@ -29,6 +39,20 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Transfer {
         let token_address = state.evaluation_stack.pop()?.try_into_value()?;
         let recipient = state.evaluation_stack.pop()?.try_into_value()?;
 
+        let condition = state
+            .conditions_stack
+            .last()
+            .cloned()
+            .ok_or(MalformedBytecode::StackUnderflow)?;
+        let amount = gadgets::arithmetic::mul::mul(
+            cs.namespace(|| "conditional amount"),
+            &amount,
+            &condition,
+        )?;
+        if let Some(true) = condition.get_value().as_ref().map(Field::is_zero) {
+            return Ok(());
+        }
+
         let token_address = token_address
             .to_bigint()
             .unwrap_or_default()
@@ -54,3 +78,102 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Transfer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::BigUint;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use zinc_build::IntegerType;
+    use zinc_build::ScalarType;
+
+    use crate::core::contract::storage::database::Storage as DatabaseStorage;
+    use crate::core::execution_state::ExecutionState;
+    use crate::gadgets::scalar::fr_bigint;
+    use crate::gadgets::scalar::Scalar;
+    use crate::instructions::call_library::INativeCallable;
+
+    use super::Transfer;
+
+    const AMOUNT: usize = 42;
+
+    fn allocate<CS>(mut cs: CS, value: usize, scalar_type: ScalarType) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        let value = fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(value));
+        let variable = cs
+            .alloc(
+                || "variable",
+                || Ok(value.expect(zinc_const::panic::TEST_DATA_VALID)),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        Scalar::new_unchecked_variable(value, variable, scalar_type)
+    }
+
+    fn transfer(condition: bool) -> (TestConstraintSystem<Bn256>, ExecutionState<Bn256>, usize) {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut state = ExecutionState::new(zinc_const::limit::VM_DATA_STACK_SIZE);
+
+        let condition = allocate(
+            cs.namespace(|| "condition"),
+            condition as usize,
+            ScalarType::Boolean,
+        );
+        state.conditions_stack.push(condition);
+        for (index, (value, scalar_type)) in [
+            (1, ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+            (0, ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+            (AMOUNT, ScalarType::Integer(IntegerType::BALANCE)),
+        ]
+        .iter()
+        .cloned()
+        .enumerate()
+        {
+            let scalar = allocate(
+                cs.namespace(|| format!("argument {}", index)),
+                value,
+                scalar_type,
+            );
+            state
+                .evaluation_stack
+                .push(scalar.into())
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+        }
+
+        let constraints_before = cs.num_constraints();
+        INativeCallable::<Bn256, DatabaseStorage<Bn256>>::call(
+            &Transfer,
+            cs.namespace(|| "transfer"),
+            &mut state,
+            None,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        (cs, state, constraints_before)
+    }
+
+    #[test]
+    fn test_condition_true() {
+        let (cs, state, constraints_before) = transfer(true);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints() - constraints_before, 1);
+        assert_eq!(state.transfers.len(), 1);
+        assert_eq!(state.transfers[0].amount, BigUint::from(AMOUNT));
+    }
+
+    #[test]
+    fn test_condition_false() {
+        let (cs, state, constraints_before) = transfer(false);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints() - constraints_before, 1);
+        assert!(state.transfers.is_empty());
+    }
+}
@@ -25,12 +25,16 @@ impl<VM: IVirtualMachine> IExecutable<VM> for LoadByIndex {
         let condition = vm.condition_top()?;
         let mut values = Vec::with_capacity(self.value_size);
         for i in 0..self.value_size {
-            let value = gadgets::array::conditional_get(
-                vm.constraint_system().namespace(|| "array_get"),
-                &condition,
-                &array[i..],
-                &index,
-            )?;
+            let value = if self.is_index_checked {
+                gadgets::array::prechecked_get(&array[i..], &index)?
+            } else {
+                gadgets::array::conditional_get(
+                    vm.constraint_system().namespace(|| "array_get"),
+                    &condition,
+                    &array[i..],
+                    &index,
+                )?
+            };
             values.push(value);
         }
 
@@ -41,3 +45,97 @@ impl<VM: IVirtualMachine> IExecutable<VM> for LoadByIndex {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use zinc_build::IntegerType;
+    use zinc_build::Type as BuildType;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    const ACCESS_COUNT: usize = 5;
+
+    ///
+    /// The `(index, array)` input, where `array` is stored at the address 1.
+    ///
+    fn input() -> BuildType {
+        BuildType::Tuple(vec![
+            BuildType::Scalar(IntegerType::U8.into()),
+            BuildType::Array(Box::new(BuildType::Scalar(IntegerType::U8.into())), 4),
+        ])
+    }
+
+    ///
+    /// Reads `array[index]` several times, checking the bounds either on each access
+    /// or only on the first one.
+    ///
+    fn new_accesses(is_prechecked: bool) -> TestRunner {
+        let mut runner = TestRunner::new();
+        for access in 0..ACCESS_COUNT {
+            runner = runner
+                .push(zinc_build::Load::new(0, 1))
+                .push(zinc_build::Cast::new(zinc_build::ScalarType::Field))
+                .push(zinc_build::LoadByIndex::new(
+                    1,
+                    1,
+                    4,
+                    is_prechecked && access > 0,
+                ));
+        }
+        runner
+    }
+
+    #[test]
+    fn test_load_by_index() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_build::Push::new_field(BigInt::from(10)))
+            .push(zinc_build::Store::new(0, 1))
+            .push(zinc_build::Push::new_field(BigInt::from(20)))
+            .push(zinc_build::Store::new(1, 1))
+            .push(zinc_build::Push::new_field(BigInt::from(30)))
+            .push(zinc_build::Store::new(2, 1))
+            .push(zinc_build::Push::new_field(BigInt::from(1)))
+            .push(zinc_build::LoadByIndex::new(0, 2, 3, false))
+            .test(&[30, 20])
+    }
+
+    #[test]
+    fn test_constraints_reduction() -> Result<(), TestingError> {
+        let values: Vec<BigInt> = [2, 5, 6, 7, 8].iter().copied().map(BigInt::from).collect();
+
+        let checked =
+            new_accesses(false).test_with_input(input(), values.as_slice(), &[7; ACCESS_COUNT])?;
+        let prechecked =
+            new_accesses(true).test_with_input(input(), values.as_slice(), &[7; ACCESS_COUNT])?;
+
+        println!(
+            "{} accesses: {} constraints with a check on each, {} with a single check",
+            ACCESS_COUNT, checked, prechecked
+        );
+        assert!(
+            prechecked < checked,
+            "the single bounds check must be cheaper than the repeated ones"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prechecked_out_of_bounds() {
+        let values: Vec<BigInt> = [4, 5, 6, 7, 8].iter().copied().map(BigInt::from).collect();
+
+        let result = TestRunner::new()
+            .push(zinc_build::Load::new(0, 1))
+            .push(zinc_build::Cast::new(zinc_build::ScalarType::Field))
+            .push(zinc_build::LoadByIndex::new(1, 1, 4, true))
+            .test_with_input(input(), values.as_slice(), &[] as &[usize]);
+
+        assert!(
+            result.is_err(),
+            "the witness must be checked even if the constraint is omitted"
+        );
+    }
+}
@@ -3,14 +3,12 @@
 //!
 
 use num::bigint::ToBigInt;
-use num::BigInt;
 use num::Signed;
 
-use franklin_crypto::bellman::SynthesisError;
-
 use zinc_build::Dbg;
 use zinc_build::IntegerType;
 use zinc_build::ScalarType;
+use zinc_build::ScalarValue;
 use zinc_build::Type as BuildType;
 use zinc_build::Value as BuildValue;
 
@@ -21,56 +19,16 @@ use crate::instructions::IExecutable;
 
 impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
     fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
-        let mut values = Vec::with_capacity(self.argument_types.len());
-
-        for argument_type in self.argument_types.into_iter().rev() {
-            let size = argument_type.size();
-            let mut flat = Vec::with_capacity(size);
-
-            match argument_type {
-                BuildType::Contract(fields) => {
-                    let mut flat = Vec::with_capacity(size);
-                    for (index, field) in fields.iter().enumerate() {
-                        let values: Vec<BigInt> = vm
-                            .storage_load(
-                                Scalar::new_constant_usize(
-                                    index,
-                                    ScalarType::Integer(IntegerType::new(
-                                        false,
-                                        zinc_const::bitlength::INDEX,
-                                    )),
-                                ),
-                                field.r#type.size(),
-                            )?
-                            .into_iter()
-                            .map(|scalar| scalar.to_bigint().unwrap_or_default())
-                            .collect();
-                        flat.extend(values);
-                    }
-                    values.push(BuildValue::from_flat_values(
-                        BuildType::Contract(fields),
-                        flat.as_slice(),
-                    ));
-                }
-                r#type => {
-                    for _ in 0..size {
-                        let value = vm.pop()?.try_into_value()?.to_bigint().ok_or_else(|| {
-                            RuntimeError::SynthesisError(SynthesisError::AssignmentMissing)
-                        })?;
-                        flat.push(value);
-                    }
-                    flat.reverse();
-                    values.push(BuildValue::from_flat_values(r#type, flat.as_slice()));
-                }
-            }
-        }
+        let values = match pop_arguments(vm, self.argument_types.clone())? {
+            Some(values) => values,
+            None => return Ok(()),
+        };
 
         if let Some(condition) = vm.condition_top()?.to_bigint() {
             if condition.is_positive() {
                 let mut buffer = self.format;
-                for value in values.into_iter().rev() {
-                    let json = serde_json::to_string(&value.into_json()).unwrap_or_default();
-                    buffer = buffer.replacen("{}", &json, 1);
+                for (value, r#type) in values.into_iter().zip(self.argument_types.iter()) {
+                    buffer = buffer.replacen("{}", format_value(value, r#type).as_str(), 1);
                 }
                 eprintln!("{}", buffer);
             }
@@ -80,12 +38,186 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
     }
 }
 
+///
+/// Formats the `value` of `r#type` in the Rust-like way, e.g. `[1, 2, 3]` or `{ x: 3, y: 5 }`.
+///
+/// The type is required to tell tuples from arrays and to print the enumeration variant names.
+///
+pub fn format_value(value: BuildValue, r#type: &BuildType) -> String {
+    match (value, r#type) {
+        (BuildValue::Unit, _) => "()".to_owned(),
+        (BuildValue::Scalar(value), BuildType::Enumeration { variants, .. }) => {
+            let value = value.to_bigint();
+            variants
+                .iter()
+                .find(|(_name, variant)| variant == &value)
+                .map(|(name, _variant)| name.to_owned())
+                .unwrap_or_else(|| value.to_string())
+        }
+        (BuildValue::Scalar(ScalarValue::Boolean(value)), _) => value.to_string(),
+        (BuildValue::Scalar(ScalarValue::Integer(value, r#type)), _)
+            if r#type.bitlength == zinc_const::bitlength::ETH_ADDRESS =>
+        {
+            format!("0x{}", value.to_str_radix(zinc_const::base::HEXADECIMAL))
+        }
+        (BuildValue::Scalar(ScalarValue::Integer(value, _)), _) => value.to_string(),
+        (BuildValue::Scalar(ScalarValue::Field(value)), _) => {
+            format!("0x{}", value.to_str_radix(zinc_const::base::HEXADECIMAL))
+        }
+        (BuildValue::Enumeration { name, .. }, _) => name,
+
+        (BuildValue::Array(values), BuildType::Tuple(types)) => format!(
+            "({})",
+            values
+                .into_iter()
+                .zip(types.iter())
+                .map(|(value, r#type)| format_value(value, r#type))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        (BuildValue::Array(values), BuildType::Array(r#type, _size)) => format!(
+            "[{}]",
+            values
+                .into_iter()
+                .map(|value| format_value(value, r#type.as_ref()))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        (BuildValue::Structure(fields), BuildType::Structure(types)) => format_fields(
+            fields
+                .into_iter()
+                .zip(types.iter())
+                .map(|((name, value), (_name, r#type))| (name, format_value(value, r#type)))
+                .collect(),
+        ),
+        (BuildValue::Contract(fields), BuildType::Contract(types)) => format_fields(
+            fields
+                .into_iter()
+                .zip(types.iter())
+                .map(|(field, r#type)| (field.name, format_value(field.value, &r#type.r#type)))
+                .collect(),
+        ),
+
+        (
+            BuildValue::Maybe {
+                is_some: true,
+                value,
+            },
+            BuildType::Maybe(r#type),
+        ) => {
+            format!("Some({})", format_value(*value, r#type.as_ref()))
+        }
+        (BuildValue::Maybe { is_some: false, .. }, _) => "None".to_owned(),
+
+        (value, _) => serde_json::to_string(&value.into_json()).unwrap_or_default(),
+    }
+}
+
+///
+/// Formats the structure or contract `fields` as `{ x: 3, y: 5 }`.
+///
+fn format_fields(fields: Vec<(String, String)>) -> String {
+    if fields.is_empty() {
+        return "{}".to_owned();
+    }
+
+    format!(
+        "{{ {} }}",
+        fields
+            .into_iter()
+            .map(|(name, value)| format!("{}: {}", name, value))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+///
+/// Pops the values of `argument_types` from the evaluation stack. The contract instances are
+/// loaded from the storage instead.
+///
+/// Returns `None`, if some values are not known, e.g. during the trusted setup.
+///
+pub fn pop_arguments<VM: IVirtualMachine>(
+    vm: &mut VM,
+    argument_types: Vec<BuildType>,
+) -> Result<Option<Vec<BuildValue>>, RuntimeError> {
+    let mut values = Vec::with_capacity(argument_types.len());
+    let mut is_known = true;
+
+    for argument_type in argument_types.into_iter().rev() {
+        let size = argument_type.size();
+        let mut flat = Vec::with_capacity(size);
+
+        match argument_type {
+            BuildType::Contract(fields) => {
+                for (index, field) in fields.iter().enumerate() {
+                    let scalars = vm.storage_load(
+                        Scalar::new_constant_usize(
+                            index,
+                            ScalarType::Integer(IntegerType::new(
+                                false,
+                                zinc_const::bitlength::INDEX,
+                            )),
+                        ),
+                        field.r#type.size(),
+                    )?;
+                    flat.extend(
+                        scalars
+                            .into_iter()
+                            .map(|scalar| scalar.to_bigint().unwrap_or_default()),
+                    );
+                }
+                values.push(BuildValue::from_flat_values(
+                    BuildType::Contract(fields),
+                    flat.as_slice(),
+                ));
+            }
+            r#type => {
+                for _ in 0..size {
+                    match vm.pop()?.try_into_value()?.to_bigint() {
+                        Some(value) => flat.push(value),
+                        None => is_known = false,
+                    }
+                }
+                if is_known {
+                    flat.reverse();
+                    values.push(BuildValue::from_flat_values(r#type, flat.as_slice()));
+                }
+            }
+        }
+    }
+
+    if !is_known {
+        return Ok(None);
+    }
+
+    values.reverse();
+    Ok(Some(values))
+}
+
 #[cfg(test)]
 mod tests {
     use num::BigInt;
 
+    use zinc_build::IntegerType;
+    use zinc_build::ScalarType;
+    use zinc_build::Type as BuildType;
+    use zinc_build::Value as BuildValue;
+
     use crate::tests::TestRunner;
 
+    fn integer(bitlength: usize) -> BuildType {
+        BuildType::Scalar(ScalarType::Integer(IntegerType::new(false, bitlength)))
+    }
+
+    fn format_flat(r#type: BuildType, values: &[i64]) -> String {
+        let values: Vec<BigInt> = values.iter().copied().map(BigInt::from).collect();
+        super::format_value(
+            BuildValue::from_flat_values(r#type.clone(), values.as_slice()),
+            &r#type,
+        )
+    }
+
     #[test]
     fn test() {
         TestRunner::new()
@@ -94,4 +226,67 @@ mod tests {
             .test::<u32>(&[])
             .expect(zinc_const::panic::TEST_DATA_VALID);
     }
+
+    #[test]
+    fn test_structure() {
+        TestRunner::new()
+            .push(zinc_build::Push::new(
+                BigInt::from(3),
+                ScalarType::Integer(IntegerType::U8),
+            ))
+            .push(zinc_build::Push::new(
+                BigInt::from(5),
+                ScalarType::Integer(IntegerType::U8),
+            ))
+            .push(zinc_build::Dbg::new(
+                "Point {}".into(),
+                vec![BuildType::Structure(vec![
+                    ("x".to_owned(), integer(8)),
+                    ("y".to_owned(), integer(8)),
+                ])],
+            ))
+            .test::<u32>(&[])
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    #[test]
+    fn test_format_structure() {
+        let r#type = BuildType::Structure(vec![
+            ("x".to_owned(), integer(8)),
+            ("y".to_owned(), integer(8)),
+        ]);
+
+        assert_eq!(format_flat(r#type, &[3, 5]), "{ x: 3, y: 5 }");
+    }
+
+    #[test]
+    fn test_format_array() {
+        let r#type = BuildType::Array(Box::new(integer(8)), 3);
+
+        assert_eq!(format_flat(r#type, &[1, 2, 3]), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_format_tuple() {
+        let r#type = BuildType::Tuple(vec![
+            integer(8),
+            BuildType::Scalar(ScalarType::Boolean),
+            BuildType::Array(Box::new(integer(8)), 2),
+        ]);
+
+        assert_eq!(format_flat(r#type, &[1, 1, 2, 3]), "(1, true, [2, 3])");
+    }
+
+    #[test]
+    fn test_format_enumeration() {
+        let r#type = BuildType::Enumeration {
+            bitlength: 8,
+            variants: vec![
+                ("Red".to_owned(), BigInt::from(1)),
+                ("Green".to_owned(), BigInt::from(2)),
+            ],
+        };
+
+        assert_eq!(format_flat(r#type, &[2]), "Green");
+    }
 }
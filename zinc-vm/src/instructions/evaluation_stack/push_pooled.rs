@@ -0,0 +1,16 @@
+//!
+//! The `PushPooled` instruction.
+//!
+
+use zinc_build::PushPooled;
+
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::MalformedBytecode;
+use crate::error::RuntimeError;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for PushPooled {
+    fn execute(self, _vm: &mut VM) -> Result<(), RuntimeError> {
+        Err(MalformedBytecode::InvalidConstantIndex(self.index).into())
+    }
+}
@@ -5,6 +5,7 @@
 use zinc_build::Else;
 use zinc_build::EndIf;
 use zinc_build::If;
+use zinc_build::ShortCircuit;
 
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::RuntimeError;
@@ -28,6 +29,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for EndIf {
     }
 }
 
+impl<VM: IVirtualMachine> IExecutable<VM> for ShortCircuit {
+    fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
+        vm.short_circuit()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cmp;
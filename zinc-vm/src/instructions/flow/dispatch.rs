@@ -0,0 +1,222 @@
+//!
+//! The `Dispatch` instruction.
+//!
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use zinc_build::Dispatch;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::RuntimeError;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for Dispatch {
+    fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
+        let mut default = Vec::with_capacity(self.size);
+        for _ in 0..self.size {
+            default.push(vm.pop()?.try_into_value()?);
+        }
+        default.reverse();
+
+        let mut branches = Vec::with_capacity(self.branch_count);
+        for _ in 0..self.branch_count {
+            let mut branch = Vec::with_capacity(self.size);
+            for _ in 0..self.size {
+                branch.push(vm.pop()?.try_into_value()?);
+            }
+            branch.reverse();
+            branches.push(branch);
+        }
+        branches.reverse();
+
+        let scrutinee = vm.pop()?.try_into_value()?;
+
+        let discriminants = (0..self.branch_count)
+            .map(|index| {
+                Scalar::new_constant_bigint(&self.start + BigInt::from(index), scrutinee.get_type())
+            })
+            .collect::<Result<Vec<Scalar<VM::E>>, RuntimeError>>()?;
+
+        let cs = vm.constraint_system();
+        let selected = gadgets::select::one_hot(
+            cs.namespace(|| "dispatch"),
+            &scrutinee,
+            discriminants.as_slice(),
+            branches.as_slice(),
+            default.as_slice(),
+        )?;
+
+        for value in selected.into_iter() {
+            vm.push(Cell::Value(value))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use zinc_build::IntegerType;
+    use zinc_build::Type as BuildType;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    const BRANCH_COUNT: usize = 16;
+
+    fn branch_value(discriminant: usize) -> usize {
+        discriminant * 2 + 1
+    }
+
+    ///
+    /// match scrutinee {
+    ///     0 => 1,
+    ///     1 => 3,
+    ///     ...
+    ///     15 => 31,
+    ///     _ => 0,
+    /// }
+    ///
+    fn new_chain() -> TestRunner {
+        let mut runner = TestRunner::new();
+        for discriminant in 0..BRANCH_COUNT {
+            runner = runner
+                .push(zinc_build::Load::new(0, 1))
+                .push(zinc_build::Push::new(
+                    BigInt::from(discriminant),
+                    IntegerType::U8.into(),
+                ))
+                .push(zinc_build::Eq)
+                .push(zinc_build::If)
+                .push(zinc_build::Push::new(
+                    BigInt::from(branch_value(discriminant)),
+                    IntegerType::U8.into(),
+                ))
+                .push(zinc_build::Else);
+        }
+        runner = runner.push(zinc_build::Push::new(
+            BigInt::from(0),
+            IntegerType::U8.into(),
+        ));
+        for _ in 0..BRANCH_COUNT {
+            runner = runner.push(zinc_build::EndIf);
+        }
+        runner
+    }
+
+    ///
+    /// The same `match` as in `new_chain`, lowered to the dispatch table.
+    ///
+    fn new_dispatch() -> TestRunner {
+        let mut runner = TestRunner::new().push(zinc_build::Load::new(0, 1));
+        for discriminant in 0..BRANCH_COUNT {
+            runner = runner.push(zinc_build::Push::new(
+                BigInt::from(branch_value(discriminant)),
+                IntegerType::U8.into(),
+            ));
+        }
+        runner
+            .push(zinc_build::Push::new(
+                BigInt::from(0),
+                IntegerType::U8.into(),
+            ))
+            .push(zinc_build::Dispatch::new(BigInt::from(0), BRANCH_COUNT, 1))
+    }
+
+    #[test]
+    fn test_dispatch() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_build::Push::new(
+                BigInt::from(2),
+                IntegerType::U8.into(),
+            ))
+            .push(zinc_build::Push::new_field(BigInt::from(10)))
+            .push(zinc_build::Push::new_field(BigInt::from(11)))
+            .push(zinc_build::Push::new_field(BigInt::from(20)))
+            .push(zinc_build::Push::new_field(BigInt::from(21)))
+            .push(zinc_build::Push::new_field(BigInt::from(30)))
+            .push(zinc_build::Push::new_field(BigInt::from(31)))
+            .push(zinc_build::Push::new_field(BigInt::from(0)))
+            .push(zinc_build::Push::new_field(BigInt::from(1)))
+            .push(zinc_build::Dispatch::new(BigInt::from(1), 3, 2))
+            .test(&[21, 20])
+    }
+
+    #[test]
+    fn test_dispatch_default() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_build::Push::new(
+                BigInt::from(4),
+                IntegerType::U8.into(),
+            ))
+            .push(zinc_build::Push::new_field(BigInt::from(10)))
+            .push(zinc_build::Push::new_field(BigInt::from(20)))
+            .push(zinc_build::Push::new_field(BigInt::from(30)))
+            .push(zinc_build::Push::new_field(BigInt::from(0)))
+            .push(zinc_build::Dispatch::new(BigInt::from(1), 3, 1))
+            .test(&[0])
+    }
+
+    #[test]
+    fn test_dispatch_variable() -> Result<(), TestingError> {
+        let input = BuildType::Scalar(IntegerType::U8.into());
+
+        for scrutinee in [0, 7, 15, 16, 255].iter().copied() {
+            let expected = if scrutinee < BRANCH_COUNT {
+                branch_value(scrutinee)
+            } else {
+                0
+            };
+
+            new_dispatch().test_with_input(
+                input.clone(),
+                &[BigInt::from(scrutinee)],
+                &[expected],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraints_reduction() -> Result<(), TestingError> {
+        let input = BuildType::Scalar(IntegerType::U8.into());
+
+        for scrutinee in [3, 200].iter().copied() {
+            let expected = if scrutinee < BRANCH_COUNT {
+                branch_value(scrutinee)
+            } else {
+                0
+            };
+
+            let chain = new_chain().test_with_input(
+                input.clone(),
+                &[BigInt::from(scrutinee)],
+                &[expected],
+            )?;
+            let dispatch = new_dispatch().test_with_input(
+                input.clone(),
+                &[BigInt::from(scrutinee)],
+                &[expected],
+            )?;
+
+            println!(
+                "{}-branch match: {} constraints as a conditional chain, {} as a dispatch table",
+                BRANCH_COUNT, chain, dispatch
+            );
+            assert!(
+                dispatch < chain,
+                "the dispatch table must be cheaper than the conditional chain"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 pub mod conditional;
+pub mod dispatch;
 pub mod exit;
 pub mod function;
 pub mod r#loop;
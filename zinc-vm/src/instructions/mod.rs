@@ -28,6 +28,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Instruction {
             Self::NoOperation(inner) => inner.execute(vm),
 
             Self::Push(inner) => inner.execute(vm),
+            Self::PushPooled(inner) => inner.execute(vm),
             Self::Slice(inner) => inner.execute(vm),
             Self::Copy(inner) => inner.execute(vm),
 
@@ -70,6 +71,8 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Instruction {
             Self::If(inner) => inner.execute(vm),
             Self::Else(inner) => inner.execute(vm),
             Self::EndIf(inner) => inner.execute(vm),
+            Self::ShortCircuit(inner) => inner.execute(vm),
+            Self::Dispatch(inner) => inner.execute(vm),
             Self::LoopBegin(inner) => inner.execute(vm),
             Self::LoopEnd(inner) => inner.execute(vm),
             Self::Call(inner) => inner.execute(vm),
@@ -69,6 +69,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Div {
 mod test {
     use num::BigInt;
 
+    use crate::error::RuntimeError;
     use crate::tests::TestRunner;
     use crate::tests::TestingError;
 
@@ -115,4 +116,46 @@ mod test {
             .push(zinc_build::Div)
             .test(&[3, -3, -2, 2])
     }
+
+    #[test]
+    fn test_div_overflow() {
+        let result = TestRunner::new()
+            .push(zinc_build::Push::new(
+                BigInt::from(i8::MIN),
+                IntegerType::I8.into(),
+            ))
+            .push(zinc_build::Push::new(
+                BigInt::from(-1),
+                IntegerType::I8.into(),
+            ))
+            .push(zinc_build::Div)
+            .test::<i32>(&[]);
+
+        assert!(matches!(
+            result,
+            Err(TestingError::RuntimeError(
+                RuntimeError::ValueOverflow { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let result = TestRunner::new()
+            .push(zinc_build::Push::new(
+                BigInt::from(9),
+                IntegerType::I8.into(),
+            ))
+            .push(zinc_build::Push::new(
+                BigInt::from(0),
+                IntegerType::I8.into(),
+            ))
+            .push(zinc_build::Div)
+            .test::<i32>(&[]);
+
+        assert!(matches!(
+            result,
+            Err(TestingError::RuntimeError(RuntimeError::DivisionByZero))
+        ));
+    }
 }
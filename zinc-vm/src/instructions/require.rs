@@ -2,17 +2,23 @@
 //! The `Require` instruction.
 //!
 
+use serde_json::Value as JsonValue;
+
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
 use zinc_build::Require;
+use zinc_build::Value as BuildValue;
 
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::RuntimeError;
 use crate::gadgets;
+use crate::instructions::dbg;
 use crate::instructions::IExecutable;
 
 impl<VM: IVirtualMachine> IExecutable<VM> for Require {
     fn execute(self, vm: &mut VM) -> Result<(), RuntimeError> {
+        let arguments = dbg::pop_arguments(vm, self.argument_types)?;
         let value = vm.pop()?.try_into_value()?;
         let condition = vm.condition_top()?;
 
@@ -21,12 +27,34 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Require {
         let not_c = gadgets::logical::not::not(cs.namespace(|| "not"), &condition)?;
         let condition = gadgets::logical::or::or(cs.namespace(|| "or"), &value, &not_c)?;
 
-        let message = match &self.message {
-            Some(message) => Some(message.as_str()),
-            None => None,
+        let is_failed = condition
+            .get_value()
+            .map(|value| value.is_zero())
+            .unwrap_or_default();
+        let message = match (self.message, arguments) {
+            (Some(format), Some(values)) if is_failed => Some(render(format, values)),
+            (message, _) => message,
+        };
+        gadgets::require::require(cs, condition, message.as_deref())
+    }
+}
+
+///
+/// Replaces the `{}` placeholders in the `format` string with the `values`.
+///
+/// Is only called if the requirement has failed, so the arguments never affect the constraint
+/// system and the happy path performance.
+///
+fn render(format: String, values: Vec<BuildValue>) -> String {
+    let mut buffer = format;
+    for value in values.into_iter() {
+        let string = match value.into_json() {
+            JsonValue::String(string) => string,
+            json => json.to_string(),
         };
-        gadgets::require::require(cs, condition, message)
+        buffer = buffer.replacen("{}", string.as_str(), 1);
     }
+    buffer
 }
 
 #[cfg(test)]
@@ -39,13 +67,15 @@ mod tests {
     use crate::tests::TestRunner;
     use crate::tests::TestingError;
 
+    use zinc_build::IntegerType;
     use zinc_build::ScalarType;
+    use zinc_build::Type as BuildType;
 
     #[test]
     fn test_require_ok() -> Result<(), TestingError> {
         TestRunner::new()
             .push(zinc_build::Push::new(BigInt::one(), ScalarType::Boolean))
-            .push(zinc_build::Require::new(None))
+            .push(zinc_build::Require::new(None, vec![]))
             .test::<i32>(&[])
     }
 
@@ -53,7 +83,7 @@ mod tests {
     fn test_require_fail() {
         let res = TestRunner::new()
             .push(zinc_build::Push::new(BigInt::zero(), ScalarType::Boolean))
-            .push(zinc_build::Require::new(None))
+            .push(zinc_build::Require::new(None, vec![]))
             .test::<i32>(&[]);
 
         match res {
@@ -68,8 +98,49 @@ mod tests {
             .push(zinc_build::Push::new(BigInt::zero(), ScalarType::Boolean))
             .push(zinc_build::If)
             .push(zinc_build::Push::new(BigInt::zero(), ScalarType::Boolean))
-            .push(zinc_build::Require::new(None))
+            .push(zinc_build::Require::new(None, vec![]))
             .push(zinc_build::EndIf)
             .test::<i32>(&[])
     }
+
+    #[test]
+    fn test_require_fail_formatted() {
+        let res = TestRunner::new()
+            .push(zinc_build::Push::new(BigInt::zero(), ScalarType::Boolean))
+            .push(zinc_build::Push::new(
+                BigInt::from(5),
+                ScalarType::Integer(IntegerType::new(false, 64)),
+            ))
+            .push(zinc_build::Push::new(
+                BigInt::from(10),
+                ScalarType::Integer(IntegerType::new(false, 64)),
+            ))
+            .push(zinc_build::Require::new(
+                Some("insufficient balance: {} < {}".to_owned()),
+                vec![
+                    BuildType::Scalar(ScalarType::Integer(IntegerType::new(false, 64))),
+                    BuildType::Scalar(ScalarType::Integer(IntegerType::new(false, 64))),
+                ],
+            ))
+            .test::<i32>(&[]);
+
+        match res {
+            Err(TestingError::RuntimeError(RuntimeError::RequireError(message))) => {
+                assert_eq!(message, "insufficient balance: 5 < 10")
+            }
+            _ => panic!("Expected require error"),
+        }
+    }
+
+    #[test]
+    fn test_require_ok_formatted() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_build::Push::new(BigInt::one(), ScalarType::Boolean))
+            .push(zinc_build::Push::new(BigInt::from(42), ScalarType::Field))
+            .push(zinc_build::Require::new(
+                Some("value: {}".to_owned()),
+                vec![BuildType::Scalar(ScalarType::Field)],
+            ))
+            .test::<i32>(&[])
+    }
 }
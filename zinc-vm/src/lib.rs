@@ -14,14 +14,23 @@ pub(crate) mod error;
 pub mod gadgets;
 pub(crate) mod instructions;
 
+pub use franklin_crypto::bellman::groth16::Proof;
+pub use franklin_crypto::bellman::groth16::VerifyingKey;
 pub use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 pub use self::core::circuit::facade::Facade as CircuitFacade;
+pub use self::core::circuit::input::Input as CircuitInput;
 pub use self::core::circuit::output::Output as CircuitOutput;
 pub use self::core::contract::facade::Facade as ContractFacade;
 pub use self::core::contract::input::Input as ContractInput;
 pub use self::core::contract::output::Output as ContractOutput;
+pub use self::core::contract::storage::proof::verify_storage_proof;
+pub use self::core::contract::storage::proof::StorageProof;
 pub use self::core::facade::Facade;
+pub use self::core::key_cache::KeyCache;
+pub use self::core::public_input;
+pub use self::core::rng::SEED_SIZE as RNG_SEED_SIZE;
+pub use self::core::setup_report::SetupReport;
 pub use self::error::RuntimeError;
 pub use self::error::VerificationError;
 
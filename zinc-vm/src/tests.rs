@@ -25,7 +25,7 @@ type TestVirtualMachine = State<Bn256, TestConstraintSystem<Bn256>>;
 
 fn new_test_constrained_vm() -> TestVirtualMachine {
     let cs = TestConstraintSystem::new();
-    TestVirtualMachine::new(cs)
+    TestVirtualMachine::new(cs, zinc_const::limit::VM_DATA_STACK_SIZE)
 }
 
 fn assert_stack_eq<VM, BI>(vm: &mut VM, expected_stack: &[BI])
@@ -84,6 +84,44 @@ impl TestRunner {
         })
     }
 
+    pub fn test_with_input<T: Into<BigInt> + Copy>(
+        self,
+        input: BuildType,
+        input_values: &[BigInt],
+        expected_stack: &[T],
+    ) -> Result<usize, TestingError> {
+        let mut vm = new_test_constrained_vm();
+
+        // the entry call at the address 0 is skipped to pass the input directly
+        let circuit = BuildCircuit::new(
+            "test".to_owned(),
+            1,
+            input,
+            BuildType::Unit,
+            HashMap::new(),
+            self.instructions,
+        );
+
+        vm.run(circuit, Some(input_values), |_| {}, |_| Ok(()))
+            .map_err(TestingError::RuntimeError)?;
+
+        let cs = vm.constraint_system();
+
+        let unconstrained = cs.find_unconstrained();
+        let satisfied = cs.is_satisfied();
+        let constraints = cs.num_constraints();
+
+        assert_stack_eq(&mut vm, expected_stack);
+
+        if !unconstrained.is_empty() {
+            Err(TestingError::Unconstrained(unconstrained))
+        } else if !satisfied {
+            Err(TestingError::Unsatisfied)
+        } else {
+            Ok(constraints)
+        }
+    }
+
     fn test_constrained<T: Into<BigInt> + Copy>(
         self,
         expected_stack: &[T],
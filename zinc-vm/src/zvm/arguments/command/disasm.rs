@@ -0,0 +1,56 @@
+//!
+//! The Zinc virtual machine `disasm` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use zinc_build::Application as BuildApplication;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `disasm` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(name = "disasm", about = "Prints the bytecode disassembly listing")]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The method or function name, if only its instructions must be printed.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = BuildApplication::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        if let Some(ref method) = self.method {
+            if application.function_address(method.as_str()).is_none() {
+                return Err(Error::MethodNotFound {
+                    name: method.to_owned(),
+                });
+            }
+        }
+
+        let mut listing = String::new();
+        application
+            .fmt_listing(&mut listing, self.method.as_deref())
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        print!("{}", listing);
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
@@ -2,16 +2,22 @@
 //! The Zinc virtual machine subcommand.
 //!
 
+pub mod disasm;
 pub mod prove;
 pub mod run;
 pub mod setup;
 pub mod test;
 pub mod verify;
 
+use std::convert::TryInto;
+
 use structopt::StructOpt;
 
+use zinc_vm::RNG_SEED_SIZE;
+
 use crate::error::Error;
 
+use self::disasm::Command as DisasmCommand;
 use self::prove::Command as ProveCommand;
 use self::run::Command as RunCommand;
 use self::setup::Command as SetupCommand;
@@ -47,6 +53,8 @@ pub enum Command {
     Prove(ProveCommand),
     /// Verifies a proof using the verifying key.
     Verify(VerifyCommand),
+    /// Prints the bytecode disassembly listing.
+    Disasm(DisasmCommand),
 }
 
 impl IExecutable for Command {
@@ -59,6 +67,30 @@ impl IExecutable for Command {
             Command::Setup(inner) => inner.execute(),
             Command::Prove(inner) => inner.execute(),
             Command::Verify(inner) => inner.execute(),
+            Command::Disasm(inner) => inner.execute(),
         }
     }
 }
+
+///
+/// Decodes the hexadecimal random number generator `seed` passed to the `setup` and `prove`
+/// subcommands.
+///
+pub fn decode_seed(seed: Option<&str>) -> Result<Option<[u8; RNG_SEED_SIZE]>, Error> {
+    let seed = match seed {
+        Some(seed) => seed,
+        None => return Ok(None),
+    };
+
+    let bytes = hex::decode(seed.trim_start_matches("0x")).map_err(|error| Error::HexDecoding {
+        context: "seed".to_owned(),
+        error,
+    })?;
+    let found = bytes.len();
+    let seed = bytes.try_into().map_err(|_bytes| Error::InvalidSeedSize {
+        expected: RNG_SEED_SIZE,
+        found,
+    })?;
+
+    Ok(Some(seed))
+}
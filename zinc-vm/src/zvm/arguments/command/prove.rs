@@ -4,8 +4,10 @@
 
 use std::convert::TryFrom;
 use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
 
+use num::BigInt;
 use serde_json::Value as JsonValue;
 use structopt::StructOpt;
 
@@ -16,12 +18,17 @@ use zinc_build::Application as BuildApplication;
 use zinc_build::ContractFieldValue as BuildContractFieldValue;
 use zinc_build::InputBuild;
 use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
 use zinc_vm::CircuitFacade;
+use zinc_vm::CircuitInput;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
+use zinc_vm::KeyCache;
+use zinc_vm::VerificationError;
 
+use crate::arguments::command;
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
 use crate::error::IErrorPath;
@@ -37,20 +44,36 @@ pub struct Command {
     pub binary_path: PathBuf,
 
     /// The path to the proving key file.
+    /// If omitted, the key is taken from the proving key cache.
     #[structopt(long = "proving-key")]
-    pub proving_key_path: PathBuf,
+    pub proving_key_path: Option<PathBuf>,
 
     /// The path to the input JSON file.
     #[structopt(long = "input")]
     pub input_path: PathBuf,
 
     /// The path to the output JSON file.
+    /// The proof public input is written there if `--witness-only` is passed.
     #[structopt(long = "output")]
     pub output_path: PathBuf,
 
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// Disables the proving key cache.
+    #[structopt(long = "no-cache")]
+    pub no_cache: bool,
+
+    /// Only computes the proof public input and writes it to the output file,
+    /// without generating the proof itself.
+    #[structopt(long = "witness-only")]
+    pub witness_only: bool,
+
+    /// The hexadecimal 32-byte random number generator seed, which makes the proof reproducible.
+    /// Is for testing only.
+    #[structopt(long = "seed")]
+    pub seed: Option<String>,
 }
 
 impl IExecutable for Command {
@@ -63,17 +86,47 @@ impl IExecutable for Command {
         let application = BuildApplication::try_from_slice(bytecode.as_slice())
             .map_err(Error::ApplicationDecoding)?;
 
+        let seed = command::decode_seed(self.seed.as_deref())?;
+
         // Read the input file
         let input_template = fs::read_to_string(&self.input_path)
             .error_with_path(|| self.input_path.to_string_lossy())?;
         let input: InputBuild = serde_json::from_str(input_template.as_str())?;
 
-        // Read the proving key
-        let proving_key_path = self.proving_key_path;
-        let file = fs::File::open(&proving_key_path)
-            .error_with_path(|| proving_key_path.to_string_lossy())?;
-        let params = Parameters::<Bn256>::read(file, true)
-            .error_with_path(|| proving_key_path.to_string_lossy())?;
+        // Read the proving key, which is not required for the witness-only mode
+        let params = match self.proving_key_path {
+            _ if self.witness_only => None,
+            Some(proving_key_path) => {
+                let file = fs::File::open(&proving_key_path)
+                    .error_with_path(|| proving_key_path.to_string_lossy())?;
+                let params = Parameters::<Bn256>::read(file, true)
+                    .error_with_path(|| proving_key_path.to_string_lossy())?;
+                Some(params)
+            }
+            None => {
+                let hash = match application {
+                    BuildApplication::Circuit(ref circuit) => KeyCache::hash_circuit(circuit),
+                    BuildApplication::Contract(ref contract) => {
+                        let method_name = self.method.as_ref().ok_or(Error::MethodNameNotFound)?;
+                        KeyCache::hash_contract_method(contract, method_name.as_str()).ok_or_else(
+                            || Error::MethodNotFound {
+                                name: method_name.to_owned(),
+                            },
+                        )?
+                    }
+                };
+
+                let cache = if self.no_cache {
+                    None
+                } else {
+                    KeyCache::new_default()
+                };
+                let params = cache
+                    .and_then(|cache| cache.load::<Bn256>(hash.as_str()))
+                    .ok_or(Error::ProvingKeyNotCached { hash })?;
+                Some(params)
+            }
+        };
 
         let proof = match application {
             BuildApplication::Circuit(circuit) => match input {
@@ -81,8 +134,18 @@ impl IExecutable for Command {
                     let input_type = circuit.input.clone();
                     let arguments = BuildValue::try_from_typed_json(arguments, input_type)?;
 
+                    let params = match params {
+                        Some(params) => params,
+                        None => {
+                            let output = CircuitFacade::new(circuit)
+                                .run::<Bn256>(CircuitInput::new(arguments))?;
+                            let public_input = zinc_vm::public_input::assemble(output.result, None);
+                            return Self::write_public_input(&self.output_path, public_input);
+                        }
+                    };
+
                     let (_output, proof) =
-                        CircuitFacade::new(circuit).prove::<Bn256>(params, arguments)?;
+                        CircuitFacade::new(circuit).prove::<Bn256>(params, arguments, seed)?;
 
                     proof
                 }
@@ -104,6 +167,7 @@ impl IExecutable for Command {
                     arguments,
                     msg: transactions,
                     storage,
+                    env,
                 } => {
                     let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
                     let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
@@ -135,7 +199,7 @@ impl IExecutable for Command {
                         }
                         value => return Err(Error::InvalidContractStorageFormat { found: value }),
                     };
-                    
+
                     let mut transaction_msgs: Vec<TransactionMsg> = Vec::new();
                     for i in 0..transactions.as_array().unwrap().len() {
                         let transaction_msg = TransactionMsg::try_from(&transactions.clone()[i])
@@ -145,22 +209,38 @@ impl IExecutable for Command {
                             })?;
                         transaction_msgs.push(transaction_msg);
                     }
+                    let environment = Environment::try_from(&env).map_err(|error| {
+                        Error::InvalidEnvironment {
+                            inner: error,
+                            found: env.clone(),
+                        }
+                    })?;
 
-                    let (_output, proof) = ContractFacade::new(contract).prove::<Bn256>(
-                        params,
-                        ContractInput::new(
-                            method_arguments,
-                            BuildValue::Contract(storage_values),
-                            method_name,
-                            transaction_msgs,
-                            //     TransactionMsg::try_from(&transaction).map_err(|error| {
-                            //         Error::InvalidTransaction {
-                            //             inner: error,
-                            //             found: transaction,
-                            //         }
-                            //     })?,
-                        ),
-                    )?;
+                    let mut input = ContractInput::new(
+                        method_arguments,
+                        BuildValue::Contract(storage_values),
+                        method_name,
+                        transaction_msgs,
+                        //     TransactionMsg::try_from(&transaction).map_err(|error| {
+                        //         Error::InvalidTransaction {
+                        //             inner: error,
+                        //             found: transaction,
+                        //         }
+                        //     })?,
+                    );
+                    input.environment = environment;
+
+                    let params = match params {
+                        Some(params) => params,
+                        None => {
+                            let (_output, public_input) =
+                                ContractFacade::new(contract).witness::<Bn256>(input)?;
+                            return Self::write_public_input(&self.output_path, public_input);
+                        }
+                    };
+
+                    let (_output, proof) =
+                        ContractFacade::new(contract).prove::<Bn256>(params, input, seed)?;
 
                     proof
                 }
@@ -176,3 +256,30 @@ impl IExecutable for Command {
         Ok(zinc_const::exit_code::SUCCESS as i32)
     }
 }
+
+impl Command {
+    ///
+    /// Writes the proof public input to the output file as an array of field elements.
+    ///
+    fn write_public_input(output_path: &Path, public_input: Vec<BigInt>) -> Result<i32, Error> {
+        let public_input = public_input
+            .into_iter()
+            .map(|value| {
+                let value = zinc_vm::gadgets::scalar::fr_bigint::bigint_to_fr::<Bn256>(&value)
+                    .ok_or_else(|| VerificationError::ValueOverflow(value))?;
+                let value =
+                    zinc_vm::gadgets::scalar::fr_bigint::fr_to_bigint::<Bn256>(&value, false);
+                Ok(JsonValue::String(format!(
+                    "0x{}",
+                    value.to_str_radix(zinc_const::base::HEXADECIMAL)
+                )))
+            })
+            .collect::<Result<Vec<JsonValue>, VerificationError>>()?;
+
+        let public_input = serde_json::to_string_pretty(&JsonValue::Array(public_input))
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        fs::write(output_path, public_input).error_with_path(|| output_path.to_string_lossy())?;
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
@@ -6,18 +6,22 @@ use std::convert::TryFrom;
 use std::fs;
 use std::path::PathBuf;
 
+use num::BigInt;
 use serde_json::Value as JsonValue;
 use structopt::StructOpt;
 
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
+use zinc_build::ContractFieldType as BuildContractFieldType;
 use zinc_build::ContractFieldValue as BuildContractFieldValue;
 use zinc_build::InputBuild;
 use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
 use zinc_zksync::TransactionMsg;
 
 use zinc_vm::CircuitFacade;
+use zinc_vm::CircuitInput;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
 
@@ -46,6 +50,19 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The maximal data stack size in cells, beyond which the execution fails.
+    #[structopt(long = "max-stack")]
+    pub max_stack: Option<usize>,
+
+    /// The path to the contract storage JSON file, which overrides the input file storage if
+    /// it exists, and is rewritten after each mutable method call.
+    #[structopt(long = "storage")]
+    pub storage_path: Option<PathBuf>,
+
+    /// Does not write the contract storage after the method call.
+    #[structopt(long = "no-storage-write")]
+    pub no_storage_write: bool,
 }
 
 impl IExecutable for Command {
@@ -70,7 +87,12 @@ impl IExecutable for Command {
                     let input_type = circuit.input.clone();
                     let arguments = BuildValue::try_from_typed_json(arguments, input_type)?;
 
-                    CircuitFacade::new(circuit).run::<Bn256>(arguments)?.result
+                    let mut input = CircuitInput::new(arguments);
+                    if let Some(max_stack) = self.max_stack {
+                        input.max_stack_size = max_stack;
+                    }
+
+                    CircuitFacade::new(circuit).run::<Bn256>(input)?.result
                 }
                 InputBuild::Contract { .. } => {
                     return Err(Error::InputDataInvalid {
@@ -90,6 +112,7 @@ impl IExecutable for Command {
                     arguments,
                     msg: transactions,
                     storage,
+                    env,
                 } => {
                     let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
                     let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
@@ -106,23 +129,18 @@ impl IExecutable for Command {
                     let method_arguments =
                         BuildValue::try_from_typed_json(method_arguments, method.input)?;
 
-                    let storage_size = contract.storage.len();
-                    let storage_values = match storage {
-                        JsonValue::Array(array) => {
-                            let mut storage_values = Vec::with_capacity(contract.storage.len());
-                            for (field, value) in contract.storage.clone().into_iter().zip(array) {
-                                storage_values.push(BuildContractFieldValue::new(
-                                    field.name,
-                                    BuildValue::try_from_typed_json(value, field.r#type)?,
-                                    field.is_public,
-                                    field.is_implicit,
-                                ));
-                            }
-                            storage_values
+                    let storage = match self.storage_path {
+                        Some(ref path) if path.exists() => {
+                            let storage = fs::read_to_string(path)
+                                .error_with_path(|| path.to_string_lossy())?;
+                            serde_json::from_str(storage.as_str())?
                         }
-                        value => return Err(Error::InvalidContractStorageFormat { found: value }),
+                        _ => storage,
                     };
-                    
+                    let storage_size = contract.storage.len();
+                    let storage_values =
+                        Self::storage_values(contract.storage.as_slice(), storage)?;
+
                     let mut transaction_msgs: Vec<TransactionMsg> = Vec::new();
                     for i in 0..transactions.as_array().unwrap().len() {
                         let transaction_msg = TransactionMsg::try_from(&transactions.clone()[i])
@@ -132,8 +150,13 @@ impl IExecutable for Command {
                             })?;
                         transaction_msgs.push(transaction_msg);
                     }
+                    let environment =
+                        Environment::try_from(&env).map_err(|error| Error::InvalidEnvironment {
+                            inner: error,
+                            found: env.clone(),
+                        })?;
 
-                    let output = ContractFacade::new(contract).run::<Bn256>(ContractInput::new(
+                    let mut input = ContractInput::new(
                         method_arguments,
                         BuildValue::Contract(storage_values),
                         method_name,
@@ -144,10 +167,28 @@ impl IExecutable for Command {
                         //         found: transaction.clone(),
                         //     }
                         // })?,
-                    ))?;
+                    );
+                    input.environment = environment;
+                    if let Some(max_stack) = self.max_stack {
+                        input.max_stack_size = max_stack;
+                    }
+
+                    let is_constructor =
+                        input.method_name.as_str() == zinc_const::contract::CONSTRUCTOR_NAME;
+                    let output = ContractFacade::new(contract).run::<Bn256>(input)?;
+                    let result = output.result.clone();
+
+                    // the constructor result is written as the storage, so it cannot be run again
+                    let storage = if is_constructor {
+                        output.into_initialized_storage(BigInt::from(
+                            zinc_const::contract::LOCAL_ADDRESS,
+                        ))
+                    } else {
+                        output.storage
+                    };
 
                     let mut storage_values = Vec::with_capacity(storage_size);
-                    match output.storage {
+                    match storage {
                         BuildValue::Contract(fields) => {
                             for field in fields.into_iter() {
                                 storage_values.push(field.value.into_json());
@@ -160,26 +201,98 @@ impl IExecutable for Command {
                         }
                     }
 
-                    let input_str = serde_json::to_string_pretty(&InputBuild::new_contract(
-                        JsonValue::Array(storage_values),
-                        transactions,
-                        arguments,
-                    ))
-                    .expect(zinc_const::panic::DATA_CONVERSION);
-                    fs::write(&input_path, input_str)
-                        .error_with_path(|| input_path.to_string_lossy())?;
+                    match self.storage_path {
+                        _ if self.no_storage_write => {}
+                        Some(path) if method.is_mutable || is_constructor => {
+                            let storage_str =
+                                serde_json::to_string_pretty(&JsonValue::Array(storage_values))
+                                    .expect(zinc_const::panic::DATA_CONVERSION)
+                                    + "\n";
 
-                    output.result
+                            // the storage is written to a temporary file first, so it is never
+                            // left half-written if the process is interrupted
+                            let mut temporary_path = path.clone().into_os_string();
+                            temporary_path.push(".tmp");
+                            let temporary_path = PathBuf::from(temporary_path);
+                            fs::write(&temporary_path, storage_str)
+                                .error_with_path(|| temporary_path.to_string_lossy())?;
+                            fs::rename(&temporary_path, &path)
+                                .error_with_path(|| path.to_string_lossy())?;
+                        }
+                        Some(_) => {}
+                        None => {
+                            let input_str =
+                                serde_json::to_string_pretty(&InputBuild::new_contract(
+                                    JsonValue::Array(storage_values),
+                                    transactions,
+                                    arguments,
+                                    env,
+                                ))
+                                .expect(zinc_const::panic::DATA_CONVERSION);
+                            fs::write(&input_path, input_str)
+                                .error_with_path(|| input_path.to_string_lossy())?;
+                        }
+                    }
+
+                    result
                 }
             },
         };
 
+        Self::write_output(output, self.output_path)
+    }
+}
+
+impl Command {
+    ///
+    /// Writes the application `output` to the file at `path` and prints it to the terminal.
+    ///
+    fn write_output(output: BuildValue, path: PathBuf) -> Result<i32, Error> {
         let output_json = serde_json::to_string_pretty(&output.into_json())? + "\n";
-        let output_path = self.output_path;
-        fs::write(&output_path, &output_json).error_with_path(|| output_path.to_string_lossy())?;
+        fs::write(&path, &output_json).error_with_path(|| path.to_string_lossy())?;
 
         print!("{}", output_json);
 
         Ok(zinc_const::exit_code::SUCCESS as i32)
     }
+
+    ///
+    /// Converts the contract `storage` JSON into the typed values, checking it against the
+    /// contract storage `fields` layout.
+    ///
+    fn storage_values(
+        fields: &[BuildContractFieldType],
+        storage: JsonValue,
+    ) -> Result<Vec<BuildContractFieldValue>, Error> {
+        let values = match storage {
+            JsonValue::Array(values) => values,
+            value => return Err(Error::InvalidContractStorageFormat { found: value }),
+        };
+        if values.len() != fields.len() {
+            return Err(Error::ContractStorageFieldCount {
+                expected: fields.len(),
+                found: values.len(),
+            });
+        }
+
+        let mut storage_values = Vec::with_capacity(fields.len());
+        for (index, (field, value)) in fields.iter().zip(values).enumerate() {
+            let value = BuildValue::try_from_typed_json(value, field.r#type.to_owned()).map_err(
+                |inner| Error::ContractStorageFieldType {
+                    index,
+                    name: field.name.to_owned(),
+                    inner,
+                },
+            )?;
+
+            storage_values.push(BuildContractFieldValue::new(
+                field.name.to_owned(),
+                value,
+                field.is_public,
+                field.is_implicit,
+            ));
+        }
+
+        Ok(storage_values)
+    }
 }
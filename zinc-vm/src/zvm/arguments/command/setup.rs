@@ -7,13 +7,16 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use franklin_crypto::bellman::groth16::Parameters;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
 
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
+use zinc_vm::KeyCache;
 
+use crate::arguments::command;
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
 use crate::error::IErrorPath;
@@ -42,6 +45,19 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The maximal number of constraints, beyond which the setup fails before generating the keys.
+    #[structopt(long = "max-constraints")]
+    pub max_constraints: Option<usize>,
+
+    /// Disables the proving key cache.
+    #[structopt(long = "no-cache")]
+    pub no_cache: bool,
+
+    /// The hexadecimal 32-byte random number generator seed, which makes the keys reproducible.
+    /// Is for testing only, since anyone knowing the seed can forge proofs. Disables the cache.
+    #[structopt(long = "seed")]
+    pub seed: Option<String>,
 }
 
 impl IExecutable for Command {
@@ -53,11 +69,47 @@ impl IExecutable for Command {
         let application = BuildApplication::try_from_slice(bytes.as_slice())
             .map_err(Error::ApplicationDecoding)?;
 
+        let seed = command::decode_seed(self.seed.as_deref())?;
+
+        let cache = if self.no_cache || seed.is_some() {
+            None
+        } else {
+            KeyCache::new_default()
+        };
+
         let params = match application {
-            BuildApplication::Circuit(circuit) => CircuitFacade::new(circuit).setup::<Bn256>()?,
+            BuildApplication::Circuit(circuit) => {
+                let hash = KeyCache::hash_circuit(&circuit);
+                match cache.as_ref().and_then(|cache| cache.load(hash.as_str())) {
+                    Some(params) => params,
+                    None => {
+                        let (params, report) = CircuitFacade::new(circuit)
+                            .setup::<Bn256>(self.max_constraints, seed)?;
+                        println!("{}", report);
+                        Self::cache(cache.as_ref(), hash.as_str(), &params);
+                        params
+                    }
+                }
+            }
             BuildApplication::Contract(contract) => {
                 let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
-                ContractFacade::new(contract).setup::<Bn256>(method_name)?
+                let hash = KeyCache::hash_contract_method(&contract, method_name.as_str())
+                    .ok_or_else(|| Error::MethodNotFound {
+                        name: method_name.clone(),
+                    })?;
+                match cache.as_ref().and_then(|cache| cache.load(hash.as_str())) {
+                    Some(params) => params,
+                    None => {
+                        let (params, report) = ContractFacade::new(contract).setup::<Bn256>(
+                            method_name,
+                            self.max_constraints,
+                            seed,
+                        )?;
+                        println!("{}", report);
+                        Self::cache(cache.as_ref(), hash.as_str(), &params);
+                        params
+                    }
+                }
             }
         };
 
@@ -77,3 +129,18 @@ impl IExecutable for Command {
         Ok(zinc_const::exit_code::SUCCESS as i32)
     }
 }
+
+impl Command {
+    ///
+    /// Stores the generated parameters in the proving key cache, if the cache is enabled.
+    ///
+    /// The cache writing errors are not fatal, since the keys are written to the project anyway.
+    ///
+    fn cache(cache: Option<&KeyCache>, hash: &str, params: &Parameters<Bn256>) {
+        if let Some(cache) = cache {
+            if let Err(error) = cache.store(hash, params) {
+                log::warn!("Proving key caching: {}", error);
+            }
+        }
+    }
+}
@@ -2,11 +2,13 @@
 //! The Zinc virtual machine `verify` subcommand.
 //!
 
+use std::convert::TryFrom;
 use std::fs;
 use std::io::Read;
 use std::path::PathBuf;
 
 use colored::Colorize;
+use num::BigInt;
 use structopt::StructOpt;
 
 use franklin_crypto::bellman::groth16::Proof;
@@ -14,7 +16,10 @@ use franklin_crypto::bellman::groth16::VerifyingKey;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_build::Application as BuildApplication;
+use zinc_build::ContractMethod as BuildContractMethod;
+use zinc_build::InputBuild;
 use zinc_build::Value as BuildValue;
+use zinc_zksync::Environment;
 
 use zinc_vm::Facade;
 
@@ -43,6 +48,11 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The path to the input JSON file, whose contract environment and `#[public]` method
+    /// arguments are committed to by the proof.
+    #[structopt(long = "input")]
+    pub input_path: Option<PathBuf>,
 }
 
 impl IExecutable for Command {
@@ -80,26 +90,35 @@ impl IExecutable for Command {
         let output_text = fs::read_to_string(&self.output_path)
             .error_with_path(|| self.output_path.to_string_lossy())?;
         let output_json = serde_json::from_str(output_text.as_str())?;
-        let output_type = match application {
-            BuildApplication::Circuit(circuit) => circuit.output,
+        let (output_type, contract_input) = match application {
+            BuildApplication::Circuit(circuit) => (circuit.output, None),
             BuildApplication::Contract(contract) => {
-                let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                let method_name = self.method.clone().ok_or(Error::MethodNameNotFound)?;
                 let method = contract
                     .methods
                     .get(method_name.as_str())
                     .cloned()
                     .ok_or(Error::MethodNotFound { name: method_name })?;
-                if method.is_mutable {
-                    method.output.into_mutable_method_output()
-                } else {
-                    method.output
-                }
+                let contract_input = self.contract_input(&method)?;
+                (
+                    method.output.into_mutable_method_output(),
+                    Some(contract_input),
+                )
             }
         };
         let output_value = BuildValue::try_from_typed_json(output_json, output_type)?;
 
         // Verify the proof
-        let verified = Facade::verify::<Bn256>(verifying_key, proof, output_value)?;
+        let verified = match contract_input {
+            Some((public_arguments, environment)) => Facade::verify_contract::<Bn256>(
+                verifying_key,
+                proof,
+                public_arguments,
+                output_value,
+                environment,
+            )?,
+            None => Facade::verify::<Bn256>(verifying_key, proof, output_value)?,
+        };
 
         Ok(if verified {
             println!("{}", " ✔ Verified".bold().green());
@@ -110,3 +129,60 @@ impl IExecutable for Command {
         })
     }
 }
+
+impl Command {
+    ///
+    /// Reads the `#[public]` method argument scalars and the contract environment from the input
+    /// file. The environment defaults to zeros if the file or its `env` section is not specified.
+    ///
+    /// The input file is required if the method has `#[public]` arguments.
+    ///
+    fn contract_input(
+        &self,
+        method: &BuildContractMethod,
+    ) -> Result<(Vec<BigInt>, Environment), Error> {
+        let input_path = match self.input_path {
+            Some(ref input_path) => input_path,
+            None if method.public_arguments.is_empty() => {
+                return Ok((vec![], Environment::default()))
+            }
+            None => {
+                return Err(Error::MethodArgumentsNotFound {
+                    name: method.name.to_owned(),
+                })
+            }
+        };
+
+        let input_text =
+            fs::read_to_string(input_path).error_with_path(|| input_path.to_string_lossy())?;
+        let input: InputBuild = serde_json::from_str(input_text.as_str())?;
+        match input {
+            InputBuild::Contract { env, arguments, .. } => {
+                let public_arguments = if method.public_arguments.is_empty() {
+                    vec![]
+                } else {
+                    let arguments = arguments.get(method.name.as_str()).cloned().ok_or(
+                        Error::MethodArgumentsNotFound {
+                            name: method.name.to_owned(),
+                        },
+                    )?;
+                    let arguments =
+                        BuildValue::try_from_typed_json(arguments, method.input.to_owned())?;
+                    method.public_argument_values(arguments)
+                };
+
+                let environment =
+                    Environment::try_from(&env).map_err(|error| Error::InvalidEnvironment {
+                        inner: error,
+                        found: env,
+                    })?;
+
+                Ok((public_arguments, environment))
+            }
+            InputBuild::Circuit { .. } => Err(Error::InputDataInvalid {
+                expected: "contract".to_owned(),
+                found: "circuit".to_owned(),
+            }),
+        }
+    }
+}
@@ -18,6 +18,10 @@ pub struct Arguments {
     #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
     pub verbosity: usize,
 
+    /// Prints the errors as JSON objects with stable error codes.
+    #[structopt(long = "json")]
+    pub json: bool,
+
     /// The subcommand variant.
     #[structopt(subcommand)]
     pub command: Command,
@@ -6,6 +6,7 @@ use std::io;
 
 use failure::Fail;
 use hex::FromHexError;
+use serde_json::json;
 use serde_json::Value as JsonValue;
 
 use zinc_build::ValueError as BuildValueError;
@@ -72,6 +73,10 @@ pub enum Error {
         found: String,
     },
 
+    /// The random number generator seed size is invalid.
+    #[fail(display = "seed must be {} bytes, but found {}", expected, found)]
+    InvalidSeedSize { expected: usize, found: usize },
+
     /// The method name is not specified.
     #[fail(display = "method name is missing")]
     MethodNameNotFound,
@@ -91,9 +96,60 @@ pub enum Error {
         found: JsonValue,
     },
 
+    /// The environment JSON is invalid.
+    #[fail(display = "environment is invalid: {}", inner)]
+    InvalidEnvironment {
+        inner: serde_json::Error,
+        found: JsonValue,
+    },
+
+    /// The proving key is omitted and not found in the proving key cache.
+    #[fail(
+        display = "proving key is not specified and not found in the cache for hash `{}`",
+        hash
+    )]
+    ProvingKeyNotCached { hash: String },
+
     /// The contract storage JSON is invalid.
     #[fail(display = "contract storage must be an array, but found `{}`", found)]
     InvalidContractStorageFormat { found: JsonValue },
+
+    /// The contract storage JSON field count does not match the contract storage layout.
+    #[fail(
+        display = "contract storage must have {} fields, but found {}",
+        expected, found
+    )]
+    ContractStorageFieldCount { expected: usize, found: usize },
+
+    /// The contract storage JSON field does not match the contract storage layout.
+    #[fail(
+        display = "contract storage field #{} `{}` is invalid: {}",
+        index, name, inner
+    )]
+    ContractStorageFieldType {
+        index: usize,
+        name: String,
+        inner: BuildValueError,
+    },
+}
+
+impl Error {
+    ///
+    /// Converts the error into a JSON object.
+    ///
+    /// The runtime errors are converted with their stable codes and context fields, whereas
+    /// the rest are given the generic code.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Runtime(inner) => inner.to_json(),
+            error => json!({
+                "code": "virtual_machine_error",
+                "message": error.to_string(),
+                "context": {},
+            }),
+        }
+    }
 }
 
 impl From<RuntimeError> for Error {
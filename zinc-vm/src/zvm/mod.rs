@@ -17,6 +17,10 @@ fn main() {
 
     match args.command.execute() {
         Ok(exit_code) => process::exit(exit_code),
+        Err(error) if args.json => {
+            eprintln!("{}", error.to_json());
+            process::exit(zinc_const::exit_code::FAILURE);
+        }
         Err(error) => {
             eprintln!("{}", error);
             process::exit(zinc_const::exit_code::FAILURE);
@@ -0,0 +1,18 @@
+//!
+//! The Zandbox privileged request credentials error.
+//!
+
+use failure::Fail;
+
+///
+/// The Zandbox privileged request credentials error.
+///
+#[derive(Debug, Fail)]
+pub enum Error {
+    /// The nonce message cannot be signed with the private key.
+    #[fail(display = "signing: {}", _0)]
+    Signing(String),
+    /// The signature is malformed or the signer cannot be recovered from it.
+    #[fail(display = "invalid signature: {}", _0)]
+    InvalidSignature(String),
+}
@@ -0,0 +1,83 @@
+//!
+//! The Zandbox privileged request credentials.
+//!
+
+pub mod error;
+
+use rustc_hex::FromHex;
+use rustc_hex::ToHex;
+
+use zksync::web3::types::Address;
+use zksync::web3::types::H256;
+use zksync_types::tx::PackedEthSignature;
+
+use self::error::Error;
+
+///
+/// The credentials of a privileged Zandbox request, e.g. re-publishing a contract.
+///
+/// The contract owner signs the server-provided single-use nonce with the EIP-191 personal
+/// message signature, and the server recovers the signer address to compare it with the owner.
+///
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    /// The nonce issued by the Zandbox server.
+    pub nonce: String,
+    /// The owner signature of the nonce message.
+    pub signature: PackedEthSignature,
+}
+
+impl Credentials {
+    ///
+    /// Signs the `nonce` message with the `private_key`.
+    ///
+    pub fn sign(nonce: String, private_key: &H256) -> Result<Self, Error> {
+        let signature =
+            PackedEthSignature::sign(private_key, Self::message(nonce.as_str()).as_bytes())
+                .map_err(|error| Error::Signing(error.to_string()))?;
+
+        Ok(Self { nonce, signature })
+    }
+
+    ///
+    /// Parses the credentials from the request header values, where the signature is a hex
+    /// string with an optional `0x` prefix.
+    ///
+    pub fn try_from_headers(nonce: &str, signature: &str) -> Result<Self, Error> {
+        let bytes: Vec<u8> = signature
+            .trim_start_matches("0x")
+            .from_hex()
+            .map_err(|error| Error::InvalidSignature(error.to_string()))?;
+        let signature = PackedEthSignature::deserialize_packed(bytes.as_slice())
+            .map_err(|error| Error::InvalidSignature(error.to_string()))?;
+
+        Ok(Self {
+            nonce: nonce.to_owned(),
+            signature,
+        })
+    }
+
+    ///
+    /// Recovers the ETH address of the nonce message signer.
+    ///
+    pub fn recover_signer(&self) -> Result<Address, Error> {
+        self.signature
+            .signature_recover_signer(Self::message(self.nonce.as_str()).as_bytes())
+            .map_err(|error| Error::InvalidSignature(error.to_string()))
+    }
+
+    ///
+    /// Returns the signature as a `0x`-prefixed hex string, which is sent in the request header.
+    ///
+    pub fn signature_hex(&self) -> String {
+        let bytes = self.signature.serialize_packed();
+        format!("0x{}", bytes.to_hex::<String>())
+    }
+
+    ///
+    /// The message, which is signed by the contract owner.
+    ///
+    pub fn message(nonce: &str) -> String {
+        format!("Zandbox authorization nonce: {}", nonce)
+    }
+}
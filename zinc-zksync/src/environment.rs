@@ -0,0 +1,60 @@
+//!
+//! The environment data which is available to the smart contract method.
+//!
+
+use std::convert::TryFrom;
+
+use num::BigInt;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+///
+/// The environment data which is available to the smart contract method.
+///
+/// Represented by the implicit `zksync::env` variable.
+///
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Environment {
+    /// The current block timestamp.
+    pub timestamp: u64,
+    /// The current block number.
+    pub block_number: u64,
+}
+
+impl Environment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(timestamp: u64, block_number: u64) -> Self {
+        Self {
+            timestamp,
+            block_number,
+        }
+    }
+
+    ///
+    /// Flattens the environment in the order of the `zksync::Environment` structure fields.
+    ///
+    pub fn into_flat_values(self) -> Vec<BigInt> {
+        vec![
+            BigInt::from(self.timestamp),
+            BigInt::from(self.block_number),
+        ]
+    }
+}
+
+impl TryFrom<&JsonValue> for Environment {
+    type Error = serde_json::Error;
+
+    ///
+    /// Parses the environment, which defaults to zero values if the JSON value is `null`.
+    ///
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        match value {
+            JsonValue::Null => Ok(Self::default()),
+            value => serde_json::from_value(value.to_owned()),
+        }
+    }
+}
@@ -2,25 +2,39 @@
 //! The Zinc source code JSON representation.
 //!
 
+pub(crate) mod auth;
+pub(crate) mod environment;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod source;
 pub(crate) mod transaction;
 pub(crate) mod utils;
 
+pub use self::auth::error::Error as CredentialsError;
+pub use self::auth::Credentials;
+pub use self::environment::Environment;
 pub use self::request::call::Body as CallRequestBody;
 pub use self::request::call::Query as CallRequestQuery;
 pub use self::request::fee::Body as FeeRequestBody;
 pub use self::request::fee::Query as FeeRequestQuery;
 pub use self::request::initialize::Body as InitializeRequestBody;
 pub use self::request::initialize::Query as InitializeRequestQuery;
+pub use self::request::key::Body as KeyRequestBody;
+pub use self::request::key::Query as KeyRequestQuery;
 pub use self::request::publish::Body as PublishRequestBody;
 pub use self::request::publish::Query as PublishRequestQuery;
 pub use self::request::query::Body as QueryRequestBody;
 pub use self::request::query::Query as QueryRequestQuery;
+pub use self::request::verify::Body as VerifyRequestBody;
+pub use self::request::verify::Path as VerifyRequestPath;
 pub use self::response::fee::Body as FeeResponseBody;
 pub use self::response::initialize::Body as InitializeResponseBody;
+pub use self::response::job::Body as JobResponseBody;
+pub use self::response::job::Status as JobStatus;
+pub use self::response::nonce::Body as NonceResponseBody;
+pub use self::response::publish::AcceptedBody as PublishAcceptedResponseBody;
 pub use self::response::publish::Body as PublishResponseBody;
+pub use self::response::verify::Body as VerifyResponseBody;
 pub use self::source::directory::Directory;
 pub use self::source::error::Error as SourceError;
 pub use self::source::file::File;
@@ -0,0 +1,70 @@
+//!
+//! The contract resource `key` PUT request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync::web3::types::H256;
+use zksync::Network;
+use zksync_types::Address;
+
+///
+/// The contract resource `key` PUT request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The network where the contract resides.
+    pub network: Network,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: Address, network: Network) -> Self {
+        Self { address, network }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![
+            (
+                "address",
+                serde_json::to_string(&self.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+                    .replace("\"", ""),
+            ),
+            ("network", self.network.to_string()),
+        ]
+        .into_iter()
+    }
+}
+
+///
+/// The contract resource `key` PUT request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The new ETH private key of the contract, whose zkSync signing key must have already been
+    /// set with the change-pubkey transaction.
+    pub private_key: H256,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(private_key: H256) -> Self {
+        Self { private_key }
+    }
+}
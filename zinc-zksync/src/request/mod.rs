@@ -5,5 +5,7 @@
 pub mod call;
 pub mod fee;
 pub mod initialize;
+pub mod key;
 pub mod publish;
 pub mod query;
+pub mod verify;
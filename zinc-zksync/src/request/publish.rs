@@ -2,6 +2,7 @@
 //! The contract resource POST request.
 //!
 
+use std::collections::HashMap;
 use std::iter::IntoIterator;
 
 use serde::Deserialize;
@@ -25,18 +26,28 @@ pub struct Query {
     pub instance: String,
     /// The network where the contract must be uploaded to.
     pub network: Network,
+    /// Whether the contract is published within the request instead of a background job.
+    #[serde(default)]
+    pub sync: bool,
 }
 
 impl Query {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(name: String, version: String, instance: String, network: Network) -> Self {
+    pub fn new(
+        name: String,
+        version: String,
+        instance: String,
+        network: Network,
+        sync: bool,
+    ) -> Self {
         Self {
             name,
             version,
             instance,
             network,
+            sync,
         }
     }
 }
@@ -52,6 +63,7 @@ impl IntoIterator for Query {
             ("version", self.version),
             ("instance", self.instance),
             ("network", self.network.to_string()),
+            ("sync", self.sync.to_string()),
         ]
         .into_iter()
     }
@@ -70,23 +82,46 @@ pub struct Body {
     pub arguments: JsonValue,
     /// The verifying key.
     pub verifying_key: Vec<u8>,
+    /// The verifying keys of the contract methods, where the keys are the method names.
+    #[serde(default)]
+    pub verifying_keys: HashMap<String, Vec<u8>>,
+    /// The storage field renames applied if the contract instance is re-published,
+    /// where the keys are the old field names and the values are the new ones.
+    #[serde(default)]
+    pub renames: HashMap<String, String>,
+    /// The token symbols or addresses the contract is allowed to transfer.
+    /// If the list is empty, any token is allowed.
+    #[serde(default)]
+    pub allowed_tokens: Vec<String>,
+    /// The contract ABI document written by the compiler.
+    #[serde(default)]
+    pub abi: Option<JsonValue>,
 }
 
 impl Body {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         source: Source,
         bytecode: Vec<u8>,
         arguments: JsonValue,
         verifying_key: Vec<u8>,
+        verifying_keys: HashMap<String, Vec<u8>>,
+        renames: HashMap<String, String>,
+        allowed_tokens: Vec<String>,
+        abi: Option<JsonValue>,
     ) -> Self {
         Self {
             source,
             bytecode,
             arguments,
             verifying_key,
+            verifying_keys,
+            renames,
+            allowed_tokens,
+            abi,
         }
     }
 }
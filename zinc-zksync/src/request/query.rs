@@ -22,6 +22,10 @@ pub struct Query {
     pub method: Option<String>,
     /// The network where the contract resides.
     pub network: Network,
+    /// Whether the storage Merkle tree proofs must be returned along with the storage.
+    pub proof: Option<bool>,
+    /// Whether the private storage fields must be returned. Requires the owner credentials.
+    pub private: Option<bool>,
 }
 
 impl Query {
@@ -33,6 +37,8 @@ impl Query {
             address,
             method,
             network,
+            proof: None,
+            private: None,
         }
     }
 }
@@ -43,7 +49,7 @@ impl IntoIterator for Query {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut result = Vec::with_capacity(3);
+        let mut result = Vec::with_capacity(5);
         result.push((
             "address",
             serde_json::to_string(&self.address)
@@ -54,6 +60,12 @@ impl IntoIterator for Query {
             result.push(("method", method));
         }
         result.push(("network", self.network.to_string()));
+        if let Some(proof) = self.proof {
+            result.push(("proof", proof.to_string()));
+        }
+        if let Some(private) = self.private {
+            result.push(("private", private.to_string()));
+        }
         result.into_iter()
     }
 }
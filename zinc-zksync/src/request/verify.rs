@@ -0,0 +1,69 @@
+//!
+//! The contract resource `verify` POST request.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use zksync_types::Address;
+
+use crate::environment::Environment;
+
+///
+/// The contract resource `verify` POST request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The contract ETH address.
+    pub address: Address,
+}
+
+impl Path {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: Address) -> Self {
+        Self { address }
+    }
+}
+
+///
+/// The contract resource `verify` POST request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The name of the method whose proof is verified.
+    pub method: String,
+    /// The JSON method output, which is the proof public input.
+    pub public_input: JsonValue,
+    /// The hex-encoded proof received from the prover.
+    pub proof_hex: String,
+    /// The JSON method arguments, whose `#[public]` ones are committed to by the proof.
+    #[serde(default)]
+    pub arguments: JsonValue,
+    /// The block environment the proof commits to, which defaults to zeros.
+    #[serde(default)]
+    pub env: Environment,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        method: String,
+        public_input: JsonValue,
+        proof_hex: String,
+        arguments: JsonValue,
+        env: Environment,
+    ) -> Self {
+        Self {
+            method,
+            public_input,
+            proof_hex,
+            arguments,
+            env,
+        }
+    }
+}
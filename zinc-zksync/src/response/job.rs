@@ -0,0 +1,115 @@
+//!
+//! The publishing job resource GET response.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::AccountId;
+use zksync_types::Address;
+
+///
+/// The publishing job status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The job has been created, but not started yet.
+    Pending,
+    /// The contract bytecode is being checked and its constructor is being run.
+    Compiling,
+    /// The contract is being written to the server cache and database.
+    Deploying,
+    /// The contract has been published.
+    Done,
+    /// The contract publishing has failed.
+    Failed,
+}
+
+impl Status {
+    ///
+    /// Checks if the job is finished, either successfully or not.
+    ///
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Done | Self::Failed)
+    }
+
+    ///
+    /// The status name, which is also used in the JSON and database representations.
+    ///
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Compiling => "compiling",
+            Self::Deploying => "deploying",
+            Self::Done => "done",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "compiling" => Ok(Self::Compiling),
+            "deploying" => Ok(Self::Deploying),
+            "done" => Ok(Self::Done),
+            "failed" => Ok(Self::Failed),
+            another => Err(another.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+///
+/// The publishing job resource GET response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The job ID.
+    pub id: String,
+    /// The job status.
+    pub status: Status,
+    /// The error details, which are only set if the job has failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The published contract address, which is only set if the job is done.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// The contract account ID, which is only set if an initialized instance has been
+    /// re-published and does not require the initialization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountId>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        id: String,
+        status: Status,
+        error: Option<String>,
+        address: Option<Address>,
+        account_id: Option<AccountId>,
+    ) -> Self {
+        Self {
+            id,
+            status,
+            error,
+            address,
+            account_id,
+        }
+    }
+}
@@ -4,4 +4,7 @@
 
 pub mod fee;
 pub mod initialize;
+pub mod job;
+pub mod nonce;
 pub mod publish;
+pub mod verify;
@@ -0,0 +1,26 @@
+//!
+//! The contract resource `nonce` POST response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract resource `nonce` POST response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The single-use nonce, which must be signed by the contract owner.
+    pub nonce: String,
+    /// The nonce lifetime in seconds.
+    pub expires_in: u64,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(nonce: String, expires_in: u64) -> Self {
+        Self { nonce, expires_in }
+    }
+}
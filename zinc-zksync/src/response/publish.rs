@@ -5,6 +5,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use zksync_types::AccountId;
 use zksync_types::Address;
 
 ///
@@ -14,6 +15,9 @@ use zksync_types::Address;
 pub struct Body {
     /// The contract address.
     pub address: Address,
+    /// The contract account ID, which is only set if an initialized instance has been
+    /// re-published and does not require the initialization.
+    pub account_id: Option<AccountId>,
 }
 
 impl Body {
@@ -21,6 +25,38 @@ impl Body {
     /// A shortcut constructor.
     ///
     pub fn new(address: Address) -> Self {
-        Self { address }
+        Self {
+            address,
+            account_id: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_republished(address: Address, account_id: AccountId) -> Self {
+        Self {
+            address,
+            account_id: Some(account_id),
+        }
+    }
+}
+
+///
+/// The contract resource POST response body, which is returned if the contract is published
+/// by a background job.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AcceptedBody {
+    /// The publishing job ID, which is used to poll the job status.
+    pub job_id: String,
+}
+
+impl AcceptedBody {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(job_id: String) -> Self {
+        Self { job_id }
     }
 }
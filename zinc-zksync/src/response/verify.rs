@@ -0,0 +1,24 @@
+//!
+//! The contract resource `verify` POST response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract resource `verify` POST response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// Whether the proof is valid for the specified public input.
+    pub valid: bool,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(valid: bool) -> Self {
+        Self { valid }
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use failure::Fail;
+use num::BigInt;
 
 ///
 /// The transfer error.
@@ -26,23 +27,34 @@ pub enum Error {
     NotAString(&'static str),
     /// The sender address is invalid.
     #[fail(
-        display = "sender address is invalid: {} (expected `0x[0-9A-Fa-f]{{40}}`)",
+        display = "sender address is invalid: {} (expected a hexadecimal, EIP-55 checksummed, or decimal address)",
         _0
     )]
-    SenderAddressInvalid(rustc_hex::FromHexError),
+    SenderAddressInvalid(zinc_math::EthAddressError),
     /// The recipient address is invalid.
     #[fail(
-        display = "recipient address is invalid: {} (expected `0x[0-9A-Fa-f]{{40}}`)",
+        display = "recipient address is invalid: {} (expected a hexadecimal, EIP-55 checksummed, or decimal address)",
         _0
     )]
-    RecipientAddressInvalid(rustc_hex::FromHexError),
+    RecipientAddressInvalid(zinc_math::EthAddressError),
     /// The token address is invalid.
     #[fail(
-        display = "token address address is invalid: {} (expected `0x[0-9A-Fa-f]{{40}}`)",
+        display = "token address is invalid: {} (expected a hexadecimal, EIP-55 checksummed, or decimal address)",
         _0
     )]
-    TokenAddressInvalid(rustc_hex::FromHexError),
+    TokenAddressInvalid(zinc_math::EthAddressError),
     /// The amount is invalid.
     #[fail(display = "amount is invalid: {} (expected a decimal number)", _0)]
     AmountInvalid(zinc_math::BigIntError),
+    /// The amount is negative or does not fit the balance type.
+    #[fail(
+        display = "amount `{}` is out of range: expected from 0 to {}",
+        value, max
+    )]
+    AmountOutOfRange {
+        /// The invalid amount.
+        value: BigInt,
+        /// The maximal allowed amount.
+        max: BigInt,
+    },
 }
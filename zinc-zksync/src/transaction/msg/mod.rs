@@ -6,12 +6,16 @@ pub mod error;
 
 use std::convert::TryFrom;
 
+use num::Signed;
+use num_old::Zero;
 use serde::Deserialize;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
 
 use zksync_types::Address;
 
+use zinc_build::IntegerType;
+
 use self::error::Error;
 
 ///
@@ -71,6 +75,15 @@ impl Msg {
             amount,
         }
     }
+
+    ///
+    /// Whether the message transfers a non-zero amount of tokens.
+    ///
+    /// The zero-amount messages are used only to pay the fee or as padding.
+    ///
+    pub fn is_transfer(&self) -> bool {
+        !self.amount.is_zero()
+    }
 }
 
 impl TryFrom<&JsonValue> for Msg {
@@ -102,7 +115,9 @@ impl TryFrom<JsonMap<String, JsonValue>> for Msg {
         let from = from
             .as_str()
             .ok_or(Error::NotAString(Self::FIELD_NAME_SENDER))?;
-        let from: Address = from[2..].parse().map_err(Error::SenderAddressInvalid)?;
+        let from: Address = zinc_math::eth_address_from_str(from)
+            .map(Address::from)
+            .map_err(Error::SenderAddressInvalid)?;
 
         let to = value
             .remove(Self::FIELD_NAME_RECIPIENT)
@@ -110,7 +125,9 @@ impl TryFrom<JsonMap<String, JsonValue>> for Msg {
         let to = to
             .as_str()
             .ok_or(Error::NotAString(Self::FIELD_NAME_RECIPIENT))?;
-        let to: Address = to[2..].parse().map_err(Error::RecipientAddressInvalid)?;
+        let to: Address = zinc_math::eth_address_from_str(to)
+            .map(Address::from)
+            .map_err(Error::RecipientAddressInvalid)?;
 
         let token_address = value
             .remove(Self::FIELD_NAME_TOKEN_ADDRESS)
@@ -118,8 +135,8 @@ impl TryFrom<JsonMap<String, JsonValue>> for Msg {
         let token_address = token_address
             .as_str()
             .ok_or(Error::NotAString(Self::FIELD_NAME_TOKEN_ADDRESS))?;
-        let token_address: Address = token_address[2..]
-            .parse()
+        let token_address: Address = zinc_math::eth_address_from_str(token_address)
+            .map(Address::from)
             .map_err(Error::TokenAddressInvalid)?;
 
         let amount = value
@@ -128,8 +145,12 @@ impl TryFrom<JsonMap<String, JsonValue>> for Msg {
         let amount = amount
             .as_str()
             .ok_or(Error::NotAString(Self::FIELD_NAME_AMOUNT))?;
-        let amount: num_old::BigUint = zinc_math::bigint_from_str(amount)
-            .map_err(Error::AmountInvalid)?
+        let amount = zinc_math::bigint_from_str(amount).map_err(Error::AmountInvalid)?;
+        let max = IntegerType::BALANCE.max();
+        if amount.is_negative() || amount > max {
+            return Err(Error::AmountOutOfRange { value: amount, max });
+        }
+        let amount: num_old::BigUint = amount
             .to_biguint()
             .map(crate::utils::num_compat_backward)
             .expect(zinc_const::panic::DATA_CONVERSION);